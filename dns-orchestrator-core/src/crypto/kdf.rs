@@ -0,0 +1,286 @@
+//! 密钥派生算法
+//!
+//! 密文前附带 KDF 前缀，记录派生密钥所用的算法与参数：
+//! - `0x01` + 迭代次数（u32 大端）：PBKDF2-HMAC-SHA256
+//! - `0x02` + `m_cost`、`t_cost`、`p_cost`（各 u32 大端）：Argon2id (RFC 9106)
+//!
+//! 参数随密文保存，调整默认参数后旧数据仍可解密。
+
+use pbkdf2::pbkdf2_hmac_array;
+use sha2::Sha256;
+
+use crate::error::{CoreError, CoreResult};
+
+use super::{KEY_LENGTH, PBKDF2_ITERATIONS};
+
+/// Argon2id 默认内存开销（KiB），OWASP 推荐的 19 MiB
+pub const ARGON2_DEFAULT_M_COST: u32 = 19 * 1024;
+/// Argon2id 默认迭代次数
+pub const ARGON2_DEFAULT_T_COST: u32 = 2;
+/// Argon2id 默认并行度
+pub const ARGON2_DEFAULT_P_COST: u32 = 1;
+
+// 解析前缀时接受的参数上限：超出视为无前缀的旧数据，
+// 避免随机字节被误当作参数导致巨量内存或计算开销
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+const MAX_ARGON2_M_COST: u32 = 1024 * 1024; // 1 GiB
+const MAX_ARGON2_T_COST: u32 = 16;
+const MAX_ARGON2_P_COST: u32 = 16;
+
+/// 密文 KDF 前缀的版本字节
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CryptoVersion {
+    /// PBKDF2-HMAC-SHA256
+    Pbkdf2 = 0x01,
+    /// Argon2id
+    Argon2id = 0x02,
+}
+
+impl CryptoVersion {
+    /// 从版本字节解析
+    #[must_use]
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x01 => Some(Self::Pbkdf2),
+            0x02 => Some(Self::Argon2id),
+            _ => None,
+        }
+    }
+}
+
+/// 密钥派生算法及参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfAlgorithm {
+    /// PBKDF2-HMAC-SHA256
+    Pbkdf2 { iterations: u32 },
+    /// Argon2id，`m_cost` 单位为 KiB
+    Argon2id {
+        m_cost: u32,
+        t_cost: u32,
+        p_cost: u32,
+    },
+}
+
+impl Default for KdfAlgorithm {
+    /// 启用 `argon2` feature 时默认使用 Argon2id，否则使用 PBKDF2
+    fn default() -> Self {
+        if cfg!(feature = "argon2") {
+            Self::Argon2id {
+                m_cost: ARGON2_DEFAULT_M_COST,
+                t_cost: ARGON2_DEFAULT_T_COST,
+                p_cost: ARGON2_DEFAULT_P_COST,
+            }
+        } else {
+            Self::Pbkdf2 {
+                iterations: PBKDF2_ITERATIONS,
+            }
+        }
+    }
+}
+
+impl KdfAlgorithm {
+    /// 对应的前缀版本字节
+    #[must_use]
+    pub const fn version(&self) -> CryptoVersion {
+        match self {
+            Self::Pbkdf2 { .. } => CryptoVersion::Pbkdf2,
+            Self::Argon2id { .. } => CryptoVersion::Argon2id,
+        }
+    }
+
+    /// 编码为密文前缀
+    pub(super) fn encode_prefix(&self) -> Vec<u8> {
+        let params: &[u32] = match self {
+            Self::Pbkdf2 { iterations } => &[*iterations],
+            Self::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => &[*m_cost, *t_cost, *p_cost],
+        };
+        let mut prefix = vec![self.version() as u8];
+        for param in params {
+            prefix.extend_from_slice(&param.to_be_bytes());
+        }
+        prefix
+    }
+
+    /// 解析密文前缀，返回算法与剩余的密文
+    ///
+    /// 版本字节未知、长度不足或参数超出合理范围时返回 `None`（视为无前缀的旧数据）。
+    pub(super) fn decode_prefix(data: &[u8]) -> Option<(Self, &[u8])> {
+        let (&version, rest) = data.split_first()?;
+        match CryptoVersion::from_byte(version)? {
+            CryptoVersion::Pbkdf2 => {
+                let (iterations, rest) = read_u32(rest)?;
+                (1..=MAX_PBKDF2_ITERATIONS)
+                    .contains(&iterations)
+                    .then_some((Self::Pbkdf2 { iterations }, rest))
+            }
+            CryptoVersion::Argon2id => {
+                let (m_cost, rest) = read_u32(rest)?;
+                let (t_cost, rest) = read_u32(rest)?;
+                let (p_cost, rest) = read_u32(rest)?;
+                let valid = (8..=MAX_ARGON2_M_COST).contains(&m_cost)
+                    && (1..=MAX_ARGON2_T_COST).contains(&t_cost)
+                    && (1..=MAX_ARGON2_P_COST).contains(&p_cost)
+                    && m_cost >= 8 * p_cost;
+                valid.then_some((
+                    Self::Argon2id {
+                        m_cost,
+                        t_cost,
+                        p_cost,
+                    },
+                    rest,
+                ))
+            }
+        }
+    }
+
+    /// 从密码派生加密密钥
+    pub(super) fn derive_key(&self, password: &str, salt: &[u8]) -> CoreResult<[u8; KEY_LENGTH]> {
+        match *self {
+            Self::Pbkdf2 { iterations } => Ok(pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(
+                password.as_bytes(),
+                salt,
+                iterations,
+            )),
+            Self::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => derive_argon2id(password, salt, m_cost, t_cost, p_cost),
+        }
+    }
+}
+
+fn read_u32(data: &[u8]) -> Option<(u32, &[u8])> {
+    let (bytes, rest) = data.split_first_chunk::<4>()?;
+    Some((u32::from_be_bytes(*bytes), rest))
+}
+
+#[cfg(feature = "argon2")]
+fn derive_argon2id(
+    password: &str,
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> CoreResult<[u8; KEY_LENGTH]> {
+    use argon2::{Algorithm, Argon2, Params, Version};
+
+    let params = Params::new(m_cost, t_cost, p_cost, Some(KEY_LENGTH))
+        .map_err(|e| CoreError::SerializationError(format!("Invalid Argon2 parameters: {e}")))?;
+    let mut key = [0u8; KEY_LENGTH];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| CoreError::SerializationError(format!("Key derivation failed: {e}")))?;
+    Ok(key)
+}
+
+#[cfg(not(feature = "argon2"))]
+fn derive_argon2id(
+    _password: &str,
+    _salt: &[u8],
+    _m_cost: u32,
+    _t_cost: u32,
+    _p_cost: u32,
+) -> CoreResult<[u8; KEY_LENGTH]> {
+    Err(CoreError::SerializationError(
+        "Argon2id key derivation requires the `argon2` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARGON2_SMALL: KdfAlgorithm = KdfAlgorithm::Argon2id {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+
+    #[test]
+    fn prefix_round_trips_and_keeps_the_body() {
+        for kdf in [KdfAlgorithm::Pbkdf2 { iterations: 1_000 }, ARGON2_SMALL] {
+            let mut data = kdf.encode_prefix();
+            assert_eq!(CryptoVersion::from_byte(data[0]), Some(kdf.version()));
+            data.extend_from_slice(b"body");
+            assert_eq!(
+                KdfAlgorithm::decode_prefix(&data),
+                Some((kdf, b"body".as_slice()))
+            );
+        }
+    }
+
+    #[test]
+    fn argon2_prefix_requires_enough_memory_per_lane() {
+        let mut data = vec![CryptoVersion::Argon2id as u8];
+        for param in [15u32, 1, 2] {
+            data.extend_from_slice(&param.to_be_bytes());
+        }
+        assert_eq!(KdfAlgorithm::decode_prefix(&data), None);
+
+        // 长度不足
+        assert_eq!(
+            KdfAlgorithm::decode_prefix(&ARGON2_SMALL.encode_prefix()[..9]),
+            None
+        );
+    }
+
+    #[test]
+    fn pbkdf2_key_depends_on_password_and_salt() -> CoreResult<()> {
+        let kdf = KdfAlgorithm::Pbkdf2 { iterations: 1_000 };
+        let key = kdf.derive_key("pw", b"salt-1")?;
+        assert_eq!(kdf.derive_key("pw", b"salt-1")?, key);
+        assert_ne!(kdf.derive_key("pw", b"salt-2")?, key);
+        assert_ne!(kdf.derive_key("other", b"salt-1")?, key);
+        assert_ne!(
+            KdfAlgorithm::Pbkdf2 { iterations: 1_001 }.derive_key("pw", b"salt-1")?,
+            key
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "argon2")]
+    #[test]
+    fn argon2id_key_depends_on_parameters() -> CoreResult<()> {
+        let salt = [7u8; 16];
+        let key = ARGON2_SMALL.derive_key("pw", &salt)?;
+        assert_eq!(ARGON2_SMALL.derive_key("pw", &salt)?, key);
+        let slower = KdfAlgorithm::Argon2id {
+            m_cost: 64,
+            t_cost: 2,
+            p_cost: 1,
+        };
+        assert_ne!(slower.derive_key("pw", &salt)?, key);
+        assert_ne!(
+            KdfAlgorithm::Pbkdf2 { iterations: 1_000 }.derive_key("pw", &salt)?,
+            key
+        );
+
+        // 盐过短时 Argon2 拒绝派生
+        assert!(matches!(
+            ARGON2_SMALL.derive_key("pw", b"short"),
+            Err(CoreError::SerializationError(_))
+        ));
+        Ok(())
+    }
+
+    #[cfg(not(feature = "argon2"))]
+    #[test]
+    fn argon2id_requires_the_feature() {
+        assert_eq!(
+            KdfAlgorithm::default(),
+            KdfAlgorithm::Pbkdf2 {
+                iterations: PBKDF2_ITERATIONS
+            }
+        );
+        assert!(matches!(
+            ARGON2_SMALL.derive_key("pw", &[7u8; 16]),
+            Err(CoreError::SerializationError(_))
+        ));
+    }
+}
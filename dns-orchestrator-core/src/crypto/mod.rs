@@ -1,19 +1,25 @@
 //! 加密模块
 //!
 //! 提供 AES-256-GCM 加密/解密功能，用于账户导入导出的加密保护。
+//! 密钥派生支持 PBKDF2-HMAC-SHA256 与 Argon2id（需启用 `argon2` feature），见 [`KdfAlgorithm`]。
 
+mod kdf;
 mod versions;
 
-pub use versions::{get_current_iterations, get_pbkdf2_iterations, CURRENT_FILE_VERSION};
+pub use kdf::{
+    CryptoVersion, KdfAlgorithm, ARGON2_DEFAULT_M_COST, ARGON2_DEFAULT_P_COST,
+    ARGON2_DEFAULT_T_COST,
+};
+pub use versions::{
+    get_current_iterations, get_pbkdf2_iterations, has_kdf_prefix, CURRENT_FILE_VERSION,
+};
 
 use aes_gcm::{
     aead::{Aead, KeyInit},
     Aes256Gcm, Nonce,
 };
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
-use pbkdf2::pbkdf2_hmac_array;
 use rand::RngCore;
-use sha2::Sha256;
 
 use crate::error::{CoreError, CoreResult};
 
@@ -23,25 +29,32 @@ const SALT_LENGTH: usize = 16;
 const NONCE_LENGTH: usize = 12;
 const KEY_LENGTH: usize = 32; // AES-256
 
-/// 从密码派生加密密钥（支持自定义迭代次数）
-fn derive_key_with_iterations(password: &str, salt: &[u8], iterations: u32) -> [u8; KEY_LENGTH] {
-    pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(password.as_bytes(), salt, iterations)
-}
-
-/// 从密码派生加密密钥（使用默认迭代次数）
-fn derive_key(password: &str, salt: &[u8]) -> [u8; KEY_LENGTH] {
-    derive_key_with_iterations(password, salt, PBKDF2_ITERATIONS)
+/// 加密数据（使用默认密钥派生算法，见 [`KdfAlgorithm::default`]）
+///
+/// # Arguments
+/// * `plaintext` - 要加密的明文数据
+/// * `password` - 加密密码
+///
+/// # Returns
+/// 返回 (`salt_base64`, `nonce_base64`, `ciphertext_base64`) 元组，密文带 KDF 前缀
+pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String, String)> {
+    encrypt_with_kdf(plaintext, password, KdfAlgorithm::default())
 }
 
-/// 加密数据
+/// 使用指定密钥派生算法加密数据
 ///
 /// # Arguments
 /// * `plaintext` - 要加密的明文数据
 /// * `password` - 加密密码
+/// * `kdf` - 密钥派生算法及参数，编码在密文前缀中
 ///
 /// # Returns
 /// 返回 (`salt_base64`, `nonce_base64`, `ciphertext_base64`) 元组
-pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String, String)> {
+pub fn encrypt_with_kdf(
+    plaintext: &[u8],
+    password: &str,
+    kdf: KdfAlgorithm,
+) -> CoreResult<(String, String, String)> {
     // 生成随机盐和 nonce
     let mut salt = [0u8; SALT_LENGTH];
     let mut nonce_bytes = [0u8; NONCE_LENGTH];
@@ -49,7 +62,7 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String,
     rand::rng().fill_bytes(&mut nonce_bytes);
 
     // 派生密钥
-    let key = derive_key(password, &salt);
+    let key = kdf.derive_key(password, &salt)?;
 
     // 创建加密器
     let cipher = Aes256Gcm::new_from_slice(&key)
@@ -61,15 +74,21 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String,
         .encrypt(nonce, plaintext)
         .map_err(|e| CoreError::SerializationError(format!("Encryption failed: {e}")))?;
 
+    let mut payload = kdf.encode_prefix();
+    payload.extend_from_slice(&ciphertext);
+
     Ok((
         BASE64.encode(salt),
         BASE64.encode(nonce_bytes),
-        BASE64.encode(ciphertext),
+        BASE64.encode(payload),
     ))
 }
 
 /// 解密数据
 ///
+/// 根据密文的 KDF 前缀选择密钥派生算法；没有前缀（或按前缀解密失败）时
+/// 按旧格式处理，使用当前版本的 PBKDF2 迭代次数。
+///
 /// # Arguments
 /// * `ciphertext_b64` - Base64 编码的密文
 /// * `password` - 解密密码
@@ -84,16 +103,21 @@ pub fn decrypt(
     salt_b64: &str,
     nonce_b64: &str,
 ) -> CoreResult<Vec<u8>> {
-    decrypt_with_iterations(
-        ciphertext_b64,
-        password,
-        salt_b64,
-        nonce_b64,
-        PBKDF2_ITERATIONS,
-    )
+    let (salt, nonce, ciphertext) = decode_parts(ciphertext_b64, salt_b64, nonce_b64)?;
+
+    // 旧格式密文的首字节是随机的，可能恰好形如前缀，按前缀解密失败时再按旧格式重试
+    let prefixed = KdfAlgorithm::decode_prefix(&ciphertext)
+        .map(|(kdf, body)| decrypt_raw(body, password, &salt, &nonce, kdf));
+    match prefixed {
+        Some(Ok(plaintext)) => Ok(plaintext),
+        Some(Err(e)) => {
+            decrypt_raw(&ciphertext, password, &salt, &nonce, legacy_kdf()).map_err(|_| e)
+        }
+        None => decrypt_raw(&ciphertext, password, &salt, &nonce, legacy_kdf()),
+    }
 }
 
-/// 使用自定义迭代次数解密数据（用于向后兼容）
+/// 使用自定义迭代次数解密无 KDF 前缀的旧格式数据（用于向后兼容）
 ///
 /// # Arguments
 /// * `ciphertext_b64` - Base64 编码的密文
@@ -111,29 +135,154 @@ pub fn decrypt_with_iterations(
     nonce_b64: &str,
     iterations: u32,
 ) -> CoreResult<Vec<u8>> {
-    // 解码 Base64
+    let (salt, nonce, ciphertext) = decode_parts(ciphertext_b64, salt_b64, nonce_b64)?;
+    decrypt_raw(
+        &ciphertext,
+        password,
+        &salt,
+        &nonce,
+        KdfAlgorithm::Pbkdf2 { iterations },
+    )
+}
+
+/// 旧格式（无前缀）密文使用的密钥派生算法
+const fn legacy_kdf() -> KdfAlgorithm {
+    KdfAlgorithm::Pbkdf2 {
+        iterations: PBKDF2_ITERATIONS,
+    }
+}
+
+/// 解码 Base64 编码的盐值、nonce 与密文
+fn decode_parts(
+    ciphertext_b64: &str,
+    salt_b64: &str,
+    nonce_b64: &str,
+) -> CoreResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
     let salt = BASE64
         .decode(salt_b64)
         .map_err(|e| CoreError::SerializationError(format!("Invalid salt: {e}")))?;
-    let nonce_bytes = BASE64
+    let nonce = BASE64
         .decode(nonce_b64)
         .map_err(|e| CoreError::SerializationError(format!("Invalid nonce: {e}")))?;
+    if nonce.len() != NONCE_LENGTH {
+        return Err(CoreError::SerializationError(format!(
+            "Invalid nonce: expected {NONCE_LENGTH} bytes, got {}",
+            nonce.len()
+        )));
+    }
     let ciphertext = BASE64
         .decode(ciphertext_b64)
         .map_err(|e| CoreError::SerializationError(format!("Invalid ciphertext: {e}")))?;
+    Ok((salt, nonce, ciphertext))
+}
 
-    // 使用指定迭代次数派生密钥
-    let key = derive_key_with_iterations(password, &salt, iterations);
+fn decrypt_raw(
+    ciphertext: &[u8],
+    password: &str,
+    salt: &[u8],
+    nonce: &[u8],
+    kdf: KdfAlgorithm,
+) -> CoreResult<Vec<u8>> {
+    let key = kdf.derive_key(password, salt)?;
 
     // 创建解密器
     let cipher = Aes256Gcm::new_from_slice(&key)
         .map_err(|e| CoreError::SerializationError(format!("Failed to create cipher: {e}")))?;
-    let nonce = Nonce::from_slice(&nonce_bytes);
 
     // 解密
-    cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
-        CoreError::SerializationError(
-            "Decryption failed: invalid password or corrupted data".to_string(),
-        )
-    })
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| {
+            CoreError::SerializationError(
+                "Decryption failed: invalid password or corrupted data".to_string(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAST_PBKDF2: KdfAlgorithm = KdfAlgorithm::Pbkdf2 { iterations: 1_000 };
+
+    /// 去掉密文的 KDF 前缀，得到旧格式密文
+    fn strip_prefix(ciphertext_b64: &str) -> CoreResult<String> {
+        let (_, _, data) = decode_parts(ciphertext_b64, "", &BASE64.encode([0u8; NONCE_LENGTH]))?;
+        let (_, body) = KdfAlgorithm::decode_prefix(&data)
+            .ok_or_else(|| CoreError::SerializationError("missing prefix".to_string()))?;
+        Ok(BASE64.encode(body))
+    }
+
+    #[test]
+    fn pbkdf2_payload_round_trips_with_prefix() -> CoreResult<()> {
+        let (salt, nonce, ciphertext) = encrypt_with_kdf(b"secret", "pw", FAST_PBKDF2)?;
+
+        let data = BASE64
+            .decode(&ciphertext)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        assert_eq!(data[0], CryptoVersion::Pbkdf2 as u8);
+        assert_eq!(
+            KdfAlgorithm::decode_prefix(&data).map(|(kdf, _)| kdf),
+            Some(FAST_PBKDF2)
+        );
+
+        assert_eq!(decrypt(&ciphertext, "pw", &salt, &nonce)?, b"secret");
+        assert!(matches!(
+            decrypt(&ciphertext, "wrong", &salt, &nonce),
+            Err(CoreError::SerializationError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_payload_without_prefix_still_decrypts() -> CoreResult<()> {
+        // 旧版本文件：显式指定迭代次数
+        let (salt, nonce, ciphertext) = encrypt_with_kdf(b"legacy", "pw", FAST_PBKDF2)?;
+        let legacy = strip_prefix(&ciphertext)?;
+        assert_eq!(
+            decrypt_with_iterations(&legacy, "pw", &salt, &nonce, 1_000)?,
+            b"legacy"
+        );
+
+        // 无版本信息的旧数据：使用当前版本的迭代次数
+        let (salt, nonce, ciphertext) = encrypt_with_kdf(b"legacy", "pw", legacy_kdf())?;
+        let legacy = strip_prefix(&ciphertext)?;
+        assert_eq!(decrypt(&legacy, "pw", &salt, &nonce)?, b"legacy");
+        Ok(())
+    }
+
+    #[test]
+    fn implausible_prefix_parameters_are_treated_as_legacy() {
+        let mut data = vec![CryptoVersion::Pbkdf2 as u8];
+        data.extend_from_slice(&0u32.to_be_bytes());
+        assert_eq!(KdfAlgorithm::decode_prefix(&data), None);
+
+        let mut data = vec![CryptoVersion::Argon2id as u8];
+        for param in [u32::MAX, 1, 1] {
+            data.extend_from_slice(&param.to_be_bytes());
+        }
+        assert_eq!(KdfAlgorithm::decode_prefix(&data), None);
+
+        assert_eq!(KdfAlgorithm::decode_prefix(&[0x7f, 0, 0, 0, 1]), None);
+        assert_eq!(
+            KdfAlgorithm::decode_prefix(&[CryptoVersion::Pbkdf2 as u8, 0]),
+            None
+        );
+    }
+
+    #[cfg(feature = "argon2")]
+    #[test]
+    fn argon2id_is_the_default_and_round_trips() -> CoreResult<()> {
+        assert_eq!(KdfAlgorithm::default().version(), CryptoVersion::Argon2id);
+
+        let kdf = KdfAlgorithm::Argon2id {
+            m_cost: 64,
+            t_cost: 1,
+            p_cost: 1,
+        };
+        let (salt, nonce, ciphertext) = encrypt_with_kdf(b"secret", "pw", kdf)?;
+        assert_eq!(decrypt(&ciphertext, "pw", &salt, &nonce)?, b"secret");
+        assert!(decrypt(&ciphertext, "wrong", &salt, &nonce).is_err());
+        Ok(())
+    }
 }
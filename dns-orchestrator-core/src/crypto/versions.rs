@@ -6,7 +6,8 @@
 //! - 文件版本号不暴露加密参数，参数在代码中隐式定义
 //! - Version 1: PBKDF2-HMAC-SHA256, 100,000 次迭代
 //! - Version 2: PBKDF2-HMAC-SHA256, 600,000 次迭代（OWASP 2023 推荐）
-//! - 将来可扩展到 Version 3（Argon2 等算法）
+//! - Version 3: 密文带 KDF 前缀（算法与参数随密文保存，默认 Argon2id），
+//!   PBKDF2 沿用 600,000 次迭代
 
 /// Version 1: PBKDF2-HMAC-SHA256, 100,000 次迭代
 const VERSION_1_ITERATIONS: u32 = 100_000;
@@ -14,21 +15,27 @@ const VERSION_1_ITERATIONS: u32 = 100_000;
 /// Version 2: PBKDF2-HMAC-SHA256, 600,000 次迭代（OWASP 2023 推荐）
 const VERSION_2_ITERATIONS: u32 = 600_000;
 
+/// Version 3: 密文带 KDF 前缀
+const VERSION_KDF_PREFIX: u32 = 3;
+
 /// 当前文件格式版本号
 ///
 /// 修改此常量即可切换版本（迭代次数会自动从版本号派生）
-pub const CURRENT_FILE_VERSION: u32 = 2;
+pub const CURRENT_FILE_VERSION: u32 = 3;
 
 /// 获取当前版本的迭代次数（编译时计算）
 ///
 /// 从 `CURRENT_FILE_VERSION` 自动派生，确保加密和解密使用相同参数
 pub const fn get_current_iterations() -> u32 {
-    match get_pbkdf2_iterations(CURRENT_FILE_VERSION) {
-        Some(iterations) => iterations,
-        None => panic!("Invalid CURRENT_FILE_VERSION"),
-    }
+    CURRENT_ITERATIONS
 }
 
+/// 版本号无效时编译失败
+const CURRENT_ITERATIONS: u32 = match get_pbkdf2_iterations(CURRENT_FILE_VERSION) {
+    Some(iterations) => iterations,
+    None => panic!("Invalid CURRENT_FILE_VERSION"),
+};
+
 /// 获取指定文件版本的 PBKDF2 迭代次数
 ///
 /// # Arguments
@@ -40,7 +47,12 @@ pub const fn get_current_iterations() -> u32 {
 pub const fn get_pbkdf2_iterations(version: u32) -> Option<u32> {
     match version {
         1 => Some(VERSION_1_ITERATIONS),
-        2 => Some(VERSION_2_ITERATIONS),
+        2 | VERSION_KDF_PREFIX => Some(VERSION_2_ITERATIONS),
         _ => None,
     }
 }
+
+/// 指定文件版本的密文是否带 KDF 前缀（由 [`super::decrypt`] 自动识别算法）
+pub const fn has_kdf_prefix(version: u32) -> bool {
+    version == VERSION_KDF_PREFIX
+}
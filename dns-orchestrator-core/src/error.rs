@@ -1,5 +1,6 @@
 //! 统一错误类型定义
 
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use thiserror::Error;
 
@@ -7,7 +8,49 @@ use thiserror::Error;
 pub use dns_orchestrator_provider::{CredentialValidationError, ProviderError};
 
 /// 核心层错误类型
-#[derive(Error, Debug, Serialize)]
+///
+/// 服务商返回的错误保留在 [`CoreError::Provider`] 中，调用方通常按“需要用户处理 /
+/// 可重试 / 其他”分类处理：
+///
+/// ```
+/// use dns_orchestrator_core::{CoreError, ProviderError};
+///
+/// enum Action {
+///     /// 凭证失效，引导用户重新填写
+///     Reauthenticate,
+///     /// 网络或限流问题，稍后重试
+///     Retry,
+///     /// 请求本身有误，向用户展示原因
+///     ShowMessage(String),
+/// }
+///
+/// fn classify(error: &CoreError) -> Action {
+///     match error {
+///         CoreError::InvalidCredentials(_)
+///         | CoreError::CredentialsExpired { .. }
+///         | CoreError::Provider(ProviderError::InvalidCredentials { .. }) => Action::Reauthenticate,
+///         CoreError::NetworkError(_)
+///         | CoreError::Timeout { .. }
+///         | CoreError::Provider(
+///             ProviderError::NetworkError { .. } | ProviderError::QuotaExceeded { .. },
+///         ) => Action::Retry,
+///         other => Action::ShowMessage(other.to_string()),
+///     }
+/// }
+///
+/// let offline = CoreError::from(ProviderError::NetworkError {
+///     provider: "cloudflare".to_string(),
+///     detail: "connection reset".to_string(),
+/// });
+/// assert!(matches!(classify(&offline), Action::Retry));
+///
+/// let invalid = CoreError::ValidationError("TTL must be at least 60".to_string());
+/// assert!(matches!(
+///     classify(&invalid),
+///     Action::ShowMessage(message) if message == "Validation error: TTL must be at least 60"
+/// ));
+/// ```
+#[derive(Error, Debug, Clone, Serialize)]
 #[serde(tag = "code", content = "details")]
 pub enum CoreError {
     /// Provider 未找到
@@ -26,6 +69,10 @@ pub enum CoreError {
     #[error("Record not found: {0}")]
     RecordNotFound(String),
 
+    /// 权重灰度任务未找到
+    #[error("Rollout not found: {0}")]
+    RolloutNotFound(String),
+
     /// 凭证存储错误
     #[error("Credential error: {0}")]
     CredentialError(String),
@@ -42,6 +89,15 @@ pub enum CoreError {
     #[error("Invalid credentials for: {0}")]
     InvalidCredentials(String),
 
+    /// 凭证（API token）已过到期时间
+    #[error(
+        "Credentials of account {account_id} expired at {expired_at}, please update the API token"
+    )]
+    CredentialsExpired {
+        account_id: String,
+        expired_at: DateTime<Utc>,
+    },
+
     /// 序列化错误
     #[error("Serialization error: {0}")]
     SerializationError(String),
@@ -50,6 +106,11 @@ pub enum CoreError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// 记录内容校验失败，`field` 为第一个出错的字段（`name`、`ttl` 或记录内容中的字段名）
+    #[error("Validation error: {message}")]
+    #[serde(rename = "RECORD_VALIDATION")]
+    RecordValidation { field: String, message: String },
+
     /// 导入导出错误
     #[error("Import/Export error: {0}")]
     ImportExportError(String),
@@ -68,6 +129,7 @@ pub enum CoreError {
 
     /// 网络错误
     #[error("Network error: {0}")]
+    #[serde(rename = "NETWORK_ERROR")]
     NetworkError(String),
 
     /// 需要迁移数据格式（v1.7.0 凭证格式升级）
@@ -78,6 +140,47 @@ pub enum CoreError {
     #[error("Migration failed: {0}")]
     MigrationFailed(String),
 
+    /// Provider 调用超时
+    #[error("Operation timed out: {operation} ({timeout_ms}ms)")]
+    Timeout { operation: String, timeout_ms: u64 },
+
+    /// 当前处于禁止变更时段，写操作被拒绝
+    #[error("Change window closed by rule '{rule_name}', next allowed at {next_open_at}")]
+    #[serde(rename = "CHANGE_WINDOW_CLOSED")]
+    ChangeWindowClosed {
+        rule_name: String,
+        next_open_at: DateTime<Utc>,
+    },
+
+    /// 因网络错误未能写入，已加入离线写队列等待用户确认重放
+    #[error("Write queued for replay as {queue_id}: {reason}")]
+    #[serde(rename = "WRITE_QUEUED")]
+    WriteQueued { queue_id: String, reason: String },
+
+    /// 资源正被其他写操作占用，等待写锁超时
+    #[error("Resource {resource} is busy with operation {holder} (waited {timeout_ms}ms)")]
+    #[serde(rename = "RESOURCE_BUSY")]
+    ResourceBusy {
+        resource: String,
+        holder: String,
+        timeout_ms: u64,
+    },
+
+    /// 当前平台或权限下无法执行该操作（如路径追踪缺少原始套接字权限）
+    #[error("Missing capability '{capability}': {detail}")]
+    #[serde(rename = "CAPABILITY_UNAVAILABLE")]
+    CapabilityUnavailable { capability: String, detail: String },
+
+    /// 隐私模式下未经确认访问非必需的第三方端点
+    #[error("External access to '{endpoint}' blocked by privacy mode: {purpose}")]
+    #[serde(rename = "EXTERNAL_ACCESS_BLOCKED")]
+    ExternalAccessBlocked { endpoint: String, purpose: String },
+
+    /// 该账户的服务商连续网络错误，熔断期间暂停调用（参数为账户 ID）
+    #[error("Provider temporarily unavailable for account {0}, retry later")]
+    #[serde(rename = "SERVICE_UNAVAILABLE")]
+    ServiceUnavailable(String),
+
     /// Provider 错误（从库转换）
     #[error("{0}")]
     Provider(#[from] ProviderError),
@@ -8,10 +8,49 @@
 //!
 //! 此库设计为平台无关，通过 trait 抽象存储层，
 //! 支持 Tauri (Desktop/Android) 和 Actix-Web 后端。
+//!
+//! # 装配
+//!
+//! 平台层实现 [`traits`] 中的存储 trait，组装成 [`ServiceContext`]，再交给各个服务：
+//!
+#![cfg_attr(feature = "testing", doc = "```")]
+#![cfg_attr(not(feature = "testing"), doc = "```ignore")]
+//! use std::sync::Arc;
+//!
+//! use dns_orchestrator_core::services::{DomainService, ServiceContext};
+//! use dns_orchestrator_core::testing::{
+//!     register_mock_account, InMemoryAccountRepository, InMemoryCredentialStore,
+//!     InMemoryDomainMetadataRepository, MockDnsProvider,
+//! };
+//! use dns_orchestrator_core::traits::InMemoryProviderRegistry;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> dns_orchestrator_core::CoreResult<()> {
+//! let ctx = Arc::new(ServiceContext::new(
+//!     Arc::new(InMemoryCredentialStore::default()),
+//!     Arc::new(InMemoryAccountRepository::default()),
+//!     Arc::new(InMemoryProviderRegistry::new()),
+//!     Arc::new(InMemoryDomainMetadataRepository::default()),
+//! ));
+//! let provider = MockDnsProvider::new().with_domain("zone-1", "example.com");
+//! register_mock_account(&ctx, "account-1", Arc::new(provider)).await?;
+//!
+//! let domains = DomainService::new(ctx)
+//!     .list_domains("account-1", None, None)
+//!     .await?;
+//! assert_eq!(domains.items[0].name, "example.com");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! 启用 `testing` feature 后，`testing` 模块提供上例中的内存实现与 Mock 服务商，
+//! 文档中依赖它的示例需要 `cargo test --features testing` 才会运行。
 
 pub mod crypto;
 pub mod error;
 pub mod services;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod traits;
 pub mod types;
 pub mod utils;
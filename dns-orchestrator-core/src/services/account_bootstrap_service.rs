@@ -9,6 +9,7 @@ use dns_orchestrator_provider::create_provider;
 use crate::error::CoreResult;
 use crate::types::AccountStatus;
 
+use super::credential_expiry_service::credentials_expired_message;
 use super::{AccountMetadataService, CredentialManagementService};
 
 /// 账户恢复结果
@@ -124,10 +125,17 @@ impl AccountBootstrapService {
                 .register_provider(account.id.clone(), provider)
                 .await;
 
-            // 更新状态为 Active
+            // 更新状态为 Active；已过凭证到期时间的账户保持过期状态
+            let (status, error) = match account.credentials_expired_at(chrono::Utc::now()) {
+                Some(expired_at) => (
+                    AccountStatus::CredentialsExpired,
+                    Some(credentials_expired_message(expired_at)),
+                ),
+                None => (AccountStatus::Active, None),
+            };
             if let Err(e) = self
                 .metadata_service
-                .update_status(&account.id, AccountStatus::Active, None)
+                .update_status(&account.id, status, error)
                 .await
             {
                 log::warn!("Failed to update status for account {}: {e}", account.id);
@@ -0,0 +1,152 @@
+//! 账户能力服务
+//!
+//! 服务商的静态能力声明按免费套餐填写，同一服务商不同套餐的能力可能不同
+//! （如阿里云付费版最低 TTL 为 1 秒）。此服务查询账户叠加探测修正后的实际能力，
+//! 并支持手动重新探测。
+
+use std::sync::Arc;
+
+use crate::error::CoreResult;
+use crate::services::ServiceContext;
+use crate::types::{
+    AccountCapabilities, AccountCapabilityCache, CapabilityProbe, CapabilitySource,
+    ProviderMetadata,
+};
+
+/// 账户能力服务
+pub struct AccountCapabilityService {
+    ctx: Arc<ServiceContext>,
+}
+
+impl AccountCapabilityService {
+    /// 创建账户能力服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self { ctx }
+    }
+
+    /// 获取账户的能力详情（首次查询时探测）
+    pub async fn get_capabilities(&self, account_id: &str) -> CoreResult<AccountCapabilities> {
+        let metadata = self.ctx.static_provider_metadata(account_id).await?;
+        let cache = self.ctx.capability_cache(account_id).await?;
+        Ok(account_capabilities(account_id, metadata, cache))
+    }
+
+    /// 重新探测账户的能力并更新缓存
+    pub async fn refresh_capabilities(&self, account_id: &str) -> CoreResult<AccountCapabilities> {
+        let metadata = self.ctx.static_provider_metadata(account_id).await?;
+        let cache = self.ctx.probe_capabilities(account_id).await?;
+        Ok(account_capabilities(account_id, metadata, Some(cache)))
+    }
+
+    /// 清除账户的探测缓存（凭证变更或账户删除时），下次使用时重新探测
+    ///
+    /// 失败只记录日志。
+    pub async fn invalidate(&self, account_id: &str) {
+        if let Some(repository) = &self.ctx.capability_repository {
+            if let Err(e) = repository.delete(account_id).await {
+                log::warn!("Failed to clear capability cache for {account_id}: {e}");
+            }
+        }
+    }
+}
+
+/// 静态声明叠加探测修正
+fn account_capabilities(
+    account_id: &str,
+    mut metadata: ProviderMetadata,
+    cache: Option<AccountCapabilityCache>,
+) -> AccountCapabilities {
+    let (corrections, probed_at, probe_error) = match cache {
+        Some(cache) => (cache.probe, Some(cache.probed_at), cache.error),
+        None => (CapabilityProbe::default(), None, None),
+    };
+    metadata.apply_probe(&corrections);
+    let source = if corrections == CapabilityProbe::default() {
+        CapabilitySource::Static
+    } else {
+        CapabilitySource::Probed
+    };
+
+    AccountCapabilities {
+        account_id: account_id.to_string(),
+        provider: metadata.id,
+        plan: corrections.plan.clone(),
+        record_types: metadata.record_types,
+        features: metadata.features,
+        limits: metadata.limits,
+        source,
+        corrections,
+        probed_at,
+        probe_error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{
+        in_memory_context, register_mock_account, InMemoryCapabilityRepository, MockDnsProvider,
+    };
+    use crate::traits::AccountCapabilityRepository;
+    use crate::ProviderError;
+
+    fn enterprise_probe() -> CapabilityProbe {
+        CapabilityProbe {
+            plan: Some("Enterprise".to_string()),
+            min_ttl: Some(30),
+            ..CapabilityProbe::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn first_use_probes_and_caches_corrections() -> CoreResult<()> {
+        let repository = Arc::new(InMemoryCapabilityRepository::default());
+        let ctx = Arc::new(in_memory_context().with_capability_cache(Arc::clone(&repository) as _));
+        let provider = Arc::new(MockDnsProvider::new().with_capabilities(enterprise_probe()));
+        register_mock_account(&ctx, "acc", Arc::clone(&provider)).await?;
+
+        // 能力检查读取账户级修正，而不是服务商的静态声明
+        let metadata = ctx.provider_metadata("acc").await?;
+        assert_eq!(metadata.limits.min_ttl, 30);
+        assert_eq!(provider.probe_count(), 1);
+
+        let service = AccountCapabilityService::new(Arc::clone(&ctx));
+        let capabilities = service.get_capabilities("acc").await?;
+        assert_eq!(capabilities.source, CapabilitySource::Probed);
+        assert_eq!(capabilities.plan.as_deref(), Some("Enterprise"));
+        assert!(capabilities.probed_at.is_some());
+        // 已缓存，不再探测
+        assert_eq!(provider.probe_count(), 1);
+
+        service.refresh_capabilities("acc").await?;
+        assert_eq!(provider.probe_count(), 2);
+
+        service.invalidate("acc").await;
+        assert!(repository.find_by_account("acc").await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_probe_falls_back_to_static_declaration() -> CoreResult<()> {
+        let repository = Arc::new(InMemoryCapabilityRepository::default());
+        let ctx = Arc::new(in_memory_context().with_capability_cache(repository));
+        let provider = Arc::new(MockDnsProvider::new());
+        register_mock_account(&ctx, "acc", Arc::clone(&provider)).await?;
+        let declared = ctx.static_provider_metadata("acc").await?;
+
+        provider.fail_with(ProviderError::NetworkError {
+            provider: "mock".to_string(),
+            detail: "connection reset".to_string(),
+        });
+        let service = AccountCapabilityService::new(Arc::clone(&ctx));
+        let capabilities = service.refresh_capabilities("acc").await?;
+        assert_eq!(capabilities.source, CapabilitySource::Static);
+        assert!(capabilities.probe_error.is_some());
+        assert_eq!(capabilities.limits.min_ttl, declared.limits.min_ttl);
+
+        let metadata = ctx.provider_metadata("acc").await?;
+        assert_eq!(metadata.limits.min_ttl, declared.limits.min_ttl);
+        Ok(())
+    }
+}
@@ -2,22 +2,48 @@
 //!
 //! 负责协调账户的完整 CRUD 操作，包括元数据、凭证和 Provider 的协同管理
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::Utc;
 
 use crate::error::{CoreError, CoreResult};
+use crate::traits::DomainDerivedStatusRepository;
 use crate::types::{
-    Account, AccountStatus, BatchDeleteFailure, BatchDeleteResult, CreateAccountRequest,
-    UpdateAccountRequest,
+    Account, AccountDeletionImpact, AccountDeletionResult, AccountStatus, BatchDeleteFailure,
+    BatchDeleteResult, CreateAccountRequest, CredentialStatus, DeletionAction, DeletionCleanupItem,
+    DeletionImpactItem, DeletionImpactKind, PaginationParams, UpdateAccountRequest,
 };
+use crate::ProviderCredentials;
 
-use super::{AccountMetadataService, CredentialManagementService};
+use super::credential_expiry_service::credentials_expired_message;
+use super::{
+    AccountCapabilityService, AccountMetadataService, CredentialManagementService,
+    DomainMetadataService, OperationKind, WriteQueueService,
+};
+
+/// 本地格式预检，清理首尾空白后的凭证写回 `credentials`
+fn precheck_credentials(credentials: &mut ProviderCredentials) -> CoreResult<()> {
+    let notices = credentials
+        .precheck()
+        .map_err(CoreError::CredentialValidation)?;
+    for notice in notices {
+        log::info!("[Credentials] {notice}");
+    }
+    Ok(())
+}
 
 /// 账户生命周期服务
 pub struct AccountLifecycleService {
     metadata_service: Arc<AccountMetadataService>,
     credential_service: Arc<CredentialManagementService>,
+    domain_metadata: Arc<DomainMetadataService>,
+    /// 离线写队列（凭证变更或账户删除时清空该账户的排队写操作）
+    write_queue: Option<Arc<WriteQueueService>>,
+    /// 账户能力服务（凭证变更或账户删除时清除能力探测缓存）
+    capabilities: Option<Arc<AccountCapabilityService>>,
+    /// 域名派生状态（账户删除时一并删除）
+    domain_status: Option<Arc<dyn DomainDerivedStatusRepository>>,
 }
 
 impl AccountLifecycleService {
@@ -26,26 +52,76 @@ impl AccountLifecycleService {
     pub fn new(
         metadata_service: Arc<AccountMetadataService>,
         credential_service: Arc<CredentialManagementService>,
+        domain_metadata_service: Arc<DomainMetadataService>,
     ) -> Self {
         Self {
             metadata_service,
             credential_service,
+            domain_metadata: domain_metadata_service,
+            write_queue: None,
+            capabilities: None,
+            domain_status: None,
+        }
+    }
+
+    /// 关联离线写队列
+    #[must_use]
+    pub fn with_write_queue(mut self, write_queue: Arc<WriteQueueService>) -> Self {
+        self.write_queue = Some(write_queue);
+        self
+    }
+
+    /// 关联账户能力服务
+    #[must_use]
+    pub fn with_capabilities(mut self, capabilities: Arc<AccountCapabilityService>) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
+    /// 关联域名派生状态仓库
+    #[must_use]
+    pub fn with_domain_status(
+        mut self,
+        repository: Arc<dyn DomainDerivedStatusRepository>,
+    ) -> Self {
+        self.domain_status = Some(repository);
+        self
+    }
+
+    /// 清除账户的能力探测缓存（换了凭证可能对应不同套餐）
+    async fn invalidate_capabilities(&self, account_id: &str) {
+        if let Some(capabilities) = &self.capabilities {
+            capabilities.invalidate(account_id).await;
+        }
+    }
+
+    /// 清空账户的排队写操作（失败只记录日志）
+    async fn clear_queued_writes(&self, account_id: &str) {
+        if let Some(queue) = &self.write_queue {
+            if let Err(e) = queue.clear_account(account_id).await {
+                log::warn!("Failed to clear queued writes for {account_id}: {e}");
+            }
         }
     }
 
     /// 创建账户
     ///
-    /// 完整流程：验证凭证 -> 保存凭证 -> 注册 Provider -> 保存元数据
+    /// 完整流程：本地格式预检 -> 验证凭证 -> 保存凭证 -> 注册 Provider -> 保存元数据
     /// 如果保存元数据失败，会自动清理已保存的凭证和已注册的 Provider
     ///
     /// # v1.7.0 变更
     /// `request.credentials` 已经是 `ProviderCredentials` 类型，无需调用 `from_map()` 转换
-    pub async fn create_account(&self, request: CreateAccountRequest) -> CoreResult<Account> {
-        // 1. 验证凭证
+    pub async fn create_account(&self, mut request: CreateAccountRequest) -> CoreResult<Account> {
+        // 1. 本地格式预检（先于网络校验），再验证凭证
+        precheck_credentials(&mut request.credentials)?;
         let provider = self
             .credential_service
             .validate_and_create_provider(&request.credentials)
             .await?;
+        let credentials_expires_at = self
+            .credential_service
+            .resolve_credentials_expiry(&provider, request.credentials_expires_at)
+            .await?;
 
         // 2. 生成账号 ID
         let account_id = uuid::Uuid::new_v4().to_string();
@@ -72,6 +148,10 @@ impl AccountLifecycleService {
             updated_at: now,
             status: Some(AccountStatus::Active),
             error: None,
+            credentials_expires_at,
+            expiry_reminder_days: None,
+            credential_status: Some(CredentialStatus::Valid),
+            last_validated_at: Some(now),
         };
 
         // 6. 保存元数据，失败时 cleanup
@@ -101,7 +181,7 @@ impl AccountLifecycleService {
     ///
     /// # v1.7.0 变更
     /// `request.credentials` 已经是 `Option<ProviderCredentials>` 类型，无需调用 `from_map()` 转换
-    pub async fn update_account(&self, request: UpdateAccountRequest) -> CoreResult<Account> {
+    pub async fn update_account(&self, mut request: UpdateAccountRequest) -> CoreResult<Account> {
         // 1. 获取现有账户
         let mut account = self
             .metadata_service
@@ -110,12 +190,17 @@ impl AccountLifecycleService {
             .ok_or_else(|| CoreError::AccountNotFound(request.id.clone()))?;
 
         // 2. 如果提供了新凭证，验证并更新
-        if let Some(ref new_credentials) = request.credentials {
-            // 2.1 验证凭证
+        if let Some(ref mut new_credentials) = request.credentials {
+            // 2.1 本地格式预检，再验证凭证
+            precheck_credentials(new_credentials)?;
             let new_provider = self
                 .credential_service
                 .validate_and_create_provider(new_credentials)
                 .await?;
+            let credentials_expires_at = self
+                .credential_service
+                .resolve_credentials_expiry(&new_provider, request.credentials_expires_at)
+                .await?;
 
             // 2.2 更新凭证存储
             log::info!("Updating credentials for account: {}", request.id);
@@ -131,9 +216,28 @@ impl AccountLifecycleService {
                 .unregister_provider(&request.id)
                 .await;
 
-            // 2.4 更新状态为 Active（凭证验证成功）
+            // 旧凭证下排队的写操作不再重放
+            self.clear_queued_writes(&request.id).await;
+            self.invalidate_capabilities(&request.id).await;
+
+            // 2.4 更新状态为 Active（凭证验证成功），到期时间随新凭证重置
             account.status = Some(AccountStatus::Active);
             account.error = None;
+            account.credentials_expires_at = credentials_expires_at;
+            account.expiry_reminder_days = None;
+            account.credential_status = Some(CredentialStatus::Valid);
+            account.last_validated_at = Some(Utc::now());
+        } else if let Some(expires_at) = request.credentials_expires_at {
+            // 仅修改到期时间：重新开始提醒；已过期则立即标记
+            account.credentials_expires_at = Some(expires_at);
+            account.expiry_reminder_days = None;
+            if let Some(expired_at) = account.credentials_expired_at(Utc::now()) {
+                account.status = Some(AccountStatus::CredentialsExpired);
+                account.error = Some(credentials_expired_message(expired_at));
+            } else if account.status == Some(AccountStatus::CredentialsExpired) {
+                account.status = Some(AccountStatus::Active);
+                account.error = None;
+            }
         }
 
         // 3. 更新名称（如果提供）
@@ -150,10 +254,105 @@ impl AccountLifecycleService {
         Ok(account)
     }
 
+    /// 账户删除影响面分析
+    ///
+    /// 纯读取：并发查询域名数量、域名元数据和凭证，单项失败时 `count` 为 `None`
+    pub async fn get_account_deletion_impact(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<AccountDeletionImpact> {
+        let account = self
+            .metadata_service
+            .get_account(account_id)
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+
+        let (domain_count, metadata, credentials) = tokio::join!(
+            self.count_domains(account_id),
+            self.domain_metadata.list_account_metadata(account_id),
+            self.credential_service.load_credentials(account_id),
+        );
+
+        let mut items = vec![impact_item(
+            DeletionImpactKind::Domains,
+            DeletionAction::Keep,
+            domain_count.map_err(|e| e.to_string()),
+        )];
+
+        match metadata {
+            Ok(entries) => {
+                let favorites = entries.iter().filter(|(_, m)| m.is_favorite).count();
+                let tags: HashSet<&String> = entries.iter().flat_map(|(_, m)| &m.tags).collect();
+                let notes = entries.iter().filter(|(_, m)| m.note.is_some()).count();
+                for (kind, count) in [
+                    (DeletionImpactKind::Favorites, favorites),
+                    (DeletionImpactKind::Tags, tags.len()),
+                    (DeletionImpactKind::Notes, notes),
+                ] {
+                    items.push(impact_item(
+                        kind,
+                        DeletionAction::Delete,
+                        Ok(u32::try_from(count).unwrap_or(u32::MAX)),
+                    ));
+                }
+            }
+            Err(e) => {
+                let reason = e.to_string();
+                for kind in [
+                    DeletionImpactKind::Favorites,
+                    DeletionImpactKind::Tags,
+                    DeletionImpactKind::Notes,
+                ] {
+                    items.push(impact_item(
+                        kind,
+                        DeletionAction::Delete,
+                        Err(reason.clone()),
+                    ));
+                }
+            }
+        }
+
+        items.push(impact_item(
+            DeletionImpactKind::Credentials,
+            DeletionAction::Delete,
+            credentials.map(|_| 1).map_err(|e| e.to_string()),
+        ));
+
+        Ok(AccountDeletionImpact {
+            account_id: account.id,
+            account_name: account.name,
+            items,
+        })
+    }
+
+    /// 查询账户下的域名总数
+    async fn count_domains(&self, account_id: &str) -> CoreResult<u32> {
+        let provider = self
+            .credential_service
+            .get_provider(account_id)
+            .await
+            .ok_or_else(|| CoreError::ProviderNotFound(account_id.to_string()))?;
+        let params = PaginationParams {
+            page: 1,
+            page_size: 1,
+        };
+        let response = self
+            .credential_service
+            .deadlines()
+            .run(
+                OperationKind::AggregateItem,
+                "list_domains",
+                provider.list_domains(&params),
+            )
+            .await?;
+        Ok(response.total_count)
+    }
+
     /// 删除账户
     ///
-    /// 流程：先删除元数据，再清理内存和凭证（避免出现"幽灵账户"）
-    pub async fn delete_account(&self, account_id: &str) -> CoreResult<()> {
+    /// 流程：先删除元数据，再清理内存、凭证和域名元数据（避免出现"幽灵账户"）
+    /// 返回各项数据的清理结果
+    pub async fn delete_account(&self, account_id: &str) -> CoreResult<AccountDeletionResult> {
         // 1. 检查账户存在
         self.metadata_service
             .get_account(account_id)
@@ -169,11 +368,45 @@ impl AccountLifecycleService {
             .await;
 
         // 4. 删除凭证（即使失败也只记录警告，因为元数据已删除，用户不会看到这个账户）
-        if let Err(e) = self.credential_service.delete_credentials(account_id).await {
+        let credentials_result = self.credential_service.delete_credentials(account_id).await;
+        if let Err(e) = &credentials_result {
             log::warn!("Failed to delete credentials for {account_id}: {e}");
         }
 
-        Ok(())
+        // 5. 删除域名元数据（收藏、标签、备注）
+        let metadata_result = self
+            .domain_metadata
+            .delete_account_metadata(account_id)
+            .await;
+        if let Err(e) = &metadata_result {
+            log::warn!("Failed to delete domain metadata for {account_id}: {e}");
+        }
+
+        self.clear_queued_writes(account_id).await;
+        self.invalidate_capabilities(account_id).await;
+        if let Some(repository) = &self.domain_status {
+            if let Err(e) = repository.delete_by_account(account_id).await {
+                log::warn!("Failed to delete domain derived status for {account_id}: {e}");
+            }
+        }
+
+        let mut cleanup = Vec::new();
+        for kind in [
+            DeletionImpactKind::Favorites,
+            DeletionImpactKind::Tags,
+            DeletionImpactKind::Notes,
+        ] {
+            cleanup.push(cleanup_item(kind, &metadata_result));
+        }
+        cleanup.push(cleanup_item(
+            DeletionImpactKind::Credentials,
+            &credentials_result,
+        ));
+
+        Ok(AccountDeletionResult {
+            account_id: account_id.to_string(),
+            cleanup,
+        })
     }
 
     /// 批量删除账户
@@ -186,11 +419,12 @@ impl AccountLifecycleService {
 
         for account_id in account_ids {
             match self.delete_account(&account_id).await {
-                Ok(()) => success_count += 1,
+                Ok(_) => success_count += 1,
                 Err(e) => {
                     failures.push(BatchDeleteFailure {
                         record_id: account_id,
                         reason: e.to_string(),
+                        attempts: None,
                     });
                 }
             }
@@ -203,3 +437,32 @@ impl AccountLifecycleService {
         })
     }
 }
+
+fn impact_item(
+    kind: DeletionImpactKind,
+    action: DeletionAction,
+    count: Result<u32, String>,
+) -> DeletionImpactItem {
+    match count {
+        Ok(count) => DeletionImpactItem {
+            kind,
+            count: Some(count),
+            action,
+            error: None,
+        },
+        Err(e) => DeletionImpactItem {
+            kind,
+            count: None,
+            action,
+            error: Some(e),
+        },
+    }
+}
+
+fn cleanup_item(kind: DeletionImpactKind, result: &CoreResult<()>) -> DeletionCleanupItem {
+    DeletionCleanupItem {
+        kind,
+        success: result.is_ok(),
+        error: result.as_ref().err().map(ToString::to_string),
+    }
+}
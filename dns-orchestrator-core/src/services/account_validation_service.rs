@@ -0,0 +1,239 @@
+//! 账户凭证校验服务
+//!
+//! 凭证失效或被吊销后，用户往往要到记录操作失败时才从服务商的错误信息里发现。此服务用一次
+//! 开销很小的调用（取第一页、每页 1 个域名）主动校验凭证，把结果作为 [`CredentialStatus`]
+//! 连同校验时间写入账户数据，账户列表据此提示。
+//!
+//! 凭证被拒绝时同时按 [`ServiceContext::handle_provider_error`] 把账户标记为失效或过期；
+//! 校验通过时恢复被标记的账户。网络错误、超时等无法判断的情况不改变账户状态。
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use dns_orchestrator_provider::ProviderError;
+use futures::stream::{self, StreamExt};
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::{OperationKind, ServiceContext};
+use crate::types::{
+    Account, AccountStatus, AccountValidation, AccountValidationSummary, CredentialStatus,
+    PaginationParams,
+};
+
+/// 批量校验时同时进行的校验数
+const VALIDATE_CONCURRENCY: usize = 4;
+
+/// 账户凭证校验服务
+pub struct AccountValidationService {
+    ctx: Arc<ServiceContext>,
+}
+
+impl AccountValidationService {
+    /// 创建账户凭证校验服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self { ctx }
+    }
+
+    /// 校验单个账户的凭证并保存结果
+    pub async fn validate_account(&self, account_id: &str) -> CoreResult<AccountValidation> {
+        let account = self
+            .ctx
+            .account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+        let validation = self.check(&account).await;
+        self.record(&validation).await?;
+        Ok(validation)
+    }
+
+    /// 并发校验全部账户并保存结果
+    ///
+    /// 单个账户保存结果失败只记录日志，不影响其他账户。
+    pub async fn validate_all_accounts(&self) -> CoreResult<AccountValidationSummary> {
+        let accounts = self.ctx.account_repository.find_all().await?;
+        // 按值传入账户，保证返回的 future 是 `Send` 的（后台任务在独立 task 中执行）
+        let mut results: Vec<AccountValidation> = stream::iter(accounts)
+            .map(|account| async move { self.check(&account).await })
+            .buffer_unordered(VALIDATE_CONCURRENCY)
+            .collect()
+            .await;
+        results.sort_by(|a, b| a.account_name.cmp(&b.account_name));
+
+        // 账户数据整体读写，逐个保存避免并发写入互相覆盖
+        let mut summary = AccountValidationSummary::default();
+        for validation in &results {
+            if let Err(e) = self.record(validation).await {
+                log::warn!(
+                    "[AccountValidation] Failed to save result for {}: {e}",
+                    validation.account_id
+                );
+            }
+            match validation.credential_status {
+                CredentialStatus::Valid => summary.valid_count += 1,
+                CredentialStatus::Invalid => summary.invalid_count += 1,
+                CredentialStatus::Unknown => summary.unknown_count += 1,
+            }
+        }
+        summary.results = results;
+        Ok(summary)
+    }
+
+    /// 调用服务商接口校验凭证（不保存结果）
+    async fn check(&self, account: &Account) -> AccountValidation {
+        let result = match self.ctx.get_provider(&account.id).await {
+            Ok(provider) => {
+                let params = PaginationParams {
+                    page: 1,
+                    page_size: 1,
+                };
+                self.ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_domains",
+                        provider.list_domains(&params),
+                    )
+                    .await
+                    .map(|_| ())
+            }
+            Err(e) => Err(e),
+        };
+
+        let (credential_status, error) = match result {
+            Ok(()) => (CredentialStatus::Valid, None),
+            Err(e) if is_credential_error(&e) => {
+                let e = self.ctx.handle_provider_error(&account.id, e).await;
+                (CredentialStatus::Invalid, Some(e.to_string()))
+            }
+            Err(e) => (CredentialStatus::Unknown, Some(e.to_string())),
+        };
+        AccountValidation {
+            account_id: account.id.clone(),
+            account_name: account.name.clone(),
+            provider: account.provider.clone(),
+            credential_status,
+            validated_at: Utc::now(),
+            error,
+        }
+    }
+
+    /// 把校验结果写入账户数据
+    async fn record(&self, validation: &AccountValidation) -> CoreResult<()> {
+        // 重新读取：校验失败时账户状态可能已被更新
+        let Some(mut account) = self
+            .ctx
+            .account_repository
+            .find_by_id(&validation.account_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        account.credential_status = Some(validation.credential_status);
+        account.last_validated_at = Some(validation.validated_at);
+        if validation.credential_status == CredentialStatus::Valid
+            && matches!(
+                account.status,
+                Some(AccountStatus::Error | AccountStatus::CredentialsExpired)
+            )
+        {
+            log::info!(
+                "[AccountValidation] Credentials of {} are valid again",
+                account.id
+            );
+            account.status = Some(AccountStatus::Active);
+            account.error = None;
+        }
+        self.ctx.account_repository.save(&account).await
+    }
+}
+
+/// 服务商是否拒绝了凭证
+fn is_credential_error(error: &CoreError) -> bool {
+    matches!(
+        error,
+        CoreError::Provider(ProviderError::InvalidCredentials { .. })
+            | CoreError::InvalidCredentials(_)
+            | CoreError::CredentialsExpired { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+    #[tokio::test]
+    async fn test_validate_account_records_status() -> CoreResult<()> {
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let service = AccountValidationService::new(Arc::clone(&ctx));
+
+        let validation = service.validate_account("account-1").await?;
+        assert_eq!(validation.credential_status, CredentialStatus::Valid);
+
+        // 凭证被拒绝：标记为无效，账户状态同时变为 Error
+        provider.fail_with(ProviderError::InvalidCredentials {
+            provider: "mock".to_string(),
+            raw_message: None,
+        });
+        let validation = service.validate_account("account-1").await?;
+        assert_eq!(validation.credential_status, CredentialStatus::Invalid);
+        let account = ctx
+            .account_repository
+            .find_by_id("account-1")
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound("account-1".to_string()))?;
+        assert_eq!(account.credential_status, Some(CredentialStatus::Invalid));
+        assert_eq!(account.status, Some(AccountStatus::Error));
+        assert!(account.last_validated_at.is_some());
+
+        // 网络错误无法判断，不改变账户状态
+        provider.fail_with(ProviderError::NetworkError {
+            provider: "mock".to_string(),
+            detail: "connection reset".to_string(),
+        });
+        let validation = service.validate_account("account-1").await?;
+        assert_eq!(validation.credential_status, CredentialStatus::Unknown);
+
+        // 恢复后账户重新变为 Active
+        provider.clear_failure();
+        service.validate_account("account-1").await?;
+        let account = ctx
+            .account_repository
+            .find_by_id("account-1")
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound("account-1".to_string()))?;
+        assert_eq!(account.status, Some(AccountStatus::Active));
+        assert_eq!(account.error, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_all_accounts_summarizes() -> CoreResult<()> {
+        let ctx = Arc::new(in_memory_context());
+        let healthy = Arc::new(MockDnsProvider::new());
+        let revoked = Arc::new(MockDnsProvider::new());
+        revoked.fail_with(ProviderError::InvalidCredentials {
+            provider: "mock".to_string(),
+            raw_message: None,
+        });
+        register_mock_account(&ctx, "account-1", healthy).await?;
+        register_mock_account(&ctx, "account-2", revoked).await?;
+
+        let summary = AccountValidationService::new(Arc::clone(&ctx))
+            .validate_all_accounts()
+            .await?;
+        assert_eq!(
+            (
+                summary.valid_count,
+                summary.invalid_count,
+                summary.unknown_count
+            ),
+            (1, 1, 0)
+        );
+        assert_eq!(summary.results[0].account_id, "account-1");
+        Ok(())
+    }
+}
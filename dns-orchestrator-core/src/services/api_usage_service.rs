@@ -0,0 +1,679 @@
+//! 服务商 API 用量统计服务
+//!
+//! [`ServiceContext::get_provider`](super::ServiceContext::get_provider) 返回的 Provider
+//! 每次接口调用都会计入所属账户当天该操作的次数。计数先累积在内存中，由平台层定期
+//! [`flush`](ApiUsageService::flush) 批量写入仓库，避免每次调用都写库；查询报告与导出前
+//! 也会先写入。统计保留最近 12 个月（含当月），更早的由 [`cleanup`](ApiUsageService::cleanup)
+//! 删除。
+//!
+//! 次数按 Provider 方法调用计算，不是 HTTP 请求数：分页拉取全部记录等内部多次请求
+//! 只计 1 次，因此报告中的数字是配额消耗的下限。
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use async_trait::async_trait;
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use dns_orchestrator_provider::{
+    get_all_provider_metadata, CapabilityProbe, ChangeResult, CreateDnsRecordRequest, DnsProvider,
+    DnsRecord, DnsRecordType, PaginatedResponse, PaginationParams, ProviderDomain,
+    ProviderMetadata, ProviderType, RecordChange, RecordQueryParams, RecordSet,
+    UpdateDnsRecordRequest,
+};
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::{AccountRepository, ApiUsageRepository};
+use crate::types::{
+    ApiOperation, ApiQuotaRule, ApiQuotaWindow, ApiUsageCount, ApiUsageDay, ApiUsageOperationTotal,
+    ApiUsageReport, ApiUsageRisk,
+};
+
+/// 统计保留的月数（含当月）
+const RETENTION_MONTHS: u32 = 12;
+
+/// 用量达到配额的该比例时提示风险
+const WARNING_RATIO: f64 = 0.8;
+
+/// 服务商公开的账户级调用配额
+///
+/// 只收录有公开数字的服务商，分钟/小时/秒级限流按全天持续调用折算为每日上限：
+/// - Cloudflare：每用户 1200 次 / 5 分钟
+/// - DigitalOcean：每 token 5000 次 / 小时
+/// - Route 53：每账户 5 次 / 秒
+///
+/// 其余服务商的限额按接口或套餐区分、未公开统一数字，报告中风险为 `Unknown`。
+const PROVIDER_QUOTAS: &[(ProviderType, ApiQuotaRule)] = &[
+    (
+        ProviderType::Cloudflare,
+        ApiQuotaRule {
+            window: ApiQuotaWindow::Day,
+            limit: 1200 * 12 * 24,
+        },
+    ),
+    (
+        ProviderType::DigitalOcean,
+        ApiQuotaRule {
+            window: ApiQuotaWindow::Day,
+            limit: 5000 * 24,
+        },
+    ),
+    (
+        ProviderType::AwsRoute53,
+        ApiQuotaRule {
+            window: ApiQuotaWindow::Day,
+            limit: 5 * 60 * 60 * 24,
+        },
+    ),
+];
+
+/// 待写入的计数：(账户, 日期, 操作) -> 次数
+type PendingCounts = HashMap<(String, NaiveDate, ApiOperation), u64>;
+
+/// API 用量统计服务
+pub struct ApiUsageService {
+    repository: Arc<dyn ApiUsageRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    pending: Mutex<PendingCounts>,
+}
+
+impl ApiUsageService {
+    /// 创建 API 用量统计服务
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn ApiUsageRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            account_repository,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 记录一次调用（只写内存，见 [`Self::flush`]）
+    pub fn record(&self, account_id: &str, operation: ApiOperation) {
+        self.record_many(account_id, operation, 1);
+    }
+
+    /// 记录多次调用
+    pub fn record_many(&self, account_id: &str, operation: ApiOperation, count: u64) {
+        if count == 0 {
+            return;
+        }
+        let key = (account_id.to_string(), Utc::now().date_naive(), operation);
+        *self
+            .pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_insert(0) += count;
+    }
+
+    /// 把内存中的计数批量写入仓库，返回写入的条数
+    ///
+    /// 写入失败时计数放回内存，下次重试。
+    pub async fn flush(&self) -> CoreResult<usize> {
+        let pending =
+            std::mem::take(&mut *self.pending.lock().unwrap_or_else(PoisonError::into_inner));
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let counts: Vec<ApiUsageCount> = pending
+            .iter()
+            .map(|((account_id, date, operation), count)| ApiUsageCount {
+                account_id: account_id.clone(),
+                date: *date,
+                operation: *operation,
+                count: *count,
+            })
+            .collect();
+        if let Err(e) = self.repository.add_counts(&counts).await {
+            let mut current = self.pending.lock().unwrap_or_else(PoisonError::into_inner);
+            for (key, count) in pending {
+                *current.entry(key).or_insert(0) += count;
+            }
+            return Err(e);
+        }
+        Ok(counts.len())
+    }
+
+    /// 删除保留期（最近 12 个月）之前的统计，返回删除的条数
+    pub async fn cleanup(&self) -> CoreResult<u64> {
+        let today = Utc::now().date_naive();
+        let cutoff = month_start(today) - Months::new(RETENTION_MONTHS - 1);
+        let deleted = self.repository.delete_before(cutoff).await?;
+        if deleted > 0 {
+            log::info!("[ApiUsage] Removed {deleted} usage entries before {cutoff}");
+        }
+        Ok(deleted)
+    }
+
+    /// 账户某月（`YYYY-MM`）的用量报告
+    pub async fn get_usage_report(
+        &self,
+        account_id: &str,
+        month: &str,
+    ) -> CoreResult<ApiUsageReport> {
+        let start = parse_month(month)?;
+        let account = self
+            .account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+
+        self.flush_before_read().await;
+        let counts = self
+            .repository
+            .query(
+                Some(account_id),
+                start - Months::new(1),
+                start + Months::new(1),
+            )
+            .await?;
+
+        Ok(build_report(
+            account_id,
+            account.provider,
+            start,
+            &counts,
+            Utc::now().date_naive(),
+        ))
+    }
+
+    /// 导出某月的每日调用次数为 CSV（`account_id` 为 `None` 时导出全部账户）
+    ///
+    /// 列：`date,account_id,operation,count`，按日期、账户、操作排序。
+    pub async fn export_csv(&self, account_id: Option<&str>, month: &str) -> CoreResult<String> {
+        let start = parse_month(month)?;
+        self.flush_before_read().await;
+        let mut counts = self
+            .repository
+            .query(account_id, start, start + Months::new(1))
+            .await?;
+        counts.sort_by(|a, b| {
+            (a.date, &a.account_id, a.operation).cmp(&(b.date, &b.account_id, b.operation))
+        });
+
+        let mut csv = String::from("date,account_id,operation,count\n");
+        for count in &counts {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{}",
+                count.date,
+                csv_field(&count.account_id),
+                count.operation.as_str(),
+                count.count
+            );
+        }
+        Ok(csv)
+    }
+
+    /// 读取前写入内存中的计数；失败只记录日志，报告缺少尚未写入的部分
+    async fn flush_before_read(&self) {
+        if let Err(e) = self.flush().await {
+            log::warn!("[ApiUsage] Failed to flush pending usage: {e}");
+        }
+    }
+}
+
+/// 解析 `YYYY-MM`，返回当月第一天
+fn parse_month(month: &str) -> CoreResult<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{month}-01"), "%Y-%m-%d")
+        .ok()
+        .filter(|_| month.len() == 7)
+        .ok_or_else(|| {
+            CoreError::ValidationError(format!("Invalid month '{month}', expected YYYY-MM"))
+        })
+}
+
+/// 所在月的第一天
+fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// 服务商公开的调用配额
+fn provider_quota(provider: &ProviderType) -> Option<ApiQuotaRule> {
+    PROVIDER_QUOTAS
+        .iter()
+        .find(|(p, _)| p == provider)
+        .map(|(_, rule)| *rule)
+}
+
+/// 由月初前一个月到月末的计数生成报告
+fn build_report(
+    account_id: &str,
+    provider: ProviderType,
+    start: NaiveDate,
+    counts: &[ApiUsageCount],
+    today: NaiveDate,
+) -> ApiUsageReport {
+    let end = start + Months::new(1);
+    let previous_start = start - Months::new(1);
+    let days_in_month = u64::try_from((end - start).num_days()).unwrap_or(0);
+
+    let mut per_day: HashMap<NaiveDate, u64> = HashMap::new();
+    let mut per_operation: HashMap<ApiOperation, u64> = HashMap::new();
+    let mut previous_month_total = 0;
+    for count in counts {
+        if count.date >= start && count.date < end {
+            *per_day.entry(count.date).or_insert(0) += count.count;
+            *per_operation.entry(count.operation).or_insert(0) += count.count;
+        } else if count.date >= previous_start && count.date < start {
+            previous_month_total += count.count;
+        }
+    }
+
+    // 月初到今天（已结束的月份到月末），无调用的日期补 0
+    let last_day = today.min(end.pred_opt().unwrap_or(start));
+    let days: Vec<ApiUsageDay> = start
+        .iter_days()
+        .take_while(|date| *date <= last_day)
+        .map(|date| ApiUsageDay {
+            date,
+            count: per_day.get(&date).copied().unwrap_or(0),
+        })
+        .collect();
+
+    let mut operations: Vec<ApiUsageOperationTotal> = per_operation
+        .into_iter()
+        .map(|(operation, count)| ApiUsageOperationTotal { operation, count })
+        .collect();
+    operations.sort_by(|a, b| b.count.cmp(&a.count).then(a.operation.cmp(&b.operation)));
+
+    let total: u64 = operations.iter().map(|op| op.count).sum();
+    let elapsed_days = u64::try_from(days.len()).unwrap_or(u64::MAX);
+    let forecast_total = if elapsed_days == 0 || elapsed_days >= days_in_month {
+        total
+    } else {
+        (total * days_in_month).div_ceil(elapsed_days)
+    };
+
+    let quota = provider_quota(&provider);
+    let quota_usage_ratio = quota.filter(|rule| rule.limit > 0).map(|rule| {
+        let used = match rule.window {
+            ApiQuotaWindow::Day => days.iter().map(|day| day.count).max().unwrap_or(0),
+            ApiQuotaWindow::Month => forecast_total,
+        };
+        as_f64(used) / as_f64(rule.limit)
+    });
+    let risk = match quota_usage_ratio {
+        None => ApiUsageRisk::Unknown,
+        Some(ratio) if ratio >= 1.0 => ApiUsageRisk::Exceeded,
+        Some(ratio) if ratio >= WARNING_RATIO => ApiUsageRisk::Warning,
+        Some(_) => ApiUsageRisk::Normal,
+    };
+
+    ApiUsageReport {
+        account_id: account_id.to_string(),
+        provider,
+        month: start.format("%Y-%m").to_string(),
+        days,
+        operations,
+        total,
+        previous_month_total,
+        forecast_total,
+        quota,
+        quota_usage_ratio,
+        risk,
+    }
+}
+
+/// 计数转为浮点（超过 `u32` 范围的按上限计，只用于比例）
+fn as_f64(value: u64) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+/// CSV 字段（含逗号、引号或换行时加引号）
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 统计接口调用次数的 Provider 包装
+///
+/// 调用发出即计数（无论成败），失败的调用同样消耗服务商配额。
+pub(crate) struct MeteredProvider {
+    inner: Arc<dyn DnsProvider>,
+    account_id: String,
+    usage: Arc<ApiUsageService>,
+}
+
+impl MeteredProvider {
+    pub(crate) fn new(
+        inner: Arc<dyn DnsProvider>,
+        account_id: &str,
+        usage: Arc<ApiUsageService>,
+    ) -> Self {
+        Self {
+            inner,
+            account_id: account_id.to_string(),
+            usage,
+        }
+    }
+
+    fn record(&self, operation: ApiOperation) {
+        self.usage.record(&self.account_id, operation);
+    }
+
+    /// 服务商是否有原生批量接口（没有时批量变更逐条调用单条接口）
+    fn native_batch(&self) -> bool {
+        get_all_provider_metadata()
+            .iter()
+            .find(|metadata| metadata.id.to_string() == self.inner.id())
+            .is_some_and(|metadata| metadata.features.batch_changes)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for MeteredProvider {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    /// 类型级元数据无法从被包装的实例得到；包装器只以 `Arc<dyn DnsProvider>` 使用，
+    /// 不会调用此方法
+    fn metadata() -> ProviderMetadata {
+        unreachable!("MeteredProvider is only used as a trait object")
+    }
+
+    async fn validate_credentials(&self) -> dns_orchestrator_provider::Result<bool> {
+        self.record(ApiOperation::ValidateCredentials);
+        self.inner.validate_credentials().await
+    }
+
+    async fn credentials_expires_at(
+        &self,
+    ) -> dns_orchestrator_provider::Result<Option<chrono::DateTime<Utc>>> {
+        self.record(ApiOperation::CredentialsExpiry);
+        self.inner.credentials_expires_at().await
+    }
+
+    async fn probe_capabilities(&self) -> dns_orchestrator_provider::Result<CapabilityProbe> {
+        self.record(ApiOperation::ProbeCapabilities);
+        self.inner.probe_capabilities().await
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> dns_orchestrator_provider::Result<PaginatedResponse<ProviderDomain>> {
+        self.record(ApiOperation::ListDomains);
+        self.inner.list_domains(params).await
+    }
+
+    async fn get_domain(
+        &self,
+        domain_id: &str,
+    ) -> dns_orchestrator_provider::Result<ProviderDomain> {
+        self.record(ApiOperation::GetDomain);
+        self.inner.get_domain(domain_id).await
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> dns_orchestrator_provider::Result<PaginatedResponse<DnsRecord>> {
+        self.record(ApiOperation::ListRecords);
+        self.inner.list_records(domain_id, params).await
+    }
+
+    async fn create_record(
+        &self,
+        req: &CreateDnsRecordRequest,
+    ) -> dns_orchestrator_provider::Result<DnsRecord> {
+        self.record(ApiOperation::CreateRecord);
+        self.inner.create_record(req).await
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> dns_orchestrator_provider::Result<DnsRecord> {
+        self.record(ApiOperation::UpdateRecord);
+        self.inner.update_record(record_id, req).await
+    }
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+        domain_id: &str,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.record(ApiOperation::DeleteRecord);
+        self.inner.delete_record(record_id, domain_id).await
+    }
+
+    async fn set_weighted_routing(
+        &self,
+        domain_id: &str,
+        subdomain: &str,
+        enabled: bool,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.record(ApiOperation::WeightedRouting);
+        self.inner
+            .set_weighted_routing(domain_id, subdomain, enabled)
+            .await
+    }
+
+    async fn set_record_weight(
+        &self,
+        record_id: &str,
+        weight: u8,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.record(ApiOperation::WeightedRouting);
+        self.inner.set_record_weight(record_id, weight).await
+    }
+
+    async fn get_record_set(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> dns_orchestrator_provider::Result<Option<RecordSet>> {
+        self.record(ApiOperation::GetRecordSet);
+        self.inner
+            .get_record_set(domain_id, name, record_type)
+            .await
+    }
+
+    async fn replace_record_set(
+        &self,
+        domain_id: &str,
+        set: &RecordSet,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.record(ApiOperation::ReplaceRecordSet);
+        self.inner.replace_record_set(domain_id, set).await
+    }
+
+    /// 原生批量接口计 1 次，逐条提交时按变更条数计数
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        let calls = if self.native_batch() {
+            1
+        } else {
+            u64::try_from(changes.len()).unwrap_or(u64::MAX)
+        };
+        self.usage
+            .record_many(&self.account_id, ApiOperation::BatchChange, calls);
+        self.inner.batch_change(domain_id, changes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{
+        in_memory_context, register_mock_account, InMemoryAccountRepository,
+        InMemoryApiUsageRepository, MockDnsProvider,
+    };
+    use crate::types::{Account, AccountStatus};
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap_or_default()
+    }
+
+    fn count(day: &str, operation: ApiOperation, count: u64) -> ApiUsageCount {
+        ApiUsageCount {
+            account_id: "acc".to_string(),
+            date: date(day),
+            operation,
+            count,
+        }
+    }
+
+    #[test]
+    fn parses_months() -> CoreResult<()> {
+        assert_eq!(parse_month("2026-02")?, date("2026-02-01"));
+        assert!(parse_month("2026-13").is_err());
+        assert!(parse_month("2026-2").is_err());
+        assert!(parse_month("2026-02-01").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn builds_report_with_forecast_and_comparison() {
+        let counts = vec![
+            count("2026-01-31", ApiOperation::ListRecords, 40),
+            count("2026-02-01", ApiOperation::ListRecords, 30),
+            count("2026-02-01", ApiOperation::CreateRecord, 5),
+            count("2026-02-03", ApiOperation::ListDomains, 35),
+        ];
+        let report = build_report(
+            "acc",
+            ProviderType::Aliyun,
+            date("2026-02-01"),
+            &counts,
+            date("2026-02-07"),
+        );
+
+        assert_eq!(report.month, "2026-02");
+        assert_eq!(report.days.len(), 7);
+        assert_eq!(report.days[1].count, 0);
+        assert_eq!(report.total, 70);
+        assert_eq!(report.previous_month_total, 40);
+        // 7 天 70 次，2 月 28 天
+        assert_eq!(report.forecast_total, 280);
+        assert_eq!(report.operations[0].operation, ApiOperation::ListDomains);
+        assert_eq!(report.operations[2].operation, ApiOperation::CreateRecord);
+        assert_eq!(report.risk, ApiUsageRisk::Unknown);
+        assert!(report.quota.is_none());
+    }
+
+    #[test]
+    fn finished_month_is_not_extrapolated() {
+        let counts = vec![count("2026-01-10", ApiOperation::ListRecords, 10)];
+        let report = build_report(
+            "acc",
+            ProviderType::DigitalOcean,
+            date("2026-01-01"),
+            &counts,
+            date("2026-03-05"),
+        );
+        assert_eq!(report.days.len(), 31);
+        assert_eq!(report.forecast_total, 10);
+        assert_eq!(report.risk, ApiUsageRisk::Normal);
+    }
+
+    #[test]
+    fn daily_quota_uses_peak_day() {
+        let counts = vec![count("2026-02-02", ApiOperation::ListRecords, 100_000)];
+        let report = build_report(
+            "acc",
+            ProviderType::DigitalOcean,
+            date("2026-02-01"),
+            &counts,
+            date("2026-02-10"),
+        );
+        assert_eq!(report.risk, ApiUsageRisk::Warning);
+
+        let counts = vec![count("2026-02-02", ApiOperation::ListRecords, 120_000)];
+        let report = build_report(
+            "acc",
+            ProviderType::DigitalOcean,
+            date("2026-02-01"),
+            &counts,
+            date("2026-02-10"),
+        );
+        assert_eq!(report.risk, ApiUsageRisk::Exceeded);
+    }
+
+    #[tokio::test]
+    async fn flushes_pending_counts_in_batches() -> CoreResult<()> {
+        let repository = Arc::new(InMemoryApiUsageRepository::default());
+        let accounts = Arc::new(InMemoryAccountRepository::default());
+        let now = Utc::now();
+        accounts
+            .save(&Account {
+                id: "acc".to_string(),
+                name: "Test".to_string(),
+                provider: ProviderType::Cloudflare,
+                created_at: now,
+                updated_at: now,
+                status: Some(AccountStatus::Active),
+                error: None,
+                credentials_expires_at: None,
+                expiry_reminder_days: None,
+                credential_status: None,
+                last_validated_at: None,
+            })
+            .await?;
+        let service = ApiUsageService::new(repository, accounts);
+
+        service.record("acc", ApiOperation::ListRecords);
+        service.record("acc", ApiOperation::ListRecords);
+        service.record("acc", ApiOperation::CreateRecord);
+        assert_eq!(service.flush().await?, 2);
+        assert_eq!(service.flush().await?, 0);
+
+        service.record("acc", ApiOperation::ListRecords);
+        let month = now.format("%Y-%m").to_string();
+        let report = service.get_usage_report("acc", &month).await?;
+        assert_eq!(report.total, 4);
+        assert_eq!(report.operations[0].count, 3);
+
+        let csv = service.export_csv(None, &month).await?;
+        let today = now.date_naive();
+        assert!(csv.contains(&format!("{today},acc,list_records,3\n")));
+        assert!(csv.starts_with("date,account_id,operation,count\n"));
+
+        assert!(matches!(
+            service.get_usage_report("missing", &month).await,
+            Err(CoreError::AccountNotFound(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn counts_calls_through_service_context() -> CoreResult<()> {
+        let ctx = in_memory_context();
+        let usage = Arc::new(ApiUsageService::new(
+            Arc::new(InMemoryApiUsageRepository::default()),
+            Arc::clone(&ctx.account_repository),
+        ));
+        let ctx = ctx.with_api_usage(Arc::clone(&usage));
+        let mock = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "acc", mock).await?;
+
+        let provider = ctx.get_provider("acc").await?;
+        provider.list_domains(&PaginationParams::default()).await?;
+        provider.get_domain("zone-1").await?;
+        provider.get_domain("zone-1").await?;
+
+        let month = Utc::now().format("%Y-%m").to_string();
+        let report = usage.get_usage_report("acc", &month).await?;
+        assert_eq!(report.total, 3);
+        assert_eq!(
+            report.operations[0],
+            ApiUsageOperationTotal {
+                operation: ApiOperation::GetDomain,
+                count: 2,
+            }
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,446 @@
+//! 审计日志查询服务
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::pin::pin;
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::zone_file::record_type_name;
+use crate::services::{
+    paginate_pages, OperationKind, Page, PageCursor, PaginateOptions, ServiceContext,
+};
+use crate::types::{
+    AuditEntry, AuditLogFilter, Changelog, ChangelogDomain, ChangelogEntry, ChangelogFormat,
+    ChangelogOutput, ChangelogRequest, ChangelogSourceFilter, DnsRecord, PaginatedResponse,
+    PaginationParams, RecordChangeType,
+};
+
+/// 单页最大条数
+const MAX_PAGE_SIZE: u32 = 200;
+
+/// 变更报告默认最多列出的变更条数
+const DEFAULT_CHANGELOG_ENTRIES: usize = 500;
+
+/// 生成变更报告时最多扫描的审计记录数
+const MAX_CHANGELOG_SCAN: usize = 20_000;
+
+/// 变更前后值摘要的最大字符数
+const MAX_SUMMARY_CHARS: usize = 120;
+
+/// 并发获取域名名称的数量
+const DOMAIN_NAME_CONCURRENCY: usize = 4;
+
+/// 审计日志查询服务
+pub struct AuditLogService {
+    ctx: Arc<ServiceContext>,
+}
+
+impl AuditLogService {
+    /// 创建审计日志服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self { ctx }
+    }
+
+    /// 分页查询审计日志（按时间倒序）
+    ///
+    /// 未启用审计日志时返回空列表。
+    pub async fn list_audit_log(
+        &self,
+        filter: AuditLogFilter,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> CoreResult<PaginatedResponse<AuditEntry>> {
+        let pagination = PaginationParams {
+            page: page.unwrap_or(1),
+            page_size: page_size.unwrap_or(20),
+        };
+        if pagination.page == 0 || pagination.page_size == 0 {
+            return Err(CoreError::ValidationError(
+                "page 与 pageSize 必须大于 0".to_string(),
+            ));
+        }
+        if pagination.page_size > MAX_PAGE_SIZE {
+            return Err(CoreError::ValidationError(format!(
+                "pageSize 不能超过 {MAX_PAGE_SIZE}"
+            )));
+        }
+        if let (Some(since), Some(until)) = (filter.since, filter.until) {
+            if since >= until {
+                return Err(CoreError::ValidationError(
+                    "起始时间必须早于截止时间".to_string(),
+                ));
+            }
+        }
+
+        match &self.ctx.audit_log_repository {
+            Some(repository) => repository.query(&filter, &pagination).await,
+            None => Ok(PaginatedResponse::new(
+                Vec::new(),
+                pagination.page,
+                pagination.page_size,
+                0,
+            )),
+        }
+    }
+    /// 生成指定时间范围内的变更报告
+    ///
+    /// 只包含成功的变更，按域名分组、组内按时间升序。时间范围与报告中的时间均为 UTC。
+    /// 符合条件的变更超过 `max_entries` 时只列出最近的部分，并在报告中注明。
+    /// 域名名称尽力获取，失败时以域名 ID 代替。
+    pub async fn generate_changelog(
+        &self,
+        request: ChangelogRequest,
+    ) -> CoreResult<ChangelogOutput> {
+        if request.since >= request.until {
+            return Err(CoreError::ValidationError(
+                "起始时间必须早于截止时间".to_string(),
+            ));
+        }
+        let max_entries = request.max_entries.unwrap_or(DEFAULT_CHANGELOG_ENTRIES);
+        if max_entries == 0 {
+            return Err(CoreError::ValidationError(
+                "maxEntries 必须大于 0".to_string(),
+            ));
+        }
+
+        // 审计记录按时间倒序返回，截断时保留最近的变更
+        let mut entries = self.collect_changes(&request).await?;
+        let total_changes = entries.len();
+        let truncated = total_changes > max_entries;
+        entries.truncate(max_entries);
+
+        let domains = self.group_by_domain(entries).await?;
+        let changelog = Changelog {
+            since: request.since,
+            until: request.until,
+            timezone: "UTC".to_string(),
+            source: request.source,
+            total_changes,
+            listed_changes: total_changes.min(max_entries),
+            truncated,
+            domains,
+        };
+
+        let content = match request.format {
+            ChangelogFormat::Markdown => render_markdown(&changelog),
+            ChangelogFormat::Json => serde_json::to_string_pretty(&changelog)
+                .map_err(|e| CoreError::SerializationError(e.to_string()))?,
+        };
+        Ok(ChangelogOutput {
+            format: request.format,
+            content,
+            total_changes,
+            truncated,
+        })
+    }
+
+    /// 拉取时间范围内符合来源过滤的成功变更（按时间倒序）
+    async fn collect_changes(&self, request: &ChangelogRequest) -> CoreResult<Vec<AuditEntry>> {
+        let Some(repository) = &self.ctx.audit_log_repository else {
+            return Ok(Vec::new());
+        };
+        let filter = AuditLogFilter {
+            account_id: request.account_id.clone(),
+            domain_id: request.domain_id.clone(),
+            record_id: None,
+            since: Some(request.since),
+            until: Some(request.until),
+        };
+
+        let filter = &filter;
+        let pages = paginate_pages(PaginateOptions::default(), |cursor: PageCursor| {
+            let pagination = PaginationParams {
+                page: cursor.page_number(),
+                page_size: MAX_PAGE_SIZE,
+            };
+            async move { repository.query(filter, &pagination).await.map(Page::from) }
+        });
+        let mut pages = pin!(pages);
+        let mut entries = Vec::new();
+        let mut scanned = 0;
+        while let Some(page) = pages.try_next().await? {
+            scanned += page.items.len();
+            let has_more = page.next.is_some();
+            entries.extend(
+                page.items
+                    .into_iter()
+                    .filter(|entry| entry.success && request.source.matches(entry.source)),
+            );
+            if has_more && scanned >= MAX_CHANGELOG_SCAN {
+                log::warn!(
+                    "[Changelog] Stopped after scanning {scanned} audit entries; older changes are omitted"
+                );
+                break;
+            }
+        }
+        Ok(entries)
+    }
+
+    /// 按账户与域名分组，并补全账户名与域名
+    async fn group_by_domain(&self, entries: Vec<AuditEntry>) -> CoreResult<Vec<ChangelogDomain>> {
+        let mut groups: BTreeMap<(String, String), Vec<AuditEntry>> = BTreeMap::new();
+        for entry in entries {
+            groups
+                .entry((entry.account_id.clone(), entry.domain_id.clone()))
+                .or_default()
+                .push(entry);
+        }
+
+        let account_names: HashMap<String, String> = self
+            .ctx
+            .account_repository
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|account| (account.id, account.name))
+            .collect();
+
+        let mut domains: Vec<ChangelogDomain> = futures::stream::iter(groups)
+            .map(|((account_id, domain_id), mut entries)| {
+                let account_names = &account_names;
+                async move {
+                    entries.sort_by_key(|entry| entry.timestamp);
+                    let domain_name = self.domain_name(&account_id, &domain_id).await;
+                    ChangelogDomain {
+                        account_name: account_names
+                            .get(&account_id)
+                            .cloned()
+                            .unwrap_or_else(|| account_id.clone()),
+                        account_id,
+                        domain_id,
+                        domain_name,
+                        entries: entries.into_iter().map(changelog_entry).collect(),
+                    }
+                }
+            })
+            .buffered(DOMAIN_NAME_CONCURRENCY)
+            .collect()
+            .await;
+        domains.sort_by(|a, b| {
+            a.domain_name
+                .cmp(&b.domain_name)
+                .then_with(|| a.account_name.cmp(&b.account_name))
+        });
+        Ok(domains)
+    }
+
+    /// 获取域名名称，失败时返回域名 ID（账户可能已删除）
+    async fn domain_name(&self, account_id: &str, domain_id: &str) -> String {
+        let Ok(provider) = self.ctx.get_provider(account_id).await else {
+            return domain_id.to_string();
+        };
+        match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain.name,
+            Err(e) => {
+                log::warn!("[Changelog] Failed to resolve domain {account_id}/{domain_id}: {e}");
+                domain_id.to_string()
+            }
+        }
+    }
+}
+
+/// 由成功的审计记录生成变更报告条目
+fn changelog_entry(entry: AuditEntry) -> ChangelogEntry {
+    let record = entry.after.as_ref().or(entry.before.as_ref());
+    ChangelogEntry {
+        changed_at: entry.timestamp,
+        change_type: entry.operation.into(),
+        source: entry.source,
+        record_name: record.map_or_else(
+            || entry.record_id.clone().unwrap_or_default(),
+            |r| r.name.clone(),
+        ),
+        record_type: record
+            .map(|r| record_type_name(&r.data).to_string())
+            .unwrap_or_default(),
+        before: entry.before.as_ref().map(record_summary),
+        after: entry.after.as_ref().map(record_summary),
+        override_reason: entry.override_reason,
+    }
+}
+
+/// 记录值摘要：值与 TTL，过长时截断
+fn record_summary(record: &DnsRecord) -> String {
+    let value = record.data.display_value();
+    let value = if value.chars().count() > MAX_SUMMARY_CHARS {
+        let head: String = value.chars().take(MAX_SUMMARY_CHARS).collect();
+        format!("{head}…")
+    } else {
+        value
+    };
+    format!("{value} (TTL {})", record.ttl)
+}
+
+/// 渲染 Markdown 格式的变更报告
+fn render_markdown(changelog: &Changelog) -> String {
+    const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# DNS Changelog");
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "- Period: {} to {} ({})",
+        changelog.since.format(TIME_FORMAT),
+        changelog.until.format(TIME_FORMAT),
+        changelog.timezone
+    );
+    let _ = writeln!(out, "- Source: {}", source_filter_label(changelog));
+    let _ = writeln!(out, "- Changes: {}", changelog.total_changes);
+    if changelog.truncated {
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "> Only the most recent {} of {} changes are listed. Narrow the time range or filter by domain to see the rest.",
+            changelog.listed_changes, changelog.total_changes
+        );
+    }
+    if changelog.domains.is_empty() {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "No changes in this period.");
+        return out;
+    }
+
+    for domain in &changelog.domains {
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "## {} ({})",
+            escape_markdown(&domain.domain_name),
+            escape_markdown(&domain.account_name)
+        );
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "| Time ({}) | Source | Change | Record | Type | Before | After |",
+            changelog.timezone
+        );
+        let _ = writeln!(out, "| --- | --- | --- | --- | --- | --- | --- |");
+        for entry in &domain.entries {
+            let change = match entry.change_type {
+                RecordChangeType::Create => "create",
+                RecordChangeType::Update => "update",
+                RecordChangeType::Delete => "delete",
+            };
+            let change = entry.override_reason.as_ref().map_or_else(
+                || change.to_string(),
+                |reason| format!("{change} (override: {})", escape_markdown(reason)),
+            );
+            let _ = writeln!(
+                out,
+                "| {} | {} | {change} | {} | {} | {} | {} |",
+                entry.changed_at.format(TIME_FORMAT),
+                entry.source.as_str(),
+                escape_markdown(&entry.record_name),
+                entry.record_type,
+                entry.before.as_deref().map_or("-".into(), escape_markdown),
+                entry.after.as_deref().map_or("-".into(), escape_markdown),
+            );
+        }
+    }
+    out
+}
+
+fn source_filter_label(changelog: &Changelog) -> &'static str {
+    match changelog.source {
+        ChangelogSourceFilter::All => "all",
+        ChangelogSourceFilter::Manual => "manual",
+        ChangelogSourceFilter::Automated => "automated (ddns, scheduled)",
+    }
+}
+
+/// 转义表格单元格中的竖线与换行
+fn escape_markdown(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AuditOperation, ChangeSource, RecordData};
+    use chrono::{TimeZone, Utc};
+
+    fn record(address: &str) -> DnsRecord {
+        DnsRecord {
+            id: "r1".to_string(),
+            domain_id: "d1".to_string(),
+            name: "www".to_string(),
+            ttl: 600,
+            data: RecordData::A {
+                address: address.to_string(),
+            },
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn source_filter_splits_manual_and_automated() {
+        assert!(ChangelogSourceFilter::All.matches(ChangeSource::Ddns));
+        assert!(ChangelogSourceFilter::Manual.matches(ChangeSource::Manual));
+        assert!(!ChangelogSourceFilter::Manual.matches(ChangeSource::Scheduled));
+        assert!(ChangelogSourceFilter::Automated.matches(ChangeSource::Ddns));
+        assert!(!ChangelogSourceFilter::Automated.matches(ChangeSource::Manual));
+    }
+
+    #[test]
+    fn markdown_groups_changes_and_notes_truncation() -> CoreResult<()> {
+        let mut entry = AuditEntry::new("acc", "d1", AuditOperation::Update);
+        entry.source = ChangeSource::Ddns;
+        entry.before = Some(record("192.0.2.1"));
+        entry.after = Some(record("192.0.2.2"));
+        let since = Utc
+            .with_ymd_and_hms(2026, 10, 1, 0, 0, 0)
+            .single()
+            .ok_or_else(|| CoreError::ValidationError("invalid date".to_string()))?;
+
+        let changelog = Changelog {
+            since,
+            until: since + chrono::Duration::days(1),
+            timezone: "UTC".to_string(),
+            source: ChangelogSourceFilter::All,
+            total_changes: 2,
+            listed_changes: 1,
+            truncated: true,
+            domains: vec![ChangelogDomain {
+                account_id: "acc".to_string(),
+                account_name: "Main | CF".to_string(),
+                domain_id: "d1".to_string(),
+                domain_name: "example.com".to_string(),
+                entries: vec![changelog_entry(entry)],
+            }],
+        };
+        let markdown = render_markdown(&changelog);
+
+        assert!(markdown.contains("2026-10-01 00:00:00 to 2026-10-02 00:00:00 (UTC)"));
+        assert!(markdown.contains("Only the most recent 1 of 2 changes"));
+        assert!(markdown.contains("## example.com (Main \\| CF)"));
+        assert!(markdown
+            .contains("| ddns | update | www | A | 192.0.2.1 (TTL 600) | 192.0.2.2 (TTL 600) |"));
+        Ok(())
+    }
+
+    #[test]
+    fn summary_truncates_long_values() {
+        let mut long = record("");
+        long.data = RecordData::TXT {
+            text: "x".repeat(MAX_SUMMARY_CHARS + 10),
+        };
+        let summary = record_summary(&long);
+        assert!(summary.starts_with(&"x".repeat(MAX_SUMMARY_CHARS)));
+        assert!(summary.ends_with("… (TTL 600)"));
+    }
+}
@@ -0,0 +1,497 @@
+//! 后台任务运行器
+//!
+//! 统一负责后台任务的调度、panic 捕获、超时中断、串行/并行策略，
+//! 并记录每个任务的上次执行结果与下次执行时间。
+//! 平台层（Tauri / Actix-Web）共用同一个运行器，只是注册的任务集合不同。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::{watch, Mutex, RwLock};
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::BackgroundJob;
+use crate::types::{BackgroundJobInfo, JobConcurrency, JobRunRecord, JobRunStatus, JobSchedule};
+
+/// 任务运行状态
+#[derive(Default)]
+struct JobState {
+    running: u32,
+    run_count: u64,
+    last_run: Option<JobRunRecord>,
+    next_run_at: Option<DateTime<Utc>>,
+}
+
+/// 任务条目（任务 + 运行状态）
+struct JobEntry {
+    job: Arc<dyn BackgroundJob>,
+    state: Mutex<JobState>,
+}
+
+impl JobEntry {
+    /// 尝试开始一次执行（串行任务正在执行时返回 `false`）
+    async fn try_begin(&self) -> bool {
+        let mut state = self.state.lock().await;
+        if state.running > 0 && self.job.concurrency() == JobConcurrency::Serial {
+            return false;
+        }
+        state.running += 1;
+        true
+    }
+
+    /// 执行一次并记录结果（调用前需 `try_begin` 成功）
+    async fn execute(self: Arc<Self>) {
+        let name = self.job.name().to_string();
+        let started_at = Utc::now();
+        let start = Instant::now();
+
+        // 在独立任务中执行，以便捕获 panic 并在超时后中断
+        let job = Arc::clone(&self.job);
+        let mut handle = tokio::spawn(async move { job.run().await });
+
+        let (status, message) = match tokio::time::timeout(self.job.timeout(), &mut handle).await {
+            Ok(Ok(Ok(summary))) => (JobRunStatus::Success, summary),
+            Ok(Ok(Err(e))) => (JobRunStatus::Failed, Some(e.to_string())),
+            Ok(Err(e)) if e.is_panic() => (JobRunStatus::Panicked, Some(panic_message(e))),
+            Ok(Err(e)) => (JobRunStatus::Failed, Some(e.to_string())),
+            Err(_) => {
+                handle.abort();
+                (
+                    JobRunStatus::TimedOut,
+                    Some(format!(
+                        "执行超过 {} 秒被中断",
+                        self.job.timeout().as_secs()
+                    )),
+                )
+            }
+        };
+
+        match status {
+            JobRunStatus::Success => log::info!("[BackgroundJob] {name} completed"),
+            _ => log::warn!("[BackgroundJob] {name} finished with {status:?}: {message:?}"),
+        }
+
+        let mut state = self.state.lock().await;
+        state.running = state.running.saturating_sub(1);
+        state.run_count += 1;
+        state.last_run = Some(JobRunRecord {
+            status,
+            message,
+            started_at,
+            duration_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+        });
+    }
+
+    async fn info(&self) -> BackgroundJobInfo {
+        let state = self.state.lock().await;
+        BackgroundJobInfo {
+            name: self.job.name().to_string(),
+            schedule: self.job.schedule(),
+            concurrency: self.job.concurrency(),
+            timeout_secs: self.job.timeout().as_secs(),
+            running: state.running,
+            run_count: state.run_count,
+            last_run: state.last_run.clone(),
+            next_run_at: state.next_run_at,
+        }
+    }
+}
+
+/// 后台任务运行器
+pub struct BackgroundJobRunner {
+    jobs: RwLock<Vec<Arc<JobEntry>>>,
+    shutdown: watch::Sender<bool>,
+    started: AtomicBool,
+}
+
+impl Default for BackgroundJobRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackgroundJobRunner {
+    /// 创建后台任务运行器实例
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            jobs: RwLock::new(Vec::new()),
+            shutdown: watch::channel(false).0,
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// 注册任务（运行器已启动时立即开始调度）
+    pub async fn register(&self, job: Arc<dyn BackgroundJob>) -> CoreResult<()> {
+        let mut jobs = self.jobs.write().await;
+        if jobs.iter().any(|e| e.job.name() == job.name()) {
+            return Err(CoreError::ValidationError(format!(
+                "后台任务已存在: {}",
+                job.name()
+            )));
+        }
+
+        let entry = Arc::new(JobEntry {
+            job,
+            state: Mutex::new(JobState::default()),
+        });
+        if self.started.load(Ordering::SeqCst) {
+            self.spawn_schedule(Arc::clone(&entry));
+        }
+        jobs.push(entry);
+        Ok(())
+    }
+
+    /// 启动调度（需在 Tokio 运行时中调用，重复调用无副作用）
+    pub async fn start(&self) {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        for entry in self.jobs.read().await.iter() {
+            self.spawn_schedule(Arc::clone(entry));
+        }
+        log::info!("[BackgroundJob] Runner started");
+    }
+
+    /// 停止调度（正在执行的任务不受影响）
+    pub fn shutdown(&self) {
+        self.started.store(false, Ordering::SeqCst);
+        let _ = self.shutdown.send(true);
+    }
+
+    /// 列出所有任务状态
+    pub async fn list_background_jobs(&self) -> Vec<BackgroundJobInfo> {
+        let entries: Vec<_> = self.jobs.read().await.iter().map(Arc::clone).collect();
+        let mut jobs = Vec::with_capacity(entries.len());
+        for entry in entries {
+            jobs.push(entry.info().await);
+        }
+        jobs
+    }
+
+    /// 手动触发任务（异步执行，立即返回）
+    pub async fn trigger_job(&self, name: &str) -> CoreResult<()> {
+        let entry = self
+            .jobs
+            .read()
+            .await
+            .iter()
+            .find(|e| e.job.name() == name)
+            .map(Arc::clone)
+            .ok_or_else(|| CoreError::ValidationError(format!("后台任务不存在: {name}")))?;
+
+        if !entry.try_begin().await {
+            return Err(CoreError::ValidationError(format!(
+                "后台任务正在执行: {name}"
+            )));
+        }
+
+        log::info!("[BackgroundJob] {name} triggered manually");
+        tokio::spawn(entry.execute());
+        Ok(())
+    }
+
+    fn spawn_schedule(&self, entry: Arc<JobEntry>) {
+        let mut shutdown = self.shutdown.subscribe();
+        // 重新启动时忽略之前的停止信号
+        shutdown.mark_unchanged();
+
+        tokio::spawn(async move {
+            loop {
+                let Some(next) = next_run_after(entry.job.schedule(), Utc::now()) else {
+                    return;
+                };
+                entry.state.lock().await.next_run_at = Some(next);
+
+                let wait = (next - Utc::now()).to_std().unwrap_or_default();
+                tokio::select! {
+                    () = tokio::time::sleep(wait) => {}
+                    _ = shutdown.changed() => {
+                        entry.state.lock().await.next_run_at = None;
+                        return;
+                    }
+                }
+
+                if entry.try_begin().await {
+                    tokio::spawn(Arc::clone(&entry).execute());
+                } else {
+                    log::debug!(
+                        "[BackgroundJob] {} still running, skip this round",
+                        entry.job.name()
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// 计算下次执行时间（手动任务返回 `None`）
+fn next_run_after(schedule: JobSchedule, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match schedule {
+        JobSchedule::Interval { interval_secs } => {
+            let interval =
+                chrono::Duration::from_std(std::time::Duration::from_secs(interval_secs.max(1)))
+                    .ok()?;
+            Some(now + interval)
+        }
+        JobSchedule::Daily { hour, minute } => {
+            let today = now
+                .date_naive()
+                .and_hms_opt(u32::from(hour), u32::from(minute), 0)?
+                .and_utc();
+            if today > now {
+                Some(today)
+            } else {
+                Some(today + chrono::Duration::days(1))
+            }
+        }
+        JobSchedule::Manual => None,
+    }
+}
+
+/// 提取 panic 信息
+fn panic_message(err: tokio::task::JoinError) -> String {
+    let payload = err.into_panic();
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// 测试任务的执行体
+    #[derive(Clone, Copy)]
+    enum Behavior {
+        Panic,
+        Sleep(Duration),
+    }
+
+    struct TestJob {
+        name: &'static str,
+        schedule: JobSchedule,
+        concurrency: JobConcurrency,
+        timeout: Duration,
+        behavior: Behavior,
+        /// 执行体完整跑完的次数（被中断时不计）
+        finished: AtomicU32,
+    }
+
+    impl TestJob {
+        fn new(name: &'static str, behavior: Behavior) -> Self {
+            Self {
+                name,
+                schedule: JobSchedule::Manual,
+                concurrency: JobConcurrency::Serial,
+                timeout: Duration::from_mins(1),
+                behavior,
+                finished: AtomicU32::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl BackgroundJob for TestJob {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn schedule(&self) -> JobSchedule {
+            self.schedule
+        }
+
+        fn timeout(&self) -> Duration {
+            self.timeout
+        }
+
+        fn concurrency(&self) -> JobConcurrency {
+            self.concurrency
+        }
+
+        #[allow(clippy::panic)]
+        async fn run(&self) -> CoreResult<Option<String>> {
+            match self.behavior {
+                Behavior::Panic => panic!("job exploded"),
+                Behavior::Sleep(duration) => tokio::time::sleep(duration).await,
+            }
+            self.finished.fetch_add(1, Ordering::SeqCst);
+            Ok(Some("done".to_string()))
+        }
+    }
+
+    async fn job_info(runner: &BackgroundJobRunner, name: &str) -> CoreResult<BackgroundJobInfo> {
+        runner
+            .list_background_jobs()
+            .await
+            .into_iter()
+            .find(|job| job.name == name)
+            .ok_or_else(|| CoreError::ValidationError(format!("missing job {name}")))
+    }
+
+    /// 等待任务记录一次执行结果
+    async fn wait_for_run(runner: &BackgroundJobRunner, name: &str) -> CoreResult<JobRunRecord> {
+        for _ in 0..1000 {
+            if let Some(last_run) = job_info(runner, name).await?.last_run {
+                return Ok(last_run);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        Err(CoreError::ValidationError(format!("{name} never finished")))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn panic_is_recorded_instead_of_escaping() -> CoreResult<()> {
+        let runner = BackgroundJobRunner::new();
+        runner
+            .register(Arc::new(TestJob::new("explode", Behavior::Panic)))
+            .await?;
+
+        runner.trigger_job("explode").await?;
+        let last_run = wait_for_run(&runner, "explode").await?;
+
+        assert_eq!(last_run.status, JobRunStatus::Panicked);
+        assert_eq!(last_run.message.as_deref(), Some("job exploded"));
+        let info = job_info(&runner, "explode").await?;
+        assert_eq!(info.running, 0);
+        assert_eq!(info.run_count, 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timeout_aborts_the_job() -> CoreResult<()> {
+        let runner = BackgroundJobRunner::new();
+        let job = Arc::new(TestJob {
+            timeout: Duration::from_secs(5),
+            ..TestJob::new("slow", Behavior::Sleep(Duration::from_mins(10)))
+        });
+        runner
+            .register(Arc::clone(&job) as Arc<dyn BackgroundJob>)
+            .await?;
+
+        runner.trigger_job("slow").await?;
+        let last_run = wait_for_run(&runner, "slow").await?;
+        assert_eq!(last_run.status, JobRunStatus::TimedOut);
+        assert_eq!(job_info(&runner, "slow").await?.running, 0);
+
+        // 执行体已被中断，原定结束时间过后也不会跑完
+        tokio::time::sleep(Duration::from_mins(15)).await;
+        assert_eq!(job.finished.load(Ordering::SeqCst), 0);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn serial_job_is_not_run_twice_at_once() -> CoreResult<()> {
+        let runner = BackgroundJobRunner::new();
+        runner
+            .register(Arc::new(TestJob::new(
+                "serial",
+                Behavior::Sleep(Duration::from_secs(10)),
+            )))
+            .await?;
+        runner
+            .register(Arc::new(TestJob {
+                concurrency: JobConcurrency::Parallel,
+                ..TestJob::new("parallel", Behavior::Sleep(Duration::from_secs(10)))
+            }))
+            .await?;
+
+        runner.trigger_job("serial").await?;
+        assert!(runner.trigger_job("serial").await.is_err());
+        assert_eq!(job_info(&runner, "serial").await?.running, 1);
+
+        runner.trigger_job("parallel").await?;
+        runner.trigger_job("parallel").await?;
+        assert_eq!(job_info(&runner, "parallel").await?.running, 2);
+
+        let last_run = wait_for_run(&runner, "serial").await?;
+        assert_eq!(last_run.status, JobRunStatus::Success);
+        assert_eq!(job_info(&runner, "serial").await?.run_count, 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn scheduled_serial_job_skips_overlapping_rounds() -> CoreResult<()> {
+        let runner = BackgroundJobRunner::new();
+        let job = Arc::new(TestJob {
+            schedule: JobSchedule::Interval { interval_secs: 1 },
+            ..TestJob::new("tick", Behavior::Sleep(Duration::from_millis(2500)))
+        });
+        runner
+            .register(Arc::clone(&job) as Arc<dyn BackgroundJob>)
+            .await?;
+        runner.start().await;
+
+        // 第 1 秒开始执行、3.5 秒结束，第 2、3 秒的调度因上一次未结束被跳过
+        tokio::time::sleep(Duration::from_millis(3700)).await;
+        let info = job_info(&runner, "tick").await?;
+        assert_eq!(info.running, 0);
+        assert_eq!(info.run_count, 1);
+        assert!(info.next_run_at.is_some());
+        assert_eq!(job.finished.load(Ordering::SeqCst), 1);
+
+        runner.shutdown();
+        Ok(())
+    }
+
+    #[test]
+    fn next_run_follows_the_schedule() -> CoreResult<()> {
+        let now = Utc
+            .with_ymd_and_hms(2024, 5, 1, 12, 30, 0)
+            .single()
+            .ok_or_else(|| CoreError::ValidationError("invalid date".to_string()))?;
+
+        assert_eq!(
+            next_run_after(JobSchedule::Interval { interval_secs: 90 }, now),
+            Some(now + chrono::Duration::seconds(90))
+        );
+        // 间隔为 0 时按 1 秒处理，避免空转
+        assert_eq!(
+            next_run_after(JobSchedule::Interval { interval_secs: 0 }, now),
+            Some(now + chrono::Duration::seconds(1))
+        );
+        assert_eq!(
+            next_run_after(
+                JobSchedule::Daily {
+                    hour: 18,
+                    minute: 0
+                },
+                now
+            ),
+            Utc.with_ymd_and_hms(2024, 5, 1, 18, 0, 0).single()
+        );
+        // 当天时间已过（含恰好为当前时刻）则顺延到次日
+        assert_eq!(
+            next_run_after(
+                JobSchedule::Daily {
+                    hour: 12,
+                    minute: 30
+                },
+                now
+            ),
+            Utc.with_ymd_and_hms(2024, 5, 2, 12, 30, 0).single()
+        );
+        assert_eq!(
+            next_run_after(
+                JobSchedule::Daily {
+                    hour: 24,
+                    minute: 0
+                },
+                now
+            ),
+            None
+        );
+        assert_eq!(next_run_after(JobSchedule::Manual, now), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+//! 向熔断器上报调用结果的 Provider 包装
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dns_orchestrator_provider::{
+    CapabilityProbe, ChangeResult, CreateDnsRecordRequest, DnsProvider, DnsRecord, DnsRecordType,
+    PaginatedResponse, PaginationParams, ProviderDomain, ProviderError, ProviderMetadata,
+    RecordChange, RecordQueryParams, RecordSet, UpdateDnsRecordRequest,
+};
+
+use crate::utils::circuit_breaker::CircuitBreaker;
+
+/// 把每次接口调用的结果计入账户的熔断器
+///
+/// 只有网络错误（请求未能到达服务商）计为失败；服务商返回的其他错误说明服务可达，与成功
+/// 一样清零失败次数。带重试的单条写操作不经过本包装，由调用方在重试结束后通过
+/// [`record_outcome`] 只上报一次。
+pub(crate) struct BreakerProvider {
+    inner: Arc<dyn DnsProvider>,
+    account_id: String,
+    breaker: Arc<CircuitBreaker<String>>,
+}
+
+impl BreakerProvider {
+    pub(crate) fn new(
+        inner: Arc<dyn DnsProvider>,
+        account_id: &str,
+        breaker: Arc<CircuitBreaker<String>>,
+    ) -> Self {
+        Self {
+            inner,
+            account_id: account_id.to_string(),
+            breaker,
+        }
+    }
+
+    fn observe<T>(
+        &self,
+        result: dns_orchestrator_provider::Result<T>,
+    ) -> dns_orchestrator_provider::Result<T> {
+        record_outcome(&self.breaker, &self.account_id, &result);
+        result
+    }
+}
+
+/// 把一次调用（或一次逻辑操作的最终结果）计入熔断器，计数规则同 [`BreakerProvider`]
+pub(crate) fn record_outcome<T>(
+    breaker: &CircuitBreaker<String>,
+    account_id: &str,
+    result: &dns_orchestrator_provider::Result<T>,
+) {
+    if matches!(result, Err(ProviderError::NetworkError { .. })) {
+        breaker.record_failure(account_id);
+    } else {
+        breaker.record_success(account_id);
+    }
+}
+
+#[async_trait]
+impl DnsProvider for BreakerProvider {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    /// 同 `MeteredProvider`，包装器只以 `Arc<dyn DnsProvider>` 使用，不会调用此方法
+    fn metadata() -> ProviderMetadata {
+        unreachable!("BreakerProvider is only used as a trait object")
+    }
+
+    async fn validate_credentials(&self) -> dns_orchestrator_provider::Result<bool> {
+        self.observe(self.inner.validate_credentials().await)
+    }
+
+    async fn credentials_expires_at(
+        &self,
+    ) -> dns_orchestrator_provider::Result<Option<DateTime<Utc>>> {
+        self.observe(self.inner.credentials_expires_at().await)
+    }
+
+    async fn probe_capabilities(&self) -> dns_orchestrator_provider::Result<CapabilityProbe> {
+        self.observe(self.inner.probe_capabilities().await)
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> dns_orchestrator_provider::Result<PaginatedResponse<ProviderDomain>> {
+        self.observe(self.inner.list_domains(params).await)
+    }
+
+    async fn get_domain(
+        &self,
+        domain_id: &str,
+    ) -> dns_orchestrator_provider::Result<ProviderDomain> {
+        self.observe(self.inner.get_domain(domain_id).await)
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> dns_orchestrator_provider::Result<PaginatedResponse<DnsRecord>> {
+        self.observe(self.inner.list_records(domain_id, params).await)
+    }
+
+    async fn create_record(
+        &self,
+        req: &CreateDnsRecordRequest,
+    ) -> dns_orchestrator_provider::Result<DnsRecord> {
+        self.observe(self.inner.create_record(req).await)
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> dns_orchestrator_provider::Result<DnsRecord> {
+        self.observe(self.inner.update_record(record_id, req).await)
+    }
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+        domain_id: &str,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.observe(self.inner.delete_record(record_id, domain_id).await)
+    }
+
+    async fn set_weighted_routing(
+        &self,
+        domain_id: &str,
+        subdomain: &str,
+        enabled: bool,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.observe(
+            self.inner
+                .set_weighted_routing(domain_id, subdomain, enabled)
+                .await,
+        )
+    }
+
+    async fn set_record_weight(
+        &self,
+        record_id: &str,
+        weight: u8,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.observe(self.inner.set_record_weight(record_id, weight).await)
+    }
+
+    async fn get_record_set(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> dns_orchestrator_provider::Result<Option<RecordSet>> {
+        self.observe(
+            self.inner
+                .get_record_set(domain_id, name, record_type)
+                .await,
+        )
+    }
+
+    async fn replace_record_set(
+        &self,
+        domain_id: &str,
+        set: &RecordSet,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.observe(self.inner.replace_record_set(domain_id, set).await)
+    }
+
+    /// 全部变更都因网络错误失败时计为一次失败，否则计为成功
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        let results = self.inner.batch_change(domain_id, changes).await;
+        let unreachable = !results.is_empty()
+            && results.iter().all(|result| {
+                matches!(
+                    result,
+                    ChangeResult::Failed(ProviderError::NetworkError { .. })
+                )
+            });
+        if unreachable {
+            self.breaker.record_failure(&self.account_id);
+        } else if !results.is_empty() {
+            self.breaker.record_success(&self.account_id);
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::testing::MockDnsProvider;
+    use crate::types::RecordData;
+    use crate::utils::circuit_breaker::CircuitState;
+
+    fn network_error() -> ProviderError {
+        ProviderError::NetworkError {
+            provider: "mock".to_string(),
+            detail: "connection reset".to_string(),
+        }
+    }
+
+    fn wrap(inner: &Arc<MockDnsProvider>) -> (BreakerProvider, Arc<CircuitBreaker<String>>) {
+        let breaker = Arc::new(CircuitBreaker::new(2, Duration::from_mins(1)));
+        let inner: Arc<dyn DnsProvider> = inner.clone();
+        (
+            BreakerProvider::new(inner, "account-1", Arc::clone(&breaker)),
+            breaker,
+        )
+    }
+
+    fn create(name: &str) -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            name: name.to_string(),
+            ttl: 300,
+            data: RecordData::A {
+                address: "192.0.2.1".to_string(),
+            },
+            proxied: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn network_errors_open_the_circuit() {
+        let inner = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        let (provider, breaker) = wrap(&inner);
+        inner.fail_with(network_error());
+
+        assert!(provider.get_domain("zone-1").await.is_err());
+        assert_eq!(breaker.state("account-1"), CircuitState::Closed);
+        assert!(provider.validate_credentials().await.is_err());
+        assert!(matches!(
+            breaker.state("account-1"),
+            CircuitState::Open { .. }
+        ));
+        assert!(!breaker.try_acquire("account-1"));
+    }
+
+    #[tokio::test]
+    async fn other_provider_errors_reset_the_failure_count() {
+        let inner = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        let (provider, breaker) = wrap(&inner);
+
+        inner.fail_with(network_error());
+        assert!(provider.get_domain("zone-1").await.is_err());
+        inner.clear_failure();
+        // 服务商返回了错误，说明可达
+        assert!(matches!(
+            provider.delete_record("missing", "zone-1").await,
+            Err(ProviderError::RecordNotFound { .. })
+        ));
+        inner.fail_with(network_error());
+        assert!(provider.get_domain("zone-1").await.is_err());
+
+        assert_eq!(breaker.state("account-1"), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn batch_counts_as_failure_only_when_every_change_is_unreachable() {
+        let inner = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        let (provider, breaker) = wrap(&inner);
+
+        inner.fail_next_writes(network_error(), 1);
+        let changes = vec![
+            RecordChange::Create(create("a")),
+            RecordChange::Create(create("b")),
+        ];
+        let results = provider.batch_change("zone-1", changes.clone()).await;
+        assert!(matches!(results[0], ChangeResult::Failed(_)));
+        assert_eq!(breaker.state("account-1"), CircuitState::Closed);
+
+        inner.fail_with(network_error());
+        provider.batch_change("zone-1", changes.clone()).await;
+        provider.batch_change("zone-1", changes).await;
+        assert!(matches!(
+            breaker.state("account-1"),
+            CircuitState::Open { .. }
+        ));
+    }
+}
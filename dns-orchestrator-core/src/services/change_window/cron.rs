@@ -0,0 +1,222 @@
+//! cron 表达式解析（5 段：分 时 日 月 周）
+//!
+//! 每段支持 `*`、数值、区间 `a-b`、步长 `*/n` / `a-b/n` / `a/n` 和逗号列表；
+//! 月份与星期可使用英文缩写（`JAN`、`FRI`），星期的 0 和 7 都表示周日。
+//! 日与周同时受限时按标准 cron 语义取并集。
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// 解析后的 cron 表达式，各段以位图表示
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// 日段不是 `*`
+    days_restricted: bool,
+    /// 周段不是 `*`
+    weekdays_restricted: bool,
+}
+
+impl CronSchedule {
+    pub(super) fn parse(expr: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return Err(format!(
+                "cron 表达式应为 5 段（分 时 日 月 周），实际为 {} 段",
+                fields.len()
+            ));
+        };
+
+        let mut weekdays = parse_field(weekday, 0, 7, &WEEKDAY_NAMES, 0)?;
+        // 7 与 0 都表示周日
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, &[], 0)?,
+            hours: parse_field(hour, 0, 23, &[], 0)?,
+            days: parse_field(day, 1, 31, &[], 0)?,
+            months: parse_field(month, 1, 12, &MONTH_NAMES, 1)?,
+            weekdays,
+            days_restricted: *day != "*",
+            weekdays_restricted: *weekday != "*",
+        })
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !has(self.months, date.month()) {
+            return false;
+        }
+        let day = has(self.days, date.day());
+        let weekday = has(self.weekdays, date.weekday().num_days_from_sunday());
+        if self.days_restricted && self.weekdays_restricted {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+
+    /// `from`（含，向上取整到分钟）之后、`until`（不含）之前第一个命中的时刻
+    pub(super) fn next_match(
+        &self,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        let mut t = from.with_second(0)?.with_nanosecond(0)?;
+        if t < from {
+            t += Duration::minutes(1);
+        }
+
+        while t < until {
+            if !self.matches_date(t.date()) {
+                t = t.date().succ_opt()?.and_time(NaiveTime::MIN);
+            } else if !has(self.hours, t.hour()) {
+                t = t.with_minute(0)? + Duration::hours(1);
+            } else if !has(self.minutes, t.minute()) {
+                t += Duration::minutes(1);
+            } else {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+fn has(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+/// 解析单段，返回命中值的位图
+///
+/// `name_base` 为 `names[0]` 对应的数值。
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    name_base: u32,
+) -> Result<u64, String> {
+    let value = |s: &str| -> Result<u32, String> {
+        let v = match names.iter().position(|name| name.eq_ignore_ascii_case(s)) {
+            Some(index) => u32::try_from(index).map_err(|e| e.to_string())? + name_base,
+            None => s
+                .parse::<u32>()
+                .map_err(|_| format!("cron 字段 `{field}` 中的值无效: {s}"))?,
+        };
+        if (min..=max).contains(&v) {
+            Ok(v)
+        } else {
+            Err(format!(
+                "cron 字段 `{field}` 中的值 {v} 超出范围 {min}-{max}"
+            ))
+        }
+    };
+
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step = step
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|step| *step > 0)
+                    .ok_or_else(|| format!("cron 字段 `{field}` 中的步长无效: {step}"))?;
+                (range, Some(step))
+            }
+            None => (part, None),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (value(start)?, value(end)?)
+        } else {
+            let start = value(range)?;
+            // `a/n` 表示从 a 开始到最大值
+            (start, if step.is_some() { max } else { start })
+        };
+        if start > end {
+            return Err(format!("cron 字段 `{field}` 中的区间无效: {range}"));
+        }
+
+        let step = step.unwrap_or(1) as usize;
+        for v in (start..=end).step_by(step) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap_or_default()
+    }
+
+    #[test]
+    fn parses_fields() -> Result<(), String> {
+        let cron = CronSchedule::parse("*/15 9-17 * JAN-MAR mon,FRI")?;
+        assert_eq!(cron.minutes, 1 | 1 << 15 | 1 << 30 | 1 << 45);
+        assert_eq!(cron.hours, (9..=17).fold(0, |bits, h| bits | 1 << h));
+        assert_eq!(cron.months, 1 << 1 | 1 << 2 | 1 << 3);
+        assert_eq!(cron.weekdays, 1 << 1 | 1 << 5);
+
+        assert_eq!(CronSchedule::parse("0 0 * * 7")?.weekdays, 1);
+        assert!(CronSchedule::parse("0 0 * *").is_err());
+        assert!(CronSchedule::parse("60 0 * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * * */0").is_err());
+        assert!(CronSchedule::parse("0 5-1 * * *").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn finds_next_match() -> Result<(), String> {
+        // 每周五 12:00
+        let cron = CronSchedule::parse("0 12 * * 5")?;
+        let until = at("2026-12-31 00:00");
+        // 2026-10-14 是周三
+        assert_eq!(
+            cron.next_match(at("2026-10-14 08:30"), until),
+            Some(at("2026-10-16 12:00"))
+        );
+        assert_eq!(
+            cron.next_match(at("2026-10-16 12:00"), until),
+            Some(at("2026-10-16 12:00"))
+        );
+        assert_eq!(
+            cron.next_match(at("2026-10-16 12:01"), until),
+            Some(at("2026-10-23 12:00"))
+        );
+        assert_eq!(
+            cron.next_match(at("2026-10-16 12:01"), at("2026-10-20 00:00")),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unions_day_and_weekday() -> Result<(), String> {
+        // 每月 1 日或每个周一
+        let cron = CronSchedule::parse("0 0 1 * 1")?;
+        let until = at("2026-12-31 00:00");
+        assert_eq!(
+            cron.next_match(at("2026-10-27 00:00"), until),
+            Some(at("2026-11-01 00:00"))
+        );
+        assert_eq!(
+            cron.next_match(at("2026-11-01 00:01"), until),
+            Some(at("2026-11-02 00:00"))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,514 @@
+//! 变更窗口服务
+//!
+//! 配置全局的禁止变更时段（周期性 cron 规则 + 一次性日期区间，按规则时区解释），
+//! 禁止时段内的记录写操作返回 `CoreError::ChangeWindowClosed`，附带下一个允许变更的时间。
+//! 紧急情况下可开启限时的强制变更，期间写操作放行并记录告警日志。
+//!
+//! 时区以固定 UTC 偏移表示（如 `+08:00`），不处理夏令时切换。
+
+mod cron;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::ChangeWindowRepository;
+use crate::types::{
+    ChangeWindowOverride, ChangeWindowRule, ChangeWindowSchedule, ClosedPeriod,
+    CreateChangeWindowRuleRequest, UpdateChangeWindowRuleRequest,
+};
+
+use cron::CronSchedule;
+
+/// 周期规则单次禁止时长上限（7 天）
+const MAX_DURATION_MINUTES: u32 = 7 * 24 * 60;
+
+/// 即将到来的禁止时段最多查询的天数
+const MAX_UPCOMING_DAYS: u32 = 366;
+
+/// 计算下一个允许变更时间时向后查找的范围
+const LOOKAHEAD_DAYS: i64 = 31;
+
+/// 强制变更最长持续时间（24 小时）
+const MAX_OVERRIDE_MINUTES: u32 = 24 * 60;
+
+/// 变更窗口服务
+pub struct ChangeWindowService {
+    repository: Arc<dyn ChangeWindowRepository>,
+    /// 当前生效的强制变更（仅保存在内存中）
+    active_override: RwLock<Option<ChangeWindowOverride>>,
+}
+
+impl ChangeWindowService {
+    /// 创建变更窗口服务实例
+    #[must_use]
+    pub fn new(repository: Arc<dyn ChangeWindowRepository>) -> Self {
+        Self {
+            repository,
+            active_override: RwLock::new(None),
+        }
+    }
+
+    /// 列出全部规则
+    pub async fn list_rules(&self) -> CoreResult<Vec<ChangeWindowRule>> {
+        self.repository.find_all().await
+    }
+
+    /// 创建规则
+    pub async fn create_rule(
+        &self,
+        request: CreateChangeWindowRuleRequest,
+    ) -> CoreResult<ChangeWindowRule> {
+        let now = Utc::now();
+        let rule = ChangeWindowRule {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: request.name.trim().to_string(),
+            enabled: request.enabled,
+            utc_offset: request.utc_offset.trim().to_string(),
+            schedule: request.schedule,
+            created_at: now,
+            updated_at: now,
+        };
+        validate_rule(&rule)?;
+        self.repository.save(&rule).await?;
+        log::info!("[ChangeWindow] Created rule {} ({})", rule.name, rule.id);
+        Ok(rule)
+    }
+
+    /// 更新规则
+    pub async fn update_rule(
+        &self,
+        request: UpdateChangeWindowRuleRequest,
+    ) -> CoreResult<ChangeWindowRule> {
+        let mut rule = self.get_rule(&request.id).await?;
+        if let Some(name) = request.name {
+            rule.name = name.trim().to_string();
+        }
+        if let Some(enabled) = request.enabled {
+            rule.enabled = enabled;
+        }
+        if let Some(utc_offset) = request.utc_offset {
+            rule.utc_offset = utc_offset.trim().to_string();
+        }
+        if let Some(schedule) = request.schedule {
+            rule.schedule = schedule;
+        }
+        validate_rule(&rule)?;
+        rule.updated_at = Utc::now();
+        self.repository.save(&rule).await?;
+        Ok(rule)
+    }
+
+    /// 删除规则
+    pub async fn delete_rule(&self, rule_id: &str) -> CoreResult<()> {
+        self.get_rule(rule_id).await?;
+        self.repository.delete(rule_id).await
+    }
+
+    /// 从当前时间起 `days` 天内的禁止变更时段（按开始时间排序，供日历展示）
+    pub async fn list_upcoming_closed_periods(&self, days: u32) -> CoreResult<Vec<ClosedPeriod>> {
+        if days == 0 || days > MAX_UPCOMING_DAYS {
+            return Err(CoreError::ValidationError(format!(
+                "days 必须在 1 到 {MAX_UPCOMING_DAYS} 之间"
+            )));
+        }
+        let now = Utc::now();
+        let rules = self.repository.find_all().await?;
+        Ok(closed_periods(
+            &rules,
+            now,
+            now + Duration::days(i64::from(days)),
+        ))
+    }
+
+    /// 下一个允许变更的时间，当前允许变更时返回 `None`（不考虑强制变更）
+    pub async fn next_open_at(&self) -> CoreResult<Option<DateTime<Utc>>> {
+        let rules = self.repository.find_all().await?;
+        Ok(closed_at(&rules, Utc::now()).map(|(_, next_open_at)| next_open_at))
+    }
+
+    /// 检查当前是否允许变更
+    ///
+    /// 处于禁止时段时返回 `CoreError::ChangeWindowClosed`；强制变更生效期间放行并记录告警日志。
+    pub async fn ensure_open(&self) -> CoreResult<()> {
+        let rules = self.repository.find_all().await?;
+        let now = Utc::now();
+        let Some((rule_name, next_open_at)) = closed_at(&rules, now) else {
+            return Ok(());
+        };
+
+        if let Some(active) = self.current_override().await {
+            log::warn!(
+                "[ChangeWindow] Change allowed during closed window '{rule_name}' by override: {}",
+                active.reason
+            );
+            return Ok(());
+        }
+        Err(CoreError::ChangeWindowClosed {
+            rule_name,
+            next_open_at,
+        })
+    }
+
+    /// 开启强制变更，`duration_minutes` 内禁止时段不生效
+    pub async fn start_override(
+        &self,
+        reason: &str,
+        duration_minutes: u32,
+    ) -> CoreResult<ChangeWindowOverride> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(CoreError::ValidationError(
+                "强制变更需要填写原因".to_string(),
+            ));
+        }
+        if duration_minutes == 0 || duration_minutes > MAX_OVERRIDE_MINUTES {
+            return Err(CoreError::ValidationError(format!(
+                "强制变更时长必须在 1 到 {MAX_OVERRIDE_MINUTES} 分钟之间"
+            )));
+        }
+
+        let now = Utc::now();
+        let active = ChangeWindowOverride {
+            reason: reason.to_string(),
+            started_at: now,
+            expires_at: now + Duration::minutes(i64::from(duration_minutes)),
+        };
+        log::warn!(
+            "[ChangeWindow] Override started until {}: {reason}",
+            active.expires_at
+        );
+        *self.active_override.write().await = Some(active.clone());
+        Ok(active)
+    }
+
+    /// 提前结束强制变更
+    pub async fn clear_override(&self) {
+        if self.active_override.write().await.take().is_some() {
+            log::info!("[ChangeWindow] Override cleared");
+        }
+    }
+
+    /// 当前生效的强制变更（已过期时为 `None`）
+    pub async fn current_override(&self) -> Option<ChangeWindowOverride> {
+        self.active_override
+            .read()
+            .await
+            .clone()
+            .filter(|active| active.expires_at > Utc::now())
+    }
+
+    async fn get_rule(&self, rule_id: &str) -> CoreResult<ChangeWindowRule> {
+        self.repository
+            .find_by_id(rule_id)
+            .await?
+            .ok_or_else(|| CoreError::ValidationError(format!("规则不存在: {rule_id}")))
+    }
+}
+
+// ============ 规则校验与时段计算 ============
+
+/// 解析 UTC 偏移：`UTC`、`Z` 或 `±HH:MM`
+fn parse_utc_offset(value: &str) -> Result<FixedOffset, String> {
+    if value.eq_ignore_ascii_case("utc") || value.eq_ignore_ascii_case("z") {
+        return FixedOffset::east_opt(0).ok_or_else(|| "无效的时区".to_string());
+    }
+    let invalid = || format!("无效的时区偏移（应为 UTC 或 ±HH:MM）: {value}");
+    let (sign, rest) = match value.chars().next() {
+        Some('+') => (1, &value[1..]),
+        Some('-') => (-1, &value[1..]),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 14 || minutes >= 60 {
+        return Err(invalid());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
+fn validate_rule(rule: &ChangeWindowRule) -> CoreResult<()> {
+    if rule.name.is_empty() {
+        return Err(CoreError::ValidationError("规则名称不能为空".to_string()));
+    }
+    parse_utc_offset(&rule.utc_offset).map_err(CoreError::ValidationError)?;
+    match &rule.schedule {
+        ChangeWindowSchedule::Recurring {
+            cron,
+            duration_minutes,
+        } => {
+            CronSchedule::parse(cron).map_err(CoreError::ValidationError)?;
+            if *duration_minutes == 0 || *duration_minutes > MAX_DURATION_MINUTES {
+                return Err(CoreError::ValidationError(format!(
+                    "禁止时长必须在 1 到 {MAX_DURATION_MINUTES} 分钟之间"
+                )));
+            }
+        }
+        ChangeWindowSchedule::OneOff { start, end } => {
+            if start >= end {
+                return Err(CoreError::ValidationError(
+                    "结束时间必须晚于开始时间".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 规则时区下的本地时间转为 UTC
+fn to_utc(local: NaiveDateTime, offset: FixedOffset) -> DateTime<Utc> {
+    (local - Duration::seconds(i64::from(offset.local_minus_utc()))).and_utc()
+}
+
+/// 单条规则在 `[from, to)` 内的禁止时段（相互重叠或相接的时段已合并）
+fn rule_periods(
+    rule: &ChangeWindowRule,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<ClosedPeriod>, String> {
+    let offset = parse_utc_offset(&rule.utc_offset)?;
+    let period = |start: NaiveDateTime, end: NaiveDateTime| ClosedPeriod {
+        rule_id: rule.id.clone(),
+        rule_name: rule.name.clone(),
+        start: to_utc(start, offset),
+        end: to_utc(end, offset),
+    };
+
+    let mut periods: Vec<ClosedPeriod> = Vec::new();
+    match &rule.schedule {
+        ChangeWindowSchedule::OneOff { start, end } => periods.push(period(*start, *end)),
+        ChangeWindowSchedule::Recurring {
+            cron,
+            duration_minutes,
+        } => {
+            let cron = CronSchedule::parse(cron)?;
+            let duration = Duration::minutes(i64::from(*duration_minutes));
+            let local_to = to.with_timezone(&offset).naive_local();
+            // 在 from 之前开始、尚未结束的时段同样需要计入
+            let mut cursor = from.with_timezone(&offset).naive_local() - duration;
+            while let Some(start) = cron.next_match(cursor, local_to) {
+                let next = period(start, start + duration);
+                match periods.last_mut() {
+                    Some(last) if next.start <= last.end => last.end = last.end.max(next.end),
+                    _ => periods.push(next),
+                }
+                cursor = start + Duration::minutes(1);
+            }
+        }
+    }
+    periods.retain(|p| p.end > from && p.start < to);
+    Ok(periods)
+}
+
+/// 已启用规则在 `[from, to)` 内的禁止时段，按开始时间排序
+fn closed_periods(
+    rules: &[ChangeWindowRule],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<ClosedPeriod> {
+    let mut periods: Vec<ClosedPeriod> = rules
+        .iter()
+        .filter(|rule| rule.enabled)
+        .flat_map(|rule| {
+            rule_periods(rule, from, to).unwrap_or_else(|e| {
+                log::warn!("[ChangeWindow] Skipping invalid rule {}: {e}", rule.id);
+                Vec::new()
+            })
+        })
+        .collect();
+    periods.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end)));
+    periods
+}
+
+/// `now` 处于禁止时段时返回 (规则名称, 下一个允许变更的时间)
+///
+/// 多条规则的时段首尾相接或重叠时顺延到全部结束；在查找范围内始终禁止时返回查找范围的终点。
+fn closed_at(rules: &[ChangeWindowRule], now: DateTime<Utc>) -> Option<(String, DateTime<Utc>)> {
+    let horizon = now + Duration::days(LOOKAHEAD_DAYS);
+    let periods = closed_periods(rules, now, horizon);
+    let active = periods.iter().find(|p| p.start <= now && now < p.end)?;
+
+    let mut open_at = active.end;
+    for period in &periods {
+        if period.start <= open_at && period.end > open_at {
+            open_at = period.end;
+        }
+    }
+    Some((active.rule_name.clone(), open_at.min(horizon)))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn local(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap_or_default()
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        local(s).and_utc()
+    }
+
+    fn rule(name: &str, utc_offset: &str, schedule: ChangeWindowSchedule) -> ChangeWindowRule {
+        ChangeWindowRule {
+            id: name.to_string(),
+            name: name.to_string(),
+            enabled: true,
+            utc_offset: utc_offset.to_string(),
+            schedule,
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+        }
+    }
+
+    /// 北京时间每周五 12:00 起禁止 12 小时
+    fn friday_afternoon() -> ChangeWindowRule {
+        rule(
+            "friday",
+            "+08:00",
+            ChangeWindowSchedule::Recurring {
+                cron: "0 12 * * FRI".to_string(),
+                duration_minutes: 12 * 60,
+            },
+        )
+    }
+
+    #[test]
+    fn parses_utc_offsets() {
+        assert_eq!(
+            parse_utc_offset("+08:00").map(|o| o.local_minus_utc()),
+            Ok(8 * 3600)
+        );
+        assert_eq!(
+            parse_utc_offset("-05:30").map(|o| o.local_minus_utc()),
+            Ok(-(5 * 3600 + 30 * 60))
+        );
+        assert_eq!(parse_utc_offset("UTC").map(|o| o.local_minus_utc()), Ok(0));
+        assert!(parse_utc_offset("Asia/Shanghai").is_err());
+        assert!(parse_utc_offset("+8").is_err());
+        assert!(parse_utc_offset("+15:00").is_err());
+    }
+
+    #[test]
+    fn converts_recurring_rule_to_utc() -> Result<(), String> {
+        // 2026-10-16 为周五：北京时间 12:00-24:00 即 UTC 04:00-16:00
+        let periods = rule_periods(
+            &friday_afternoon(),
+            utc("2026-10-12 00:00"),
+            utc("2026-10-24 00:00"),
+        )?;
+        let spans: Vec<(DateTime<Utc>, DateTime<Utc>)> =
+            periods.iter().map(|p| (p.start, p.end)).collect();
+        assert_eq!(
+            spans,
+            [
+                (utc("2026-10-16 04:00"), utc("2026-10-16 16:00")),
+                (utc("2026-10-23 04:00"), utc("2026-10-23 16:00")),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn includes_period_started_before_range() -> Result<(), String> {
+        let periods = rule_periods(
+            &friday_afternoon(),
+            utc("2026-10-16 10:00"),
+            utc("2026-10-17 00:00"),
+        )?;
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].start, utc("2026-10-16 04:00"));
+        Ok(())
+    }
+
+    #[test]
+    fn merges_consecutive_matches() -> Result<(), String> {
+        // 每天 22:00-23:59 每分钟命中、各持续 1 分钟，合并为一段
+        let nightly = rule(
+            "nightly",
+            "UTC",
+            ChangeWindowSchedule::Recurring {
+                cron: "* 22-23 * * *".to_string(),
+                duration_minutes: 1,
+            },
+        );
+        let periods = rule_periods(&nightly, utc("2026-10-16 00:00"), utc("2026-10-17 00:00"))?;
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].start, utc("2026-10-16 22:00"));
+        assert_eq!(periods[0].end, utc("2026-10-17 00:00"));
+        Ok(())
+    }
+
+    #[test]
+    fn converts_one_off_range_with_negative_offset() -> Result<(), String> {
+        let sale = rule(
+            "sale",
+            "-05:00",
+            ChangeWindowSchedule::OneOff {
+                start: local("2026-11-27 00:00"),
+                end: local("2026-11-30 00:00"),
+            },
+        );
+        let periods = rule_periods(&sale, utc("2026-11-01 00:00"), utc("2026-12-01 00:00"))?;
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].start, utc("2026-11-27 05:00"));
+        assert_eq!(periods[0].end, utc("2026-11-30 05:00"));
+        Ok(())
+    }
+
+    #[test]
+    fn reports_next_open_time_across_chained_rules() {
+        // 周五禁止到北京时间 24:00（UTC 16:00），紧接着的一次性禁止到 UTC 周六 02:00
+        let chained = rule(
+            "chained",
+            "UTC",
+            ChangeWindowSchedule::OneOff {
+                start: local("2026-10-16 16:00"),
+                end: local("2026-10-17 02:00"),
+            },
+        );
+        let rules = [friday_afternoon(), chained];
+
+        assert_eq!(closed_at(&rules, utc("2026-10-16 03:59")), None);
+        assert_eq!(
+            closed_at(&rules, utc("2026-10-16 04:00")),
+            Some(("friday".to_string(), utc("2026-10-17 02:00")))
+        );
+        assert_eq!(
+            closed_at(&rules, utc("2026-10-16 20:00")),
+            Some(("chained".to_string(), utc("2026-10-17 02:00")))
+        );
+        assert_eq!(closed_at(&rules, utc("2026-10-17 02:00")), None);
+    }
+
+    #[test]
+    fn ignores_disabled_rules() {
+        let mut disabled = friday_afternoon();
+        disabled.enabled = false;
+        assert_eq!(closed_at(&[disabled], utc("2026-10-16 06:00")), None);
+    }
+
+    #[test]
+    fn validates_rules() {
+        let mut invalid = friday_afternoon();
+        invalid.utc_offset = "Asia/Shanghai".to_string();
+        assert!(validate_rule(&invalid).is_err());
+
+        let day = NaiveDate::from_ymd_opt(2026, 11, 11).unwrap_or_default();
+        let empty = rule(
+            "empty",
+            "+08:00",
+            ChangeWindowSchedule::OneOff {
+                start: day.and_hms_opt(0, 0, 0).unwrap_or_default(),
+                end: day.and_hms_opt(0, 0, 0).unwrap_or_default(),
+            },
+        );
+        assert!(validate_rule(&empty).is_err());
+        assert!(validate_rule(&friday_afternoon()).is_ok());
+    }
+}
@@ -0,0 +1,487 @@
+//! 记录中的可疑字符检测
+//!
+//! 零宽字符、全角标点、与 ASCII 同形的西里尔/希腊字母肉眼难以分辨，服务商通常照单全收，
+//! 但记录永远无法按预期解析（或被用于域名仿冒）。检测表按类别维护，新增字符只需追加表项。
+
+use crate::types::{RecordData, UnsafeCharKind, UnsafeCharacter};
+
+/// 检测范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CharScope {
+    /// 主机名、地址等只应包含 ASCII 的字段
+    Strict,
+    /// TXT 等自由文本：不可见字符总是报告，其余仅在与拉丁字母数字混写的词中报告
+    Text,
+}
+
+/// 全角 ASCII（U+FF01–U+FF5E）与对应 ASCII 的码点差
+const FULLWIDTH_OFFSET: u32 = 0xFEE0;
+
+/// 不可见字符，规范化时直接删除
+const INVISIBLE: &[char] = &[
+    '\u{00AD}', // 软连字符
+    '\u{180E}', // 蒙古文元音分隔符
+    '\u{200B}', // 零宽空格
+    '\u{200C}', // 零宽非连接符
+    '\u{200D}', // 零宽连接符
+    '\u{200E}', // 从左到右标记
+    '\u{200F}', // 从右到左标记
+    '\u{202A}', // 从左到右嵌入
+    '\u{202B}', // 从右到左嵌入
+    '\u{202C}', // 嵌入结束
+    '\u{202D}', // 从左到右覆盖
+    '\u{202E}', // 从右到左覆盖
+    '\u{2060}', // 词连接符
+    '\u{2061}', // 不可见函数应用
+    '\u{2062}', // 不可见乘号
+    '\u{2063}', // 不可见分隔符
+    '\u{2064}', // 不可见加号
+    '\u{2066}', // 从左到右隔离
+    '\u{2067}', // 从右到左隔离
+    '\u{2068}', // 首字母强方向隔离
+    '\u{2069}', // 隔离结束
+    '\u{FEFF}', // BOM / 零宽不换行空格
+];
+
+/// 非 ASCII 的标点与空白（全角 ASCII 区段另行计算）
+const PUNCTUATION: &[(char, char)] = &[
+    ('\u{00A0}', ' '),  // 不换行空格
+    ('\u{2002}', ' '),  // en 空格
+    ('\u{2003}', ' '),  // em 空格
+    ('\u{2004}', ' '),  // 三分之一 em 空格
+    ('\u{2005}', ' '),  // 四分之一 em 空格
+    ('\u{2006}', ' '),  // 六分之一 em 空格
+    ('\u{2007}', ' '),  // 数字空格
+    ('\u{2008}', ' '),  // 标点空格
+    ('\u{2009}', ' '),  // 窄空格
+    ('\u{200A}', ' '),  // 极窄空格
+    ('\u{202F}', ' '),  // 窄不换行空格
+    ('\u{205F}', ' '),  // 数学中等空格
+    ('\u{3000}', ' '),  // 全角空格
+    ('\u{3002}', '.'),  // 句号 。
+    ('\u{FF61}', '.'),  // 半角句号
+    ('\u{FE52}', '.'),  // 小句号
+    ('\u{2024}', '.'),  // 单点前导符
+    ('\u{2010}', '-'),  // 连字符
+    ('\u{2011}', '-'),  // 不换行连字符
+    ('\u{2012}', '-'),  // 数字线
+    ('\u{2013}', '-'),  // en 破折号
+    ('\u{2014}', '-'),  // em 破折号
+    ('\u{2212}', '-'),  // 减号
+    ('\u{FE63}', '-'),  // 小连字符
+    ('\u{2018}', '\''), // 左单引号
+    ('\u{2019}', '\''), // 右单引号
+    ('\u{201A}', '\''), // 低单引号
+    ('\u{2032}', '\''), // 撇号
+    ('\u{201C}', '"'),  // 左双引号
+    ('\u{201D}', '"'),  // 右双引号
+    ('\u{201E}', '"'),  // 低双引号
+    ('\u{2033}', '"'),  // 双撇号
+    ('\u{2044}', '/'),  // 分数斜线
+    ('\u{2215}', '/'),  // 除号斜线
+    ('\u{2236}', ':'),  // 比例号
+];
+
+/// 与 ASCII 字母同形的西里尔/希腊字母
+const HOMOGLYPHS: &[(char, char)] = &[
+    // 西里尔小写
+    ('\u{0430}', 'a'), // а
+    ('\u{0441}', 'c'), // с
+    ('\u{0501}', 'd'), // ԁ
+    ('\u{0435}', 'e'), // е
+    ('\u{04BB}', 'h'), // һ
+    ('\u{0456}', 'i'), // і
+    ('\u{0458}', 'j'), // ј
+    ('\u{04CF}', 'l'), // ӏ
+    ('\u{043E}', 'o'), // о
+    ('\u{0440}', 'p'), // р
+    ('\u{051B}', 'q'), // ԛ
+    ('\u{0455}', 's'), // ѕ
+    ('\u{051D}', 'w'), // ԝ
+    ('\u{0445}', 'x'), // х
+    ('\u{0443}', 'y'), // у
+    // 西里尔大写
+    ('\u{0410}', 'A'), // А
+    ('\u{0412}', 'B'), // В
+    ('\u{0421}', 'C'), // С
+    ('\u{0415}', 'E'), // Е
+    ('\u{041D}', 'H'), // Н
+    ('\u{0406}', 'I'), // І
+    ('\u{0408}', 'J'), // Ј
+    ('\u{041A}', 'K'), // К
+    ('\u{041C}', 'M'), // М
+    ('\u{041E}', 'O'), // О
+    ('\u{0420}', 'P'), // Р
+    ('\u{051A}', 'Q'), // Ԛ
+    ('\u{0405}', 'S'), // Ѕ
+    ('\u{0422}', 'T'), // Т
+    ('\u{051C}', 'W'), // Ԝ
+    ('\u{0425}', 'X'), // Х
+    ('\u{04AE}', 'Y'), // Ү
+    // 希腊小写
+    ('\u{03B1}', 'a'), // α
+    ('\u{03B9}', 'i'), // ι
+    ('\u{03BA}', 'k'), // κ
+    ('\u{03BD}', 'v'), // ν
+    ('\u{03BF}', 'o'), // ο
+    ('\u{03C1}', 'p'), // ρ
+    ('\u{03C5}', 'u'), // υ
+    // 希腊大写
+    ('\u{0391}', 'A'), // Α
+    ('\u{0392}', 'B'), // Β
+    ('\u{0395}', 'E'), // Ε
+    ('\u{0396}', 'Z'), // Ζ
+    ('\u{0397}', 'H'), // Η
+    ('\u{0399}', 'I'), // Ι
+    ('\u{039A}', 'K'), // Κ
+    ('\u{039C}', 'M'), // Μ
+    ('\u{039D}', 'N'), // Ν
+    ('\u{039F}', 'O'), // Ο
+    ('\u{03A1}', 'P'), // Ρ
+    ('\u{03A4}', 'T'), // Τ
+    ('\u{03A5}', 'Y'), // Υ
+    ('\u{03A7}', 'X'), // Χ
+];
+
+/// 字符的类别与 ASCII 替换（`None` 表示删除），不在检测表中时返回 `None`
+fn classify(c: char) -> Option<(UnsafeCharKind, Option<char>)> {
+    if c.is_ascii() {
+        return None;
+    }
+    if INVISIBLE.contains(&c) {
+        return Some((UnsafeCharKind::Invisible, None));
+    }
+    if let Some(ascii) = fullwidth_ascii(c) {
+        return Some((UnsafeCharKind::Fullwidth, Some(ascii)));
+    }
+    lookup(PUNCTUATION, c)
+        .map(|ascii| (UnsafeCharKind::Punctuation, Some(ascii)))
+        .or_else(|| lookup(HOMOGLYPHS, c).map(|ascii| (UnsafeCharKind::Homoglyph, Some(ascii))))
+}
+
+fn lookup(table: &[(char, char)], c: char) -> Option<char> {
+    table
+        .iter()
+        .find(|(from, _)| *from == c)
+        .map(|(_, ascii)| *ascii)
+}
+
+fn fullwidth_ascii(c: char) -> Option<char> {
+    let code = u32::from(c);
+    if (0xFF01..=0xFF5E).contains(&code) {
+        char::from_u32(code - FULLWIDTH_OFFSET)
+    } else {
+        None
+    }
+}
+
+/// ASCII 或全角的字母数字（判断一个词是否为拉丁文本）
+fn is_latin_alphanumeric(c: char) -> bool {
+    c.is_ascii_alphanumeric() || fullwidth_ascii(c).is_some_and(|a| a.is_ascii_alphanumeric())
+}
+
+fn is_separator(c: char, scope: CharScope) -> bool {
+    c.is_ascii_whitespace() || (scope == CharScope::Strict && c == '.')
+}
+
+/// 查找字段中的可疑字符，按位置排序
+///
+/// 不可见字符总是报告。`Strict` 范围内全角字符与非 ASCII 标点总是报告，
+/// 同形字母在所在标签混有拉丁字母数字、或整个标签都由 ASCII 与同形字符组成时报告
+/// （正常的西里尔/希腊文 IDN 标签不受影响）。`Text` 范围内除不可见字符外，
+/// 只报告与拉丁字母数字混写的词中的字符，中文标点等正常文本不受影响。
+pub(crate) fn find_unsafe_chars(
+    field: &str,
+    value: &str,
+    scope: CharScope,
+) -> Vec<UnsafeCharacter> {
+    let chars: Vec<char> = value.chars().collect();
+    let mut found = Vec::new();
+
+    let mut start = 0;
+    while start < chars.len() {
+        let end = chars[start..]
+            .iter()
+            .position(|c| is_separator(*c, scope))
+            .map_or(chars.len(), |offset| start + offset);
+        let word = &chars[start..end];
+        let latin = word.iter().any(|c| is_latin_alphanumeric(*c));
+        let all_confusable = word.iter().all(|c| c.is_ascii() || classify(*c).is_some());
+
+        for (offset, &c) in word.iter().enumerate() {
+            let Some((kind, replacement)) = classify(c) else {
+                continue;
+            };
+            let flagged = match (kind, scope) {
+                (UnsafeCharKind::Invisible, _)
+                | (UnsafeCharKind::Fullwidth | UnsafeCharKind::Punctuation, CharScope::Strict) => {
+                    true
+                }
+                (UnsafeCharKind::Homoglyph, CharScope::Strict) => latin || all_confusable,
+                (_, CharScope::Text) => latin,
+            };
+            if flagged {
+                found.push(UnsafeCharacter {
+                    field: field.to_string(),
+                    position: start + offset + 1,
+                    codepoint: format!("U+{:04X}", u32::from(c)),
+                    character: c,
+                    kind,
+                    replacement: replacement.map(String::from).unwrap_or_default(),
+                });
+            }
+        }
+        start = end + 1;
+    }
+    found
+}
+
+/// 把字段中的可疑字符替换为对应 ASCII，返回替换后的值与所做替换
+pub(crate) fn normalize_chars(
+    field: &str,
+    value: &str,
+    scope: CharScope,
+) -> (String, Vec<UnsafeCharacter>) {
+    let found = find_unsafe_chars(field, value, scope);
+    if found.is_empty() {
+        return (value.to_string(), found);
+    }
+
+    let mut normalized = String::with_capacity(value.len());
+    let mut pending = found.iter().peekable();
+    for (index, c) in value.chars().enumerate() {
+        match pending.next_if(|ch| ch.position == index + 1) {
+            Some(ch) => normalized.push_str(&ch.replacement),
+            None => normalized.push(c),
+        }
+    }
+    (normalized, found)
+}
+
+/// 规范化记录名称与内容中的可疑字符，返回所做替换
+pub(crate) fn normalize_record(name: &mut String, data: &mut RecordData) -> Vec<UnsafeCharacter> {
+    let mut found = normalize_field("name", name, CharScope::Strict);
+    let (field, value, scope) = match data {
+        RecordData::A { address } | RecordData::AAAA { address } => {
+            ("address", address, CharScope::Strict)
+        }
+        RecordData::CNAME { target } | RecordData::SRV { target, .. } => {
+            ("target", target, CharScope::Strict)
+        }
+        RecordData::MX { exchange, .. } => ("exchange", exchange, CharScope::Strict),
+        RecordData::TXT { text } => ("text", text, CharScope::Text),
+        RecordData::NS { nameserver } => ("nameserver", nameserver, CharScope::Strict),
+        RecordData::CAA { tag, value, .. } => {
+            found.extend(normalize_field("tag", tag, CharScope::Strict));
+            ("value", value, CharScope::Strict)
+        }
+        RecordData::SOA { mname, rname, .. } => {
+            found.extend(normalize_field("mname", mname, CharScope::Strict));
+            ("rname", rname, CharScope::Strict)
+        }
+    };
+    found.extend(normalize_field(field, value, scope));
+    found
+}
+
+/// 查找记录名称与内容中的可疑字符（不修改记录）
+pub(crate) fn find_in_record(name: &str, data: &RecordData) -> Vec<UnsafeCharacter> {
+    let mut name = name.to_string();
+    let mut data = data.clone();
+    normalize_record(&mut name, &mut data)
+}
+
+/// 写入前处理记录中的可疑字符
+///
+/// `normalize` 为 true 时替换为 ASCII 并把替换说明追加到 `notes`；否则发现可疑字符时返回拒绝原因。
+pub(crate) fn screen_record(
+    name: &mut String,
+    data: &mut RecordData,
+    normalize: bool,
+    notes: &mut Vec<String>,
+) -> Result<(), String> {
+    if normalize {
+        let replaced = normalize_record(name, data);
+        notes.extend(
+            replaced
+                .iter()
+                .map(|ch| format!("{name}: {}", ch.replacement_note())),
+        );
+        return Ok(());
+    }
+    check_record(name, data)
+}
+
+/// 记录含可疑字符时返回拒绝原因
+pub(crate) fn check_record(name: &str, data: &RecordData) -> Result<(), String> {
+    let found = find_in_record(name, data);
+    if found.is_empty() {
+        return Ok(());
+    }
+    let details: Vec<String> = found.iter().map(UnsafeCharacter::describe).collect();
+    Err(format!("含有可疑字符: {}", details.join("; ")))
+}
+
+fn normalize_field(field: &str, value: &mut String, scope: CharScope) -> Vec<UnsafeCharacter> {
+    let (normalized, found) = normalize_chars(field, value, scope);
+    *value = normalized;
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(found: &[UnsafeCharacter]) -> Vec<UnsafeCharKind> {
+        found.iter().map(|ch| ch.kind).collect()
+    }
+
+    #[test]
+    fn test_detects_fullwidth_dot_and_zero_width_space() {
+        let found = find_unsafe_chars(
+            "target",
+            "cdn\u{200B}.example\u{3002}com",
+            CharScope::Strict,
+        );
+        assert_eq!(
+            kinds(&found),
+            [UnsafeCharKind::Invisible, UnsafeCharKind::Punctuation]
+        );
+        assert_eq!(found[0].position, 4);
+        assert_eq!(found[0].codepoint, "U+200B");
+        assert_eq!(found[0].replacement, "");
+        assert_eq!(found[1].position, 13);
+        assert_eq!(found[1].codepoint, "U+3002");
+        assert_eq!(found[1].replacement, ".");
+    }
+
+    #[test]
+    fn test_detects_bom_and_bidi_controls() {
+        let found = find_unsafe_chars("name", "\u{FEFF}www\u{202E}", CharScope::Strict);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].codepoint, "U+FEFF");
+        assert_eq!(found[1].position, 5);
+    }
+
+    #[test]
+    fn test_fullwidth_range_maps_to_ascii() {
+        let (normalized, found) =
+            normalize_chars("address", "１９２．０．２．１", CharScope::Strict);
+        assert_eq!(normalized, "192.0.2.1");
+        assert!(found.iter().all(|ch| ch.kind == UnsafeCharKind::Fullwidth));
+        assert_eq!(found.len(), 9);
+    }
+
+    #[test]
+    fn test_mixed_script_homoglyphs_are_flagged() {
+        // 西里尔 а 与希腊 ο 混入拉丁标签
+        let found = find_unsafe_chars("target", "p\u{0430}yp\u{03BF}l.com", CharScope::Strict);
+        assert_eq!(
+            kinds(&found),
+            [UnsafeCharKind::Homoglyph, UnsafeCharKind::Homoglyph]
+        );
+        assert_eq!(found[0].replacement, "a");
+        assert_eq!(found[1].replacement, "o");
+    }
+
+    #[test]
+    fn test_whole_script_confusable_label_is_flagged() {
+        // "арр" 全部由同形字母组成
+        let found = find_unsafe_chars("target", "\u{0430}\u{0440}\u{0440}.com", CharScope::Strict);
+        assert_eq!(found.len(), 3);
+    }
+
+    #[test]
+    fn test_genuine_cyrillic_label_is_not_flagged() {
+        // "пример" 包含没有 ASCII 同形的字母，是正常的西里尔文标签
+        let found = find_unsafe_chars("target", "пример.рф", CharScope::Strict);
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_text_scope_ignores_natural_language() {
+        assert!(find_unsafe_chars("text", "你好，世界。", CharScope::Text).is_empty());
+        assert!(find_unsafe_chars("text", "Привет мир", CharScope::Text).is_empty());
+    }
+
+    #[test]
+    fn test_text_scope_flags_mixed_words_and_invisible() {
+        let found = find_unsafe_chars(
+            "text",
+            "v=spf1\u{3000}include:_spf.example.com",
+            CharScope::Text,
+        );
+        assert_eq!(kinds(&found), [UnsafeCharKind::Punctuation]);
+        assert_eq!(found[0].position, 7);
+
+        let fullwidth = find_unsafe_chars("text", "ｖ＝ｓｐｆ１ -all", CharScope::Text);
+        assert_eq!(fullwidth.len(), 6);
+
+        let invisible = find_unsafe_chars("text", "你好\u{200D}世界", CharScope::Text);
+        assert_eq!(kinds(&invisible), [UnsafeCharKind::Invisible]);
+    }
+
+    #[test]
+    fn test_normalize_record_rewrites_all_fields() {
+        let mut name = "www\u{200B}".to_string();
+        let mut data = RecordData::CAA {
+            flags: 0,
+            tag: "\u{0456}ssue".to_string(),
+            value: "letsencrypt\u{FF0E}org".to_string(),
+        };
+        let found = normalize_record(&mut name, &mut data);
+
+        assert_eq!(name, "www");
+        assert_eq!(
+            data,
+            RecordData::CAA {
+                flags: 0,
+                tag: "issue".to_string(),
+                value: "letsencrypt.org".to_string(),
+            }
+        );
+        let fields: Vec<&str> = found.iter().map(|ch| ch.field.as_str()).collect();
+        assert_eq!(fields, ["name", "tag", "value"]);
+    }
+
+    #[test]
+    fn test_find_in_record_leaves_clean_records_alone() {
+        let data = RecordData::MX {
+            priority: 10,
+            exchange: "mail.example.com".to_string(),
+        };
+        assert!(find_in_record("@", &data).is_empty());
+    }
+
+    #[test]
+    fn test_screen_record_rejects_or_normalizes() {
+        let mut name = "www".to_string();
+        let mut data = RecordData::NS {
+            nameserver: "ns1\u{FF0E}example.net".to_string(),
+        };
+        let mut notes = Vec::new();
+
+        let rejected = screen_record(&mut name, &mut data.clone(), false, &mut notes);
+        assert!(rejected.is_err_and(|reason| reason.contains("U+FF0E")));
+        assert!(notes.is_empty());
+
+        assert!(screen_record(&mut name, &mut data, true, &mut notes).is_ok());
+        assert_eq!(
+            data,
+            RecordData::NS {
+                nameserver: "ns1.example.net".to_string(),
+            }
+        );
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].starts_with("www: "));
+    }
+
+    #[test]
+    fn test_tables_only_contain_non_ascii_sources() {
+        assert!(INVISIBLE.iter().all(|c| !c.is_ascii()));
+        for (from, to) in PUNCTUATION.iter().chain(HOMOGLYPHS) {
+            assert!(!from.is_ascii());
+            assert!(to.is_ascii());
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! 凭证到期提醒服务
+//!
+//! 根据账户的 `credentials_expires_at` 在到期前按档位提醒，过期后将账户标记为
+//! `CredentialsExpired`，使后续操作的错误直接归因到 token 过期。
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::error::CoreResult;
+use crate::types::{Account, AccountStatus, CredentialExpiryCheck, ExpiringCredential};
+
+use super::AccountMetadataService;
+
+/// 到期提醒档位（距到期天数），从大到小
+pub const EXPIRY_REMINDER_DAYS: [u32; 3] = [14, 7, 1];
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// 账户因凭证过期被标记时记录的错误信息
+pub(crate) fn credentials_expired_message(expired_at: DateTime<Utc>) -> String {
+    format!(
+        "API token 已于 {} 过期，请更新凭证",
+        expired_at.format("%Y-%m-%d %H:%M UTC")
+    )
+}
+
+/// 距到期剩余天数，不足一天按一天计
+pub(crate) fn days_remaining(expires_at: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+    let seconds = (expires_at - now).num_seconds();
+    seconds.div_euclid(SECONDS_PER_DAY) + i64::from(seconds.rem_euclid(SECONDS_PER_DAY) > 0)
+}
+
+/// 剩余天数所处的提醒档位（不超过该天数的最小档位）
+fn reminder_tier(days_remaining: i64) -> Option<u32> {
+    EXPIRY_REMINDER_DAYS
+        .iter()
+        .copied()
+        .filter(|tier| days_remaining <= i64::from(*tier))
+        .min()
+}
+
+fn expiring_credential(
+    account: &Account,
+    expires_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> ExpiringCredential {
+    ExpiringCredential {
+        account_id: account.id.clone(),
+        account_name: account.name.clone(),
+        provider: account.provider.clone(),
+        expires_at,
+        days_remaining: days_remaining(expires_at, now),
+        expired: expires_at <= now,
+    }
+}
+
+/// 凭证到期提醒服务
+pub struct CredentialExpiryService {
+    metadata_service: Arc<AccountMetadataService>,
+}
+
+impl CredentialExpiryService {
+    /// 创建凭证到期提醒服务实例
+    #[must_use]
+    pub fn new(metadata_service: Arc<AccountMetadataService>) -> Self {
+        Self { metadata_service }
+    }
+
+    /// 列出 `within_days` 天内到期（含已过期）的账户凭证，按到期时间升序
+    pub async fn list_expiring_credentials(
+        &self,
+        within_days: u32,
+    ) -> CoreResult<Vec<ExpiringCredential>> {
+        let now = Utc::now();
+        let mut expiring: Vec<ExpiringCredential> = self
+            .metadata_service
+            .list_accounts()
+            .await?
+            .iter()
+            .filter_map(|account| {
+                let expires_at = account.credentials_expires_at?;
+                let item = expiring_credential(account, expires_at, now);
+                (item.days_remaining <= i64::from(within_days)).then_some(item)
+            })
+            .collect();
+        expiring.sort_by_key(|item| item.expires_at);
+        Ok(expiring)
+    }
+
+    /// 检查所有账户的凭证到期情况
+    ///
+    /// - 进入新的提醒档位时返回提醒，并记录档位，避免同一档位重复提醒
+    /// - 已过期且尚未标记的账户标记为 `CredentialsExpired`
+    pub async fn check_expirations(&self) -> CoreResult<CredentialExpiryCheck> {
+        let now = Utc::now();
+        let mut check = CredentialExpiryCheck::default();
+
+        for mut account in self.metadata_service.list_accounts().await? {
+            let Some(expires_at) = account.credentials_expires_at else {
+                continue;
+            };
+            let item = expiring_credential(&account, expires_at, now);
+
+            if item.expired {
+                if account.status == Some(AccountStatus::CredentialsExpired) {
+                    continue;
+                }
+                self.metadata_service
+                    .update_status(
+                        &account.id,
+                        AccountStatus::CredentialsExpired,
+                        Some(credentials_expired_message(expires_at)),
+                    )
+                    .await?;
+                log::warn!("[CredentialExpiry] {} expired at {expires_at}", account.id);
+                check.expired.push(item);
+                continue;
+            }
+
+            let Some(tier) = reminder_tier(item.days_remaining) else {
+                continue;
+            };
+            if account
+                .expiry_reminder_days
+                .is_some_and(|sent| sent <= tier)
+            {
+                continue;
+            }
+            account.expiry_reminder_days = Some(tier);
+            self.metadata_service.save_account(&account).await?;
+            log::info!(
+                "[CredentialExpiry] {} expires in {} days",
+                account.id,
+                item.days_remaining
+            );
+            check.reminders.push(item);
+        }
+
+        Ok(check)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn days_remaining_rounds_up_partial_days() {
+        let now = Utc::now();
+        assert_eq!(days_remaining(now + Duration::hours(1), now), 1);
+        assert_eq!(days_remaining(now + Duration::days(7), now), 7);
+        assert_eq!(
+            days_remaining(now + Duration::days(7) + Duration::hours(1), now),
+            8
+        );
+        assert_eq!(days_remaining(now, now), 0);
+        assert_eq!(days_remaining(now - Duration::hours(1), now), 0);
+        assert_eq!(days_remaining(now - Duration::days(2), now), -2);
+    }
+
+    #[test]
+    fn reminder_tier_picks_smallest_reached_threshold() {
+        assert_eq!(reminder_tier(30), None);
+        assert_eq!(reminder_tier(15), None);
+        assert_eq!(reminder_tier(14), Some(14));
+        assert_eq!(reminder_tier(8), Some(14));
+        assert_eq!(reminder_tier(7), Some(7));
+        assert_eq!(reminder_tier(2), Some(7));
+        assert_eq!(reminder_tier(1), Some(1));
+    }
+}
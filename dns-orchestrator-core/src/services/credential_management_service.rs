@@ -4,15 +4,18 @@
 
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use dns_orchestrator_provider::{create_provider, DnsProvider, ProviderCredentials};
 
 use crate::error::{CoreError, CoreResult};
+use crate::services::{DeadlineConfig, OperationKind};
 use crate::traits::{CredentialStore, CredentialsMap, ProviderRegistry};
 
 /// 凭证管理服务
 pub struct CredentialManagementService {
     credential_store: Arc<dyn CredentialStore>,
     provider_registry: Arc<dyn ProviderRegistry>,
+    deadlines: DeadlineConfig,
 }
 
 impl CredentialManagementService {
@@ -25,9 +28,23 @@ impl CredentialManagementService {
         Self {
             credential_store,
             provider_registry,
+            deadlines: DeadlineConfig::default(),
         }
     }
 
+    /// 覆盖默认的 Provider 调用超时配置
+    #[must_use]
+    pub const fn with_deadlines(mut self, deadlines: DeadlineConfig) -> Self {
+        self.deadlines = deadlines;
+        self
+    }
+
+    /// Provider 调用超时配置
+    #[must_use]
+    pub const fn deadlines(&self) -> &DeadlineConfig {
+        &self.deadlines
+    }
+
     /// 验证凭证并创建 Provider 实例
     ///
     /// # v1.7.0 变更
@@ -40,7 +57,14 @@ impl CredentialManagementService {
         let provider = create_provider(credentials.clone())?;
 
         // 2. 验证凭证
-        let is_valid = provider.validate_credentials().await?;
+        let is_valid = self
+            .deadlines
+            .run(
+                OperationKind::Read,
+                "validate_credentials",
+                provider.validate_credentials(),
+            )
+            .await?;
         if !is_valid {
             return Err(CoreError::InvalidCredentials(
                 credentials.provider_type().to_string(),
@@ -50,6 +74,41 @@ impl CredentialManagementService {
         Ok(provider)
     }
 
+    /// 确定新凭证的到期时间
+    ///
+    /// 优先使用用户填写的值（不能早于当前时间）；未填写时尽力从服务商查询，
+    /// 查询失败或服务商不支持时视为未知。
+    pub async fn resolve_credentials_expiry(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        requested: Option<DateTime<Utc>>,
+    ) -> CoreResult<Option<DateTime<Utc>>> {
+        if let Some(expires_at) = requested {
+            if expires_at <= Utc::now() {
+                return Err(CoreError::ValidationError(
+                    "凭证到期时间不能早于当前时间".to_string(),
+                ));
+            }
+            return Ok(Some(expires_at));
+        }
+
+        match self
+            .deadlines
+            .run(
+                OperationKind::Read,
+                "credentials_expires_at",
+                provider.credentials_expires_at(),
+            )
+            .await
+        {
+            Ok(expires_at) => Ok(expires_at),
+            Err(e) => {
+                log::warn!("Failed to query credentials expiry: {e}");
+                Ok(None)
+            }
+        }
+    }
+
     /// 保存凭证
     ///
     /// # v1.7.0 变更
@@ -93,6 +152,11 @@ impl CredentialManagementService {
         self.provider_registry.register(account_id, provider).await;
     }
 
+    /// 获取已注册的 Provider
+    pub async fn get_provider(&self, account_id: &str) -> Option<Arc<dyn DnsProvider>> {
+        self.provider_registry.get(account_id).await
+    }
+
     /// 注销 Provider
     pub async fn unregister_provider(&self, account_id: &str) {
         self.provider_registry.unregister(account_id).await;
@@ -0,0 +1,180 @@
+//! Provider 调用超时控制
+//!
+//! 所有 provider 调用都经由 `DeadlineConfig::run` 包装，单个 provider 卡死时
+//! 返回 `CoreError::Timeout` 而不是一直占用调用方（尤其是并发聚合多个账户的接口）。
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::{CoreError, CoreResult};
+
+/// Provider 调用类型，决定默认超时时长
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    /// 读操作（列表、详情）
+    Read,
+    /// 写操作（创建、更新、删除）
+    Write,
+    /// 聚合接口中的单个子项，超时后该项降级而不拖慢整体
+    AggregateItem,
+}
+
+/// 各类操作的超时配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeadlineConfig {
+    pub read: Duration,
+    pub write: Duration,
+    pub aggregate_item: Duration,
+}
+
+impl Default for DeadlineConfig {
+    fn default() -> Self {
+        Self {
+            read: Duration::from_secs(10),
+            write: Duration::from_secs(30),
+            aggregate_item: Duration::from_secs(3),
+        }
+    }
+}
+
+impl DeadlineConfig {
+    /// 获取指定操作类型的超时时长
+    #[must_use]
+    pub const fn timeout_for(&self, kind: OperationKind) -> Duration {
+        match kind {
+            OperationKind::Read => self.read,
+            OperationKind::Write => self.write,
+            OperationKind::AggregateItem => self.aggregate_item,
+        }
+    }
+
+    /// 在超时限制内执行 `fut`
+    ///
+    /// `operation` 为操作名称（如 `list_records`），写入超时错误便于定位卡住的调用。
+    pub async fn run<T, E, F>(&self, kind: OperationKind, operation: &str, fut: F) -> CoreResult<T>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: Into<CoreError>,
+    {
+        let limit = self.timeout_for(kind);
+        if let Ok(result) = tokio::time::timeout(limit, fut).await {
+            return result.map_err(Into::into);
+        }
+        log::warn!("[Deadline] {operation} timed out after {limit:?}");
+        Err(CoreError::Timeout {
+            operation: operation.to_string(),
+            timeout_ms: u64::try_from(limit.as_millis()).unwrap_or(u64::MAX),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dns_orchestrator_provider::ProviderError;
+    use tokio::time::{sleep, Instant};
+
+    async fn slow(duration: Duration) -> Result<u32, ProviderError> {
+        sleep(duration).await;
+        Ok(42)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn completes_within_limit() -> CoreResult<()> {
+        let deadlines = DeadlineConfig::default();
+        let value = deadlines
+            .run(
+                OperationKind::Read,
+                "list_records",
+                slow(Duration::from_millis(9_999)),
+            )
+            .await?;
+        assert_eq!(value, 42);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn times_out_with_operation_name() {
+        let deadlines = DeadlineConfig::default();
+        let start = Instant::now();
+        let result = deadlines
+            .run(
+                OperationKind::Read,
+                "list_records",
+                slow(Duration::from_hours(1)),
+            )
+            .await;
+
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+        assert!(matches!(
+            &result,
+            Err(CoreError::Timeout { operation, timeout_ms: 10_000 }) if operation == "list_records"
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn uses_limit_per_operation_kind() {
+        let deadlines = DeadlineConfig::default();
+        for (kind, expected) in [
+            (OperationKind::Read, 10),
+            (OperationKind::Write, 30),
+            (OperationKind::AggregateItem, 3),
+        ] {
+            let start = Instant::now();
+            let result = deadlines
+                .run(kind, "op", slow(Duration::from_hours(1)))
+                .await;
+            assert!(matches!(result, Err(CoreError::Timeout { .. })));
+            assert_eq!(start.elapsed(), Duration::from_secs(expected));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn custom_config_and_error_passthrough() {
+        let deadlines = DeadlineConfig {
+            write: Duration::from_millis(500),
+            ..DeadlineConfig::default()
+        };
+        let result = deadlines
+            .run(OperationKind::Write, "create_record", async {
+                Err::<(), _>(ProviderError::RecordNotFound {
+                    provider: "cloudflare".to_string(),
+                    record_id: "r1".to_string(),
+                    raw_message: None,
+                })
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(CoreError::Provider(ProviderError::RecordNotFound { .. }))
+        ));
+
+        let result = deadlines
+            .run(
+                OperationKind::Write,
+                "create_record",
+                slow(Duration::from_secs(1)),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(CoreError::Timeout {
+                timeout_ms: 500,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn timeout_error_serializes_with_code() -> CoreResult<()> {
+        let err = CoreError::Timeout {
+            operation: "list_domains".to_string(),
+            timeout_ms: 3000,
+        };
+        let value =
+            serde_json::to_value(&err).map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        assert_eq!(value["code"], "Timeout");
+        assert_eq!(value["details"]["operation"], "list_domains");
+        Ok(())
+    }
+}
@@ -1,17 +1,116 @@
 //! DNS 记录管理服务
 
-use std::sync::Arc;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::future::Future;
+use std::net::IpAddr;
+use std::ops::ControlFlow;
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 
-use dns_orchestrator_provider::ProviderError;
+use dns_orchestrator_provider::{DnsProvider, ProviderError, RecordChange as ProviderRecordChange};
+use futures::stream::{self, StreamExt, TryStreamExt};
 
 use crate::error::{CoreError, CoreResult};
-use crate::services::ServiceContext;
+use crate::services::breaker_provider::{record_outcome, BreakerProvider};
+use crate::services::char_safety;
+use crate::services::domain_group_service::normalized_value;
+use crate::services::record_validator::AUTO_TTL;
+use crate::services::write_queue_service::{
+    is_offline_error, replay_check, same_as_create, target_record_id, ReplayCheck,
+};
+use crate::services::zone_file::{
+    is_provider_managed, parse_zone_file, record_type_name, relative_name, render_zone_file,
+};
+use crate::services::{
+    paginate_pages, retry_provider_op, OperationKind, Page, PageCursor, PaginateOptions,
+    RecordValidator, RetryPolicy, ServiceContext,
+};
+use crate::traits::WriteLockTarget;
 use crate::types::{
-    BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DnsRecord,
-    DnsRecordType, PaginatedResponse, RecordQueryParams, UpdateDnsRecordRequest,
+    AuditEntry, AuditLogFilter, AuditOperation, BatchCreateFailure, BatchCreateRequest,
+    BatchCreateResult, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult,
+    BatchUpdateFailure, BatchUpdateItem, BatchUpdateRequest, BatchUpdateResult, ChangeSource,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainDiffResult, DomainStatus,
+    PaginatedResponse, PaginationParams, ProviderMetadata, QueuedWrite, QueuedWriteOperation,
+    RecordChange, RecordChangeEntry, RecordChangeType, RecordCharacterWarning, RecordData,
+    RecordImportFailure, RecordListProgress, RecordPair, RecordQueryParams, RecordSet,
+    RecordSetChangeFailure, RecordSetDetail, RecordSort, RecordSortField, RecordValidationReport,
+    ReplaceRecordValueRequest, SortOrder, UpdateDnsRecordRequest, UpdateRecordSetRequest,
+    UpdateRecordSetResult, WriteReplayOutcome, WriteReplayStatus, ZoneFileImportResult,
+    ZoneFileSkippedEntry,
 };
+use crate::utils::domain_name;
+
+/// 拉取域名全部记录时的分页大小（服务商未声明单页上限时使用）
+const LIST_ALL_PAGE_SIZE: u32 = 100;
+
+/// 全量拉取记录时每拉取一页的回调（当前进度与该页记录），返回 `Break` 时停止翻页
+type PageCallback<'a> =
+    dyn Fn(&RecordListProgress, &[DnsRecord]) -> ControlFlow<()> + Send + Sync + 'a;
+
+/// 逐条批量写入记录时的最大并发数（Cloudflare 等服务商限流严格）
+const BATCH_WRITE_CONCURRENCY: usize = 5;
+
+/// 单条记录变更历史的最大条数
+const MAX_RECORD_HISTORY: u32 = 200;
+
+/// 域名处于 Pending 状态时记录操作附带的警告
+const PENDING_DOMAIN_WARNING: &str = "域名尚未激活，记录可能不生效";
 
 /// DNS 记录管理服务
+///
+/// 写操作调用服务商接口前按涉及的记录获取写锁（见 [`ServiceContext::lock_for_write`]），
+/// 同一记录的并发写入依次执行。
+///
+/// # Examples
+///
+/// 使用 `testing` feature 的内存实现创建并查询记录：
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use std::sync::Arc;
+///
+/// use dns_orchestrator_core::services::DnsService;
+/// use dns_orchestrator_core::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+/// use dns_orchestrator_core::types::{CreateDnsRecordRequest, RecordData};
+/// use dns_orchestrator_core::CoreError;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> dns_orchestrator_core::CoreResult<()> {
+/// let ctx = Arc::new(in_memory_context());
+/// let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+/// register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+/// let dns = DnsService::new(ctx);
+///
+/// let record = dns
+///     .create_record(
+///         "account-1",
+///         CreateDnsRecordRequest {
+///             domain_id: "zone-1".to_string(),
+///             name: "www".to_string(),
+///             ttl: 300,
+///             data: RecordData::A {
+///                 address: "192.0.2.1".to_string(),
+///             },
+///             proxied: None,
+///         },
+///     )
+///     .await?;
+/// assert_eq!(provider.records().len(), 1);
+///
+/// dns.delete_record("account-1", &record.id, "zone-1").await?;
+/// assert!(provider.records().is_empty());
+///
+/// // 未注册的账户
+/// let err = dns
+///     .list_records("missing", "zone-1", None, None, None, None, None)
+///     .await
+///     .unwrap_err();
+/// assert!(matches!(err, CoreError::AccountNotFound(_)));
+/// # Ok(())
+/// # }
+/// ```
 pub struct DnsService {
     ctx: Arc<ServiceContext>,
 }
@@ -24,6 +123,10 @@ impl DnsService {
     }
 
     /// 列出域名下的所有 DNS 记录（分页 + 搜索）
+    ///
+    /// 指定 `sort` 时，支持服务端排序的服务商按页直接返回排序结果；其他服务商先拉取全部匹配的
+    /// 记录，在本地排序后再分页。
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_records(
         &self,
         account_id: &str,
@@ -32,60 +135,421 @@ impl DnsService {
         page_size: Option<u32>,
         keyword: Option<String>,
         record_type: Option<DnsRecordType>,
+        sort: Option<RecordSort>,
     ) -> CoreResult<PaginatedResponse<DnsRecord>> {
-        let provider = self.ctx.get_provider(account_id).await?;
+        let provider = self.get_provider(account_id).await?;
 
         let params = RecordQueryParams {
-            page: page.unwrap_or(1),
+            page: page.unwrap_or(1).max(1),
             page_size: page_size.unwrap_or(20),
             keyword,
             record_type,
+            sort,
         };
 
-        match provider.list_records(domain_id, &params).await {
+        if let Some(sort) = sort {
+            let server_side = self
+                .ctx
+                .static_provider_metadata(account_id)
+                .await
+                .is_ok_and(|metadata| metadata.features.server_side_sort);
+            if !server_side {
+                let mut records = self
+                    .fetch_record_pages(
+                        &provider,
+                        account_id,
+                        domain_id,
+                        params.keyword.clone(),
+                        params.record_type.clone(),
+                        None,
+                    )
+                    .await?;
+                sort_records(&mut records, sort);
+                return Ok(paginate(records, params.page, params.page_size));
+            }
+        }
+
+        match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "list_records",
+                provider.list_records(domain_id, &params),
+            )
+            .await
+        {
             Ok(response) => Ok(response),
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
+    /// 拉取域名下的全部记录（按服务商的单页上限自动翻页）
+    pub async fn list_all_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        let provider = self.get_provider(account_id).await?;
+        self.fetch_all_records(&provider, account_id, domain_id, None)
+            .await
+    }
+
+    /// 同 [`Self::list_all_records`]，每拉取一页调用一次 `on_progress`
+    pub async fn list_all_records_with_progress<F>(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        on_progress: F,
+    ) -> CoreResult<Vec<DnsRecord>>
+    where
+        F: Fn(&RecordListProgress) + Send + Sync,
+    {
+        let provider = self.get_provider(account_id).await?;
+        let on_page = |progress: &RecordListProgress, _: &[DnsRecord]| {
+            on_progress(progress);
+            ControlFlow::Continue(())
+        };
+        self.fetch_record_pages(&provider, account_id, domain_id, None, None, Some(&on_page))
+            .await
+    }
+
     /// 创建 DNS 记录
+    ///
+    /// 启用离线写队列时，网络错误导致的失败进入队列并返回 `CoreError::WriteQueued`。
     pub async fn create_record(
         &self,
         account_id: &str,
         request: CreateDnsRecordRequest,
     ) -> CoreResult<DnsRecord> {
-        let provider = self.ctx.get_provider(account_id).await?;
-        match provider.create_record(&request).await {
-            Ok(record) => Ok(record),
-            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        self.create_record_inner(account_id, request, true, ChangeSource::Manual)
+            .await
+    }
+
+    /// 计划任务创建记录：审计来源记为计划任务，网络错误不进入离线写队列（由调用方重试）
+    pub(crate) async fn create_scheduled_record(
+        &self,
+        account_id: &str,
+        request: CreateDnsRecordRequest,
+    ) -> CoreResult<DnsRecord> {
+        self.create_record_inner(account_id, request, false, ChangeSource::Scheduled)
+            .await
+    }
+
+    async fn create_record_inner(
+        &self,
+        account_id: &str,
+        request: CreateDnsRecordRequest,
+        queue_offline: bool,
+        source: ChangeSource,
+    ) -> CoreResult<DnsRecord> {
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "create_record",
+                vec![WriteLockTarget::records(account_id, &request.domain_id, [])],
+            )
+            .await?;
+        self.ensure_valid_record(account_id, &request, None).await?;
+        let request = CreateDnsRecordRequest {
+            name: domain_name::normalize_record_name(&request.name)?,
+            ..request
+        };
+        let provider = self.acquire_provider(account_id).await?;
+        let retrying = AtomicBool::new(false);
+        let (provider_ref, request_ref, retrying) = (&provider, &request, &retrying);
+        let result = self
+            .retry_write(account_id, "create_record", || async move {
+                // 上一次尝试可能已在服务商侧生效（如响应丢失），重试前先查找相同记录
+                if retrying.swap(true, Ordering::Relaxed) {
+                    if let Some(record) = find_created_record(provider_ref, request_ref).await? {
+                        return Ok(record);
+                    }
+                }
+                provider_ref.create_record(request_ref).await
+            })
+            .await;
+        let mut entry = audit_entry(
+            account_id,
+            &request.domain_id,
+            AuditOperation::Create,
+            None,
+            None,
+            result.as_ref().map(Some),
+        );
+        entry.source = source;
+        self.ctx.record_audit(entry).await;
+        match result {
+            Err(e) if queue_offline => Err(self
+                .queue_offline_write(
+                    account_id,
+                    &request.domain_id,
+                    QueuedWriteOperation::Create {
+                        request: request.clone(),
+                    },
+                    None,
+                    e,
+                )
+                .await),
+            result => result,
         }
     }
 
     /// 更新 DNS 记录
+    ///
+    /// 启用离线写队列时，网络错误导致的失败进入队列并返回 `CoreError::WriteQueued`。
     pub async fn update_record(
         &self,
         account_id: &str,
         record_id: &str,
         request: UpdateDnsRecordRequest,
     ) -> CoreResult<DnsRecord> {
-        let provider = self.ctx.get_provider(account_id).await?;
-        match provider.update_record(record_id, &request).await {
-            Ok(record) => Ok(record),
-            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        self.update_record_inner(account_id, record_id, request, true)
+            .await
+    }
+
+    async fn update_record_inner(
+        &self,
+        account_id: &str,
+        record_id: &str,
+        request: UpdateDnsRecordRequest,
+        queue_offline: bool,
+    ) -> CoreResult<DnsRecord> {
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "update_record",
+                vec![WriteLockTarget::record(
+                    account_id,
+                    &request.domain_id,
+                    record_id,
+                )],
+            )
+            .await?;
+        let as_create = CreateDnsRecordRequest {
+            domain_id: request.domain_id.clone(),
+            name: request.name.clone(),
+            ttl: request.ttl,
+            data: request.data.clone(),
+            proxied: request.proxied,
+        };
+        if matches!(request.data, RecordData::SOA { .. }) {
+            self.ensure_soa_editable(account_id).await?;
+        }
+        self.ensure_valid_record(account_id, &as_create, Some(record_id))
+            .await?;
+        let request = UpdateDnsRecordRequest {
+            name: domain_name::normalize_record_name(&request.name)?,
+            ..request
+        };
+        let provider = self.acquire_provider(account_id).await?;
+        let before = self
+            .snapshot_records(
+                &self.observed(account_id, &provider),
+                account_id,
+                &request.domain_id,
+                &[record_id],
+                Some((&request.name, &request.data.record_type())),
+            )
+            .await
+            .remove(record_id);
+        let result = self
+            .retry_write(account_id, "update_record", || {
+                provider.update_record(record_id, &request)
+            })
+            .await;
+        self.ctx
+            .record_audit(audit_entry(
+                account_id,
+                &request.domain_id,
+                AuditOperation::Update,
+                Some(record_id),
+                before.clone(),
+                result.as_ref().map(Some),
+            ))
+            .await;
+        match result {
+            Err(e) if queue_offline => Err(self
+                .queue_offline_write(
+                    account_id,
+                    &request.domain_id,
+                    QueuedWriteOperation::Update {
+                        record_id: record_id.to_string(),
+                        request: request.clone(),
+                    },
+                    before,
+                    e,
+                )
+                .await),
+            result => result,
+        }
+    }
+
+    /// 校验记录（类型规则 + 与同名记录的 CNAME 冲突 + SOA 写入限制），`record_id` 为正在更新的记录
+    ///
+    /// 类型规则见 [`RecordValidator::validate_record`]，TTL 上限按账户能力放宽。
+    /// 拉取现有记录失败时跳过冲突检查。
+    pub async fn validate_record(
+        &self,
+        account_id: &str,
+        request: &CreateDnsRecordRequest,
+        record_id: Option<&str>,
+    ) -> CoreResult<RecordValidationReport> {
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+        let mut report =
+            RecordValidator::validate_record_with_max_ttl(request, metadata.limits.max_ttl);
+        if report.has_errors() {
+            return Ok(report);
+        }
+        // 现有记录的名称为 punycode，冲突检查按 punycode 比较
+        let request = &CreateDnsRecordRequest {
+            name: domain_name::normalize_record_name(&request.name)?,
+            ..request.clone()
+        };
+
+        match self
+            .fetch_domain_records(account_id, &request.domain_id)
+            .await
+        {
+            Ok((origin, records)) => {
+                report.errors.extend(RecordValidator::check_conflicts(
+                    request, &origin, &records, record_id,
+                ));
+                report.errors.extend(RecordValidator::check_soa_write(
+                    request, &records, record_id,
+                ));
+            }
+            Err(e) => log::warn!(
+                "[RecordValidator] Skipped conflict check for {}: {e}",
+                request.domain_id
+            ),
+        }
+        Ok(report)
+    }
+
+    /// 服务商未声明 `soa_editing` 时拒绝修改 SOA（多数云服务商不开放 SOA 写入）
+    async fn ensure_soa_editable(&self, account_id: &str) -> CoreResult<()> {
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+        if metadata.features.soa_editing {
+            return Ok(());
+        }
+        Err(CoreError::Provider(ProviderError::UnsupportedCapability {
+            provider: metadata.id.to_string(),
+            capability: "soa_editing".to_string(),
+        }))
+    }
+
+    /// 先按账户能力检查（见 [`check_capabilities`]），校验失败时返回 [`CoreError::RecordValidation`]，
+    /// 警告只记录日志
+    async fn ensure_valid_record(
+        &self,
+        account_id: &str,
+        request: &CreateDnsRecordRequest,
+        record_id: Option<&str>,
+    ) -> CoreResult<()> {
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+        check_capabilities(&metadata, request.ttl, &request.data, request.proxied)?;
+        let report = self.validate_record(account_id, request, record_id).await?;
+        for warning in &report.warnings {
+            log::warn!(
+                "[RecordValidator] {} ({}): {}",
+                request.name,
+                request.domain_id,
+                warning.message
+            );
         }
+        if let Some(error) = RecordValidator::rejection(&report.errors) {
+            return Err(error);
+        }
+        Ok(())
     }
 
     /// 删除 DNS 记录
+    ///
+    /// 启用离线写队列时，网络错误导致的失败进入队列并返回 `CoreError::WriteQueued`。
     pub async fn delete_record(
         &self,
         account_id: &str,
         record_id: &str,
         domain_id: &str,
     ) -> CoreResult<()> {
-        let provider = self.ctx.get_provider(account_id).await?;
-        match provider.delete_record(record_id, domain_id).await {
-            Ok(()) => Ok(()),
-            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        self.delete_record_inner(account_id, record_id, domain_id, true, ChangeSource::Manual)
+            .await
+    }
+
+    /// 计划任务删除记录：审计来源记为计划任务，网络错误不进入离线写队列（由调用方重试）
+    pub(crate) async fn delete_scheduled_record(
+        &self,
+        account_id: &str,
+        record_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<()> {
+        self.delete_record_inner(
+            account_id,
+            record_id,
+            domain_id,
+            false,
+            ChangeSource::Scheduled,
+        )
+        .await
+    }
+
+    async fn delete_record_inner(
+        &self,
+        account_id: &str,
+        record_id: &str,
+        domain_id: &str,
+        queue_offline: bool,
+        source: ChangeSource,
+    ) -> CoreResult<()> {
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "delete_record",
+                vec![WriteLockTarget::record(account_id, domain_id, record_id)],
+            )
+            .await?;
+        let provider = self.acquire_provider(account_id).await?;
+        let before = self
+            .snapshot_records(
+                &self.observed(account_id, &provider),
+                account_id,
+                domain_id,
+                &[record_id],
+                None,
+            )
+            .await
+            .remove(record_id);
+        let result = self
+            .retry_write(account_id, "delete_record", || {
+                provider.delete_record(record_id, domain_id)
+            })
+            .await;
+        let mut entry = audit_entry(
+            account_id,
+            domain_id,
+            AuditOperation::Delete,
+            Some(record_id),
+            before.clone(),
+            result.as_ref().map(|()| None),
+        );
+        entry.source = source;
+        self.ctx.record_audit(entry).await;
+        match result {
+            Err(e) if queue_offline => Err(self
+                .queue_offline_write(
+                    account_id,
+                    domain_id,
+                    QueuedWriteOperation::Delete {
+                        record_id: record_id.to_string(),
+                    },
+                    before,
+                    e,
+                )
+                .await),
+            result => result,
         }
     }
 
@@ -95,43 +559,69 @@ impl DnsService {
         account_id: &str,
         request: BatchDeleteRequest,
     ) -> CoreResult<BatchDeleteResult> {
-        let provider = self.ctx.get_provider(account_id).await?;
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "batch_delete_records",
+                vec![WriteLockTarget::records(
+                    account_id,
+                    &request.domain_id,
+                    request.record_ids.clone(),
+                )],
+            )
+            .await?;
+        let provider = self.get_provider(account_id).await?;
+        let record_ids: Vec<&str> = request.record_ids.iter().map(String::as_str).collect();
+        let mut before = self
+            .snapshot_records(&provider, account_id, &request.domain_id, &record_ids, None)
+            .await;
 
         let mut success_count = 0;
         let mut failures = Vec::new();
 
-        // 并行删除所有记录
-        let delete_futures: Vec<_> = request
+        let changes = request
             .record_ids
             .iter()
-            .map(|record_id| {
-                let provider = provider.clone();
-                let domain_id = request.domain_id.clone();
-                let record_id = record_id.clone();
-                async move {
-                    match provider.delete_record(&record_id, &domain_id).await {
-                        Ok(()) => Ok(record_id),
-                        Err(e) => Err((record_id, e)),
-                    }
-                }
+            .map(|record_id| ProviderRecordChange::Delete {
+                record_id: record_id.clone(),
             })
             .collect();
+        let results = self
+            .submit_changes(&provider, account_id, &request.domain_id, changes)
+            .await;
 
-        let results = futures::future::join_all(delete_futures).await;
-
-        for result in results {
+        for (record_id, (result, attempts)) in request.record_ids.iter().cloned().zip(results) {
             match result {
-                Ok(_) => success_count += 1,
-                Err((record_id, e)) => {
-                    // 检查是否是凭证失效
-                    if let ProviderError::InvalidCredentials { .. } = &e {
-                        self.ctx
-                            .mark_account_invalid(account_id, "凭证已失效")
-                            .await;
-                    }
+                Ok(_) => {
+                    success_count += 1;
+                    self.ctx
+                        .record_audit(audit_entry(
+                            account_id,
+                            &request.domain_id,
+                            AuditOperation::BatchDelete,
+                            Some(&record_id),
+                            before.remove(&record_id),
+                            Ok(None),
+                        ))
+                        .await;
+                }
+                Err(e) => {
+                    let e = self.handle_provider_error(account_id, e).await;
+                    self.ctx
+                        .record_audit(audit_entry(
+                            account_id,
+                            &request.domain_id,
+                            AuditOperation::BatchDelete,
+                            Some(&record_id),
+                            before.remove(&record_id),
+                            Err(&e),
+                        ))
+                        .await;
                     failures.push(BatchDeleteFailure {
                         record_id,
                         reason: e.to_string(),
+                        attempts: Some(attempts),
                     });
                 }
             }
@@ -144,13 +634,3130 @@ impl DnsService {
         })
     }
 
-    /// 处理 Provider 错误，如果是凭证失效则更新账户状态
-    async fn handle_provider_error(&self, account_id: &str, err: ProviderError) -> CoreError {
-        if let ProviderError::InvalidCredentials { .. } = &err {
+    /// 批量创建 DNS 记录
+    ///
+    /// 经 [`Self::submit_changes`] 提交（原生批量或有限并发逐条），单条失败不影响其他记录。
+    /// 结果中的记录按请求顺序排列，失败项附带原始请求，便于调用方选择性重试。
+    /// 含不可见字符或同形字符的记录直接计为失败，`normalize` 为 true 时先替换为 ASCII。
+    /// SOA 记录只能通过 [`Self::update_record`] 单独修改，批量写入时计为失败。
+    pub async fn batch_create_records(
+        &self,
+        account_id: &str,
+        request: BatchCreateRequest,
+    ) -> CoreResult<BatchCreateResult> {
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "batch_create_records",
+                vec![WriteLockTarget::records(account_id, &request.domain_id, [])],
+            )
+            .await?;
+        let provider = self.get_provider(account_id).await?;
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+        let domain_id = request.domain_id;
+
+        let mut warnings = Vec::new();
+        let mut failures = Vec::new();
+        let mut pending = Vec::new();
+        for (index, record) in request.records.into_iter().enumerate() {
+            let mut record = CreateDnsRecordRequest {
+                domain_id: domain_id.clone(),
+                ..record
+            };
+            match reject_soa(&record.data)
+                .and_then(|()| {
+                    check_capabilities(&metadata, record.ttl, &record.data, record.proxied)
+                        .map_err(|e| e.to_string())
+                })
+                .and_then(|()| {
+                    char_safety::screen_record(
+                        &mut record.name,
+                        &mut record.data,
+                        request.normalize,
+                        &mut warnings,
+                    )
+                }) {
+                Ok(()) => pending.push((index, record)),
+                Err(reason) => failures.push(BatchCreateFailure {
+                    request_index: index,
+                    record_name: record.name.clone(),
+                    reason,
+                    request: record,
+                }),
+            }
+        }
+
+        let changes = pending
+            .iter()
+            .map(|(_, record)| ProviderRecordChange::Create(record.clone()))
+            .collect();
+        let results = self
+            .submit_changes(&provider, account_id, &domain_id, changes)
+            .await;
+
+        let mut created_records = Vec::new();
+        for ((request_index, request), (result, _)) in pending.into_iter().zip(results) {
+            let result = match result {
+                Ok(record) => Ok(record),
+                Err(e) => Err(self.handle_provider_error(account_id, e).await),
+            };
+            self.ctx
+                .record_audit(audit_entry(
+                    account_id,
+                    &domain_id,
+                    AuditOperation::BatchCreate,
+                    None,
+                    None,
+                    result.as_ref().map(Option::as_ref),
+                ))
+                .await;
+            match result {
+                Ok(record) => created_records.extend(record),
+                Err(e) => {
+                    failures.push(BatchCreateFailure {
+                        request_index,
+                        record_name: request.name.clone(),
+                        reason: e.to_string(),
+                        request,
+                    });
+                }
+            }
+        }
+
+        failures.sort_by_key(|failure| failure.request_index);
+
+        Ok(BatchCreateResult {
+            success_count: created_records.len(),
+            failed_count: failures.len(),
+            created_records,
+            failures,
+            warnings,
+        })
+    }
+
+    /// 批量更新 DNS 记录
+    ///
+    /// 提交方式同 [`Self::batch_create_records`]，单条失败不影响其他记录。
+    /// 结果中的记录按请求顺序排列。
+    /// 可疑字符的处理同 [`Self::batch_create_records`]。
+    pub async fn batch_update_records(
+        &self,
+        account_id: &str,
+        request: BatchUpdateRequest,
+    ) -> CoreResult<BatchUpdateResult> {
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "batch_update_records",
+                vec![WriteLockTarget::records(
+                    account_id,
+                    &request.domain_id,
+                    request.updates.iter().map(|item| item.record_id.clone()),
+                )],
+            )
+            .await?;
+        let provider = self.get_provider(account_id).await?;
+        let record_ids: Vec<&str> = request
+            .updates
+            .iter()
+            .map(|item| item.record_id.as_str())
+            .collect();
+        let before = self
+            .snapshot_records(&provider, account_id, &request.domain_id, &record_ids, None)
+            .await;
+        self.apply_batch_update(&provider, account_id, request, before)
+            .await
+    }
+
+    /// 执行批量更新，`before` 为审计日志使用的变更前快照
+    async fn apply_batch_update(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        request: BatchUpdateRequest,
+        mut before: HashMap<String, DnsRecord>,
+    ) -> CoreResult<BatchUpdateResult> {
+        let domain_id = request.domain_id;
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+
+        let mut warnings = Vec::new();
+        let mut failures = Vec::new();
+        let mut pending = Vec::new();
+        for item in request.updates {
+            let mut update = UpdateDnsRecordRequest {
+                domain_id: domain_id.clone(),
+                ..item.request
+            };
+            match reject_soa(&update.data)
+                .and_then(|()| {
+                    check_capabilities(&metadata, update.ttl, &update.data, update.proxied)
+                        .map_err(|e| e.to_string())
+                })
+                .and_then(|()| {
+                    char_safety::screen_record(
+                        &mut update.name,
+                        &mut update.data,
+                        request.normalize,
+                        &mut warnings,
+                    )
+                }) {
+                Ok(()) => pending.push((item.record_id, update)),
+                Err(reason) => failures.push(BatchUpdateFailure {
+                    record_id: item.record_id,
+                    reason,
+                }),
+            }
+        }
+
+        let (record_ids, changes): (Vec<String>, Vec<ProviderRecordChange>) = pending
+            .into_iter()
+            .map(|(record_id, update)| {
+                let change = ProviderRecordChange::Update {
+                    record_id: record_id.clone(),
+                    request: update,
+                };
+                (record_id, change)
+            })
+            .unzip();
+        let results = self
+            .submit_changes(provider, account_id, &domain_id, changes)
+            .await;
+
+        let mut updated_records = Vec::new();
+        for (record_id, (result, _)) in record_ids.into_iter().zip(results) {
+            let result = match result {
+                Ok(record) => Ok(record),
+                Err(e) => Err(self.handle_provider_error(account_id, e).await),
+            };
             self.ctx
-                .mark_account_invalid(account_id, "凭证已失效")
+                .record_audit(audit_entry(
+                    account_id,
+                    &domain_id,
+                    AuditOperation::BatchUpdate,
+                    Some(&record_id),
+                    before.remove(&record_id),
+                    result.as_ref().map(Option::as_ref),
+                ))
+                .await;
+            match result {
+                Ok(record) => updated_records.extend(record),
+                Err(e) => {
+                    failures.push(BatchUpdateFailure {
+                        record_id,
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(BatchUpdateResult {
+            success_count: updated_records.len(),
+            failed_count: failures.len(),
+            updated_records,
+            failures,
+            warnings,
+        })
+    }
+
+    /// 按值批量替换记录
+    ///
+    /// 先列出域名下全部记录，找出值等于 `old_value` 的记录（可按类型过滤），
+    /// 再以与 [`Self::batch_update_records`] 相同的方式更新为 `new_value`，其余字段保持不变。
+    pub async fn replace_record_value(
+        &self,
+        account_id: &str,
+        request: ReplaceRecordValueRequest,
+    ) -> CoreResult<BatchUpdateResult> {
+        let old_value = request.old_value.trim();
+        let new_value = request.new_value.trim();
+        if old_value.is_empty() || new_value.is_empty() {
+            return Err(CoreError::ValidationError("新旧值不能为空".to_string()));
+        }
+        if old_value == new_value {
+            return Err(CoreError::ValidationError("新旧值相同".to_string()));
+        }
+
+        self.ctx.ensure_change_window_open().await?;
+        // 先读后写，读取前独占整个域名
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "replace_record_value",
+                vec![WriteLockTarget::domain(account_id, &request.domain_id)],
+            )
+            .await?;
+        let provider = self.get_provider(account_id).await?;
+        let records = self
+            .fetch_all_records(
+                &provider,
+                account_id,
+                &request.domain_id,
+                request.record_type.clone(),
+            )
+            .await?;
+
+        let before: HashMap<String, DnsRecord> = if self.ctx.audit_log_repository.is_some() {
+            records
+                .iter()
+                .map(|record| (record.id.clone(), record.clone()))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+        let updates: Vec<BatchUpdateItem> = records
+            .into_iter()
+            .filter(|record| {
+                request
+                    .record_type
+                    .as_ref()
+                    .is_none_or(|t| record.data.record_type() == *t)
+            })
+            .filter(|record| value_matches(&record.data, old_value))
+            .map(|record| BatchUpdateItem {
+                request: UpdateDnsRecordRequest {
+                    domain_id: request.domain_id.clone(),
+                    name: record.name,
+                    ttl: record.ttl,
+                    data: replace_value(record.data, new_value),
+                    proxied: record.proxied,
+                },
+                record_id: record.id,
+            })
+            .collect();
+
+        log::info!(
+            "[ReplaceValue] {}: {} records matched",
+            request.domain_id,
+            updates.len()
+        );
+        self.apply_batch_update(
+            &provider,
+            account_id,
+            BatchUpdateRequest {
+                domain_id: request.domain_id,
+                updates,
+                normalize: false,
+            },
+            before,
+        )
+        .await
+    }
+
+    /// 获取同名同类型的一组记录（多值 A/AAAA/TXT 等）
+    ///
+    /// 服务商声明 `record_sets` 时直接读取服务商的记录组，组内各值共用整组的 ID。
+    pub async fn get_record_set(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        name: &str,
+        record_type: DnsRecordType,
+    ) -> CoreResult<RecordSetDetail> {
+        let provider = self.get_provider(account_id).await?;
+        let native = self
+            .ctx
+            .provider_metadata(account_id)
+            .await?
+            .features
+            .record_sets;
+        let records = if native {
+            self.native_record_set(&provider, account_id, domain_id, name, &record_type)
+                .await?
+        } else {
+            let origin = match self
+                .ctx
+                .with_deadline(
+                    OperationKind::Read,
+                    "get_domain",
+                    provider.get_domain(domain_id),
+                )
+                .await
+            {
+                Ok(domain) => domain.name,
+                Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+            };
+            self.fetch_all_records(&provider, account_id, domain_id, Some(record_type.clone()))
+                .await?
+                .into_iter()
+                .filter(|record| in_record_set(record, &origin, name, &record_type))
+                .collect()
+        };
+        Ok(record_set_detail(
+            domain_id,
+            name,
+            record_type,
+            records,
+            native,
+        ))
+    }
+
+    /// 将一组记录整体更新为期望的值集合
+    ///
+    /// 与当前记录比较后生成最小变更集（见 [`plan_record_set`]）。服务商声明 `record_sets` 时
+    /// 一次替换整组；否则逐条执行（先创建、再更新、最后删除），遇到第一个失败即停止，
+    /// 结果中列出已生效、失败与未执行的变更。
+    pub async fn update_record_set(
+        &self,
+        account_id: &str,
+        request: UpdateRecordSetRequest,
+    ) -> CoreResult<UpdateRecordSetResult> {
+        check_record_set_request(&request)?;
+
+        self.ctx.ensure_change_window_open().await?;
+        // 先读后写，读取前独占整个域名
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "update_record_set",
+                vec![WriteLockTarget::domain(account_id, &request.domain_id)],
+            )
+            .await?;
+        let provider = self.get_provider(account_id).await?;
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+        let native = metadata.features.record_sets;
+        let (origin, records) = self
+            .fetch_domain_records(account_id, &request.domain_id)
+            .await?;
+        let (current, others): (Vec<DnsRecord>, Vec<DnsRecord>) =
+            records.into_iter().partition(|record| {
+                in_record_set(record, &origin, &request.name, &request.record_type)
+            });
+        let current = if native {
+            // 原生记录组的列表接口可能只展示部分值，以整组读取为准
+            self.native_record_set(
+                &provider,
+                account_id,
+                &request.domain_id,
+                &request.name,
+                &request.record_type,
+            )
+            .await?
+        } else {
+            current
+        };
+        validate_record_set_values(&request, metadata.limits.max_ttl, &origin, &others)?;
+
+        let changes = plan_record_set(&origin, &current, &request);
+        log::info!(
+            "[RecordSet] {} {} {:?}: {} changes (native: {native})",
+            request.domain_id,
+            request.name,
+            request.record_type,
+            changes.len()
+        );
+        if changes.is_empty() {
+            return Ok(UpdateRecordSetResult {
+                native,
+                applied: Vec::new(),
+                failure: None,
+                skipped: Vec::new(),
+            });
+        }
+
+        if native {
+            let set = RecordSet {
+                id: None,
+                name: request.name.clone(),
+                record_type: request.record_type.clone(),
+                ttl: request.ttl,
+                values: distinct_values(&origin, &request.values)
+                    .into_iter()
+                    .map(|(data, _)| data.clone())
+                    .collect(),
+            };
+            let result = match self
+                .ctx
+                .with_deadline(
+                    OperationKind::Write,
+                    "replace_record_set",
+                    provider.replace_record_set(&request.domain_id, &set),
+                )
+                .await
+            {
+                Ok(()) => Ok(()),
+                Err(e) => Err(self.handle_provider_error(account_id, e).await),
+            };
+            for change in &changes {
+                self.record_change_audit(
+                    account_id,
+                    &request.domain_id,
+                    change,
+                    &current,
+                    result.as_ref().map(|()| None),
+                )
+                .await;
+            }
+            result?;
+            return Ok(UpdateRecordSetResult {
+                native,
+                applied: changes,
+                failure: None,
+                skipped: Vec::new(),
+            });
+        }
+
+        let mut applied = Vec::new();
+        let mut pending = changes.into_iter();
+        while let Some(change) = pending.next() {
+            let result = self
+                .apply_record_change(&provider, account_id, &request.domain_id, &change)
                 .await;
+            self.record_change_audit(
+                account_id,
+                &request.domain_id,
+                &change,
+                &current,
+                result.as_ref().map(Option::as_ref),
+            )
+            .await;
+            if let Err(e) = result {
+                return Ok(UpdateRecordSetResult {
+                    native,
+                    applied,
+                    failure: Some(RecordSetChangeFailure {
+                        change,
+                        reason: e.to_string(),
+                    }),
+                    skipped: pending.collect(),
+                });
+            }
+            applied.push(change);
+        }
+        Ok(UpdateRecordSetResult {
+            native,
+            applied,
+            failure: None,
+            skipped: Vec::new(),
+        })
+    }
+
+    /// 读取服务商原生的记录组，展开为逐值的记录
+    async fn native_record_set(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        let set = match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_record_set",
+                provider.get_record_set(domain_id, name, record_type),
+            )
+            .await
+        {
+            Ok(set) => set,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        Ok(set.map_or_else(Vec::new, |set| {
+            let id = set.id.unwrap_or_default();
+            set.values
+                .into_iter()
+                .map(|data| DnsRecord {
+                    id: id.clone(),
+                    domain_id: domain_id.to_string(),
+                    name: set.name.clone(),
+                    ttl: set.ttl,
+                    data,
+                    proxied: None,
+                    created_at: None,
+                    updated_at: None,
+                })
+                .collect()
+        }))
+    }
+
+    /// 逐条执行整组更新中的一条变更，成功时返回变更后的记录（删除为 `None`）
+    async fn apply_record_change(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        change: &RecordChange,
+    ) -> CoreResult<Option<DnsRecord>> {
+        let result = match change {
+            RecordChange::Create { request } => self
+                .ctx
+                .with_deadline(
+                    OperationKind::Write,
+                    "create_record",
+                    provider.create_record(request),
+                )
+                .await
+                .map(Some),
+            RecordChange::Update {
+                record_id, request, ..
+            } => self
+                .ctx
+                .with_deadline(
+                    OperationKind::Write,
+                    "update_record",
+                    provider.update_record(record_id, request),
+                )
+                .await
+                .map(Some),
+            RecordChange::Delete { record_id, .. } => self
+                .ctx
+                .with_deadline(
+                    OperationKind::Write,
+                    "delete_record",
+                    provider.delete_record(record_id, domain_id),
+                )
+                .await
+                .map(|()| None),
+        };
+        match result {
+            Ok(record) => Ok(record),
+            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        }
+    }
+
+    /// 记录整组更新中单条变更的审计日志，`current` 为变更前的组内记录
+    async fn record_change_audit(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        change: &RecordChange,
+        current: &[DnsRecord],
+        outcome: Result<Option<&DnsRecord>, &CoreError>,
+    ) {
+        let (operation, record_id) = match change {
+            RecordChange::Create { .. } => (AuditOperation::Create, None),
+            RecordChange::Update { record_id, .. } => {
+                (AuditOperation::Update, Some(record_id.as_str()))
+            }
+            RecordChange::Delete { record_id, .. } => {
+                (AuditOperation::Delete, Some(record_id.as_str()))
+            }
+        };
+        let before =
+            record_id.and_then(|id| current.iter().find(|record| record.id == id).cloned());
+        self.ctx
+            .record_audit(audit_entry(
+                account_id, domain_id, operation, record_id, before, outcome,
+            ))
+            .await;
+    }
+
+    /// 执行其他服务规划好的单条记录变更（记录复制、域名组同步、记录导入、灰度切换）
+    ///
+    /// 变更窗口与写锁由调用方负责。写入前做与手动写入相同的能力检查、类型规则与可疑字符校验
+    /// （规划时已与现有记录比对，不再做冲突检查），写入按重试策略执行并计入熔断器。
+    /// `before` 为变更前的记录，为空时按需快照；无论成败都写入审计日志。
+    /// 成功时返回变更后的记录（删除为 `None`）。
+    pub(crate) async fn apply_planned_change(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        change: &RecordChange,
+        before: Option<DnsRecord>,
+        source: ChangeSource,
+    ) -> CoreResult<Option<DnsRecord>> {
+        let change = match change {
+            RecordChange::Create { request } => {
+                self.ensure_valid_planned_record(account_id, request)
+                    .await?;
+                RecordChange::Create {
+                    request: CreateDnsRecordRequest {
+                        name: domain_name::normalize_record_name(&request.name)?,
+                        ..request.clone()
+                    },
+                }
+            }
+            RecordChange::Update {
+                record_id,
+                previous_value,
+                request,
+            } => {
+                let as_create = CreateDnsRecordRequest {
+                    domain_id: request.domain_id.clone(),
+                    name: request.name.clone(),
+                    ttl: request.ttl,
+                    data: request.data.clone(),
+                    proxied: request.proxied,
+                };
+                self.ensure_valid_planned_record(account_id, &as_create)
+                    .await?;
+                RecordChange::Update {
+                    record_id: record_id.clone(),
+                    previous_value: previous_value.clone(),
+                    request: UpdateDnsRecordRequest {
+                        name: domain_name::normalize_record_name(&request.name)?,
+                        ..request.clone()
+                    },
+                }
+            }
+            RecordChange::Delete { .. } => change.clone(),
+        };
+
+        let provider = self.acquire_provider(account_id).await?;
+        let (operation, record_id, hint) = match &change {
+            RecordChange::Create { .. } => (AuditOperation::Create, None, None),
+            RecordChange::Update {
+                record_id, request, ..
+            } => (
+                AuditOperation::Update,
+                Some(record_id.as_str()),
+                Some((request.name.as_str(), request.data.record_type())),
+            ),
+            RecordChange::Delete { record_id, .. } => {
+                (AuditOperation::Delete, Some(record_id.as_str()), None)
+            }
+        };
+        let before = match (before, record_id) {
+            (Some(before), _) => Some(before),
+            (None, Some(record_id)) => self
+                .snapshot_records(
+                    &self.observed(account_id, &provider),
+                    account_id,
+                    domain_id,
+                    &[record_id],
+                    hint.as_ref()
+                        .map(|(name, record_type)| (*name, record_type)),
+                )
+                .await
+                .remove(record_id),
+            (None, None) => None,
+        };
+
+        let result = match &change {
+            RecordChange::Create { request } => {
+                let retrying = AtomicBool::new(false);
+                let (provider_ref, retrying) = (&provider, &retrying);
+                self.retry_write(account_id, "create_record", || async move {
+                    // 上一次尝试可能已在服务商侧生效（如响应丢失），重试前先查找相同记录
+                    if retrying.swap(true, Ordering::Relaxed) {
+                        if let Some(record) = find_created_record(provider_ref, request).await? {
+                            return Ok(record);
+                        }
+                    }
+                    provider_ref.create_record(request).await
+                })
+                .await
+                .map(Some)
+            }
+            RecordChange::Update {
+                record_id, request, ..
+            } => self
+                .retry_write(account_id, "update_record", || {
+                    provider.update_record(record_id, request)
+                })
+                .await
+                .map(Some),
+            RecordChange::Delete { record_id, .. } => self
+                .retry_write(account_id, "delete_record", || {
+                    provider.delete_record(record_id, domain_id)
+                })
+                .await
+                .map(|()| None),
+        };
+        let mut entry = audit_entry(
+            account_id,
+            domain_id,
+            operation,
+            record_id,
+            before,
+            result.as_ref().map(Option::as_ref),
+        );
+        entry.source = source;
+        self.ctx.record_audit(entry).await;
+        result
+    }
+
+    /// 校验规划好的写入：SOA 限制、账户能力、类型规则与可疑字符
+    async fn ensure_valid_planned_record(
+        &self,
+        account_id: &str,
+        request: &CreateDnsRecordRequest,
+    ) -> CoreResult<()> {
+        reject_soa(&request.data).map_err(CoreError::ValidationError)?;
+        let metadata = self.ctx.provider_metadata(account_id).await?;
+        check_capabilities(&metadata, request.ttl, &request.data, request.proxied)?;
+        let report =
+            RecordValidator::validate_record_with_max_ttl(request, metadata.limits.max_ttl);
+        if let Some(error) = RecordValidator::rejection(&report.errors) {
+            return Err(error);
+        }
+        char_safety::check_record(&request.name, &request.data).map_err(CoreError::ValidationError)
+    }
+
+    /// 导出域名的全部记录为 BIND zone 文件（RFC 1035）
+    pub async fn export_zone_file(&self, account_id: &str, domain_id: &str) -> CoreResult<String> {
+        let provider = self.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        let records = self
+            .fetch_all_records(&provider, account_id, domain_id, None)
+            .await?;
+
+        log::info!(
+            "[ZoneExport] {}: {} records exported",
+            domain.name,
+            records.len()
+        );
+        Ok(render_zone_file(&domain.name, &records))
+    }
+
+    /// 从 BIND zone 文件导入记录
+    ///
+    /// `dry_run` 为 true 时只解析并返回预览，不调用服务商写接口。
+    /// 记录通过 [`Self::batch_create_records`] 并发创建，单条失败不影响其他记录。
+    /// `normalize` 为 true 时先把不可见字符与同形字符替换为 ASCII，否则含此类字符的记录计入跳过。
+    pub async fn import_zone_file(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        zone_text: &str,
+        dry_run: bool,
+        normalize: bool,
+    ) -> CoreResult<ZoneFileImportResult> {
+        let provider = self.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        let parsed = parse_zone_file(zone_text, &domain.name);
+        let mut warnings = Vec::new();
+        let mut skipped = parsed.skipped;
+        let mut records = Vec::with_capacity(parsed.records.len());
+        for record in parsed.records {
+            let mut request = CreateDnsRecordRequest {
+                domain_id: domain_id.to_string(),
+                name: record.name,
+                ttl: record.ttl,
+                data: record.data,
+                proxied: None,
+            };
+            // 含可疑字符的记录写入时会被拒绝，预览阶段就计入跳过
+            match char_safety::screen_record(
+                &mut request.name,
+                &mut request.data,
+                normalize,
+                &mut warnings,
+            ) {
+                Ok(()) => records.push(request),
+                Err(reason) => skipped.push(ZoneFileSkippedEntry {
+                    line: record.line,
+                    content: record.content,
+                    reason,
+                }),
+            }
+        }
+        skipped.sort_by_key(|entry| entry.line);
+
+        let mut result = ZoneFileImportResult {
+            dry_run,
+            records,
+            skipped,
+            success_count: 0,
+            failed_count: 0,
+            failures: Vec::new(),
+            warnings,
+        };
+        if dry_run {
+            return Ok(result);
         }
-        CoreError::Provider(err)
+
+        let created = self
+            .batch_create_records(
+                account_id,
+                BatchCreateRequest {
+                    domain_id: domain_id.to_string(),
+                    records: result.records.clone(),
+                    normalize: false,
+                },
+            )
+            .await?;
+        result.success_count = created.success_count;
+        result.failed_count = created.failed_count;
+        result.failures = created
+            .failures
+            .into_iter()
+            .map(|failure| RecordImportFailure {
+                record_type: format!("{:?}", failure.request.data.record_type()).to_uppercase(),
+                name: failure.record_name,
+                reason: failure.reason,
+            })
+            .collect();
+
+        log::info!(
+            "[ZoneImport] {}: {} created, {} failed, {} skipped",
+            domain.name,
+            result.success_count,
+            result.failed_count,
+            result.skipped.len()
+        );
+        Ok(result)
+    }
+
+    /// 对比两个域名（可跨账户）的记录
+    ///
+    /// 按 名称 + 类型 对齐：名称转为相对名称，值规范化后忽略大小写比较，
+    /// 自动 TTL 不参与比较，根域 NS 由服务商托管，不参与对比。
+    pub async fn diff_domains(
+        &self,
+        account_a: &str,
+        domain_a: &str,
+        account_b: &str,
+        domain_b: &str,
+    ) -> CoreResult<DomainDiffResult> {
+        let (a, b) = futures::future::join(
+            self.fetch_domain_records(account_a, domain_a),
+            self.fetch_domain_records(account_b, domain_b),
+        )
+        .await;
+        let ((name_a, records_a), (name_b, records_b)) = (a?, b?);
+
+        let result = diff_records(&name_a, &records_a, &name_b, &records_b);
+        log::info!(
+            "[DomainDiff] {name_a} <-> {name_b}: {} only in a, {} only in b, {} different",
+            result.only_in_a.len(),
+            result.only_in_b.len(),
+            result.different.len()
+        );
+        Ok(result)
+    }
+
+    /// 扫描现有记录中的不可见字符、全角字符与同形字母
+    ///
+    /// 只读检查，结果作为警告展示；修复时带 `normalize` 重新保存对应记录即可。
+    pub async fn scan_record_characters(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<Vec<RecordCharacterWarning>> {
+        let (domain_name, records) = self.fetch_domain_records(account_id, domain_id).await?;
+        let warnings: Vec<RecordCharacterWarning> = records
+            .into_iter()
+            .filter_map(|record| {
+                let characters = char_safety::find_in_record(&record.name, &record.data);
+                (!characters.is_empty()).then(|| RecordCharacterWarning {
+                    record_type: record_type_name(&record.data).to_string(),
+                    record_id: record.id,
+                    name: record.name,
+                    characters,
+                })
+            })
+            .collect();
+
+        log::info!(
+            "[CharSafety] {domain_name}: {} records with suspicious characters",
+            warnings.len()
+        );
+        Ok(warnings)
+    }
+
+    /// 查询单条记录的变更历史（按时间倒序，最多 `limit` 条）
+    ///
+    /// 基于审计日志，只包含成功的变更；未启用审计日志时返回空列表。
+    pub async fn get_record_history(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        record_id: &str,
+        limit: u32,
+    ) -> CoreResult<Vec<RecordChangeEntry>> {
+        if limit == 0 || limit > MAX_RECORD_HISTORY {
+            return Err(CoreError::ValidationError(format!(
+                "limit 必须在 1 到 {MAX_RECORD_HISTORY} 之间"
+            )));
+        }
+        let Some(repository) = &self.ctx.audit_log_repository else {
+            return Ok(Vec::new());
+        };
+
+        let filter = AuditLogFilter {
+            account_id: Some(account_id.to_string()),
+            domain_id: Some(domain_id.to_string()),
+            record_id: Some(record_id.to_string()),
+            ..AuditLogFilter::default()
+        };
+        let page = repository
+            .query(
+                &filter,
+                &PaginationParams {
+                    page: 1,
+                    page_size: limit,
+                },
+            )
+            .await?;
+        Ok(page
+            .items
+            .into_iter()
+            .filter_map(RecordChangeEntry::from_audit_entry)
+            .collect())
+    }
+
+    /// 将记录恢复到某次变更之前的状态
+    ///
+    /// 删除的记录按变更前快照重新创建（记录 ID 会变化）；更新的记录改回变更前的值。
+    /// 恢复本身也经由普通写操作写入审计日志。创建操作没有变更前状态，无法恢复。
+    pub async fn restore_record(&self, account_id: &str, change_id: &str) -> CoreResult<DnsRecord> {
+        let Some(repository) = &self.ctx.audit_log_repository else {
+            return Err(CoreError::ValidationError(
+                "未启用审计日志，无法恢复记录".to_string(),
+            ));
+        };
+        let change = repository
+            .find_by_id(change_id)
+            .await?
+            .filter(|entry| entry.account_id == account_id)
+            .and_then(RecordChangeEntry::from_audit_entry)
+            .ok_or_else(|| CoreError::ValidationError(format!("变更记录不存在: {change_id}")))?;
+        if change.change_type == RecordChangeType::Create {
+            return Err(CoreError::ValidationError(
+                "创建操作没有变更前状态，无法恢复".to_string(),
+            ));
+        }
+        let before = change.before.ok_or_else(|| {
+            CoreError::ValidationError("该变更缺少变更前的记录快照，无法恢复".to_string())
+        })?;
+
+        if change.change_type == RecordChangeType::Update {
+            return self
+                .update_record(
+                    account_id,
+                    &change.record_id,
+                    UpdateDnsRecordRequest {
+                        domain_id: change.domain_id,
+                        name: before.name,
+                        ttl: before.ttl,
+                        data: before.data,
+                        proxied: before.proxied,
+                    },
+                )
+                .await;
+        }
+        self.create_record(
+            account_id,
+            CreateDnsRecordRequest {
+                domain_id: change.domain_id,
+                name: before.name,
+                ttl: before.ttl,
+                data: before.data,
+                proxied: before.proxied,
+            },
+        )
+        .await
+    }
+
+    /// 检查域名是否尚未激活，返回记录操作需附带的警告
+    ///
+    /// 尽力而为：获取域名失败或超时时不返回警告，也不影响记录操作本身。
+    pub async fn pending_domain_warning(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> Option<String> {
+        let provider = self.get_provider(account_id).await.ok()?;
+        let domain = self
+            .ctx
+            .with_deadline(
+                OperationKind::AggregateItem,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+            .ok()?;
+        (domain.status == DomainStatus::Pending).then(|| PENDING_DOMAIN_WARNING.to_string())
+    }
+
+    /// 拉取域名名称及其全部记录
+    async fn fetch_domain_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<(String, Vec<DnsRecord>)> {
+        let provider = self.get_provider(account_id).await?;
+        let domain = match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let records = self
+            .fetch_all_records(&provider, account_id, domain_id, None)
+            .await?;
+        Ok((domain.name, records))
+    }
+
+    /// 按 ID 查找域名下的记录（不存在时为 `None`）
+    pub(crate) async fn find_record(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        record_id: &str,
+    ) -> CoreResult<Option<DnsRecord>> {
+        let provider = self.get_provider(account_id).await?;
+        Ok(self
+            .fetch_all_records(&provider, account_id, domain_id, None)
+            .await?
+            .into_iter()
+            .find(|record| record.id == record_id))
+    }
+
+    /// 分页拉取域名下的全部记录（可按类型过滤）
+    async fn fetch_all_records(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        self.fetch_record_pages(provider, account_id, domain_id, None, record_type, None)
+            .await
+    }
+
+    /// 逐页拉取匹配 `keyword`、`record_type` 的全部记录
+    ///
+    /// 每页大小取服务商声明的单页上限。翻页规则见 [`paginate_pages`]，此外服务商忽略页码、重复返回
+    /// 上一页时，或 `on_page` 返回 `Break` 时也停止翻页。
+    async fn fetch_record_pages(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        keyword: Option<String>,
+        record_type: Option<DnsRecordType>,
+        on_page: Option<&PageCallback<'_>>,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        let page_size = self
+            .ctx
+            .static_provider_metadata(account_id)
+            .await
+            .ok()
+            .map(|metadata| metadata.limits.max_page_size_records)
+            .filter(|size| *size > 0)
+            .unwrap_or(LIST_ALL_PAGE_SIZE);
+        let fetch = move |cursor: PageCursor| {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size,
+                keyword: keyword.clone(),
+                record_type: record_type.clone(),
+                sort: None,
+            };
+            async move {
+                match self
+                    .ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_records",
+                        provider.list_records(domain_id, &params),
+                    )
+                    .await
+                {
+                    Ok(response) => Ok(Page::from(response)),
+                    Err(e) => Err(self.handle_provider_error(account_id, e).await),
+                }
+            }
+        };
+        let mut pages = pin!(paginate_pages(PaginateOptions::default(), fetch));
+        let mut records = Vec::new();
+        let mut previous_page = Vec::new();
+        let mut page_number = 0;
+        while let Some(page) = pages.try_next().await? {
+            page_number += 1;
+            // 服务商忽略页码时会重复返回同一页；记录组的各个值共用同一 ID，按 ID 加值比较
+            let page_keys: Vec<(String, String)> = page
+                .items
+                .iter()
+                .map(|record| (record.id.clone(), record.data.display_value()))
+                .collect();
+            if !page_keys.is_empty() && page_keys == previous_page {
+                log::warn!(
+                    "[DnsService] list_records for {domain_id} repeated page {page_number}, stopping pagination"
+                );
+                break;
+            }
+            let fetched = records.len() + page.items.len();
+            let flow = on_page.map_or(ControlFlow::Continue(()), |on_page| {
+                on_page(
+                    &RecordListProgress {
+                        account_id: account_id.to_string(),
+                        domain_id: domain_id.to_string(),
+                        page: page_number,
+                        fetched,
+                        total: page.total.unwrap_or_default(),
+                    },
+                    &page.items,
+                )
+            });
+            records.extend(page.items);
+            if flow.is_break() {
+                break;
+            }
+            previous_page = page_keys;
+        }
+        Ok(records)
+    }
+
+    /// 获取审计日志用的变更前快照（记录 ID -> 记录），只包含 `record_ids` 中的记录
+    ///
+    /// 仅在启用审计日志或离线写队列时拉取。`hint` 为目标记录的名称与类型（单条更新时已知），
+    /// 先按其过滤查询；仍有记录未找到时（如更新修改了名称）再逐页查找，目标记录全部找到即停止翻页。
+    /// 拉取失败时返回已找到的记录，不影响后续写操作。
+    async fn snapshot_records(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        record_ids: &[&str],
+        hint: Option<(&str, &DnsRecordType)>,
+    ) -> HashMap<String, DnsRecord> {
+        let write_queue_enabled = match &self.ctx.write_queue {
+            Some(queue) => queue.is_enabled().await,
+            None => false,
+        };
+        let mut snapshot = HashMap::new();
+        if record_ids.is_empty()
+            || (self.ctx.audit_log_repository.is_none() && !write_queue_enabled)
+        {
+            return snapshot;
+        }
+        let collect = |snapshot: &mut HashMap<String, DnsRecord>, records: Vec<DnsRecord>| {
+            for record in records {
+                if record_ids.contains(&record.id.as_str()) {
+                    snapshot.insert(record.id.clone(), record);
+                }
+            }
+        };
+
+        if let Some((name, record_type)) = hint {
+            match self
+                .fetch_record_pages(
+                    provider,
+                    account_id,
+                    domain_id,
+                    Some(name.to_string()),
+                    Some(record_type.clone()),
+                    None,
+                )
+                .await
+            {
+                Ok(records) => collect(&mut snapshot, records),
+                Err(e) => {
+                    log::warn!("[Audit] Failed to snapshot records of {domain_id}: {e}");
+                    return snapshot;
+                }
+            }
+            if record_ids.iter().all(|id| snapshot.contains_key(*id)) {
+                return snapshot;
+            }
+        }
+
+        let remaining: Mutex<HashSet<&str>> = Mutex::new(
+            record_ids
+                .iter()
+                .copied()
+                .filter(|id| !snapshot.contains_key(*id))
+                .collect(),
+        );
+        let on_page = |_: &RecordListProgress, page: &[DnsRecord]| {
+            let mut remaining = remaining.lock().unwrap_or_else(PoisonError::into_inner);
+            for record in page {
+                remaining.remove(record.id.as_str());
+            }
+            if remaining.is_empty() {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        match self
+            .fetch_record_pages(provider, account_id, domain_id, None, None, Some(&on_page))
+            .await
+        {
+            Ok(records) => collect(&mut snapshot, records),
+            Err(e) => log::warn!("[Audit] Failed to snapshot records of {domain_id}: {e}"),
+        }
+        snapshot
+    }
+
+    /// 网络错误且启用了离线写队列时入队并返回 `CoreError::WriteQueued`，否则原样返回错误
+    async fn queue_offline_write(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        operation: QueuedWriteOperation,
+        before: Option<DnsRecord>,
+        error: CoreError,
+    ) -> CoreError {
+        let Some(queue) = &self.ctx.write_queue else {
+            return error;
+        };
+        if !is_offline_error(&error) {
+            return error;
+        }
+        match queue
+            .enqueue(account_id, domain_id, operation, before, &error)
+            .await
+        {
+            Ok(Some(queue_id)) => CoreError::WriteQueued {
+                queue_id,
+                reason: error.to_string(),
+            },
+            Ok(None) => error,
+            Err(e) => {
+                log::error!("[WriteQueue] Failed to queue write for {account_id}/{domain_id}: {e}");
+                error
+            }
+        }
+    }
+
+    /// 重放离线写队列中的写操作（按入队顺序逐个执行）
+    ///
+    /// 重放前重新检测冲突：目标记录在入队后被其他途径修改、已被删除，
+    /// 或入队时未能获取快照时标记为冲突并保留在队列中；`force` 为用户审阅后确认覆盖。
+    /// 重放成功、目标已是期望状态或已过期的写操作移出队列。
+    pub async fn replay_queued_writes(
+        &self,
+        ids: &[String],
+        force: bool,
+    ) -> CoreResult<Vec<WriteReplayOutcome>> {
+        let Some(queue) = &self.ctx.write_queue else {
+            return Err(CoreError::ValidationError("未启用离线写队列".to_string()));
+        };
+
+        let mut outcomes = Vec::new();
+        let mut entries = Vec::new();
+        for id in ids {
+            match queue.get(id).await? {
+                Some(entry) => entries.push(entry),
+                None => outcomes.push(replay_outcome(id, WriteReplayStatus::NotFound, None)),
+            }
+        }
+        entries.sort_by_key(|entry| entry.queued_at);
+
+        for entry in entries {
+            let outcome = self.replay_queued_write(&entry, force).await;
+            if matches!(
+                outcome.status,
+                WriteReplayStatus::Applied
+                    | WriteReplayStatus::AlreadyApplied
+                    | WriteReplayStatus::Expired
+            ) {
+                queue.discard(std::slice::from_ref(&entry.id)).await?;
+            }
+            log::info!(
+                "[WriteQueue] Replayed {} for {}/{}: {:?}",
+                entry.id,
+                entry.account_id,
+                entry.domain_id,
+                outcome.status
+            );
+            outcomes.push(outcome);
+        }
+        Ok(outcomes)
+    }
+
+    async fn replay_queued_write(&self, entry: &QueuedWrite, force: bool) -> WriteReplayOutcome {
+        if entry.expires_at <= chrono::Utc::now() {
+            return replay_outcome(&entry.id, WriteReplayStatus::Expired, None);
+        }
+        let failed = |e: CoreError| {
+            replay_outcome(&entry.id, WriteReplayStatus::Failed, Some(e.to_string()))
+        };
+
+        let provider = match self.get_provider(&entry.account_id).await {
+            Ok(provider) => provider,
+            Err(e) => return failed(e),
+        };
+        let existing = match self
+            .fetch_all_records(&provider, &entry.account_id, &entry.domain_id, None)
+            .await
+        {
+            Ok(records) => records,
+            Err(e) => return failed(e),
+        };
+        let current = target_record_id(&entry.operation)
+            .and_then(|record_id| existing.iter().find(|record| record.id == record_id));
+
+        match replay_check(entry, current, &existing, force) {
+            ReplayCheck::AlreadyApplied => {
+                replay_outcome(&entry.id, WriteReplayStatus::AlreadyApplied, None)
+            }
+            ReplayCheck::Conflict(message) => WriteReplayOutcome {
+                current: current.cloned(),
+                ..replay_outcome(&entry.id, WriteReplayStatus::Conflict, Some(message))
+            },
+            ReplayCheck::Proceed => {
+                let account_id = &entry.account_id;
+                let result = match &entry.operation {
+                    QueuedWriteOperation::Create { request } => self
+                        .create_record_inner(
+                            account_id,
+                            request.clone(),
+                            false,
+                            ChangeSource::Manual,
+                        )
+                        .await
+                        .map(|_| ()),
+                    QueuedWriteOperation::Update { record_id, request } => self
+                        .update_record_inner(account_id, record_id, request.clone(), false)
+                        .await
+                        .map(|_| ()),
+                    QueuedWriteOperation::Delete { record_id } => {
+                        self.delete_record_inner(
+                            account_id,
+                            record_id,
+                            &entry.domain_id,
+                            false,
+                            ChangeSource::Manual,
+                        )
+                        .await
+                    }
+                };
+                match result {
+                    Ok(()) => replay_outcome(&entry.id, WriteReplayStatus::Applied, None),
+                    Err(e) => failed(e),
+                }
+            }
+        }
+    }
+
+    /// 提交一批记录变更，结果（及尝试次数）与 `changes` 一一对应
+    ///
+    /// 账户能力声明支持原生批量时按单批上限分块、依次调用 [`DnsProvider::batch_change`]；
+    /// 否则以有限并发逐条调用单条接口。每块（或每条）各自受写操作截止时间约束，
+    /// 整块超时计入块内全部变更。
+    /// 网络错误与限流按 [`ServiceContext::retry_policy`] 退避后只重新提交失败的变更。
+    /// 结果未知的创建（请求可能已生效）重新提交前先查找相同记录，找到即视为成功，
+    /// 查找失败时不再重新提交，避免重复创建。
+    async fn submit_changes(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        changes: Vec<ProviderRecordChange>,
+    ) -> Vec<(CoreResult<Option<DnsRecord>>, u32)> {
+        let chunk_size = match self.ctx.provider_metadata(account_id).await {
+            Ok(metadata) if metadata.features.batch_changes => {
+                usize::try_from(metadata.limits.max_batch_changes)
+                    .ok()
+                    .filter(|&size| size > 0)
+            }
+            Ok(_) => None,
+            Err(e) => {
+                log::warn!("[Batch] Writing one by one for {account_id}: {e}");
+                None
+            }
+        };
+
+        let policy = self.ctx.retry_policy;
+        let mut outcomes: Vec<Option<(CoreResult<Option<DnsRecord>>, u32)>> =
+            changes.iter().map(|_| None).collect();
+        let mut pending: Vec<(usize, ProviderRecordChange)> =
+            changes.into_iter().enumerate().collect();
+        let mut attempt = 0;
+        while !pending.is_empty() {
+            attempt += 1;
+            let batch = pending.iter().map(|(_, change)| change.clone()).collect();
+            let results = self
+                .submit_once(provider, account_id, domain_id, chunk_size, batch)
+                .await;
+            let mut retry = Vec::new();
+            for ((index, change), result) in pending.into_iter().zip(results) {
+                match result {
+                    Err(e) if attempt < policy.max_attempts && RetryPolicy::is_retryable(&e) => {
+                        retry.push((index, change, e));
+                    }
+                    result => outcomes[index] = Some((result, attempt)),
+                }
+            }
+            if retry.is_empty() {
+                break;
+            }
+
+            let delay = policy.backoff(attempt);
+            log::warn!(
+                "[Batch] Retrying {} change(s) for {account_id} in {delay:?} (attempt {})",
+                retry.len(),
+                attempt + 1
+            );
+            tokio::time::sleep(delay).await;
+            pending = Vec::with_capacity(retry.len());
+            for (index, change, error) in retry {
+                if let ProviderRecordChange::Create(request) = &change {
+                    if outcome_unknown(&error) {
+                        match self.find_applied_create(provider, request).await {
+                            Ok(Some(record)) => {
+                                outcomes[index] = Some((Ok(Some(record)), attempt));
+                                continue;
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                log::warn!(
+                                    "[Batch] Not retrying create of {} for {account_id}, lookup failed: {e}",
+                                    request.name
+                                );
+                                outcomes[index] = Some((Err(error), attempt));
+                                continue;
+                            }
+                        }
+                    }
+                }
+                pending.push((index, change));
+            }
+        }
+        outcomes.into_iter().flatten().collect()
+    }
+
+    /// 查找上一次尝试可能已创建的记录，受读操作截止时间约束
+    async fn find_applied_create(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        request: &CreateDnsRecordRequest,
+    ) -> CoreResult<Option<DnsRecord>> {
+        self.ctx
+            .with_deadline(
+                OperationKind::Read,
+                "list_records",
+                find_created_record(provider, request),
+            )
+            .await
+    }
+
+    /// 提交一次变更（不重试），每次 Provider 调用占用该账户的一个并发许可
+    async fn submit_once(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        account_id: &str,
+        domain_id: &str,
+        chunk_size: Option<usize>,
+        changes: Vec<ProviderRecordChange>,
+    ) -> Vec<CoreResult<Option<DnsRecord>>> {
+        let Some(chunk_size) = chunk_size else {
+            let mut results: Vec<(usize, CoreResult<Option<DnsRecord>>)> =
+                stream::iter(changes.into_iter().enumerate())
+                    .map(|(index, change)| {
+                        let provider = provider.clone();
+                        let ctx = Arc::clone(&self.ctx);
+                        let domain_id = domain_id.to_string();
+                        async move {
+                            let _permit = ctx.account_permits.acquire(account_id).await;
+                            let result = match change {
+                                ProviderRecordChange::Create(request) => ctx
+                                    .with_deadline(
+                                        OperationKind::Write,
+                                        "create_record",
+                                        provider.create_record(&request),
+                                    )
+                                    .await
+                                    .map(Some),
+                                ProviderRecordChange::Update { record_id, request } => ctx
+                                    .with_deadline(
+                                        OperationKind::Write,
+                                        "update_record",
+                                        provider.update_record(&record_id, &request),
+                                    )
+                                    .await
+                                    .map(Some),
+                                ProviderRecordChange::Delete { record_id } => ctx
+                                    .with_deadline(
+                                        OperationKind::Write,
+                                        "delete_record",
+                                        provider.delete_record(&record_id, &domain_id),
+                                    )
+                                    .await
+                                    .map(|()| None),
+                            };
+                            (index, result)
+                        }
+                    })
+                    .buffer_unordered(BATCH_WRITE_CONCURRENCY)
+                    .collect()
+                    .await;
+            results.sort_by_key(|(index, _)| *index);
+            return results.into_iter().map(|(_, result)| result).collect();
+        };
+
+        let mut results = Vec::with_capacity(changes.len());
+        let mut changes = changes.into_iter().peekable();
+        while changes.peek().is_some() {
+            let chunk: Vec<_> = changes.by_ref().take(chunk_size).collect();
+            let size = chunk.len();
+            let _permit = self.ctx.account_permits.acquire(account_id).await;
+            let outcome = self
+                .ctx
+                .with_deadline(OperationKind::Write, "batch_change", async {
+                    Ok::<_, ProviderError>(provider.batch_change(domain_id, chunk).await)
+                })
+                .await;
+            match outcome {
+                Ok(chunk_results) => results.extend(
+                    chunk_results
+                        .into_iter()
+                        .map(|result| result.into_result().map_err(CoreError::from)),
+                ),
+                Err(e) => results.extend((0..size).map(|_| Err(e.clone()))),
+            }
+        }
+        results
+    }
+
+    /// 获取 Provider 实例，账户熔断中时返回 [`CoreError::ServiceUnavailable`]
+    ///
+    /// 返回的实例把每次调用结果计入熔断器，连续网络错误达到阈值后熔断（见
+    /// [`CircuitBreaker`](crate::utils::circuit_breaker::CircuitBreaker)）。
+    /// 其他服务的读取与非记录写入（如权重）也经此获取实例。
+    pub(crate) async fn get_provider(&self, account_id: &str) -> CoreResult<Arc<dyn DnsProvider>> {
+        let provider = self.acquire_provider(account_id).await?;
+        Ok(self.observed(account_id, &provider))
+    }
+
+    /// 获取未包装的 Provider 实例，账户熔断中时返回 [`CoreError::ServiceUnavailable`]
+    ///
+    /// 调用结果不自动计入熔断器，供 [`Self::retry_write`] 在重试结束后统一上报。
+    async fn acquire_provider(&self, account_id: &str) -> CoreResult<Arc<dyn DnsProvider>> {
+        let provider = self.ctx.get_provider(account_id).await?;
+        if !self.ctx.circuit_breaker.try_acquire(account_id) {
+            return Err(CoreError::ServiceUnavailable(account_id.to_string()));
+        }
+        Ok(provider)
+    }
+
+    /// 把实例包装为每次调用都计入熔断器的 [`BreakerProvider`]
+    fn observed(&self, account_id: &str, provider: &Arc<dyn DnsProvider>) -> Arc<dyn DnsProvider> {
+        Arc::new(BreakerProvider::new(
+            Arc::clone(provider),
+            account_id,
+            Arc::clone(&self.ctx.circuit_breaker),
+        ))
+    }
+
+    /// 按重试策略执行单条写操作，整个操作（含重试）只计入熔断器一次
+    async fn retry_write<T, F, Fut>(
+        &self,
+        account_id: &str,
+        operation: &str,
+        op: F,
+    ) -> CoreResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = dns_orchestrator_provider::Result<T>>,
+    {
+        let attempts = async {
+            let result = retry_provider_op(&self.ctx.retry_policy, operation, op).await;
+            record_outcome(&self.ctx.circuit_breaker, account_id, &result);
+            result
+        };
+        match self
+            .ctx
+            .with_deadline(OperationKind::Write, operation, attempts)
+            .await
+        {
+            Ok(value) => Ok(value),
+            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        }
+    }
+
+    /// 处理 Provider 错误，见 [`ServiceContext::handle_provider_error`]
+    async fn handle_provider_error(&self, account_id: &str, err: CoreError) -> CoreError {
+        self.ctx.handle_provider_error(account_id, err).await
+    }
+}
+
+/// 写操作失败后是否无法确定服务商是否已执行（限流拒绝的请求不会被执行）
+fn outcome_unknown(error: &CoreError) -> bool {
+    !matches!(
+        error,
+        CoreError::Provider(ProviderError::QuotaExceeded { .. })
+    )
+}
+
+/// 查找与创建请求一致的记录，用于创建重试前确认上一次尝试是否已生效
+async fn find_created_record(
+    provider: &Arc<dyn DnsProvider>,
+    request: &CreateDnsRecordRequest,
+) -> dns_orchestrator_provider::Result<Option<DnsRecord>> {
+    let params = RecordQueryParams {
+        page: 1,
+        page_size: 100,
+        keyword: (request.name != "@").then(|| request.name.clone()),
+        record_type: Some(request.data.record_type()),
+        sort: None,
+    };
+    let page = provider.list_records(&request.domain_id, &params).await?;
+    Ok(page
+        .items
+        .into_iter()
+        .find(|record| same_as_create(record, request)))
+}
+
+fn replay_outcome(
+    id: &str,
+    status: WriteReplayStatus,
+    message: Option<String>,
+) -> WriteReplayOutcome {
+    WriteReplayOutcome {
+        id: id.to_string(),
+        status,
+        message,
+        current: None,
+    }
+}
+
+/// 构造审计记录
+///
+/// `outcome` 为写操作结果：成功时携带变更后的记录（删除为 `None`），失败时携带错误。
+fn audit_entry(
+    account_id: &str,
+    domain_id: &str,
+    operation: AuditOperation,
+    record_id: Option<&str>,
+    before: Option<DnsRecord>,
+    outcome: Result<Option<&DnsRecord>, &CoreError>,
+) -> AuditEntry {
+    let mut entry = AuditEntry::new(account_id, domain_id, operation);
+    entry.record_id = record_id.map(ToString::to_string);
+    entry.before = before;
+    match outcome {
+        Ok(after) => {
+            if entry.record_id.is_none() {
+                entry.record_id = after.map(|record| record.id.clone());
+            }
+            entry.after = after.cloned();
+        }
+        Err(e) => {
+            entry.success = false;
+            entry.error = Some(e.to_string());
+        }
+    }
+    entry
+}
+
+/// 对比用的规范化记录：(规范化值, 显式 TTL, 记录)，自动 TTL 为 `None`
+type DiffEntry<'a> = (String, Option<u32>, &'a DnsRecord);
+
+/// 按 名称 + 类型 分组（跳过 SOA 与根域 NS），组内按值排序
+fn group_for_diff<'a>(
+    domain_name: &str,
+    records: &'a [DnsRecord],
+) -> BTreeMap<(String, &'static str), Vec<DiffEntry<'a>>> {
+    let origin = domain_name.trim_end_matches('.').to_lowercase();
+    let mut groups: BTreeMap<(String, &'static str), Vec<DiffEntry<'a>>> = BTreeMap::new();
+    for record in records {
+        let name = relative_name(&record.name, &origin).to_lowercase();
+        if is_provider_managed(&name, &record.data) {
+            continue;
+        }
+        groups
+            .entry((name, record_type_name(&record.data)))
+            .or_default()
+            .push((
+                normalized_value(&record.data, &origin).to_lowercase(),
+                (record.ttl > 1).then_some(record.ttl),
+                record,
+            ));
+    }
+    for group in groups.values_mut() {
+        group.sort_by(|x, y| x.0.cmp(&y.0));
+    }
+    groups
+}
+
+/// 对比两个域名的记录
+///
+/// 同名同类型下先按值配对：值相同且 TTL 一致的忽略，TTL 不一致的计入 `different`；
+/// 剩余记录按值的顺序两两配对计入 `different`，多出的计入 `only_in_a` / `only_in_b`。
+fn diff_records(
+    domain_a: &str,
+    records_a: &[DnsRecord],
+    domain_b: &str,
+    records_b: &[DnsRecord],
+) -> DomainDiffResult {
+    let groups_a = group_for_diff(domain_a, records_a);
+    let groups_b = group_for_diff(domain_b, records_b);
+    let keys: BTreeSet<&(String, &'static str)> = groups_a.keys().chain(groups_b.keys()).collect();
+
+    let mut result = DomainDiffResult {
+        only_in_a: Vec::new(),
+        only_in_b: Vec::new(),
+        different: Vec::new(),
+    };
+    for key in keys {
+        let side_a = groups_a.get(key).map_or(&[][..], Vec::as_slice);
+        let side_b = groups_b.get(key).map_or(&[][..], Vec::as_slice);
+        let pair = |a: &DnsRecord, b: &DnsRecord| RecordPair {
+            name: key.0.clone(),
+            record_type: a.data.record_type(),
+            a: a.clone(),
+            b: b.clone(),
+        };
+
+        let mut matched_b = vec![false; side_b.len()];
+        let mut rest_a = Vec::new();
+        for (value, ttl, record) in side_a {
+            let found = side_b
+                .iter()
+                .enumerate()
+                .position(|(i, (other, _, _))| !matched_b[i] && other == value);
+            match found {
+                Some(i) => {
+                    matched_b[i] = true;
+                    let (_, other_ttl, other) = &side_b[i];
+                    if ttl.is_some() && other_ttl.is_some() && ttl != other_ttl {
+                        result.different.push(pair(record, other));
+                    }
+                }
+                None => rest_a.push(*record),
+            }
+        }
+        let rest_b: Vec<&DnsRecord> = side_b
+            .iter()
+            .zip(&matched_b)
+            .filter(|(_, matched)| !**matched)
+            .map(|((_, _, record), _)| *record)
+            .collect();
+
+        for (a, b) in rest_a.iter().zip(&rest_b) {
+            result.different.push(pair(a, b));
+        }
+        let paired = rest_a.len().min(rest_b.len());
+        result
+            .only_in_a
+            .extend(rest_a[paired..].iter().map(|record| (*record).clone()));
+        result
+            .only_in_b
+            .extend(rest_b[paired..].iter().map(|record| (*record).clone()));
+    }
+    result
+}
+
+/// 判断记录值是否等于 `old`
+///
+/// IP 按地址比较（`2001:db8::1` 与展开写法视为相同），主机名忽略大小写和末尾的点，
+/// TXT/CAA 精确匹配。
+fn value_matches(data: &RecordData, old: &str) -> bool {
+    match data {
+        RecordData::A { address } | RecordData::AAAA { address } => {
+            match (address.parse::<IpAddr>(), old.parse::<IpAddr>()) {
+                (Ok(current), Ok(old)) => current == old,
+                _ => address == old,
+            }
+        }
+        RecordData::CNAME { target } | RecordData::SRV { target, .. } => hostname_eq(target, old),
+        RecordData::MX { exchange, .. } => hostname_eq(exchange, old),
+        RecordData::NS { nameserver } => hostname_eq(nameserver, old),
+        RecordData::TXT { text } => text == old,
+        RecordData::CAA { value, .. } => value == old,
+        // SOA 只能单独修改计时参数，不参与按值替换
+        RecordData::SOA { .. } => false,
+    }
+}
+
+/// 记录是否属于指定名称与类型的记录组（名称按相对名称比较）
+fn in_record_set(
+    record: &DnsRecord,
+    origin: &str,
+    name: &str,
+    record_type: &DnsRecordType,
+) -> bool {
+    record.data.record_type() == *record_type
+        && hostname_eq(
+            &relative_name(&record.name, origin),
+            &relative_name(name, origin),
+        )
+}
+
+fn record_set_detail(
+    domain_id: &str,
+    name: &str,
+    record_type: DnsRecordType,
+    records: Vec<DnsRecord>,
+    native: bool,
+) -> RecordSetDetail {
+    let ttls: BTreeSet<u32> = records.iter().map(|record| record.ttl).collect();
+    RecordSetDetail {
+        domain_id: domain_id.to_string(),
+        name: name.to_string(),
+        record_type,
+        ttl: if ttls.len() == 1 {
+            ttls.first().copied()
+        } else {
+            None
+        },
+        ttl_mismatch: ttls.len() > 1,
+        records,
+        native,
+    }
+}
+
+/// 整组更新请求的基本校验（不依赖现有记录）
+fn check_record_set_request(request: &UpdateRecordSetRequest) -> CoreResult<()> {
+    if request.name.trim().is_empty() {
+        return Err(CoreError::ValidationError("记录名称不能为空".to_string()));
+    }
+    if request.record_type == DnsRecordType::Soa {
+        return Err(CoreError::ValidationError(
+            "SOA 记录不支持整组编辑".to_string(),
+        ));
+    }
+    if let Some(data) = request
+        .values
+        .iter()
+        .find(|data| data.record_type() != request.record_type)
+    {
+        return Err(CoreError::ValidationError(format!(
+            "值 {} 与记录类型 {:?} 不符",
+            data.display_value(),
+            request.record_type
+        )));
+    }
+    if request.record_type == DnsRecordType::Cname && request.values.len() > 1 {
+        return Err(CoreError::ValidationError(
+            "CNAME 记录只能有一个值".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 按类型规则校验每个值，并检查与组外同名记录的冲突
+fn validate_record_set_values(
+    request: &UpdateRecordSetRequest,
+    max_ttl: u32,
+    origin: &str,
+    others: &[DnsRecord],
+) -> CoreResult<()> {
+    let mut errors = Vec::new();
+    for data in &request.values {
+        let record = CreateDnsRecordRequest {
+            domain_id: request.domain_id.clone(),
+            name: request.name.clone(),
+            ttl: request.ttl,
+            data: data.clone(),
+            proxied: None,
+        };
+        let mut report = RecordValidator::validate_record_with_max_ttl(&record, max_ttl);
+        if !report.has_errors() {
+            report.errors.extend(RecordValidator::check_conflicts(
+                &record, origin, others, None,
+            ));
+        }
+        errors.extend(report.errors);
+    }
+    match RecordValidator::rejection(&errors) {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// 按规范化值去重，保留首次出现的顺序，返回 (值, 规范化值)
+fn distinct_values<'a>(origin: &str, values: &'a [RecordData]) -> Vec<(&'a RecordData, String)> {
+    let mut distinct: Vec<(&RecordData, String)> = Vec::new();
+    for data in values {
+        let value = normalized_value(data, origin);
+        if !distinct.iter().any(|(_, seen)| *seen == value) {
+            distinct.push((data, value));
+        }
+    }
+    distinct
+}
+
+/// 生成把 `current` 变为请求中值集合的变更，按 创建 → 更新 → 删除 排列
+///
+/// 值按 [`normalized_value`] 比较。保留的值仅在 TTL 不同时更新；新增的值优先改写
+/// 待删除的旧记录（原地更新），不足时新建，多余的旧记录删除。
+fn plan_record_set(
+    origin: &str,
+    current: &[DnsRecord],
+    request: &UpdateRecordSetRequest,
+) -> Vec<RecordChange> {
+    let update =
+        |record: &DnsRecord, previous_value: String, data: &RecordData| RecordChange::Update {
+            record_id: record.id.clone(),
+            previous_value,
+            request: UpdateDnsRecordRequest {
+                domain_id: request.domain_id.clone(),
+                name: record.name.clone(),
+                ttl: request.ttl,
+                data: data.clone(),
+                proxied: record.proxied,
+            },
+        };
+
+    let mut stale: Vec<(&DnsRecord, String)> = current
+        .iter()
+        .map(|record| (record, normalized_value(&record.data, origin)))
+        .collect();
+    let mut updates = Vec::new();
+    let mut additions = Vec::new();
+    for (data, value) in distinct_values(origin, &request.values) {
+        match stale.iter().position(|(_, existing)| *existing == value) {
+            Some(index) => {
+                let (record, previous) = stale.remove(index);
+                if record.ttl != request.ttl {
+                    updates.push(update(record, previous, data));
+                }
+            }
+            None => additions.push(data),
+        }
+    }
+
+    let mut stale = stale.into_iter();
+    let proxied = current.first().and_then(|record| record.proxied);
+    let mut creates = Vec::new();
+    for data in additions {
+        match stale.next() {
+            Some((record, previous)) => updates.push(update(record, previous, data)),
+            None => creates.push(RecordChange::Create {
+                request: CreateDnsRecordRequest {
+                    domain_id: request.domain_id.clone(),
+                    name: request.name.clone(),
+                    ttl: request.ttl,
+                    data: data.clone(),
+                    proxied,
+                },
+            }),
+        }
+    }
+    let deletes = stale.map(|(record, value)| RecordChange::Delete {
+        record_id: record.id.clone(),
+        name: record.name.clone(),
+        record_type: request.record_type.clone(),
+        value,
+    });
+
+    creates.into_iter().chain(updates).chain(deletes).collect()
+}
+
+/// 批量写入不接受 SOA 记录
+fn reject_soa(data: &RecordData) -> Result<(), String> {
+    match data {
+        RecordData::SOA { .. } => Err("SOA 记录不支持批量写入".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// 按账户能力检查记录类型、代理与 TTL，避免提交注定被服务商拒绝的请求
+///
+/// SOA 不在 `record_types` 中，其写入限制由 `features.soa_editing` 单独检查。
+/// 声明 `auto_ttl` 的服务商额外接受 TTL 为 1（自动）。
+fn check_capabilities(
+    metadata: &ProviderMetadata,
+    ttl: u32,
+    data: &RecordData,
+    proxied: Option<bool>,
+) -> Result<(), ProviderError> {
+    let record_type = data.record_type();
+    if record_type != DnsRecordType::Soa && !metadata.record_types.contains(&record_type) {
+        return Err(ProviderError::UnsupportedRecordType {
+            provider: metadata.id.to_string(),
+            record_type: record_type_name(data).to_string(),
+        });
+    }
+    if proxied == Some(true) && !metadata.features.proxy {
+        return Err(ProviderError::UnsupportedCapability {
+            provider: metadata.id.to_string(),
+            capability: "proxy".to_string(),
+        });
+    }
+    let limits = &metadata.limits;
+    let auto = ttl == AUTO_TTL && metadata.features.auto_ttl;
+    if !auto && !(limits.min_ttl..=limits.max_ttl).contains(&ttl) {
+        return Err(ProviderError::InvalidParameter {
+            provider: metadata.id.to_string(),
+            param: "ttl".to_string(),
+            detail: format!(
+                "TTL {ttl} 超出允许范围 {}–{} 秒",
+                limits.min_ttl, limits.max_ttl
+            ),
+        });
+    }
+    // 超过 255 字节的 TXT 值由 Provider 自动分段，这里只检查拼接后的总长度
+    if let RecordData::TXT { text } = data {
+        let max = usize::try_from(limits.max_txt_length).unwrap_or(usize::MAX);
+        if max > 0 && text.len() > max {
+            return Err(ProviderError::InvalidParameter {
+                provider: metadata.id.to_string(),
+                param: "text".to_string(),
+                detail: format!("TXT 值长 {} 字节，超出上限 {max} 字节", text.len()),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn hostname_eq(a: &str, b: &str) -> bool {
+    a.trim_end_matches('.')
+        .eq_ignore_ascii_case(b.trim_end_matches('.'))
+}
+
+/// 用 `new_value` 替换记录的主要值（即 `display_value`），其余字段不变
+fn replace_value(data: RecordData, new_value: &str) -> RecordData {
+    let new_value = new_value.to_string();
+    match data {
+        RecordData::A { .. } => RecordData::A { address: new_value },
+        RecordData::AAAA { .. } => RecordData::AAAA { address: new_value },
+        RecordData::CNAME { .. } => RecordData::CNAME { target: new_value },
+        RecordData::MX { priority, .. } => RecordData::MX {
+            priority,
+            exchange: new_value,
+        },
+        RecordData::TXT { .. } => RecordData::TXT { text: new_value },
+        RecordData::NS { .. } => RecordData::NS {
+            nameserver: new_value,
+        },
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            ..
+        } => RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target: new_value,
+        },
+        RecordData::CAA { flags, tag, .. } => RecordData::CAA {
+            flags,
+            tag,
+            value: new_value,
+        },
+        soa @ RecordData::SOA { .. } => soa,
+    }
+}
+
+/// 按指定字段排序记录（名称与值忽略大小写），用于不支持服务端排序的服务商
+fn sort_records(records: &mut [DnsRecord], sort: RecordSort) {
+    records.sort_by(|a, b| {
+        let ordering = match sort.field {
+            RecordSortField::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            RecordSortField::Type => record_type_name(&a.data).cmp(record_type_name(&b.data)),
+            RecordSortField::Value => a
+                .data
+                .display_value()
+                .to_lowercase()
+                .cmp(&b.data.display_value().to_lowercase()),
+            RecordSortField::Ttl => a.ttl.cmp(&b.ttl),
+        };
+        match sort.order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        }
+    });
+}
+
+/// 在本地按页截取已排序的全部记录
+fn paginate(records: Vec<DnsRecord>, page: u32, page_size: u32) -> PaginatedResponse<DnsRecord> {
+    let total_count = u32::try_from(records.len()).unwrap_or(u32::MAX);
+    let start = usize::try_from(page.saturating_sub(1))
+        .unwrap_or(usize::MAX)
+        .saturating_mul(usize::try_from(page_size).unwrap_or(usize::MAX));
+    let items = records
+        .into_iter()
+        .skip(start)
+        .take(usize::try_from(page_size).unwrap_or(usize::MAX))
+        .collect();
+    PaginatedResponse::new(items, page, page_size, total_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_matches_normalizes_ip_and_hostname() {
+        let aaaa = RecordData::AAAA {
+            address: "2001:db8::1".to_string(),
+        };
+        assert!(value_matches(&aaaa, "2001:0db8:0:0:0:0:0:1"));
+        assert!(!value_matches(&aaaa, "2001:db8::2"));
+
+        let mx = RecordData::MX {
+            priority: 10,
+            exchange: "Mail.Example.com.".to_string(),
+        };
+        assert!(value_matches(&mx, "mail.example.com"));
+
+        let txt = RecordData::TXT {
+            text: "v=spf1 -all".to_string(),
+        };
+        assert!(!value_matches(&txt, "V=SPF1 -ALL"));
+    }
+
+    #[test]
+    fn test_replace_value_keeps_other_fields() {
+        let srv = RecordData::SRV {
+            priority: 10,
+            weight: 5,
+            port: 443,
+            target: "old.example.com".to_string(),
+        };
+        assert_eq!(
+            replace_value(srv, "new.example.com"),
+            RecordData::SRV {
+                priority: 10,
+                weight: 5,
+                port: 443,
+                target: "new.example.com".to_string(),
+            }
+        );
+
+        let a = RecordData::A {
+            address: "192.0.2.1".to_string(),
+        };
+        assert_eq!(
+            replace_value(a, "198.51.100.7"),
+            RecordData::A {
+                address: "198.51.100.7".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_sort_records_and_paginate_locally() {
+        let record = |id: &str, name: &str, ttl: u32, data: RecordData| DnsRecord {
+            id: id.to_string(),
+            domain_id: "zone-1".to_string(),
+            name: name.to_string(),
+            ttl,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        };
+        let mut records = vec![
+            record("1", "www", 600, txt("b")),
+            record(
+                "2",
+                "Api",
+                300,
+                RecordData::A {
+                    address: "192.0.2.1".to_string(),
+                },
+            ),
+            record("3", "mail", 3600, txt("A")),
+        ];
+
+        sort_records(
+            &mut records,
+            RecordSort {
+                field: RecordSortField::Name,
+                order: SortOrder::Asc,
+            },
+        );
+        let ids = |records: &[DnsRecord]| records.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&records), ["2", "3", "1"]);
+
+        sort_records(
+            &mut records,
+            RecordSort {
+                field: RecordSortField::Ttl,
+                order: SortOrder::Desc,
+            },
+        );
+        assert_eq!(ids(&records), ["3", "1", "2"]);
+
+        let page = paginate(records, 2, 2);
+        assert_eq!(ids(&page.items), ["2"]);
+        assert_eq!(page.total_count, 3);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_audit_entry_records_outcome() {
+        let err = CoreError::ValidationError("bad".to_string());
+        let failed = audit_entry(
+            "acc",
+            "example.com",
+            AuditOperation::Delete,
+            Some("r1"),
+            None,
+            Err(&err),
+        );
+        assert!(!failed.success);
+        assert_eq!(failed.record_id.as_deref(), Some("r1"));
+        assert!(failed.error.is_some_and(|e| e.contains("bad")));
+
+        let deleted = audit_entry(
+            "acc",
+            "example.com",
+            AuditOperation::BatchDelete,
+            Some("r1"),
+            None,
+            Ok(None),
+        );
+        assert!(deleted.success);
+        assert!(deleted.after.is_none());
+        assert_eq!(deleted.error, None);
+    }
+
+    #[test]
+    fn test_diff_records_pairs_by_name_and_type() {
+        let record = |id: &str, name: &str, ttl: u32, data: RecordData| DnsRecord {
+            id: id.to_string(),
+            domain_id: "d".to_string(),
+            name: name.to_string(),
+            ttl,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        };
+        let a = |address: &str| RecordData::A {
+            address: address.to_string(),
+        };
+        let cname = |target: &str| RecordData::CNAME {
+            target: target.to_string(),
+        };
+        let ns = || RecordData::NS {
+            nameserver: "ns1.provider.net".to_string(),
+        };
+
+        let records_a = vec![
+            record("a1", "www.example.com", 300, a("192.0.2.1")),
+            record("a2", "API", 300, cname("Backend.Example.com.")),
+            record("a3", "mail", 300, a("192.0.2.10")),
+            record("a4", "ttl", 300, a("192.0.2.20")),
+            record("a5", "auto", 1, a("192.0.2.30")),
+            record("a6", "old", 300, a("192.0.2.40")),
+            record("a7", "@", 300, ns()),
+        ];
+        let records_b = vec![
+            record("b1", "www.staging.io", 300, a("192.0.2.1")),
+            record("b2", "api", 300, cname("backend.example.com")),
+            record("b3", "mail", 300, a("192.0.2.11")),
+            record("b4", "ttl", 600, a("192.0.2.20")),
+            record("b5", "auto", 600, a("192.0.2.30")),
+            record("b6", "new", 300, a("192.0.2.50")),
+        ];
+
+        let diff = diff_records("example.com", &records_a, "staging.io", &records_b);
+        let ids = |records: &[DnsRecord]| -> Vec<String> {
+            records.iter().map(|r| r.id.clone()).collect()
+        };
+        assert_eq!(ids(&diff.only_in_a), ["a6"]);
+        assert_eq!(ids(&diff.only_in_b), ["b6"]);
+        let pairs: Vec<(&str, &str, &str)> = diff
+            .different
+            .iter()
+            .map(|p| (p.name.as_str(), p.a.id.as_str(), p.b.id.as_str()))
+            .collect();
+        assert_eq!(pairs, [("mail", "a3", "b3"), ("ttl", "a4", "b4")]);
+        assert_eq!(diff.different[0].record_type, DnsRecordType::A);
+    }
+
+    fn a_record(id: &str, ttl: u32, address: &str) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            domain_id: "zone-1".to_string(),
+            name: "www".to_string(),
+            ttl,
+            data: RecordData::A {
+                address: address.to_string(),
+            },
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn a_set(ttl: u32, addresses: &[&str]) -> UpdateRecordSetRequest {
+        UpdateRecordSetRequest {
+            domain_id: "zone-1".to_string(),
+            name: "www".to_string(),
+            record_type: DnsRecordType::A,
+            ttl,
+            values: addresses
+                .iter()
+                .map(|address| RecordData::A {
+                    address: (*address).to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_plan_record_set_reuses_stale_records() {
+        let current = vec![
+            a_record("1", 300, "192.0.2.1"),
+            a_record("2", 300, "192.0.2.2"),
+            a_record("3", 600, "192.0.2.3"),
+        ];
+        let request = a_set(
+            300,
+            &[
+                "192.0.2.1",
+                "192.0.2.3",
+                "192.0.2.4",
+                "192.0.2.5",
+                "192.0.2.1",
+            ],
+        );
+
+        let changes = plan_record_set("example.com", &current, &request);
+        assert_eq!(changes.len(), 3);
+        // 新值先改写待删除的记录 2，不足的部分新建
+        assert!(matches!(
+            &changes[0],
+            RecordChange::Create { request } if request.data.display_value() == "192.0.2.5"
+        ));
+        assert!(matches!(
+            &changes[1],
+            RecordChange::Update { record_id, request, .. }
+                if record_id == "3" && request.ttl == 300
+        ));
+        assert!(matches!(
+            &changes[2],
+            RecordChange::Update { record_id, previous_value, request }
+                if record_id == "2"
+                    && previous_value == "192.0.2.2"
+                    && request.data.display_value() == "192.0.2.4"
+        ));
+
+        // 空集合删除整组
+        let changes = plan_record_set("example.com", &current, &a_set(300, &[]));
+        assert_eq!(changes.len(), 3);
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, RecordChange::Delete { .. })));
+        assert!(plan_record_set(
+            "example.com",
+            &current[..2],
+            &a_set(300, &["192.0.2.2", "192.0.2.1"])
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_check_record_set_request_rejects_mismatched_values() {
+        let mut request = a_set(300, &["192.0.2.1"]);
+        request.values.push(txt("v=spf1 -all"));
+        assert!(check_record_set_request(&request).is_err());
+
+        let cname = UpdateRecordSetRequest {
+            record_type: DnsRecordType::Cname,
+            values: vec![
+                RecordData::CNAME {
+                    target: "a.example.net".to_string(),
+                },
+                RecordData::CNAME {
+                    target: "b.example.net".to_string(),
+                },
+            ],
+            ..a_set(300, &[])
+        };
+        assert!(check_record_set_request(&cname).is_err());
+        assert!(check_record_set_request(&a_set(300, &[])).is_ok());
+    }
+
+    #[test]
+    fn test_check_capabilities_uses_account_limits() -> CoreResult<()> {
+        use crate::types::ProviderType;
+
+        let metadata = |id: ProviderType| {
+            dns_orchestrator_provider::get_all_provider_metadata()
+                .into_iter()
+                .find(|metadata| metadata.id == id)
+        };
+        let a = RecordData::A {
+            address: "192.0.2.1".to_string(),
+        };
+
+        let cloudflare = metadata(ProviderType::Cloudflare)
+            .ok_or_else(|| CoreError::ProviderNotFound("cloudflare".to_string()))?;
+        assert!(check_capabilities(&cloudflare, 1, &a, Some(true)).is_ok());
+        assert!(matches!(
+            check_capabilities(&cloudflare, 30, &a, None),
+            Err(ProviderError::InvalidParameter { param, .. }) if param == "ttl"
+        ));
+
+        let mut aliyun = metadata(ProviderType::Aliyun)
+            .ok_or_else(|| CoreError::ProviderNotFound("aliyun".to_string()))?;
+        assert!(matches!(
+            check_capabilities(&aliyun, 600, &a, Some(true)),
+            Err(ProviderError::UnsupportedCapability { capability, .. }) if capability == "proxy"
+        ));
+        // 不声明自动 TTL 的服务商按最小 TTL 检查
+        assert!(check_capabilities(&aliyun, 1, &a, None).is_err());
+
+        aliyun.record_types.retain(|t| *t != DnsRecordType::Caa);
+        let caa = RecordData::CAA {
+            flags: 0,
+            tag: "issue".to_string(),
+            value: "letsencrypt.org".to_string(),
+        };
+        assert!(matches!(
+            check_capabilities(&aliyun, 600, &caa, None),
+            Err(ProviderError::UnsupportedRecordType { record_type, .. }) if record_type == "CAA"
+        ));
+
+        // TXT 总长度按拼接后的字节数检查，单段 255 字节的限制由 Provider 分段处理
+        let txt = |len: usize| RecordData::TXT {
+            text: "a".repeat(len),
+        };
+        assert!(check_capabilities(&aliyun, 600, &txt(512), None).is_ok());
+        assert!(matches!(
+            check_capabilities(&aliyun, 600, &txt(513), None),
+            Err(ProviderError::InvalidParameter { param, .. }) if param == "text"
+        ));
+        aliyun.limits.max_txt_length = 0;
+        assert!(check_capabilities(&aliyun, 600, &txt(10_000), None).is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_record_set_applies_changes_per_record() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+        let ctx = Arc::new(in_memory_context());
+        let mut provider = MockDnsProvider::new().with_domain("zone-1", "example.com");
+        for index in 1..=6 {
+            provider = provider.with_record(
+                "zone-1",
+                "www",
+                300,
+                RecordData::A {
+                    address: format!("192.0.2.{index}"),
+                },
+            );
+        }
+        let provider = Arc::new(provider.with_record("zone-1", "mail", 300, txt("keep")));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+
+        let set = dns
+            .get_record_set("account-1", "zone-1", "www", DnsRecordType::A)
+            .await?;
+        assert_eq!(set.records.len(), 6);
+        assert_eq!(set.ttl, Some(300));
+        assert!(!set.native);
+
+        let result = dns
+            .update_record_set(
+                "account-1",
+                a_set(300, &["192.0.2.1", "192.0.2.2", "198.51.100.1"]),
+            )
+            .await?;
+        assert!(result.failure.is_none());
+        assert_eq!(result.applied.len(), 4);
+
+        let set = dns
+            .get_record_set("account-1", "zone-1", "www", DnsRecordType::A)
+            .await?;
+        let mut values: Vec<String> = set
+            .records
+            .iter()
+            .map(|record| record.data.display_value())
+            .collect();
+        values.sort();
+        assert_eq!(values, ["192.0.2.1", "192.0.2.2", "198.51.100.1"]);
+        assert_eq!(provider.records().len(), 4);
+        Ok(())
+    }
+
+    fn txt(text: &str) -> RecordData {
+        RecordData::TXT {
+            text: text.to_string(),
+        }
+    }
+
+    fn txt_update(text: &str) -> UpdateDnsRecordRequest {
+        UpdateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            name: "_note".to_string(),
+            ttl: 300,
+            data: txt(text),
+            proxied: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writes_to_same_record_are_serialized() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(
+            MockDnsProvider::new()
+                .with_domain("zone-1", "example.com")
+                .with_record("zone-1", "_note", 300, txt("v0"))
+                .with_record("zone-1", "_other", 300, txt("v0"))
+                .with_write_delay(Duration::from_millis(20)),
+        );
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+        let records = provider.records();
+        let (target, other) = (&records[0].id, &records[1].id);
+
+        // 同一记录：更新、批量更新与删除交叉执行，服务商侧不会同时收到两个写请求
+        let (first, batch, deleted) = futures::join!(
+            dns.update_record("account-1", target, txt_update("v1")),
+            dns.batch_update_records(
+                "account-1",
+                BatchUpdateRequest {
+                    domain_id: "zone-1".to_string(),
+                    updates: vec![BatchUpdateItem {
+                        record_id: target.clone(),
+                        request: txt_update("v2"),
+                    }],
+                    normalize: false,
+                },
+            ),
+            dns.delete_record("account-1", target, "zone-1"),
+        );
+        first?;
+        assert_eq!(batch?.success_count, 1);
+        deleted?;
+        assert_eq!(provider.max_concurrent_writes(), 1);
+        assert!(provider.records().iter().all(|record| record.id != *target));
+
+        // 不同记录的写操作仍然并行
+        let (a, b) = futures::join!(
+            dns.update_record("account-1", other, txt_update("v3")),
+            dns.create_record(
+                "account-1",
+                CreateDnsRecordRequest {
+                    domain_id: "zone-1".to_string(),
+                    name: "_new".to_string(),
+                    ttl: 300,
+                    data: txt("v0"),
+                    proxied: None,
+                },
+            ),
+        );
+        a?;
+        b?;
+        assert_eq!(provider.max_concurrent_writes(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batch_writes_use_native_batches_within_limit() -> CoreResult<()> {
+        use crate::testing::{
+            in_memory_context, register_mock_account, InMemoryCapabilityRepository, MockDnsProvider,
+        };
+        use dns_orchestrator_provider::CapabilityProbe;
+
+        let ctx = Arc::new(
+            in_memory_context()
+                .with_capability_cache(Arc::new(InMemoryCapabilityRepository::default())),
+        );
+        let provider = Arc::new(
+            MockDnsProvider::new()
+                .with_domain("zone-1", "example.com")
+                .with_capabilities(CapabilityProbe {
+                    max_batch_changes: Some(2),
+                    ..CapabilityProbe::default()
+                }),
+        );
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+
+        // 按账户的单批上限分块，结果保持请求顺序
+        let records = (1..=5)
+            .map(|index| CreateDnsRecordRequest {
+                domain_id: "zone-1".to_string(),
+                name: format!("_note{index}"),
+                ttl: 300,
+                data: txt("v0"),
+                proxied: None,
+            })
+            .collect();
+        let created = dns
+            .batch_create_records(
+                "account-1",
+                BatchCreateRequest {
+                    domain_id: "zone-1".to_string(),
+                    records,
+                    normalize: false,
+                },
+            )
+            .await?;
+        assert_eq!(created.success_count, 5);
+        assert_eq!(provider.batch_sizes(), [2, 2, 1]);
+        let names: Vec<&str> = created
+            .created_records
+            .iter()
+            .map(|record| record.name.as_str())
+            .collect();
+        assert_eq!(names, ["_note1", "_note2", "_note3", "_note4", "_note5"]);
+
+        // 同批内单条失败不影响其他变更
+        let deleted = dns
+            .batch_delete_records(
+                "account-1",
+                BatchDeleteRequest {
+                    domain_id: "zone-1".to_string(),
+                    record_ids: vec![
+                        created.created_records[0].id.clone(),
+                        "missing".to_string(),
+                        created.created_records[1].id.clone(),
+                    ],
+                },
+            )
+            .await?;
+        assert_eq!(deleted.success_count, 2);
+        assert_eq!(deleted.failures.len(), 1);
+        assert_eq!(deleted.failures[0].record_id, "missing");
+        assert_eq!(deleted.failures[0].attempts, Some(1));
+        assert_eq!(provider.batch_sizes(), [2, 2, 1, 2, 1]);
+        assert_eq!(provider.records().len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_retries_network_and_quota_errors() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context().with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            max_concurrent_per_account: 2,
+            jitter: false,
+        }));
+        let provider = Arc::new((1..=4).fold(
+            MockDnsProvider::new().with_domain("zone-1", "example.com"),
+            |provider, index| {
+                provider.with_record("zone-1", &format!("_note{index}"), 300, txt("v0"))
+            },
+        ));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+        let ids: Vec<String> = provider.records().into_iter().map(|r| r.id).collect();
+        let delete = |record_ids: Vec<String>| {
+            dns.batch_delete_records(
+                "account-1",
+                BatchDeleteRequest {
+                    domain_id: "zone-1".to_string(),
+                    record_ids,
+                },
+            )
+        };
+
+        // 限流的变更退避后重新提交，最终成功
+        provider.fail_next_writes(
+            ProviderError::QuotaExceeded {
+                provider: "mock".to_string(),
+                raw_message: Some("429 Too Many Requests".to_string()),
+            },
+            2,
+        );
+        let deleted = delete(ids[..2].to_vec()).await?;
+        assert_eq!(deleted.success_count, 2);
+        assert!(deleted.failures.is_empty());
+
+        // 持续的网络错误在用完尝试次数后放弃
+        provider.fail_next_writes(
+            ProviderError::NetworkError {
+                provider: "mock".to_string(),
+                detail: "connection reset".to_string(),
+            },
+            6,
+        );
+        let deleted = delete(ids[2..].to_vec()).await?;
+        assert_eq!(deleted.failed_count, 2);
+        assert!(deleted
+            .failures
+            .iter()
+            .all(|failure| failure.attempts == Some(3)));
+        assert_eq!(provider.records().len(), 2);
+
+        // 记录不存在不重试
+        let deleted = delete(vec!["missing".to_string()]).await?;
+        assert_eq!(deleted.failures[0].attempts, Some(1));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_single_record_writes_retry_server_errors() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context().with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::ZERO,
+            jitter: false,
+            ..RetryPolicy::default()
+        }));
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+        let create = |name: &str| {
+            dns.create_record(
+                "account-1",
+                CreateDnsRecordRequest {
+                    domain_id: "zone-1".to_string(),
+                    name: name.to_string(),
+                    ttl: 300,
+                    data: txt("v0"),
+                    proxied: None,
+                },
+            )
+        };
+        let server_error = || ProviderError::HttpError {
+            provider: "mock".to_string(),
+            status: 503,
+            raw_message: None,
+        };
+
+        // 两次 5xx 后第三次成功
+        provider.fail_next_writes(server_error(), 2);
+        let created = create("_retry").await?;
+        assert_eq!(provider.records().len(), 1);
+
+        // 用完尝试次数后返回最后一次的错误
+        provider.fail_next_writes(server_error(), 3);
+        assert!(matches!(
+            create("_give_up").await,
+            Err(CoreError::Provider(ProviderError::HttpError {
+                status: 503,
+                ..
+            }))
+        ));
+
+        provider.fail_next_writes(server_error(), 1);
+        dns.delete_record("account-1", &created.id, "zone-1")
+            .await?;
+        assert!(provider.records().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_retry_is_idempotent_and_counts_once() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use crate::utils::circuit_breaker::CircuitState;
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context().with_retry_policy(RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::ZERO,
+            jitter: false,
+            ..RetryPolicy::default()
+        }));
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(Arc::clone(&ctx));
+        let create = |name: &str| {
+            dns.create_record(
+                "account-1",
+                CreateDnsRecordRequest {
+                    domain_id: "zone-1".to_string(),
+                    name: name.to_string(),
+                    ttl: 300,
+                    data: txt("v0"),
+                    proxied: None,
+                },
+            )
+        };
+        let network_error = || ProviderError::NetworkError {
+            provider: "mock".to_string(),
+            detail: "connection reset".to_string(),
+        };
+
+        // 创建已生效但响应丢失，重试时找到该记录而不是再创建一条
+        provider.lose_next_create_responses(network_error(), 1);
+        let created = create("_lost").await?;
+        assert_eq!(provider.records().len(), 1);
+        assert_eq!(provider.records()[0].id, created.id);
+
+        // 一次操作的多次重试只计为一次失败，不会触发熔断（阈值为 5）
+        provider.fail_next_writes(network_error(), 5);
+        assert!(create("_down").await.is_err());
+        assert_eq!(ctx.circuit_breaker.state("account-1"), CircuitState::Closed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_retry_skips_applied_creates() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context().with_retry_policy(RetryPolicy {
+            base_delay: Duration::ZERO,
+            jitter: false,
+            ..RetryPolicy::default()
+        }));
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+        let batch = |prefix: &str| BatchCreateRequest {
+            domain_id: "zone-1".to_string(),
+            records: (1..=2)
+                .map(|index| CreateDnsRecordRequest {
+                    domain_id: "zone-1".to_string(),
+                    name: format!("_{prefix}{index}"),
+                    ttl: 300,
+                    data: txt("v0"),
+                    proxied: None,
+                })
+                .collect(),
+            normalize: false,
+        };
+
+        // 响应丢失的创建已生效，重试前查到该记录，不再重复创建
+        provider.lose_next_create_responses(
+            ProviderError::NetworkError {
+                provider: "mock".to_string(),
+                detail: "connection reset".to_string(),
+            },
+            1,
+        );
+        let created = dns.batch_create_records("account-1", batch("lost")).await?;
+        assert_eq!(created.success_count, 2);
+        assert_eq!(provider.records().len(), 2);
+        assert_eq!(provider.list_records_count(), 1);
+
+        // 限流拒绝的请求未被执行，直接重新提交
+        provider.fail_next_writes(
+            ProviderError::QuotaExceeded {
+                provider: "mock".to_string(),
+                raw_message: None,
+            },
+            1,
+        );
+        let created = dns
+            .batch_create_records("account-1", batch("limited"))
+            .await?;
+        assert_eq!(created.success_count, 2);
+        assert_eq!(provider.records().len(), 4);
+        assert_eq!(provider.list_records_count(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_all_records_reports_progress() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(
+            MockDnsProvider::new()
+                .with_domain("zone-1", "example.com")
+                .with_record("zone-1", "a", 300, txt("1"))
+                .with_record("zone-1", "b", 300, txt("2"))
+                .with_record("zone-2", "c", 300, txt("3")),
+        );
+        register_mock_account(&ctx, "account-1", provider).await?;
+        let dns = DnsService::new(ctx);
+
+        let progress = Mutex::new(Vec::new());
+        let records = dns
+            .list_all_records_with_progress("account-1", "zone-1", |p| {
+                if let Ok(mut progress) = progress.lock() {
+                    progress.push((p.page, p.fetched, p.total));
+                }
+            })
+            .await?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(progress.lock().ok().as_deref(), Some(&vec![(1, 2, 2)]));
+        Ok(())
+    }
+
+    /// 只用于开启审计快照的空审计日志仓库
+    struct NullAuditLog;
+
+    #[async_trait::async_trait]
+    impl crate::traits::AuditLogRepository for NullAuditLog {
+        async fn append(&self, _entry: &AuditEntry) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &str) -> CoreResult<Option<AuditEntry>> {
+            Ok(None)
+        }
+
+        async fn query(
+            &self,
+            _filter: &AuditLogFilter,
+            pagination: &PaginationParams,
+        ) -> CoreResult<PaginatedResponse<AuditEntry>> {
+            Ok(PaginatedResponse::new(
+                Vec::new(),
+                pagination.page,
+                pagination.page_size,
+                0,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_fetches_only_touched_records() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+        let ctx = Arc::new(in_memory_context().with_audit_log(Arc::new(NullAuditLog)));
+        let mut provider = MockDnsProvider::new().with_domain("zone-1", "example.com");
+        for index in 0..12_000 {
+            provider = provider.with_record("zone-1", &format!("_note{index}"), 300, txt("v"));
+        }
+        let provider = Arc::new(provider);
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+        let dyn_provider: Arc<dyn DnsProvider> = Arc::clone(&provider) as _;
+        let ids: Vec<String> = provider.records().into_iter().map(|r| r.id).collect();
+
+        // 按名称与类型过滤，一次查询即可
+        let snapshot = dns
+            .snapshot_records(
+                &dyn_provider,
+                "account-1",
+                "zone-1",
+                &[&ids[7]],
+                Some(("_note7", &DnsRecordType::Txt)),
+            )
+            .await;
+        assert_eq!(snapshot.keys().collect::<Vec<_>>(), [&ids[7]]);
+        assert_eq!(provider.list_records_count(), 1);
+
+        // 没有提示时逐页查找，目标记录都找到后不再翻页（每页 5000 条，共 3 页）
+        let snapshot = dns
+            .snapshot_records(
+                &dyn_provider,
+                "account-1",
+                "zone-1",
+                &[&ids[3], &ids[6000]],
+                None,
+            )
+            .await;
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(provider.list_records_count(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_zone_import_preview_skips_unsafe_records() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+
+        // 第 3 行的目标含西里尔字母 а（U+0430）
+        let zone =
+            "www 300 IN A 192.0.2.1\nbad 300 IN BOGUS x\nshop 300 IN CNAME ex\u{430}mple.net.\n";
+        let preview = dns
+            .import_zone_file("account-1", "zone-1", zone, true, false)
+            .await?;
+        assert_eq!(preview.records.len(), 1);
+        assert_eq!(preview.records[0].name, "www");
+        let lines: Vec<usize> = preview.skipped.iter().map(|entry| entry.line).collect();
+        assert_eq!(lines, [2, 3]);
+        assert!(preview.skipped[1].reason.contains("可疑字符"));
+        assert!(preview.warnings.is_empty());
+
+        // 规范化后可以导入
+        let imported = dns
+            .import_zone_file("account-1", "zone-1", zone, false, true)
+            .await?;
+        assert_eq!(imported.success_count, 2);
+        assert_eq!(imported.skipped.len(), 1);
+        assert_eq!(imported.warnings.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_circuit_opens_after_consecutive_network_errors() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+
+        provider.fail_with(ProviderError::NetworkError {
+            provider: "mock".to_string(),
+            detail: "connection refused".to_string(),
+        });
+        for _ in 0..5 {
+            assert!(matches!(
+                dns.list_all_records("account-1", "zone-1").await,
+                Err(CoreError::Provider(ProviderError::NetworkError { .. }))
+            ));
+        }
+
+        // 熔断期间不再调用服务商
+        provider.clear_failure();
+        assert!(matches!(
+            dns.list_all_records("account-1", "zone-1").await,
+            Err(CoreError::ServiceUnavailable(account_id)) if account_id == "account-1"
+        ));
+
+        // 熔断时长过后放行探测请求，成功后恢复
+        tokio::time::advance(Duration::from_mins(1)).await;
+        dns.list_all_records("account-1", "zone-1").await?;
+        dns.list_all_records("account-1", "zone-1").await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_lock_timeout_reports_holder() -> CoreResult<()> {
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+        use crate::traits::InProcessWriteLocks;
+        use std::time::Duration;
+
+        let ctx = Arc::new(in_memory_context().with_write_locks(Arc::new(
+            InProcessWriteLocks::with_wait_timeout(Duration::from_millis(10)),
+        )));
+        let provider = Arc::new(
+            MockDnsProvider::new()
+                .with_domain("zone-1", "example.com")
+                .with_record("zone-1", "_note", 300, txt("v0"))
+                .with_write_delay(Duration::from_millis(200)),
+        );
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let dns = DnsService::new(ctx);
+        let target = provider.records()[0].id.clone();
+
+        let (updated, replaced) = futures::join!(
+            dns.update_record("account-1", &target, txt_update("v1")),
+            async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                dns.replace_record_value(
+                    "account-1",
+                    ReplaceRecordValueRequest {
+                        domain_id: "zone-1".to_string(),
+                        record_type: None,
+                        old_value: "v0".to_string(),
+                        new_value: "v2".to_string(),
+                    },
+                )
+                .await
+            },
+        );
+        updated?;
+        assert!(matches!(
+            replaced,
+            Err(CoreError::ResourceBusy { resource, holder, .. })
+                if resource == "account-1/zone-1" && holder.starts_with("update_record:")
+        ));
+        Ok(())
     }
 }
@@ -0,0 +1,820 @@
+//! 域名组服务
+//!
+//! 将同一主域在多个账户/服务商下的 (`account_id`, `domain_id`) 绑定为一个域名组，
+//! 并排拉取各成员的记录，按 名称 + 类型 对齐比较，或以某一成员为准生成同步变更集。
+//!
+//! 比较前使用与 zone 文件相同的规范化（相对名称、FQDN 目标、TXT 引号等），并排除服务商特有差异：
+//! - 顶级 NS 由服务商托管，不参与比较与同步
+//! - Cloudflare 的 `proxied` 不参与比较，同步时保留目标记录原值
+//! - 自动 TTL（值为 1）不参与 TTL 比较
+//!
+//! 记录模型不包含解析线路，多线路记录会按同名同类型的多个值处理。
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::Ipv6Addr;
+use std::sync::Arc;
+
+use chrono::Utc;
+use futures::TryStreamExt;
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::zone_file::{
+    effective_ttl, is_provider_managed, rdata, record_type_name, relative_name,
+};
+use crate::services::{
+    paginate_all, DnsService, OperationKind, Page, PageCursor, PaginateOptions, ServiceContext,
+};
+use crate::traits::{DomainGroupRepository, WriteLockTarget};
+use crate::types::{
+    ChangeSource, CreateDnsRecordRequest, CreateDomainGroupRequest, DnsRecord, DomainGroup,
+    DomainGroupChangeset, DomainGroupComparison, DomainGroupMember, DomainGroupMemberStatus,
+    DomainGroupSyncFailure, DomainGroupSyncResult, RecordChange, RecordComparisonCell,
+    RecordComparisonRow, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    UpdateDomainGroupRequest,
+};
+
+/// 拉取成员记录的分页大小
+const MEMBER_RECORDS_PAGE_SIZE: u32 = 100;
+
+/// 域名组最少成员数
+const MIN_GROUP_MEMBERS: usize = 2;
+
+/// 记录对齐键：(规范化名称, 记录类型)
+type RecordKey = (String, &'static str);
+
+/// 规范化后的记录
+struct NormalizedRecord<'a> {
+    value: String,
+    /// 自动 TTL 为 `None`
+    ttl: Option<u32>,
+    record: &'a DnsRecord,
+}
+
+/// 成员记录拉取结果
+struct MemberRecords {
+    domain_name: String,
+    records: Vec<DnsRecord>,
+}
+
+/// 域名组服务
+pub struct DomainGroupService {
+    ctx: Arc<ServiceContext>,
+    dns: DnsService,
+    repository: Arc<dyn DomainGroupRepository>,
+}
+
+impl DomainGroupService {
+    /// 创建域名组服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>, repository: Arc<dyn DomainGroupRepository>) -> Self {
+        Self {
+            dns: DnsService::new(Arc::clone(&ctx)),
+            ctx,
+            repository,
+        }
+    }
+
+    /// 列出所有域名组
+    pub async fn list_groups(&self) -> CoreResult<Vec<DomainGroup>> {
+        self.repository.find_all().await
+    }
+
+    /// 获取域名组
+    pub async fn get_group(&self, group_id: &str) -> CoreResult<DomainGroup> {
+        self.repository
+            .find_by_id(group_id)
+            .await?
+            .ok_or_else(|| CoreError::ValidationError(format!("域名组不存在: {group_id}")))
+    }
+
+    /// 创建域名组
+    pub async fn create_group(&self, request: CreateDomainGroupRequest) -> CoreResult<DomainGroup> {
+        let name = validate_name(&request.name)?;
+        validate_members(&request.members)?;
+
+        let now = Utc::now();
+        let group = DomainGroup {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            members: request.members,
+            created_at: now,
+            updated_at: now,
+        };
+        self.repository.save(&group).await?;
+        Ok(group)
+    }
+
+    /// 更新域名组（名称、成员）
+    pub async fn update_group(&self, request: UpdateDomainGroupRequest) -> CoreResult<DomainGroup> {
+        let mut group = self.get_group(&request.id).await?;
+
+        if let Some(name) = request.name {
+            group.name = validate_name(&name)?;
+        }
+        if let Some(members) = request.members {
+            validate_members(&members)?;
+            group.members = members;
+        }
+        group.updated_at = Utc::now();
+
+        self.repository.save(&group).await?;
+        Ok(group)
+    }
+
+    /// 删除域名组（不影响成员的记录）
+    pub async fn delete_group(&self, group_id: &str) -> CoreResult<()> {
+        self.get_group(group_id).await?;
+        self.repository.delete(group_id).await
+    }
+
+    /// 并排比较域名组各成员的记录
+    ///
+    /// 单个成员拉取失败时记录在 `members[].error` 中，其余成员照常比较。
+    pub async fn get_group_records_comparison(
+        &self,
+        group_id: &str,
+    ) -> CoreResult<DomainGroupComparison> {
+        let group = self.get_group(group_id).await?;
+        let fetched = self.fetch_all_members(&group.members).await;
+
+        let members = group
+            .members
+            .iter()
+            .zip(&fetched)
+            .map(|(member, result)| match result {
+                Ok(fetched) => DomainGroupMemberStatus {
+                    member: member.clone(),
+                    domain_name: fetched.domain_name.clone(),
+                    record_count: fetched.records.len(),
+                    error: None,
+                },
+                Err(e) => DomainGroupMemberStatus {
+                    member: member.clone(),
+                    domain_name: String::new(),
+                    record_count: 0,
+                    error: Some(e.to_string()),
+                },
+            })
+            .collect();
+
+        let inputs: Vec<Option<(&str, &[DnsRecord])>> = fetched
+            .iter()
+            .map(|result| {
+                result
+                    .as_ref()
+                    .ok()
+                    .map(|m| (m.domain_name.as_str(), m.records.as_slice()))
+            })
+            .collect();
+        let rows = compare_records(&inputs);
+        let inconsistent_count = rows.iter().filter(|row| !row.consistent).count();
+
+        Ok(DomainGroupComparison {
+            group_id: group.id,
+            members,
+            rows,
+            inconsistent_count,
+        })
+    }
+
+    /// 以 `source` 成员为准，生成同步到组内其他成员的变更集（不执行）
+    pub async fn preview_group_sync(
+        &self,
+        group_id: &str,
+        source: &DomainGroupMember,
+    ) -> CoreResult<Vec<DomainGroupChangeset>> {
+        let group = self.get_group(group_id).await?;
+        if !group.members.contains(source) {
+            return Err(CoreError::ValidationError(format!(
+                "{}/{} 不是域名组 {} 的成员",
+                source.account_id, source.domain_id, group.name
+            )));
+        }
+
+        let source_records = self.fetch_member(source).await?;
+        let targets: Vec<DomainGroupMember> = group
+            .members
+            .into_iter()
+            .filter(|member| member != source)
+            .collect();
+
+        // 任一目标拉取失败都无法生成完整变更集，直接返回错误
+        let mut changesets = Vec::with_capacity(targets.len());
+        for (target, fetched) in targets.iter().zip(self.fetch_all_members(&targets).await) {
+            let target_records = fetched?;
+            changesets.push(DomainGroupChangeset {
+                target: target.clone(),
+                changes: build_changes(
+                    &source_records.domain_name,
+                    &source_records.records,
+                    &target_records.domain_name,
+                    &target.domain_id,
+                    &target_records.records,
+                ),
+            });
+        }
+        Ok(changesets)
+    }
+
+    /// 执行用户确认后的同步变更集
+    ///
+    /// 变更逐条经 [`DnsService`] 的写入路径执行并写入审计日志，单条失败不影响其他变更。
+    /// 目标必须是域名组成员。
+    pub async fn apply_group_sync(
+        &self,
+        group_id: &str,
+        changesets: Vec<DomainGroupChangeset>,
+    ) -> CoreResult<DomainGroupSyncResult> {
+        let group = self.get_group(group_id).await?;
+        if let Some(changeset) = changesets
+            .iter()
+            .find(|c| !group.members.contains(&c.target))
+        {
+            return Err(CoreError::ValidationError(format!(
+                "{}/{} 不是域名组 {} 的成员",
+                changeset.target.account_id, changeset.target.domain_id, group.name
+            )));
+        }
+        self.ctx.ensure_change_window_open().await?;
+
+        let mut success_count = 0;
+        let mut failures = Vec::new();
+
+        for changeset in changesets {
+            let target = &changeset.target;
+            for change in &changeset.changes {
+                match self.apply_change(target, change).await {
+                    Ok(()) => success_count += 1,
+                    Err(e) => failures.push(DomainGroupSyncFailure {
+                        target: target.clone(),
+                        change: describe_change(change),
+                        reason: e.to_string(),
+                    }),
+                }
+            }
+        }
+
+        log::info!(
+            "[DomainGroup] {}: sync applied, {} succeeded, {} failed",
+            group.name,
+            success_count,
+            failures.len()
+        );
+        Ok(DomainGroupSyncResult {
+            success_count,
+            failed_count: failures.len(),
+            failures,
+        })
+    }
+
+    async fn apply_change(
+        &self,
+        target: &DomainGroupMember,
+        change: &RecordChange,
+    ) -> CoreResult<()> {
+        let record_ids = match change {
+            RecordChange::Create { .. } => Vec::new(),
+            RecordChange::Update { record_id, .. } | RecordChange::Delete { record_id, .. } => {
+                vec![record_id.clone()]
+            }
+        };
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "sync_domain_group",
+                vec![WriteLockTarget::records(
+                    &target.account_id,
+                    &target.domain_id,
+                    record_ids,
+                )],
+            )
+            .await?;
+        self.dns
+            .apply_planned_change(
+                &target.account_id,
+                &target.domain_id,
+                change,
+                None,
+                ChangeSource::Manual,
+            )
+            .await
+            .map(|_| ())
+    }
+
+    async fn fetch_all_members(
+        &self,
+        members: &[DomainGroupMember],
+    ) -> Vec<CoreResult<MemberRecords>> {
+        futures::future::join_all(members.iter().map(|member| self.fetch_member(member))).await
+    }
+
+    /// 拉取成员域名及其全部记录
+    async fn fetch_member(&self, member: &DomainGroupMember) -> CoreResult<MemberRecords> {
+        let account_id = &member.account_id;
+        let provider = self.dns.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(&member.domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        let provider = &provider;
+        let records = paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size: MEMBER_RECORDS_PAGE_SIZE,
+                keyword: None,
+                record_type: None,
+                sort: None,
+            };
+            async move {
+                match self
+                    .ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_records",
+                        provider.list_records(&member.domain_id, &params),
+                    )
+                    .await
+                {
+                    Ok(response) => Ok(Page::from(response)),
+                    Err(e) => Err(self.handle_provider_error(account_id, e).await),
+                }
+            }
+        })
+        .try_collect()
+        .await?;
+
+        Ok(MemberRecords {
+            domain_name: domain.name,
+            records,
+        })
+    }
+
+    /// 处理 Provider 错误，见 [`ServiceContext::handle_provider_error`]
+    async fn handle_provider_error(&self, account_id: &str, err: CoreError) -> CoreError {
+        self.ctx.handle_provider_error(account_id, err).await
+    }
+}
+
+fn validate_name(name: &str) -> CoreResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(CoreError::ValidationError("域名组名称不能为空".to_string()));
+    }
+    Ok(name.to_string())
+}
+
+fn validate_members(members: &[DomainGroupMember]) -> CoreResult<()> {
+    if members.len() < MIN_GROUP_MEMBERS {
+        return Err(CoreError::ValidationError(format!(
+            "域名组至少需要 {MIN_GROUP_MEMBERS} 个成员"
+        )));
+    }
+    for (i, member) in members.iter().enumerate() {
+        if members[..i].contains(member) {
+            return Err(CoreError::ValidationError(format!(
+                "域名组成员重复: {}/{}",
+                member.account_id, member.domain_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+// ============ 规范化与比较 ============
+
+/// 规范化记录值：目标主机名转为小写 FQDN，IPv6 转为标准形式，TXT 去掉服务商附带的外层引号
+pub(crate) fn normalized_value(data: &RecordData, origin: &str) -> String {
+    match data {
+        RecordData::AAAA { address } => address
+            .parse::<Ipv6Addr>()
+            .map_or_else(|_| address.to_lowercase(), |ip| ip.to_string()),
+        RecordData::TXT { text } => {
+            let text = text
+                .strip_prefix('"')
+                .and_then(|t| t.strip_suffix('"'))
+                .unwrap_or(text);
+            rdata(
+                &RecordData::TXT {
+                    text: text.to_string(),
+                },
+                origin,
+            )
+        }
+        RecordData::CAA { .. } => rdata(data, origin),
+        _ => rdata(data, origin).to_lowercase(),
+    }
+}
+
+/// 按 名称 + 类型 分组（跳过 SOA 与顶级 NS），组内按值排序
+fn group_records<'a>(
+    domain_name: &str,
+    records: &'a [DnsRecord],
+) -> BTreeMap<RecordKey, Vec<NormalizedRecord<'a>>> {
+    let origin = domain_name.trim_end_matches('.').to_lowercase();
+    let mut groups: BTreeMap<RecordKey, Vec<NormalizedRecord<'a>>> = BTreeMap::new();
+
+    for record in records {
+        let name = relative_name(&record.name, &origin).to_lowercase();
+        if is_provider_managed(&name, &record.data) {
+            continue;
+        }
+        groups
+            .entry((name, record_type_name(&record.data)))
+            .or_default()
+            .push(NormalizedRecord {
+                value: normalized_value(&record.data, &origin),
+                ttl: (record.ttl > 1).then_some(record.ttl),
+                record,
+            });
+    }
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| a.value.cmp(&b.value));
+    }
+    groups
+}
+
+/// 比较各成员的记录，`None` 表示该成员拉取失败（不参与一致性判断）
+fn compare_records(members: &[Option<(&str, &[DnsRecord])>]) -> Vec<RecordComparisonRow> {
+    let grouped: Vec<Option<BTreeMap<RecordKey, Vec<NormalizedRecord<'_>>>>> = members
+        .iter()
+        .map(|member| member.map(|(domain_name, records)| group_records(domain_name, records)))
+        .collect();
+
+    let keys: BTreeSet<&RecordKey> = grouped.iter().flatten().flat_map(BTreeMap::keys).collect();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let record_type = grouped
+                .iter()
+                .flatten()
+                .find_map(|groups| groups.get(key)?.first())?
+                .record
+                .data
+                .record_type();
+            let cells: Vec<Option<RecordComparisonCell>> = grouped
+                .iter()
+                .map(|groups| {
+                    let records = groups.as_ref()?.get(key)?;
+                    Some(RecordComparisonCell {
+                        values: records.iter().map(|r| r.value.clone()).collect(),
+                        ttl: records.iter().filter_map(|r| r.ttl).min(),
+                    })
+                })
+                .collect();
+
+            let fetched: Vec<Option<&RecordComparisonCell>> = cells
+                .iter()
+                .zip(&grouped)
+                .filter(|(_, groups)| groups.is_some())
+                .map(|(cell, _)| cell.as_ref())
+                .collect();
+            let consistent = cells_consistent(&fetched);
+
+            Some(RecordComparisonRow {
+                name: key.0.clone(),
+                record_type,
+                cells,
+                consistent,
+            })
+        })
+        .collect()
+}
+
+/// 值完全一致，且各自非自动的 TTL 相同
+fn cells_consistent(cells: &[Option<&RecordComparisonCell>]) -> bool {
+    let Some(present) = cells.iter().copied().collect::<Option<Vec<_>>>() else {
+        return false;
+    };
+    let Some(first) = present.first() else {
+        return true;
+    };
+    let mut ttls = present.iter().filter_map(|cell| cell.ttl);
+    let ttl_consistent = ttls.next().is_none_or(|ttl| ttls.all(|other| other == ttl));
+    present.iter().all(|cell| cell.values == first.values) && ttl_consistent
+}
+
+/// 生成将目标成员同步为与源成员一致的变更
+///
+/// 同名同类型下，值相同的记录只在 TTL 不同时更新；多出的目标记录优先改写为缺少的源值，
+/// 仍有剩余则删除；仍缺少的源值则新建。
+fn build_changes(
+    source_domain: &str,
+    source: &[DnsRecord],
+    target_domain: &str,
+    target_domain_id: &str,
+    target: &[DnsRecord],
+) -> Vec<RecordChange> {
+    let source_groups = group_records(source_domain, source);
+    let target_groups = group_records(target_domain, target);
+    let keys: BTreeSet<&RecordKey> = source_groups.keys().chain(target_groups.keys()).collect();
+
+    let mut changes = Vec::new();
+    for key in keys {
+        let source_records = source_groups.get(key).map_or(&[][..], Vec::as_slice);
+        let target_records = target_groups.get(key).map_or(&[][..], Vec::as_slice);
+
+        let mut unmatched_source: Vec<&NormalizedRecord<'_>> = source_records.iter().collect();
+        let mut unmatched_target = Vec::new();
+
+        for t in target_records {
+            if let Some(pos) = unmatched_source.iter().position(|s| s.value == t.value) {
+                let s = unmatched_source.remove(pos);
+                if let Some(ttl) = s.ttl.filter(|ttl| t.ttl != Some(*ttl)) {
+                    changes.push(update_change(
+                        t,
+                        key,
+                        ttl,
+                        s.record.data.clone(),
+                        target_domain_id,
+                    ));
+                }
+            } else {
+                unmatched_target.push(t);
+            }
+        }
+
+        let mut unmatched_target = unmatched_target.into_iter();
+        for s in unmatched_source {
+            let ttl = effective_ttl(s.record.ttl);
+            if let Some(t) = unmatched_target.next() {
+                changes.push(update_change(
+                    t,
+                    key,
+                    ttl,
+                    s.record.data.clone(),
+                    target_domain_id,
+                ));
+            } else {
+                changes.push(RecordChange::Create {
+                    request: CreateDnsRecordRequest {
+                        domain_id: target_domain_id.to_string(),
+                        name: key.0.clone(),
+                        ttl,
+                        data: s.record.data.clone(),
+                        proxied: None,
+                    },
+                });
+            }
+        }
+        for t in unmatched_target {
+            changes.push(RecordChange::Delete {
+                record_id: t.record.id.clone(),
+                name: key.0.clone(),
+                record_type: t.record.data.record_type(),
+                value: t.value.clone(),
+            });
+        }
+    }
+    changes
+}
+
+fn update_change(
+    target: &NormalizedRecord<'_>,
+    key: &RecordKey,
+    ttl: u32,
+    data: RecordData,
+    target_domain_id: &str,
+) -> RecordChange {
+    RecordChange::Update {
+        record_id: target.record.id.clone(),
+        previous_value: target.value.clone(),
+        request: UpdateDnsRecordRequest {
+            domain_id: target_domain_id.to_string(),
+            name: key.0.clone(),
+            ttl,
+            data,
+            proxied: target.record.proxied,
+        },
+    }
+}
+
+/// 变更描述（用于失败列表）
+fn describe_change(change: &RecordChange) -> String {
+    match change {
+        RecordChange::Create { request } => format!(
+            "create {} {}",
+            request.name,
+            record_type_name(&request.data)
+        ),
+        RecordChange::Update { request, .. } => format!(
+            "update {} {}",
+            request.name,
+            record_type_name(&request.data)
+        ),
+        RecordChange::Delete {
+            name, record_id, ..
+        } => format!("delete {name} ({record_id})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, name: &str, ttl: u32, data: RecordData) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            domain_id: "example.com".to_string(),
+            name: name.to_string(),
+            ttl,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn a(address: &str) -> RecordData {
+        RecordData::A {
+            address: address.to_string(),
+        }
+    }
+
+    #[test]
+    fn compares_normalized_records() {
+        let cloudflare = vec![
+            record("1", "www.example.com", 1, a("192.0.2.1")),
+            record(
+                "2",
+                "example.com",
+                1,
+                RecordData::MX {
+                    priority: 10,
+                    exchange: "Mail.Example.com".to_string(),
+                },
+            ),
+            record(
+                "3",
+                "example.com",
+                1,
+                RecordData::NS {
+                    nameserver: "ada.ns.cloudflare.com".to_string(),
+                },
+            ),
+            record(
+                "4",
+                "v6",
+                300,
+                RecordData::AAAA {
+                    address: "2001:DB8:0:0::1".to_string(),
+                },
+            ),
+        ];
+        let aliyun = vec![
+            record("a", "www", 600, a("192.0.2.1")),
+            record(
+                "b",
+                "@",
+                600,
+                RecordData::MX {
+                    priority: 10,
+                    exchange: "mail.example.com.".to_string(),
+                },
+            ),
+            record(
+                "c",
+                "@",
+                600,
+                RecordData::NS {
+                    nameserver: "dns1.hichina.com".to_string(),
+                },
+            ),
+            record(
+                "d",
+                "v6",
+                600,
+                RecordData::AAAA {
+                    address: "2001:db8::1".to_string(),
+                },
+            ),
+            record("e", "api", 600, a("192.0.2.2")),
+        ];
+
+        let rows = compare_records(&[
+            Some(("example.com", &cloudflare)),
+            Some(("example.com", &aliyun)),
+            None,
+        ]);
+
+        // 顶级 NS 被排除
+        assert_eq!(rows.len(), 4);
+        let row = |name: &str, record_type: &str| {
+            rows.iter().find(|r| {
+                r.name == name && format!("{:?}", r.record_type).eq_ignore_ascii_case(record_type)
+            })
+        };
+
+        // 自动 TTL 不参与比较
+        assert!(row("www", "A").is_some_and(|r| r.consistent));
+        assert!(row("@", "MX").is_some_and(|r| r.consistent));
+        // 值一致但 TTL 不同
+        assert!(row("v6", "AAAA").is_some_and(|r| !r.consistent));
+        // 只存在于一侧
+        let api = row("api", "A");
+        assert!(api.is_some_and(|r| !r.consistent && r.cells[0].is_none() && r.cells[2].is_none()));
+    }
+
+    #[test]
+    fn builds_sync_changes() {
+        let source = vec![
+            record("s1", "www", 600, a("192.0.2.1")),
+            record("s2", "www", 600, a("192.0.2.2")),
+            record("s3", "api", 300, a("192.0.2.10")),
+            record(
+                "s4",
+                "@",
+                600,
+                RecordData::NS {
+                    nameserver: "dns1.hichina.com".to_string(),
+                },
+            ),
+        ];
+        let target = vec![
+            record("t1", "www.example.com", 600, a("192.0.2.1")),
+            record("t2", "www.example.com", 600, a("192.0.2.9")),
+            record("t3", "api.example.com", 1, a("192.0.2.10")),
+            record("t4", "old.example.com", 600, a("192.0.2.20")),
+            record(
+                "t5",
+                "example.com",
+                1,
+                RecordData::NS {
+                    nameserver: "ada.ns.cloudflare.com".to_string(),
+                },
+            ),
+        ];
+
+        let changes = build_changes("example.com", &source, "example.com", "zone-2", &target);
+
+        assert_eq!(changes.len(), 3);
+        // api: 值相同，源 TTL 非自动 -> 更新 TTL
+        assert!(matches!(
+            &changes[0],
+            RecordChange::Update { record_id, request, .. } if record_id == "t3" && request.ttl == 300
+        ));
+        // old: 源中不存在 -> 删除
+        assert!(matches!(
+            &changes[1],
+            RecordChange::Delete { record_id, .. } if record_id == "t4"
+        ));
+        // www: 多出的 192.0.2.9 改写为缺少的 192.0.2.2
+        assert!(matches!(
+            &changes[2],
+            RecordChange::Update { record_id, previous_value, request }
+                if record_id == "t2"
+                    && previous_value == "192.0.2.9"
+                    && request.domain_id == "zone-2"
+                    && matches!(&request.data, RecordData::A { address } if address == "192.0.2.2")
+        ));
+    }
+
+    #[test]
+    fn creates_missing_records() {
+        let source = vec![record(
+            "s1",
+            "_dmarc",
+            1,
+            RecordData::TXT {
+                text: "\"v=DMARC1; p=none\"".to_string(),
+            },
+        )];
+        let target = vec![record(
+            "t1",
+            "_dmarc.example.com",
+            600,
+            RecordData::TXT {
+                text: "v=DMARC1; p=none".to_string(),
+            },
+        )];
+
+        // 外层引号差异视为一致，自动 TTL 不触发更新
+        assert!(build_changes("example.com", &source, "example.com", "zone-2", &target).is_empty());
+
+        let changes = build_changes("example.com", &source, "example.com", "zone-2", &[]);
+        assert!(matches!(
+            &changes[..],
+            [RecordChange::Create { request }] if request.name == "_dmarc" && request.ttl == 300
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_members() {
+        let member = DomainGroupMember {
+            account_id: "acc".to_string(),
+            domain_id: "zone".to_string(),
+        };
+        assert!(validate_members(std::slice::from_ref(&member)).is_err());
+        assert!(validate_members(&[member.clone(), member]).is_err());
+        assert!(validate_name("  ").is_err());
+    }
+}
@@ -11,6 +11,35 @@ use crate::types::{
 };
 
 /// 域名元数据管理服务
+///
+/// # Examples
+///
+#[cfg_attr(feature = "testing", doc = "```")]
+#[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+/// use std::sync::Arc;
+///
+/// use dns_orchestrator_core::services::DomainMetadataService;
+/// use dns_orchestrator_core::testing::InMemoryDomainMetadataRepository;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> dns_orchestrator_core::CoreResult<()> {
+/// let service = DomainMetadataService::new(Arc::new(InMemoryDomainMetadataRepository::default()));
+///
+/// assert!(service.toggle_favorite("account-1", "zone-1").await?);
+/// let tags = service
+///     .add_tag("account-1", "zone-1", " production ".to_string())
+///     .await?;
+/// assert_eq!(tags, ["production"]);
+///
+/// let favorites = service.list_favorites("account-1").await?;
+/// assert_eq!(favorites.len(), 1);
+/// assert_eq!(favorites[0].domain_id, "zone-1");
+///
+/// // 标签校验失败
+/// assert!(service.add_tag("account-1", "zone-1", "  ".to_string()).await.is_err());
+/// # Ok(())
+/// # }
+/// ```
 pub struct DomainMetadataService {
     repository: Arc<dyn DomainMetadataRepository>,
 }
@@ -122,6 +151,14 @@ impl DomainMetadataService {
         self.repository.find_favorites_by_account(account_id).await
     }
 
+    /// 获取账户下的所有元数据
+    pub async fn list_account_metadata(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<(DomainMetadataKey, DomainMetadata)>> {
+        self.repository.find_by_account(account_id).await
+    }
+
     /// 删除账户下的所有元数据（账户删除时调用）
     pub async fn delete_account_metadata(&self, account_id: &str) -> CoreResult<()> {
         self.repository.delete_by_account(account_id).await
@@ -0,0 +1,767 @@
+//! 域名到期监控服务
+//!
+//! 遍历所有账户下的域名，通过 WHOIS / RDAP 查询注册到期时间，按 7/30/90 天（可配置）
+//! 档位生成到期报告。每次检查的结果通过 [`MonitorRepository`] 保存，
+//! 下次检查时与之比较，只把进入更严重档位的域名列为新提醒。
+//!
+//! 另提供按账户的 SSL 证书检查报告，检查每个域名的根域名与 `www` 子域名。
+//!
+//! 两类检查成功的结果会合并进域名派生状态（见 [`DomainDerivedStatus`]），
+//! 随域名列表返回；检查失败的域名保留上次的值。
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::{
+    paginate_all, OperationKind, Page, PageCursor, PaginateOptions, ServiceContext, ToolboxService,
+};
+use crate::traits::MonitorRepository;
+use crate::types::{
+    Account, CertificateExpiryEntry, CertificateReport, DomainDerivedStatus, DomainExpiryAccount,
+    DomainExpiryAccountError, DomainExpiryEntry, DomainExpiryReport, DomainExpiryStatus,
+    DomainExpiryThresholds, PaginationParams, SslCheckResult, SslCheckTarget,
+};
+
+use super::credential_expiry_service::days_remaining;
+
+/// 拉取账户域名列表的分页大小
+const LIST_DOMAINS_PAGE_SIZE: u32 = 100;
+
+/// WHOIS 中常见的纯日期格式
+const DATE_FORMATS: [&str; 5] = ["%Y-%m-%d", "%d-%b-%Y", "%Y.%m.%d", "%Y/%m/%d", "%d.%m.%Y"];
+
+/// WHOIS 中常见的不带时区的日期时间格式（按 UTC 处理）
+const DATETIME_FORMATS: [&str; 3] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S%.f",
+];
+
+/// 域名到期监控配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainMonitorConfig {
+    /// 默认提醒档位
+    pub thresholds: DomainExpiryThresholds,
+    /// 同时进行的 WHOIS 查询数
+    pub concurrency: usize,
+    /// 证书剩余天数少于该值时列入证书报告的即将到期列表
+    pub certificate_warning_days: u32,
+}
+
+impl Default for DomainMonitorConfig {
+    fn default() -> Self {
+        Self {
+            thresholds: DomainExpiryThresholds::default(),
+            concurrency: 8,
+            certificate_warning_days: 30,
+        }
+    }
+}
+
+/// 域名到期监控服务
+pub struct DomainMonitorService {
+    ctx: Arc<ServiceContext>,
+    toolbox: Arc<ToolboxService>,
+    repository: Arc<dyn MonitorRepository>,
+    config: DomainMonitorConfig,
+}
+
+impl DomainMonitorService {
+    /// 创建域名到期监控服务实例
+    ///
+    /// `toolbox` 应与工具箱命令共享同一实例，以复用 WHOIS 查询缓存。
+    #[must_use]
+    pub fn new(
+        ctx: Arc<ServiceContext>,
+        toolbox: Arc<ToolboxService>,
+        repository: Arc<dyn MonitorRepository>,
+    ) -> Self {
+        Self {
+            ctx,
+            toolbox,
+            repository,
+            config: DomainMonitorConfig::default(),
+        }
+    }
+
+    /// 覆盖默认的档位与并发配置
+    #[must_use]
+    pub const fn with_config(mut self, config: DomainMonitorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 检查所有账户下域名的到期时间
+    ///
+    /// `thresholds` 为空时使用配置中的默认档位。同一域名在多个账户下时只查询一次。
+    /// 查询失败的域名沿用上次成功检查的到期时间作为比较基准。
+    pub async fn check_domain_expiry(
+        &self,
+        thresholds: Option<DomainExpiryThresholds>,
+    ) -> CoreResult<DomainExpiryReport> {
+        let thresholds = validate_thresholds(thresholds.unwrap_or(self.config.thresholds))?;
+        let (domains, failed_accounts) = self.collect_domains().await?;
+        let total_domains = domains.len();
+        log::info!("[DomainMonitor] Checking expiry of {total_domains} domains");
+
+        let now = Utc::now();
+        let entries: Vec<DomainExpiryEntry> = stream::iter(domains)
+            .map(|(name, accounts)| async move {
+                match self.lookup_expiration(&name).await {
+                    Ok(expires_at) => expiry_entry(name, accounts, Some(expires_at), None, now),
+                    Err(e) => expiry_entry(name, accounts, None, Some(e), now),
+                }
+            })
+            .buffer_unordered(self.config.concurrency.max(1))
+            .collect()
+            .await;
+
+        let checked: Vec<(String, String, DateTime<Utc>)> = entries
+            .iter()
+            .filter_map(|entry| Some((entry.expires_at?, &entry.accounts)))
+            .flat_map(|(expires_at, accounts)| {
+                accounts
+                    .iter()
+                    .map(move |a| (a.account_id.clone(), a.domain_id.clone(), expires_at))
+            })
+            .collect();
+        self.update_derived_status(checked, |status, expires_at| {
+            status.expires_at = Some(expires_at);
+            status.expiry_days_remaining = Some(days_remaining(expires_at, now));
+            status.expiry_checked_at = Some(now);
+        })
+        .await;
+
+        let previous = self.repository.find_expiry_results().await?;
+        let (report, saved) = build_report(
+            entries,
+            previous,
+            failed_accounts,
+            thresholds,
+            now,
+            total_domains,
+        );
+        self.repository.save_expiry_results(&saved).await?;
+        Ok(report)
+    }
+
+    /// 列出最近一次检查中需要关注的域名（不重新查询 WHOIS）
+    ///
+    /// 剩余天数与档位按当前时间和 `thresholds` 重新计算；结果按到期时间升序，
+    /// 无法确定到期时间的域名排在最后。
+    pub async fn list_expiry_warnings(
+        &self,
+        thresholds: Option<DomainExpiryThresholds>,
+    ) -> CoreResult<Vec<DomainExpiryEntry>> {
+        let thresholds = validate_thresholds(thresholds.unwrap_or(self.config.thresholds))?;
+        let now = Utc::now();
+        let mut warnings: Vec<DomainExpiryEntry> = self
+            .repository
+            .find_expiry_results()
+            .await?
+            .into_iter()
+            .map(|mut entry| {
+                if let Some(expires_at) = entry.expires_at {
+                    entry.days_remaining = Some(days_remaining(expires_at, now));
+                    entry.status = expiry_status(expires_at, now, thresholds);
+                }
+                entry
+            })
+            .filter(|entry| entry.status != DomainExpiryStatus::Ok)
+            .collect();
+        sort_by_expiry(&mut warnings);
+        Ok(warnings)
+    }
+
+    /// 检查账户下每个域名根域名与 `www` 子域名的 SSL 证书
+    ///
+    /// 单个主机检查失败只计入 `failed_count`，不影响整份报告。
+    #[cfg(feature = "rustls")]
+    pub async fn certificate_report(&self, account_id: &str) -> CoreResult<CertificateReport> {
+        let account = self
+            .ctx
+            .account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+        let domains = self.list_account_domains(&account).await?;
+        let mut domain_names: Vec<String> = domains.iter().map(|(name, _)| name.clone()).collect();
+        domain_names.sort();
+        domain_names.dedup();
+        log::info!(
+            "[DomainMonitor] Checking certificates of {} domains in {}",
+            domain_names.len(),
+            account.id
+        );
+
+        let checked_at = Utc::now();
+        let targets = domain_names
+            .iter()
+            .flat_map(|name| [name.clone(), format!("www.{name}")])
+            .map(|domain| SslCheckTarget { domain, port: None })
+            .collect();
+        let results = ToolboxService::ssl_check_batch(targets).await;
+
+        let earliest = earliest_certificate_expiry(&domain_names, &results);
+        let checked = domains
+            .into_iter()
+            .filter_map(|(name, item)| {
+                Some((item.account_id, item.domain_id, *earliest.get(&name)?))
+            })
+            .collect();
+        self.update_derived_status(checked, |status, expires_at| {
+            status.certificate_expires_at = Some(expires_at);
+            status.certificate_days_remaining = Some(days_remaining(expires_at, checked_at));
+            status.certificate_checked_at = Some(checked_at);
+        })
+        .await;
+
+        let warning_days = self.config.certificate_warning_days;
+        Ok(CertificateReport {
+            account_id: account.id,
+            account_name: account.name,
+            checked_at,
+            warning_days,
+            total_domains: domain_names.len(),
+            expiring: expiring_certificates(&domain_names, &results, warning_days),
+            failed_count: results.iter().filter(|r| r.cert_info.is_none()).count(),
+            results,
+        })
+    }
+
+    /// 把本次检查成功的结果合并进域名派生状态
+    ///
+    /// `targets` 为「账户 ID, 域名 ID, 检查结果」，由 `apply` 写入对应的派生状态。
+    /// 未配置仓库时跳过；读写失败只记录日志，不影响检查报告。
+    async fn update_derived_status<T>(
+        &self,
+        targets: Vec<(String, String, T)>,
+        apply: impl Fn(&mut DomainDerivedStatus, T),
+    ) {
+        let Some(repository) = &self.ctx.domain_status_repository else {
+            return;
+        };
+        if targets.is_empty() {
+            return;
+        }
+
+        let account_ids: BTreeSet<&str> = targets.iter().map(|(id, _, _)| id.as_str()).collect();
+        let mut current: HashMap<(String, String), DomainDerivedStatus> = HashMap::new();
+        for account_id in account_ids {
+            match repository.find_by_account(account_id).await {
+                Ok(statuses) => current.extend(
+                    statuses
+                        .into_iter()
+                        .map(|s| ((s.account_id.clone(), s.domain_id.clone()), s)),
+                ),
+                Err(e) => {
+                    log::warn!(
+                        "[DomainMonitor] Failed to load derived status of {account_id}: {e}"
+                    );
+                    return;
+                }
+            }
+        }
+
+        let statuses: Vec<DomainDerivedStatus> = targets
+            .into_iter()
+            .map(|(account_id, domain_id, value)| {
+                let mut status = current
+                    .remove(&(account_id.clone(), domain_id.clone()))
+                    .unwrap_or_else(|| DomainDerivedStatus::new(account_id, domain_id));
+                apply(&mut status, value);
+                status
+            })
+            .collect();
+        if let Err(e) = repository.save_batch(&statuses).await {
+            log::warn!("[DomainMonitor] Failed to save derived status: {e}");
+        }
+    }
+
+    /// 收集所有账户下的域名（按小写域名去重），以及拉取失败的账户
+    async fn collect_domains(
+        &self,
+    ) -> CoreResult<(
+        BTreeMap<String, Vec<DomainExpiryAccount>>,
+        Vec<DomainExpiryAccountError>,
+    )> {
+        let mut domains: BTreeMap<String, Vec<DomainExpiryAccount>> = BTreeMap::new();
+        let mut failed_accounts = Vec::new();
+
+        for account in self.ctx.account_repository.find_all().await? {
+            match self.list_account_domains(&account).await {
+                Ok(items) => {
+                    for (name, item) in items {
+                        domains.entry(name).or_default().push(item);
+                    }
+                }
+                Err(e) => {
+                    log::warn!(
+                        "[DomainMonitor] Failed to list domains of {}: {e}",
+                        account.id
+                    );
+                    failed_accounts.push(DomainExpiryAccountError {
+                        account_id: account.id,
+                        account_name: account.name,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok((domains, failed_accounts))
+    }
+
+    /// 分页拉取账户下的全部域名
+    async fn list_account_domains(
+        &self,
+        account: &Account,
+    ) -> CoreResult<Vec<(String, DomainExpiryAccount)>> {
+        let provider = self.ctx.get_provider(&account.id).await?;
+        let provider = &provider;
+        paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = PaginationParams {
+                page: cursor.page_number(),
+                page_size: LIST_DOMAINS_PAGE_SIZE,
+            };
+            async move {
+                match self
+                    .ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_domains",
+                        provider.list_domains(&params),
+                    )
+                    .await
+                {
+                    Ok(response) => Ok(Page::from(response)),
+                    Err(e) => Err(self.ctx.handle_provider_error(&account.id, e).await),
+                }
+            }
+        })
+        .map_ok(|domain| {
+            (
+                normalize_domain(&domain.name),
+                DomainExpiryAccount {
+                    account_id: account.id.clone(),
+                    account_name: account.name.clone(),
+                    provider: account.provider.clone(),
+                    domain_id: domain.id,
+                },
+            )
+        })
+        .try_collect()
+        .await
+    }
+
+    /// 查询域名的注册到期时间，失败时返回原因
+    async fn lookup_expiration(&self, domain: &str) -> Result<DateTime<Utc>, String> {
+        let result = self
+            .toolbox
+            .whois_lookup(domain, false)
+            .await
+            .map_err(|e| e.to_string())?;
+        let raw = result
+            .expiration_date
+            .ok_or_else(|| "WHOIS/RDAP 响应中没有到期日期".to_string())?;
+        parse_expiration_date(&raw).ok_or_else(|| format!("无法解析到期日期: {raw}"))
+    }
+}
+
+/// 档位需满足 严重 ≤ 警告 ≤ 提醒
+fn validate_thresholds(thresholds: DomainExpiryThresholds) -> CoreResult<DomainExpiryThresholds> {
+    if thresholds.critical_days > thresholds.warning_days
+        || thresholds.warning_days > thresholds.notice_days
+    {
+        return Err(CoreError::ValidationError(format!(
+            "到期提醒档位需满足 严重 ≤ 警告 ≤ 提醒（当前为 {}/{}/{} 天）",
+            thresholds.critical_days, thresholds.warning_days, thresholds.notice_days
+        )));
+    }
+    Ok(thresholds)
+}
+
+/// 域名比较键：小写、去掉首尾空白与末尾的点
+fn normalize_domain(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_lowercase()
+}
+
+/// 解析 WHOIS / RDAP 返回的到期日期
+///
+/// 支持 RFC 3339、不带时区的日期时间（按 UTC 处理）以及常见的纯日期格式；
+/// 纯日期按当天 00:00 UTC 处理。
+fn parse_expiration_date(raw: &str) -> Option<DateTime<Utc>> {
+    let raw = raw.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(raw) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+
+    let without_zone = raw
+        .trim_end_matches(" UTC")
+        .trim_end_matches(" GMT")
+        .trim_end_matches('Z');
+    if let Some(datetime) = DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(without_zone, format).ok())
+    {
+        return Some(datetime.and_utc());
+    }
+
+    let date_part = raw.split_whitespace().next()?;
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date_part, format).ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+}
+
+/// 到期时间所处的档位
+fn expiry_status(
+    expires_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    thresholds: DomainExpiryThresholds,
+) -> DomainExpiryStatus {
+    if expires_at <= now {
+        return DomainExpiryStatus::Expired;
+    }
+    let days = days_remaining(expires_at, now);
+    if days <= i64::from(thresholds.critical_days) {
+        DomainExpiryStatus::Critical
+    } else if days <= i64::from(thresholds.warning_days) {
+        DomainExpiryStatus::Warning
+    } else if days <= i64::from(thresholds.notice_days) {
+        DomainExpiryStatus::Notice
+    } else {
+        DomainExpiryStatus::Ok
+    }
+}
+
+/// 构造检查结果，档位在 [`build_report`] 中按本次档位计算
+fn expiry_entry(
+    domain_name: String,
+    accounts: Vec<DomainExpiryAccount>,
+    expires_at: Option<DateTime<Utc>>,
+    error: Option<String>,
+    now: DateTime<Utc>,
+) -> DomainExpiryEntry {
+    DomainExpiryEntry {
+        domain_name,
+        accounts,
+        expires_at,
+        days_remaining: expires_at.map(|expires_at| days_remaining(expires_at, now)),
+        status: DomainExpiryStatus::Unknown,
+        error,
+        checked_at: now,
+    }
+}
+
+/// 按到期时间升序，无法确定到期时间的排在最后
+fn sort_by_expiry(entries: &mut [DomainExpiryEntry]) {
+    entries.sort_by(|a, b| match (a.expires_at, b.expires_at) {
+        (Some(a_at), Some(b_at)) => a_at.cmp(&b_at),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.domain_name.cmp(&b.domain_name),
+    });
+}
+
+/// 剩余天数少于 `warning_days` 的证书，按剩余天数升序
+///
+/// `results` 与 [`DomainMonitorService::certificate_report`] 的检查目标一一对应，
+/// 即每个域名依次为根域名与 `www` 子域名。
+fn expiring_certificates(
+    domain_names: &[String],
+    results: &[SslCheckResult],
+    warning_days: u32,
+) -> Vec<CertificateExpiryEntry> {
+    let mut expiring: Vec<CertificateExpiryEntry> = domain_names
+        .iter()
+        .flat_map(|name| [name, name])
+        .zip(results)
+        .filter_map(|(domain_name, result)| {
+            let cert = result.cert_info.as_ref()?;
+            (cert.days_remaining < i64::from(warning_days)).then(|| CertificateExpiryEntry {
+                domain_name: domain_name.clone(),
+                host: result.domain.clone(),
+                issuer: cert.issuer.clone(),
+                valid_to: cert.valid_to.clone(),
+                days_remaining: cert.days_remaining,
+                is_expired: cert.is_expired,
+            })
+        })
+        .collect();
+    expiring.sort_by(|a, b| {
+        a.days_remaining
+            .cmp(&b.days_remaining)
+            .then_with(|| a.host.cmp(&b.host))
+    });
+    expiring
+}
+
+/// 每个域名根域名与 `www` 子域名中较早到期的证书的到期时间
+///
+/// `results` 的顺序同 [`expiring_certificates`]；两个主机都检查失败的域名不在结果中。
+fn earliest_certificate_expiry(
+    domain_names: &[String],
+    results: &[SslCheckResult],
+) -> HashMap<String, DateTime<Utc>> {
+    let mut earliest: HashMap<String, DateTime<Utc>> = HashMap::new();
+    for (domain_name, result) in domain_names
+        .iter()
+        .flat_map(|name| [name, name])
+        .zip(results)
+    {
+        let Some(valid_to) = result
+            .cert_info
+            .as_ref()
+            .and_then(|cert| DateTime::parse_from_rfc2822(&cert.valid_to).ok())
+        else {
+            continue;
+        };
+        let valid_to = valid_to.with_timezone(&Utc);
+        earliest
+            .entry(domain_name.clone())
+            .and_modify(|at| *at = (*at).min(valid_to))
+            .or_insert(valid_to);
+    }
+    earliest
+}
+
+/// 根据本次查询结果与上次保存的结果生成报告，并返回需要保存的结果
+///
+/// 本次查询失败但上次有到期时间的域名，保存上次的结果，避免下次检查时重复提醒。
+fn build_report(
+    mut entries: Vec<DomainExpiryEntry>,
+    previous: Vec<DomainExpiryEntry>,
+    failed_accounts: Vec<DomainExpiryAccountError>,
+    thresholds: DomainExpiryThresholds,
+    now: DateTime<Utc>,
+    total_domains: usize,
+) -> (DomainExpiryReport, Vec<DomainExpiryEntry>) {
+    let mut previous: HashMap<String, DomainExpiryEntry> = previous
+        .into_iter()
+        .map(|entry| (entry.domain_name.clone(), entry))
+        .collect();
+
+    for entry in &mut entries {
+        if let Some(expires_at) = entry.expires_at {
+            entry.status = expiry_status(expires_at, now, thresholds);
+        }
+    }
+    sort_by_expiry(&mut entries);
+
+    let mut report = DomainExpiryReport {
+        checked_at: now,
+        thresholds,
+        total_domains,
+        expiring: Vec::new(),
+        unknown: Vec::new(),
+        newly_flagged: Vec::new(),
+        failed_accounts,
+    };
+    let mut saved = Vec::with_capacity(entries.len());
+
+    for entry in entries {
+        let last = previous.remove(&entry.domain_name);
+        match entry.status {
+            DomainExpiryStatus::Unknown => {
+                report.unknown.push(entry.clone());
+                match last {
+                    Some(last) if last.expires_at.is_some() => saved.push(last),
+                    _ => saved.push(entry),
+                }
+            }
+            DomainExpiryStatus::Ok => saved.push(entry),
+            status => {
+                if last.is_none_or(|last| last.status < status) {
+                    log::info!(
+                        "[DomainMonitor] {} is now {status:?} (expires {:?})",
+                        entry.domain_name,
+                        entry.expires_at
+                    );
+                    report.newly_flagged.push(entry.clone());
+                }
+                report.expiring.push(entry.clone());
+                saved.push(entry);
+            }
+        }
+    }
+
+    (report, saved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn entry(
+        name: &str,
+        expires_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> DomainExpiryEntry {
+        let error = expires_at.is_none().then(|| "timeout".to_string());
+        expiry_entry(name.to_string(), Vec::new(), expires_at, error, now)
+    }
+
+    fn names(entries: &[DomainExpiryEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.domain_name.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_common_whois_date_formats() {
+        let expected = Utc.with_ymd_and_hms(2027, 1, 2, 3, 4, 5).single();
+        assert_eq!(parse_expiration_date("2027-01-02T03:04:05Z"), expected);
+        assert_eq!(parse_expiration_date("2027-01-02T03:04:05.0Z"), expected);
+        assert_eq!(parse_expiration_date("2027-01-02T11:04:05+08:00"), expected);
+        assert_eq!(parse_expiration_date("2027-01-02 03:04:05"), expected);
+        assert_eq!(parse_expiration_date("2027-01-02 03:04:05 UTC"), expected);
+
+        let date_only = Utc.with_ymd_and_hms(2027, 1, 2, 0, 0, 0).single();
+        assert_eq!(parse_expiration_date("2027-01-02"), date_only);
+        assert_eq!(parse_expiration_date("02-Jan-2027"), date_only);
+        assert_eq!(parse_expiration_date("2027.01.02"), date_only);
+        assert_eq!(parse_expiration_date("2027/01/02"), date_only);
+        assert_eq!(parse_expiration_date("02.01.2027"), date_only);
+        assert_eq!(parse_expiration_date("not a date"), None);
+    }
+
+    #[test]
+    fn expiry_status_follows_thresholds() {
+        let now = Utc::now();
+        let thresholds = DomainExpiryThresholds::default();
+        let status = |days: i64| expiry_status(now + Duration::days(days), now, thresholds);
+
+        assert_eq!(status(-1), DomainExpiryStatus::Expired);
+        assert_eq!(status(7), DomainExpiryStatus::Critical);
+        assert_eq!(status(8), DomainExpiryStatus::Warning);
+        assert_eq!(status(30), DomainExpiryStatus::Warning);
+        assert_eq!(status(90), DomainExpiryStatus::Notice);
+        assert_eq!(status(91), DomainExpiryStatus::Ok);
+    }
+
+    #[test]
+    fn rejects_unordered_thresholds() {
+        let thresholds = DomainExpiryThresholds {
+            critical_days: 30,
+            warning_days: 7,
+            notice_days: 90,
+        };
+        assert!(validate_thresholds(thresholds).is_err());
+        assert!(validate_thresholds(DomainExpiryThresholds::default()).is_ok());
+    }
+
+    #[test]
+    fn flags_only_domains_entering_a_more_severe_tier() {
+        let thresholds = DomainExpiryThresholds::default();
+        let first_run = Utc::now();
+        let at = |days| Some(first_run + Duration::days(days));
+        let entries = vec![
+            entry("stable.com", at(20), first_run),
+            entry("closer.com", at(10), first_run),
+            entry("far.com", at(200), first_run),
+            entry("flaky.com", at(60), first_run),
+        ];
+        let (report, saved) =
+            build_report(entries, Vec::new(), Vec::new(), thresholds, first_run, 4);
+        assert_eq!(
+            names(&report.newly_flagged),
+            ["closer.com", "stable.com", "flaky.com"]
+        );
+
+        let second_run = first_run + Duration::days(5);
+        let entries = vec![
+            entry("stable.com", at(20), second_run),
+            entry("closer.com", at(10), second_run),
+            entry("far.com", at(200), second_run),
+            entry("flaky.com", None, second_run),
+        ];
+        let (report, saved) = build_report(entries, saved, Vec::new(), thresholds, second_run, 4);
+        // closer.com 剩余 5 天，从警告进入严重
+        assert_eq!(names(&report.newly_flagged), ["closer.com"]);
+        assert_eq!(names(&report.expiring), ["closer.com", "stable.com"]);
+        assert_eq!(names(&report.unknown), ["flaky.com"]);
+
+        // 查询失败时保留上次的到期时间作为比较基准
+        let flaky = saved.iter().find(|e| e.domain_name == "flaky.com");
+        assert!(flaky.is_some_and(|e| e.expires_at.is_some()));
+    }
+
+    fn checked(host: &str, days: Option<i64>) -> SslCheckResult {
+        SslCheckResult {
+            domain: host.to_string(),
+            unicode_domain: None,
+            port: 443,
+            connected_addr: None,
+            connection_status: if days.is_some() { "https" } else { "failed" }.to_string(),
+            cert_info: days.map(|days| crate::types::SslCertInfo {
+                domain: host.to_string(),
+                issuer: "CN=Test CA".to_string(),
+                subject: format!("CN={host}"),
+                valid_from: String::new(),
+                valid_to: (Utc::now() + Duration::days(days)).to_rfc2822(),
+                days_remaining: days,
+                is_expired: days < 0,
+                is_valid: days >= 0,
+                san: Vec::new(),
+                serial_number: String::new(),
+                signature_algorithm: String::new(),
+                certificate_chain: Vec::new(),
+                tls_version: None,
+                negotiated_cipher: None,
+                supports_tls10: false,
+                supports_tls11: false,
+                protocol_probe: None,
+                ocsp_status: None,
+                ocsp_stapled: false,
+            }),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn expiring_certificates_pairs_results_with_domains() {
+        let domains = ["a.com".to_string(), "b.com".to_string()];
+        let results = [
+            checked("a.com", Some(45)),
+            checked("www.a.com", Some(-2)),
+            checked("b.com", Some(12)),
+            checked("www.b.com", None),
+        ];
+
+        let expiring = expiring_certificates(&domains, &results, 30);
+        let hosts: Vec<(&str, &str)> = expiring
+            .iter()
+            .map(|e| (e.domain_name.as_str(), e.host.as_str()))
+            .collect();
+        assert_eq!(hosts, [("a.com", "www.a.com"), ("b.com", "b.com")]);
+        assert!(expiring[0].is_expired);
+    }
+
+    #[test]
+    fn earliest_certificate_expiry_takes_the_sooner_host() {
+        let domains = [
+            "a.com".to_string(),
+            "b.com".to_string(),
+            "c.com".to_string(),
+        ];
+        let results = [
+            checked("a.com", Some(45)),
+            checked("www.a.com", Some(10)),
+            checked("b.com", None),
+            checked("www.b.com", Some(20)),
+            checked("c.com", None),
+            checked("www.c.com", None),
+        ];
+
+        let earliest = earliest_certificate_expiry(&domains, &results);
+        let days = |name: &str| earliest.get(name).map(|at| days_remaining(*at, Utc::now()));
+        assert_eq!(days("a.com"), Some(10));
+        assert_eq!(days("b.com"), Some(20));
+        assert!(!earliest.contains_key("c.com"));
+    }
+}
@@ -1,12 +1,17 @@
 //! 域名管理服务
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::ProviderError;
+use chrono::{DateTime, Utc};
 
 use crate::error::{CoreError, CoreResult};
-use crate::services::{DomainMetadataService, ServiceContext};
-use crate::types::{AppDomain, DomainMetadataKey, PaginatedResponse, PaginationParams};
+use crate::services::{DomainMetadataService, OperationKind, ServiceContext};
+use crate::types::{
+    AppDomain, DomainDerivedStatus, DomainMetadataKey, PaginatedResponse, PaginationParams,
+};
+
+use super::credential_expiry_service::days_remaining;
 
 /// 域名管理服务
 pub struct DomainService {
@@ -34,7 +39,15 @@ impl DomainService {
             page_size: page_size.unwrap_or(20),
         };
 
-        match provider.list_domains(&params).await {
+        match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "list_domains",
+                provider.list_domains(&params),
+            )
+            .await
+        {
             Ok(lib_response) => {
                 let mut domains: Vec<AppDomain> = lib_response
                     .items
@@ -61,6 +74,8 @@ impl DomainService {
                     }
                 }
 
+                self.attach_derived_status(account_id, &mut domains).await;
+
                 Ok(PaginatedResponse::new(
                     domains,
                     lib_response.page,
@@ -76,7 +91,15 @@ impl DomainService {
     pub async fn get_domain(&self, account_id: &str, domain_id: &str) -> CoreResult<AppDomain> {
         let provider = self.ctx.get_provider(account_id).await?;
 
-        match provider.get_domain(domain_id).await {
+        match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
             Ok(provider_domain) => Ok(AppDomain::from_provider(
                 provider_domain,
                 account_id.to_string(),
@@ -85,13 +108,84 @@ impl DomainService {
         }
     }
 
-    /// 处理 Provider 错误，如果是凭证失效则更新账户状态
-    async fn handle_provider_error(&self, account_id: &str, err: ProviderError) -> CoreError {
-        if let ProviderError::InvalidCredentials { .. } = &err {
-            self.ctx
-                .mark_account_invalid(account_id, "凭证已失效")
-                .await;
+    /// 附加检查任务生成的派生状态，剩余天数按当前时间重算
+    ///
+    /// 未配置仓库或读取失败时不附加（只记录日志）。
+    async fn attach_derived_status(&self, account_id: &str, domains: &mut [AppDomain]) {
+        let Some(repository) = &self.ctx.domain_status_repository else {
+            return;
+        };
+        let mut statuses: HashMap<String, DomainDerivedStatus> =
+            match repository.find_by_account(account_id).await {
+                Ok(statuses) => statuses
+                    .into_iter()
+                    .map(|s| (s.domain_id.clone(), s))
+                    .collect(),
+                Err(e) => {
+                    log::warn!("Failed to load derived status of {account_id}: {e}");
+                    return;
+                }
+            };
+
+        let now = Utc::now();
+        let remaining = |at: Option<DateTime<Utc>>| at.map(|at| days_remaining(at, now));
+        for domain in domains {
+            domain.derived_status = statuses.remove(&domain.id).map(|mut status| {
+                status.expiry_days_remaining = remaining(status.expires_at);
+                status.certificate_days_remaining = remaining(status.certificate_expires_at);
+                status
+            });
         }
-        CoreError::Provider(err)
+    }
+
+    /// 处理 Provider 错误，见 [`ServiceContext::handle_provider_error`]
+    async fn handle_provider_error(&self, account_id: &str, err: CoreError) -> CoreError {
+        self.ctx.handle_provider_error(account_id, err).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{
+        in_memory_context, register_mock_account, InMemoryDomainStatusRepository, MockDnsProvider,
+    };
+    use crate::traits::DomainDerivedStatusRepository;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn list_domains_attaches_derived_status() -> CoreResult<()> {
+        let repository = Arc::new(InMemoryDomainStatusRepository::default());
+        let ctx = Arc::new(in_memory_context().with_domain_status(Arc::clone(&repository) as _));
+        let provider = Arc::new(
+            MockDnsProvider::new()
+                .with_domain("zone-1", "example.com")
+                .with_domain("zone-2", "example.org"),
+        );
+        register_mock_account(&ctx, "account-1", provider).await?;
+
+        // 保存时的剩余天数已过时，返回时按当前时间重算
+        let now = Utc::now();
+        let mut status = DomainDerivedStatus::new("account-1".to_string(), "zone-1".to_string());
+        status.expires_at = Some(now + Duration::days(20));
+        status.expiry_days_remaining = Some(30);
+        status.expiry_checked_at = Some(now - Duration::days(10));
+        repository.save_batch(&[status]).await?;
+
+        let page = DomainService::new(ctx)
+            .list_domains("account-1", None, None)
+            .await?;
+        let derived = page
+            .items
+            .iter()
+            .find(|d| d.id == "zone-1")
+            .and_then(|d| d.derived_status.as_ref());
+        assert_eq!(derived.and_then(|d| d.expiry_days_remaining), Some(20));
+        assert!(derived.is_some_and(|d| d.certificate_expires_at.is_none()));
+        assert!(page
+            .items
+            .iter()
+            .any(|d| d.id == "zone-2" && d.derived_status.is_none()));
+        Ok(())
     }
 }
@@ -1,18 +1,37 @@
 //! 账户导入导出服务
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
-use dns_orchestrator_provider::{create_provider, ProviderCredentials};
+use chrono::{DateTime, Utc};
+use dns_orchestrator_provider::{
+    create_provider, DnsProvider, ProviderCredentials, ProviderDomain, ProviderType,
+};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
 
 use crate::crypto;
 use crate::error::{CoreError, CoreResult};
-use crate::services::ServiceContext;
+use crate::services::credential_expiry_service::credentials_expired_message;
+use crate::services::{
+    paginate_all, OperationKind, Page, PageCursor, PaginateOptions, ServiceContext,
+};
 use crate::types::{
-    Account, AccountStatus, ExportAccountsRequest, ExportAccountsResponse, ExportFile,
-    ExportFileHeader, ExportedAccount, ImportAccountsRequest, ImportFailure, ImportPreview,
-    ImportPreviewAccount, ImportResult,
+    Account, AccountStatus, BackupAccount, BackupDomainMetadata, BackupPayload, DiagnosticsAccount,
+    DiagnosticsDomain, DiagnosticsReport, DnsRecord, ExportAccountsRequest, ExportAccountsResponse,
+    ExportDiagnosticsRequest, ExportDiagnosticsResponse, ExportFile, ExportFileHeader,
+    ExportedAccount, ImportAccountsRequest, ImportFailure, ImportPreview, ImportPreviewAccount,
+    ImportResult, NetworkEnvInfo, PaginationParams, RecordQueryParams, RestoreBackupResult,
 };
+use crate::utils::anonymize::Anonymizer;
+
+/// 当前完整备份内容格式版本
+///
+/// 备份内容结构变化时递增，恢复时拒绝高于此版本的备份。
+const BACKUP_VERSION: u32 = 1;
+
+/// 收集诊断数据时分页拉取的每页数量
+const DIAGNOSTICS_PAGE_SIZE: u32 = 100;
 
 /// 账户导入导出服务
 pub struct ImportExportService {
@@ -35,17 +54,10 @@ impl ImportExportService {
         let export_file: ExportFile = serde_json::from_str(content)
             .map_err(|e| CoreError::ImportExportError(format!("无效的导入文件: {e}")))?;
 
-        // 2. 检查文件格式版本并获取加密参数
-        let kdf_iterations = if export_file.header.encrypted {
-            crypto::get_pbkdf2_iterations(export_file.header.version).ok_or_else(|| {
-                CoreError::ImportExportError(format!(
-                    "不支持的文件版本: {}",
-                    export_file.header.version
-                ))
-            })?
-        } else {
-            0 // 未加密文件不需要迭代次数
-        };
+        // 2. 检查文件格式版本
+        if export_file.header.encrypted {
+            kdf_iterations(export_file.header.version)?;
+        }
 
         // 3. 如果加密但未提供密码，返回 None 表示需要密码
         if export_file.header.encrypted && password.is_none() {
@@ -56,34 +68,7 @@ impl ImportExportService {
         let accounts: Vec<ExportedAccount> = if export_file.header.encrypted {
             let password = password
                 .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
-
-            log::info!(
-                "解密版本 {} 的文件，使用 PBKDF2-HMAC-SHA256 ({} 次迭代)",
-                export_file.header.version,
-                kdf_iterations
-            );
-
-            let ciphertext = export_file
-                .data
-                .as_str()
-                .ok_or_else(|| CoreError::ImportExportError("无效的加密数据".to_string()))?;
-            let salt = export_file
-                .header
-                .salt
-                .as_ref()
-                .ok_or_else(|| CoreError::ImportExportError("缺少加密盐值".to_string()))?;
-            let nonce = export_file
-                .header
-                .nonce
-                .as_ref()
-                .ok_or_else(|| CoreError::ImportExportError("缺少加密 nonce".to_string()))?;
-
-            // 使用版本对应的迭代次数解密
-            let plaintext =
-                crypto::decrypt_with_iterations(ciphertext, password, salt, nonce, kdf_iterations)
-                    .map_err(|_| {
-                        CoreError::ImportExportError("解密失败，请检查密码是否正确".to_string())
-                    })?;
+            let plaintext = decrypt_export_data(&export_file, password)?;
 
             serde_json::from_slice(&plaintext)
                 .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?
@@ -119,80 +104,45 @@ impl ImportExportService {
         // 2. 加载凭证并构建导出数据
         let mut exported_accounts = Vec::new();
         for account in selected_accounts {
-            let credentials =
-                if let Some(creds) = self.ctx.credential_store.get(&account.id).await? {
-                    creds
-                } else {
-                    log::warn!("No credentials found for account: {}", account.id);
-                    continue;
-                };
+            let Some(credentials) = self.ctx.credential_store.get(&account.id).await? else {
+                log::warn!("No credentials found for account: {}", account.id);
+                continue;
+            };
 
-            // 转换 ProviderCredentials 为 HashMap
-            let credentials_map = credentials.to_map();
+            // 可复现导出保留原 ID 并省略时间戳；否则生成新 ID，避免导入时冲突
+            let (id, created_at, updated_at) = if request.reproducible {
+                (account.id.clone(), None, None)
+            } else {
+                (
+                    uuid::Uuid::new_v4().to_string(),
+                    Some(account.created_at),
+                    Some(account.updated_at),
+                )
+            };
 
             exported_accounts.push(ExportedAccount {
-                id: uuid::Uuid::new_v4().to_string(), // 生成新 ID，避免导入时冲突
+                id,
                 name: account.name.clone(),
                 provider: account.provider.clone(),
-                created_at: account.created_at,
-                updated_at: account.updated_at,
-                credentials: credentials_map,
+                created_at,
+                updated_at,
+                credentials: credentials.to_map().into_iter().collect(),
+                credentials_expires_at: account.credentials_expires_at,
             });
         }
 
-        // 3. 序列化账号数据
-        let accounts_json = serde_json::to_value(&exported_accounts)
-            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
-
-        // 4. 构建导出文件
-        let now = chrono::Utc::now();
+        // 3. 生成文件内容
+        let content = render_export_file(exported_accounts, &request, app_version, Utc::now())?;
 
-        let export_file = if request.encrypt {
-            let password = request
-                .password
-                .as_ref()
-                .ok_or_else(|| CoreError::ValidationError("加密导出需要提供密码".to_string()))?;
-
-            let plaintext = serde_json::to_vec(&accounts_json)
-                .map_err(|e| CoreError::SerializationError(e.to_string()))?;
-
-            let (salt, nonce, ciphertext) = crypto::encrypt(&plaintext, password)
-                .map_err(|e| CoreError::ImportExportError(e.to_string()))?;
-
-            ExportFile {
-                header: ExportFileHeader {
-                    version: crypto::CURRENT_FILE_VERSION,
-                    encrypted: true,
-                    salt: Some(salt),
-                    nonce: Some(nonce),
-                    exported_at: now.to_rfc3339(),
-                    app_version: app_version.to_string(),
-                },
-                data: serde_json::Value::String(ciphertext),
-            }
+        let suggested_filename = if request.reproducible {
+            "dns-orchestrator-backup.dnso".to_string()
         } else {
-            ExportFile {
-                header: ExportFileHeader {
-                    version: crypto::CURRENT_FILE_VERSION,
-                    encrypted: false,
-                    salt: None,
-                    nonce: None,
-                    exported_at: now.to_rfc3339(),
-                    app_version: app_version.to_string(),
-                },
-                data: accounts_json,
-            }
+            format!(
+                "dns-orchestrator-backup-{}.dnso",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            )
         };
 
-        // 5. 生成文件内容
-        let content = serde_json::to_string_pretty(&export_file)
-            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
-
-        let suggested_filename = format!(
-            "dns-orchestrator-backup-{}.dnso",
-            chrono::Local::now().format("%Y%m%d-%H%M%S")
-        );
-
         Ok(ExportAccountsResponse {
             content,
             suggested_filename,
@@ -239,6 +189,9 @@ impl ImportExportService {
     }
 
     /// 执行导入
+    ///
+    /// 与已有账户（或同一文件中先导入的账户）服务商与凭证都相同的账户视为重复，跳过不导入；
+    /// 仅名称相同的账户照常导入。
     pub async fn import_accounts(
         &self,
         request: ImportAccountsRequest,
@@ -251,23 +204,33 @@ impl ImportExportService {
             .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
 
         // 2. 逐个导入账号
+        let mut known = self.known_credentials().await?;
         let mut success_count = 0;
+        let mut skipped_count = 0;
         let mut failures = Vec::new();
         let now = chrono::Utc::now();
 
         for exported in accounts {
             // 2.1 转换凭证并创建 provider 实例
-            let credentials =
-                match ProviderCredentials::from_map(&exported.provider, &exported.credentials) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        failures.push(ImportFailure {
-                            name: exported.name.clone(),
-                            reason: format!("凭证格式错误: {e}"),
-                        });
-                        continue;
-                    }
-                };
+            let credentials = match ProviderCredentials::from_map(
+                &exported.provider,
+                &exported.credentials.clone().into_iter().collect(),
+            ) {
+                Ok(c) => c,
+                Err(e) => {
+                    failures.push(ImportFailure {
+                        name: exported.name.clone(),
+                        reason: format!("凭证格式错误: {e}"),
+                    });
+                    continue;
+                }
+            };
+            let fingerprint = (exported.provider.clone(), credentials.to_map());
+            if known.contains(&fingerprint) {
+                log::info!("Skipping duplicate account on import: {}", exported.name);
+                skipped_count += 1;
+                continue;
+            }
             let provider = match create_provider(credentials.clone()) {
                 Ok(p) => p,
                 Err(e) => {
@@ -303,7 +266,7 @@ impl ImportExportService {
                 .await;
 
             // 2.5 创建账号元数据
-            let account = Account {
+            let mut account = Account {
                 id: account_id.clone(),
                 name: exported.name.clone(),
                 provider: exported.provider,
@@ -311,7 +274,15 @@ impl ImportExportService {
                 updated_at: now,
                 status: Some(AccountStatus::Active),
                 error: None,
+                credentials_expires_at: exported.credentials_expires_at,
+                expiry_reminder_days: None,
+                credential_status: None,
+                last_validated_at: None,
             };
+            if let Some(expired_at) = account.credentials_expired_at(now) {
+                account.status = Some(AccountStatus::CredentialsExpired);
+                account.error = Some(credentials_expired_message(expired_at));
+            }
 
             // 2.6 保存到仓库，失败时 cleanup
             if let Err(e) = self.ctx.account_repository.save(&account).await {
@@ -326,12 +297,946 @@ impl ImportExportService {
                 continue;
             }
 
+            known.push(fingerprint);
             success_count += 1;
         }
 
         Ok(ImportResult {
             success_count,
+            skipped_count,
             failures,
         })
     }
+
+    /// 已有账户的服务商与凭证，用于导入时识别重复账户（读取凭证失败的账户忽略）
+    async fn known_credentials(&self) -> CoreResult<Vec<(ProviderType, HashMap<String, String>)>> {
+        let mut known = Vec::new();
+        for account in self.ctx.account_repository.find_all().await? {
+            match self.ctx.credential_store.get(&account.id).await {
+                Ok(Some(credentials)) => known.push((account.provider, credentials.to_map())),
+                Ok(None) => {}
+                Err(e) => log::warn!("Failed to load credentials for {}: {e}", account.id),
+            }
+        }
+        Ok(known)
+    }
+
+    /// 导出完整备份（全部账户、凭证与域名元数据）
+    ///
+    /// 备份内容整体用 `password` 加密，文件结构与账户导出文件相同，
+    /// 账户保留原 ID 以便恢复时按 ID 覆盖。
+    pub async fn export_backup(
+        &self,
+        password: &str,
+        app_version: &str,
+    ) -> CoreResult<ExportAccountsResponse> {
+        if password.is_empty() {
+            return Err(CoreError::ValidationError("备份需要提供密码".to_string()));
+        }
+
+        let mut accounts = self.ctx.account_repository.find_all().await?;
+        accounts.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut backup_accounts = Vec::with_capacity(accounts.len());
+        let mut domain_metadata = Vec::new();
+        for account in accounts {
+            let Some(credentials) = self.ctx.credential_store.get(&account.id).await? else {
+                log::warn!("[Backup] No credentials found for account: {}", account.id);
+                continue;
+            };
+            let mut entries = self
+                .ctx
+                .domain_metadata_repository
+                .find_by_account(&account.id)
+                .await?;
+            entries.sort_by(|(a, _), (b, _)| a.domain_id.cmp(&b.domain_id));
+            domain_metadata.extend(
+                entries
+                    .into_iter()
+                    .map(|(key, metadata)| BackupDomainMetadata { key, metadata }),
+            );
+            backup_accounts.push(BackupAccount {
+                account,
+                credentials: credentials.to_map().into_iter().collect(),
+            });
+        }
+
+        let now = Utc::now();
+        let payload = BackupPayload {
+            backup_version: BACKUP_VERSION,
+            created_at: now,
+            accounts: backup_accounts,
+            domain_metadata,
+        };
+        let plaintext = serde_json::to_vec(&payload)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        let content = render_encrypted_file(&plaintext, password, app_version, now)?;
+
+        Ok(ExportAccountsResponse {
+            content,
+            suggested_filename: format!(
+                "dns-orchestrator-full-backup-{}.dnso",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ),
+        })
+    }
+
+    /// 导出诊断数据（账户、域名与记录，不含凭证），用于提交问题时复现
+    ///
+    /// 账户或域名收集失败时记录错误现场并继续，不修改账户状态。`network_env` 由宿主通过
+    /// [`ToolboxService::network_env_info`](crate::services::ToolboxService::network_env_info)
+    /// 探测后传入（遵循隐私模式）。`anonymize` 为 true 时报告经 [`Anonymizer`] 替换为假名；
+    /// 同时提供 `mapping_password` 时返回加密的映射表，由用户在本地单独保存以便对照。
+    pub async fn export_diagnostics(
+        &self,
+        request: ExportDiagnosticsRequest,
+        app_version: &str,
+        network_env: Option<NetworkEnvInfo>,
+    ) -> CoreResult<ExportDiagnosticsResponse> {
+        if request.anonymize && request.mapping_password.as_deref() == Some("") {
+            return Err(CoreError::ValidationError(
+                "映射表加密密码不能为空".to_string(),
+            ));
+        }
+
+        let mut accounts = self.ctx.account_repository.find_all().await?;
+        accounts.retain(|a| request.account_ids.contains(&a.id));
+        if accounts.is_empty() {
+            return Err(CoreError::NoAccountsSelected);
+        }
+        accounts.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+        let mut collected = Vec::with_capacity(accounts.len());
+        for account in &accounts {
+            collected.push(self.collect_diagnostics(account).await);
+        }
+
+        let now = Utc::now();
+        let mut report = DiagnosticsReport {
+            app_version: app_version.to_string(),
+            generated_at: now,
+            anonymized: false,
+            accounts: collected,
+            network_env,
+        };
+        let mut mapping = None;
+        if request.anonymize {
+            let mut anonymizer = Anonymizer::new();
+            report = anonymizer.report(&report);
+            if let Some(password) = &request.mapping_password {
+                let plaintext = serde_json::to_vec(&anonymizer.into_mapping())
+                    .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+                mapping = Some(render_encrypted_file(
+                    &plaintext,
+                    password,
+                    app_version,
+                    now,
+                )?);
+            }
+        }
+
+        let content = serde_json::to_string_pretty(&report)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        log::info!(
+            "[Diagnostics] Exported {} accounts (anonymized: {})",
+            report.accounts.len(),
+            report.anonymized
+        );
+        Ok(ExportDiagnosticsResponse {
+            content,
+            suggested_filename: format!("dns-orchestrator-diagnostics-{stamp}.json"),
+            mapping_filename: mapping
+                .is_some()
+                .then(|| format!("dns-orchestrator-diagnostics-{stamp}-mapping.dnso")),
+            mapping,
+        })
+    }
+
+    /// 收集单个账户的域名与记录，失败时把错误写入报告
+    async fn collect_diagnostics(&self, account: &Account) -> DiagnosticsAccount {
+        let mut diagnostics = DiagnosticsAccount {
+            id: account.id.clone(),
+            name: account.name.clone(),
+            provider: account.provider.clone(),
+            status: account.status.clone(),
+            error: account.error.clone(),
+            collect_error: None,
+            domains: Vec::new(),
+        };
+
+        let domains = match self.ctx.get_provider(&account.id).await {
+            Ok(provider) => self
+                .list_all_domains(&provider)
+                .await
+                .map(|d| (provider, d)),
+            Err(e) => Err(e),
+        };
+        let (provider, domains) = match domains {
+            Ok(result) => result,
+            Err(e) => {
+                diagnostics.collect_error = Some(error_value(&e));
+                return diagnostics;
+            }
+        };
+
+        for domain in domains {
+            let (records, collect_error) = match self.list_all_records(&provider, &domain.id).await
+            {
+                Ok(records) => (records, None),
+                Err(e) => (Vec::new(), Some(error_value(&e))),
+            };
+            let mut record_types = BTreeMap::new();
+            for record in &records {
+                *record_types
+                    .entry(format!("{:?}", record.data.record_type()).to_uppercase())
+                    .or_insert(0) += 1;
+            }
+            diagnostics.domains.push(DiagnosticsDomain {
+                id: domain.id,
+                name: domain.name,
+                status: domain.status,
+                record_count: records.len(),
+                record_types,
+                records,
+                collect_error,
+            });
+        }
+        diagnostics
+    }
+
+    async fn list_all_domains(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+    ) -> CoreResult<Vec<ProviderDomain>> {
+        paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = PaginationParams {
+                page: cursor.page_number(),
+                page_size: DIAGNOSTICS_PAGE_SIZE,
+            };
+            async move {
+                self.ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_domains",
+                        provider.list_domains(&params),
+                    )
+                    .await
+                    .map(Page::from)
+            }
+        })
+        .try_collect()
+        .await
+    }
+
+    async fn list_all_records(
+        &self,
+        provider: &Arc<dyn DnsProvider>,
+        domain_id: &str,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size: DIAGNOSTICS_PAGE_SIZE,
+                keyword: None,
+                record_type: None,
+                sort: None,
+            };
+            async move {
+                self.ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_records",
+                        provider.list_records(domain_id, &params),
+                    )
+                    .await
+                    .map(Page::from)
+            }
+        })
+        .try_collect()
+        .await
+    }
+
+    /// 从完整备份恢复
+    ///
+    /// 按账户 ID 覆盖已有账户、新建缺失账户，再写入域名元数据；
+    /// 同一备份重复恢复得到相同结果。恢复失败的账户跳过其元数据。
+    pub async fn import_backup(
+        &self,
+        content: &str,
+        password: &str,
+    ) -> CoreResult<RestoreBackupResult> {
+        let export_file: ExportFile = serde_json::from_str(content)
+            .map_err(|e| CoreError::ImportExportError(format!("无效的备份文件: {e}")))?;
+        if !export_file.header.encrypted {
+            return Err(CoreError::ImportExportError(
+                "备份文件必须是加密文件".to_string(),
+            ));
+        }
+        let plaintext = decrypt_export_data(&export_file, password)?;
+        let payload = parse_backup_payload(&plaintext)?;
+
+        let now = Utc::now();
+        let mut result = RestoreBackupResult {
+            created_accounts: 0,
+            updated_accounts: 0,
+            restored_metadata: 0,
+            failures: Vec::new(),
+        };
+        let mut restored_ids = HashSet::new();
+
+        for BackupAccount {
+            mut account,
+            credentials,
+        } in payload.accounts
+        {
+            let credentials = match ProviderCredentials::from_map(
+                &account.provider,
+                &credentials.into_iter().collect(),
+            ) {
+                Ok(c) => c,
+                Err(e) => {
+                    result.failures.push(ImportFailure {
+                        name: account.name,
+                        reason: format!("凭证格式错误: {e}"),
+                    });
+                    continue;
+                }
+            };
+            let provider = match create_provider(credentials.clone()) {
+                Ok(p) => p,
+                Err(e) => {
+                    result.failures.push(ImportFailure {
+                        name: account.name,
+                        reason: format!("创建 Provider 失败: {e}"),
+                    });
+                    continue;
+                }
+            };
+
+            let existed = self
+                .ctx
+                .account_repository
+                .find_by_id(&account.id)
+                .await?
+                .is_some();
+
+            if let Err(e) = self
+                .ctx
+                .credential_store
+                .set(&account.id, &credentials)
+                .await
+            {
+                result.failures.push(ImportFailure {
+                    name: account.name,
+                    reason: format!("保存凭证失败: {e}"),
+                });
+                continue;
+            }
+
+            // 运行时状态不随备份恢复，按凭证到期时间重新判定
+            account.status = Some(AccountStatus::Active);
+            account.error = None;
+            if let Some(expired_at) = account.credentials_expired_at(now) {
+                account.status = Some(AccountStatus::CredentialsExpired);
+                account.error = Some(credentials_expired_message(expired_at));
+            }
+
+            if let Err(e) = self.ctx.account_repository.save(&account).await {
+                // 新建的账户回滚凭证；已有账户的凭证已被覆盖，保留以免账户失去凭证
+                if !existed {
+                    let _ = self.ctx.credential_store.remove(&account.id).await;
+                }
+                result.failures.push(ImportFailure {
+                    name: account.name,
+                    reason: format!("保存账户失败: {e}"),
+                });
+                continue;
+            }
+
+            self.ctx
+                .provider_registry
+                .register(account.id.clone(), provider)
+                .await;
+            if existed {
+                result.updated_accounts += 1;
+            } else {
+                result.created_accounts += 1;
+            }
+            restored_ids.insert(account.id);
+        }
+
+        let entries: Vec<_> = payload
+            .domain_metadata
+            .into_iter()
+            .filter(|entry| restored_ids.contains(&entry.key.account_id))
+            .map(|entry| (entry.key, entry.metadata))
+            .collect();
+        self.ctx
+            .domain_metadata_repository
+            .batch_save(&entries)
+            .await?;
+        result.restored_metadata = entries.len();
+
+        log::info!(
+            "[Backup] Restored {} new and {} existing accounts, {} metadata entries",
+            result.created_accounts,
+            result.updated_accounts,
+            result.restored_metadata
+        );
+        Ok(result)
+    }
+}
+
+/// 解析备份明文并校验备份内容版本
+fn parse_backup_payload(plaintext: &[u8]) -> CoreResult<BackupPayload> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct VersionProbe {
+        backup_version: u32,
+    }
+
+    // 先只读版本号，避免新版本格式变化时报出难以理解的解析错误
+    let probe: VersionProbe = serde_json::from_slice(plaintext)
+        .map_err(|e| CoreError::ImportExportError(format!("无效的备份内容: {e}")))?;
+    if probe.backup_version == 0 || probe.backup_version > BACKUP_VERSION {
+        return Err(CoreError::ImportExportError(format!(
+            "不支持的备份版本: {}",
+            probe.backup_version
+        )));
+    }
+    serde_json::from_slice(plaintext)
+        .map_err(|e| CoreError::ImportExportError(format!("解析备份内容失败: {e}")))
+}
+
+/// 文件格式版本对应的 PBKDF2 迭代次数，不支持的版本返回错误
+fn kdf_iterations(version: u32) -> CoreResult<u32> {
+    crypto::get_pbkdf2_iterations(version)
+        .ok_or_else(|| CoreError::ImportExportError(format!("不支持的文件版本: {version}")))
+}
+
+/// 解密导出文件的 `data` 字段
+fn decrypt_export_data(export_file: &ExportFile, password: &str) -> CoreResult<Vec<u8>> {
+    let version = export_file.header.version;
+    let iterations = kdf_iterations(version)?;
+    if crypto::has_kdf_prefix(version) {
+        log::info!("解密版本 {version} 的文件，按密文前缀选择密钥派生算法");
+    } else {
+        log::info!("解密版本 {version} 的文件，使用 PBKDF2-HMAC-SHA256 ({iterations} 次迭代)");
+    }
+
+    let ciphertext = export_file
+        .data
+        .as_str()
+        .ok_or_else(|| CoreError::ImportExportError("无效的加密数据".to_string()))?;
+    let salt = export_file
+        .header
+        .salt
+        .as_ref()
+        .ok_or_else(|| CoreError::ImportExportError("缺少加密盐值".to_string()))?;
+    let nonce = export_file
+        .header
+        .nonce
+        .as_ref()
+        .ok_or_else(|| CoreError::ImportExportError("缺少加密 nonce".to_string()))?;
+
+    // 带 KDF 前缀的版本由前缀决定算法，旧版本使用版本对应的迭代次数解密
+    let decrypted = if crypto::has_kdf_prefix(version) {
+        crypto::decrypt(ciphertext, password, salt, nonce)
+    } else {
+        crypto::decrypt_with_iterations(ciphertext, password, salt, nonce, iterations)
+    };
+    decrypted.map_err(|_| CoreError::ImportExportError("解密失败，请检查密码是否正确".to_string()))
+}
+
+/// 导出文件的序列化视图
+///
+/// 直接序列化强类型数据而不经过 `serde_json::Value`：`Value` 的键顺序取决于
+/// `serde_json` 是否启用 `preserve_order`（由依赖树的 feature 合并决定），
+/// 会导致不同构建之间输出不一致。
+#[derive(Serialize)]
+struct ExportFileView<'a> {
+    header: ExportFileHeader,
+    data: ExportDataView<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ExportDataView<'a> {
+    Plain(&'a [ExportedAccount]),
+    Encrypted(String),
+}
+
+/// 生成整体加密的导出文件（完整备份、诊断映射表），文件结构与账户导出文件相同
+fn render_encrypted_file(
+    plaintext: &[u8],
+    password: &str,
+    app_version: &str,
+    now: DateTime<Utc>,
+) -> CoreResult<String> {
+    let (salt, nonce, ciphertext) = crypto::encrypt(plaintext, password)
+        .map_err(|e| CoreError::ImportExportError(e.to_string()))?;
+    let header = ExportFileHeader {
+        version: crypto::CURRENT_FILE_VERSION,
+        encrypted: true,
+        salt: Some(salt),
+        nonce: Some(nonce),
+        exported_at: Some(now.to_rfc3339()),
+        app_version: app_version.to_string(),
+    };
+    serde_json::to_string_pretty(&ExportFileView {
+        header,
+        data: ExportDataView::Encrypted(ciphertext),
+    })
+    .map_err(|e| CoreError::SerializationError(e.to_string()))
+}
+
+/// 错误按 `{ code, details }` 序列化，保留排障所需的错误现场
+fn error_value(error: &CoreError) -> serde_json::Value {
+    serde_json::to_value(error).unwrap_or_else(|_| serde_json::Value::String(error.to_string()))
+}
+
+/// 生成导出文件内容
+///
+/// 账户按名称排序（同名按创建时间、ID），保证同样的数据得到同样的账户顺序。
+/// 可复现模式下不写入导出时间。
+fn render_export_file(
+    mut accounts: Vec<ExportedAccount>,
+    request: &ExportAccountsRequest,
+    app_version: &str,
+    now: DateTime<Utc>,
+) -> CoreResult<String> {
+    accounts.sort_by(|a, b| {
+        a.name
+            .cmp(&b.name)
+            .then_with(|| a.created_at.cmp(&b.created_at))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut header = ExportFileHeader {
+        version: crypto::CURRENT_FILE_VERSION,
+        encrypted: request.encrypt,
+        salt: None,
+        nonce: None,
+        exported_at: (!request.reproducible).then(|| now.to_rfc3339()),
+        app_version: app_version.to_string(),
+    };
+
+    let data = if request.encrypt {
+        let password = request
+            .password
+            .as_ref()
+            .ok_or_else(|| CoreError::ValidationError("加密导出需要提供密码".to_string()))?;
+
+        let plaintext = serde_json::to_vec(&accounts)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        let (salt, nonce, ciphertext) = crypto::encrypt(&plaintext, password)
+            .map_err(|e| CoreError::ImportExportError(e.to_string()))?;
+        header.salt = Some(salt);
+        header.nonce = Some(nonce);
+        ExportDataView::Encrypted(ciphertext)
+    } else {
+        ExportDataView::Plain(&accounts)
+    };
+
+    serde_json::to_string_pretty(&ExportFileView { header, data })
+        .map_err(|e| CoreError::SerializationError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+    use crate::types::{
+        DnsHijackCheck, DomainMetadata, DomainMetadataKey, ProxySettings, RecordData,
+    };
+    use dns_orchestrator_provider::ProviderType;
+
+    fn account(id: &str, name: &str, credentials: &[(&str, &str)]) -> ExportedAccount {
+        ExportedAccount {
+            id: id.to_string(),
+            name: name.to_string(),
+            provider: ProviderType::Aliyun,
+            created_at: None,
+            updated_at: None,
+            credentials: credentials
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect(),
+            credentials_expires_at: None,
+        }
+    }
+
+    fn request(encrypt: bool) -> ExportAccountsRequest {
+        ExportAccountsRequest {
+            account_ids: Vec::new(),
+            encrypt,
+            password: encrypt.then(|| "correct horse".to_string()),
+            reproducible: true,
+        }
+    }
+
+    fn render(accounts: Vec<ExportedAccount>) -> CoreResult<String> {
+        render_export_file(accounts, &request(false), "1.8.0", Utc::now())
+    }
+
+    #[test]
+    fn test_reproducible_export_is_byte_identical() -> CoreResult<()> {
+        let first = render(vec![
+            account(
+                "b",
+                "prod",
+                &[("accessKeyId", "AK"), ("accessKeySecret", "SK")],
+            ),
+            account(
+                "a",
+                "dev",
+                &[("accessKeySecret", "SK2"), ("accessKeyId", "AK2")],
+            ),
+        ])?;
+        let second = render(vec![
+            account(
+                "a",
+                "dev",
+                &[("accessKeyId", "AK2"), ("accessKeySecret", "SK2")],
+            ),
+            account(
+                "b",
+                "prod",
+                &[("accessKeySecret", "SK"), ("accessKeyId", "AK")],
+            ),
+        ])?;
+
+        assert_eq!(first, second);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reproducible_export_golden_output() -> CoreResult<()> {
+        // 固定输出，防止字段顺序、换行或缩进随平台/依赖变化
+        let content = render(vec![account("a", "dev", &[("b", "2"), ("a", "1")])])?;
+        let expected = format!(
+            r#"{{
+  "header": {{
+    "version": {},
+    "encrypted": false,
+    "appVersion": "1.8.0"
+  }},
+  "data": [
+    {{
+      "id": "a",
+      "name": "dev",
+      "provider": "aliyun",
+      "credentials": {{
+        "a": "1",
+        "b": "2"
+      }}
+    }}
+  ]
+}}"#,
+            crypto::CURRENT_FILE_VERSION
+        );
+
+        assert_eq!(content, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reproducible_encrypted_plaintext_is_identical() -> CoreResult<()> {
+        let decrypt = |content: &str| -> CoreResult<(Option<String>, Vec<u8>)> {
+            let file: ExportFile = serde_json::from_str(content)
+                .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+            let ciphertext = file.data.as_str().unwrap_or_default();
+            let salt = file.header.salt.as_deref().unwrap_or_default();
+            let nonce = file.header.nonce.as_deref().unwrap_or_default();
+            crypto::decrypt(ciphertext, "correct horse", salt, nonce)
+                .map(|plaintext| (file.header.salt.clone(), plaintext))
+                .map_err(|e| CoreError::ImportExportError(e.to_string()))
+        };
+
+        let accounts = vec![
+            account("b", "prod", &[("accessKeyId", "AK")]),
+            account("a", "dev", &[("accessKeyId", "AK2")]),
+        ];
+        let mut reversed = accounts.clone();
+        reversed.reverse();
+
+        let (first_salt, first) = decrypt(&render_export_file(
+            accounts,
+            &request(true),
+            "1.8.0",
+            Utc::now(),
+        )?)?;
+        let (second_salt, second) = decrypt(&render_export_file(
+            reversed,
+            &request(true),
+            "1.8.0",
+            Utc::now(),
+        )?)?;
+
+        assert_ne!(first_salt, second_salt);
+        assert_eq!(first, second);
+        Ok(())
+    }
+
+    #[test]
+    fn test_legacy_export_still_parses() -> CoreResult<()> {
+        let content = r#"{
+            "header": {"version": 1, "encrypted": false, "exportedAt": "2024-01-01T00:00:00+00:00", "appVersion": "1.0.0"},
+            "data": [{"id": "x", "name": "old", "provider": "aliyun",
+                      "createdAt": "2024-01-01T00:00:00Z", "updatedAt": "2024-01-01T00:00:00Z",
+                      "credentials": {"accessKeyId": "AK", "accessKeySecret": "SK"}}]
+        }"#;
+
+        let (file, accounts) = ImportExportService::parse_and_decrypt_accounts(content, None)?;
+        let accounts = accounts.unwrap_or_default();
+        assert_eq!(
+            file.header.exported_at.as_deref(),
+            Some("2024-01-01T00:00:00+00:00")
+        );
+        assert_eq!(accounts.len(), 1);
+        assert!(accounts[0].created_at.is_some());
+        Ok(())
+    }
+
+    async fn seeded_context() -> CoreResult<Arc<ServiceContext>> {
+        let ctx = Arc::new(in_memory_context());
+        register_mock_account(&ctx, "acc", Arc::new(MockDnsProvider::new())).await?;
+        ctx.credential_store
+            .set(
+                "acc",
+                &ProviderCredentials::Cloudflare {
+                    api_token: "token".to_string(),
+                },
+            )
+            .await?;
+        let metadata = DomainMetadata {
+            is_favorite: true,
+            tags: vec!["prod".to_string()],
+            ..DomainMetadata::default()
+        };
+        ctx.domain_metadata_repository
+            .save(
+                &DomainMetadataKey::new("acc".to_string(), "zone-1".to_string()),
+                &metadata,
+            )
+            .await?;
+        Ok(ctx)
+    }
+
+    #[tokio::test]
+    async fn test_import_skips_duplicate_accounts() -> CoreResult<()> {
+        let ctx = seeded_context().await?;
+        let service = ImportExportService::new(Arc::clone(&ctx));
+        let exported = service
+            .export_accounts(
+                ExportAccountsRequest {
+                    account_ids: vec!["acc".to_string()],
+                    ..request(true)
+                },
+                "1.8.0",
+            )
+            .await?;
+        let import = ImportAccountsRequest {
+            content: exported.content,
+            password: Some("correct horse".to_string()),
+        };
+
+        // 凭证与已有账户相同：跳过
+        let result = service.import_accounts(import.clone()).await?;
+        assert_eq!(result.success_count, 0);
+        assert_eq!(result.skipped_count, 1);
+
+        // 导入到新环境后再次导入：第二次全部跳过
+        let target = ImportExportService::new(Arc::new(in_memory_context()));
+        let first = target.import_accounts(import.clone()).await?;
+        assert_eq!((first.success_count, first.skipped_count), (1, 0));
+        let second = target.import_accounts(import).await?;
+        assert_eq!((second.success_count, second.skipped_count), (0, 1));
+        assert!(second.failures.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_backup_restore_is_idempotent() -> CoreResult<()> {
+        let source = ImportExportService::new(seeded_context().await?);
+        let backup = source.export_backup("backup pw", "1.8.0").await?;
+
+        let target_ctx = Arc::new(in_memory_context());
+        let target = ImportExportService::new(Arc::clone(&target_ctx));
+        let first = target.import_backup(&backup.content, "backup pw").await?;
+        assert_eq!(first.created_accounts, 1);
+        assert_eq!(first.restored_metadata, 1);
+        assert!(first.failures.is_empty());
+
+        let second = target.import_backup(&backup.content, "backup pw").await?;
+        assert_eq!(second.created_accounts, 0);
+        assert_eq!(second.updated_accounts, 1);
+
+        let accounts = target_ctx.account_repository.find_all().await?;
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].id, "acc");
+        assert!(target_ctx.credential_store.get("acc").await?.is_some());
+        assert!(target_ctx.provider_registry.get("acc").await.is_some());
+        let favorites = target_ctx
+            .domain_metadata_repository
+            .find_favorites_by_account("acc")
+            .await?;
+        assert_eq!(favorites.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_wrong_password_and_unknown_version() -> CoreResult<()> {
+        let service = ImportExportService::new(seeded_context().await?);
+        let backup = service.export_backup("backup pw", "1.8.0").await?;
+        assert!(matches!(
+            service.import_backup(&backup.content, "wrong").await,
+            Err(CoreError::ImportExportError(_))
+        ));
+
+        let future = format!(r#"{{"backupVersion": {}}}"#, BACKUP_VERSION + 1);
+        assert!(matches!(
+            parse_backup_payload(future.as_bytes()),
+            Err(CoreError::ImportExportError(message)) if message.contains("不支持的备份版本")
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_anonymized_diagnostics_leak_no_original_values() -> CoreResult<()> {
+        let ctx = Arc::new(in_memory_context());
+        let provider = MockDnsProvider::new()
+            .with_domain("zone-acme", "acme-payroll.com")
+            .with_record(
+                "zone-acme",
+                "@",
+                600,
+                RecordData::A {
+                    address: "93.184.216.34".to_string(),
+                },
+            )
+            .with_record(
+                "zone-acme",
+                "vpn",
+                600,
+                RecordData::AAAA {
+                    address: "2606:2800:220:1:248:1893:25c8:1946".to_string(),
+                },
+            )
+            .with_record(
+                "zone-acme",
+                "www",
+                600,
+                RecordData::CNAME {
+                    target: "vpn.acme-payroll.com".to_string(),
+                },
+            )
+            .with_record(
+                "zone-acme",
+                "@",
+                600,
+                RecordData::MX {
+                    priority: 10,
+                    exchange: "mail.acme-payroll.com".to_string(),
+                },
+            )
+            .with_record(
+                "zone-acme",
+                "@",
+                600,
+                RecordData::TXT {
+                    text: "v=spf1 ip4:93.184.216.34 include:acme-payroll.com -all".to_string(),
+                },
+            );
+        let mut account = register_mock_account(&ctx, "acc-prod", Arc::new(provider)).await?;
+        account.error = Some("Failed to reach 93.184.216.34 for www.acme-payroll.com".to_string());
+        ctx.account_repository.save(&account).await?;
+        // 未注册 Provider 的账户收集失败，错误现场写入报告
+        ctx.account_repository
+            .save(&Account {
+                id: "acc-broken".to_string(),
+                name: "Payroll Backup".to_string(),
+                error: None,
+                ..account.clone()
+            })
+            .await?;
+
+        let service = ImportExportService::new(Arc::clone(&ctx));
+        let exported = service
+            .export_diagnostics(
+                ExportDiagnosticsRequest {
+                    account_ids: vec!["acc-prod".to_string(), "acc-broken".to_string()],
+                    anonymize: true,
+                    mapping_password: Some("map pw".to_string()),
+                },
+                "1.8.0",
+                Some(NetworkEnvInfo {
+                    system_dns_servers: vec!["10.20.30.40".to_string()],
+                    dns_source: "system".to_string(),
+                    dns_hijack: DnsHijackCheck {
+                        probe_domain: "dnso-probe-test.com".to_string(),
+                        hijacked: Some(true),
+                        answers: vec!["100.64.7.8".to_string()],
+                        error: None,
+                    },
+                    public_ipv4: Some("81.2.69.160".to_string()),
+                    public_ipv6: None,
+                    proxy: ProxySettings {
+                        https_proxy: Some("http://proxy.acme-payroll.com:3128".to_string()),
+                        ..ProxySettings::default()
+                    },
+                    elapsed_ms: 12,
+                }),
+            )
+            .await?;
+
+        let content = exported.content.to_lowercase();
+        for original in [
+            "acme-payroll",
+            "93.184.216.34",
+            "2606:2800",
+            "acc-prod",
+            "acc-broken",
+            "payroll backup",
+            "zone-acme",
+            "v=spf1",
+            "10.20.30.40",
+            "100.64.7.8",
+            "81.2.69.160",
+        ] {
+            assert!(!content.contains(original), "diagnostics leak {original}");
+        }
+
+        // 结构、数量、类型分布与错误现场保留
+        let report: DiagnosticsReport = serde_json::from_str(&exported.content)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        assert!(report.anonymized);
+        assert_eq!(report.accounts.len(), 2);
+        let domains: Vec<&DiagnosticsDomain> = report
+            .accounts
+            .iter()
+            .flat_map(|account| &account.domains)
+            .collect();
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[0].record_count, 5);
+        assert_eq!(domains[0].record_types.get("MX"), Some(&1));
+        assert!(report
+            .accounts
+            .iter()
+            .any(|account| account.collect_error.is_some()));
+
+        // 映射表单独加密，用密码解密后可对照原值
+        let mapping = exported
+            .mapping
+            .ok_or_else(|| CoreError::ImportExportError("missing mapping".to_string()))?;
+        assert!(!mapping.contains("acme-payroll"));
+        let file: ExportFile = serde_json::from_str(&mapping)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        let plaintext = decrypt_export_data(&file, "map pw")?;
+        assert!(String::from_utf8_lossy(&plaintext).contains("acme-payroll.com"));
+        Ok(())
+    }
 }
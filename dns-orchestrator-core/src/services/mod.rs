@@ -1,38 +1,91 @@
 //! 业务逻辑服务层
 
 mod account_bootstrap_service;
+mod account_capability_service;
 mod account_lifecycle_service;
 mod account_metadata_service;
+mod account_validation_service;
+mod api_usage_service;
+mod audit_log_service;
+mod background_job_runner;
+mod breaker_provider;
+mod change_window;
+mod char_safety;
+mod credential_expiry_service;
 mod credential_management_service;
+mod deadline;
 mod dns_service;
+mod domain_group_service;
 mod domain_metadata_service;
+mod domain_monitor_service;
 mod domain_service;
 mod import_export_service;
 mod migration_service;
+mod pagination;
 mod provider_metadata_service;
+mod record_copy_service;
+mod record_import;
+mod record_validator;
+mod retry;
+mod temporary_record_service;
 mod toolbox;
+mod weighted_rollout_service;
+mod write_queue_service;
+mod zone_file;
 
 pub use account_bootstrap_service::{AccountBootstrapService, RestoreResult};
+pub use account_capability_service::AccountCapabilityService;
 pub use account_lifecycle_service::AccountLifecycleService;
 pub use account_metadata_service::AccountMetadataService;
+pub use account_validation_service::AccountValidationService;
+pub use api_usage_service::ApiUsageService;
+pub use audit_log_service::AuditLogService;
+pub use background_job_runner::BackgroundJobRunner;
+pub use change_window::ChangeWindowService;
+pub use credential_expiry_service::{CredentialExpiryService, EXPIRY_REMINDER_DAYS};
 pub use credential_management_service::CredentialManagementService;
+pub use deadline::{DeadlineConfig, OperationKind};
 pub use dns_service::DnsService;
+pub use domain_group_service::DomainGroupService;
 pub use domain_metadata_service::DomainMetadataService;
+pub use domain_monitor_service::{DomainMonitorConfig, DomainMonitorService};
 pub use domain_service::DomainService;
 pub use import_export_service::ImportExportService;
 pub use migration_service::{MigrationResult, MigrationService};
+pub use pagination::{
+    paginate_all, paginate_pages, Page, PageCursor, PaginateOptions, MAX_PAGINATED_ITEMS,
+};
 pub use provider_metadata_service::ProviderMetadataService;
-pub use toolbox::ToolboxService;
+pub use record_copy_service::RecordCopyService;
+pub use record_import::RecordImportService;
+pub use record_validator::RecordValidator;
+pub use retry::{retry_provider_op, RetryPolicy};
+pub use temporary_record_service::TemporaryRecordService;
+pub use toolbox::{HickoryResolverBackend, ToolboxService, WhoisCacheConfig};
+pub use weighted_rollout_service::WeightedRolloutService;
+pub use write_queue_service::WriteQueueService;
 
+use std::future::Future;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::DnsProvider;
+use chrono::Utc;
+use dns_orchestrator_provider::{
+    get_all_provider_metadata, CapabilityProbe, DnsProvider, ProviderError,
+};
 
 use crate::error::{CoreError, CoreResult};
 use crate::traits::{
-    AccountRepository, CredentialStore, DomainMetadataRepository, ProviderRegistry,
+    AccountCapabilityRepository, AccountRepository, AuditLogRepository, CredentialStore,
+    DomainDerivedStatusRepository, DomainMetadataRepository, InProcessWriteLocks, ProviderRegistry,
+    RateLimitConfig, RateLimitedProviderRegistry, WriteLockBackend, WriteLockGuard,
+    WriteLockTarget,
 };
-use crate::types::AccountStatus;
+use crate::types::{AccountCapabilityCache, AccountStatus, AuditEntry, ProviderMetadata};
+use crate::utils::circuit_breaker::CircuitBreaker;
+
+use api_usage_service::MeteredProvider;
+use credential_expiry_service::credentials_expired_message;
+use retry::AccountPermits;
 
 /// 服务上下文 - 持有所有依赖
 ///
@@ -46,6 +99,28 @@ pub struct ServiceContext {
     pub provider_registry: Arc<dyn ProviderRegistry>,
     /// 域名元数据仓库
     pub domain_metadata_repository: Arc<dyn DomainMetadataRepository>,
+    /// Provider 调用超时配置
+    pub deadlines: DeadlineConfig,
+    /// 记录变更审计日志（未配置时不记录）
+    pub audit_log_repository: Option<Arc<dyn AuditLogRepository>>,
+    /// 变更窗口（未配置时不限制变更时段）
+    pub change_windows: Option<Arc<ChangeWindowService>>,
+    /// 离线写队列（未配置时网络错误直接返回）
+    pub write_queue: Option<Arc<WriteQueueService>>,
+    /// 记录写锁（默认为进程内实现）
+    pub write_locks: Arc<dyn WriteLockBackend>,
+    /// 账户能力探测缓存（未配置时能力检查只使用服务商静态声明）
+    pub capability_repository: Option<Arc<dyn AccountCapabilityRepository>>,
+    /// 服务商 API 用量统计（未配置时不统计）
+    pub api_usage: Option<Arc<ApiUsageService>>,
+    /// 域名派生状态（未配置时检查结果不附加到域名列表）
+    pub domain_status_repository: Option<Arc<dyn DomainDerivedStatusRepository>>,
+    /// 批量写入的失败重试与单账户并发上限（默认不重试）
+    pub retry_policy: RetryPolicy,
+    /// 按账户的并发许可，上限取自 `retry_policy`
+    pub(crate) account_permits: AccountPermits,
+    /// 按账户的熔断器，连续网络错误后 `DnsService` 暂停调用该账户的服务商
+    pub(crate) circuit_breaker: Arc<CircuitBreaker<String>>,
 }
 
 impl ServiceContext {
@@ -62,15 +137,296 @@ impl ServiceContext {
             account_repository,
             provider_registry,
             domain_metadata_repository,
+            deadlines: DeadlineConfig::default(),
+            audit_log_repository: None,
+            change_windows: None,
+            write_queue: None,
+            write_locks: Arc::new(InProcessWriteLocks::new()),
+            capability_repository: None,
+            api_usage: None,
+            domain_status_repository: None,
+            retry_policy: RetryPolicy::default(),
+            account_permits: AccountPermits::new(RetryPolicy::default().max_concurrent_per_account),
+            circuit_breaker: Arc::new(CircuitBreaker::default()),
+        }
+    }
+
+    /// 覆盖默认的 Provider 调用超时配置
+    #[must_use]
+    pub const fn with_deadlines(mut self, deadlines: DeadlineConfig) -> Self {
+        self.deadlines = deadlines;
+        self
+    }
+
+    /// 启用记录变更审计日志
+    #[must_use]
+    pub fn with_audit_log(mut self, repository: Arc<dyn AuditLogRepository>) -> Self {
+        self.audit_log_repository = Some(repository);
+        self
+    }
+
+    /// 启用变更窗口限制
+    #[must_use]
+    pub fn with_change_windows(mut self, service: Arc<ChangeWindowService>) -> Self {
+        self.change_windows = Some(service);
+        self
+    }
+
+    /// 启用离线写队列
+    #[must_use]
+    pub fn with_write_queue(mut self, service: Arc<WriteQueueService>) -> Self {
+        self.write_queue = Some(service);
+        self
+    }
+
+    /// 替换默认的进程内写锁（如多实例部署的分布式实现）
+    #[must_use]
+    pub fn with_write_locks(mut self, write_locks: Arc<dyn WriteLockBackend>) -> Self {
+        self.write_locks = write_locks;
+        self
+    }
+
+    /// 启用账户能力的运行时探测与缓存
+    #[must_use]
+    pub fn with_capability_cache(
+        mut self,
+        repository: Arc<dyn AccountCapabilityRepository>,
+    ) -> Self {
+        self.capability_repository = Some(repository);
+        self
+    }
+
+    /// 启用服务商 API 用量统计
+    #[must_use]
+    pub fn with_api_usage(mut self, service: Arc<ApiUsageService>) -> Self {
+        self.api_usage = Some(service);
+        self
+    }
+
+    /// 启用域名派生状态（到期、证书检查结果）的保存
+    #[must_use]
+    pub fn with_domain_status(
+        mut self,
+        repository: Arc<dyn DomainDerivedStatusRepository>,
+    ) -> Self {
+        self.domain_status_repository = Some(repository);
+        self
+    }
+
+    /// 覆盖默认的重试策略（默认最多尝试 3 次，见 [`RetryPolicy::default`]）
+    #[must_use]
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self.account_permits = AccountPermits::new(policy.max_concurrent_per_account);
+        self
+    }
+
+    /// 按服务商类型限制每个账户的请求速率，见 [`RateLimitedProviderRegistry`]
+    ///
+    /// 包装当前注册表，注册与注销转发给原注册表；之后取得的 Provider 每次接口调用都经过限流，
+    /// 直接持有原注册表的服务不经过限流。一般使用 [`RateLimitConfig::recommended`]。
+    #[must_use]
+    pub fn with_rate_limits(mut self, config: RateLimitConfig) -> Self {
+        self.provider_registry = Arc::new(RateLimitedProviderRegistry::new(
+            self.provider_registry,
+            config,
+        ));
+        self
+    }
+
+    /// 获取写锁，见 [`WriteLockBackend::acquire`]
+    pub async fn lock_for_write(
+        &self,
+        operation: &str,
+        targets: Vec<WriteLockTarget>,
+    ) -> CoreResult<WriteLockGuard> {
+        self.write_locks.acquire(operation, targets).await
+    }
+
+    /// 检查当前是否允许变更记录，见 [`ChangeWindowService::ensure_open`]
+    pub async fn ensure_change_window_open(&self) -> CoreResult<()> {
+        match &self.change_windows {
+            Some(service) => service.ensure_open().await,
+            None => Ok(()),
         }
     }
 
+    /// 写入审计记录
+    ///
+    /// 尽力而为：写入失败只记录日志，不影响已完成的记录操作。
+    /// 强制变更生效期间写入的记录附带强制变更原因。
+    pub async fn record_audit(&self, mut entry: AuditEntry) {
+        let Some(repository) = &self.audit_log_repository else {
+            return;
+        };
+        if let Some(service) = &self.change_windows {
+            entry.override_reason = service.current_override().await.map(|o| o.reason);
+        }
+        if let Err(e) = repository.append(&entry).await {
+            log::error!(
+                "Failed to write audit entry for {}/{}: {e}",
+                entry.account_id,
+                entry.domain_id
+            );
+        }
+    }
+
+    /// 在超时限制内执行 Provider 调用
+    ///
+    /// 超时返回 `CoreError::Timeout`，错误中带有 `operation` 名称。
+    pub async fn with_deadline<T, E, F>(
+        &self,
+        kind: OperationKind,
+        operation: &str,
+        fut: F,
+    ) -> CoreResult<T>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: Into<CoreError>,
+    {
+        self.deadlines.run(kind, operation, fut).await
+    }
+
     /// 获取 Provider 实例
+    ///
+    /// 启用 API 用量统计时，返回的实例每次接口调用都计入该账户的用量。
     pub async fn get_provider(&self, account_id: &str) -> CoreResult<Arc<dyn DnsProvider>> {
-        self.provider_registry
+        let provider = self
+            .provider_registry
             .get(account_id)
             .await
-            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+        let Some(usage) = &self.api_usage else {
+            return Ok(provider);
+        };
+        Ok(Arc::new(MeteredProvider::new(
+            provider,
+            account_id,
+            Arc::clone(usage),
+        )))
+    }
+
+    /// 账户的实际能力元数据（TTL 范围、是否支持代理等）
+    ///
+    /// 服务商静态声明叠加该账户缓存的探测修正；账户首次使用时先探测，
+    /// 探测失败回退到静态声明。
+    pub async fn provider_metadata(&self, account_id: &str) -> CoreResult<ProviderMetadata> {
+        let mut metadata = self.static_provider_metadata(account_id).await?;
+        match self.capability_cache(account_id).await {
+            Ok(Some(cache)) => metadata.apply_probe(&cache.probe),
+            Ok(None) => {}
+            Err(e) => log::warn!("[Capabilities] Using static capabilities for {account_id}: {e}"),
+        }
+        Ok(metadata)
+    }
+
+    /// 账户所属服务商静态声明的元数据
+    pub async fn static_provider_metadata(&self, account_id: &str) -> CoreResult<ProviderMetadata> {
+        let account = self
+            .account_repository
+            .find_by_id(account_id)
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+        get_all_provider_metadata()
+            .into_iter()
+            .find(|metadata| metadata.id == account.provider)
+            .ok_or_else(|| CoreError::ProviderNotFound(account.provider.to_string()))
+    }
+
+    /// 账户缓存的能力探测结果
+    ///
+    /// 未配置能力缓存时返回 `None`；尚未探测过（账户首次使用）时立即探测并缓存。
+    pub async fn capability_cache(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Option<AccountCapabilityCache>> {
+        let Some(repository) = &self.capability_repository else {
+            return Ok(None);
+        };
+        if let Some(cache) = repository.find_by_account(account_id).await? {
+            return Ok(Some(cache));
+        }
+        self.probe_capabilities(account_id).await.map(Some)
+    }
+
+    /// 探测账户的实际能力并写入缓存
+    ///
+    /// 服务商接口调用失败不返回错误：缓存空的修正与失败原因，能力检查回退到静态声明，
+    /// 直到下次手动刷新。
+    pub async fn probe_capabilities(&self, account_id: &str) -> CoreResult<AccountCapabilityCache> {
+        let provider = self.get_provider(account_id).await?;
+        let result = self
+            .with_deadline(
+                OperationKind::Read,
+                "probe_capabilities",
+                provider.probe_capabilities(),
+            )
+            .await;
+        let cache = match result {
+            Ok(probe) => {
+                log::info!("[Capabilities] Probed {account_id}: {probe:?}");
+                AccountCapabilityCache {
+                    account_id: account_id.to_string(),
+                    probe,
+                    probed_at: Utc::now(),
+                    error: None,
+                }
+            }
+            Err(e) => {
+                log::warn!("[Capabilities] Probe failed for {account_id}: {e}");
+                AccountCapabilityCache {
+                    account_id: account_id.to_string(),
+                    probe: CapabilityProbe::default(),
+                    probed_at: Utc::now(),
+                    error: Some(e.to_string()),
+                }
+            }
+        };
+        if let Some(repository) = &self.capability_repository {
+            repository.save(&cache).await?;
+        }
+        Ok(cache)
+    }
+
+    /// 处理 Provider 错误，凭证失效时更新账户状态
+    ///
+    /// 账户已过凭证到期时间时归因为 token 过期：标记为 `CredentialsExpired`，
+    /// 并返回 `CoreError::CredentialsExpired`；否则标记为无效并原样返回错误。
+    pub async fn handle_provider_error(&self, account_id: &str, err: CoreError) -> CoreError {
+        if !matches!(
+            err,
+            CoreError::Provider(ProviderError::InvalidCredentials { .. })
+        ) {
+            return err;
+        }
+
+        let expired_at = match self.account_repository.find_by_id(account_id).await {
+            Ok(account) => account.and_then(|a| a.credentials_expired_at(Utc::now())),
+            Err(e) => {
+                log::warn!("Failed to load account {account_id}: {e}");
+                None
+            }
+        };
+        let Some(expired_at) = expired_at else {
+            self.mark_account_invalid(account_id, "凭证已失效").await;
+            return err;
+        };
+
+        if let Err(e) = self
+            .account_repository
+            .update_status(
+                account_id,
+                AccountStatus::CredentialsExpired,
+                Some(credentials_expired_message(expired_at)),
+            )
+            .await
+        {
+            log::error!("Failed to mark account {account_id} as expired: {e}");
+        }
+        CoreError::CredentialsExpired {
+            account_id: account_id.to_string(),
+            expired_at,
+        }
     }
 
     /// 标记账户为无效状态
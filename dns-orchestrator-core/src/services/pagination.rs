@@ -0,0 +1,304 @@
+//! 分页遍历辅助
+//!
+//! 导出、诊断、成员比对等功能都需要"循环调用 list 接口直到最后一页"。[`paginate_all`] 把
+//! 翻页统一为按条目产出的 [`Stream`]，[`paginate_pages`] 按页产出（需要逐页上报进度时使用）：
+//! - 支持页码与游标两种分页方式（见 [`PageCursor`]）
+//! - 最后一页以 `next` 为准；空页或游标原地不动时停止翻页，避免服务商分页异常导致死循环
+//! - 可配置页间延迟，降低触发服务商限流的概率
+//! - 累计条目数超过 `max_items` 时以错误结束
+//!
+//! 任一页拉取失败时先产出该错误再结束，之前各页的条目已经产出，不会重试或跳过失败的页。
+
+use std::future::Future;
+use std::time::Duration;
+
+use futures::stream::{self, Stream, TryStreamExt};
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::PaginatedResponse;
+
+/// 默认的最大条目数（单个域名的记录数远低于此值）
+pub const MAX_PAGINATED_ITEMS: usize = 100_000;
+
+/// 要拉取的页
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageCursor {
+    /// 页码分页，从 1 开始
+    Page(u32),
+    /// 游标分页，`None` 为第一页
+    Token(Option<String>),
+}
+
+impl PageCursor {
+    /// 页码分页的页码；游标分页恒为 1
+    pub const fn page_number(&self) -> u32 {
+        match self {
+            Self::Page(page) => *page,
+            Self::Token(_) => 1,
+        }
+    }
+}
+
+/// 一页结果
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// 下一页，`None` 表示这是最后一页
+    pub next: Option<PageCursor>,
+    /// 服务商报告的总条目数（未知时为 `None`）
+    pub total: Option<u32>,
+}
+
+impl<T> From<PaginatedResponse<T>> for Page<T> {
+    fn from(response: PaginatedResponse<T>) -> Self {
+        Self {
+            next: response
+                .has_more
+                .then(|| PageCursor::Page(response.page.saturating_add(1))),
+            total: Some(response.total_count),
+            items: response.items,
+        }
+    }
+}
+
+/// 翻页选项
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginateOptions {
+    /// 第一页
+    pub start: PageCursor,
+    /// 两页之间的等待时长
+    pub page_delay: Duration,
+    /// 累计条目数上限，超过时以错误结束
+    pub max_items: usize,
+}
+
+impl Default for PaginateOptions {
+    fn default() -> Self {
+        Self {
+            start: PageCursor::Page(1),
+            page_delay: Duration::ZERO,
+            max_items: MAX_PAGINATED_ITEMS,
+        }
+    }
+}
+
+struct PagingState<F> {
+    fetch: F,
+    next: Option<PageCursor>,
+    fetched: usize,
+    first: bool,
+    page_delay: Duration,
+    max_items: usize,
+}
+
+/// 按页拉取，直到最后一页、出错或超过条目上限
+pub fn paginate_pages<T, F, Fut>(
+    options: PaginateOptions,
+    fetch: F,
+) -> impl Stream<Item = CoreResult<Page<T>>>
+where
+    F: FnMut(PageCursor) -> Fut,
+    Fut: Future<Output = CoreResult<Page<T>>>,
+{
+    let state = PagingState {
+        fetch,
+        next: Some(options.start),
+        fetched: 0,
+        first: true,
+        page_delay: options.page_delay,
+        max_items: options.max_items,
+    };
+    stream::unfold(state, |mut state| async move {
+        let cursor = state.next.take()?;
+        if !state.first && !state.page_delay.is_zero() {
+            tokio::time::sleep(state.page_delay).await;
+        }
+        state.first = false;
+
+        let page = match (state.fetch)(cursor.clone()).await {
+            Ok(page) => page,
+            Err(e) => return Some((Err(e), state)),
+        };
+        state.fetched = state.fetched.saturating_add(page.items.len());
+        if state.fetched > state.max_items {
+            log::warn!(
+                "[Pagination] Stopped after {} items (limit {})",
+                state.fetched,
+                state.max_items
+            );
+            return Some((
+                Err(CoreError::ValidationError(format!(
+                    "分页结果超过 {} 条，已停止翻页",
+                    state.max_items
+                ))),
+                state,
+            ));
+        }
+        state.next = match page.next.clone() {
+            Some(next) if page.items.is_empty() || next == cursor => {
+                log::warn!(
+                    "[Pagination] {cursor:?} returned no progress but reported more pages, stopping"
+                );
+                None
+            }
+            next => next,
+        };
+        Some((Ok(page), state))
+    })
+}
+
+/// 同 [`paginate_pages`]，逐条产出各页的条目
+pub fn paginate_all<T, F, Fut>(
+    options: PaginateOptions,
+    fetch: F,
+) -> impl Stream<Item = CoreResult<T>>
+where
+    F: FnMut(PageCursor) -> Fut,
+    Fut: Future<Output = CoreResult<Page<T>>>,
+{
+    paginate_pages(options, fetch)
+        .map_ok(|page| stream::iter(page.items.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+
+    use dns_orchestrator_provider::{DnsProvider, ProviderError};
+
+    use super::*;
+    use crate::testing::MockDnsProvider;
+    use crate::types::{DnsRecord, RecordData, RecordQueryParams};
+
+    fn mock_with_records(count: usize) -> Arc<MockDnsProvider> {
+        let mut provider = MockDnsProvider::new().with_domain("zone-1", "example.com");
+        for i in 0..count {
+            provider = provider.with_record(
+                "zone-1",
+                &format!("r{i}"),
+                300,
+                RecordData::TXT {
+                    text: format!("v{i}"),
+                },
+            );
+        }
+        Arc::new(provider)
+    }
+
+    fn list_records(
+        provider: &MockDnsProvider,
+        page_size: u32,
+    ) -> impl Stream<Item = CoreResult<DnsRecord>> + '_ {
+        paginate_all(PaginateOptions::default(), move |cursor| async move {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size,
+                keyword: None,
+                record_type: None,
+                sort: None,
+            };
+            Ok(Page::from(provider.list_records("zone-1", &params).await?))
+        })
+    }
+
+    #[tokio::test]
+    async fn test_paginate_all_handles_full_and_empty_pages() -> CoreResult<()> {
+        // 恰好整页：最后一页满页后不再多拉一页
+        let provider = mock_with_records(4);
+        let records: Vec<DnsRecord> = list_records(&provider, 2).try_collect().await?;
+        assert_eq!(records.len(), 4);
+
+        // 不足一页
+        let records: Vec<DnsRecord> = list_records(&provider, 3).try_collect().await?;
+        assert_eq!(records.len(), 4);
+
+        // 空结果
+        let empty = mock_with_records(0);
+        let records: Vec<DnsRecord> = list_records(&empty, 2).try_collect().await?;
+        assert!(records.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paginate_all_stops_after_error_mid_way() -> CoreResult<()> {
+        let provider = mock_with_records(3);
+        let mut records = pin!(list_records(&provider, 2));
+        let mut fetched = Vec::new();
+        for _ in 0..2 {
+            if let Some(record) = records.next().await {
+                fetched.push(record?);
+            }
+        }
+        // 第一页已经产出，第二页失败时产出错误后结束
+        provider.fail_with(ProviderError::NetworkError {
+            provider: "mock".to_string(),
+            detail: "connection reset".to_string(),
+        });
+        assert!(matches!(records.next().await, Some(Err(_))));
+        assert!(records.next().await.is_none());
+        assert_eq!(fetched.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paginate_pages_guards_against_stuck_cursors() -> CoreResult<()> {
+        // 游标原地不动
+        let pages: Vec<Page<u32>> = paginate_pages(
+            PaginateOptions {
+                start: PageCursor::Token(None),
+                ..PaginateOptions::default()
+            },
+            |cursor| async move {
+                let next = PageCursor::Token(Some("same".to_string()));
+                let items = if cursor == next { vec![2] } else { vec![1] };
+                Ok(Page {
+                    items,
+                    next: Some(next),
+                    total: None,
+                })
+            },
+        )
+        .try_collect()
+        .await?;
+        assert_eq!(
+            pages.iter().map(|p| p.items.clone()).collect::<Vec<_>>(),
+            [[1], [2]]
+        );
+
+        // 空页却声明还有更多
+        let pages: Vec<Page<u32>> =
+            paginate_pages(PaginateOptions::default(), |cursor| async move {
+                Ok(Page {
+                    items: Vec::new(),
+                    next: Some(PageCursor::Page(cursor.page_number() + 1)),
+                    total: None,
+                })
+            })
+            .try_collect()
+            .await?;
+        assert_eq!(pages.len(), 1);
+
+        // 超过条目上限
+        let result: CoreResult<Vec<u32>> = paginate_all(
+            PaginateOptions {
+                max_items: 3,
+                ..PaginateOptions::default()
+            },
+            |cursor| async move {
+                Ok(Page {
+                    items: vec![1, 2],
+                    next: Some(PageCursor::Page(cursor.page_number() + 1)),
+                    total: None,
+                })
+            },
+        )
+        .try_collect()
+        .await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,719 @@
+//! 跨账户记录复制服务
+//!
+//! 将一个账户下域名的记录复制到另一个域名（可为同一账户，也可为不同服务商），
+//! 用于迁移 DNS 托管或以现有域名为模板配置新域名：
+//! 拉取源记录 -> 转换为目标服务商可接受的请求 -> 与目标现有记录比对生成计划 -> 逐条执行。
+//!
+//! 转换时处理服务商差异：
+//! - 顶级 NS 与 SOA 由服务商托管，不复制；子域 NS 可按选项跳过
+//! - TTL 按目标服务商的范围调整；自动 TTL（值为 1）在不支持的服务商上转换为 300 秒
+//! - 目标服务商不支持代理时丢弃 `proxied`
+//!
+//! 比对使用与域名组相同的规范化（相对名称、FQDN 目标、TXT 引号等）。
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::domain_group_service::normalized_value;
+use crate::services::zone_file::{effective_ttl, record_type_name, relative_name};
+use crate::services::{
+    paginate_all, DnsService, OperationKind, Page, PageCursor, PaginateOptions, ServiceContext,
+};
+use crate::traits::WriteLockTarget;
+use crate::types::{
+    ChangeSource, CreateDnsRecordRequest, DnsRecord, ProviderMetadata, RecordChange,
+    RecordConflictStrategy, RecordCopyItem, RecordCopyOptions, RecordCopyResult, RecordData,
+    RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+/// 拉取记录的分页大小
+const LIST_PAGE_SIZE: u32 = 100;
+
+/// 记录对齐键：(规范化名称, 记录类型)
+type RecordKey = (String, &'static str);
+
+/// 域名及其全部记录
+struct DomainRecords {
+    domain_name: String,
+    records: Vec<DnsRecord>,
+}
+
+/// 记录复制服务
+pub struct RecordCopyService {
+    ctx: Arc<ServiceContext>,
+    dns: DnsService,
+}
+
+impl RecordCopyService {
+    /// 创建记录复制服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self {
+            dns: DnsService::new(Arc::clone(&ctx)),
+            ctx,
+        }
+    }
+
+    /// 复制源域名的记录到目标域名
+    ///
+    /// `options.record_type` 非空时只复制该类型的记录；`options.dry_run` 为 true 时只返回计划。与目标现有记录完全相同的记录总是跳过，
+    /// 同名同类型但值不同的记录按 `options.conflict_strategy` 处理。
+    /// 记录逐条经 [`DnsService`] 的写入路径执行（校验、重试、熔断、审计），单条失败不影响其他记录。
+    pub async fn copy_records(
+        &self,
+        source_account_id: &str,
+        source_domain_id: &str,
+        target_account_id: &str,
+        target_domain_id: &str,
+        options: RecordCopyOptions,
+    ) -> CoreResult<RecordCopyResult> {
+        if source_account_id == target_account_id && source_domain_id == target_domain_id {
+            return Err(CoreError::ValidationError(
+                "源域名与目标域名不能相同".to_string(),
+            ));
+        }
+
+        let target_metadata = self.ctx.provider_metadata(target_account_id).await?;
+        let (source, target) = futures::future::join(
+            self.fetch_domain(source_account_id, source_domain_id),
+            self.fetch_domain(target_account_id, target_domain_id),
+        )
+        .await;
+        let (source, target) = (source?, target?);
+
+        let mut items = plan_copy(
+            &source,
+            &target,
+            target_domain_id,
+            &target_metadata,
+            &options,
+        );
+
+        let mut result = RecordCopyResult {
+            dry_run: options.dry_run,
+            items: Vec::new(),
+            created_count: 0,
+            updated_count: 0,
+            skipped_count: items.iter().filter(|item| item.change.is_none()).count(),
+            failed_count: 0,
+        };
+        if options.dry_run {
+            result.items = items;
+            return Ok(result);
+        }
+
+        self.ctx.ensure_change_window_open().await?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "copy_records",
+                vec![WriteLockTarget::domain(target_account_id, target_domain_id)],
+            )
+            .await?;
+        for item in &mut items {
+            // 计划中不会生成删除
+            let Some(change @ (RecordChange::Create { .. } | RecordChange::Update { .. })) =
+                &item.change
+            else {
+                continue;
+            };
+            let before = match change {
+                RecordChange::Update { record_id, .. } => target
+                    .records
+                    .iter()
+                    .find(|record| &record.id == record_id)
+                    .cloned(),
+                _ => None,
+            };
+            match self
+                .dns
+                .apply_planned_change(
+                    target_account_id,
+                    target_domain_id,
+                    change,
+                    before,
+                    ChangeSource::Manual,
+                )
+                .await
+            {
+                Ok(_) if matches!(change, RecordChange::Update { .. }) => {
+                    result.updated_count += 1;
+                }
+                Ok(_) => result.created_count += 1,
+                Err(e) => {
+                    item.error = Some(e.to_string());
+                    result.failed_count += 1;
+                }
+            }
+        }
+        result.items = items;
+
+        log::info!(
+            "[RecordCopy] {} -> {}: {} created, {} updated, {} skipped, {} failed",
+            source.domain_name,
+            target.domain_name,
+            result.created_count,
+            result.updated_count,
+            result.skipped_count,
+            result.failed_count
+        );
+        Ok(result)
+    }
+
+    /// 拉取域名及其全部记录
+    async fn fetch_domain(&self, account_id: &str, domain_id: &str) -> CoreResult<DomainRecords> {
+        let provider = self.dns.get_provider(account_id).await?;
+
+        let domain = match self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(domain_id),
+            )
+            .await
+        {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        let provider = &provider;
+        let records = paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size: LIST_PAGE_SIZE,
+                keyword: None,
+                record_type: None,
+                sort: None,
+            };
+            async move {
+                match self
+                    .ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_records",
+                        provider.list_records(domain_id, &params),
+                    )
+                    .await
+                {
+                    Ok(response) => Ok(Page::from(response)),
+                    Err(e) => Err(self.handle_provider_error(account_id, e).await),
+                }
+            }
+        })
+        .try_collect()
+        .await?;
+
+        Ok(DomainRecords {
+            domain_name: domain.name,
+            records,
+        })
+    }
+
+    /// 处理 Provider 错误，见 [`ServiceContext::handle_provider_error`]
+    async fn handle_provider_error(&self, account_id: &str, err: CoreError) -> CoreError {
+        self.ctx.handle_provider_error(account_id, err).await
+    }
+}
+
+/// 按 名称 + 类型 分组，返回 (规范化值, 记录)
+fn group_by_key<'a>(
+    origin: &str,
+    records: &'a [DnsRecord],
+) -> BTreeMap<RecordKey, Vec<(String, &'a DnsRecord)>> {
+    let mut groups: BTreeMap<RecordKey, Vec<(String, &'a DnsRecord)>> = BTreeMap::new();
+    for record in records {
+        let name = relative_name(&record.name, origin).to_lowercase();
+        groups
+            .entry((name, record_type_name(&record.data)))
+            .or_default()
+            .push((normalized_value(&record.data, origin), record));
+    }
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| a.0.cmp(&b.0));
+    }
+    groups
+}
+
+/// 生成复制计划
+///
+/// 同名同类型下，与目标值相同的源记录跳过；其余源记录在目标存在同名同类型记录时视为冲突：
+/// 跳过、改写尚未匹配的目标记录（不足时新建）或直接新建。目标多出的记录不会删除。
+fn plan_copy(
+    source: &DomainRecords,
+    target: &DomainRecords,
+    target_domain_id: &str,
+    target_metadata: &ProviderMetadata,
+    options: &RecordCopyOptions,
+) -> Vec<RecordCopyItem> {
+    let source_origin = source.domain_name.trim_end_matches('.').to_lowercase();
+    let target_origin = target.domain_name.trim_end_matches('.').to_lowercase();
+    let source_groups = group_by_key(&source_origin, &source.records);
+    let target_groups = group_by_key(&target_origin, &target.records);
+
+    let mut items = Vec::new();
+    for (key, records) in &source_groups {
+        if let Some(record_type) = &options.record_type {
+            if records
+                .first()
+                .is_some_and(|(_, record)| record.data.record_type() != *record_type)
+            {
+                continue;
+            }
+        }
+        let existing = target_groups.get(key).map_or(&[][..], Vec::as_slice);
+        let mut unmatched_target = existing
+            .iter()
+            .filter(|(value, _)| !records.iter().any(|(v, _)| v == value));
+
+        for (value, record) in records {
+            let name = relative_name(&record.name, &source_origin);
+            let mut item = RecordCopyItem {
+                source_record_id: record.id.clone(),
+                name: name.clone(),
+                record_type: record.data.record_type(),
+                value: record.data.display_value(),
+                change: None,
+                skip_reason: None,
+                adjustments: Vec::new(),
+                error: None,
+            };
+
+            if let Some(reason) = skip_reason(&key.0, &record.data, options) {
+                item.skip_reason = Some(reason.to_string());
+                items.push(item);
+                continue;
+            }
+            if existing.iter().any(|(v, _)| v == value) {
+                item.skip_reason = Some("目标已存在相同记录".to_string());
+                items.push(item);
+                continue;
+            }
+
+            let ttl = adapt_ttl(record.ttl, target_metadata, &mut item.adjustments);
+            let proxied = adapt_proxied(record.proxied, target_metadata, &mut item.adjustments);
+            let overwrite = if existing.is_empty() {
+                None
+            } else {
+                match options.conflict_strategy {
+                    RecordConflictStrategy::Skip => {
+                        item.skip_reason = Some("与目标现有记录冲突".to_string());
+                        items.push(item);
+                        continue;
+                    }
+                    RecordConflictStrategy::Overwrite => unmatched_target.next(),
+                    RecordConflictStrategy::Append => None,
+                }
+            };
+
+            item.change = Some(match overwrite {
+                Some((previous_value, target_record)) => RecordChange::Update {
+                    record_id: target_record.id.clone(),
+                    previous_value: previous_value.clone(),
+                    request: UpdateDnsRecordRequest {
+                        domain_id: target_domain_id.to_string(),
+                        name,
+                        ttl,
+                        data: record.data.clone(),
+                        proxied,
+                    },
+                },
+                None => RecordChange::Create {
+                    request: CreateDnsRecordRequest {
+                        domain_id: target_domain_id.to_string(),
+                        name,
+                        ttl,
+                        data: record.data.clone(),
+                        proxied,
+                    },
+                },
+            });
+            items.push(item);
+        }
+    }
+    items
+}
+
+fn skip_reason(name: &str, data: &RecordData, options: &RecordCopyOptions) -> Option<&'static str> {
+    match data {
+        RecordData::SOA { .. } => Some("SOA 由 DNS 服务商管理"),
+        RecordData::NS { .. } if name == "@" => Some("顶级 NS 由 DNS 服务商管理"),
+        RecordData::NS { .. } if options.skip_ns => Some("已按选项跳过 NS 记录"),
+        _ => None,
+    }
+}
+
+/// 将 TTL 调整到目标服务商支持的范围
+fn adapt_ttl(ttl: u32, target: &ProviderMetadata, adjustments: &mut Vec<String>) -> u32 {
+    if ttl <= 1 {
+        if target.features.auto_ttl {
+            return 1;
+        }
+        let converted = effective_ttl(ttl).clamp(target.limits.min_ttl, target.limits.max_ttl);
+        adjustments.push(format!("目标服务商不支持自动 TTL，已改为 {converted}"));
+        return converted;
+    }
+
+    let adjusted = ttl.clamp(target.limits.min_ttl, target.limits.max_ttl);
+    if adjusted > ttl {
+        adjustments.push(format!("TTL {ttl} 低于目标服务商下限，已调整为 {adjusted}"));
+    } else if adjusted < ttl {
+        adjustments.push(format!("TTL {ttl} 超过目标服务商上限，已调整为 {adjusted}"));
+    }
+    adjusted
+}
+
+/// 目标服务商不支持代理时丢弃 `proxied`
+fn adapt_proxied(
+    proxied: Option<bool>,
+    target: &ProviderMetadata,
+    adjustments: &mut Vec<String>,
+) -> Option<bool> {
+    if target.features.proxy {
+        return proxied;
+    }
+    if proxied == Some(true) {
+        adjustments.push("目标服务商不支持代理，已改为仅 DNS 解析".to_string());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use dns_orchestrator_provider::{
+        ProviderFeatures, ProviderLimits, ProviderType, TxtFormat, STANDARD_RECORD_TYPES,
+    };
+
+    use super::*;
+    use crate::types::DnsRecordType;
+
+    fn record(id: &str, name: &str, ttl: u32, data: RecordData) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            domain_id: "example.com".to_string(),
+            name: name.to_string(),
+            ttl,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn a(address: &str) -> RecordData {
+        RecordData::A {
+            address: address.to_string(),
+        }
+    }
+
+    fn domain(records: Vec<DnsRecord>) -> DomainRecords {
+        DomainRecords {
+            domain_name: "example.com".to_string(),
+            records,
+        }
+    }
+
+    fn metadata(proxy: bool, auto_ttl: bool, min_ttl: u32) -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Aliyun,
+            name: String::new(),
+            description: String::new(),
+            required_fields: Vec::new(),
+            optional_fields: Vec::new(),
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                proxy,
+                weighted_records: false,
+                auto_ttl,
+                soa_editing: false,
+                record_sets: false,
+                batch_changes: false,
+                txt_format: TxtFormat::Raw,
+                server_side_sort: false,
+            },
+            limits: ProviderLimits {
+                max_page_size_domains: 100,
+                max_page_size_records: 100,
+                min_ttl,
+                max_ttl: 86_400,
+                max_batch_changes: 0,
+                max_txt_length: 0,
+            },
+        }
+    }
+
+    fn options(conflict_strategy: RecordConflictStrategy) -> RecordCopyOptions {
+        RecordCopyOptions {
+            skip_ns: true,
+            record_type: None,
+            conflict_strategy,
+            dry_run: true,
+        }
+    }
+
+    #[test]
+    fn plans_creates_and_skips_provider_managed_records() {
+        let mut proxied = record("s3", "www.example.com", 1, a("192.0.2.3"));
+        proxied.proxied = Some(true);
+        let source = domain(vec![
+            record(
+                "s1",
+                "@",
+                600,
+                RecordData::NS {
+                    nameserver: "ns1.provider.net".to_string(),
+                },
+            ),
+            record(
+                "s2",
+                "sub",
+                600,
+                RecordData::NS {
+                    nameserver: "ns1.other.net".to_string(),
+                },
+            ),
+            proxied,
+            record("s4", "api", 60, a("192.0.2.4")),
+        ]);
+
+        let items = plan_copy(
+            &source,
+            &domain(Vec::new()),
+            "target-id",
+            &metadata(false, false, 600),
+            &options(RecordConflictStrategy::Skip),
+        );
+
+        let planned: Vec<(&str, Option<u32>, Option<&str>)> = items
+            .iter()
+            .map(|item| {
+                let ttl = match &item.change {
+                    Some(RecordChange::Create { request }) => {
+                        assert_eq!(request.domain_id, "target-id");
+                        assert_eq!(request.proxied, None);
+                        Some(request.ttl)
+                    }
+                    _ => None,
+                };
+                (item.name.as_str(), ttl, item.skip_reason.as_deref())
+            })
+            .collect();
+        assert_eq!(
+            planned,
+            vec![
+                ("@", None, Some("顶级 NS 由 DNS 服务商管理")),
+                ("api", Some(600), None),
+                ("sub", None, Some("已按选项跳过 NS 记录")),
+                ("www", Some(600), None),
+            ]
+        );
+        assert_eq!(items[1].adjustments.len(), 1);
+        // 自动 TTL 与代理各一条调整说明
+        assert_eq!(items[3].adjustments.len(), 2);
+    }
+
+    #[test]
+    fn keeps_auto_ttl_and_proxy_when_supported() {
+        let mut proxied = record("s1", "www", 1, a("192.0.2.1"));
+        proxied.proxied = Some(true);
+
+        let items = plan_copy(
+            &domain(vec![proxied]),
+            &domain(Vec::new()),
+            "target-id",
+            &metadata(true, true, 60),
+            &options(RecordConflictStrategy::Skip),
+        );
+
+        assert!(matches!(
+            &items[0].change,
+            Some(RecordChange::Create { request })
+                if request.ttl == 1 && request.proxied == Some(true)
+        ));
+        assert!(items[0].adjustments.is_empty());
+    }
+
+    #[test]
+    fn handles_duplicates_and_conflicts() {
+        let source = domain(vec![
+            record("s1", "www", 600, a("192.0.2.1")),
+            record("s2", "www", 600, a("192.0.2.2")),
+        ]);
+        let target = domain(vec![
+            record("t1", "www.example.com", 600, a("192.0.2.1")),
+            record("t2", "www.example.com", 600, a("192.0.2.9")),
+        ]);
+        let plan = |strategy| {
+            plan_copy(
+                &source,
+                &target,
+                "target-id",
+                &metadata(false, false, 600),
+                &options(strategy),
+            )
+        };
+
+        let skipped = plan(RecordConflictStrategy::Skip);
+        assert_eq!(
+            skipped[0].skip_reason.as_deref(),
+            Some("目标已存在相同记录")
+        );
+        assert_eq!(
+            skipped[1].skip_reason.as_deref(),
+            Some("与目标现有记录冲突")
+        );
+
+        let overwritten = plan(RecordConflictStrategy::Overwrite);
+        assert!(overwritten[0].change.is_none());
+        assert!(matches!(
+            &overwritten[1].change,
+            Some(RecordChange::Update { record_id, previous_value, .. })
+                if record_id == "t2" && previous_value == "192.0.2.9"
+        ));
+
+        let appended = plan(RecordConflictStrategy::Append);
+        assert!(matches!(
+            &appended[1].change,
+            Some(RecordChange::Create { .. })
+        ));
+    }
+
+    #[test]
+    fn filters_by_record_type() {
+        let source = domain(vec![
+            record("s1", "www", 600, a("192.0.2.1")),
+            record(
+                "s2",
+                "@",
+                600,
+                RecordData::MX {
+                    priority: 10,
+                    exchange: "mail.example.com".to_string(),
+                },
+            ),
+        ]);
+        let mut options = options(RecordConflictStrategy::Skip);
+        options.record_type = Some(DnsRecordType::Mx);
+
+        let items = plan_copy(
+            &source,
+            &domain(Vec::new()),
+            "target-id",
+            &metadata(false, false, 60),
+            &options,
+        );
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].source_record_id, "s2");
+        assert!(items[0].change.is_some());
+    }
+
+    #[tokio::test]
+    async fn copy_writes_are_audited() -> CoreResult<()> {
+        use crate::testing::{
+            in_memory_context, register_mock_account, InMemoryAuditLogRepository, MockDnsProvider,
+        };
+        use crate::types::AuditOperation;
+
+        let audit = Arc::new(InMemoryAuditLogRepository::default());
+        let ctx = Arc::new(in_memory_context().with_audit_log(audit.clone()));
+        let source = MockDnsProvider::new()
+            .with_domain("src", "example.com")
+            .with_record("src", "www", 600, a("192.0.2.1"))
+            .with_record("src", "api", 600, a("192.0.2.2"));
+        let target = MockDnsProvider::new()
+            .with_domain("dst", "example.org")
+            .with_record("dst", "api", 600, a("192.0.2.9"));
+        register_mock_account(&ctx, "source", Arc::new(source)).await?;
+        register_mock_account(&ctx, "target", Arc::new(target)).await?;
+
+        let mut options = options(RecordConflictStrategy::Overwrite);
+        options.dry_run = false;
+        let result = RecordCopyService::new(Arc::clone(&ctx))
+            .copy_records("source", "src", "target", "dst", options)
+            .await?;
+        assert_eq!(
+            (
+                result.created_count,
+                result.updated_count,
+                result.failed_count
+            ),
+            (1, 1, 0)
+        );
+
+        let entries: Vec<(AuditOperation, bool, Option<String>)> = audit
+            .entries()
+            .await
+            .into_iter()
+            .map(|entry| {
+                (
+                    entry.operation,
+                    entry.success,
+                    entry.before.map(|record| record.data.display_value()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (AuditOperation::Update, true, Some("192.0.2.9".to_string())),
+                (AuditOperation::Create, true, None),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn copy_validates_and_retries_writes() -> CoreResult<()> {
+        use std::time::Duration;
+
+        use dns_orchestrator_provider::ProviderError;
+
+        use crate::services::RetryPolicy;
+        use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+        let ctx = Arc::new(in_memory_context().with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::ZERO,
+            jitter: false,
+            ..RetryPolicy::default()
+        }));
+        let source = MockDnsProvider::new()
+            .with_domain("src", "example.com")
+            .with_record("src", "www", 600, a("192.0.2.1"))
+            .with_record(
+                "src",
+                "cdn",
+                600,
+                RecordData::CNAME {
+                    target: "cdn\u{200b}.example.net".to_string(),
+                },
+            );
+        let target = Arc::new(MockDnsProvider::new().with_domain("dst", "example.org"));
+        register_mock_account(&ctx, "source", Arc::new(source)).await?;
+        register_mock_account(&ctx, "target", Arc::clone(&target)).await?;
+
+        target.fail_next_writes(
+            ProviderError::HttpError {
+                provider: "mock".to_string(),
+                status: 503,
+                raw_message: None,
+            },
+            1,
+        );
+        let mut options = options(RecordConflictStrategy::Skip);
+        options.dry_run = false;
+        let result = RecordCopyService::new(ctx)
+            .copy_records("source", "src", "target", "dst", options)
+            .await?;
+
+        // 含零宽字符的记录未通过校验，另一条在一次 5xx 后重试成功
+        assert_eq!((result.created_count, result.failed_count), (1, 1));
+        assert!(result.items[0].error.is_some());
+        assert_eq!(target.records().len(), 1);
+        Ok(())
+    }
+}
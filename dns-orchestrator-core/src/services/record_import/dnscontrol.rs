@@ -0,0 +1,200 @@
+//! dnscontrol JSON IR 解析
+//!
+//! `dnsconfig.js` 是任意 JavaScript（宏、循环、变量），无法直接解析；
+//! 需要先用 `dnscontrol print-ir` 导出展开后的 JSON IR。
+//! Provider 专有的伪记录类型（如 `CF_REDIRECT`、`R53_ALIAS`）列为警告。
+
+use serde::Deserialize;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::RecordData;
+
+use super::{strip_trailing_dot, ParsedRecord, ParsedRecords};
+
+/// dnscontrol 默认 TTL
+const DEFAULT_TTL: u32 = 300;
+
+#[derive(Debug, Deserialize)]
+struct IrConfig {
+    #[serde(default)]
+    domains: Vec<IrDomain>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IrDomain {
+    name: String,
+    #[serde(default)]
+    records: Vec<IrRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IrRecord {
+    #[serde(rename = "type")]
+    record_type: String,
+    name: String,
+    #[serde(default)]
+    target: String,
+    ttl: Option<u32>,
+    mxpreference: Option<u16>,
+    srvpriority: Option<u16>,
+    srvweight: Option<u16>,
+    srvport: Option<u16>,
+    caaflag: Option<u8>,
+    caatag: Option<String>,
+    /// 旧版 IR 中 TXT 记录的分段内容
+    txtstrings: Option<Vec<String>>,
+    #[serde(default)]
+    meta: std::collections::HashMap<String, String>,
+}
+
+/// 解析 dnscontrol JSON IR，只取与 `zone_name` 匹配的域名
+pub(super) fn parse(content: &str, zone_name: &str) -> CoreResult<ParsedRecords> {
+    let config: IrConfig = serde_json::from_str(content).map_err(|e| {
+        CoreError::ValidationError(format!(
+            "dnscontrol 配置需先用 `dnscontrol print-ir` 导出为 JSON: {e}"
+        ))
+    })?;
+
+    let zone = strip_trailing_dot(zone_name).to_lowercase();
+    let domain = match config.domains.as_slice() {
+        [only] => only,
+        domains => domains
+            .iter()
+            .find(|d| strip_trailing_dot(&d.name).to_lowercase() == zone)
+            .ok_or_else(|| {
+                CoreError::ValidationError(format!("dnscontrol 配置中未找到域名 {zone_name}"))
+            })?,
+    };
+
+    let mut parsed = ParsedRecords::default();
+    for record in &domain.records {
+        let record_type = record.record_type.to_uppercase();
+        let label = format!("{} {record_type}", record.name);
+
+        let data = match to_record_data(&record_type, record) {
+            Ok(data) => data,
+            Err(reason) => {
+                parsed.warnings.push(format!("{label}: {reason}"));
+                continue;
+            }
+        };
+
+        let mut proxied = None;
+        for (key, value) in &record.meta {
+            match key.as_str() {
+                "cloudflare_proxy" => proxied = Some(value != "off"),
+                // dnscontrol 内部字段
+                "orig_custom_type" => {}
+                _ => parsed
+                    .warnings
+                    .push(format!("{label}: 忽略 meta.{key} 配置")),
+            }
+        }
+
+        parsed.records.push(ParsedRecord {
+            name: if record.name.is_empty() {
+                "@".to_string()
+            } else {
+                record.name.clone()
+            },
+            ttl: record.ttl.unwrap_or(DEFAULT_TTL),
+            data,
+            proxied,
+        });
+    }
+
+    Ok(parsed)
+}
+
+fn to_record_data(record_type: &str, record: &IrRecord) -> Result<RecordData, String> {
+    let target = strip_trailing_dot(&record.target);
+    let missing = |field: &str| format!("缺少 {field} 字段");
+
+    match record_type {
+        "A" => Ok(RecordData::A { address: target }),
+        "AAAA" => Ok(RecordData::AAAA { address: target }),
+        "CNAME" => Ok(RecordData::CNAME { target }),
+        "NS" => Ok(RecordData::NS { nameserver: target }),
+        "TXT" => Ok(RecordData::TXT {
+            text: match &record.txtstrings {
+                Some(parts) if !parts.is_empty() => parts.concat(),
+                _ => record.target.clone(),
+            },
+        }),
+        "MX" => Ok(RecordData::MX {
+            priority: record.mxpreference.ok_or_else(|| missing("mxpreference"))?,
+            exchange: target,
+        }),
+        "SRV" => Ok(RecordData::SRV {
+            priority: record.srvpriority.ok_or_else(|| missing("srvpriority"))?,
+            weight: record.srvweight.ok_or_else(|| missing("srvweight"))?,
+            port: record.srvport.ok_or_else(|| missing("srvport"))?,
+            target,
+        }),
+        "CAA" => Ok(RecordData::CAA {
+            flags: record.caaflag.unwrap_or(0),
+            tag: record.caatag.clone().ok_or_else(|| missing("caatag"))?,
+            value: record.target.clone(),
+        }),
+        other => Err(format!("不支持的记录类型 {other}，已跳过")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../tests/fixtures/record_import/dnscontrol_ir.json");
+
+    #[test]
+    fn parses_example_ir() -> CoreResult<()> {
+        let parsed = parse(EXAMPLE, "example.com")?;
+
+        let types: Vec<_> = parsed
+            .records
+            .iter()
+            .map(|r| (r.name.as_str(), r.data.record_type()))
+            .collect();
+        assert!(types.contains(&("@", crate::types::DnsRecordType::A)));
+        assert!(types.contains(&("@", crate::types::DnsRecordType::Mx)));
+        assert!(types.contains(&("www", crate::types::DnsRecordType::Cname)));
+        assert!(types.contains(&("_sip._tcp", crate::types::DnsRecordType::Srv)));
+        assert!(types.contains(&("@", crate::types::DnsRecordType::Caa)));
+
+        let www = parsed.records.iter().find(|r| r.name == "www");
+        assert_eq!(www.and_then(|r| r.proxied), Some(true));
+        assert_eq!(
+            www.map(|r| r.data.display_value()).as_deref(),
+            Some("example.com")
+        );
+
+        let txt = parsed
+            .records
+            .iter()
+            .find(|r| r.data.record_type() == crate::types::DnsRecordType::Txt);
+        assert_eq!(
+            txt.map(|r| r.data.display_value()).as_deref(),
+            Some("v=spf1 include:_spf.google.com ~all")
+        );
+
+        assert!(parsed.warnings.iter().any(|w| w.contains("CF_REDIRECT")));
+        Ok(())
+    }
+
+    #[test]
+    fn selects_domain_by_name() -> CoreResult<()> {
+        let parsed = parse(EXAMPLE, "example.org.")?;
+        assert_eq!(parsed.records.len(), 1);
+        assert!(parse(EXAMPLE, "missing.com").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_javascript_config() {
+        assert!(parse(
+            "D(\"example.com\", REG_NONE, DnsProvider(DSP));",
+            "example.com"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,440 @@
+//! 记录导入服务
+//!
+//! 从外部 DNS 管理工具的配置文件导入记录定义：解析 -> 预览（标记重复与冲突）-> 按冲突策略逐条创建/覆盖。
+//! 各格式的解析器只负责把文件映射为 `ParsedRecord` 列表，不支持的记录类型与修饰列为警告而不是失败。
+
+mod dnscontrol;
+mod octodns;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use dns_orchestrator_provider::DnsProvider;
+use futures::TryStreamExt;
+
+use crate::error::CoreResult;
+use crate::services::{
+    char_safety, paginate_all, DnsService, OperationKind, Page, PageCursor, PaginateOptions,
+    ServiceContext,
+};
+use crate::traits::WriteLockTarget;
+use crate::types::{
+    ChangeSource, CreateDnsRecordRequest, DnsRecord, RecordChange, RecordConflictStrategy,
+    RecordData, RecordImportFailure, RecordImportFormat, RecordImportPreview,
+    RecordImportPreviewItem, RecordImportRequest, RecordImportResult, RecordQueryParams,
+    UpdateDnsRecordRequest,
+};
+
+/// 列出现有记录时的分页大小
+const LIST_PAGE_SIZE: u32 = 100;
+
+/// 解析出的单条记录（名称为相对名称，根域为 `@`）
+#[derive(Debug, Clone)]
+pub(crate) struct ParsedRecord {
+    pub name: String,
+    pub ttl: u32,
+    pub data: RecordData,
+    pub proxied: Option<bool>,
+}
+
+/// 解析结果
+#[derive(Debug, Default)]
+pub(crate) struct ParsedRecords {
+    pub records: Vec<ParsedRecord>,
+    pub warnings: Vec<String>,
+}
+
+/// 去掉末尾的点（配置文件中的目标通常是 FQDN）
+fn strip_trailing_dot(value: &str) -> String {
+    value.trim_end_matches('.').to_string()
+}
+
+/// 记录导入服务
+pub struct RecordImportService {
+    ctx: Arc<ServiceContext>,
+    dns: DnsService,
+}
+
+impl RecordImportService {
+    /// 创建记录导入服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self {
+            dns: DnsService::new(Arc::clone(&ctx)),
+            ctx,
+        }
+    }
+
+    /// 预览导入（不做任何修改）
+    pub async fn preview_record_import(
+        &self,
+        request: &RecordImportRequest,
+    ) -> CoreResult<RecordImportPreview> {
+        let provider = self.dns.get_provider(&request.account_id).await?;
+        let (items, warnings, _) = self.prepare(provider.as_ref(), request).await?;
+        Ok(RecordImportPreview { items, warnings })
+    }
+
+    /// 执行导入
+    ///
+    /// 与现有记录完全相同的记录总是跳过；同名同类型但值不同的记录按 `conflict_strategy` 处理。
+    /// 记录逐条经 [`DnsService`] 的写入路径校验、创建并写入审计日志，单条失败不影响其他记录；
+    /// 未开启规范化时含可疑字符的记录在校验时失败，原因已在预览警告中列出。
+    pub async fn import_records(
+        &self,
+        request: RecordImportRequest,
+    ) -> CoreResult<RecordImportResult> {
+        self.ctx.ensure_change_window_open().await?;
+        // 与现有记录比对后再写入，比对前独占整个域名
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "import_records",
+                vec![WriteLockTarget::domain(
+                    &request.account_id,
+                    &request.domain_id,
+                )],
+            )
+            .await?;
+        let provider = self.dns.get_provider(&request.account_id).await?;
+        let (items, warnings, existing) = self.prepare(provider.as_ref(), &request).await?;
+
+        let mut result = RecordImportResult {
+            created_count: 0,
+            updated_count: 0,
+            skipped_count: 0,
+            failures: Vec::new(),
+            warnings,
+        };
+        // 覆盖模式下已被占用的现有记录，避免多条导入记录覆盖同一条
+        let mut overwritten: HashSet<String> = HashSet::new();
+
+        for item in items {
+            if item.duplicate
+                || (item.has_conflict && request.conflict_strategy == RecordConflictStrategy::Skip)
+            {
+                result.skipped_count += 1;
+                continue;
+            }
+
+            let target = if item.has_conflict
+                && request.conflict_strategy == RecordConflictStrategy::Overwrite
+            {
+                existing
+                    .iter()
+                    .find(|r| same_name_and_type(r, &item.request) && !overwritten.contains(&r.id))
+            } else {
+                None
+            };
+
+            let change = match target {
+                Some(record) => {
+                    overwritten.insert(record.id.clone());
+                    RecordChange::Update {
+                        record_id: record.id.clone(),
+                        previous_value: record.data.display_value(),
+                        request: UpdateDnsRecordRequest {
+                            domain_id: item.request.domain_id.clone(),
+                            name: item.request.name.clone(),
+                            ttl: item.request.ttl,
+                            data: item.request.data.clone(),
+                            proxied: item.request.proxied,
+                        },
+                    }
+                }
+                None => RecordChange::Create {
+                    request: item.request.clone(),
+                },
+            };
+            let outcome = self
+                .dns
+                .apply_planned_change(
+                    &request.account_id,
+                    &request.domain_id,
+                    &change,
+                    target.cloned(),
+                    ChangeSource::Manual,
+                )
+                .await;
+
+            match outcome {
+                Ok(_) if target.is_some() => result.updated_count += 1,
+                Ok(_) => result.created_count += 1,
+                Err(e) => {
+                    result.failures.push(RecordImportFailure {
+                        name: item.request.name.clone(),
+                        record_type: format!("{:?}", item.request.data.record_type())
+                            .to_uppercase(),
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        log::info!(
+            "[RecordImport] {}: {} created, {} updated, {} skipped, {} failed",
+            request.domain_id,
+            result.created_count,
+            result.updated_count,
+            result.skipped_count,
+            result.failures.len()
+        );
+        Ok(result)
+    }
+
+    /// 解析文件并与现有记录比对
+    async fn prepare(
+        &self,
+        provider: &dyn DnsProvider,
+        request: &RecordImportRequest,
+    ) -> CoreResult<(Vec<RecordImportPreviewItem>, Vec<String>, Vec<DnsRecord>)> {
+        let domain = self
+            .ctx
+            .with_deadline(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(&request.domain_id),
+            )
+            .await?;
+        let parsed = match request.format {
+            RecordImportFormat::OctoDns => octodns::parse(&request.content)?,
+            RecordImportFormat::DnsControl => dnscontrol::parse(&request.content, &domain.name)?,
+        };
+        let existing = self.list_all_records(provider, &request.domain_id).await?;
+
+        let normalize = request.normalize;
+        let mut warnings = parsed.warnings;
+        let items = parsed
+            .records
+            .into_iter()
+            .map(|record| {
+                let mut request = CreateDnsRecordRequest {
+                    domain_id: request.domain_id.clone(),
+                    name: record.name,
+                    ttl: record.ttl,
+                    data: record.data,
+                    proxied: record.proxied,
+                };
+                if let Err(reason) = char_safety::screen_record(
+                    &mut request.name,
+                    &mut request.data,
+                    normalize,
+                    &mut warnings,
+                ) {
+                    warnings.push(format!("{}: {reason}", request.name));
+                }
+                let duplicate = existing
+                    .iter()
+                    .any(|r| same_name_and_type(r, &request) && r.data == request.data);
+                let has_conflict =
+                    !duplicate && existing.iter().any(|r| same_name_and_type(r, &request));
+                RecordImportPreviewItem {
+                    request,
+                    duplicate,
+                    has_conflict,
+                }
+            })
+            .collect();
+
+        Ok((items, warnings, existing))
+    }
+
+    async fn list_all_records(
+        &self,
+        provider: &dyn DnsProvider,
+        domain_id: &str,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size: LIST_PAGE_SIZE,
+                keyword: None,
+                record_type: None,
+                sort: None,
+            };
+            async move {
+                self.ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "list_records",
+                        provider.list_records(domain_id, &params),
+                    )
+                    .await
+                    .map(Page::from)
+            }
+        })
+        .try_collect()
+        .await
+    }
+}
+
+fn same_name_and_type(record: &DnsRecord, request: &CreateDnsRecordRequest) -> bool {
+    record.name.eq_ignore_ascii_case(&request.name)
+        && record.data.record_type() == request.data.record_type()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+
+    fn a(address: &str) -> RecordData {
+        RecordData::A {
+            address: address.to_string(),
+        }
+    }
+
+    /// www 与现有记录相同，api 与现有记录冲突，new 为新记录
+    const ZONE: &str = "\
+www:
+  type: A
+  value: 192.0.2.1
+api:
+  type: A
+  value: 192.0.2.2
+new:
+  type: A
+  value: 192.0.2.3
+";
+
+    async fn service() -> CoreResult<(RecordImportService, Arc<MockDnsProvider>)> {
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(
+            MockDnsProvider::new()
+                .with_domain("zone-1", "example.com")
+                .with_record("zone-1", "www", 3600, a("192.0.2.1"))
+                .with_record("zone-1", "api", 3600, a("192.0.2.9")),
+        );
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        Ok((RecordImportService::new(ctx), provider))
+    }
+
+    fn request(
+        content: &str,
+        conflict_strategy: RecordConflictStrategy,
+        normalize: bool,
+    ) -> RecordImportRequest {
+        RecordImportRequest {
+            account_id: "account-1".to_string(),
+            domain_id: "zone-1".to_string(),
+            format: RecordImportFormat::OctoDns,
+            content: content.to_string(),
+            conflict_strategy,
+            normalize,
+        }
+    }
+
+    fn values(provider: &MockDnsProvider) -> Vec<(String, String)> {
+        let mut values: Vec<(String, String)> = provider
+            .records()
+            .into_iter()
+            .map(|record| (record.name, record.data.display_value()))
+            .collect();
+        values.sort();
+        values
+    }
+
+    #[tokio::test]
+    async fn preview_marks_duplicates_and_conflicts() -> CoreResult<()> {
+        let (service, provider) = service().await?;
+        let preview = service
+            .preview_record_import(&request(ZONE, RecordConflictStrategy::Skip, false))
+            .await?;
+
+        let flags: Vec<(&str, bool, bool)> = preview
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.request.name.as_str(),
+                    item.duplicate,
+                    item.has_conflict,
+                )
+            })
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                ("www", true, false),
+                ("api", false, true),
+                ("new", false, false)
+            ]
+        );
+        assert_eq!(provider.records().len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn skip_strategy_keeps_conflicting_records() -> CoreResult<()> {
+        let (service, provider) = service().await?;
+        let result = service
+            .import_records(request(ZONE, RecordConflictStrategy::Skip, false))
+            .await?;
+
+        assert_eq!(
+            (
+                result.created_count,
+                result.updated_count,
+                result.skipped_count
+            ),
+            (1, 0, 2)
+        );
+        assert_eq!(
+            values(&provider),
+            vec![
+                ("api".to_string(), "192.0.2.9".to_string()),
+                ("new".to_string(), "192.0.2.3".to_string()),
+                ("www".to_string(), "192.0.2.1".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn overwrite_strategy_updates_conflicting_records() -> CoreResult<()> {
+        let (service, provider) = service().await?;
+        let result = service
+            .import_records(request(ZONE, RecordConflictStrategy::Overwrite, false))
+            .await?;
+
+        assert_eq!(
+            (
+                result.created_count,
+                result.updated_count,
+                result.skipped_count
+            ),
+            (1, 1, 1)
+        );
+        assert!(result.failures.is_empty());
+        assert_eq!(
+            values(&provider),
+            vec![
+                ("api".to_string(), "192.0.2.2".to_string()),
+                ("new".to_string(), "192.0.2.3".to_string()),
+                ("www".to_string(), "192.0.2.1".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn suspicious_characters_fail_unless_normalized() -> CoreResult<()> {
+        let zone = "cdn:\n  type: CNAME\n  value: \"cdn\u{200b}.example.net.\"\n";
+
+        let (service, provider) = service().await?;
+        let result = service
+            .import_records(request(zone, RecordConflictStrategy::Skip, false))
+            .await?;
+        assert_eq!(result.created_count, 0);
+        assert_eq!(result.failures.len(), 1);
+        assert!(result.warnings.iter().any(|w| w.starts_with("cdn:")));
+        assert_eq!(provider.records().len(), 2);
+
+        let result = service
+            .import_records(request(zone, RecordConflictStrategy::Skip, true))
+            .await?;
+        assert_eq!(result.created_count, 1);
+        assert!(values(&provider).contains(&("cdn".to_string(), "cdn.example.net".to_string())));
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+//! octoDNS zone YAML 解析
+//!
+//! 顶层为 `记录名 -> 记录 | [记录]` 的映射，空字符串表示根域。
+//! `dynamic`、`geo` 规则与 `octodns.healthcheck` 等修饰只导入默认值并给出警告。
+
+use serde_yaml::{Mapping, Value};
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::RecordData;
+
+use super::{strip_trailing_dot, ParsedRecord, ParsedRecords};
+
+/// octoDNS 默认 TTL
+const DEFAULT_TTL: u32 = 3600;
+
+/// 解析 octoDNS zone YAML
+pub(super) fn parse(content: &str) -> CoreResult<ParsedRecords> {
+    let root: Value = serde_yaml::from_str(content)
+        .map_err(|e| CoreError::ValidationError(format!("octoDNS YAML 解析失败: {e}")))?;
+    let Value::Mapping(root) = root else {
+        return Err(CoreError::ValidationError(
+            "octoDNS zone 文件顶层必须是记录名映射".to_string(),
+        ));
+    };
+
+    let mut parsed = ParsedRecords::default();
+    for (key, value) in &root {
+        let name = match key {
+            Value::String(s) if s.is_empty() => "@".to_string(),
+            Value::String(s) => s.clone(),
+            Value::Null => "@".to_string(),
+            other => {
+                parsed.warnings.push(format!("忽略无效的记录名: {other:?}"));
+                continue;
+            }
+        };
+
+        match value {
+            Value::Mapping(record) => parse_record(&name, record, &mut parsed),
+            Value::Sequence(records) => {
+                for record in records {
+                    if let Value::Mapping(record) = record {
+                        parse_record(&name, record, &mut parsed);
+                    } else {
+                        parsed.warnings.push(format!("{name}: 忽略无效的记录定义"));
+                    }
+                }
+            }
+            _ => parsed.warnings.push(format!("{name}: 忽略无效的记录定义")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_record(name: &str, record: &Mapping, parsed: &mut ParsedRecords) {
+    let Some(record_type) = get_str(record, "type").map(str::to_uppercase) else {
+        parsed.warnings.push(format!("{name}: 缺少 type 字段"));
+        return;
+    };
+    let label = format!("{name} {record_type}");
+
+    let ttl = get_u64(record, "ttl")
+        .and_then(|t| u32::try_from(t).ok())
+        .unwrap_or(DEFAULT_TTL);
+
+    // 不支持的修饰
+    for key in ["dynamic", "geo"] {
+        if record.contains_key(key) {
+            parsed
+                .warnings
+                .push(format!("{label}: 不支持 {key} 规则，仅导入默认值"));
+        }
+    }
+    let mut proxied = None;
+    if let Some(Value::Mapping(octodns)) = record.get("octodns") {
+        for (key, value) in octodns {
+            match key.as_str() {
+                Some("cloudflare") => {
+                    proxied = value.get("proxied").and_then(Value::as_bool);
+                }
+                Some(key) => parsed
+                    .warnings
+                    .push(format!("{label}: 忽略 octodns.{key} 配置")),
+                None => {}
+            }
+        }
+    }
+
+    let values: Vec<&Value> = match (record.get("values"), record.get("value")) {
+        (Some(Value::Sequence(values)), _) => values.iter().collect(),
+        (Some(value), _) | (None, Some(value)) => vec![value],
+        (None, None) => {
+            parsed.warnings.push(format!("{label}: 缺少 value/values"));
+            return;
+        }
+    };
+
+    for value in values {
+        match to_record_data(&record_type, value) {
+            Ok(data) => parsed.records.push(ParsedRecord {
+                name: name.to_string(),
+                ttl,
+                data,
+                proxied,
+            }),
+            Err(reason) => parsed.warnings.push(format!("{label}: {reason}")),
+        }
+    }
+}
+
+fn to_record_data(record_type: &str, value: &Value) -> Result<RecordData, String> {
+    let text = || {
+        value
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| "值必须是字符串".to_string())
+    };
+
+    match record_type {
+        "A" => Ok(RecordData::A { address: text()? }),
+        "AAAA" => Ok(RecordData::AAAA { address: text()? }),
+        "CNAME" => Ok(RecordData::CNAME {
+            target: strip_trailing_dot(&text()?),
+        }),
+        "NS" => Ok(RecordData::NS {
+            nameserver: strip_trailing_dot(&text()?),
+        }),
+        // octoDNS 要求 TXT 中的分号转义为 `\;`
+        "TXT" => Ok(RecordData::TXT {
+            text: text()?.replace("\\;", ";"),
+        }),
+        "MX" => Ok(RecordData::MX {
+            priority: field_u16(value, &["preference", "priority"])?,
+            exchange: strip_trailing_dot(&field_str(value, &["exchange", "value"])?),
+        }),
+        "SRV" => Ok(RecordData::SRV {
+            priority: field_u16(value, &["priority"])?,
+            weight: field_u16(value, &["weight"])?,
+            port: field_u16(value, &["port"])?,
+            target: strip_trailing_dot(&field_str(value, &["target"])?),
+        }),
+        "CAA" => Ok(RecordData::CAA {
+            flags: field_u16(value, &["flags"])
+                .ok()
+                .and_then(|f| u8::try_from(f).ok())
+                .unwrap_or(0),
+            tag: field_str(value, &["tag"])?,
+            value: field_str(value, &["value"])?,
+        }),
+        other => Err(format!("不支持的记录类型 {other}，已跳过")),
+    }
+}
+
+fn get_str<'a>(map: &'a Mapping, key: &str) -> Option<&'a str> {
+    map.get(key).and_then(Value::as_str)
+}
+
+fn get_u64(map: &Mapping, key: &str) -> Option<u64> {
+    map.get(key).and_then(Value::as_u64)
+}
+
+/// 读取字符串字段（按顺序尝试多个字段名）
+fn field_str(value: &Value, keys: &[&str]) -> Result<String, String> {
+    keys.iter()
+        .find_map(|key| value.get(key).and_then(Value::as_str))
+        .map(ToString::to_string)
+        .ok_or_else(|| format!("缺少 {} 字段", keys[0]))
+}
+
+/// 读取数字字段（按顺序尝试多个字段名）
+fn field_u16(value: &Value, keys: &[&str]) -> Result<u16, String> {
+    keys.iter()
+        .find_map(|key| value.get(key).and_then(Value::as_u64))
+        .and_then(|n| u16::try_from(n).ok())
+        .ok_or_else(|| format!("缺少或无效的 {} 字段", keys[0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        include_str!("../../../tests/fixtures/record_import/octodns_example.yaml");
+
+    #[test]
+    fn parses_example_zone() -> CoreResult<()> {
+        let parsed = parse(EXAMPLE)?;
+
+        let find = |name: &str, predicate: fn(&RecordData) -> bool| {
+            parsed
+                .records
+                .iter()
+                .filter(|r| r.name == name && predicate(&r.data))
+                .count()
+        };
+
+        assert_eq!(find("@", |d| matches!(d, RecordData::A { .. })), 2);
+        assert_eq!(find("@", |d| matches!(d, RecordData::MX { .. })), 2);
+        assert_eq!(find("@", |d| matches!(d, RecordData::CAA { .. })), 1);
+        assert_eq!(find("www", |d| matches!(d, RecordData::CNAME { .. })), 1);
+        assert_eq!(
+            find("_srv._tcp", |d| matches!(d, RecordData::SRV { .. })),
+            2
+        );
+
+        let txt = parsed
+            .records
+            .iter()
+            .find(|r| r.name == "txt")
+            .map(|r| r.data.display_value());
+        assert_eq!(txt.as_deref(), Some("v=spf1 -all; note"));
+
+        let proxied = parsed.records.iter().find(|r| r.name == "proxied");
+        assert_eq!(proxied.and_then(|r| r.proxied), Some(true));
+
+        // healthcheck / dynamic 修饰与不支持的类型只产生警告
+        assert!(parsed.warnings.iter().any(|w| w.contains("healthcheck")));
+        assert!(parsed.warnings.iter().any(|w| w.contains("dynamic")));
+        assert!(parsed.warnings.iter().any(|w| w.contains("PTR")));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_non_mapping_root() {
+        assert!(parse("- a\n- b\n").is_err());
+    }
+}
@@ -0,0 +1,1112 @@
+//! DNS 记录校验
+//!
+//! 写入服务商之前按记录类型检查常见错误（非法 IP、超长 TXT 分段、SRV 命名、CNAME 冲突、
+//! SOA 计时参数等）。
+//! 硬性错误拒绝写入；不合理的 TTL 等只给出警告和建议值。
+//! TTL 默认不超过 1 天，服务商声明了更高上限时以服务商为准。
+//! 不可见字符与同形字符默认拒绝，调用方可先用 [`RecordValidator::normalize_record`] 替换为 ASCII。
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ops::RangeInclusive;
+
+use crate::error::CoreError;
+use crate::services::char_safety;
+use crate::services::zone_file::{record_type_name, relative_name};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, RecordData, RecordValidationError, RecordValidationReport,
+    RecordValidationRule, RecordValidationWarning, UnsafeCharacter, UpdateDnsRecordRequest,
+};
+use crate::utils::domain_name;
+
+/// 服务商约定的"自动" TTL
+pub(crate) const AUTO_TTL: u32 = 1;
+
+/// 低于该值视为过短
+const MIN_REASONABLE_TTL: u32 = 60;
+
+/// 高于该值（7 天）视为过长
+const MAX_REASONABLE_TTL: u32 = 604_800;
+
+/// RFC 2181 §8 规定的 TTL 上限（2^31 - 1）
+const MAX_TTL: u32 = 2_147_483_647;
+
+/// 服务商未声明更高上限时允许的最大 TTL（1 天）
+pub(crate) const DEFAULT_MAX_TTL: u32 = 86_400;
+
+/// 单个 TXT character-string 的最大字节数（RFC 1035 §3.3）
+const MAX_TXT_SEGMENT: usize = dns_orchestrator_provider::txt::MAX_SEGMENT_BYTES;
+
+/// CAA 属性标签：RFC 8659 §4.2–4.4 的 `issue`、`issuewild`、`iodef` 与 RFC 9495 的 `issuemail`
+const CAA_TAGS: [&str; 4] = ["issue", "issuewild", "iodef", "issuemail"];
+
+/// SOA refresh 的建议范围（20 分钟 – 12 小时，RFC 1912 §2.2）
+const SOA_REFRESH_RANGE: RangeInclusive<u32> = 1200..=43_200;
+
+/// SOA expire 的建议范围（2 – 4 周，RFC 1912 §2.2）
+const SOA_EXPIRE_RANGE: RangeInclusive<u32> = 1_209_600..=2_419_200;
+
+/// SOA minimum（否定缓存时间）的建议上限（1 天，RFC 2308 §5）
+const SOA_MAX_MINIMUM: u32 = 86_400;
+
+/// 记录校验器
+pub struct RecordValidator;
+
+impl RecordValidator {
+    /// 按记录类型校验单条记录（纯逻辑，不访问服务商）
+    ///
+    /// TTL 上限为 [`DEFAULT_MAX_TTL`]，服务商允许更长的 TTL 时见 [`Self::validate_record_with_max_ttl`]。
+    /// CNAME 与同名记录的共存冲突需要现有记录，见 [`Self::check_conflicts`]。
+    pub fn validate_record(request: &CreateDnsRecordRequest) -> RecordValidationReport {
+        Self::validate_record_with_max_ttl(request, DEFAULT_MAX_TTL)
+    }
+
+    /// 同 [`Self::validate_record`]，`max_ttl` 为服务商声明的 TTL 上限（`ProviderLimits::max_ttl`）
+    ///
+    /// 低于 [`DEFAULT_MAX_TTL`] 的上限不在这里收紧，由账户能力检查拒绝。
+    pub fn validate_record_with_max_ttl(
+        request: &CreateDnsRecordRequest,
+        max_ttl: u32,
+    ) -> RecordValidationReport {
+        // 可疑字符会连带触发格式错误，只报告字符本身
+        let mut report = RecordValidationReport {
+            errors: Self::check_characters(request),
+            ..RecordValidationReport::default()
+        };
+        if report.has_errors() {
+            return report;
+        }
+        if domain_name::normalize_record_name(&request.name).is_err() {
+            push_error(
+                &mut report,
+                RecordValidationRule::Name,
+                "name",
+                format!("无效的记录名称: {}", request.name),
+            );
+        }
+        check_ttl(
+            request,
+            max_ttl.clamp(DEFAULT_MAX_TTL, MAX_TTL),
+            &mut report,
+        );
+
+        match &request.data {
+            RecordData::A { address } => {
+                if address.trim().parse::<Ipv4Addr>().is_err() {
+                    push_error(
+                        &mut report,
+                        RecordValidationRule::IpAddress,
+                        "address",
+                        format!("无效的 IPv4 地址: {address}"),
+                    );
+                }
+            }
+            RecordData::AAAA { address } => {
+                if address.trim().parse::<Ipv6Addr>().is_err() {
+                    push_error(
+                        &mut report,
+                        RecordValidationRule::IpAddress,
+                        "address",
+                        format!("无效的 IPv6 地址: {address}"),
+                    );
+                }
+            }
+            RecordData::CNAME { target } => {
+                check_hostname(
+                    &mut report,
+                    RecordValidationRule::Target,
+                    "CNAME",
+                    "target",
+                    target,
+                );
+                if is_apex(&request.name) {
+                    push_warning(
+                        &mut report,
+                        RecordValidationRule::CnameConflict,
+                        "根域名的 CNAME 会与 SOA/NS 记录冲突，仅支持 CNAME 拉平的服务商可用"
+                            .to_string(),
+                    );
+                }
+            }
+            // 优先级为 u16，0–65535 的范围由类型本身保证
+            RecordData::MX { priority, exchange } => {
+                if exchange.trim() == "." {
+                    // RFC 7505 空 MX：声明该域名不接收邮件
+                    if *priority != 0 {
+                        push_warning(
+                            &mut report,
+                            RecordValidationRule::Mx,
+                            "空 MX（目标为 \".\"）的优先级应为 0".to_string(),
+                        );
+                    }
+                } else {
+                    check_hostname(
+                        &mut report,
+                        RecordValidationRule::Mx,
+                        "MX",
+                        "exchange",
+                        exchange,
+                    );
+                }
+            }
+            RecordData::TXT { text } => check_txt(&mut report, text),
+            RecordData::NS { nameserver } => {
+                check_hostname(
+                    &mut report,
+                    RecordValidationRule::Target,
+                    "NS",
+                    "nameserver",
+                    nameserver,
+                );
+            }
+            RecordData::SRV { target, .. } => {
+                if !is_valid_srv_name(&request.name) {
+                    push_error(
+                        &mut report,
+                        RecordValidationRule::SrvName,
+                        "name",
+                        format!(
+                            "SRV 记录名称应为 _service._proto.name 格式（如 _sip._tcp）: {}",
+                            request.name
+                        ),
+                    );
+                }
+                // 目标为 "." 表示该服务不可用（RFC 2782）
+                if target.trim() != "." {
+                    check_hostname(
+                        &mut report,
+                        RecordValidationRule::Target,
+                        "SRV",
+                        "target",
+                        target,
+                    );
+                }
+            }
+            RecordData::CAA { tag, value, .. } => check_caa(&mut report, tag, value),
+            RecordData::SOA {
+                rname,
+                refresh,
+                retry,
+                expire,
+                minimum,
+                ..
+            } => {
+                check_soa_rname(&mut report, rname);
+                check_soa_timers(&mut report, *refresh, *retry, *expire, *minimum);
+            }
+        }
+
+        report
+    }
+
+    /// SOA 只能由服务商创建，更新时不能改动 mname 与 serial，也不能与其他类型互相转换
+    ///
+    /// `existing` 为域名下的现有记录，`record_id` 为正在更新的记录（新建时为 `None`）。
+    pub fn check_soa_write(
+        request: &CreateDnsRecordRequest,
+        existing: &[DnsRecord],
+        record_id: Option<&str>,
+    ) -> Vec<RecordValidationError> {
+        let current = record_id.and_then(|id| existing.iter().find(|record| record.id == id));
+        let messages = match (&request.data, current.map(|record| &record.data)) {
+            (RecordData::SOA { .. }, None) if record_id.is_none() => {
+                vec![("type", "SOA 记录由 DNS 服务商创建，不能新建".to_string())]
+            }
+            (
+                RecordData::SOA { mname, serial, .. },
+                Some(RecordData::SOA {
+                    mname: current_mname,
+                    serial: current_serial,
+                    ..
+                }),
+            ) => {
+                let mut messages = Vec::new();
+                if !mname
+                    .trim_end_matches('.')
+                    .eq_ignore_ascii_case(current_mname.trim_end_matches('.'))
+                {
+                    messages.push((
+                        "mname",
+                        format!(
+                            "SOA 的主服务器（mname）由 DNS 服务商维护，不能修改: {current_mname}"
+                        ),
+                    ));
+                }
+                if serial != current_serial {
+                    messages.push((
+                        "serial",
+                        format!("SOA 的 serial 由 DNS 服务商维护，不能修改: {current_serial}"),
+                    ));
+                }
+                messages
+            }
+            (RecordData::SOA { .. }, Some(data)) => {
+                vec![(
+                    "type",
+                    format!("不能把 {} 记录改为 SOA", record_type_name(data)),
+                )]
+            }
+            (data, Some(RecordData::SOA { .. })) => {
+                vec![(
+                    "type",
+                    format!("SOA 记录不能改为 {}", record_type_name(data)),
+                )]
+            }
+            _ => Vec::new(),
+        };
+
+        messages
+            .into_iter()
+            .map(|(field, message)| RecordValidationError {
+                rule: RecordValidationRule::Soa,
+                field: field.to_string(),
+                message,
+                character: None,
+            })
+            .collect()
+    }
+
+    /// 检查与同名现有记录的共存冲突：CNAME 不能与同名的任何其他记录共存（RFC 1034 §3.6.2）
+    ///
+    /// `origin` 为域名，用于统一服务商返回的 FQDN 与相对名称；`exclude_id` 为正在更新的记录。
+    pub fn check_conflicts(
+        request: &CreateDnsRecordRequest,
+        origin: &str,
+        existing: &[DnsRecord],
+        exclude_id: Option<&str>,
+    ) -> Vec<RecordValidationError> {
+        let name = relative_name(&request.name, origin);
+        let is_cname = matches!(request.data, RecordData::CNAME { .. });
+
+        let conflict = existing
+            .iter()
+            .filter(|record| Some(record.id.as_str()) != exclude_id)
+            .filter(|record| relative_name(&record.name, origin).eq_ignore_ascii_case(&name))
+            .find(|record| is_cname || matches!(record.data, RecordData::CNAME { .. }));
+
+        conflict
+            .map(|record| {
+                let message = if is_cname {
+                    format!(
+                        "{name} 已存在 {} 记录，CNAME 不能与同名的其他记录共存",
+                        record_type_name(&record.data)
+                    )
+                } else {
+                    format!("{name} 已存在 CNAME 记录，不能再添加其他类型的记录")
+                };
+                RecordValidationError {
+                    rule: RecordValidationRule::CnameConflict,
+                    field: "name".to_string(),
+                    message,
+                    character: None,
+                }
+            })
+            .into_iter()
+            .collect()
+    }
+
+    /// 检查名称与内容中的不可见字符、全角字符与同形字母，每个字符一条错误
+    pub fn check_characters(request: &CreateDnsRecordRequest) -> Vec<RecordValidationError> {
+        char_safety::find_in_record(&request.name, &request.data)
+            .into_iter()
+            .map(|ch| {
+                let fix = if ch.replacement.is_empty() {
+                    "删除".to_string()
+                } else {
+                    format!("替换为 \"{}\"", ch.replacement)
+                };
+                RecordValidationError {
+                    rule: RecordValidationRule::UnsafeCharacter,
+                    field: ch.field.clone(),
+                    message: format!("{}，可使用 normalize 自动{fix}", ch.describe()),
+                    character: Some(ch),
+                }
+            })
+            .collect()
+    }
+
+    /// 把校验错误合并为 [`CoreError::RecordValidation`]，没有错误时返回 `None`
+    ///
+    /// `field` 取第一个错误的字段，消息按出现顺序合并（去掉重复）。
+    pub fn rejection(errors: &[RecordValidationError]) -> Option<CoreError> {
+        let first = errors.first()?;
+        let mut messages: Vec<&str> = Vec::new();
+        for error in errors {
+            if !messages.contains(&error.message.as_str()) {
+                messages.push(&error.message);
+            }
+        }
+        Some(CoreError::RecordValidation {
+            field: first.field.clone(),
+            message: messages.join("; "),
+        })
+    }
+
+    /// 把可疑字符替换为对应 ASCII（不可见字符直接删除），返回所做替换
+    pub fn normalize_record(request: &mut CreateDnsRecordRequest) -> Vec<UnsafeCharacter> {
+        char_safety::normalize_record(&mut request.name, &mut request.data)
+    }
+
+    /// 同 [`Self::normalize_record`]，用于更新请求
+    pub fn normalize_update(request: &mut UpdateDnsRecordRequest) -> Vec<UnsafeCharacter> {
+        char_safety::normalize_record(&mut request.name, &mut request.data)
+    }
+}
+
+fn push_error(
+    report: &mut RecordValidationReport,
+    rule: RecordValidationRule,
+    field: &str,
+    message: String,
+) {
+    report.errors.push(RecordValidationError {
+        rule,
+        field: field.to_string(),
+        message,
+        character: None,
+    });
+}
+
+fn push_warning(report: &mut RecordValidationReport, rule: RecordValidationRule, message: String) {
+    report.warnings.push(RecordValidationWarning {
+        rule,
+        message,
+        suggested_ttl: None,
+    });
+}
+
+/// 按记录类型给出的建议 TTL
+const fn suggested_ttl(data: &RecordData) -> u32 {
+    match data {
+        // 地址类记录常随部署变动，短 TTL 便于切换
+        RecordData::A { .. } | RecordData::AAAA { .. } | RecordData::CNAME { .. } => 300,
+        // 委派记录很少变动
+        RecordData::NS { .. } => 86_400,
+        RecordData::MX { .. }
+        | RecordData::TXT { .. }
+        | RecordData::SRV { .. }
+        | RecordData::CAA { .. }
+        | RecordData::SOA { .. } => 3600,
+    }
+}
+
+fn check_ttl(request: &CreateDnsRecordRequest, max_ttl: u32, report: &mut RecordValidationReport) {
+    let ttl = request.ttl;
+    if ttl == AUTO_TTL {
+        return;
+    }
+    if ttl > max_ttl {
+        push_error(
+            report,
+            RecordValidationRule::Ttl,
+            "ttl",
+            format!("TTL {ttl} 超过上限 {max_ttl}"),
+        );
+        return;
+    }
+
+    let message = if ttl == 0 {
+        "TTL 为 0，解析器不会缓存该记录".to_string()
+    } else if ttl < MIN_REASONABLE_TTL {
+        format!("TTL 过短（{ttl} 秒），会显著增加解析请求")
+    } else if ttl > MAX_REASONABLE_TTL {
+        format!("TTL 过长（{ttl} 秒），修改后需要很久才能全面生效")
+    } else {
+        return;
+    };
+    report.warnings.push(RecordValidationWarning {
+        rule: RecordValidationRule::Ttl,
+        message,
+        suggested_ttl: Some(suggested_ttl(&request.data)),
+    });
+}
+
+fn check_hostname(
+    report: &mut RecordValidationReport,
+    rule: RecordValidationRule,
+    record_type: &str,
+    field: &str,
+    target: &str,
+) {
+    let target = target.trim();
+    let message = if target.is_empty() {
+        format!("{record_type} 记录的目标不能为空")
+    } else if target.contains(char::is_whitespace) {
+        format!("{record_type} 记录的目标包含空白字符: {target}")
+    } else if target.parse::<IpAddr>().is_ok() {
+        format!("{record_type} 记录的目标必须是主机名，不能是 IP 地址: {target}")
+    } else {
+        return;
+    };
+    push_error(report, rule, field, message);
+}
+
+/// rname 为管理员邮箱，`@` 写作 `.`（如 `hostmaster.example.com`）
+fn check_soa_rname(report: &mut RecordValidationReport, rname: &str) {
+    if rname.contains('@') {
+        push_error(
+            report,
+            RecordValidationRule::Soa,
+            "rname",
+            format!("SOA 的 rname 应把邮箱中的 @ 写作 .（如 hostmaster.example.com）: {rname}"),
+        );
+    } else {
+        check_hostname(report, RecordValidationRule::Soa, "SOA", "rname", rname);
+    }
+}
+
+/// 计时参数：相互矛盾的取值拒绝写入，偏离 RFC 1912 / RFC 2308 建议范围的只给出警告
+fn check_soa_timers(
+    report: &mut RecordValidationReport,
+    refresh: u32,
+    retry: u32,
+    expire: u32,
+    minimum: u32,
+) {
+    let zero = [("refresh", refresh), ("retry", retry), ("expire", expire)]
+        .into_iter()
+        .find(|(_, value)| *value == 0);
+    if let Some((field, _)) = zero {
+        push_error(
+            report,
+            RecordValidationRule::Soa,
+            field,
+            "SOA 的 refresh / retry / expire 不能为 0".to_string(),
+        );
+        return;
+    }
+    if retry >= refresh {
+        push_error(
+            report,
+            RecordValidationRule::Soa,
+            "retry",
+            format!("SOA 的 retry（{retry}）应小于 refresh（{refresh}）"),
+        );
+    }
+    // 过期时间不超过一次刷新加重试时，辅助服务器可能还没来得及重试就已让 zone 过期
+    if expire <= refresh.saturating_add(retry) {
+        push_error(
+            report,
+            RecordValidationRule::Soa,
+            "expire",
+            format!("SOA 的 expire（{expire}）应大于 refresh 与 retry 之和"),
+        );
+    }
+
+    if !SOA_REFRESH_RANGE.contains(&refresh) {
+        push_warning(
+            report,
+            RecordValidationRule::Soa,
+            format!(
+                "SOA 的 refresh 为 {refresh} 秒，建议在 {}–{} 秒之间",
+                SOA_REFRESH_RANGE.start(),
+                SOA_REFRESH_RANGE.end()
+            ),
+        );
+    }
+    if !SOA_EXPIRE_RANGE.contains(&expire) {
+        push_warning(
+            report,
+            RecordValidationRule::Soa,
+            format!(
+                "SOA 的 expire 为 {expire} 秒，建议在 {}–{} 秒（2–4 周）之间",
+                SOA_EXPIRE_RANGE.start(),
+                SOA_EXPIRE_RANGE.end()
+            ),
+        );
+    }
+    if minimum > SOA_MAX_MINIMUM {
+        push_warning(
+            report,
+            RecordValidationRule::Soa,
+            format!(
+                "SOA 的 minimum（否定缓存时间）为 {minimum} 秒，超过建议上限 {SOA_MAX_MINIMUM} 秒"
+            ),
+        );
+    }
+}
+
+fn check_txt(report: &mut RecordValidationReport, text: &str) {
+    // 未分段的长文本由 Provider 按服务商格式自动分段，总长度上限见 `check_capabilities`
+    let Some(lengths) = quoted_segment_lengths(text) else {
+        return;
+    };
+    for (index, length) in lengths.into_iter().enumerate() {
+        if length > MAX_TXT_SEGMENT {
+            push_error(
+                report,
+                RecordValidationRule::TxtLength,
+                "text",
+                format!(
+                    "TXT 第 {} 段长度为 {length} 字节，超过 {MAX_TXT_SEGMENT} 字节上限",
+                    index + 1
+                ),
+            );
+        }
+    }
+}
+
+/// 标签不区分大小写（RFC 8659 §4.1）；`iodef` 的取值须为 `mailto:` 或 HTTP(S) URL（§4.4）
+fn check_caa(report: &mut RecordValidationReport, tag: &str, value: &str) {
+    let tag = tag.trim();
+    if !CAA_TAGS.iter().any(|known| known.eq_ignore_ascii_case(tag)) {
+        push_error(
+            report,
+            RecordValidationRule::Caa,
+            "tag",
+            format!("不支持的 CAA 标签: {tag}（可用: {}）", CAA_TAGS.join(", ")),
+        );
+        return;
+    }
+    let value = value.trim();
+    let is_report_url = ["mailto:", "http://", "https://"].iter().any(|scheme| {
+        value
+            .get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    });
+    if tag.eq_ignore_ascii_case("iodef") && !is_report_url {
+        push_error(
+            report,
+            RecordValidationRule::Caa,
+            "value",
+            format!("CAA iodef 的取值应为 mailto: 或 http(s):// 地址: {value}"),
+        );
+    }
+}
+
+/// 带引号写法（`"part1" "part2"`）的各分段字节数，非该写法时返回 `None`
+///
+/// 转义序列（`\"`、`\\`、`\DDD`）按转义后的单个字符计算。
+fn quoted_segment_lengths(text: &str) -> Option<Vec<usize>> {
+    let text = text.trim();
+    if !text.starts_with('"') {
+        return None;
+    }
+
+    let mut lengths = Vec::new();
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        match chars.next() {
+            None => return Some(lengths),
+            Some('"') => {}
+            Some(_) => return None,
+        }
+
+        let mut length = 0;
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => {
+                    let escaped = chars.next()?;
+                    if escaped.is_ascii_digit() {
+                        chars.next()?;
+                        chars.next()?;
+                        length += 1;
+                    } else {
+                        length += escaped.len_utf8();
+                    }
+                }
+                c => length += c.len_utf8(),
+            }
+        }
+        lengths.push(length);
+    }
+}
+
+fn is_apex(name: &str) -> bool {
+    let name = name.trim();
+    name.is_empty() || name == "@"
+}
+
+/// SRV 名称：前两个标签为 `_service` 与 `_proto`
+fn is_valid_srv_name(name: &str) -> bool {
+    let mut labels = name.trim().split('.');
+    let underscore_label = |label: Option<&str>| {
+        label
+            .and_then(|label| label.strip_prefix('_'))
+            .is_some_and(|rest| {
+                !rest.is_empty() && rest.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            })
+    };
+    underscore_label(labels.next()) && underscore_label(labels.next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(name: &str, ttl: u32, data: RecordData) -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            name: name.to_string(),
+            ttl,
+            data,
+            proxied: None,
+        }
+    }
+
+    fn record(id: &str, name: &str, data: RecordData) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            domain_id: "example.com".to_string(),
+            name: name.to_string(),
+            ttl: 300,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn a(address: &str) -> RecordData {
+        RecordData::A {
+            address: address.to_string(),
+        }
+    }
+
+    fn txt(text: &str) -> RecordData {
+        RecordData::TXT {
+            text: text.to_string(),
+        }
+    }
+
+    fn cname(target: &str) -> RecordData {
+        RecordData::CNAME {
+            target: target.to_string(),
+        }
+    }
+
+    fn error_rules(report: &RecordValidationReport) -> Vec<RecordValidationRule> {
+        report.errors.iter().map(|e| e.rule).collect()
+    }
+
+    #[test]
+    fn test_ttl_warnings_suggest_per_type_value() {
+        let auto = RecordValidator::validate_record(&request("www", 1, a("192.0.2.1")));
+        assert!(auto.warnings.is_empty());
+
+        let zero = RecordValidator::validate_record(&request("www", 0, a("192.0.2.1")));
+        assert_eq!(zero.warnings[0].rule, RecordValidationRule::Ttl);
+        assert_eq!(zero.warnings[0].suggested_ttl, Some(300));
+        assert!(!zero.has_errors());
+
+        // 服务商允许时，超过 7 天的 TTL 只给出警告
+        let ns = RecordValidator::validate_record_with_max_ttl(
+            &request(
+                "dev",
+                MAX_TTL,
+                RecordData::NS {
+                    nameserver: "ns1.example.net".to_string(),
+                },
+            ),
+            MAX_TTL,
+        );
+        assert!(!ns.has_errors());
+        assert_eq!(ns.warnings[0].suggested_ttl, Some(86_400));
+
+        let over = RecordValidator::validate_record(&request("www", MAX_TTL + 1, a("192.0.2.1")));
+        assert_eq!(error_rules(&over), [RecordValidationRule::Ttl]);
+    }
+
+    #[test]
+    fn test_ttl_limit_follows_provider_max() {
+        let day = request("www", DEFAULT_MAX_TTL, a("192.0.2.1"));
+        assert!(!RecordValidator::validate_record(&day).has_errors());
+
+        let week = request("www", 604_800, a("192.0.2.1"));
+        assert_eq!(
+            error_rules(&RecordValidator::validate_record(&week)),
+            [RecordValidationRule::Ttl]
+        );
+        assert!(!RecordValidator::validate_record_with_max_ttl(&week, 604_800).has_errors());
+        // 服务商上限更低时不在这里收紧
+        assert!(!RecordValidator::validate_record_with_max_ttl(&day, 3600).has_errors());
+    }
+
+    #[test]
+    fn test_rejects_invalid_addresses() {
+        let bad_v4 = RecordValidator::validate_record(&request("www", 300, a("192.0.2.256")));
+        assert_eq!(error_rules(&bad_v4), [RecordValidationRule::IpAddress]);
+
+        let v6_in_a = RecordValidator::validate_record(&request("www", 300, a("2001:db8::1")));
+        assert!(v6_in_a.has_errors());
+
+        let aaaa = RecordValidator::validate_record(&request(
+            "www",
+            300,
+            RecordData::AAAA {
+                address: "2001:db8::1".to_string(),
+            },
+        ));
+        assert!(!aaaa.has_errors());
+    }
+
+    fn caa(tag: &str, value: &str) -> RecordData {
+        RecordData::CAA {
+            flags: 0,
+            tag: tag.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn error_fields(report: &RecordValidationReport) -> Vec<&str> {
+        report.errors.iter().map(|e| e.field.as_str()).collect()
+    }
+
+    #[test]
+    fn test_error_fields_per_record_type() {
+        let srv = |target: &str| RecordData::SRV {
+            priority: 10,
+            weight: 5,
+            port: 5060,
+            target: target.to_string(),
+        };
+        let cases = [
+            (request("www", 300, a("10.0.0")), "address"),
+            (
+                request(
+                    "www",
+                    300,
+                    RecordData::AAAA {
+                        address: "192.0.2.1".to_string(),
+                    },
+                ),
+                "address",
+            ),
+            (request("www", 300, cname("192.0.2.1")), "target"),
+            (
+                request(
+                    "@",
+                    300,
+                    RecordData::MX {
+                        priority: 10,
+                        exchange: "2001:db8::1".to_string(),
+                    },
+                ),
+                "exchange",
+            ),
+            (
+                request(
+                    "dev",
+                    300,
+                    RecordData::NS {
+                        nameserver: "ns1 example.net".to_string(),
+                    },
+                ),
+                "nameserver",
+            ),
+            (request("_sip._tcp", 300, srv("")), "target"),
+            (request("sip", 300, srv("sip.example.com")), "name"),
+            (
+                request("@", 300, txt(&format!("\"{}\"", "a".repeat(256)))),
+                "text",
+            ),
+            (request("@", 300, caa("policy", "letsencrypt.org")), "tag"),
+            (
+                request("@", 300, caa("iodef", "security@example.com")),
+                "value",
+            ),
+            (request("www", MAX_TTL + 1, a("192.0.2.1")), "ttl"),
+        ];
+        for (case, field) in cases {
+            let report = RecordValidator::validate_record(&case);
+            assert_eq!(error_fields(&report), [field], "{:?}", case.data);
+        }
+    }
+
+    #[test]
+    fn test_caa_tags() {
+        for (tag, value) in [
+            ("issue", "letsencrypt.org"),
+            ("ISSUEWILD", ";"),
+            ("iodef", "mailto:security@example.com"),
+            ("iodef", "https://example.com/caa-report"),
+            ("issuemail", "ca.example.net"),
+        ] {
+            let report = RecordValidator::validate_record(&request("@", 300, caa(tag, value)));
+            assert!(!report.has_errors(), "{tag} {value}");
+        }
+
+        let unknown = RecordValidator::validate_record(&request("@", 300, caa("issuer", "ca.net")));
+        assert_eq!(error_rules(&unknown), [RecordValidationRule::Caa]);
+        assert!(unknown.errors[0].message.contains("issuer"));
+    }
+
+    #[test]
+    fn test_rejection_keeps_first_field_and_merges_messages() {
+        assert!(RecordValidator::rejection(&[]).is_none());
+
+        let mut report =
+            RecordValidator::validate_record(&request("www", MAX_TTL + 1, a("not-an-ip")));
+        report.errors.push(report.errors[1].clone());
+        assert!(matches!(
+            RecordValidator::rejection(&report.errors),
+            Some(CoreError::RecordValidation { field, message })
+                if field == "ttl" && message.matches("IPv4").count() == 1 && message.contains("; ")
+        ));
+    }
+
+    #[test]
+    fn test_txt_segment_length() {
+        let long = "a".repeat(256);
+
+        let quoted = RecordValidator::validate_record(&request(
+            "@",
+            300,
+            txt(&format!("\"ok\" \"{long}\"")),
+        ));
+        assert_eq!(error_rules(&quoted), [RecordValidationRule::TxtLength]);
+        assert!(quoted.errors[0].message.contains("第 2 段"));
+
+        // 转义字符按一个字节计算
+        let escaped = format!("\"{}\\\"\"", "a".repeat(254));
+        let report = RecordValidator::validate_record(&request("@", 300, txt(&escaped)));
+        assert!(!report.has_errors());
+
+        // 未分段的长文本由 Provider 自动分段
+        let unquoted = RecordValidator::validate_record(&request("@", 300, txt(&long)));
+        assert!(!unquoted.has_errors());
+        assert!(unquoted.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_mx_and_hostname_targets() {
+        let mx = |priority: u16, exchange: &str| RecordData::MX {
+            priority,
+            exchange: exchange.to_string(),
+        };
+
+        let ip_target = RecordValidator::validate_record(&request("@", 300, mx(10, "192.0.2.1")));
+        assert_eq!(error_rules(&ip_target), [RecordValidationRule::Mx]);
+
+        let null_mx = RecordValidator::validate_record(&request("@", 300, mx(10, ".")));
+        assert!(!null_mx.has_errors());
+        assert_eq!(null_mx.warnings[0].rule, RecordValidationRule::Mx);
+
+        let empty_cname = RecordValidator::validate_record(&request("www", 300, cname(" ")));
+        assert_eq!(error_rules(&empty_cname), [RecordValidationRule::Target]);
+
+        let apex_cname =
+            RecordValidator::validate_record(&request("@", 300, cname("lb.example.net")));
+        assert!(!apex_cname.has_errors());
+        assert_eq!(
+            apex_cname.warnings[0].rule,
+            RecordValidationRule::CnameConflict
+        );
+    }
+
+    #[test]
+    fn test_srv_name_format() {
+        let srv = RecordData::SRV {
+            priority: 10,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com".to_string(),
+        };
+
+        for name in [
+            "_sip._tcp",
+            "_sip._tcp.example.com",
+            "_xmpp-server._tcp.chat",
+        ] {
+            let report = RecordValidator::validate_record(&request(name, 300, srv.clone()));
+            assert!(!report.has_errors(), "{name}");
+        }
+        for name in ["sip._tcp", "_sip", "_sip.tcp", "_._tcp"] {
+            let report = RecordValidator::validate_record(&request(name, 300, srv.clone()));
+            assert_eq!(
+                error_rules(&report),
+                [RecordValidationRule::SrvName],
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cname_conflicts_with_same_name_records() {
+        let existing = vec![
+            record("r1", "www.example.com", a("192.0.2.1")),
+            record("r2", "api", cname("backend.example.net")),
+        ];
+
+        let new_cname = request("WWW", 300, cname("lb.example.net"));
+        let errors = RecordValidator::check_conflicts(&new_cname, "example.com", &existing, None);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rule, RecordValidationRule::CnameConflict);
+
+        let new_a = request("api", 300, a("192.0.2.2"));
+        assert_eq!(
+            RecordValidator::check_conflicts(&new_a, "example.com", &existing, None).len(),
+            1
+        );
+
+        // 更新 CNAME 自身不算冲突
+        let update = request("api", 300, cname("other.example.net"));
+        assert!(
+            RecordValidator::check_conflicts(&update, "example.com", &existing, Some("r2"))
+                .is_empty()
+        );
+
+        let other_name = request("mail", 300, a("192.0.2.3"));
+        assert!(
+            RecordValidator::check_conflicts(&other_name, "example.com", &existing, None)
+                .is_empty()
+        );
+    }
+
+    fn soa(rname: &str, serial: u32, timers: [u32; 4]) -> RecordData {
+        let [refresh, retry, expire, minimum] = timers;
+        RecordData::SOA {
+            mname: "ns1.provider.net.".to_string(),
+            rname: rname.to_string(),
+            serial,
+            refresh,
+            retry,
+            expire,
+            minimum,
+        }
+    }
+
+    #[test]
+    fn test_soa_timers() {
+        let sane = request(
+            "@",
+            3600,
+            soa("hostmaster.example.com", 1, [7200, 3600, 1_209_600, 300]),
+        );
+        let report = RecordValidator::validate_record(&sane);
+        assert!(report.errors.is_empty() && report.warnings.is_empty());
+
+        let inverted = request(
+            "@",
+            3600,
+            soa("hostmaster.example.com", 1, [3600, 7200, 1_209_600, 300]),
+        );
+        assert_eq!(
+            error_rules(&RecordValidator::validate_record(&inverted)),
+            [RecordValidationRule::Soa]
+        );
+
+        let short_expire = request(
+            "@",
+            3600,
+            soa("hostmaster.example.com", 1, [7200, 3600, 9000, 300]),
+        );
+        let report = RecordValidator::validate_record(&short_expire);
+        assert_eq!(error_rules(&report), [RecordValidationRule::Soa]);
+        // 同时偏离 expire 建议范围
+        assert_eq!(report.warnings.len(), 1);
+
+        let unusual = request(
+            "@",
+            3600,
+            soa("hostmaster.example.com", 1, [600, 300, 4_838_400, 172_800]),
+        );
+        let report = RecordValidator::validate_record(&unusual);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings.len(), 3);
+
+        let email = request(
+            "@",
+            3600,
+            soa("admin@example.com", 1, [7200, 3600, 1_209_600, 300]),
+        );
+        assert_eq!(
+            error_rules(&RecordValidator::validate_record(&email)),
+            [RecordValidationRule::Soa]
+        );
+    }
+
+    #[test]
+    fn test_soa_write_keeps_provider_managed_fields() {
+        let timers = [7200, 3600, 1_209_600, 300];
+        let existing = vec![
+            record("soa", "@", soa("hostmaster.example.com.", 42, timers)),
+            record("r1", "www", a("192.0.2.1")),
+        ];
+
+        // 只改计时参数与 rname
+        let update = request(
+            "@",
+            3600,
+            soa("admin.example.com", 42, [3600, 600, 1_209_600, 300]),
+        );
+        assert!(RecordValidator::check_soa_write(&update, &existing, Some("soa")).is_empty());
+
+        let new_serial = request("@", 3600, soa("hostmaster.example.com", 43, timers));
+        assert_eq!(
+            RecordValidator::check_soa_write(&new_serial, &existing, Some("soa")).len(),
+            1
+        );
+
+        let mut new_mname = soa("hostmaster.example.com", 42, timers);
+        if let RecordData::SOA { mname, .. } = &mut new_mname {
+            *mname = "ns9.other.net".to_string();
+        }
+        assert_eq!(
+            RecordValidator::check_soa_write(
+                &request("@", 3600, new_mname),
+                &existing,
+                Some("soa")
+            )
+            .len(),
+            1
+        );
+
+        let create = request("@", 3600, soa("hostmaster.example.com", 1, timers));
+        assert_eq!(
+            RecordValidator::check_soa_write(&create, &existing, None).len(),
+            1
+        );
+        let to_soa = request("www", 3600, soa("hostmaster.example.com", 1, timers));
+        assert_eq!(
+            RecordValidator::check_soa_write(&to_soa, &existing, Some("r1")).len(),
+            1
+        );
+        let from_soa = request("@", 3600, a("192.0.2.9"));
+        assert_eq!(
+            RecordValidator::check_soa_write(&from_soa, &existing, Some("soa")).len(),
+            1
+        );
+        assert!(RecordValidator::check_soa_write(
+            &request("www", 300, a("192.0.2.2")),
+            &existing,
+            Some("r1")
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_unsafe_characters_reported_with_position() {
+        let report = RecordValidator::validate_record(&request(
+            "cdn",
+            300,
+            cname("cdn\u{200B}.example\u{3002}com"),
+        ));
+        assert_eq!(
+            error_rules(&report),
+            [
+                RecordValidationRule::UnsafeCharacter,
+                RecordValidationRule::UnsafeCharacter
+            ]
+        );
+        let positions: Vec<usize> = report
+            .errors
+            .iter()
+            .filter_map(|e| e.character.as_ref().map(|ch| ch.position))
+            .collect();
+        assert_eq!(positions, [4, 13]);
+        assert!(report.errors[0].message.contains("U+200B"));
+    }
+
+    #[test]
+    fn test_normalize_record_then_validate_passes() {
+        let mut req = request("www", 300, a("１９２.0.2.1"));
+        assert!(RecordValidator::validate_record(&req).has_errors());
+
+        let replaced = RecordValidator::normalize_record(&mut req);
+        assert_eq!(replaced.len(), 3);
+        assert_eq!(req.data, a("192.0.2.1"));
+        assert!(!RecordValidator::validate_record(&req).has_errors());
+    }
+}
@@ -0,0 +1,273 @@
+//! Provider 调用的失败重试
+//!
+//! 网络抖动、服务商限流（429）与服务端错误（5xx）会让写操作偶发失败。[`RetryPolicy`] 对这些
+//! 错误按指数退避加随机抖动重试，其他错误（凭证无效、记录不存在等）立即返回。
+//! 单条记录的写操作通过 [`retry_provider_op`] 重试，批量写入只重新提交失败的变更。
+//! 同一账户的并发调用数由 [`AccountPermits`] 限制，避免多个批量操作同时打满服务商配额。
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use dns_orchestrator_provider::ProviderError;
+use rand::Rng;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+use crate::error::CoreError;
+
+use super::write_queue_service::is_offline_error;
+
+/// 重试策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 最多尝试次数（含首次），1 表示不重试
+    pub max_attempts: u32,
+    /// 第一次重试前的等待时长，之后每次翻倍
+    pub base_delay: Duration,
+    /// 单次等待的上限（不含抖动）
+    pub max_delay: Duration,
+    /// 同一账户同时进行的 Provider 写调用数
+    pub max_concurrent_per_account: usize,
+    /// 是否在等待时长上叠加随机抖动
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            max_concurrent_per_account: 5,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 是否值得重试：网络错误、服务商限流与服务端错误
+    pub fn is_retryable(error: &CoreError) -> bool {
+        match error {
+            CoreError::Provider(error) => Self::is_retryable_provider_error(error),
+            error => is_offline_error(error),
+        }
+    }
+
+    /// [`Self::is_retryable`] 的 Provider 错误版本
+    pub fn is_retryable_provider_error(error: &ProviderError) -> bool {
+        match error {
+            ProviderError::NetworkError { .. } | ProviderError::QuotaExceeded { .. } => true,
+            ProviderError::HttpError { status, .. } => (500..600).contains(status),
+            _ => false,
+        }
+    }
+
+    /// 第 `attempt` 次尝试失败后、下一次尝试前的等待时长
+    ///
+    /// `base_delay * 2^(attempt - 1)`，不超过 `max_delay`。启用 `jitter` 时再加上至多一半的
+    /// 随机抖动，让同时失败的多个调用错开重试。
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1_u32 << exponent)
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let jitter_ms = u64::try_from(delay.as_millis() / 2).unwrap_or(u64::MAX);
+        delay + Duration::from_millis(rand::rng().random_range(0..=jitter_ms))
+    }
+}
+
+/// 按 `policy` 执行 Provider 调用，可重试的错误（见 [`RetryPolicy::is_retryable`]）
+/// 退避后重新调用 `op`，每次重试以 WARN 级别记录
+pub async fn retry_provider_op<T, F, Fut>(
+    policy: &RetryPolicy,
+    operation: &str,
+    mut op: F,
+) -> Result<T, ProviderError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ProviderError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(e)
+                if attempt < policy.max_attempts
+                    && RetryPolicy::is_retryable_provider_error(&e) =>
+            {
+                let delay = policy.backoff(attempt);
+                log::warn!(
+                    "[Retry] {operation} failed (attempt {attempt}/{}), retrying in {delay:?}: {e}",
+                    policy.max_attempts
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// 按账户限制并发的信号量
+pub(crate) struct AccountPermits {
+    limit: usize,
+    semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl AccountPermits {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            limit: limit.max(1),
+            semaphores: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 取得该账户的一个调用许可，许可释放前其他调用按先来后到等待
+    pub(crate) async fn acquire(&self, account_id: &str) -> OwnedSemaphorePermit {
+        let semaphore = Arc::clone(
+            self.semaphores
+                .lock()
+                .await
+                .entry(account_id.to_string())
+                .or_insert_with(|| Arc::new(Semaphore::new(self.limit))),
+        );
+        // 信号量从不关闭，acquire 不会失败
+        match semaphore.acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => unreachable!("account semaphore is never closed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay_with_bounded_jitter() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            max_concurrent_per_account: 1,
+            jitter: true,
+        };
+        for (attempt, base) in [(1, 100), (2, 200), (3, 300), (10, 300)] {
+            let delay = policy.backoff(attempt);
+            assert!(delay >= Duration::from_millis(base), "{attempt}: {delay:?}");
+            assert!(
+                delay <= Duration::from_millis(base * 3 / 2),
+                "{attempt}: {delay:?}"
+            );
+        }
+
+        let exact = RetryPolicy {
+            jitter: false,
+            ..policy
+        };
+        assert_eq!(exact.backoff(2), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn only_transient_errors_are_retryable() {
+        let provider = "cloudflare".to_string();
+        assert!(RetryPolicy::is_retryable(&CoreError::from(
+            ProviderError::NetworkError {
+                provider: provider.clone(),
+                detail: "connection reset".to_string(),
+            }
+        )));
+        assert!(RetryPolicy::is_retryable(&CoreError::from(
+            ProviderError::QuotaExceeded {
+                provider: provider.clone(),
+                raw_message: Some("429".to_string()),
+            }
+        )));
+        assert!(RetryPolicy::is_retryable(&CoreError::from(
+            ProviderError::HttpError {
+                provider: provider.clone(),
+                status: 503,
+                raw_message: None,
+            }
+        )));
+        assert!(!RetryPolicy::is_retryable(&CoreError::from(
+            ProviderError::InvalidCredentials {
+                provider: provider.clone(),
+                raw_message: None,
+            }
+        )));
+        assert!(!RetryPolicy::is_retryable(&CoreError::from(
+            ProviderError::RecordNotFound {
+                provider,
+                record_id: "rec-1".to_string(),
+                raw_message: None,
+            }
+        )));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_provider_op_stops_at_max_attempts() {
+        // 默认最多尝试 3 次
+        let policy = RetryPolicy::default();
+        let server_error = || ProviderError::HttpError {
+            provider: "cloudflare".to_string(),
+            status: 502,
+            raw_message: None,
+        };
+
+        let mut calls = 0;
+        let result = retry_provider_op(&policy, "create_record", || {
+            calls += 1;
+            let result = if calls < 3 {
+                Err(server_error())
+            } else {
+                Ok(calls)
+            };
+            async move { result }
+        })
+        .await;
+        assert!(matches!(result, Ok(3)));
+
+        let mut calls = 0;
+        let result: Result<(), _> = retry_provider_op(&policy, "delete_record", || {
+            calls += 1;
+            let error = server_error();
+            async move { Err(error) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+
+        // 不可重试的错误立即返回
+        let mut calls = 0;
+        let result: Result<(), _> = retry_provider_op(&policy, "update_record", || {
+            calls += 1;
+            async {
+                Err(ProviderError::PermissionDenied {
+                    provider: "cloudflare".to_string(),
+                    raw_message: None,
+                })
+            }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test]
+    async fn permits_are_limited_per_account() {
+        let permits = AccountPermits::new(1);
+        let held = permits.acquire("acc-1").await;
+        // 其他账户不受影响
+        let _other = permits.acquire("acc-2").await;
+
+        let waiting = tokio::time::timeout(Duration::from_millis(20), permits.acquire("acc-1"));
+        assert!(waiting.await.is_err());
+        drop(held);
+        let _next = permits.acquire("acc-1").await;
+    }
+}
@@ -0,0 +1,545 @@
+//! 临时记录服务
+//!
+//! 临时记录在生效时间段内存在：到 `active_from` 时创建记录，到 `active_until` 时删除记录。
+//! 两个计划任务作为一个临时记录实体保存在仓库中，修改时间、取消都同步作用于两个任务。
+//! 平台层按固定间隔调用 [`TemporaryRecordService::run_due_tasks`] 执行到期的任务，
+//! 因此实际执行时间最多比计划晚一个检查间隔。
+//!
+//! 删除前比对创建时记住的记录指纹：记录已被手工删除时视为已清理，
+//! 记录已被修改时不删除并标记为异常，避免误删别人改过的记录。
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use dns_orchestrator_provider::ProviderError;
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::write_queue_service::is_offline_error;
+use crate::services::{DnsService, RecordValidator, ServiceContext};
+use crate::traits::TemporaryRecordRepository;
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, TemporaryRecord, TemporaryRecordStatus, TemporaryRecordTask,
+    TemporaryRecordTaskKind,
+};
+
+/// 临时记录服务
+pub struct TemporaryRecordService {
+    ctx: Arc<ServiceContext>,
+    dns: DnsService,
+    repository: Arc<dyn TemporaryRecordRepository>,
+    /// 串行化临时记录的读改写（执行到期任务与修改、取消互斥）
+    lock: Mutex<()>,
+}
+
+impl TemporaryRecordService {
+    /// 创建临时记录服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>, repository: Arc<dyn TemporaryRecordRepository>) -> Self {
+        Self {
+            dns: DnsService::new(Arc::clone(&ctx)),
+            ctx,
+            repository,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// 创建临时记录（到点创建、到点删除）
+    ///
+    /// `request.domain_id` 以 `domain_id` 为准。记录内容在此按类型规则校验，
+    /// 与现有记录的冲突在到点创建时检查。
+    pub async fn create_temporary_record(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        mut request: CreateDnsRecordRequest,
+        active_from: DateTime<Utc>,
+        active_until: DateTime<Utc>,
+    ) -> CoreResult<TemporaryRecord> {
+        validate_period(active_from, active_until, Utc::now())?;
+        request.domain_id = domain_id.to_string();
+        let report = RecordValidator::validate_record(&request);
+        if let Some(error) = RecordValidator::rejection(&report.errors) {
+            return Err(error);
+        }
+        self.ctx.get_provider(account_id).await?;
+
+        let now = Utc::now();
+        let record = TemporaryRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            account_id: account_id.to_string(),
+            domain_id: domain_id.to_string(),
+            request,
+            status: TemporaryRecordStatus::Pending,
+            create_task: TemporaryRecordTask::new(TemporaryRecordTaskKind::Create, active_from),
+            delete_task: TemporaryRecordTask::new(TemporaryRecordTaskKind::Delete, active_until),
+            record_id: None,
+            fingerprint: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let _guard = self.lock.lock().await;
+        self.repository.save(&record).await?;
+        log::info!(
+            "[TemporaryRecord] {} scheduled: {} in {domain_id} from {active_from} until {active_until}",
+            record.id,
+            record.request.name
+        );
+        Ok(record)
+    }
+
+    /// 列出所有临时记录（按生效时间升序）
+    pub async fn list_temporary_records(&self) -> CoreResult<Vec<TemporaryRecord>> {
+        let mut records = self.repository.find_all().await?;
+        records.sort_by_key(TemporaryRecord::active_from);
+        Ok(records)
+    }
+
+    /// 获取临时记录
+    pub async fn get_temporary_record(&self, id: &str) -> CoreResult<TemporaryRecord> {
+        self.repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| CoreError::ValidationError(format!("临时记录不存在: {id}")))
+    }
+
+    /// 修改生效时间段，同步调整两个计划任务
+    ///
+    /// 待生效时两个时间都可修改；生效中只能修改失效时间（`active_from` 需保持不变）；
+    /// 已清理或异常的临时记录不能修改。
+    pub async fn reschedule_temporary_record(
+        &self,
+        id: &str,
+        active_from: DateTime<Utc>,
+        active_until: DateTime<Utc>,
+    ) -> CoreResult<TemporaryRecord> {
+        let _guard = self.lock.lock().await;
+        let mut record = self.get_temporary_record(id).await?;
+        match record.status {
+            TemporaryRecordStatus::Pending => {
+                validate_period(active_from, active_until, Utc::now())?;
+                record.create_task.run_at = active_from;
+            }
+            TemporaryRecordStatus::Active => {
+                if active_from != record.active_from() {
+                    return Err(CoreError::ValidationError(
+                        "记录已生效，不能修改生效时间".to_string(),
+                    ));
+                }
+                if active_until <= Utc::now() {
+                    return Err(CoreError::ValidationError(
+                        "失效时间必须晚于当前时间".to_string(),
+                    ));
+                }
+            }
+            TemporaryRecordStatus::Cleaned | TemporaryRecordStatus::Failed => {
+                return Err(CoreError::ValidationError(
+                    "临时记录已结束，不能修改时间".to_string(),
+                ));
+            }
+        }
+        record.delete_task.run_at = active_until;
+        record.updated_at = Utc::now();
+        self.repository.save(&record).await?;
+        Ok(record)
+    }
+
+    /// 取消临时记录：移除两个计划任务
+    ///
+    /// 已创建的记录保留不动，需要时请手动删除。
+    pub async fn cancel_temporary_record(&self, id: &str) -> CoreResult<()> {
+        let _guard = self.lock.lock().await;
+        let record = self.get_temporary_record(id).await?;
+        self.repository.delete(id).await?;
+        log::info!(
+            "[TemporaryRecord] {id} cancelled in status {:?}",
+            record.status
+        );
+        Ok(())
+    }
+
+    /// 执行所有到期的计划任务，返回状态发生变化的临时记录
+    pub async fn run_due_tasks(&self) -> CoreResult<Vec<TemporaryRecord>> {
+        self.run_due_tasks_at(Utc::now()).await
+    }
+
+    async fn run_due_tasks_at(&self, now: DateTime<Utc>) -> CoreResult<Vec<TemporaryRecord>> {
+        let _guard = self.lock.lock().await;
+        let mut changed = Vec::new();
+        for mut record in self.repository.find_all().await? {
+            let before = task_state(&record);
+            match record.status {
+                TemporaryRecordStatus::Pending => self.run_create(&mut record, now).await,
+                TemporaryRecordStatus::Active if record.active_until() <= now => {
+                    self.run_delete(&mut record, now).await;
+                }
+                _ => continue,
+            }
+            if task_state(&record) == before {
+                continue;
+            }
+            record.updated_at = now;
+            self.repository.save(&record).await?;
+            if record.status != before.0 {
+                changed.push(record);
+            }
+        }
+        Ok(changed)
+    }
+
+    /// 待生效：到点创建记录；失效时间已过仍未创建时标记为异常
+    async fn run_create(&self, record: &mut TemporaryRecord, now: DateTime<Utc>) {
+        if record.active_until() <= now {
+            record.status = TemporaryRecordStatus::Failed;
+            record.error = Some("错过生效时间段，记录未创建".to_string());
+            log::warn!("[TemporaryRecord] {} missed its active period", record.id);
+            return;
+        }
+        if record.active_from() > now {
+            return;
+        }
+
+        match self
+            .dns
+            .create_scheduled_record(&record.account_id, record.request.clone())
+            .await
+        {
+            Ok(created) => {
+                record.status = TemporaryRecordStatus::Active;
+                record.fingerprint = Some(record_fingerprint(&created));
+                record.record_id = Some(created.id);
+                record.create_task.executed_at = Some(now);
+                record.create_task.last_error = None;
+                log::info!("[TemporaryRecord] {} activated", record.id);
+            }
+            Err(e) => fail_task(record, TemporaryRecordTaskKind::Create, &e),
+        }
+    }
+
+    /// 生效中：到点删除记录（记录已不存在时视为已清理，已被修改时不删除）
+    async fn run_delete(&self, record: &mut TemporaryRecord, now: DateTime<Utc>) {
+        let Some(record_id) = record.record_id.clone() else {
+            record.status = TemporaryRecordStatus::Failed;
+            record.error = Some("缺少已创建记录的 ID，无法清理".to_string());
+            return;
+        };
+
+        let current = match self
+            .dns
+            .find_record(&record.account_id, &record.domain_id, &record_id)
+            .await
+        {
+            Ok(current) => current,
+            Err(e) => {
+                fail_task(record, TemporaryRecordTaskKind::Delete, &e);
+                return;
+            }
+        };
+        let Some(current) = current else {
+            record.status = TemporaryRecordStatus::Cleaned;
+            record.delete_task.executed_at = Some(now);
+            record.delete_task.last_error = None;
+            log::info!(
+                "[TemporaryRecord] {} already removed by someone else",
+                record.id
+            );
+            return;
+        };
+        if record.fingerprint.as_deref() != Some(record_fingerprint(&current).as_str()) {
+            record.status = TemporaryRecordStatus::Failed;
+            record.error = Some("记录在生效期间已被修改，未自动删除".to_string());
+            log::warn!(
+                "[TemporaryRecord] {} was modified since creation, record {record_id} kept",
+                record.id
+            );
+            return;
+        }
+
+        match self
+            .dns
+            .delete_scheduled_record(&record.account_id, &record_id, &record.domain_id)
+            .await
+        {
+            Ok(()) | Err(CoreError::Provider(ProviderError::RecordNotFound { .. })) => {
+                record.status = TemporaryRecordStatus::Cleaned;
+                record.delete_task.executed_at = Some(now);
+                record.delete_task.last_error = None;
+                log::info!("[TemporaryRecord] {} cleaned up", record.id);
+            }
+            Err(e) => fail_task(record, TemporaryRecordTaskKind::Delete, &e),
+        }
+    }
+}
+
+/// 校验生效时间段
+fn validate_period(
+    active_from: DateTime<Utc>,
+    active_until: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> CoreResult<()> {
+    if active_until <= active_from {
+        return Err(CoreError::ValidationError(
+            "失效时间必须晚于生效时间".to_string(),
+        ));
+    }
+    if active_until <= now {
+        return Err(CoreError::ValidationError(
+            "失效时间必须晚于当前时间".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 记录任务失败：可重试的错误保留状态等待下次检查，其他错误标记为异常
+fn fail_task(record: &mut TemporaryRecord, kind: TemporaryRecordTaskKind, error: &CoreError) {
+    let task = match kind {
+        TemporaryRecordTaskKind::Create => &mut record.create_task,
+        TemporaryRecordTaskKind::Delete => &mut record.delete_task,
+    };
+    task.last_error = Some(error.to_string());
+    if is_retryable(error) {
+        log::warn!(
+            "[TemporaryRecord] {} {kind:?} task failed, will retry: {error}",
+            record.id
+        );
+        return;
+    }
+    log::error!(
+        "[TemporaryRecord] {} {kind:?} task failed: {error}",
+        record.id
+    );
+    record.status = TemporaryRecordStatus::Failed;
+    record.error = Some(error.to_string());
+}
+
+/// 网络错误、超时、禁止变更时段与写锁占用在下次检查时重试
+fn is_retryable(error: &CoreError) -> bool {
+    is_offline_error(error)
+        || matches!(
+            error,
+            CoreError::Timeout { .. }
+                | CoreError::ChangeWindowClosed { .. }
+                | CoreError::ResourceBusy { .. }
+        )
+}
+
+/// 状态与两个任务的失败原因，执行前后不同时才需要保存
+fn task_state(record: &TemporaryRecord) -> (TemporaryRecordStatus, Option<String>, Option<String>) {
+    (
+        record.status,
+        record.create_task.last_error.clone(),
+        record.delete_task.last_error.clone(),
+    )
+}
+
+/// 记录内容指纹：名称、TTL、值与代理状态的 SHA-256
+fn record_fingerprint(record: &DnsRecord) -> String {
+    let content = serde_json::json!({
+        "name": record.name,
+        "ttl": record.ttl,
+        "data": record.data,
+        "proxied": record.proxied,
+    });
+    hex::encode(Sha256::digest(content.to_string().as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Duration;
+    use dns_orchestrator_provider::DnsProvider;
+    use tokio::sync::RwLock;
+
+    use super::*;
+    use crate::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+    use crate::types::{RecordData, UpdateDnsRecordRequest};
+
+    #[derive(Default)]
+    struct InMemoryTemporaryRecordRepository {
+        records: RwLock<HashMap<String, TemporaryRecord>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TemporaryRecordRepository for InMemoryTemporaryRecordRepository {
+        async fn find_all(&self) -> CoreResult<Vec<TemporaryRecord>> {
+            Ok(self.records.read().await.values().cloned().collect())
+        }
+
+        async fn find_by_id(&self, id: &str) -> CoreResult<Option<TemporaryRecord>> {
+            Ok(self.records.read().await.get(id).cloned())
+        }
+
+        async fn save(&self, record: &TemporaryRecord) -> CoreResult<()> {
+            self.records
+                .write()
+                .await
+                .insert(record.id.clone(), record.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, id: &str) -> CoreResult<()> {
+            self.records.write().await.remove(id);
+            Ok(())
+        }
+    }
+
+    async fn setup() -> CoreResult<(TemporaryRecordService, Arc<MockDnsProvider>)> {
+        let ctx = Arc::new(in_memory_context());
+        let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        let service = TemporaryRecordService::new(
+            ctx,
+            Arc::new(InMemoryTemporaryRecordRepository::default()),
+        );
+        Ok((service, provider))
+    }
+
+    fn cname(target: &str) -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: String::new(),
+            name: "promo".to_string(),
+            ttl: 300,
+            data: RecordData::CNAME {
+                target: target.to_string(),
+            },
+            proxied: None,
+        }
+    }
+
+    async fn schedule(
+        service: &TemporaryRecordService,
+    ) -> CoreResult<(TemporaryRecord, DateTime<Utc>)> {
+        let from = Utc::now() + Duration::hours(1);
+        let record = service
+            .create_temporary_record(
+                "account-1",
+                "zone-1",
+                cname("campaign.example.net"),
+                from,
+                from + Duration::days(1),
+            )
+            .await?;
+        Ok((record, from))
+    }
+
+    #[tokio::test]
+    async fn creates_and_removes_record_on_schedule() -> CoreResult<()> {
+        let (service, provider) = setup().await?;
+        let (scheduled, from) = schedule(&service).await?;
+        assert_eq!(scheduled.status, TemporaryRecordStatus::Pending);
+        assert_eq!(scheduled.request.domain_id, "zone-1");
+
+        // 未到生效时间
+        assert!(service.run_due_tasks_at(Utc::now()).await?.is_empty());
+        assert!(provider.records().is_empty());
+
+        let changed = service.run_due_tasks_at(from).await?;
+        assert_eq!(changed.len(), 1);
+        let active = service.get_temporary_record(&scheduled.id).await?;
+        assert_eq!(active.status, TemporaryRecordStatus::Active);
+        assert!(active.create_task.executed_at.is_some());
+        assert_eq!(provider.records().len(), 1);
+        assert_eq!(
+            active.record_id.as_deref(),
+            Some(provider.records()[0].id.as_str())
+        );
+
+        service.run_due_tasks_at(active.active_until()).await?;
+        let cleaned = service.get_temporary_record(&scheduled.id).await?;
+        assert_eq!(cleaned.status, TemporaryRecordStatus::Cleaned);
+        assert!(cleaned.delete_task.executed_at.is_some());
+        assert!(provider.records().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cleanup_respects_manual_changes() -> CoreResult<()> {
+        // 记录已被手工删除：静默视为已清理
+        let (service, provider) = setup().await?;
+        let (scheduled, from) = schedule(&service).await?;
+        service.run_due_tasks_at(from).await?;
+        let record_id = provider.records()[0].id.clone();
+        provider.delete_record(&record_id, "zone-1").await?;
+        service.run_due_tasks_at(scheduled.active_until()).await?;
+        let cleaned = service.get_temporary_record(&scheduled.id).await?;
+        assert_eq!(cleaned.status, TemporaryRecordStatus::Cleaned);
+        assert_eq!(cleaned.error, None);
+
+        // 记录值已被修改：不删除并标记为异常
+        let (scheduled, from) = schedule(&service).await?;
+        service.run_due_tasks_at(from).await?;
+        let record_id = provider.records()[0].id.clone();
+        provider
+            .update_record(
+                &record_id,
+                &UpdateDnsRecordRequest {
+                    domain_id: "zone-1".to_string(),
+                    name: "promo".to_string(),
+                    ttl: 300,
+                    data: RecordData::CNAME {
+                        target: "other.example.net".to_string(),
+                    },
+                    proxied: None,
+                },
+            )
+            .await?;
+        let changed = service.run_due_tasks_at(scheduled.active_until()).await?;
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].status, TemporaryRecordStatus::Failed);
+        assert!(changed[0].error.is_some());
+        assert_eq!(provider.records().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reschedule_moves_both_tasks() -> CoreResult<()> {
+        let (service, _provider) = setup().await?;
+        let (scheduled, from) = schedule(&service).await?;
+
+        let later = from + Duration::hours(2);
+        let moved = service
+            .reschedule_temporary_record(&scheduled.id, later, later + Duration::hours(3))
+            .await?;
+        assert_eq!(moved.active_from(), later);
+        assert_eq!(moved.active_until(), later + Duration::hours(3));
+        assert!(matches!(
+            service
+                .reschedule_temporary_record(&scheduled.id, later, later)
+                .await,
+            Err(CoreError::ValidationError(_))
+        ));
+
+        // 生效后只能修改失效时间
+        service.run_due_tasks_at(later).await?;
+        assert!(matches!(
+            service
+                .reschedule_temporary_record(&scheduled.id, from, later + Duration::days(2))
+                .await,
+            Err(CoreError::ValidationError(_))
+        ));
+        let extended = service
+            .reschedule_temporary_record(&scheduled.id, later, later + Duration::days(2))
+            .await?;
+        assert_eq!(extended.status, TemporaryRecordStatus::Active);
+        assert_eq!(extended.active_until(), later + Duration::days(2));
+
+        service.cancel_temporary_record(&scheduled.id).await?;
+        assert!(service.list_temporary_records().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missed_period_is_reported() -> CoreResult<()> {
+        let (service, provider) = setup().await?;
+        let (scheduled, _) = schedule(&service).await?;
+        let changed = service.run_due_tasks_at(scheduled.active_until()).await?;
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].status, TemporaryRecordStatus::Failed);
+        assert!(provider.records().is_empty());
+        Ok(())
+    }
+}
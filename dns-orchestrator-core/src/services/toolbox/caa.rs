@@ -0,0 +1,374 @@
+//! CAA 记录检查模块
+//!
+//! 按 RFC 8659 §3 从域名开始逐级向上查找第一个非空的 CAA 记录集，
+//! 解析 `issue` / `issuewild` / `iodef` / `contactemail` 并给出签发限制的解读。
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    CaaCheckResult, DnsQuery, DnsQueryType, DnsRecordData, DnsServer, ParsedCaaRecord,
+};
+
+use super::HickoryResolverBackend;
+
+/// CA 普遍识别的属性标签（RFC 8659、RFC 9495 与 CA/B 论坛基线要求）
+const KNOWN_TAGS: &[&str] = &[
+    "issue",
+    "issuewild",
+    "issuemail",
+    "issuevmc",
+    "iodef",
+    "contactemail",
+    "contactphone",
+];
+
+/// CAA 检查
+pub async fn caa_check(domain: &str) -> CoreResult<CaaCheckResult> {
+    check(&HickoryResolverBackend, domain).await
+}
+
+pub(super) async fn check(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+) -> CoreResult<CaaCheckResult> {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    // 通配符证书按去掉 `*.` 后的域名查找
+    let domain = domain.strip_prefix("*.").unwrap_or(&domain).to_string();
+    if domain.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+
+    let (effective_domain, records) = relevant_records(backend, &domain).await?;
+    log::debug!(
+        "[CAA] {domain}: {} records found at {effective_domain:?}",
+        records.len()
+    );
+    Ok(analyze(domain, effective_domain, records))
+}
+
+/// 逐级向上查找，第一个非空的 CAA 记录集即为生效记录（不查询根域）
+async fn relevant_records(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+) -> CoreResult<(String, Vec<ParsedCaaRecord>)> {
+    let mut name = domain;
+    loop {
+        let records = caa(backend, name).await?;
+        if !records.is_empty() {
+            return Ok((name.to_string(), records));
+        }
+        match name.split_once('.') {
+            Some((_, parent)) if !parent.is_empty() => name = parent,
+            _ => return Ok((String::new(), Vec::new())),
+        }
+    }
+}
+
+async fn caa(backend: &dyn DnsResolverBackend, name: &str) -> CoreResult<Vec<ParsedCaaRecord>> {
+    match backend
+        .query(&DnsQuery::new(name, DnsQueryType::Caa, DnsServer::System))
+        .await
+    {
+        // 解析器会跟随 CNAME，应答中的 CNAME 记录直接忽略
+        Ok(response) => Ok(response
+            .records
+            .iter()
+            .filter_map(|record| match &record.data {
+                DnsRecordData::Caa {
+                    critical,
+                    tag,
+                    value,
+                } => Some(parse_record(*critical, tag, value)),
+                _ => None,
+            })
+            .collect()),
+        Err(e) if e.is_answered() => Ok(Vec::new()),
+        Err(e) => Err(CoreError::NetworkError(format!(
+            "查询 {name} CAA 记录失败: {e}"
+        ))),
+    }
+}
+
+fn is_issue_tag(tag: &str) -> bool {
+    tag == "issue" || tag == "issuewild"
+}
+
+/// 解析单条 CAA 记录，`issue` / `issuewild` 的值格式为 `[issuer] *(; key=value)`
+fn parse_record(critical: bool, tag: &str, value: &str) -> ParsedCaaRecord {
+    let tag = tag.to_ascii_lowercase();
+    let (issuer, parameters) = if is_issue_tag(&tag) {
+        let mut parts = value.split(';');
+        let issuer = parts.next().unwrap_or_default().trim();
+        let parameters = parts
+            .map(str::trim)
+            .filter(|parameter| !parameter.is_empty())
+            .map(ToString::to_string)
+            .collect();
+        (
+            (!issuer.is_empty()).then(|| issuer.to_lowercase()),
+            parameters,
+        )
+    } else {
+        (None, Vec::new())
+    };
+
+    ParsedCaaRecord {
+        critical,
+        tag,
+        value: value.trim().to_string(),
+        issuer,
+        parameters,
+    }
+}
+
+/// 指定标签授权的 CA（去重）；没有该标签时返回 `None`，只有 `;` 时返回空列表
+fn issuers(records: &[ParsedCaaRecord], tag: &str) -> Option<Vec<String>> {
+    let mut found = false;
+    let mut cas: Vec<String> = Vec::new();
+    for record in records.iter().filter(|record| record.tag == tag) {
+        found = true;
+        if let Some(issuer) = &record.issuer {
+            if !cas.contains(issuer) {
+                cas.push(issuer.clone());
+            }
+        }
+    }
+    found.then_some(cas)
+}
+
+fn values(records: &[ParsedCaaRecord], tag: &str) -> Vec<String> {
+    records
+        .iter()
+        .filter(|record| record.tag == tag)
+        .map(|record| record.value.clone())
+        .collect()
+}
+
+fn analyze(
+    domain: String,
+    effective_domain: String,
+    records: Vec<ParsedCaaRecord>,
+) -> CaaCheckResult {
+    let issue = issuers(&records, "issue");
+    let issuewild = issuers(&records, "issuewild");
+    let iodef = values(&records, "iodef");
+    let contact_emails = values(&records, "contactemail");
+    let is_protected = issue.is_some() || issuewild.is_some();
+    let mut issues = Vec::new();
+
+    if records.is_empty() {
+        issues.push("未配置 CAA 记录，任何 CA 都可以为该域名签发证书".to_string());
+    }
+    match (&issue, &issuewild) {
+        (Some(cas), None) if !cas.is_empty() => issues.push(format!(
+            "未配置 issuewild，{} 仍可签发通配符证书",
+            cas.join(", ")
+        )),
+        (None, Some(_)) => {
+            issues.push("只配置了 issuewild，普通证书的签发不受限制".to_string());
+        }
+        (None, None) if !records.is_empty() => {
+            issues.push("CAA 记录中没有 issue / issuewild，不限制证书签发".to_string());
+        }
+        _ => {}
+    }
+    if issue.as_ref().is_some_and(Vec::is_empty) {
+        issues.push("issue 禁止所有 CA 签发普通证书".to_string());
+    }
+
+    for record in &records {
+        if let Some(issuer) = record
+            .issuer
+            .as_ref()
+            .filter(|issuer| !issuer.contains('.'))
+        {
+            issues.push(format!("{} 的 CA 标识 {issuer} 不是有效的域名", record.tag));
+        }
+        if record.critical && !KNOWN_TAGS.contains(&record.tag.as_str()) {
+            issues.push(format!(
+                "未知标签 {} 带有关键标志，CA 将拒绝签发任何证书",
+                record.tag
+            ));
+        }
+    }
+    for url in &iodef {
+        let lower = url.to_ascii_lowercase();
+        if !["mailto:", "http://", "https://"]
+            .iter()
+            .any(|scheme| lower.starts_with(scheme))
+        {
+            issues.push(format!(
+                "iodef 地址格式无效（应为 mailto: 或 http(s):// URL）: {url}"
+            ));
+        }
+    }
+    if is_protected && iodef.is_empty() {
+        issues.push("未配置 iodef，CA 无法报告违反 CAA 策略的签发请求".to_string());
+    }
+
+    let wildcard_cas = issuewild
+        .clone()
+        .or_else(|| issue.clone())
+        .unwrap_or_default();
+    CaaCheckResult {
+        domain,
+        effective_domain,
+        records,
+        is_protected,
+        authorized_cas: issue.unwrap_or_default(),
+        wildcard_cas,
+        iodef,
+        contact_emails,
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+    use crate::types::DnsQueryErrorKind;
+
+    fn caa_data(critical: bool, tag: &str, value: &str) -> DnsRecordData {
+        DnsRecordData::Caa {
+            critical,
+            tag: tag.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_issue_parameters() {
+        let record = parse_record(
+            false,
+            "issue",
+            "LetsEncrypt.org; accounturi=https://acme-v02.api.letsencrypt.org/acme/acct/1; validationmethods=dns-01",
+        );
+        assert_eq!(record.issuer.as_deref(), Some("letsencrypt.org"));
+        assert_eq!(
+            record.parameters,
+            [
+                "accounturi=https://acme-v02.api.letsencrypt.org/acme/acct/1",
+                "validationmethods=dns-01"
+            ]
+        );
+
+        let deny = parse_record(false, "issuewild", ";");
+        assert_eq!(deny.issuer, None);
+        assert!(deny.parameters.is_empty());
+
+        let iodef = parse_record(false, "IODEF", "mailto:security@example.com");
+        assert_eq!(iodef.tag, "iodef");
+        assert_eq!(iodef.issuer, None);
+    }
+
+    #[tokio::test]
+    async fn climbs_to_parent_and_flags_missing_issuewild() -> CoreResult<()> {
+        let backend = FakeDnsResolver::new()
+            .fail(
+                "www.example.com",
+                DnsQueryType::Caa,
+                DnsQueryErrorKind::NoRecords,
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Caa,
+                [
+                    caa_data(false, "issue", "letsencrypt.org"),
+                    caa_data(false, "issue", "pki.goog; cansignhttpexchanges=yes"),
+                    caa_data(false, "contactemail", "admin@example.com"),
+                ],
+            );
+
+        let result = check(&backend, "WWW.Example.com.").await?;
+        assert_eq!(result.domain, "www.example.com");
+        assert_eq!(result.effective_domain, "example.com");
+        assert!(result.is_protected);
+        assert_eq!(result.authorized_cas, ["letsencrypt.org", "pki.goog"]);
+        assert_eq!(result.wildcard_cas, result.authorized_cas);
+        assert_eq!(result.contact_emails, ["admin@example.com"]);
+        assert!(result
+            .issues
+            .iter()
+            .any(|issue| issue.contains("issuewild")));
+        assert!(result.issues.iter().any(|issue| issue.contains("iodef")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn issuewild_restricts_wildcards() -> CoreResult<()> {
+        let backend = FakeDnsResolver::new().answer(
+            "example.com",
+            DnsQueryType::Caa,
+            [
+                caa_data(false, "issue", "letsencrypt.org"),
+                caa_data(false, "issuewild", ";"),
+                caa_data(false, "iodef", "mailto:security@example.com"),
+            ],
+        );
+
+        let result = check(&backend, "*.example.com").await?;
+        assert_eq!(result.effective_domain, "example.com");
+        assert_eq!(result.authorized_cas, ["letsencrypt.org"]);
+        assert!(result.wildcard_cas.is_empty());
+        assert_eq!(result.iodef, ["mailto:security@example.com"]);
+        assert!(result.issues.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unprotected_domain_queries_every_label() -> CoreResult<()> {
+        let backend = FakeDnsResolver::new();
+
+        let result = check(&backend, "a.b.example.com").await?;
+        assert!(!result.is_protected);
+        assert!(result.effective_domain.is_empty());
+        assert!(result.records.is_empty());
+        assert_eq!(result.issues.len(), 1);
+
+        let names: Vec<String> = backend.queries().into_iter().map(|q| q.name).collect();
+        assert_eq!(
+            names,
+            ["a.b.example.com", "b.example.com", "example.com", "com"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flags_unknown_critical_tag_and_bad_iodef() -> CoreResult<()> {
+        let backend = FakeDnsResolver::new().answer(
+            "example.com",
+            DnsQueryType::Caa,
+            [
+                caa_data(false, "issuewild", "digicert.com"),
+                caa_data(true, "tbs", "unknown"),
+                caa_data(false, "iodef", "security@example.com"),
+            ],
+        );
+
+        let result = check(&backend, "example.com").await?;
+        assert!(result.is_protected);
+        assert!(result.authorized_cas.is_empty());
+        assert_eq!(result.wildcard_cas, ["digicert.com"]);
+        assert!(result.issues.iter().any(|issue| issue.contains("普通证书")));
+        assert!(result.issues.iter().any(|issue| issue.contains("tbs")));
+        assert!(result
+            .issues
+            .iter()
+            .any(|issue| issue.contains("iodef 地址格式无效")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn network_failure_is_an_error() {
+        let backend = FakeDnsResolver::new().fail(
+            "example.com",
+            DnsQueryType::Caa,
+            DnsQueryErrorKind::Timeout,
+        );
+        assert!(matches!(
+            check(&backend, "example.com").await,
+            Err(CoreError::NetworkError(_))
+        ));
+    }
+}
@@ -1,323 +1,992 @@
 //! DNS 查询模块
 
-use std::net::IpAddr;
+use std::fmt::Write as _;
+use std::net::{IpAddr, SocketAddr};
 
 use futures::future::join_all;
-use hickory_resolver::{
-    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
-    name_server::TokioConnectionProvider,
-    TokioResolver,
-};
+
+use dns_orchestrator_provider::txt;
 
 use crate::error::{CoreError, CoreResult};
-use crate::types::{DnsLookupRecord, DnsLookupResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsAnswerRecord, DnsLookupRecord, DnsLookupRecordData, DnsLookupResult, DnsLookupTransport,
+    DnsQuery, DnsQueryError, DnsQueryOptions, DnsQueryType, DnsRecordData, DnsServer, DnsTransport,
+    PtrRecord, ReverseLookupResult,
+};
+
+use super::HickoryResolverBackend;
+
+/// DNS over HTTPS 服务器前缀，如 `doh:https://cloudflare-dns.com/dns-query`
+///
+/// 旧写法，未指定传输协议时仍按 `DoH` 处理。
+const DOH_PREFIX: &str = "doh:";
+
+/// DNS over TLS 默认端口（RFC 7858）
+const DOT_PORT: u16 = 853;
+
+/// `ALL` 查询包含的记录类型（`DoH` 路径共用）
+pub(super) const ALL_TYPES: [DnsQueryType; 11] = [
+    DnsQueryType::A,
+    DnsQueryType::Aaaa,
+    DnsQueryType::Cname,
+    DnsQueryType::Mx,
+    DnsQueryType::Txt,
+    DnsQueryType::Ns,
+    DnsQueryType::Soa,
+    DnsQueryType::Srv,
+    DnsQueryType::Caa,
+    DnsQueryType::Naptr,
+    DnsQueryType::Ptr,
+];
+
+/// 获取系统默认 DNS 服务器地址（用于结果展示）
+fn get_system_dns(backend: &dyn DnsResolverBackend) -> String {
+    let servers = backend.system_nameservers();
+    if servers.is_empty() {
+        "系统默认".to_string()
+    } else {
+        servers.join(", ")
+    }
+}
 
 /// DNS 查询
+///
+/// `transport` 为空时使用明文 UDP；`nameserver` 带 `doh:` 前缀时按 `DoH` 处理（兼容旧写法）。
+/// 指定的传输协议不可用时直接返回错误，不会降级到其他协议。
 pub async fn dns_lookup(
     domain: &str,
     record_type: &str,
     nameserver: Option<&str>,
+    transport: Option<DnsLookupTransport>,
 ) -> CoreResult<DnsLookupResult> {
-    // 获取系统默认 DNS 服务器地址
-    fn get_system_dns() -> String {
-        let config = ResolverConfig::default();
-        let servers: Vec<String> = config
-            .name_servers()
-            .iter()
-            .map(|ns| ns.socket_addr.ip().to_string())
-            .collect();
-        if servers.is_empty() {
-            "系统默认".to_string()
-        } else {
-            servers.join(", ")
+    let nameserver = nameserver.map(str::trim).filter(|ns| !ns.is_empty());
+    let legacy_doh = nameserver.and_then(|ns| ns.strip_prefix(DOH_PREFIX));
+    let transport = match (transport, legacy_doh) {
+        (None, Some(_)) => DnsLookupTransport::Doh,
+        (transport, _) => transport.unwrap_or_default(),
+    };
+
+    if transport == DnsLookupTransport::Doh {
+        let url = legacy_doh
+            .or(nameserver)
+            .map(str::trim)
+            .ok_or_else(|| CoreError::ValidationError("DoH 查询需要指定服务器 URL".to_string()))?;
+        #[cfg(feature = "doh")]
+        {
+            return super::doh::dns_lookup_doh(domain, record_type, url).await;
+        }
+        #[cfg(not(feature = "doh"))]
+        {
+            return Err(CoreError::ValidationError(format!(
+                "当前构建未启用 DoH 支持: {url}"
+            )));
         }
     }
 
-    // 根据 nameserver 参数决定使用自定义还是系统默认
-    let (resolver, used_nameserver) = if let Some(ns) = nameserver {
-        if ns.is_empty() {
-            let system_dns = get_system_dns();
-            let provider = TokioConnectionProvider::default();
-            let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-                .with_options(ResolverOpts::default())
-                .build();
-            (resolver, system_dns)
-        } else {
-            let ns_ip: IpAddr = ns
-                .parse()
-                .map_err(|_| CoreError::ValidationError(format!("无效的 DNS 服务器地址: {ns}")))?;
-
-            let config = ResolverConfig::from_parts(
-                None,
-                vec![],
-                NameServerConfigGroup::from_ips_clear(&[ns_ip], 53, true),
-            );
-            let provider = TokioConnectionProvider::default();
-            let resolver = TokioResolver::builder_with_config(config, provider)
-                .with_options(ResolverOpts::default())
-                .build();
-            (resolver, ns.to_string())
-        }
+    lookup_with(
+        &HickoryResolverBackend,
+        domain,
+        record_type,
+        nameserver,
+        transport,
+    )
+    .await
+}
+
+/// 使用指定解析后端查询（不处理 `DoH`）
+pub(super) async fn lookup_with(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    record_type: &str,
+    nameserver: Option<&str>,
+    transport: DnsLookupTransport,
+) -> CoreResult<DnsLookupResult> {
+    let query_types = if record_type.eq_ignore_ascii_case("ALL") {
+        ALL_TYPES.to_vec()
     } else {
-        let system_dns = get_system_dns();
-        let provider = TokioConnectionProvider::default();
-        let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-            .with_options(ResolverOpts::default())
-            .build();
-        (resolver, system_dns)
+        let query_type = parse_query_type(record_type).ok_or_else(|| {
+            CoreError::ValidationError(format!("不支持的记录类型: {record_type}"))
+        })?;
+        vec![query_type]
     };
 
-    let mut records: Vec<DnsLookupRecord> = Vec::new();
-    let record_type_upper = record_type.to_uppercase();
-
-    match record_type_upper.as_str() {
-        "A" => lookup_a(&resolver, domain, &mut records).await,
-        "AAAA" => lookup_aaaa(&resolver, domain, &mut records).await,
-        "MX" => lookup_mx(&resolver, domain, &mut records).await,
-        "TXT" => lookup_txt(&resolver, domain, &mut records).await,
-        "NS" => lookup_ns(&resolver, domain, &mut records).await,
-        "CNAME" => lookup_cname(&resolver, domain, &mut records).await,
-        "SOA" => lookup_soa(&resolver, domain, &mut records).await,
-        "SRV" => lookup_srv(&resolver, domain, &mut records).await,
-        "CAA" => lookup_caa(&resolver, domain, &mut records).await,
-        "PTR" => lookup_ptr(&resolver, domain, &mut records).await,
-        "ALL" => {
-            let types = vec![
-                "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR",
-            ];
-            let ns = nameserver.map(String::from);
-            let futures: Vec<_> = types
-                .into_iter()
-                .map(|t| {
-                    let ns = ns.clone();
-                    let domain = domain.to_string();
-                    async move { dns_lookup(&domain, t, ns.as_deref()).await }
-                })
-                .collect();
-
-            let results = join_all(futures).await;
-            for result in results.into_iter().flatten() {
-                records.extend(result.records);
+    let target = resolve_target(backend, nameserver, transport).await?;
+
+    let results = join_all(
+        query_types
+            .iter()
+            .map(|query_type| lookup_type(backend, &target, domain, *query_type)),
+    )
+    .await;
+
+    // 单类型查询失败、或 ALL 查询全部失败时，说明所选传输协议/服务器不可用
+    let mut records = Vec::new();
+    let mut first_error = None;
+    let mut answered = false;
+    for result in results {
+        match result {
+            Ok(found) => {
+                answered = true;
+                records.extend(found);
+            }
+            Err(e) => {
+                first_error.get_or_insert(e);
             }
-        }
-        _ => {
-            return Err(CoreError::ValidationError(format!(
-                "不支持的记录类型: {record_type}"
-            )));
         }
     }
+    if let (false, Some(e)) = (answered, first_error) {
+        return Err(CoreError::NetworkError(format!(
+            "{} 查询失败（{}）: {e}",
+            transport.label(),
+            target.nameserver
+        )));
+    }
 
     Ok(DnsLookupResult {
-        nameserver: used_nameserver,
+        domain: domain.to_string(),
+        unicode_domain: None,
+        nameserver: target.nameserver,
+        transport,
         records,
     })
 }
 
-async fn lookup_a(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.ipv4_lookup(domain).await {
-        for ip in response.iter() {
-            records.push(DnsLookupRecord {
-                record_type: "A".to_string(),
-                name: domain.to_string(),
-                value: ip.to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
-            });
-        }
-    }
+/// 解析后的查询目标
+struct LookupTarget {
+    server: DnsServer,
+    options: DnsQueryOptions,
+    /// 结果中展示的服务器
+    nameserver: String,
 }
 
-async fn lookup_aaaa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.ipv6_lookup(domain).await {
-        for ip in response.iter() {
-            records.push(DnsLookupRecord {
-                record_type: "AAAA".to_string(),
-                name: domain.to_string(),
-                value: ip.to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
+async fn resolve_target(
+    backend: &dyn DnsResolverBackend,
+    nameserver: Option<&str>,
+    transport: DnsLookupTransport,
+) -> CoreResult<LookupTarget> {
+    let plain = |transport: DnsTransport| DnsQueryOptions {
+        transport,
+        ..DnsQueryOptions::default()
+    };
+
+    match transport {
+        DnsLookupTransport::Udp | DnsLookupTransport::Tcp => {
+            let options = plain(if transport == DnsLookupTransport::Tcp {
+                DnsTransport::Tcp
+            } else {
+                DnsTransport::UdpWithTcpFallback
             });
+            // 根据 nameserver 参数决定使用自定义还是系统默认
+            let (server, nameserver) = match nameserver {
+                Some(ns) => {
+                    let ns_ip: IpAddr = ns.parse().map_err(|_| {
+                        CoreError::ValidationError(format!("无效的 DNS 服务器地址: {ns}"))
+                    })?;
+                    (DnsServer::Ip(ns_ip), ns.to_string())
+                }
+                None => (DnsServer::System, get_system_dns(backend)),
+            };
+            Ok(LookupTarget {
+                server,
+                options,
+                nameserver,
+            })
         }
+        DnsLookupTransport::Dot => {
+            let spec = nameserver
+                .ok_or_else(|| CoreError::ValidationError("DoT 查询需要指定服务器".to_string()))?;
+            let dot = parse_dot_server(spec)?;
+            let addr = resolve_dot_host(backend, &dot).await?;
+            Ok(LookupTarget {
+                server: DnsServer::Socket(addr),
+                options: DnsQueryOptions {
+                    tls_server_name: Some(dot.server_name.clone()),
+                    ..plain(DnsTransport::Tls)
+                },
+                nameserver: format!("{addr}#{}", dot.server_name),
+            })
+        }
+        DnsLookupTransport::Doh => Err(CoreError::ValidationError(
+            "DoH 查询不经过解析后端".to_string(),
+        )),
     }
 }
 
-async fn lookup_mx(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.mx_lookup(domain).await {
-        for mx in response.iter() {
-            records.push(DnsLookupRecord {
-                record_type: "MX".to_string(),
-                name: domain.to_string(),
-                value: mx.exchange().to_string().trim_end_matches('.').to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: Some(mx.preference()),
-            });
-        }
-    }
+/// `DoT` 服务器：`host[:port][#TLS 服务器名称]`
+#[derive(Debug, PartialEq, Eq)]
+struct DotServer {
+    host: String,
+    port: u16,
+    /// 证书校验用的服务器名称，省略时取主机名（主机为 IP 时必须指定）
+    server_name: String,
 }
 
-async fn lookup_txt(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.txt_lookup(domain).await {
-        for txt in response.iter() {
-            let txt_data: String = txt
-                .iter()
-                .map(|data| String::from_utf8_lossy(data).to_string())
-                .collect::<String>();
-            records.push(DnsLookupRecord {
-                record_type: "TXT".to_string(),
-                name: domain.to_string(),
-                value: txt_data,
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
-            });
-        }
+fn parse_dot_server(spec: &str) -> CoreResult<DotServer> {
+    let invalid = || {
+        CoreError::ValidationError(format!(
+            "无效的 DoT 服务器: {spec}（格式如 1.1.1.1:853#cloudflare-dns.com 或 dns.google:853）"
+        ))
+    };
+
+    let (address, server_name) = match spec.split_once('#') {
+        Some((address, name)) => (address.trim(), Some(name.trim()).filter(|n| !n.is_empty())),
+        None => (spec.trim(), None),
+    };
+
+    let (host, port) = if let Ok(addr) = address.parse::<SocketAddr>() {
+        (addr.ip().to_string(), addr.port())
+    } else if let Ok(ip) = address
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        (ip.to_string(), DOT_PORT)
+    } else if let Some((host, port)) = address.rsplit_once(':') {
+        (host.to_string(), port.parse().map_err(|_| invalid())?)
+    } else {
+        (address.to_string(), DOT_PORT)
+    };
+
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return Err(invalid());
     }
-}
 
-async fn lookup_ns(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.ns_lookup(domain).await {
-        for ns in response.iter() {
-            records.push(DnsLookupRecord {
-                record_type: "NS".to_string(),
-                name: domain.to_string(),
-                value: ns.to_string().trim_end_matches('.').to_string(),
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
-            });
+    let server_name = match server_name {
+        Some(name) => name.to_string(),
+        None if host.parse::<IpAddr>().is_err() => host.clone(),
+        None => {
+            return Err(CoreError::ValidationError(format!(
+                "DoT 服务器为 IP 地址时需要用 # 指定 TLS 服务器名称: {spec}"
+            )));
         }
-    }
+    };
+
+    Ok(DotServer {
+        host,
+        port,
+        server_name,
+    })
 }
 
-async fn lookup_cname(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver
-        .lookup(domain, hickory_resolver::proto::rr::RecordType::CNAME)
-        .await
-    {
-        for record in response.record_iter() {
-            if let Some(cname) = record.data().as_cname() {
-                records.push(DnsLookupRecord {
-                    record_type: "CNAME".to_string(),
-                    name: domain.to_string(),
-                    value: cname.0.to_string().trim_end_matches('.').to_string(),
-                    ttl: record.ttl(),
-                    priority: None,
-                });
-            }
-        }
+/// 解析 `DoT` 服务器地址，主机名通过系统 DNS 查询
+async fn resolve_dot_host(
+    backend: &dyn DnsResolverBackend,
+    dot: &DotServer,
+) -> CoreResult<SocketAddr> {
+    if let Ok(ip) = dot.host.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, dot.port));
     }
-}
 
-async fn lookup_soa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.soa_lookup(domain).await {
-        if let Some(soa) = response.iter().next() {
-            let value = format!(
-                "{} {} {} {} {} {} {}",
-                soa.mname().to_string().trim_end_matches('.'),
-                soa.rname().to_string().trim_end_matches('.'),
-                soa.serial(),
-                soa.refresh(),
-                soa.retry(),
-                soa.expire(),
-                soa.minimum()
-            );
-            records.push(DnsLookupRecord {
-                record_type: "SOA".to_string(),
-                name: domain.to_string(),
-                value,
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: None,
-            });
+    for query_type in [DnsQueryType::A, DnsQueryType::Aaaa] {
+        let query = DnsQuery::new(dot.host.as_str(), query_type, DnsServer::System);
+        let Ok(answer) = backend.query(&query).await else {
+            continue;
+        };
+        let ip = answer.records.iter().find_map(|record| match record.data {
+            DnsRecordData::A(ip) => Some(IpAddr::V4(ip)),
+            DnsRecordData::Aaaa(ip) => Some(IpAddr::V6(ip)),
+            _ => None,
+        });
+        if let Some(ip) = ip {
+            return Ok(SocketAddr::new(ip, dot.port));
         }
     }
+
+    Err(CoreError::NetworkError(format!(
+        "无法解析 DoT 服务器地址: {}",
+        dot.host
+    )))
+}
+
+pub(super) fn parse_query_type(record_type: &str) -> Option<DnsQueryType> {
+    let upper = record_type.to_uppercase();
+    ALL_TYPES
+        .into_iter()
+        .find(|query_type| query_type.as_str() == upper)
 }
 
-async fn lookup_srv(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver.srv_lookup(domain).await {
-        for srv in response.iter() {
-            let value = format!(
-                "{} {} {}",
-                srv.weight(),
-                srv.port(),
-                srv.target().to_string().trim_end_matches('.')
-            );
-            records.push(DnsLookupRecord {
-                record_type: "SRV".to_string(),
-                name: domain.to_string(),
+/// 查询单个记录类型
+///
+/// 服务器给出应答但没有记录（NXDOMAIN、SERVFAIL 等）时返回空列表，
+/// 超时、连接失败等传输层错误返回 `Err`。
+async fn lookup_type(
+    backend: &dyn DnsResolverBackend,
+    target: &LookupTarget,
+    domain: &str,
+    query_type: DnsQueryType,
+) -> Result<Vec<DnsLookupRecord>, DnsQueryError> {
+    let query =
+        DnsQuery::new(domain, query_type, target.server).with_options(target.options.clone());
+    let answer = match backend.query(&query).await {
+        Ok(answer) => answer,
+        Err(e) if e.is_answered() => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    // 除 CNAME/CAA/PTR 外，TTL 取应答中第一条记录（可能是 CNAME 链）的 TTL
+    let answer_ttl = answer.records.first().map_or(0, |record| record.ttl);
+    let limit = if query_type == DnsQueryType::Soa {
+        1
+    } else {
+        usize::MAX
+    };
+
+    Ok(answer
+        .records
+        .iter()
+        .filter_map(|record| lookup_record(domain, query_type, record, answer_ttl))
+        .take(limit)
+        .collect())
+}
+
+/// 将应答记录转换为查询结果，与查询类型不符的记录返回 `None`
+pub(super) fn lookup_record(
+    domain: &str,
+    query_type: DnsQueryType,
+    record: &DnsAnswerRecord,
+    answer_ttl: u32,
+) -> Option<DnsLookupRecord> {
+    let (value, priority, ttl) = format_record(query_type, record, answer_ttl)?;
+    Some(DnsLookupRecord {
+        record_type: query_type.as_str().to_string(),
+        name: domain.to_string(),
+        value,
+        ttl,
+        priority,
+        record_data: record_data(&record.data),
+    })
+}
+
+/// 将应答记录格式化为 (值, 优先级, TTL)，与查询类型不符的记录返回 `None`
+fn format_record(
+    query_type: DnsQueryType,
+    record: &DnsAnswerRecord,
+    answer_ttl: u32,
+) -> Option<(String, Option<u16>, u32)> {
+    let formatted = match (query_type, &record.data) {
+        (DnsQueryType::A, DnsRecordData::A(ip)) => (ip.to_string(), None, answer_ttl),
+        (DnsQueryType::Aaaa, DnsRecordData::Aaaa(ip)) => (ip.to_string(), None, answer_ttl),
+        (
+            DnsQueryType::Mx,
+            DnsRecordData::Mx {
+                preference,
+                exchange,
+            },
+        ) => (trim_dot(exchange), Some(*preference), answer_ttl),
+        (DnsQueryType::Txt, DnsRecordData::Txt(segments)) => {
+            (txt::join(segments), None, answer_ttl)
+        }
+        (DnsQueryType::Ns, DnsRecordData::Ns(ns)) => (trim_dot(ns), None, answer_ttl),
+        (DnsQueryType::Cname, DnsRecordData::Cname(cname)) => (trim_dot(cname), None, record.ttl),
+        (
+            DnsQueryType::Soa,
+            DnsRecordData::Soa {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            },
+        ) => (
+            format!(
+                "{} {} {serial} {refresh} {retry} {expire} {minimum}",
+                trim_dot(mname),
+                trim_dot(rname),
+            ),
+            None,
+            answer_ttl,
+        ),
+        (
+            DnsQueryType::Srv,
+            DnsRecordData::Srv {
+                priority,
+                weight,
+                port,
+                target,
+            },
+        ) => (
+            format!("{weight} {port} {}", trim_dot(target)),
+            Some(*priority),
+            answer_ttl,
+        ),
+        (
+            DnsQueryType::Caa,
+            DnsRecordData::Caa {
+                critical,
+                tag,
                 value,
-                ttl: response
-                    .as_lookup()
-                    .record_iter()
-                    .next()
-                    .map_or(0, hickory_resolver::proto::rr::Record::ttl),
-                priority: Some(srv.priority()),
-            });
+            },
+        ) => (
+            format!("{} {tag} \"{value}\"", if *critical { 128 } else { 0 }),
+            None,
+            record.ttl,
+        ),
+        (
+            DnsQueryType::Naptr,
+            DnsRecordData::Naptr {
+                order,
+                preference,
+                flags,
+                services,
+                regexp,
+                replacement,
+            },
+        ) => (
+            format!(
+                "{order} {preference} \"{flags}\" \"{services}\" \"{regexp}\" {}",
+                naptr_replacement(replacement)
+            ),
+            None,
+            answer_ttl,
+        ),
+        (DnsQueryType::Ptr, DnsRecordData::Ptr(ptr)) => (trim_dot(ptr), None, record.ttl),
+        // 后端无法解析的记录保留文本形式
+        (_, DnsRecordData::Other { record_type, data }) if record_type == query_type.as_str() => {
+            (data.clone(), None, record.ttl)
         }
+        _ => return None,
+    };
+    Some(formatted)
+}
+
+/// 提取结构化数据（仅 CAA/SRV/SOA/NAPTR）
+fn record_data(data: &DnsRecordData) -> Option<DnsLookupRecordData> {
+    let structured = match data {
+        DnsRecordData::Caa {
+            critical,
+            tag,
+            value,
+        } => DnsLookupRecordData::Caa {
+            flags: if *critical { 128 } else { 0 },
+            tag: tag.clone(),
+            value: value.clone(),
+        },
+        DnsRecordData::Srv {
+            priority,
+            weight,
+            port,
+            target,
+        } => DnsLookupRecordData::Srv {
+            priority: *priority,
+            weight: *weight,
+            port: *port,
+            target: trim_dot(target),
+        },
+        DnsRecordData::Soa {
+            mname,
+            rname,
+            serial,
+            refresh,
+            retry,
+            expire,
+            minimum,
+        } => DnsLookupRecordData::Soa {
+            mname: trim_dot(mname),
+            rname: trim_dot(rname),
+            serial: *serial,
+            refresh: *refresh,
+            retry: *retry,
+            expire: *expire,
+            minimum: *minimum,
+        },
+        DnsRecordData::Naptr {
+            order,
+            preference,
+            flags,
+            services,
+            regexp,
+            replacement,
+        } => DnsLookupRecordData::Naptr {
+            order: *order,
+            preference: *preference,
+            flags: flags.clone(),
+            services: services.clone(),
+            regexp: regexp.clone(),
+            replacement: naptr_replacement(replacement),
+        },
+        _ => return None,
+    };
+    Some(structured)
+}
+
+/// NAPTR 的 replacement 为根域（`.`）时表示不使用，保留 `.` 而不是输出空字符串
+fn naptr_replacement(replacement: &str) -> String {
+    if replacement == "." {
+        replacement.to_string()
+    } else {
+        trim_dot(replacement)
     }
 }
 
-async fn lookup_caa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver
-        .lookup(domain, hickory_resolver::proto::rr::RecordType::CAA)
-        .await
-    {
-        for record in response.record_iter() {
-            if let Some(caa) = record.data().as_caa() {
-                let value = format!(
-                    "{} {} \"{}\"",
-                    if caa.issuer_critical() { 128 } else { 0 },
-                    caa.tag().as_str(),
-                    String::from_utf8_lossy(caa.raw_value())
-                );
-                records.push(DnsLookupRecord {
-                    record_type: "CAA".to_string(),
-                    name: domain.to_string(),
-                    value,
-                    ttl: record.ttl(),
-                    priority: None,
-                });
+fn trim_dot(name: &str) -> String {
+    name.trim_end_matches('.').to_string()
+}
+
+/// 将 IP 地址转换为反向解析域名
+///
+/// IPv4 按字节倒序拼接 `in-addr.arpa`，IPv6 展开为 32 个半字节后倒序拼接 `ip6.arpa`。
+pub fn reverse_name(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, c, d] = v4.octets();
+            format!("{d}.{c}.{b}.{a}.in-addr.arpa")
+        }
+        IpAddr::V6(v6) => {
+            let mut name = String::with_capacity(72);
+            for byte in v6.octets().iter().rev() {
+                let _ = write!(name, "{:x}.{:x}.", byte & 0x0f, byte >> 4);
             }
+            name.push_str("ip6.arpa");
+            name
         }
     }
 }
 
-async fn lookup_ptr(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
-    if let Ok(response) = resolver
-        .lookup(domain, hickory_resolver::proto::rr::RecordType::PTR)
-        .await
-    {
-        for record in response.record_iter() {
-            if let Some(ptr) = record.data().as_ptr() {
-                records.push(DnsLookupRecord {
-                    record_type: "PTR".to_string(),
-                    name: domain.to_string(),
-                    value: ptr.0.to_string().trim_end_matches('.').to_string(),
-                    ttl: record.ttl(),
-                    priority: None,
-                });
+/// 反向 DNS（PTR）查询
+pub async fn reverse_lookup(ip: &str, nameserver: Option<&str>) -> CoreResult<ReverseLookupResult> {
+    let addr: IpAddr = ip
+        .trim()
+        .parse()
+        .map_err(|_| CoreError::ValidationError(format!("无效的 IP 地址: {ip}")))?;
+    let name = reverse_name(addr);
+
+    let lookup = dns_lookup(&name, "PTR", nameserver, None).await?;
+    let ptr_records = lookup
+        .records
+        .into_iter()
+        .map(|record| PtrRecord {
+            hostname: record.value,
+            ttl: record.ttl,
+        })
+        .collect();
+
+    Ok(ReverseLookupResult {
+        ip: addr.to_string(),
+        reverse_name: name,
+        ptr_records,
+        nameserver: lookup.nameserver,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+    use crate::types::DnsQueryErrorKind;
+
+    #[test]
+    fn reverse_name_ipv4() {
+        assert_eq!(
+            reverse_name(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 10))),
+            "10.2.0.192.in-addr.arpa"
+        );
+    }
+
+    #[test]
+    fn reverse_name_expands_compressed_ipv6() {
+        let ip = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+        assert_eq!(
+            reverse_name(ip),
+            "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa"
+        );
+    }
+
+    #[tokio::test]
+    async fn reverse_lookup_rejects_invalid_ip() {
+        let result = reverse_lookup("not-an-ip", None).await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+    }
+
+    fn example_zone() -> FakeDnsResolver {
+        FakeDnsResolver::new()
+            .with_system_nameservers(&["192.0.2.53"])
+            .answer(
+                "example.com",
+                DnsQueryType::A,
+                [DnsRecordData::A(Ipv4Addr::new(192, 0, 2, 1))],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Mx,
+                [DnsRecordData::Mx {
+                    preference: 10,
+                    exchange: "mail.example.com.".to_string(),
+                }],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Txt,
+                [DnsRecordData::Txt(vec![
+                    "v=spf1 ".to_string(),
+                    "-all".to_string(),
+                ])],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Soa,
+                [DnsRecordData::Soa {
+                    mname: "ns1.example.com.".to_string(),
+                    rname: "hostmaster.example.com.".to_string(),
+                    serial: 2_026_101_601,
+                    refresh: 7200,
+                    retry: 3600,
+                    expire: 1_209_600,
+                    minimum: 300,
+                }],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Caa,
+                [DnsRecordData::Caa {
+                    critical: false,
+                    tag: "issue".to_string(),
+                    value: "letsencrypt.org".to_string(),
+                }],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Naptr,
+                [DnsRecordData::Naptr {
+                    order: 100,
+                    preference: 10,
+                    flags: "S".to_string(),
+                    services: "SIP+D2T".to_string(),
+                    regexp: String::new(),
+                    replacement: "_sip._tcp.example.com.".to_string(),
+                }],
+            )
+            .answer(
+                "_sip._tcp.example.com",
+                DnsQueryType::Srv,
+                [DnsRecordData::Srv {
+                    priority: 10,
+                    weight: 60,
+                    port: 5060,
+                    target: "sip.example.com.".to_string(),
+                }],
+            )
+            .fail("example.com", DnsQueryType::Ns, DnsQueryErrorKind::ServFail)
+            .fail(
+                "example.com",
+                DnsQueryType::Aaaa,
+                DnsQueryErrorKind::Timeout,
+            )
+    }
+
+    #[tokio::test]
+    async fn all_lookup_snapshot() -> CoreResult<()> {
+        let result = lookup_with(
+            &example_zone(),
+            "example.com",
+            "all",
+            None,
+            DnsLookupTransport::Udp,
+        )
+        .await?;
+
+        let snapshot = serde_json::to_value(&result)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        assert_eq!(
+            snapshot,
+            json!({
+                "domain": "example.com",
+                "unicodeDomain": null,
+                "nameserver": "192.0.2.53",
+                "transport": "udp",
+                "records": [
+                    { "recordType": "A", "name": "example.com", "value": "192.0.2.1", "ttl": 300, "priority": null },
+                    { "recordType": "MX", "name": "example.com", "value": "mail.example.com", "ttl": 300, "priority": 10 },
+                    { "recordType": "TXT", "name": "example.com", "value": "v=spf1 -all", "ttl": 300, "priority": null },
+                    {
+                        "recordType": "SOA",
+                        "name": "example.com",
+                        "value": "ns1.example.com hostmaster.example.com 2026101601 7200 3600 1209600 300",
+                        "ttl": 300,
+                        "priority": null,
+                        "recordData": {
+                            "type": "SOA",
+                            "content": {
+                                "mname": "ns1.example.com",
+                                "rname": "hostmaster.example.com",
+                                "serial": 2_026_101_601,
+                                "refresh": 7200,
+                                "retry": 3600,
+                                "expire": 1_209_600,
+                                "minimum": 300
+                            }
+                        }
+                    },
+                    {
+                        "recordType": "CAA",
+                        "name": "example.com",
+                        "value": "0 issue \"letsencrypt.org\"",
+                        "ttl": 300,
+                        "priority": null,
+                        "recordData": {
+                            "type": "CAA",
+                            "content": { "flags": 0, "tag": "issue", "value": "letsencrypt.org" }
+                        }
+                    },
+                    {
+                        "recordType": "NAPTR",
+                        "name": "example.com",
+                        "value": "100 10 \"S\" \"SIP+D2T\" \"\" _sip._tcp.example.com",
+                        "ttl": 300,
+                        "priority": null,
+                        "recordData": {
+                            "type": "NAPTR",
+                            "content": {
+                                "order": 100,
+                                "preference": 10,
+                                "flags": "S",
+                                "services": "SIP+D2T",
+                                "regexp": "",
+                                "replacement": "_sip._tcp.example.com"
+                            }
+                        }
+                    },
+                ]
+            })
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn srv_lookup_uses_custom_nameserver() -> CoreResult<()> {
+        let resolver = example_zone();
+        let result = lookup_with(
+            &resolver,
+            "_sip._tcp.example.com",
+            "SRV",
+            Some("192.0.2.99"),
+            DnsLookupTransport::Udp,
+        )
+        .await?;
+
+        assert_eq!(result.nameserver, "192.0.2.99");
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].value, "60 5060 sip.example.com");
+        assert_eq!(result.records[0].priority, Some(10));
+        assert_eq!(
+            result.records[0].record_data,
+            Some(DnsLookupRecordData::Srv {
+                priority: 10,
+                weight: 60,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            })
+        );
+        assert_eq!(
+            resolver.queries()[0].server,
+            DnsServer::Ip(Ipv4Addr::new(192, 0, 2, 99).into())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn a_lookup_ignores_cname_chain_records() -> CoreResult<()> {
+        let resolver = FakeDnsResolver::new().answer_records(
+            "www.example.com",
+            DnsQueryType::A,
+            vec![
+                DnsAnswerRecord {
+                    name: "www.example.com.".to_string(),
+                    ttl: 60,
+                    data: DnsRecordData::Cname("example.com.".to_string()),
+                },
+                DnsAnswerRecord {
+                    name: "example.com.".to_string(),
+                    ttl: 300,
+                    data: DnsRecordData::A(Ipv4Addr::new(192, 0, 2, 1)),
+                },
+            ],
+        );
+        let result = lookup_with(
+            &resolver,
+            "www.example.com",
+            "A",
+            None,
+            DnsLookupTransport::Udp,
+        )
+        .await?;
+
+        assert_eq!(result.nameserver, "系统默认");
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].value, "192.0.2.1");
+        assert_eq!(result.records[0].ttl, 60);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unparsed_records_fall_back_to_text() -> CoreResult<()> {
+        let resolver = FakeDnsResolver::new().answer(
+            "example.com",
+            DnsQueryType::Naptr,
+            [DnsRecordData::Other {
+                record_type: "NAPTR".to_string(),
+                data: "100 10 \"U\" \"E2U+sip\" \"!^.*$!sip:info@example.com!\" .".to_string(),
+            }],
+        );
+        let result = lookup_with(
+            &resolver,
+            "example.com",
+            "NAPTR",
+            None,
+            DnsLookupTransport::Udp,
+        )
+        .await?;
+
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(
+            result.records[0].value,
+            "100 10 \"U\" \"E2U+sip\" \"!^.*$!sip:info@example.com!\" ."
+        );
+        assert_eq!(result.records[0].record_data, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_lookup_arguments() {
+        let resolver = example_zone();
+
+        let result = lookup_with(
+            &resolver,
+            "example.com",
+            "DNSKEY",
+            None,
+            DnsLookupTransport::Udp,
+        )
+        .await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+
+        let result = lookup_with(
+            &resolver,
+            "example.com",
+            "A",
+            Some("dns.example"),
+            DnsLookupTransport::Udp,
+        )
+        .await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+        assert!(resolver.queries().is_empty());
+    }
+
+    #[test]
+    fn parses_dot_server_spec() -> CoreResult<()> {
+        assert_eq!(
+            parse_dot_server("1.1.1.1:853#cloudflare-dns.com")?,
+            DotServer {
+                host: "1.1.1.1".to_string(),
+                port: 853,
+                server_name: "cloudflare-dns.com".to_string(),
             }
-        }
+        );
+        assert_eq!(
+            parse_dot_server("dns.google")?,
+            DotServer {
+                host: "dns.google".to_string(),
+                port: 853,
+                server_name: "dns.google".to_string(),
+            }
+        );
+        assert_eq!(
+            parse_dot_server("[2606:4700::1111]:8853#one.one.one.one")?,
+            DotServer {
+                host: "2606:4700::1111".to_string(),
+                port: 8853,
+                server_name: "one.one.one.one".to_string(),
+            }
+        );
+        assert_eq!(
+            parse_dot_server("2606:4700::1111#cloudflare-dns.com")?.port,
+            853
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_dot_server_spec() {
+        // IP 地址必须显式给出 TLS 服务器名称
+        assert!(matches!(
+            parse_dot_server("1.1.1.1:853"),
+            Err(CoreError::ValidationError(_))
+        ));
+        assert!(matches!(
+            parse_dot_server("dns.google:port"),
+            Err(CoreError::ValidationError(_))
+        ));
+        assert!(matches!(
+            parse_dot_server(":853#dns.google"),
+            Err(CoreError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn dot_lookup_resolves_host_and_uses_tls() -> CoreResult<()> {
+        let resolver = example_zone().answer(
+            "dns.google",
+            DnsQueryType::A,
+            [DnsRecordData::A(Ipv4Addr::new(8, 8, 8, 8))],
+        );
+        let result = lookup_with(
+            &resolver,
+            "example.com",
+            "A",
+            Some("dns.google"),
+            DnsLookupTransport::Dot,
+        )
+        .await?;
+
+        assert_eq!(result.transport, DnsLookupTransport::Dot);
+        assert_eq!(result.nameserver, "8.8.8.8:853#dns.google");
+        assert_eq!(result.records.len(), 1);
+
+        // 第一条为通过系统 DNS 解析 DoT 服务器地址的查询
+        let queries = resolver.queries();
+        let lookup = &queries[1];
+        assert_eq!(
+            lookup.server,
+            DnsServer::Socket(SocketAddr::new(Ipv4Addr::new(8, 8, 8, 8).into(), 853))
+        );
+        assert_eq!(lookup.options.transport, DnsTransport::Tls);
+        assert_eq!(
+            lookup.options.tls_server_name.as_deref(),
+            Some("dns.google")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn transport_failure_is_reported_instead_of_empty_result() {
+        let resolver =
+            FakeDnsResolver::new().fail("example.com", DnsQueryType::A, DnsQueryErrorKind::Timeout);
+        let result = lookup_with(
+            &resolver,
+            "example.com",
+            "A",
+            Some("192.0.2.99"),
+            DnsLookupTransport::Tcp,
+        )
+        .await;
+        assert!(matches!(result, Err(CoreError::NetworkError(_))));
+        assert_eq!(resolver.queries()[0].options.transport, DnsTransport::Tcp);
+
+        // 服务器明确应答无记录时仍返回空结果
+        let result = lookup_with(
+            &example_zone(),
+            "example.com",
+            "NS",
+            None,
+            DnsLookupTransport::Udp,
+        )
+        .await;
+        assert!(result.is_ok_and(|r| r.records.is_empty()));
     }
 }
@@ -0,0 +1,326 @@
+//! DNS 解析性能基准测试模块
+//!
+//! 与传播检查关注"各服务器结果是否一致"不同，这里关注解析延迟：
+//! 对每个 DNS 服务器按固定间隔重复查询，首次查询（可能未命中服务器缓存）单独统计，
+//! 其余查询统计 min/p50/p95/max 与超时率，最后探测 EDNS 与 TCP 支持。
+//! 本地解析器缓存已关闭，每次查询都会真实发往服务器。
+
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+use futures::stream::{self, StreamExt};
+use tokio::time::timeout;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsBenchmarkProgress, DnsBenchmarkResult, DnsBenchmarkServerResult, DnsQuery,
+    DnsQueryErrorKind, DnsQueryOptions, DnsQueryType, DnsServer, DnsTransport, LatencyStats,
+};
+
+use super::HickoryResolverBackend;
+
+/// 每个服务器的最大查询次数
+const MAX_ITERATIONS: u32 = 100;
+/// 最大并发测试的服务器数
+const MAX_CONCURRENCY: u32 = 8;
+/// 总时长上限
+const TOTAL_TIME_LIMIT: Duration = Duration::from_mins(1);
+/// 单次查询超时
+const QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+/// 同一服务器相邻两次查询的间隔
+const QUERY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 单次查询结果
+enum QueryOutcome {
+    /// 收到应答（包括 NXDOMAIN / 无记录）
+    Answered,
+    Timeout,
+    Error(String),
+}
+
+/// 进度跟踪
+struct Progress<'a> {
+    completed: AtomicU32,
+    total: u32,
+    callback: &'a (dyn Fn(DnsBenchmarkProgress) + Send + Sync),
+}
+
+impl Progress<'_> {
+    fn tick(&self, nameserver: &str) {
+        let completed = self.completed.fetch_add(1, Ordering::SeqCst) + 1;
+        (self.callback)(DnsBenchmarkProgress {
+            completed,
+            total: self.total,
+            nameserver: nameserver.to_string(),
+        });
+    }
+}
+
+/// DNS 解析性能基准测试
+///
+/// `concurrency` 为同时测试的服务器数量，同一服务器的查询始终串行，避免相互干扰。
+/// 每次查询前检查 `cancelled`，取消或达到总时长上限时返回已收集的部分结果。
+pub async fn dns_benchmark(
+    domain: &str,
+    nameservers: &[String],
+    iterations: u32,
+    concurrency: u32,
+    on_progress: &(dyn Fn(DnsBenchmarkProgress) + Send + Sync),
+    cancelled: &AtomicBool,
+) -> CoreResult<DnsBenchmarkResult> {
+    let domain = domain.trim();
+    if domain.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+    if nameservers.is_empty() {
+        return Err(CoreError::ValidationError(
+            "至少需要一个 DNS 服务器".to_string(),
+        ));
+    }
+    if !(1..=MAX_ITERATIONS).contains(&iterations) {
+        return Err(CoreError::ValidationError(format!(
+            "查询次数需在 1-{MAX_ITERATIONS} 之间"
+        )));
+    }
+
+    let servers = nameservers
+        .iter()
+        .map(|ns| {
+            ns.trim()
+                .parse::<IpAddr>()
+                .map(|ip| (ip, ns.trim().to_string()))
+                .map_err(|_| CoreError::ValidationError(format!("无效的 DNS 服务器地址: {ns}")))
+        })
+        .collect::<CoreResult<Vec<_>>>()?;
+
+    let start_time = Instant::now();
+    let deadline = start_time + TOTAL_TIME_LIMIT;
+    let progress = Progress {
+        completed: AtomicU32::new(0),
+        total: iterations.saturating_mul(u32::try_from(servers.len()).unwrap_or(u32::MAX)),
+        callback: on_progress,
+    };
+
+    let concurrency = concurrency.clamp(1, MAX_CONCURRENCY) as usize;
+    let mut results: Vec<(usize, DnsBenchmarkServerResult)> =
+        stream::iter(servers.into_iter().enumerate())
+            .map(|(index, (ip, nameserver))| {
+                let progress = &progress;
+                async move {
+                    let result = benchmark_server(
+                        ip, nameserver, domain, iterations, deadline, cancelled, progress,
+                    )
+                    .await;
+                    (index, result)
+                }
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+    results.sort_by_key(|(index, _)| *index);
+
+    let cancelled = cancelled.load(Ordering::SeqCst);
+    Ok(DnsBenchmarkResult {
+        domain: domain.to_string(),
+        iterations,
+        results: results.into_iter().map(|(_, result)| result).collect(),
+        total_time_ms: elapsed_ms(start_time),
+        cancelled,
+        time_limited: !cancelled && Instant::now() >= deadline,
+    })
+}
+
+/// 测试单个服务器
+async fn benchmark_server(
+    ip: IpAddr,
+    nameserver: String,
+    domain: &str,
+    iterations: u32,
+    deadline: Instant,
+    cancelled: &AtomicBool,
+    progress: &Progress<'_>,
+) -> DnsBenchmarkServerResult {
+    let backend = &HickoryResolverBackend;
+    let probe = build_query(ip, domain, DnsTransport::UdpWithTcpFallback, false);
+
+    let mut cold_ms = None;
+    let mut samples_ms = Vec::new();
+    let mut sent = 0;
+    let mut timeouts = 0;
+    let mut errors = 0;
+    let mut error = None;
+
+    for i in 0..iterations {
+        if cancelled.load(Ordering::SeqCst) || Instant::now() >= deadline {
+            break;
+        }
+        if i > 0 {
+            tokio::time::sleep(QUERY_INTERVAL).await;
+        }
+
+        let query_start = Instant::now();
+        let outcome = query(backend, &probe).await;
+        let elapsed = elapsed_ms(query_start);
+        sent += 1;
+        progress.tick(&nameserver);
+
+        match outcome {
+            QueryOutcome::Answered if i == 0 => cold_ms = Some(elapsed),
+            QueryOutcome::Answered => samples_ms.push(elapsed),
+            QueryOutcome::Timeout => timeouts += 1,
+            QueryOutcome::Error(e) => {
+                errors += 1;
+                error = Some(e);
+            }
+        }
+    }
+
+    // 服务器可达时才探测 EDNS / TCP，否则结果没有意义
+    let reachable = cold_ms.is_some() || !samples_ms.is_empty();
+    let (edns_supported, tcp_supported) =
+        if reachable && !cancelled.load(Ordering::SeqCst) && Instant::now() < deadline {
+            tokio::join!(
+                probe_edns(backend, ip, domain),
+                probe_tcp(backend, ip, domain)
+            )
+        } else {
+            (None, None)
+        };
+
+    let mut sorted = samples_ms.clone();
+    sorted.sort_unstable();
+    let stats = LatencyStats {
+        min: sorted.first().copied(),
+        p50: percentile(&sorted, 50),
+        p95: percentile(&sorted, 95),
+        max: sorted.last().copied(),
+    };
+
+    DnsBenchmarkServerResult {
+        nameserver,
+        cold_ms,
+        samples_ms,
+        stats,
+        sent,
+        timeouts,
+        errors,
+        loss_rate: if sent == 0 {
+            0.0
+        } else {
+            f64::from(timeouts) * 100.0 / f64::from(sent)
+        },
+        edns_supported,
+        tcp_supported,
+        error,
+    }
+}
+
+/// 探测 EDNS 支持：开启 EDNS 后仍能得到应答即支持，收到错误应答（如 FORMERR）视为不支持
+async fn probe_edns(backend: &dyn DnsResolverBackend, ip: IpAddr, domain: &str) -> Option<bool> {
+    let probe = build_query(ip, domain, DnsTransport::Udp, true);
+    match query(backend, &probe).await {
+        QueryOutcome::Answered => Some(true),
+        QueryOutcome::Error(_) => Some(false),
+        QueryOutcome::Timeout => None,
+    }
+}
+
+/// 探测 TCP 支持（用于应答截断时的回退）
+async fn probe_tcp(backend: &dyn DnsResolverBackend, ip: IpAddr, domain: &str) -> Option<bool> {
+    let probe = build_query(ip, domain, DnsTransport::Tcp, false);
+    match query(backend, &probe).await {
+        QueryOutcome::Answered => Some(true),
+        QueryOutcome::Timeout | QueryOutcome::Error(_) => Some(false),
+    }
+}
+
+/// 构造只查询指定服务器、不重试的 A 记录查询
+fn build_query(ip: IpAddr, domain: &str, transport: DnsTransport, edns0: bool) -> DnsQuery {
+    DnsQuery::new(domain, DnsQueryType::A, DnsServer::Ip(ip)).with_options(DnsQueryOptions {
+        transport,
+        edns0,
+        timeout: Some(QUERY_TIMEOUT),
+        single_attempt: true,
+        ..DnsQueryOptions::default()
+    })
+}
+
+async fn query(backend: &dyn DnsResolverBackend, probe: &DnsQuery) -> QueryOutcome {
+    // 比解析器超时稍长，兜底防止卡死
+    match timeout(QUERY_TIMEOUT * 2, backend.query(probe)).await {
+        Ok(Ok(_)) => QueryOutcome::Answered,
+        Ok(Err(e)) if e.is_answered() => QueryOutcome::Answered,
+        Ok(Err(e)) if e.kind == DnsQueryErrorKind::Timeout => QueryOutcome::Timeout,
+        Ok(Err(e)) => QueryOutcome::Error(e.to_string()),
+        Err(_) => QueryOutcome::Timeout,
+    }
+}
+
+/// 最近秩法计算百分位数（输入需已排序）
+fn percentile(sorted: &[u64], p: usize) -> Option<u64> {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+fn elapsed_ms(start: Instant) -> u64 {
+    u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+
+    const SERVER: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 53));
+
+    async fn outcome(kind: DnsQueryErrorKind) -> QueryOutcome {
+        let resolver = FakeDnsResolver::new().otherwise(Err(kind));
+        let probe = build_query(SERVER, "example.com", DnsTransport::Udp, false);
+        query(&resolver, &probe).await
+    }
+
+    #[tokio::test]
+    async fn classifies_query_outcomes() {
+        // 服务器给出了应答（即使是错误响应码）都计入延迟统计
+        for kind in [
+            DnsQueryErrorKind::NxDomain,
+            DnsQueryErrorKind::NoRecords,
+            DnsQueryErrorKind::ServFail,
+            DnsQueryErrorKind::Refused,
+        ] {
+            assert!(matches!(outcome(kind).await, QueryOutcome::Answered));
+        }
+        assert!(matches!(
+            outcome(DnsQueryErrorKind::Timeout).await,
+            QueryOutcome::Timeout
+        ));
+        assert!(matches!(
+            outcome(DnsQueryErrorKind::Truncated).await,
+            QueryOutcome::Error(message) if message == "response truncated"
+        ));
+    }
+
+    #[tokio::test]
+    async fn probes_use_requested_transport() {
+        let resolver = FakeDnsResolver::new();
+        assert_eq!(
+            probe_tcp(&resolver, SERVER, "example.com").await,
+            Some(true)
+        );
+        assert_eq!(
+            probe_edns(&resolver, SERVER, "example.com").await,
+            Some(true)
+        );
+
+        let queries = resolver.queries();
+        assert_eq!(queries[0].options.transport, DnsTransport::Tcp);
+        assert!(!queries[0].options.edns0);
+        assert_eq!(queries[1].options.transport, DnsTransport::Udp);
+        assert!(queries[1].options.edns0);
+        assert!(queries.iter().all(|q| q.options.single_attempt));
+    }
+}
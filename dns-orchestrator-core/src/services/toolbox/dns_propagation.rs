@@ -1,19 +1,45 @@
 //! DNS 传播检查模块
 
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::time::Instant;
 
 use futures::future::join_all;
 use tokio::time::{timeout, Duration};
 
 use crate::error::CoreResult;
-use crate::types::{DnsPropagationResult, DnsPropagationServer, DnsPropagationServerResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsLookupRecord, DnsLookupTransport, DnsPropagationResult, DnsPropagationServer,
+    DnsPropagationServerResult, PropagationCheckResult, PropagationResolverResult,
+};
 
-use super::dns::dns_lookup;
+use super::dns::lookup_with;
+use super::external_endpoints::EndpointDeclaration;
+use super::HickoryResolverBackend;
 
 /// DNS 查询超时时间（秒）
 const QUERY_TIMEOUT_SECS: u64 = 5;
 
+/// 公共解析器传播检查的外发端点标识
+pub(super) const PUBLIC_RESOLVERS_ENDPOINT: &str = "dns_propagation.public_resolvers";
+
+/// 本模块访问的第三方端点
+pub(super) fn external_endpoints() -> Vec<EndpointDeclaration> {
+    vec![EndpointDeclaration {
+        id: PUBLIC_RESOLVERS_ENDPOINT,
+        module: "dns_propagation",
+        targets: get_global_dns_servers()
+            .into_iter()
+            .map(|server| format!("{}:53 ({})", server.ip, server.name))
+            .collect(),
+        purpose: "向全球公共 DNS 解析器查询记录传播情况",
+        sends_query_target: true,
+        essential: false,
+        confirmable: true,
+    }]
+}
+
 /// 获取全球 DNS 服务器列表
 fn get_global_dns_servers() -> Vec<DnsPropagationServer> {
     vec![
@@ -93,6 +119,24 @@ fn get_global_dns_servers() -> Vec<DnsPropagationServer> {
             region: "欧洲".to_string(),
             country_code: "EU".to_string(),
         },
+        DnsPropagationServer {
+            name: "Verisign DNS".to_string(),
+            ip: "64.6.64.6".to_string(),
+            region: "美国".to_string(),
+            country_code: "US".to_string(),
+        },
+        DnsPropagationServer {
+            name: "Yandex DNS".to_string(),
+            ip: "77.88.8.8".to_string(),
+            region: "俄罗斯（欧洲）".to_string(),
+            country_code: "RU".to_string(),
+        },
+        DnsPropagationServer {
+            name: "KT DNS".to_string(),
+            ip: "168.126.63.1".to_string(),
+            region: "韩国（亚洲）".to_string(),
+            country_code: "KR".to_string(),
+        },
         DnsPropagationServer {
             name: "Telstra Corporation Ltd".to_string(),
             ip: "139.130.4.4".to_string(),
@@ -102,12 +146,21 @@ fn get_global_dns_servers() -> Vec<DnsPropagationServer> {
     ]
 }
 
-/// 计算一致性百分比和唯一值
-fn calculate_consistency(results: &[DnsPropagationServerResult]) -> (f32, Vec<String>) {
+/// 一致性统计
+#[derive(Debug, Default)]
+struct Consensus {
+    percentage: f32,
+    unique_values: Vec<String>,
+    value: Option<String>,
+    propagated_count: usize,
+}
+
+/// 计算一致性百分比、唯一值与多数应答
+fn calculate_consistency(results: &[DnsPropagationServerResult]) -> Consensus {
     let successful_results: Vec<_> = results.iter().filter(|r| r.status == "success").collect();
 
     if successful_results.is_empty() {
-        return (0.0, vec![]);
+        return Consensus::default();
     }
 
     // 将每个结果的记录值序列化为字符串（排序后）
@@ -133,12 +186,127 @@ fn calculate_consistency(results: &[DnsPropagationServerResult]) -> (f32, Vec<St
     }
 
     let total = successful_results.len();
-    let max_count = value_counts.values().max().copied().unwrap_or(0);
-    let consistency = (max_count as f32 / total as f32) * 100.0;
+    // 票数相同时取字典序最小的值，保证结果稳定
+    let (consensus, max_count) = value_counts
+        .iter()
+        .max_by(|(a_value, a_count), (b_value, b_count)| {
+            a_count.cmp(b_count).then_with(|| b_value.cmp(a_value))
+        })
+        .map(|(value, count)| (value.clone(), *count))
+        .unwrap_or_default();
+    #[allow(clippy::cast_precision_loss)]
+    let percentage = (max_count as f32 / total as f32) * 100.0;
+
+    let mut unique_values: Vec<_> = value_counts.into_keys().collect();
+    unique_values.sort();
+
+    // 多数服务器均无应答时不视为已传播
+    if consensus.is_empty() {
+        return Consensus {
+            percentage,
+            unique_values,
+            value: None,
+            propagated_count: 0,
+        };
+    }
+
+    Consensus {
+        percentage,
+        unique_values,
+        value: Some(consensus),
+        propagated_count: max_count,
+    }
+}
+
+/// 查询单个 DNS 服务器（超时独立计算）
+async fn query_server(
+    backend: &dyn DnsResolverBackend,
+    server: DnsPropagationServer,
+    domain: &str,
+    record_type: &str,
+) -> DnsPropagationServerResult {
+    let query_start = Instant::now();
+    let result = timeout(
+        Duration::from_secs(QUERY_TIMEOUT_SECS),
+        lookup_with(
+            backend,
+            domain,
+            record_type,
+            Some(&server.ip),
+            DnsLookupTransport::Udp,
+        ),
+    )
+    .await;
+    let elapsed = u64::try_from(query_start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    match result {
+        Ok(Ok(lookup_result)) => DnsPropagationServerResult {
+            server,
+            status: "success".to_string(),
+            records: lookup_result.records,
+            error: None,
+            response_time_ms: elapsed,
+        },
+        Ok(Err(e)) => DnsPropagationServerResult {
+            server,
+            status: "error".to_string(),
+            records: vec![],
+            error: Some(e.to_string()),
+            response_time_ms: elapsed,
+        },
+        Err(_) => DnsPropagationServerResult {
+            server,
+            status: "timeout".to_string(),
+            records: vec![],
+            error: Some(format!("Query timeout ({QUERY_TIMEOUT_SECS}s)")),
+            response_time_ms: elapsed,
+        },
+    }
+}
+
+/// 并发查询所有 DNS 服务器
+async fn query_all_servers(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    record_type: &str,
+) -> Vec<DnsPropagationServerResult> {
+    join_all(
+        get_global_dns_servers()
+            .into_iter()
+            .map(|server| query_server(backend, server, domain, record_type)),
+    )
+    .await
+}
 
-    let unique_values: Vec<_> = value_counts.keys().cloned().collect();
+/// 判断解析到的记录是否等于期望值
+///
+/// MX 等带优先级的记录既可只写值，也可写成 `优先级 值`。
+fn record_matches(record: &DnsLookupRecord, expected: &str) -> bool {
+    let expected = expected.trim();
+    if value_matches(&record.record_type, &record.value, expected) {
+        return true;
+    }
+    match (record.priority, expected.split_once(char::is_whitespace)) {
+        (Some(priority), Some((p, rest))) => {
+            p.parse() == Ok(priority) && value_matches(&record.record_type, &record.value, rest)
+        }
+        _ => false,
+    }
+}
 
-    (consistency, unique_values)
+/// IP 按地址比较，TXT 去掉引号后精确比较，其余（主机名）忽略大小写和末尾的点
+fn value_matches(record_type: &str, value: &str, expected: &str) -> bool {
+    let expected = expected.trim();
+    match record_type {
+        "A" | "AAAA" => match (value.parse::<IpAddr>(), expected.parse::<IpAddr>()) {
+            (Ok(actual), Ok(expected)) => actual == expected,
+            _ => value == expected,
+        },
+        "TXT" => value.trim_matches('"') == expected.trim_matches('"'),
+        _ => value
+            .trim_end_matches('.')
+            .eq_ignore_ascii_case(expected.trim_end_matches('.')),
+    }
 }
 
 /// DNS 传播检查
@@ -146,64 +314,209 @@ pub async fn dns_propagation_check(
     domain: &str,
     record_type: &str,
 ) -> CoreResult<DnsPropagationResult> {
-    let servers = get_global_dns_servers();
     let start_time = Instant::now();
-
-    // 并发查询所有 DNS 服务器
-    let futures: Vec<_> = servers
-        .into_iter()
-        .map(|server| {
-            let domain = domain.to_string();
-            let record_type = record_type.to_string();
-            async move {
-                let query_start = Instant::now();
-                let result = timeout(
-                    Duration::from_secs(QUERY_TIMEOUT_SECS),
-                    dns_lookup(&domain, &record_type, Some(&server.ip)),
-                )
-                .await;
-                let elapsed = query_start.elapsed().as_millis() as u64;
-
-                match result {
-                    Ok(Ok(lookup_result)) => DnsPropagationServerResult {
-                        server,
-                        status: "success".to_string(),
-                        records: lookup_result.records,
-                        error: None,
-                        response_time_ms: elapsed,
-                    },
-                    Ok(Err(e)) => DnsPropagationServerResult {
-                        server,
-                        status: "error".to_string(),
-                        records: vec![],
-                        error: Some(e.to_string()),
-                        response_time_ms: elapsed,
-                    },
-                    Err(_) => DnsPropagationServerResult {
-                        server,
-                        status: "timeout".to_string(),
-                        records: vec![],
-                        error: Some(format!("Query timeout ({QUERY_TIMEOUT_SECS}s)")),
-                        response_time_ms: elapsed,
-                    },
-                }
-            }
-        })
-        .collect();
-
-    let results = join_all(futures).await;
+    let results = query_all_servers(&HickoryResolverBackend, domain, record_type).await;
 
     // 计算一致性
-    let (consistency_percentage, unique_values) = calculate_consistency(&results);
+    let consensus = calculate_consistency(&results);
+    let total_count = results.len();
 
-    let total_time_ms = start_time.elapsed().as_millis() as u64;
+    let total_time_ms = u64::try_from(start_time.elapsed().as_millis()).unwrap_or(u64::MAX);
 
     Ok(DnsPropagationResult {
         domain: domain.to_string(),
         record_type: record_type.to_string(),
         results,
         total_time_ms,
-        consistency_percentage,
-        unique_values,
+        consistency_percentage: consensus.percentage,
+        unique_values: consensus.unique_values,
+        consensus_value: consensus.value,
+        propagated_count: consensus.propagated_count,
+        total_count,
+    })
+}
+
+/// 期望值传播检查：并发查询全部公共解析器，逐个判断是否已解析到期望值
+pub async fn propagation_check(
+    domain: &str,
+    record_type: &str,
+    expected_value: &str,
+) -> CoreResult<PropagationCheckResult> {
+    propagation_check_with(&HickoryResolverBackend, domain, record_type, expected_value).await
+}
+
+async fn propagation_check_with(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    record_type: &str,
+    expected_value: &str,
+) -> CoreResult<PropagationCheckResult> {
+    let start_time = Instant::now();
+    let results: Vec<PropagationResolverResult> = query_all_servers(backend, domain, record_type)
+        .await
+        .into_iter()
+        .map(|result| PropagationResolverResult {
+            matches: result
+                .records
+                .iter()
+                .any(|record| record_matches(record, expected_value)),
+            values: result.records.into_iter().map(|r| r.value).collect(),
+            server: result.server,
+            response_time_ms: result.response_time_ms,
+            error: result.error,
+        })
+        .collect();
+
+    Ok(PropagationCheckResult {
+        domain: domain.to_string(),
+        record_type: record_type.to_string(),
+        expected_value: expected_value.trim().to_string(),
+        matched_count: results.iter().filter(|r| r.matches).count(),
+        total_count: results.len(),
+        results,
+        total_time_ms: u64::try_from(start_time.elapsed().as_millis()).unwrap_or(u64::MAX),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::error::CoreError;
+    use crate::testing::FakeDnsResolver;
+    use crate::types::{
+        DnsLookupRecord, DnsQueryErrorKind, DnsQueryType, DnsRecordData, DnsServer,
+    };
+
+    fn server_result(status: &str, values: &[&str]) -> DnsPropagationServerResult {
+        DnsPropagationServerResult {
+            server: DnsPropagationServer {
+                name: "test".to_string(),
+                ip: "192.0.2.1".to_string(),
+                region: "test".to_string(),
+                country_code: "US".to_string(),
+            },
+            status: status.to_string(),
+            records: values
+                .iter()
+                .map(|value| DnsLookupRecord {
+                    record_type: "A".to_string(),
+                    name: "example.com".to_string(),
+                    value: (*value).to_string(),
+                    ttl: 300,
+                    priority: None,
+                    record_data: None,
+                })
+                .collect(),
+            error: None,
+            response_time_ms: 10,
+        }
+    }
+
+    #[test]
+    fn picks_majority_answer() {
+        let results = [
+            server_result("success", &["192.0.2.10"]),
+            server_result("success", &["192.0.2.10"]),
+            server_result("success", &["192.0.2.20"]),
+            server_result("timeout", &[]),
+        ];
+        let consensus = calculate_consistency(&results);
+
+        assert_eq!(consensus.value.as_deref(), Some("192.0.2.10"));
+        assert_eq!(consensus.propagated_count, 2);
+        assert_eq!(consensus.unique_values, vec!["192.0.2.10", "192.0.2.20"]);
+    }
+
+    #[test]
+    fn record_order_does_not_matter() {
+        let results = [
+            server_result("success", &["192.0.2.1", "192.0.2.2"]),
+            server_result("success", &["192.0.2.2", "192.0.2.1"]),
+        ];
+        let consensus = calculate_consistency(&results);
+
+        assert_eq!(consensus.value.as_deref(), Some("192.0.2.1|192.0.2.2"));
+        assert_eq!(consensus.propagated_count, 2);
+    }
+
+    #[test]
+    fn empty_answers_are_not_propagated() {
+        let results = [
+            server_result("success", &[]),
+            server_result("success", &[]),
+            server_result("success", &["192.0.2.10"]),
+        ];
+        let consensus = calculate_consistency(&results);
+
+        assert_eq!(consensus.value, None);
+        assert_eq!(consensus.propagated_count, 0);
+
+        let consensus = calculate_consistency(&[server_result("error", &[])]);
+        assert_eq!(consensus.value, None);
+        assert!(consensus.unique_values.is_empty());
+    }
+
+    #[test]
+    fn expected_value_matching() {
+        let record = |record_type: &str, value: &str, priority: Option<u16>| DnsLookupRecord {
+            record_type: record_type.to_string(),
+            name: "example.com".to_string(),
+            value: value.to_string(),
+            ttl: 300,
+            priority,
+            record_data: None,
+        };
+
+        let aaaa = record("AAAA", "2001:db8::1", None);
+        assert!(record_matches(&aaaa, " 2001:0db8:0:0:0:0:0:1 "));
+        assert!(!record_matches(&aaaa, "2001:db8::2"));
+
+        let mx = record("MX", "Mail.Example.com.", Some(10));
+        assert!(record_matches(&mx, "mail.example.com"));
+        assert!(record_matches(&mx, "10 mail.example.com"));
+        assert!(!record_matches(&mx, "20 mail.example.com"));
+
+        let txt = record("TXT", "v=spf1 -all", None);
+        assert!(record_matches(&txt, "\"v=spf1 -all\""));
+        assert!(!record_matches(&txt, "V=SPF1 -ALL"));
+    }
+
+    #[tokio::test]
+    async fn counts_resolvers_with_expected_value() -> CoreResult<()> {
+        let server = |ip: [u8; 4]| DnsServer::Ip(Ipv4Addr::from(ip).into());
+        let resolver = FakeDnsResolver::new()
+            .answer(
+                "example.com",
+                DnsQueryType::A,
+                [DnsRecordData::A(Ipv4Addr::new(192, 0, 2, 10))],
+            )
+            .answer_from(
+                server([8, 8, 8, 8]),
+                "example.com",
+                DnsQueryType::A,
+                [DnsRecordData::A(Ipv4Addr::new(192, 0, 2, 99))],
+            )
+            .fail_from(
+                server([1, 1, 1, 1]),
+                "example.com",
+                DnsQueryType::A,
+                DnsQueryErrorKind::Timeout,
+            );
+
+        let result = propagation_check_with(&resolver, "example.com", "A", "192.0.2.10").await?;
+
+        let total = get_global_dns_servers().len();
+        assert_eq!(result.total_count, total);
+        assert_eq!(result.matched_count, total - 2);
+        let google = result
+            .results
+            .iter()
+            .find(|r| r.server.ip == "8.8.8.8")
+            .ok_or_else(|| CoreError::ValidationError("missing 8.8.8.8".to_string()))?;
+        assert!(!google.matches);
+        assert_eq!(google.values, ["192.0.2.99"]);
+        Ok(())
+    }
+}
@@ -3,18 +3,21 @@
 use std::net::IpAddr;
 use std::time::Instant;
 
-use hickory_resolver::{
-    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
-    name_server::TokioConnectionProvider,
-    proto::{
-        dnssec::{rdata::DNSSECRData, PublicKey},
-        rr::{record_data::RData, RecordType},
-    },
-    TokioResolver,
+use crate::error::{CoreError, CoreResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsQuery, DnsQueryError, DnsQueryErrorKind, DnsQueryOptions, DnsQueryType, DnsRecordData,
+    DnsServer, DnskeyRecord, DnssecResult, DsRecord, RrsigRecord,
 };
 
-use crate::error::{CoreError, CoreResult};
-use crate::types::{DnskeyRecord, DnssecResult, DsRecord, RrsigRecord};
+use super::HickoryResolverBackend;
+
+/// DNSKEY flags：Zone Key（RFC 4034 §2.1.1）
+const FLAG_ZONE_KEY: u16 = 0x0100;
+/// DNSKEY flags：Secure Entry Point
+const FLAG_SECURE_ENTRY_POINT: u16 = 0x0001;
+/// DNSKEY flags：Revoke（RFC 5011）
+const FLAG_REVOKE: u16 = 0x0080;
 
 /// Get algorithm name from algorithm number (RFC 8624)
 fn get_algorithm_name(algorithm: u8) -> String {
@@ -47,8 +50,9 @@ fn get_digest_type_name(digest_type: u8) -> String {
 }
 
 /// 从 RRSIG/SIG 记录提取签名信息
+#[allow(clippy::too_many_arguments)]
 fn extract_signature_record(
-    type_covered: RecordType,
+    type_covered: &str,
     algorithm: u8,
     labels: u8,
     original_ttl: u32,
@@ -76,7 +80,7 @@ fn extract_signature_record(
     let signature_b64 = STANDARD.encode(signature_bytes);
 
     RrsigRecord {
-        type_covered: format!("{type_covered:?}"),
+        type_covered: type_covered.to_string(),
         algorithm,
         algorithm_name: get_algorithm_name(algorithm),
         labels,
@@ -89,95 +93,108 @@ fn extract_signature_record(
     }
 }
 
+/// 计算 DNSKEY 的密钥标签（RFC 4034 附录 B，不支持已弃用的 RSA/MD5）
+fn calculate_key_tag(flags: u16, algorithm: u8, public_key: &[u8]) -> u16 {
+    let [flags_hi, flags_lo] = flags.to_be_bytes();
+    let rdata = [flags_hi, flags_lo, 3, algorithm];
+    let mut ac: u32 = 0;
+    for (i, byte) in rdata.iter().chain(public_key).enumerate() {
+        ac += if i & 1 == 0 {
+            u32::from(*byte) << 8
+        } else {
+            u32::from(*byte)
+        };
+    }
+    ac += ac >> 16;
+    (ac & 0xFFFF) as u16
+}
+
+/// 没有任何 DNSSEC 记录且 SOA 查询失败时，只有域名不存在视为错误
+///
+/// 服务器无响应、拒绝查询或 SERVFAIL（可能是签名验证失败）等无法判断的情况仍按未启用 DNSSEC 处理。
+fn check_domain_exists(domain: &str, error: &DnsQueryError) -> CoreResult<()> {
+    if error.kind == DnsQueryErrorKind::NxDomain {
+        return Err(CoreError::DomainNotFound(domain.to_string()));
+    }
+    Ok(())
+}
+
 /// DNSSEC 验证
+///
+/// 没有查到任何 DNSSEC 记录且 SOA 查询返回 NXDOMAIN 时返回 `CoreError::DomainNotFound`，
+/// 其余情况视为未启用 DNSSEC。
 pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<DnssecResult> {
+    dnssec_check_with(&HickoryResolverBackend, domain, nameserver).await
+}
+
+async fn dnssec_check_with(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    nameserver: Option<&str>,
+) -> CoreResult<DnssecResult> {
     let start_time = Instant::now();
 
     // Get system default DNS server addresses
-    fn get_system_dns() -> String {
-        let config = ResolverConfig::default();
-        let servers: Vec<String> = config
-            .name_servers()
-            .iter()
-            .map(|ns| ns.socket_addr.ip().to_string())
-            .collect();
+    let get_system_dns = || {
+        let servers = backend.system_nameservers();
         if servers.is_empty() {
             "System Default".to_string()
         } else {
             servers.join(", ")
         }
-    }
+    };
 
     // 根据 nameserver 参数决定使用自定义还是系统默认
     let effective_ns = nameserver.filter(|s| !s.is_empty());
 
-    let (resolver, used_nameserver) = if let Some(ns) = effective_ns {
+    let (server, used_nameserver) = if let Some(ns) = effective_ns {
         let ns_ip: IpAddr = ns
             .parse()
             .map_err(|_| CoreError::ValidationError(format!("Invalid DNS server address: {ns}")))?;
-
-        let config = ResolverConfig::from_parts(
-            None,
-            vec![],
-            NameServerConfigGroup::from_ips_clear(&[ns_ip], 53, true),
-        );
-        let provider = TokioConnectionProvider::default();
-        let mut opts = ResolverOpts::default();
-        opts.validate = true; // 启用 DNSSEC 验证
-        let resolver = TokioResolver::builder_with_config(config, provider)
-            .with_options(opts)
-            .build();
-        (resolver, ns.to_string())
+        (DnsServer::Ip(ns_ip), ns.to_string())
     } else {
-        let system_dns = get_system_dns();
-        let provider = TokioConnectionProvider::default();
-        let mut opts = ResolverOpts::default();
-        opts.validate = true; // 启用 DNSSEC 验证
-        let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-            .with_options(opts)
-            .build();
-        (resolver, system_dns)
+        (DnsServer::System, get_system_dns())
+    };
+    // 启用 DNSSEC 验证
+    let options = DnsQueryOptions {
+        dnssec: true,
+        ..DnsQueryOptions::default()
     };
+    let query =
+        |query_type| DnsQuery::new(domain, query_type, server).with_options(options.clone());
 
     let mut dnskey_records = Vec::new();
     let mut ds_records = Vec::new();
     let mut rrsig_records = Vec::new();
     let mut dnssec_enabled = false;
-    let mut validation_status = "indeterminate".to_string();
-
     // Query DNSKEY records
-    if let Ok(response) = resolver.lookup(domain, RecordType::DNSKEY).await {
+    if let Ok(response) = backend.query(&query(DnsQueryType::Dnskey)).await {
         dnssec_enabled = true;
-        for record in response.record_iter() {
-            // Try to parse DNSKEY from RData
-            match record.data() {
-                RData::DNSSEC(DNSSECRData::DNSKEY(dnskey)) => {
-                    // Extract flags
-                    let flags = dnskey.flags();
-
-                    // Extract algorithm
-                    let public_key = dnskey.public_key();
-                    let algorithm = public_key.algorithm();
-                    let algorithm_u8: u8 = algorithm.into();
+        for record in &response.records {
+            match &record.data {
+                DnsRecordData::Dnskey {
+                    flags,
+                    algorithm,
+                    public_key,
+                } => {
+                    use base64::{engine::general_purpose::STANDARD, Engine};
+
+                    let flags = *flags;
+                    let algorithm = *algorithm;
 
                     // Extract public key bytes and encode as Base64
-                    let public_key_bytes = public_key.public_bytes();
-                    use base64::{engine::general_purpose::STANDARD, Engine};
-                    let public_key_b64 = STANDARD.encode(public_key_bytes);
-
-                    // Calculate key tag
-                    let key_tag = match dnskey.calculate_key_tag() {
-                        Ok(tag) => tag,
-                        Err(e) => {
-                            log::warn!("Failed to calculate key_tag: {e}");
-                            0
-                        }
-                    };
+                    let public_key_b64 = STANDARD.encode(public_key);
+
+                    let key_tag = calculate_key_tag(flags, algorithm, public_key);
 
                     // Determine key type based on flags
-                    let key_type = if dnskey.is_key_signing_key() {
+                    let zone_key = flags & FLAG_ZONE_KEY != 0;
+                    let key_type = if zone_key
+                        && flags & FLAG_SECURE_ENTRY_POINT != 0
+                        && flags & FLAG_REVOKE == 0
+                    {
                         "KSK".to_string()
-                    } else if dnskey.zone_key() {
+                    } else if zone_key {
                         "ZSK".to_string()
                     } else {
                         format!("Unknown (flags={flags})")
@@ -186,97 +203,90 @@ pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<
                     dnskey_records.push(DnskeyRecord {
                         flags,
                         protocol: 3,
-                        algorithm: algorithm_u8,
-                        algorithm_name: get_algorithm_name(algorithm_u8),
+                        algorithm,
+                        algorithm_name: get_algorithm_name(algorithm),
                         public_key: public_key_b64,
                         key_tag,
                         key_type,
                     });
                 }
-                _ => {
-                    log::warn!("Unexpected RData type in DNSKEY query: {:?}", record.data());
+                other => {
+                    log::warn!("Unexpected RData type in DNSKEY query: {other:?}");
                 }
             }
         }
     }
 
     // Query DS records
-    if let Ok(response) = resolver.lookup(domain, RecordType::DS).await {
+    if let Ok(response) = backend.query(&query(DnsQueryType::Ds)).await {
         dnssec_enabled = true;
-        for record in response.record_iter() {
-            match record.data() {
-                RData::DNSSEC(DNSSECRData::DS(ds)) => {
-                    // Extract fields
-                    let key_tag = ds.key_tag();
-                    let algorithm: u8 = ds.algorithm().into();
-                    let digest_type_enum = ds.digest_type();
-                    let digest_type_u8: u8 = digest_type_enum.into();
-                    let digest_bytes = ds.digest();
-
-                    // Hex encode digest
-                    let digest_hex = hex::encode(digest_bytes);
-
+        for record in &response.records {
+            match &record.data {
+                DnsRecordData::Ds {
+                    key_tag,
+                    algorithm,
+                    digest_type,
+                    digest,
+                } => {
                     ds_records.push(DsRecord {
-                        key_tag,
-                        algorithm,
-                        algorithm_name: get_algorithm_name(algorithm),
-                        digest_type: digest_type_u8,
-                        digest_type_name: get_digest_type_name(digest_type_u8),
-                        digest: digest_hex,
+                        key_tag: *key_tag,
+                        algorithm: *algorithm,
+                        algorithm_name: get_algorithm_name(*algorithm),
+                        digest_type: *digest_type,
+                        digest_type_name: get_digest_type_name(*digest_type),
+                        digest: hex::encode(digest),
                     });
                 }
-                _ => {
-                    log::warn!("Unexpected RData type in DS query: {:?}", record.data());
+                other => {
+                    log::warn!("Unexpected RData type in DS query: {other:?}");
                 }
             }
         }
     }
 
     // Query RRSIG records
-    if let Ok(response) = resolver.soa_lookup(domain).await {
-        for record in response.as_lookup().record_iter() {
-            if record.record_type() == RecordType::RRSIG {
-                dnssec_enabled = true;
-
-                match record.data() {
-                    RData::DNSSEC(DNSSECRData::RRSIG(rrsig)) => {
-                        let sig_record = extract_signature_record(
-                            rrsig.type_covered(),
-                            rrsig.algorithm().into(),
-                            rrsig.num_labels(),
-                            rrsig.original_ttl(),
-                            rrsig.sig_expiration().get(),
-                            rrsig.sig_inception().get(),
-                            rrsig.key_tag(),
-                            &rrsig.signer_name().to_string(),
-                            rrsig.sig(),
-                        );
-                        rrsig_records.push(sig_record);
-                    }
-                    RData::DNSSEC(DNSSECRData::SIG(sig)) => {
-                        let sig_record = extract_signature_record(
-                            sig.type_covered(),
-                            sig.algorithm().into(),
-                            sig.num_labels(),
-                            sig.original_ttl(),
-                            sig.sig_expiration().get(),
-                            sig.sig_inception().get(),
-                            sig.key_tag(),
-                            &sig.signer_name().to_string(),
-                            sig.sig(),
-                        );
-                        rrsig_records.push(sig_record);
-                    }
-                    _ => {
-                        log::warn!("Unexpected RData type in RRSIG query: {:?}", record.data());
-                    }
+    let soa_error = match backend.query(&query(DnsQueryType::Soa)).await {
+        Ok(response) => {
+            for record in &response.records {
+                if let DnsRecordData::Rrsig {
+                    type_covered,
+                    algorithm,
+                    labels,
+                    original_ttl,
+                    expiration,
+                    inception,
+                    key_tag,
+                    signer_name,
+                    signature,
+                } = &record.data
+                {
+                    dnssec_enabled = true;
+                    rrsig_records.push(extract_signature_record(
+                        type_covered,
+                        *algorithm,
+                        *labels,
+                        *original_ttl,
+                        *expiration,
+                        *inception,
+                        *key_tag,
+                        signer_name,
+                        signature,
+                    ));
                 }
             }
+            None
+        }
+        Err(e) => Some(e),
+    };
+    if dnskey_records.is_empty() && ds_records.is_empty() && rrsig_records.is_empty() {
+        if let Some(e) = soa_error {
+            check_domain_exists(domain, &e)?;
         }
     }
 
     // 确定验证状态
-    // 注意：由于启用了 ResolverOpts.validate = true，hickory-resolver 会自动验证 DNSSEC
+    let validation_status;
+    // 注意：查询时启用了 DNSSEC 验证，后端会自动验证签名
     // 如果验证失败（bogus 签名），查询会返回 SERVFAIL 错误
     // 因此，能成功查询到 DNSSEC 记录说明验证通过或未启用 DNSSEC
     if dnssec_enabled {
@@ -307,7 +317,7 @@ pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<
         log::debug!("DNSSEC validation for {domain}: DNSSEC not enabled");
     }
 
-    let response_time_ms = start_time.elapsed().as_millis() as u64;
+    let response_time_ms = u64::try_from(start_time.elapsed().as_millis()).unwrap_or(u64::MAX);
 
     Ok(DnssecResult {
         domain: domain.to_string(),
@@ -321,3 +331,136 @@ pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<
         error: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use hickory_resolver::proto::dnssec::rdata::DNSKEY;
+
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+
+    const PUBLIC_KEY: [u8; 8] = [0x03, 0x01, 0x00, 0x01, 0xAB, 0xCD, 0xEF, 0x42];
+
+    fn dnskey(flags: u16) -> DnsRecordData {
+        DnsRecordData::Dnskey {
+            flags,
+            algorithm: 13,
+            public_key: PUBLIC_KEY.to_vec(),
+        }
+    }
+
+    #[test]
+    fn key_tag_matches_rfc_4034_checksum() {
+        let mut rdata = vec![0x01, 0x01, 3, 13];
+        rdata.extend_from_slice(&PUBLIC_KEY);
+
+        assert_eq!(
+            calculate_key_tag(257, 13, &PUBLIC_KEY),
+            DNSKEY::calculate_key_tag_internal(&rdata)
+        );
+    }
+
+    #[tokio::test]
+    async fn signed_zone_is_secure() -> CoreResult<()> {
+        let resolver = FakeDnsResolver::new()
+            .answer(
+                "example.com",
+                DnsQueryType::Dnskey,
+                [dnskey(257), dnskey(256)],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Ds,
+                [DnsRecordData::Ds {
+                    key_tag: 12345,
+                    algorithm: 13,
+                    digest_type: 2,
+                    digest: vec![0xAB, 0xCD],
+                }],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Soa,
+                [DnsRecordData::Rrsig {
+                    type_covered: "SOA".to_string(),
+                    algorithm: 13,
+                    labels: 2,
+                    original_ttl: 3600,
+                    expiration: 1_800_000_000,
+                    inception: 1_700_000_000,
+                    key_tag: 12345,
+                    signer_name: "example.com.".to_string(),
+                    signature: vec![1, 2, 3],
+                }],
+            );
+
+        let result = dnssec_check_with(&resolver, "example.com", Some("192.0.2.53")).await?;
+
+        assert!(result.dnssec_enabled);
+        assert_eq!(result.validation_status, "secure");
+        assert_eq!(result.nameserver, "192.0.2.53");
+        let key_types: Vec<_> = result
+            .dnskey_records
+            .iter()
+            .map(|k| k.key_type.as_str())
+            .collect();
+        assert_eq!(key_types, ["KSK", "ZSK"]);
+        assert_eq!(result.dnskey_records[0].algorithm_name, "ECDSAP256SHA256");
+        assert_eq!(result.ds_records[0].digest, "abcd");
+        assert_eq!(result.ds_records[0].digest_type_name, "SHA-256");
+        assert_eq!(result.rrsig_records[0].type_covered, "SOA");
+        assert_eq!(
+            result.rrsig_records[0].signature_expiration,
+            "2027-01-15 08:00:00 UTC"
+        );
+        assert!(resolver.queries().iter().all(|query| query.options.dnssec));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unsigned_or_failing_zone_is_insecure() -> CoreResult<()> {
+        let resolver = FakeDnsResolver::new()
+            .with_system_nameservers(&["192.0.2.53", "192.0.2.54"])
+            .fail(
+                "example.com",
+                DnsQueryType::Dnskey,
+                DnsQueryErrorKind::ServFail,
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Soa,
+                [DnsRecordData::Soa {
+                    mname: "ns1.example.com.".to_string(),
+                    rname: "hostmaster.example.com.".to_string(),
+                    serial: 1,
+                    refresh: 7200,
+                    retry: 3600,
+                    expire: 1_209_600,
+                    minimum: 300,
+                }],
+            );
+
+        let result = dnssec_check_with(&resolver, "example.com", None).await?;
+
+        assert!(!result.dnssec_enabled);
+        assert_eq!(result.validation_status, "insecure");
+        assert_eq!(result.nameserver, "192.0.2.53, 192.0.2.54");
+        assert!(result.rrsig_records.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_domain_is_error_and_unreachable_server_is_insecure() -> CoreResult<()> {
+        let missing = FakeDnsResolver::new().otherwise(Err(DnsQueryErrorKind::NxDomain));
+        assert!(matches!(
+            dnssec_check_with(&missing, "missing.example", None).await,
+            Err(CoreError::DomainNotFound(domain)) if domain == "missing.example"
+        ));
+
+        let unreachable = FakeDnsResolver::new().otherwise(Err(DnsQueryErrorKind::Timeout));
+        let result = dnssec_check_with(&unreachable, "example.com", Some("192.0.2.53")).await?;
+        assert!(!result.dnssec_enabled);
+        assert_eq!(result.validation_status, "insecure");
+        Ok(())
+    }
+}
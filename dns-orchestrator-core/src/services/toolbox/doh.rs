@@ -0,0 +1,268 @@
+//! DNS-over-HTTPS 查询（RFC 8484）
+//!
+//! 以 `application/dns-message` 格式 POST 线格式报文，用于在企业网络中
+//! 单独验证 DNS over HTTPS 解析结果（排查 split-horizon 等问题）。
+
+use std::time::Duration;
+
+use futures::future::join_all;
+use hickory_resolver::proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_resolver::proto::rr::{Name, RecordType};
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{DnsLookupRecord, DnsLookupResult, DnsLookupTransport, DnsQueryType};
+
+use super::dns::{lookup_record, parse_query_type, ALL_TYPES};
+use super::hickory_backend;
+
+/// RFC 8484 规定的媒体类型
+const DNS_MESSAGE: &str = "application/dns-message";
+
+/// 单次 HTTPS 请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 通过 DNS over HTTPS 服务器查询
+///
+/// `url` 为 HTTPS 端点（如 `https://cloudflare-dns.com/dns-query`），原样回填到结果的 `nameserver`。
+pub async fn dns_lookup_doh(
+    domain: &str,
+    record_type: &str,
+    url: &str,
+) -> CoreResult<DnsLookupResult> {
+    let endpoint = url::Url::parse(url)
+        .ok()
+        .filter(|u| u.scheme() == "https")
+        .ok_or_else(|| CoreError::ValidationError(format!("无效的 DoH 地址: {url}")))?;
+
+    let name = Name::from_utf8(domain)
+        .map_err(|_| CoreError::ValidationError(format!("无效的域名: {domain}")))?;
+
+    // `ALL` 展开的记录类型与 UDP 路径保持一致
+    let types: Vec<DnsQueryType> = if record_type.eq_ignore_ascii_case("ALL") {
+        ALL_TYPES.to_vec()
+    } else {
+        let parsed = parse_query_type(record_type).ok_or_else(|| {
+            CoreError::ValidationError(format!("不支持的记录类型: {record_type}"))
+        })?;
+        vec![parsed]
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| CoreError::NetworkError(format!("初始化 DoH 客户端失败: {e}")))?;
+
+    let responses = join_all(
+        types
+            .iter()
+            .map(|t| query(&client, &endpoint, &name, hickory_backend::record_type(*t))),
+    )
+    .await;
+
+    let mut records = Vec::new();
+    if types.len() == 1 {
+        // 单类型查询时网络错误需要直接反馈给用户
+        for message in responses {
+            records.extend(answers_to_records(domain, types[0], &message?));
+        }
+    } else {
+        let mut answered = false;
+        let mut first_error = None;
+        for (t, message) in types.iter().zip(responses) {
+            match message {
+                Ok(message) => {
+                    answered = true;
+                    records.extend(answers_to_records(domain, *t, &message));
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        // 全部类型都失败说明 DoH 服务器不可用，报错而不是返回空结果
+        if let (false, Some(e)) = (answered, first_error) {
+            return Err(e);
+        }
+    }
+
+    Ok(DnsLookupResult {
+        domain: domain.to_string(),
+        unicode_domain: None,
+        nameserver: url.to_string(),
+        transport: DnsLookupTransport::Doh,
+        records,
+    })
+}
+
+/// 构造查询报文（RFC 8484 §4.1 建议 ID 置 0 以利于 HTTP 缓存）
+fn build_query(name: &Name, record_type: RecordType) -> CoreResult<Vec<u8>> {
+    let mut message = Message::new();
+    message
+        .set_id(0)
+        .set_message_type(MessageType::Query)
+        .set_op_code(OpCode::Query)
+        .set_recursion_desired(true)
+        .add_query(Query::query(name.clone(), record_type));
+
+    message
+        .to_vec()
+        .map_err(|e| CoreError::SerializationError(format!("DNS 报文编码失败: {e}")))
+}
+
+async fn query(
+    client: &reqwest::Client,
+    endpoint: &url::Url,
+    name: &Name,
+    record_type: RecordType,
+) -> CoreResult<Message> {
+    let body = build_query(name, record_type)?;
+
+    let response = client
+        .post(endpoint.clone())
+        .header(reqwest::header::CONTENT_TYPE, DNS_MESSAGE)
+        .header(reqwest::header::ACCEPT, DNS_MESSAGE)
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("DoH 查询失败: {e}")))?;
+
+    if !response.status().is_success() {
+        return Err(CoreError::NetworkError(format!(
+            "DoH 查询失败: HTTP {}",
+            response.status()
+        )));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("读取 DoH 响应失败: {e}")))?;
+
+    Message::from_vec(&bytes).map_err(|e| CoreError::NetworkError(format!("DoH 响应解析失败: {e}")))
+}
+
+/// 提取应答中与查询类型匹配的记录
+///
+/// 与 UDP 路径一致：NXDOMAIN/SERVFAIL 等视为无记录。CNAME 链上的中间记录不计入
+/// （查询 CNAME 本身时除外）。值格式与 UDP 路径共用，TTL 取各记录自身的 TTL。
+fn answers_to_records(
+    domain: &str,
+    query_type: DnsQueryType,
+    message: &Message,
+) -> Vec<DnsLookupRecord> {
+    if message.response_code() != ResponseCode::NoError {
+        return Vec::new();
+    }
+
+    message
+        .answers()
+        .iter()
+        .filter(|r| r.record_type() == hickory_backend::record_type(query_type))
+        .filter_map(|r| {
+            lookup_record(
+                domain,
+                query_type,
+                &hickory_backend::convert_record(r),
+                r.ttl(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use hickory_resolver::proto::rr::rdata::{A, CNAME, MX};
+    use hickory_resolver::proto::rr::{RData, Record};
+
+    use super::*;
+
+    fn name(s: &str) -> Name {
+        Name::from_utf8(s).unwrap_or_else(|_| Name::root())
+    }
+
+    fn response(code: ResponseCode, answers: Vec<Record>) -> Message {
+        let mut message = Message::new();
+        message
+            .set_message_type(MessageType::Response)
+            .set_response_code(code)
+            .insert_answers(answers);
+        message
+    }
+
+    #[test]
+    fn test_build_query_is_cache_friendly() -> CoreResult<()> {
+        let bytes = build_query(&name("example.com"), RecordType::MX)?;
+        let message =
+            Message::from_vec(&bytes).map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        assert_eq!(message.id(), 0);
+        assert!(message.recursion_desired());
+        assert_eq!(message.queries().len(), 1);
+        assert_eq!(message.queries()[0].query_type(), RecordType::MX);
+        assert_eq!(message.queries()[0].name().to_string(), "example.com.");
+        Ok(())
+    }
+
+    #[test]
+    fn test_answers_skip_cname_chain() {
+        let message = response(
+            ResponseCode::NoError,
+            vec![
+                Record::from_rdata(
+                    name("www.example.com."),
+                    300,
+                    RData::CNAME(CNAME(name("example.com."))),
+                ),
+                Record::from_rdata(
+                    name("example.com."),
+                    60,
+                    RData::A(A(Ipv4Addr::new(192, 0, 2, 1))),
+                ),
+            ],
+        );
+
+        let records = answers_to_records("www.example.com", DnsQueryType::A, &message);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].value, "192.0.2.1");
+        assert_eq!(records[0].ttl, 60);
+        assert_eq!(records[0].name, "www.example.com");
+
+        let cnames = answers_to_records("www.example.com", DnsQueryType::Cname, &message);
+        assert_eq!(cnames[0].value, "example.com");
+    }
+
+    #[test]
+    fn test_mx_priority_and_error_rcode() {
+        let mx = Record::from_rdata(
+            name("example.com."),
+            3600,
+            RData::MX(MX::new(10, name("mail.example.com."))),
+        );
+
+        let records = answers_to_records(
+            "example.com",
+            DnsQueryType::Mx,
+            &response(ResponseCode::NoError, vec![mx.clone()]),
+        );
+        assert_eq!(records[0].value, "mail.example.com");
+        assert_eq!(records[0].priority, Some(10));
+
+        let records = answers_to_records(
+            "example.com",
+            DnsQueryType::Mx,
+            &response(ResponseCode::ServFail, vec![mx]),
+        );
+        assert!(records.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_non_https_endpoint() {
+        let result = dns_lookup_doh("example.com", "A", "http://dns.example/dns-query").await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+
+        let result = dns_lookup_doh("example.com", "HINFO", "https://dns.example/dns-query").await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+    }
+}
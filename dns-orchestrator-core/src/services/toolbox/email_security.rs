@@ -0,0 +1,629 @@
+//! 邮件安全检查模块（SPF / DKIM / DMARC）
+
+use std::collections::{HashSet, VecDeque};
+
+use dns_orchestrator_provider::txt;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DkimRecord, DmarcRecord, DnsQuery, DnsQueryType, DnsRecordData, DnsServer, EmailSecurityResult,
+    SpfInclude, SpfMechanism, SpfQualifier, SpfRecord,
+};
+use base64::Engine;
+
+use super::HickoryResolverBackend;
+
+/// `include:` / `redirect=` 最大展开深度
+const MAX_INCLUDE_DEPTH: u8 = 3;
+
+/// RFC 7208 §4.6.4：SPF 评估过程中 DNS 查询次数上限
+const MAX_SPF_DNS_LOOKUPS: u32 = 10;
+
+/// 低于此长度的 RSA 密钥视为弱密钥
+const MIN_RSA_KEY_BITS: u32 = 2048;
+
+/// RSA `SubjectPublicKeyInfo` DER 编码中模数以外的固定开销（字节）
+const RSA_SPKI_OVERHEAD: usize = 38;
+
+/// 查询 TXT 记录，每条记录的多个 character-string 直接拼接；不存在时返回空列表
+pub(super) async fn txt(backend: &dyn DnsResolverBackend, name: &str) -> CoreResult<Vec<String>> {
+    match backend
+        .query(&DnsQuery::new(name, DnsQueryType::Txt, DnsServer::System))
+        .await
+    {
+        Ok(response) => Ok(response
+            .records
+            .iter()
+            .filter_map(|record| match &record.data {
+                DnsRecordData::Txt(segments) => Some(txt::join(segments)),
+                _ => None,
+            })
+            .collect()),
+        Err(e) if e.is_answered() => Ok(Vec::new()),
+        Err(e) => Err(CoreError::NetworkError(format!(
+            "查询 {name} TXT 记录失败: {e}"
+        ))),
+    }
+}
+
+/// 邮件安全检查
+pub async fn email_security_check(
+    domain: &str,
+    dkim_selector: Option<&str>,
+) -> CoreResult<EmailSecurityResult> {
+    check(&HickoryResolverBackend, domain, dkim_selector).await
+}
+
+async fn check(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    dkim_selector: Option<&str>,
+) -> CoreResult<EmailSecurityResult> {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+    let dkim_selector = dkim_selector.map(str::trim).filter(|s| !s.is_empty());
+
+    let mut issues = Vec::new();
+    let mut score = 0u32;
+
+    // SPF
+    let root_txts = txt(backend, &domain).await?;
+    let spf_records = spf_records(&root_txts);
+    let spf = match spf_records.as_slice() {
+        [] => {
+            issues.push("未配置 SPF 记录".to_string());
+            None
+        }
+        [raw] => {
+            let spf = resolve_spf(backend, raw, &mut issues).await;
+            score += spf_score(&spf, &mut issues);
+            Some(spf)
+        }
+        [raw, ..] => {
+            issues.push(format!(
+                "存在 {} 条 SPF 记录，接收方会判定为 PermError",
+                spf_records.len()
+            ));
+            Some(resolve_spf(backend, raw, &mut issues).await)
+        }
+    };
+
+    // DMARC
+    let dmarc_name = format!("_dmarc.{domain}");
+    let dmarc = txt(backend, &dmarc_name)
+        .await?
+        .iter()
+        .find(|txt| has_version_tag(txt, "v=DMARC1"))
+        .map(|raw| parse_dmarc(raw, &mut issues));
+    match &dmarc {
+        Some(dmarc) => score += dmarc_score(dmarc, &mut issues),
+        None => issues.push("未配置 DMARC 记录".to_string()),
+    }
+
+    // DKIM
+    let dkim = if let Some(selector) = dkim_selector {
+        let name = format!("{selector}._domainkey.{domain}");
+        match txt(backend, &name).await {
+            Ok(records) => {
+                let dkim = records
+                    .iter()
+                    .find(|txt| has_version_tag(txt, "v=DKIM1") || txt.contains("p="))
+                    .map(|raw| parse_dkim(selector, raw));
+                match &dkim {
+                    Some(dkim) => score += dkim_score(dkim, &mut issues),
+                    None => issues.push(format!("未找到 DKIM 记录: {name}")),
+                }
+                dkim
+            }
+            Err(e) => {
+                issues.push(e.to_string());
+                None
+            }
+        }
+    } else {
+        issues.push("未指定 DKIM selector，未检查 DKIM".to_string());
+        None
+    };
+
+    Ok(EmailSecurityResult {
+        domain,
+        spf,
+        dmarc,
+        dkim,
+        score: u8::try_from(score.min(100)).unwrap_or(100),
+        issues,
+    })
+}
+
+/// 判断记录是否以指定版本标签开头（大小写不敏感）
+pub(super) fn has_version_tag(txt: &str, tag: &str) -> bool {
+    let txt = txt.trim_start();
+    txt.get(..tag.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(tag))
+        && txt[tag.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| c == ';' || c.is_whitespace())
+}
+
+pub(super) fn spf_records(txts: &[String]) -> Vec<&String> {
+    txts.iter()
+        .filter(|txt| has_version_tag(txt, "v=spf1"))
+        .collect()
+}
+
+// ============ SPF ============
+
+/// 单条 SPF 记录的解析结果
+pub(super) struct ParsedSpf {
+    pub(super) mechanisms: Vec<SpfMechanism>,
+    pub(super) redirect: Option<String>,
+    /// 需要 DNS 查询的机制数（include / a / mx / ptr / exists / redirect）
+    lookups: u32,
+}
+
+pub(super) fn parse_spf(raw: &str, issues: &mut Vec<String>) -> ParsedSpf {
+    let mut parsed = ParsedSpf {
+        mechanisms: Vec::new(),
+        redirect: None,
+        lookups: 0,
+    };
+
+    for term in raw.split_whitespace().skip(1) {
+        // 修饰符：name=value（机制参数中可能出现 `=`，因此要求 `=` 出现在 `:` 和 `/` 之前）
+        if let Some((name, value)) = term.split_once('=') {
+            if !name.contains([':', '/']) {
+                if name.eq_ignore_ascii_case("redirect") {
+                    parsed.redirect = Some(value.to_lowercase());
+                    parsed.lookups += 1;
+                }
+                continue;
+            }
+        }
+
+        let (qualifier, rest) = match term.chars().next() {
+            Some('+') => (SpfQualifier::Pass, &term[1..]),
+            Some('-') => (SpfQualifier::Fail, &term[1..]),
+            Some('~') => (SpfQualifier::SoftFail, &term[1..]),
+            Some('?') => (SpfQualifier::Neutral, &term[1..]),
+            _ => (SpfQualifier::Pass, term),
+        };
+        let split = rest.find([':', '/']).unwrap_or(rest.len());
+        let mechanism = rest[..split].to_lowercase();
+        let value = rest[split..].strip_prefix(':').unwrap_or(&rest[split..]);
+
+        match mechanism.as_str() {
+            "include" | "a" | "mx" | "ptr" | "exists" => parsed.lookups += 1,
+            "all" | "ip4" | "ip6" => {}
+            _ => {
+                issues.push(format!("无法识别的 SPF 机制: {term}"));
+                continue;
+            }
+        }
+        parsed.mechanisms.push(SpfMechanism {
+            qualifier,
+            mechanism,
+            value: (!value.is_empty()).then(|| value.to_string()),
+        });
+    }
+    parsed
+}
+
+pub(super) fn all_qualifier(mechanisms: &[SpfMechanism]) -> Option<SpfQualifier> {
+    mechanisms
+        .iter()
+        .find(|m| m.mechanism == "all")
+        .map(|m| m.qualifier)
+}
+
+/// 被引用的域名（`include:` 目标与 `redirect=` 目标）
+fn referenced_domains(parsed: &ParsedSpf) -> Vec<String> {
+    parsed
+        .mechanisms
+        .iter()
+        .filter(|m| m.mechanism == "include")
+        .filter_map(|m| m.value.as_ref().map(|v| v.to_lowercase()))
+        .chain(parsed.redirect.clone())
+        .collect()
+}
+
+/// 解析根 SPF 记录并按广度优先展开 `include:` / `redirect=` 链
+async fn resolve_spf(
+    backend: &dyn DnsResolverBackend,
+    raw: &str,
+    issues: &mut Vec<String>,
+) -> SpfRecord {
+    let root = parse_spf(raw, issues);
+    let mut all = all_qualifier(&root.mechanisms);
+    let mut dns_lookup_count = root.lookups;
+    let mut includes = Vec::new();
+
+    let mut queue: VecDeque<(String, u8)> = referenced_domains(&root)
+        .into_iter()
+        .map(|domain| (domain, 1))
+        .collect();
+    let mut visited: HashSet<String> = HashSet::new();
+
+    while let Some((domain, depth)) = queue.pop_front() {
+        if !visited.insert(domain.clone()) {
+            continue;
+        }
+        if depth > MAX_INCLUDE_DEPTH {
+            issues.push(format!(
+                "SPF include 嵌套超过 {MAX_INCLUDE_DEPTH} 层，未继续展开: {domain}"
+            ));
+            continue;
+        }
+
+        let records = match txt(backend, &domain).await {
+            Ok(txts) => spf_records(&txts).into_iter().cloned().collect::<Vec<_>>(),
+            Err(e) => {
+                includes.push(SpfInclude {
+                    domain,
+                    depth,
+                    raw: None,
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+        let [record] = records.as_slice() else {
+            let error = if records.is_empty() {
+                "未找到 SPF 记录".to_string()
+            } else {
+                format!("存在 {} 条 SPF 记录", records.len())
+            };
+            issues.push(format!("SPF 引用的 {domain} 无效: {error}"));
+            includes.push(SpfInclude {
+                domain,
+                depth,
+                raw: None,
+                error: Some(error),
+            });
+            continue;
+        };
+
+        let parsed = parse_spf(record, issues);
+        dns_lookup_count += parsed.lookups;
+        if all.is_none() && root.redirect.as_deref() == Some(domain.as_str()) {
+            all = all_qualifier(&parsed.mechanisms);
+        }
+        queue.extend(
+            referenced_domains(&parsed)
+                .into_iter()
+                .map(|next| (next, depth + 1)),
+        );
+        includes.push(SpfInclude {
+            domain,
+            depth,
+            raw: Some(record.clone()),
+            error: None,
+        });
+    }
+
+    SpfRecord {
+        raw: raw.to_string(),
+        mechanisms: root.mechanisms,
+        all,
+        redirect: root.redirect,
+        includes,
+        dns_lookup_count,
+    }
+}
+
+/// SPF 评分（满分 35）
+fn spf_score(spf: &SpfRecord, issues: &mut Vec<String>) -> u32 {
+    let mut score: u32 = 15;
+    score += match spf.all {
+        Some(SpfQualifier::Fail) => 20,
+        Some(SpfQualifier::SoftFail) => 15,
+        Some(SpfQualifier::Neutral) => {
+            issues.push("SPF 使用 ?all，未对其他发件服务器做任何限制".to_string());
+            5
+        }
+        Some(SpfQualifier::Pass) => {
+            issues.push("SPF 使用 +all，允许任何服务器代发邮件".to_string());
+            0
+        }
+        None => {
+            issues.push("SPF 缺少 all 机制".to_string());
+            0
+        }
+    };
+    if spf.dns_lookup_count > MAX_SPF_DNS_LOOKUPS {
+        issues.push(format!(
+            "SPF 需要 {} 次 DNS 查询，超过 RFC 7208 上限 {MAX_SPF_DNS_LOOKUPS}",
+            spf.dns_lookup_count
+        ));
+        score = score.saturating_sub(10);
+    }
+    score
+}
+
+// ============ DMARC ============
+
+/// 解析 `key=value; ...` 形式的标签列表
+fn parse_tags(raw: &str) -> Vec<(String, String)> {
+    raw.split(';')
+        .filter_map(|tag| {
+            let (key, value) = tag.split_once('=')?;
+            Some((key.trim().to_lowercase(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+pub(super) fn parse_dmarc(raw: &str, issues: &mut Vec<String>) -> DmarcRecord {
+    let tags = parse_tags(raw);
+    let tag = |name: &str| {
+        tags.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+    let uris = |name: &str| {
+        tag(name)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|uri| !uri.is_empty())
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    let policy = match tag("p").map(|p| p.to_lowercase()) {
+        Some(policy) if matches!(policy.as_str(), "none" | "quarantine" | "reject") => policy,
+        Some(policy) => {
+            issues.push(format!("DMARC 策略无效: p={policy}"));
+            "none".to_string()
+        }
+        None => {
+            issues.push("DMARC 记录缺少 p 标签".to_string());
+            "none".to_string()
+        }
+    };
+    let percentage = tag("pct")
+        .and_then(|pct| pct.parse::<u8>().ok())
+        .map_or(100, |pct| pct.min(100));
+
+    DmarcRecord {
+        raw: raw.to_string(),
+        policy,
+        subdomain_policy: tag("sp").map(|sp| sp.to_lowercase()),
+        percentage,
+        rua: uris("rua"),
+        ruf: uris("ruf"),
+        adkim: tag("adkim").map(|v| v.to_lowercase()),
+        aspf: tag("aspf").map(|v| v.to_lowercase()),
+    }
+}
+
+/// DMARC 评分（满分 40）
+fn dmarc_score(dmarc: &DmarcRecord, issues: &mut Vec<String>) -> u32 {
+    let policy_score: u32 = match dmarc.policy.as_str() {
+        "reject" => 25,
+        "quarantine" => 20,
+        _ => {
+            issues.push("DMARC 策略为 p=none，仅监控不拦截".to_string());
+            0
+        }
+    };
+    if dmarc.percentage < 100 && policy_score > 0 {
+        issues.push(format!("DMARC 策略仅应用于 {}% 的邮件", dmarc.percentage));
+    }
+
+    let mut score = 10 + policy_score * u32::from(dmarc.percentage) / 100;
+    if dmarc.rua.is_empty() {
+        issues.push("DMARC 未配置汇总报告地址 (rua)".to_string());
+    } else {
+        score += 5;
+    }
+    score
+}
+
+// ============ DKIM ============
+
+fn parse_dkim(selector: &str, raw: &str) -> DkimRecord {
+    let tags = parse_tags(raw);
+    let tag = |name: &str| {
+        tags.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    let key_type = tag("k").unwrap_or("rsa").to_lowercase();
+    let public_key: String = tag("p")
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let key_bits = if key_type == "rsa" {
+        estimate_rsa_key_bits(&public_key)
+    } else {
+        None
+    };
+
+    DkimRecord {
+        selector: selector.to_string(),
+        raw: raw.to_string(),
+        key_type,
+        has_public_key: !public_key.is_empty(),
+        key_bits,
+        testing: tag("t").is_some_and(|flags| flags.split(':').any(|f| f.trim() == "y")),
+    }
+}
+
+/// 按 DER 编码长度估算 RSA 密钥位数（取整到 512 位）
+fn estimate_rsa_key_bits(public_key: &str) -> Option<u32> {
+    let der = base64::engine::general_purpose::STANDARD
+        .decode(public_key)
+        .ok()?;
+    let modulus_bits = der.len().checked_sub(RSA_SPKI_OVERHEAD)? * 8;
+    u32::try_from((modulus_bits + 256) / 512 * 512).ok()
+}
+
+/// DKIM 评分（满分 25）
+fn dkim_score(dkim: &DkimRecord, issues: &mut Vec<String>) -> u32 {
+    if !dkim.has_public_key {
+        issues.push(format!("DKIM 密钥已撤销 (selector {})", dkim.selector));
+        return 0;
+    }
+    let mut score: u32 = 25;
+    if dkim.testing {
+        issues.push("DKIM 处于测试模式 (t=y)".to_string());
+        score -= 10;
+    }
+    if let Some(bits) = dkim.key_bits.filter(|bits| *bits < MIN_RSA_KEY_BITS) {
+        issues.push(format!(
+            "DKIM RSA 密钥长度约 {bits} 位，建议至少 {MIN_RSA_KEY_BITS} 位"
+        ));
+        score -= 10;
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+
+    fn source<const N: usize>(entries: [(&str, Vec<&str>); N]) -> FakeDnsResolver {
+        entries
+            .into_iter()
+            .fold(FakeDnsResolver::new(), |resolver, (name, txts)| {
+                resolver.answer(
+                    name,
+                    DnsQueryType::Txt,
+                    txts.into_iter()
+                        .map(|txt| DnsRecordData::Txt(vec![txt.to_string()])),
+                )
+            })
+    }
+
+    /// 2048 位 RSA 公钥（`SubjectPublicKeyInfo`，294 字节）
+    fn rsa_2048_key() -> String {
+        base64::engine::general_purpose::STANDARD.encode([0u8; 294])
+    }
+
+    #[test]
+    fn parses_spf_mechanisms() {
+        let mut issues = Vec::new();
+        let parsed = parse_spf(
+            "v=spf1 ip4:192.0.2.0/24 a/24 mx include:_spf.example.net ~all exp=explain.example.com",
+            &mut issues,
+        );
+
+        assert!(issues.is_empty());
+        assert_eq!(parsed.mechanisms.len(), 5);
+        assert_eq!(parsed.lookups, 3);
+        assert_eq!(parsed.mechanisms[0].value.as_deref(), Some("192.0.2.0/24"));
+        assert_eq!(parsed.mechanisms[1].value.as_deref(), Some("/24"));
+        assert_eq!(
+            all_qualifier(&parsed.mechanisms),
+            Some(SpfQualifier::SoftFail)
+        );
+    }
+
+    #[test]
+    fn parses_dmarc_tags() {
+        let mut issues = Vec::new();
+        let dmarc = parse_dmarc(
+            "v=DMARC1; p=Quarantine; sp=reject; pct=50; rua=mailto:a@example.com, mailto:b@example.com; adkim=s",
+            &mut issues,
+        );
+
+        assert!(issues.is_empty());
+        assert_eq!(dmarc.policy, "quarantine");
+        assert_eq!(dmarc.subdomain_policy.as_deref(), Some("reject"));
+        assert_eq!(dmarc.percentage, 50);
+        assert_eq!(dmarc.rua.len(), 2);
+        assert!(dmarc.ruf.is_empty());
+        assert_eq!(dmarc.adkim.as_deref(), Some("s"));
+        // 10 + 20 * 50% + 5
+        assert_eq!(dmarc_score(&dmarc, &mut issues), 25);
+    }
+
+    #[test]
+    fn parses_dkim_key() {
+        let dkim = parse_dkim("s1", &format!("v=DKIM1; k=rsa; t=y; p={}", rsa_2048_key()));
+        assert!(dkim.has_public_key);
+        assert!(dkim.testing);
+        assert_eq!(dkim.key_bits, Some(2048));
+
+        let revoked = parse_dkim("s1", "v=DKIM1; p=");
+        assert!(!revoked.has_public_key);
+    }
+
+    #[tokio::test]
+    async fn expands_include_chain_up_to_three_levels() -> CoreResult<()> {
+        let source = source([
+            (
+                "example.com",
+                vec!["v=spf1 include:l1.example.net redirect=r.example.net"],
+            ),
+            (
+                "l1.example.net",
+                vec!["v=spf1 include:l2.example.net ip4:192.0.2.1 -all"],
+            ),
+            ("l2.example.net", vec!["v=spf1 include:l3.example.net -all"]),
+            ("l3.example.net", vec!["v=spf1 include:l4.example.net -all"]),
+            ("l4.example.net", vec!["v=spf1 ip4:192.0.2.4 -all"]),
+            ("r.example.net", vec!["v=spf1 mx -all"]),
+        ]);
+
+        let result = check(&source, "Example.com.", None).await?;
+        let spf = result
+            .spf
+            .ok_or(CoreError::ValidationError("missing spf".to_string()))?;
+
+        let expanded: Vec<(&str, u8)> = spf
+            .includes
+            .iter()
+            .map(|i| (i.domain.as_str(), i.depth))
+            .collect();
+        assert_eq!(
+            expanded,
+            [
+                ("l1.example.net", 1),
+                ("r.example.net", 1),
+                ("l2.example.net", 2),
+                ("l3.example.net", 3)
+            ]
+        );
+        // 根记录没有 all 时取 redirect 目标的
+        assert_eq!(spf.all, Some(SpfQualifier::Fail));
+        // include + redirect + include + mx + include + include
+        assert_eq!(spf.dns_lookup_count, 6);
+        assert!(result.issues.iter().any(|i| i.contains("l4.example.net")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn scores_fully_configured_domain() -> CoreResult<()> {
+        let dkim = format!("v=DKIM1; k=rsa; p={}", rsa_2048_key());
+        let source = source([
+            (
+                "example.com",
+                vec!["google-site-verification=abc", "v=spf1 mx -all"],
+            ),
+            (
+                "_dmarc.example.com",
+                vec!["v=DMARC1; p=reject; rua=mailto:d@example.com"],
+            ),
+            ("s1._domainkey.example.com", vec![dkim.as_str()]),
+        ]);
+
+        let result = check(&source, "example.com", Some("s1")).await?;
+        assert_eq!(result.score, 100);
+        assert!(result.issues.is_empty());
+
+        let result = check(&source, "example.org", None).await?;
+        assert_eq!(result.score, 0);
+        assert!(result.spf.is_none() && result.dmarc.is_none() && result.dkim.is_none());
+        Ok(())
+    }
+}
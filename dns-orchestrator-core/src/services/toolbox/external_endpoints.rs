@@ -0,0 +1,257 @@
+//! 第三方外发端点登记
+//!
+//! 会访问第三方服务的功能模块各自通过 `external_endpoints()` 声明端点，这里汇总后
+//! 供用户审查（[`ToolboxService::list_external_endpoints`](super::ToolboxService::list_external_endpoints)），
+//! 隐私模式也按这里的声明拦截。只访问用户指定目标（输入的域名、主机、服务器或系统解析器）的模块无需登记。
+//!
+//! 新增外发而未登记时，本模块的测试会失败。
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{ExternalEndpoint, ExternalEndpointStatus, ThreatIntelConfig};
+
+use super::{dns_propagation, ip, network_env, rdap, ssl, threat_intel, whois};
+use super::{RDAP_BOOTSTRAP, WHOIS_SERVERS};
+
+/// 功能模块登记的外发端点
+pub(super) struct EndpointDeclaration {
+    /// 端点标识，按 `模块.用途` 命名
+    pub id: &'static str,
+    pub module: &'static str,
+    pub targets: Vec<String>,
+    pub purpose: &'static str,
+    /// 请求中是否携带查询目标
+    pub sends_query_target: bool,
+    /// 必需外发不受隐私模式影响
+    pub essential: bool,
+    /// 能否逐次确认：后台发起的外发无法确认，隐私模式下直接禁用
+    pub confirmable: bool,
+}
+
+impl EndpointDeclaration {
+    fn status(&self, privacy_mode: bool) -> ExternalEndpointStatus {
+        if self.essential || !privacy_mode {
+            ExternalEndpointStatus::Allowed
+        } else if self.confirmable {
+            ExternalEndpointStatus::RequiresConfirmation
+        } else {
+            ExternalEndpointStatus::Disabled
+        }
+    }
+
+    fn into_endpoint(self, privacy_mode: bool) -> ExternalEndpoint {
+        ExternalEndpoint {
+            status: self.status(privacy_mode),
+            id: self.id.to_string(),
+            module: self.module.to_string(),
+            targets: self.targets,
+            purpose: self.purpose.to_string(),
+            sends_query_target: self.sends_query_target,
+            essential: self.essential,
+        }
+    }
+}
+
+/// 当前配置下登记的全部端点（未配置的威胁情报源不计入）
+fn declarations(threat_intel: &ThreatIntelConfig) -> Vec<EndpointDeclaration> {
+    [
+        ip::external_endpoints(),
+        network_env::external_endpoints(),
+        threat_intel::external_endpoints(threat_intel),
+        dns_propagation::external_endpoints(),
+        ssl::external_endpoints(),
+        whois::external_endpoints(WHOIS_SERVERS),
+        rdap::external_endpoints(RDAP_BOOTSTRAP),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// 列出当前配置下可能访问的第三方端点
+pub(super) fn list(threat_intel: &ThreatIntelConfig, privacy_mode: bool) -> Vec<ExternalEndpoint> {
+    declarations(threat_intel)
+        .into_iter()
+        .map(|declaration| declaration.into_endpoint(privacy_mode))
+        .collect()
+}
+
+/// 隐私模式下检查未经确认的访问：必需端点放行，其余返回
+/// [`CoreError::ExternalAccessBlocked`]
+pub(super) fn ensure_allowed(endpoint: &str, threat_intel: &ThreatIntelConfig) -> CoreResult<()> {
+    let declaration = declarations(threat_intel)
+        .into_iter()
+        .find(|declaration| declaration.id == endpoint);
+    match declaration {
+        Some(declaration) if declaration.essential => Ok(()),
+        Some(declaration) => Err(CoreError::ExternalAccessBlocked {
+            endpoint: endpoint.to_string(),
+            purpose: declaration.purpose.to_string(),
+        }),
+        None => Err(CoreError::ExternalAccessBlocked {
+            endpoint: endpoint.to_string(),
+            purpose: "未登记的外发端点".to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use regex::Regex;
+
+    use super::*;
+
+    /// 只访问用户指定目标的模块
+    const USER_TARGET_MODULES: &[&str] = &[
+        "dns",
+        "dns_benchmark",
+        "dnssec",
+        "doh",
+        "hickory_backend",
+        "http_headers",
+        "http_trace",
+        "mail_check",
+        "port",
+        "tls_probe",
+        "traceroute",
+    ];
+
+    /// 表明源文件会访问网络的用法
+    const NETWORK_MARKERS: &[&str] = &[
+        "reqwest::",
+        "TcpStream",
+        "UdpSocket",
+        "Socket::new",
+        "WhoIs::",
+        "hickory_resolver::",
+        "lookup_with(",
+    ];
+
+    fn all_sources_config() -> ThreatIntelConfig {
+        ThreatIntelConfig {
+            abuseipdb_api_key: Some("key".to_string()),
+            spamhaus_drop: true,
+            ..ThreatIntelConfig::default()
+        }
+    }
+
+    fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                collect_rust_files(&path, files)?;
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    /// crate 内各源文件去掉测试模块与注释行后的代码
+    fn production_sources() -> io::Result<Vec<(PathBuf, String)>> {
+        let mut files = Vec::new();
+        collect_rust_files(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut files,
+        )?;
+        files
+            .into_iter()
+            .map(|path| {
+                let source = fs::read_to_string(&path)?;
+                let code = source
+                    .split("#[cfg(test)]\nmod tests")
+                    .next()
+                    .unwrap_or_default()
+                    .lines()
+                    .filter(|line| !line.trim_start().starts_with("//"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Ok((path, code))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_hardcoded_url_is_declared() -> io::Result<()> {
+        let targets: Vec<String> = declarations(&all_sources_config())
+            .into_iter()
+            .flat_map(|declaration| declaration.targets)
+            .collect();
+        let url = Regex::new(r"https?://([A-Za-z0-9.-]+)").map_err(io::Error::other)?;
+
+        for (path, code) in production_sources()? {
+            for captures in url.captures_iter(&code) {
+                let host = &captures[1];
+                assert!(
+                    targets.iter().any(|target| target.contains(host)),
+                    "{} contacts {host} without declaring it in external_endpoints()",
+                    path.display()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn every_network_module_is_declared_or_user_targeted() -> io::Result<()> {
+        for (path, code) in production_sources()? {
+            let Some(module) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            // mod.rs 只做转发
+            if module == "mod" || !NETWORK_MARKERS.iter().any(|marker| code.contains(marker)) {
+                continue;
+            }
+            assert!(
+                code.contains("fn external_endpoints(") || USER_TARGET_MODULES.contains(&module),
+                "{} accesses the network but neither declares external_endpoints() \
+                 nor only contacts user-specified targets",
+                path.display()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn endpoint_ids_are_unique() {
+        let declarations = declarations(&all_sources_config());
+        let ids: HashSet<&str> = declarations.iter().map(|d| d.id).collect();
+        assert_eq!(ids.len(), declarations.len());
+        assert!(declarations.iter().all(|d| !d.targets.is_empty()));
+    }
+
+    #[test]
+    fn privacy_mode_blocks_non_essential_endpoints() {
+        let config = ThreatIntelConfig::default();
+        assert!(matches!(
+            ensure_allowed(ip::GEO_ENDPOINT, &config),
+            Err(CoreError::ExternalAccessBlocked { .. })
+        ));
+        assert!(ensure_allowed(whois::WHOIS_ENDPOINT, &config).is_ok());
+
+        let endpoints = list(&config, true);
+        let status = |id: &str| endpoints.iter().find(|e| e.id == id).map(|e| e.status);
+        assert_eq!(
+            status(ip::GEO_ENDPOINT),
+            Some(ExternalEndpointStatus::RequiresConfirmation)
+        );
+        assert_eq!(
+            status(network_env::PUBLIC_IP_ENDPOINT),
+            Some(ExternalEndpointStatus::Disabled)
+        );
+        assert_eq!(
+            status(whois::WHOIS_ENDPOINT),
+            Some(ExternalEndpointStatus::Allowed)
+        );
+        // 未配置的情报源不会被访问，也不列出
+        assert_eq!(status(threat_intel::ABUSEIPDB_ENDPOINT), None);
+
+        assert!(list(&config, false)
+            .iter()
+            .all(|e| e.status == ExternalEndpointStatus::Allowed));
+    }
+}
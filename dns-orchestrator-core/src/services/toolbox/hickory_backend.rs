@@ -0,0 +1,370 @@
+//! 基于 hickory-resolver 的 DNS 解析后端
+//!
+//! 工具箱对 hickory 解析器的依赖集中在这里，升级 hickory 时只需调整本模块。
+
+use std::net::SocketAddr;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use hickory_resolver::{
+    config::{NameServerConfig, NameServerConfigGroup, ResolverConfig, ResolverOpts},
+    name_server::TokioConnectionProvider,
+    proto::{
+        dnssec::{rdata::DNSSECRData, PublicKey},
+        op::ResponseCode,
+        rr::{RData, Record, RecordType},
+        xfer::Protocol,
+        ProtoError, ProtoErrorKind,
+    },
+    ResolveError, TokioResolver,
+};
+
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsAnswer, DnsAnswerRecord, DnsQuery, DnsQueryError, DnsQueryErrorKind, DnsQueryOptions,
+    DnsQueryType, DnsRecordData, DnsServer, DnsTransport,
+};
+
+/// hickory-resolver 解析后端
+///
+/// 每次查询新建解析器且不使用本地缓存，保证结果反映服务器的实时应答。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HickoryResolverBackend;
+
+#[async_trait]
+impl DnsResolverBackend for HickoryResolverBackend {
+    async fn query(&self, query: &DnsQuery) -> Result<DnsAnswer, DnsQueryError> {
+        let resolver = build_resolver(query)?;
+        let start = Instant::now();
+        let lookup = resolver
+            .lookup(query.name.as_str(), record_type(query.record_type))
+            .await
+            .map_err(|e| convert_error(&e))?;
+
+        Ok(DnsAnswer {
+            records: lookup.record_iter().map(convert_record).collect(),
+            elapsed: start.elapsed(),
+        })
+    }
+
+    fn system_nameservers(&self) -> Vec<String> {
+        let config = ResolverConfig::default();
+        let mut servers: Vec<String> = Vec::new();
+        for ns in config.name_servers() {
+            let ip = ns.socket_addr.ip().to_string();
+            if !servers.contains(&ip) {
+                servers.push(ip);
+            }
+        }
+        servers
+    }
+}
+
+fn build_resolver(query: &DnsQuery) -> Result<TokioResolver, DnsQueryError> {
+    let options = &query.options;
+    let mut opts = ResolverOpts::default();
+    opts.cache_size = 0;
+    opts.edns0 = options.edns0;
+    opts.validate = options.dnssec;
+    if let Some(timeout) = options.timeout {
+        opts.timeout = timeout;
+    }
+    if options.single_attempt {
+        opts.attempts = 1;
+    }
+
+    let config = match query.server {
+        DnsServer::System => system_config(options.transport)?,
+        DnsServer::Ip(ip) => server_config(SocketAddr::new(ip, 53), options, &mut opts)?,
+        DnsServer::Socket(addr) => server_config(addr, options, &mut opts)?,
+    };
+
+    Ok(
+        TokioResolver::builder_with_config(config, TokioConnectionProvider::default())
+            .with_options(opts)
+            .build(),
+    )
+}
+
+/// 系统 DNS 配置，指定 UDP/TCP 时只保留对应协议的服务器
+fn system_config(transport: DnsTransport) -> Result<ResolverConfig, DnsQueryError> {
+    let config = ResolverConfig::default();
+    let protocol = match transport {
+        DnsTransport::Auto | DnsTransport::UdpWithTcpFallback => return Ok(config),
+        DnsTransport::Udp => Protocol::Udp,
+        DnsTransport::Tcp => Protocol::Tcp,
+        DnsTransport::Tls => {
+            return Err(DnsQueryError::new(
+                DnsQueryErrorKind::Other,
+                "DNS over TLS 需要指定服务器",
+            ));
+        }
+    };
+
+    let group: NameServerConfigGroup = config
+        .name_servers()
+        .iter()
+        .filter(|ns| ns.protocol == protocol)
+        .cloned()
+        .collect::<Vec<_>>()
+        .into();
+    Ok(ResolverConfig::from_parts(None, vec![], group))
+}
+
+fn server_config(
+    addr: SocketAddr,
+    options: &DnsQueryOptions,
+    opts: &mut ResolverOpts,
+) -> Result<ResolverConfig, DnsQueryError> {
+    let group = match options.transport {
+        DnsTransport::Auto => {
+            NameServerConfigGroup::from_ips_clear(&[addr.ip()], addr.port(), true)
+        }
+        DnsTransport::UdpWithTcpFallback => {
+            // 不并发发起 UDP 与 TCP，只在 UDP 应答截断时才走 TCP
+            opts.num_concurrent_reqs = 1;
+            name_servers(addr, &[Protocol::Udp, Protocol::Tcp])
+        }
+        DnsTransport::Udp => name_servers(addr, &[Protocol::Udp]),
+        DnsTransport::Tcp => name_servers(addr, &[Protocol::Tcp]),
+        DnsTransport::Tls => tls_name_server(addr, options.tls_server_name.as_deref())?,
+    };
+    Ok(ResolverConfig::from_parts(None, vec![], group))
+}
+
+#[cfg(feature = "dot")]
+fn tls_name_server(
+    addr: SocketAddr,
+    server_name: Option<&str>,
+) -> Result<NameServerConfigGroup, DnsQueryError> {
+    let server_name = server_name.filter(|name| !name.is_empty()).ok_or_else(|| {
+        DnsQueryError::new(DnsQueryErrorKind::Other, "DNS over TLS 缺少 TLS 服务器名称")
+    })?;
+
+    let mut config = NameServerConfig::new(addr, Protocol::Tls);
+    config.tls_dns_name = Some(server_name.to_string());
+    Ok(vec![config].into())
+}
+
+#[cfg(not(feature = "dot"))]
+fn tls_name_server(
+    _addr: SocketAddr,
+    _server_name: Option<&str>,
+) -> Result<NameServerConfigGroup, DnsQueryError> {
+    Err(DnsQueryError::new(
+        DnsQueryErrorKind::Other,
+        "当前构建未启用 DoT 支持",
+    ))
+}
+
+fn name_servers(addr: SocketAddr, protocols: &[Protocol]) -> NameServerConfigGroup {
+    protocols
+        .iter()
+        .map(|protocol| NameServerConfig::new(addr, *protocol))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+pub(super) const fn record_type(query_type: DnsQueryType) -> RecordType {
+    match query_type {
+        DnsQueryType::A => RecordType::A,
+        DnsQueryType::Aaaa => RecordType::AAAA,
+        DnsQueryType::Cname => RecordType::CNAME,
+        DnsQueryType::Mx => RecordType::MX,
+        DnsQueryType::Txt => RecordType::TXT,
+        DnsQueryType::Ns => RecordType::NS,
+        DnsQueryType::Soa => RecordType::SOA,
+        DnsQueryType::Srv => RecordType::SRV,
+        DnsQueryType::Caa => RecordType::CAA,
+        DnsQueryType::Naptr => RecordType::NAPTR,
+        DnsQueryType::Ptr => RecordType::PTR,
+        DnsQueryType::Dnskey => RecordType::DNSKEY,
+        DnsQueryType::Ds => RecordType::DS,
+    }
+}
+
+fn convert_error(err: &ResolveError) -> DnsQueryError {
+    let kind = match err.proto().map(ProtoError::kind) {
+        Some(ProtoErrorKind::NoRecordsFound { response_code, .. }) => match *response_code {
+            ResponseCode::NXDomain => DnsQueryErrorKind::NxDomain,
+            ResponseCode::NoError => DnsQueryErrorKind::NoRecords,
+            ResponseCode::ServFail => DnsQueryErrorKind::ServFail,
+            ResponseCode::Refused => DnsQueryErrorKind::Refused,
+            _ => DnsQueryErrorKind::ErrorResponse,
+        },
+        Some(ProtoErrorKind::Timeout) => DnsQueryErrorKind::Timeout,
+        _ => DnsQueryErrorKind::Other,
+    };
+    DnsQueryError::new(kind, err.to_string())
+}
+
+pub(super) fn convert_record(record: &Record) -> DnsAnswerRecord {
+    let data = match record.data() {
+        RData::A(a) => DnsRecordData::A(a.0),
+        RData::AAAA(aaaa) => DnsRecordData::Aaaa(aaaa.0),
+        RData::CNAME(cname) => DnsRecordData::Cname(cname.0.to_string()),
+        RData::NS(ns) => DnsRecordData::Ns(ns.0.to_string()),
+        RData::PTR(ptr) => DnsRecordData::Ptr(ptr.0.to_string()),
+        RData::MX(mx) => DnsRecordData::Mx {
+            preference: mx.preference(),
+            exchange: mx.exchange().to_string(),
+        },
+        RData::TXT(txt) => DnsRecordData::Txt(
+            txt.iter()
+                .map(|data| String::from_utf8_lossy(data).to_string())
+                .collect(),
+        ),
+        RData::SOA(soa) => DnsRecordData::Soa {
+            mname: soa.mname().to_string(),
+            rname: soa.rname().to_string(),
+            serial: soa.serial(),
+            refresh: soa.refresh(),
+            retry: soa.retry(),
+            expire: soa.expire(),
+            minimum: soa.minimum(),
+        },
+        RData::SRV(srv) => DnsRecordData::Srv {
+            priority: srv.priority(),
+            weight: srv.weight(),
+            port: srv.port(),
+            target: srv.target().to_string(),
+        },
+        RData::CAA(caa) => DnsRecordData::Caa {
+            critical: caa.issuer_critical(),
+            tag: caa.tag().as_str().to_string(),
+            value: String::from_utf8_lossy(caa.raw_value()).to_string(),
+        },
+        RData::NAPTR(naptr) => DnsRecordData::Naptr {
+            order: naptr.order(),
+            preference: naptr.preference(),
+            flags: String::from_utf8_lossy(naptr.flags()).to_string(),
+            services: String::from_utf8_lossy(naptr.services()).to_string(),
+            regexp: String::from_utf8_lossy(naptr.regexp()).to_string(),
+            replacement: naptr.replacement().to_string(),
+        },
+        RData::DNSSEC(DNSSECRData::DNSKEY(dnskey)) => DnsRecordData::Dnskey {
+            flags: dnskey.flags(),
+            algorithm: dnskey.public_key().algorithm().into(),
+            public_key: dnskey.public_key().public_bytes().to_vec(),
+        },
+        RData::DNSSEC(DNSSECRData::DS(ds)) => DnsRecordData::Ds {
+            key_tag: ds.key_tag(),
+            algorithm: ds.algorithm().into(),
+            digest_type: ds.digest_type().into(),
+            digest: ds.digest().to_vec(),
+        },
+        RData::DNSSEC(DNSSECRData::RRSIG(rrsig)) => DnsRecordData::Rrsig {
+            type_covered: format!("{:?}", rrsig.type_covered()),
+            algorithm: rrsig.algorithm().into(),
+            labels: rrsig.num_labels(),
+            original_ttl: rrsig.original_ttl(),
+            expiration: rrsig.sig_expiration().get(),
+            inception: rrsig.sig_inception().get(),
+            key_tag: rrsig.key_tag(),
+            signer_name: rrsig.signer_name().to_string(),
+            signature: rrsig.sig().to_vec(),
+        },
+        other => DnsRecordData::Other {
+            record_type: record.record_type().to_string(),
+            data: other.to_string(),
+        },
+    };
+
+    DnsAnswerRecord {
+        name: record.name().to_string(),
+        ttl: record.ttl(),
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use hickory_resolver::proto::rr::{
+        rdata::{MX, NAPTR, TXT},
+        Name,
+    };
+
+    use super::*;
+
+    fn response_error(response_code: ResponseCode) -> ResolveError {
+        ProtoError::nx_error(Box::default(), None, None, None, response_code, false, None).into()
+    }
+
+    #[test]
+    fn maps_response_codes_to_error_kinds() {
+        let cases = [
+            (ResponseCode::NXDomain, DnsQueryErrorKind::NxDomain),
+            (ResponseCode::NoError, DnsQueryErrorKind::NoRecords),
+            (ResponseCode::ServFail, DnsQueryErrorKind::ServFail),
+            (ResponseCode::Refused, DnsQueryErrorKind::Refused),
+            (ResponseCode::FormErr, DnsQueryErrorKind::ErrorResponse),
+        ];
+        for (code, kind) in cases {
+            let error = convert_error(&response_error(code));
+            assert_eq!(error.kind, kind);
+            assert!(error.is_answered());
+        }
+
+        let timeout = convert_error(&ProtoError::from(ProtoErrorKind::Timeout).into());
+        assert_eq!(timeout.kind, DnsQueryErrorKind::Timeout);
+        assert!(!timeout.is_answered());
+    }
+
+    #[test]
+    fn converts_record_data() -> Result<(), ProtoError> {
+        let name = Name::from_str("example.com.")?;
+        let mx = Record::from_rdata(
+            name.clone(),
+            300,
+            RData::MX(MX::new(10, Name::from_str("mail.example.com.")?)),
+        );
+        assert_eq!(
+            convert_record(&mx),
+            DnsAnswerRecord {
+                name: "example.com.".to_string(),
+                ttl: 300,
+                data: DnsRecordData::Mx {
+                    preference: 10,
+                    exchange: "mail.example.com.".to_string(),
+                },
+            }
+        );
+
+        let txt = Record::from_rdata(
+            name.clone(),
+            60,
+            RData::TXT(TXT::new(vec!["v=spf1 ".to_string(), "-all".to_string()])),
+        );
+        assert_eq!(
+            convert_record(&txt).data,
+            DnsRecordData::Txt(vec!["v=spf1 ".to_string(), "-all".to_string()])
+        );
+
+        let naptr = Record::from_rdata(
+            name,
+            300,
+            RData::NAPTR(NAPTR::new(
+                100,
+                10,
+                b"S".to_vec().into_boxed_slice(),
+                b"SIP+D2U".to_vec().into_boxed_slice(),
+                Box::default(),
+                Name::from_str("_sip._udp.example.com.")?,
+            )),
+        );
+        assert_eq!(
+            convert_record(&naptr).data,
+            DnsRecordData::Naptr {
+                order: 100,
+                preference: 10,
+                flags: "S".to_string(),
+                services: "SIP+D2U".to_string(),
+                regexp: String::new(),
+                replacement: "_sip._udp.example.com.".to_string(),
+            }
+        );
+        Ok(())
+    }
+}
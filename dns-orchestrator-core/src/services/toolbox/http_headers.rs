@@ -1,16 +1,24 @@
 //! HTTP 头检查模块
 
+use std::collections::HashSet;
+use std::fmt::Write as _;
 use std::time::Instant;
 
 use log::debug;
-use reqwest::{Client, Method};
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
+use reqwest::{Client, Method, Response, StatusCode};
 use url::Url;
 
 use crate::error::{CoreError, CoreResult};
 use crate::types::{
-    HttpHeader, HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpMethod, SecurityHeaderAnalysis,
+    HttpHeader, HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpHop, HttpMethod,
+    SecurityHeaderAnalysis, SecurityScore, SecuritySeverity,
 };
 
+use super::http_trace::{normalize_url, MAX_REDIRECTS_LIMIT};
+use super::security_headers::{self, csp_frame_ancestors, Findings};
+
 const REQUEST_TIMEOUT_SECS: u64 = 10;
 
 /// 必需的安全头列表
@@ -22,34 +30,57 @@ const REQUIRED_SECURITY_HEADERS: &[&str] = &[
 ];
 
 /// 建议的安全头列表
-const RECOMMENDED_SECURITY_HEADERS: &[&str] =
-    &["referrer-policy", "permissions-policy", "x-xss-protection"];
+const RECOMMENDED_SECURITY_HEADERS: &[&str] = &[
+    "referrer-policy",
+    "permissions-policy",
+    "x-xss-protection",
+    "cross-origin-opener-policy",
+    "cross-origin-embedder-policy",
+    "cross-origin-resource-policy",
+];
+
+/// 安全头评分权重（满分 100），`Cross-Origin-*` 三个头共 15 分；
+/// 未列出的头（如已弃用的 `X-XSS-Protection`）不计分
+const SECURITY_HEADER_WEIGHTS: &[(&str, u8)] = &[
+    ("strict-transport-security", 20),
+    ("content-security-policy", 25),
+    ("x-frame-options", 10),
+    ("x-content-type-options", 10),
+    ("referrer-policy", 10),
+    ("permissions-policy", 10),
+    ("cross-origin-opener-policy", 5),
+    ("cross-origin-embedder-policy", 5),
+    ("cross-origin-resource-policy", 5),
+];
 
 /// HTTP 头检查
+///
+/// `follow_redirects` 开启时逐跳跟随重定向（最多 `max_redirects` 次），记录各跳，
+/// 头部分析与原始报文针对最终响应。重定向循环或超出次数时停止在最后一个响应并置位标志。
 pub async fn http_header_check(
     request: &HttpHeaderCheckRequest,
 ) -> CoreResult<HttpHeaderCheckResult> {
+    if request.max_redirects > MAX_REDIRECTS_LIMIT {
+        return Err(CoreError::ValidationError(format!(
+            "重定向次数不能超过 {MAX_REDIRECTS_LIMIT}"
+        )));
+    }
     debug!("[HTTP] Checking headers for {}", request.url);
     let start = Instant::now();
 
     // 确保 URL 包含协议，如果没有则默认添加 https://
-    let url = if request.url.starts_with("http://") || request.url.starts_with("https://") {
-        request.url.clone()
-    } else {
-        format!("https://{}", request.url)
-    };
-
-    debug!("[HTTP] Normalized URL: {url}");
+    let start_url = normalize_url(&request.url)?;
+    debug!("[HTTP] Normalized URL: {start_url}");
 
-    // 构建 HTTP 客户端
+    // 构建 HTTP 客户端，重定向由下面的循环处理
     let client = Client::builder()
         .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
-        .redirect(reqwest::redirect::Policy::limited(5))
+        .redirect(Policy::none())
         .build()
         .map_err(|e| CoreError::NetworkError(format!("HTTP client initialization failed: {e}")))?;
 
     // 转换 HTTP 方法
-    let method = match request.method {
+    let mut method = match request.method {
         HttpMethod::GET => Method::GET,
         HttpMethod::HEAD => Method::HEAD,
         HttpMethod::POST => Method::POST,
@@ -58,30 +89,79 @@ pub async fn http_header_check(
         HttpMethod::PATCH => Method::PATCH,
         HttpMethod::OPTIONS => Method::OPTIONS,
     };
+    let mut custom_headers = request.custom_headers.clone();
+    let mut body = request.body.clone();
+
+    let mut redirect_chain = Vec::new();
+    let mut visited = HashSet::new();
+    let mut current = start_url.clone();
+    let mut redirect_limit_reached = false;
+    let mut loop_detected = false;
+
+    let response = loop {
+        visited.insert(current.clone());
+        let hop_start = Instant::now();
+        let response = send(
+            &client,
+            method.clone(),
+            &current,
+            &custom_headers,
+            body.as_deref(),
+            request.content_type.as_deref(),
+        )
+        .await?;
+        if !request.follow_redirects {
+            break response;
+        }
 
-    // 构建请求
-    let mut req_builder = client.request(method.clone(), &url);
-
-    // 添加自定义请求头
-    for header in &request.custom_headers {
-        req_builder = req_builder.header(&header.name, &header.value);
-    }
+        let status = response.status();
+        let location_header = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        redirect_chain.push(HttpHop {
+            url: current.to_string(),
+            status_code: status.as_u16(),
+            location_header: location_header.clone(),
+            response_time_ms: u64::try_from(hop_start.elapsed().as_millis()).unwrap_or(u64::MAX),
+            tls_info: None,
+        });
 
-    // 添加请求体（POST/PUT/PATCH）
-    if let Some(body) = &request.body {
-        if let Some(content_type) = &request.content_type {
-            req_builder = req_builder.header("Content-Type", content_type);
+        let Some(next) = location_header
+            .filter(|_| status.is_redirection())
+            .and_then(|location| current.join(&location).ok())
+        else {
+            break response;
+        };
+        if visited.contains(&next) {
+            loop_detected = true;
+            break response;
+        }
+        if redirect_chain.len() > usize::from(request.max_redirects) {
+            redirect_limit_reached = true;
+            break response;
         }
-        req_builder = req_builder.body(body.clone());
-    }
 
-    // 发送请求
-    let response = req_builder
-        .send()
-        .await
-        .map_err(|e| CoreError::NetworkError(format!("HTTP request failed: {e}")))?;
+        // 303 以及 POST 请求的 301/302 改用 GET 并丢弃请求体（与浏览器一致）
+        if (status == StatusCode::SEE_OTHER && method != Method::HEAD)
+            || (matches!(status, StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND)
+                && method == Method::POST)
+        {
+            method = Method::GET;
+            body = None;
+        }
+        // 跳转到其他主机时不再携带认证信息
+        if next.host_str() != current.host_str()
+            || next.port_or_known_default() != current.port_or_known_default()
+        {
+            custom_headers.retain(|header| !is_sensitive_header(&header.name));
+        }
+        current = next;
+    };
 
     let elapsed = start.elapsed();
+    let url = current.to_string();
     let status_code = response.status().as_u16();
     let status_text = response
         .status()
@@ -108,39 +188,37 @@ pub async fn http_header_check(
     let content_length = Some(body_bytes.len() as u64);
 
     // 将 body_bytes 转换为字符串（用于 raw_response）
-    let body = String::from_utf8_lossy(&body_bytes).to_string();
+    let response_body = String::from_utf8_lossy(&body_bytes).to_string();
 
     // 安全头分析
     let security_analysis = analyze_security_headers(&headers);
+    let security_score = score_security_headers(&security_analysis);
 
-    // 构建原始请求报文
+    // 构建原始请求报文（最后一次请求）
     let mut raw_request = format!("{} {} HTTP/1.1\r\n", method.as_str(), url);
 
-    // 使用 url crate 解析 Host
-    if let Ok(parsed_url) = Url::parse(&url) {
-        if let Some(host) = parsed_url.host_str() {
-            let host_header = if let Some(port) = parsed_url.port() {
-                format!("{host}:{port}")
-            } else {
-                host.to_string()
-            };
-            raw_request.push_str(&format!("Host: {host_header}\r\n"));
-        }
+    if let Some(host) = current.host_str() {
+        let host_header = if let Some(port) = current.port() {
+            format!("{host}:{port}")
+        } else {
+            host.to_string()
+        };
+        let _ = write!(raw_request, "Host: {host_header}\r\n");
     }
 
     // 添加自定义请求头
-    for header in &request.custom_headers {
+    for header in &custom_headers {
         if !header.name.is_empty() && !header.value.is_empty() {
-            raw_request.push_str(&format!("{}: {}\r\n", header.name, header.value));
+            let _ = write!(raw_request, "{}: {}\r\n", header.name, header.value);
         }
     }
 
     // 添加 Content-Type 和请求体
-    if let Some(body) = &request.body {
+    if let Some(body) = &body {
         if let Some(content_type) = &request.content_type {
-            raw_request.push_str(&format!("Content-Type: {content_type}\r\n"));
+            let _ = write!(raw_request, "Content-Type: {content_type}\r\n");
         }
-        raw_request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        let _ = write!(raw_request, "Content-Length: {}\r\n", body.len());
         raw_request.push_str("\r\n");
         raw_request.push_str(body);
     } else {
@@ -150,83 +228,162 @@ pub async fn http_header_check(
     // 构建原始响应报文
     let mut raw_response = format!("HTTP/1.1 {status_code} {status_text}\r\n");
     for header in &headers {
-        raw_response.push_str(&format!("{}: {}\r\n", header.name, header.value));
+        let _ = write!(raw_response, "{}: {}\r\n", header.name, header.value);
     }
     raw_response.push_str("\r\n");
-    raw_response.push_str(&body);
+    raw_response.push_str(&response_body);
 
     debug!(
-        "[HTTP] Check completed: {} - status={}, headers={}, time={:?}",
+        "[HTTP] Check completed: {} - status={}, headers={}, hops={}, time={:?}",
         url,
         status_code,
         headers.len(),
+        redirect_chain.len(),
         elapsed
     );
 
     Ok(HttpHeaderCheckResult {
-        url,
+        url: start_url.to_string(),
         status_code,
         status_text,
-        response_time_ms: elapsed.as_millis() as u64,
+        response_time_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
         headers,
         security_analysis,
+        security_score,
         content_length,
         raw_request,
         raw_response,
+        final_url: url,
+        redirect_chain,
+        redirect_limit_reached,
+        loop_detected,
     })
 }
 
+/// 发送单次请求（不跟随重定向）
+async fn send(
+    client: &Client,
+    method: Method,
+    url: &Url,
+    custom_headers: &[HttpHeader],
+    body: Option<&str>,
+    content_type: Option<&str>,
+) -> CoreResult<Response> {
+    let mut req_builder = client.request(method, url.clone());
+
+    // 添加自定义请求头
+    for header in custom_headers {
+        req_builder = req_builder.header(&header.name, &header.value);
+    }
+
+    // 添加请求体（POST/PUT/PATCH）
+    if let Some(body) = body {
+        if let Some(content_type) = content_type {
+            req_builder = req_builder.header("Content-Type", content_type);
+        }
+        req_builder = req_builder.body(body.to_string());
+    }
+
+    req_builder
+        .send()
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("HTTP request to {url} failed: {e}")))
+}
+
+/// 跨主机重定向时需要移除的请求头
+fn is_sensitive_header(name: &str) -> bool {
+    ["authorization", "cookie", "proxy-authorization"]
+        .iter()
+        .any(|sensitive| name.eq_ignore_ascii_case(sensitive))
+}
+
 /// 分析安全头
+///
+/// 缺少必需的头为错误、缺少建议的头为警告；存在的头按取值分析。
+/// CSP 的 `frame-ancestors` 可以替代 `X-Frame-Options`。
 fn analyze_security_headers(headers: &[HttpHeader]) -> Vec<SecurityHeaderAnalysis> {
-    let mut analysis = Vec::new();
+    let find = |name: &str| headers.iter().find(|h| h.name.eq_ignore_ascii_case(name));
+    let csp = find("content-security-policy").map(|h| h.value.as_str());
+
+    let required = REQUIRED_SECURITY_HEADERS
+        .iter()
+        .map(|&name| (name, SecuritySeverity::Error));
+    let recommended = RECOMMENDED_SECURITY_HEADERS
+        .iter()
+        .map(|&name| (name, SecuritySeverity::Warning));
+
+    required
+        .chain(recommended)
+        .map(|(header_name, missing_severity)| {
+            let found = find(header_name);
+            let findings = match found {
+                Some(header) => security_headers::evaluate(header_name, &header.value, csp),
+                None if header_name == "x-frame-options"
+                    && csp.and_then(csp_frame_ancestors).is_some() =>
+                {
+                    let mut findings = Findings::default();
+                    findings
+                        .note("Not set, but CSP frame-ancestors provides clickjacking protection");
+                    findings
+                }
+                None => Findings {
+                    severity: missing_severity,
+                    messages: vec![get_recommendation(header_name)],
+                },
+            };
 
-    // 检查必需的安全头
-    for &header_name in REQUIRED_SECURITY_HEADERS {
-        let found = headers
-            .iter()
-            .find(|h| h.name.to_lowercase() == header_name);
-
-        analysis.push(SecurityHeaderAnalysis {
-            name: header_name.to_string(),
-            present: found.is_some(),
-            value: found.map(|h| h.value.clone()),
-            status: if found.is_some() {
-                "good".to_string()
-            } else {
-                "missing".to_string()
-            },
-            recommendation: if found.is_none() {
-                Some(get_recommendation(header_name))
-            } else {
-                None
-            },
-        });
-    }
+            SecurityHeaderAnalysis {
+                name: header_name.to_string(),
+                present: found.is_some(),
+                value: found.map(|h| h.value.clone()),
+                severity: findings.severity,
+                findings: findings.messages,
+            }
+        })
+        .collect()
+}
 
-    // 检查建议的安全头
-    for &header_name in RECOMMENDED_SECURITY_HEADERS {
-        let found = headers
+/// 按权重为安全头分析结果评分
+///
+/// 严重程度为 ok 的头计满分，存在但有警告的头计一半，其余不得分；
+/// `max_possible` 为参与评分的头的满分之和，等级按得分占满分的比例划分（≥90% 为 A，此后每 10% 降一级，低于 50% 为 F）。
+pub fn score_security_headers(analyses: &[SecurityHeaderAnalysis]) -> SecurityScore {
+    let mut score: u8 = 0;
+    let mut max_possible: u8 = 0;
+    for analysis in analyses {
+        let Some(&(_, weight)) = SECURITY_HEADER_WEIGHTS
             .iter()
-            .find(|h| h.name.to_lowercase() == header_name);
-
-        analysis.push(SecurityHeaderAnalysis {
-            name: header_name.to_string(),
-            present: found.is_some(),
-            value: found.map(|h| h.value.clone()),
-            status: if found.is_some() {
-                "good".to_string()
-            } else {
-                "warning".to_string()
-            },
-            recommendation: if found.is_none() {
-                Some(get_recommendation(header_name))
-            } else {
-                None
-            },
+            .find(|(name, _)| analysis.name.eq_ignore_ascii_case(name))
+        else {
+            continue;
+        };
+        max_possible = max_possible.saturating_add(weight);
+        score = score.saturating_add(match analysis.severity {
+            SecuritySeverity::Ok => weight,
+            SecuritySeverity::Warning if analysis.present => weight / 2,
+            SecuritySeverity::Warning | SecuritySeverity::Error => 0,
         });
     }
 
-    analysis
+    let percent = if max_possible == 0 {
+        0
+    } else {
+        u32::from(score) * 100 / u32::from(max_possible)
+    };
+    let overall_grade = match percent {
+        90.. => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        50..=59 => 'E',
+        _ => 'F',
+    };
+
+    SecurityScore {
+        overall_grade,
+        score,
+        max_possible,
+    }
 }
 
 /// 获取安全头建议
@@ -239,6 +396,252 @@ fn get_recommendation(header_name: &str) -> String {
         "referrer-policy" => "Set Referrer-Policy to control referrer information".to_string(),
         "permissions-policy" => "Set Permissions-Policy to restrict browser features".to_string(),
         "x-xss-protection" => "Add to enable browser XSS filter".to_string(),
+        "cross-origin-opener-policy" => {
+            "Set Cross-Origin-Opener-Policy to isolate the browsing context".to_string()
+        }
+        "cross-origin-embedder-policy" => {
+            "Set Cross-Origin-Embedder-Policy to control cross-origin embedding".to_string()
+        }
+        "cross-origin-resource-policy" => {
+            "Set Cross-Origin-Resource-Policy to restrict who can load resources".to_string()
+        }
         _ => "Consider adding this security header".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// 以合理取值构造响应头
+    fn headers(names: &[&str]) -> Vec<HttpHeader> {
+        names
+            .iter()
+            .map(|name| HttpHeader {
+                name: (*name).to_string(),
+                value: match name.to_ascii_lowercase().as_str() {
+                    "strict-transport-security" => "max-age=63072000; includeSubDomains; preload",
+                    "content-security-policy" => "default-src 'self'; frame-ancestors 'none'",
+                    "x-frame-options" => "DENY",
+                    "x-content-type-options" => "nosniff",
+                    "referrer-policy" => "strict-origin-when-cross-origin",
+                    "permissions-policy" => "camera=(), microphone=()",
+                    _ => "x",
+                }
+                .to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn all_weighted_headers_score_full_marks() {
+        let names: Vec<&str> = SECURITY_HEADER_WEIGHTS
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+        let score = score_security_headers(&analyze_security_headers(&headers(&names)));
+        assert_eq!(score.score, 100);
+        assert_eq!(score.max_possible, 100);
+        assert_eq!(score.overall_grade, 'A');
+    }
+
+    #[test]
+    fn missing_headers_lower_the_grade() {
+        let score = score_security_headers(&analyze_security_headers(&headers(&[])));
+        assert_eq!(score.score, 0);
+        assert_eq!(score.max_possible, 100);
+        assert_eq!(score.overall_grade, 'F');
+
+        // 缺少 CSP（25 分）：75 分为 C
+        let score = score_security_headers(&analyze_security_headers(&headers(&[
+            "Strict-Transport-Security",
+            "X-Frame-Options",
+            "X-Content-Type-Options",
+            "Referrer-Policy",
+            "Permissions-Policy",
+            "Cross-Origin-Opener-Policy",
+            "Cross-Origin-Embedder-Policy",
+            "Cross-Origin-Resource-Policy",
+            "X-XSS-Protection",
+        ])));
+        assert_eq!(score.score, 75);
+        assert_eq!(score.overall_grade, 'C');
+    }
+
+    #[test]
+    fn grade_is_relative_to_scored_headers() {
+        let analyses = vec![SecurityHeaderAnalysis {
+            name: "strict-transport-security".to_string(),
+            present: true,
+            value: Some("max-age=31536000".to_string()),
+            severity: SecuritySeverity::Ok,
+            findings: Vec::new(),
+        }];
+        let score = score_security_headers(&analyses);
+        assert_eq!(score.score, 20);
+        assert_eq!(score.max_possible, 20);
+        assert_eq!(score.overall_grade, 'A');
+    }
+
+    #[test]
+    fn weak_values_earn_partial_credit() {
+        let mut weak = headers(&["Content-Security-Policy", "X-Content-Type-Options"]);
+        weak.push(HttpHeader {
+            name: "Strict-Transport-Security".to_string(),
+            value: "max-age=86400".to_string(),
+        });
+        weak.push(HttpHeader {
+            name: "X-Frame-Options".to_string(),
+            value: "SAMEORIGIN".to_string(),
+        });
+        let analyses = analyze_security_headers(&weak);
+        let severity = |name: &str| {
+            analyses
+                .iter()
+                .find(|analysis| analysis.name == name)
+                .map(|analysis| analysis.severity)
+        };
+        assert_eq!(
+            severity("strict-transport-security"),
+            Some(SecuritySeverity::Warning)
+        );
+        // 与 CSP frame-ancestors 'none' 冲突
+        assert_eq!(severity("x-frame-options"), Some(SecuritySeverity::Warning));
+        assert_eq!(severity("referrer-policy"), Some(SecuritySeverity::Warning));
+
+        // CSP 25 + nosniff 10 + HSTS 20/2 + XFO 10/2
+        assert_eq!(score_security_headers(&analyses).score, 50);
+    }
+
+    #[test]
+    fn frame_ancestors_covers_missing_x_frame_options() {
+        let analyses = analyze_security_headers(&headers(&["Content-Security-Policy"]));
+        let xfo = analyses
+            .iter()
+            .find(|analysis| analysis.name == "x-frame-options");
+        assert!(xfo.is_some_and(|xfo| !xfo.present && xfo.severity == SecuritySeverity::Ok));
+    }
+
+    /// 本地 HTTP 服务：`/a` → `/b` → `/c`（带 HSTS），`/loop` ↔ `/loop2`
+    async fn redirect_server() -> CoreResult<SocketAddr> {
+        let io_err = |e: std::io::Error| CoreError::NetworkError(e.to_string());
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_err)?;
+        let addr = listener.local_addr().map_err(io_err)?;
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = vec![0u8; 4096];
+                    let Ok(read) = stream.read(&mut buf).await else {
+                        return;
+                    };
+                    let request = String::from_utf8_lossy(&buf[..read]).to_string();
+                    let path = request.split_whitespace().nth(1).unwrap_or("/");
+                    let response = match path {
+                        "/a" => "HTTP/1.1 301 Moved Permanently\r\nLocation: /b\r\n",
+                        "/b" => "HTTP/1.1 302 Found\r\nLocation: c\r\n",
+                        "/c" => {
+                            "HTTP/1.1 200 OK\r\nStrict-Transport-Security: max-age=31536000\r\n"
+                        }
+                        "/loop" => "HTTP/1.1 302 Found\r\nLocation: /loop2\r\n",
+                        "/loop2" => "HTTP/1.1 302 Found\r\nLocation: /loop\r\n",
+                        _ => "HTTP/1.1 404 Not Found\r\n",
+                    };
+                    let _ = stream
+                        .write_all(
+                            format!("{response}Content-Length: 0\r\nConnection: close\r\n\r\n")
+                                .as_bytes(),
+                        )
+                        .await;
+                });
+            }
+        });
+        Ok(addr)
+    }
+
+    fn check_request(
+        url: String,
+        follow_redirects: bool,
+        max_redirects: u8,
+    ) -> HttpHeaderCheckRequest {
+        HttpHeaderCheckRequest {
+            url,
+            method: HttpMethod::GET,
+            custom_headers: Vec::new(),
+            body: None,
+            content_type: None,
+            follow_redirects,
+            max_redirects,
+        }
+    }
+
+    #[tokio::test]
+    async fn follows_redirects_and_analyzes_final_response() -> CoreResult<()> {
+        let addr = redirect_server().await?;
+        let result = http_header_check(&check_request(format!("http://{addr}/a"), true, 5)).await?;
+
+        let chain: Vec<(u16, Option<&str>)> = result
+            .redirect_chain
+            .iter()
+            .map(|hop| (hop.status_code, hop.location_header.as_deref()))
+            .collect();
+        assert_eq!(chain, [(301, Some("/b")), (302, Some("c")), (200, None)]);
+        assert_eq!(result.url, format!("http://{addr}/a"));
+        assert_eq!(result.final_url, format!("http://{addr}/c"));
+        assert_eq!(result.status_code, 200);
+        assert!(result
+            .security_analysis
+            .iter()
+            .any(|analysis| { analysis.name == "strict-transport-security" && analysis.present }));
+        assert!(result
+            .raw_request
+            .starts_with(&format!("GET http://{addr}/c ")));
+        assert!(!result.redirect_limit_reached);
+        assert!(!result.loop_detected);
+
+        // 不跟随时只检查第一个响应
+        let result =
+            http_header_check(&check_request(format!("http://{addr}/a"), false, 5)).await?;
+        assert_eq!(result.status_code, 301);
+        assert_eq!(result.final_url, result.url);
+        assert!(result.redirect_chain.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn loops_and_limits_stop_with_partial_chain() -> CoreResult<()> {
+        let addr = redirect_server().await?;
+
+        let result =
+            http_header_check(&check_request(format!("http://{addr}/loop"), true, 5)).await?;
+        assert!(result.loop_detected);
+        assert!(!result.redirect_limit_reached);
+        assert_eq!(result.redirect_chain.len(), 2);
+        assert_eq!(result.status_code, 302);
+        assert_eq!(result.final_url, format!("http://{addr}/loop2"));
+
+        let result = http_header_check(&check_request(format!("http://{addr}/a"), true, 1)).await?;
+        assert!(result.redirect_limit_reached);
+        assert!(!result.loop_detected);
+        assert_eq!(result.redirect_chain.len(), 2);
+        assert_eq!(result.status_code, 302);
+        assert_eq!(result.final_url, format!("http://{addr}/b"));
+
+        assert!(matches!(
+            http_header_check(&check_request(
+                format!("http://{addr}/a"),
+                true,
+                MAX_REDIRECTS_LIMIT + 1
+            ))
+            .await,
+            Err(CoreError::ValidationError(_))
+        ));
+        Ok(())
+    }
+}
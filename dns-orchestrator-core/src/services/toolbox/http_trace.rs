@@ -0,0 +1,191 @@
+//! HTTP 重定向链跟踪模块
+//!
+//! 关闭 reqwest 的自动重定向，逐跳发送请求并记录状态码与 Location 头，
+//! 便于排查 HTTP→HTTPS、裸域→www 等跳转配置。HTTPS 跳额外附带证书信息。
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use log::debug;
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
+use reqwest::Client;
+use url::Url;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{HttpHop, HttpTraceResult, SslCertInfo};
+
+use super::ssl;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 允许的最大重定向次数
+pub(super) const MAX_REDIRECTS_LIMIT: u8 = 20;
+
+/// 跟踪重定向链，最多跟随 `max_redirects` 次跳转
+///
+/// 起始请求失败时返回错误；中途失败时返回已记录的各跳，并在 `error` 中注明原因。
+pub async fn http_trace(url: &str, max_redirects: u8) -> CoreResult<HttpTraceResult> {
+    if max_redirects > MAX_REDIRECTS_LIMIT {
+        return Err(CoreError::ValidationError(format!(
+            "重定向次数不能超过 {MAX_REDIRECTS_LIMIT}"
+        )));
+    }
+    let start_url = normalize_url(url)?;
+    debug!("[HTTP] Tracing redirects from {start_url}");
+
+    let client = Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .redirect(Policy::none())
+        .build()
+        .map_err(|e| CoreError::NetworkError(format!("HTTP client initialization failed: {e}")))?;
+
+    let mut hops = Vec::new();
+    let mut visited = HashSet::new();
+    let mut current = start_url.clone();
+    let mut redirect_limit_reached = false;
+    let mut loop_detected = false;
+    let mut error = None;
+
+    loop {
+        visited.insert(current.clone());
+        let started = Instant::now();
+        let response = match client.get(current.clone()).send().await {
+            Ok(response) => response,
+            Err(e) if hops.is_empty() => {
+                return Err(CoreError::NetworkError(format!("HTTP request failed: {e}")));
+            }
+            Err(e) => {
+                error = Some(format!("Request to {current} failed: {e}"));
+                break;
+            }
+        };
+        let response_time_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let status = response.status();
+        let location_header = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        hops.push(HttpHop {
+            url: current.to_string(),
+            status_code: status.as_u16(),
+            location_header: location_header.clone(),
+            response_time_ms,
+            tls_info: None,
+        });
+
+        let Some(location) = location_header.filter(|_| status.is_redirection()) else {
+            break;
+        };
+        let next = match current.join(&location) {
+            Ok(next) => next,
+            Err(e) => {
+                error = Some(format!("Invalid Location header {location}: {e}"));
+                break;
+            }
+        };
+        if visited.contains(&next) {
+            loop_detected = true;
+            break;
+        }
+        if hops.len() > usize::from(max_redirects) {
+            redirect_limit_reached = true;
+            break;
+        }
+        current = next;
+    }
+
+    attach_tls_info(&mut hops).await;
+
+    Ok(HttpTraceResult {
+        url: start_url.to_string(),
+        final_url: hops
+            .last()
+            .map_or_else(|| start_url.to_string(), |hop| hop.url.clone()),
+        hops,
+        redirect_limit_reached,
+        loop_detected,
+        error,
+    })
+}
+
+/// 补全协议（默认 https）并校验 URL
+pub(super) fn normalize_url(url: &str) -> CoreResult<Url> {
+    let url = url.trim();
+    let url = if url.starts_with("http://") || url.starts_with("https://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    };
+    Url::parse(&url).map_err(|e| CoreError::ValidationError(format!("无效的 URL: {e}")))
+}
+
+/// 为 HTTPS 跳附带证书信息，同一主机与端口只检查一次
+async fn attach_tls_info(hops: &mut [HttpHop]) {
+    let mut targets: Vec<(String, u16)> =
+        hops.iter().filter_map(|hop| tls_target(&hop.url)).collect();
+    targets.sort();
+    targets.dedup();
+
+    let infos: Vec<((String, u16), Option<SslCertInfo>)> =
+        join_all(targets.into_iter().map(|(host, port)| async move {
+            let info = ssl::ssl_check(&host, Some(port), None, false)
+                .await
+                .ok()
+                .and_then(|result| result.cert_info);
+            ((host, port), info)
+        }))
+        .await;
+
+    for hop in hops {
+        let Some(target) = tls_target(&hop.url) else {
+            continue;
+        };
+        hop.tls_info = infos
+            .iter()
+            .find(|(key, _)| *key == target)
+            .and_then(|(_, info)| info.clone());
+    }
+}
+
+fn tls_target(url: &str) -> Option<(String, u16)> {
+    let url = Url::parse(url).ok()?;
+    if url.scheme() != "https" {
+        return None;
+    }
+    Some((url.host_str()?.to_string(), url.port_or_known_default()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_defaults_to_https() -> CoreResult<()> {
+        assert_eq!(
+            normalize_url(" example.com/path ")?.as_str(),
+            "https://example.com/path"
+        );
+        assert_eq!(
+            normalize_url("http://example.com")?.as_str(),
+            "http://example.com/"
+        );
+        assert!(normalize_url("https://").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tls_target_only_for_https() {
+        assert_eq!(
+            tls_target("https://www.example.com/"),
+            Some(("www.example.com".to_string(), 443))
+        );
+        assert_eq!(
+            tls_target("https://example.com:8443/a"),
+            Some(("example.com".to_string(), 8443))
+        );
+        assert_eq!(tls_target("http://example.com/"), None);
+    }
+}
@@ -1,14 +1,52 @@
 //! IP 地理位置查询模块
 
-use hickory_resolver::{
-    config::{ResolverConfig, ResolverOpts},
-    name_server::TokioConnectionProvider,
-    TokioResolver,
-};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
 use serde::Deserialize;
+use tokio::sync::Mutex;
+use tokio::time::{interval, MissedTickBehavior};
 
 use crate::error::{CoreError, CoreResult};
-use crate::types::{IpGeoInfo, IpLookupResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsQuery, DnsQueryType, DnsRecordData, DnsServer, IpGeoInfo, IpLookupBatchEntry, IpLookupResult,
+};
+use crate::utils::domain_name;
+
+use super::external_endpoints::EndpointDeclaration;
+use super::threat_intel::ThreatIntel;
+use super::HickoryResolverBackend;
+
+/// 地理位置查询的外发端点标识
+pub(super) const GEO_ENDPOINT: &str = "ip.geolocation";
+
+/// 批量查询最多接受的条目数（去重后）
+const MAX_BATCH_QUERIES: usize = 100;
+
+/// CIDR 最多展开的主机位数（IPv4 /29、IPv6 /125，即 8 个地址）
+const MAX_CIDR_HOST_BITS: u32 = 3;
+
+/// 批量查询的并发数（域名解析与地理位置请求分别计）
+const BATCH_CONCURRENCY: usize = 4;
+
+/// 批量查询中相邻两次地理位置请求的最小间隔，避免触发上游限流
+const BATCH_REQUEST_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 本模块访问的第三方端点
+pub(super) fn external_endpoints() -> Vec<EndpointDeclaration> {
+    vec![EndpointDeclaration {
+        id: GEO_ENDPOINT,
+        module: "ip",
+        targets: vec!["https://ipwho.is".to_string()],
+        purpose: "IP 地理位置与 ASN 查询",
+        sends_query_target: true,
+        essential: false,
+        confirmable: true,
+    }]
+}
 
 /// ipwhois.io 响应结构
 #[derive(Deserialize)]
@@ -101,11 +139,48 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> CoreResult<IpGe
         org: org.clone(),
         asn,
         as_name: org,
+        threat: None,
     })
 }
 
-/// IP/域名 地理位置查询
-pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
+/// 附加威胁情报标注（未配置情报源时保持为空）
+///
+/// 调用方已放行本次查询的外发（隐私模式下经过确认），AbuseIPDB 随地理位置查询一并访问。
+async fn annotate_threat(info: &mut IpGeoInfo, threat_intel: Option<&ThreatIntel>) {
+    let Some(threat_intel) = threat_intel.filter(|t| t.config().has_sources()) else {
+        return;
+    };
+    if let Ok(ip) = info.ip.parse() {
+        info.threat = Some(threat_intel.annotate(ip, true).await);
+    }
+}
+
+/// 通过系统 DNS 解析域名的 IPv4 与 IPv6 地址（先 A 后 AAAA）
+async fn resolve_addresses(backend: &dyn DnsResolverBackend, domain: &str) -> Vec<String> {
+    let mut ips: Vec<String> = Vec::new();
+    for query_type in [DnsQueryType::A, DnsQueryType::Aaaa] {
+        let query = DnsQuery::new(domain, query_type, DnsServer::System);
+        if let Ok(response) = backend.query(&query).await {
+            ips.extend(
+                response
+                    .records
+                    .iter()
+                    .filter_map(|record| match record.data {
+                        DnsRecordData::A(ip) => Some(ip.to_string()),
+                        DnsRecordData::Aaaa(ip) => Some(ip.to_string()),
+                        _ => None,
+                    }),
+            );
+        }
+    }
+    ips
+}
+
+/// IP/域名 地理位置查询，配置了威胁情报源时附带标注
+pub async fn ip_lookup(
+    query: &str,
+    threat_intel: Option<&ThreatIntel>,
+) -> CoreResult<IpLookupResult> {
     let query = query.trim().to_string();
     if query.is_empty() {
         return Err(CoreError::ValidationError(
@@ -117,35 +192,20 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
 
     // 检查是否为 IP 地址
     if query.parse::<std::net::IpAddr>().is_ok() {
-        let result = lookup_single_ip(&query, &client).await?;
+        let mut result = lookup_single_ip(&query, &client).await?;
+        annotate_threat(&mut result, threat_intel).await;
         return Ok(IpLookupResult {
             query,
+            unicode_domain: None,
             is_domain: false,
             results: vec![result],
         });
     }
 
-    // 作为域名处理，解析 A 和 AAAA 记录
-    let provider = TokioConnectionProvider::default();
-    let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-        .with_options(ResolverOpts::default())
-        .build();
-
-    let mut ips: Vec<String> = Vec::new();
-
-    // 解析 IPv4 (A 记录)
-    if let Ok(response) = resolver.ipv4_lookup(&query).await {
-        for ip in response.iter() {
-            ips.push(ip.to_string());
-        }
-    }
-
-    // 解析 IPv6 (AAAA 记录)
-    if let Ok(response) = resolver.ipv6_lookup(&query).await {
-        for ip in response.iter() {
-            ips.push(ip.to_string());
-        }
-    }
+    // 作为域名处理（国际化域名转为 punycode），解析 A 和 AAAA 记录
+    let name = domain_name::normalize(&query)?;
+    let query = name.ascii.clone();
+    let ips = resolve_addresses(&HickoryResolverBackend, &query).await;
 
     if ips.is_empty() {
         return Err(CoreError::NetworkError(format!("无法解析域名: {query}")));
@@ -155,7 +215,10 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
     let mut results = Vec::new();
     for ip in ips {
         match lookup_single_ip(&ip, &client).await {
-            Ok(info) => results.push(info),
+            Ok(mut info) => {
+                annotate_threat(&mut info, threat_intel).await;
+                results.push(info);
+            }
             Err(e) => {
                 log::warn!("查询 IP {ip} 失败: {e}");
             }
@@ -170,7 +233,254 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
 
     Ok(IpLookupResult {
         query,
+        unicode_domain: name.unicode_if_idn(),
         is_domain: true,
         results,
     })
 }
+
+/// 将 CIDR 展开为成员地址；不是 CIDR 写法时返回 `None`
+fn expand_cidr(query: &str) -> Option<Result<Vec<IpAddr>, String>> {
+    let (addr, prefix) = query.split_once('/')?;
+    let invalid = || format!("无效的 CIDR: {query}");
+
+    let (Ok(addr), Ok(prefix)) = (addr.parse::<IpAddr>(), prefix.parse::<u32>()) else {
+        return Some(Err(invalid()));
+    };
+    let bits = if addr.is_ipv4() { 32 } else { 128 };
+    if prefix > bits {
+        return Some(Err(invalid()));
+    }
+    let host_bits = bits - prefix;
+    if host_bits > MAX_CIDR_HOST_BITS {
+        return Some(Err(format!(
+            "CIDR 范围过大: {query}（最多展开 {} 个地址）",
+            1 << MAX_CIDR_HOST_BITS
+        )));
+    }
+
+    let count = 1u32 << host_bits;
+    let addrs = match addr {
+        IpAddr::V4(v4) => {
+            let network = u32::from(v4) >> host_bits << host_bits;
+            (0..count)
+                .map(|i| IpAddr::V4(Ipv4Addr::from(network + i)))
+                .collect()
+        }
+        IpAddr::V6(v6) => {
+            let network = u128::from(v6) >> host_bits << host_bits;
+            (0..count)
+                .map(|i| IpAddr::V6(Ipv6Addr::from(network + u128::from(i))))
+                .collect()
+        }
+    };
+    Some(Ok(addrs))
+}
+
+/// 将批量查询中的单个条目（IP、CIDR 或域名）解析为待查询的地址
+async fn resolve_batch_query(query: &str) -> Result<Vec<IpAddr>, String> {
+    if let Ok(ip) = query.parse::<IpAddr>() {
+        return Ok(vec![ip]);
+    }
+    if let Some(expanded) = expand_cidr(query) {
+        return expanded;
+    }
+
+    let name = domain_name::normalize(query).map_err(|e| e.to_string())?;
+    let ips: Vec<IpAddr> = resolve_addresses(&HickoryResolverBackend, &name.ascii)
+        .await
+        .iter()
+        .filter_map(|ip| ip.parse().ok())
+        .collect();
+    if ips.is_empty() {
+        return Err(format!("无法解析域名: {}", name.ascii));
+    }
+    Ok(ips)
+}
+
+/// 批量 IP/域名/CIDR 地理位置查询
+///
+/// 条目去重后先并发解析为地址，所有地址再去重，以有限并发和固定间隔查询地理位置，
+/// 多个条目解析到同一地址时只请求一次。结果以原始查询（去除首尾空白）为键，
+/// 单个条目失败记录在对应结果中，不影响整批。
+pub async fn ip_lookup_batch(
+    queries: Vec<String>,
+    threat_intel: Option<&ThreatIntel>,
+) -> CoreResult<BTreeMap<String, IpLookupBatchEntry>> {
+    let mut seen = HashSet::new();
+    let queries: Vec<String> = queries
+        .into_iter()
+        .map(|query| query.trim().to_string())
+        .filter(|query| !query.is_empty() && seen.insert(query.clone()))
+        .collect();
+    if queries.is_empty() {
+        return Err(CoreError::ValidationError(
+            "请输入 IP 地址、域名或 CIDR".to_string(),
+        ));
+    }
+    if queries.len() > MAX_BATCH_QUERIES {
+        return Err(CoreError::ValidationError(format!(
+            "单次最多查询 {MAX_BATCH_QUERIES} 条"
+        )));
+    }
+
+    let resolved: Vec<(String, Result<Vec<IpAddr>, String>)> = stream::iter(queries)
+        .map(|query| async move {
+            let addrs = resolve_batch_query(&query).await;
+            (query, addrs)
+        })
+        .buffered(BATCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    let ips: BTreeSet<IpAddr> = resolved
+        .iter()
+        .filter_map(|(_, addrs)| addrs.as_ref().ok())
+        .flatten()
+        .copied()
+        .collect();
+
+    let client = reqwest::Client::new();
+    let mut ticker = interval(BATCH_REQUEST_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let limiter = Mutex::new(ticker);
+
+    let lookups: HashMap<IpAddr, Result<IpGeoInfo, String>> = stream::iter(ips)
+        .map(|ip| {
+            let client = &client;
+            let limiter = &limiter;
+            async move {
+                limiter.lock().await.tick().await;
+                let result = match lookup_single_ip(&ip.to_string(), client).await {
+                    Ok(mut info) => {
+                        annotate_threat(&mut info, threat_intel).await;
+                        Ok(info)
+                    }
+                    Err(e) => {
+                        log::warn!("查询 IP {ip} 失败: {e}");
+                        Err(e.to_string())
+                    }
+                };
+                (ip, result)
+            }
+        })
+        .buffer_unordered(BATCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(resolved
+        .into_iter()
+        .map(|(query, addrs)| {
+            let entry = match addrs {
+                Ok(addrs) => batch_entry(&addrs, &lookups),
+                Err(error) => IpLookupBatchEntry {
+                    results: Vec::new(),
+                    error: Some(error),
+                },
+            };
+            (query, entry)
+        })
+        .collect())
+}
+
+/// 汇总条目各地址的查询结果；全部失败时取第一个错误
+fn batch_entry(
+    addrs: &[IpAddr],
+    lookups: &HashMap<IpAddr, Result<IpGeoInfo, String>>,
+) -> IpLookupBatchEntry {
+    let results: Vec<IpGeoInfo> = addrs
+        .iter()
+        .filter_map(|ip| lookups.get(ip)?.as_ref().ok().cloned())
+        .collect();
+    let error = if results.is_empty() {
+        Some(
+            addrs
+                .iter()
+                .find_map(|ip| lookups.get(ip)?.as_ref().err().cloned())
+                .unwrap_or_else(|| "所有 IP 地址查询均失败".to_string()),
+        )
+    } else {
+        None
+    };
+    IpLookupBatchEntry { results, error }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(query: &str) -> Option<Result<Vec<String>, String>> {
+        expand_cidr(query)
+            .map(|result| result.map(|addrs| addrs.iter().map(ToString::to_string).collect()))
+    }
+
+    #[test]
+    fn test_expand_cidr() {
+        assert_eq!(
+            expand("192.0.2.9/30"),
+            Some(Ok(vec![
+                "192.0.2.8".to_string(),
+                "192.0.2.9".to_string(),
+                "192.0.2.10".to_string(),
+                "192.0.2.11".to_string(),
+            ]))
+        );
+        assert_eq!(
+            expand("192.0.2.1/32"),
+            Some(Ok(vec!["192.0.2.1".to_string()]))
+        );
+        assert_eq!(
+            expand("192.0.2.0/29").map(|r| r.map(|a| a.len())),
+            Some(Ok(8))
+        );
+        assert_eq!(
+            expand("2001:db8::5/126"),
+            Some(Ok(vec![
+                "2001:db8::4".to_string(),
+                "2001:db8::5".to_string(),
+                "2001:db8::6".to_string(),
+                "2001:db8::7".to_string(),
+            ]))
+        );
+
+        // 非 CIDR 写法交给 IP/域名处理
+        assert_eq!(expand("192.0.2.1"), None);
+        assert_eq!(expand("example.com"), None);
+
+        assert!(matches!(expand("192.0.2.0/28"), Some(Err(_))));
+        assert!(matches!(expand("2001:db8::/64"), Some(Err(_))));
+        assert!(matches!(expand("192.0.2.0/33"), Some(Err(_))));
+        assert!(matches!(expand("example.com/30"), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_batch_entry_keeps_partial_results() {
+        let ok_ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        let bad_ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 2));
+        let info = IpGeoInfo {
+            ip: ok_ip.to_string(),
+            ip_version: "IPv4".to_string(),
+            country: None,
+            country_code: None,
+            region: None,
+            city: None,
+            latitude: None,
+            longitude: None,
+            timezone: None,
+            isp: None,
+            org: None,
+            asn: None,
+            as_name: None,
+            threat: None,
+        };
+        let lookups = HashMap::from([(ok_ip, Ok(info)), (bad_ip, Err("查询失败".to_string()))]);
+
+        let partial = batch_entry(&[ok_ip, bad_ip], &lookups);
+        assert_eq!(partial.results.len(), 1);
+        assert_eq!(partial.error, None);
+
+        let failed = batch_entry(&[bad_ip], &lookups);
+        assert!(failed.results.is_empty());
+        assert_eq!(failed.error.as_deref(), Some("查询失败"));
+    }
+}
@@ -0,0 +1,467 @@
+//! 邮件服务健康检查模块（MX / SMTP 连通性 / SPF / DMARC）
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsQuery, DnsQueryType, DnsRecordData, DnsServer, MailCheckResult, MailPolicyCheck,
+    MxHostCheck, SmtpProbe,
+};
+
+use super::email_security::{
+    all_qualifier, has_version_tag, parse_dmarc, parse_spf, spf_records, txt,
+};
+use super::HickoryResolverBackend;
+
+/// SMTP 端口
+const SMTP_PORT: u16 = 25;
+
+/// 单个地址的 TCP 连接超时
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 等待 SMTP 欢迎消息的超时
+const BANNER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 欢迎消息最多读取的字节数
+const MAX_BANNER_BYTES: u64 = 512;
+
+/// 默认整体超时
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 整体超时上限
+const MAX_TIMEOUT: Duration = Duration::from_mins(2);
+
+/// 邮件服务健康检查
+///
+/// `overall_timeout` 为空时使用默认值（30 秒），超时返回 `CoreError::Timeout`。
+pub async fn mail_check(
+    domain: &str,
+    overall_timeout: Option<Duration>,
+) -> CoreResult<MailCheckResult> {
+    check(&HickoryResolverBackend, domain, SMTP_PORT, overall_timeout).await
+}
+
+async fn check(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    port: u16,
+    overall_timeout: Option<Duration>,
+) -> CoreResult<MailCheckResult> {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+    let overall_timeout = overall_timeout.unwrap_or(DEFAULT_TIMEOUT);
+    if overall_timeout.is_zero() || overall_timeout > MAX_TIMEOUT {
+        return Err(CoreError::ValidationError(format!(
+            "超时时间必须在 1 到 {} 秒之间",
+            MAX_TIMEOUT.as_secs()
+        )));
+    }
+
+    timeout(overall_timeout, run_checks(backend, &domain, port))
+        .await
+        .map_err(|_| CoreError::Timeout {
+            operation: "mail_check".to_string(),
+            timeout_ms: u64::try_from(overall_timeout.as_millis()).unwrap_or(u64::MAX),
+        })?
+}
+
+async fn run_checks(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+    port: u16,
+) -> CoreResult<MailCheckResult> {
+    let dmarc_name = format!("_dmarc.{domain}");
+    let (mx, root_txts, dmarc_txts) = tokio::join!(
+        mx_records(backend, domain),
+        txt(backend, domain),
+        txt(backend, &dmarc_name),
+    );
+    let mx = mx?;
+    let mut issues = Vec::new();
+
+    // RFC 7505：唯一一条 `0 .` 表示域名不接收邮件
+    let null_mx = matches!(mx.as_slice(), [(0, host)] if host.is_empty());
+    let mx_hosts = if null_mx {
+        issues.push("域名配置了 Null MX，声明不接收邮件".to_string());
+        Vec::new()
+    } else {
+        join_all(
+            mx.into_iter()
+                .map(|(preference, host)| check_mx_host(backend, host, preference, port)),
+        )
+        .await
+    };
+    if mx_hosts.is_empty() && !null_mx {
+        issues.push("未配置 MX 记录，发件方将回退投递到域名的 A/AAAA 地址".to_string());
+    }
+    for mx in &mx_hosts {
+        if let Some(error) = &mx.error {
+            issues.push(format!("MX 主机 {} 解析失败: {error}", mx.host));
+        } else if !mx.probes.iter().any(|probe| probe.connected) {
+            issues.push(format!("MX 主机 {} 的 {port} 端口均无法连接", mx.host));
+        } else if !mx.probes.iter().any(is_ready) {
+            issues.push(format!("MX 主机 {} 未返回 220 欢迎消息", mx.host));
+        }
+    }
+
+    let spf = check_spf(&root_txts?);
+    let dmarc = check_dmarc(&dmarc_txts?);
+    for (name, policy) in [("SPF", &spf), ("DMARC", &dmarc)] {
+        if !policy.present {
+            issues.push(format!("未配置 {name} 记录"));
+        } else if !policy.valid {
+            issues.push(format!("{name} 记录存在语法问题"));
+        }
+    }
+
+    Ok(MailCheckResult {
+        domain: domain.to_string(),
+        mx_hosts,
+        null_mx,
+        spf,
+        dmarc,
+        issues,
+    })
+}
+
+/// 查询 MX 记录，返回按优先级排序的 (优先级, 主机名)；主机名去掉末尾的点并转为小写
+async fn mx_records(
+    backend: &dyn DnsResolverBackend,
+    domain: &str,
+) -> CoreResult<Vec<(u16, String)>> {
+    let mut records: Vec<(u16, String)> = match backend
+        .query(&DnsQuery::new(domain, DnsQueryType::Mx, DnsServer::System))
+        .await
+    {
+        Ok(response) => response
+            .records
+            .into_iter()
+            .filter_map(|record| match record.data {
+                DnsRecordData::Mx {
+                    preference,
+                    exchange,
+                } => Some((preference, exchange.trim_end_matches('.').to_lowercase())),
+                _ => None,
+            })
+            .collect(),
+        Err(e) if e.is_answered() => Vec::new(),
+        Err(e) => {
+            return Err(CoreError::NetworkError(format!(
+                "查询 {domain} MX 记录失败: {e}"
+            )))
+        }
+    };
+    records.sort();
+    records.dedup();
+    Ok(records)
+}
+
+/// 解析 MX 主机的 A/AAAA 地址并并发探测 SMTP 端口
+async fn check_mx_host(
+    backend: &dyn DnsResolverBackend,
+    host: String,
+    preference: u16,
+    port: u16,
+) -> MxHostCheck {
+    let v4_query = DnsQuery::new(host.as_str(), DnsQueryType::A, DnsServer::System);
+    let v6_query = DnsQuery::new(host.as_str(), DnsQueryType::Aaaa, DnsServer::System);
+    let (v4, v6) = tokio::join!(backend.query(&v4_query), backend.query(&v6_query));
+
+    let mut addresses: Vec<IpAddr> = Vec::new();
+    let mut errors = Vec::new();
+    for response in [v4, v6] {
+        match response {
+            Ok(response) => {
+                addresses.extend(
+                    response
+                        .records
+                        .iter()
+                        .filter_map(|record| match record.data {
+                            DnsRecordData::A(ip) => Some(IpAddr::V4(ip)),
+                            DnsRecordData::Aaaa(ip) => Some(IpAddr::V6(ip)),
+                            _ => None,
+                        }),
+                );
+            }
+            Err(e) if e.is_answered() => {}
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    let error = if addresses.is_empty() {
+        Some(if errors.is_empty() {
+            "未解析到 A/AAAA 地址".to_string()
+        } else {
+            errors.join("; ")
+        })
+    } else {
+        None
+    };
+    let probes = join_all(addresses.iter().map(|ip| probe(SocketAddr::new(*ip, port)))).await;
+
+    MxHostCheck {
+        host,
+        preference,
+        addresses: addresses.iter().map(ToString::to_string).collect(),
+        probes,
+        error,
+    }
+}
+
+/// 连接 SMTP 端口并读取欢迎消息首行
+async fn probe(addr: SocketAddr) -> SmtpProbe {
+    let mut result = SmtpProbe {
+        address: addr.ip().to_string(),
+        connected: false,
+        banner: None,
+        connect_time_ms: None,
+        error: None,
+    };
+
+    let start = Instant::now();
+    let stream = match timeout(CONNECT_TIMEOUT, TcpStream::connect(addr)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => {
+            result.error = Some(format!("连接失败: {e}"));
+            return result;
+        }
+        Err(_) => {
+            result.error = Some(format!("连接超时（{}s）", CONNECT_TIMEOUT.as_secs()));
+            return result;
+        }
+    };
+    result.connected = true;
+    result.connect_time_ms = Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX));
+
+    let mut line = String::new();
+    let mut reader = BufReader::new(stream.take(MAX_BANNER_BYTES));
+    match timeout(BANNER_TIMEOUT, reader.read_line(&mut line)).await {
+        Ok(Ok(0)) => result.error = Some("服务器未发送欢迎消息即关闭连接".to_string()),
+        Ok(Ok(_)) => result.banner = Some(line.trim_end().to_string()),
+        Ok(Err(e)) => result.error = Some(format!("读取欢迎消息失败: {e}")),
+        Err(_) => {
+            result.error = Some(format!("等待欢迎消息超时（{}s）", BANNER_TIMEOUT.as_secs()));
+        }
+    }
+    result
+}
+
+/// 欢迎消息以 `220` 开头表示服务就绪
+fn is_ready(probe: &SmtpProbe) -> bool {
+    probe
+        .banner
+        .as_deref()
+        .is_some_and(|banner| banner.starts_with("220"))
+}
+
+fn check_spf(txts: &[String]) -> MailPolicyCheck {
+    let records = spf_records(txts);
+    let mut issues = Vec::new();
+    if records.len() > 1 {
+        issues.push(format!(
+            "存在 {} 条 SPF 记录，接收方会判定为 PermError",
+            records.len()
+        ));
+    }
+    if let Some(raw) = records.first() {
+        let parsed = parse_spf(raw, &mut issues);
+        if parsed.redirect.is_none() && all_qualifier(&parsed.mechanisms).is_none() {
+            issues.push("SPF 缺少 all 机制或 redirect 修饰符".to_string());
+        }
+    }
+    policy_check(records.first().map(|raw| raw.as_str()), issues)
+}
+
+fn check_dmarc(txts: &[String]) -> MailPolicyCheck {
+    let records: Vec<&String> = txts
+        .iter()
+        .filter(|txt| has_version_tag(txt, "v=DMARC1"))
+        .collect();
+    let mut issues = Vec::new();
+    if records.len() > 1 {
+        issues.push(format!(
+            "存在 {} 条 DMARC 记录，接收方会忽略 DMARC 策略",
+            records.len()
+        ));
+    }
+    if let Some(raw) = records.first() {
+        parse_dmarc(raw, &mut issues);
+    }
+    policy_check(records.first().map(|raw| raw.as_str()), issues)
+}
+
+fn policy_check(raw: Option<&str>, issues: Vec<String>) -> MailPolicyCheck {
+    MailPolicyCheck {
+        present: raw.is_some(),
+        valid: raw.is_some() && issues.is_empty(),
+        raw: raw.map(ToString::to_string),
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+
+    fn txt_data(text: &str) -> [DnsRecordData; 1] {
+        [DnsRecordData::Txt(vec![text.to_string()])]
+    }
+
+    /// 本地 SMTP 服务：接受一个连接，`banner` 非空时发送欢迎消息
+    async fn smtp_server(banner: Option<&'static str>) -> std::io::Result<u16> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let port = listener.local_addr()?.port();
+        tokio::spawn(async move {
+            if let Ok((mut stream, _)) = listener.accept().await {
+                match banner {
+                    Some(banner) => {
+                        let _ = stream.write_all(banner.as_bytes()).await;
+                    }
+                    None => tokio::time::sleep(Duration::from_mins(1)).await,
+                }
+            }
+        });
+        Ok(port)
+    }
+
+    fn mail_zone() -> FakeDnsResolver {
+        FakeDnsResolver::new()
+            .answer(
+                "example.com",
+                DnsQueryType::Mx,
+                [
+                    DnsRecordData::Mx {
+                        preference: 20,
+                        exchange: "backup.example.com.".to_string(),
+                    },
+                    DnsRecordData::Mx {
+                        preference: 10,
+                        exchange: "MX.example.com.".to_string(),
+                    },
+                ],
+            )
+            .answer(
+                "mx.example.com",
+                DnsQueryType::A,
+                [DnsRecordData::A(Ipv4Addr::LOCALHOST)],
+            )
+            .answer("example.com", DnsQueryType::Txt, txt_data("v=spf1 mx -all"))
+            .answer(
+                "_dmarc.example.com",
+                DnsQueryType::Txt,
+                txt_data("v=DMARC1; p=reject; rua=mailto:d@example.com"),
+            )
+    }
+
+    #[tokio::test]
+    async fn checks_mx_hosts_and_policies() -> CoreResult<()> {
+        let port = smtp_server(Some("220 mx.example.com ESMTP ready\r\n250 extra\r\n"))
+            .await
+            .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+
+        let result = check(&mail_zone(), "Example.com.", port, None).await?;
+
+        assert_eq!(result.domain, "example.com");
+        assert!(!result.null_mx);
+        let hosts: Vec<(&str, u16)> = result
+            .mx_hosts
+            .iter()
+            .map(|mx| (mx.host.as_str(), mx.preference))
+            .collect();
+        assert_eq!(hosts, [("mx.example.com", 10), ("backup.example.com", 20)]);
+
+        let primary = &result.mx_hosts[0];
+        assert_eq!(primary.addresses, ["127.0.0.1"]);
+        assert!(primary.probes[0].connected);
+        assert_eq!(
+            primary.probes[0].banner.as_deref(),
+            Some("220 mx.example.com ESMTP ready")
+        );
+        assert!(result.mx_hosts[1].error.is_some());
+
+        assert!(result.spf.present && result.spf.valid);
+        assert!(result.dmarc.present && result.dmarc.valid);
+        assert_eq!(
+            result.issues,
+            ["MX 主机 backup.example.com 解析失败: 未解析到 A/AAAA 地址"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_null_mx_and_invalid_policies() -> CoreResult<()> {
+        let resolver = FakeDnsResolver::new()
+            .answer(
+                "example.com",
+                DnsQueryType::Mx,
+                [DnsRecordData::Mx {
+                    preference: 0,
+                    exchange: ".".to_string(),
+                }],
+            )
+            .answer(
+                "example.com",
+                DnsQueryType::Txt,
+                [
+                    DnsRecordData::Txt(vec!["v=spf1 -all".to_string()]),
+                    DnsRecordData::Txt(vec!["v=spf1 bogus:x ~all".to_string()]),
+                ],
+            );
+
+        let result = check(&resolver, "example.com", SMTP_PORT, None).await?;
+
+        assert!(result.null_mx);
+        assert!(result.mx_hosts.is_empty());
+        assert!(result.spf.present);
+        assert!(!result.spf.valid);
+        assert!(!result.dmarc.present);
+        assert!(result
+            .issues
+            .iter()
+            .any(|issue| issue == "未配置 DMARC 记录"));
+        Ok(())
+    }
+
+    #[test]
+    fn flags_spf_without_all() {
+        let spf = check_spf(&["v=spf1 include:_spf.example.net".to_string()]);
+        assert!(spf.present);
+        assert!(!spf.valid);
+
+        let redirect = check_spf(&["v=spf1 redirect=_spf.example.net".to_string()]);
+        assert!(redirect.valid);
+    }
+
+    #[tokio::test]
+    async fn times_out_when_banner_never_arrives() -> CoreResult<()> {
+        let port = smtp_server(None)
+            .await
+            .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+
+        let result = check(
+            &mail_zone(),
+            "example.com",
+            port,
+            Some(Duration::from_millis(200)),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CoreError::Timeout { .. })));
+        Ok(())
+    }
+}
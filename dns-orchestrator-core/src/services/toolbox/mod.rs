@@ -1,54 +1,337 @@
 //! 工具箱服务模块
 //!
-//! 提供各种 DNS 相关的工具函数，所有方法都是无状态的关联函数。
+//! 提供各种 DNS 相关的工具函数。除 WHOIS / RDAP 查询（结果缓存在服务实例中）、
+//! IP 查询（使用实例上配置的威胁情报源）与受隐私模式约束的第三方外发外，
+//! 其余方法都是无状态的关联函数。
 
+mod caa;
 mod dns;
+mod dns_benchmark;
 mod dns_propagation;
 mod dnssec;
+#[cfg(feature = "doh")]
+mod doh;
+mod email_security;
+mod external_endpoints;
+mod hickory_backend;
 mod http_headers;
+mod http_trace;
 mod ip;
+mod mail_check;
+mod network_env;
+#[cfg(feature = "ocsp")]
+mod ocsp;
+mod port;
+mod rdap;
+mod security_headers;
 mod ssl;
+mod threat_intel;
+#[cfg(feature = "rustls")]
+mod tls_probe;
+mod traceroute;
 mod whois;
+mod whois_cache;
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::RwLock;
+
+pub use hickory_backend::HickoryResolverBackend;
+pub use whois_cache::WhoisCacheConfig;
+
+use threat_intel::ThreatIntel;
+use whois_cache::{WhoisCache, WhoisSource};
 
 use crate::error::CoreResult;
+use crate::traits::DnsResolverBackend;
 use crate::types::{
-    DnsLookupResult, DnsPropagationResult, DnssecResult, HttpHeaderCheckResult, IpLookupResult,
-    WhoisResult,
+    CaaCheckResult, DnsBenchmarkProgress, DnsBenchmarkResult, DnsLookupResult, DnsLookupTransport,
+    DnsPropagationResult, DnssecResult, EmailSecurityResult, ExternalEndpoint,
+    HttpHeaderCheckResult, HttpTraceResult, IpLookupBatchEntry, IpLookupResult, IpThreatInfo,
+    MailCheckResult, NetworkEnvInfo, PortCheckResult, PortProtocol, PrivacyConfig,
+    PropagationCheckResult, ReverseLookupResult, SslCheckResult, SslCheckTarget, ThreatIntelConfig,
+    TraceRouteResult, WhoisResult,
 };
+use crate::utils::domain_name;
 
 /// 嵌入 WHOIS 服务器配置
 const WHOIS_SERVERS: &str = include_str!("whois_servers.json");
 
-/// 工具箱服务（无状态，所有方法为关联函数）
-pub struct ToolboxService;
+/// 嵌入 IANA RDAP bootstrap 配置（<https://data.iana.org/rdap/dns.json>）
+const RDAP_BOOTSTRAP: &str = include_str!("rdap_dns.json");
+
+/// 工具箱服务
+///
+/// 实例持有 WHOIS / RDAP 查询缓存、威胁情报源与隐私模式开关，平台层应在应用状态中共享同一个实例。
+pub struct ToolboxService {
+    whois_cache: WhoisCache,
+    threat_intel: RwLock<Option<Arc<ThreatIntel>>>,
+    privacy_mode: AtomicBool,
+}
+
+impl Default for ToolboxService {
+    fn default() -> Self {
+        Self::new(WhoisCacheConfig::default())
+    }
+}
 
 impl ToolboxService {
-    /// WHOIS 查询
-    pub async fn whois_lookup(domain: &str) -> CoreResult<WhoisResult> {
-        whois::whois_lookup(domain, WHOIS_SERVERS).await
+    /// 创建工具箱服务
+    #[must_use]
+    pub fn new(whois_cache: WhoisCacheConfig) -> Self {
+        Self {
+            whois_cache: WhoisCache::new(whois_cache),
+            threat_intel: RwLock::new(None),
+            privacy_mode: AtomicBool::new(false),
+        }
+    }
+
+    /// 应用隐私模式配置
+    pub fn set_privacy_config(&self, config: &PrivacyConfig) {
+        self.privacy_mode
+            .store(config.privacy_mode, Ordering::Relaxed);
+    }
+
+    /// 当前的隐私模式配置
+    pub fn privacy_config(&self) -> PrivacyConfig {
+        PrivacyConfig {
+            privacy_mode: self.privacy_mode(),
+        }
+    }
+
+    fn privacy_mode(&self) -> bool {
+        self.privacy_mode.load(Ordering::Relaxed)
+    }
+
+    /// 列出当前配置下工具可能访问的第三方端点及用途，供用户审查
+    ///
+    /// 服务商 API 与用户指定的服务器不在此列。
+    pub async fn list_external_endpoints(&self) -> Vec<ExternalEndpoint> {
+        external_endpoints::list(&self.threat_intel_config().await, self.privacy_mode())
+    }
+
+    /// 隐私模式下拦截未经确认的非必需外发
+    async fn ensure_external_allowed(&self, endpoint: &str, confirmed: bool) -> CoreResult<()> {
+        if confirmed || !self.privacy_mode() {
+            return Ok(());
+        }
+        external_endpoints::ensure_allowed(endpoint, &self.threat_intel_config().await)
+    }
+
+    /// 配置威胁情报源
+    ///
+    /// `drop_snapshot` 为 Spamhaus DROP 列表的本地快照路径：启用 DROP 时先从快照加载，
+    /// [`Self::refresh_threat_intel`] 下载的新列表也会写回该路径。
+    /// 替换配置会清空 `AbuseIPDB` 结果缓存与配额计数。
+    pub async fn set_threat_intel(
+        &self,
+        config: ThreatIntelConfig,
+        drop_snapshot: Option<PathBuf>,
+    ) {
+        let threat_intel = ThreatIntel::new(config, drop_snapshot);
+        threat_intel.load_drop_snapshot().await;
+        *self.threat_intel.write().await = Some(Arc::new(threat_intel));
+    }
+
+    /// 当前的威胁情报源配置
+    pub async fn threat_intel_config(&self) -> ThreatIntelConfig {
+        self.threat_intel
+            .read()
+            .await
+            .as_ref()
+            .map(|t| t.config().clone())
+            .unwrap_or_default()
+    }
+
+    /// 下载最新的 Spamhaus DROP 列表，返回网段数；未启用 DROP 或处于隐私模式时返回 `None`
+    pub async fn refresh_threat_intel(&self) -> CoreResult<Option<usize>> {
+        if self.privacy_mode() {
+            log::debug!("[ThreatIntel] Privacy mode on, skipping DROP list refresh");
+            return Ok(None);
+        }
+        let threat_intel = self.threat_intel.read().await.clone();
+        match threat_intel {
+            Some(threat_intel) if threat_intel.config().spamhaus_drop => {
+                threat_intel.refresh_drop_list().await.map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// 查询单个 IP 的威胁情报，未配置任何情报源时返回 `None`
+    ///
+    /// 供记录检查等场景标注指向恶意地址的记录。隐私模式下只匹配本地 DROP 列表。
+    pub async fn ip_threat(&self, ip: IpAddr) -> Option<IpThreatInfo> {
+        let threat_intel = self.threat_intel.read().await.clone()?;
+        if !threat_intel.config().has_sources() {
+            return None;
+        }
+        Some(threat_intel.annotate(ip, !self.privacy_mode()).await)
+    }
+
+    /// WHOIS 查询（无结果时回退到 RDAP）
+    ///
+    /// 国际化域名按 punycode 查询与缓存。结果按域名缓存，`force_refresh` 为 true 时跳过缓存重新查询。
+    pub async fn whois_lookup(&self, domain: &str, force_refresh: bool) -> CoreResult<WhoisResult> {
+        let name = domain_name::normalize(domain)?;
+        let mut result = self
+            .whois_cache
+            .get_or_fetch(WhoisSource::Whois, &name.ascii, force_refresh, || {
+                whois::whois_lookup(&name.ascii, WHOIS_SERVERS, RDAP_BOOTSTRAP)
+            })
+            .await?;
+        result.unicode_domain = name.unicode_if_idn();
+        Ok(result)
+    }
+
+    /// RDAP 查询
+    ///
+    /// 国际化域名与缓存的处理同 [`Self::whois_lookup`]。
+    pub async fn rdap_lookup(&self, domain: &str, force_refresh: bool) -> CoreResult<WhoisResult> {
+        let name = domain_name::normalize(domain)?;
+        let mut result = self
+            .whois_cache
+            .get_or_fetch(WhoisSource::Rdap, &name.ascii, force_refresh, || {
+                rdap::rdap_lookup(&name.ascii, RDAP_BOOTSTRAP)
+            })
+            .await?;
+        result.unicode_domain = name.unicode_if_idn();
+        Ok(result)
     }
 
     /// DNS 查询
+    ///
+    /// `transport` 选择传输协议（默认 UDP）：
+    /// - `doh`：`nameserver` 为 HTTPS URL（需启用 `doh` feature）
+    /// - `dot`：`nameserver` 为 `host[:port][#TLS 服务器名称]`（需启用 `dot` feature）
+    ///
+    /// 兼容旧写法：未指定 `transport` 且 `nameserver` 为 `doh:<https URL>` 时走 DNS over HTTPS。
+    /// 国际化域名转为 punycode 后查询。
     pub async fn dns_lookup(
         domain: &str,
         record_type: &str,
         nameserver: Option<&str>,
+        transport: Option<DnsLookupTransport>,
+    ) -> CoreResult<DnsLookupResult> {
+        let name = domain_name::normalize(domain)?;
+        let mut result = dns::dns_lookup(&name.ascii, record_type, nameserver, transport).await?;
+        result.unicode_domain = name.unicode_if_idn();
+        Ok(result)
+    }
+
+    /// 使用指定解析后端的 DNS 查询（UDP，不处理 `DoH`）
+    ///
+    /// 供平台层注入自定义解析后端，或在测试中注入 `testing::FakeDnsResolver`：
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use dns_orchestrator_core::services::ToolboxService;
+    /// use dns_orchestrator_core::testing::FakeDnsResolver;
+    /// use dns_orchestrator_core::types::{DnsQueryType, DnsRecordData};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> dns_orchestrator_core::CoreResult<()> {
+    /// let resolver = FakeDnsResolver::new().answer(
+    ///     "example.com",
+    ///     DnsQueryType::A,
+    ///     [DnsRecordData::A("192.0.2.1".parse().unwrap())],
+    /// );
+    ///
+    /// let result = ToolboxService::dns_lookup_with(&resolver, "example.com", "A", None).await?;
+    /// assert_eq!(result.records.len(), 1);
+    /// assert_eq!(result.records[0].value, "192.0.2.1");
+    ///
+    /// // 不支持的记录类型返回校验错误
+    /// assert!(ToolboxService::dns_lookup_with(&resolver, "example.com", "BOGUS", None)
+    ///     .await
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dns_lookup_with(
+        backend: &dyn DnsResolverBackend,
+        domain: &str,
+        record_type: &str,
+        nameserver: Option<&str>,
     ) -> CoreResult<DnsLookupResult> {
-        dns::dns_lookup(domain, record_type, nameserver).await
+        let name = domain_name::normalize(domain)?;
+        let mut result = dns::lookup_with(
+            backend,
+            &name.ascii,
+            record_type,
+            nameserver,
+            DnsLookupTransport::Udp,
+        )
+        .await?;
+        result.unicode_domain = name.unicode_if_idn();
+        Ok(result)
+    }
+
+    /// 反向 DNS（PTR）查询
+    ///
+    /// `nameserver` 语义同 [`Self::dns_lookup`]。
+    pub async fn reverse_lookup(
+        ip: &str,
+        nameserver: Option<&str>,
+    ) -> CoreResult<ReverseLookupResult> {
+        dns::reverse_lookup(ip, nameserver).await
     }
 
     /// IP/域名 地理位置查询
-    pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
-        ip::ip_lookup(query).await
+    ///
+    /// 配置了威胁情报源时，每个结果附带 `threat` 标注。
+    /// 隐私模式下需要 `confirmed` 为 true，确认后本次查询的地理位置与威胁情报外发均放行，
+    /// 否则返回 [`CoreError::ExternalAccessBlocked`](crate::error::CoreError::ExternalAccessBlocked)。
+    pub async fn ip_lookup(&self, query: &str, confirmed: bool) -> CoreResult<IpLookupResult> {
+        self.ensure_external_allowed(ip::GEO_ENDPOINT, confirmed)
+            .await?;
+        let threat_intel = self.threat_intel.read().await.clone();
+        ip::ip_lookup(query, threat_intel.as_deref()).await
+    }
+
+    /// 批量 IP/域名/CIDR 地理位置查询
+    ///
+    /// 去重后最多 100 条；CIDR 限 /29（IPv6 /125）以内，展开为成员地址逐一查询。
+    /// 结果以原始查询为键，单个条目失败记录在对应结果中。隐私模式语义同 [`Self::ip_lookup`]。
+    pub async fn ip_lookup_batch(
+        &self,
+        queries: Vec<String>,
+        confirmed: bool,
+    ) -> CoreResult<BTreeMap<String, IpLookupBatchEntry>> {
+        self.ensure_external_allowed(ip::GEO_ENDPOINT, confirmed)
+            .await?;
+        let threat_intel = self.threat_intel.read().await.clone();
+        ip::ip_lookup_batch(queries, threat_intel.as_deref()).await
     }
 
     /// SSL 证书检查
+    ///
+    /// 始终探测服务器是否仍接受 TLS 1.0 / 1.1；`probe_versions` 为 true 时额外对
+    /// TLS 1.0 ~ 1.3 逐一单独握手，结果写入 `protocol_probe`。
+    ///
+    /// `connect_addr`（IP 或主机名）指定时连接该地址，SNI 与证书域名匹配仍使用 `domain`；
+    /// 地址无效或无法解析时返回校验错误。
     #[cfg(feature = "rustls")]
     pub async fn ssl_check(
         domain: &str,
         port: Option<u16>,
-    ) -> CoreResult<crate::types::SslCheckResult> {
-        ssl::ssl_check(domain, port).await
+        connect_addr: Option<&str>,
+        probe_versions: bool,
+    ) -> CoreResult<SslCheckResult> {
+        ssl::ssl_check(domain, port, connect_addr, probe_versions).await
+    }
+
+    /// 批量 SSL 证书检查
+    ///
+    /// 结果与 `targets` 顺序一致；单个目标连接失败或超时记录在对应结果中，不影响整批。
+    #[cfg(feature = "rustls")]
+    pub async fn ssl_check_batch(targets: Vec<SslCheckTarget>) -> Vec<SslCheckResult> {
+        ssl::ssl_check_batch(targets).await
     }
 
     /// HTTP 头检查
@@ -58,16 +341,150 @@ impl ToolboxService {
         http_headers::http_header_check(request).await
     }
 
+    /// HTTP 重定向链跟踪
+    ///
+    /// 逐跳记录状态码、Location 头与响应时间，最多跟随 `max_redirects`（不超过 20）次跳转。
+    pub async fn http_trace(url: &str, max_redirects: u8) -> CoreResult<HttpTraceResult> {
+        http_trace::http_trace(url, max_redirects).await
+    }
+
+    /// 端口连通性检查
+    ///
+    /// 解析 `host` 后并发探测各个端口（最多 100 个，超时 `timeout_ms` 不超过 30 秒），
+    /// 报告开放、关闭或过滤；TCP 连接成功时读取服务器主动发送的 banner。
+    pub async fn port_check(
+        host: &str,
+        ports: Vec<u16>,
+        protocol: PortProtocol,
+        timeout_ms: u64,
+    ) -> CoreResult<PortCheckResult> {
+        port::port_check(host, ports, protocol, timeout_ms).await
+    }
+
+    /// 路径追踪（traceroute）
+    ///
+    /// 逐跳统计响应地址、反向解析主机名与最小 / 平均 / 最大往返时延（`max_hops` 不超过 64，
+    /// `probes_per_hop` 不超过 10）。优先使用原始 ICMP 套接字，无权限时回退为 TCP 443 连接探测；
+    /// 两者都不可用时返回 [`CoreError::CapabilityUnavailable`](crate::error::CoreError::CapabilityUnavailable)。
+    pub async fn trace_route(
+        host: &str,
+        max_hops: u8,
+        probes_per_hop: u8,
+    ) -> CoreResult<TraceRouteResult> {
+        traceroute::trace_route(host, max_hops, probes_per_hop).await
+    }
+
     /// DNS 传播检查
+    ///
+    /// 查询公共解析器，隐私模式下需要 `confirmed` 为 true。
     pub async fn dns_propagation_check(
+        &self,
         domain: &str,
         record_type: &str,
+        confirmed: bool,
     ) -> CoreResult<DnsPropagationResult> {
+        self.ensure_external_allowed(dns_propagation::PUBLIC_RESOLVERS_ENDPOINT, confirmed)
+            .await?;
         dns_propagation::dns_propagation_check(domain, record_type).await
     }
 
+    /// 期望值传播检查（并发查询公共解析器，逐个判断是否已解析到 `expected_value`）
+    ///
+    /// 隐私模式下需要 `confirmed` 为 true。
+    pub async fn propagation_check(
+        &self,
+        domain: &str,
+        record_type: &str,
+        expected_value: &str,
+        confirmed: bool,
+    ) -> CoreResult<PropagationCheckResult> {
+        self.ensure_external_allowed(dns_propagation::PUBLIC_RESOLVERS_ENDPOINT, confirmed)
+            .await?;
+        dns_propagation::propagation_check(domain, record_type, expected_value).await
+    }
+
+    /// DNS 解析性能基准测试（对比多个 DNS 服务器的解析延迟）
+    pub async fn dns_benchmark(
+        domain: &str,
+        nameservers: &[String],
+        iterations: u32,
+        concurrency: u32,
+        on_progress: &(dyn Fn(DnsBenchmarkProgress) + Send + Sync),
+        cancelled: &AtomicBool,
+    ) -> CoreResult<DnsBenchmarkResult> {
+        dns_benchmark::dns_benchmark(
+            domain,
+            nameservers,
+            iterations,
+            concurrency,
+            on_progress,
+            cancelled,
+        )
+        .await
+    }
+
     /// DNSSEC 验证
     pub async fn dnssec_check(domain: &str, nameserver: Option<&str>) -> CoreResult<DnssecResult> {
         dnssec::dnssec_check(domain, nameserver).await
     }
+
+    /// 邮件安全检查（SPF / DMARC，指定 selector 时同时检查 DKIM）
+    pub async fn email_security_check(
+        domain: &str,
+        dkim_selector: Option<&str>,
+    ) -> CoreResult<EmailSecurityResult> {
+        email_security::email_security_check(domain, dkim_selector).await
+    }
+
+    /// 邮件服务健康检查
+    ///
+    /// 查询 MX 并并发探测各主机 25 端口的 SMTP 欢迎消息，同时检查 SPF / DMARC 记录是否存在、语法是否正确。
+    /// `timeout` 为整体超时，为空时使用默认值（30 秒）。
+    pub async fn mail_check(
+        domain: &str,
+        timeout: Option<Duration>,
+    ) -> CoreResult<MailCheckResult> {
+        mail_check::mail_check(domain, timeout).await
+    }
+
+    /// CAA 记录检查
+    ///
+    /// 从域名开始逐级向上查找生效的 CAA 记录集，列出授权的 CA 并提示潜在问题（如缺少 `issuewild`）。
+    pub async fn caa_check(domain: &str) -> CoreResult<CaaCheckResult> {
+        caa::caa_check(domain).await
+    }
+
+    /// 使用指定解析后端的 CAA 检查
+    ///
+    #[cfg_attr(feature = "testing", doc = "```")]
+    #[cfg_attr(not(feature = "testing"), doc = "```ignore")]
+    /// use dns_orchestrator_core::services::ToolboxService;
+    /// use dns_orchestrator_core::testing::FakeDnsResolver;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> dns_orchestrator_core::CoreResult<()> {
+    /// // 没有任何 CAA 记录：所有 CA 都可以签发
+    /// let result = ToolboxService::caa_check_with(&FakeDnsResolver::new(), "www.example.com").await?;
+    /// assert!(!result.is_protected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn caa_check_with(
+        backend: &dyn DnsResolverBackend,
+        domain: &str,
+    ) -> CoreResult<CaaCheckResult> {
+        caa::check(backend, domain).await
+    }
+
+    /// 获取系统配置的 DNS 服务器列表
+    pub fn get_system_dns() -> Vec<String> {
+        network_env::get_system_dns()
+    }
+
+    /// 本机网络环境诊断（系统 DNS、DNS 劫持、出口 IP、代理设置）
+    ///
+    /// 隐私模式下不探测出口 IP。
+    pub async fn network_env_info(&self) -> NetworkEnvInfo {
+        network_env::network_env_info(!self.privacy_mode()).await
+    }
 }
@@ -0,0 +1,221 @@
+//! 本机网络环境诊断模块
+//!
+//! 用于排查"为什么我的解析结果和别人不一样"：系统 DNS、DNS 劫持迹象、出口 IP、代理设置。
+//! 各项探测相互独立，单项失败不影响其他项，整体在 `TOTAL_TIMEOUT` 内完成。
+
+use std::time::{Duration, Instant};
+
+use tokio::time::timeout;
+use url::Url;
+
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsHijackCheck, DnsQuery, DnsQueryOptions, DnsQueryType, DnsRecordData, DnsServer,
+    NetworkEnvInfo, ProxySettings,
+};
+
+use super::external_endpoints::EndpointDeclaration;
+use super::HickoryResolverBackend;
+
+/// 整体探测时限
+const TOTAL_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 出口 IP 探测源（仅返回纯文本 IP）
+const PUBLIC_IPV4_ENDPOINT: &str = "https://api4.ipify.org";
+const PUBLIC_IPV6_ENDPOINT: &str = "https://api6.ipify.org";
+
+/// 出口 IP 探测的外发端点标识
+pub(super) const PUBLIC_IP_ENDPOINT: &str = "network_env.public_ip";
+
+/// 本模块访问的第三方端点
+pub(super) fn external_endpoints() -> Vec<EndpointDeclaration> {
+    vec![EndpointDeclaration {
+        id: PUBLIC_IP_ENDPOINT,
+        module: "network_env",
+        targets: vec![
+            PUBLIC_IPV4_ENDPOINT.to_string(),
+            PUBLIC_IPV6_ENDPOINT.to_string(),
+        ],
+        purpose: "探测本机出口 IP",
+        sends_query_target: false,
+        essential: false,
+        // 网络环境诊断的附带项，隐私模式下直接跳过
+        confirmable: false,
+    }]
+}
+
+/// 获取系统配置的 DNS 服务器列表
+pub fn get_system_dns() -> Vec<String> {
+    HickoryResolverBackend.system_nameservers()
+}
+
+/// 本机网络环境诊断
+///
+/// `public_ip_allowed` 为 false 时（隐私模式）跳过出口 IP 探测。
+pub async fn network_env_info(public_ip_allowed: bool) -> NetworkEnvInfo {
+    let start = Instant::now();
+
+    let client = reqwest::Client::builder()
+        .timeout(TOTAL_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+
+    let public_ip = |endpoint| {
+        let client = &client;
+        async move {
+            if public_ip_allowed {
+                probe_public_ip(client, endpoint).await
+            } else {
+                None
+            }
+        }
+    };
+
+    let (dns_hijack, public_ipv4, public_ipv6) = tokio::join!(
+        check_dns_hijack(&HickoryResolverBackend),
+        public_ip(PUBLIC_IPV4_ENDPOINT),
+        public_ip(PUBLIC_IPV6_ENDPOINT),
+    );
+
+    NetworkEnvInfo {
+        system_dns_servers: HickoryResolverBackend.system_nameservers(),
+        // 系统解析配置无法区分 DHCP / 手动 / VPN 推送
+        dns_source: "system".to_string(),
+        dns_hijack,
+        public_ipv4,
+        public_ipv6,
+        proxy: read_proxy_settings(),
+        elapsed_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+    }
+}
+
+/// DNS 劫持检测：查询一个随机生成的不存在域名，正常应返回 NXDOMAIN
+async fn check_dns_hijack(backend: &dyn DnsResolverBackend) -> DnsHijackCheck {
+    let probe_domain = format!("dnso-probe-{}.com", uuid::Uuid::new_v4().simple());
+
+    let query = DnsQuery::new(probe_domain.as_str(), DnsQueryType::A, DnsServer::System)
+        .with_options(DnsQueryOptions {
+            timeout: Some(TOTAL_TIMEOUT),
+            single_attempt: true,
+            ..DnsQueryOptions::default()
+        });
+
+    let (hijacked, answers, error) = match timeout(TOTAL_TIMEOUT, backend.query(&query)).await {
+        Ok(Ok(response)) => {
+            let answers: Vec<String> = response
+                .records
+                .iter()
+                .filter_map(|record| match record.data {
+                    DnsRecordData::A(ip) => Some(ip.to_string()),
+                    _ => None,
+                })
+                .collect();
+            (Some(!answers.is_empty()), answers, None)
+        }
+        Ok(Err(e)) if e.is_answered() => (Some(false), vec![], None),
+        Ok(Err(e)) => (None, vec![], Some(e.to_string())),
+        Err(_) => (None, vec![], Some("查询超时".to_string())),
+    };
+
+    if hijacked == Some(true) {
+        log::warn!("[NetworkEnv] {probe_domain} unexpectedly resolved to {answers:?}");
+    }
+
+    DnsHijackCheck {
+        probe_domain,
+        hijacked,
+        answers,
+        error,
+    }
+}
+
+/// 探测默认出口 IP（失败返回 None）
+async fn probe_public_ip(client: &reqwest::Client, endpoint: &str) -> Option<String> {
+    let result = timeout(TOTAL_TIMEOUT, async {
+        client.get(endpoint).send().await?.text().await
+    })
+    .await;
+
+    match result {
+        Ok(Ok(text)) => {
+            let ip = text.trim();
+            ip.parse::<std::net::IpAddr>().ok().map(|_| ip.to_string())
+        }
+        Ok(Err(e)) => {
+            log::debug!("[NetworkEnv] Public IP probe {endpoint} failed: {e}");
+            None
+        }
+        Err(_) => {
+            log::debug!("[NetworkEnv] Public IP probe {endpoint} timed out");
+            None
+        }
+    }
+}
+
+/// 读取系统代理环境变量（同时兼容大小写）
+fn read_proxy_settings() -> ProxySettings {
+    fn read_env(name: &str) -> Option<String> {
+        [name.to_uppercase(), name.to_lowercase()]
+            .iter()
+            .find_map(|key| std::env::var(key).ok())
+            .filter(|v| !v.trim().is_empty())
+    }
+
+    ProxySettings {
+        http_proxy: read_env("HTTP_PROXY").map(|v| mask_proxy_credentials(&v)),
+        https_proxy: read_env("HTTPS_PROXY").map(|v| mask_proxy_credentials(&v)),
+        all_proxy: read_env("ALL_PROXY").map(|v| mask_proxy_credentials(&v)),
+        no_proxy: read_env("NO_PROXY"),
+    }
+}
+
+/// 隐藏代理 URL 中的用户名和密码
+fn mask_proxy_credentials(value: &str) -> String {
+    match Url::parse(value) {
+        Ok(mut url) if !url.username().is_empty() || url.password().is_some() => {
+            let _ = url.set_username("***");
+            let _ = url.set_password(None);
+            url.to_string()
+        }
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::testing::FakeDnsResolver;
+    use crate::types::DnsQueryErrorKind;
+
+    #[tokio::test]
+    async fn nxdomain_probe_is_not_hijacked() {
+        let resolver = FakeDnsResolver::new().otherwise(Err(DnsQueryErrorKind::NxDomain));
+        let check = check_dns_hijack(&resolver).await;
+
+        assert_eq!(check.hijacked, Some(false));
+        assert!(check.answers.is_empty());
+        assert!(check.error.is_none());
+        assert_eq!(resolver.queries()[0].name, check.probe_domain);
+    }
+
+    #[tokio::test]
+    async fn answered_probe_is_hijacked() {
+        let resolver = FakeDnsResolver::new()
+            .otherwise(Ok(vec![DnsRecordData::A(Ipv4Addr::new(198, 51, 100, 7))]));
+        let check = check_dns_hijack(&resolver).await;
+
+        assert_eq!(check.hijacked, Some(true));
+        assert_eq!(check.answers, ["198.51.100.7"]);
+    }
+
+    #[tokio::test]
+    async fn failed_probe_is_inconclusive() {
+        let resolver = FakeDnsResolver::new().otherwise(Err(DnsQueryErrorKind::Timeout));
+        let check = check_dns_hijack(&resolver).await;
+
+        assert_eq!(check.hijacked, None);
+        assert_eq!(check.error.as_deref(), Some("request timed out"));
+    }
+}
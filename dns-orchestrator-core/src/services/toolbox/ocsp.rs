@@ -0,0 +1,542 @@
+//! OCSP 吊销状态检查（RFC 6960）
+//!
+//! 只实现检查所需的最小 DER 编解码：构造单证书的 `OCSPRequest`，
+//! 校验 `BasicOCSPResponse` 的签名（颁发者或其委托的响应器）后，
+//! 取出与序列号匹配的 `SingleResponse` 的证书状态。不校验 thisUpdate / nextUpdate。
+//!
+//! 服务器在握手中装订（stapling）的 OCSP 响应通过 [`StapleRecorder`] 记录，
+//! 该验证器把证书校验全部委托给 webpki，只额外保存装订的响应。
+
+use std::sync::{Arc, Mutex};
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{VerifierBuilderError, WebPkiServerVerifier};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
+use sha1::{Digest, Sha1};
+use x509_parser::asn1_rs::BitString;
+use x509_parser::prelude::*;
+
+use crate::types::OcspStatus;
+
+/// id-pkix-ocsp（AIA 中的 OCSP 响应器访问方式）
+const OID_AD_OCSP: &str = "1.3.6.1.5.5.7.48.1";
+/// id-pkix-ocsp-basic 的 DER 编码内容
+const OID_OCSP_BASIC: [u8; 9] = [0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x30, 0x01, 0x01];
+/// id-sha1 的 DER 编码内容
+const OID_SHA1: [u8; 5] = [0x2b, 0x0e, 0x03, 0x02, 0x1a];
+
+// DER 标签
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_NULL: u8 = 0x05;
+const TAG_OID: u8 = 0x06;
+const TAG_ENUMERATED: u8 = 0x0a;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_SEQUENCE: u8 = 0x30;
+/// 上下文标签 [0] 构造类型（显式标签）
+const TAG_CONTEXT_0: u8 = 0xa0;
+/// certStatus: good [0] IMPLICIT NULL
+const TAG_STATUS_GOOD: u8 = 0x80;
+/// certStatus: revoked [1] IMPLICIT `RevokedInfo`
+const TAG_STATUS_REVOKED: u8 = 0xa1;
+/// certStatus: unknown [2] IMPLICIT NULL
+const TAG_STATUS_UNKNOWN: u8 = 0x82;
+
+/// 记录服务器装订的 OCSP 响应的证书验证器
+#[derive(Debug)]
+pub(super) struct StapleRecorder {
+    inner: Arc<WebPkiServerVerifier>,
+    stapled: Mutex<Option<Vec<u8>>>,
+}
+
+impl StapleRecorder {
+    pub(super) fn new(roots: RootCertStore) -> Result<Self, VerifierBuilderError> {
+        Ok(Self {
+            inner: WebPkiServerVerifier::builder(Arc::new(roots)).build()?,
+            stapled: Mutex::new(None),
+        })
+    }
+
+    /// 取出握手中记录的装订响应（服务器未装订时为 `None`）
+    pub(super) fn take_stapled(&self) -> Option<Vec<u8>> {
+        self.stapled
+            .lock()
+            .ok()
+            .and_then(|mut stapled| stapled.take())
+    }
+}
+
+impl ServerCertVerifier for StapleRecorder {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if !ocsp_response.is_empty() {
+            if let Ok(mut stapled) = self.stapled.lock() {
+                *stapled = Some(ocsp_response.to_vec());
+            }
+        }
+        self.inner
+            .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// 证书 AIA 扩展中的 OCSP 响应器地址
+pub(super) fn responder_url(cert: &X509Certificate) -> Option<String> {
+    cert.extensions().iter().find_map(|ext| {
+        let ParsedExtension::AuthorityInfoAccess(aia) = ext.parsed_extension() else {
+            return None;
+        };
+        aia.accessdescs.iter().find_map(|desc| {
+            if desc.access_method.to_id_string() != OID_AD_OCSP {
+                return None;
+            }
+            match &desc.access_location {
+                GeneralName::URI(uri) => Some(uri.to_string()),
+                _ => None,
+            }
+        })
+    })
+}
+
+/// 构造查询 `cert` 状态的 OCSPRequest（CertID 使用 SHA-1，兼容性最好）
+pub(super) fn build_request(cert: &X509Certificate, issuer: &X509Certificate) -> Vec<u8> {
+    let name_hash = Sha1::digest(issuer.subject().as_raw());
+    let key_hash = Sha1::digest(&*issuer.public_key().subject_public_key.data);
+
+    let hash_algorithm = tlv(
+        TAG_SEQUENCE,
+        &[tlv(TAG_OID, &OID_SHA1), tlv(TAG_NULL, &[])].concat(),
+    );
+    let cert_id = tlv(
+        TAG_SEQUENCE,
+        &[
+            hash_algorithm,
+            tlv(TAG_OCTET_STRING, &name_hash),
+            tlv(TAG_OCTET_STRING, &key_hash),
+            tlv(TAG_INTEGER, cert.raw_serial()),
+        ]
+        .concat(),
+    );
+    let request = tlv(TAG_SEQUENCE, &cert_id);
+    let request_list = tlv(TAG_SEQUENCE, &request);
+    let tbs_request = tlv(TAG_SEQUENCE, &request_list);
+    tlv(TAG_SEQUENCE, &tbs_request)
+}
+
+/// 解析 OCSPResponse，校验签名后返回序列号为 `serial`（INTEGER 内容字节）的证书状态
+///
+/// 响应须由 `issuer` 直接签名，或由 `issuer` 签发、带 `OCSPSigning` 扩展用途的委托响应器证书签名。
+pub(super) fn parse_response(
+    der: &[u8],
+    serial: &[u8],
+    issuer: &X509Certificate,
+) -> Result<OcspStatus, String> {
+    let basic = basic_response(der)?;
+    verify_signature(basic, issuer)?;
+    cert_status(basic, serial)
+}
+
+/// 取出 `OCSPResponse` 中的 `BasicOCSPResponse`（SEQUENCE 内容）
+fn basic_response(der: &[u8]) -> Result<&[u8], String> {
+    let malformed = || "OCSP 响应格式错误".to_string();
+
+    let (response, _) = expect_tlv(der, TAG_SEQUENCE).ok_or_else(malformed)?;
+    let (status, rest) = expect_tlv(response, TAG_ENUMERATED).ok_or_else(malformed)?;
+    match status {
+        [0] => {}
+        [code] => {
+            return Err(format!(
+                "OCSP 响应器返回错误: {}",
+                response_status_name(*code)
+            ))
+        }
+        _ => return Err(malformed()),
+    }
+
+    // responseBytes [0] EXPLICIT SEQUENCE { responseType, response OCTET STRING }
+    let (response_bytes, _) = expect_tlv(rest, TAG_CONTEXT_0).ok_or_else(malformed)?;
+    let (response_bytes, _) = expect_tlv(response_bytes, TAG_SEQUENCE).ok_or_else(malformed)?;
+    let (response_type, rest) = expect_tlv(response_bytes, TAG_OID).ok_or_else(malformed)?;
+    if response_type != OID_OCSP_BASIC {
+        return Err("不支持的 OCSP 响应类型".to_string());
+    }
+    let (basic, _) = expect_tlv(rest, TAG_OCTET_STRING).ok_or_else(malformed)?;
+    let (basic, _) = expect_tlv(basic, TAG_SEQUENCE).ok_or_else(malformed)?;
+    Ok(basic)
+}
+
+/// 校验 `BasicOCSPResponse` 的签名
+///
+/// ```text
+/// BasicOCSPResponse ::= SEQUENCE { tbsResponseData, signatureAlgorithm, signature BIT STRING,
+///     certs [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL }
+/// ```
+fn verify_signature(basic: &[u8], issuer: &X509Certificate) -> Result<(), String> {
+    let malformed = || "OCSP 响应格式错误".to_string();
+
+    let (_, rest) = expect_tlv(basic, TAG_SEQUENCE).ok_or_else(malformed)?;
+    let tbs = &basic[..basic.len() - rest.len()];
+    let (_, after_algorithm) = expect_tlv(rest, TAG_SEQUENCE).ok_or_else(malformed)?;
+    let (_, algorithm) = AlgorithmIdentifier::from_der(&rest[..rest.len() - after_algorithm.len()])
+        .map_err(|_| malformed())?;
+    let (_, after_signature) = expect_tlv(after_algorithm, TAG_BIT_STRING).ok_or_else(malformed)?;
+    let (_, signature) =
+        BitString::from_der(&after_algorithm[..after_algorithm.len() - after_signature.len()])
+            .map_err(|_| malformed())?;
+
+    let verify = |key: &SubjectPublicKeyInfo| {
+        x509_parser::verify::verify_signature(key, &algorithm, &signature, tbs).is_ok()
+    };
+    if verify(issuer.public_key()) {
+        return Ok(());
+    }
+
+    // 委托响应器：响应中附带的第一张证书
+    let delegated = expect_tlv(after_signature, TAG_CONTEXT_0)
+        .and_then(|(certs, _)| expect_tlv(certs, TAG_SEQUENCE))
+        .and_then(|(certs, _)| X509Certificate::from_der(certs).ok())
+        .map(|(_, responder)| responder);
+    if let Some(responder) = delegated {
+        let ocsp_signing = matches!(
+            responder.extended_key_usage(),
+            Ok(Some(eku)) if eku.value.ocsp_signing
+        );
+        if ocsp_signing
+            && responder
+                .verify_signature(Some(issuer.public_key()))
+                .is_ok()
+            && verify(responder.public_key())
+        {
+            return Ok(());
+        }
+    }
+    Err("OCSP 响应签名校验失败".to_string())
+}
+
+/// 从 `BasicOCSPResponse` 的 `ResponseData` 中查找序列号为 `serial` 的证书状态
+fn cert_status(basic: &[u8], serial: &[u8]) -> Result<OcspStatus, String> {
+    let malformed = || "OCSP 响应格式错误".to_string();
+
+    let (mut data, _) = expect_tlv(basic, TAG_SEQUENCE).ok_or_else(malformed)?;
+    // 跳过 version [0]（可选）、responderID、producedAt
+    if let Some((_, rest)) = expect_tlv(data, TAG_CONTEXT_0) {
+        data = rest;
+    }
+    let (_, _, rest) = read_tlv(data).ok_or_else(malformed)?;
+    let (_, rest) = expect_tlv(rest, TAG_GENERALIZED_TIME).ok_or_else(malformed)?;
+    let (mut responses, _) = expect_tlv(rest, TAG_SEQUENCE).ok_or_else(malformed)?;
+
+    while !responses.is_empty() {
+        let (single, rest) = expect_tlv(responses, TAG_SEQUENCE).ok_or_else(malformed)?;
+        responses = rest;
+        let (cert_id, cert_status) = expect_tlv(single, TAG_SEQUENCE).ok_or_else(malformed)?;
+        if cert_id_serial(cert_id) == Some(serial) {
+            return parse_cert_status(cert_status).ok_or_else(malformed);
+        }
+    }
+    Err("OCSP 响应中没有该证书的状态".to_string())
+}
+
+/// `CertID` 中的序列号（跳过 hashAlgorithm、issuerNameHash、issuerKeyHash）
+fn cert_id_serial(cert_id: &[u8]) -> Option<&[u8]> {
+    let (_, rest) = expect_tlv(cert_id, TAG_SEQUENCE)?;
+    let (_, rest) = expect_tlv(rest, TAG_OCTET_STRING)?;
+    let (_, rest) = expect_tlv(rest, TAG_OCTET_STRING)?;
+    let (serial, _) = expect_tlv(rest, TAG_INTEGER)?;
+    Some(serial)
+}
+
+fn parse_cert_status(input: &[u8]) -> Option<OcspStatus> {
+    let (tag, content, _) = read_tlv(input)?;
+    match tag {
+        TAG_STATUS_GOOD => Some(OcspStatus::Good),
+        TAG_STATUS_UNKNOWN => Some(OcspStatus::Unknown),
+        TAG_STATUS_REVOKED => {
+            // RevokedInfo { revocationTime, revocationReason [0] EXPLICIT CRLReason OPTIONAL }
+            let (time, rest) = expect_tlv(content, TAG_GENERALIZED_TIME)?;
+            let reason = expect_tlv(rest, TAG_CONTEXT_0)
+                .and_then(|(reason, _)| expect_tlv(reason, TAG_ENUMERATED))
+                .and_then(|(code, _)| code.first().copied());
+            Some(OcspStatus::Revoked {
+                reason: revocation_reason_name(reason.unwrap_or(0)).to_string(),
+                revocation_time: format_generalized_time(time),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// `GeneralizedTime`（`YYYYMMDDHHMMSS[.fff]Z`）转 RFC 2822，与证书有效期格式一致
+fn format_generalized_time(raw: &[u8]) -> String {
+    let text = String::from_utf8_lossy(raw);
+    let seconds = text.split(['.', 'Z']).next().unwrap_or_default();
+    chrono::NaiveDateTime::parse_from_str(seconds, "%Y%m%d%H%M%S")
+        .map_or_else(|_| text.to_string(), |dt| dt.and_utc().to_rfc2822())
+}
+
+/// CRLReason（RFC 5280 5.3.1）
+fn revocation_reason_name(code: u8) -> &'static str {
+    match code {
+        1 => "keyCompromise",
+        2 => "cACompromise",
+        3 => "affiliationChanged",
+        4 => "superseded",
+        5 => "cessationOfOperation",
+        6 => "certificateHold",
+        8 => "removeFromCRL",
+        9 => "privilegeWithdrawn",
+        10 => "aACompromise",
+        _ => "unspecified",
+    }
+}
+
+/// OCSPResponseStatus（RFC 6960 4.2.1）
+fn response_status_name(code: u8) -> &'static str {
+    match code {
+        1 => "malformedRequest",
+        2 => "internalError",
+        3 => "tryLater",
+        5 => "sigRequired",
+        6 => "unauthorized",
+        _ => "unknown",
+    }
+}
+
+/// 编码一个 DER TLV
+fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        out.push(u8::try_from(len).unwrap_or(0));
+    } else {
+        let bytes = len.to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        out.push(0x80 | u8::try_from(bytes.len() - skip).unwrap_or(0));
+        out.extend_from_slice(&bytes[skip..]);
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+/// 读取一个 DER TLV，返回（标签, 内容, 剩余字节）
+fn read_tlv(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+    let (len, rest) = if first < 0x80 {
+        (usize::from(first), rest)
+    } else {
+        let count = usize::from(first & 0x7f);
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let (len_bytes, rest) = rest.split_at(count);
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | usize::from(*b));
+        (len, rest)
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let (content, rest) = rest.split_at(len);
+    Some((tag, content, rest))
+}
+
+/// 读取一个标签为 `tag` 的 TLV，返回（内容, 剩余字节）
+fn expect_tlv(input: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (actual, content, rest) = read_tlv(input)?;
+    (actual == tag).then_some((content, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::pem::Pem;
+
+    use super::*;
+
+    /// 由 `OCSP_ISSUER` 签发、序列号为 0x1234 的证书的响应（均为 good）
+    const SERIAL: [u8; 2] = [0x12, 0x34];
+    const OCSP_ISSUER: &[u8] = include_bytes!("../../../tests/fixtures/ssl/ocsp_issuer.pem");
+    /// 颁发者直接签名
+    const OCSP_GOOD: &[u8] = include_bytes!("../../../tests/fixtures/ssl/ocsp_good.der");
+    /// 颁发者签发的委托响应器签名
+    const OCSP_DELEGATED: &[u8] = include_bytes!("../../../tests/fixtures/ssl/ocsp_delegated.der");
+    /// 自签名的响应器签名（带 `OCSPSigning` 扩展用途，但不是颁发者签发的）
+    const OCSP_ROGUE: &[u8] =
+        include_bytes!("../../../tests/fixtures/ssl/ocsp_rogue_responder.der");
+    const SELF_SIGNED: &[u8] = include_bytes!("../../../tests/fixtures/ssl/self_signed.pem");
+
+    /// 构造只含一个 `SingleResponse` 的 `OCSPResponse`
+    fn ocsp_response(serial: &[u8], cert_status: &[u8]) -> Vec<u8> {
+        let cert_id = tlv(
+            TAG_SEQUENCE,
+            &[
+                tlv(
+                    TAG_SEQUENCE,
+                    &[tlv(TAG_OID, &OID_SHA1), tlv(TAG_NULL, &[])].concat(),
+                ),
+                tlv(TAG_OCTET_STRING, &[0; 20]),
+                tlv(TAG_OCTET_STRING, &[0; 20]),
+                tlv(TAG_INTEGER, serial),
+            ]
+            .concat(),
+        );
+        let this_update = tlv(TAG_GENERALIZED_TIME, b"20260101000000Z");
+        let single = tlv(
+            TAG_SEQUENCE,
+            &[cert_id, cert_status.to_vec(), this_update].concat(),
+        );
+        let data = tlv(
+            TAG_SEQUENCE,
+            &[
+                // responderID byKey [2]
+                tlv(0xa2, &tlv(TAG_OCTET_STRING, &[0; 20])),
+                tlv(TAG_GENERALIZED_TIME, b"20260101000000Z"),
+                tlv(TAG_SEQUENCE, &single),
+            ]
+            .concat(),
+        );
+        // 只用于测试状态解析，省略签名部分
+        let basic = tlv(TAG_SEQUENCE, &data);
+        let response_bytes = tlv(
+            TAG_SEQUENCE,
+            &[tlv(TAG_OID, &OID_OCSP_BASIC), tlv(TAG_OCTET_STRING, &basic)].concat(),
+        );
+        tlv(
+            TAG_SEQUENCE,
+            &[
+                tlv(TAG_ENUMERATED, &[0]),
+                tlv(TAG_CONTEXT_0, &response_bytes),
+            ]
+            .concat(),
+        )
+    }
+
+    /// 不校验签名，只解析状态
+    fn unsigned_status(der: &[u8], serial: &[u8]) -> Result<OcspStatus, String> {
+        cert_status(basic_response(der)?, serial)
+    }
+
+    fn parse_pem(pem: &[u8]) -> Result<Pem, String> {
+        Pem::iter_from_buffer(pem)
+            .next()
+            .ok_or_else(|| "empty PEM".to_string())?
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn tlv_round_trips_long_lengths() {
+        let content = vec![0x42; 300];
+        let encoded = tlv(TAG_OCTET_STRING, &content);
+        assert_eq!(&encoded[..4], &[TAG_OCTET_STRING, 0x82, 0x01, 0x2c]);
+        assert_eq!(
+            read_tlv(&encoded),
+            Some((TAG_OCTET_STRING, content.as_slice(), &[][..]))
+        );
+        // 长度超出剩余字节
+        assert_eq!(read_tlv(&[TAG_SEQUENCE, 0x05, 0x00]), None);
+    }
+
+    #[test]
+    fn parses_good_and_unknown_status() {
+        let serial = [0x01, 0x23, 0x45];
+        let good = ocsp_response(&serial, &[TAG_STATUS_GOOD, 0]);
+        assert_eq!(unsigned_status(&good, &serial), Ok(OcspStatus::Good));
+
+        let unknown = ocsp_response(&serial, &[TAG_STATUS_UNKNOWN, 0]);
+        assert_eq!(unsigned_status(&unknown, &serial), Ok(OcspStatus::Unknown));
+
+        // 响应中没有查询的序列号
+        assert!(unsigned_status(&good, &[0x09]).is_err());
+    }
+
+    #[test]
+    fn parses_revoked_status_with_reason() {
+        let serial = [0x7f];
+        let revoked_info = [
+            tlv(TAG_GENERALIZED_TIME, b"20250301120000Z"),
+            tlv(TAG_CONTEXT_0, &tlv(TAG_ENUMERATED, &[1])),
+        ]
+        .concat();
+        let response = ocsp_response(&serial, &tlv(TAG_STATUS_REVOKED, &revoked_info));
+
+        assert_eq!(
+            unsigned_status(&response, &serial),
+            Ok(OcspStatus::Revoked {
+                reason: "keyCompromise".to_string(),
+                revocation_time: "Sat, 1 Mar 2025 12:00:00 +0000".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn reports_responder_error_status() {
+        let try_later = tlv(TAG_SEQUENCE, &tlv(TAG_ENUMERATED, &[3]));
+        assert_eq!(
+            unsigned_status(&try_later, &[0x01]),
+            Err("OCSP 响应器返回错误: tryLater".to_string())
+        );
+    }
+
+    #[test]
+    fn verifies_response_signature() -> Result<(), String> {
+        let issuer_pem = parse_pem(OCSP_ISSUER)?;
+        let issuer = issuer_pem.parse_x509().map_err(|e| e.to_string())?;
+
+        assert_eq!(
+            parse_response(OCSP_GOOD, &SERIAL, &issuer),
+            Ok(OcspStatus::Good)
+        );
+        assert_eq!(
+            parse_response(OCSP_DELEGATED, &SERIAL, &issuer),
+            Ok(OcspStatus::Good)
+        );
+        assert!(parse_response(OCSP_ROGUE, &SERIAL, &issuer).is_err());
+
+        // 篡改响应内容
+        let mut tampered = OCSP_GOOD.to_vec();
+        let Some(pos) = tampered.windows(2).position(|w| w == SERIAL) else {
+            return Err("serial not found in fixture".to_string());
+        };
+        tampered[pos + 1] ^= 0x01;
+        assert!(parse_response(&tampered, &[0x12, 0x35], &issuer).is_err());
+
+        // 其他证书作为颁发者
+        let other_pem = parse_pem(SELF_SIGNED)?;
+        let other = other_pem.parse_x509().map_err(|e| e.to_string())?;
+        assert_eq!(
+            parse_response(OCSP_GOOD, &SERIAL, &other),
+            Err("OCSP 响应签名校验失败".to_string())
+        );
+        Ok(())
+    }
+}
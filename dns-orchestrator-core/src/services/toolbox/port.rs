@@ -0,0 +1,371 @@
+//! 端口连通性检查模块
+//!
+//! 先解析主机，再并发探测解析到的地址的多个端口，记录端口状态与响应耗时：
+//! - TCP：发起连接，被拒绝为关闭，超时或不可达视为过滤。连接成功后短暂等待服务器主动
+//!   发送的欢迎信息（SMTP、POP3、IMAP 等协议会先发送），最多读取 [`BANNER_MAX_BYTES`]
+//!   字节作为 banner。
+//! - UDP：发送探测报文（53 端口发送 DNS 查询，其余为空报文），收到响应为开放，
+//!   收到 ICMP 端口不可达为关闭，超时无响应视为过滤（无法与不响应空报文的开放端口区分）。
+
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use log::debug;
+use tokio::io::{AsyncReadExt, Interest};
+use tokio::net::{lookup_host, TcpStream, UdpSocket};
+use tokio::time::timeout;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{PortCheckResult, PortProtocol, PortState, PortStatus};
+
+/// 单次检查的最大端口数
+const MAX_PORTS: usize = 100;
+
+/// 允许的最大连接超时（毫秒）
+const MAX_TIMEOUT_MS: u64 = 30_000;
+
+/// banner 最多读取的字节数
+const BANNER_MAX_BYTES: usize = 64;
+
+/// 等待 banner 的最长时间（不超过连接超时）
+const BANNER_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// 53 端口的 UDP 探测报文：查询根域 NS 记录（不带 EDNS）
+const DNS_PROBE: [u8; 17] = [
+    0x00, 0x00, // ID
+    0x01, 0x00, // 标志：RD
+    0x00, 0x01, // QDCOUNT
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ANCOUNT / NSCOUNT / ARCOUNT
+    0x00, // 根域
+    0x00, 0x02, // QTYPE = NS
+    0x00, 0x01, // QCLASS = IN
+];
+
+/// 检查 `host` 的多个端口，结果按 `ports` 去重后的顺序排列
+///
+/// 主机无法解析时返回校验错误，不发起探测。
+pub async fn port_check(
+    host: &str,
+    ports: Vec<u16>,
+    protocol: PortProtocol,
+    timeout_ms: u64,
+) -> CoreResult<PortCheckResult> {
+    // 允许 `[::1]` 形式的 IPv6 地址
+    let host = host
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    if host.is_empty() {
+        return Err(CoreError::ValidationError("主机不能为空".to_string()));
+    }
+    let ports = dedup_ports(ports)?;
+    if timeout_ms == 0 || timeout_ms > MAX_TIMEOUT_MS {
+        return Err(CoreError::ValidationError(format!(
+            "超时时间必须在 1 到 {MAX_TIMEOUT_MS} 毫秒之间"
+        )));
+    }
+    let probe_timeout = Duration::from_millis(timeout_ms);
+    let address = resolve(&host).await?;
+
+    debug!(
+        "[Port] Checking {} {protocol:?} port(s) on {host} ({address})",
+        ports.len()
+    );
+    let results = join_all(ports.iter().map(|&port| {
+        let target = SocketAddr::new(address.ip(), port);
+        async move {
+            match protocol {
+                PortProtocol::Tcp => check_tcp(target, probe_timeout).await,
+                PortProtocol::Udp => check_udp(target, probe_timeout).await,
+            }
+        }
+    }))
+    .await;
+
+    Ok(PortCheckResult {
+        host,
+        address: address.ip().to_string(),
+        protocol,
+        results,
+    })
+}
+
+/// 解析主机，取第一个地址
+async fn resolve(host: &str) -> CoreResult<SocketAddr> {
+    let mut addresses = lookup_host((host, 0))
+        .await
+        .map_err(|e| CoreError::ValidationError(format!("无法解析主机 {host}: {e}")))?;
+    addresses
+        .next()
+        .ok_or_else(|| CoreError::ValidationError(format!("主机 {host} 没有可用地址")))
+}
+
+/// 去重并校验端口列表
+fn dedup_ports(ports: Vec<u16>) -> CoreResult<Vec<u16>> {
+    let mut unique = Vec::with_capacity(ports.len());
+    for port in ports {
+        if port == 0 {
+            return Err(CoreError::ValidationError(
+                "端口必须在 1 到 65535 之间".to_string(),
+            ));
+        }
+        if !unique.contains(&port) {
+            unique.push(port);
+        }
+    }
+    if unique.is_empty() {
+        return Err(CoreError::ValidationError("至少需要一个端口".to_string()));
+    }
+    if unique.len() > MAX_PORTS {
+        return Err(CoreError::ValidationError(format!(
+            "单次最多检查 {MAX_PORTS} 个端口"
+        )));
+    }
+    Ok(unique)
+}
+
+async fn check_tcp(target: SocketAddr, connect_timeout: Duration) -> PortStatus {
+    let port = target.port();
+    let start = Instant::now();
+    let mut stream = match timeout(connect_timeout, TcpStream::connect(target)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+            debug!("[Port] {target} closed: {e}");
+            return unanswered(port, PortState::Closed);
+        }
+        Ok(Err(e)) => {
+            debug!("[Port] {target} unreachable: {e}");
+            return unanswered(port, PortState::Filtered);
+        }
+        Err(_) => {
+            debug!("[Port] {target} timed out");
+            return unanswered(port, PortState::Filtered);
+        }
+    };
+    let response_time_ms = elapsed_ms(start);
+
+    let mut buf = [0u8; BANNER_MAX_BYTES];
+    let banner = match timeout(BANNER_TIMEOUT.min(connect_timeout), stream.read(&mut buf)).await {
+        Ok(Ok(read)) => banner_text(&buf[..read]),
+        _ => None,
+    };
+
+    PortStatus {
+        port,
+        state: PortState::Open,
+        response_time_ms: Some(response_time_ms),
+        banner,
+    }
+}
+
+async fn check_udp(target: SocketAddr, read_timeout: Duration) -> PortStatus {
+    let port = target.port();
+    let bind_addr: SocketAddr = if target.is_ipv4() {
+        (Ipv4Addr::UNSPECIFIED, 0).into()
+    } else {
+        (Ipv6Addr::UNSPECIFIED, 0).into()
+    };
+    let payload: &[u8] = if port == 53 { &DNS_PROBE } else { &[] };
+
+    let start = Instant::now();
+    let probe = async {
+        let socket = UdpSocket::bind(bind_addr).await?;
+        // 连接后才能收到 ICMP 端口不可达（表现为 ConnectionRefused）
+        socket.connect(target).await?;
+        socket.send(payload).await?;
+        let mut buf = [0u8; BANNER_MAX_BYTES];
+        // 端口不可达只产生错误事件而非可读事件，recv 等不到，需同时等待 ERROR 并取出套接字错误
+        socket
+            .async_io(Interest::READABLE | Interest::ERROR, || {
+                if let Some(e) = socket.take_error()? {
+                    return Err(e);
+                }
+                socket.try_recv(&mut buf)
+            })
+            .await
+    };
+    match timeout(read_timeout, probe).await {
+        Ok(Ok(_)) => PortStatus {
+            port,
+            state: PortState::Open,
+            response_time_ms: Some(elapsed_ms(start)),
+            banner: None,
+        },
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+            debug!("[Port] {target}/udp closed: {e}");
+            unanswered(port, PortState::Closed)
+        }
+        Ok(Err(e)) => {
+            debug!("[Port] {target}/udp probe failed: {e}");
+            unanswered(port, PortState::Filtered)
+        }
+        Err(_) => {
+            debug!("[Port] {target}/udp no response");
+            unanswered(port, PortState::Filtered)
+        }
+    }
+}
+
+fn elapsed_ms(start: Instant) -> u64 {
+    u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)
+}
+
+fn unanswered(port: u16, state: PortState) -> PortStatus {
+    PortStatus {
+        port,
+        state,
+        response_time_ms: None,
+        banner: None,
+    }
+}
+
+/// banner 转为单行文本，控制字符替换为空格，空内容返回 `None`
+fn banner_text(bytes: &[u8]) -> Option<String> {
+    let text: String = String::from_utf8_lossy(bytes)
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_open_closed_ports_and_banner() -> CoreResult<()> {
+        let io_err = |e: std::io::Error| CoreError::NetworkError(e.to_string());
+
+        // 发送欢迎信息的服务（类似 SMTP）
+        let smtp = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_err)?;
+        let smtp_port = smtp.local_addr().map_err(io_err)?.port();
+        tokio::spawn(async move {
+            if let Ok((mut stream, _)) = smtp.accept().await {
+                let _ = stream.write_all(b"220 mx.example.com ESMTP\r\n").await;
+            }
+        });
+
+        // 不主动发送数据的服务（类似 HTTP）
+        let http = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_err)?;
+        let http_port = http.local_addr().map_err(io_err)?.port();
+        tokio::spawn(async move {
+            if let Ok((_stream, _)) = http.accept().await {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+        });
+
+        // 未监听的端口
+        let closed = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_err)?;
+        let closed_port = closed.local_addr().map_err(io_err)?.port();
+        drop(closed);
+
+        let result = port_check(
+            "127.0.0.1",
+            vec![smtp_port, http_port, closed_port, smtp_port],
+            PortProtocol::Tcp,
+            2000,
+        )
+        .await?;
+
+        assert_eq!(result.host, "127.0.0.1");
+        assert_eq!(result.address, "127.0.0.1");
+        let ports: Vec<u16> = result.results.iter().map(|r| r.port).collect();
+        assert_eq!(ports, [smtp_port, http_port, closed_port]);
+
+        let smtp = &result.results[0];
+        assert_eq!(smtp.state, PortState::Open);
+        assert!(smtp.response_time_ms.is_some());
+        assert_eq!(smtp.banner.as_deref(), Some("220 mx.example.com ESMTP"));
+
+        let http = &result.results[1];
+        assert_eq!(http.state, PortState::Open);
+        assert_eq!(http.banner, None);
+
+        let closed = &result.results[2];
+        assert_eq!(closed.state, PortState::Closed);
+        assert_eq!(closed.response_time_ms, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_arguments() {
+        for (host, ports, timeout_ms) in [
+            ("", vec![80], 1000),
+            ("example.com", vec![], 1000),
+            ("example.com", vec![0], 1000),
+            ("example.com", vec![80], 0),
+            ("example.com", vec![80], MAX_TIMEOUT_MS + 1),
+            ("example.com", (1..=101).collect(), 1000),
+        ] {
+            assert!(
+                matches!(
+                    port_check(host, ports.clone(), PortProtocol::Tcp, timeout_ms).await,
+                    Err(CoreError::ValidationError(_))
+                ),
+                "{host:?} {ports:?} {timeout_ms}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn udp_reports_responding_and_refusing_ports() -> CoreResult<()> {
+        let io_err = |e: std::io::Error| CoreError::NetworkError(e.to_string());
+
+        // 回显服务
+        let echo = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_err)?;
+        let echo_port = echo.local_addr().map_err(io_err)?.port();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 64];
+            if let Ok((read, peer)) = echo.recv_from(&mut buf).await {
+                let _ = echo.send_to(&buf[..read], peer).await;
+            }
+        });
+
+        // 未监听的端口（本机回环会返回 ICMP 端口不可达）
+        let closed = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_err)?;
+        let closed_port = closed.local_addr().map_err(io_err)?.port();
+        drop(closed);
+
+        let result = port_check(
+            "127.0.0.1",
+            vec![echo_port, closed_port],
+            PortProtocol::Udp,
+            2000,
+        )
+        .await?;
+
+        assert_eq!(result.protocol, PortProtocol::Udp);
+        assert_eq!(result.results[0].state, PortState::Open);
+        assert!(result.results[0].response_time_ms.is_some());
+        assert_eq!(result.results[1].state, PortState::Closed);
+        Ok(())
+    }
+
+    #[test]
+    fn banner_text_is_single_line() {
+        assert_eq!(
+            banner_text(b"+OK POP3 ready\r\n").as_deref(),
+            Some("+OK POP3 ready")
+        );
+        assert_eq!(banner_text(b"\r\n"), None);
+        assert_eq!(banner_text(b""), None);
+    }
+}
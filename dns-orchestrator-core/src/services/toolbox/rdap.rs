@@ -0,0 +1,285 @@
+//! RDAP 查询模块
+//!
+//! 许多 gTLD 已关闭 port-43 WHOIS 服务，WHOIS 返回空结果时改用 RDAP（RFC 9083 JSON）。
+//! 查询端点由嵌入的 IANA bootstrap 文件（`https://data.iana.org/rdap/dns.json`）按 TLD 确定。
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::WhoisResult;
+
+use super::external_endpoints::EndpointDeclaration;
+
+/// `WhoisResult.raw` 前缀，标识结果来自 RDAP
+const RDAP_RAW_PREFIX: &str = "[RDAP]";
+
+/// 请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// RDAP 查询的外发端点标识
+pub(super) const RDAP_ENDPOINT: &str = "rdap.servers";
+
+/// 本模块访问的第三方端点：bootstrap 中的各 TLD RDAP 服务
+pub(super) fn external_endpoints(bootstrap: &str) -> Vec<EndpointDeclaration> {
+    let urls: BTreeSet<String> = serde_json::from_str::<Bootstrap>(bootstrap)
+        .map(|bootstrap| {
+            bootstrap
+                .services
+                .into_iter()
+                .flat_map(|(_, urls)| urls)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    vec![EndpointDeclaration {
+        id: RDAP_ENDPOINT,
+        module: "rdap",
+        targets: urls.into_iter().collect(),
+        purpose: "WHOIS 无结果时通过 RDAP 查询域名注册信息",
+        sends_query_target: true,
+        essential: true,
+        confirmable: true,
+    }]
+}
+
+/// IANA RDAP bootstrap 文件
+#[derive(Deserialize)]
+struct Bootstrap {
+    /// `[[TLD 列表], [端点 URL 列表]]`
+    services: Vec<(Vec<String>, Vec<String>)>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RdapDomain {
+    #[serde(default)]
+    entities: Vec<RdapEntity>,
+    #[serde(default)]
+    events: Vec<RdapEvent>,
+    #[serde(default)]
+    nameservers: Vec<RdapNameserver>,
+    #[serde(default)]
+    status: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RdapEntity {
+    #[serde(default)]
+    roles: Vec<String>,
+    /// jCard（RFC 7095）：`["vcard", [[name, params, type, value], ...]]`
+    vcard_array: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RdapEvent {
+    event_action: String,
+    event_date: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RdapNameserver {
+    ldh_name: Option<String>,
+}
+
+/// RDAP 查询
+pub async fn rdap_lookup(domain: &str, bootstrap: &str) -> CoreResult<WhoisResult> {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    let base = find_endpoint(&domain, bootstrap)?;
+    let url = format!("{}/domain/{domain}", base.trim_end_matches('/'));
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| CoreError::NetworkError(format!("初始化 RDAP 客户端失败: {e}")))?;
+
+    let response = client
+        .get(&url)
+        .header(reqwest::header::ACCEPT, "application/rdap+json")
+        .send()
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("RDAP 查询失败: {e}")))?;
+
+    if !response.status().is_success() {
+        return Err(CoreError::NetworkError(format!(
+            "RDAP 查询失败: HTTP {}",
+            response.status()
+        )));
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("读取 RDAP 响应失败: {e}")))?;
+
+    parse_rdap_response(&domain, &url, &body)
+}
+
+/// 按最长后缀匹配 TLD 对应的 RDAP 端点
+fn find_endpoint(domain: &str, bootstrap: &str) -> CoreResult<String> {
+    let bootstrap: Bootstrap = serde_json::from_str(bootstrap)
+        .map_err(|e| CoreError::SerializationError(format!("解析 RDAP bootstrap 失败: {e}")))?;
+
+    bootstrap
+        .services
+        .into_iter()
+        .flat_map(|(tlds, urls)| {
+            tlds.into_iter()
+                .filter(|tld| domain == tld || domain.ends_with(&format!(".{tld}")))
+                .filter_map(|tld| {
+                    // 优先 HTTPS 端点
+                    let url = urls
+                        .iter()
+                        .find(|u| u.starts_with("https://"))
+                        .or_else(|| urls.first())?;
+                    Some((tld.len(), url.clone()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, url)| url)
+        .ok_or_else(|| CoreError::ValidationError(format!("{domain} 所属 TLD 不支持 RDAP 查询")))
+}
+
+/// 解析 RDAP 域名响应
+fn parse_rdap_response(domain: &str, url: &str, body: &str) -> CoreResult<WhoisResult> {
+    let rdap: RdapDomain = serde_json::from_str(body)
+        .map_err(|e| CoreError::SerializationError(format!("解析 RDAP 响应失败: {e}")))?;
+
+    let event_date = |actions: &[&str]| {
+        rdap.events
+            .iter()
+            .find(|e| actions.contains(&e.event_action.as_str()))
+            .map(|e| e.event_date.clone())
+    };
+
+    let registrar = rdap
+        .entities
+        .iter()
+        .find(|e| e.roles.iter().any(|r| r == "registrar"))
+        .and_then(|e| e.vcard_array.as_ref())
+        .and_then(vcard_full_name);
+
+    let mut name_servers = Vec::new();
+    for ns in &rdap.nameservers {
+        if let Some(name) = &ns.ldh_name {
+            let name = name.trim_end_matches('.').to_lowercase();
+            if !name_servers.contains(&name) {
+                name_servers.push(name);
+            }
+        }
+    }
+
+    let pretty = serde_json::from_str::<serde_json::Value>(body)
+        .and_then(|v| serde_json::to_string_pretty(&v))
+        .unwrap_or_else(|_| body.to_string());
+
+    Ok(WhoisResult {
+        domain: domain.to_string(),
+        unicode_domain: None,
+        registrar,
+        registrant_org: None,
+        registrant_country: None,
+        registrar_abuse_email: None,
+        registrar_abuse_phone: None,
+        creation_date: event_date(&["registration"]),
+        expiration_date: event_date(&["expiration"]),
+        updated_date: event_date(&["last changed"]),
+        name_servers,
+        status: rdap.status,
+        dnssec: None,
+        queried_servers: reqwest::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(ToString::to_string))
+            .into_iter()
+            .collect(),
+        raw: format!("{RDAP_RAW_PREFIX} {url}\n\n{pretty}"),
+        cached: false,
+        fetched_at: Utc::now(),
+    })
+}
+
+/// 从 jCard 中取 `fn`（全名）
+fn vcard_full_name(vcard: &serde_json::Value) -> Option<String> {
+    vcard
+        .get(1)?
+        .as_array()?
+        .iter()
+        .find(|prop| prop.get(0).and_then(serde_json::Value::as_str) == Some("fn"))
+        .and_then(|prop| prop.get(3))
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOTSTRAP: &str = include_str!("rdap_dns.json");
+
+    const RESPONSE: &str = r#"{
+        "objectClassName": "domain",
+        "ldhName": "EXAMPLE.LTD",
+        "status": ["client transfer prohibited", "active"],
+        "events": [
+            {"eventAction": "registration", "eventDate": "2020-01-02T03:04:05Z"},
+            {"eventAction": "expiration", "eventDate": "2027-01-02T03:04:05Z"},
+            {"eventAction": "last changed", "eventDate": "2026-01-02T03:04:05Z"},
+            {"eventAction": "last update of RDAP database", "eventDate": "2026-10-01T00:00:00Z"}
+        ],
+        "entities": [
+            {"roles": ["registrant"], "vcardArray": ["vcard", [["fn", {}, "text", "REDACTED"]]]},
+            {"roles": ["registrar"], "vcardArray": ["vcard", [
+                ["version", {}, "text", "4.0"],
+                ["fn", {}, "text", "Example Registrar, Inc."]
+            ]]}
+        ],
+        "nameservers": [
+            {"ldhName": "NS1.EXAMPLE.NET"},
+            {"ldhName": "ns2.example.net."}
+        ]
+    }"#;
+
+    #[test]
+    fn finds_endpoint_for_tld() -> CoreResult<()> {
+        assert_eq!(
+            find_endpoint("example.com", BOOTSTRAP)?,
+            "https://rdap.verisign.com/com/v1/"
+        );
+        assert_eq!(
+            find_endpoint("www.example.online", BOOTSTRAP)?,
+            "https://rdap.centralnic.com/online/"
+        );
+        assert!(find_endpoint("example.invalid", BOOTSTRAP).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parses_domain_response() -> CoreResult<()> {
+        let url = "https://rdap.identitydigital.services/rdap/domain/example.ltd";
+        let result = parse_rdap_response("example.ltd", url, RESPONSE)?;
+
+        assert_eq!(result.registrar.as_deref(), Some("Example Registrar, Inc."));
+        assert_eq!(
+            result.creation_date.as_deref(),
+            Some("2020-01-02T03:04:05Z")
+        );
+        assert_eq!(
+            result.expiration_date.as_deref(),
+            Some("2027-01-02T03:04:05Z")
+        );
+        assert_eq!(result.updated_date.as_deref(), Some("2026-01-02T03:04:05Z"));
+        assert_eq!(result.name_servers, ["ns1.example.net", "ns2.example.net"]);
+        assert_eq!(result.status, ["client transfer prohibited", "active"]);
+        assert!(result.raw.starts_with("[RDAP] https://"));
+        Ok(())
+    }
+}
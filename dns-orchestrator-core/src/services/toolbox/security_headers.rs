@@ -0,0 +1,365 @@
+//! 安全头取值分析
+//!
+//! 解析 HSTS、CSP、`X-Frame-Options`、`Referrer-Policy`、`Permissions-Policy` 等头的取值，
+//! 给出具体问题及其严重程度，供 HTTP 头检查使用。
+
+use crate::types::SecuritySeverity;
+
+/// HSTS 建议的最短有效期（6 个月，按 180 天计）
+const HSTS_MIN_MAX_AGE: u64 = 180 * 24 * 60 * 60;
+
+/// 允许加载脚本的 CSP 指令，这些指令使用通配来源视为错误
+const SCRIPT_DIRECTIVES: &[&str] = &["default-src", "script-src", "script-src-elem", "object-src"];
+
+/// CSP 中等同于放开所有来源的写法
+const WILDCARD_SOURCES: &[&str] = &["*", "http:", "https:"];
+
+/// 不应对所有来源开放的浏览器功能
+const SENSITIVE_PERMISSIONS: &[&str] = &[
+    "camera",
+    "microphone",
+    "geolocation",
+    "payment",
+    "usb",
+    "serial",
+    "hid",
+    "bluetooth",
+    "display-capture",
+];
+
+/// 单个安全头的检查结论
+#[derive(Debug, Default)]
+pub(super) struct Findings {
+    /// 各项问题中最严重的程度
+    pub severity: SecuritySeverity,
+    /// 具体问题或说明
+    pub messages: Vec<String>,
+}
+
+impl Findings {
+    /// 附加说明，不影响严重程度
+    pub fn note(&mut self, message: impl Into<String>) {
+        self.push(SecuritySeverity::Ok, message);
+    }
+
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.push(SecuritySeverity::Warning, message);
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(SecuritySeverity::Error, message);
+    }
+
+    fn push(&mut self, severity: SecuritySeverity, message: impl Into<String>) {
+        self.severity = self.severity.max(severity);
+        self.messages.push(message.into());
+    }
+}
+
+/// 分析已存在的安全头取值
+///
+/// `csp` 为响应中的 `Content-Security-Policy`，用于检查与 `X-Frame-Options` 的冲突。
+/// 未做取值分析的头（如 `Cross-Origin-*`）存在即视为合理。
+pub(super) fn evaluate(name: &str, value: &str, csp: Option<&str>) -> Findings {
+    let mut findings = Findings::default();
+    match name {
+        "strict-transport-security" => evaluate_hsts(value, &mut findings),
+        "content-security-policy" => evaluate_csp(value, &mut findings),
+        "x-frame-options" => evaluate_x_frame_options(value, csp, &mut findings),
+        "x-content-type-options" if !value.trim().eq_ignore_ascii_case("nosniff") => {
+            findings.error(format!(
+                "Unrecognized value '{value}'; only 'nosniff' is supported"
+            ));
+        }
+        "referrer-policy" => evaluate_referrer_policy(value, &mut findings),
+        "permissions-policy" => evaluate_permissions_policy(value, &mut findings),
+        _ => {}
+    }
+    findings
+}
+
+/// CSP 中 `frame-ancestors` 指令的来源列表（小写），未设置时返回 `None`
+pub(super) fn csp_frame_ancestors(csp: &str) -> Option<Vec<String>> {
+    parse_csp(csp)
+        .into_iter()
+        .find(|(name, _)| name == "frame-ancestors")
+        .map(|(_, sources)| sources.iter().map(|s| s.to_ascii_lowercase()).collect())
+}
+
+fn evaluate_hsts(value: &str, findings: &mut Findings) {
+    let mut max_age = None;
+    let mut include_subdomains = false;
+    let mut preload = false;
+    for directive in value.split(';').map(str::trim).filter(|d| !d.is_empty()) {
+        let (name, arg) = match directive.split_once('=') {
+            Some((name, arg)) => (name.trim(), Some(arg.trim().trim_matches('"'))),
+            None => (directive, None),
+        };
+        if name.eq_ignore_ascii_case("max-age") {
+            max_age = arg.and_then(|arg| arg.parse::<u64>().ok());
+        } else if name.eq_ignore_ascii_case("includesubdomains") {
+            include_subdomains = true;
+        } else if name.eq_ignore_ascii_case("preload") {
+            preload = true;
+        }
+    }
+
+    match max_age {
+        None => findings.error("max-age is missing or invalid; browsers ignore the header"),
+        Some(0) => findings.error("max-age=0 instructs browsers to forget the HSTS policy"),
+        Some(age) if age < HSTS_MIN_MAX_AGE => findings.warn(format!(
+            "max-age={age} is shorter than 6 months ({HSTS_MIN_MAX_AGE} seconds)"
+        )),
+        Some(_) => {}
+    }
+    if !include_subdomains {
+        findings.warn("includeSubDomains is not set; subdomains can still be reached over HTTP");
+    }
+    if !preload {
+        findings
+            .warn("preload is not set; first visits are unprotected until the domain is preloaded");
+    }
+}
+
+/// 解析 CSP 为（小写指令名，来源列表）；多个策略（逗号分隔）合并处理
+fn parse_csp(value: &str) -> Vec<(String, Vec<&str>)> {
+    value
+        .split([';', ','])
+        .filter_map(|directive| {
+            let mut tokens = directive.split_whitespace();
+            let name = tokens.next()?.to_ascii_lowercase();
+            Some((name, tokens.collect()))
+        })
+        .collect()
+}
+
+fn evaluate_csp(value: &str, findings: &mut Findings) {
+    let directives = parse_csp(value);
+    if directives.is_empty() {
+        findings.error("Policy is empty");
+        return;
+    }
+    if !directives.iter().any(|(name, _)| name == "default-src") {
+        findings
+            .warn("default-src is missing; resource types without a directive are unrestricted");
+    }
+
+    for (name, sources) in &directives {
+        if !name.ends_with("-src") && name != "frame-ancestors" {
+            continue;
+        }
+        let has_source = |source: &str| sources.iter().any(|s| s.eq_ignore_ascii_case(source));
+        // 存在 nonce 或 hash 时，支持 CSP2 的浏览器会忽略 'unsafe-inline'
+        let has_nonce_or_hash = sources.iter().any(|s| {
+            let s = s.to_ascii_lowercase();
+            ["'nonce-", "'sha256-", "'sha384-", "'sha512-"]
+                .iter()
+                .any(|prefix| s.starts_with(prefix))
+        });
+        if has_source("'unsafe-inline'") && !has_nonce_or_hash {
+            findings.warn(format!("{name} allows 'unsafe-inline'"));
+        }
+        if has_source("'unsafe-eval'") {
+            findings.warn(format!("{name} allows 'unsafe-eval'"));
+        }
+        if let Some(wildcard) = sources
+            .iter()
+            .find(|s| WILDCARD_SOURCES.iter().any(|w| s.eq_ignore_ascii_case(w)))
+        {
+            let message = format!("{name} allows any source via '{wildcard}'");
+            if SCRIPT_DIRECTIVES.contains(&name.as_str()) {
+                findings.error(message);
+            } else {
+                findings.warn(message);
+            }
+        }
+    }
+}
+
+fn evaluate_x_frame_options(value: &str, csp: Option<&str>, findings: &mut Findings) {
+    let normalized = value.trim().to_ascii_uppercase();
+    let equivalent_ancestors = match normalized.as_str() {
+        "DENY" => Some("'none'"),
+        "SAMEORIGIN" => Some("'self'"),
+        v if v.starts_with("ALLOW-FROM") => {
+            findings.warn(
+                "ALLOW-FROM is obsolete and ignored by modern browsers; use CSP frame-ancestors",
+            );
+            None
+        }
+        _ => {
+            findings.error(format!(
+                "Unrecognized value '{value}'; expected DENY or SAMEORIGIN"
+            ));
+            None
+        }
+    };
+
+    let Some(ancestors) = csp.and_then(csp_frame_ancestors) else {
+        return;
+    };
+    if equivalent_ancestors.is_none_or(|expected| ancestors != [expected]) {
+        findings.warn(format!(
+            "Conflicts with CSP frame-ancestors '{}'; browsers supporting CSP ignore X-Frame-Options",
+            ancestors.join(" ")
+        ));
+    }
+}
+
+fn evaluate_referrer_policy(value: &str, findings: &mut Findings) {
+    const POLICIES: &[&str] = &[
+        "no-referrer",
+        "no-referrer-when-downgrade",
+        "same-origin",
+        "origin",
+        "strict-origin",
+        "origin-when-cross-origin",
+        "strict-origin-when-cross-origin",
+        "unsafe-url",
+    ];
+    // 可以列出多个策略作为回退，浏览器使用最后一个能识别的
+    let policy = value
+        .split(',')
+        .rev()
+        .map(|p| p.trim().to_ascii_lowercase())
+        .find(|p| POLICIES.contains(&p.as_str()));
+
+    match policy.as_deref() {
+        None => findings.warn(format!(
+            "Unrecognized policy '{value}'; browsers fall back to strict-origin-when-cross-origin"
+        )),
+        Some("unsafe-url") => {
+            findings
+                .error("unsafe-url sends the full URL, including path and query, to every site");
+        }
+        Some("no-referrer-when-downgrade") => {
+            findings.warn("no-referrer-when-downgrade sends the full URL to other HTTPS sites");
+        }
+        Some(policy @ ("origin" | "origin-when-cross-origin")) => {
+            findings.warn(format!(
+                "{policy} sends the origin even on HTTPS to HTTP requests"
+            ));
+        }
+        Some(_) => {}
+    }
+}
+
+fn evaluate_permissions_policy(value: &str, findings: &mut Findings) {
+    let entries: Vec<&str> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .collect();
+    if entries.is_empty() {
+        findings.warn("Policy is empty and restricts no features");
+        return;
+    }
+
+    for entry in entries {
+        let Some((feature, allowlist)) = entry.split_once('=') else {
+            findings.warn(format!(
+                "Unparseable entry '{entry}'; expected feature=(allowlist)"
+            ));
+            continue;
+        };
+        let feature = feature.trim().to_ascii_lowercase();
+        if allowlist.trim() == "*" && SENSITIVE_PERMISSIONS.contains(&feature.as_str()) {
+            findings.warn(format!("{feature} is allowed for every origin"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsts_flags_short_max_age_and_missing_directives() {
+        let findings = evaluate(
+            "strict-transport-security",
+            "max-age=63072000; includeSubDomains; preload",
+            None,
+        );
+        assert_eq!(findings.severity, SecuritySeverity::Ok);
+        assert!(findings.messages.is_empty());
+
+        let findings = evaluate("strict-transport-security", "max-age=86400", None);
+        assert_eq!(findings.severity, SecuritySeverity::Warning);
+        assert_eq!(findings.messages.len(), 3);
+
+        let findings = evaluate("strict-transport-security", "max-age=0", None);
+        assert_eq!(findings.severity, SecuritySeverity::Error);
+    }
+
+    #[test]
+    fn csp_flags_unsafe_keywords_and_wildcards() {
+        let findings = evaluate(
+            "content-security-policy",
+            "default-src 'self'; script-src 'self' 'nonce-abc' 'unsafe-inline'",
+            None,
+        );
+        assert_eq!(findings.severity, SecuritySeverity::Ok);
+
+        let findings = evaluate(
+            "content-security-policy",
+            "style-src 'self' 'unsafe-inline'; img-src *",
+            None,
+        );
+        assert_eq!(findings.severity, SecuritySeverity::Warning);
+        // 缺少 default-src、unsafe-inline、img-src 通配
+        assert_eq!(findings.messages.len(), 3);
+
+        let findings = evaluate(
+            "content-security-policy",
+            "default-src 'self'; script-src https: 'unsafe-eval'",
+            None,
+        );
+        assert_eq!(findings.severity, SecuritySeverity::Error);
+    }
+
+    #[test]
+    fn x_frame_options_conflicting_with_frame_ancestors() {
+        let csp = "default-src 'self'; frame-ancestors 'self'";
+        let findings = evaluate("x-frame-options", "SAMEORIGIN", Some(csp));
+        assert_eq!(findings.severity, SecuritySeverity::Ok);
+
+        let findings = evaluate("x-frame-options", "DENY", Some(csp));
+        assert_eq!(findings.severity, SecuritySeverity::Warning);
+
+        let findings = evaluate("x-frame-options", "ALLOWALL", None);
+        assert_eq!(findings.severity, SecuritySeverity::Error);
+    }
+
+    #[test]
+    fn referrer_and_permissions_policy_values() {
+        let severity = |name: &str, value: &str| evaluate(name, value, None).severity;
+        assert_eq!(
+            severity(
+                "referrer-policy",
+                "no-referrer, strict-origin-when-cross-origin"
+            ),
+            SecuritySeverity::Ok
+        );
+        assert_eq!(
+            severity("referrer-policy", "no-referrer-when-downgrade"),
+            SecuritySeverity::Warning
+        );
+        assert_eq!(
+            severity("referrer-policy", "unsafe-url"),
+            SecuritySeverity::Error
+        );
+
+        assert_eq!(
+            severity("permissions-policy", "camera=(), fullscreen=*"),
+            SecuritySeverity::Ok
+        );
+        assert_eq!(
+            severity("permissions-policy", "geolocation=*"),
+            SecuritySeverity::Warning
+        );
+        assert_eq!(
+            severity("permissions-policy", "camera 'none'"),
+            SecuritySeverity::Warning
+        );
+    }
+}
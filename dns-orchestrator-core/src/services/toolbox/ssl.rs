@@ -1,13 +1,18 @@
 //! SSL 证书检查模块
 //!
-//! 使用 rustls 实现纯异步的 SSL 证书检查，支持完整证书链获取
+//! 使用 rustls 实现纯异步的 SSL 证书检查，支持完整证书链获取，
+//! 并报告协商的 TLS 版本与密码套件、探测服务器是否仍接受 TLS 1.0 / 1.1，
+//! 按需逐一探测 TLS 1.0 ~ 1.3 各版本是否被接受。
+//! 启用 `ocsp` feature 时附带检查证书的 OCSP 吊销状态
 
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::time::Duration;
 
+use futures::stream::{self, StreamExt};
 use log::{debug, error, trace, warn};
 use rustls::crypto::CryptoProvider;
-use rustls::{ClientConfig, RootCertStore};
+use rustls::{ClientConfig, ClientConnection, ProtocolVersion, RootCertStore};
 use rustls_pki_types::{CertificateDer, ServerName};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
@@ -15,15 +20,51 @@ use tokio::time::timeout;
 use tokio_rustls::TlsConnector;
 use x509_parser::prelude::*;
 
-use crate::error::CoreResult;
-use crate::types::{CertChainItem, SslCertInfo, SslCheckResult};
+use crate::error::{CoreError, CoreResult};
+#[cfg(feature = "ocsp")]
+use crate::types::OcspStatus;
+use crate::types::{CertChainItem, SslCertInfo, SslCheckResult, SslCheckTarget, TlsVersionSupport};
+use crate::utils::domain_name;
+
+use super::external_endpoints::EndpointDeclaration;
+#[cfg(feature = "ocsp")]
+use super::ocsp;
+use super::tls_probe;
 
 // 超时配置常量
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 const TLS_TIMEOUT: Duration = Duration::from_secs(5);
 const HTTP_TIMEOUT: Duration = Duration::from_secs(3);
+#[cfg(feature = "ocsp")]
+const OCSP_TIMEOUT: Duration = Duration::from_secs(5);
+/// 批量检查中单个目标的超时（握手、旧版本协议探测与 OCSP 查询合计）
+const BATCH_TARGET_TIMEOUT: Duration = Duration::from_secs(30);
+/// 批量检查同时进行的目标数
+const BATCH_CONCURRENCY: usize = 8;
+
+/// OCSP 查询的外发端点标识
+pub(super) const OCSP_ENDPOINT: &str = "ssl.ocsp";
+
+/// 本模块访问的第三方端点：服务器未装订 OCSP 响应时查询证书中的响应器
+pub(super) fn external_endpoints() -> Vec<EndpointDeclaration> {
+    if !cfg!(feature = "ocsp") {
+        return Vec::new();
+    }
+    vec![EndpointDeclaration {
+        id: OCSP_ENDPOINT,
+        module: "ssl",
+        targets: vec!["证书 AIA 扩展中的 OCSP 响应器（由签发 CA 运营）".to_string()],
+        purpose: "查询被检查证书的吊销状态",
+        sends_query_target: true,
+        essential: true,
+        confirmable: true,
+    }]
+}
 
 /// 初始化 rustls CryptoProvider（仅初始化一次）
+///
+/// 无法安装时 TLS 检查全部不可用，属于启动期致命错误，直接 panic。
+#[allow(clippy::panic)]
 fn ensure_crypto_provider() {
     use std::sync::Once;
     static INIT: Once = Once::new();
@@ -38,18 +79,15 @@ fn ensure_crypto_provider() {
     });
 }
 
-/// 检查 HTTP 连接是否可用（异步版本）
-async fn check_http_connection(domain: &str, port: u16) -> bool {
+/// 检查 `addr` 上的 HTTP 服务是否可用（`Host` 头使用 `domain`）
+async fn check_http_connection(domain: &str, addr: SocketAddr) -> bool {
     // 使用 timeout 包装整个 HTTP 检测过程
     let result = timeout(HTTP_TIMEOUT, async {
         // 建立 TCP 连接
-        let mut stream = timeout(
-            CONNECT_TIMEOUT,
-            TcpStream::connect(format!("{domain}:{port}")),
-        )
-        .await
-        .ok()?
-        .ok()?;
+        let mut stream = timeout(CONNECT_TIMEOUT, TcpStream::connect(addr))
+            .await
+            .ok()?
+            .ok()?;
 
         // 发送 HTTP HEAD 请求
         let request = format!("HEAD / HTTP/1.1\r\nHost: {domain}\r\nConnection: close\r\n\r\n");
@@ -67,26 +105,88 @@ async fn check_http_connection(domain: &str, port: u16) -> bool {
     result.unwrap_or(None).unwrap_or(false)
 }
 
+/// 解析指定的连接地址（IP 地址或主机名，IPv6 地址可带方括号）
+///
+/// 地址无效或无法解析时返回错误，而不是记为连接失败。
+async fn resolve_connect_addr(addr: &str, port: u16) -> CoreResult<Vec<SocketAddr>> {
+    let addr = addr.trim().trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = addr.parse::<IpAddr>() {
+        return Ok(vec![SocketAddr::new(ip, port)]);
+    }
+    if !matches!(ServerName::try_from(addr), Ok(ServerName::DnsName(_))) {
+        return Err(CoreError::ValidationError(format!(
+            "无效的连接地址: {addr}"
+        )));
+    }
+
+    let resolved: Vec<SocketAddr> =
+        match timeout(CONNECT_TIMEOUT, tokio::net::lookup_host((addr, port))).await {
+            Ok(Ok(addrs)) => addrs.collect(),
+            Ok(Err(e)) => {
+                return Err(CoreError::ValidationError(format!(
+                    "无法解析连接地址 {addr}: {e}"
+                )));
+            }
+            Err(_) => {
+                return Err(CoreError::ValidationError(format!(
+                    "解析连接地址 {addr} 超时"
+                )));
+            }
+        };
+    if resolved.is_empty() {
+        return Err(CoreError::ValidationError(format!(
+            "连接地址 {addr} 没有可用的 IP"
+        )));
+    }
+    Ok(resolved)
+}
+
 /// SSL 证书检查（使用 rustls 纯异步实现）
+///
+/// 指定 `connect_addr` 时 TCP 连接发往该地址，SNI 与证书域名匹配仍使用 `domain`，
+/// 用于在切换解析前检查源站返回的证书。国际化域名先转为 punycode 再连接与匹配证书。
 #[cfg(feature = "rustls")]
-pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckResult> {
+pub async fn ssl_check(
+    domain: &str,
+    port: Option<u16>,
+    connect_addr: Option<&str>,
+    probe_versions: bool,
+) -> CoreResult<SslCheckResult> {
+    let name = domain_name::normalize(domain)?;
+    let mut result = check_certificate(&name.ascii, port, connect_addr, probe_versions).await?;
+    result.unicode_domain = name.unicode_if_idn();
+    Ok(result)
+}
+
+#[cfg(feature = "rustls")]
+async fn check_certificate(
+    domain: &str,
+    port: Option<u16>,
+    connect_addr: Option<&str>,
+    probe_versions: bool,
+) -> CoreResult<SslCheckResult> {
     // 确保 CryptoProvider 已初始化
     ensure_crypto_provider();
 
     let port = port.unwrap_or(443);
     let domain = domain.to_string();
+    let connect_addrs = match connect_addr {
+        Some(addr) => Some(resolve_connect_addr(addr, port).await?),
+        None => None,
+    };
 
-    debug!("[SSL] Starting check for {domain}:{port}");
+    debug!("[SSL] Starting check for {domain}:{port} (connect_addr={connect_addr:?})");
     let start_time = std::time::Instant::now();
 
     // 1. 建立 TCP 连接（带超时）
     trace!("[SSL] Establishing TCP connection...");
-    let stream = match timeout(
-        CONNECT_TIMEOUT,
-        TcpStream::connect(format!("{domain}:{port}")),
-    )
-    .await
-    {
+    let connect = async {
+        match &connect_addrs {
+            Some(addrs) => TcpStream::connect(addrs.as_slice()).await,
+            None => TcpStream::connect(format!("{domain}:{port}")).await,
+        }
+    };
+    let stream = match timeout(CONNECT_TIMEOUT, connect).await {
         Ok(Ok(s)) => {
             trace!(
                 "[SSL] TCP connection succeeded, took {:?}",
@@ -98,7 +198,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             warn!("[SSL] TCP connection failed: {e}");
             return Ok(SslCheckResult {
                 domain,
+                unicode_domain: None,
                 port,
+                connected_addr: None,
                 connection_status: "failed".to_string(),
                 cert_info: None,
                 error: Some(format!("连接失败: {e}")),
@@ -111,7 +213,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             );
             return Ok(SslCheckResult {
                 domain,
+                unicode_domain: None,
                 port,
+                connected_addr: None,
                 connection_status: "failed".to_string(),
                 cert_info: None,
                 error: Some("连接超时".to_string()),
@@ -119,10 +223,46 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
         }
     };
 
+    let Ok(peer_addr) = stream.peer_addr() else {
+        return Ok(SslCheckResult {
+            domain,
+            unicode_domain: None,
+            port,
+            connected_addr: None,
+            connection_status: "failed".to_string(),
+            cert_info: None,
+            error: Some("无法获取连接地址".to_string()),
+        });
+    };
+    let connected_addr = Some(peer_addr.to_string());
+
     // 2. 配置 rustls 客户端
     let mut root_store = RootCertStore::empty();
     root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
 
+    // 启用 OCSP 时使用记录装订响应的验证器（证书校验仍由 webpki 完成）
+    #[cfg(feature = "ocsp")]
+    let staple_recorder = match ocsp::StapleRecorder::new(root_store) {
+        Ok(recorder) => Arc::new(recorder),
+        Err(e) => {
+            error!("[SSL] Failed to build certificate verifier: {e}");
+            return Ok(SslCheckResult {
+                domain,
+                unicode_domain: None,
+                port,
+                connected_addr: connected_addr.clone(),
+                connection_status: "failed".to_string(),
+                cert_info: None,
+                error: Some(format!("证书验证器初始化失败: {e}")),
+            });
+        }
+    };
+    #[cfg(feature = "ocsp")]
+    let config = ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(staple_recorder.clone())
+        .with_no_client_auth();
+    #[cfg(not(feature = "ocsp"))]
     let config = ClientConfig::builder()
         .with_root_certificates(root_store)
         .with_no_client_auth();
@@ -133,7 +273,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
         warn!("[SSL] Invalid domain name: {domain}");
         return Ok(SslCheckResult {
             domain,
+            unicode_domain: None,
             port,
+            connected_addr: connected_addr.clone(),
             connection_status: "failed".to_string(),
             cert_info: None,
             error: Some("无效的域名".to_string()),
@@ -157,14 +299,16 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             warn!("[SSL] TLS handshake failed: {e}");
             // TLS 握手失败，检查是否为 HTTP
             trace!("[SSL] Checking if HTTP connection...");
-            if check_http_connection(&domain, port).await {
+            if check_http_connection(&domain, peer_addr).await {
                 debug!(
                     "[SSL] Detected HTTP connection, total time {:?}",
                     start_time.elapsed()
                 );
                 return Ok(SslCheckResult {
                     domain,
+                    unicode_domain: None,
                     port,
+                    connected_addr: connected_addr.clone(),
                     connection_status: "http".to_string(),
                     cert_info: None,
                     error: None,
@@ -172,7 +316,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             }
             return Ok(SslCheckResult {
                 domain,
+                unicode_domain: None,
                 port,
+                connected_addr: connected_addr.clone(),
                 connection_status: "failed".to_string(),
                 cert_info: None,
                 error: Some(format!("TLS 握手失败: {e}")),
@@ -182,14 +328,16 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             warn!("[SSL] TLS handshake timeout ({}s)", TLS_TIMEOUT.as_secs());
             // 超时
             trace!("[SSL] Checking if HTTP connection...");
-            if check_http_connection(&domain, port).await {
+            if check_http_connection(&domain, peer_addr).await {
                 debug!(
                     "[SSL] Detected HTTP connection, total time {:?}",
                     start_time.elapsed()
                 );
                 return Ok(SslCheckResult {
                     domain,
+                    unicode_domain: None,
                     port,
+                    connected_addr: connected_addr.clone(),
                     connection_status: "http".to_string(),
                     cert_info: None,
                     error: None,
@@ -197,7 +345,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             }
             return Ok(SslCheckResult {
                 domain,
+                unicode_domain: None,
                 port,
+                connected_addr: connected_addr.clone(),
                 connection_status: "failed".to_string(),
                 cert_info: None,
                 error: Some("TLS 握手超时".to_string()),
@@ -205,6 +355,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
         }
     };
 
+    // 协议版本探测在后台进行，与证书解析并行
+    let protocol_probe = tokio::spawn(tls_probe::probe(domain.clone(), peer_addr, probe_versions));
+
     // 4. 获取证书链
     trace!("[SSL] Retrieving certificate chain...");
     let (_, tls_conn) = tls_stream.get_ref();
@@ -217,7 +370,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             warn!("[SSL] No certificates found");
             return Ok(SslCheckResult {
                 domain,
+                unicode_domain: None,
                 port,
+                connected_addr: connected_addr.clone(),
                 connection_status: "https".to_string(),
                 cert_info: None,
                 error: Some("未找到证书".to_string()),
@@ -235,7 +390,9 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
             warn!("[SSL] Certificate parsing failed: {e}");
             return Ok(SslCheckResult {
                 domain,
+                unicode_domain: None,
                 port,
+                connected_addr: connected_addr.clone(),
                 connection_status: "https".to_string(),
                 cert_info: None,
                 error: Some(format!("证书解析失败: {e}")),
@@ -244,59 +401,118 @@ pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckRe
     };
 
     // 6. 解析证书信息
-    let mut cert_info = parse_certificate(&domain, port, &cert);
+    let mut cert_info = parse_certificate(&domain, port, &cert, tls_conn);
 
-    // 7. 解析完整证书链
+    // 7. 解析完整证书链（含交叉签名的中间证书）
     cert_info.certificate_chain = certs
         .iter()
         .filter_map(|c: &CertificateDer| {
             X509Certificate::from_der(c.as_ref())
                 .ok()
-                .map(|(_, parsed)| CertChainItem {
-                    subject: parsed.subject().to_string(),
-                    issuer: parsed.issuer().to_string(),
-                    is_ca: parsed.is_ca(),
-                })
+                .map(|(_, parsed)| chain_item(&parsed))
         })
         .collect();
 
+    // 8. OCSP 吊销状态：优先使用服务器装订的响应，否则向证书中的响应器查询
+    #[cfg(feature = "ocsp")]
+    {
+        let issuer_der = certs.get(1).map(AsRef::as_ref);
+        let stapled = staple_recorder.take_stapled();
+        cert_info.ocsp_stapled = stapled.is_some();
+        cert_info.ocsp_status = Some(match (stapled, issuer_der) {
+            // 没有颁发者证书时无法校验装订响应的签名
+            (Some(response), Some(issuer_der)) => X509Certificate::from_der(issuer_der)
+                .map_err(|e| e.to_string())
+                .and_then(|(_, issuer)| ocsp::parse_response(&response, cert.raw_serial(), &issuer))
+                .unwrap_or_else(|e| {
+                    warn!("[SSL] Invalid stapled OCSP response: {e}");
+                    OcspStatus::Unknown
+                }),
+            (None, Some(issuer_der)) => check_ocsp(cert_der, issuer_der).await,
+            (_, None) => OcspStatus::NotChecked,
+        });
+    }
+
+    // 9. 等待协议版本探测结果
+    let protocols = protocol_probe.await.unwrap_or_default();
+    cert_info.supports_tls10 = protocols.tls10 == TlsVersionSupport::Accepted;
+    cert_info.supports_tls11 = protocols.tls11 == TlsVersionSupport::Accepted;
+    cert_info.protocol_probe = probe_versions.then_some(protocols);
+
     debug!(
-        "[SSL] Check completed: {} - valid={}, expired={}, days_remaining={}, chain_length={}, total_time={:?}",
+        "[SSL] Check completed: {} - valid={}, expired={}, days_remaining={}, chain_length={}, tls={:?}, legacy_tls={}/{}, ocsp={:?}, total_time={:?}",
         domain,
         cert_info.is_valid,
         cert_info.is_expired,
         cert_info.days_remaining,
         cert_info.certificate_chain.len(),
+        cert_info.tls_version,
+        cert_info.supports_tls10,
+        cert_info.supports_tls11,
+        cert_info.ocsp_status,
         start_time.elapsed()
     );
 
     Ok(SslCheckResult {
         domain: domain.clone(),
+        unicode_domain: None,
         port,
+        connected_addr,
         connection_status: "https".to_string(),
         cert_info: Some(cert_info),
         error: None,
     })
 }
 
-/// 解析证书信息
+/// 批量 SSL 证书检查
+///
+/// 最多同时检查 [`BATCH_CONCURRENCY`] 个目标，结果与输入顺序一致。
+/// 单个目标出错或超时时记为 `failed`，不影响其他目标。
+#[cfg(feature = "rustls")]
+pub async fn ssl_check_batch(targets: Vec<SslCheckTarget>) -> Vec<SslCheckResult> {
+    stream::iter(targets)
+        .map(|target| async move {
+            let port = target.port.unwrap_or(443);
+            let error = match timeout(
+                BATCH_TARGET_TIMEOUT,
+                ssl_check(&target.domain, target.port, None, false),
+            )
+            .await
+            {
+                Ok(Ok(result)) => return result,
+                Ok(Err(e)) => e.to_string(),
+                Err(_) => "检查超时".to_string(),
+            };
+            warn!(
+                "[SSL] Batch check for {}:{port} failed: {error}",
+                target.domain
+            );
+            SslCheckResult {
+                domain: target.domain,
+                unicode_domain: None,
+                port,
+                connected_addr: None,
+                connection_status: "failed".to_string(),
+                cert_info: None,
+                error: Some(error),
+            }
+        })
+        .buffered(BATCH_CONCURRENCY)
+        .collect()
+        .await
+}
+
+/// 解析证书信息与本次连接协商的协议参数
 #[cfg(feature = "rustls")]
 fn parse_certificate(
     query: &str,
     _port: u16,
     cert: &x509_parser::certificate::X509Certificate,
+    tls_conn: &ClientConnection,
 ) -> SslCertInfo {
     let subject = cert.subject().to_string();
     let issuer = cert.issuer().to_string();
-    let valid_from = cert.validity().not_before.to_rfc2822().unwrap_or_default();
-    let valid_to = cert.validity().not_after.to_rfc2822().unwrap_or_default();
-
-    // 计算剩余天数
-    let now = chrono::Utc::now();
-    let not_after = chrono::DateTime::parse_from_rfc2822(&valid_to)
-        .map(|dt| dt.with_timezone(&chrono::Utc))
-        .unwrap_or(now);
-    let days_remaining = (not_after - now).num_days();
+    let (valid_from, valid_to, days_remaining) = validity(cert);
     let is_expired = days_remaining < 0;
 
     // 提取 SAN
@@ -340,9 +556,17 @@ fn parse_certificate(
     let serial_number = cert.serial.to_str_radix(16).to_uppercase();
     let signature_algorithm = cert.signature_algorithm.algorithm.to_string();
 
-    // 证书链将在 ssl_check 函数中填充，这里先初始化为空
+    // 证书链与旧版本协议探测结果将在 ssl_check 函数中填充，这里先初始化为空
     let certificate_chain = vec![];
 
+    let tls_version = tls_conn.protocol_version().map(tls_version_name);
+    let negotiated_cipher = tls_conn.negotiated_cipher_suite().map(|suite| {
+        let suite = suite.suite();
+        suite
+            .as_str()
+            .map_or_else(|| format!("{suite:?}"), String::from)
+    });
+
     SslCertInfo {
         domain: cert_domain,
         issuer,
@@ -356,6 +580,100 @@ fn parse_certificate(
         serial_number,
         signature_algorithm,
         certificate_chain,
+        tls_version,
+        negotiated_cipher,
+        supports_tls10: false,
+        supports_tls11: false,
+        protocol_probe: None,
+        ocsp_status: None,
+        ocsp_stapled: false,
+    }
+}
+
+/// 证书有效期（RFC 2822）与剩余天数
+#[cfg(feature = "rustls")]
+fn validity(cert: &X509Certificate) -> (String, String, i64) {
+    let valid_from = cert.validity().not_before.to_rfc2822().unwrap_or_default();
+    let valid_to = cert.validity().not_after.to_rfc2822().unwrap_or_default();
+
+    let now = chrono::Utc::now();
+    let not_after = chrono::DateTime::parse_from_rfc2822(&valid_to)
+        .map_or(now, |dt| dt.with_timezone(&chrono::Utc));
+    let days_remaining = (not_after - now).num_days();
+
+    (valid_from, valid_to, days_remaining)
+}
+
+/// 证书链中的一个证书
+#[cfg(feature = "rustls")]
+fn chain_item(cert: &X509Certificate) -> CertChainItem {
+    let (valid_from, valid_to, days_remaining) = validity(cert);
+    CertChainItem {
+        subject: cert.subject().to_string(),
+        issuer: cert.issuer().to_string(),
+        is_ca: cert.is_ca(),
+        valid_from,
+        valid_to,
+        days_remaining,
+    }
+}
+
+/// 向叶子证书 AIA 扩展中的 OCSP 响应器查询吊销状态
+///
+/// `cert_der` 为叶子证书，`issuer_der` 为其颁发者证书（用于计算 `CertID`）。
+/// 证书中没有响应器地址时返回 [`OcspStatus::NotChecked`]，
+/// 请求或解析失败时返回 [`OcspStatus::Unknown`]。
+#[cfg(feature = "ocsp")]
+pub async fn check_ocsp(cert_der: &[u8], issuer_der: &[u8]) -> OcspStatus {
+    let (Ok((_, cert)), Ok((_, issuer))) = (
+        X509Certificate::from_der(cert_der),
+        X509Certificate::from_der(issuer_der),
+    ) else {
+        warn!("[SSL] Failed to parse certificates for OCSP check");
+        return OcspStatus::Unknown;
+    };
+    let Some(url) = ocsp::responder_url(&cert) else {
+        trace!("[SSL] Certificate has no OCSP responder");
+        return OcspStatus::NotChecked;
+    };
+
+    trace!("[SSL] Querying OCSP responder {url}");
+    let request = ocsp::build_request(&cert, &issuer);
+    let response = async {
+        let client = reqwest::Client::builder()
+            .timeout(OCSP_TIMEOUT)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let response = client
+            .post(&url)
+            .header(reqwest::header::CONTENT_TYPE, "application/ocsp-request")
+            .body(request)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| e.to_string())?;
+        response.bytes().await.map_err(|e| e.to_string())
+    }
+    .await;
+
+    match response.and_then(|body| ocsp::parse_response(&body, cert.raw_serial(), &issuer)) {
+        Ok(status) => status,
+        Err(e) => {
+            warn!("[SSL] OCSP check via {url} failed: {e}");
+            OcspStatus::Unknown
+        }
+    }
+}
+
+/// 协议版本的展示名称（如 `TLS 1.3`）
+#[cfg(feature = "rustls")]
+fn tls_version_name(version: ProtocolVersion) -> String {
+    match version {
+        ProtocolVersion::TLSv1_0 => "TLS 1.0".to_string(),
+        ProtocolVersion::TLSv1_1 => "TLS 1.1".to_string(),
+        ProtocolVersion::TLSv1_2 => "TLS 1.2".to_string(),
+        ProtocolVersion::TLSv1_3 => "TLS 1.3".to_string(),
+        other => format!("{other:?}"),
     }
 }
 
@@ -406,8 +724,130 @@ fn matches_domain(query: &str, pattern: &str) -> bool {
 
 /// 无 rustls 支持时的 SSL 检查（返回错误）
 #[cfg(not(feature = "rustls"))]
-pub async fn ssl_check(_domain: &str, _port: Option<u16>) -> CoreResult<SslCheckResult> {
+pub async fn ssl_check(
+    _domain: &str,
+    _port: Option<u16>,
+    _connect_addr: Option<&str>,
+    _probe_versions: bool,
+) -> CoreResult<SslCheckResult> {
     Err(CoreError::ValidationError(
         "SSL 检查功能未启用，请编译时启用 rustls feature".to_string(),
     ))
 }
+
+#[cfg(all(test, feature = "rustls"))]
+mod tests {
+    use x509_parser::pem::Pem;
+
+    use super::*;
+
+    const CROSS_SIGNED_CHAIN: &[u8] =
+        include_bytes!("../../../tests/fixtures/ssl/cross_signed_chain.pem");
+    const SELF_SIGNED: &[u8] = include_bytes!("../../../tests/fixtures/ssl/self_signed.pem");
+
+    fn parse_chain(pem: &[u8]) -> CoreResult<Vec<CertChainItem>> {
+        Pem::iter_from_buffer(pem)
+            .map(|pem| {
+                let pem = pem.map_err(|e| CoreError::ValidationError(e.to_string()))?;
+                let cert = pem
+                    .parse_x509()
+                    .map_err(|e| CoreError::ValidationError(e.to_string()))?;
+                Ok(chain_item(&cert))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cross_signed_chain_reports_validity_per_hop() -> CoreResult<()> {
+        let chain = parse_chain(CROSS_SIGNED_CHAIN)?;
+        let [leaf, intermediate, cross] = chain.as_slice() else {
+            return Err(CoreError::ValidationError(format!(
+                "expected 3 certificates, got {}",
+                chain.len()
+            )));
+        };
+
+        assert_eq!(leaf.subject, "CN=leaf.example.test");
+        assert_eq!(leaf.issuer, intermediate.subject);
+        assert!(!leaf.is_ca);
+        assert_eq!(intermediate.issuer, cross.subject);
+        assert!(intermediate.is_ca);
+        // 交叉签名：主题为新根，颁发者为旧根
+        assert_eq!(cross.subject, "CN=Test New Root");
+        assert_eq!(cross.issuer, "CN=Test Old Root");
+        assert!(cross.is_ca);
+
+        for item in &chain {
+            assert!(!item.valid_from.is_empty());
+            assert!(!item.valid_to.is_empty());
+            assert!(item.days_remaining > 0);
+        }
+        // 交叉签名证书最先到期
+        let first_expiring = chain.iter().min_by_key(|item| item.days_remaining);
+        assert_eq!(
+            first_expiring.map(|item| item.subject.as_str()),
+            Some("CN=Test New Root")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn self_signed_certificate_is_single_hop() -> CoreResult<()> {
+        let chain = parse_chain(SELF_SIGNED)?;
+        assert_eq!(chain.len(), 1);
+        assert_eq!(chain[0].subject, chain[0].issuer);
+        assert_eq!(chain[0].subject, "CN=self-signed.example.test");
+        assert!(chain[0].days_remaining > 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resolves_connect_addr() -> CoreResult<()> {
+        let v4 = resolve_connect_addr("192.0.2.10", 443).await?;
+        assert_eq!(v4, [SocketAddr::new(IpAddr::from([192, 0, 2, 10]), 443)]);
+        let v6 = resolve_connect_addr("[2001:db8::1]", 8443).await?;
+        assert_eq!(v6[0].port(), 8443);
+        assert!(v6[0].is_ipv6());
+
+        for invalid in ["", "not a host", "origin_server!"] {
+            assert!(
+                matches!(
+                    resolve_connect_addr(invalid, 443).await,
+                    Err(CoreError::ValidationError(_))
+                ),
+                "{invalid:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn connect_addr_overrides_tcp_target_but_not_domain() -> CoreResult<()> {
+        let io_err = |e: std::io::Error| CoreError::NetworkError(e.to_string());
+        // 接受连接后立即断开，握手失败
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(io_err)?;
+        let addr = listener.local_addr().map_err(io_err)?;
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                drop(stream);
+            }
+        });
+
+        let result = ssl_check(
+            "origin.example.com",
+            Some(addr.port()),
+            Some("127.0.0.1"),
+            false,
+        )
+        .await?;
+        assert_eq!(result.domain, "origin.example.com");
+        assert_eq!(
+            result.connected_addr.as_deref(),
+            Some(addr.to_string().as_str())
+        );
+        assert_eq!(result.connection_status, "failed");
+        Ok(())
+    }
+}
@@ -0,0 +1,610 @@
+//! IP 威胁情报标注
+//!
+//! 支持两类可选的情报源：
+//! - Spamhaus DROP：定期下载到本地快照，按 CIDR 前缀树离线匹配
+//! - AbuseIPDB：按 IP 调用 API，结果缓存，并限制每日请求次数以免耗尽配额
+//!
+//! 未配置任何情报源时不产生标注。
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use serde::Deserialize;
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::Instant;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{IpThreatInfo, ThreatIntelConfig};
+
+use super::external_endpoints::EndpointDeclaration;
+
+const DROP_SOURCE: &str = "Spamhaus DROP";
+const ABUSEIPDB_SOURCE: &str = "AbuseIPDB";
+
+/// Spamhaus DROP 列表（IPv4 / IPv6，每行一个 JSON 对象）
+const DROP_LIST_URLS: [&str; 2] = [
+    "https://www.spamhaus.org/drop/drop_v4.json",
+    "https://www.spamhaus.org/drop/drop_v6.json",
+];
+
+const ABUSEIPDB_CHECK_URL: &str = "https://api.abuseipdb.com/api/v2/check";
+
+/// DROP 列表下载的外发端点标识
+pub(super) const DROP_LIST_ENDPOINT: &str = "threat_intel.spamhaus_drop";
+/// `AbuseIPDB` 查询的外发端点标识
+pub(super) const ABUSEIPDB_ENDPOINT: &str = "threat_intel.abuseipdb";
+
+/// 当前配置启用的情报源访问的第三方端点
+pub(super) fn external_endpoints(config: &ThreatIntelConfig) -> Vec<EndpointDeclaration> {
+    let mut endpoints = Vec::new();
+    if config.spamhaus_drop {
+        endpoints.push(EndpointDeclaration {
+            id: DROP_LIST_ENDPOINT,
+            module: "threat_intel",
+            targets: DROP_LIST_URLS.iter().map(ToString::to_string).collect(),
+            purpose: "定期下载 Spamhaus DROP 列表",
+            sends_query_target: false,
+            essential: false,
+            // 由后台任务刷新
+            confirmable: false,
+        });
+    }
+    if config
+        .abuseipdb_api_key
+        .as_deref()
+        .is_some_and(|key| !key.trim().is_empty())
+    {
+        endpoints.push(EndpointDeclaration {
+            id: ABUSEIPDB_ENDPOINT,
+            module: "threat_intel",
+            targets: vec![ABUSEIPDB_CHECK_URL.to_string()],
+            purpose: "AbuseIPDB IP 信誉查询",
+            sends_query_target: true,
+            essential: false,
+            confirmable: true,
+        });
+    }
+    endpoints
+}
+
+/// `AbuseIPDB` 统计举报的时间范围（天）
+const ABUSEIPDB_MAX_AGE_DAYS: &str = "90";
+
+/// 请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `AbuseIPDB` 结果缓存的最大条目数
+const MAX_CACHE_ENTRIES: usize = 1024;
+
+/// `AbuseIPDB` 举报类别（<https://www.abuseipdb.com/categories>）
+const ABUSEIPDB_CATEGORIES: [&str; 23] = [
+    "DNS Compromise",
+    "DNS Poisoning",
+    "Fraud Orders",
+    "DDoS Attack",
+    "FTP Brute-Force",
+    "Ping of Death",
+    "Phishing",
+    "Fraud VoIP",
+    "Open Proxy",
+    "Web Spam",
+    "Email Spam",
+    "Blog Spam",
+    "VPN IP",
+    "Port Scan",
+    "Hacking",
+    "SQL Injection",
+    "Spoofing",
+    "Brute-Force",
+    "Bad Web Bot",
+    "Exploited Host",
+    "Web App Attack",
+    "SSH",
+    "IoT Targeted",
+];
+
+#[derive(Default)]
+struct PrefixNode {
+    children: [Option<Box<PrefixNode>>; 2],
+    /// 以该节点结尾的网段标签（SBL 编号）
+    label: Option<String>,
+}
+
+/// 二进制前缀树，地址按高位对齐存放在 `u128` 中
+#[derive(Default)]
+struct PrefixTree {
+    root: PrefixNode,
+}
+
+impl PrefixTree {
+    fn insert(&mut self, bits: u128, prefix_len: u8, label: String) {
+        let mut node = &mut self.root;
+        for depth in 0..prefix_len {
+            let bit = usize::from(bit_at(bits, depth));
+            node = node.children[bit].get_or_insert_with(Box::default);
+        }
+        node.label = Some(label);
+    }
+
+    /// 最长前缀匹配
+    fn longest_match(&self, bits: u128, max_len: u8) -> Option<&str> {
+        let mut node = &self.root;
+        let mut matched = node.label.as_deref();
+        for depth in 0..max_len {
+            let Some(child) = node.children[usize::from(bit_at(bits, depth))].as_deref() else {
+                break;
+            };
+            node = child;
+            matched = node.label.as_deref().or(matched);
+        }
+        matched
+    }
+}
+
+fn bit_at(bits: u128, depth: u8) -> u8 {
+    u8::from((bits >> (127 - u32::from(depth))) & 1 == 1)
+}
+
+/// 地址按高位对齐后的位串与地址长度
+fn address_bits(ip: IpAddr) -> (u128, u8) {
+    match ip {
+        IpAddr::V4(v4) => (u128::from(u32::from(v4)) << 96, 32),
+        IpAddr::V6(v6) => (u128::from(v6), 128),
+    }
+}
+
+/// DROP 列表 JSON 行（首行之外的元数据行没有 `cidr`）
+#[derive(Deserialize)]
+struct DropJsonLine {
+    cidr: Option<String>,
+    sblid: Option<String>,
+}
+
+/// 已加载的 DROP 列表
+#[derive(Default)]
+struct DropList {
+    v4: PrefixTree,
+    v6: PrefixTree,
+    len: usize,
+}
+
+impl DropList {
+    /// 解析 DROP 列表，兼容 JSON 行格式与旧的 `CIDR ; SBL` 文本格式
+    fn parse(text: &str) -> Self {
+        let mut list = Self::default();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            let (cidr, label) = if line.starts_with('{') {
+                match serde_json::from_str::<DropJsonLine>(line) {
+                    Ok(DropJsonLine {
+                        cidr: Some(cidr),
+                        sblid,
+                    }) => (cidr, sblid),
+                    _ => continue,
+                }
+            } else {
+                let mut parts = line.splitn(2, ';');
+                let cidr = parts.next().unwrap_or_default().trim().to_string();
+                let label = parts.next().map(|label| label.trim().to_string());
+                (cidr, label)
+            };
+            if let Some((ip, prefix_len)) = parse_cidr(&cidr) {
+                let (bits, _) = address_bits(ip);
+                let tree = if ip.is_ipv4() {
+                    &mut list.v4
+                } else {
+                    &mut list.v6
+                };
+                tree.insert(bits, prefix_len, label.unwrap_or_else(|| cidr.clone()));
+                list.len += 1;
+            }
+        }
+        list
+    }
+
+    /// 命中时返回网段的 SBL 编号
+    fn lookup(&self, ip: IpAddr) -> Option<&str> {
+        let (bits, max_len) = address_bits(ip);
+        let tree = if ip.is_ipv4() { &self.v4 } else { &self.v6 };
+        tree.longest_match(bits, max_len)
+    }
+}
+
+fn parse_cidr(cidr: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix_len) = cidr.split_once('/')?;
+    let ip: IpAddr = address.trim().parse().ok()?;
+    let prefix_len: u8 = prefix_len.trim().parse().ok()?;
+    let (_, max_len) = address_bits(ip);
+    (prefix_len <= max_len).then_some((ip, prefix_len))
+}
+
+#[derive(Deserialize)]
+struct AbuseIpDbResponse {
+    data: AbuseIpDbData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AbuseIpDbData {
+    abuse_confidence_score: u8,
+    last_reported_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    reports: Vec<AbuseIpDbReport>,
+}
+
+#[derive(Deserialize)]
+struct AbuseIpDbReport {
+    #[serde(default)]
+    categories: Vec<usize>,
+}
+
+/// `AbuseIPDB` 查询结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AbuseReport {
+    confidence: u8,
+    last_reported: Option<DateTime<Utc>>,
+    /// 举报类别，按出现次数降序
+    categories: Vec<String>,
+}
+
+impl From<AbuseIpDbData> for AbuseReport {
+    fn from(data: AbuseIpDbData) -> Self {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for id in data.reports.iter().flat_map(|r| r.categories.iter()) {
+            *counts.entry(*id).or_default() += 1;
+        }
+        let mut ids: Vec<(usize, usize)> = counts.into_iter().collect();
+        ids.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        Self {
+            confidence: data.abuse_confidence_score,
+            last_reported: data.last_reported_at,
+            categories: ids
+                .into_iter()
+                .filter_map(|(id, _)| ABUSEIPDB_CATEGORIES.get(id.checked_sub(1)?))
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// `AbuseIPDB` 每日请求配额
+struct Quota {
+    day: NaiveDate,
+    used: u32,
+    /// 服务端返回 429 后暂停请求直到该时间
+    blocked_until: Option<DateTime<Utc>>,
+}
+
+impl Quota {
+    fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            day: now.date_naive(),
+            used: 0,
+            blocked_until: None,
+        }
+    }
+
+    /// 占用一次请求配额，配额耗尽或被限流时返回 false
+    fn try_acquire(&mut self, now: DateTime<Utc>, daily_limit: u32) -> bool {
+        if self.blocked_until.is_some_and(|until| now < until) {
+            return false;
+        }
+        if self.day != now.date_naive() {
+            *self = Self::new(now);
+        }
+        if self.used >= daily_limit {
+            return false;
+        }
+        self.used += 1;
+        true
+    }
+
+    /// 被服务端限流：暂停到 `retry_after` 之后，未提供时暂停到次日 0 点（UTC）
+    fn block(&mut self, now: DateTime<Utc>, retry_after: Option<Duration>) {
+        let until = retry_after
+            .and_then(|delay| chrono::Duration::from_std(delay).ok())
+            .map(|delay| now + delay)
+            .or_else(|| {
+                now.date_naive()
+                    .checked_add_days(Days::new(1))
+                    .and_then(|day| day.and_hms_opt(0, 0, 0))
+                    .map(|midnight| midnight.and_utc())
+            });
+        self.blocked_until = until;
+    }
+}
+
+/// 威胁情报查询器
+pub(super) struct ThreatIntel {
+    config: ThreatIntelConfig,
+    /// DROP 列表本地快照路径，为空时只保存在内存中
+    drop_snapshot: Option<PathBuf>,
+    drop_list: RwLock<Option<DropList>>,
+    abuse_cache: Mutex<HashMap<IpAddr, (Instant, AbuseReport)>>,
+    quota: Mutex<Quota>,
+    client: reqwest::Client,
+}
+
+impl ThreatIntel {
+    pub(super) fn new(config: ThreatIntelConfig, drop_snapshot: Option<PathBuf>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            drop_snapshot,
+            drop_list: RwLock::new(None),
+            abuse_cache: Mutex::new(HashMap::new()),
+            quota: Mutex::new(Quota::new(Utc::now())),
+            client,
+        }
+    }
+
+    pub(super) fn config(&self) -> &ThreatIntelConfig {
+        &self.config
+    }
+
+    /// 从本地快照加载 DROP 列表（未启用或快照不存在时跳过）
+    pub(super) async fn load_drop_snapshot(&self) {
+        if !self.config.spamhaus_drop {
+            return;
+        }
+        let Some(path) = &self.drop_snapshot else {
+            return;
+        };
+        match tokio::fs::read_to_string(path).await {
+            Ok(text) => {
+                let list = DropList::parse(&text);
+                log::info!(
+                    "[ThreatIntel] Loaded {} DROP entries from {}",
+                    list.len,
+                    path.display()
+                );
+                *self.drop_list.write().await = Some(list);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => log::warn!("[ThreatIntel] Failed to read DROP snapshot: {e}"),
+        }
+    }
+
+    /// 下载最新的 DROP 列表并写入本地快照，返回网段数
+    pub(super) async fn refresh_drop_list(&self) -> CoreResult<usize> {
+        let mut text = String::new();
+        for url in DROP_LIST_URLS {
+            let body = self
+                .client
+                .get(url)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| CoreError::NetworkError(format!("下载 DROP 列表失败: {e}")))?
+                .text()
+                .await
+                .map_err(|e| CoreError::NetworkError(format!("下载 DROP 列表失败: {e}")))?;
+            text.push_str(&body);
+            text.push('\n');
+        }
+
+        let list = DropList::parse(&text);
+        if list.len == 0 {
+            return Err(CoreError::NetworkError(
+                "DROP 列表为空，保留现有快照".to_string(),
+            ));
+        }
+        if let Some(path) = &self.drop_snapshot {
+            if let Err(e) = tokio::fs::write(path, &text).await {
+                log::warn!("[ThreatIntel] Failed to write DROP snapshot: {e}");
+            }
+        }
+
+        let len = list.len;
+        *self.drop_list.write().await = Some(list);
+        log::info!("[ThreatIntel] Refreshed DROP list: {len} entries");
+        Ok(len)
+    }
+
+    /// 查询 IP 的威胁情报
+    ///
+    /// `remote_allowed` 为 false 时（隐私模式）只匹配本地 DROP 列表，不查询 `AbuseIPDB`。
+    pub(super) async fn annotate(&self, ip: IpAddr, remote_allowed: bool) -> IpThreatInfo {
+        let mut info = IpThreatInfo::default();
+
+        if self.config.spamhaus_drop {
+            if let Some(list) = self.drop_list.read().await.as_ref() {
+                info.checked_sources.push(DROP_SOURCE.to_string());
+                if let Some(sbl) = list.lookup(ip) {
+                    info.sources.push(DROP_SOURCE.to_string());
+                    info.categories.push(sbl.to_string());
+                }
+            }
+        }
+
+        let report = if remote_allowed {
+            self.abuseipdb_report(ip).await
+        } else {
+            None
+        };
+        if let Some(report) = report {
+            info.checked_sources.push(ABUSEIPDB_SOURCE.to_string());
+            info.last_reported = report.last_reported;
+            if report.confidence >= self.config.abuseipdb_min_confidence {
+                info.sources.push(ABUSEIPDB_SOURCE.to_string());
+                info.categories.extend(report.categories);
+            }
+        }
+
+        info.listed = !info.sources.is_empty();
+        info
+    }
+
+    /// 查询 AbuseIPDB（未配置、配额耗尽或请求失败时返回 `None`）
+    async fn abuseipdb_report(&self, ip: IpAddr) -> Option<AbuseReport> {
+        let key = self
+            .config
+            .abuseipdb_api_key
+            .as_deref()
+            .map(str::trim)
+            .filter(|key| !key.is_empty())?;
+
+        if let Some((expires_at, report)) = self.abuse_cache.lock().await.get(&ip) {
+            if *expires_at > Instant::now() {
+                return Some(report.clone());
+            }
+        }
+
+        if !self
+            .quota
+            .lock()
+            .await
+            .try_acquire(Utc::now(), self.config.abuseipdb_daily_limit)
+        {
+            log::debug!("[ThreatIntel] AbuseIPDB quota exhausted, skipping {ip}");
+            return None;
+        }
+
+        match self.fetch_abuseipdb(ip, key).await {
+            Ok(report) => {
+                self.cache_report(ip, report.clone()).await;
+                Some(report)
+            }
+            Err(e) => {
+                log::warn!("[ThreatIntel] AbuseIPDB lookup for {ip} failed: {e}");
+                None
+            }
+        }
+    }
+
+    async fn fetch_abuseipdb(&self, ip: IpAddr, key: &str) -> CoreResult<AbuseReport> {
+        let url = url::Url::parse_with_params(
+            ABUSEIPDB_CHECK_URL,
+            [
+                ("ipAddress", ip.to_string().as_str()),
+                ("maxAgeInDays", ABUSEIPDB_MAX_AGE_DAYS),
+                ("verbose", ""),
+            ],
+        )
+        .map_err(|e| CoreError::ValidationError(e.to_string()))?;
+
+        let response = self
+            .client
+            .get(url)
+            .header("Key", key)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| CoreError::NetworkError(format!("请求失败: {e}")))?;
+
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs);
+            self.quota.lock().await.block(Utc::now(), retry_after);
+            return Err(CoreError::NetworkError(
+                "AbuseIPDB 请求过于频繁，已暂停查询".to_string(),
+            ));
+        }
+
+        let body: AbuseIpDbResponse = response
+            .error_for_status()
+            .map_err(|e| CoreError::NetworkError(format!("请求失败: {e}")))?
+            .json()
+            .await
+            .map_err(|e| CoreError::NetworkError(format!("解析失败: {e}")))?;
+        Ok(body.data.into())
+    }
+
+    async fn cache_report(&self, ip: IpAddr, report: AbuseReport) {
+        let now = Instant::now();
+        let mut cache = self.abuse_cache.lock().await;
+        if cache.len() >= MAX_CACHE_ENTRIES {
+            cache.retain(|_, (expires_at, _)| *expires_at > now);
+        }
+        if cache.len() >= MAX_CACHE_ENTRIES {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, (expires_at, _))| *expires_at)
+                .map(|(ip, _)| *ip);
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        let ttl = Duration::from_secs(self.config.cache_ttl_secs);
+        cache.insert(ip, (now + ttl, report));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse()
+            .unwrap_or(IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED))
+    }
+
+    #[test]
+    fn drop_list_matches_longest_prefix() {
+        let list = DropList::parse(
+            "; Spamhaus DROP List\n\
+             1.10.16.0/20 ; SBL256894\n\
+             1.10.16.0/24 ; SBL000001\n\
+             {\"cidr\":\"2001:db8::/32\",\"sblid\":\"SBL300000\",\"rir\":\"ripencc\"}\n\
+             {\"type\":\"metadata\",\"timestamp\":1700000000,\"size\":3}\n\
+             not-a-cidr ; SBL999999\n",
+        );
+        assert_eq!(list.len, 3);
+        assert_eq!(list.lookup(ip("1.10.16.5")), Some("SBL000001"));
+        assert_eq!(list.lookup(ip("1.10.31.255")), Some("SBL256894"));
+        assert_eq!(list.lookup(ip("1.10.32.0")), None);
+        assert_eq!(list.lookup(ip("2001:db8:1::1")), Some("SBL300000"));
+        assert_eq!(list.lookup(ip("2001:db9::1")), None);
+    }
+
+    #[test]
+    fn rejects_invalid_prefix_length() {
+        assert_eq!(parse_cidr("10.0.0.0/33"), None);
+        assert_eq!(parse_cidr("10.0.0.0/8"), Some((ip("10.0.0.0"), 8)));
+    }
+
+    #[test]
+    fn maps_abuseipdb_categories_by_frequency() {
+        let report = AbuseReport::from(AbuseIpDbData {
+            abuse_confidence_score: 90,
+            last_reported_at: None,
+            reports: vec![
+                AbuseIpDbReport {
+                    categories: vec![18, 22],
+                },
+                AbuseIpDbReport {
+                    categories: vec![22, 99],
+                },
+            ],
+        });
+        assert_eq!(report.categories, ["SSH", "Brute-Force"]);
+    }
+
+    #[test]
+    fn quota_limits_daily_requests_and_honors_rate_limit() {
+        let now = Utc::now();
+        let mut quota = Quota::new(now);
+        assert!(quota.try_acquire(now, 2));
+        assert!(quota.try_acquire(now, 2));
+        assert!(!quota.try_acquire(now, 2));
+        // 次日重置
+        assert!(quota.try_acquire(now + chrono::Duration::days(1), 2));
+
+        let mut quota = Quota::new(now);
+        quota.block(now, Some(Duration::from_mins(1)));
+        assert!(!quota.try_acquire(now, 10));
+        assert!(quota.try_acquire(now + chrono::Duration::seconds(61), 10));
+    }
+}
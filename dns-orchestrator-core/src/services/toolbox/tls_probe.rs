@@ -0,0 +1,343 @@
+//! TLS 协议版本探测
+//!
+//! 每个版本单独建立连接探测，四个版本并行：
+//! - TLS 1.2 / 1.3：使用只启用该版本的 rustls 握手，不校验证书（只关心协议是否被接受）
+//! - TLS 1.0 / 1.1：rustls 不支持这两个版本，无法通过正常握手判断服务器是否仍接受。
+//!   这里手工构造只声明目标版本的 ClientHello，读取服务器的第一条响应：
+//!   返回同版本的 `ServerHello` 即视为接受，Alert 或断开连接视为不接受。
+//!   探测只进行到 ServerHello，不完成握手。
+//!
+//! 连接失败或超时时结果为 [`TlsVersionSupport::Unknown`]。
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme, SupportedProtocolVersion};
+use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+
+use crate::types::{TlsProtocolInfo, TlsVersionSupport};
+
+/// TLS 1.0 协议版本号
+const TLS10: u16 = 0x0301;
+/// TLS 1.1 协议版本号
+const TLS11: u16 = 0x0302;
+
+/// 单次探测的整体超时（连接 + 握手）
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// TLS 记录类型：握手
+const CONTENT_TYPE_HANDSHAKE: u8 = 0x16;
+/// 握手消息类型
+const HANDSHAKE_CLIENT_HELLO: u8 = 0x01;
+const HANDSHAKE_SERVER_HELLO: u8 = 0x02;
+
+/// TLS 1.0 / 1.1 可用的 CBC 套件（ECDHE、DHE 与 RSA 密钥交换）
+const LEGACY_CIPHER_SUITES: [u16; 9] = [
+    0xc00a, 0xc009, 0xc014, 0xc013, 0x0039, 0x0033, 0x0035, 0x002f, 0x000a,
+];
+
+/// 探测 `addr` 上的服务器接受的 TLS 版本，SNI 使用 `host`
+///
+/// `all_versions` 为 false 时只探测已弃用的 TLS 1.0 / 1.1，TLS 1.2 / 1.3 记为 `Unknown`。
+pub(super) async fn probe(host: String, addr: SocketAddr, all_versions: bool) -> TlsProtocolInfo {
+    let legacy = async {
+        tokio::join!(
+            probe_legacy(&host, addr, TLS10),
+            probe_legacy(&host, addr, TLS11)
+        )
+    };
+    let modern = async {
+        if all_versions {
+            tokio::join!(
+                probe_modern(&host, addr, &rustls::version::TLS12),
+                probe_modern(&host, addr, &rustls::version::TLS13)
+            )
+        } else {
+            (TlsVersionSupport::Unknown, TlsVersionSupport::Unknown)
+        }
+    };
+    let ((tls10, tls11), (tls12, tls13)) = tokio::join!(legacy, modern);
+
+    let info = TlsProtocolInfo {
+        tls10,
+        tls11,
+        tls12,
+        tls13,
+    };
+    log::trace!("[SSL] TLS version probe for {host} ({addr}): {info:?}");
+    info
+}
+
+/// 连接结果转换为探测结果：连接失败或超时（`None`）无法确定
+fn support(accepted: Option<bool>) -> TlsVersionSupport {
+    match accepted {
+        Some(true) => TlsVersionSupport::Accepted,
+        Some(false) => TlsVersionSupport::Rejected,
+        None => TlsVersionSupport::Unknown,
+    }
+}
+
+/// 手工 `ClientHello` 探测 `version`（[`TLS10`] 或 [`TLS11`]）
+async fn probe_legacy(host: &str, addr: SocketAddr, version: u16) -> TlsVersionSupport {
+    support(
+        timeout(PROBE_TIMEOUT, async {
+            let mut stream = TcpStream::connect(addr).await.ok()?;
+            Some(read_server_hello(&mut stream, host, version).await)
+        })
+        .await
+        .ok()
+        .flatten(),
+    )
+}
+
+/// 发送 `ClientHello`，服务器是否以同版本的 `ServerHello` 响应
+async fn read_server_hello(stream: &mut TcpStream, host: &str, version: u16) -> bool {
+    if stream
+        .write_all(&client_hello(host, version))
+        .await
+        .is_err()
+    {
+        return false;
+    }
+    let mut header = [0u8; 5];
+    if stream.read_exact(&mut header).await.is_err() || header[0] != CONTENT_TYPE_HANDSHAKE {
+        return false;
+    }
+    let mut handshake = [0u8; 6];
+    stream.read_exact(&mut handshake).await.is_ok() && is_server_hello(handshake, version)
+}
+
+/// 只启用 `version` 的 rustls 握手
+async fn probe_modern(
+    host: &str,
+    addr: SocketAddr,
+    version: &'static SupportedProtocolVersion,
+) -> TlsVersionSupport {
+    let Ok(server_name) = ServerName::try_from(host.to_string()) else {
+        return TlsVersionSupport::Unknown;
+    };
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let config = match ClientConfig::builder_with_provider(Arc::clone(&provider))
+        .with_protocol_versions(&[version])
+    {
+        Ok(builder) => builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+            .with_no_client_auth(),
+        Err(e) => {
+            log::warn!("[SSL] Failed to build probe config for {version:?}: {e}");
+            return TlsVersionSupport::Unknown;
+        }
+    };
+    let connector = TlsConnector::from(Arc::new(config));
+
+    support(
+        timeout(PROBE_TIMEOUT, async {
+            let stream = TcpStream::connect(addr).await.ok()?;
+            Some(connector.connect(server_name, stream).await.is_ok())
+        })
+        .await
+        .ok()
+        .flatten(),
+    )
+}
+
+/// 接受任意证书的验证器（握手签名仍正常校验）
+///
+/// 版本探测只关心协议是否被接受，证书问题由主检查报告。
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// 握手消息开头是否为协商到 `version` 的 `ServerHello`
+fn is_server_hello(handshake: [u8; 6], version: u16) -> bool {
+    handshake[0] == HANDSHAKE_SERVER_HELLO
+        && u16::from_be_bytes([handshake[4], handshake[5]]) == version
+}
+
+/// 构造只声明 `version` 的 `ClientHello` 记录
+fn client_hello(host: &str, version: u16) -> Vec<u8> {
+    let mut extensions = Vec::new();
+    // server_name（IP 地址不发送 SNI）
+    if host.parse::<std::net::IpAddr>().is_err() {
+        let name = host.as_bytes();
+        let mut server_name = Vec::new();
+        push_u16(&mut server_name, len_u16(name.len() + 3));
+        server_name.push(0);
+        push_u16(&mut server_name, len_u16(name.len()));
+        server_name.extend_from_slice(name);
+        push_extension(&mut extensions, 0x0000, &server_name);
+    }
+    // supported_groups: x25519, secp256r1, secp384r1
+    push_extension(
+        &mut extensions,
+        0x000a,
+        &[0, 6, 0x00, 0x1d, 0x00, 0x17, 0x00, 0x18],
+    );
+    // ec_point_formats: uncompressed
+    push_extension(&mut extensions, 0x000b, &[1, 0]);
+    // renegotiation_info（空）
+    push_extension(&mut extensions, 0xff01, &[0]);
+
+    let mut body = Vec::new();
+    push_u16(&mut body, version);
+    body.extend_from_slice(&rand::random::<[u8; 32]>());
+    // session_id 为空
+    body.push(0);
+    push_u16(&mut body, len_u16(LEGACY_CIPHER_SUITES.len() * 2));
+    for suite in LEGACY_CIPHER_SUITES {
+        push_u16(&mut body, suite);
+    }
+    // 压缩方法：null
+    body.extend_from_slice(&[1, 0]);
+    push_u16(&mut body, len_u16(extensions.len()));
+    body.extend_from_slice(&extensions);
+
+    let mut handshake = vec![HANDSHAKE_CLIENT_HELLO];
+    handshake.extend_from_slice(&u32::try_from(body.len()).unwrap_or(0).to_be_bytes()[1..]);
+    handshake.extend_from_slice(&body);
+
+    // 记录层版本固定为 TLS 1.0，兼容只认该值的旧服务器
+    let mut record = vec![CONTENT_TYPE_HANDSHAKE];
+    push_u16(&mut record, TLS10);
+    push_u16(&mut record, len_u16(handshake.len()));
+    record.extend_from_slice(&handshake);
+    record
+}
+
+fn push_extension(extensions: &mut Vec<u8>, kind: u16, data: &[u8]) {
+    push_u16(extensions, kind);
+    push_u16(extensions, len_u16(data.len()));
+    extensions.extend_from_slice(data);
+}
+
+fn push_u16(buf: &mut Vec<u8>, value: u16) {
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+/// `ClientHello` 各字段长度远小于 64 KiB
+fn len_u16(len: usize) -> u16 {
+    u16::try_from(len).unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_hello_lengths_are_consistent() {
+        let record = client_hello("example.com", TLS11);
+
+        assert_eq!(record[0], CONTENT_TYPE_HANDSHAKE);
+        assert_eq!(u16::from_be_bytes([record[1], record[2]]), TLS10);
+        let record_len = usize::from(u16::from_be_bytes([record[3], record[4]]));
+        assert_eq!(record.len(), 5 + record_len);
+
+        let handshake = &record[5..];
+        assert_eq!(handshake[0], HANDSHAKE_CLIENT_HELLO);
+        let body_len = (usize::from(handshake[2]) << 8) | usize::from(handshake[3]);
+        assert_eq!(handshake.len(), 4 + body_len);
+        assert_eq!(u16::from_be_bytes([handshake[4], handshake[5]]), TLS11);
+
+        let sni = b"example.com";
+        assert!(record.windows(sni.len()).any(|window| window == sni));
+    }
+
+    #[test]
+    fn omits_sni_for_ip_address() {
+        let with_name = client_hello("example.com", TLS10);
+        let with_ip = client_hello("192.0.2.1", TLS10);
+        assert_eq!(with_name.len() - with_ip.len(), 4 + 5 + "example.com".len());
+    }
+
+    #[test]
+    fn recognizes_server_hello_version() {
+        assert!(is_server_hello([0x02, 0, 0, 0x46, 0x03, 0x01], TLS10));
+        // 服务器降级到其他版本
+        assert!(!is_server_hello([0x02, 0, 0, 0x46, 0x03, 0x01], TLS11));
+        assert!(!is_server_hello([0x0b, 0, 0, 0x46, 0x03, 0x02], TLS11));
+    }
+
+    #[tokio::test]
+    async fn closed_connection_is_rejected_and_refused_is_unknown() -> std::io::Result<()> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        // 读取 ClientHello 后直接断开，相当于服务器拒绝该版本
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = [0u8; 512];
+                let _ = stream.read(&mut buf).await;
+            }
+        });
+
+        let info = probe("127.0.0.1".to_string(), addr, true).await;
+        assert_eq!(info.tls10, TlsVersionSupport::Rejected);
+        assert_eq!(info.tls11, TlsVersionSupport::Rejected);
+        // IP 地址可作为 rustls 的 ServerName，握手失败即为拒绝
+        assert_eq!(info.tls12, TlsVersionSupport::Rejected);
+        assert_eq!(info.tls13, TlsVersionSupport::Rejected);
+
+        // 只探测旧版本时不尝试 TLS 1.2 / 1.3
+        let legacy_only = probe("127.0.0.1".to_string(), addr, false).await;
+        assert_eq!(legacy_only.tls12, TlsVersionSupport::Unknown);
+
+        // 端口未监听：无法确定
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let closed_addr = closed.local_addr()?;
+        drop(closed);
+        let info = probe("127.0.0.1".to_string(), closed_addr, false).await;
+        assert_eq!(info.tls10, TlsVersionSupport::Unknown);
+        Ok(())
+    }
+}
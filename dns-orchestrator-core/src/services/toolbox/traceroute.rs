@@ -0,0 +1,531 @@
+//! 路径追踪（traceroute）模块
+//!
+//! 逐跳递增 TTL 发送探测包，按跳统计响应地址与往返时延（类似 MTR 的每跳统计）。
+//! 优先使用原始 ICMP 套接字（需要 root / `CAP_NET_RAW` 或管理员权限）；无法创建时回退为
+//! 向目标 443 端口发起 TCP 连接。回退模式下路由器返回的 ICMP 超时报文由内核处理，
+//! 只能得到中间跳的时延，无法得到其地址。
+
+use std::io::{self, ErrorKind};
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use log::debug;
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{TraceHop, TraceMethod, TraceRouteResult};
+
+use super::dns::reverse_lookup;
+
+/// 允许的最大跳数
+const MAX_HOPS_LIMIT: u8 = 64;
+
+/// 每跳允许的最大探测次数
+const MAX_PROBES_PER_HOP: u8 = 10;
+
+/// 单次探测的等待时间
+const PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// TCP 回退使用的目标端口
+const TCP_FALLBACK_PORT: u16 = 443;
+
+/// Echo 请求携带的数据
+const ECHO_PAYLOAD: &[u8] = b"dns-orchestrator";
+
+const ICMPV4_ECHO_REPLY: u8 = 0;
+const ICMPV4_DEST_UNREACHABLE: u8 = 3;
+const ICMPV4_ECHO_REQUEST: u8 = 8;
+const ICMPV4_TIME_EXCEEDED: u8 = 11;
+const ICMPV6_DEST_UNREACHABLE: u8 = 1;
+const ICMPV6_TIME_EXCEEDED: u8 = 3;
+const ICMPV6_ECHO_REQUEST: u8 = 128;
+const ICMPV6_ECHO_REPLY: u8 = 129;
+
+/// IPv6 固定头长度
+const IPV6_HEADER_LEN: usize = 40;
+
+/// 单次探测收到的响应
+struct Reply {
+    /// 响应方地址（TCP 回退的中间跳为空）
+    from: Option<IpAddr>,
+    rtt: Duration,
+    /// 到达目标
+    reached: bool,
+    /// 收到目标不可达
+    unreachable: bool,
+}
+
+/// 单跳的原始探测结果
+struct HopProbes {
+    ttl: u8,
+    responder: Option<IpAddr>,
+    rtts: Vec<Duration>,
+    reached: bool,
+    unreachable: bool,
+}
+
+/// 追踪到 `host` 的路径
+///
+/// 每跳发送 `probes_per_hop` 次探测（每次最多等待 1 秒），到达目标或收到目标不可达时停止。
+/// 原始套接字与 TCP 连接都不可用时返回 [`CoreError::CapabilityUnavailable`]。
+pub async fn trace_route(
+    host: &str,
+    max_hops: u8,
+    probes_per_hop: u8,
+) -> CoreResult<TraceRouteResult> {
+    // 允许 `[::1]` 形式的 IPv6 地址
+    let host = host
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    if host.is_empty() {
+        return Err(CoreError::ValidationError("主机不能为空".to_string()));
+    }
+    if max_hops == 0 || max_hops > MAX_HOPS_LIMIT {
+        return Err(CoreError::ValidationError(format!(
+            "最大跳数必须在 1 到 {MAX_HOPS_LIMIT} 之间"
+        )));
+    }
+    if probes_per_hop == 0 || probes_per_hop > MAX_PROBES_PER_HOP {
+        return Err(CoreError::ValidationError(format!(
+            "每跳探测次数必须在 1 到 {MAX_PROBES_PER_HOP} 之间"
+        )));
+    }
+
+    let target = resolve(&host).await?;
+    debug!("[Trace] Tracing route to {host} ({target}), max_hops={max_hops}");
+
+    // 套接字操作是阻塞的，整个探测过程放到阻塞线程池中执行
+    let (method, fallback_reason, hops) =
+        tokio::task::spawn_blocking(move || run_probes(target, max_hops, probes_per_hop))
+            .await
+            .map_err(|e| CoreError::NetworkError(format!("Traceroute task failed: {e}")))??;
+
+    let hostnames = join_all(hops.iter().map(|hop| async move {
+        match hop.responder {
+            Some(ip) => reverse_hostname(ip).await,
+            None => None,
+        }
+    }))
+    .await;
+
+    let reached = hops.iter().any(|hop| hop.reached);
+    let hops: Vec<TraceHop> = hops
+        .iter()
+        .zip(hostnames)
+        .map(|(hop, hostname)| hop_stats(hop, hostname, probes_per_hop))
+        .collect();
+    debug!(
+        "[Trace] Completed {host}: method={method:?}, hops={}, reached={reached}",
+        hops.len()
+    );
+
+    Ok(TraceRouteResult {
+        host,
+        target_ip: target.to_string(),
+        method,
+        fallback_reason,
+        hops,
+        reached,
+    })
+}
+
+/// 解析目标地址，有 IPv4 地址时优先使用
+async fn resolve(host: &str) -> CoreResult<IpAddr> {
+    if let Ok(ip) = host.parse() {
+        return Ok(ip);
+    }
+    let addrs: Vec<IpAddr> = tokio::net::lookup_host((host, 0))
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("Failed to resolve {host}: {e}")))?
+        .map(|addr| addr.ip())
+        .collect();
+    addrs
+        .iter()
+        .find(|ip| ip.is_ipv4())
+        .or_else(|| addrs.first())
+        .copied()
+        .ok_or_else(|| CoreError::NetworkError(format!("No addresses found for {host}")))
+}
+
+/// 反向解析响应方的主机名，失败时忽略
+async fn reverse_hostname(ip: IpAddr) -> Option<String> {
+    let result = reverse_lookup(&ip.to_string(), None).await.ok()?;
+    result
+        .ptr_records
+        .into_iter()
+        .next()
+        .map(|record| record.hostname.trim_end_matches('.').to_string())
+}
+
+/// 执行探测：优先原始 ICMP，无法创建原始套接字时回退到 TCP
+fn run_probes(
+    target: IpAddr,
+    max_hops: u8,
+    probes_per_hop: u8,
+) -> CoreResult<(TraceMethod, Option<String>, Vec<HopProbes>)> {
+    let icmp_error = match IcmpProber::open(target) {
+        Ok(prober) => {
+            let hops = trace_hops(max_hops, probes_per_hop, |ttl, seq| prober.probe(ttl, seq))
+                .map_err(|e| CoreError::NetworkError(format!("ICMP probe failed: {e}")))?;
+            return Ok((TraceMethod::Icmp, None, hops));
+        }
+        Err(e) => e,
+    };
+
+    debug!("[Trace] Raw ICMP socket unavailable ({icmp_error}), falling back to TCP");
+    let destination = SocketAddr::new(target, TCP_FALLBACK_PORT);
+    let hops = trace_hops(max_hops, probes_per_hop, |ttl, _| {
+        tcp_probe(destination, ttl)
+    })
+    .map_err(|e| match e.kind() {
+        ErrorKind::PermissionDenied | ErrorKind::Unsupported => CoreError::CapabilityUnavailable {
+            capability: "raw ICMP socket or outbound TCP connection".to_string(),
+            detail: format!("ICMP: {icmp_error}; TCP: {e}"),
+        },
+        _ => CoreError::NetworkError(format!("TCP probe failed: {e}")),
+    })?;
+    Ok((
+        TraceMethod::Tcp,
+        Some(format!("Raw ICMP socket unavailable: {icmp_error}")),
+        hops,
+    ))
+}
+
+/// 逐跳探测，`probe` 接收 TTL 与序号，超时返回 `Ok(None)`
+fn trace_hops(
+    max_hops: u8,
+    probes_per_hop: u8,
+    mut probe: impl FnMut(u8, u16) -> io::Result<Option<Reply>>,
+) -> io::Result<Vec<HopProbes>> {
+    let mut hops = Vec::new();
+    let mut seq: u16 = 0;
+    for ttl in 1..=max_hops {
+        let mut hop = HopProbes {
+            ttl,
+            responder: None,
+            rtts: Vec::new(),
+            reached: false,
+            unreachable: false,
+        };
+        for _ in 0..probes_per_hop {
+            seq = seq.wrapping_add(1);
+            if let Some(reply) = probe(ttl, seq)? {
+                hop.responder = hop.responder.or(reply.from);
+                hop.rtts.push(reply.rtt);
+                hop.reached |= reply.reached;
+                hop.unreachable |= reply.unreachable;
+            }
+        }
+        let done = hop.reached || hop.unreachable;
+        hops.push(hop);
+        if done {
+            break;
+        }
+    }
+    Ok(hops)
+}
+
+/// 汇总单跳的时延统计
+fn hop_stats(hop: &HopProbes, hostname: Option<String>, probes_per_hop: u8) -> TraceHop {
+    let sent = u32::from(probes_per_hop);
+    let received = u32::try_from(hop.rtts.len()).unwrap_or(u32::MAX);
+    let rtts_ms: Vec<f64> = hop
+        .rtts
+        .iter()
+        .map(|rtt| rtt.as_secs_f64() * 1000.0)
+        .collect();
+
+    TraceHop {
+        ttl: hop.ttl,
+        ip: hop.responder.map(|ip| ip.to_string()),
+        hostname,
+        sent,
+        received,
+        loss_rate: f64::from(sent.saturating_sub(received)) * 100.0 / f64::from(sent),
+        min_ms: rtts_ms.iter().copied().reduce(f64::min),
+        avg_ms: (received > 0).then(|| rtts_ms.iter().sum::<f64>() / f64::from(received)),
+        max_ms: rtts_ms.iter().copied().reduce(f64::max),
+        unreachable: hop.unreachable,
+    }
+}
+
+/// 设置探测包的 TTL（IPv6 为跳数限制）
+fn set_hop_limit(socket: &Socket, target: IpAddr, ttl: u8) -> io::Result<()> {
+    match target {
+        IpAddr::V4(_) => socket.set_ttl_v4(u32::from(ttl)),
+        IpAddr::V6(_) => socket.set_unicast_hops_v6(u32::from(ttl)),
+    }
+}
+
+/// 原始 ICMP 套接字探测
+struct IcmpProber {
+    /// 原始套接字转为标准库套接字，以便安全地读取数据报
+    socket: UdpSocket,
+    target: IpAddr,
+    /// Echo 标识符，用于从套接字收到的所有 ICMP 报文中筛选本次追踪的响应
+    identifier: u16,
+}
+
+impl IcmpProber {
+    fn open(target: IpAddr) -> io::Result<Self> {
+        let (domain, protocol) = match target {
+            IpAddr::V4(_) => (Domain::IPV4, Protocol::ICMPV4),
+            IpAddr::V6(_) => (Domain::IPV6, Protocol::ICMPV6),
+        };
+        let socket = Socket::new(domain, Type::RAW, Some(protocol))?;
+        Ok(Self {
+            socket: socket.into(),
+            target,
+            identifier: rand::random(),
+        })
+    }
+
+    fn probe(&self, ttl: u8, seq: u16) -> io::Result<Option<Reply>> {
+        set_hop_limit(&SockRef::from(&self.socket), self.target, ttl)?;
+        let packet = echo_request(self.target.is_ipv4(), self.identifier, seq);
+        let start = Instant::now();
+        self.socket
+            .send_to(&packet, SocketAddr::new(self.target, 0))?;
+
+        let mut buf = [0u8; 1500];
+        loop {
+            let remaining = PROBE_TIMEOUT.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            self.socket.set_read_timeout(Some(remaining))?;
+            let (len, from) = match self.socket.recv_from(&mut buf) {
+                Ok(received) => received,
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    return Ok(None);
+                }
+                Err(e) => return Err(e),
+            };
+            if let Some(kind) =
+                match_reply(&buf[..len], self.target.is_ipv4(), self.identifier, seq)
+            {
+                return Ok(Some(Reply {
+                    from: Some(from.ip()),
+                    rtt: start.elapsed(),
+                    reached: kind == ReplyKind::EchoReply,
+                    unreachable: kind == ReplyKind::Unreachable,
+                }));
+            }
+        }
+    }
+}
+
+/// TCP 连接探测（回退方式）
+///
+/// 连接成功或被拒绝说明已到达目标；其他连接错误通常是中间路由器返回了 ICMP 超时，
+/// 记录时延但地址未知；超时视为丢包。
+fn tcp_probe(destination: SocketAddr, ttl: u8) -> io::Result<Option<Reply>> {
+    let socket = Socket::new(
+        Domain::for_address(destination),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    set_hop_limit(&socket, destination.ip(), ttl)?;
+
+    let start = Instant::now();
+    let result = socket.connect_timeout(&destination.into(), PROBE_TIMEOUT);
+    let rtt = start.elapsed();
+    let reply = |from, reached| Reply {
+        from,
+        rtt,
+        reached,
+        unreachable: false,
+    };
+    let Err(error) = result else {
+        return Ok(Some(reply(Some(destination.ip()), true)));
+    };
+    match error.kind() {
+        ErrorKind::ConnectionRefused => Ok(Some(reply(Some(destination.ip()), true))),
+        ErrorKind::TimedOut | ErrorKind::WouldBlock => Ok(None),
+        ErrorKind::PermissionDenied | ErrorKind::Unsupported => Err(error),
+        _ => Ok(Some(reply(None, false))),
+    }
+}
+
+/// 与本次探测匹配的 ICMP 响应类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReplyKind {
+    EchoReply,
+    TimeExceeded,
+    Unreachable,
+}
+
+/// 构造 Echo 请求；ICMPv6 的校验和由内核计算
+fn echo_request(ipv4: bool, identifier: u16, seq: u16) -> Vec<u8> {
+    let kind = if ipv4 {
+        ICMPV4_ECHO_REQUEST
+    } else {
+        ICMPV6_ECHO_REQUEST
+    };
+    let mut packet = vec![kind, 0, 0, 0];
+    packet.extend_from_slice(&identifier.to_be_bytes());
+    packet.extend_from_slice(&seq.to_be_bytes());
+    packet.extend_from_slice(ECHO_PAYLOAD);
+    if ipv4 {
+        let checksum = internet_checksum(&packet);
+        packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+    }
+    packet
+}
+
+/// RFC 1071 校验和
+fn internet_checksum(data: &[u8]) -> u16 {
+    let mut sum: u32 = data
+        .chunks(2)
+        .map(|chunk| {
+            u32::from(u16::from_be_bytes([
+                chunk[0],
+                chunk.get(1).copied().unwrap_or(0),
+            ]))
+        })
+        .sum();
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !u16::try_from(sum).unwrap_or(u16::MAX)
+}
+
+/// 解析收到的 ICMP 报文，属于本次探测时返回响应类型
+///
+/// IPv4 原始套接字收到的数据包含 IP 头，IPv6 不包含。超时与不可达报文中附带原始探测包
+/// （IPv4 为 IP 头加至少 8 字节，IPv6 为固定头加数据），据此匹配标识符与序号。
+fn match_reply(packet: &[u8], ipv4: bool, identifier: u16, seq: u16) -> Option<ReplyKind> {
+    let icmp = if ipv4 {
+        strip_ipv4_header(packet)?
+    } else {
+        packet
+    };
+    let kind = match (ipv4, *icmp.first()?) {
+        (true, ICMPV4_ECHO_REPLY) | (false, ICMPV6_ECHO_REPLY) => {
+            return echo_matches(icmp, identifier, seq).then_some(ReplyKind::EchoReply);
+        }
+        (true, ICMPV4_TIME_EXCEEDED) | (false, ICMPV6_TIME_EXCEEDED) => ReplyKind::TimeExceeded,
+        (true, ICMPV4_DEST_UNREACHABLE) | (false, ICMPV6_DEST_UNREACHABLE) => {
+            ReplyKind::Unreachable
+        }
+        _ => return None,
+    };
+
+    let original = icmp.get(8..)?;
+    let original_icmp = if ipv4 {
+        strip_ipv4_header(original)?
+    } else {
+        original.get(IPV6_HEADER_LEN..)?
+    };
+    echo_matches(original_icmp, identifier, seq).then_some(kind)
+}
+
+fn strip_ipv4_header(packet: &[u8]) -> Option<&[u8]> {
+    let header_len = usize::from(packet.first()? & 0x0f) * 4;
+    packet.get(header_len..)
+}
+
+/// Echo 报文的标识符与序号（第 4–7 字节）是否匹配
+fn echo_matches(icmp: &[u8], identifier: u16, seq: u16) -> bool {
+    let mut expected = [0u8; 4];
+    expected[..2].copy_from_slice(&identifier.to_be_bytes());
+    expected[2..].copy_from_slice(&seq.to_be_bytes());
+    icmp.get(4..8) == Some(&expected[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 构造带 20 字节 IPv4 头的数据包
+    fn ipv4_packet(payload: &[u8]) -> Vec<u8> {
+        let mut packet = vec![0x45];
+        packet.resize(20, 0);
+        packet.extend_from_slice(payload);
+        packet
+    }
+
+    #[test]
+    fn echo_request_checksum_verifies() {
+        let packet = echo_request(true, 0x1234, 7);
+        assert_eq!(packet[0], ICMPV4_ECHO_REQUEST);
+        // 含校验和的报文重新计算结果为 0
+        assert_eq!(internet_checksum(&packet), 0);
+    }
+
+    #[test]
+    fn matches_replies_for_this_probe_only() {
+        let request = echo_request(true, 0x1234, 7);
+
+        let mut reply = request.clone();
+        reply[0] = ICMPV4_ECHO_REPLY;
+        assert_eq!(
+            match_reply(&ipv4_packet(&reply), true, 0x1234, 7),
+            Some(ReplyKind::EchoReply)
+        );
+        assert_eq!(match_reply(&ipv4_packet(&reply), true, 0x1234, 8), None);
+
+        // 超时报文：ICMP 头 8 字节 + 原始 IP 头 + 原始 Echo 请求
+        let mut time_exceeded = vec![ICMPV4_TIME_EXCEEDED, 0, 0, 0, 0, 0, 0, 0];
+        time_exceeded.extend_from_slice(&ipv4_packet(&request));
+        assert_eq!(
+            match_reply(&ipv4_packet(&time_exceeded), true, 0x1234, 7),
+            Some(ReplyKind::TimeExceeded)
+        );
+        assert_eq!(
+            match_reply(&ipv4_packet(&time_exceeded), true, 0x4321, 7),
+            None
+        );
+
+        // IPv6 不含 IP 头，附带的原始包为 40 字节固定头 + 数据
+        let request = echo_request(false, 0x1234, 7);
+        let mut unreachable = vec![ICMPV6_DEST_UNREACHABLE, 0, 0, 0, 0, 0, 0, 0];
+        unreachable.extend_from_slice(&[0u8; IPV6_HEADER_LEN]);
+        unreachable.extend_from_slice(&request);
+        assert_eq!(
+            match_reply(&unreachable, false, 0x1234, 7),
+            Some(ReplyKind::Unreachable)
+        );
+    }
+
+    #[test]
+    fn trace_stops_at_destination() -> CoreResult<()> {
+        let hops = trace_hops(30, 2, |ttl, _| {
+            Ok((ttl != 2).then(|| Reply {
+                from: None,
+                rtt: Duration::from_millis(u64::from(ttl) * 10),
+                reached: ttl == 3,
+                unreachable: false,
+            }))
+        })
+        .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+        assert_eq!(hops.len(), 3);
+
+        let stats: Vec<TraceHop> = hops.iter().map(|hop| hop_stats(hop, None, 2)).collect();
+        assert_eq!(stats[0].received, 2);
+        assert_eq!(stats[1].received, 0);
+        assert!((stats[1].loss_rate - 100.0).abs() < f64::EPSILON);
+        assert_eq!(stats[1].avg_ms, None);
+        assert!(stats[2].min_ms.is_some_and(|ms| (ms - 30.0).abs() < 1e-6));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_arguments() {
+        assert!(matches!(
+            trace_route("", 30, 3).await,
+            Err(CoreError::ValidationError(_))
+        ));
+        assert!(matches!(
+            trace_route("example.com", MAX_HOPS_LIMIT + 1, 3).await,
+            Err(CoreError::ValidationError(_))
+        ));
+        assert!(matches!(
+            trace_route("example.com", 30, 0).await,
+            Err(CoreError::ValidationError(_))
+        ));
+    }
+}
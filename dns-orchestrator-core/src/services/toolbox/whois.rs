@@ -1,31 +1,218 @@
 //! WHOIS 查询模块
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+use chrono::Utc;
 use regex::Regex;
-use whois_rust::{WhoIs, WhoIsLookupOptions};
+use whois_rust::{WhoIs, WhoIsLookupOptions, WhoIsServerValue};
 
 use crate::error::{CoreError, CoreResult};
 use crate::types::WhoisResult;
 
+use super::external_endpoints::EndpointDeclaration;
+use super::rdap;
+
+/// WHOIS 查询的外发端点标识
+pub(super) const WHOIS_ENDPOINT: &str = "whois.servers";
+
+/// 单个 WHOIS 服务器的查询超时（注册商服务器无响应时不拖住整个查询）
+const QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 最多跟随的注册商转介次数
+const MAX_REFERRAL_DEPTH: usize = 2;
+
+/// 本模块访问的第三方端点：配置中的各 TLD WHOIS 服务器
+pub(super) fn external_endpoints(whois_servers: &str) -> Vec<EndpointDeclaration> {
+    let servers: BTreeMap<String, serde_json::Value> =
+        serde_json::from_str(whois_servers).unwrap_or_default();
+    let hosts: BTreeSet<String> = servers
+        .values()
+        .filter_map(server_host)
+        .map(|host| format!("{host}:43"))
+        .collect();
+
+    vec![EndpointDeclaration {
+        id: WHOIS_ENDPOINT,
+        module: "whois",
+        targets: hosts.into_iter().collect(),
+        purpose: "查询域名注册信息（可能跟随转介访问注册商的 WHOIS 服务器）",
+        sends_query_target: true,
+        essential: true,
+        confirmable: true,
+    }]
+}
+
 /// WHOIS 查询
-pub async fn whois_lookup(domain: &str, whois_servers: &str) -> CoreResult<WhoisResult> {
+///
+/// WHOIS 查询失败、返回空响应或没有名称服务器时回退到 RDAP；RDAP 也失败时返回 WHOIS 的结果。
+pub async fn whois_lookup(
+    domain: &str,
+    whois_servers: &str,
+    rdap_bootstrap: &str,
+) -> CoreResult<WhoisResult> {
+    let whois_result = query_whois(domain, whois_servers).await;
+    if let Ok(result) = &whois_result {
+        if !result.raw.trim().is_empty() && !result.name_servers.is_empty() {
+            return whois_result;
+        }
+    }
+
+    match rdap::rdap_lookup(domain, rdap_bootstrap).await {
+        Ok(result) => Ok(result),
+        Err(e) => {
+            log::debug!("RDAP fallback failed for {domain}: {e}");
+            whois_result
+        }
+    }
+}
+
+/// 查询注册局 WHOIS，并跟随响应中的注册商转介（最多 [`MAX_REFERRAL_DEPTH`] 次）
+///
+/// 转介查询失败或超时时保留已有结果。各服务器的原始响应依次拼接在 `raw` 中。
+async fn query_whois(domain: &str, whois_servers: &str) -> CoreResult<WhoisResult> {
     let whois = WhoIs::from_string(whois_servers)
         .map_err(|e| CoreError::NetworkError(format!("初始化 WHOIS 客户端失败: {e}")))?;
 
-    let options = WhoIsLookupOptions::from_string(domain)
+    let raw = lookup_once(&whois, domain, None).await?;
+    let mut result = parse_whois_response(domain, &raw);
+    let mut servers: Vec<String> = registry_server(domain, whois_servers).into_iter().collect();
+
+    let mut last_raw = raw;
+    for _ in 0..MAX_REFERRAL_DEPTH {
+        let Some(referral) = extract_referral(&last_raw) else {
+            break;
+        };
+        if servers
+            .iter()
+            .any(|server| server.eq_ignore_ascii_case(&referral))
+        {
+            break;
+        }
+        match lookup_once(&whois, domain, Some(&referral)).await {
+            Ok(raw) if !raw.trim().is_empty() => {
+                let raw_so_far = std::mem::take(&mut result.raw);
+                result = merge_referral(result, parse_whois_response(domain, &raw));
+                result.raw = format!("{raw_so_far}\n\n% ---------- {referral} ----------\n\n{raw}");
+                servers.push(referral);
+                last_raw = raw;
+            }
+            Ok(_) => break,
+            Err(e) => {
+                log::debug!("[Whois] Referral to {referral} failed for {domain}: {e}");
+                break;
+            }
+        }
+    }
+
+    result.queried_servers = servers;
+    Ok(result)
+}
+
+/// 单次 WHOIS 查询，`server` 为空时使用配置中 TLD 对应的服务器
+async fn lookup_once(whois: &WhoIs, domain: &str, server: Option<&str>) -> CoreResult<String> {
+    let mut options = WhoIsLookupOptions::from_string(domain)
         .map_err(|e| CoreError::ValidationError(format!("无效的域名: {e}")))?;
+    // 转介由 `query_whois` 处理，以便限制深度并保留每一跳的响应
+    options.follow = 0;
+    if let Some(server) = server {
+        options.server =
+            Some(WhoIsServerValue::from_string(server).map_err(|e| {
+                CoreError::NetworkError(format!("无效的 WHOIS 服务器 {server}: {e}"))
+            })?);
+    }
 
-    let raw = whois
-        .lookup_async(options)
+    tokio::time::timeout(QUERY_TIMEOUT, whois.lookup_async(options))
         .await
-        .map_err(|e| CoreError::NetworkError(format!("WHOIS 查询失败: {e}")))?;
+        .map_err(|_| {
+            CoreError::NetworkError(format!("WHOIS 查询超时: {}", server.unwrap_or(domain)))
+        })?
+        .map_err(|e| CoreError::NetworkError(format!("WHOIS 查询失败: {e}")))
+}
+
+/// 配置项中的服务器主机名（值为字符串或带 `host` 的对象）
+fn server_host(server: &serde_json::Value) -> Option<String> {
+    match server {
+        serde_json::Value::String(host) => Some(host.clone()),
+        serde_json::Value::Object(options) => options
+            .get("host")
+            .and_then(serde_json::Value::as_str)
+            .map(ToString::to_string),
+        _ => None,
+    }
+}
+
+/// 配置中域名所属 TLD 的注册局 WHOIS 服务器（最长后缀优先，未配置的 TLD 使用 `""` 项）
+fn registry_server(domain: &str, whois_servers: &str) -> Option<String> {
+    let servers: BTreeMap<String, serde_json::Value> =
+        serde_json::from_str(whois_servers).unwrap_or_default();
+    let domain = domain.trim_end_matches('.').to_lowercase();
+
+    let mut rest = domain.as_str();
+    while let Some((_, suffix)) = rest.split_once('.') {
+        if let Some(server) = servers.get(suffix) {
+            return server_host(server);
+        }
+        rest = suffix;
+    }
+    servers.get("").and_then(server_host)
+}
+
+/// 提取转介的 WHOIS 服务器（注册局响应中的 `Registrar WHOIS Server:`，IANA 响应中的 `whois:`）
+fn extract_referral(raw: &str) -> Option<String> {
+    let server = extract_field(
+        raw,
+        &[
+            r"(?im)^[ \t]*Registrar WHOIS Server:[ \t]*(\S+)",
+            r"(?im)^[ \t]*whois:[ \t]*(\S+)",
+        ],
+    )?;
+    let server = server
+        .trim_start_matches("whois://")
+        .trim_end_matches('/')
+        .to_lowercase();
+    // 部分注册局在该字段填写网页地址，不是 WHOIS 服务器
+    (!server.is_empty() && !server.contains("://")).then_some(server)
+}
 
-    Ok(parse_whois_response(domain, &raw))
+/// 合并注册商的 WHOIS 响应
+///
+/// 注册人、滥用投诉联系方式与日期以注册商为准；注册商名称、名称服务器、状态与 DNSSEC
+/// 以注册局为准，注册局缺失时才用注册商的值。`raw` 由调用方拼接。
+fn merge_referral(registry: WhoisResult, registrar: WhoisResult) -> WhoisResult {
+    WhoisResult {
+        registrant_org: registrar.registrant_org.or(registry.registrant_org),
+        registrant_country: registrar.registrant_country.or(registry.registrant_country),
+        registrar_abuse_email: registrar
+            .registrar_abuse_email
+            .or(registry.registrar_abuse_email),
+        registrar_abuse_phone: registrar
+            .registrar_abuse_phone
+            .or(registry.registrar_abuse_phone),
+        creation_date: registrar.creation_date.or(registry.creation_date),
+        expiration_date: registrar.expiration_date.or(registry.expiration_date),
+        updated_date: registrar.updated_date.or(registry.updated_date),
+        registrar: registry.registrar.or(registrar.registrar),
+        name_servers: if registry.name_servers.is_empty() {
+            registrar.name_servers
+        } else {
+            registry.name_servers
+        },
+        status: if registry.status.is_empty() {
+            registrar.status
+        } else {
+            registry.status
+        },
+        dnssec: registry.dnssec.or(registrar.dnssec),
+        ..registry
+    }
 }
 
 /// 解析 WHOIS 原始响应
 fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
     WhoisResult {
         domain: domain.to_string(),
+        unicode_domain: None,
         registrar: extract_field(
             raw,
             &[
@@ -34,6 +221,27 @@ fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
                 r"(?i)Sponsoring Registrar:\s*(.+)",
             ],
         ),
+        // 值为空的字段不能跨行匹配到下一行，故用 `[ \t]*`；nic.uk 的值在标签的下一行
+        registrant_org: extract_field(
+            raw,
+            &[
+                r"(?im)^[ \t]*Registrant Organi[sz]ation:[ \t]*(\S.*)",
+                r"(?im)^[ \t]*Registrant:[ \t]*(\S.*)",
+                r"(?im)^[ \t]*Registrant:[ \t]*\r?\n[ \t]+(\S.*)",
+            ],
+        ),
+        registrant_country: extract_field(
+            raw,
+            &[r"(?im)^[ \t]*Registrant Country(?:/Economy)?:[ \t]*(\S.*)"],
+        ),
+        registrar_abuse_email: extract_field(
+            raw,
+            &[r"(?im)^[ \t]*Registrar Abuse Contact Email:[ \t]*(\S.*)"],
+        ),
+        registrar_abuse_phone: extract_field(
+            raw,
+            &[r"(?im)^[ \t]*Registrar Abuse Contact Phone:[ \t]*(\S.*)"],
+        ),
         creation_date: extract_field(
             raw,
             &[
@@ -63,7 +271,17 @@ fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
         ),
         name_servers: extract_name_servers(raw),
         status: extract_status(raw),
+        dnssec: extract_field(
+            raw,
+            &[
+                r"(?im)^[ \t]*DNSSEC:[ \t]*(\S.*)",
+                r"(?im)^[ \t]*DNSSEC:[ \t]*\r?\n[ \t]+(\S.*)",
+            ],
+        ),
+        queried_servers: Vec::new(),
         raw: raw.to_string(),
+        cached: false,
+        fetched_at: Utc::now(),
     }
 }
 
@@ -138,3 +356,137 @@ fn extract_status(text: &str) -> Vec<String> {
 
     statuses
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COM: &str = include_str!("../../../tests/fixtures/whois/com.txt");
+    const CN: &str = include_str!("../../../tests/fixtures/whois/cn.txt");
+    const UK: &str = include_str!("../../../tests/fixtures/whois/uk.txt");
+    const IO: &str = include_str!("../../../tests/fixtures/whois/io.txt");
+    const COM_REGISTRAR: &str = include_str!("../../../tests/fixtures/whois/com-registrar.txt");
+
+    #[test]
+    fn parses_verisign_response() {
+        let result = parse_whois_response("example-shop.com", COM);
+
+        assert_eq!(result.registrar.as_deref(), Some("Example Registrar, LLC"));
+        assert_eq!(
+            result.registrar_abuse_email.as_deref(),
+            Some("abuse@example-registrar.com")
+        );
+        assert_eq!(
+            result.registrar_abuse_phone.as_deref(),
+            Some("+1.4805058800")
+        );
+        assert_eq!(result.dnssec.as_deref(), Some("unsigned"));
+        // 注册局的精简响应不含注册人信息
+        assert_eq!(result.registrant_org, None);
+        assert_eq!(result.registrant_country, None);
+        assert_eq!(result.raw, COM);
+    }
+
+    #[test]
+    fn merges_registrar_referral() {
+        assert_eq!(
+            extract_referral(COM).as_deref(),
+            Some("whois.example-registrar.com")
+        );
+        // 字段为空或填写网页地址时不跟随
+        assert_eq!(
+            extract_referral("Registrar WHOIS Server: \nDomain Status: ok"),
+            None
+        );
+        assert_eq!(
+            extract_referral("Registrar WHOIS Server: https://whois.example-registrar.com/"),
+            None
+        );
+
+        let result = merge_referral(
+            parse_whois_response("example-shop.com", COM),
+            parse_whois_response("example-shop.com", COM_REGISTRAR),
+        );
+        // 注册人与日期取注册商的值
+        assert_eq!(result.registrant_org.as_deref(), Some("Example Shop Inc."));
+        assert_eq!(result.registrant_country.as_deref(), Some("US"));
+        assert_eq!(
+            result.expiration_date.as_deref(),
+            Some("2026-08-13T04:00:00.0Z")
+        );
+        // 注册局已有的字段不被覆盖
+        assert_eq!(result.registrar.as_deref(), Some("Example Registrar, LLC"));
+        assert_eq!(
+            result.name_servers,
+            ["ns1.example-dns.net", "ns2.example-dns.net"]
+        );
+        assert_eq!(result.raw, COM);
+    }
+
+    #[test]
+    fn finds_registry_server_by_longest_suffix() {
+        let servers = r#"{
+            "": "whois.iana.org",
+            "com": {"host": "whois.verisign-grs.com", "query": "DOMAIN $addr\r\n"},
+            "uk": "whois.nic.uk",
+            "br.com": "whois.centralnic.net",
+            "xyz": null
+        }"#;
+        assert_eq!(
+            registry_server("Example.COM.", servers).as_deref(),
+            Some("whois.verisign-grs.com")
+        );
+        assert_eq!(
+            registry_server("shop.br.com", servers).as_deref(),
+            Some("whois.centralnic.net")
+        );
+        assert_eq!(
+            registry_server("example.co.uk", servers).as_deref(),
+            Some("whois.nic.uk")
+        );
+        assert_eq!(
+            registry_server("example.dev", servers).as_deref(),
+            Some("whois.iana.org")
+        );
+        assert_eq!(registry_server("example.xyz", servers), None);
+    }
+
+    #[test]
+    fn parses_donuts_style_registrant_contact() {
+        let result = parse_whois_response("example-app.io", IO);
+
+        assert_eq!(result.registrant_org.as_deref(), Some("Example Apps Ltd"));
+        assert_eq!(result.registrant_country.as_deref(), Some("GB"));
+        assert_eq!(
+            result.registrar_abuse_email.as_deref(),
+            Some("abuse@example-registrar.com")
+        );
+        assert_eq!(
+            result.registrar_abuse_phone.as_deref(),
+            Some("+1.2025550123")
+        );
+        assert_eq!(result.dnssec.as_deref(), Some("signedDelegation"));
+        assert_eq!(result.raw, IO);
+    }
+
+    #[test]
+    fn parses_cnnic_response() {
+        let result = parse_whois_response("example.cn", CN);
+
+        assert_eq!(result.registrant_org.as_deref(), Some("示例科技有限公司"));
+        assert_eq!(result.registrant_country, None);
+        assert_eq!(result.registrar.as_deref(), Some("示例网络科技有限公司"));
+        assert_eq!(result.dnssec.as_deref(), Some("unsigned"));
+        assert_eq!(result.raw, CN);
+    }
+
+    #[test]
+    fn parses_nominet_multiline_fields() {
+        let result = parse_whois_response("example-shop.co.uk", UK);
+
+        assert_eq!(result.registrant_org.as_deref(), Some("Example Shop Ltd"));
+        assert_eq!(result.dnssec.as_deref(), Some("Signed"));
+        assert_eq!(result.registrar_abuse_email, None);
+        assert_eq!(result.raw, UK);
+    }
+}
@@ -0,0 +1,270 @@
+//! WHOIS / RDAP 查询结果缓存
+//!
+//! 同一会话内反复查询同一域名时直接返回缓存，避免频繁请求注册局服务器。
+//! 条目超过 TTL 后失效，条目数达到上限时淘汰最久未使用的条目。
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::error::CoreResult;
+use crate::types::WhoisResult;
+
+/// WHOIS 缓存配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WhoisCacheConfig {
+    /// 缓存有效期
+    pub ttl: Duration,
+    /// 最大条目数（WHOIS 与 RDAP 分别计入）
+    pub max_entries: usize,
+}
+
+impl Default for WhoisCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_mins(10),
+            max_entries: 128,
+        }
+    }
+}
+
+/// 查询来源（WHOIS 查询会回退到 RDAP，两者结果分开缓存）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum WhoisSource {
+    Whois,
+    Rdap,
+}
+
+struct CacheEntry {
+    result: WhoisResult,
+    expires_at: Instant,
+    /// 最近一次访问的序号，用于 LRU 淘汰
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<(WhoisSource, String), CacheEntry>,
+    /// 单调递增的访问序号
+    tick: u64,
+}
+
+impl CacheState {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// 带 TTL 与 LRU 淘汰的 WHOIS 结果缓存
+pub(super) struct WhoisCache {
+    config: WhoisCacheConfig,
+    state: Mutex<CacheState>,
+}
+
+impl WhoisCache {
+    pub(super) fn new(config: WhoisCacheConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// 命中缓存时返回缓存结果（`cached` 为 true），否则调用 `fetch` 并缓存成功的结果
+    ///
+    /// `force_refresh` 为 true 时跳过缓存直接查询，查询结果仍会写入缓存。
+    /// 查询期间不持有锁，并发查询同一域名时各自请求，以后写入的结果为准。
+    pub(super) async fn get_or_fetch<F, Fut>(
+        &self,
+        source: WhoisSource,
+        domain: &str,
+        force_refresh: bool,
+        fetch: F,
+    ) -> CoreResult<WhoisResult>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CoreResult<WhoisResult>>,
+    {
+        let key = (source, cache_key(domain));
+        if !force_refresh {
+            if let Some(result) = self.get(&key).await {
+                log::debug!("[WhoisCache] Hit for {}", key.1);
+                return Ok(result);
+            }
+        }
+
+        let result = fetch().await?;
+        self.insert(key, result.clone()).await;
+        Ok(result)
+    }
+
+    async fn get(&self, key: &(WhoisSource, String)) -> Option<WhoisResult> {
+        let mut state = self.state.lock().await;
+        let tick = state.next_tick();
+        let entry = state.entries.get_mut(key)?;
+        if entry.expires_at <= Instant::now() {
+            state.entries.remove(key);
+            return None;
+        }
+        entry.last_used = tick;
+        Some(WhoisResult {
+            cached: true,
+            ..entry.result.clone()
+        })
+    }
+
+    async fn insert(&self, key: (WhoisSource, String), result: WhoisResult) {
+        if self.config.max_entries == 0 {
+            return;
+        }
+        let mut state = self.state.lock().await;
+        let now = Instant::now();
+        state.entries.retain(|_, entry| entry.expires_at > now);
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.config.max_entries {
+            let least_recent = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recent) = least_recent {
+                state.entries.remove(&least_recent);
+            }
+        }
+        let last_used = state.next_tick();
+        state.entries.insert(
+            key,
+            CacheEntry {
+                result,
+                expires_at: now + self.config.ttl,
+                last_used,
+            },
+        );
+    }
+}
+
+/// 缓存键：小写、去掉首尾空白与末尾的点
+fn cache_key(domain: &str) -> String {
+    domain.trim().trim_end_matches('.').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::error::CoreError;
+
+    fn whois_result(domain: &str) -> WhoisResult {
+        WhoisResult {
+            domain: domain.to_string(),
+            unicode_domain: None,
+            registrar: None,
+            registrant_org: None,
+            registrant_country: None,
+            registrar_abuse_email: None,
+            registrar_abuse_phone: None,
+            creation_date: None,
+            expiration_date: None,
+            updated_date: None,
+            name_servers: Vec::new(),
+            status: Vec::new(),
+            dnssec: None,
+            queried_servers: Vec::new(),
+            raw: String::new(),
+            cached: false,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    async fn lookup(
+        cache: &WhoisCache,
+        calls: &AtomicUsize,
+        domain: &str,
+        force_refresh: bool,
+    ) -> CoreResult<WhoisResult> {
+        cache
+            .get_or_fetch(WhoisSource::Whois, domain, force_refresh, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(whois_result(domain))
+            })
+            .await
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn caches_until_ttl_expires() -> CoreResult<()> {
+        let cache = WhoisCache::new(WhoisCacheConfig::default());
+        let calls = AtomicUsize::new(0);
+
+        let first = lookup(&cache, &calls, "example.com", false).await?;
+        assert!(!first.cached);
+        let second = lookup(&cache, &calls, "EXAMPLE.com.", false).await?;
+        assert!(second.cached);
+        assert_eq!(second.fetched_at, first.fetched_at);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        tokio::time::advance(Duration::from_mins(10)).await;
+        assert!(!lookup(&cache, &calls, "example.com", false).await?.cached);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn force_refresh_bypasses_cache() -> CoreResult<()> {
+        let cache = WhoisCache::new(WhoisCacheConfig::default());
+        let calls = AtomicUsize::new(0);
+
+        lookup(&cache, &calls, "example.com", false).await?;
+        assert!(!lookup(&cache, &calls, "example.com", true).await?.cached);
+        assert!(lookup(&cache, &calls, "example.com", false).await?.cached);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn evicts_least_recently_used() -> CoreResult<()> {
+        let cache = WhoisCache::new(WhoisCacheConfig {
+            max_entries: 2,
+            ..WhoisCacheConfig::default()
+        });
+        let calls = AtomicUsize::new(0);
+
+        lookup(&cache, &calls, "a.com", false).await?;
+        lookup(&cache, &calls, "b.com", false).await?;
+        // 访问 a.com 后 b.com 成为最久未使用的条目
+        lookup(&cache, &calls, "a.com", false).await?;
+        lookup(&cache, &calls, "c.com", false).await?;
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        assert!(lookup(&cache, &calls, "a.com", false).await?.cached);
+        assert!(lookup(&cache, &calls, "c.com", false).await?.cached);
+        assert!(!lookup(&cache, &calls, "b.com", false).await?.cached);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() -> CoreResult<()> {
+        let cache = WhoisCache::new(WhoisCacheConfig::default());
+        let failed = cache
+            .get_or_fetch(WhoisSource::Rdap, "example.com", false, || async {
+                Err(CoreError::NetworkError("timeout".to_string()))
+            })
+            .await;
+        assert!(failed.is_err());
+
+        let calls = AtomicUsize::new(0);
+        let result = cache
+            .get_or_fetch(WhoisSource::Rdap, "example.com", false, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(whois_result("example.com"))
+            })
+            .await?;
+        assert!(!result.cached);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,1254 @@
+//! 权重灰度切换服务
+//!
+//! 将同名记录的流量从旧值按阶段逐步迁移到新值：
+//! 创建新记录 -> 开启权重解析 -> 逐阶段调整权重并保持 -> 删除旧记录、关闭权重解析。
+//! 任务在后台执行，可查询状态、暂停/恢复保持期计时，或随时回退到 0%（删除新记录）。
+//! 配置了验证探测时，保持期内定期 TCP 连接新记录值的指定端口，连接失败自动回退。
+//! 阶段切换遇到禁止变更时段时顺延到窗口开启后执行。
+//! 任务状态仅保存在内存中，应用重启后不会恢复。
+
+use std::collections::HashMap;
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use dns_orchestrator_provider::{DnsProvider, ProviderError};
+use futures::TryStreamExt;
+use tokio::sync::{watch, RwLock};
+use tokio::time::Instant;
+
+use crate::error::{CoreError, CoreResult};
+use crate::services::{
+    paginate_all, ChangeWindowService, DeadlineConfig, DnsService, OperationKind, Page, PageCursor,
+    PaginateOptions, ServiceContext, ToolboxService,
+};
+use crate::traits::{WriteLockBackend, WriteLockTarget};
+use crate::types::{
+    ChangeSource, CreateDnsRecordRequest, CreateWeightedRolloutRequest, DnsRecord, DnsRecordType,
+    PortProtocol, PortState, RecordChange, RecordData, RecordQueryParams, RolloutStatus,
+    RolloutVerification, WeightedRollout,
+};
+
+/// 禁止变更时段内重新检查变更窗口的间隔（感知规则修改与强制变更）
+const CHANGE_WINDOW_RECHECK: Duration = Duration::from_mins(1);
+
+/// 验证探测的默认间隔（秒）
+const DEFAULT_VERIFY_INTERVAL_SECS: u64 = 30;
+
+/// 验证探测的默认连接超时（毫秒）
+const DEFAULT_VERIFY_TIMEOUT_MS: u64 = 3000;
+
+/// 验证探测的连接超时上限（毫秒，与端口检查一致）
+const MAX_VERIFY_TIMEOUT_MS: u64 = 30_000;
+
+/// 后台任务控制信号
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RolloutControl {
+    Run,
+    Pause,
+    Rollback,
+}
+
+/// 任务条目（状态 + 控制通道）
+struct RolloutEntry {
+    state: RwLock<WeightedRollout>,
+    control: watch::Sender<RolloutControl>,
+    /// 新旧记录的类型（删除记录时写入审计日志）
+    record_type: DnsRecordType,
+}
+
+/// 权重灰度切换服务
+pub struct WeightedRolloutService {
+    ctx: Arc<ServiceContext>,
+    dns: Arc<DnsService>,
+    rollouts: RwLock<HashMap<String, Arc<RolloutEntry>>>,
+}
+
+impl WeightedRolloutService {
+    /// 创建权重灰度切换服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self {
+            dns: Arc::new(DnsService::new(Arc::clone(&ctx))),
+            ctx,
+            rollouts: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 创建并启动灰度任务
+    ///
+    /// Provider 不支持权重解析时返回 `UnsupportedCapability`，
+    /// 此时可改用多条同名 A 记录（轮询）近似实现。
+    /// 新记录创建后立即设置首个阶段的权重；创建或设置权重失败时删除新记录并关闭权重解析。
+    pub async fn create_weighted_rollout(
+        &self,
+        request: CreateWeightedRolloutRequest,
+    ) -> CoreResult<WeightedRollout> {
+        Self::validate_stages(&request)?;
+        Self::validate_verification(request.verification.as_ref())?;
+        self.ctx.ensure_change_window_open().await?;
+
+        let provider = self.dns.get_provider(&request.account_id).await?;
+        let deadlines = self.ctx.deadlines;
+
+        // 1. 查找旧记录
+        let old_record = Self::find_record(
+            &deadlines,
+            provider.as_ref(),
+            &request.domain_id,
+            &request.record_name,
+            &request.from_value,
+        )
+        .await?
+        .ok_or_else(|| {
+            CoreError::RecordNotFound(format!("{} -> {}", request.record_name, request.from_value))
+        })?;
+        let _lock = self
+            .ctx
+            .lock_for_write(
+                "weighted_rollout",
+                vec![WriteLockTarget::record(
+                    &request.account_id,
+                    &request.domain_id,
+                    &old_record.id,
+                )],
+            )
+            .await?;
+
+        let new_data = match &old_record.data {
+            RecordData::A { .. } => RecordData::A {
+                address: request.to_value.clone(),
+            },
+            RecordData::AAAA { .. } => RecordData::AAAA {
+                address: request.to_value.clone(),
+            },
+            RecordData::CNAME { .. } => RecordData::CNAME {
+                target: request.to_value.clone(),
+            },
+            _ => {
+                return Err(CoreError::ValidationError(
+                    "Weighted rollout only supports A, AAAA and CNAME records".to_string(),
+                ))
+            }
+        };
+
+        // 2. 开启权重解析（不支持的 Provider 在此返回 UnsupportedCapability）
+        let domain = deadlines
+            .run(
+                OperationKind::Read,
+                "get_domain",
+                provider.get_domain(&request.domain_id),
+            )
+            .await?;
+        let subdomain = full_name(&request.record_name, &domain.name);
+        deadlines
+            .run(
+                OperationKind::Write,
+                "set_weighted_routing",
+                provider.set_weighted_routing(&request.domain_id, &subdomain, true),
+            )
+            .await?;
+
+        // 3. 创建新记录并立即设置首个阶段的权重，避免新记录在阶段 1 生效前承接全部流量
+        let create_request = CreateDnsRecordRequest {
+            domain_id: request.domain_id.clone(),
+            name: old_record.name.clone(),
+            ttl: old_record.ttl,
+            data: new_data,
+            proxied: None,
+        };
+        let first_weight = request.stages[0].weight_percent;
+        let mut created = None;
+        let result: CoreResult<DnsRecord> = async {
+            let record = self
+                .dns
+                .apply_planned_change(
+                    &request.account_id,
+                    &request.domain_id,
+                    &RecordChange::Create {
+                        request: create_request.clone(),
+                    },
+                    None,
+                    ChangeSource::Manual,
+                )
+                .await?
+                .ok_or_else(|| CoreError::RecordNotFound(create_request.name.clone()))?;
+            created = Some(record.clone());
+            if first_weight < 100 {
+                apply_weights(
+                    &deadlines,
+                    provider.as_ref(),
+                    &old_record.id,
+                    &record.id,
+                    first_weight,
+                )
+                .await?;
+            }
+            Ok(record)
+        }
+        .await;
+        let new_record = match result {
+            Ok(record) => record,
+            Err(e) => {
+                self.abort_create(&request, provider.as_ref(), &subdomain, created.as_ref())
+                    .await;
+                return Err(e);
+            }
+        };
+
+        let now = Utc::now();
+        let rollout = WeightedRollout {
+            id: uuid::Uuid::new_v4().to_string(),
+            account_id: request.account_id,
+            domain_id: request.domain_id,
+            record_name: request.record_name,
+            from_value: request.from_value,
+            to_value: request.to_value,
+            stages: request.stages,
+            verification: request.verification,
+            status: RolloutStatus::Running,
+            current_stage: None,
+            current_weight: 0,
+            old_record_id: old_record.id,
+            new_record_id: new_record.id,
+            deferred_until: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let (control, receiver) = watch::channel(RolloutControl::Run);
+        let entry = Arc::new(RolloutEntry {
+            state: RwLock::new(rollout.clone()),
+            control,
+            record_type: old_record.data.record_type(),
+        });
+        self.rollouts
+            .write()
+            .await
+            .insert(rollout.id.clone(), Arc::clone(&entry));
+
+        log::info!(
+            "[Rollout] {} started: {} {} -> {}",
+            rollout.id,
+            subdomain,
+            rollout.from_value,
+            rollout.to_value
+        );
+        tokio::spawn(run_rollout(
+            Arc::clone(&self.dns),
+            deadlines,
+            self.ctx.change_windows.clone(),
+            Arc::clone(&self.ctx.write_locks),
+            provider,
+            entry,
+            receiver,
+            subdomain,
+        ));
+
+        Ok(rollout)
+    }
+
+    /// 获取灰度任务状态
+    pub async fn get_weighted_rollout(&self, rollout_id: &str) -> CoreResult<WeightedRollout> {
+        let entry = self.get_entry(rollout_id).await?;
+        let state = entry.state.read().await;
+        Ok(state.clone())
+    }
+
+    /// 列出所有灰度任务
+    pub async fn list_weighted_rollouts(&self) -> Vec<WeightedRollout> {
+        let rollouts = self.rollouts.read().await;
+        let mut result = Vec::with_capacity(rollouts.len());
+        for entry in rollouts.values() {
+            result.push(entry.state.read().await.clone());
+        }
+        result.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+        result
+    }
+
+    /// 暂停灰度任务（保持期计时暂停，当前权重保持不变）
+    pub async fn pause_weighted_rollout(&self, rollout_id: &str) -> CoreResult<WeightedRollout> {
+        self.set_control(rollout_id, RolloutControl::Pause, RolloutStatus::Paused)
+            .await
+    }
+
+    /// 恢复灰度任务
+    pub async fn resume_weighted_rollout(&self, rollout_id: &str) -> CoreResult<WeightedRollout> {
+        self.set_control(rollout_id, RolloutControl::Run, RolloutStatus::Running)
+            .await
+    }
+
+    /// 立即回退到 0%：删除新记录并关闭权重解析
+    ///
+    /// 运行中/暂停的任务由后台任务执行回退；失败的任务直接在此执行回退。
+    pub async fn rollback_weighted_rollout(&self, rollout_id: &str) -> CoreResult<WeightedRollout> {
+        let entry = self.get_entry(rollout_id).await?;
+        let status = entry.state.read().await.status;
+        if !matches!(status, RolloutStatus::Completed | RolloutStatus::RolledBack) {
+            self.ctx.ensure_change_window_open().await?;
+        }
+
+        match status {
+            RolloutStatus::Running | RolloutStatus::Paused => {
+                entry.control.send_replace(RolloutControl::Rollback);
+                Ok(entry.state.read().await.clone())
+            }
+            RolloutStatus::Failed => {
+                let account_id = entry.state.read().await.account_id.clone();
+                let provider = self.dns.get_provider(&account_id).await?;
+                let mut state = entry.state.write().await;
+                let domain = self
+                    .ctx
+                    .with_deadline(
+                        OperationKind::Read,
+                        "get_domain",
+                        provider.get_domain(&state.domain_id),
+                    )
+                    .await?;
+                let subdomain = full_name(&state.record_name, &domain.name);
+                rollback(
+                    &self.dns,
+                    &self.ctx.deadlines,
+                    self.ctx.write_locks.as_ref(),
+                    provider.as_ref(),
+                    &mut state,
+                    &entry.record_type,
+                    &subdomain,
+                )
+                .await?;
+                Ok(state.clone())
+            }
+            RolloutStatus::Completed | RolloutStatus::RolledBack => Err(
+                CoreError::ValidationError(format!("Rollout {rollout_id} is already finished")),
+            ),
+        }
+    }
+
+    async fn get_entry(&self, rollout_id: &str) -> CoreResult<Arc<RolloutEntry>> {
+        self.rollouts
+            .read()
+            .await
+            .get(rollout_id)
+            .cloned()
+            .ok_or_else(|| CoreError::RolloutNotFound(rollout_id.to_string()))
+    }
+
+    /// 创建失败后的清理：删除已创建的新记录并关闭权重解析（尽力而为，失败仅记录日志）
+    async fn abort_create(
+        &self,
+        request: &CreateWeightedRolloutRequest,
+        provider: &dyn DnsProvider,
+        subdomain: &str,
+        new_record: Option<&DnsRecord>,
+    ) {
+        if let Some(record) = new_record {
+            let delete = RecordChange::Delete {
+                record_id: record.id.clone(),
+                name: record.name.clone(),
+                record_type: record.data.record_type(),
+                value: request.to_value.clone(),
+            };
+            if let Err(e) = self
+                .dns
+                .apply_planned_change(
+                    &request.account_id,
+                    &request.domain_id,
+                    &delete,
+                    Some(record.clone()),
+                    ChangeSource::Manual,
+                )
+                .await
+            {
+                log::warn!(
+                    "[Rollout] Failed to delete new record {} after setup failure: {e}",
+                    record.id
+                );
+            }
+        }
+        if let Err(e) = self
+            .ctx
+            .with_deadline(
+                OperationKind::Write,
+                "set_weighted_routing",
+                provider.set_weighted_routing(&request.domain_id, subdomain, false),
+            )
+            .await
+        {
+            log::warn!("[Rollout] Failed to disable weighted routing for {subdomain}: {e}");
+        }
+    }
+
+    async fn set_control(
+        &self,
+        rollout_id: &str,
+        control: RolloutControl,
+        status: RolloutStatus,
+    ) -> CoreResult<WeightedRollout> {
+        let entry = self.get_entry(rollout_id).await?;
+        let mut state = entry.state.write().await;
+        if !matches!(state.status, RolloutStatus::Running | RolloutStatus::Paused) {
+            return Err(CoreError::ValidationError(format!(
+                "Rollout {rollout_id} is not running"
+            )));
+        }
+        // 回退已请求时不允许再暂停/恢复
+        if *entry.control.borrow() == RolloutControl::Rollback {
+            return Err(CoreError::ValidationError(format!(
+                "Rollout {rollout_id} is rolling back"
+            )));
+        }
+        entry.control.send_replace(control);
+        state.status = status;
+        state.updated_at = Utc::now();
+        Ok(state.clone())
+    }
+
+    /// 校验阶段配置：非空、权重 1-100 且严格递增、最后一个阶段为 100
+    fn validate_stages(request: &CreateWeightedRolloutRequest) -> CoreResult<()> {
+        if request.from_value == request.to_value {
+            return Err(CoreError::ValidationError(
+                "fromValue and toValue must be different".to_string(),
+            ));
+        }
+        let Some(last) = request.stages.last() else {
+            return Err(CoreError::ValidationError(
+                "At least one stage is required".to_string(),
+            ));
+        };
+        if last.weight_percent != 100 {
+            return Err(CoreError::ValidationError(
+                "The last stage must be 100%".to_string(),
+            ));
+        }
+        let mut prev = 0;
+        for stage in &request.stages {
+            if stage.weight_percent == 0 || stage.weight_percent > 100 {
+                return Err(CoreError::ValidationError(format!(
+                    "Invalid stage weight: {}",
+                    stage.weight_percent
+                )));
+            }
+            if stage.weight_percent <= prev {
+                return Err(CoreError::ValidationError(
+                    "Stage weights must be strictly increasing".to_string(),
+                ));
+            }
+            prev = stage.weight_percent;
+        }
+        Ok(())
+    }
+
+    /// 校验验证探测配置：端口非 0、间隔非 0、超时 1-30000 毫秒
+    fn validate_verification(verification: Option<&RolloutVerification>) -> CoreResult<()> {
+        let Some(verification) = verification else {
+            return Ok(());
+        };
+        if verification.port == 0 {
+            return Err(CoreError::ValidationError(
+                "Verification port must not be 0".to_string(),
+            ));
+        }
+        if verification.interval_secs == Some(0) {
+            return Err(CoreError::ValidationError(
+                "Verification interval must be at least 1 second".to_string(),
+            ));
+        }
+        if let Some(timeout_ms) = verification.timeout_ms {
+            if timeout_ms == 0 || timeout_ms > MAX_VERIFY_TIMEOUT_MS {
+                return Err(CoreError::ValidationError(format!(
+                    "Verification timeout must be between 1 and {MAX_VERIFY_TIMEOUT_MS} ms"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 按名称和值精确查找记录
+    async fn find_record(
+        deadlines: &DeadlineConfig,
+        provider: &dyn DnsProvider,
+        domain_id: &str,
+        name: &str,
+        value: &str,
+    ) -> CoreResult<Option<DnsRecord>> {
+        let records = paginate_all(PaginateOptions::default(), |cursor: PageCursor| {
+            let params = RecordQueryParams {
+                page: cursor.page_number(),
+                page_size: 100,
+                keyword: Some(name.to_string()),
+                record_type: None,
+                sort: None,
+            };
+            async move {
+                deadlines
+                    .run(
+                        OperationKind::Read,
+                        "list_records",
+                        provider.list_records(domain_id, &params),
+                    )
+                    .await
+                    .map(Page::from)
+            }
+        });
+        // 找到后不再拉取后续页
+        let mut records = pin!(records);
+        while let Some(record) = records.try_next().await? {
+            let matches = record.name == name
+                && match &record.data {
+                    RecordData::A { address } | RecordData::AAAA { address } => address == value,
+                    RecordData::CNAME { target } => target == value,
+                    _ => false,
+                };
+            if matches {
+                return Ok(Some(record));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// 相对名称转完整域名
+fn full_name(record_name: &str, domain: &str) -> String {
+    if record_name.is_empty() || record_name == "@" {
+        domain.to_string()
+    } else {
+        format!("{record_name}.{domain}")
+    }
+}
+
+/// 后台执行灰度任务
+#[allow(clippy::too_many_arguments)]
+async fn run_rollout(
+    dns: Arc<DnsService>,
+    deadlines: DeadlineConfig,
+    change_windows: Option<Arc<ChangeWindowService>>,
+    write_locks: Arc<dyn WriteLockBackend>,
+    provider: Arc<dyn DnsProvider>,
+    entry: Arc<RolloutEntry>,
+    mut control: watch::Receiver<RolloutControl>,
+    subdomain: String,
+) {
+    let (stages, verification, to_value, old_record_id, new_record_id, lock_target) = {
+        let state = entry.state.read().await;
+        (
+            state.stages.clone(),
+            state.verification.clone(),
+            state.to_value.clone(),
+            state.old_record_id.clone(),
+            state.new_record_id.clone(),
+            WriteLockTarget::records(
+                &state.account_id,
+                &state.domain_id,
+                [state.old_record_id.clone(), state.new_record_id.clone()],
+            ),
+        )
+    };
+
+    let mut rollback_requested = false;
+    // 验证探测失败触发的回退原因
+    let mut rollback_reason = None;
+    for (index, stage) in stages.iter().enumerate() {
+        if wait_while_paused(&mut control).await == RolloutControl::Rollback {
+            rollback_requested = true;
+            break;
+        }
+        match wait_for_change_window(change_windows.as_deref(), &entry, &mut control).await {
+            Ok(RolloutControl::Rollback) => {
+                rollback_requested = true;
+                break;
+            }
+            Ok(RolloutControl::Run | RolloutControl::Pause) => {}
+            Err(e) => {
+                fail(&entry, &e).await;
+                return;
+            }
+        }
+
+        // 100% 阶段无需调整权重，直接进入清理
+        if stage.weight_percent < 100 {
+            let result = async {
+                let _lock = write_locks
+                    .acquire("weighted_rollout", vec![lock_target.clone()])
+                    .await?;
+                apply_weights(
+                    &deadlines,
+                    provider.as_ref(),
+                    &old_record_id,
+                    &new_record_id,
+                    stage.weight_percent,
+                )
+                .await
+            }
+            .await;
+            if let Err(e) = result {
+                fail(&entry, &e).await;
+                return;
+            }
+        }
+
+        {
+            let mut state = entry.state.write().await;
+            state.current_stage = Some(index);
+            state.current_weight = stage.weight_percent;
+            state.updated_at = Utc::now();
+        }
+        log::info!(
+            "[Rollout] {subdomain} stage {} reached {}%",
+            index + 1,
+            stage.weight_percent
+        );
+
+        if stage.weight_percent < 100 {
+            let held = hold_and_verify(
+                &mut control,
+                Duration::from_secs(stage.hold_secs),
+                verification.as_ref(),
+                &to_value,
+            )
+            .await;
+            match held {
+                Ok(RolloutControl::Run | RolloutControl::Pause) => {}
+                Ok(RolloutControl::Rollback) => {
+                    rollback_requested = true;
+                    break;
+                }
+                Err(reason) => {
+                    log::warn!("[Rollout] {subdomain} rolling back: {reason}");
+                    rollback_reason = Some(reason);
+                    rollback_requested = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    // 清理旧记录同样需要等待变更窗口
+    if !rollback_requested {
+        match wait_for_change_window(change_windows.as_deref(), &entry, &mut control).await {
+            Ok(RolloutControl::Rollback) => rollback_requested = true,
+            Ok(RolloutControl::Run | RolloutControl::Pause) => {}
+            Err(e) => {
+                fail(&entry, &e).await;
+                return;
+            }
+        }
+    }
+
+    let mut state = entry.state.write().await;
+    if rollback_requested || *control.borrow() == RolloutControl::Rollback {
+        match rollback(
+            &dns,
+            &deadlines,
+            write_locks.as_ref(),
+            provider.as_ref(),
+            &mut state,
+            &entry.record_type,
+            &subdomain,
+        )
+        .await
+        {
+            Ok(()) => state.error = rollback_reason,
+            Err(e) => {
+                state.status = RolloutStatus::Failed;
+                state.error = Some(e.to_string());
+                state.updated_at = Utc::now();
+            }
+        }
+        return;
+    }
+
+    // 全部阶段完成：删除旧记录，关闭权重解析
+    let result = async {
+        let _lock = write_locks
+            .acquire("weighted_rollout", vec![lock_target])
+            .await?;
+        dns.apply_planned_change(
+            &state.account_id,
+            &state.domain_id,
+            &RecordChange::Delete {
+                record_id: old_record_id.clone(),
+                name: state.record_name.clone(),
+                record_type: entry.record_type.clone(),
+                value: state.from_value.clone(),
+            },
+            None,
+            ChangeSource::Scheduled,
+        )
+        .await?;
+        deadlines
+            .run(
+                OperationKind::Write,
+                "set_weighted_routing",
+                provider.set_weighted_routing(&state.domain_id, &subdomain, false),
+            )
+            .await
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            state.status = RolloutStatus::Completed;
+            state.current_weight = 100;
+            log::info!("[Rollout] {} completed", state.id);
+        }
+        Err(e) => {
+            state.status = RolloutStatus::Failed;
+            state.error = Some(e.to_string());
+        }
+    }
+    state.updated_at = Utc::now();
+}
+
+/// 设置新旧记录的权重（新值占 `percent`%）
+async fn apply_weights(
+    deadlines: &DeadlineConfig,
+    provider: &dyn DnsProvider,
+    old_record_id: &str,
+    new_record_id: &str,
+    percent: u8,
+) -> CoreResult<()> {
+    deadlines
+        .run(
+            OperationKind::Write,
+            "set_record_weight",
+            provider.set_record_weight(new_record_id, percent),
+        )
+        .await?;
+    deadlines
+        .run(
+            OperationKind::Write,
+            "set_record_weight",
+            provider.set_record_weight(old_record_id, 100 - percent),
+        )
+        .await
+}
+
+/// 回退：删除新记录并关闭权重解析
+async fn rollback(
+    dns: &DnsService,
+    deadlines: &DeadlineConfig,
+    write_locks: &dyn WriteLockBackend,
+    provider: &dyn DnsProvider,
+    state: &mut WeightedRollout,
+    record_type: &DnsRecordType,
+    subdomain: &str,
+) -> CoreResult<()> {
+    let _lock = write_locks
+        .acquire(
+            "weighted_rollout",
+            vec![WriteLockTarget::record(
+                &state.account_id,
+                &state.domain_id,
+                &state.new_record_id,
+            )],
+        )
+        .await?;
+    let delete = RecordChange::Delete {
+        record_id: state.new_record_id.clone(),
+        name: state.record_name.clone(),
+        record_type: record_type.clone(),
+        value: state.to_value.clone(),
+    };
+    match dns
+        .apply_planned_change(
+            &state.account_id,
+            &state.domain_id,
+            &delete,
+            None,
+            ChangeSource::Scheduled,
+        )
+        .await
+    {
+        Ok(_) | Err(CoreError::Provider(ProviderError::RecordNotFound { .. })) => {}
+        Err(e) => return Err(e),
+    }
+    deadlines
+        .run(
+            OperationKind::Write,
+            "set_weighted_routing",
+            provider.set_weighted_routing(&state.domain_id, subdomain, false),
+        )
+        .await?;
+
+    state.status = RolloutStatus::RolledBack;
+    state.current_weight = 0;
+    state.error = None;
+    state.updated_at = Utc::now();
+    log::info!("[Rollout] {} rolled back", state.id);
+    Ok(())
+}
+
+async fn fail(entry: &RolloutEntry, error: &CoreError) {
+    let mut state = entry.state.write().await;
+    log::error!("[Rollout] {} failed: {error}", state.id);
+    state.status = RolloutStatus::Failed;
+    state.error = Some(error.to_string());
+    state.updated_at = Utc::now();
+}
+
+/// 处于禁止变更时段时等待窗口开启，返回 `Rollback` 表示需要回退
+///
+/// 等待期间在任务状态中记录顺延到的时间，并定期重新检查以感知规则修改与强制变更。
+async fn wait_for_change_window(
+    change_windows: Option<&ChangeWindowService>,
+    entry: &RolloutEntry,
+    control: &mut watch::Receiver<RolloutControl>,
+) -> CoreResult<RolloutControl> {
+    let Some(change_windows) = change_windows else {
+        return Ok(RolloutControl::Run);
+    };
+
+    loop {
+        let next_open_at = match change_windows.ensure_open().await {
+            Ok(()) => break,
+            Err(CoreError::ChangeWindowClosed { next_open_at, .. }) => next_open_at,
+            Err(e) => return Err(e),
+        };
+        {
+            let mut state = entry.state.write().await;
+            if state.deferred_until != Some(next_open_at) {
+                log::warn!(
+                    "[Rollout] {} deferred until {next_open_at} by change window",
+                    state.id
+                );
+                state.deferred_until = Some(next_open_at);
+                state.updated_at = Utc::now();
+            }
+        }
+
+        let wait = (next_open_at - Utc::now())
+            .to_std()
+            .unwrap_or_default()
+            .min(CHANGE_WINDOW_RECHECK);
+        if hold(control, wait).await == RolloutControl::Rollback {
+            return Ok(RolloutControl::Rollback);
+        }
+    }
+
+    let mut state = entry.state.write().await;
+    if state.deferred_until.take().is_some() {
+        log::info!("[Rollout] {} resumed after change window", state.id);
+        state.updated_at = Utc::now();
+    }
+    Ok(RolloutControl::Run)
+}
+
+/// 保持期等待，配置了验证探测时在开始、每个间隔及结束时探测一次
+///
+/// 返回 `Rollback` 表示需要回退，探测失败时返回失败原因。
+async fn hold_and_verify(
+    control: &mut watch::Receiver<RolloutControl>,
+    duration: Duration,
+    verification: Option<&RolloutVerification>,
+    host: &str,
+) -> Result<RolloutControl, String> {
+    let Some(verification) = verification else {
+        return Ok(hold(control, duration).await);
+    };
+    let interval = Duration::from_secs(
+        verification
+            .interval_secs
+            .unwrap_or(DEFAULT_VERIFY_INTERVAL_SECS),
+    );
+    let mut remaining = duration;
+    loop {
+        verify(verification, host).await?;
+        if remaining.is_zero() {
+            return Ok(RolloutControl::Run);
+        }
+        let chunk = remaining.min(interval);
+        if hold(control, chunk).await == RolloutControl::Rollback {
+            return Ok(RolloutControl::Rollback);
+        }
+        remaining -= chunk;
+    }
+}
+
+/// TCP 连接新记录值的验证端口，端口未开放时返回失败原因
+async fn verify(verification: &RolloutVerification, host: &str) -> Result<(), String> {
+    let port = verification.port;
+    let timeout_ms = verification.timeout_ms.unwrap_or(DEFAULT_VERIFY_TIMEOUT_MS);
+    let result = ToolboxService::port_check(host, vec![port], PortProtocol::Tcp, timeout_ms)
+        .await
+        .map_err(|e| format!("Verification probe {host}:{port} failed: {e}"))?;
+    match result.results.first().map(|status| status.state) {
+        Some(PortState::Open) => Ok(()),
+        Some(PortState::Closed) => Err(format!(
+            "Verification probe {host}:{port} failed: connection refused"
+        )),
+        Some(PortState::Filtered) | None => Err(format!(
+            "Verification probe {host}:{port} failed: no response within {timeout_ms} ms"
+        )),
+    }
+}
+
+/// 暂停时阻塞，直到恢复或请求回退
+async fn wait_while_paused(control: &mut watch::Receiver<RolloutControl>) -> RolloutControl {
+    loop {
+        let current = *control.borrow_and_update();
+        if current != RolloutControl::Pause {
+            return current;
+        }
+        if control.changed().await.is_err() {
+            return RolloutControl::Run;
+        }
+    }
+}
+
+/// 保持期等待（暂停期间不计时），返回 `Rollback` 表示需要回退
+async fn hold(control: &mut watch::Receiver<RolloutControl>, duration: Duration) -> RolloutControl {
+    let mut remaining = duration;
+    loop {
+        if wait_while_paused(control).await == RolloutControl::Rollback {
+            return RolloutControl::Rollback;
+        }
+        let started = Instant::now();
+        tokio::select! {
+            () = tokio::time::sleep(remaining) => return RolloutControl::Run,
+            changed = control.changed() => {
+                if changed.is_err() {
+                    tokio::time::sleep(remaining.saturating_sub(started.elapsed())).await;
+                    return RolloutControl::Run;
+                }
+                remaining = remaining.saturating_sub(started.elapsed());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration as ChronoDuration;
+
+    use super::*;
+    use crate::testing::{
+        in_memory_context, register_mock_account, InMemoryChangeWindowRepository, MockDnsProvider,
+    };
+    use crate::types::{ChangeWindowSchedule, CreateChangeWindowRuleRequest, RolloutStage};
+
+    fn a(address: &str) -> RecordData {
+        RecordData::A {
+            address: address.to_string(),
+        }
+    }
+
+    fn provider() -> MockDnsProvider {
+        MockDnsProvider::new()
+            .with_domain("zone-1", "example.com")
+            .with_record("zone-1", "www", 300, a("192.0.2.1"))
+    }
+
+    fn request(to_value: &str, stages: &[(u8, u64)]) -> CreateWeightedRolloutRequest {
+        CreateWeightedRolloutRequest {
+            account_id: "account-1".to_string(),
+            domain_id: "zone-1".to_string(),
+            record_name: "www".to_string(),
+            from_value: "192.0.2.1".to_string(),
+            to_value: to_value.to_string(),
+            stages: stages
+                .iter()
+                .map(|&(weight_percent, hold_secs)| RolloutStage {
+                    weight_percent,
+                    hold_secs,
+                })
+                .collect(),
+            verification: None,
+        }
+    }
+
+    async fn service(
+        ctx: ServiceContext,
+        provider: MockDnsProvider,
+    ) -> CoreResult<(WeightedRolloutService, Arc<MockDnsProvider>)> {
+        let ctx = Arc::new(ctx);
+        let provider = Arc::new(provider);
+        register_mock_account(&ctx, "account-1", Arc::clone(&provider)).await?;
+        Ok((WeightedRolloutService::new(ctx), provider))
+    }
+
+    async fn sleep_secs(secs: u64) {
+        tokio::time::sleep(Duration::from_secs(secs)).await;
+    }
+
+    fn values(provider: &MockDnsProvider) -> Vec<String> {
+        provider
+            .records()
+            .iter()
+            .map(|record| record.data.display_value())
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stages_progress_to_completion() -> CoreResult<()> {
+        let (service, provider) =
+            service(in_memory_context(), provider().with_weighted_routing()).await?;
+        let rollout = service
+            .create_weighted_rollout(request("192.0.2.2", &[(25, 60), (50, 60), (100, 0)]))
+            .await?;
+
+        // 新记录创建后立即带上首个阶段的权重
+        assert_eq!(provider.weighted_subdomains(), vec!["www.example.com"]);
+        assert_eq!(provider.record_weight(&rollout.new_record_id), Some(25));
+        assert_eq!(provider.record_weight(&rollout.old_record_id), Some(75));
+
+        sleep_secs(1).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!((state.current_stage, state.current_weight), (Some(0), 25));
+
+        sleep_secs(60).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!((state.current_stage, state.current_weight), (Some(1), 50));
+        assert_eq!(provider.record_weight(&rollout.new_record_id), Some(50));
+        assert_eq!(provider.record_weight(&rollout.old_record_id), Some(50));
+
+        sleep_secs(60).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(state.status, RolloutStatus::Completed);
+        assert_eq!(state.current_weight, 100);
+        assert_eq!(values(&provider), vec!["192.0.2.2"]);
+        assert!(provider.weighted_subdomains().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pause_stops_the_hold_clock() -> CoreResult<()> {
+        let (service, provider) =
+            service(in_memory_context(), provider().with_weighted_routing()).await?;
+        let rollout = service
+            .create_weighted_rollout(request("192.0.2.2", &[(50, 60), (100, 0)]))
+            .await?;
+
+        sleep_secs(30).await;
+        let paused = service.pause_weighted_rollout(&rollout.id).await?;
+        assert_eq!(paused.status, RolloutStatus::Paused);
+
+        // 暂停期间保持期不计时
+        sleep_secs(120).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(
+            (state.status, state.current_weight),
+            (RolloutStatus::Paused, 50)
+        );
+        assert_eq!(provider.records().len(), 2);
+
+        service.resume_weighted_rollout(&rollout.id).await?;
+        sleep_secs(20).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(
+            (state.status, state.current_weight),
+            (RolloutStatus::Running, 50)
+        );
+
+        sleep_secs(15).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(state.status, RolloutStatus::Completed);
+        assert_eq!(values(&provider), vec!["192.0.2.2"]);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rollback_removes_the_new_record() -> CoreResult<()> {
+        let (service, provider) =
+            service(in_memory_context(), provider().with_weighted_routing()).await?;
+        let rollout = service
+            .create_weighted_rollout(request("192.0.2.2", &[(50, 600), (100, 0)]))
+            .await?;
+
+        sleep_secs(1).await;
+        service.rollback_weighted_rollout(&rollout.id).await?;
+        sleep_secs(1).await;
+
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(
+            (state.status, state.current_weight),
+            (RolloutStatus::RolledBack, 0)
+        );
+        assert_eq!(values(&provider), vec!["192.0.2.1"]);
+        assert!(provider.weighted_subdomains().is_empty());
+        assert!(matches!(
+            service.rollback_weighted_rollout(&rollout.id).await,
+            Err(CoreError::ValidationError(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn closed_change_window_defers_the_next_stage() -> CoreResult<()> {
+        let change_windows = Arc::new(ChangeWindowService::new(Arc::new(
+            InMemoryChangeWindowRepository::default(),
+        )));
+        let ctx = in_memory_context().with_change_windows(Arc::clone(&change_windows));
+        let (service, provider) = service(ctx, provider().with_weighted_routing()).await?;
+        let rollout = service
+            .create_weighted_rollout(request("192.0.2.2", &[(50, 60), (100, 0)]))
+            .await?;
+        sleep_secs(1).await;
+
+        let now = Utc::now().naive_utc();
+        let rule = change_windows
+            .create_rule(CreateChangeWindowRuleRequest {
+                name: "freeze".to_string(),
+                enabled: true,
+                utc_offset: "+00:00".to_string(),
+                schedule: ChangeWindowSchedule::OneOff {
+                    start: now - ChronoDuration::hours(1),
+                    end: now + ChronoDuration::days(1),
+                },
+            })
+            .await?;
+
+        sleep_secs(60).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(
+            (state.status, state.current_weight),
+            (RolloutStatus::Running, 50)
+        );
+        assert!(state.deferred_until.is_some());
+        assert_eq!(provider.records().len(), 2);
+
+        change_windows.delete_rule(&rule.id).await?;
+        sleep_secs(61).await;
+        let state = service.get_weighted_rollout(&rollout.id).await?;
+        assert_eq!(state.status, RolloutStatus::Completed);
+        assert_eq!(state.deferred_until, None);
+        assert_eq!(values(&provider), vec!["192.0.2.2"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unsupported_provider_is_rejected_without_changes() -> CoreResult<()> {
+        let (service, provider) = service(in_memory_context(), provider()).await?;
+        let result = service
+            .create_weighted_rollout(request("192.0.2.2", &[(50, 60), (100, 0)]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::Provider(
+                ProviderError::UnsupportedCapability { .. }
+            ))
+        ));
+        assert_eq!(values(&provider), vec!["192.0.2.1"]);
+        assert!(service.list_weighted_rollouts().await.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_create_disables_weighted_routing() -> CoreResult<()> {
+        let (service, provider) =
+            service(in_memory_context(), provider().with_weighted_routing()).await?;
+        provider.fail_next_writes(
+            ProviderError::InvalidParameter {
+                provider: "mock".to_string(),
+                param: "content".to_string(),
+                detail: "rejected".to_string(),
+            },
+            1,
+        );
+
+        let result = service
+            .create_weighted_rollout(request("192.0.2.2", &[(50, 60), (100, 0)]))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(values(&provider), vec!["192.0.2.1"]);
+        assert!(provider.weighted_subdomains().is_empty());
+        Ok(())
+    }
+
+    /// 轮询直到任务结束（验证探测走真实网络，不能使用暂停的时钟）
+    async fn wait_until_finished(
+        service: &WeightedRolloutService,
+        rollout_id: &str,
+    ) -> CoreResult<WeightedRollout> {
+        for _ in 0..500 {
+            let state = service.get_weighted_rollout(rollout_id).await?;
+            if !matches!(state.status, RolloutStatus::Running | RolloutStatus::Paused) {
+                return Ok(state);
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        service.get_weighted_rollout(rollout_id).await
+    }
+
+    #[tokio::test]
+    async fn failed_verification_rolls_back() -> CoreResult<()> {
+        // 绑定后立即释放，得到一个没有监听者的端口
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .map_err(|e| CoreError::NetworkError(e.to_string()))?
+            .port();
+        let (service, provider) =
+            service(in_memory_context(), provider().with_weighted_routing()).await?;
+        let mut request = request("127.0.0.1", &[(50, 600), (100, 0)]);
+        request.verification = Some(RolloutVerification {
+            port,
+            interval_secs: None,
+            timeout_ms: Some(1000),
+        });
+        let rollout = service.create_weighted_rollout(request).await?;
+
+        let state = wait_until_finished(&service, &rollout.id).await?;
+        assert_eq!(state.status, RolloutStatus::RolledBack);
+        assert!(state
+            .error
+            .is_some_and(|error| error.contains("Verification probe")));
+        assert_eq!(values(&provider), vec!["192.0.2.1"]);
+        assert!(provider.weighted_subdomains().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passing_verification_completes() -> CoreResult<()> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| CoreError::NetworkError(e.to_string()))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| CoreError::NetworkError(e.to_string()))?
+            .port();
+        let (service, provider) =
+            service(in_memory_context(), provider().with_weighted_routing()).await?;
+        let mut request = request("127.0.0.1", &[(50, 0), (100, 0)]);
+        request.verification = Some(RolloutVerification {
+            port,
+            interval_secs: None,
+            timeout_ms: Some(1000),
+        });
+        let rollout = service.create_weighted_rollout(request).await?;
+
+        let state = wait_until_finished(&service, &rollout.id).await?;
+        assert_eq!(state.status, RolloutStatus::Completed);
+        assert_eq!(values(&provider), vec!["127.0.0.1"]);
+        drop(listener);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_requests_are_rejected() -> CoreResult<()> {
+        let (service, _) = service(in_memory_context(), provider().with_weighted_routing()).await?;
+        assert!(matches!(
+            service.get_weighted_rollout("missing").await,
+            Err(CoreError::RolloutNotFound(id)) if id == "missing"
+        ));
+
+        let mut invalid = request("192.0.2.2", &[(50, 60), (100, 0)]);
+        invalid.verification = Some(RolloutVerification {
+            port: 0,
+            interval_secs: None,
+            timeout_ms: None,
+        });
+        assert!(matches!(
+            service.create_weighted_rollout(invalid).await,
+            Err(CoreError::ValidationError(_))
+        ));
+        assert!(matches!(
+            service
+                .create_weighted_rollout(request("192.0.2.2", &[(50, 60), (40, 0)]))
+                .await,
+            Err(CoreError::ValidationError(_))
+        ));
+        Ok(())
+    }
+}
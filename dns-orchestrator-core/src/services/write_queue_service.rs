@@ -0,0 +1,426 @@
+//! 离线写队列服务
+//!
+//! 启用后（默认关闭），记录写操作因 Provider 网络错误失败时进入本地持久化队列，
+//! 返回 `CoreError::WriteQueued`。网络恢复后由用户审阅队列并确认重放，
+//! 重放前重新做冲突检测：目标记录已被其他途径修改时标记冲突，不自动覆盖。
+//!
+//! 这不是自动重试：队列面向分钟级的离线，每次重放都需要用户确认。
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use dns_orchestrator_provider::ProviderError;
+use tokio::sync::{Mutex, RwLock};
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::WriteQueueRepository;
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, QueuedWrite, QueuedWriteOperation, WriteQueueConfig,
+};
+
+/// 队列容量上限的最大值
+const MAX_QUEUE_ENTRIES: usize = 1000;
+
+/// 有效期的最大值（7 天）
+const MAX_TTL_HOURS: u32 = 7 * 24;
+
+/// 重放前的冲突检测结果
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ReplayCheck {
+    /// 可以重放
+    Proceed,
+    /// 目标记录已是期望状态
+    AlreadyApplied,
+    /// 冲突，需人工处理
+    Conflict(String),
+}
+
+/// 离线写队列服务
+pub struct WriteQueueService {
+    repository: Arc<dyn WriteQueueRepository>,
+    config: RwLock<WriteQueueConfig>,
+    /// 串行化队列的读改写
+    lock: Mutex<()>,
+}
+
+impl WriteQueueService {
+    /// 创建离线写队列服务，`config` 为平台层持久化的配置
+    #[must_use]
+    pub fn new(repository: Arc<dyn WriteQueueRepository>, config: WriteQueueConfig) -> Self {
+        Self {
+            repository,
+            config: RwLock::new(config),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// 当前配置
+    pub async fn config(&self) -> WriteQueueConfig {
+        self.config.read().await.clone()
+    }
+
+    /// 更新配置
+    ///
+    /// 关闭后不再入队，已在队列中的写操作保留，仍可审阅、重放或丢弃。
+    pub async fn set_config(&self, config: WriteQueueConfig) -> CoreResult<()> {
+        if config.max_entries == 0 || config.max_entries > MAX_QUEUE_ENTRIES {
+            return Err(CoreError::ValidationError(format!(
+                "队列容量必须在 1 到 {MAX_QUEUE_ENTRIES} 之间"
+            )));
+        }
+        if config.ttl_hours == 0 || config.ttl_hours > MAX_TTL_HOURS {
+            return Err(CoreError::ValidationError(format!(
+                "有效期必须在 1 到 {MAX_TTL_HOURS} 小时之间"
+            )));
+        }
+        *self.config.write().await = config;
+        Ok(())
+    }
+
+    /// 是否启用
+    pub async fn is_enabled(&self) -> bool {
+        self.config.read().await.enabled
+    }
+
+    /// 列出队列中未过期的写操作（按入队顺序），过期项同时移出队列
+    pub async fn list(&self) -> CoreResult<Vec<QueuedWrite>> {
+        let _guard = self.lock.lock().await;
+        let entries = self.repository.find_all().await?;
+        let now = Utc::now();
+        let (live, expired): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|entry| entry.expires_at > now);
+        if !expired.is_empty() {
+            log::info!("[WriteQueue] Dropped {} expired entries", expired.len());
+            self.repository.save_all(&live).await?;
+        }
+        Ok(live)
+    }
+
+    /// 获取单个写操作（包括已过期的）
+    pub(crate) async fn get(&self, id: &str) -> CoreResult<Option<QueuedWrite>> {
+        let _guard = self.lock.lock().await;
+        Ok(self
+            .repository
+            .find_all()
+            .await?
+            .into_iter()
+            .find(|entry| entry.id == id))
+    }
+
+    /// 丢弃指定的写操作，返回实际移除的数量
+    pub async fn discard(&self, ids: &[String]) -> CoreResult<usize> {
+        self.remove_where(|entry| ids.contains(&entry.id)).await
+    }
+
+    /// 清空账户相关的写操作（凭证变更或账户删除时调用），返回移除的数量
+    pub async fn clear_account(&self, account_id: &str) -> CoreResult<usize> {
+        let removed = self
+            .remove_where(|entry| entry.account_id == account_id)
+            .await?;
+        if removed > 0 {
+            log::info!("[WriteQueue] Cleared {removed} entries of account {account_id}");
+        }
+        Ok(removed)
+    }
+
+    /// 将因网络错误失败的写操作加入队列
+    ///
+    /// 未启用或队列已满时返回 `None`，调用方应原样返回网络错误。
+    pub(crate) async fn enqueue(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        operation: QueuedWriteOperation,
+        before: Option<DnsRecord>,
+        error: &CoreError,
+    ) -> CoreResult<Option<String>> {
+        let config = self.config().await;
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let _guard = self.lock.lock().await;
+        let now = Utc::now();
+        let mut entries: Vec<QueuedWrite> = self
+            .repository
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|entry| entry.expires_at > now)
+            .collect();
+        if entries.len() >= config.max_entries {
+            log::warn!(
+                "[WriteQueue] Queue is full ({} entries), not queuing write for {account_id}/{domain_id}",
+                entries.len()
+            );
+            return Ok(None);
+        }
+
+        let entry = QueuedWrite {
+            id: uuid::Uuid::new_v4().to_string(),
+            account_id: account_id.to_string(),
+            domain_id: domain_id.to_string(),
+            operation,
+            before,
+            error: error.to_string(),
+            queued_at: now,
+            expires_at: now + Duration::hours(i64::from(config.ttl_hours)),
+        };
+        let id = entry.id.clone();
+        entries.push(entry);
+        self.repository.save_all(&entries).await?;
+        log::info!("[WriteQueue] Queued write {id} for {account_id}/{domain_id}: {error}");
+        Ok(Some(id))
+    }
+
+    async fn remove_where(&self, predicate: impl Fn(&QueuedWrite) -> bool) -> CoreResult<usize> {
+        let _guard = self.lock.lock().await;
+        let entries = self.repository.find_all().await?;
+        let before = entries.len();
+        let kept: Vec<QueuedWrite> = entries
+            .into_iter()
+            .filter(|entry| !predicate(entry))
+            .collect();
+        let removed = before - kept.len();
+        if removed > 0 {
+            self.repository.save_all(&kept).await?;
+        }
+        Ok(removed)
+    }
+}
+
+/// 是否为可进入离线写队列的网络错误（请求未能到达服务商）
+pub(crate) fn is_offline_error(error: &CoreError) -> bool {
+    matches!(
+        error,
+        CoreError::NetworkError(_) | CoreError::Provider(ProviderError::NetworkError { .. })
+    )
+}
+
+/// 重放前的冲突检测
+///
+/// `current` 为目标记录的当前状态（更新、删除时按记录 ID 查找，不存在为 `None`），
+/// `existing` 为域名下的全部记录（创建时检查是否已存在相同记录）。
+/// `force` 为用户审阅后确认覆盖，跳过“已被修改”的检测。
+pub(crate) fn replay_check(
+    entry: &QueuedWrite,
+    current: Option<&DnsRecord>,
+    existing: &[DnsRecord],
+    force: bool,
+) -> ReplayCheck {
+    match &entry.operation {
+        QueuedWriteOperation::Create { request } => {
+            if existing
+                .iter()
+                .any(|record| same_as_create(record, request))
+            {
+                ReplayCheck::AlreadyApplied
+            } else {
+                ReplayCheck::Proceed
+            }
+        }
+        QueuedWriteOperation::Update { request, .. } => {
+            let Some(current) = current else {
+                return ReplayCheck::Conflict("目标记录已被删除".to_string());
+            };
+            let desired = CreateDnsRecordRequest {
+                domain_id: request.domain_id.clone(),
+                name: request.name.clone(),
+                ttl: request.ttl,
+                data: request.data.clone(),
+                proxied: request.proxied,
+            };
+            if same_as_create(current, &desired) {
+                return ReplayCheck::AlreadyApplied;
+            }
+            unchanged_since_queued(entry.before.as_ref(), current, force)
+        }
+        QueuedWriteOperation::Delete { .. } => match current {
+            None => ReplayCheck::AlreadyApplied,
+            Some(current) => unchanged_since_queued(entry.before.as_ref(), current, force),
+        },
+    }
+}
+
+fn unchanged_since_queued(
+    before: Option<&DnsRecord>,
+    current: &DnsRecord,
+    force: bool,
+) -> ReplayCheck {
+    if force {
+        return ReplayCheck::Proceed;
+    }
+    match before {
+        Some(before) if same_content(before, current) => ReplayCheck::Proceed,
+        Some(_) => ReplayCheck::Conflict("目标记录在入队后已被其他途径修改".to_string()),
+        None => ReplayCheck::Conflict("入队时未能获取记录快照，无法确认记录未被修改".to_string()),
+    }
+}
+
+fn same_content(a: &DnsRecord, b: &DnsRecord) -> bool {
+    a.name == b.name && a.ttl == b.ttl && a.data == b.data && a.proxied == b.proxied
+}
+
+/// 记录是否与创建请求一致（请求未指定代理状态时不比较代理）
+pub(crate) fn same_as_create(record: &DnsRecord, request: &CreateDnsRecordRequest) -> bool {
+    record.name == request.name
+        && record.ttl == request.ttl
+        && record.data == request.data
+        && (request.proxied.is_none() || record.proxied == request.proxied)
+}
+
+/// 写操作的目标记录 ID（创建为 `None`）
+pub(crate) fn target_record_id(operation: &QueuedWriteOperation) -> Option<&str> {
+    match operation {
+        QueuedWriteOperation::Create { .. } => None,
+        QueuedWriteOperation::Update { record_id, .. }
+        | QueuedWriteOperation::Delete { record_id } => Some(record_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RecordData, UpdateDnsRecordRequest};
+
+    fn record(address: &str) -> DnsRecord {
+        DnsRecord {
+            id: "r1".to_string(),
+            domain_id: "d1".to_string(),
+            name: "www".to_string(),
+            ttl: 600,
+            data: RecordData::A {
+                address: address.to_string(),
+            },
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn queued(operation: QueuedWriteOperation, before: Option<DnsRecord>) -> QueuedWrite {
+        let now = Utc::now();
+        QueuedWrite {
+            id: "q1".to_string(),
+            account_id: "acc".to_string(),
+            domain_id: "d1".to_string(),
+            operation,
+            before,
+            error: "Network error".to_string(),
+            queued_at: now,
+            expires_at: now + Duration::hours(1),
+        }
+    }
+
+    fn update_to(address: &str) -> QueuedWriteOperation {
+        QueuedWriteOperation::Update {
+            record_id: "r1".to_string(),
+            request: UpdateDnsRecordRequest {
+                domain_id: "d1".to_string(),
+                name: "www".to_string(),
+                ttl: 600,
+                data: RecordData::A {
+                    address: address.to_string(),
+                },
+                proxied: None,
+            },
+        }
+    }
+
+    #[test]
+    fn update_conflicts_when_modified_elsewhere() {
+        let entry = queued(update_to("192.0.2.2"), Some(record("192.0.2.1")));
+
+        let unchanged = record("192.0.2.1");
+        assert_eq!(
+            replay_check(&entry, Some(&unchanged), &[], false),
+            ReplayCheck::Proceed
+        );
+
+        let applied = record("192.0.2.2");
+        assert_eq!(
+            replay_check(&entry, Some(&applied), &[], false),
+            ReplayCheck::AlreadyApplied
+        );
+
+        let modified = record("192.0.2.9");
+        assert!(matches!(
+            replay_check(&entry, Some(&modified), &[], false),
+            ReplayCheck::Conflict(_)
+        ));
+        assert_eq!(
+            replay_check(&entry, Some(&modified), &[], true),
+            ReplayCheck::Proceed
+        );
+        assert!(matches!(
+            replay_check(&entry, None, &[], true),
+            ReplayCheck::Conflict(_)
+        ));
+    }
+
+    #[test]
+    fn missing_snapshot_requires_confirmation() {
+        let entry = queued(
+            QueuedWriteOperation::Delete {
+                record_id: "r1".to_string(),
+            },
+            None,
+        );
+        let current = record("192.0.2.1");
+        assert!(matches!(
+            replay_check(&entry, Some(&current), &[], false),
+            ReplayCheck::Conflict(_)
+        ));
+        assert_eq!(
+            replay_check(&entry, Some(&current), &[], true),
+            ReplayCheck::Proceed
+        );
+        assert_eq!(
+            replay_check(&entry, None, &[], false),
+            ReplayCheck::AlreadyApplied
+        );
+    }
+
+    #[test]
+    fn create_skips_existing_record() {
+        let entry = queued(
+            QueuedWriteOperation::Create {
+                request: CreateDnsRecordRequest {
+                    domain_id: "d1".to_string(),
+                    name: "www".to_string(),
+                    ttl: 600,
+                    data: RecordData::A {
+                        address: "192.0.2.1".to_string(),
+                    },
+                    proxied: None,
+                },
+            },
+            None,
+        );
+        assert_eq!(
+            replay_check(&entry, None, &[record("192.0.2.1")], false),
+            ReplayCheck::AlreadyApplied
+        );
+        assert_eq!(
+            replay_check(&entry, None, &[record("192.0.2.2")], false),
+            ReplayCheck::Proceed
+        );
+    }
+
+    #[test]
+    fn offline_errors() {
+        assert!(is_offline_error(&CoreError::NetworkError(
+            "reset".to_string()
+        )));
+        assert!(is_offline_error(&CoreError::Provider(
+            ProviderError::NetworkError {
+                provider: "cloudflare".to_string(),
+                detail: "timeout".to_string(),
+            }
+        )));
+        assert!(!is_offline_error(&CoreError::ValidationError(
+            "bad".to_string()
+        )));
+    }
+}
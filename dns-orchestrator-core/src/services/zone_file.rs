@@ -0,0 +1,1001 @@
+//! BIND zone 文件（RFC 1035 主文件格式）生成与解析
+//!
+//! 导出时优先使用服务商返回的 SOA；多数服务商不通过记录接口暴露 SOA，此时合成一条
+//! 使文件可被 BIND 等工具直接加载（见 [`soa_line`]）。顶级 NS 由服务商托管，不导出。导入时两者都跳过。
+//! 记录名称输出为相对名称（根域为 `@`），目标主机名输出为 FQDN。
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use dns_orchestrator_provider::txt;
+
+use crate::types::{DnsRecord, RecordData, ZoneFileSkippedEntry};
+
+/// 没有记录时使用的默认 `$TTL`
+const DEFAULT_TTL: u32 = 3600;
+/// Cloudflare 的 "自动" TTL（值为 1）在导出时对应的实际 TTL
+const AUTO_TTL: u32 = 300;
+/// 合成 SOA 的 refresh / retry / expire / minimum（RFC 1912 §2.2 建议值）
+const SOA_TIMERS: (u32, u32, u32, u32) = (7200, 3600, 1_209_600, 300);
+
+/// 生成 zone 文件内容
+pub(crate) fn render_zone_file(domain_name: &str, records: &[DnsRecord]) -> String {
+    let origin = domain_name.trim_end_matches('.').to_lowercase();
+    let default_ttl = most_common_ttl(records);
+
+    let provider_soa = records
+        .iter()
+        .find(|record| matches!(record.data, RecordData::SOA { .. }));
+
+    let mut out = String::new();
+    let _ = writeln!(out, "; Zone file for {origin}");
+    let _ = writeln!(out, "; Exported by DNS Orchestrator");
+    if provider_soa.is_some() {
+        let _ = writeln!(
+            out,
+            "; Apex NS records are managed by the provider and not included."
+        );
+    } else {
+        let _ = writeln!(
+            out,
+            "; SOA is synthesized; the provider's actual SOA and apex NS records are not included."
+        );
+    }
+    let _ = writeln!(out, "$ORIGIN {origin}.");
+    let _ = writeln!(out, "$TTL {default_ttl}");
+    out.push('\n');
+    match provider_soa {
+        Some(record) => {
+            let ttl = ttl_column(effective_ttl(record.ttl), default_ttl);
+            let _ = writeln!(
+                out,
+                "{:<24} {ttl:<6} IN {:<5} {}",
+                "@",
+                "SOA",
+                rdata(&record.data, &origin)
+            );
+        }
+        None => {
+            let _ = writeln!(out, "{}", soa_line(&origin, records));
+        }
+    }
+
+    // 按 名称 + 类型 + 值 稳定排序，服务商返回顺序变化时输出保持不变
+    let mut lines: Vec<(String, &str, String, u32)> = records
+        .iter()
+        .map(|record| (relative_name(&record.name, &origin), record))
+        .filter(|(name, record)| !is_provider_managed(name, &record.data))
+        .map(|(name, record)| {
+            (
+                name,
+                record_type_name(&record.data),
+                rdata(&record.data, &origin),
+                effective_ttl(record.ttl),
+            )
+        })
+        .collect();
+    lines.sort();
+
+    for (name, record_type, value, ttl) in lines {
+        let ttl = ttl_column(ttl, default_ttl);
+        let _ = writeln!(out, "{name:<24} {ttl:<6} IN {record_type:<5} {value}");
+    }
+
+    out
+}
+
+/// 与 `$TTL` 相同时省略 TTL 列
+fn ttl_column(ttl: u32, default_ttl: u32) -> String {
+    if ttl == default_ttl {
+        String::new()
+    } else {
+        ttl.to_string()
+    }
+}
+
+/// 由服务商托管、不参与导出/复制/对比的记录：SOA 与顶级 NS
+///
+/// `name` 为相对名称（根域为 `@`）。
+pub(crate) fn is_provider_managed(name: &str, data: &RecordData) -> bool {
+    match data {
+        RecordData::SOA { .. } => true,
+        RecordData::NS { .. } => name == "@",
+        _ => false,
+    }
+}
+
+/// 合成的 SOA 记录
+///
+/// - MNAME 取服务商返回的第一个顶级 NS（按名称排序），没有时使用域名本身
+/// - serial 取记录最后更新日期（`YYYYMMDD01`），记录不变时导出结果保持不变；
+///   服务商不返回更新时间时为 1
+fn soa_line(origin: &str, records: &[DnsRecord]) -> String {
+    let mname = records
+        .iter()
+        .filter(|r| relative_name(&r.name, origin) == "@")
+        .filter_map(|r| match &r.data {
+            RecordData::NS { nameserver } => Some(absolute_name(nameserver, origin).to_lowercase()),
+            _ => None,
+        })
+        .min()
+        .unwrap_or_else(|| format!("{origin}."));
+    let serial = records
+        .iter()
+        .filter_map(|r| r.updated_at)
+        .max()
+        .and_then(|t| t.format("%Y%m%d01").to_string().parse::<u32>().ok())
+        .unwrap_or(1);
+    let (refresh, retry, expire, minimum) = SOA_TIMERS;
+    format!(
+        "{:<24} {:<6} IN {:<5} {mname} hostmaster.{origin}. {serial} {refresh} {retry} {expire} {minimum}",
+        "@", "", "SOA"
+    )
+}
+
+/// 最常见的 TTL 作为 `$TTL`，相同次数时取较小值
+fn most_common_ttl(records: &[DnsRecord]) -> u32 {
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for record in records {
+        *counts.entry(effective_ttl(record.ttl)).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|(a_ttl, a_count), (b_ttl, b_count)| {
+            a_count.cmp(b_count).then_with(|| b_ttl.cmp(a_ttl))
+        })
+        .map_or(DEFAULT_TTL, |(ttl, _)| ttl)
+}
+
+pub(crate) const fn effective_ttl(ttl: u32) -> u32 {
+    if ttl <= 1 {
+        AUTO_TTL
+    } else {
+        ttl
+    }
+}
+
+/// 转换为相对于 origin 的名称（兼容服务商返回 FQDN 的情况）
+pub(crate) fn relative_name(name: &str, origin: &str) -> String {
+    let name = name.trim_end_matches('.');
+    if name.is_empty() || name == "@" || name.eq_ignore_ascii_case(origin) {
+        return "@".to_string();
+    }
+    let lower = name.to_lowercase();
+    match lower.strip_suffix(origin) {
+        Some(prefix) if prefix.ends_with('.') => name[..prefix.len() - 1].to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// 目标主机名转为 FQDN
+fn absolute_name(target: &str, origin: &str) -> String {
+    if target.is_empty() || target == "@" {
+        return format!("{origin}.");
+    }
+    if target.ends_with('.') {
+        target.to_string()
+    } else {
+        format!("{target}.")
+    }
+}
+
+pub(crate) fn record_type_name(data: &RecordData) -> &'static str {
+    match data {
+        RecordData::A { .. } => "A",
+        RecordData::AAAA { .. } => "AAAA",
+        RecordData::CNAME { .. } => "CNAME",
+        RecordData::MX { .. } => "MX",
+        RecordData::TXT { .. } => "TXT",
+        RecordData::NS { .. } => "NS",
+        RecordData::SRV { .. } => "SRV",
+        RecordData::CAA { .. } => "CAA",
+        RecordData::SOA { .. } => "SOA",
+    }
+}
+
+pub(crate) fn rdata(data: &RecordData, origin: &str) -> String {
+    match data {
+        RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+        RecordData::CNAME { target } => absolute_name(target, origin),
+        RecordData::NS { nameserver } => absolute_name(nameserver, origin),
+        RecordData::MX { priority, exchange } => {
+            format!("{priority} {}", absolute_name(exchange, origin))
+        }
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => format!(
+            "{priority} {weight} {port} {}",
+            absolute_name(target, origin)
+        ),
+        RecordData::TXT { text } => txt_strings(text),
+        RecordData::CAA { flags, tag, value } => format!("{flags} {tag} {}", quote(value)),
+        RecordData::SOA {
+            mname,
+            rname,
+            serial,
+            refresh,
+            retry,
+            expire,
+            minimum,
+        } => format!(
+            "{} {} {serial} {refresh} {retry} {expire} {minimum}",
+            absolute_name(mname, origin),
+            absolute_name(rname, origin)
+        ),
+    }
+}
+
+/// TXT 值按 255 字节拆分为多个 character-string
+fn txt_strings(text: &str) -> String {
+    txt::split(text)
+        .into_iter()
+        .map(quote)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 加引号并转义 `"` 与 `\`
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        if ch == '"' || ch == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(ch);
+    }
+    quoted.push('"');
+    quoted
+}
+
+// ============ 解析 ============
+
+/// 解析出的单条记录（名称为相对于域名的名称，根域为 `@`）
+#[derive(Debug, Clone)]
+pub(crate) struct ZoneFileRecord {
+    pub name: String,
+    pub ttl: u32,
+    pub data: RecordData,
+    /// 来源行号（写入前被拒绝时计入跳过）
+    pub line: usize,
+    /// 原始内容（已去除注释）
+    pub content: String,
+}
+
+/// zone 文件解析结果
+#[derive(Debug, Default)]
+pub(crate) struct ParsedZoneFile {
+    pub records: Vec<ZoneFileRecord>,
+    pub skipped: Vec<ZoneFileSkippedEntry>,
+}
+
+/// 合并括号续行后的单个条目
+struct Entry {
+    line: usize,
+    text: String,
+    /// 以空白开头，沿用上一条记录的名称
+    inherits_owner: bool,
+}
+
+/// 词法单元
+struct Token {
+    text: String,
+    quoted: bool,
+}
+
+/// 解析 zone 文件
+///
+/// 不支持的记录类型、指令与不属于 `domain_name` 的记录列入 `skipped`，不会使整体解析失败。
+pub(crate) fn parse_zone_file(zone_text: &str, domain_name: &str) -> ParsedZoneFile {
+    let domain = domain_name.trim_end_matches('.').to_lowercase();
+    let mut origin = domain.clone();
+    let mut default_ttl: Option<u32> = None;
+    let mut last_ttl: Option<u32> = None;
+    let mut last_owner: Option<String> = None;
+    let mut parsed = ParsedZoneFile::default();
+
+    for entry in logical_entries(zone_text) {
+        let tokens = tokenize(&entry.text);
+        let Some(first) = tokens.first() else {
+            continue;
+        };
+        let content = entry.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut skip = |reason: String| {
+            parsed.skipped.push(ZoneFileSkippedEntry {
+                line: entry.line,
+                content: content.clone(),
+                reason,
+            });
+        };
+
+        // 指令
+        if !entry.inherits_owner && !first.quoted && first.text.starts_with('$') {
+            let value = tokens.get(1).map(|t| t.text.as_str());
+            match (first.text.to_uppercase().as_str(), value) {
+                ("$TTL", Some(value)) => match parse_ttl(value) {
+                    Some(ttl) => default_ttl = Some(ttl),
+                    None => skip(format!("无效的 $TTL: {value}")),
+                },
+                ("$ORIGIN", Some(value)) => origin = absolute_fqdn(value, &origin),
+                (directive, _) => skip(format!("不支持 {directive} 指令")),
+            }
+            continue;
+        }
+
+        let mut rest = tokens.iter();
+        let owner = if entry.inherits_owner {
+            last_owner.clone()
+        } else {
+            rest.next().map(|t| absolute_fqdn(&t.text, &origin))
+        };
+        let Some(owner) = owner else {
+            skip("缺少记录名".to_string());
+            continue;
+        };
+        last_owner = Some(owner.clone());
+
+        // TTL 与 class 顺序任意，均可省略
+        let mut ttl = None;
+        let mut record_type = None;
+        for token in rest.by_ref() {
+            if let Some(value) = parse_ttl(&token.text) {
+                ttl = Some(value);
+            } else if ["IN", "CH", "HS", "CS"].contains(&token.text.to_uppercase().as_str()) {
+                if !token.text.eq_ignore_ascii_case("IN") {
+                    record_type = Some(String::new());
+                    break;
+                }
+            } else {
+                record_type = Some(token.text.to_uppercase());
+                break;
+            }
+        }
+        let rdata: Vec<&Token> = rest.collect();
+
+        let Some(record_type) = record_type.filter(|t| !t.is_empty()) else {
+            skip("缺少记录类型或不是 IN class".to_string());
+            continue;
+        };
+        let ttl = ttl.or(default_ttl).or(last_ttl).unwrap_or(DEFAULT_TTL);
+        last_ttl = Some(ttl);
+
+        let Some(name) = relative_to_domain(&owner, &domain) else {
+            skip(format!("{owner} 不属于 {domain}"));
+            continue;
+        };
+        match record_type.as_str() {
+            "SOA" => {
+                skip("SOA 由 DNS 服务商管理".to_string());
+                continue;
+            }
+            "NS" if name == "@" => {
+                skip("根域 NS 由 DNS 服务商管理".to_string());
+                continue;
+            }
+            _ => {}
+        }
+
+        match to_record_data(&record_type, &rdata, &origin) {
+            Ok(data) => parsed.records.push(ZoneFileRecord {
+                name,
+                ttl,
+                data,
+                line: entry.line,
+                content,
+            }),
+            Err(reason) => skip(format!("{record_type}: {reason}")),
+        }
+    }
+
+    parsed
+}
+
+/// 去除注释并合并括号内的续行
+fn logical_entries(zone_text: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut current: Option<Entry> = None;
+    let mut depth = 0usize;
+
+    for (index, raw) in zone_text.lines().enumerate() {
+        let content = strip_comment(raw, &mut depth);
+        let entry = match current.as_mut() {
+            Some(entry) => entry,
+            None if content.trim().is_empty() => continue,
+            None => current.insert(Entry {
+                line: index + 1,
+                text: String::new(),
+                inherits_owner: raw.starts_with([' ', '\t']),
+            }),
+        };
+        entry.text.push_str(&content);
+        entry.text.push(' ');
+
+        if depth == 0 {
+            entries.extend(current.take());
+        }
+    }
+    // 括号未闭合时按已读取的内容处理
+    entries.extend(current);
+    entries
+}
+
+/// 去掉 `;` 注释，括号替换为空格并更新嵌套深度
+fn strip_comment(line: &str, depth: &mut usize) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_quote = false;
+    let mut escaped = false;
+    for ch in line.chars() {
+        if escaped {
+            escaped = false;
+            out.push(ch);
+            continue;
+        }
+        match ch {
+            '\\' => {
+                escaped = true;
+                out.push(ch);
+            }
+            '"' => {
+                in_quote = !in_quote;
+                out.push(ch);
+            }
+            ';' if !in_quote => break,
+            '(' if !in_quote => {
+                *depth += 1;
+                out.push(' ');
+            }
+            ')' if !in_quote => {
+                *depth = depth.saturating_sub(1);
+                out.push(' ');
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// 按空白切分，引号内的内容作为单个 token 并处理转义
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if ch == '"' {
+            chars.next();
+            let mut bytes = Vec::new();
+            while let Some(ch) = chars.next() {
+                match ch {
+                    '"' => break,
+                    '\\' => {
+                        let digits: String =
+                            std::iter::from_fn(|| chars.next_if(char::is_ascii_digit))
+                                .take(3)
+                                .collect();
+                        if digits.len() == 3 {
+                            // `\DDD` 十进制字节
+                            bytes.push(digits.parse::<u8>().unwrap_or(b'?'));
+                        } else if !digits.is_empty() {
+                            bytes.extend_from_slice(digits.as_bytes());
+                        } else if let Some(escaped) = chars.next() {
+                            let mut buf = [0u8; 4];
+                            bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                        }
+                    }
+                    _ => {
+                        let mut buf = [0u8; 4];
+                        bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+                    }
+                }
+            }
+            tokens.push(Token {
+                text: String::from_utf8_lossy(&bytes).into_owned(),
+                quoted: true,
+            });
+        } else {
+            let text: String =
+                std::iter::from_fn(|| chars.next_if(|c| !c.is_whitespace())).collect();
+            tokens.push(Token {
+                text,
+                quoted: false,
+            });
+        }
+    }
+    tokens
+}
+
+/// 解析 TTL，支持 BIND 单位（如 `1h30m`）
+fn parse_ttl(value: &str) -> Option<u32> {
+    if !value.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut total: u64 = 0;
+    let mut number: u64 = 0;
+    let mut has_digits = false;
+    for ch in value.chars() {
+        if let Some(digit) = ch.to_digit(10) {
+            number = number.checked_mul(10)?.checked_add(u64::from(digit))?;
+            has_digits = true;
+            continue;
+        }
+        let unit = match ch.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86_400,
+            'w' => 604_800,
+            _ => return None,
+        };
+        if !has_digits {
+            return None;
+        }
+        total = total.checked_add(number.checked_mul(unit)?)?;
+        number = 0;
+        has_digits = false;
+    }
+    u32::try_from(total.checked_add(number)?).ok()
+}
+
+/// 转为不带末尾点的小写 FQDN
+fn absolute_fqdn(name: &str, origin: &str) -> String {
+    if name == "@" {
+        return origin.to_string();
+    }
+    if let Some(absolute) = name.strip_suffix('.') {
+        return absolute.to_lowercase();
+    }
+    if origin.is_empty() {
+        name.to_lowercase()
+    } else {
+        format!("{}.{origin}", name.to_lowercase())
+    }
+}
+
+/// FQDN 转为相对于域名的名称，不属于该域名时返回 `None`
+fn relative_to_domain(fqdn: &str, domain: &str) -> Option<String> {
+    if fqdn == domain {
+        return Some("@".to_string());
+    }
+    fqdn.strip_suffix(domain)
+        .and_then(|prefix| prefix.strip_suffix('.'))
+        .filter(|prefix| !prefix.is_empty())
+        .map(ToString::to_string)
+}
+
+fn to_record_data(record_type: &str, rdata: &[&Token], origin: &str) -> Result<RecordData, String> {
+    let field = |index: usize, name: &str| {
+        rdata
+            .get(index)
+            .map(|t| t.text.as_str())
+            .ok_or_else(|| format!("缺少 {name}"))
+    };
+    let number = |index: usize, name: &str| {
+        field(index, name)?
+            .parse::<u16>()
+            .map_err(|_| format!("无效的 {name}"))
+    };
+    let host = |index: usize, name: &str| field(index, name).map(|v| absolute_fqdn(v, origin));
+
+    match record_type {
+        "A" => {
+            let address = field(0, "地址")?;
+            address
+                .parse::<Ipv4Addr>()
+                .map_err(|_| format!("无效的 IPv4 地址 {address}"))?;
+            Ok(RecordData::A {
+                address: address.to_string(),
+            })
+        }
+        "AAAA" => {
+            let address = field(0, "地址")?;
+            address
+                .parse::<Ipv6Addr>()
+                .map_err(|_| format!("无效的 IPv6 地址 {address}"))?;
+            Ok(RecordData::AAAA {
+                address: address.to_string(),
+            })
+        }
+        "CNAME" => Ok(RecordData::CNAME {
+            target: host(0, "目标")?,
+        }),
+        "NS" => Ok(RecordData::NS {
+            nameserver: host(0, "名称服务器")?,
+        }),
+        "MX" => Ok(RecordData::MX {
+            priority: number(0, "优先级")?,
+            exchange: host(1, "邮件服务器")?,
+        }),
+        "SRV" => Ok(RecordData::SRV {
+            priority: number(0, "优先级")?,
+            weight: number(1, "权重")?,
+            port: number(2, "端口")?,
+            target: host(3, "目标")?,
+        }),
+        "TXT" => {
+            if rdata.is_empty() {
+                return Err("缺少文本内容".to_string());
+            }
+            // 多个 character-string 拼接为完整值
+            Ok(RecordData::TXT {
+                text: rdata.iter().map(|t| t.text.as_str()).collect(),
+            })
+        }
+        "CAA" => Ok(RecordData::CAA {
+            flags: field(0, "flags")?
+                .parse::<u8>()
+                .map_err(|_| "无效的 flags".to_string())?,
+            tag: field(1, "tag")?.to_lowercase(),
+            value: field(2, "value")?.to_string(),
+        }),
+        other => Err(format!("不支持的记录类型 {other}，已跳过")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, ttl: u32, data: RecordData) -> DnsRecord {
+        DnsRecord {
+            id: name.to_string(),
+            domain_id: "example.com".to_string(),
+            name: name.to_string(),
+            ttl,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// 记录行（不含注释、指令与合成的 SOA）
+    fn lines(zone: &str) -> Vec<String> {
+        zone.lines()
+            .filter(|l| !l.starts_with(';') && !l.starts_with('$') && !l.is_empty())
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|l| !l.starts_with("@ IN SOA "))
+            .collect()
+    }
+
+    #[test]
+    fn renders_common_records() {
+        let records = vec![
+            record(
+                "@",
+                600,
+                RecordData::A {
+                    address: "192.0.2.1".to_string(),
+                },
+            ),
+            record(
+                "*",
+                600,
+                RecordData::CNAME {
+                    target: "example.com".to_string(),
+                },
+            ),
+            record(
+                "www.example.com",
+                1,
+                RecordData::CNAME {
+                    target: "cdn.example.net.".to_string(),
+                },
+            ),
+            record(
+                "@",
+                600,
+                RecordData::MX {
+                    priority: 10,
+                    exchange: "mail.example.com".to_string(),
+                },
+            ),
+            record(
+                "_sip._tcp",
+                600,
+                RecordData::SRV {
+                    priority: 10,
+                    weight: 60,
+                    port: 5060,
+                    target: "sip.example.com".to_string(),
+                },
+            ),
+            record(
+                "@",
+                600,
+                RecordData::CAA {
+                    flags: 0,
+                    tag: "issue".to_string(),
+                    value: "letsencrypt.org".to_string(),
+                },
+            ),
+            record(
+                "@",
+                600,
+                RecordData::NS {
+                    nameserver: "ns1.provider.net".to_string(),
+                },
+            ),
+            record(
+                "sub",
+                600,
+                RecordData::NS {
+                    nameserver: "ns1.other.net".to_string(),
+                },
+            ),
+        ];
+
+        let zone = render_zone_file("example.com.", &records);
+        assert!(zone.contains("$ORIGIN example.com.\n"));
+        assert!(zone.contains("$TTL 600\n"));
+        assert_eq!(
+            lines(&zone),
+            vec![
+                "* IN CNAME example.com.",
+                "@ IN A 192.0.2.1",
+                "@ IN CAA 0 issue \"letsencrypt.org\"",
+                "@ IN MX 10 mail.example.com.",
+                "_sip._tcp IN SRV 10 60 5060 sip.example.com.",
+                "sub IN NS ns1.other.net.",
+                "www 300 IN CNAME cdn.example.net.",
+            ]
+        );
+    }
+
+    #[test]
+    fn escapes_and_splits_txt() {
+        let long = "a".repeat(300);
+        let records = vec![
+            record(
+                "@",
+                3600,
+                RecordData::TXT {
+                    text: r#"say "hi" \ bye"#.to_string(),
+                },
+            ),
+            record("long", 3600, RecordData::TXT { text: long }),
+        ];
+
+        let zone = render_zone_file("example.com", &records);
+        let lines = lines(&zone);
+        assert_eq!(lines[0], r#"@ IN TXT "say \"hi\" \\ bye""#);
+        assert_eq!(
+            lines[1],
+            format!("long IN TXT \"{}\" \"{}\"", "a".repeat(255), "a".repeat(45))
+        );
+    }
+
+    #[test]
+    fn output_is_independent_of_record_order() {
+        let mut records = vec![
+            record(
+                "www",
+                600,
+                RecordData::A {
+                    address: "192.0.2.2".to_string(),
+                },
+            ),
+            record(
+                "www",
+                600,
+                RecordData::A {
+                    address: "192.0.2.1".to_string(),
+                },
+            ),
+            record(
+                "api",
+                600,
+                RecordData::TXT {
+                    text: "v=1".to_string(),
+                },
+            ),
+        ];
+        let first = render_zone_file("example.com", &records);
+        records.reverse();
+        assert_eq!(first, render_zone_file("example.com", &records));
+        assert_eq!(
+            lines(&first),
+            vec![
+                "api IN TXT \"v=1\"",
+                "www IN A 192.0.2.1",
+                "www IN A 192.0.2.2",
+            ]
+        );
+    }
+
+    #[test]
+    fn synthesizes_soa() {
+        let mut ns = record(
+            "@",
+            600,
+            RecordData::NS {
+                nameserver: "NS2.provider.net".to_string(),
+            },
+        );
+        ns.updated_at = chrono::DateTime::parse_from_rfc3339("2024-03-05T23:00:00Z")
+            .ok()
+            .map(|t| t.with_timezone(&chrono::Utc));
+        let records = vec![
+            ns,
+            record(
+                "@",
+                600,
+                RecordData::NS {
+                    nameserver: "ns1.provider.net.".to_string(),
+                },
+            ),
+        ];
+
+        let zone = render_zone_file("example.com", &records);
+        let soa = zone
+            .lines()
+            .find(|l| l.starts_with('@') && l.contains(" SOA "))
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "));
+        assert_eq!(
+            soa.as_deref(),
+            Some("@ IN SOA ns1.provider.net. hostmaster.example.com. 2024030501 7200 3600 1209600 300")
+        );
+
+        let empty = render_zone_file("example.com", &[]);
+        assert!(empty.contains(" example.com. hostmaster.example.com. 1 "));
+    }
+
+    #[test]
+    fn uses_provider_soa() {
+        let records = vec![
+            record(
+                "@",
+                300,
+                RecordData::SOA {
+                    mname: "ns1.provider.net.".to_string(),
+                    rname: "admin.example.com".to_string(),
+                    serial: 2_026_101_601,
+                    refresh: 3600,
+                    retry: 600,
+                    expire: 1_209_600,
+                    minimum: 300,
+                },
+            ),
+            record(
+                "www",
+                300,
+                RecordData::A {
+                    address: "192.0.2.1".to_string(),
+                },
+            ),
+        ];
+
+        let zone = render_zone_file("example.com", &records);
+        assert!(!zone.contains("SOA is synthesized"));
+        let soa: Vec<String> = zone
+            .lines()
+            .filter(|l| l.contains(" SOA "))
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(
+            soa,
+            ["@ IN SOA ns1.provider.net. admin.example.com. 2026101601 3600 600 1209600 300"]
+        );
+        assert_eq!(lines(&zone), vec!["www IN A 192.0.2.1"]);
+    }
+
+    #[test]
+    fn empty_zone_uses_default_ttl() {
+        let zone = render_zone_file("example.com", &[]);
+        assert!(zone.contains("$TTL 3600\n"));
+        assert!(lines(&zone).is_empty());
+    }
+
+    const ZONE: &str = r#"$ORIGIN example.com.
+$TTL 1h
+; 注释行
+@       IN SOA ns1.provider.net. admin.example.com. (
+            2024010101 ; serial
+            7200 3600 1209600 300 )
+@           IN  NS      ns1.provider.net.
+@       600 IN  A       192.0.2.1
+            IN  AAAA    2001:db8::1
+www         IN  CNAME   @
+*.dev   IN 300  CNAME   cdn.example.net.
+mail.example.com.  IN MX 10 mx1
+@           IN  TXT     ( "v=spf1 include:_spf.example.net "
+                          "-all" ) ; 多段 TXT
+quote       IN  TXT     "say \"hi\"; ok"
+_sip._tcp   IN  SRV     10 60 5060 sip
+@           IN  CAA     0 issue "letsencrypt.org"
+sub         IN  NS      ns1.other.net.
+ptr         IN  PTR     host.example.com.
+other.org.  IN  A       192.0.2.9
+bad         IN  A       not-an-ip
+$ORIGIN lab.example.com.
+host            A       192.0.2.10
+$INCLUDE other.zone
+"#;
+
+    #[test]
+    fn parses_zone_file() {
+        let parsed = parse_zone_file(ZONE, "example.com");
+        let records: Vec<String> = parsed
+            .records
+            .iter()
+            .map(|r| format!("{} {} {}", r.name, r.ttl, r.data.display_value()))
+            .collect();
+
+        assert_eq!(
+            records,
+            vec![
+                "@ 600 192.0.2.1",
+                "@ 3600 2001:db8::1",
+                "www 3600 example.com",
+                "*.dev 300 cdn.example.net",
+                "mail 3600 mx1.example.com",
+                "@ 3600 v=spf1 include:_spf.example.net -all",
+                "quote 3600 say \"hi\"; ok",
+                "_sip._tcp 3600 sip.example.com",
+                "@ 3600 letsencrypt.org",
+                "sub 3600 ns1.other.net",
+                "host.lab 3600 192.0.2.10",
+            ]
+        );
+
+        let reasons: Vec<(usize, &str)> = parsed
+            .skipped
+            .iter()
+            .map(|s| (s.line, s.reason.as_str()))
+            .collect();
+        assert_eq!(reasons.len(), 6);
+        assert_eq!(reasons[0], (4, "SOA 由 DNS 服务商管理"));
+        assert_eq!(reasons[1], (7, "根域 NS 由 DNS 服务商管理"));
+        assert!(reasons[2].1.contains("PTR"));
+        assert!(reasons[3].1.contains("不属于"));
+        assert!(reasons[4].1.contains("无效的 IPv4 地址"));
+        assert!(reasons[5].1.contains("$INCLUDE"));
+    }
+
+    #[test]
+    fn parses_ttl_units() {
+        assert_eq!(parse_ttl("300"), Some(300));
+        assert_eq!(parse_ttl("1h30m"), Some(5400));
+        assert_eq!(parse_ttl("1W"), Some(604_800));
+        assert_eq!(parse_ttl("IN"), None);
+        assert_eq!(parse_ttl("5x"), None);
+    }
+
+    #[test]
+    fn round_trips_exported_zone() {
+        let records = vec![
+            record(
+                "@",
+                600,
+                RecordData::MX {
+                    priority: 10,
+                    exchange: "mail.example.com".to_string(),
+                },
+            ),
+            record(
+                "long",
+                600,
+                RecordData::TXT {
+                    text: format!("{}\"{}", "a".repeat(254), "b".repeat(20)),
+                },
+            ),
+        ];
+        let zone = render_zone_file("example.com", &records);
+        let parsed = parse_zone_file(&zone, "example.com");
+
+        // 只跳过合成的 SOA
+        assert_eq!(parsed.skipped.len(), 1);
+        assert!(parsed.skipped[0].content.contains("SOA"));
+        assert_eq!(parsed.records.len(), 2);
+        for (original, imported) in records.iter().zip(&parsed.records) {
+            assert_eq!(imported.name, original.name);
+            assert_eq!(imported.ttl, original.ttl);
+            assert_eq!(imported.data, original.data);
+        }
+    }
+}
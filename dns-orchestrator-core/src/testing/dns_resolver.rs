@@ -0,0 +1,210 @@
+//! 可编程的假 DNS 解析后端，用于在不访问网络的情况下测试工具箱逻辑
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::traits::DnsResolverBackend;
+use crate::types::{
+    DnsAnswer, DnsAnswerRecord, DnsQuery, DnsQueryError, DnsQueryErrorKind, DnsQueryType,
+    DnsRecordData, DnsServer,
+};
+
+/// 预置应答的默认 TTL
+pub const FAKE_TTL: u32 = 300;
+
+type FakeResponse = Result<Vec<DnsAnswerRecord>, DnsQueryErrorKind>;
+
+/// 可编程的假 DNS 解析后端
+///
+/// 按（服务器、域名、记录类型）预置应答，域名忽略大小写和末尾的点。
+/// 查找顺序：指定服务器的应答 → 不限服务器的应答 → [`Self::otherwise`] 设置的兜底应答，
+/// 均未命中时返回 NOERROR 无记录。
+#[derive(Debug, Default)]
+pub struct FakeDnsResolver {
+    responses: HashMap<(Option<DnsServer>, String, DnsQueryType), FakeResponse>,
+    fallback: Option<FakeResponse>,
+    system_nameservers: Vec<String>,
+    queries: Mutex<Vec<DnsQuery>>,
+}
+
+impl FakeDnsResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 预置应答（任意服务器）
+    #[must_use]
+    pub fn answer(
+        self,
+        name: &str,
+        record_type: DnsQueryType,
+        data: impl IntoIterator<Item = DnsRecordData>,
+    ) -> Self {
+        self.respond(None, name, record_type, Ok(records(name, data)))
+    }
+
+    /// 预置指定服务器的应答
+    #[must_use]
+    pub fn answer_from(
+        self,
+        server: DnsServer,
+        name: &str,
+        record_type: DnsQueryType,
+        data: impl IntoIterator<Item = DnsRecordData>,
+    ) -> Self {
+        self.respond(Some(server), name, record_type, Ok(records(name, data)))
+    }
+
+    /// 预置带自定义 TTL 等字段的完整记录
+    #[must_use]
+    pub fn answer_records(
+        self,
+        name: &str,
+        record_type: DnsQueryType,
+        records: Vec<DnsAnswerRecord>,
+    ) -> Self {
+        self.respond(None, name, record_type, Ok(records))
+    }
+
+    /// 预置失败（NXDOMAIN、SERVFAIL、超时、截断等）
+    #[must_use]
+    pub fn fail(self, name: &str, record_type: DnsQueryType, kind: DnsQueryErrorKind) -> Self {
+        self.respond(None, name, record_type, Err(kind))
+    }
+
+    /// 预置指定服务器的失败
+    #[must_use]
+    pub fn fail_from(
+        self,
+        server: DnsServer,
+        name: &str,
+        record_type: DnsQueryType,
+        kind: DnsQueryErrorKind,
+    ) -> Self {
+        self.respond(Some(server), name, record_type, Err(kind))
+    }
+
+    /// 未预置的查询统一返回的应答（记录名使用查询的域名）
+    #[must_use]
+    pub fn otherwise(mut self, response: Result<Vec<DnsRecordData>, DnsQueryErrorKind>) -> Self {
+        self.fallback = Some(response.map(|data| {
+            data.into_iter()
+                .map(|data| DnsAnswerRecord {
+                    name: String::new(),
+                    ttl: FAKE_TTL,
+                    data,
+                })
+                .collect()
+        }));
+        self
+    }
+
+    /// 设置系统 DNS 服务器列表
+    #[must_use]
+    pub fn with_system_nameservers(mut self, servers: &[&str]) -> Self {
+        self.system_nameservers = servers.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// 已收到的查询（按时间顺序）
+    pub fn queries(&self) -> Vec<DnsQuery> {
+        self.queries
+            .lock()
+            .map(|queries| queries.clone())
+            .unwrap_or_default()
+    }
+
+    fn respond(
+        mut self,
+        server: Option<DnsServer>,
+        name: &str,
+        record_type: DnsQueryType,
+        response: FakeResponse,
+    ) -> Self {
+        self.responses
+            .insert((server, normalize(name), record_type), response);
+        self
+    }
+
+    fn lookup(&self, query: &DnsQuery) -> FakeResponse {
+        let name = normalize(&query.name);
+        let response = self
+            .responses
+            .get(&(Some(query.server), name.clone(), query.record_type))
+            .or_else(|| self.responses.get(&(None, name, query.record_type)));
+        if let Some(response) = response {
+            return response.clone();
+        }
+
+        match &self.fallback {
+            Some(Ok(records)) => Ok(records
+                .iter()
+                .map(|record| DnsAnswerRecord {
+                    name: fqdn(&query.name),
+                    ..record.clone()
+                })
+                .collect()),
+            Some(Err(kind)) => Err(*kind),
+            None => Err(DnsQueryErrorKind::NoRecords),
+        }
+    }
+}
+
+#[async_trait]
+impl DnsResolverBackend for FakeDnsResolver {
+    async fn query(&self, query: &DnsQuery) -> Result<DnsAnswer, DnsQueryError> {
+        if let Ok(mut queries) = self.queries.lock() {
+            queries.push(query.clone());
+        }
+
+        match self.lookup(query) {
+            Ok(records) if records.is_empty() => Err(error(DnsQueryErrorKind::NoRecords, query)),
+            Ok(records) => Ok(DnsAnswer {
+                records,
+                elapsed: Duration::ZERO,
+            }),
+            Err(kind) => Err(error(kind, query)),
+        }
+    }
+
+    fn system_nameservers(&self) -> Vec<String> {
+        self.system_nameservers.clone()
+    }
+}
+
+fn records(name: &str, data: impl IntoIterator<Item = DnsRecordData>) -> Vec<DnsAnswerRecord> {
+    data.into_iter()
+        .map(|data| DnsAnswerRecord {
+            name: fqdn(name),
+            ttl: FAKE_TTL,
+            data,
+        })
+        .collect()
+}
+
+fn normalize(name: &str) -> String {
+    name.trim_end_matches('.').to_lowercase()
+}
+
+fn fqdn(name: &str) -> String {
+    format!("{}.", name.trim_end_matches('.'))
+}
+
+fn error(kind: DnsQueryErrorKind, query: &DnsQuery) -> DnsQueryError {
+    let message = match kind {
+        DnsQueryErrorKind::NxDomain => format!("{} does not exist (NXDOMAIN)", query.name),
+        DnsQueryErrorKind::NoRecords => {
+            format!("no {} records found for {}", query.record_type, query.name)
+        }
+        DnsQueryErrorKind::ServFail => "server failure (SERVFAIL)".to_string(),
+        DnsQueryErrorKind::Refused => "query refused (REFUSED)".to_string(),
+        DnsQueryErrorKind::ErrorResponse => "error response (FORMERR)".to_string(),
+        DnsQueryErrorKind::Timeout => "request timed out".to_string(),
+        DnsQueryErrorKind::Truncated => "response truncated".to_string(),
+        DnsQueryErrorKind::Other => "network unreachable".to_string(),
+    };
+    DnsQueryError::new(kind, message)
+}
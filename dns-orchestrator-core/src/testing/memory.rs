@@ -0,0 +1,454 @@
+//! 内存存储实现
+
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tokio::sync::RwLock;
+
+use crate::error::{CoreError, CoreResult};
+use crate::traits::{
+    AccountCapabilityRepository, AccountRepository, ApiUsageRepository, AuditLogRepository,
+    ChangeWindowRepository, CredentialStore, CredentialsMap, DomainDerivedStatusRepository,
+    DomainMetadataRepository,
+};
+use crate::types::{
+    Account, AccountCapabilityCache, AccountStatus, ApiOperation, ApiUsageCount, AuditEntry,
+    AuditLogFilter, ChangeWindowRule, DomainDerivedStatus, DomainMetadata, DomainMetadataKey,
+    DomainMetadataUpdate, PaginatedResponse, PaginationParams, ProviderCredentials,
+};
+
+/// 内存账户仓库
+#[derive(Default)]
+pub struct InMemoryAccountRepository {
+    accounts: RwLock<Vec<Account>>,
+}
+
+#[async_trait]
+impl AccountRepository for InMemoryAccountRepository {
+    async fn find_all(&self) -> CoreResult<Vec<Account>> {
+        Ok(self.accounts.read().await.clone())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<Account>> {
+        Ok(self
+            .accounts
+            .read()
+            .await
+            .iter()
+            .find(|a| a.id == id)
+            .cloned())
+    }
+
+    async fn save(&self, account: &Account) -> CoreResult<()> {
+        let mut accounts = self.accounts.write().await;
+        if let Some(existing) = accounts.iter_mut().find(|a| a.id == account.id) {
+            *existing = account.clone();
+        } else {
+            accounts.push(account.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        self.accounts.write().await.retain(|a| a.id != id);
+        Ok(())
+    }
+
+    async fn save_all(&self, accounts: &[Account]) -> CoreResult<()> {
+        *self.accounts.write().await = accounts.to_vec();
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        id: &str,
+        status: AccountStatus,
+        error: Option<String>,
+    ) -> CoreResult<()> {
+        let mut accounts = self.accounts.write().await;
+        let account = accounts
+            .iter_mut()
+            .find(|a| a.id == id)
+            .ok_or_else(|| CoreError::AccountNotFound(id.to_string()))?;
+
+        account.status = Some(status);
+        account.error = error;
+        account.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+}
+
+/// 内存凭证存储
+#[derive(Default)]
+pub struct InMemoryCredentialStore {
+    credentials: RwLock<CredentialsMap>,
+}
+
+#[async_trait]
+impl CredentialStore for InMemoryCredentialStore {
+    async fn load_all(&self) -> CoreResult<CredentialsMap> {
+        Ok(self.credentials.read().await.clone())
+    }
+
+    async fn save_all(&self, credentials: &CredentialsMap) -> CoreResult<()> {
+        (*self.credentials.write().await).clone_from(credentials);
+        Ok(())
+    }
+
+    async fn get(&self, account_id: &str) -> CoreResult<Option<ProviderCredentials>> {
+        Ok(self.credentials.read().await.get(account_id).cloned())
+    }
+
+    async fn set(&self, account_id: &str, credentials: &ProviderCredentials) -> CoreResult<()> {
+        self.credentials
+            .write()
+            .await
+            .insert(account_id.to_string(), credentials.clone());
+        Ok(())
+    }
+
+    async fn remove(&self, account_id: &str) -> CoreResult<()> {
+        self.credentials.write().await.remove(account_id);
+        Ok(())
+    }
+
+    async fn load_raw_json(&self) -> CoreResult<String> {
+        let credentials = self.credentials.read().await;
+        serde_json::to_string(&*credentials)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    async fn save_raw_json(&self, json: &str) -> CoreResult<()> {
+        let credentials: CredentialsMap =
+            serde_json::from_str(json).map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        *self.credentials.write().await = credentials;
+        Ok(())
+    }
+}
+
+/// 内存域名元数据仓库
+#[derive(Default)]
+pub struct InMemoryDomainMetadataRepository {
+    entries: RwLock<HashMap<DomainMetadataKey, DomainMetadata>>,
+}
+
+#[async_trait]
+impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
+    async fn find_by_key(&self, key: &DomainMetadataKey) -> CoreResult<Option<DomainMetadata>> {
+        Ok(self.entries.read().await.get(key).cloned())
+    }
+
+    async fn find_by_keys(
+        &self,
+        keys: &[DomainMetadataKey],
+    ) -> CoreResult<HashMap<DomainMetadataKey, DomainMetadata>> {
+        let entries = self.entries.read().await;
+        Ok(keys
+            .iter()
+            .filter_map(|key| entries.get(key).map(|m| (key.clone(), m.clone())))
+            .collect())
+    }
+
+    async fn save(&self, key: &DomainMetadataKey, metadata: &DomainMetadata) -> CoreResult<()> {
+        let mut entries = self.entries.write().await;
+        // 空元数据不保存
+        if metadata.is_empty() {
+            entries.remove(key);
+        } else {
+            entries.insert(key.clone(), metadata.clone());
+        }
+        Ok(())
+    }
+
+    async fn batch_save(&self, entries: &[(DomainMetadataKey, DomainMetadata)]) -> CoreResult<()> {
+        for (key, metadata) in entries {
+            self.save(key, metadata).await?;
+        }
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        key: &DomainMetadataKey,
+        update: &DomainMetadataUpdate,
+    ) -> CoreResult<()> {
+        let mut metadata = self.find_by_key(key).await?.unwrap_or_default();
+        update.apply_to(&mut metadata);
+        self.save(key, &metadata).await
+    }
+
+    async fn delete(&self, key: &DomainMetadataKey) -> CoreResult<()> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn delete_by_account(&self, account_id: &str) -> CoreResult<()> {
+        self.entries
+            .write()
+            .await
+            .retain(|key, _| key.account_id != account_id);
+        Ok(())
+    }
+
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<(DomainMetadataKey, DomainMetadata)>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|(key, _)| key.account_id == account_id)
+            .map(|(key, metadata)| (key.clone(), metadata.clone()))
+            .collect())
+    }
+
+    async fn find_favorites_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<DomainMetadataKey>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|(key, metadata)| key.account_id == account_id && metadata.is_favorite)
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+
+    async fn find_by_tag(&self, tag: &str) -> CoreResult<Vec<DomainMetadataKey>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|(_, metadata)| metadata.tags.iter().any(|t| t == tag))
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+
+    async fn list_all_tags(&self) -> CoreResult<Vec<String>> {
+        let entries = self.entries.read().await;
+        let tags: HashSet<&String> = entries.values().flat_map(|m| m.tags.iter()).collect();
+        let mut result: Vec<String> = tags.into_iter().cloned().collect();
+        result.sort();
+        Ok(result)
+    }
+}
+
+/// 内存账户能力缓存仓库
+#[derive(Default)]
+pub struct InMemoryCapabilityRepository {
+    entries: RwLock<HashMap<String, AccountCapabilityCache>>,
+}
+
+#[async_trait]
+impl AccountCapabilityRepository for InMemoryCapabilityRepository {
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Option<AccountCapabilityCache>> {
+        Ok(self.entries.read().await.get(account_id).cloned())
+    }
+
+    async fn save(&self, cache: &AccountCapabilityCache) -> CoreResult<()> {
+        self.entries
+            .write()
+            .await
+            .insert(cache.account_id.clone(), cache.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, account_id: &str) -> CoreResult<()> {
+        self.entries.write().await.remove(account_id);
+        Ok(())
+    }
+}
+
+/// 内存 API 用量仓库
+#[derive(Default)]
+pub struct InMemoryApiUsageRepository {
+    counts: RwLock<HashMap<(String, NaiveDate, ApiOperation), u64>>,
+}
+
+#[async_trait]
+impl ApiUsageRepository for InMemoryApiUsageRepository {
+    async fn add_counts(&self, counts: &[ApiUsageCount]) -> CoreResult<()> {
+        let mut entries = self.counts.write().await;
+        for count in counts {
+            *entries
+                .entry((count.account_id.clone(), count.date, count.operation))
+                .or_insert(0) += count.count;
+        }
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        account_id: Option<&str>,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> CoreResult<Vec<ApiUsageCount>> {
+        Ok(self
+            .counts
+            .read()
+            .await
+            .iter()
+            .filter(|((account, date, _), _)| {
+                account_id.is_none_or(|id| id == account) && *date >= since && *date < until
+            })
+            .map(|((account, date, operation), count)| ApiUsageCount {
+                account_id: account.clone(),
+                date: *date,
+                operation: *operation,
+                count: *count,
+            })
+            .collect())
+    }
+
+    async fn delete_before(&self, before: NaiveDate) -> CoreResult<u64> {
+        let mut entries = self.counts.write().await;
+        let len = entries.len();
+        entries.retain(|(_, date, _), _| *date >= before);
+        Ok(u64::try_from(len - entries.len()).unwrap_or(u64::MAX))
+    }
+}
+
+/// 内存域名派生状态仓库
+#[derive(Default)]
+pub struct InMemoryDomainStatusRepository {
+    entries: RwLock<HashMap<(String, String), DomainDerivedStatus>>,
+}
+
+#[async_trait]
+impl DomainDerivedStatusRepository for InMemoryDomainStatusRepository {
+    async fn find_by_account(&self, account_id: &str) -> CoreResult<Vec<DomainDerivedStatus>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .values()
+            .filter(|status| status.account_id == account_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_batch(&self, statuses: &[DomainDerivedStatus]) -> CoreResult<()> {
+        let mut entries = self.entries.write().await;
+        for status in statuses {
+            entries.insert(
+                (status.account_id.clone(), status.domain_id.clone()),
+                status.clone(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn delete_by_account(&self, account_id: &str) -> CoreResult<()> {
+        self.entries
+            .write()
+            .await
+            .retain(|(account, _), _| account != account_id);
+        Ok(())
+    }
+}
+
+/// 内存审计日志仓库
+#[derive(Default)]
+pub struct InMemoryAuditLogRepository {
+    entries: RwLock<Vec<AuditEntry>>,
+}
+
+impl InMemoryAuditLogRepository {
+    /// 按追加顺序返回全部审计记录
+    pub async fn entries(&self) -> Vec<AuditEntry> {
+        self.entries.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for InMemoryAuditLogRepository {
+    async fn append(&self, entry: &AuditEntry) -> CoreResult<()> {
+        self.entries.write().await.push(entry.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<AuditEntry>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .find(|entry| entry.id == id)
+            .cloned())
+    }
+
+    async fn query(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: &PaginationParams,
+    ) -> CoreResult<PaginatedResponse<AuditEntry>> {
+        let entries = self.entries.read().await;
+        // 按追加顺序存储，倒序遍历即为时间倒序
+        let matched: Vec<&AuditEntry> = entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .collect();
+        let offset = pagination.page.saturating_sub(1) * pagination.page_size;
+        let items = matched
+            .iter()
+            .skip(offset as usize)
+            .take(pagination.page_size as usize)
+            .map(|entry| (*entry).clone())
+            .collect();
+        let total = u32::try_from(matched.len()).unwrap_or(u32::MAX);
+        Ok(PaginatedResponse::new(
+            items,
+            pagination.page,
+            pagination.page_size,
+            total,
+        ))
+    }
+}
+
+/// 内存禁止变更规则仓库
+#[derive(Default)]
+pub struct InMemoryChangeWindowRepository {
+    rules: RwLock<Vec<ChangeWindowRule>>,
+}
+
+#[async_trait]
+impl ChangeWindowRepository for InMemoryChangeWindowRepository {
+    async fn find_all(&self) -> CoreResult<Vec<ChangeWindowRule>> {
+        Ok(self.rules.read().await.clone())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<ChangeWindowRule>> {
+        Ok(self
+            .rules
+            .read()
+            .await
+            .iter()
+            .find(|rule| rule.id == id)
+            .cloned())
+    }
+
+    async fn save(&self, rule: &ChangeWindowRule) -> CoreResult<()> {
+        let mut rules = self.rules.write().await;
+        if let Some(existing) = rules.iter_mut().find(|r| r.id == rule.id) {
+            *existing = rule.clone();
+        } else {
+            rules.push(rule.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        self.rules.write().await.retain(|rule| rule.id != id);
+        Ok(())
+    }
+}
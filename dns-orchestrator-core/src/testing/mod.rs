@@ -0,0 +1,94 @@
+//! 测试辅助（`testing` feature）
+//!
+//! 提供不访问网络、不落盘的 trait 实现，用于单元测试、下游 crate 的集成测试与文档示例：
+//! - [`FakeDnsResolver`]：可编程的假 DNS 解析后端（工具箱查询）
+//! - [`MockDnsProvider`]：内存中的 DNS 服务商（记录增删改查、注入错误）
+//! - [`InMemoryAccountRepository`] / [`InMemoryCredentialStore`] /
+//!   [`InMemoryDomainMetadataRepository`] / [`InMemoryCapabilityRepository`] /
+//!   [`InMemoryApiUsageRepository`] / [`InMemoryDomainStatusRepository`] /
+//!   [`InMemoryAuditLogRepository`] / [`InMemoryChangeWindowRepository`]：内存存储
+//!
+//! [`in_memory_context`] 组装一个全部使用内存实现的 [`ServiceContext`]，
+//! 再用 [`register_mock_account`] 挂上 Mock 服务商即可调用各个服务：
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use dns_orchestrator_core::services::DnsService;
+//! use dns_orchestrator_core::testing::{in_memory_context, register_mock_account, MockDnsProvider};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> dns_orchestrator_core::CoreResult<()> {
+//! let ctx = Arc::new(in_memory_context());
+//! let provider = Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com"));
+//! register_mock_account(&ctx, "account-1", provider).await?;
+//!
+//! let dns = DnsService::new(Arc::clone(&ctx));
+//! let page = dns
+//!     .list_records("account-1", "zone-1", None, None, None, None, None)
+//!     .await?;
+//! assert!(page.items.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+mod dns_resolver;
+mod memory;
+mod provider;
+
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::error::CoreResult;
+use crate::services::ServiceContext;
+use crate::traits::InMemoryProviderRegistry;
+use crate::types::{Account, AccountStatus};
+
+pub use dns_resolver::{FakeDnsResolver, FAKE_TTL};
+pub use memory::{
+    InMemoryAccountRepository, InMemoryApiUsageRepository, InMemoryAuditLogRepository,
+    InMemoryCapabilityRepository, InMemoryChangeWindowRepository, InMemoryCredentialStore,
+    InMemoryDomainMetadataRepository, InMemoryDomainStatusRepository,
+};
+pub use provider::MockDnsProvider;
+
+/// 全部使用内存实现的服务上下文（不启用审计日志、变更窗口与离线写队列）
+#[must_use]
+pub fn in_memory_context() -> ServiceContext {
+    ServiceContext::new(
+        Arc::new(InMemoryCredentialStore::default()),
+        Arc::new(InMemoryAccountRepository::default()),
+        Arc::new(InMemoryProviderRegistry::new()),
+        Arc::new(InMemoryDomainMetadataRepository::default()),
+    )
+}
+
+/// 保存一个使用 Mock 服务商的账户并注册 Provider 实例
+///
+/// 账户的服务商类型与 [`MockDnsProvider`] 的元数据一致（Cloudflare）。
+pub async fn register_mock_account(
+    ctx: &ServiceContext,
+    account_id: &str,
+    provider: Arc<MockDnsProvider>,
+) -> CoreResult<Account> {
+    let now = Utc::now();
+    let account = Account {
+        id: account_id.to_string(),
+        name: format!("Mock {account_id}"),
+        provider: provider::MOCK_PROVIDER_TYPE,
+        created_at: now,
+        updated_at: now,
+        status: Some(AccountStatus::Active),
+        error: None,
+        credentials_expires_at: None,
+        expiry_reminder_days: None,
+        credential_status: None,
+        last_validated_at: None,
+    };
+    ctx.account_repository.save(&account).await?;
+    ctx.provider_registry
+        .register(account_id.to_string(), provider)
+        .await;
+    Ok(account)
+}
@@ -0,0 +1,461 @@
+//! 内存中的 Mock DNS 服务商
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use dns_orchestrator_provider::{
+    CapabilityProbe, ChangeResult, CloudflareProvider, DnsProvider, ProviderError, RecordChange,
+    Result,
+};
+
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DomainStatus, PaginatedResponse, PaginationParams,
+    ProviderDomain, ProviderMetadata, ProviderType, RecordData, RecordQueryParams,
+    UpdateDnsRecordRequest,
+};
+
+/// Mock 服务商对外声明的服务商类型（元数据沿用 Cloudflare）
+pub(super) const MOCK_PROVIDER_TYPE: ProviderType = ProviderType::Cloudflare;
+
+const PROVIDER_NAME: &str = "mock";
+
+/// 内存中的 DNS 服务商
+///
+/// 域名在构造时预置，记录的增删改直接作用于内存列表。
+/// [`Self::fail_with`] 设置后所有调用都返回该错误，用于测试错误处理（如网络错误、凭证失效）；
+/// [`Self::fail_next_writes`] 只让接下来的若干次写操作失败，用于测试重试；
+/// [`Self::lose_next_create_responses`] 让创建生效但返回错误，模拟响应丢失。
+/// [`Self::with_write_delay`] 让写操作耗时一段时间，配合 [`Self::max_concurrent_writes`]
+/// 检查并发写入是否被串行化。[`Self::batch_sizes`] 记录每次批量变更（原生批量接口）的条数。
+/// 权重解析默认不支持（返回 `UnsupportedCapability`），[`Self::with_weighted_routing`] 开启后
+/// 可通过 [`Self::weighted_subdomains`] 与 [`Self::record_weight`] 检查当前状态。
+///
+/// ```
+/// use dns_orchestrator_core::testing::MockDnsProvider;
+/// use dns_orchestrator_core::types::RecordData;
+///
+/// let provider = MockDnsProvider::new()
+///     .with_domain("zone-1", "example.com")
+///     .with_record(
+///         "zone-1",
+///         "www",
+///         300,
+///         RecordData::A {
+///             address: "192.0.2.1".to_string(),
+///         },
+///     );
+/// assert_eq!(provider.records().len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct MockDnsProvider {
+    domains: Vec<ProviderDomain>,
+    records: Mutex<Vec<DnsRecord>>,
+    next_id: AtomicU64,
+    failure: Mutex<Option<ProviderError>>,
+    write_failures: Mutex<VecDeque<ProviderError>>,
+    lost_create_responses: Mutex<VecDeque<ProviderError>>,
+    write_delay: Duration,
+    writes_in_flight: AtomicUsize,
+    max_writes_in_flight: AtomicUsize,
+    capabilities: CapabilityProbe,
+    probes: AtomicUsize,
+    record_lists: AtomicUsize,
+    batch_sizes: Mutex<Vec<usize>>,
+    weighted_routing: bool,
+    weighted_subdomains: Mutex<BTreeSet<String>>,
+    record_weights: Mutex<HashMap<String, u8>>,
+}
+
+impl MockDnsProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 预置域名（状态为 Active）
+    #[must_use]
+    pub fn with_domain(mut self, id: &str, name: &str) -> Self {
+        self.domains.push(ProviderDomain {
+            id: id.to_string(),
+            name: name.to_string(),
+            provider: MOCK_PROVIDER_TYPE,
+            status: DomainStatus::Active,
+            provider_status_raw: None,
+            record_count: None,
+        });
+        self
+    }
+
+    /// 预置记录
+    #[must_use]
+    pub fn with_record(self, domain_id: &str, name: &str, ttl: u32, data: RecordData) -> Self {
+        let record = self.new_record(domain_id.to_string(), name.to_string(), ttl, data, None);
+        self.lock_records().push(record);
+        self
+    }
+
+    /// 每次写操作（创建、更新、删除）耗时 `delay`
+    #[must_use]
+    pub const fn with_write_delay(mut self, delay: Duration) -> Self {
+        self.write_delay = delay;
+        self
+    }
+
+    /// 能力探测返回 `probe`（默认为空的修正）
+    #[must_use]
+    pub fn with_capabilities(mut self, probe: CapabilityProbe) -> Self {
+        self.capabilities = probe;
+        self
+    }
+
+    /// 支持权重解析
+    #[must_use]
+    pub const fn with_weighted_routing(mut self) -> Self {
+        self.weighted_routing = true;
+        self
+    }
+
+    /// 当前开启了权重解析的子域名（完整域名，按字典序）
+    pub fn weighted_subdomains(&self) -> Vec<String> {
+        self.weighted_subdomains
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// 记录当前的权重（未设置过时为 `None`）
+    pub fn record_weight(&self, record_id: &str) -> Option<u8> {
+        self.record_weights
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(record_id)
+            .copied()
+    }
+
+    /// 当前全部记录
+    pub fn records(&self) -> Vec<DnsRecord> {
+        self.lock_records().clone()
+    }
+
+    /// 同时进行中的写操作数的最大值
+    pub fn max_concurrent_writes(&self) -> usize {
+        self.max_writes_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// 能力探测的调用次数
+    pub fn probe_count(&self) -> usize {
+        self.probes.load(Ordering::SeqCst)
+    }
+
+    /// 记录列表接口的调用次数
+    pub fn list_records_count(&self) -> usize {
+        self.record_lists.load(Ordering::SeqCst)
+    }
+
+    /// 每次调用批量变更接口时的变更条数（按调用顺序）
+    pub fn batch_sizes(&self) -> Vec<usize> {
+        self.batch_sizes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// 之后的所有调用都返回 `error`，直到 [`Self::clear_failure`]
+    pub fn fail_with(&self, error: ProviderError) {
+        *self.failure.lock().unwrap_or_else(PoisonError::into_inner) = Some(error);
+    }
+
+    /// 接下来的 `times` 次写操作（创建、更新、删除）返回 `error`，之后恢复正常
+    pub fn fail_next_writes(&self, error: ProviderError, times: usize) {
+        self.write_failures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(std::iter::repeat_n(error, times));
+    }
+
+    /// 接下来的 `times` 次创建照常写入记录，但返回 `error`（如请求已送达而响应超时）
+    pub fn lose_next_create_responses(&self, error: ProviderError, times: usize) {
+        self.lost_create_responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(std::iter::repeat_n(error, times));
+    }
+
+    /// 恢复正常响应
+    pub fn clear_failure(&self) {
+        *self.failure.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    fn check_failure(&self) -> Result<()> {
+        match &*self.failure.lock().unwrap_or_else(PoisonError::into_inner) {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// 模拟写操作耗时，并记录同时进行中的写操作数；有待返回的写失败时直接返回该错误
+    async fn simulate_write(&self) -> Result<()> {
+        let failure = self
+            .write_failures
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front();
+        if let Some(error) = failure {
+            return Err(error);
+        }
+        let in_flight = self.writes_in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_writes_in_flight
+            .fetch_max(in_flight, Ordering::SeqCst);
+        if !self.write_delay.is_zero() {
+            tokio::time::sleep(self.write_delay).await;
+        }
+        self.writes_in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn lock_records(&self) -> std::sync::MutexGuard<'_, Vec<DnsRecord>> {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn new_record(
+        &self,
+        domain_id: String,
+        name: String,
+        ttl: u32,
+        data: RecordData,
+        proxied: Option<bool>,
+    ) -> DnsRecord {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let now = Utc::now();
+        DnsRecord {
+            id: format!("record-{id}"),
+            domain_id,
+            name,
+            ttl,
+            data,
+            proxied,
+            created_at: Some(now),
+            updated_at: Some(now),
+        }
+    }
+
+    fn ensure_weighted_routing(&self) -> Result<()> {
+        if self.weighted_routing {
+            Ok(())
+        } else {
+            Err(ProviderError::UnsupportedCapability {
+                provider: PROVIDER_NAME.to_string(),
+                capability: "weighted_records".to_string(),
+            })
+        }
+    }
+
+    fn record_not_found(record_id: &str) -> ProviderError {
+        ProviderError::RecordNotFound {
+            provider: PROVIDER_NAME.to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        }
+    }
+}
+
+/// 按页截取
+fn paginate<T: Clone>(items: &[T], page: u32, page_size: u32) -> PaginatedResponse<T> {
+    let start = (page.saturating_sub(1) as usize).saturating_mul(page_size as usize);
+    let page_items = items
+        .iter()
+        .skip(start)
+        .take(page_size as usize)
+        .cloned()
+        .collect();
+    PaginatedResponse::new(
+        page_items,
+        page,
+        page_size,
+        u32::try_from(items.len()).unwrap_or(u32::MAX),
+    )
+}
+
+#[async_trait]
+impl DnsProvider for MockDnsProvider {
+    fn id(&self) -> &'static str {
+        PROVIDER_NAME
+    }
+
+    fn metadata() -> ProviderMetadata {
+        CloudflareProvider::metadata()
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        self.check_failure()?;
+        Ok(true)
+    }
+
+    async fn probe_capabilities(&self) -> Result<CapabilityProbe> {
+        self.probes.fetch_add(1, Ordering::SeqCst);
+        self.check_failure()?;
+        Ok(self.capabilities.clone())
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        self.check_failure()?;
+        Ok(paginate(&self.domains, params.page, params.page_size))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.check_failure()?;
+        self.domains
+            .iter()
+            .find(|domain| domain.id == domain_id)
+            .cloned()
+            .ok_or_else(|| ProviderError::DomainNotFound {
+                provider: PROVIDER_NAME.to_string(),
+                domain: domain_id.to_string(),
+                raw_message: None,
+            })
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        self.check_failure()?;
+        self.record_lists.fetch_add(1, Ordering::SeqCst);
+        let keyword = params.keyword.as_deref().map(str::to_lowercase);
+        let records: Vec<DnsRecord> = self
+            .lock_records()
+            .iter()
+            .filter(|record| record.domain_id == domain_id)
+            .filter(|record| {
+                params
+                    .record_type
+                    .as_ref()
+                    .is_none_or(|record_type| record.data.record_type() == *record_type)
+            })
+            .filter(|record| {
+                keyword.as_deref().is_none_or(|keyword| {
+                    record.name.to_lowercase().contains(keyword)
+                        || record.data.display_value().to_lowercase().contains(keyword)
+                })
+            })
+            .cloned()
+            .collect();
+        Ok(paginate(&records, params.page, params.page_size))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        self.check_failure()?;
+        self.simulate_write().await?;
+        let record = self.new_record(
+            req.domain_id.clone(),
+            req.name.clone(),
+            req.ttl,
+            req.data.clone(),
+            req.proxied,
+        );
+        self.lock_records().push(record.clone());
+        let lost = self
+            .lost_create_responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front();
+        match lost {
+            Some(error) => Err(error),
+            None => Ok(record),
+        }
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        self.check_failure()?;
+        self.simulate_write().await?;
+        let mut records = self.lock_records();
+        let record = records
+            .iter_mut()
+            .find(|record| record.id == record_id && record.domain_id == req.domain_id)
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+        record.name.clone_from(&req.name);
+        record.ttl = req.ttl;
+        record.data = req.data.clone();
+        record.proxied = req.proxied;
+        record.updated_at = Some(Utc::now());
+        Ok(record.clone())
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.check_failure()?;
+        self.simulate_write().await?;
+        let mut records = self.lock_records();
+        let before = records.len();
+        records.retain(|record| !(record.id == record_id && record.domain_id == domain_id));
+        if records.len() == before {
+            return Err(Self::record_not_found(record_id));
+        }
+        Ok(())
+    }
+
+    async fn set_weighted_routing(
+        &self,
+        _domain_id: &str,
+        subdomain: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        self.check_failure()?;
+        self.ensure_weighted_routing()?;
+        let mut subdomains = self
+            .weighted_subdomains
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if enabled {
+            subdomains.insert(subdomain.to_string());
+        } else {
+            subdomains.remove(subdomain);
+        }
+        Ok(())
+    }
+
+    async fn set_record_weight(&self, record_id: &str, weight: u8) -> Result<()> {
+        self.check_failure()?;
+        self.ensure_weighted_routing()?;
+        if !self.lock_records().iter().any(|r| r.id == record_id) {
+            return Err(Self::record_not_found(record_id));
+        }
+        self.record_weights
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(record_id.to_string(), weight);
+        Ok(())
+    }
+
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        self.batch_sizes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(changes.len());
+        let mut results = Vec::with_capacity(changes.len());
+        for change in changes {
+            results.push(match change {
+                RecordChange::Create(req) => self.create_record(&req).await.into(),
+                RecordChange::Update { record_id, request } => {
+                    self.update_record(&record_id, &request).await.into()
+                }
+                RecordChange::Delete { record_id } => {
+                    self.delete_record(&record_id, domain_id).await.into()
+                }
+            });
+        }
+        results
+    }
+}
@@ -0,0 +1,23 @@
+//! 账户能力缓存持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::AccountCapabilityCache;
+
+/// 账户能力缓存仓库 Trait
+///
+/// 保存每个账户的运行时能力探测结果，避免每次能力检查都调用服务商接口。
+/// 平台层需要实现此 trait 以提供持久化。
+#[async_trait]
+pub trait AccountCapabilityRepository: Send + Sync {
+    /// 获取账户的探测结果（从未探测时为 `None`）
+    async fn find_by_account(&self, account_id: &str)
+        -> CoreResult<Option<AccountCapabilityCache>>;
+
+    /// 保存探测结果（新增或覆盖）
+    async fn save(&self, cache: &AccountCapabilityCache) -> CoreResult<()>;
+
+    /// 删除账户的探测结果
+    async fn delete(&self, account_id: &str) -> CoreResult<()>;
+}
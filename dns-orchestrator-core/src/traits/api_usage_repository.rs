@@ -0,0 +1,31 @@
+//! 服务商 API 用量持久化抽象 Trait
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::error::CoreResult;
+use crate::types::ApiUsageCount;
+
+/// API 用量仓库 Trait
+///
+/// 按「账户 + 日期 + 操作」累计调用次数。
+///
+/// 平台实现:
+/// - Tauri: `TauriApiUsageRepository` (tauri-plugin-store)
+/// - Actix-Web: `SeaOrmApiUsageRepository` (`SeaORM`)，未配置数据库时为内存实现
+#[async_trait]
+pub trait ApiUsageRepository: Send + Sync {
+    /// 累加一批调用次数（同一「账户 + 日期 + 操作」已存在时相加）
+    async fn add_counts(&self, counts: &[ApiUsageCount]) -> CoreResult<()>;
+
+    /// 查询 `[since, until)` 内的调用次数，`account_id` 为 `None` 时查询全部账户
+    async fn query(
+        &self,
+        account_id: Option<&str>,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> CoreResult<Vec<ApiUsageCount>>;
+
+    /// 删除 `before` 之前的统计，返回删除的条数
+    async fn delete_before(&self, before: NaiveDate) -> CoreResult<u64>;
+}
@@ -0,0 +1,29 @@
+//! 审计日志持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::{AuditEntry, AuditLogFilter, PaginatedResponse, PaginationParams};
+
+/// 审计日志仓库 Trait
+///
+/// 只追加，不修改已写入的记录。
+///
+/// 平台实现:
+/// - Tauri: `TauriAuditLogRepository` (JSON Lines 文件)
+/// - Actix-Web: `SeaOrmAuditLogRepository` (`SeaORM`)，未配置数据库时为内存实现
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// 追加一条审计记录
+    async fn append(&self, entry: &AuditEntry) -> CoreResult<()>;
+
+    /// 按 ID 查找审计记录
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<AuditEntry>>;
+
+    /// 分页查询审计记录，按时间倒序
+    async fn query(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: &PaginationParams,
+    ) -> CoreResult<PaginatedResponse<AuditEntry>>;
+}
@@ -0,0 +1,39 @@
+//! 后台任务抽象 Trait
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::{JobConcurrency, JobSchedule};
+
+/// 默认执行超时
+const DEFAULT_JOB_TIMEOUT: Duration = Duration::from_mins(5);
+
+/// 后台任务 Trait
+///
+/// 由 `BackgroundJobRunner` 统一调度，各平台只需注册各自的任务集合。
+#[async_trait]
+pub trait BackgroundJob: Send + Sync {
+    /// 任务名称（运行器内唯一，也用于手动触发）
+    fn name(&self) -> &str;
+
+    /// 调度方式
+    fn schedule(&self) -> JobSchedule;
+
+    /// 单次执行超时，超时后执行体会被中断
+    fn timeout(&self) -> Duration {
+        DEFAULT_JOB_TIMEOUT
+    }
+
+    /// 并发策略
+    fn concurrency(&self) -> JobConcurrency {
+        JobConcurrency::Serial
+    }
+
+    /// 执行体
+    ///
+    /// # Returns
+    /// * `Some(summary)` - 执行摘要（展示在系统状态页）
+    async fn run(&self) -> CoreResult<Option<String>>;
+}
@@ -0,0 +1,24 @@
+//! 禁止变更规则持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::ChangeWindowRule;
+
+/// 禁止变更规则仓库 Trait
+///
+/// 平台层需要实现此 trait 以提供规则持久化。
+#[async_trait]
+pub trait ChangeWindowRepository: Send + Sync {
+    /// 获取所有规则
+    async fn find_all(&self) -> CoreResult<Vec<ChangeWindowRule>>;
+
+    /// 根据 ID 获取规则
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<ChangeWindowRule>>;
+
+    /// 保存规则（新增或更新）
+    async fn save(&self, rule: &ChangeWindowRule) -> CoreResult<()>;
+
+    /// 删除规则
+    async fn delete(&self, id: &str) -> CoreResult<()>;
+}
@@ -25,6 +25,83 @@ pub type LegacyCredentialsMap = HashMap<String, HashMap<String, String>>;
 /// - 方法重命名以符合 Rust 惯用法：`load()` → `get()`, `save()` → `set()`, `delete()` → `remove()`
 /// - 新增 `save_all()` 用于批量保存（迁移场景）
 /// - 新增 `load_raw_json()` 和 `save_raw_json()` 用于迁移检测
+///
+/// # Examples
+///
+/// 基于内存映射的最小实现（真实实现应加密落盘或使用系统钥匙串）：
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use async_trait::async_trait;
+/// use dns_orchestrator_core::traits::{CredentialStore, CredentialsMap};
+/// use dns_orchestrator_core::{CoreError, CoreResult, ProviderCredentials};
+///
+/// #[derive(Default)]
+/// struct MapCredentialStore {
+///     map: Mutex<CredentialsMap>,
+/// }
+///
+/// impl MapCredentialStore {
+///     fn lock(&self) -> CoreResult<std::sync::MutexGuard<'_, CredentialsMap>> {
+///         self.map
+///             .lock()
+///             .map_err(|e| CoreError::CredentialError(e.to_string()))
+///     }
+/// }
+///
+/// #[async_trait]
+/// impl CredentialStore for MapCredentialStore {
+///     async fn load_all(&self) -> CoreResult<CredentialsMap> {
+///         Ok(self.lock()?.clone())
+///     }
+///
+///     async fn save_all(&self, credentials: &CredentialsMap) -> CoreResult<()> {
+///         *self.lock()? = credentials.clone();
+///         Ok(())
+///     }
+///
+///     async fn get(&self, account_id: &str) -> CoreResult<Option<ProviderCredentials>> {
+///         Ok(self.lock()?.get(account_id).cloned())
+///     }
+///
+///     async fn set(&self, account_id: &str, credentials: &ProviderCredentials) -> CoreResult<()> {
+///         self.lock()?
+///             .insert(account_id.to_string(), credentials.clone());
+///         Ok(())
+///     }
+///
+///     async fn remove(&self, account_id: &str) -> CoreResult<()> {
+///         self.lock()?.remove(account_id);
+///         Ok(())
+///     }
+///
+///     async fn load_raw_json(&self) -> CoreResult<String> {
+///         serde_json::to_string(&*self.lock()?)
+///             .map_err(|e| CoreError::SerializationError(e.to_string()))
+///     }
+///
+///     async fn save_raw_json(&self, json: &str) -> CoreResult<()> {
+///         *self.lock()? = serde_json::from_str(json)
+///             .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+///         Ok(())
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> CoreResult<()> {
+/// let store = MapCredentialStore::default();
+/// let credentials = ProviderCredentials::Cloudflare {
+///     api_token: "token".to_string(),
+/// };
+/// store.set("account-1", &credentials).await?;
+/// assert!(store.get("account-1").await?.is_some());
+///
+/// store.remove("account-1").await?;
+/// assert!(store.load_all().await?.is_empty());
+/// # Ok(())
+/// # }
+/// ```
 #[async_trait]
 pub trait CredentialStore: Send + Sync {
     /// 加载所有凭证（新格式）
@@ -0,0 +1,21 @@
+//! DNS 解析后端抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::types::{DnsAnswer, DnsQuery, DnsQueryError};
+
+/// DNS 解析后端
+///
+/// 工具箱的 DNS 类工具都通过此 Trait 发起查询，默认实现为
+/// [`HickoryResolverBackend`](crate::services::HickoryResolverBackend)；
+/// 单元测试可使用 `testing` feature 提供的 `FakeDnsResolver`。
+#[async_trait]
+pub trait DnsResolverBackend: Send + Sync {
+    /// 执行一次查询
+    ///
+    /// 查询成功但没有记录时应返回 [`DnsQueryErrorKind::NoRecords`](crate::types::DnsQueryErrorKind::NoRecords)。
+    async fn query(&self, query: &DnsQuery) -> Result<DnsAnswer, DnsQueryError>;
+
+    /// 系统配置的 DNS 服务器地址列表（去重，保持原有顺序）
+    fn system_nameservers(&self) -> Vec<String>;
+}
@@ -0,0 +1,22 @@
+//! 域名派生状态持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::DomainDerivedStatus;
+
+/// 域名派生状态仓库 Trait
+///
+/// 按「账户 + 域名」保存检查任务生成的派生状态，供域名列表附带与筛选。
+/// 平台层需要实现此 trait 以提供持久化。
+#[async_trait]
+pub trait DomainDerivedStatusRepository: Send + Sync {
+    /// 获取账户下所有域名的派生状态
+    async fn find_by_account(&self, account_id: &str) -> CoreResult<Vec<DomainDerivedStatus>>;
+
+    /// 批量保存（同一「账户 + 域名」已存在时覆盖）
+    async fn save_batch(&self, statuses: &[DomainDerivedStatus]) -> CoreResult<()>;
+
+    /// 删除账户下所有域名的派生状态
+    async fn delete_by_account(&self, account_id: &str) -> CoreResult<()>;
+}
@@ -0,0 +1,24 @@
+//! 域名组持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::DomainGroup;
+
+/// 域名组仓库 Trait
+///
+/// 平台层需要实现此 trait 以提供域名组持久化。
+#[async_trait]
+pub trait DomainGroupRepository: Send + Sync {
+    /// 获取所有域名组
+    async fn find_all(&self) -> CoreResult<Vec<DomainGroup>>;
+
+    /// 根据 ID 获取域名组
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<DomainGroup>>;
+
+    /// 保存域名组（新增或更新）
+    async fn save(&self, group: &DomainGroup) -> CoreResult<()>;
+
+    /// 删除域名组
+    async fn delete(&self, id: &str) -> CoreResult<()>;
+}
@@ -11,6 +11,107 @@ use crate::types::{DomainMetadata, DomainMetadataKey, DomainMetadataUpdate};
 /// 平台实现:
 /// - Tauri: `TauriDomainMetadataRepository` (tauri-plugin-store)
 /// - Actix-Web: `DatabaseDomainMetadataRepository` (`SeaORM`)
+///
+/// # Examples
+///
+/// 自定义实现的骨架：以键值存储为例，批量与查询方法可以在单条读写之上组合实现。
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use async_trait::async_trait;
+/// use dns_orchestrator_core::traits::DomainMetadataRepository;
+/// use dns_orchestrator_core::types::{DomainMetadata, DomainMetadataKey, DomainMetadataUpdate};
+/// use dns_orchestrator_core::CoreResult;
+/// use tokio::sync::RwLock;
+///
+/// #[derive(Default)]
+/// struct KvDomainMetadataRepository {
+///     entries: RwLock<HashMap<DomainMetadataKey, DomainMetadata>>,
+/// }
+///
+/// #[async_trait]
+/// impl DomainMetadataRepository for KvDomainMetadataRepository {
+///     async fn find_by_key(&self, key: &DomainMetadataKey) -> CoreResult<Option<DomainMetadata>> {
+///         Ok(self.entries.read().await.get(key).cloned())
+///     }
+///
+///     async fn find_by_keys(
+///         &self,
+///         keys: &[DomainMetadataKey],
+///     ) -> CoreResult<HashMap<DomainMetadataKey, DomainMetadata>> {
+///         let entries = self.entries.read().await;
+///         Ok(keys
+///             .iter()
+///             .filter_map(|key| Some((key.clone(), entries.get(key)?.clone())))
+///             .collect())
+///     }
+///
+///     async fn save(&self, key: &DomainMetadataKey, metadata: &DomainMetadata) -> CoreResult<()> {
+///         let mut entries = self.entries.write().await;
+///         // 空元数据不占用存储
+///         if metadata.is_empty() {
+///             entries.remove(key);
+///         } else {
+///             entries.insert(key.clone(), metadata.clone());
+///         }
+///         Ok(())
+///     }
+///
+///     async fn batch_save(&self, items: &[(DomainMetadataKey, DomainMetadata)]) -> CoreResult<()> {
+///         for (key, metadata) in items {
+///             self.save(key, metadata).await?;
+///         }
+///         Ok(())
+///     }
+///
+///     async fn update(
+///         &self,
+///         key: &DomainMetadataKey,
+///         update: &DomainMetadataUpdate,
+///     ) -> CoreResult<()> {
+///         let mut metadata = self.find_by_key(key).await?.unwrap_or_default();
+///         update.apply_to(&mut metadata);
+///         self.save(key, &metadata).await
+///     }
+///
+///     async fn delete(&self, key: &DomainMetadataKey) -> CoreResult<()> {
+///         self.entries.write().await.remove(key);
+///         Ok(())
+///     }
+///
+///     async fn delete_by_account(&self, account_id: &str) -> CoreResult<()> {
+///         self.entries
+///             .write()
+///             .await
+///             .retain(|key, _| key.account_id != account_id);
+///         Ok(())
+///     }
+///
+///     // 以下查询方法按存储后端的索引能力实现
+///     async fn find_by_account(
+///         &self,
+///         account_id: &str,
+///     ) -> CoreResult<Vec<(DomainMetadataKey, DomainMetadata)>> {
+///         todo!()
+///     }
+///
+///     async fn find_favorites_by_account(
+///         &self,
+///         account_id: &str,
+///     ) -> CoreResult<Vec<DomainMetadataKey>> {
+///         todo!()
+///     }
+///
+///     async fn find_by_tag(&self, tag: &str) -> CoreResult<Vec<DomainMetadataKey>> {
+///         todo!()
+///     }
+///
+///     async fn list_all_tags(&self) -> CoreResult<Vec<String>> {
+///         todo!()
+///     }
+/// }
+/// ```
 #[async_trait]
 pub trait DomainMetadataRepository: Send + Sync {
     /// 获取单个域名的元数据
@@ -65,6 +166,12 @@ pub trait DomainMetadataRepository: Send + Sync {
     /// 删除账户下的所有元数据（账户删除时调用）
     async fn delete_by_account(&self, account_id: &str) -> CoreResult<()>;
 
+    /// 获取账户下的所有元数据
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<(DomainMetadataKey, DomainMetadata)>>;
+
     /// 获取账户下所有收藏的域名键
     async fn find_favorites_by_account(
         &self,
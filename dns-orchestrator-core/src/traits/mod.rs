@@ -1,11 +1,38 @@
 //! 存储层抽象 Trait 定义
 
+mod account_capability_repository;
 mod account_repository;
+mod api_usage_repository;
+mod audit_log_repository;
+mod background_job;
+mod change_window_repository;
 mod credential_store;
+mod dns_resolver_backend;
+mod domain_derived_status_repository;
+mod domain_group_repository;
 mod domain_metadata_repository;
+mod monitor_repository;
 mod provider_registry;
+mod temporary_record_repository;
+mod write_lock;
+mod write_queue_repository;
 
+pub use account_capability_repository::AccountCapabilityRepository;
 pub use account_repository::AccountRepository;
+pub use api_usage_repository::ApiUsageRepository;
+pub use audit_log_repository::AuditLogRepository;
+pub use background_job::BackgroundJob;
+pub use change_window_repository::ChangeWindowRepository;
 pub use credential_store::{CredentialStore, CredentialsMap, LegacyCredentialsMap};
+pub use dns_resolver_backend::DnsResolverBackend;
+pub use domain_derived_status_repository::DomainDerivedStatusRepository;
+pub use domain_group_repository::DomainGroupRepository;
 pub use domain_metadata_repository::DomainMetadataRepository;
-pub use provider_registry::{InMemoryProviderRegistry, ProviderRegistry};
+pub use monitor_repository::MonitorRepository;
+pub use provider_registry::{
+    InMemoryProviderRegistry, ProviderRegistry, RateLimit, RateLimitConfig,
+    RateLimitedProviderRegistry,
+};
+pub use temporary_record_repository::TemporaryRecordRepository;
+pub use write_lock::{InProcessWriteLocks, WriteLockBackend, WriteLockGuard, WriteLockTarget};
+pub use write_queue_repository::WriteQueueRepository;
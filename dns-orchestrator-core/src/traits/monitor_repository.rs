@@ -0,0 +1,19 @@
+//! 监控结果持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::DomainExpiryEntry;
+
+/// 监控结果仓库 Trait
+///
+/// 保存最近一次域名到期检查的结果，供下次检查比较档位变化。
+/// 平台层需要实现此 trait 以提供持久化。
+#[async_trait]
+pub trait MonitorRepository: Send + Sync {
+    /// 获取最近一次到期检查的结果（从未检查过时为空）
+    async fn find_expiry_results(&self) -> CoreResult<Vec<DomainExpiryEntry>>;
+
+    /// 用本次检查结果替换已保存的结果
+    async fn save_expiry_results(&self, entries: &[DomainExpiryEntry]) -> CoreResult<()>;
+}
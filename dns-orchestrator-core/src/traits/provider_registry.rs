@@ -1,16 +1,76 @@
 //! Provider 注册表抽象 Trait
 
 use async_trait::async_trait;
+use chrono::Utc;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
 
-use dns_orchestrator_provider::DnsProvider;
+use dns_orchestrator_provider::{
+    get_all_provider_metadata, CapabilityProbe, ChangeResult, CreateDnsRecordRequest, DnsProvider,
+    DnsRecord, DnsRecordType, PaginatedResponse, PaginationParams, ProviderDomain, ProviderError,
+    ProviderMetadata, ProviderType, RecordChange, RecordQueryParams, RecordSet,
+    UpdateDnsRecordRequest,
+};
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{HealthCheckFailure, ProviderHealthStatus};
+
+/// 连通性检查的超时时长
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Provider 注册表 Trait
 ///
 /// 管理所有已注册的 Provider 实例，按 `account_id` 索引。
 /// 提供默认的内存实现 `InMemoryProviderRegistry`。
+/// 连通性检查（[`health_check`](Self::health_check) /
+/// [`check_all_providers`](Self::check_all_providers)）有基于 `get` 的默认实现。
+///
+/// # Examples
+///
+/// 包装默认实现、在注册时记录日志的自定义注册表：
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use async_trait::async_trait;
+/// use dns_orchestrator_core::traits::{InMemoryProviderRegistry, ProviderRegistry};
+/// use dns_orchestrator_core::DnsProvider;
+///
+/// struct LoggingRegistry {
+///     inner: InMemoryProviderRegistry,
+/// }
+///
+/// #[async_trait]
+/// impl ProviderRegistry for LoggingRegistry {
+///     async fn register(&self, account_id: String, provider: Arc<dyn DnsProvider>) {
+///         log::info!("[Registry] Register provider for account {account_id}");
+///         self.inner.register(account_id, provider).await;
+///     }
+///
+///     async fn unregister(&self, account_id: &str) {
+///         self.inner.unregister(account_id).await;
+///     }
+///
+///     async fn get(&self, account_id: &str) -> Option<Arc<dyn DnsProvider>> {
+///         self.inner.get(account_id).await
+///     }
+///
+///     async fn list_account_ids(&self) -> Vec<String> {
+///         self.inner.list_account_ids().await
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let registry = LoggingRegistry {
+///     inner: InMemoryProviderRegistry::new(),
+/// };
+/// assert!(registry.get("account-1").await.is_none());
+/// assert!(registry.list_account_ids().await.is_empty());
+/// # }
+/// ```
 #[async_trait]
 pub trait ProviderRegistry: Send + Sync {
     /// 注册 Provider 实例
@@ -34,6 +94,101 @@ pub trait ProviderRegistry: Send + Sync {
 
     /// 列出所有已注册的 `account_id`
     async fn list_account_ids(&self) -> Vec<String>;
+
+    /// 检查账户 Provider 的连通性
+    ///
+    /// 以 5 秒超时拉取一条域名。检查失败时仍返回 `Ok`，原因写入 `error`，类别写入
+    /// `failure`（凭证或权限被拒绝时服务商仍视为可达）；账户未注册时返回 `AccountNotFound`。
+    /// 检查直接调用 Provider，不计入 API 用量。
+    async fn health_check(&self, account_id: &str) -> CoreResult<ProviderHealthStatus> {
+        let provider = self
+            .get(account_id)
+            .await
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+        check_provider_health(account_id, provider.as_ref()).await
+    }
+
+    /// 并发检查所有已注册账户的连通性，按 `account_id` 排序
+    ///
+    /// 检查期间被注销的账户不在结果中。
+    async fn check_all_providers(&self) -> CoreResult<Vec<ProviderHealthStatus>> {
+        let mut account_ids = self.list_account_ids().await;
+        account_ids.sort();
+        let results =
+            futures::future::join_all(account_ids.iter().map(|id| self.health_check(id))).await;
+        let mut statuses = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(status) => statuses.push(status),
+                Err(CoreError::AccountNotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(statuses)
+    }
+}
+
+/// 调用一次 `list_domains` 检查 Provider 是否可达
+async fn check_provider_health(
+    account_id: &str,
+    provider: &dyn DnsProvider,
+) -> CoreResult<ProviderHealthStatus> {
+    let provider_type = provider_type_of(provider)
+        .ok_or_else(|| CoreError::ProviderNotFound(provider.id().to_string()))?;
+
+    let params = PaginationParams {
+        page: 1,
+        page_size: 1,
+    };
+    let started = Instant::now();
+    let result = tokio::time::timeout(HEALTH_CHECK_TIMEOUT, provider.list_domains(&params)).await;
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let (failure, error) = match result {
+        Ok(Ok(_)) => (None, None),
+        Ok(Err(e)) => (Some(failure_of(&e)), Some(e.to_string())),
+        Err(_) => (
+            Some(HealthCheckFailure::Timeout),
+            Some(format!(
+                "Timed out after {}s",
+                HEALTH_CHECK_TIMEOUT.as_secs()
+            )),
+        ),
+    };
+    if let (Some(failure), Some(error)) = (failure, &error) {
+        log::warn!("[Health] Provider check of {account_id} failed ({failure:?}): {error}");
+    }
+
+    Ok(ProviderHealthStatus {
+        account_id: account_id.to_string(),
+        provider_type,
+        reachable: !matches!(
+            failure,
+            Some(HealthCheckFailure::Network | HealthCheckFailure::Timeout)
+        ),
+        latency_ms,
+        error,
+        failure,
+        checked_at: Utc::now(),
+    })
+}
+
+/// 按 Provider 错误判断检查失败的类别
+fn failure_of(error: &ProviderError) -> HealthCheckFailure {
+    match error {
+        ProviderError::NetworkError { .. } => HealthCheckFailure::Network,
+        ProviderError::InvalidCredentials { .. } => HealthCheckFailure::Credentials,
+        ProviderError::PermissionDenied { .. } => HealthCheckFailure::Permission,
+        _ => HealthCheckFailure::Provider,
+    }
+}
+
+/// 按 `provider.id()` 查找服务商类型（测试用的 Mock 等未知服务商返回 `None`）
+fn provider_type_of(provider: &dyn DnsProvider) -> Option<ProviderType> {
+    get_all_provider_metadata()
+        .into_iter()
+        .find(|metadata| metadata.id.to_string() == provider.id())
+        .map(|metadata| metadata.id)
 }
 
 /// 内存实现的 Provider 注册表
@@ -78,3 +233,447 @@ impl ProviderRegistry for InMemoryProviderRegistry {
         self.providers.read().await.keys().cloned().collect()
     }
 }
+
+/// 单个服务商类型的限流参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// 每秒补充的请求数
+    pub requests_per_second: u32,
+    /// 桶容量，即空闲后允许连续发出的请求数
+    pub burst: u32,
+}
+
+/// 按服务商类型的限流配置，未配置的类型不限流
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitConfig {
+    pub limits: HashMap<ProviderType, RateLimit>,
+}
+
+impl RateLimitConfig {
+    /// 按各服务商公开的 API 频率限制留出余量的默认配置
+    ///
+    /// Cloudflare 为每 5 分钟 1200 次，Route 53 为每秒 5 次，DigitalOcean 为每小时 5000 次，
+    /// 其余服务商按每秒 10 次。
+    #[must_use]
+    pub fn recommended() -> Self {
+        let default = RateLimit {
+            requests_per_second: 10,
+            burst: 20,
+        };
+        Self::default()
+            .with_limit(
+                ProviderType::Cloudflare,
+                RateLimit {
+                    requests_per_second: 4,
+                    burst: 20,
+                },
+            )
+            .with_limit(ProviderType::Aliyun, default)
+            .with_limit(ProviderType::Dnspod, default)
+            .with_limit(ProviderType::Huaweicloud, default)
+            .with_limit(
+                ProviderType::AwsRoute53,
+                RateLimit {
+                    requests_per_second: 5,
+                    burst: 5,
+                },
+            )
+            .with_limit(ProviderType::GcpDns, default)
+            .with_limit(
+                ProviderType::DigitalOcean,
+                RateLimit {
+                    requests_per_second: 1,
+                    burst: 50,
+                },
+            )
+    }
+
+    /// 设置某类服务商的限流参数
+    #[must_use]
+    pub fn with_limit(mut self, provider_type: ProviderType, limit: RateLimit) -> Self {
+        self.limits.insert(provider_type, limit);
+        self
+    }
+
+    /// 获取某类服务商的限流参数
+    #[must_use]
+    pub fn limit_for(&self, provider_type: &ProviderType) -> Option<RateLimit> {
+        self.limits.get(provider_type).copied()
+    }
+}
+
+/// 令牌桶：按固定间隔补充令牌，最多攒到 `burst` 个
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    tokens: u32,
+    refilled_at: tokio::time::Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst.max(1),
+            refilled_at: tokio::time::Instant::now(),
+        }
+    }
+
+    /// 补充一个令牌的间隔
+    fn interval(&self) -> Duration {
+        (Duration::from_secs(1) / self.limit.requests_per_second.max(1))
+            .max(Duration::from_nanos(1))
+    }
+
+    fn refill(&mut self, now: tokio::time::Instant) {
+        let interval = self.interval();
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        let earned = u32::try_from(elapsed.as_nanos() / interval.as_nanos()).unwrap_or(u32::MAX);
+        if earned == 0 {
+            return;
+        }
+        let capacity = self.limit.burst.max(1);
+        self.tokens = self.tokens.saturating_add(earned).min(capacity);
+        // 桶满时不保留零头，避免空闲后多攒令牌
+        self.refilled_at = if self.tokens == capacity {
+            now
+        } else {
+            self.refilled_at + interval * earned
+        };
+    }
+
+    /// 取一个令牌，桶空时等到下一个令牌补充
+    async fn acquire(&mut self) {
+        self.refill(tokio::time::Instant::now());
+        if self.tokens == 0 {
+            tokio::time::sleep_until(self.refilled_at + self.interval()).await;
+            self.refill(tokio::time::Instant::now());
+        }
+        self.tokens = self.tokens.saturating_sub(1);
+    }
+}
+
+/// 按账户限流的 Provider 注册表
+///
+/// 包装任意注册表，[`get`](ProviderRegistry::get) 返回的实例每次接口调用前从该账户的令牌桶
+/// 取一个令牌，桶空时等待补充而不是直接失败，避免批量操作触发服务商的 429。
+/// 桶参数按服务商类型取自 [`RateLimitConfig`]，未配置的类型不限流。
+/// 同一账户的等待者按先来后到依次取得令牌。
+pub struct RateLimitedProviderRegistry {
+    inner: Arc<dyn ProviderRegistry>,
+    config: RateLimitConfig,
+    buckets: Mutex<HashMap<String, Arc<Mutex<TokenBucket>>>>,
+}
+
+impl RateLimitedProviderRegistry {
+    /// 包装已有注册表
+    #[must_use]
+    pub fn new(inner: Arc<dyn ProviderRegistry>, config: RateLimitConfig) -> Self {
+        Self {
+            inner,
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn bucket(&self, account_id: &str, limit: RateLimit) -> Arc<Mutex<TokenBucket>> {
+        let mut buckets = self.buckets.lock().await;
+        Arc::clone(
+            buckets
+                .entry(account_id.to_string())
+                .or_insert_with(|| Arc::new(Mutex::new(TokenBucket::new(limit)))),
+        )
+    }
+}
+
+#[async_trait]
+impl ProviderRegistry for RateLimitedProviderRegistry {
+    async fn register(&self, account_id: String, provider: Arc<dyn DnsProvider>) {
+        // 重新注册可能换了服务商类型，桶按新参数重建
+        self.buckets.lock().await.remove(&account_id);
+        self.inner.register(account_id, provider).await;
+    }
+
+    async fn unregister(&self, account_id: &str) {
+        self.buckets.lock().await.remove(account_id);
+        self.inner.unregister(account_id).await;
+    }
+
+    async fn get(&self, account_id: &str) -> Option<Arc<dyn DnsProvider>> {
+        let provider = self.inner.get(account_id).await?;
+        let Some(metadata) = get_all_provider_metadata()
+            .into_iter()
+            .find(|metadata| metadata.id.to_string() == provider.id())
+        else {
+            return Some(provider);
+        };
+        let Some(limit) = self.config.limit_for(&metadata.id) else {
+            return Some(provider);
+        };
+        let bucket = self.bucket(account_id, limit).await;
+        Some(Arc::new(RateLimitedProvider {
+            inner: provider,
+            bucket,
+            native_batch: metadata.features.batch_changes,
+        }))
+    }
+
+    async fn list_account_ids(&self) -> Vec<String> {
+        self.inner.list_account_ids().await
+    }
+}
+
+/// 每次接口调用前从账户的令牌桶取一个令牌
+struct RateLimitedProvider {
+    inner: Arc<dyn DnsProvider>,
+    bucket: Arc<Mutex<TokenBucket>>,
+    /// 服务商是否有原生批量接口（没有时批量变更逐条调用单条接口）
+    native_batch: bool,
+}
+
+impl RateLimitedProvider {
+    async fn acquire(&self) {
+        self.bucket.lock().await.acquire().await;
+    }
+}
+
+#[async_trait]
+impl DnsProvider for RateLimitedProvider {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    /// 同 `MeteredProvider`，包装器只以 `Arc<dyn DnsProvider>` 使用，不会调用此方法
+    fn metadata() -> ProviderMetadata {
+        unreachable!("RateLimitedProvider is only used as a trait object")
+    }
+
+    async fn validate_credentials(&self) -> dns_orchestrator_provider::Result<bool> {
+        self.acquire().await;
+        self.inner.validate_credentials().await
+    }
+
+    async fn credentials_expires_at(
+        &self,
+    ) -> dns_orchestrator_provider::Result<Option<chrono::DateTime<Utc>>> {
+        self.acquire().await;
+        self.inner.credentials_expires_at().await
+    }
+
+    async fn probe_capabilities(&self) -> dns_orchestrator_provider::Result<CapabilityProbe> {
+        self.acquire().await;
+        self.inner.probe_capabilities().await
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> dns_orchestrator_provider::Result<PaginatedResponse<ProviderDomain>> {
+        self.acquire().await;
+        self.inner.list_domains(params).await
+    }
+
+    async fn get_domain(
+        &self,
+        domain_id: &str,
+    ) -> dns_orchestrator_provider::Result<ProviderDomain> {
+        self.acquire().await;
+        self.inner.get_domain(domain_id).await
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> dns_orchestrator_provider::Result<PaginatedResponse<DnsRecord>> {
+        self.acquire().await;
+        self.inner.list_records(domain_id, params).await
+    }
+
+    async fn create_record(
+        &self,
+        req: &CreateDnsRecordRequest,
+    ) -> dns_orchestrator_provider::Result<DnsRecord> {
+        self.acquire().await;
+        self.inner.create_record(req).await
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> dns_orchestrator_provider::Result<DnsRecord> {
+        self.acquire().await;
+        self.inner.update_record(record_id, req).await
+    }
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+        domain_id: &str,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.acquire().await;
+        self.inner.delete_record(record_id, domain_id).await
+    }
+
+    async fn set_weighted_routing(
+        &self,
+        domain_id: &str,
+        subdomain: &str,
+        enabled: bool,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.acquire().await;
+        self.inner
+            .set_weighted_routing(domain_id, subdomain, enabled)
+            .await
+    }
+
+    async fn set_record_weight(
+        &self,
+        record_id: &str,
+        weight: u8,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.acquire().await;
+        self.inner.set_record_weight(record_id, weight).await
+    }
+
+    async fn get_record_set(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> dns_orchestrator_provider::Result<Option<RecordSet>> {
+        self.acquire().await;
+        self.inner
+            .get_record_set(domain_id, name, record_type)
+            .await
+    }
+
+    async fn replace_record_set(
+        &self,
+        domain_id: &str,
+        set: &RecordSet,
+    ) -> dns_orchestrator_provider::Result<()> {
+        self.acquire().await;
+        self.inner.replace_record_set(domain_id, set).await
+    }
+
+    /// 原生批量接口一次请求只取一个令牌；没有原生接口时逐条提交，每条各取一个令牌
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        if self.native_batch {
+            self.acquire().await;
+            return self.inner.batch_change(domain_id, changes).await;
+        }
+        let mut results = Vec::with_capacity(changes.len());
+        for change in changes {
+            results.push(match change {
+                RecordChange::Create(request) => self.create_record(&request).await.into(),
+                RecordChange::Update { record_id, request } => {
+                    self.update_record(&record_id, &request).await.into()
+                }
+                RecordChange::Delete { record_id } => {
+                    self.delete_record(&record_id, domain_id).await.into()
+                }
+            });
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(requests_per_second: u32, burst: u32) -> RateLimit {
+        RateLimit {
+            requests_per_second,
+            burst,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bucket_allows_burst_then_waits_for_refill() {
+        let started = tokio::time::Instant::now();
+        let mut bucket = TokenBucket::new(limit(2, 3));
+        for _ in 0..3 {
+            bucket.acquire().await;
+        }
+        assert_eq!(started.elapsed(), Duration::ZERO);
+
+        bucket.acquire().await;
+        assert_eq!(started.elapsed(), Duration::from_millis(500));
+        bucket.acquire().await;
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn idle_bucket_refills_up_to_burst() {
+        let mut bucket = TokenBucket::new(limit(10, 2));
+        bucket.acquire().await;
+        bucket.acquire().await;
+        tokio::time::advance(Duration::from_mins(1)).await;
+
+        let started = tokio::time::Instant::now();
+        bucket.acquire().await;
+        bucket.acquire().await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+        bucket.acquire().await;
+        assert_eq!(started.elapsed(), Duration::from_millis(100));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn every_provider_call_takes_a_token() {
+        use crate::testing::MockDnsProvider;
+
+        let provider = RateLimitedProvider {
+            inner: Arc::new(MockDnsProvider::new().with_domain("zone-1", "example.com")),
+            bucket: Arc::new(Mutex::new(TokenBucket::new(limit(2, 1)))),
+            native_batch: false,
+        };
+        let params = PaginationParams {
+            page: 1,
+            page_size: 20,
+        };
+        let started = tokio::time::Instant::now();
+        for _ in 0..3 {
+            assert!(provider.list_domains(&params).await.is_ok());
+        }
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn credential_errors_are_not_network_failures() {
+        let provider = "cloudflare".to_string();
+        assert_eq!(
+            failure_of(&ProviderError::NetworkError {
+                provider: provider.clone(),
+                detail: "connection refused".to_string(),
+            }),
+            HealthCheckFailure::Network
+        );
+        assert_eq!(
+            failure_of(&ProviderError::InvalidCredentials {
+                provider: provider.clone(),
+                raw_message: None,
+            }),
+            HealthCheckFailure::Credentials
+        );
+        assert_eq!(
+            failure_of(&ProviderError::PermissionDenied {
+                provider,
+                raw_message: None,
+            }),
+            HealthCheckFailure::Permission
+        );
+    }
+
+    #[test]
+    fn config_only_limits_listed_providers() {
+        let config = RateLimitConfig::default().with_limit(ProviderType::Cloudflare, limit(4, 20));
+        assert_eq!(
+            config.limit_for(&ProviderType::Cloudflare),
+            Some(limit(4, 20))
+        );
+        assert_eq!(config.limit_for(&ProviderType::Dnspod), None);
+    }
+}
@@ -0,0 +1,24 @@
+//! 临时记录持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::TemporaryRecord;
+
+/// 临时记录仓库 Trait
+///
+/// 平台层需要实现此 trait 以提供临时记录持久化，应用重启后计划任务继续执行。
+#[async_trait]
+pub trait TemporaryRecordRepository: Send + Sync {
+    /// 获取所有临时记录
+    async fn find_all(&self) -> CoreResult<Vec<TemporaryRecord>>;
+
+    /// 根据 ID 获取临时记录
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<TemporaryRecord>>;
+
+    /// 保存临时记录（新增或更新）
+    async fn save(&self, record: &TemporaryRecord) -> CoreResult<()>;
+
+    /// 删除临时记录
+    async fn delete(&self, id: &str) -> CoreResult<()>;
+}
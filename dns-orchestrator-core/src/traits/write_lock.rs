@@ -0,0 +1,414 @@
+//! 记录写锁抽象 Trait
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::{OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock};
+use tokio::time::{timeout_at, Instant};
+
+use crate::error::{CoreError, CoreResult};
+
+/// 默认的写锁等待超时
+const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 写锁目标
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteLockTarget {
+    /// 整个域名：独占，与该域名下的任何记录写操作互斥（按值替换、整批导入等读改写操作）
+    Domain {
+        account_id: String,
+        domain_id: String,
+    },
+    /// 域名下的若干记录：记录独占、域名共享，不同记录的写操作可以并行。
+    /// `record_ids` 为空时只共享持有域名锁（创建记录）
+    Records {
+        account_id: String,
+        domain_id: String,
+        record_ids: Vec<String>,
+    },
+}
+
+impl WriteLockTarget {
+    /// 整个域名
+    #[must_use]
+    pub fn domain(account_id: &str, domain_id: &str) -> Self {
+        Self::Domain {
+            account_id: account_id.to_string(),
+            domain_id: domain_id.to_string(),
+        }
+    }
+
+    /// 域名下的若干记录
+    #[must_use]
+    pub fn records(
+        account_id: &str,
+        domain_id: &str,
+        record_ids: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self::Records {
+            account_id: account_id.to_string(),
+            domain_id: domain_id.to_string(),
+            record_ids: record_ids.into_iter().collect(),
+        }
+    }
+
+    /// 单条记录
+    #[must_use]
+    pub fn record(account_id: &str, domain_id: &str, record_id: &str) -> Self {
+        Self::records(account_id, domain_id, [record_id.to_string()])
+    }
+}
+
+/// 写锁守卫，drop 时释放全部锁
+#[must_use = "写锁在守卫 drop 时立即释放"]
+pub struct WriteLockGuard {
+    operation_id: String,
+    _held: Box<dyn Send + Sync>,
+}
+
+impl WriteLockGuard {
+    /// 由实现方构造，`held` drop 时应释放全部锁
+    pub fn new(operation_id: String, held: impl Send + Sync + 'static) -> Self {
+        Self {
+            operation_id,
+            _held: Box::new(held),
+        }
+    }
+
+    /// 持有写锁的操作 ID
+    pub fn operation_id(&self) -> &str {
+        &self.operation_id
+    }
+}
+
+/// 写锁 Trait
+///
+/// 所有记录写路径在调用服务商接口前按涉及的资源获取写锁，避免并发写同一记录产生中间状态。
+/// 默认实现 [`InProcessWriteLocks`] 只在进程内生效；Web 版多实例部署时可以注入基于
+/// 外部存储的分布式实现。
+#[async_trait]
+pub trait WriteLockBackend: Send + Sync {
+    /// 获取 `targets` 涉及的全部写锁
+    ///
+    /// # Arguments
+    /// * `operation` - 操作名称，用于生成操作 ID（占用提示中展示）
+    /// * `targets` - 写锁目标
+    ///
+    /// 等待超时返回 `CoreError::ResourceBusy`，附带占用该资源的操作 ID。
+    async fn acquire(
+        &self,
+        operation: &str,
+        targets: Vec<WriteLockTarget>,
+    ) -> CoreResult<WriteLockGuard>;
+}
+
+/// 锁键，域名锁排在记录锁之前
+///
+/// 加锁按键的顺序进行（先全部域名锁、再全部记录锁，各自按字典序），
+/// 所有操作的加锁顺序一致，不会互相等待形成死锁。
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum LockKey {
+    Domain(String, String),
+    Record(String, String, String),
+}
+
+impl LockKey {
+    fn resource(&self) -> String {
+        match self {
+            Self::Domain(account_id, domain_id) => format!("{account_id}/{domain_id}"),
+            Self::Record(account_id, domain_id, record_id) => {
+                format!("{account_id}/{domain_id}/{record_id}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockMode {
+    Shared,
+    Exclusive,
+}
+
+/// 单个锁键的状态
+#[derive(Default)]
+struct LockSlot {
+    lock: Arc<RwLock<()>>,
+    /// 当前持有该锁的操作 ID
+    holders: Mutex<Vec<String>>,
+}
+
+impl LockSlot {
+    fn holders(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.holders.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// 只为持有到 drop，不读取
+#[allow(dead_code)]
+enum Permit {
+    Shared(OwnedRwLockReadGuard<()>),
+    Exclusive(OwnedRwLockWriteGuard<()>),
+}
+
+/// 已获取的锁，drop 时移除占用记录并释放
+struct HeldLock {
+    slot: Arc<LockSlot>,
+    operation_id: String,
+    _permit: Permit,
+}
+
+impl Drop for HeldLock {
+    fn drop(&mut self) {
+        let mut holders = self.slot.holders();
+        if let Some(index) = holders.iter().position(|id| *id == self.operation_id) {
+            holders.swap_remove(index);
+        }
+    }
+}
+
+/// 进程内写锁
+///
+/// 默认实现，适用于桌面端与单实例 Web 部署。
+pub struct InProcessWriteLocks {
+    slots: Mutex<HashMap<LockKey, Arc<LockSlot>>>,
+    wait_timeout: Duration,
+}
+
+impl InProcessWriteLocks {
+    /// 创建进程内写锁，等待超时为 30 秒
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_wait_timeout(DEFAULT_WAIT_TIMEOUT)
+    }
+
+    /// 创建进程内写锁，指定等待超时
+    #[must_use]
+    pub fn with_wait_timeout(wait_timeout: Duration) -> Self {
+        Self {
+            slots: Mutex::new(HashMap::new()),
+            wait_timeout,
+        }
+    }
+
+    /// 获取锁键的状态，顺带清理无人持有的锁键
+    fn slot(&self, key: &LockKey) -> Arc<LockSlot> {
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        slots.retain(|_, slot| Arc::strong_count(slot) > 1);
+        Arc::clone(slots.entry(key.clone()).or_default())
+    }
+}
+
+impl Default for InProcessWriteLocks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 展开写锁目标：域名锁（独占优先于共享）与记录锁，按加锁顺序排列
+fn lock_plan(targets: Vec<WriteLockTarget>) -> BTreeMap<LockKey, LockMode> {
+    let mut plan = BTreeMap::new();
+    for target in targets {
+        match target {
+            WriteLockTarget::Domain {
+                account_id,
+                domain_id,
+            } => {
+                plan.insert(LockKey::Domain(account_id, domain_id), LockMode::Exclusive);
+            }
+            WriteLockTarget::Records {
+                account_id,
+                domain_id,
+                record_ids,
+            } => {
+                for record_id in record_ids {
+                    plan.insert(
+                        LockKey::Record(account_id.clone(), domain_id.clone(), record_id),
+                        LockMode::Exclusive,
+                    );
+                }
+                plan.entry(LockKey::Domain(account_id, domain_id))
+                    .or_insert(LockMode::Shared);
+            }
+        }
+    }
+    plan
+}
+
+#[async_trait]
+impl WriteLockBackend for InProcessWriteLocks {
+    async fn acquire(
+        &self,
+        operation: &str,
+        targets: Vec<WriteLockTarget>,
+    ) -> CoreResult<WriteLockGuard> {
+        let operation_id = format!("{operation}:{}", uuid::Uuid::new_v4());
+        let deadline = Instant::now() + self.wait_timeout;
+
+        let mut held = Vec::new();
+        for (key, mode) in lock_plan(targets) {
+            let slot = self.slot(&key);
+            let permit = match mode {
+                LockMode::Shared => timeout_at(deadline, Arc::clone(&slot.lock).read_owned())
+                    .await
+                    .map(Permit::Shared),
+                LockMode::Exclusive => timeout_at(deadline, Arc::clone(&slot.lock).write_owned())
+                    .await
+                    .map(Permit::Exclusive),
+            };
+            let Ok(permit) = permit else {
+                let holder = slot.holders().first().cloned();
+                let resource = key.resource();
+                log::warn!(
+                    "[WriteLock] {operation_id} gave up waiting for {resource} held by {holder:?}"
+                );
+                return Err(CoreError::ResourceBusy {
+                    resource,
+                    holder: holder.unwrap_or_else(|| "unknown".to_string()),
+                    timeout_ms: u64::try_from(self.wait_timeout.as_millis()).unwrap_or(u64::MAX),
+                });
+            };
+            slot.holders().push(operation_id.clone());
+            held.push(HeldLock {
+                slot,
+                operation_id: operation_id.clone(),
+                _permit: permit,
+            });
+        }
+
+        Ok(WriteLockGuard::new(operation_id, held))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locks(wait_ms: u64) -> InProcessWriteLocks {
+        InProcessWriteLocks::with_wait_timeout(Duration::from_millis(wait_ms))
+    }
+
+    #[tokio::test]
+    async fn different_records_do_not_block_each_other() -> CoreResult<()> {
+        let locks = locks(50);
+        let _a = locks
+            .acquire(
+                "update_record",
+                vec![WriteLockTarget::record("acc", "zone", "r1")],
+            )
+            .await?;
+        let _b = locks
+            .acquire(
+                "delete_record",
+                vec![WriteLockTarget::record("acc", "zone", "r2")],
+            )
+            .await?;
+        let _create = locks
+            .acquire(
+                "create_record",
+                vec![WriteLockTarget::records("acc", "zone", [])],
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn busy_record_reports_holder() -> CoreResult<()> {
+        let locks = locks(50);
+        let guard = locks
+            .acquire(
+                "update_record",
+                vec![WriteLockTarget::record("acc", "zone", "r1")],
+            )
+            .await?;
+
+        let result = locks
+            .acquire(
+                "batch_delete_records",
+                vec![WriteLockTarget::records(
+                    "acc",
+                    "zone",
+                    ["r2".to_string(), "r1".to_string()],
+                )],
+            )
+            .await;
+        assert!(matches!(
+            &result,
+            Err(CoreError::ResourceBusy { resource, holder, .. })
+                if resource == "acc/zone/r1" && holder == guard.operation_id()
+        ));
+        assert!(guard.operation_id().starts_with("update_record:"));
+
+        // 超时放弃时已获取的 r2 一并释放
+        drop(guard);
+        let _guard = locks
+            .acquire(
+                "delete_record",
+                vec![WriteLockTarget::record("acc", "zone", "r2")],
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn domain_lock_excludes_record_locks() -> CoreResult<()> {
+        let locks = locks(50);
+        let record = locks
+            .acquire(
+                "update_record",
+                vec![WriteLockTarget::record("acc", "zone", "r1")],
+            )
+            .await?;
+        assert!(matches!(
+            locks
+                .acquire(
+                    "replace_record_value",
+                    vec![WriteLockTarget::domain("acc", "zone")]
+                )
+                .await,
+            Err(CoreError::ResourceBusy { .. })
+        ));
+        drop(record);
+
+        let _domain = locks
+            .acquire(
+                "replace_record_value",
+                vec![WriteLockTarget::domain("acc", "zone")],
+            )
+            .await?;
+        assert!(matches!(
+            locks
+                .acquire(
+                    "create_record",
+                    vec![WriteLockTarget::records("acc", "zone", [])]
+                )
+                .await,
+            Err(CoreError::ResourceBusy { .. })
+        ));
+        // 其他域名不受影响
+        let _other = locks
+            .acquire(
+                "update_record",
+                vec![WriteLockTarget::record("acc", "other", "r1")],
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn released_slots_are_cleaned_up() -> CoreResult<()> {
+        let locks = locks(50);
+        let guard = locks
+            .acquire(
+                "update_record",
+                vec![WriteLockTarget::record("acc", "zone", "r1")],
+            )
+            .await?;
+        drop(guard);
+        let _ = locks.slot(&LockKey::Domain("acc".to_string(), "other".to_string()));
+        let slots = locks.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        assert_eq!(slots.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+//! 离线写队列持久化抽象 Trait
+
+use async_trait::async_trait;
+
+use crate::error::CoreResult;
+use crate::types::QueuedWrite;
+
+/// 离线写队列仓库 Trait
+///
+/// 队列内容包含记录值，平台层实现时应加密存储。
+#[async_trait]
+pub trait WriteQueueRepository: Send + Sync {
+    /// 获取队列中的全部写操作（按入队顺序）
+    async fn find_all(&self) -> CoreResult<Vec<QueuedWrite>>;
+
+    /// 用 `entries` 替换整个队列
+    async fn save_all(&self, entries: &[QueuedWrite]) -> CoreResult<()>;
+}
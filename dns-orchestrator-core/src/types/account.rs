@@ -13,6 +13,21 @@ pub enum AccountStatus {
     Active,
     /// 错误状态（凭证失效等）
     Error,
+    /// 凭证（API token）已过用户填写或服务商返回的到期时间
+    #[serde(rename = "credentials_expired")]
+    CredentialsExpired,
+}
+
+/// 凭证健康状态（最近一次主动校验的结果）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CredentialStatus {
+    /// 服务商接受了凭证
+    Valid,
+    /// 服务商拒绝了凭证（失效、吊销或过期）
+    Invalid,
+    /// 无法判断（网络错误、超时等）
+    Unknown,
 }
 
 /// 账户信息
@@ -38,6 +53,45 @@ pub struct Account {
     /// 错误信息（状态为 Error 时）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// 凭证到期时间（`None` 表示未知或不过期）
+    #[serde(
+        rename = "credentialsExpiresAt",
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
+    /// 已发送的到期提醒档位（距到期天数），更换凭证或修改到期时间时清空
+    #[serde(
+        rename = "expiryReminderDays",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expiry_reminder_days: Option<u32>,
+    /// 最近一次凭证校验的结果（从未校验时为 `None`）
+    #[serde(
+        rename = "credentialStatus",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub credential_status: Option<CredentialStatus>,
+    /// 最近一次凭证校验的时间
+    #[serde(
+        rename = "lastValidatedAt",
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_validated_at: Option<DateTime<Utc>>,
+}
+
+impl Account {
+    /// 凭证已过期时返回到期时间
+    #[must_use]
+    pub fn credentials_expired_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.credentials_expires_at
+            .filter(|expires_at| *expires_at <= now)
+    }
 }
 
 /// 创建账户请求（v1.7.0 类型安全重构）
@@ -49,6 +103,13 @@ pub struct CreateAccountRequest {
     pub provider: ProviderType,
     /// 凭证（结构化类型）
     pub credentials: ProviderCredentials,
+    /// 凭证到期时间（可选，未填写时尝试从服务商查询）
+    #[serde(
+        rename = "credentialsExpiresAt",
+        default,
+        with = "crate::utils::datetime::option"
+    )]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
 }
 
 /// 更新账户请求（v1.7.0 类型安全重构）
@@ -60,4 +121,81 @@ pub struct UpdateAccountRequest {
     pub name: Option<String>,
     /// 新的凭证（可选，提供时会覆盖原有凭证）
     pub credentials: Option<ProviderCredentials>,
+    /// 凭证到期时间
+    ///
+    /// 提供新凭证时总是以此值为准（未填写时尝试从服务商查询）；
+    /// 未提供新凭证时仅在填写了此值时更新。
+    #[serde(
+        rename = "credentialsExpiresAt",
+        default,
+        with = "crate::utils::datetime::option"
+    )]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
+}
+
+/// 账户删除时受影响的数据类别
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DeletionImpactKind {
+    /// 服务商上的域名（仅解除关联，不会删除服务商上的数据）
+    Domains,
+    /// 收藏的域名
+    Favorites,
+    /// 域名标签
+    Tags,
+    /// 域名备注
+    Notes,
+    /// 本地保存的凭证
+    Credentials,
+}
+
+/// 受影响数据的处理方式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeletionAction {
+    /// 随账户一起删除
+    Delete,
+    /// 保留（不受影响）
+    Keep,
+}
+
+/// 单项影响
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionImpactItem {
+    pub kind: DeletionImpactKind,
+    /// 数量（`None` 表示查询失败，前端显示为 unknown）
+    pub count: Option<u32>,
+    pub action: DeletionAction,
+    /// 查询失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 账户删除影响面分析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDeletionImpact {
+    pub account_id: String,
+    pub account_name: String,
+    pub items: Vec<DeletionImpactItem>,
+}
+
+/// 单项清理结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionCleanupItem {
+    pub kind: DeletionImpactKind,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 账户删除结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDeletionResult {
+    pub account_id: String,
+    /// 各项数据的清理结果（仅包含处理方式为 `Delete` 的项）
+    pub cleanup: Vec<DeletionCleanupItem>,
 }
@@ -0,0 +1,61 @@
+//! 账户能力相关类型定义
+
+use chrono::{DateTime, Utc};
+use dns_orchestrator_provider::{
+    CapabilityProbe, DnsRecordType, ProviderFeatures, ProviderLimits, ProviderType,
+};
+use serde::{Deserialize, Serialize};
+
+/// 能力来源
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CapabilitySource {
+    /// 服务商的静态声明（未探测或探测失败）
+    Static,
+    /// 静态声明经运行时探测修正
+    Probed,
+}
+
+/// 账户能力探测结果缓存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCapabilityCache {
+    pub account_id: String,
+    /// 探测得到的修正（探测失败时为空）
+    #[serde(default)]
+    pub probe: CapabilityProbe,
+    /// 探测时间
+    #[serde(with = "crate::utils::datetime")]
+    pub probed_at: DateTime<Utc>,
+    /// 探测失败原因
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 账户的实际能力：服务商静态声明叠加该账户的探测修正
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCapabilities {
+    pub account_id: String,
+    pub provider: ProviderType,
+    /// 探测到的套餐名称
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    /// 可创建的记录类型（不含 SOA）
+    pub record_types: Vec<DnsRecordType>,
+    pub features: ProviderFeatures,
+    pub limits: ProviderLimits,
+    pub source: CapabilitySource,
+    /// 相对静态声明的修正项
+    pub corrections: CapabilityProbe,
+    /// 上次探测时间（从未探测时为 `None`）
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub probed_at: Option<DateTime<Utc>>,
+    /// 上次探测失败的原因
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probe_error: Option<String>,
+}
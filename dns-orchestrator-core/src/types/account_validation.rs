@@ -0,0 +1,34 @@
+//! 账户凭证校验相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use dns_orchestrator_provider::ProviderType;
+
+use super::CredentialStatus;
+
+/// 单个账户的凭证校验结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountValidation {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider: ProviderType,
+    pub credential_status: CredentialStatus,
+    #[serde(with = "crate::utils::datetime")]
+    pub validated_at: DateTime<Utc>,
+    /// 凭证无效或无法判断时的原因
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 批量校验的汇总
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountValidationSummary {
+    pub valid_count: usize,
+    pub invalid_count: usize,
+    pub unknown_count: usize,
+    /// 各账户的结果，按账户名排序
+    pub results: Vec<AccountValidation>,
+}
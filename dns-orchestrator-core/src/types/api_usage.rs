@@ -0,0 +1,156 @@
+//! 服务商 API 用量统计相关类型定义
+
+use chrono::NaiveDate;
+use dns_orchestrator_provider::ProviderType;
+use serde::{Deserialize, Serialize};
+
+/// 计入用量的服务商接口调用
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiOperation {
+    ValidateCredentials,
+    CredentialsExpiry,
+    ProbeCapabilities,
+    ListDomains,
+    GetDomain,
+    ListRecords,
+    CreateRecord,
+    UpdateRecord,
+    DeleteRecord,
+    WeightedRouting,
+    GetRecordSet,
+    ReplaceRecordSet,
+    BatchChange,
+}
+
+impl ApiOperation {
+    /// 全部操作
+    pub const ALL: [Self; 13] = [
+        Self::ValidateCredentials,
+        Self::CredentialsExpiry,
+        Self::ProbeCapabilities,
+        Self::ListDomains,
+        Self::GetDomain,
+        Self::ListRecords,
+        Self::CreateRecord,
+        Self::UpdateRecord,
+        Self::DeleteRecord,
+        Self::WeightedRouting,
+        Self::GetRecordSet,
+        Self::ReplaceRecordSet,
+        Self::BatchChange,
+    ];
+
+    /// 存储与导出使用的名称（与 serde 序列化一致）
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ValidateCredentials => "validate_credentials",
+            Self::CredentialsExpiry => "credentials_expiry",
+            Self::ProbeCapabilities => "probe_capabilities",
+            Self::ListDomains => "list_domains",
+            Self::GetDomain => "get_domain",
+            Self::ListRecords => "list_records",
+            Self::CreateRecord => "create_record",
+            Self::UpdateRecord => "update_record",
+            Self::DeleteRecord => "delete_record",
+            Self::WeightedRouting => "weighted_routing",
+            Self::GetRecordSet => "get_record_set",
+            Self::ReplaceRecordSet => "replace_record_set",
+            Self::BatchChange => "batch_change",
+        }
+    }
+
+    /// 按名称解析，未知名称返回 `None`
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|op| op.as_str() == value)
+    }
+}
+
+/// 某账户某天某操作的调用次数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageCount {
+    pub account_id: String,
+    /// 调用日期（UTC）
+    pub date: NaiveDate,
+    pub operation: ApiOperation,
+    pub count: u64,
+}
+
+/// 每日调用次数
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageDay {
+    pub date: NaiveDate,
+    pub count: u64,
+}
+
+/// 单个操作的调用次数合计
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageOperationTotal {
+    pub operation: ApiOperation,
+    pub count: u64,
+}
+
+/// 配额的统计周期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiQuotaWindow {
+    /// 每天（由服务商的分钟/小时级限流折算）
+    Day,
+    /// 每月
+    Month,
+}
+
+/// 服务商公开的调用配额
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiQuotaRule {
+    pub window: ApiQuotaWindow,
+    /// 周期内允许的调用次数
+    pub limit: u64,
+}
+
+/// 超限风险
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiUsageRisk {
+    /// 服务商未公开配额
+    Unknown,
+    /// 低于配额的 80%
+    Normal,
+    /// 达到配额的 80%
+    Warning,
+    /// 达到或超过配额
+    Exceeded,
+}
+
+/// 账户某月的 API 用量报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageReport {
+    pub account_id: String,
+    pub provider: ProviderType,
+    /// 统计月份（`YYYY-MM`）
+    pub month: String,
+    /// 每日调用次数（月初到今天或月末，无调用的日期为 0）
+    pub days: Vec<ApiUsageDay>,
+    /// 按操作的调用次数，按次数倒序
+    pub operations: Vec<ApiUsageOperationTotal>,
+    /// 本月调用总数
+    pub total: u64,
+    /// 上月调用总数
+    pub previous_month_total: u64,
+    /// 按已过天数的日均值推算的本月总数（已结束的月份等于 `total`）
+    pub forecast_total: u64,
+    /// 服务商公开的调用配额（未公开时为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<ApiQuotaRule>,
+    /// 用量占配额的比例：按月配额比较预测总数，按天配额比较单日峰值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota_usage_ratio: Option<f64>,
+    pub risk: ApiUsageRisk,
+}
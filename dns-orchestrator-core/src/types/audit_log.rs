@@ -0,0 +1,330 @@
+//! DNS 记录变更审计日志相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::DnsRecord;
+
+/// 审计操作类型
+///
+/// 批量操作按记录逐条写入日志，以 `Batch*` 区分于单条操作。
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditOperation {
+    Create,
+    Update,
+    Delete,
+    BatchCreate,
+    BatchUpdate,
+    BatchDelete,
+}
+
+impl AuditOperation {
+    /// 持久化用的字符串表示（与 serde 序列化一致）
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+            Self::BatchCreate => "batchCreate",
+            Self::BatchUpdate => "batchUpdate",
+            Self::BatchDelete => "batchDelete",
+        }
+    }
+
+    /// 从持久化字符串解析
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            Self::Create,
+            Self::Update,
+            Self::Delete,
+            Self::BatchCreate,
+            Self::BatchUpdate,
+            Self::BatchDelete,
+        ]
+        .into_iter()
+        .find(|op| op.as_str() == value)
+    }
+}
+
+/// 变更来源
+///
+/// 用于在变更报告中区分人工变更与自动化变更（DDNS、计划任务）。
+/// 没有该字段的历史审计记录视为人工变更。
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeSource {
+    /// 通过界面或 API 手动发起
+    #[default]
+    Manual,
+    /// DDNS 自动更新
+    Ddns,
+    /// 计划任务
+    Scheduled,
+}
+
+impl ChangeSource {
+    /// 持久化用的字符串表示（与 serde 序列化一致）
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Ddns => "ddns",
+            Self::Scheduled => "scheduled",
+        }
+    }
+
+    /// 从持久化字符串解析
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Manual, Self::Ddns, Self::Scheduled]
+            .into_iter()
+            .find(|source| source.as_str() == value)
+    }
+
+    /// 是否为自动化来源
+    #[must_use]
+    pub const fn is_automated(self) -> bool {
+        !matches!(self, Self::Manual)
+    }
+}
+
+/// 审计记录：一次记录变更的快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub id: String,
+    #[serde(with = "crate::utils::datetime")]
+    pub timestamp: DateTime<Utc>,
+    pub account_id: String,
+    pub domain_id: String,
+    pub operation: AuditOperation,
+    #[serde(default)]
+    pub source: ChangeSource,
+    /// 记录 ID（创建失败时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<String>,
+    /// 变更前的记录（创建时为空；获取快照失败时也可能为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<DnsRecord>,
+    /// 变更后的记录（删除或失败时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<DnsRecord>,
+    pub success: bool,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 强制变更原因（变更发生在强制变更生效期间）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub override_reason: Option<String>,
+}
+
+impl AuditEntry {
+    /// 创建一条审计记录，时间戳取当前时间
+    #[must_use]
+    pub fn new(account_id: &str, domain_id: &str, operation: AuditOperation) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            account_id: account_id.to_string(),
+            domain_id: domain_id.to_string(),
+            operation,
+            source: ChangeSource::Manual,
+            record_id: None,
+            before: None,
+            after: None,
+            success: true,
+            error: None,
+            override_reason: None,
+        }
+    }
+}
+
+/// 审计日志查询条件（各字段为空表示不过滤）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogFilter {
+    #[serde(default)]
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub domain_id: Option<String>,
+    #[serde(default)]
+    pub record_id: Option<String>,
+    /// 起始时间（含）
+    #[serde(default, with = "crate::utils::datetime::option")]
+    pub since: Option<DateTime<Utc>>,
+    /// 截止时间（不含）
+    #[serde(default, with = "crate::utils::datetime::option")]
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl AuditLogFilter {
+    /// 判断审计记录是否满足过滤条件（供内存/文件实现使用）
+    #[must_use]
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.account_id
+            .as_ref()
+            .is_none_or(|id| *id == entry.account_id)
+            && self
+                .domain_id
+                .as_ref()
+                .is_none_or(|id| *id == entry.domain_id)
+            && self
+                .record_id
+                .as_ref()
+                .is_none_or(|id| entry.record_id.as_ref() == Some(id))
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp < until)
+    }
+}
+
+/// 记录变更类型（批量操作归入对应的单条类型）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordChangeType {
+    Create,
+    Update,
+    Delete,
+}
+
+impl From<AuditOperation> for RecordChangeType {
+    fn from(operation: AuditOperation) -> Self {
+        match operation {
+            AuditOperation::Create | AuditOperation::BatchCreate => Self::Create,
+            AuditOperation::Update | AuditOperation::BatchUpdate => Self::Update,
+            AuditOperation::Delete | AuditOperation::BatchDelete => Self::Delete,
+        }
+    }
+}
+
+/// 单条记录的变更历史项（由成功的审计记录生成）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordChangeEntry {
+    /// 对应审计记录 ID，用于恢复
+    pub id: String,
+    pub account_id: String,
+    pub domain_id: String,
+    pub record_id: String,
+    pub change_type: RecordChangeType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<DnsRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<DnsRecord>,
+    #[serde(with = "crate::utils::datetime")]
+    pub changed_at: DateTime<Utc>,
+}
+
+impl RecordChangeEntry {
+    /// 由审计记录生成，失败或缺少记录 ID 的审计记录返回 `None`
+    #[must_use]
+    pub fn from_audit_entry(entry: AuditEntry) -> Option<Self> {
+        if !entry.success {
+            return None;
+        }
+        Some(Self {
+            id: entry.id,
+            account_id: entry.account_id,
+            domain_id: entry.domain_id,
+            record_id: entry.record_id?,
+            change_type: entry.operation.into(),
+            before: entry.before,
+            after: entry.after,
+            changed_at: entry.timestamp,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn operation_round_trips_through_str() -> serde_json::Result<()> {
+        for op in [
+            AuditOperation::Create,
+            AuditOperation::BatchDelete,
+            AuditOperation::BatchUpdate,
+        ] {
+            assert_eq!(AuditOperation::parse(op.as_str()), Some(op));
+            assert_eq!(serde_json::to_string(&op)?, format!("\"{}\"", op.as_str()));
+        }
+        assert_eq!(AuditOperation::parse("rename"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn source_defaults_to_manual() -> serde_json::Result<()> {
+        assert_eq!(ChangeSource::parse("ddns"), Some(ChangeSource::Ddns));
+        assert!(ChangeSource::Scheduled.is_automated());
+
+        let mut value = serde_json::to_value(AuditEntry::new(
+            "acc",
+            "example.com",
+            AuditOperation::Create,
+        ))?;
+        assert_eq!(value["source"], "manual");
+        if let Some(object) = value.as_object_mut() {
+            object.remove("source");
+        }
+        let entry: AuditEntry = serde_json::from_value(value)?;
+        assert_eq!(entry.source, ChangeSource::Manual);
+        Ok(())
+    }
+
+    #[test]
+    fn filter_matches_account_domain_and_range() {
+        let entry = AuditEntry::new("acc", "example.com", AuditOperation::Create);
+        assert!(AuditLogFilter::default().matches(&entry));
+
+        let filter = AuditLogFilter {
+            account_id: Some("acc".to_string()),
+            domain_id: Some("example.com".to_string()),
+            record_id: None,
+            since: Some(entry.timestamp),
+            until: Some(entry.timestamp + Duration::seconds(1)),
+        };
+        assert!(filter.matches(&entry));
+
+        let other_domain = AuditLogFilter {
+            domain_id: Some("example.org".to_string()),
+            ..filter.clone()
+        };
+        assert!(!other_domain.matches(&entry));
+
+        let until_now = AuditLogFilter {
+            until: Some(entry.timestamp),
+            ..filter
+        };
+        assert!(!until_now.matches(&entry));
+    }
+
+    #[test]
+    fn change_entry_from_audit_entry() {
+        let mut entry = AuditEntry::new("acc", "example.com", AuditOperation::BatchDelete);
+        entry.record_id = Some("r1".to_string());
+
+        let filter = AuditLogFilter {
+            record_id: Some("r1".to_string()),
+            ..AuditLogFilter::default()
+        };
+        assert!(filter.matches(&entry));
+        assert!(!AuditLogFilter {
+            record_id: Some("r2".to_string()),
+            ..AuditLogFilter::default()
+        }
+        .matches(&entry));
+
+        let change = RecordChangeEntry::from_audit_entry(entry.clone());
+        assert_eq!(
+            change.map(|c| c.change_type),
+            Some(RecordChangeType::Delete)
+        );
+
+        entry.success = false;
+        assert!(RecordChangeEntry::from_audit_entry(entry).is_none());
+    }
+}
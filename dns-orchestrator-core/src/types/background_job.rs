@@ -0,0 +1,70 @@
+//! 后台任务相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 后台任务调度方式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum JobSchedule {
+    /// 固定间隔执行（首次在启动后一个间隔执行）
+    #[serde(rename_all = "camelCase")]
+    Interval { interval_secs: u64 },
+    /// 每天固定时间执行（UTC）
+    Daily { hour: u8, minute: u8 },
+    /// 仅手动触发
+    Manual,
+}
+
+/// 并发策略
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum JobConcurrency {
+    /// 串行：上一次尚未结束时跳过本次调度
+    Serial,
+    /// 并行：允许多次执行重叠
+    Parallel,
+}
+
+/// 单次执行结果状态
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum JobRunStatus {
+    Success,
+    Failed,
+    /// 超时被中断
+    TimedOut,
+    /// 执行体 panic
+    Panicked,
+}
+
+/// 单次执行记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobRunRecord {
+    pub status: JobRunStatus,
+    /// 执行摘要或错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(with = "crate::utils::datetime")]
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+}
+
+/// 后台任务状态（供"系统状态"页展示）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundJobInfo {
+    pub name: String,
+    pub schedule: JobSchedule,
+    pub concurrency: JobConcurrency,
+    pub timeout_secs: u64,
+    /// 正在执行的实例数
+    pub running: u32,
+    /// 累计执行次数
+    pub run_count: u64,
+    /// 上次执行记录
+    pub last_run: Option<JobRunRecord>,
+    /// 下次计划执行时间（手动任务或运行器未启动时为 `None`）
+    pub next_run_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,86 @@
+//! 变更窗口（禁止变更时段）相关类型定义
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 禁止变更时段的时间规则
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ChangeWindowSchedule {
+    /// 周期规则：cron 表达式（`分 时 日 月 周`）命中的时刻开始禁止，持续 `duration_minutes`
+    #[serde(rename_all = "camelCase")]
+    Recurring { cron: String, duration_minutes: u32 },
+    /// 一次性日期区间（规则时区下的本地时间，左闭右开）
+    OneOff {
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    },
+}
+
+/// 禁止变更规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeWindowRule {
+    /// 规则 ID (UUID)
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    /// 规则使用的时区（UTC 偏移，如 `+08:00`，不处理夏令时）
+    pub utc_offset: String,
+    pub schedule: ChangeWindowSchedule,
+    #[serde(with = "crate::utils::datetime")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 创建禁止变更规则请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChangeWindowRuleRequest {
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub utc_offset: String,
+    pub schedule: ChangeWindowSchedule,
+}
+
+const fn default_enabled() -> bool {
+    true
+}
+
+/// 更新禁止变更规则请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChangeWindowRuleRequest {
+    pub id: String,
+    pub name: Option<String>,
+    pub enabled: Option<bool>,
+    pub utc_offset: Option<String>,
+    pub schedule: Option<ChangeWindowSchedule>,
+}
+
+/// 一段禁止变更时段（UTC）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPeriod {
+    pub rule_id: String,
+    pub rule_name: String,
+    #[serde(with = "crate::utils::datetime")]
+    pub start: DateTime<Utc>,
+    /// 结束时间（不含）
+    #[serde(with = "crate::utils::datetime")]
+    pub end: DateTime<Utc>,
+}
+
+/// 强制变更（在禁止时段内放行写操作）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeWindowOverride {
+    /// 强制变更原因
+    pub reason: String,
+    #[serde(with = "crate::utils::datetime")]
+    pub started_at: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,129 @@
+//! 变更报告（changelog）相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{ChangeSource, RecordChangeType};
+
+/// 变更报告输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangelogFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+/// 按变更来源过滤
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangelogSourceFilter {
+    #[default]
+    All,
+    /// 只看人工变更
+    Manual,
+    /// 只看自动化变更（DDNS、计划任务）
+    Automated,
+}
+
+impl ChangelogSourceFilter {
+    #[must_use]
+    pub const fn matches(self, source: ChangeSource) -> bool {
+        match self {
+            Self::All => true,
+            Self::Manual => !source.is_automated(),
+            Self::Automated => source.is_automated(),
+        }
+    }
+}
+
+/// 生成变更报告请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogRequest {
+    /// 只包含该账户的变更（为空表示所有账户）
+    #[serde(default)]
+    pub account_id: Option<String>,
+    /// 只包含该域名的变更（为空表示所有域名）
+    #[serde(default)]
+    pub domain_id: Option<String>,
+    /// 起始时间（含，UTC）
+    #[serde(with = "crate::utils::datetime")]
+    pub since: DateTime<Utc>,
+    /// 截止时间（不含，UTC）
+    #[serde(with = "crate::utils::datetime")]
+    pub until: DateTime<Utc>,
+    #[serde(default)]
+    pub format: ChangelogFormat,
+    #[serde(default)]
+    pub source: ChangelogSourceFilter,
+    /// 最多列出的变更条数，超出时截断并在报告中注明（默认 500）
+    #[serde(default)]
+    pub max_entries: Option<usize>,
+}
+
+/// 变更报告中的一条变更
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogEntry {
+    #[serde(with = "crate::utils::datetime")]
+    pub changed_at: DateTime<Utc>,
+    pub change_type: RecordChangeType,
+    pub source: ChangeSource,
+    /// 记录名（主机记录）
+    pub record_name: String,
+    pub record_type: String,
+    /// 变更前的值摘要（创建时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// 变更后的值摘要（删除时为空）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// 强制变更原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub override_reason: Option<String>,
+}
+
+/// 按域名分组的变更
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogDomain {
+    pub account_id: String,
+    pub account_name: String,
+    pub domain_id: String,
+    /// 域名（无法获取时为域名 ID）
+    pub domain_name: String,
+    /// 按时间升序
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// 变更报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Changelog {
+    #[serde(with = "crate::utils::datetime")]
+    pub since: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub until: DateTime<Utc>,
+    /// 报告中时间所用的时区（固定为 `UTC`）
+    pub timezone: String,
+    pub source: ChangelogSourceFilter,
+    /// 时间范围内符合条件的变更总数
+    pub total_changes: usize,
+    /// 报告中列出的变更数（截断时小于总数）
+    pub listed_changes: usize,
+    pub truncated: bool,
+    /// 按域名排序
+    pub domains: Vec<ChangelogDomain>,
+}
+
+/// 变更报告生成结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogOutput {
+    pub format: ChangelogFormat,
+    /// 按 `format` 渲染的报告正文
+    pub content: String,
+    pub total_changes: usize,
+    pub truncated: bool,
+}
@@ -0,0 +1,30 @@
+//! 凭证到期提醒相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use dns_orchestrator_provider::ProviderType;
+
+/// 即将到期（或已过期）的账户凭证
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiringCredential {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider: ProviderType,
+    #[serde(with = "crate::utils::datetime")]
+    pub expires_at: DateTime<Utc>,
+    /// 距到期剩余天数（向上取整，已过期时为 0 或负数）
+    pub days_remaining: i64,
+    pub expired: bool,
+}
+
+/// 一次到期检查的结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialExpiryCheck {
+    /// 本次进入新提醒档位（14/7/1 天）的账户，每个档位只提醒一次
+    pub reminders: Vec<ExpiringCredential>,
+    /// 本次新标记为 `CredentialsExpired` 的账户
+    pub expired: Vec<ExpiringCredential>,
+}
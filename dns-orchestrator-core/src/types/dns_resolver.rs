@@ -0,0 +1,266 @@
+//! DNS 解析后端相关类型定义
+//!
+//! 工具箱通过 [`DnsResolverBackend`](crate::traits::DnsResolverBackend) 发起查询，
+//! 这里的类型与具体的解析库（hickory 等）无关。
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+/// 查询的记录类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DnsQueryType {
+    A,
+    Aaaa,
+    Cname,
+    Mx,
+    Txt,
+    Ns,
+    Soa,
+    Srv,
+    Caa,
+    Naptr,
+    Ptr,
+    Dnskey,
+    Ds,
+}
+
+impl DnsQueryType {
+    /// 记录类型名称（大写）
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::Aaaa => "AAAA",
+            Self::Cname => "CNAME",
+            Self::Mx => "MX",
+            Self::Txt => "TXT",
+            Self::Ns => "NS",
+            Self::Soa => "SOA",
+            Self::Srv => "SRV",
+            Self::Caa => "CAA",
+            Self::Naptr => "NAPTR",
+            Self::Ptr => "PTR",
+            Self::Dnskey => "DNSKEY",
+            Self::Ds => "DS",
+        }
+    }
+}
+
+impl fmt::Display for DnsQueryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 查询目标服务器
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DnsServer {
+    /// 系统配置的 DNS 服务器
+    System,
+    /// 指定 IP 的 DNS 服务器（53 端口）
+    Ip(IpAddr),
+    /// 指定地址与端口的 DNS 服务器（如 853 端口的 `DoT` 服务器）
+    Socket(SocketAddr),
+}
+
+/// 传输协议
+///
+/// 系统 DNS 仅支持 [`Udp`](Self::Udp) / [`Tcp`](Self::Tcp) 限定协议，其余方式使用后端默认。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DnsTransport {
+    /// 后端默认（hickory：UDP 与 TCP 同时发起）
+    #[default]
+    Auto,
+    /// 先走 UDP，应答被截断时回退 TCP
+    UdpWithTcpFallback,
+    /// 仅 UDP
+    Udp,
+    /// 仅 TCP
+    Tcp,
+    /// DNS over TLS，需要在 [`DnsQueryOptions::tls_server_name`] 中指定证书校验用的服务器名称
+    Tls,
+}
+
+/// 查询选项
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DnsQueryOptions {
+    pub transport: DnsTransport,
+    /// 是否启用 EDNS0
+    pub edns0: bool,
+    /// 是否进行 DNSSEC 验证（验证失败时查询返回错误）
+    pub dnssec: bool,
+    /// 单次尝试的超时时间，`None` 使用后端默认值
+    pub timeout: Option<Duration>,
+    /// 只尝试一次，不重试
+    pub single_attempt: bool,
+    /// `DoT` 的 TLS 服务器名称（SNI 与证书校验）
+    pub tls_server_name: Option<String>,
+}
+
+/// 一次 DNS 查询
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnsQuery {
+    pub name: String,
+    pub record_type: DnsQueryType,
+    pub server: DnsServer,
+    pub options: DnsQueryOptions,
+}
+
+impl DnsQuery {
+    /// 使用默认选项创建查询
+    pub fn new(name: impl Into<String>, record_type: DnsQueryType, server: DnsServer) -> Self {
+        Self {
+            name: name.into(),
+            record_type,
+            server,
+            options: DnsQueryOptions::default(),
+        }
+    }
+
+    /// 替换查询选项
+    #[must_use]
+    pub fn with_options(mut self, options: DnsQueryOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// 记录数据
+///
+/// 域名类字段保留后端返回的原始形式（hickory 为带末尾点的 FQDN），由调用方决定是否去掉。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DnsRecordData {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Cname(String),
+    Ns(String),
+    Ptr(String),
+    Mx {
+        preference: u16,
+        exchange: String,
+    },
+    /// 多个 character-string 分段
+    Txt(Vec<String>),
+    Soa {
+        mname: String,
+        rname: String,
+        serial: u32,
+        refresh: i32,
+        retry: i32,
+        expire: i32,
+        minimum: u32,
+    },
+    Srv {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: String,
+    },
+    Caa {
+        critical: bool,
+        tag: String,
+        value: String,
+    },
+    Naptr {
+        order: u16,
+        preference: u16,
+        flags: String,
+        services: String,
+        regexp: String,
+        replacement: String,
+    },
+    Dnskey {
+        flags: u16,
+        algorithm: u8,
+        public_key: Vec<u8>,
+    },
+    Ds {
+        key_tag: u16,
+        algorithm: u8,
+        digest_type: u8,
+        digest: Vec<u8>,
+    },
+    Rrsig {
+        type_covered: String,
+        algorithm: u8,
+        labels: u8,
+        original_ttl: u32,
+        expiration: u32,
+        inception: u32,
+        key_tag: u16,
+        signer_name: String,
+        signature: Vec<u8>,
+    },
+    /// 工具箱未使用的记录类型，保留文本形式
+    Other {
+        record_type: String,
+        data: String,
+    },
+}
+
+/// 应答中的一条记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnsAnswerRecord {
+    pub name: String,
+    pub ttl: u32,
+    pub data: DnsRecordData,
+}
+
+/// 查询应答
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnsAnswer {
+    /// 应答记录（包括 CNAME 链、DNSSEC 验证时附带的 RRSIG 等）
+    pub records: Vec<DnsAnswerRecord>,
+    /// 查询耗时
+    pub elapsed: Duration,
+}
+
+/// 查询失败类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DnsQueryErrorKind {
+    /// 域名不存在
+    NxDomain,
+    /// 域名存在但没有该类型的记录
+    NoRecords,
+    /// 服务器返回 SERVFAIL（包括 DNSSEC 验证失败）
+    ServFail,
+    /// 服务器拒绝查询
+    Refused,
+    /// 服务器返回其他错误响应码（FORMERR、NOTIMP 等）
+    ErrorResponse,
+    /// 查询超时
+    Timeout,
+    /// 应答被截断且无法回退
+    Truncated,
+    /// 网络错误等其他失败
+    Other,
+}
+
+/// 查询失败
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message}")]
+pub struct DnsQueryError {
+    pub kind: DnsQueryErrorKind,
+    pub message: String,
+}
+
+impl DnsQueryError {
+    pub fn new(kind: DnsQueryErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    /// 服务器给出了应答（NXDOMAIN、无记录或错误响应码），而不是超时或网络错误
+    pub const fn is_answered(&self) -> bool {
+        matches!(
+            self.kind,
+            DnsQueryErrorKind::NxDomain
+                | DnsQueryErrorKind::NoRecords
+                | DnsQueryErrorKind::ServFail
+                | DnsQueryErrorKind::Refused
+                | DnsQueryErrorKind::ErrorResponse
+        )
+    }
+}
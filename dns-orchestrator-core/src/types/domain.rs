@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use dns_orchestrator_provider::{DomainStatus, ProviderDomain, ProviderType};
 
+use super::domain_derived_status::DomainDerivedStatus;
 use super::domain_metadata::DomainMetadata;
 
 /// 应用层域名类型（包含 `account_id`）
@@ -20,12 +21,18 @@ pub struct AppDomain {
     pub provider: ProviderType,
     /// 域名状态
     pub status: DomainStatus,
+    /// Provider 返回的原始状态字符串
+    #[serde(rename = "providerStatusRaw", skip_serializing_if = "Option::is_none")]
+    pub provider_status_raw: Option<String>,
     /// DNS 记录数量
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
     /// 用户自定义元数据
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<DomainMetadata>,
+    /// 检查任务生成的派生状态（到期时间、证书等）
+    #[serde(rename = "derivedStatus", skip_serializing_if = "Option::is_none")]
+    pub derived_status: Option<DomainDerivedStatus>,
 }
 
 impl AppDomain {
@@ -38,8 +45,10 @@ impl AppDomain {
             account_id,
             provider: provider_domain.provider,
             status: provider_domain.status,
+            provider_status_raw: provider_domain.provider_status_raw,
             record_count: provider_domain.record_count,
             metadata: None,
+            derived_status: None,
         }
     }
 
@@ -0,0 +1,67 @@
+//! 域名派生状态相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 域名的派生状态
+///
+/// 由检查任务（域名到期检查、证书检查）完成后写入，可随时重新检查得到；
+/// 与用户维护的 [`DomainMetadata`](super::DomainMetadata) 分开保存。
+/// 每项数据带各自的检查时间，调用方据此判断是否已过时。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainDerivedStatus {
+    pub account_id: String,
+    pub domain_id: String,
+    /// 注册到期时间（WHOIS / RDAP）
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// 距注册到期的剩余天数（返回列表时按当前时间重算）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry_days_remaining: Option<i64>,
+    /// 最近一次成功查询到期时间的时间
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub expiry_checked_at: Option<DateTime<Utc>>,
+    /// 根域名与 `www` 子域名中较早到期的证书的到期时间
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub certificate_expires_at: Option<DateTime<Utc>>,
+    /// 距证书到期的剩余天数（返回列表时按当前时间重算）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_days_remaining: Option<i64>,
+    /// 最近一次成功检查证书的时间
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub certificate_checked_at: Option<DateTime<Utc>>,
+}
+
+impl DomainDerivedStatus {
+    /// 创建空的派生状态
+    #[must_use]
+    pub fn new(account_id: String, domain_id: String) -> Self {
+        Self {
+            account_id,
+            domain_id,
+            expires_at: None,
+            expiry_days_remaining: None,
+            expiry_checked_at: None,
+            certificate_expires_at: None,
+            certificate_days_remaining: None,
+            certificate_checked_at: None,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! 跨域名记录对比相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+use super::{DnsRecord, DnsRecordType};
+
+/// 同名同类型下值或 TTL 不同的一对记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordPair {
+    /// 相对名称（小写，根域为 `@`）
+    pub name: String,
+    pub record_type: DnsRecordType,
+    /// 域名 A 中的记录
+    pub a: DnsRecord,
+    /// 域名 B 中的记录
+    pub b: DnsRecord,
+}
+
+/// 两个域名的记录对比结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainDiffResult {
+    /// 仅存在于域名 A 的记录
+    pub only_in_a: Vec<DnsRecord>,
+    /// 仅存在于域名 B 的记录
+    pub only_in_b: Vec<DnsRecord>,
+    /// 同名同类型但值或 TTL 不同的记录
+    pub different: Vec<RecordPair>,
+}
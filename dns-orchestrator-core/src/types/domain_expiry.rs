@@ -0,0 +1,147 @@
+//! 域名到期监控相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use dns_orchestrator_provider::ProviderType;
+
+use super::SslCheckResult;
+
+/// 到期提醒档位（距到期天数）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryThresholds {
+    /// 严重：默认 7 天
+    pub critical_days: u32,
+    /// 警告：默认 30 天
+    pub warning_days: u32,
+    /// 提醒：默认 90 天
+    pub notice_days: u32,
+}
+
+impl Default for DomainExpiryThresholds {
+    fn default() -> Self {
+        Self {
+            critical_days: 7,
+            warning_days: 30,
+            notice_days: 90,
+        }
+    }
+}
+
+/// 域名到期状态，按严重程度从低到高排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DomainExpiryStatus {
+    /// 无法确定到期时间（查询失败或响应中没有到期日期）
+    Unknown,
+    /// 距到期超过提醒档位
+    Ok,
+    /// 进入提醒档位
+    Notice,
+    /// 进入警告档位
+    Warning,
+    /// 进入严重档位
+    Critical,
+    /// 已过期
+    Expired,
+}
+
+/// 单个域名的到期检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryEntry {
+    /// 域名（小写，不含末尾的点）
+    pub domain_name: String,
+    /// 托管该域名的账户，同一域名在多个账户下时全部列出
+    pub accounts: Vec<DomainExpiryAccount>,
+    #[serde(
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// 距到期剩余天数（向上取整，已过期时为 0 或负数）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_remaining: Option<i64>,
+    pub status: DomainExpiryStatus,
+    /// 无法确定到期时间的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(with = "crate::utils::datetime")]
+    pub checked_at: DateTime<Utc>,
+}
+
+/// 托管域名的账户
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryAccount {
+    pub account_id: String,
+    pub account_name: String,
+    pub provider: ProviderType,
+    pub domain_id: String,
+}
+
+/// 拉取域名列表失败的账户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryAccountError {
+    pub account_id: String,
+    pub account_name: String,
+    pub error: String,
+}
+
+/// 一次域名到期检查的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryReport {
+    #[serde(with = "crate::utils::datetime")]
+    pub checked_at: DateTime<Utc>,
+    pub thresholds: DomainExpiryThresholds,
+    /// 检查的域名数（去重后）
+    pub total_domains: usize,
+    /// 进入提醒档位或已过期的域名，按到期时间升序
+    pub expiring: Vec<DomainExpiryEntry>,
+    /// 无法确定到期时间的域名
+    pub unknown: Vec<DomainExpiryEntry>,
+    /// 相比上次检查进入了更严重档位的域名（首次检查时为所有进入档位的域名）
+    pub newly_flagged: Vec<DomainExpiryEntry>,
+    /// 拉取域名列表失败的账户，这些账户下的域名未被检查
+    pub failed_accounts: Vec<DomainExpiryAccountError>,
+}
+
+/// 即将到期的 SSL 证书
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateExpiryEntry {
+    /// 所属域名
+    pub domain_name: String,
+    /// 检查的主机名（根域名或 `www` 子域名）
+    pub host: String,
+    pub issuer: String,
+    /// 证书有效期截止（RFC 2822）
+    pub valid_to: String,
+    /// 剩余天数（已过期时为负数）
+    pub days_remaining: i64,
+    pub is_expired: bool,
+}
+
+/// 账户证书检查报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateReport {
+    pub account_id: String,
+    pub account_name: String,
+    #[serde(with = "crate::utils::datetime")]
+    pub checked_at: DateTime<Utc>,
+    /// 剩余天数少于该值的证书视为即将到期
+    pub warning_days: u32,
+    /// 检查的域名数
+    pub total_domains: usize,
+    /// 全部检查结果，每个域名依次为根域名与 `www` 子域名
+    pub results: Vec<SslCheckResult>,
+    /// 即将到期或已过期的证书，按剩余天数升序
+    pub expiring: Vec<CertificateExpiryEntry>,
+    /// 未取得证书的目标数（连接失败、仅 HTTP 或握手失败）
+    pub failed_count: usize,
+}
@@ -0,0 +1,141 @@
+//! 域名组（同一主域在多个账户/服务商下的组合视图）相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{CreateDnsRecordRequest, DnsRecordType, UpdateDnsRecordRequest};
+
+/// 域名组成员
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroupMember {
+    pub account_id: String,
+    pub domain_id: String,
+}
+
+/// 域名组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroup {
+    /// 组 ID (UUID)
+    pub id: String,
+    pub name: String,
+    pub members: Vec<DomainGroupMember>,
+    #[serde(with = "crate::utils::datetime")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 创建域名组请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDomainGroupRequest {
+    pub name: String,
+    pub members: Vec<DomainGroupMember>,
+}
+
+/// 更新域名组请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDomainGroupRequest {
+    pub id: String,
+    pub name: Option<String>,
+    pub members: Option<Vec<DomainGroupMember>>,
+}
+
+/// 某一成员在比较行中的值
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordComparisonCell {
+    /// 规范化后的记录值（已排序）
+    pub values: Vec<String>,
+    /// 各记录中最小的非自动 TTL（全部为自动 TTL 时为 `None`）
+    pub ttl: Option<u32>,
+}
+
+/// 按名称 + 类型对齐的一行
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordComparisonRow {
+    pub name: String,
+    pub record_type: DnsRecordType,
+    /// 与 `members` 顺序一致，成员没有该记录时为 `None`
+    pub cells: Vec<Option<RecordComparisonCell>>,
+    /// 各成员的值是否一致
+    pub consistent: bool,
+}
+
+/// 成员拉取状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroupMemberStatus {
+    pub member: DomainGroupMember,
+    /// 域名名称（获取失败时为空）
+    pub domain_name: String,
+    pub record_count: usize,
+    /// 拉取失败原因（失败的成员不参与一致性判断）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 域名组记录比较结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroupComparison {
+    pub group_id: String,
+    pub members: Vec<DomainGroupMemberStatus>,
+    pub rows: Vec<RecordComparisonRow>,
+    /// 不一致的行数
+    pub inconsistent_count: usize,
+}
+
+/// 单条记录变更
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum RecordChange {
+    Create {
+        request: CreateDnsRecordRequest,
+    },
+    #[serde(rename_all = "camelCase")]
+    Update {
+        record_id: String,
+        /// 变更前的值（展示用）
+        previous_value: String,
+        request: UpdateDnsRecordRequest,
+    },
+    #[serde(rename_all = "camelCase")]
+    Delete {
+        record_id: String,
+        name: String,
+        record_type: DnsRecordType,
+        value: String,
+    },
+}
+
+/// 同步到单个目标成员的变更集
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroupChangeset {
+    pub target: DomainGroupMember,
+    pub changes: Vec<RecordChange>,
+}
+
+/// 同步失败项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroupSyncFailure {
+    pub target: DomainGroupMember,
+    /// 变更描述（如 `create www A`）
+    pub change: String,
+    pub reason: String,
+}
+
+/// 同步结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainGroupSyncResult {
+    pub success_count: usize,
+    pub failed_count: usize,
+    pub failures: Vec<DomainGroupSyncFailure>,
+}
@@ -2,28 +2,47 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-use dns_orchestrator_provider::ProviderType;
+use dns_orchestrator_provider::{DnsRecord, DomainStatus, ProviderType};
+
+use super::{Account, AccountStatus, DomainMetadata, DomainMetadataKey, NetworkEnvInfo};
 
 /// 单个账号的导出数据（包含凭证）
+///
+/// 字段顺序即序列化顺序；凭证使用 `BTreeMap` 保证键有序，同样的数据总是得到同样的 JSON。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportedAccount {
-    /// 账户 ID
+    /// 账户 ID（可复现导出时为原账户 ID，否则为新生成的 ID）
     pub id: String,
     /// 账户名称
     pub name: String,
     /// DNS 服务商类型
     pub provider: ProviderType,
-    /// 创建时间
-    #[serde(with = "crate::utils::datetime")]
-    pub created_at: DateTime<Utc>,
-    /// 更新时间
-    #[serde(with = "crate::utils::datetime")]
-    pub updated_at: DateTime<Utc>,
+    /// 创建时间（可复现导出时省略）
+    #[serde(
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<DateTime<Utc>>,
+    /// 更新时间（可复现导出时省略）
+    #[serde(
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<DateTime<Utc>>,
     /// 凭证数据
-    pub credentials: HashMap<String, String>,
+    pub credentials: BTreeMap<String, String>,
+    /// 凭证到期时间
+    #[serde(
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
 }
 
 /// 导出文件头部（明文部分）
@@ -40,8 +59,9 @@ pub struct ExportFileHeader {
     /// 加密时使用的 IV/Nonce（Base64 编码）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<String>,
-    /// 导出时间
-    pub exported_at: String,
+    /// 导出时间（可复现导出时省略）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exported_at: Option<String>,
     /// 应用版本
     pub app_version: String,
 }
@@ -67,6 +87,10 @@ pub struct ExportAccountsRequest {
     /// 加密密码（仅当 encrypt=true 时需要）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    /// 可复现导出：保留原账户 ID、省略所有时间戳，同样的数据导出结果字节级一致
+    /// （加密时解密后的明文一致，salt/nonce 仍随机）
+    #[serde(default)]
+    pub reproducible: bool,
 }
 
 /// 导出响应
@@ -121,6 +145,9 @@ pub struct ImportPreviewAccount {
 pub struct ImportResult {
     /// 成功导入的账号数量
     pub success_count: usize,
+    /// 与已有账户重复（服务商与凭证相同）而跳过的账号数量
+    #[serde(default)]
+    pub skipped_count: usize,
     /// 失败的账号及原因
     pub failures: Vec<ImportFailure>,
 }
@@ -134,3 +161,146 @@ pub struct ImportFailure {
     /// 失败原因
     pub reason: String,
 }
+
+/// 完整备份中的账户（保留原账户 ID 与时间戳，含凭证）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupAccount {
+    /// 账户元数据
+    #[serde(flatten)]
+    pub account: Account,
+    /// 凭证数据
+    pub credentials: BTreeMap<String, String>,
+}
+
+/// 完整备份中的域名元数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupDomainMetadata {
+    /// 元数据键
+    #[serde(flatten)]
+    pub key: DomainMetadataKey,
+    /// 元数据
+    pub metadata: DomainMetadata,
+}
+
+/// 完整备份的明文内容（整体加密后写入导出文件的 `data` 字段）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupPayload {
+    /// 备份内容格式版本，恢复时校验
+    pub backup_version: u32,
+    /// 备份时间
+    #[serde(with = "crate::utils::datetime")]
+    pub created_at: DateTime<Utc>,
+    /// 全部账户
+    pub accounts: Vec<BackupAccount>,
+    /// 全部域名元数据
+    pub domain_metadata: Vec<BackupDomainMetadata>,
+}
+
+/// 备份恢复结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBackupResult {
+    /// 新建的账户数量
+    pub created_accounts: usize,
+    /// 按 ID 覆盖更新的已有账户数量
+    pub updated_accounts: usize,
+    /// 写入的域名元数据条数
+    pub restored_metadata: usize,
+    /// 恢复失败的账户及原因（其元数据同样跳过）
+    pub failures: Vec<ImportFailure>,
+}
+
+/// 诊断数据导出请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDiagnosticsRequest {
+    /// 要导出的账号 ID 列表
+    pub account_ids: Vec<String>,
+    /// 是否匿名化（域名、IP、TXT 值、账户名与 ID 替换为假名）
+    #[serde(default)]
+    pub anonymize: bool,
+    /// 匿名化映射表的加密密码（匿名化且提供密码时才生成映射表）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mapping_password: Option<String>,
+}
+
+/// 诊断数据导出响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDiagnosticsResponse {
+    /// 诊断报告（JSON）
+    pub content: String,
+    /// 建议的文件名
+    pub suggested_filename: String,
+    /// 加密的匿名化映射表（与账户导出文件结构相同），由用户在本地单独保存
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping: Option<String>,
+    /// 映射表建议的文件名
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping_filename: Option<String>,
+}
+
+/// 诊断报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsReport {
+    /// 应用版本
+    pub app_version: String,
+    /// 生成时间
+    #[serde(with = "crate::utils::datetime")]
+    pub generated_at: DateTime<Utc>,
+    /// 是否已匿名化
+    pub anonymized: bool,
+    /// 账户
+    pub accounts: Vec<DiagnosticsAccount>,
+    /// 本机网络环境（宿主未提供时缺省）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_env: Option<NetworkEnvInfo>,
+}
+
+/// 诊断报告中的账户（不含凭证）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsAccount {
+    /// 账户 ID
+    pub id: String,
+    /// 账户名称
+    pub name: String,
+    /// DNS 服务商类型
+    pub provider: ProviderType,
+    /// 账户状态
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AccountStatus>,
+    /// 账户上保存的错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 收集域名列表时的错误（按 `{ code, details }` 序列化）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect_error: Option<serde_json::Value>,
+    /// 域名
+    pub domains: Vec<DiagnosticsDomain>,
+}
+
+/// 诊断报告中的域名
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsDomain {
+    /// 域名 ID
+    pub id: String,
+    /// 域名
+    pub name: String,
+    /// 域名状态
+    pub status: DomainStatus,
+    /// 记录数量
+    pub record_count: usize,
+    /// 各记录类型的数量
+    pub record_types: BTreeMap<String, usize>,
+    /// 记录
+    pub records: Vec<DnsRecord>,
+    /// 收集记录时的错误（按 `{ code, details }` 序列化）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect_error: Option<serde_json::Value>,
+}
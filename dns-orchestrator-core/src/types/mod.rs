@@ -1,33 +1,136 @@
 //! 类型定义模块
 
 mod account;
+mod account_capabilities;
+mod account_validation;
+mod api_usage;
+mod audit_log;
+mod background_job;
+mod change_window;
+mod changelog;
+mod credential_expiry;
+mod dns_resolver;
 mod domain;
+mod domain_derived_status;
+mod domain_diff;
+mod domain_expiry;
+mod domain_group;
 mod domain_metadata;
 mod export;
+mod provider_health;
+mod record_copy;
+mod record_import;
+mod record_list;
+mod record_set;
+mod record_validation;
 mod response;
+mod temporary_record;
 mod toolbox;
+mod weighted_rollout;
+mod write_queue;
 
-pub use account::{Account, AccountStatus, CreateAccountRequest, UpdateAccountRequest};
+pub use account::{
+    Account, AccountDeletionImpact, AccountDeletionResult, AccountStatus, CreateAccountRequest,
+    CredentialStatus, DeletionAction, DeletionCleanupItem, DeletionImpactItem, DeletionImpactKind,
+    UpdateAccountRequest,
+};
+pub use account_capabilities::{AccountCapabilities, AccountCapabilityCache, CapabilitySource};
+pub use account_validation::{AccountValidation, AccountValidationSummary};
+pub use api_usage::{
+    ApiOperation, ApiQuotaRule, ApiQuotaWindow, ApiUsageCount, ApiUsageDay, ApiUsageOperationTotal,
+    ApiUsageReport, ApiUsageRisk,
+};
+pub use audit_log::{
+    AuditEntry, AuditLogFilter, AuditOperation, ChangeSource, RecordChangeEntry, RecordChangeType,
+};
+pub use background_job::{
+    BackgroundJobInfo, JobConcurrency, JobRunRecord, JobRunStatus, JobSchedule,
+};
+pub use change_window::{
+    ChangeWindowOverride, ChangeWindowRule, ChangeWindowSchedule, ClosedPeriod,
+    CreateChangeWindowRuleRequest, UpdateChangeWindowRuleRequest,
+};
+pub use changelog::{
+    Changelog, ChangelogDomain, ChangelogEntry, ChangelogFormat, ChangelogOutput, ChangelogRequest,
+    ChangelogSourceFilter,
+};
+pub use credential_expiry::{CredentialExpiryCheck, ExpiringCredential};
+pub use dns_resolver::{
+    DnsAnswer, DnsAnswerRecord, DnsQuery, DnsQueryError, DnsQueryErrorKind, DnsQueryOptions,
+    DnsQueryType, DnsRecordData, DnsServer, DnsTransport,
+};
 pub use domain::AppDomain;
+pub use domain_derived_status::DomainDerivedStatus;
+pub use domain_diff::{DomainDiffResult, RecordPair};
+pub use domain_expiry::{
+    CertificateExpiryEntry, CertificateReport, DomainExpiryAccount, DomainExpiryAccountError,
+    DomainExpiryEntry, DomainExpiryReport, DomainExpiryStatus, DomainExpiryThresholds,
+};
+pub use domain_group::{
+    CreateDomainGroupRequest, DomainGroup, DomainGroupChangeset, DomainGroupComparison,
+    DomainGroupMember, DomainGroupMemberStatus, DomainGroupSyncFailure, DomainGroupSyncResult,
+    RecordChange, RecordComparisonCell, RecordComparisonRow, UpdateDomainGroupRequest,
+};
 pub use domain_metadata::{
     BatchTagFailure, BatchTagRequest, BatchTagResult, DomainMetadata, DomainMetadataKey,
     DomainMetadataUpdate,
 };
 pub use export::{
-    ExportAccountsRequest, ExportAccountsResponse, ExportFile, ExportFileHeader, ExportedAccount,
+    BackupAccount, BackupDomainMetadata, BackupPayload, DiagnosticsAccount, DiagnosticsDomain,
+    DiagnosticsReport, ExportAccountsRequest, ExportAccountsResponse, ExportDiagnosticsRequest,
+    ExportDiagnosticsResponse, ExportFile, ExportFileHeader, ExportedAccount,
     ImportAccountsRequest, ImportFailure, ImportPreview, ImportPreviewAccount, ImportResult,
+    RestoreBackupResult,
+};
+pub use provider_health::{HealthCheckFailure, ProviderHealthStatus};
+pub use record_copy::{RecordCopyItem, RecordCopyOptions, RecordCopyRequest, RecordCopyResult};
+pub use record_import::{
+    RecordConflictStrategy, RecordImportFailure, RecordImportFormat, RecordImportPreview,
+    RecordImportPreviewItem, RecordImportRequest, RecordImportResult, ZoneFileImportResult,
+    ZoneFileSkippedEntry,
+};
+pub use record_list::RecordListProgress;
+pub use record_set::{
+    RecordSetChangeFailure, RecordSetDetail, UpdateRecordSetRequest, UpdateRecordSetResult,
+};
+pub use record_validation::{
+    RecordCharacterWarning, RecordValidationError, RecordValidationReport, RecordValidationRule,
+    RecordValidationWarning, UnsafeCharKind, UnsafeCharacter,
+};
+pub use response::{
+    ApiResponse, BatchCreateRequest, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult,
+    BatchUpdateRequest, ErrorSuggestion, ReplaceRecordValueRequest,
+};
+pub use temporary_record::{
+    TemporaryRecord, TemporaryRecordStatus, TemporaryRecordTask, TemporaryRecordTaskKind,
 };
-pub use response::{ApiResponse, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult};
 pub use toolbox::{
-    CertChainItem, DnsLookupRecord, DnsLookupResult, DnsPropagationResult, DnsPropagationServer,
-    DnsPropagationServerResult, DnskeyRecord, DnssecResult, DsRecord, HttpHeader,
-    HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpMethod, IpGeoInfo, IpLookupResult,
-    RrsigRecord, SecurityHeaderAnalysis, SslCertInfo, SslCheckResult, WhoisResult,
+    CaaCheckResult, CertChainItem, DkimRecord, DmarcRecord, DnsBenchmarkProgress,
+    DnsBenchmarkResult, DnsBenchmarkServerResult, DnsHijackCheck, DnsLookupRecord,
+    DnsLookupRecordData, DnsLookupResult, DnsLookupTransport, DnsPropagationResult,
+    DnsPropagationServer, DnsPropagationServerResult, DnskeyRecord, DnssecResult, DsRecord,
+    EmailSecurityResult, ExternalEndpoint, ExternalEndpointStatus, HttpHeader,
+    HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpHop, HttpMethod, HttpTraceResult, IpGeoInfo,
+    IpLookupBatchEntry, IpLookupResult, IpThreatInfo, LatencyStats, MailCheckResult,
+    MailPolicyCheck, MxHostCheck, NetworkEnvInfo, OcspStatus, ParsedCaaRecord, PortCheckResult,
+    PortProtocol, PortState, PortStatus, PrivacyConfig, PropagationCheckResult,
+    PropagationResolverResult, ProxySettings, PtrRecord, ReverseLookupResult, RrsigRecord,
+    SecurityHeaderAnalysis, SecurityScore, SecuritySeverity, SmtpProbe, SpfInclude, SpfMechanism,
+    SpfQualifier, SpfRecord, SslCertInfo, SslCheckResult, SslCheckTarget, ThreatIntelConfig,
+    TlsProtocolInfo, TlsVersionSupport, TraceHop, TraceMethod, TraceRouteResult, WhoisResult,
+};
+pub use weighted_rollout::{
+    CreateWeightedRolloutRequest, RolloutStage, RolloutStatus, RolloutVerification, WeightedRollout,
+};
+pub use write_queue::{
+    QueuedWrite, QueuedWriteOperation, WriteQueueConfig, WriteReplayOutcome, WriteReplayStatus,
 };
 
 // Re-export provider 库的公共类型
 pub use dns_orchestrator_provider::{
-    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, PaginatedResponse,
-    PaginationParams, ProviderCredentials, ProviderDomain, ProviderMetadata, ProviderType,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    BatchCreateFailure, BatchCreateResult, BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult,
+    CapabilityProbe, CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus,
+    PaginatedResponse, PaginationParams, ProviderCredentials, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordData, RecordQueryParams, RecordSet,
+    RecordSort, RecordSortField, SortOrder, UpdateDnsRecordRequest,
 };
@@ -0,0 +1,41 @@
+//! Provider 连通性检查相关类型定义
+
+use chrono::{DateTime, Utc};
+use dns_orchestrator_provider::ProviderType;
+use serde::{Deserialize, Serialize};
+
+/// 账户 Provider 的连通性检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderHealthStatus {
+    pub account_id: String,
+    pub provider_type: ProviderType,
+    /// 服务商 API 是否在超时内响应（凭证或权限被拒绝也算可达）
+    pub reachable: bool,
+    /// 请求耗时（毫秒，超时时为超时时长）
+    pub latency_ms: u64,
+    /// 失败原因（凭证失效、网络错误、超时等）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 失败类别，检查成功时为空
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<HealthCheckFailure>,
+    #[serde(with = "crate::utils::datetime")]
+    pub checked_at: DateTime<Utc>,
+}
+
+/// 连通性检查失败的类别，区分网络问题与凭证、权限问题
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthCheckFailure {
+    /// 请求未能到达服务商
+    Network,
+    /// 超时未响应
+    Timeout,
+    /// 服务商拒绝了凭证
+    Credentials,
+    /// 凭证有效但无权访问
+    Permission,
+    /// 服务商返回了其他错误
+    Provider,
+}
@@ -0,0 +1,74 @@
+//! 跨账户记录复制相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+use super::{DnsRecordType, RecordChange, RecordConflictStrategy};
+
+/// 记录复制选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordCopyOptions {
+    /// 跳过子域 NS 记录（顶级 NS 与 SOA 由服务商托管，总是跳过）
+    #[serde(default)]
+    pub skip_ns: bool,
+    /// 只复制指定类型的记录（为空表示全部）
+    #[serde(default)]
+    pub record_type: Option<DnsRecordType>,
+    /// 与目标现有记录冲突（同名同类型、值不同）时的处理策略
+    #[serde(default)]
+    pub conflict_strategy: RecordConflictStrategy,
+    /// 只生成复制计划，不调用目标服务商写接口
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 记录复制请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordCopyRequest {
+    pub source_account_id: String,
+    pub source_domain_id: String,
+    pub target_account_id: String,
+    pub target_domain_id: String,
+    #[serde(default)]
+    pub options: RecordCopyOptions,
+}
+
+/// 单条源记录的复制计划及执行结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordCopyItem {
+    /// 源记录 ID
+    pub source_record_id: String,
+    /// 相对名称（根域为 `@`）
+    pub name: String,
+    pub record_type: DnsRecordType,
+    /// 源记录值（展示用）
+    pub value: String,
+    /// 计划对目标执行的变更，`None` 表示跳过
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub change: Option<RecordChange>,
+    /// 跳过原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+    /// 为适配目标服务商所做的调整（TTL 范围、代理等）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub adjustments: Vec<String>,
+    /// 执行失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 记录复制结果
+///
+/// `dry_run` 时只返回计划，各计数中只有 `skipped_count` 有效。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordCopyResult {
+    pub dry_run: bool,
+    pub items: Vec<RecordCopyItem>,
+    pub created_count: usize,
+    pub updated_count: usize,
+    pub skipped_count: usize,
+    pub failed_count: usize,
+}
@@ -0,0 +1,119 @@
+//! 记录导入相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+use super::CreateDnsRecordRequest;
+
+/// 记录定义文件格式
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordImportFormat {
+    /// octoDNS zone YAML
+    #[serde(rename = "octodns")]
+    OctoDns,
+    /// dnscontrol JSON IR（`dnscontrol print-ir` 的输出）
+    #[serde(rename = "dnscontrol")]
+    DnsControl,
+}
+
+/// 与现有记录冲突（同名同类型、值不同）时的处理策略
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordConflictStrategy {
+    /// 跳过冲突记录
+    #[default]
+    Skip,
+    /// 用导入的值覆盖现有记录
+    Overwrite,
+    /// 保留现有记录，额外创建
+    Append,
+}
+
+/// 解析出的单条记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportPreviewItem {
+    /// 将要创建的记录
+    pub request: CreateDnsRecordRequest,
+    /// 与现有记录完全相同（总是跳过）
+    pub duplicate: bool,
+    /// 与现有记录同名同类型但值不同
+    pub has_conflict: bool,
+}
+
+/// 记录导入预览
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportPreview {
+    pub items: Vec<RecordImportPreviewItem>,
+    /// 不支持的记录类型或修饰（已忽略）
+    pub warnings: Vec<String>,
+}
+
+/// 记录导入请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportRequest {
+    pub account_id: String,
+    pub domain_id: String,
+    pub format: RecordImportFormat,
+    /// 文件内容
+    pub content: String,
+    #[serde(default)]
+    pub conflict_strategy: RecordConflictStrategy,
+    /// 把不可见字符与同形字符替换为 ASCII（默认含此类字符的记录导入失败）
+    #[serde(default)]
+    pub normalize: bool,
+}
+
+/// 导入失败项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportFailure {
+    /// 记录名称
+    pub name: String,
+    /// 记录类型
+    pub record_type: String,
+    /// 失败原因
+    pub reason: String,
+}
+
+/// 记录导入结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordImportResult {
+    pub created_count: usize,
+    pub updated_count: usize,
+    pub skipped_count: usize,
+    pub failures: Vec<RecordImportFailure>,
+    pub warnings: Vec<String>,
+}
+
+/// zone 文件中被跳过的条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneFileSkippedEntry {
+    /// 行号（从 1 开始，多行记录为起始行）
+    pub line: usize,
+    /// 原始内容（已去除注释）
+    pub content: String,
+    /// 跳过原因
+    pub reason: String,
+}
+
+/// zone 文件导入结果
+///
+/// `dry_run` 时只返回解析预览，`success_count` / `failures` 为空。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneFileImportResult {
+    pub dry_run: bool,
+    /// 将要创建（或已尝试创建）的记录
+    pub records: Vec<CreateDnsRecordRequest>,
+    pub skipped: Vec<ZoneFileSkippedEntry>,
+    pub success_count: usize,
+    pub failed_count: usize,
+    pub failures: Vec<RecordImportFailure>,
+    /// 规范化可疑字符时所做的替换（未规范化时含可疑字符的记录计入 `skipped`）
+    pub warnings: Vec<String>,
+}
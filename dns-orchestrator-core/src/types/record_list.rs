@@ -0,0 +1,17 @@
+//! 记录全量拉取相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+/// 全量拉取记录时每拉取一页上报的进度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordListProgress {
+    pub account_id: String,
+    pub domain_id: String,
+    /// 刚拉取完成的页码（从 1 开始）
+    pub page: u32,
+    /// 已拉取的记录数
+    pub fetched: usize,
+    /// 服务商返回的记录总数（未知时为 0）
+    pub total: u32,
+}
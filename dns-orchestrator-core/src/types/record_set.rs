@@ -0,0 +1,56 @@
+//! 记录组（同名同类型的多值记录）相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+use super::{DnsRecord, DnsRecordType, RecordChange, RecordData};
+
+/// 记录组详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSetDetail {
+    pub domain_id: String,
+    /// 相对名称（根域为 `@`）
+    pub name: String,
+    pub record_type: DnsRecordType,
+    /// 组内各值（原生记录组的各值共用整组的 ID）
+    pub records: Vec<DnsRecord>,
+    /// 共同 TTL（组为空或 TTL 不一致时为空）
+    pub ttl: Option<u32>,
+    /// 组内 TTL 是否不一致
+    pub ttl_mismatch: bool,
+    /// 服务商是否原生使用 record set 模型（整组一次替换）
+    pub native: bool,
+}
+
+/// 整组更新请求：`values` 为期望的值集合，为空时删除整组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRecordSetRequest {
+    pub domain_id: String,
+    pub name: String,
+    pub record_type: DnsRecordType,
+    /// 整组统一的 TTL
+    pub ttl: u32,
+    pub values: Vec<RecordData>,
+}
+
+/// 执行失败的单条变更
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSetChangeFailure {
+    pub change: RecordChange,
+    pub reason: String,
+}
+
+/// 整组更新结果
+///
+/// 逐条执行时遇到第一个失败即停止：`applied` 为已生效的变更，`failure` 为失败的那一条，
+/// `skipped` 为未执行的变更。原生记录组一次调用完成，失败时直接返回错误。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRecordSetResult {
+    pub native: bool,
+    pub applied: Vec<RecordChange>,
+    pub failure: Option<RecordSetChangeFailure>,
+    pub skipped: Vec<RecordChange>,
+}
@@ -0,0 +1,144 @@
+//! 记录校验相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+/// 校验规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordValidationRule {
+    /// TTL 取值
+    Ttl,
+    /// A/AAAA 地址格式
+    IpAddress,
+    /// TXT 字符串分段长度
+    TxtLength,
+    /// MX 优先级与目标
+    Mx,
+    /// CNAME 与同名记录共存
+    CnameConflict,
+    /// SRV 名称格式（`_service._proto.name`）
+    SrvName,
+    /// 目标主机名
+    Target,
+    /// SOA 计时参数与服务商维护的字段
+    Soa,
+    /// 不可见字符、全角字符或与 ASCII 同形的字母
+    UnsafeCharacter,
+    /// 记录名称（国际化标签须能转为 punycode）
+    Name,
+    /// CAA 标签与取值
+    Caa,
+}
+
+/// 可疑字符类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnsafeCharKind {
+    /// 零宽字符、BOM、方向控制符等不可见字符
+    Invisible,
+    /// 全角 ASCII 字符（`ａ`、`．`、`１` 等）
+    Fullwidth,
+    /// 非 ASCII 的标点与空白（`。`、不换行空格、弯引号等）
+    Punctuation,
+    /// 与 ASCII 字母同形的西里尔/希腊字母
+    Homoglyph,
+}
+
+impl UnsafeCharKind {
+    /// 展示名称
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Invisible => "不可见字符",
+            Self::Fullwidth => "全角字符",
+            Self::Punctuation => "非 ASCII 标点",
+            Self::Homoglyph => "与 ASCII 同形的字母",
+        }
+    }
+}
+
+/// 记录中的单个可疑字符
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsafeCharacter {
+    /// 所在字段（`name` 或记录内容中的字段名，如 `target`）
+    pub field: String,
+    /// 字符位置（从 1 开始，按 Unicode 字符计数）
+    pub position: usize,
+    /// 码点（如 `U+200B`）
+    pub codepoint: String,
+    pub character: char,
+    pub kind: UnsafeCharKind,
+    /// 对应的 ASCII 替换，空字符串表示直接删除
+    pub replacement: String,
+}
+
+impl UnsafeCharacter {
+    /// 位置描述，如 `target 第 12 个字符 U+3002（非 ASCII 标点）`
+    pub fn describe(&self) -> String {
+        format!(
+            "{} 第 {} 个字符 {}（{}）",
+            self.field,
+            self.position,
+            self.codepoint,
+            self.kind.label()
+        )
+    }
+
+    /// 规范化时所做替换的说明
+    pub fn replacement_note(&self) -> String {
+        if self.replacement.is_empty() {
+            format!("已删除 {}", self.describe())
+        } else {
+            format!("已将 {} 替换为 \"{}\"", self.describe(), self.replacement)
+        }
+    }
+}
+
+/// 现有记录中的可疑字符（扫描结果）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordCharacterWarning {
+    pub record_id: String,
+    pub name: String,
+    pub record_type: String,
+    pub characters: Vec<UnsafeCharacter>,
+}
+
+/// 校验警告（不阻止写入）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordValidationWarning {
+    pub rule: RecordValidationRule,
+    pub message: String,
+    /// 建议的 TTL（仅 TTL 相关警告）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_ttl: Option<u32>,
+}
+
+/// 校验错误（拒绝写入）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordValidationError {
+    pub rule: RecordValidationRule,
+    /// 出错的字段（`name`、`ttl`、`type` 或记录内容中的字段名，如 `address`），供界面定位输入框
+    pub field: String,
+    pub message: String,
+    /// 触发错误的字符（仅可疑字符错误）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character: Option<UnsafeCharacter>,
+}
+
+/// 记录校验结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordValidationReport {
+    pub warnings: Vec<RecordValidationWarning>,
+    pub errors: Vec<RecordValidationError>,
+}
+
+impl RecordValidationReport {
+    /// 是否存在阻止写入的错误
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
@@ -2,13 +2,21 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::{BatchUpdateItem, CreateDnsRecordRequest, DnsRecordType};
+
 /// API 响应包装类型
+///
+/// Tauri 命令与 actix-web handler 共用。失败不经过此类型：
+/// Tauri 端返回 `Err`，actix-web 端返回带 `error` 字段的错误响应。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
     /// 是否成功
     pub success: bool,
     /// 响应数据
     pub data: Option<T>,
+    /// 操作成功但需要提示用户的警告
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 impl<T> ApiResponse<T> {
@@ -18,8 +26,77 @@ impl<T> ApiResponse<T> {
         Self {
             success: true,
             data: Some(data),
+            warnings: Vec::new(),
         }
     }
+
+    /// 创建部分成功响应（如批量操作部分条目失败），结果与警告同时返回
+    #[must_use]
+    pub fn partial(data: T, warnings: impl IntoIterator<Item = String>) -> Self {
+        Self::success(data).with_warnings(warnings)
+    }
+
+    /// 附加警告
+    #[must_use]
+    pub fn with_warnings(mut self, warnings: impl IntoIterator<Item = String>) -> Self {
+        self.warnings.extend(warnings);
+        self
+    }
+}
+
+/// 错误的修复建议
+///
+/// 文案由前端按 i18n 键渲染，见 [`crate::utils::error_suggestions`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorSuggestion {
+    /// 操作标题的 i18n 键
+    pub action_key: String,
+    /// 说明文案的 i18n 键
+    pub description_key: String,
+    /// 相关文档或控制台链接
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+}
+
+/// 批量创建 DNS 记录请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateRequest {
+    /// 域名 ID（覆盖各条记录中的 `domain_id`）
+    pub domain_id: String,
+    /// 待创建的记录
+    pub records: Vec<CreateDnsRecordRequest>,
+    /// 把不可见字符与同形字符替换为 ASCII（默认含此类字符的记录直接失败）
+    #[serde(default)]
+    pub normalize: bool,
+}
+
+/// 批量更新 DNS 记录请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdateRequest {
+    /// 域名 ID（覆盖各条更新中的 `domain_id`）
+    pub domain_id: String,
+    /// 待更新的记录
+    pub updates: Vec<BatchUpdateItem>,
+    /// 把不可见字符与同形字符替换为 ASCII（默认含此类字符的记录直接失败）
+    #[serde(default)]
+    pub normalize: bool,
+}
+
+/// 按值批量替换记录请求（如服务器换 IP 后更新所有指向旧地址的记录）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceRecordValueRequest {
+    /// 域名 ID
+    pub domain_id: String,
+    /// 旧值（IP 按地址比较，主机名忽略大小写和末尾的点）
+    pub old_value: String,
+    /// 新值
+    pub new_value: String,
+    /// 仅替换该类型的记录
+    pub record_type: Option<DnsRecordType>,
 }
 
 /// 批量删除 DNS 记录请求
@@ -52,4 +129,7 @@ pub struct BatchDeleteFailure {
     pub record_id: String,
     /// 失败原因
     pub reason: String,
+    /// 放弃前对服务商的尝试次数（含重试，不涉及服务商调用的失败为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
 }
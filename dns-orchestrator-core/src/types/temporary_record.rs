@@ -0,0 +1,109 @@
+//! 临时记录相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::CreateDnsRecordRequest;
+
+/// 临时记录状态
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TemporaryRecordStatus {
+    /// 待生效：等待到点创建
+    Pending,
+    /// 生效中：记录已创建，等待到点删除
+    Active,
+    /// 已清理：记录已删除（或已被手工删除）
+    Cleaned,
+    /// 异常：创建失败、错过生效时间段，或记录已被修改而未删除
+    Failed,
+}
+
+/// 临时记录关联的计划任务类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum TemporaryRecordTaskKind {
+    /// 到点创建记录
+    Create,
+    /// 到点删除记录
+    Delete,
+}
+
+/// 临时记录关联的计划任务
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemporaryRecordTask {
+    pub kind: TemporaryRecordTaskKind,
+    /// 计划执行时间
+    #[serde(with = "crate::utils::datetime")]
+    pub run_at: DateTime<Utc>,
+    /// 实际执行完成的时间（未执行时为 `None`）
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub executed_at: Option<DateTime<Utc>>,
+    /// 上次执行失败的原因（网络错误等可重试的失败会在下次检查时重试）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl TemporaryRecordTask {
+    /// 创建尚未执行的计划任务
+    #[must_use]
+    pub fn new(kind: TemporaryRecordTaskKind, run_at: DateTime<Utc>) -> Self {
+        Self {
+            kind,
+            run_at,
+            executed_at: None,
+            last_error: None,
+        }
+    }
+}
+
+/// 临时记录：在生效时间段内存在的记录
+///
+/// 由两个关联的计划任务组成：`active_from` 创建记录，`active_until` 删除记录。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemporaryRecord {
+    /// 临时记录 ID (UUID)
+    pub id: String,
+    pub account_id: String,
+    pub domain_id: String,
+    /// 到点创建时使用的请求
+    pub request: CreateDnsRecordRequest,
+    pub status: TemporaryRecordStatus,
+    /// 到点创建任务（`run_at` 即生效时间）
+    pub create_task: TemporaryRecordTask,
+    /// 到点删除任务（`run_at` 即失效时间）
+    pub delete_task: TemporaryRecordTask,
+    /// 创建出的记录 ID（尚未创建时为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_id: Option<String>,
+    /// 创建时记录内容的指纹，删除前用于确认记录未被修改
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// 异常原因
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(with = "crate::utils::datetime")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TemporaryRecord {
+    /// 生效时间
+    #[must_use]
+    pub const fn active_from(&self) -> DateTime<Utc> {
+        self.create_task.run_at
+    }
+
+    /// 失效时间
+    #[must_use]
+    pub const fn active_until(&self) -> DateTime<Utc> {
+        self.delete_task.run_at
+    }
+}
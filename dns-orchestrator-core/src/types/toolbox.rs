@@ -1,5 +1,6 @@
 //! 工具箱相关类型定义
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// WHOIS 查询结果
@@ -8,8 +9,23 @@ use serde::{Deserialize, Serialize};
 pub struct WhoisResult {
     /// 域名
     pub domain: String,
+    /// 国际化域名的 Unicode 形式（`domain` 为 punycode）
+    #[serde(default)]
+    pub unicode_domain: Option<String>,
     /// 注册商
     pub registrar: Option<String>,
+    /// 注册人组织
+    #[serde(default)]
+    pub registrant_org: Option<String>,
+    /// 注册人国家或地区
+    #[serde(default)]
+    pub registrant_country: Option<String>,
+    /// 注册商滥用投诉邮箱
+    #[serde(default)]
+    pub registrar_abuse_email: Option<String>,
+    /// 注册商滥用投诉电话
+    #[serde(default)]
+    pub registrar_abuse_phone: Option<String>,
     /// 创建日期
     pub creation_date: Option<String>,
     /// 过期日期
@@ -20,8 +36,19 @@ pub struct WhoisResult {
     pub name_servers: Vec<String>,
     /// 状态
     pub status: Vec<String>,
-    /// 原始响应
+    /// DNSSEC 状态（原样保留注册局的写法，如 `unsigned`、`signedDelegation`）
+    #[serde(default)]
+    pub dnssec: Option<String>,
+    /// 实际查询过的服务器（WHOIS 按转介顺序；RDAP 为服务端点的主机名）
+    #[serde(default)]
+    pub queried_servers: Vec<String>,
+    /// 原始响应（跟随转介时各服务器的响应依次拼接，以分隔行隔开）
     pub raw: String,
+    /// 是否来自查询缓存
+    #[serde(default)]
+    pub cached: bool,
+    /// 实际向服务器查询的时间（命中缓存时为缓存写入时的查询时间）
+    pub fetched_at: DateTime<Utc>,
 }
 
 /// DNS 查询记录结果
@@ -38,18 +65,116 @@ pub struct DnsLookupRecord {
     pub ttl: u32,
     /// 优先级（MX/SRV 记录）
     pub priority: Option<u16>,
+    /// 结构化数据（CAA/SRV/SOA/NAPTR 记录），其余类型只有文本形式的 `value`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_data: Option<DnsLookupRecordData>,
+}
+
+/// DNS 查询记录的结构化数据
+///
+/// 域名类字段不含末尾的点。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content", rename_all = "UPPERCASE")]
+pub enum DnsLookupRecordData {
+    Caa {
+        /// 标志位（128 为关键标志）
+        flags: u8,
+        tag: String,
+        value: String,
+    },
+    Srv {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: String,
+    },
+    Soa {
+        mname: String,
+        rname: String,
+        serial: u32,
+        refresh: i32,
+        retry: i32,
+        expire: i32,
+        minimum: u32,
+    },
+    Naptr {
+        order: u16,
+        preference: u16,
+        flags: String,
+        services: String,
+        regexp: String,
+        replacement: String,
+    },
+}
+
+/// DNS 查询使用的传输协议
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DnsLookupTransport {
+    /// 明文 UDP（默认，应答截断时由解析器改走 TCP）
+    #[default]
+    Udp,
+    /// 明文 TCP
+    Tcp,
+    /// DNS over HTTPS，nameserver 为 HTTPS URL
+    Doh,
+    /// DNS over TLS，nameserver 为 `host[:port][#TLS 服务器名称]`
+    Dot,
+}
+
+impl DnsLookupTransport {
+    /// 展示名称
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Udp => "UDP",
+            Self::Tcp => "TCP",
+            Self::Doh => "DoH",
+            Self::Dot => "DoT",
+        }
+    }
 }
 
 /// DNS 查询结果（包含 nameserver 信息）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DnsLookupResult {
+    /// 查询的域名（punycode 形式）
+    pub domain: String,
+    /// 国际化域名的 Unicode 形式（`domain` 为 punycode）
+    #[serde(default)]
+    pub unicode_domain: Option<String>,
     /// 使用的 DNS 服务器
     pub nameserver: String,
+    /// 实际使用的传输协议
+    pub transport: DnsLookupTransport,
     /// 查询记录列表
     pub records: Vec<DnsLookupRecord>,
 }
 
+/// PTR 记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PtrRecord {
+    /// 指向的主机名（不含末尾的点）
+    pub hostname: String,
+    /// TTL
+    pub ttl: u32,
+}
+
+/// 反向 DNS（PTR）查询结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseLookupResult {
+    /// 查询的 IP 地址（规范化后）
+    pub ip: String,
+    /// 反向解析域名（`in-addr.arpa` / `ip6.arpa`）
+    pub reverse_name: String,
+    /// 查询到的 PTR 记录
+    pub ptr_records: Vec<PtrRecord>,
+    /// 使用的 DNS 服务器
+    pub nameserver: String,
+}
+
 /// IP 地理位置信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -80,23 +205,143 @@ pub struct IpGeoInfo {
     pub asn: Option<String>,
     /// AS 名称
     pub as_name: Option<String>,
+    /// 威胁情报标注（未配置情报源时不出现）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threat: Option<IpThreatInfo>,
+}
+
+/// IP 威胁情报标注
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpThreatInfo {
+    /// 是否被任一情报源列为恶意地址
+    pub listed: bool,
+    /// 将该地址列为恶意的情报源
+    pub sources: Vec<String>,
+    /// 恶意类别（如 `SBL123456`、`Port Scan`）
+    pub categories: Vec<String>,
+    /// 最近一次被举报的时间（仅 API 型情报源提供）
+    #[serde(
+        default,
+        with = "crate::utils::datetime::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_reported: Option<DateTime<Utc>>,
+    /// 本次实际查询到的情报源（配额耗尽或列表未下载的源不计入）
+    pub checked_sources: Vec<String>,
+}
+
+/// 威胁情报源配置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ThreatIntelConfig {
+    /// `AbuseIPDB` API key，为空时不查询 `AbuseIPDB`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abuseipdb_api_key: Option<String>,
+    /// `AbuseIPDB` 置信度达到该值（0-100）时视为恶意
+    pub abuseipdb_min_confidence: u8,
+    /// `AbuseIPDB` 每日最多请求次数（免费账户为 1000）
+    pub abuseipdb_daily_limit: u32,
+    /// API 查询结果缓存时间（秒）
+    pub cache_ttl_secs: u64,
+    /// 是否启用 Spamhaus DROP 列表（定期下载到本地离线匹配）
+    pub spamhaus_drop: bool,
+}
+
+impl Default for ThreatIntelConfig {
+    fn default() -> Self {
+        Self {
+            abuseipdb_api_key: None,
+            abuseipdb_min_confidence: 50,
+            abuseipdb_daily_limit: 1000,
+            cache_ttl_secs: 6 * 60 * 60,
+            spamhaus_drop: false,
+        }
+    }
+}
+
+impl ThreatIntelConfig {
+    /// 是否配置了任一情报源
+    pub fn has_sources(&self) -> bool {
+        self.spamhaus_drop
+            || self
+                .abuseipdb_api_key
+                .as_deref()
+                .is_some_and(|key| !key.trim().is_empty())
+    }
+}
+
+/// 隐私模式配置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PrivacyConfig {
+    /// 开启后非必需的第三方外发（IP 地理位置、威胁情报、公共 DNS 传播检查等）需要逐次确认，
+    /// 无法确认的后台外发直接禁用；服务商 API 与用户指定的服务器不受影响
+    pub privacy_mode: bool,
+}
+
+/// 第三方端点在当前配置下的访问状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExternalEndpointStatus {
+    /// 按需访问
+    Allowed,
+    /// 需要逐次显式确认
+    RequiresConfirmation,
+    /// 已禁用（隐私模式下的后台外发）
+    Disabled,
+}
+
+/// 工具可能访问的第三方端点
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalEndpoint {
+    /// 端点标识（如 `ip.geolocation`），隐私模式拦截时随错误返回
+    pub id: String,
+    /// 声明该端点的功能模块
+    pub module: String,
+    /// 访问的地址（URL、主机名或 `IP:端口`）
+    pub targets: Vec<String>,
+    /// 用途
+    pub purpose: String,
+    /// 请求中是否携带查询目标（IP、域名等）
+    pub sends_query_target: bool,
+    /// 是否为必需外发（不受隐私模式影响）
+    pub essential: bool,
+    /// 当前配置下的访问状态
+    pub status: ExternalEndpointStatus,
 }
 
 /// IP 查询结果（支持域名解析多个 IP）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IpLookupResult {
-    /// 查询的原始输入（IP 或域名）
+    /// 查询的 IP 或域名（国际化域名为 punycode 形式）
     pub query: String,
+    /// 国际化域名的 Unicode 形式
+    #[serde(default)]
+    pub unicode_domain: Option<String>,
     /// 是否为域名查询
     pub is_domain: bool,
     /// IP 地理位置结果列表
     pub results: Vec<IpGeoInfo>,
 }
 
+/// 批量 IP 查询中单个条目的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpLookupBatchEntry {
+    /// 条目对应各地址的地理位置（域名、CIDR 可能有多个；部分地址失败时只含成功项）
+    pub results: Vec<IpGeoInfo>,
+    /// 条目整体失败的原因
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
 /// SSL 证书信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[allow(clippy::struct_excessive_bools)]
 pub struct SslCertInfo {
     /// 域名
     pub domain: String,
@@ -122,6 +367,64 @@ pub struct SslCertInfo {
     pub signature_algorithm: String,
     /// 证书链
     pub certificate_chain: Vec<CertChainItem>,
+    /// 协商的 TLS 版本（如 `TLS 1.3`）
+    pub tls_version: Option<String>,
+    /// 协商的密码套件（IANA 名称，如 `TLS13_AES_128_GCM_SHA256`）
+    pub negotiated_cipher: Option<String>,
+    /// 服务器是否仍接受 TLS 1.0（已弃用，接受即视为风险）
+    pub supports_tls10: bool,
+    /// 服务器是否仍接受 TLS 1.1（已弃用，接受即视为风险）
+    pub supports_tls11: bool,
+    /// 逐版本协议探测结果（仅在请求 `probe_versions` 时存在）
+    pub protocol_probe: Option<TlsProtocolInfo>,
+    /// OCSP 吊销状态（未启用 `ocsp` feature 时为 `None`）
+    pub ocsp_status: Option<OcspStatus>,
+    /// 服务器是否在握手中装订了 OCSP 响应
+    pub ocsp_stapled: bool,
+}
+
+/// 证书的 OCSP 吊销状态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum OcspStatus {
+    /// 未被吊销
+    Good,
+    /// 已被吊销（`reason` 为 RFC 5280 `CRLReason` 名称，`revocation_time` 为 RFC 2822 格式）
+    #[serde(rename_all = "camelCase")]
+    Revoked {
+        reason: String,
+        revocation_time: String,
+    },
+    /// 响应器不认识该证书，或查询、解析响应失败，或响应签名校验失败
+    Unknown,
+    /// 未检查（服务器未发送颁发者证书，或证书中没有 OCSP 响应器地址）
+    NotChecked,
+}
+
+/// 单个 TLS 版本的探测结果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TlsVersionSupport {
+    /// 服务器接受该版本
+    Accepted,
+    /// 服务器拒绝该版本（返回 Alert 或断开连接）
+    Rejected,
+    /// 无法确定（连接失败或超时）
+    #[default]
+    Unknown,
+}
+
+/// 逐版本的 TLS 协议探测结果
+///
+/// 每个版本单独握手：TLS 1.2 / 1.3 使用只启用该版本的 rustls 握手（不校验证书），
+/// TLS 1.0 / 1.1 手工构造 ClientHello，只进行到 `ServerHello`。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsProtocolInfo {
+    pub tls10: TlsVersionSupport,
+    pub tls11: TlsVersionSupport,
+    pub tls12: TlsVersionSupport,
+    pub tls13: TlsVersionSupport,
 }
 
 /// SSL 检查结果（包含连接状态）
@@ -130,8 +433,13 @@ pub struct SslCertInfo {
 pub struct SslCheckResult {
     /// 查询的域名
     pub domain: String,
+    /// 国际化域名的 Unicode 形式（`domain` 为 punycode）
+    #[serde(default)]
+    pub unicode_domain: Option<String>,
     /// 检查的端口
     pub port: u16,
+    /// 实际连接的地址（`IP:端口`，TCP 连接失败时为空）
+    pub connected_addr: Option<String>,
     /// 连接状态: "https" | "http" | "failed"
     pub connection_status: String,
     /// 证书信息（仅当 HTTPS 连接成功时存在）
@@ -140,6 +448,16 @@ pub struct SslCheckResult {
     pub error: Option<String>,
 }
 
+/// 批量 SSL 检查的目标
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SslCheckTarget {
+    pub domain: String,
+    /// 端口，默认 443
+    #[serde(default)]
+    pub port: Option<u16>,
+}
+
 /// 证书链项
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -150,6 +468,12 @@ pub struct CertChainItem {
     pub issuer: String,
     /// 是否为 CA 证书
     pub is_ca: bool,
+    /// 有效期起始（RFC 2822）
+    pub valid_from: String,
+    /// 有效期截止（RFC 2822）
+    pub valid_to: String,
+    /// 剩余天数（已过期时为负数）
+    pub days_remaining: i64,
 }
 
 /// HTTP 请求方法
@@ -188,6 +512,16 @@ pub struct HttpHeaderCheckRequest {
     pub body: Option<String>,
     /// 请求体内容类型
     pub content_type: Option<String>,
+    /// 是否跟随重定向，关闭时只检查第一个响应
+    #[serde(default)]
+    pub follow_redirects: bool,
+    /// 最多跟随的重定向次数（不超过 20）
+    #[serde(default = "default_max_redirects")]
+    pub max_redirects: u8,
+}
+
+const fn default_max_redirects() -> u8 {
+    5
 }
 
 /// 安全头分析结果
@@ -200,10 +534,37 @@ pub struct SecurityHeaderAnalysis {
     pub present: bool,
     /// 头值（如果存在）
     pub value: Option<String>,
-    /// 状态: "good" | "warning" | "missing"
-    pub status: String,
-    /// 建议
-    pub recommendation: Option<String>,
+    /// 严重程度（取各项问题中最严重的）
+    pub severity: SecuritySeverity,
+    /// 发现的具体问题或建议，按检查顺序排列
+    pub findings: Vec<String>,
+}
+
+/// 安全头问题的严重程度
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum SecuritySeverity {
+    /// 配置合理
+    #[default]
+    Ok,
+    /// 存在可改进之处，或缺少建议的安全头
+    Warning,
+    /// 配置无效、明显不安全，或缺少必需的安全头
+    Error,
+}
+
+/// 安全头评分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityScore {
+    /// 等级（A–F）
+    pub overall_grade: char,
+    /// 得分（0–100）
+    pub score: u8,
+    /// 参与评分的安全头满分之和
+    pub max_possible: u8,
 }
 
 /// HTTP 头检查结果
@@ -222,12 +583,156 @@ pub struct HttpHeaderCheckResult {
     pub headers: Vec<HttpHeader>,
     /// 安全头分析
     pub security_analysis: Vec<SecurityHeaderAnalysis>,
+    /// 安全头评分
+    pub security_score: SecurityScore,
     /// Content-Length
     pub content_length: Option<u64>,
     /// 原始请求报文
     pub raw_request: String,
     /// 原始响应报文
     pub raw_response: String,
+    /// 最终响应的 URL（未跟随重定向时与 `url` 相同）
+    pub final_url: String,
+    /// 按请求顺序排列的各跳，包含最终响应；未跟随重定向时为空
+    pub redirect_chain: Vec<HttpHop>,
+    /// 达到重定向次数上限时仍未结束，此时头部分析针对最后一个重定向响应
+    pub redirect_limit_reached: bool,
+    /// 重定向回到已访问过的 URL
+    pub loop_detected: bool,
+}
+
+/// 重定向链中的一跳
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpHop {
+    /// 本跳请求的 URL
+    pub url: String,
+    /// HTTP 状态码
+    pub status_code: u16,
+    /// 响应中的 Location 头（原样保留，可能为相对地址）
+    pub location_header: Option<String>,
+    /// 响应时间（毫秒）
+    pub response_time_ms: u64,
+    /// HTTPS 跳的证书信息（获取失败或非 HTTPS 时为空）
+    pub tls_info: Option<SslCertInfo>,
+}
+
+/// 重定向链跟踪结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpTraceResult {
+    /// 起始 URL（已补全协议）
+    pub url: String,
+    /// 按请求顺序排列的各跳
+    pub hops: Vec<HttpHop>,
+    /// 最后一跳的 URL
+    pub final_url: String,
+    /// 达到重定向次数上限时仍未结束
+    pub redirect_limit_reached: bool,
+    /// 重定向回到已访问过的 URL
+    pub loop_detected: bool,
+    /// 跟踪中途失败的原因（之前的各跳仍保留）
+    pub error: Option<String>,
+}
+
+/// 端口检查的协议
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PortProtocol {
+    #[default]
+    Tcp,
+    Udp,
+}
+
+/// 端口状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PortState {
+    /// TCP 连接成功，或 UDP 收到响应
+    Open,
+    /// TCP 连接被拒绝，或 UDP 收到 ICMP 端口不可达
+    Closed,
+    /// 超时或不可达（UDP 无响应时也可能是不响应探测报文的开放端口）
+    Filtered,
+}
+
+/// 单个端口的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortStatus {
+    pub port: u16,
+    pub state: PortState,
+    /// 建立连接（TCP）或收到响应（UDP）耗时（毫秒，端口未开放时为空）
+    pub response_time_ms: Option<u64>,
+    /// TCP 连接后服务器主动发送的欢迎信息（最多 64 字节，控制字符替换为空格）
+    pub banner: Option<String>,
+}
+
+/// 端口连通性检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortCheckResult {
+    /// 检查的主机
+    pub host: String,
+    /// 实际探测的地址（主机解析结果中的第一个）
+    pub address: String,
+    pub protocol: PortProtocol,
+    /// 各端口结果，按请求顺序排列（已去重）
+    pub results: Vec<PortStatus>,
+}
+
+/// 路径追踪的探测方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceMethod {
+    /// 原始套接字发送 ICMP Echo，可获得每一跳的地址
+    Icmp,
+    /// 向目标 443 端口发起 TCP 连接（无原始套接字权限时的回退），中间跳只有时延没有地址
+    Tcp,
+}
+
+/// 路径中的一跳
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceHop {
+    /// 探测包的 TTL（即跳数，从 1 开始）
+    pub ttl: u8,
+    /// 响应的地址（无响应或 TCP 回退的中间跳为空）
+    pub ip: Option<String>,
+    /// 反向解析得到的主机名
+    pub hostname: Option<String>,
+    /// 已发送探测数
+    pub sent: u32,
+    /// 收到响应数
+    pub received: u32,
+    /// 丢包率（0-100%）
+    pub loss_rate: f64,
+    /// 最小往返时延（毫秒）
+    pub min_ms: Option<f64>,
+    /// 平均往返时延（毫秒）
+    pub avg_ms: Option<f64>,
+    /// 最大往返时延（毫秒）
+    pub max_ms: Option<f64>,
+    /// 该跳返回了目标不可达，追踪在此终止
+    pub unreachable: bool,
+}
+
+/// 路径追踪结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceRouteResult {
+    /// 追踪的主机
+    pub host: String,
+    /// 解析得到的目标地址
+    pub target_ip: String,
+    /// 实际使用的探测方式
+    pub method: TraceMethod,
+    /// 回退到 TCP 的原因（使用 ICMP 时为空）
+    pub fallback_reason: Option<String>,
+    /// 各跳结果，按 TTL 排列
+    pub hops: Vec<TraceHop>,
+    /// 是否到达目标
+    pub reached: bool,
 }
 
 /// DNS 传播检查服务器信息
@@ -276,6 +781,48 @@ pub struct DnsPropagationResult {
     pub consistency_percentage: f32,
     /// 唯一值列表（用于检测一致性）
     pub unique_values: Vec<String>,
+    /// 多数服务器返回的应答（无成功应答或应答为空时为 None）
+    pub consensus_value: Option<String>,
+    /// 返回多数应答的服务器数
+    pub propagated_count: usize,
+    /// 查询的服务器总数
+    pub total_count: usize,
+}
+
+/// 单个解析器的传播检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationResolverResult {
+    /// 解析器信息
+    pub server: DnsPropagationServer,
+    /// 解析到的值
+    pub values: Vec<String>,
+    /// 是否解析到期望值（任一值匹配即可）
+    pub matches: bool,
+    /// 查询耗时（毫秒）
+    pub response_time_ms: u64,
+    /// 错误信息（查询失败或超时时）
+    pub error: Option<String>,
+}
+
+/// 期望值传播检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationCheckResult {
+    /// 查询的域名
+    pub domain: String,
+    /// 查询的记录类型
+    pub record_type: String,
+    /// 期望值
+    pub expected_value: String,
+    /// 各解析器查询结果
+    pub results: Vec<PropagationResolverResult>,
+    /// 已解析到期望值的解析器数
+    pub matched_count: usize,
+    /// 查询的解析器总数
+    pub total_count: usize,
+    /// 总查询时间（毫秒）
+    pub total_time_ms: u64,
 }
 
 /// DNSSEC DNSKEY 记录
@@ -365,3 +912,331 @@ pub struct DnssecResult {
     /// 错误信息（查询失败时）
     pub error: Option<String>,
 }
+
+/// DNS 劫持检测结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsHijackCheck {
+    /// 用于探测的随机域名（理论上不存在）
+    pub probe_domain: String,
+    /// 是否检测到劫持（探测失败时为 None）
+    pub hijacked: Option<bool>,
+    /// 不存在的域名竟然返回的应答
+    pub answers: Vec<String>,
+    /// 错误信息（探测失败时）
+    pub error: Option<String>,
+}
+
+/// 系统代理设置摘要（来自环境变量，凭证已脱敏）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxySettings {
+    /// `HTTP_PROXY`
+    pub http_proxy: Option<String>,
+    /// `HTTPS_PROXY`
+    pub https_proxy: Option<String>,
+    /// `ALL_PROXY`
+    pub all_proxy: Option<String>,
+    /// `NO_PROXY`
+    pub no_proxy: Option<String>,
+}
+
+/// 本机网络环境信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkEnvInfo {
+    /// 系统配置的 DNS 服务器列表
+    pub system_dns_servers: Vec<String>,
+    /// DNS 配置来源（无法区分 DHCP/手动/VPN 推送时为 "system"）
+    pub dns_source: String,
+    /// DNS 劫持检测
+    pub dns_hijack: DnsHijackCheck,
+    /// 默认出口 IPv4（探测失败时为 None）
+    pub public_ipv4: Option<String>,
+    /// 默认出口 IPv6（无 IPv6 出口或探测失败时为 None）
+    pub public_ipv6: Option<String>,
+    /// 系统代理设置
+    pub proxy: ProxySettings,
+    /// 总耗时（毫秒）
+    pub elapsed_ms: u64,
+}
+
+/// DNS 基准测试进度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsBenchmarkProgress {
+    /// 已完成查询数
+    pub completed: u32,
+    /// 总查询数
+    pub total: u32,
+    /// 刚完成查询的 DNS 服务器
+    pub nameserver: String,
+}
+
+/// 延迟分布统计（毫秒，无成功样本时为 None）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyStats {
+    pub min: Option<u64>,
+    pub p50: Option<u64>,
+    pub p95: Option<u64>,
+    pub max: Option<u64>,
+}
+
+/// 单个 DNS 服务器的基准测试结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsBenchmarkServerResult {
+    /// DNS 服务器地址
+    pub nameserver: String,
+    /// 首次查询耗时（可能未命中服务器缓存，单独统计）
+    pub cold_ms: Option<u64>,
+    /// 后续查询的耗时样本（按发送顺序，可直接用于箱线图）
+    pub samples_ms: Vec<u64>,
+    /// 后续查询的延迟分布
+    pub stats: LatencyStats,
+    /// 已发送查询数
+    pub sent: u32,
+    /// 超时次数
+    pub timeouts: u32,
+    /// 其他错误次数
+    pub errors: u32,
+    /// 丢包（超时）率（0-100%）
+    pub loss_rate: f64,
+    /// 是否支持 EDNS（无法判断时为 None）
+    pub edns_supported: Option<bool>,
+    /// 是否支持 TCP 查询（无法判断时为 None）
+    pub tcp_supported: Option<bool>,
+    /// 最近一次错误信息
+    pub error: Option<String>,
+}
+
+/// DNS 基准测试结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsBenchmarkResult {
+    /// 查询的域名
+    pub domain: String,
+    /// 每个服务器的查询次数
+    pub iterations: u32,
+    /// 各服务器结果（按请求顺序）
+    pub results: Vec<DnsBenchmarkServerResult>,
+    /// 总耗时（毫秒）
+    pub total_time_ms: u64,
+    /// 是否被取消
+    pub cancelled: bool,
+    /// 是否因达到总时长上限提前结束
+    pub time_limited: bool,
+}
+
+/// SPF 限定符
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpfQualifier {
+    /// `+`（默认）
+    Pass,
+    /// `-`
+    Fail,
+    /// `~`
+    SoftFail,
+    /// `?`
+    Neutral,
+}
+
+/// SPF 机制（如 `ip4:192.0.2.0/24`、`include:_spf.example.com`、`-all`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpfMechanism {
+    pub qualifier: SpfQualifier,
+    /// 机制名称（小写）: "all" | "include" | "a" | "mx" | "ptr" | "ip4" | "ip6" | "exists"
+    pub mechanism: String,
+    /// 机制参数（`:` 之后或 CIDR 部分）
+    pub value: Option<String>,
+}
+
+/// SPF `include:` / `redirect=` 展开结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpfInclude {
+    /// 被引用的域名
+    pub domain: String,
+    /// 嵌套深度（根记录直接引用为 1）
+    pub depth: u8,
+    /// 该域名的 SPF 记录原文
+    pub raw: Option<String>,
+    /// 查询或解析失败原因
+    pub error: Option<String>,
+}
+
+/// SPF 记录解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpfRecord {
+    /// 记录原文
+    pub raw: String,
+    pub mechanisms: Vec<SpfMechanism>,
+    /// `all` 的限定符（根记录没有 `all` 时取 `redirect` 目标的）
+    pub all: Option<SpfQualifier>,
+    /// `redirect=` 目标
+    pub redirect: Option<String>,
+    /// 展开的 `include:` / `redirect=` 链（最多 3 层）
+    pub includes: Vec<SpfInclude>,
+    /// 需要 DNS 查询的机制数量（RFC 7208 限制为 10，只统计已展开的部分）
+    pub dns_lookup_count: u32,
+}
+
+/// DMARC 记录解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DmarcRecord {
+    /// 记录原文
+    pub raw: String,
+    /// 策略: "none" | "quarantine" | "reject"
+    pub policy: String,
+    /// 子域策略（`sp`）
+    pub subdomain_policy: Option<String>,
+    /// 策略应用比例（`pct`，默认 100）
+    pub percentage: u8,
+    /// 汇总报告地址（`rua`）
+    pub rua: Vec<String>,
+    /// 失败报告地址（`ruf`）
+    pub ruf: Vec<String>,
+    /// DKIM 对齐模式（`adkim`）: "r" | "s"
+    pub adkim: Option<String>,
+    /// SPF 对齐模式（`aspf`）: "r" | "s"
+    pub aspf: Option<String>,
+}
+
+/// DKIM 记录解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DkimRecord {
+    pub selector: String,
+    /// 记录原文
+    pub raw: String,
+    /// 密钥类型（`k`，默认 "rsa"）
+    pub key_type: String,
+    /// 是否包含公钥（`p=` 为空表示密钥已撤销）
+    pub has_public_key: bool,
+    /// RSA 密钥长度（按公钥长度估算）
+    pub key_bits: Option<u32>,
+    /// 是否处于测试模式（`t=y`）
+    pub testing: bool,
+}
+
+/// 邮件安全（SPF/DKIM/DMARC）检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSecurityResult {
+    /// 查询的域名
+    pub domain: String,
+    pub spf: Option<SpfRecord>,
+    pub dmarc: Option<DmarcRecord>,
+    /// 未指定 selector 时不检查
+    pub dkim: Option<DkimRecord>,
+    /// 评分（0-100）：SPF 35 分、DMARC 40 分、DKIM 25 分
+    pub score: u8,
+    /// 发现的问题
+    pub issues: Vec<String>,
+}
+
+/// SMTP 端口连通性探测结果（单个地址）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmtpProbe {
+    /// 探测的 IP 地址
+    pub address: String,
+    /// TCP 连接是否成功
+    pub connected: bool,
+    /// 服务器欢迎消息（首行，正常应以 `220` 开头）
+    pub banner: Option<String>,
+    /// 建立连接耗时（毫秒）
+    pub connect_time_ms: Option<u64>,
+    /// 连接或读取欢迎消息失败的原因
+    pub error: Option<String>,
+}
+
+/// 单个 MX 主机的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MxHostCheck {
+    /// MX 主机名（不含末尾的点）
+    pub host: String,
+    /// MX 优先级
+    pub preference: u16,
+    /// 解析到的 A/AAAA 地址
+    pub addresses: Vec<String>,
+    /// 各地址的 25 端口探测结果
+    pub probes: Vec<SmtpProbe>,
+    /// 主机名解析失败的原因
+    pub error: Option<String>,
+}
+
+/// 邮件策略记录（SPF / DMARC）的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailPolicyCheck {
+    /// 是否存在该记录
+    pub present: bool,
+    /// 语法是否基本正确（唯一一条且没有无法识别的内容）
+    pub valid: bool,
+    /// 原始记录（存在多条时为第一条）
+    pub raw: Option<String>,
+    /// 发现的问题
+    pub issues: Vec<String>,
+}
+
+/// 邮件服务健康检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailCheckResult {
+    /// 查询的域名
+    pub domain: String,
+    /// MX 主机（按优先级排序）
+    pub mx_hosts: Vec<MxHostCheck>,
+    /// 是否为 Null MX（RFC 7505，声明不接收邮件）
+    pub null_mx: bool,
+    pub spf: MailPolicyCheck,
+    pub dmarc: MailPolicyCheck,
+    /// 发现的问题
+    pub issues: Vec<String>,
+}
+
+/// 解析后的 CAA 记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedCaaRecord {
+    /// 关键标志：CA 不认识该标签时必须拒绝签发
+    pub critical: bool,
+    /// 属性标签（小写）
+    pub tag: String,
+    /// 原始值
+    pub value: String,
+    /// `issue` / `issuewild` 授权的 CA 域名，值为 `;` 时为空（禁止签发）
+    pub issuer: Option<String>,
+    /// `issue` / `issuewild` 的参数（如 `accounturi=...`、`validationmethods=...`）
+    pub parameters: Vec<String>,
+}
+
+/// CAA 检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaaCheckResult {
+    /// 查询的域名
+    pub domain: String,
+    /// 找到 CAA 记录的域名（自身或最近的上级域名），未找到时为空
+    pub effective_domain: String,
+    pub records: Vec<ParsedCaaRecord>,
+    /// 是否限制了证书签发（存在 `issue` 或 `issuewild`）
+    pub is_protected: bool,
+    /// 可签发普通证书的 CA
+    pub authorized_cas: Vec<String>,
+    /// 可签发通配符证书的 CA（未配置 `issuewild` 时沿用 `issue`）
+    pub wildcard_cas: Vec<String>,
+    /// 违规报告地址（`iodef`）
+    pub iodef: Vec<String>,
+    /// 联系邮箱（`contactemail`）
+    pub contact_emails: Vec<String>,
+    /// 发现的问题
+    pub issues: Vec<String>,
+}
@@ -0,0 +1,102 @@
+//! 权重灰度切换相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 灰度阶段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolloutStage {
+    /// 新值的流量百分比（1-100）
+    pub weight_percent: u8,
+    /// 该阶段的保持时间（秒）
+    pub hold_secs: u64,
+}
+
+/// 保持期内的验证探测：TCP 连接新记录值的指定端口，连接失败时自动回退
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RolloutVerification {
+    /// 探测的端口
+    pub port: u16,
+    /// 探测间隔（秒，默认 30）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_secs: Option<u64>,
+    /// 单次连接超时（毫秒，默认 3000，不超过 30000）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+}
+
+/// 创建权重灰度切换请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWeightedRolloutRequest {
+    pub account_id: String,
+    pub domain_id: String,
+    /// 记录名称（相对名称，如 `www`、`@`）
+    pub record_name: String,
+    /// 旧记录值（必须已存在）
+    pub from_value: String,
+    /// 新记录值
+    pub to_value: String,
+    /// 灰度阶段（权重需递增，最后一个阶段必须为 100）
+    pub stages: Vec<RolloutStage>,
+    /// 保持期内的验证探测（为空时不探测）
+    #[serde(default)]
+    pub verification: Option<RolloutVerification>,
+}
+
+/// 灰度任务状态
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RolloutStatus {
+    /// 执行中（调整权重或保持期内）
+    Running,
+    /// 已暂停（保持期计时暂停）
+    Paused,
+    /// 全部阶段完成，旧记录已清理
+    Completed,
+    /// 已回退到 0%（新记录已删除；验证探测失败触发的回退在 `error` 中注明原因）
+    RolledBack,
+    /// 执行失败
+    Failed,
+}
+
+/// 权重灰度切换任务
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedRollout {
+    /// 任务 ID (UUID)
+    pub id: String,
+    pub account_id: String,
+    pub domain_id: String,
+    pub record_name: String,
+    pub from_value: String,
+    pub to_value: String,
+    pub stages: Vec<RolloutStage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<RolloutVerification>,
+    pub status: RolloutStatus,
+    /// 当前阶段索引（尚未进入任何阶段时为 `None`）
+    pub current_stage: Option<usize>,
+    /// 新值当前的流量百分比
+    pub current_weight: u8,
+    /// 旧记录 ID
+    pub old_record_id: String,
+    /// 新记录 ID
+    pub new_record_id: String,
+    /// 因禁止变更时段顺延到的时间（未被顺延时为 `None`）
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::datetime::option"
+    )]
+    pub deferred_until: Option<DateTime<Utc>>,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(with = "crate::utils::datetime")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub updated_at: DateTime<Utc>,
+}
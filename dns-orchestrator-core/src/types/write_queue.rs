@@ -0,0 +1,98 @@
+//! 离线写队列相关类型定义
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{CreateDnsRecordRequest, DnsRecord, UpdateDnsRecordRequest};
+
+/// 离线写队列配置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WriteQueueConfig {
+    /// 是否启用（默认关闭）
+    pub enabled: bool,
+    /// 队列最多保存的写操作数，已满时不再入队
+    pub max_entries: usize,
+    /// 入队后的有效期（小时），过期的写操作不再重放
+    pub ttl_hours: u32,
+}
+
+impl Default for WriteQueueConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: 100,
+            ttl_hours: 24,
+        }
+    }
+}
+
+/// 排队的写操作（保留原始请求）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum QueuedWriteOperation {
+    Create {
+        request: CreateDnsRecordRequest,
+    },
+    #[serde(rename_all = "camelCase")]
+    Update {
+        record_id: String,
+        request: UpdateDnsRecordRequest,
+    },
+    #[serde(rename_all = "camelCase")]
+    Delete {
+        record_id: String,
+    },
+}
+
+/// 因网络错误进入离线写队列的写操作
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedWrite {
+    pub id: String,
+    pub account_id: String,
+    pub domain_id: String,
+    pub operation: QueuedWriteOperation,
+    /// 入队时目标记录的快照，用于重放前的冲突检测
+    /// （创建时为空；离线时通常无法获取）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<DnsRecord>,
+    /// 入队时的网络错误
+    pub error: String,
+    #[serde(with = "crate::utils::datetime")]
+    pub queued_at: DateTime<Utc>,
+    #[serde(with = "crate::utils::datetime")]
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 单个写操作的重放结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WriteReplayStatus {
+    /// 已重放成功，移出队列
+    Applied,
+    /// 目标记录已是期望状态（可能之前的请求实际已生效），移出队列
+    AlreadyApplied,
+    /// 目标记录已被其他途径修改，保留在队列中等待人工处理
+    Conflict,
+    /// 重放失败（如仍无法连接），保留在队列中
+    Failed,
+    /// 已过期，移出队列
+    Expired,
+    /// 队列中不存在该写操作
+    NotFound,
+}
+
+/// 重放结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteReplayOutcome {
+    pub id: String,
+    pub status: WriteReplayStatus,
+    /// 冲突或失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// 目标记录的当前状态（冲突时供对比）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<DnsRecord>,
+}
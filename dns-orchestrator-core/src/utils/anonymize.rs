@@ -0,0 +1,673 @@
+//! 诊断数据匿名化
+//!
+//! 用户提交问题时附带的账户、域名与记录数据在这里统一替换为假名：域名映射为
+//! `example-xxxx.test`，IP 替换为文档保留段（RFC 5737 / RFC 3849），TXT 值替换为哈希，
+//! 账户名与各类 ID 重新编号。记录类型、TTL、优先级等排障需要的结构保持不变。
+//!
+//! 同一个 [`Anonymizer`] 中同一原值总是得到同一假名，记录之间的引用关系（CNAME 指向、
+//! MX 主机）得以保留。哈希使用每次导出随机生成的密钥，无法通过字典反推原值；
+//! 原值与假名的对照由 [`Anonymizer::into_mapping`] 导出，由调用方加密保存。
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::types::{
+    DiagnosticsAccount, DiagnosticsDomain, DiagnosticsReport, DnsHijackCheck, DnsRecord,
+    NetworkEnvInfo, ProxySettings, RecordData,
+};
+
+/// 域名、标签假名的哈希起始长度（十六进制位数），冲突时加长
+const SHORT_HASH_LEN: usize = 4;
+
+/// 文本值哈希的长度（十六进制位数）
+const TEXT_HASH_LEN: usize = 16;
+
+/// RFC 5737 文档保留段 192.0.2.0/24、198.51.100.0/24、203.0.113.0/24
+const IPV4_DOC_NETS: [u32; 3] = [0xC000_0200, 0xC633_6400, 0xCB00_7100];
+
+/// 文档保留段用完后使用的基准测试保留段 198.18.0.0/15（RFC 2544）
+const IPV4_BENCHMARK_NET: u32 = 0xC612_0000;
+const IPV4_BENCHMARK_SIZE: u32 = 0x2_0000;
+
+/// RFC 3849 文档保留段 `2001:db8::/32`
+const IPV6_DOC_NET: u128 = 0x2001_0db8_0000_0000_0000_0000_0000_0000;
+
+/// 匿名化的数据类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnonymizedKind {
+    /// 域名（整体替换）
+    Domain,
+    /// 子域标签
+    Label,
+    /// IPv4 地址
+    Ipv4,
+    /// IPv6 地址
+    Ipv6,
+    /// TXT 等文本值
+    Text,
+    /// 账户 ID
+    AccountId,
+    /// 账户名称
+    AccountName,
+    /// 域名 ID
+    DomainId,
+    /// 记录 ID
+    RecordId,
+}
+
+/// 映射表条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymizationEntry {
+    /// 数据类别
+    pub kind: AnonymizedKind,
+    /// 原值
+    pub original: String,
+    /// 假名
+    pub pseudonym: String,
+}
+
+/// 匿名化器，保存本次导出的密钥与已分配的假名
+pub struct Anonymizer {
+    key: [u8; 32],
+    /// 已知域名（小写），其下的主机名只替换子域标签
+    zones: Vec<String>,
+    forward: HashMap<(AnonymizedKind, String), String>,
+    used: HashSet<String>,
+    counters: HashMap<AnonymizedKind, u32>,
+    /// 按分配顺序保存，用于导出映射表
+    entries: Vec<AnonymizationEntry>,
+}
+
+impl Default for Anonymizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Anonymizer {
+    /// 使用随机密钥创建匿名化器
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_key(rand::random())
+    }
+
+    fn with_key(key: [u8; 32]) -> Self {
+        Self {
+            key,
+            zones: Vec::new(),
+            forward: HashMap::new(),
+            used: HashSet::new(),
+            counters: HashMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// 登记域名并返回假名；之后其下的主机名保留层级，只替换子域标签
+    pub fn zone(&mut self, name: &str) -> String {
+        let zone = name.trim_end_matches('.').to_lowercase();
+        if zone.is_empty() {
+            return name.to_string();
+        }
+        if !self.zones.contains(&zone) {
+            self.zones.push(zone.clone());
+        }
+        self.domain_alias(&zone)
+    }
+
+    /// 主机名（FQDN）：位于已登记域名下时只替换子域标签，否则整体作为域名替换
+    ///
+    /// 保留末尾的点；IP 地址按 [`Self::ip`] 替换。
+    pub fn hostname(&mut self, name: &str) -> String {
+        let trimmed = name.trim_end_matches('.');
+        if trimmed.is_empty() {
+            return name.to_string();
+        }
+        let suffix = &name[trimmed.len()..];
+        let lower = trimmed.to_lowercase();
+        if lower.parse::<IpAddr>().is_ok() {
+            return self.ip(&lower);
+        }
+
+        let zone = self
+            .zones
+            .iter()
+            .filter(|zone| lower == **zone || lower.ends_with(&format!(".{zone}")))
+            .max_by_key(|zone| zone.len())
+            .cloned();
+        let alias = match zone {
+            Some(zone) => {
+                let prefix = lower[..lower.len() - zone.len()].trim_end_matches('.');
+                let zone_alias = self.domain_alias(&zone);
+                if prefix.is_empty() {
+                    zone_alias
+                } else {
+                    format!("{}.{zone_alias}", self.labels(prefix))
+                }
+            }
+            None => self.domain_alias(&lower),
+        };
+        format!("{alias}{suffix}")
+    }
+
+    /// 记录名称：`@` 原样保留，FQDN 按 [`Self::hostname`] 替换，相对名称逐个替换标签
+    pub fn record_name(&mut self, zone: &str, name: &str) -> String {
+        let lower = name.trim_end_matches('.').to_lowercase();
+        let zone = zone.trim_end_matches('.').to_lowercase();
+        if lower.is_empty() || lower == "@" {
+            return name.to_string();
+        }
+        if lower == zone || lower.ends_with(&format!(".{zone}")) {
+            return self.hostname(name);
+        }
+        self.labels(&lower)
+    }
+
+    /// IP 地址按出现顺序替换为文档保留段地址；无法解析时按文本哈希
+    pub fn ip(&mut self, address: &str) -> String {
+        match address.trim().parse::<IpAddr>() {
+            Ok(IpAddr::V4(addr)) => self.numbered(AnonymizedKind::Ipv4, &addr.to_string(), |n| {
+                ipv4_alias(n).to_string()
+            }),
+            Ok(IpAddr::V6(addr)) => self.numbered(AnonymizedKind::Ipv6, &addr.to_string(), |n| {
+                Ipv6Addr::from(IPV6_DOC_NET + u128::from(n)).to_string()
+            }),
+            Err(_) => self.text(address),
+        }
+    }
+
+    /// 文本值替换为带密钥的哈希，空文本原样保留
+    pub fn text(&mut self, text: &str) -> String {
+        if text.is_empty() {
+            return String::new();
+        }
+        self.hashed(AnonymizedKind::Text, text, TEXT_HASH_LEN, |hash| {
+            format!("sha256:{hash}")
+        })
+    }
+
+    /// 账户、域名、记录 ID 与账户名称按出现顺序重新编号
+    pub fn id(&mut self, kind: AnonymizedKind, original: &str) -> String {
+        let prefix = match kind {
+            AnonymizedKind::AccountId => "account",
+            AnonymizedKind::AccountName => "Account",
+            AnonymizedKind::DomainId => "domain",
+            AnonymizedKind::RecordId => "record",
+            _ => return self.text(original),
+        };
+        self.numbered(kind, original, |n| format!("{prefix}-{n}"))
+    }
+
+    /// 替换自由文本（如错误信息）中出现的已登记域名下的主机名、IP 地址与其他已替换的原值
+    ///
+    /// 单遍替换，替换结果不会被再次匹配。
+    pub fn scrub(&mut self, text: &str) -> String {
+        let mut zones: Vec<&String> = self.zones.iter().collect();
+        zones.sort_by_key(|zone| Reverse(zone.len()));
+        let mut literals: Vec<&String> = self
+            .forward
+            .keys()
+            .filter(|(kind, _)| *kind != AnonymizedKind::Label)
+            .map(|(_, original)| original)
+            .collect();
+        literals.sort_by_key(|original| Reverse(original.len()));
+
+        let mut alternatives = Vec::new();
+        if !zones.is_empty() {
+            let zones: Vec<String> = zones.into_iter().map(|zone| regex::escape(zone)).collect();
+            alternatives.push(format!(
+                r"(?P<host>(?:[a-z0-9_-]+\.)*(?:{})\b)",
+                zones.join("|")
+            ));
+        }
+        alternatives.push(
+            r"(?P<ip>\b(?:\d{1,3}\.){3}\d{1,3}\b|\b[0-9a-f]{0,4}(?::[0-9a-f]{0,4}){2,7}\b)"
+                .to_string(),
+        );
+        if !literals.is_empty() {
+            let literals: Vec<String> = literals.into_iter().map(|l| word_pattern(l)).collect();
+            alternatives.push(format!("(?P<literal>{})", literals.join("|")));
+        }
+        let Ok(re) = Regex::new(&format!("(?i){}", alternatives.join("|"))) else {
+            return text.to_string();
+        };
+
+        re.replace_all(text, |caps: &Captures<'_>| {
+            let matched = &caps[0];
+            if caps.name("host").is_some() {
+                return self.hostname(matched);
+            }
+            if caps.name("ip").is_some() {
+                // `::` 等不含数字的匹配多为路径分隔符，不视为地址
+                let is_address = matched.parse::<IpAddr>().is_ok()
+                    && matched.chars().any(|c| c.is_ascii_hexdigit());
+                return if is_address {
+                    self.ip(matched)
+                } else {
+                    matched.to_string()
+                };
+            }
+            self.forward
+                .iter()
+                .find(|((kind, original), _)| {
+                    *kind != AnonymizedKind::Label && original.eq_ignore_ascii_case(matched)
+                })
+                .map_or_else(|| matched.to_string(), |(_, alias)| alias.clone())
+        })
+        .into_owned()
+    }
+
+    /// 替换 JSON 中的全部字符串（错误现场），键名与结构不变
+    pub fn scrub_value(&mut self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::String(text) => *text = self.scrub(text),
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    self.scrub_value(item);
+                }
+            }
+            serde_json::Value::Object(map) => {
+                for item in map.values_mut() {
+                    self.scrub_value(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 匿名化记录：ID、名称与值替换，类型、TTL、优先级、代理状态与时间保留
+    pub fn record(&mut self, zone: &str, record: &DnsRecord) -> DnsRecord {
+        DnsRecord {
+            id: self.id(AnonymizedKind::RecordId, &record.id),
+            domain_id: self.id(AnonymizedKind::DomainId, &record.domain_id),
+            name: self.record_name(zone, &record.name),
+            data: self.record_data(&record.data),
+            ..record.clone()
+        }
+    }
+
+    /// 匿名化诊断报告
+    ///
+    /// 先登记全部域名，使跨账户、跨域名的引用也指向同一假名；错误信息最后替换，
+    /// 此时已知的原值最全。
+    pub fn report(&mut self, report: &DiagnosticsReport) -> DiagnosticsReport {
+        for domain in report.accounts.iter().flat_map(|account| &account.domains) {
+            self.zone(&domain.name);
+        }
+
+        let mut accounts: Vec<DiagnosticsAccount> = report
+            .accounts
+            .iter()
+            .map(|account| DiagnosticsAccount {
+                id: self.id(AnonymizedKind::AccountId, &account.id),
+                name: self.id(AnonymizedKind::AccountName, &account.name),
+                domains: account
+                    .domains
+                    .iter()
+                    .map(|domain| DiagnosticsDomain {
+                        id: self.id(AnonymizedKind::DomainId, &domain.id),
+                        name: self.zone(&domain.name),
+                        records: domain
+                            .records
+                            .iter()
+                            .map(|record| self.record(&domain.name, record))
+                            .collect(),
+                        ..domain.clone()
+                    })
+                    .collect(),
+                ..account.clone()
+            })
+            .collect();
+
+        for account in &mut accounts {
+            account.error = account.error.as_deref().map(|error| self.scrub(error));
+            if let Some(error) = &mut account.collect_error {
+                self.scrub_value(error);
+            }
+            for domain in &mut account.domains {
+                if let Some(error) = &mut domain.collect_error {
+                    self.scrub_value(error);
+                }
+            }
+        }
+
+        DiagnosticsReport {
+            anonymized: true,
+            accounts,
+            network_env: report.network_env.as_ref().map(|env| self.network_env(env)),
+            ..report.clone()
+        }
+    }
+
+    /// 导出映射表（按分配顺序）
+    #[must_use]
+    pub fn into_mapping(self) -> Vec<AnonymizationEntry> {
+        self.entries
+    }
+
+    /// 网络环境中的 DNS 服务器、出口 IP 与劫持应答替换为保留段地址，代理地址按文本哈希
+    fn network_env(&mut self, env: &NetworkEnvInfo) -> NetworkEnvInfo {
+        let mut text = |value: &Option<String>| value.as_deref().map(|v| self.text(v));
+        let proxy = ProxySettings {
+            http_proxy: text(&env.proxy.http_proxy),
+            https_proxy: text(&env.proxy.https_proxy),
+            all_proxy: text(&env.proxy.all_proxy),
+            no_proxy: text(&env.proxy.no_proxy),
+        };
+        NetworkEnvInfo {
+            system_dns_servers: env.system_dns_servers.iter().map(|s| self.ip(s)).collect(),
+            dns_hijack: DnsHijackCheck {
+                answers: env.dns_hijack.answers.iter().map(|a| self.ip(a)).collect(),
+                error: env.dns_hijack.error.as_deref().map(|e| self.scrub(e)),
+                ..env.dns_hijack.clone()
+            },
+            public_ipv4: env.public_ipv4.as_deref().map(|ip| self.ip(ip)),
+            public_ipv6: env.public_ipv6.as_deref().map(|ip| self.ip(ip)),
+            proxy,
+            ..env.clone()
+        }
+    }
+
+    fn record_data(&mut self, data: &RecordData) -> RecordData {
+        match data {
+            RecordData::A { address } => RecordData::A {
+                address: self.ip(address),
+            },
+            RecordData::AAAA { address } => RecordData::AAAA {
+                address: self.ip(address),
+            },
+            RecordData::CNAME { target } => RecordData::CNAME {
+                target: self.hostname(target),
+            },
+            RecordData::MX { priority, exchange } => RecordData::MX {
+                priority: *priority,
+                exchange: self.hostname(exchange),
+            },
+            RecordData::TXT { text } => RecordData::TXT {
+                text: self.text(text),
+            },
+            RecordData::NS { nameserver } => RecordData::NS {
+                nameserver: self.hostname(nameserver),
+            },
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => RecordData::SRV {
+                priority: *priority,
+                weight: *weight,
+                port: *port,
+                target: self.hostname(target),
+            },
+            RecordData::CAA { flags, tag, value } => {
+                // 只有 CA 名称的 issue/issuewild 值不含用户数据，保留以便排查签发问题
+                let issuer_only = (tag.eq_ignore_ascii_case("issue")
+                    || tag.eq_ignore_ascii_case("issuewild"))
+                    && !value.trim_end_matches(';').contains(';');
+                RecordData::CAA {
+                    flags: *flags,
+                    tag: tag.clone(),
+                    value: if issuer_only {
+                        value.clone()
+                    } else {
+                        self.text(value)
+                    },
+                }
+            }
+            RecordData::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => RecordData::SOA {
+                mname: self.hostname(mname),
+                rname: self.hostname(rname),
+                serial: *serial,
+                refresh: *refresh,
+                retry: *retry,
+                expire: *expire,
+                minimum: *minimum,
+            },
+        }
+    }
+
+    fn domain_alias(&mut self, name: &str) -> String {
+        self.hashed(AnonymizedKind::Domain, name, SHORT_HASH_LEN, |hash| {
+            format!("example-{hash}.test")
+        })
+    }
+
+    /// 逐个替换子域标签，`*` 与 `_` 开头的服务标签（`_dmarc`、`_tcp`）保留
+    fn labels(&mut self, labels: &str) -> String {
+        labels
+            .split('.')
+            .map(|label| {
+                if label.is_empty() || label == "*" || label.starts_with('_') {
+                    label.to_string()
+                } else {
+                    self.hashed(AnonymizedKind::Label, label, SHORT_HASH_LEN, |hash| {
+                        format!("host-{hash}")
+                    })
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// 带密钥的哈希假名，与已分配的假名冲突时加长哈希
+    fn hashed(
+        &mut self,
+        kind: AnonymizedKind,
+        original: &str,
+        len: usize,
+        render: impl Fn(&str) -> String,
+    ) -> String {
+        if let Some(alias) = self.forward.get(&(kind, original.to_string())) {
+            return alias.clone();
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(self.key);
+        hasher.update(original.as_bytes());
+        let digest = hex::encode(hasher.finalize());
+
+        let mut len = len;
+        let alias = loop {
+            let alias = render(&digest[..len]);
+            if !self.used.contains(&alias) || len >= digest.len() {
+                break alias;
+            }
+            len += 2;
+        };
+        self.remember(kind, original, alias)
+    }
+
+    /// 按类别顺序编号的假名（从 1 开始）
+    fn numbered(
+        &mut self,
+        kind: AnonymizedKind,
+        original: &str,
+        render: impl Fn(u32) -> String,
+    ) -> String {
+        if let Some(alias) = self.forward.get(&(kind, original.to_string())) {
+            return alias.clone();
+        }
+        let counter = self.counters.entry(kind).or_insert(0);
+        *counter += 1;
+        let alias = render(*counter);
+        self.remember(kind, original, alias)
+    }
+
+    fn remember(&mut self, kind: AnonymizedKind, original: &str, alias: String) -> String {
+        self.forward
+            .insert((kind, original.to_string()), alias.clone());
+        self.used.insert(alias.clone());
+        self.entries.push(AnonymizationEntry {
+            kind,
+            original: original.to_string(),
+            pseudonym: alias.clone(),
+        });
+        alias
+    }
+}
+
+/// 第 `n` 个 IPv4 假名（从 1 开始）：先用三个文档保留段，再用基准测试保留段
+fn ipv4_alias(n: u32) -> Ipv4Addr {
+    let index = n.saturating_sub(1);
+    let hosts_per_net = 254;
+    let base = match index / hosts_per_net {
+        0 => IPV4_DOC_NETS[0],
+        1 => IPV4_DOC_NETS[1],
+        2 => IPV4_DOC_NETS[2],
+        _ => {
+            let offset = (index - 3 * hosts_per_net) % IPV4_BENCHMARK_SIZE;
+            return Ipv4Addr::from(IPV4_BENCHMARK_NET + offset);
+        }
+    };
+    Ipv4Addr::from(base + index % hosts_per_net + 1)
+}
+
+/// 字面量的正则：首尾为单词字符时加单词边界，避免替换到更长单词的一部分
+fn word_pattern(literal: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let start = if literal.starts_with(is_word) {
+        r"\b"
+    } else {
+        ""
+    };
+    let end = if literal.ends_with(is_word) {
+        r"\b"
+    } else {
+        ""
+    };
+    format!("{start}{}{end}", regex::escape(literal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, name: &str, data: RecordData) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            domain_id: "zone-1".to_string(),
+            name: name.to_string(),
+            ttl: 600,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_same_value_maps_to_same_pseudonym() {
+        let mut anonymizer = Anonymizer::with_key([7; 32]);
+        let zone = anonymizer.zone("Shop-Internal.com.");
+        assert!(zone.starts_with("example-") && zone.strip_suffix(".test").is_some());
+        assert_eq!(anonymizer.zone("shop-internal.com"), zone);
+
+        // 记录之间的引用关系保留：CNAME 目标与 A 记录名称映射一致
+        let name = anonymizer.record_name("shop-internal.com", "api");
+        let target = anonymizer.hostname("api.shop-internal.com.");
+        assert_eq!(target, format!("{name}.{zone}."));
+        assert_eq!(anonymizer.record_name("shop-internal.com", "@"), "@");
+        assert_eq!(
+            anonymizer.record_name("shop-internal.com", "_dmarc"),
+            "_dmarc"
+        );
+        assert!(anonymizer
+            .record_name("shop-internal.com", "*.api")
+            .starts_with("*.host-"));
+
+        assert_eq!(anonymizer.ip("10.1.2.3"), "192.0.2.1");
+        assert_eq!(anonymizer.ip("10.1.2.4"), "192.0.2.2");
+        assert_eq!(anonymizer.ip("10.1.2.3"), "192.0.2.1");
+        assert_eq!(anonymizer.ip("2400:cb00::1"), "2001:db8::1");
+        assert_eq!(
+            anonymizer.id(AnonymizedKind::AccountId, "acc-x"),
+            "account-1"
+        );
+        assert_eq!(
+            anonymizer.id(AnonymizedKind::AccountId, "acc-x"),
+            "account-1"
+        );
+
+        let mapping = anonymizer.into_mapping();
+        assert!(mapping
+            .iter()
+            .any(|entry| entry.original == "10.1.2.4" && entry.pseudonym == "192.0.2.2"));
+    }
+
+    #[test]
+    fn test_ipv4_pseudonyms_stay_in_reserved_ranges() {
+        assert_eq!(ipv4_alias(254), Ipv4Addr::new(192, 0, 2, 254));
+        assert_eq!(ipv4_alias(255), Ipv4Addr::new(198, 51, 100, 1));
+        assert_eq!(ipv4_alias(762), Ipv4Addr::new(203, 0, 113, 254));
+        assert_eq!(ipv4_alias(763), Ipv4Addr::new(198, 18, 0, 0));
+    }
+
+    #[test]
+    fn test_record_values_are_replaced() {
+        let mut anonymizer = Anonymizer::with_key([1; 32]);
+        anonymizer.zone("acme.io");
+
+        let txt = anonymizer.record(
+            "acme.io",
+            &record(
+                "r1",
+                "@",
+                RecordData::TXT {
+                    text: "v=spf1 ip4:93.184.216.34 -all".to_string(),
+                },
+            ),
+        );
+        assert!(matches!(&txt.data, RecordData::TXT { text } if text.starts_with("sha256:")));
+        assert_eq!(txt.id, "record-1");
+        assert_eq!(txt.domain_id, "domain-1");
+        assert_eq!(txt.ttl, 600);
+
+        let caa = anonymizer.record(
+            "acme.io",
+            &record(
+                "r2",
+                "@",
+                RecordData::CAA {
+                    flags: 0,
+                    tag: "issue".to_string(),
+                    value: "letsencrypt.org".to_string(),
+                },
+            ),
+        );
+        assert!(
+            matches!(caa.data, RecordData::CAA { ref value, .. } if value == "letsencrypt.org")
+        );
+    }
+
+    #[test]
+    fn test_scrub_replaces_known_values_once() {
+        let mut anonymizer = Anonymizer::with_key([3; 32]);
+        let zone = anonymizer.zone("acme.io");
+        let account = anonymizer.id(AnonymizedKind::AccountId, "prod-acc");
+
+        let scrubbed = anonymizer.scrub(
+            "Record www.ACME.io -> 93.184.216.34 failed for prod-acc (HTTP 429) at 12:30:45",
+        );
+        assert!(scrubbed.contains(&format!(".{zone}")));
+        assert!(scrubbed.contains("192.0.2.1"));
+        assert!(scrubbed.contains(&account));
+        assert!(scrubbed.contains("HTTP 429"));
+        assert!(scrubbed.contains("12:30:45"));
+        assert!(!scrubbed.to_lowercase().contains("acme.io"));
+        assert!(!scrubbed.contains("93.184.216.34"));
+    }
+}
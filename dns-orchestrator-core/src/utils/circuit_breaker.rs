@@ -0,0 +1,211 @@
+//! 熔断器
+//!
+//! 服务商整体不可用时，即使有重试，每次操作仍要等完几轮退避才失败，同时持续向服务商发请求。
+//! [`CircuitBreaker`] 按键（通常为账户 ID）统计连续失败次数：
+//! - 连续失败达到阈值后熔断（[`CircuitState::Open`]），熔断期间直接拒绝请求
+//! - 熔断时长过后进入半开（[`CircuitState::HalfOpen`]），只放行一个探测请求：成功则恢复，
+//!   失败则重新熔断
+//!
+//! 哪些结果计为失败由调用方决定，熔断器只负责记录与判断。
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// 默认的熔断阈值（连续失败次数）
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+
+/// 默认的熔断时长
+pub const DEFAULT_OPEN_DURATION: Duration = Duration::from_mins(1);
+
+/// 熔断状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 正常放行
+    Closed,
+    /// 熔断中，`until` 之前拒绝请求
+    Open { until: Instant },
+    /// 熔断时长已过，放行一个探测请求
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: CircuitState,
+    /// 连续失败次数
+    failures: u32,
+    /// 半开状态下探测请求的放行时间
+    probe_started: Option<Instant>,
+}
+
+/// 按键统计连续失败的熔断器
+#[derive(Debug)]
+pub struct CircuitBreaker<K> {
+    failure_threshold: u32,
+    open_duration: Duration,
+    circuits: Mutex<HashMap<K, Circuit>>,
+}
+
+impl<K: Eq + Hash> Default for CircuitBreaker<K> {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, DEFAULT_OPEN_DURATION)
+    }
+}
+
+impl<K: Eq + Hash> CircuitBreaker<K> {
+    /// 连续失败 `failure_threshold` 次后熔断 `open_duration`
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 当前状态（熔断时长已过但尚未放行探测请求时仍为 `Open`）
+    pub fn state<Q>(&self, key: &Q) -> CircuitState
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.circuits
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .map_or(CircuitState::Closed, |circuit| circuit.state)
+    }
+
+    /// 是否放行请求
+    ///
+    /// 熔断时长已过时转为半开并放行一个探测请求。探测请求迟迟没有结果（如调用方超时后放弃）
+    /// 时，再过一个熔断时长后放行下一个探测请求，避免一直停留在半开状态。
+    pub fn try_acquire<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let now = Instant::now();
+        let mut circuits = self.circuits.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(circuit) = circuits.get_mut(key) else {
+            return true;
+        };
+        let allowed = match (circuit.state, circuit.probe_started) {
+            (CircuitState::Closed, _) => return true,
+            (CircuitState::Open { until }, _) => now >= until,
+            (CircuitState::HalfOpen, Some(started)) => now >= started + self.open_duration,
+            (CircuitState::HalfOpen, None) => true,
+        };
+        if allowed {
+            circuit.state = CircuitState::HalfOpen;
+            circuit.probe_started = Some(now);
+        }
+        allowed
+    }
+
+    /// 记录一次成功：恢复放行并清零失败次数
+    pub fn record_success<Q>(&self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ?Sized,
+    {
+        let mut circuits = self.circuits.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(circuit) = circuits.remove(key) {
+            if circuit.state != CircuitState::Closed {
+                log::info!("[CircuitBreaker] Circuit for {key:?} closed");
+            }
+        }
+    }
+
+    /// 记录一次失败：连续失败达到阈值或探测请求失败时熔断
+    pub fn record_failure<Q>(&self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + Debug + ToOwned<Owned = K> + ?Sized,
+    {
+        let now = Instant::now();
+        let mut circuits = self.circuits.lock().unwrap_or_else(PoisonError::into_inner);
+        let circuit = circuits.entry(key.to_owned()).or_insert(Circuit {
+            state: CircuitState::Closed,
+            failures: 0,
+            probe_started: None,
+        });
+        circuit.failures = circuit.failures.saturating_add(1);
+        let trip = match circuit.state {
+            CircuitState::Closed => circuit.failures >= self.failure_threshold,
+            CircuitState::HalfOpen => true,
+            // 熔断前已发出的请求失败，不延长熔断时长
+            CircuitState::Open { .. } => false,
+        };
+        if trip {
+            log::warn!(
+                "[CircuitBreaker] Circuit for {key:?} opened for {:?} after {} consecutive failures",
+                self.open_duration,
+                circuit.failures
+            );
+            circuit.state = CircuitState::Open {
+                until: now + self.open_duration,
+            };
+            circuit.probe_started = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_trips_after_threshold_and_probes_once() {
+        let breaker: CircuitBreaker<String> = CircuitBreaker::new(3, Duration::from_mins(1));
+
+        // 中间有成功时重新计数
+        breaker.record_failure("acc-1");
+        breaker.record_failure("acc-1");
+        breaker.record_success("acc-1");
+        breaker.record_failure("acc-1");
+        breaker.record_failure("acc-1");
+        assert_eq!(breaker.state("acc-1"), CircuitState::Closed);
+
+        breaker.record_failure("acc-1");
+        assert!(matches!(breaker.state("acc-1"), CircuitState::Open { .. }));
+        assert!(!breaker.try_acquire("acc-1"));
+        // 其他账户不受影响
+        assert!(breaker.try_acquire("acc-2"));
+
+        // 熔断时长过后只放行一个探测请求
+        tokio::time::advance(Duration::from_mins(1)).await;
+        assert!(breaker.try_acquire("acc-1"));
+        assert_eq!(breaker.state("acc-1"), CircuitState::HalfOpen);
+        assert!(!breaker.try_acquire("acc-1"));
+
+        // 探测失败重新熔断
+        breaker.record_failure("acc-1");
+        assert!(matches!(breaker.state("acc-1"), CircuitState::Open { .. }));
+
+        // 探测成功恢复
+        tokio::time::advance(Duration::from_mins(1)).await;
+        assert!(breaker.try_acquire("acc-1"));
+        breaker.record_success("acc-1");
+        assert_eq!(breaker.state("acc-1"), CircuitState::Closed);
+        assert!(breaker.try_acquire("acc-1"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_abandoned_probe_is_replaced() {
+        let breaker: CircuitBreaker<String> = CircuitBreaker::new(1, Duration::from_mins(1));
+        breaker.record_failure("acc-1");
+        tokio::time::advance(Duration::from_mins(1)).await;
+        assert!(breaker.try_acquire("acc-1"));
+
+        // 探测请求没有上报结果
+        tokio::time::advance(Duration::from_secs(30)).await;
+        assert!(!breaker.try_acquire("acc-1"));
+        tokio::time::advance(Duration::from_secs(30)).await;
+        assert!(breaker.try_acquire("acc-1"));
+    }
+}
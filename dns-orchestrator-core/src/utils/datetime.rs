@@ -41,9 +41,10 @@ where
             // Unix 时间戳（自动判断秒/毫秒）
             parse_unix_timestamp(ts).ok_or_else(|| Error::custom("Invalid Unix timestamp"))
         }
-        TimestampOrString::U64(ts) => {
-            parse_unix_timestamp(ts as i64).ok_or_else(|| Error::custom("Invalid Unix timestamp"))
-        }
+        TimestampOrString::U64(ts) => i64::try_from(ts)
+            .ok()
+            .and_then(parse_unix_timestamp)
+            .ok_or_else(|| Error::custom("Invalid Unix timestamp")),
     }
 }
 
@@ -82,7 +83,9 @@ pub mod option {
             Some(OptionalTimestamp::I64(ts)) => parse_unix_timestamp(ts)
                 .map(Some)
                 .ok_or_else(|| Error::custom("Invalid Unix timestamp")),
-            Some(OptionalTimestamp::U64(ts)) => parse_unix_timestamp(ts as i64)
+            Some(OptionalTimestamp::U64(ts)) => i64::try_from(ts)
+                .ok()
+                .and_then(parse_unix_timestamp)
                 .map(Some)
                 .ok_or_else(|| Error::custom("Invalid Unix timestamp")),
             None => Ok(None),
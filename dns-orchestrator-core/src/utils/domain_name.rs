@@ -0,0 +1,112 @@
+//! 国际化域名（IDN）规范化
+//!
+//! 用户输入的域名可能含 Unicode 标签（如 `例え.jp`），访问网络或写入服务商前按 UTS #46
+//! 转为 punycode（A-label）。结果同时保留 Unicode 形式，供界面展示原始输入。
+
+use std::net::IpAddr;
+
+use crate::error::{CoreError, CoreResult};
+
+/// 规范化后的域名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainName {
+    /// punycode 形式（小写，不含末尾的点）
+    pub ascii: String,
+    /// Unicode 形式
+    pub unicode: String,
+}
+
+impl DomainName {
+    /// 是否含国际化标签
+    pub fn is_idn(&self) -> bool {
+        self.ascii != self.unicode
+    }
+
+    /// 含国际化标签时返回 Unicode 形式，用于结果中的 `unicode_domain` 字段
+    pub fn unicode_if_idn(&self) -> Option<String> {
+        self.is_idn().then(|| self.unicode.clone())
+    }
+}
+
+/// 规范化域名：去掉首尾空白与末尾的点，Unicode 标签转为 punycode 并转小写
+///
+/// IP 地址原样返回。标签无效（空标签、不合法的 `xn--` 标签、禁止的码点）时返回
+/// [`CoreError::ValidationError`]；`_dmarc`、`*` 等 DNS 中常见的非主机名字符保留。
+pub fn normalize(input: &str) -> CoreResult<DomainName> {
+    let trimmed = input.trim().trim_end_matches('.');
+    if trimmed.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+    if trimmed.parse::<IpAddr>().is_ok() {
+        return Ok(DomainName {
+            ascii: trimmed.to_string(),
+            unicode: trimmed.to_string(),
+        });
+    }
+
+    let invalid = || CoreError::ValidationError(format!("无效的域名: {trimmed}"));
+    let ascii = idna::domain_to_ascii(trimmed).map_err(|_| invalid())?;
+    if ascii.split('.').any(str::is_empty) {
+        return Err(invalid());
+    }
+    let (unicode, result) = idna::domain_to_unicode(&ascii);
+    result.map_err(|_| invalid())?;
+    Ok(DomainName { ascii, unicode })
+}
+
+/// 记录名称中的 Unicode 标签转为 punycode
+///
+/// 记录名称可能是相对名称（`@`、`*.api`）或末尾带点的 FQDN；纯 ASCII 的名称原样返回，
+/// 不转小写，也不去掉末尾的点。
+pub fn normalize_record_name(name: &str) -> CoreResult<String> {
+    if name.is_ascii() {
+        return Ok(name.to_string());
+    }
+    let ascii = normalize(name)?.ascii;
+    Ok(if name.trim().ends_with('.') {
+        format!("{ascii}.")
+    } else {
+        ascii
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_converts_unicode_labels() -> CoreResult<()> {
+        let name = normalize(" 例え.JP. ")?;
+        assert_eq!(name.ascii, "xn--r8jz45g.jp");
+        assert_eq!(name.unicode, "例え.jp");
+        assert_eq!(name.unicode_if_idn().as_deref(), Some("例え.jp"));
+
+        let name = normalize("_DMARC.Example.com")?;
+        assert_eq!(name.ascii, "_dmarc.example.com");
+        assert!(!name.is_idn());
+
+        // 已是 punycode 的输入同样得到 Unicode 形式
+        assert_eq!(normalize("xn--r8jz45g.jp")?.unicode, "例え.jp");
+        assert_eq!(normalize("2001:db8::1")?.ascii, "2001:db8::1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_rejects_invalid_labels() {
+        assert!(normalize("").is_err());
+        assert!(normalize("a..example.com").is_err());
+        assert!(normalize("xn--a.example.com").is_err());
+    }
+
+    #[test]
+    fn test_normalize_record_name_keeps_ascii_names() -> CoreResult<()> {
+        assert_eq!(normalize_record_name("@")?, "@");
+        assert_eq!(normalize_record_name("WWW")?, "WWW");
+        assert_eq!(normalize_record_name("*.例え")?, "*.xn--r8jz45g");
+        assert_eq!(
+            normalize_record_name("例え.example.com.")?,
+            "xn--r8jz45g.example.com."
+        );
+        Ok(())
+    }
+}
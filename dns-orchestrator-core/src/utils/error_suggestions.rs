@@ -0,0 +1,298 @@
+//! 错误修复建议
+//!
+//! 错误码到修复建议的映射表维护在 `error_suggestions.json` 中，随版本更新。规则按错误码匹配，
+//! 可再按服务商、参数名（`InvalidParameter`）或服务商原始错误码（`Unknown`）细化，多条规则
+//! 命中时取限定条件最多的一条。建议只包含前端 i18n 键，本模块的测试保证键在语言资源中存在。
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::ErrorSuggestion;
+
+/// 嵌入错误建议映射表
+const ERROR_SUGGESTIONS: &str = include_str!("error_suggestions.json");
+
+#[derive(Debug, Deserialize)]
+struct SuggestionTable {
+    rules: Vec<SuggestionRule>,
+}
+
+/// 映射规则：`code` 必须相同，其余条件为空时不限制
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SuggestionRule {
+    code: String,
+    provider: Option<String>,
+    param: Option<String>,
+    raw_code: Option<String>,
+    suggestions: Vec<ErrorSuggestion>,
+}
+
+impl SuggestionRule {
+    /// 命中时返回具体程度（限定条件越多越具体），未命中返回 `None`
+    fn specificity(&self, key: &ErrorKey<'_>) -> Option<u8> {
+        if self.code != key.code {
+            return None;
+        }
+        let conditions = [
+            (&self.provider, key.provider, 1),
+            (&self.param, key.param, 2),
+            (&self.raw_code, key.raw_code, 4),
+        ];
+        let mut score = 0;
+        for (expected, actual, weight) in conditions {
+            if let Some(expected) = expected {
+                if actual != Some(expected.as_str()) {
+                    return None;
+                }
+                score += weight;
+            }
+        }
+        Some(score)
+    }
+}
+
+/// 从错误的序列化结果中提取的匹配条件
+#[derive(Debug, Default)]
+struct ErrorKey<'a> {
+    code: &'a str,
+    provider: Option<&'a str>,
+    param: Option<&'a str>,
+    raw_code: Option<&'a str>,
+}
+
+impl<'a> ErrorKey<'a> {
+    /// 错误按 `{ code, details }` 序列化，服务商错误（`Provider`）取 `details` 中的错误码
+    fn from_value(value: &'a Value) -> Option<Self> {
+        let code = value.get("code")?.as_str()?;
+        let details = value.get("details");
+        if code == "Provider" {
+            return Some(Self {
+                code: str_field(details, "code")?,
+                provider: str_field(details, "provider"),
+                param: str_field(details, "param"),
+                raw_code: str_field(details, "raw_code"),
+            });
+        }
+        Some(Self {
+            code,
+            provider: str_field(details, "provider"),
+            ..Self::default()
+        })
+    }
+}
+
+fn str_field<'a>(details: Option<&'a Value>, name: &str) -> Option<&'a str> {
+    details?.get(name)?.as_str()
+}
+
+fn load_rules() -> Vec<SuggestionRule> {
+    match serde_json::from_str::<SuggestionTable>(ERROR_SUGGESTIONS) {
+        Ok(table) => table.rules,
+        Err(e) => {
+            log::warn!("[ErrorSuggestions] Invalid suggestion table: {e}");
+            Vec::new()
+        }
+    }
+}
+
+/// 查找错误对应的修复建议，没有匹配的规则时为空
+///
+/// `error` 须按 `{ code, details }` 序列化，如 [`CoreError`](crate::CoreError)
+/// 或应用层与之同构的错误类型。
+pub fn suggestions_for<E: Serialize + ?Sized>(error: &E) -> Vec<ErrorSuggestion> {
+    let Ok(value) = serde_json::to_value(error) else {
+        return Vec::new();
+    };
+    let Some(key) = ErrorKey::from_value(&value) else {
+        return Vec::new();
+    };
+
+    let mut best: Option<(u8, SuggestionRule)> = None;
+    for rule in load_rules() {
+        let Some(score) = rule.specificity(&key) else {
+            continue;
+        };
+        if !matches!(best, Some((best_score, _)) if best_score >= score) {
+            best = Some((score, rule));
+        }
+    }
+    best.map(|(_, rule)| rule.suggestions).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io;
+    use std::path::Path;
+
+    use super::*;
+    use crate::error::{CoreError, ProviderError};
+
+    fn action_keys(error: &CoreError) -> Vec<String> {
+        suggestions_for(error)
+            .into_iter()
+            .map(|suggestion| suggestion.action_key)
+            .collect()
+    }
+
+    /// 在 TS 语言资源中按点分路径查找键
+    ///
+    /// 只识别资源文件 `key: { ... }` / `key: "..."` 的写法；插值 `{{ }}` 成对出现，不影响层级计数。
+    fn locale_has_key(source: &str, path: &str) -> bool {
+        let Some(start) = source.find('{') else {
+            return false;
+        };
+        let mut scope = &source[start + 1..];
+        for segment in path.split('.') {
+            match entry(scope, segment) {
+                Some(value) => scope = value,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// 在 `scope` 最外层查找 `name`：对象返回其内部文本，叶子返回空串
+    fn entry<'a>(scope: &'a str, name: &str) -> Option<&'a str> {
+        let prefix = format!("{name}:");
+        let mut depth = 0i32;
+        let mut offset = 0;
+        for line in scope.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            if depth == 0 && trimmed.starts_with(&prefix) {
+                if !trimmed[prefix.len()..].trim_start().starts_with('{') {
+                    return Some("");
+                }
+                return Some(&scope[offset + line.find('{')? + 1..]);
+            }
+            for ch in line.chars() {
+                match ch {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+            }
+            if depth < 0 {
+                return None;
+            }
+            offset += line.len();
+        }
+        None
+    }
+
+    #[test]
+    fn test_most_specific_rule_wins() {
+        let quota = |provider: &str| {
+            CoreError::from(ProviderError::QuotaExceeded {
+                provider: provider.to_string(),
+                raw_message: None,
+            })
+        };
+        assert_eq!(
+            action_keys(&quota("dnspod")),
+            [
+                "errors.suggestions.reduce_concurrency.action",
+                "errors.suggestions.check_plan_quota.action"
+            ]
+        );
+
+        let ttl = CoreError::from(ProviderError::InvalidParameter {
+            provider: "aliyun".to_string(),
+            param: "ttl".to_string(),
+            detail: "TTL too small".to_string(),
+        });
+        assert_eq!(
+            action_keys(&ttl),
+            ["errors.suggestions.ttl_out_of_range.action"]
+        );
+        let proxied = |provider: &str| {
+            CoreError::from(ProviderError::InvalidParameter {
+                provider: provider.to_string(),
+                param: "proxied".to_string(),
+                detail: "cannot be proxied".to_string(),
+            })
+        };
+        assert_eq!(
+            action_keys(&proxied("cloudflare")),
+            ["errors.suggestions.disable_proxy.action"]
+        );
+        assert_eq!(
+            action_keys(&proxied("aliyun")),
+            ["errors.suggestions.check_record_value.action"]
+        );
+
+        // 未映射的原始错误码按服务商细化
+        let throttled = CoreError::from(ProviderError::Unknown {
+            provider: "aliyun".to_string(),
+            raw_code: Some("Throttling.Api".to_string()),
+            raw_message: "Request was denied due to api flow control".to_string(),
+        });
+        assert_eq!(
+            action_keys(&throttled),
+            ["errors.suggestions.reduce_concurrency.action"]
+        );
+
+        let busy = CoreError::ResourceBusy {
+            resource: "acc/example.com".to_string(),
+            holder: "update_record".to_string(),
+            timeout_ms: 5000,
+        };
+        assert_eq!(
+            action_keys(&busy),
+            ["errors.suggestions.retry_later.action"]
+        );
+        assert!(suggestions_for(&CoreError::NoAccountsSelected).is_empty());
+    }
+
+    #[test]
+    fn test_provider_rules_carry_console_links() {
+        let expired = CoreError::from(ProviderError::InvalidCredentials {
+            provider: "cloudflare".to_string(),
+            raw_message: None,
+        });
+        let suggestions = suggestions_for(&expired);
+        assert_eq!(suggestions.len(), 1);
+        assert!(suggestions[0].link.is_some());
+
+        let generic = CoreError::InvalidCredentials("acc".to_string());
+        assert!(suggestions_for(&generic)
+            .iter()
+            .all(|suggestion| suggestion.link.is_none()));
+    }
+
+    #[test]
+    fn test_suggestion_keys_exist_in_locales() -> io::Result<()> {
+        let rules = serde_json::from_str::<SuggestionTable>(ERROR_SUGGESTIONS)
+            .map_err(io::Error::other)?
+            .rules;
+        assert!(!rules.is_empty());
+
+        let locales = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/i18n/locales");
+        for locale in ["en-US.ts", "zh-CN.ts"] {
+            let source = fs::read_to_string(locales.join(locale))?;
+            assert!(locale_has_key(
+                &source,
+                "errors.provider.common.quota_exceeded"
+            ));
+            assert!(!locale_has_key(
+                &source,
+                "errors.suggestions.missing.action"
+            ));
+
+            for rule in &rules {
+                assert!(
+                    !rule.suggestions.is_empty(),
+                    "rule for {} has no suggestions",
+                    rule.code
+                );
+                for suggestion in &rule.suggestions {
+                    for key in [&suggestion.action_key, &suggestion.description_key] {
+                        assert!(locale_has_key(&source, key), "{locale} is missing {key}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
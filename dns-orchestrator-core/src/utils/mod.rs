@@ -1,3 +1,7 @@
 //! 工具模块
 
+pub mod anonymize;
+pub mod circuit_breaker;
 pub mod datetime;
+pub mod domain_name;
+pub mod error_suggestions;
@@ -41,12 +41,25 @@ pub enum ProviderError {
         record_type: String,
     },
 
+    /// Provider 不支持该能力（如权重解析）
+    UnsupportedCapability {
+        provider: String,
+        capability: String,
+    },
+
     /// 配额超限
     QuotaExceeded {
         provider: String,
         raw_message: Option<String>,
     },
 
+    /// 服务商服务端错误（HTTP 5xx），通常是暂时性的
+    HttpError {
+        provider: String,
+        status: u16,
+        raw_message: Option<String>,
+    },
+
     /// 域名不存在
     DomainNotFound {
         provider: String,
@@ -124,9 +137,26 @@ impl std::fmt::Display for ProviderError {
             } => {
                 write!(f, "[{provider}] Unsupported record type: {record_type}")
             }
+            Self::UnsupportedCapability {
+                provider,
+                capability,
+            } => {
+                write!(f, "[{provider}] Unsupported capability: {capability}")
+            }
             Self::QuotaExceeded { provider, .. } => {
                 write!(f, "[{provider}] Quota exceeded")
             }
+            Self::HttpError {
+                provider,
+                status,
+                raw_message,
+            } => {
+                if let Some(msg) = raw_message {
+                    write!(f, "[{provider}] HTTP {status}: {msg}")
+                } else {
+                    write!(f, "[{provider}] HTTP {status}")
+                }
+            }
             Self::DomainNotFound {
                 provider,
                 domain,
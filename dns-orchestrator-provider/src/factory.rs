@@ -10,10 +10,16 @@ use crate::types::{ProviderCredentials, ProviderMetadata};
 use crate::providers::AliyunProvider;
 #[cfg(feature = "cloudflare")]
 use crate::providers::CloudflareProvider;
+#[cfg(feature = "digitalocean")]
+use crate::providers::DigitalOceanProvider;
 #[cfg(feature = "dnspod")]
 use crate::providers::DnspodProvider;
+#[cfg(feature = "gcp-dns")]
+use crate::providers::GcpDnsProvider;
 #[cfg(feature = "huaweicloud")]
 use crate::providers::HuaweicloudProvider;
+#[cfg(feature = "route53")]
+use crate::providers::Route53Provider;
 
 /// 工厂函数 - 根据凭证类型创建 Provider 实例
 pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsProvider>> {
@@ -26,10 +32,12 @@ pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsPr
         ProviderCredentials::Aliyun {
             access_key_id,
             access_key_secret,
-        } => Ok(Arc::new(AliyunProvider::new(
-            access_key_id,
-            access_key_secret,
-        ))),
+            api_variant,
+        } => Ok(Arc::new(
+            AliyunProvider::builder(access_key_id, access_key_secret)
+                .api_variant(api_variant.unwrap_or_default())
+                .build(),
+        )),
         #[cfg(feature = "dnspod")]
         ProviderCredentials::Dnspod {
             secret_id,
@@ -43,6 +51,29 @@ pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsPr
             access_key_id,
             secret_access_key,
         ))),
+        #[cfg(feature = "route53")]
+        ProviderCredentials::AwsRoute53 {
+            access_key_id,
+            secret_access_key,
+            region,
+            role_arn,
+        } => Ok(Arc::new(
+            Route53Provider::builder(access_key_id, secret_access_key, region)
+                .role_arn(role_arn)
+                .build(),
+        )),
+        #[cfg(feature = "gcp-dns")]
+        ProviderCredentials::GcpDns {
+            project_id,
+            service_account_json,
+        } => Ok(Arc::new(GcpDnsProvider::new(
+            project_id,
+            service_account_json,
+        ))),
+        #[cfg(feature = "digitalocean")]
+        ProviderCredentials::DigitalOcean { token } => {
+            Ok(Arc::new(DigitalOceanProvider::new(token)))
+        }
     }
 }
 
@@ -57,5 +88,11 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
         DnspodProvider::metadata(),
         #[cfg(feature = "huaweicloud")]
         HuaweicloudProvider::metadata(),
+        #[cfg(feature = "route53")]
+        Route53Provider::metadata(),
+        #[cfg(feature = "gcp-dns")]
+        GcpDnsProvider::metadata(),
+        #[cfg(feature = "digitalocean")]
+        DigitalOceanProvider::metadata(),
     ]
 }
@@ -31,6 +31,8 @@ impl HttpUtils {
     /// # Returns
     /// * `Ok((status_code, response_text))` - 成功时返回状态码和响应文本
     /// * `Err(ProviderError::NetworkError)` - 网络错误
+    /// * `Err(ProviderError::QuotaExceeded)` - 被限流（HTTP 429）
+    /// * `Err(ProviderError::HttpError)` - 服务端错误（HTTP 5xx）
     pub async fn execute_request(
         request_builder: RequestBuilder,
         provider_name: &str,
@@ -51,6 +53,21 @@ impl HttpUtils {
         let status_code = response.status().as_u16();
         log::debug!("[{}] Response Status: {}", provider_name, status_code);
 
+        // 被限流时不解析响应体，统一返回配额错误并附带 Retry-After
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok());
+            log::warn!(
+                "[{}] Rate limited: {} {}",
+                provider_name,
+                method_name,
+                url_or_action
+            );
+            return Err(rate_limited_error(provider_name, retry_after));
+        }
+
         // 读取响应体
         let response_text = response
             .text()
@@ -62,6 +79,18 @@ impl HttpUtils {
 
         log::debug!("[{}] Response Body: {}", provider_name, response_text);
 
+        // 5xx 的响应体多为网关错误页，不交给各 Provider 解析
+        if (500..600).contains(&status_code) {
+            log::warn!(
+                "[{}] Server error {}: {} {}",
+                provider_name,
+                status_code,
+                method_name,
+                url_or_action
+            );
+            return Err(server_error(provider_name, status_code, &response_text));
+        }
+
         Ok((status_code, response_text))
     }
 
@@ -189,6 +218,31 @@ impl HttpUtils {
     }
 }
 
+/// HTTP 429 对应的配额错误，`Retry-After` 为秒数或 HTTP 日期
+fn rate_limited_error(provider_name: &str, retry_after: Option<&str>) -> ProviderError {
+    let message = match retry_after.map(str::trim) {
+        Some(seconds) if seconds.parse::<u64>().is_ok() => {
+            format!("Rate limited (HTTP 429), retry after {seconds}s")
+        }
+        Some(date) if !date.is_empty() => format!("Rate limited (HTTP 429), retry after {date}"),
+        _ => "Rate limited (HTTP 429)".to_string(),
+    };
+    ProviderError::QuotaExceeded {
+        provider: provider_name.to_string(),
+        raw_message: Some(message),
+    }
+}
+
+/// HTTP 5xx 对应的错误，响应体截取前 200 个字符
+fn server_error(provider_name: &str, status: u16, body: &str) -> ProviderError {
+    let body = body.trim();
+    ProviderError::HttpError {
+        provider: provider_name.to_string(),
+        status,
+        raw_message: (!body.is_empty()).then(|| body.chars().take(200).collect()),
+    }
+}
+
 /// 判断错误是否可重试
 ///
 /// 只有网络错误才适合重试，业务错误（如认证失败、记录不存在）不应重试
@@ -205,3 +259,47 @@ fn backoff_delay(attempt: u32) -> Duration {
     let delay_ms = delay_ms.min(10_000); // 最大 10 秒
     Duration::from_millis(delay_ms)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_message(error: ProviderError) -> Option<String> {
+        match error {
+            ProviderError::QuotaExceeded { raw_message, .. }
+            | ProviderError::HttpError { raw_message, .. } => raw_message,
+            other => unreachable!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rate_limited_error_includes_retry_after() {
+        assert_eq!(
+            raw_message(rate_limited_error("cloudflare", Some("30"))).as_deref(),
+            Some("Rate limited (HTTP 429), retry after 30s")
+        );
+        assert_eq!(
+            raw_message(rate_limited_error(
+                "cloudflare",
+                Some("Wed, 21 Oct 2026 07:28:00 GMT")
+            ))
+            .as_deref(),
+            Some("Rate limited (HTTP 429), retry after Wed, 21 Oct 2026 07:28:00 GMT")
+        );
+        assert_eq!(
+            raw_message(rate_limited_error("cloudflare", None)).as_deref(),
+            Some("Rate limited (HTTP 429)")
+        );
+    }
+
+    #[test]
+    fn server_error_keeps_status_and_truncates_body() {
+        let error = server_error("route53", 503, &"x".repeat(500));
+        assert!(matches!(
+            error,
+            ProviderError::HttpError { status: 503, .. }
+        ));
+        assert_eq!(raw_message(error).map(|m| m.len()), Some(200));
+        assert_eq!(raw_message(server_error("route53", 502, "  ")), None);
+    }
+}
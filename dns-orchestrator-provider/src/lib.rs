@@ -1,7 +1,8 @@
 //! DNS Provider abstraction library for multiple cloud platforms
 //!
 //! This library provides a unified interface for managing DNS records across
-//! different cloud providers including Cloudflare, Aliyun, DNSPod, and Huaweicloud.
+//! different cloud providers including Cloudflare, Aliyun, DNSPod, Huaweicloud, AWS Route53,
+//! Google Cloud DNS, and DigitalOcean.
 //!
 //! # Features
 //!
@@ -9,6 +10,9 @@
 //! - `aliyun` - Enable Aliyun DNS provider
 //! - `dnspod` - Enable Tencent Cloud DNSPod provider
 //! - `huaweicloud` - Enable Huawei Cloud DNS provider
+//! - `route53` - Enable AWS Route53 provider
+//! - `gcp-dns` - Enable Google Cloud DNS provider
+//! - `digitalocean` - Enable DigitalOcean DNS provider
 //! - `all-providers` - Enable all providers
 //! - `native-tls` - Use native TLS backend (default)
 //! - `rustls` - Use rustls TLS backend (recommended for Android)
@@ -35,6 +39,7 @@
 //! }
 //! ```
 
+mod credential_format;
 mod error;
 mod factory;
 mod http_client;
@@ -55,15 +60,19 @@ pub use traits::DnsProvider;
 // Re-export types
 pub use types::{
     BatchCreateFailure, BatchCreateResult, BatchDeleteFailure, BatchDeleteResult,
-    BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CreateDnsRecordRequest,
-    CredentialValidationError, DnsRecord, DnsRecordType, DomainStatus, FieldType,
-    PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderCredentials,
-    ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata, ProviderType, RecordData,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CapabilityProbe, ChangeResult,
+    CreateDnsRecordRequest, CredentialValidationError, DnsRecord, DnsRecordType, DomainStatus,
+    FieldOption, FieldType, PaginatedResponse, PaginationParams, ProviderCredentialField,
+    ProviderCredentials, ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata,
+    ProviderType, RecordChange, RecordData, RecordQueryParams, RecordSet, RecordSort,
+    RecordSortField, STANDARD_RECORD_TYPES, SortOrder, TxtFormat, UpdateDnsRecordRequest,
 };
 
+#[cfg(feature = "aliyun")]
+pub use types::AliyunApiVariant;
+
 // Re-export utils module
-pub use utils::datetime;
+pub use utils::{datetime, txt};
 
 // Re-export concrete providers (behind feature flags)
 #[cfg(feature = "cloudflare")]
@@ -77,3 +86,12 @@ pub use providers::DnspodProvider;
 
 #[cfg(feature = "huaweicloud")]
 pub use providers::HuaweicloudProvider;
+
+#[cfg(feature = "route53")]
+pub use providers::Route53Provider;
+
+#[cfg(feature = "gcp-dns")]
+pub use providers::GcpDnsProvider;
+
+#[cfg(feature = "digitalocean")]
+pub use providers::DigitalOceanProvider;
@@ -0,0 +1,216 @@
+//! 阿里云 API 站点变体兼容层
+//!
+//! 中国站与国际站的 API 在端点、个别字段名和枚举值上存在差异。
+//! 每个变体对应一份 `VariantProfile`（纯数据），请求/响应在进出时按映射表归一化为中国站格式，
+//! 因此 provider 的其余实现只需要面向一套字段。新增变体时只需增加一份 profile。
+
+use serde_json::Value;
+
+use crate::types::AliyunApiVariant;
+
+/// 值转换函数（在字段名归一化之后执行）
+pub(crate) type ValueConverter = fn(&mut Value);
+
+/// 站点变体配置
+pub(crate) struct VariantProfile {
+    /// API 端点
+    pub host: &'static str,
+    /// 请求参数名映射：(标准名, 变体名)
+    pub request_fields: &'static [(&'static str, &'static str)],
+    /// 响应字段名映射：(变体名, 标准名)
+    pub response_fields: &'static [(&'static str, &'static str)],
+    /// 响应枚举值映射：(字段名, 变体值, 标准值)，字段名为归一化后的标准名
+    pub response_values: &'static [(&'static str, &'static str, &'static str)],
+    /// 显式注册的转换函数，按顺序作用于整个响应
+    pub converters: &'static [ValueConverter],
+}
+
+/// 中国站（标准格式，无需转换）
+const CN_PROFILE: VariantProfile = VariantProfile {
+    host: "alidns.cn-hangzhou.aliyuncs.com",
+    request_fields: &[],
+    response_fields: &[],
+    response_values: &[],
+    converters: &[],
+};
+
+/// 国际站
+const INTL_PROFILE: VariantProfile = VariantProfile {
+    host: "alidns.ap-southeast-1.aliyuncs.com",
+    request_fields: &[],
+    response_fields: &[("Ttl", "TTL")],
+    response_values: &[
+        ("DomainStatus", "Enable", "ENABLE"),
+        ("DomainStatus", "Pause", "PAUSE"),
+        ("DomainStatus", "Spam", "SPAM"),
+    ],
+    converters: &[numeric_strings_to_numbers],
+};
+
+/// 获取变体对应的配置
+pub(crate) fn profile(variant: AliyunApiVariant) -> &'static VariantProfile {
+    match variant {
+        AliyunApiVariant::Cn => &CN_PROFILE,
+        AliyunApiVariant::Intl => &INTL_PROFILE,
+    }
+}
+
+impl VariantProfile {
+    /// 将标准请求参数转换为变体格式（仅处理顶层参数）
+    pub fn adapt_request(&self, mut params: Value) -> Value {
+        if let Value::Object(map) = &mut params {
+            for (standard, variant) in self.request_fields {
+                if let Some(v) = map.remove(*standard) {
+                    map.insert((*variant).to_string(), v);
+                }
+            }
+        }
+        params
+    }
+
+    /// 将变体响应归一化为标准格式
+    pub fn normalize_response(&self, mut value: Value) -> Value {
+        if !self.response_fields.is_empty() || !self.response_values.is_empty() {
+            self.normalize_value(&mut value);
+        }
+        for converter in self.converters {
+            converter(&mut value);
+        }
+        value
+    }
+
+    fn normalize_value(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (variant, standard) in self.response_fields {
+                    // 标准字段已存在时不覆盖
+                    if !map.contains_key(*standard)
+                        && let Some(v) = map.remove(*variant)
+                    {
+                        map.insert((*standard).to_string(), v);
+                    }
+                }
+                for (field, variant, standard) in self.response_values {
+                    if let Some(v) = map.get_mut(*field)
+                        && v.as_str() == Some(*variant)
+                    {
+                        *v = Value::String((*standard).to_string());
+                    }
+                }
+                for v in map.values_mut() {
+                    self.normalize_value(v);
+                }
+            }
+            Value::Array(arr) => {
+                for v in arr {
+                    self.normalize_value(v);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 需要为数字类型的字段（国际站部分接口以字符串形式返回）
+const NUMERIC_FIELDS: &[&str] = &[
+    "TTL",
+    "Priority",
+    "TotalCount",
+    "RecordCount",
+    "PageNumber",
+    "PageSize",
+    "CreateTimestamp",
+    "UpdateTimestamp",
+];
+
+/// 将字符串形式的数字字段转换为数字
+fn numeric_strings_to_numbers(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                if NUMERIC_FIELDS.contains(&k.as_str())
+                    && let Some(n) = v.as_str().and_then(|s| s.trim().parse::<i64>().ok())
+                {
+                    *v = Value::from(n);
+                } else {
+                    numeric_strings_to_numbers(v);
+                }
+            }
+        }
+        Value::Array(arr) => arr.iter_mut().for_each(numeric_strings_to_numbers),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::aliyun::{DescribeDomainRecordsResponse, DescribeDomainsResponse};
+
+    const CN_DOMAINS: &str =
+        include_str!("../../../tests/fixtures/aliyun/cn_describe_domains.json");
+    const INTL_DOMAINS: &str =
+        include_str!("../../../tests/fixtures/aliyun/intl_describe_domains.json");
+    const CN_RECORDS: &str =
+        include_str!("../../../tests/fixtures/aliyun/cn_describe_domain_records.json");
+    const INTL_RECORDS: &str =
+        include_str!("../../../tests/fixtures/aliyun/intl_describe_domain_records.json");
+
+    fn parse<T: serde::de::DeserializeOwned>(variant: AliyunApiVariant, raw: &str) -> T {
+        let value = serde_json::from_str(raw).unwrap();
+        serde_json::from_value(profile(variant).normalize_response(value)).unwrap()
+    }
+
+    fn assert_domains(resp: &DescribeDomainsResponse) {
+        assert_eq!(resp.total_count, Some(2));
+        let domains = resp.domains.as_ref().unwrap().domain.as_ref().unwrap();
+        assert_eq!(domains[0].domain_name, "example.com");
+        assert_eq!(domains[0].domain_status.as_deref(), Some("ENABLE"));
+        assert_eq!(domains[0].record_count, Some(5));
+        assert_eq!(domains[1].domain_status.as_deref(), Some("PAUSE"));
+    }
+
+    fn assert_records(resp: &DescribeDomainRecordsResponse) {
+        assert_eq!(resp.total_count, Some(2));
+        let records = resp
+            .domain_records
+            .as_ref()
+            .unwrap()
+            .record
+            .as_ref()
+            .unwrap();
+        assert_eq!(records[0].rr, "www");
+        assert_eq!(records[0].record_type, "A");
+        assert_eq!(records[0].ttl, 600);
+        assert_eq!(records[0].create_timestamp, Some(1_700_000_000_000));
+        assert_eq!(records[1].record_type, "MX");
+        assert_eq!(records[1].priority, Some(10));
+    }
+
+    #[test]
+    fn cn_fixtures_parse() {
+        assert_domains(&parse(AliyunApiVariant::Cn, CN_DOMAINS));
+        assert_records(&parse(AliyunApiVariant::Cn, CN_RECORDS));
+    }
+
+    #[test]
+    fn intl_fixtures_parse() {
+        assert_domains(&parse(AliyunApiVariant::Intl, INTL_DOMAINS));
+        assert_records(&parse(AliyunApiVariant::Intl, INTL_RECORDS));
+    }
+
+    #[test]
+    fn request_fields_are_renamed() {
+        const PROFILE: VariantProfile = VariantProfile {
+            host: "",
+            request_fields: &[("RRKeyWord", "KeyWord")],
+            response_fields: &[],
+            response_values: &[],
+            converters: &[],
+        };
+        let params = serde_json::json!({ "DomainName": "example.com", "RRKeyWord": "www" });
+        let adapted = PROFILE.adapt_request(params);
+        assert_eq!(adapted["KeyWord"], "www");
+        assert!(adapted.get("RRKeyWord").is_none());
+    }
+}
@@ -3,12 +3,13 @@
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
+use crate::error::{ProviderError, Result};
 use crate::http_client::HttpUtils;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
+use super::compat::profile;
 use super::{
-    ALIYUN_DNS_HOST, ALIYUN_DNS_VERSION, AliyunProvider, AliyunResponse, EMPTY_BODY_SHA256,
+    ALIYUN_DNS_VERSION, AliyunProvider, AliyunResponse, EMPTY_BODY_SHA256,
     serialize_to_query_string,
 };
 
@@ -20,27 +21,35 @@ impl AliyunProvider {
         params: &B,
         ctx: ErrorContext,
     ) -> Result<T> {
-        // 1. 序列化参数为 query string
-        let query_string = serialize_to_query_string(params)?;
+        let profile = profile(self.api_variant);
+        let host = profile.host;
+
+        // 1. 按站点变体转换参数名，序列化为 query string
+        let params =
+            serde_json::to_value(params).map_err(|e| ProviderError::SerializationError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            })?;
+        let query_string = serialize_to_query_string(&profile.adapt_request(params))?;
 
         let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
         let nonce = uuid::Uuid::new_v4().to_string();
 
         // 2. 生成签名 (使用 query string)
-        let authorization = self.sign(action, &query_string, &timestamp, &nonce);
+        let authorization = self.sign(host, action, &query_string, &timestamp, &nonce);
 
         // 3. 构造 URL (参数在 query string 中)
         let url = if query_string.is_empty() {
-            format!("https://{ALIYUN_DNS_HOST}/")
+            format!("https://{host}/")
         } else {
-            format!("https://{ALIYUN_DNS_HOST}/?{query_string}")
+            format!("https://{host}/?{query_string}")
         };
 
         // 4. 发送请求 (body 为空，使用 HttpUtils)
         let request = self
             .client
             .post(&url)
-            .header("Host", ALIYUN_DNS_HOST)
+            .header("Host", host)
             .header("x-acs-action", action)
             .header("x-acs-version", ALIYUN_DNS_VERSION)
             .header("x-acs-date", &timestamp)
@@ -65,7 +74,15 @@ impl AliyunProvider {
             return Err(self.map_error(RawApiError::with_code(&code, &message), ctx));
         }
 
-        // 6. 解析成功响应
-        HttpUtils::parse_json(&response_text, self.provider_name())
+        // 6. 解析成功响应（先归一化为标准字段格式）
+        let value: serde_json::Value = HttpUtils::parse_json(&response_text, self.provider_name())?;
+        serde_json::from_value(profile.normalize_response(value)).map_err(|e| {
+            log::error!("[{}] JSON 解析失败: {}", self.provider_name(), e);
+            log::error!("[{}] 原始响应: {}", self.provider_name(), response_text);
+            ProviderError::ParseError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            }
+        })
     }
 }
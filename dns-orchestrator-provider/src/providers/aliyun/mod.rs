@@ -1,5 +1,6 @@
 //! 阿里云 DNS Provider
 
+mod compat;
 mod error;
 mod http;
 mod provider;
@@ -9,14 +10,15 @@ mod types;
 use reqwest::Client;
 
 use crate::providers::common::create_http_client;
+use crate::types::AliyunApiVariant;
 
 pub(crate) use types::{
     AddDomainRecordResponse, AliyunResponse, DeleteDomainRecordResponse,
     DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
-    UpdateDomainRecordResponse, serialize_to_query_string,
+    SetDnsSlbStatusResponse, UpdateDnsSlbWeightResponse, UpdateDomainRecordResponse,
+    serialize_to_query_string,
 };
 
-pub(crate) const ALIYUN_DNS_HOST: &str = "alidns.cn-hangzhou.aliyuncs.com";
 pub(crate) const ALIYUN_DNS_VERSION: &str = "2015-01-09";
 /// 空 body 的 SHA256 hash (固定值)
 pub(crate) const EMPTY_BODY_SHA256: &str =
@@ -29,6 +31,7 @@ pub struct AliyunProvider {
     pub(crate) client: Client,
     pub(crate) access_key_id: String,
     pub(crate) access_key_secret: String,
+    pub(crate) api_variant: AliyunApiVariant,
     pub(crate) max_retries: u32,
 }
 
@@ -36,6 +39,7 @@ pub struct AliyunProvider {
 pub struct AliyunProviderBuilder {
     access_key_id: String,
     access_key_secret: String,
+    api_variant: AliyunApiVariant,
     max_retries: u32,
 }
 
@@ -44,6 +48,7 @@ impl AliyunProviderBuilder {
         Self {
             access_key_id,
             access_key_secret,
+            api_variant: AliyunApiVariant::default(),
             max_retries: 2,
         }
     }
@@ -53,11 +58,18 @@ impl AliyunProviderBuilder {
         self
     }
 
+    /// 设置 API 站点变体（中国站 / 国际站）
+    pub fn api_variant(mut self, variant: AliyunApiVariant) -> Self {
+        self.api_variant = variant;
+        self
+    }
+
     pub fn build(self) -> AliyunProvider {
         AliyunProvider {
             client: create_http_client(),
             access_key_id: self.access_key_id,
             access_key_secret: self.access_key_secret,
+            api_variant: self.api_variant,
             max_retries: self.max_retries,
         }
     }
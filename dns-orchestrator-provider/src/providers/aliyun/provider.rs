@@ -4,30 +4,44 @@ use async_trait::async_trait;
 use chrono::DateTime;
 use serde::Serialize;
 
+use crate::credential_format::{ALIYUN_ACCESS_KEY_ID, ALIYUN_ACCESS_KEY_SECRET};
 use crate::error::{ProviderError, Result};
-use crate::providers::common::record_type_to_string;
+use crate::providers::common::{
+    KEYWORD_SPECIAL_CHARS, LiteralKeyword, list_records_by_keyword, record_type_to_string,
+};
 use crate::traits::{DnsProvider, ErrorContext};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    AliyunApiVariant, CapabilityProbe, CreateDnsRecordRequest, DnsRecord, DomainStatus,
+    FieldOption, FieldType, PaginatedResponse, PaginationParams, ProviderCredentialField,
+    ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata, ProviderType, RecordData,
+    RecordQueryParams, STANDARD_RECORD_TYPES, TxtFormat, UpdateDnsRecordRequest,
 };
 
 use super::{
     AddDomainRecordResponse, AliyunProvider, DeleteDomainRecordResponse,
     DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
-    MAX_PAGE_SIZE, UpdateDomainRecordResponse,
+    MAX_PAGE_SIZE, SetDnsSlbStatusResponse, UpdateDnsSlbWeightResponse, UpdateDomainRecordResponse,
 };
 
+/// 免费版套餐的版本编码
+const FREE_VERSION_CODE: &str = "mianfei";
+
 impl AliyunProvider {
     /// 将阿里云域名状态转换为内部状态
+    /// 阿里云状态：ENABLE, PAUSE, SPAM, LOCK；付费实例过期由 `InstanceExpired` 单独标识
     /// 注意：阿里云 `DescribeDomains` API 实际上不返回 `DomainStatus` 字段
-    pub(crate) fn convert_domain_status(status: Option<&str>) -> DomainStatus {
-        match status {
-            Some("ENABLE" | "enable") => DomainStatus::Active,
-            Some("PAUSE" | "pause") => DomainStatus::Paused,
-            Some("SPAM" | "spam") => DomainStatus::Error,
-            _ => DomainStatus::Unknown,
+    pub(crate) fn convert_domain_status(
+        status: Option<&str>,
+        instance_expired: Option<bool>,
+    ) -> DomainStatus {
+        if instance_expired == Some(true) {
+            return DomainStatus::Expired;
+        }
+        match status.map(str::to_ascii_uppercase).as_deref() {
+            Some("ENABLE") => DomainStatus::Active,
+            // 暂停解析 / 违规封禁 / 锁定
+            Some("PAUSE" | "SPAM" | "LOCK") => DomainStatus::Suspended,
+            _ => DomainStatus::Unknown(status.unwrap_or_default().to_string()),
         }
     }
 
@@ -138,8 +152,80 @@ impl AliyunProvider {
                 target,
             } => (format!("{priority} {weight} {port} {target}"), None),
             RecordData::CAA { flags, tag, value } => (format!("{flags} {tag} \"{value}\""), None),
+            RecordData::SOA { .. } => (data.display_value(), None),
         }
     }
+
+    /// 执行一次 DescribeDomainRecords 分页查询（`params.keyword` 原样作为 RRKeyWord）
+    async fn query_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        #[derive(Serialize)]
+        struct DescribeDomainRecordsRequest {
+            #[serde(rename = "DomainName")]
+            domain_name: String,
+            #[serde(rename = "PageNumber")]
+            page_number: u32,
+            #[serde(rename = "PageSize")]
+            page_size: u32,
+            /// 主机记录关键字（模糊搜索）
+            #[serde(rename = "RRKeyWord", skip_serializing_if = "Option::is_none")]
+            rr_keyword: Option<String>,
+            /// 记录类型过滤
+            #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+            record_type: Option<String>,
+        }
+
+        // 阿里云的 domain_id 就是域名名称，可以直接使用
+        let req = DescribeDomainRecordsRequest {
+            domain_name: domain_id.to_string(),
+            page_number: params.page,
+            page_size: params.page_size.min(MAX_PAGE_SIZE),
+            rr_keyword: params.keyword.clone(),
+            record_type: params
+                .record_type
+                .as_ref()
+                .map(|t| record_type_to_string(t).to_string()),
+        };
+
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let response: DescribeDomainRecordsResponse =
+            self.request("DescribeDomainRecords", &req, ctx).await?;
+
+        let total_count = response.total_count.unwrap_or(0);
+        let records = response
+            .domain_records
+            .and_then(|r| r.record)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|r| {
+                let data = Self::parse_record_data(&r.record_type, &r.value, r.priority).ok()?;
+                Some(DnsRecord {
+                    id: r.record_id,
+                    domain_id: domain_id.to_string(),
+                    name: r.rr,
+                    ttl: r.ttl,
+                    data,
+                    proxied: None, // 阿里云不支持代理
+                    created_at: Self::timestamp_to_datetime(r.create_timestamp),
+                    updated_at: Self::timestamp_to_datetime(r.update_timestamp),
+                })
+            })
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
 }
 
 #[async_trait]
@@ -160,6 +246,8 @@ impl DnsProvider for AliyunProvider {
                     field_type: FieldType::Text,
                     placeholder: Some("输入 AccessKey ID".to_string()),
                     help_text: None,
+                    pattern: Some(ALIYUN_ACCESS_KEY_ID.pattern()),
+                    options: None,
                 },
                 ProviderCredentialField {
                     key: "accessKeySecret".to_string(),
@@ -167,12 +255,45 @@ impl DnsProvider for AliyunProvider {
                     field_type: FieldType::Password,
                     placeholder: Some("输入 AccessKey Secret".to_string()),
                     help_text: None,
+                    pattern: Some(ALIYUN_ACCESS_KEY_SECRET.pattern()),
+                    options: None,
                 },
             ],
-            features: ProviderFeatures::default(),
+            optional_fields: vec![ProviderCredentialField {
+                key: "apiVariant".to_string(),
+                label: "API 站点".to_string(),
+                field_type: FieldType::Select,
+                placeholder: None,
+                help_text: Some("国际站账号请选择国际站，默认中国站".to_string()),
+                pattern: None,
+                options: Some(
+                    AliyunApiVariant::ALL
+                        .into_iter()
+                        .map(|v| FieldOption {
+                            value: v.as_str().to_string(),
+                            label: v.label().to_string(),
+                        })
+                        .collect(),
+                ),
+            }],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                proxy: false,
+                weighted_records: true,
+                auto_ttl: false,
+                soa_editing: false,
+                record_sets: false,
+                batch_changes: false,
+                txt_format: TxtFormat::Raw,
+                server_side_sort: false,
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 100,
                 max_page_size_records: 100,
+                min_ttl: 600,
+                max_ttl: 86400,
+                max_batch_changes: 0,
+                max_txt_length: 512,
             },
         }
     }
@@ -204,6 +325,59 @@ impl DnsProvider for AliyunProvider {
         }
     }
 
+    /// 阿里云按域名购买套餐：优先读取付费域名的套餐，付费版最低 TTL 可低至 1 秒
+    async fn probe_capabilities(&self) -> Result<CapabilityProbe> {
+        #[derive(Serialize)]
+        struct DescribeDomainsRequest {
+            #[serde(rename = "PageNumber")]
+            page_number: u32,
+            #[serde(rename = "PageSize")]
+            page_size: u32,
+        }
+
+        #[derive(Serialize)]
+        struct DescribeDomainInfoRequest {
+            #[serde(rename = "DomainName")]
+            domain_name: String,
+        }
+
+        let req = DescribeDomainsRequest {
+            page_number: 1,
+            page_size: MAX_PAGE_SIZE,
+        };
+        let response: DescribeDomainsResponse = self
+            .request("DescribeDomains", &req, ErrorContext::default())
+            .await?;
+        let domains = response.domains.and_then(|d| d.domain).unwrap_or_default();
+        let Some(domain) = domains
+            .iter()
+            .find(|d| {
+                d.version_code
+                    .as_deref()
+                    .is_some_and(|code| code != FREE_VERSION_CODE)
+            })
+            .or_else(|| domains.first())
+        else {
+            return Ok(CapabilityProbe::default());
+        };
+
+        let req = DescribeDomainInfoRequest {
+            domain_name: domain.domain_name.clone(),
+        };
+        let ctx = ErrorContext {
+            domain: Some(domain.domain_name.clone()),
+            ..Default::default()
+        };
+        let info: DescribeDomainInfoResponse =
+            self.request("DescribeDomainInfo", &req, ctx).await?;
+
+        Ok(CapabilityProbe {
+            plan: info.version_name,
+            min_ttl: info.min_ttl,
+            ..CapabilityProbe::default()
+        })
+    }
+
     async fn list_domains(
         &self,
         params: &PaginationParams,
@@ -236,7 +410,8 @@ impl DnsProvider for AliyunProvider {
                 id: d.domain_name.clone(),
                 name: d.domain_name,
                 provider: ProviderType::Aliyun,
-                status: Self::convert_domain_status(d.domain_status.as_deref()),
+                status: Self::convert_domain_status(d.domain_status.as_deref(), d.instance_expired),
+                provider_status_raw: d.domain_status.clone(),
                 record_count: d.record_count,
             })
             .collect();
@@ -275,7 +450,11 @@ impl DnsProvider for AliyunProvider {
             id: response.domain_name.clone(),
             name: response.domain_name,
             provider: ProviderType::Aliyun,
-            status: Self::convert_domain_status(response.domain_status.as_deref()),
+            status: Self::convert_domain_status(
+                response.domain_status.as_deref(),
+                response.instance_expired,
+            ),
+            provider_status_raw: response.domain_status.clone(),
             record_count: response.record_count,
         })
     }
@@ -285,69 +464,11 @@ impl DnsProvider for AliyunProvider {
         domain_id: &str,
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>> {
-        #[derive(Serialize)]
-        struct DescribeDomainRecordsRequest {
-            #[serde(rename = "DomainName")]
-            domain_name: String,
-            #[serde(rename = "PageNumber")]
-            page_number: u32,
-            #[serde(rename = "PageSize")]
-            page_size: u32,
-            /// 主机记录关键字（模糊搜索）
-            #[serde(rename = "RRKeyWord", skip_serializing_if = "Option::is_none")]
-            rr_keyword: Option<String>,
-            /// 记录类型过滤
-            #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
-            record_type: Option<String>,
-        }
-
-        // 阿里云的 domain_id 就是域名名称，可以直接使用
-        let req = DescribeDomainRecordsRequest {
-            domain_name: domain_id.to_string(),
-            page_number: params.page,
-            page_size: params.page_size.min(MAX_PAGE_SIZE),
-            rr_keyword: params.keyword.clone().filter(|k| !k.is_empty()),
-            record_type: params
-                .record_type
-                .as_ref()
-                .map(|t| record_type_to_string(t).to_string()),
-        };
-
-        let ctx = ErrorContext {
-            domain: Some(domain_id.to_string()),
-            ..Default::default()
-        };
-
-        let response: DescribeDomainRecordsResponse =
-            self.request("DescribeDomainRecords", &req, ctx).await?;
-
-        let total_count = response.total_count.unwrap_or(0);
-        let records = response
-            .domain_records
-            .and_then(|r| r.record)
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|r| {
-                let data = Self::parse_record_data(&r.record_type, &r.value, r.priority).ok()?;
-                Some(DnsRecord {
-                    id: r.record_id,
-                    domain_id: domain_id.to_string(),
-                    name: r.rr,
-                    ttl: r.ttl,
-                    data,
-                    proxied: None, // 阿里云不支持代理
-                    created_at: Self::timestamp_to_datetime(r.create_timestamp),
-                    updated_at: Self::timestamp_to_datetime(r.update_timestamp),
-                })
-            })
-            .collect();
-
-        Ok(PaginatedResponse::new(
-            records,
-            params.page,
-            params.page_size,
-            total_count,
-        ))
+        let keyword = LiteralKeyword::new(params.keyword.as_deref(), KEYWORD_SPECIAL_CHARS);
+        list_records_by_keyword(keyword, params, MAX_PAGE_SIZE, |params| async move {
+            self.query_records(domain_id, &params).await
+        })
+        .await
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
@@ -481,4 +602,97 @@ impl DnsProvider for AliyunProvider {
 
         Ok(())
     }
+
+    /// 阿里云权重解析: `SetDNSSLBStatus`
+    async fn set_weighted_routing(
+        &self,
+        domain_id: &str,
+        subdomain: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct SetDnsSlbStatusRequest {
+            #[serde(rename = "DomainName")]
+            domain_name: String,
+            #[serde(rename = "SubDomain")]
+            sub_domain: String,
+            #[serde(rename = "Open")]
+            open: bool,
+        }
+
+        let api_req = SetDnsSlbStatusRequest {
+            domain_name: domain_id.to_string(),
+            sub_domain: subdomain.to_string(),
+            open: enabled,
+        };
+
+        let ctx = ErrorContext {
+            record_name: Some(subdomain.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let _response: SetDnsSlbStatusResponse =
+            self.request("SetDNSSLBStatus", &api_req, ctx).await?;
+
+        Ok(())
+    }
+
+    /// 阿里云权重解析: `UpdateDNSSLBWeight`（权重范围 1-100）
+    async fn set_record_weight(&self, record_id: &str, weight: u8) -> Result<()> {
+        #[derive(Serialize)]
+        struct UpdateDnsSlbWeightRequest {
+            #[serde(rename = "RecordId")]
+            record_id: String,
+            #[serde(rename = "Weight")]
+            weight: u8,
+        }
+
+        if !(1..=100).contains(&weight) {
+            return Err(ProviderError::InvalidParameter {
+                provider: "aliyun".to_string(),
+                param: "weight".to_string(),
+                detail: format!("Weight must be between 1 and 100, got {weight}"),
+            });
+        }
+
+        let api_req = UpdateDnsSlbWeightRequest {
+            record_id: record_id.to_string(),
+            weight,
+        };
+
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            ..Default::default()
+        };
+
+        let _response: UpdateDnsSlbWeightResponse =
+            self.request("UpdateDNSSLBWeight", &api_req, ctx).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_domain_status() {
+        for (raw, expected) in [
+            (Some("ENABLE"), DomainStatus::Active),
+            (Some("enable"), DomainStatus::Active),
+            (Some("PAUSE"), DomainStatus::Suspended),
+            (Some("SPAM"), DomainStatus::Suspended),
+            (Some("LOCK"), DomainStatus::Suspended),
+            (Some("OTHER"), DomainStatus::Unknown("OTHER".to_string())),
+            (None, DomainStatus::Unknown(String::new())),
+        ] {
+            assert_eq!(AliyunProvider::convert_domain_status(raw, None), expected);
+        }
+        assert_eq!(
+            AliyunProvider::convert_domain_status(Some("ENABLE"), Some(true)),
+            DomainStatus::Expired
+        );
+    }
 }
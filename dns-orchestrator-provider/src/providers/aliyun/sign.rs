@@ -4,13 +4,14 @@ use sha2::{Digest, Sha256};
 
 use crate::providers::common::hmac_sha256;
 
-use super::{ALIYUN_DNS_HOST, ALIYUN_DNS_VERSION, AliyunProvider, EMPTY_BODY_SHA256};
+use super::{ALIYUN_DNS_VERSION, AliyunProvider, EMPTY_BODY_SHA256};
 
 impl AliyunProvider {
     /// 生成 ACS3-HMAC-SHA256 签名
     /// 参考: <https://www.alibabacloud.com/help/zh/sdk/product-overview/v3-request-structure-and-signature>
     pub(crate) fn sign(
         &self,
+        host: &str,
         action: &str,
         query_string: &str,
         timestamp: &str,
@@ -18,7 +19,7 @@ impl AliyunProvider {
     ) -> String {
         // 1. 构造规范化请求头 (使用空 body 的 hash)
         let canonical_headers = format!(
-            "host:{ALIYUN_DNS_HOST}\nx-acs-action:{action}\nx-acs-content-sha256:{EMPTY_BODY_SHA256}\nx-acs-date:{timestamp}\nx-acs-signature-nonce:{nonce}\nx-acs-version:{ALIYUN_DNS_VERSION}\n"
+            "host:{host}\nx-acs-action:{action}\nx-acs-content-sha256:{EMPTY_BODY_SHA256}\nx-acs-date:{timestamp}\nx-acs-signature-nonce:{nonce}\nx-acs-version:{ALIYUN_DNS_VERSION}\n"
         );
 
         let signed_headers =
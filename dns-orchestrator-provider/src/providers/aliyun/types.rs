@@ -117,8 +117,14 @@ pub struct AliyunDomain {
     pub domain_name: String,
     #[serde(rename = "DomainStatus")]
     pub domain_status: Option<String>,
+    /// 付费实例是否已过期
+    #[serde(rename = "InstanceExpired")]
+    pub instance_expired: Option<bool>,
     #[serde(rename = "RecordCount")]
     pub record_count: Option<u32>,
+    /// 套餐版本编码，免费版为 `mianfei`
+    #[serde(rename = "VersionCode")]
+    pub version_code: Option<String>,
 }
 
 /// ErrorRequireCheck: DescribeDomainInfo API 响应结构，需验证字段映射是否正确
@@ -130,8 +136,17 @@ pub struct DescribeDomainInfoResponse {
     pub domain_name: String,
     #[serde(rename = "DomainStatus")]
     pub domain_status: Option<String>,
+    /// 付费实例是否已过期
+    #[serde(rename = "InstanceExpired")]
+    pub instance_expired: Option<bool>,
     #[serde(rename = "RecordCount")]
     pub record_count: Option<u32>,
+    /// 套餐版本名称
+    #[serde(rename = "VersionName")]
+    pub version_name: Option<String>,
+    /// 该域名套餐允许的最小 TTL
+    #[serde(rename = "MinTtl")]
+    pub min_ttl: Option<u32>,
 }
 
 // ============ 记录相关结构 ============
@@ -189,3 +204,19 @@ pub struct DeleteDomainRecordResponse {
     #[allow(dead_code)]
     pub record_id: Option<String>,
 }
+
+// ============ 权重解析相关结构 ============
+
+#[derive(Debug, Deserialize)]
+pub struct SetDnsSlbStatusResponse {
+    #[serde(rename = "RecordCount")]
+    #[allow(dead_code)]
+    pub record_count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateDnsSlbWeightResponse {
+    #[serde(rename = "RecordId")]
+    #[allow(dead_code)]
+    pub record_id: Option<String>,
+}
@@ -10,7 +10,8 @@ use reqwest::Client;
 use crate::providers::common::create_http_client;
 
 pub(crate) use types::{
-    CloudflareCaaData, CloudflareDnsRecord, CloudflareResponse, CloudflareSrvData, CloudflareZone,
+    CloudflareBatchResult, CloudflareCaaData, CloudflareDnsRecord, CloudflarePlan,
+    CloudflareResponse, CloudflareSrvData, CloudflareZone,
 };
 
 pub(crate) const CF_API_BASE: &str = "https://api.cloudflare.com/client/v4";
@@ -18,6 +19,8 @@ pub(crate) const CF_API_BASE: &str = "https://api.cloudflare.com/client/v4";
 pub(crate) const MAX_PAGE_SIZE_ZONES: u32 = 50;
 /// Cloudflare DNS Records API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE_RECORDS: u32 = 100;
+/// Cloudflare 批量记录接口单批最大变更数（免费套餐）
+pub(crate) const MAX_BATCH_CHANGES: u32 = 200;
 
 /// Cloudflare DNS Provider
 pub struct CloudflareProvider {
@@ -3,40 +3,160 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 
+use crate::credential_format::CLOUDFLARE_API_TOKEN;
 use crate::error::Result;
-use crate::providers::common::{full_name_to_relative, relative_to_full_name};
-use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::providers::common::{
+    KEYWORD_SPECIAL_CHARS, LiteralKeyword, encode_path_segment, full_name_to_relative,
+    list_records_by_keyword, relative_to_full_name,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper, fallback_after_batch_error};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    CapabilityProbe, ChangeResult, CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType,
+    PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordChange, RecordData, RecordQueryParams,
+    RecordSort, RecordSortField, STANDARD_RECORD_TYPES, SortOrder, TxtFormat,
+    UpdateDnsRecordRequest,
 };
 
 use super::{
-    CloudflareCaaData, CloudflareDnsRecord, CloudflareProvider, CloudflareSrvData, CloudflareZone,
-    MAX_PAGE_SIZE_RECORDS,
+    CloudflareBatchResult, CloudflareCaaData, CloudflareDnsRecord, CloudflarePlan,
+    CloudflareProvider, CloudflareSrvData, CloudflareZone, MAX_BATCH_CHANGES,
+    MAX_PAGE_SIZE_RECORDS, MAX_PAGE_SIZE_ZONES,
 };
 
+/// 企业版套餐的最低 TTL（其他套餐为 60 秒）
+const ENTERPRISE_MIN_TTL: u32 = 30;
+/// 付费套餐批量记录接口单批最大变更数
+const PAID_MAX_BATCH_CHANGES: u32 = 3500;
+
 impl CloudflareProvider {
-    /// 将 Cloudflare zone 转换为 ProviderDomain
-    /// Cloudflare 状态：active, pending, initializing, moved
-    pub(crate) fn zone_to_domain(zone: CloudflareZone) -> ProviderDomain {
-        let status = match zone.status.as_str() {
+    /// 将 Cloudflare zone 状态转换为内部状态
+    /// Cloudflare 状态：active, pending, initializing, moved, deactivated, read only
+    pub(crate) fn convert_domain_status(status: &str) -> DomainStatus {
+        match status {
             "active" => DomainStatus::Active,
+            // pending: NS 尚未切换到 Cloudflare
             "pending" | "initializing" => DomainStatus::Pending,
-            "moved" => DomainStatus::Paused,
-            _ => DomainStatus::Unknown,
-        };
+            "moved" => DomainStatus::Moved,
+            "deactivated" | "read only" => DomainStatus::Suspended,
+            other => DomainStatus::Unknown(other.to_string()),
+        }
+    }
 
+    /// 将 Cloudflare zone 转换为 ProviderDomain
+    pub(crate) fn zone_to_domain(zone: CloudflareZone) -> ProviderDomain {
         ProviderDomain {
             id: zone.id,
             name: zone.name,
             provider: ProviderType::Cloudflare,
-            status,
+            status: Self::convert_domain_status(&zone.status),
+            provider_status_raw: Some(zone.status),
             record_count: None,
         }
     }
 
+    /// 按账户下等级最高的 zone 套餐修正能力
+    pub(crate) fn plan_capabilities<'a>(
+        plans: impl IntoIterator<Item = &'a CloudflarePlan>,
+    ) -> CapabilityProbe {
+        let rank = |plan: &CloudflarePlan| match plan.legacy_id.as_deref() {
+            Some("enterprise") => 3,
+            Some("business") => 2,
+            Some("pro") => 1,
+            _ => 0,
+        };
+        let Some(best) = plans.into_iter().max_by_key(|plan| rank(plan)) else {
+            return CapabilityProbe::default();
+        };
+        CapabilityProbe {
+            plan: best.name.clone().or_else(|| best.legacy_id.clone()),
+            min_ttl: (best.legacy_id.as_deref() == Some("enterprise"))
+                .then_some(ENTERPRISE_MIN_TTL),
+            max_batch_changes: (rank(best) > 0).then_some(PAID_MAX_BATCH_CHANGES),
+            ..CapabilityProbe::default()
+        }
+    }
+
+    /// 通过批量记录接口一次提交整批变更，结果与 `changes` 一一对应
+    ///
+    /// 接口按 deletes → patches → posts 的顺序执行，整批原子生效。
+    async fn submit_batch(
+        &self,
+        domain_id: &str,
+        changes: &[RecordChange],
+    ) -> Result<Vec<ChangeResult>> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{}", encode_path_segment(domain_id)),
+                ctx.clone(),
+            )
+            .await?;
+
+        let mut deletes = Vec::new();
+        let mut patches = Vec::new();
+        let mut posts = Vec::new();
+        for change in changes {
+            match change {
+                RecordChange::Create(req) => {
+                    let full_name = relative_to_full_name(&req.name, &zone.name);
+                    posts.push(self.build_create_body(&full_name, req.ttl, &req.data, req.proxied));
+                }
+                RecordChange::Update { record_id, request } => {
+                    let full_name = relative_to_full_name(&request.name, &zone.name);
+                    let mut body = self.build_create_body(
+                        &full_name,
+                        request.ttl,
+                        &request.data,
+                        request.proxied,
+                    );
+                    body["id"] = serde_json::Value::from(record_id.as_str());
+                    patches.push(body);
+                }
+                RecordChange::Delete { record_id } => {
+                    deletes.push(serde_json::json!({ "id": record_id }));
+                }
+            }
+        }
+
+        let result: CloudflareBatchResult = self
+            .post_json(
+                &format!(
+                    "/zones/{}/dns_records/batch",
+                    encode_path_segment(domain_id)
+                ),
+                serde_json::json!({
+                    "deletes": deletes,
+                    "patches": patches,
+                    "posts": posts,
+                }),
+                ctx,
+            )
+            .await?;
+
+        let mut patched = result.patches.into_iter();
+        let mut posted = result.posts.into_iter();
+        Ok(changes
+            .iter()
+            .map(|change| {
+                let record = match change {
+                    RecordChange::Delete { .. } => return ChangeResult::Deleted,
+                    RecordChange::Update { .. } => patched.next(),
+                    RecordChange::Create(_) => posted.next(),
+                };
+                match record {
+                    Some(record) => self
+                        .cf_record_to_dns_record(record, domain_id, &zone.name)
+                        .into(),
+                    None => ChangeResult::Failed(self.parse_error("批量响应中的记录数少于请求")),
+                }
+            })
+            .collect())
+    }
+
     /// 将 Cloudflare 记录转换为 `DnsRecord`
     pub(crate) fn cf_record_to_dns_record(
         &self,
@@ -267,7 +387,63 @@ impl CloudflareProvider {
                     "value": value,
                 }
             }),
+            // Cloudflare 不开放 SOA 写入，请求会被 API 拒绝
+            RecordData::SOA { .. } => serde_json::json!({
+                "type": "SOA",
+                "name": full_name,
+                "content": data.display_value(),
+                "ttl": ttl,
+            }),
+        }
+    }
+
+    /// 执行一次记录分页查询（`params.keyword` 原样作为 `name.contains`，只搜索记录名称）
+    async fn query_records(
+        &self,
+        domain_id: &str,
+        zone_name: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        // 构建查询 URL，包含搜索参数
+        let mut url = format!(
+            "/zones/{}/dns_records?page={}&per_page={}",
+            encode_path_segment(domain_id),
+            params.page,
+            params.page_size.min(MAX_PAGE_SIZE_RECORDS)
+        );
+
+        if let Some(ref keyword) = params.keyword {
+            url.push_str(&format!("&name.contains={}", urlencoding::encode(keyword)));
         }
+
+        // 添加记录类型过滤
+        if let Some(ref record_type) = params.record_type {
+            let type_str = crate::providers::common::record_type_to_string(record_type);
+            url.push_str(&format!("&type={}", urlencoding::encode(type_str)));
+        }
+
+        if let Some(sort) = params.sort {
+            url.push_str(&sort_query(sort));
+        }
+
+        let (cf_records, total_count) = self.get_records(&url, ctx).await?;
+
+        let records: Vec<DnsRecord> = cf_records
+            .into_iter()
+            .map(|r| self.cf_record_to_dns_record(r, domain_id, zone_name))
+            .collect::<Result<_>>()?;
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
     }
 }
 
@@ -290,11 +466,28 @@ impl DnsProvider for CloudflareProvider {
                 help_text: Some(
                     "在 Cloudflare Dashboard -> My Profile -> API Tokens 创建".to_string(),
                 ),
+                pattern: Some(CLOUDFLARE_API_TOKEN.pattern()),
+                options: None,
             }],
-            features: ProviderFeatures { proxy: true },
+            optional_fields: vec![],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                proxy: true,
+                weighted_records: false,
+                auto_ttl: true,
+                soa_editing: false,
+                record_sets: false,
+                batch_changes: true,
+                txt_format: TxtFormat::Raw,
+                server_side_sort: true,
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 50,
                 max_page_size_records: 5000,
+                min_ttl: 60,
+                max_ttl: 86400,
+                max_batch_changes: MAX_BATCH_CHANGES,
+                max_txt_length: 2048,
             },
         }
     }
@@ -314,6 +507,32 @@ impl DnsProvider for CloudflareProvider {
         }
     }
 
+    async fn credentials_expires_at(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        #[derive(Deserialize)]
+        struct VerifyResponse {
+            /// 未设置有效期的 token 不返回此字段
+            expires_on: Option<chrono::DateTime<chrono::Utc>>,
+        }
+
+        let resp = self
+            .get::<VerifyResponse>("/user/tokens/verify", ErrorContext::default())
+            .await?;
+        Ok(resp.expires_on)
+    }
+
+    async fn probe_capabilities(&self) -> Result<CapabilityProbe> {
+        let params = PaginationParams {
+            page: 1,
+            page_size: MAX_PAGE_SIZE_ZONES,
+        };
+        let (zones, _): (Vec<CloudflareZone>, u32) = self
+            .get_paginated("/zones", &params, ErrorContext::default())
+            .await?;
+        Ok(Self::plan_capabilities(
+            zones.iter().filter_map(|zone| zone.plan.as_ref()),
+        ))
+    }
+
     async fn list_domains(
         &self,
         params: &PaginationParams,
@@ -335,7 +554,9 @@ impl DnsProvider for CloudflareProvider {
             domain: Some(domain_id.to_string()),
             ..Default::default()
         };
-        let zone: CloudflareZone = self.get(&format!("/zones/{domain_id}"), ctx).await?;
+        let zone: CloudflareZone = self
+            .get(&format!("/zones/{}", encode_path_segment(domain_id)), ctx)
+            .await?;
         Ok(Self::zone_to_domain(zone))
     }
 
@@ -351,44 +572,16 @@ impl DnsProvider for CloudflareProvider {
 
         // 先获取 zone 信息以获取域名
         let zone: CloudflareZone = self
-            .get(&format!("/zones/{domain_id}"), ctx.clone())
+            .get(&format!("/zones/{}", encode_path_segment(domain_id)), ctx)
             .await?;
         let zone_name = zone.name;
 
-        // 构建查询 URL，包含搜索参数
-        let mut url = format!(
-            "/zones/{}/dns_records?page={}&per_page={}",
-            domain_id,
-            params.page,
-            params.page_size.min(MAX_PAGE_SIZE_RECORDS)
-        );
-
-        // 添加搜索关键词（只搜索记录名称）
-        if let Some(ref keyword) = params.keyword
-            && !keyword.is_empty()
-        {
-            url.push_str(&format!("&name.contains={}", urlencoding::encode(keyword)));
-        }
-
-        // 添加记录类型过滤
-        if let Some(ref record_type) = params.record_type {
-            let type_str = crate::providers::common::record_type_to_string(record_type);
-            url.push_str(&format!("&type={}", urlencoding::encode(type_str)));
-        }
-
-        let (cf_records, total_count) = self.get_records(&url, ctx).await?;
-
-        let records: Result<Vec<DnsRecord>> = cf_records
-            .into_iter()
-            .map(|r| self.cf_record_to_dns_record(r, domain_id, &zone_name))
-            .collect();
-
-        Ok(PaginatedResponse::new(
-            records?,
-            params.page,
-            params.page_size,
-            total_count,
-        ))
+        let keyword = LiteralKeyword::new(params.keyword.as_deref(), KEYWORD_SPECIAL_CHARS);
+        list_records_by_keyword(keyword, params, MAX_PAGE_SIZE_RECORDS, |params| {
+            let zone_name = &zone_name;
+            async move { self.query_records(domain_id, zone_name, &params).await }
+        })
+        .await
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
@@ -400,7 +593,10 @@ impl DnsProvider for CloudflareProvider {
 
         // 先获取 zone 信息
         let zone: CloudflareZone = self
-            .get(&format!("/zones/{}", req.domain_id), ctx.clone())
+            .get(
+                &format!("/zones/{}", encode_path_segment(&req.domain_id)),
+                ctx.clone(),
+            )
             .await?;
         let zone_name = zone.name;
 
@@ -408,7 +604,11 @@ impl DnsProvider for CloudflareProvider {
         let body = self.build_create_body(&full_name, req.ttl, &req.data, req.proxied);
 
         let cf_record: CloudflareDnsRecord = self
-            .post_json(&format!("/zones/{}/dns_records", req.domain_id), body, ctx)
+            .post_json(
+                &format!("/zones/{}/dns_records", encode_path_segment(&req.domain_id)),
+                body,
+                ctx,
+            )
             .await?;
 
         self.cf_record_to_dns_record(cf_record, &req.domain_id, &zone_name)
@@ -427,7 +627,10 @@ impl DnsProvider for CloudflareProvider {
 
         // 先获取 zone 信息
         let zone: CloudflareZone = self
-            .get(&format!("/zones/{}", req.domain_id), ctx.clone())
+            .get(
+                &format!("/zones/{}", encode_path_segment(&req.domain_id)),
+                ctx.clone(),
+            )
             .await?;
         let zone_name = zone.name;
 
@@ -436,7 +639,11 @@ impl DnsProvider for CloudflareProvider {
 
         let cf_record: CloudflareDnsRecord = self
             .patch_json(
-                &format!("/zones/{}/dns_records/{}", req.domain_id, record_id),
+                &format!(
+                    "/zones/{}/dns_records/{}",
+                    encode_path_segment(&req.domain_id),
+                    encode_path_segment(record_id)
+                ),
                 body,
                 ctx,
             )
@@ -451,7 +658,104 @@ impl DnsProvider for CloudflareProvider {
             domain: Some(domain_id.to_string()),
             ..Default::default()
         };
-        self.delete(&format!("/zones/{domain_id}/dns_records/{record_id}"), ctx)
-            .await
+        self.delete(
+            &format!(
+                "/zones/{}/dns_records/{}",
+                encode_path_segment(domain_id),
+                encode_path_segment(record_id)
+            ),
+            ctx,
+        )
+        .await
+    }
+
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        if changes.is_empty() {
+            return Vec::new();
+        }
+        match self.submit_batch(domain_id, &changes).await {
+            Ok(results) => results,
+            Err(e) => fallback_after_batch_error(self, domain_id, changes, e).await,
+        }
+    }
+}
+
+/// 记录列表的排序参数（`content` 即记录值）
+fn sort_query(sort: RecordSort) -> String {
+    let order = match sort.field {
+        RecordSortField::Name => "name",
+        RecordSortField::Type => "type",
+        RecordSortField::Value => "content",
+        RecordSortField::Ttl => "ttl",
+    };
+    let direction = match sort.order {
+        SortOrder::Asc => "asc",
+        SortOrder::Desc => "desc",
+    };
+    format!("&order={order}&direction={direction}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_zone_status() {
+        for (raw, expected) in [
+            ("active", DomainStatus::Active),
+            ("pending", DomainStatus::Pending),
+            ("initializing", DomainStatus::Pending),
+            ("moved", DomainStatus::Moved),
+            ("deactivated", DomainStatus::Suspended),
+            ("read only", DomainStatus::Suspended),
+            ("archived", DomainStatus::Unknown("archived".to_string())),
+        ] {
+            assert_eq!(CloudflareProvider::convert_domain_status(raw), expected);
+        }
+    }
+
+    #[test]
+    fn builds_sort_query() {
+        let sort = |field, order| sort_query(RecordSort { field, order });
+        assert_eq!(
+            sort(RecordSortField::Value, SortOrder::Desc),
+            "&order=content&direction=desc"
+        );
+        assert_eq!(
+            sort(RecordSortField::Ttl, SortOrder::Asc),
+            "&order=ttl&direction=asc"
+        );
+    }
+
+    #[test]
+    fn probes_highest_zone_plan() {
+        let plan = |legacy_id: &str, name: &str| CloudflarePlan {
+            name: Some(name.to_string()),
+            legacy_id: Some(legacy_id.to_string()),
+        };
+
+        let free = [plan("free", "Free Website")];
+        assert_eq!(
+            CloudflareProvider::plan_capabilities(&free),
+            CapabilityProbe {
+                plan: Some("Free Website".to_string()),
+                ..CapabilityProbe::default()
+            }
+        );
+
+        let mixed = [
+            plan("free", "Free Website"),
+            plan("enterprise", "Enterprise Website"),
+            plan("pro", "Pro Website"),
+        ];
+        let probe = CloudflareProvider::plan_capabilities(&mixed);
+        assert_eq!(probe.plan.as_deref(), Some("Enterprise Website"));
+        assert_eq!(probe.min_ttl, Some(ENTERPRISE_MIN_TTL));
+        assert_eq!(probe.max_batch_changes, Some(PAID_MAX_BATCH_CHANGES));
+
+        assert_eq!(
+            CloudflareProvider::plan_capabilities(std::iter::empty()),
+            CapabilityProbe::default()
+        );
     }
 }
@@ -34,6 +34,18 @@ pub struct CloudflareZone {
     pub id: String,
     pub name: String,
     pub status: String,
+    /// zone 的套餐（Cloudflare 按 zone 计费）
+    #[serde(default)]
+    pub plan: Option<CloudflarePlan>,
+}
+
+/// Cloudflare 套餐
+#[derive(Debug, Deserialize)]
+pub struct CloudflarePlan {
+    /// 套餐名称，如 `Free Website`
+    pub name: Option<String>,
+    /// 套餐标识：`free`、`pro`、`business`、`enterprise`
+    pub legacy_id: Option<String>,
 }
 
 /// Cloudflare DNS Record 结构（响应）
@@ -58,6 +70,15 @@ pub struct CloudflareDnsRecord {
     pub data: Option<Value>,
 }
 
+/// 批量记录接口的响应（各组记录与请求中的顺序一致）
+#[derive(Debug, Default, Deserialize)]
+pub struct CloudflareBatchResult {
+    #[serde(default)]
+    pub patches: Vec<CloudflareDnsRecord>,
+    #[serde(default)]
+    pub posts: Vec<CloudflareDnsRecord>,
+}
+
 /// SRV 记录的 data 字段
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CloudflareSrvData {
@@ -8,7 +8,7 @@ use reqwest::Client;
 use sha2::Sha256;
 
 use crate::error::{ProviderError, Result};
-use crate::types::DnsRecordType;
+use crate::types::{DnsRecord, DnsRecordType, PaginatedResponse, RecordData, RecordQueryParams};
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -48,6 +48,7 @@ pub fn parse_record_type(record_type: &str, provider: &str) -> Result<DnsRecordT
         "NS" => Ok(DnsRecordType::Ns),
         "SRV" => Ok(DnsRecordType::Srv),
         "CAA" => Ok(DnsRecordType::Caa),
+        "SOA" => Ok(DnsRecordType::Soa),
         _ => Err(ProviderError::InvalidParameter {
             provider: provider.to_string(),
             param: "record_type".to_string(),
@@ -67,9 +68,44 @@ pub fn record_type_to_string(record_type: &DnsRecordType) -> &'static str {
         DnsRecordType::Ns => "NS",
         DnsRecordType::Srv => "SRV",
         DnsRecordType::Caa => "CAA",
+        DnsRecordType::Soa => "SOA",
     }
 }
 
+/// 解析 SOA 文本值（`mname rname serial refresh retry expire minimum`）
+///
+/// 兼容 zone 文件风格的括号写法，如 `ns1.example.com. hostmaster.example.com. (1 7200 900 1209600 300)`。
+pub fn parse_soa(value: &str, provider: &str) -> Result<RecordData> {
+    let cleaned = value.replace(['(', ')'], " ");
+    let parts: Vec<&str> = cleaned.split_whitespace().collect();
+    let invalid = || ProviderError::ParseError {
+        provider: provider.to_string(),
+        detail: format!(
+            "Invalid SOA record format: expected 'mname rname serial refresh retry expire minimum', got '{value}'"
+        ),
+    };
+    let [mname, rname, numbers @ ..] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    let numbers = numbers
+        .iter()
+        .map(|n| n.parse::<u32>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| invalid())?;
+    let [serial, refresh, retry, expire, minimum] = numbers.as_slice() else {
+        return Err(invalid());
+    };
+    Ok(RecordData::SOA {
+        mname: (*mname).to_string(),
+        rname: (*rname).to_string(),
+        serial: *serial,
+        refresh: *refresh,
+        retry: *retry,
+        expire: *expire,
+        minimum: *minimum,
+    })
+}
+
 // ============ HMAC-SHA256 ============
 
 /// HMAC-SHA256 计算（供 aliyun/dnspod/huaweicloud 使用）
@@ -114,3 +150,260 @@ pub fn relative_to_full_name(relative_name: &str, zone_name: &str) -> String {
         format!("{relative_name}.{zone}")
     }
 }
+
+/// 对 URL 路径片段（如 zone/record ID）做百分号编码，避免用户输入改变请求路径
+pub fn encode_path_segment(segment: &str) -> String {
+    urlencoding::encode(segment).into_owned()
+}
+
+// ============ 搜索关键字 ============
+
+/// 各 Provider 搜索参数中可能被当作查询语法的字符（通配符、转义符、引号）
+pub const KEYWORD_SPECIAL_CHARS: &[char] = &['%', '_', '*', '?', '\\', '"', '\''];
+
+/// 本地过滤时最多扫描的页数（按 Provider 单页上限取），避免超大 zone 无限翻页
+const MAX_KEYWORD_SCAN_PAGES: u32 = 100;
+
+/// 记录名称是否包含关键字（字面量、不区分大小写，`keyword` 需已转小写）
+///
+/// 所有 Provider 的关键字搜索都只匹配记录名称，服务端搜索与本地过滤保持一致。
+pub fn record_name_matches(record: &DnsRecord, keyword: &str) -> bool {
+    record.name.to_lowercase().contains(keyword)
+}
+
+/// 按字面量匹配的搜索关键字
+///
+/// Provider 的搜索参数各有查询语法（`%`/`_` 通配、`*` 泛匹配等），直接透传用户输入会导致
+/// 结果不一致。关键字含特殊字符时，只把不含特殊字符的最长片段交给服务端，
+/// 再由 [`list_records_by_keyword`] 取回全部候选记录，在本地按名称做字面量过滤后重新分页。
+pub struct LiteralKeyword {
+    keyword: String,
+    server_keyword: Option<String>,
+    needs_post_filter: bool,
+}
+
+impl LiteralKeyword {
+    /// 空关键字返回 `None`
+    pub fn new(keyword: Option<&str>, special_chars: &[char]) -> Option<Self> {
+        let keyword = keyword.filter(|k| !k.is_empty())?;
+
+        if !keyword.contains(special_chars) {
+            return Some(Self {
+                keyword: keyword.to_lowercase(),
+                server_keyword: Some(keyword.to_string()),
+                needs_post_filter: false,
+            });
+        }
+
+        let server_keyword = keyword
+            .split(special_chars)
+            .max_by_key(|part| part.len())
+            .filter(|part| !part.is_empty())
+            .map(ToString::to_string);
+
+        Some(Self {
+            keyword: keyword.to_lowercase(),
+            server_keyword,
+            needs_post_filter: true,
+        })
+    }
+
+    /// 服务端搜索还会匹配名称以外的字段（如记录值）时，总是在本地按名称二次过滤
+    #[must_use]
+    pub fn always_post_filter(mut self) -> Self {
+        self.needs_post_filter = true;
+        self
+    }
+
+    /// 可安全交给服务端的关键字
+    pub fn server_keyword(&self) -> Option<String> {
+        self.server_keyword.clone()
+    }
+
+    /// 记录是否按字面量匹配关键字
+    pub fn matches(&self, record: &DnsRecord) -> bool {
+        record_name_matches(record, &self.keyword)
+    }
+}
+
+/// 按关键字分页查询记录
+///
+/// `fetch` 执行一次服务端分页查询，收到的参数中 `keyword` 已替换为可安全交给服务端的关键字。
+/// 无需本地过滤时直接透传请求的分页；否则以 `max_page_size` 取回全部候选页，
+/// 按名称过滤后在本地分页，`total_count` 为过滤后的总数。
+pub async fn list_records_by_keyword<F, Fut>(
+    keyword: Option<LiteralKeyword>,
+    params: &RecordQueryParams,
+    max_page_size: u32,
+    mut fetch: F,
+) -> Result<PaginatedResponse<DnsRecord>>
+where
+    F: FnMut(RecordQueryParams) -> Fut,
+    Fut: Future<Output = Result<PaginatedResponse<DnsRecord>>>,
+{
+    let server_params = RecordQueryParams {
+        keyword: keyword.as_ref().and_then(LiteralKeyword::server_keyword),
+        ..params.clone()
+    };
+    let Some(keyword) = keyword.filter(|k| k.needs_post_filter) else {
+        return fetch(server_params).await;
+    };
+
+    let mut matched = Vec::new();
+    let mut page = 1;
+    loop {
+        let response = fetch(RecordQueryParams {
+            page,
+            page_size: max_page_size,
+            ..server_params.clone()
+        })
+        .await?;
+        let has_more = response.has_more;
+        matched.extend(response.items.into_iter().filter(|r| keyword.matches(r)));
+        if !has_more || page >= MAX_KEYWORD_SCAN_PAGES {
+            break;
+        }
+        page += 1;
+    }
+
+    let total_count = u32::try_from(matched.len()).unwrap_or(u32::MAX);
+    let items = matched
+        .into_iter()
+        .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+        .take(params.page_size as usize)
+        .collect();
+
+    Ok(PaginatedResponse::new(
+        items,
+        params.page,
+        params.page_size,
+        total_count,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, value: &str) -> DnsRecord {
+        DnsRecord {
+            id: name.to_string(),
+            domain_id: "example.com".to_string(),
+            name: name.to_string(),
+            ttl: 600,
+            data: RecordData::TXT {
+                text: value.to_string(),
+            },
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    /// 模拟服务端：按 `keyword` 对名称或值做不区分大小写的模糊匹配后分页
+    fn server_page(
+        records: &[DnsRecord],
+        params: &RecordQueryParams,
+    ) -> PaginatedResponse<DnsRecord> {
+        let keyword = params.keyword.as_deref().map(str::to_lowercase);
+        let candidates: Vec<DnsRecord> = records
+            .iter()
+            .filter(|r| {
+                keyword.as_deref().is_none_or(|k| {
+                    r.name.to_lowercase().contains(k)
+                        || r.data.display_value().to_lowercase().contains(k)
+                })
+            })
+            .cloned()
+            .collect();
+        let total_count = u32::try_from(candidates.len()).unwrap();
+        let items = candidates
+            .into_iter()
+            .skip(((params.page - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+        PaginatedResponse::new(items, params.page, params.page_size, total_count)
+    }
+
+    fn params(keyword: &str, page: u32, page_size: u32) -> RecordQueryParams {
+        RecordQueryParams {
+            page,
+            page_size,
+            keyword: Some(keyword.to_string()),
+            ..RecordQueryParams::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn plain_keyword_is_passed_through() {
+        let records = vec![record("www", "a"), record("api", "www")];
+        let keyword = LiteralKeyword::new(Some("www"), KEYWORD_SPECIAL_CHARS);
+
+        let response = list_records_by_keyword(keyword, &params("www", 1, 10), 2, |p| {
+            let response = server_page(&records, &p);
+            async move { Ok(response) }
+        })
+        .await
+        .unwrap();
+
+        // 无特殊字符时原样使用服务端结果
+        assert_eq!(response.items.len(), 2);
+        assert_eq!(response.total_count, 2);
+    }
+
+    #[tokio::test]
+    async fn post_filter_counts_across_pages() {
+        let records = vec![
+            record("a_b", "x"),
+            record("ab", "x"),
+            record("c_b", "x"),
+            record("d", "a_b"),
+            record("A_B2", "x"),
+        ];
+        let keyword = LiteralKeyword::new(Some("a_b"), KEYWORD_SPECIAL_CHARS);
+        let mut requests = Vec::new();
+
+        let response = list_records_by_keyword(keyword, &params("a_b", 1, 1), 2, |p| {
+            requests.push((p.page, p.page_size, p.keyword.clone()));
+            let response = server_page(&records, &p);
+            async move { Ok(response) }
+        })
+        .await
+        .unwrap();
+
+        // 服务端只收到不含特殊字符的片段，并以单页上限翻完全部候选
+        assert_eq!(
+            requests,
+            vec![
+                (1, 2, Some("b".to_string())),
+                (2, 2, Some("b".to_string())),
+                (3, 2, Some("b".to_string())),
+            ]
+        );
+        // 只按名称匹配（值为 a_b 的记录不算），不区分大小写
+        assert_eq!(response.total_count, 2);
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.items[0].name, "a_b");
+        assert!(response.has_more);
+    }
+
+    #[tokio::test]
+    async fn forced_post_filter_matches_name_only() {
+        let records = vec![record("www", "a"), record("api", "www")];
+        let keyword = LiteralKeyword::new(Some("www"), KEYWORD_SPECIAL_CHARS)
+            .map(LiteralKeyword::always_post_filter);
+
+        let response = list_records_by_keyword(keyword, &params("www", 1, 10), 100, |p| {
+            let response = server_page(&records, &p);
+            async move { Ok(response) }
+        })
+        .await
+        .unwrap();
+
+        // 服务端同时按值命中的记录在本地被剔除，总数随之修正
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.items[0].name, "www");
+        assert_eq!(response.total_count, 1);
+        assert!(!response.has_more);
+    }
+}
@@ -0,0 +1,93 @@
+//! DigitalOcean 错误映射
+//!
+//! 参考: <https://docs.digitalocean.com/reference/api/digitalocean/#section/Introduction/HTTP-Statuses>
+//!
+//! 错误码取响应中的 `id`（如 `not_found`、`unprocessable_entity`），
+//! 同一个 `id` 覆盖多种情况时再按 `message` 细分。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::DigitalOceanProvider;
+
+/// DigitalOcean 错误码映射实现
+impl ProviderErrorMapper for DigitalOceanProvider {
+    fn provider_name(&self) -> &'static str {
+        "digitalocean"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        let message = raw.message.to_lowercase();
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            // token 无效、已吊销或已过期
+            Some("unauthorized" | "Unauthorized") => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 权限不足 ============
+            // 细粒度 token 缺少 domain:read / domain:update 等 scope，或账号被锁定
+            Some("forbidden") => ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 频率限制 ============
+            Some("too_many_requests") => ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 资源不存在 ============
+            // 域名与记录不存在时返回同样的消息，按是否在操作具体记录区分
+            Some("not_found") if context.record_id.is_some() => ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: context.record_id.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+            Some("not_found") => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 记录冲突 ============
+            // CNAME 不能与其他记录同名；同名同值的记录不能重复创建
+            Some("unprocessable_entity")
+                if message.contains("already exists") || message.contains("cannot share") =>
+            {
+                ProviderError::RecordExists {
+                    provider: self.provider_name().to_string(),
+                    record_name: context.record_name.unwrap_or_default(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 参数无效 ============
+            Some("unprocessable_entity" | "bad_request") => {
+                let param = if message.contains("ttl") {
+                    "ttl"
+                } else if message.starts_with("name") {
+                    "name"
+                } else {
+                    "value"
+                };
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: param.to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 服务端错误 ============
+            Some("server_error" | "service_unavailable") => ProviderError::NetworkError {
+                provider: self.provider_name().to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
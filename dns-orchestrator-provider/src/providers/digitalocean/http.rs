@@ -0,0 +1,203 @@
+//! DigitalOcean HTTP 请求方法
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::ErrorResponse;
+use super::{DO_API_BASE, DigitalOceanProvider};
+
+impl DigitalOceanProvider {
+    // ==================== 辅助方法 ====================
+
+    /// 统一处理 DigitalOcean 响应错误
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+
+        if let Ok(error) = serde_json::from_str::<ErrorResponse>(response_text) {
+            log::error!("API 错误: {}", error.message);
+            return Err(self.map_error(RawApiError::with_code(error.id, error.message), ctx));
+        }
+
+        // 回退到通用错误
+        Err(self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}"))))
+    }
+
+    /// 执行请求并返回状态码与响应体
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<(u16, String)> {
+        let mut request = self
+            .client
+            .request(method.clone(), url)
+            .header("Authorization", format!("Bearer {}", self.token));
+        if let Some(body) = body {
+            if log::log_enabled!(log::Level::Debug) {
+                let body_json = serde_json::to_string_pretty(&body)
+                    .unwrap_or_else(|_| "无法序列化请求体".to_string());
+                log::debug!("Request Body: {body_json}");
+            }
+            request = request.json(&body);
+        }
+
+        HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            method.as_str(),
+            url,
+            self.max_retries,
+        )
+        .await
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 请求（`path` 相对于 API 根路径，可带查询参数）
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        self.get_url(&format!("{DO_API_BASE}{path}"), ctx).await
+    }
+
+    /// 按完整 URL 执行 GET 请求（用于跟随 `links.pages.next`）
+    ///
+    /// 只接受 API 根路径下的地址，避免把 token 发往其他主机。
+    pub(crate) async fn get_url<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        if !url.starts_with(&format!("{DO_API_BASE}/")) {
+            return Err(self.parse_error(format!("分页链接不在 API 地址下: {url}")));
+        }
+
+        let (status, response_text) = self.send(reqwest::Method::GET, url, None).await?;
+        self.handle_response_error(status, &response_text, ctx)?;
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 执行 POST 请求
+    pub(crate) async fn post_json<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        self.request_with_body(reqwest::Method::POST, path, body, ctx)
+            .await
+    }
+
+    /// 执行 PUT 请求
+    pub(crate) async fn put_json<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        self.request_with_body(reqwest::Method::PUT, path, body, ctx)
+            .await
+    }
+
+    /// 执行 DELETE 请求（成功时返回 204，无响应体）
+    pub(crate) async fn delete(&self, path: &str, ctx: ErrorContext) -> Result<()> {
+        let url = format!("{DO_API_BASE}{path}");
+        let (status, response_text) = self.send(reqwest::Method::DELETE, &url, None).await?;
+        self.handle_response_error(status, &response_text, ctx)
+    }
+
+    /// 执行带 body 的请求（POST/PUT）
+    async fn request_with_body<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = format!("{DO_API_BASE}{path}");
+        let body = serde_json::to_value(body)
+            .map_err(|e| self.parse_error(format!("无法序列化请求体: {e}")))?;
+
+        let (status, response_text) = self.send(method, &url, Some(body)).await?;
+        self.handle_response_error(status, &response_text, ctx)?;
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ProviderError;
+
+    #[test]
+    fn maps_digitalocean_errors() {
+        let provider = DigitalOceanProvider::new(String::new());
+        let ctx = |record_id: Option<&str>| ErrorContext {
+            record_name: Some("www".to_string()),
+            record_id: record_id.map(ToString::to_string),
+            domain: Some("example.com".to_string()),
+        };
+        let error =
+            |id: &str, message: &str| format!(r#"{{"id": "{id}", "message": "{message}"}}"#);
+
+        let unauthorized = provider.handle_response_error(
+            401,
+            &error("Unauthorized", "Unable to authenticate you"),
+            ctx(None),
+        );
+        assert!(matches!(
+            unauthorized,
+            Err(ProviderError::InvalidCredentials { .. })
+        ));
+
+        let not_found = "The resource you were accessing could not be found.";
+        let no_domain =
+            provider.handle_response_error(404, &error("not_found", not_found), ctx(None));
+        assert!(
+            matches!(no_domain, Err(ProviderError::DomainNotFound { domain, .. }) if domain == "example.com")
+        );
+        let no_record =
+            provider.handle_response_error(404, &error("not_found", not_found), ctx(Some("123")));
+        assert!(
+            matches!(no_record, Err(ProviderError::RecordNotFound { record_id, .. }) if record_id == "123")
+        );
+
+        let conflict = provider.handle_response_error(
+            422,
+            &error(
+                "unprocessable_entity",
+                "CNAME records cannot share a name with other records.",
+            ),
+            ctx(None),
+        );
+        assert!(
+            matches!(conflict, Err(ProviderError::RecordExists { record_name, .. }) if record_name == "www")
+        );
+
+        let ttl = provider.handle_response_error(
+            422,
+            &error("unprocessable_entity", "TTL must be at least 30 seconds"),
+            ctx(None),
+        );
+        assert!(
+            matches!(ttl, Err(ProviderError::InvalidParameter { param, .. }) if param == "ttl")
+        );
+
+        let plain = provider.handle_response_error(502, "Bad Gateway", ctx(None));
+        assert!(matches!(plain, Err(ProviderError::Unknown { .. })));
+    }
+}
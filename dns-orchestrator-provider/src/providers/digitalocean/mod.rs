@@ -0,0 +1,59 @@
+//! DigitalOcean DNS Provider
+
+mod error;
+mod http;
+mod provider;
+mod types;
+
+use reqwest::Client;
+
+use crate::providers::common::create_http_client;
+
+pub(crate) const DO_API_BASE: &str = "https://api.digitalocean.com/v2";
+/// DigitalOcean API 单页最大条目数
+pub(crate) const MAX_PAGE_SIZE: u32 = 200;
+
+/// DigitalOcean DNS Provider
+pub struct DigitalOceanProvider {
+    pub(crate) client: Client,
+    pub(crate) token: String,
+    pub(crate) max_retries: u32,
+}
+
+/// DigitalOcean Provider Builder
+pub struct DigitalOceanProviderBuilder {
+    token: String,
+    max_retries: u32,
+}
+
+impl DigitalOceanProviderBuilder {
+    fn new(token: String) -> Self {
+        Self {
+            token,
+            max_retries: 2, // 默认重试 2 次
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn build(self) -> DigitalOceanProvider {
+        DigitalOceanProvider {
+            client: create_http_client(),
+            token: self.token,
+            max_retries: self.max_retries,
+        }
+    }
+}
+
+impl DigitalOceanProvider {
+    pub fn new(token: String) -> Self {
+        Self::builder(token).build()
+    }
+
+    pub fn builder(token: String) -> DigitalOceanProviderBuilder {
+        DigitalOceanProviderBuilder::new(token)
+    }
+}
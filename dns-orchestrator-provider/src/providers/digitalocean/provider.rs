@@ -0,0 +1,524 @@
+//! DigitalOcean DnsProvider trait 实现
+
+use async_trait::async_trait;
+
+use crate::credential_format::DIGITALOCEAN_TOKEN;
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    encode_path_segment, normalize_domain_name, record_name_matches, record_type_to_string,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, STANDARD_RECORD_TYPES,
+    UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    AccountResponse, Domain, DomainRecord, DomainRecordBody, DomainRecordResponse,
+    DomainRecordsResponse, DomainResponse, DomainsResponse,
+};
+use super::{DigitalOceanProvider, MAX_PAGE_SIZE};
+
+impl DigitalOceanProvider {
+    /// 将 DigitalOcean 域名转换为 ProviderDomain
+    ///
+    /// DigitalOcean 不返回域名状态，已添加的域名即视为正常解析。
+    pub(crate) fn domain_to_provider_domain(domain: Domain) -> ProviderDomain {
+        ProviderDomain {
+            id: domain.name.clone(),
+            name: domain.name,
+            provider: ProviderType::DigitalOcean,
+            status: DomainStatus::Active,
+            provider_status_raw: None,
+            record_count: None,
+        }
+    }
+
+    /// 将 API 返回的主机名转换为完整域名（`@` 表示根域名）
+    fn host_from_api(data: &str, zone_name: &str) -> String {
+        if data == "@" {
+            normalize_domain_name(zone_name)
+        } else {
+            normalize_domain_name(data)
+        }
+    }
+
+    /// 主机名写入时必须以 `.` 结尾，否则会被拼接上当前域名
+    fn host_to_api(host: &str) -> String {
+        if host == "@" || host.ends_with('.') {
+            host.to_string()
+        } else {
+            format!("{host}.")
+        }
+    }
+
+    /// 将 DigitalOcean `domain_record` 转换为 `DnsRecord`
+    pub(crate) fn do_record_to_dns_record(
+        &self,
+        record: DomainRecord,
+        domain_id: &str,
+    ) -> Result<DnsRecord> {
+        let missing = |field: &str| {
+            self.parse_error(format!(
+                "{} record missing {field} field",
+                record.record_type
+            ))
+        };
+        let data = match record.record_type.as_str() {
+            "A" => RecordData::A {
+                address: record.data.clone(),
+            },
+            "AAAA" => RecordData::AAAA {
+                address: record.data.clone(),
+            },
+            "CNAME" => RecordData::CNAME {
+                target: Self::host_from_api(&record.data, domain_id),
+            },
+            "MX" => RecordData::MX {
+                priority: record.priority.ok_or_else(|| missing("priority"))?,
+                exchange: Self::host_from_api(&record.data, domain_id),
+            },
+            "TXT" => RecordData::TXT {
+                text: record.data.clone(),
+            },
+            "NS" => RecordData::NS {
+                nameserver: Self::host_from_api(&record.data, domain_id),
+            },
+            "SRV" => RecordData::SRV {
+                priority: record.priority.ok_or_else(|| missing("priority"))?,
+                weight: record.weight.ok_or_else(|| missing("weight"))?,
+                port: record.port.ok_or_else(|| missing("port"))?,
+                target: Self::host_from_api(&record.data, domain_id),
+            },
+            "CAA" => RecordData::CAA {
+                flags: record.flags.unwrap_or(0),
+                tag: record.tag.clone().ok_or_else(|| missing("tag"))?,
+                value: record.data.clone(),
+            },
+            // SOA 只返回 TTL（data 为 "1800"），无法还原为完整的 SOA 记录
+            other => {
+                return Err(ProviderError::UnsupportedRecordType {
+                    provider: self.provider_name().to_string(),
+                    record_type: other.to_string(),
+                });
+            }
+        };
+
+        Ok(DnsRecord {
+            id: record.id.to_string(),
+            domain_id: domain_id.to_string(),
+            name: record.name,
+            ttl: record.ttl,
+            data,
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        })
+    }
+
+    /// 将 RecordData 转换为 DigitalOcean API 请求体
+    fn build_record_body(
+        &self,
+        name: &str,
+        ttl: u32,
+        data: &RecordData,
+    ) -> Result<DomainRecordBody> {
+        let mut body = DomainRecordBody {
+            record_type: record_type_to_string(&data.record_type()),
+            name: if name.is_empty() { "@" } else { name }.to_string(),
+            data: String::new(),
+            ttl,
+            priority: None,
+            port: None,
+            weight: None,
+            flags: None,
+            tag: None,
+        };
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => {
+                body.data = address.clone();
+            }
+            RecordData::CNAME { target } => body.data = Self::host_to_api(target),
+            RecordData::MX { priority, exchange } => {
+                body.priority = Some(*priority);
+                body.data = Self::host_to_api(exchange);
+            }
+            RecordData::TXT { text } => body.data = text.clone(),
+            RecordData::NS { nameserver } => body.data = Self::host_to_api(nameserver),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => {
+                body.priority = Some(*priority);
+                body.weight = Some(*weight);
+                body.port = Some(*port);
+                body.data = Self::host_to_api(target);
+            }
+            RecordData::CAA { flags, tag, value } => {
+                body.flags = Some(*flags);
+                body.tag = Some(tag.clone());
+                body.data = value.clone();
+            }
+            // SOA 由 DigitalOcean 维护，不开放写入
+            RecordData::SOA { .. } => {
+                return Err(ProviderError::UnsupportedRecordType {
+                    provider: self.provider_name().to_string(),
+                    record_type: "SOA".to_string(),
+                });
+            }
+        }
+        Ok(body)
+    }
+
+    /// 跟随 `links.pages.next` 取出域名下的全部记录
+    async fn list_all_records(
+        &self,
+        domain_id: &str,
+        record_type: Option<&DnsRecordType>,
+    ) -> Result<Vec<DomainRecord>> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let mut path = format!(
+            "/domains/{}/records?page=1&per_page={MAX_PAGE_SIZE}",
+            encode_path_segment(domain_id)
+        );
+        if let Some(record_type) = record_type {
+            path.push_str(&format!("&type={}", record_type_to_string(record_type)));
+        }
+
+        let mut response: DomainRecordsResponse = self.get(&path, ctx.clone()).await?;
+        let mut records = std::mem::take(&mut response.domain_records);
+        while let Some(next) = response.links.pages.and_then(|pages| pages.next) {
+            response = self.get_url(&next, ctx.clone()).await?;
+            records.append(&mut response.domain_records);
+        }
+        Ok(records)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for DigitalOceanProvider {
+    fn id(&self) -> &'static str {
+        "digitalocean"
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::DigitalOcean,
+            name: "DigitalOcean".to_string(),
+            description: "面向开发者的云平台，提供免费 DNS 托管".to_string(),
+            required_fields: vec![ProviderCredentialField {
+                key: "token".to_string(),
+                label: "Personal Access Token".to_string(),
+                field_type: FieldType::Password,
+                placeholder: Some("输入 DigitalOcean Personal Access Token".to_string()),
+                help_text: Some(
+                    "在 DigitalOcean 控制台 -> API -> Tokens 创建，需要 domain 读写权限"
+                        .to_string(),
+                ),
+                pattern: Some(DIGITALOCEAN_TOKEN.pattern()),
+                options: None,
+            }],
+            optional_fields: vec![],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE,
+                max_page_size_records: MAX_PAGE_SIZE,
+                min_ttl: 30,
+                max_ttl: 86400,
+                max_batch_changes: 0,
+                max_txt_length: 0,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<AccountResponse>("/account", ErrorContext::default())
+            .await
+        {
+            Ok(resp) => Ok(resp.account.status != "locked"),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let response: DomainsResponse = self
+            .get(
+                &format!(
+                    "/domains?page={}&per_page={}",
+                    params.page,
+                    params.page_size.min(MAX_PAGE_SIZE)
+                ),
+                ErrorContext::default(),
+            )
+            .await?;
+
+        let total_count = response.meta.map_or(0, |meta| meta.total);
+        let domains = response
+            .domains
+            .into_iter()
+            .map(Self::domain_to_provider_domain)
+            .collect();
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let response: DomainResponse = self
+            .get(&format!("/domains/{}", encode_path_segment(domain_id)), ctx)
+            .await?;
+        Ok(Self::domain_to_provider_domain(response.domain))
+    }
+
+    /// 记录接口不支持按名称片段搜索，取全部记录后在本地过滤、分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let records = self
+            .list_all_records(domain_id, params.record_type.as_ref())
+            .await?;
+
+        let keyword = params
+            .keyword
+            .as_deref()
+            .filter(|k| !k.is_empty())
+            .map(str::to_lowercase);
+        let records: Vec<DnsRecord> = records
+            .into_iter()
+            // 跳过 SOA 等无法表示的记录
+            .filter_map(|r| self.do_record_to_dns_record(r, domain_id).ok())
+            .filter(|r| {
+                keyword
+                    .as_ref()
+                    .is_none_or(|keyword| record_name_matches(r, keyword))
+            })
+            .collect();
+
+        let total_count = u32::try_from(records.len()).unwrap_or(u32::MAX);
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        let body = self.build_record_body(&req.name, req.ttl, &req.data)?;
+
+        let response: DomainRecordResponse = self
+            .post_json(
+                &format!("/domains/{}/records", encode_path_segment(&req.domain_id)),
+                &body,
+                ctx,
+            )
+            .await?;
+
+        self.do_record_to_dns_record(response.domain_record, &req.domain_id)
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let body = self.build_record_body(&req.name, req.ttl, &req.data)?;
+
+        let response: DomainRecordResponse = self
+            .put_json(
+                &format!(
+                    "/domains/{}/records/{}",
+                    encode_path_segment(&req.domain_id),
+                    encode_path_segment(record_id)
+                ),
+                &body,
+                ctx,
+            )
+            .await?;
+
+        self.do_record_to_dns_record(response.domain_record, &req.domain_id)
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.delete(
+            &format!(
+                "/domains/{}/records/{}",
+                encode_path_segment(domain_id),
+                encode_path_segment(record_id)
+            ),
+            ctx,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(json: &str) -> DomainRecord {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn converts_domain_records() {
+        let provider = DigitalOceanProvider::new(String::new());
+
+        let mx = provider
+            .do_record_to_dns_record(
+                record(
+                    r#"{"id": 28448433, "type": "MX", "name": "@", "data": "mail.example.com", "priority": 10, "port": null, "ttl": 1800, "weight": null, "flags": null, "tag": null}"#,
+                ),
+                "example.com",
+            )
+            .unwrap();
+        assert_eq!(mx.id, "28448433");
+        assert_eq!(mx.name, "@");
+        assert_eq!(
+            mx.data,
+            RecordData::MX {
+                priority: 10,
+                exchange: "mail.example.com".to_string(),
+            }
+        );
+
+        let cname = provider
+            .do_record_to_dns_record(
+                record(
+                    r#"{"id": 3352896, "type": "CNAME", "name": "www", "data": "@", "priority": null, "port": null, "ttl": 3600, "weight": null, "flags": null, "tag": null}"#,
+                ),
+                "example.com",
+            )
+            .unwrap();
+        assert_eq!(
+            cname.data,
+            RecordData::CNAME {
+                target: "example.com".to_string(),
+            }
+        );
+
+        let caa = provider
+            .do_record_to_dns_record(
+                record(
+                    r#"{"id": 3352897, "type": "CAA", "name": "@", "data": "letsencrypt.org", "priority": null, "port": null, "ttl": 3600, "weight": null, "flags": 0, "tag": "issue"}"#,
+                ),
+                "example.com",
+            )
+            .unwrap();
+        assert_eq!(
+            caa.data,
+            RecordData::CAA {
+                flags: 0,
+                tag: "issue".to_string(),
+                value: "letsencrypt.org".to_string(),
+            }
+        );
+
+        let soa = provider.do_record_to_dns_record(
+            record(
+                r#"{"id": 1, "type": "SOA", "name": "@", "data": "1800", "priority": null, "port": null, "ttl": 1800, "weight": null, "flags": null, "tag": null}"#,
+            ),
+            "example.com",
+        );
+        assert!(matches!(
+            soa,
+            Err(ProviderError::UnsupportedRecordType { .. })
+        ));
+    }
+
+    #[test]
+    fn builds_record_bodies() {
+        let provider = DigitalOceanProvider::new(String::new());
+
+        let srv = provider
+            .build_record_body(
+                "_sip._tcp",
+                600,
+                &RecordData::SRV {
+                    priority: 10,
+                    weight: 5,
+                    port: 5060,
+                    target: "sip.example.com".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&srv).unwrap(),
+            serde_json::json!({
+                "type": "SRV",
+                "name": "_sip._tcp",
+                "data": "sip.example.com.",
+                "ttl": 600,
+                "priority": 10,
+                "port": 5060,
+                "weight": 5,
+            })
+        );
+
+        let txt = provider
+            .build_record_body(
+                "",
+                1800,
+                &RecordData::TXT {
+                    text: "v=spf1 -all".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&txt).unwrap(),
+            serde_json::json!({
+                "type": "TXT",
+                "name": "@",
+                "data": "v=spf1 -all",
+                "ttl": 1800,
+            })
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! DigitalOcean API 类型定义
+
+use serde::{Deserialize, Serialize};
+
+// ============ DigitalOcean API 响应结构 ============
+
+/// 错误响应：{"id": "not_found", "message": "..."}
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub id: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountResponse {
+    pub account: Account,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Account {
+    /// `active`、`warning` 或 `locked`
+    pub status: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Links {
+    pub pages: Option<Pages>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Pages {
+    /// 下一页的完整 URL，最后一页不返回
+    pub next: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Meta {
+    pub total: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainsResponse {
+    #[serde(default)]
+    pub domains: Vec<Domain>,
+    pub meta: Option<Meta>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainResponse {
+    pub domain: Domain,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Domain {
+    /// 域名（同时作为 API 路径中的标识）
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainRecordsResponse {
+    #[serde(default)]
+    pub domain_records: Vec<DomainRecord>,
+    #[serde(default)]
+    pub links: Links,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainRecordResponse {
+    pub domain_record: DomainRecord,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainRecord {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// 相对名称，根域名为 `@`
+    pub name: String,
+    pub data: String,
+    pub priority: Option<u16>,
+    pub port: Option<u16>,
+    pub ttl: u32,
+    pub weight: Option<u16>,
+    pub flags: Option<u8>,
+    pub tag: Option<String>,
+}
+
+// ============ DigitalOcean API 请求结构 ============
+
+/// 创建/更新记录的请求体
+#[derive(Debug, Serialize)]
+pub struct DomainRecordBody {
+    #[serde(rename = "type")]
+    pub record_type: &'static str,
+    pub name: String,
+    pub data: String,
+    pub ttl: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
@@ -3,13 +3,17 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
+use crate::credential_format::{DNSPOD_SECRET_ID, DNSPOD_SECRET_KEY};
 use crate::error::{ProviderError, Result};
-use crate::providers::common::record_type_to_string;
+use crate::providers::common::{
+    KEYWORD_SPECIAL_CHARS, LiteralKeyword, list_records_by_keyword, record_type_to_string,
+};
 use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
 use crate::types::{
     CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
     PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, RecordSortField,
+    STANDARD_RECORD_TYPES, SortOrder, UpdateDnsRecordRequest,
 };
 
 use super::{
@@ -19,13 +23,22 @@ use super::{
 
 impl DnspodProvider {
     /// 将 DNSPod 域名状态转换为内部状态
+    /// DNSPod 状态：ENABLE, PAUSE, SPAM, LOCK；DNSStatus 为 DNSERROR 表示 NS 未指向 DNSPod
     pub(crate) fn convert_domain_status(status: &str, dns_status: &str) -> DomainStatus {
-        match (status, dns_status) {
-            ("ENABLE" | "enable", "") => DomainStatus::Active,
-            ("PAUSE" | "pause", _) => DomainStatus::Paused,
-            ("ENABLE" | "enable", "DNSERROR") => DomainStatus::Error,
-            ("SPAM" | "spam", _) => DomainStatus::Error,
-            _ => DomainStatus::Unknown,
+        match (status.to_ascii_uppercase().as_str(), dns_status) {
+            ("ENABLE", "") => DomainStatus::Active,
+            ("ENABLE", "DNSERROR") => DomainStatus::Pending,
+            ("PAUSE" | "SPAM" | "LOCK", _) => DomainStatus::Suspended,
+            _ => DomainStatus::Unknown(Self::raw_domain_status(status, dns_status)),
+        }
+    }
+
+    /// 原始状态字符串（`Status` 与非空的 `DNSStatus` 以 `/` 拼接）
+    pub(crate) fn raw_domain_status(status: &str, dns_status: &str) -> String {
+        if dns_status.is_empty() {
+            status.to_string()
+        } else {
+            format!("{status}/{dns_status}")
         }
     }
 
@@ -127,6 +140,113 @@ impl DnspodProvider {
                 target,
             } => (format!("{priority} {weight} {port} {target}"), None),
             RecordData::CAA { flags, tag, value } => (format!("{flags} {tag} \"{value}\""), None),
+            RecordData::SOA { .. } => (data.display_value(), None),
+        }
+    }
+
+    /// 执行一次 DescribeRecordList 分页查询（`params.keyword` 原样作为 Keyword）
+    async fn query_records(
+        &self,
+        domain_id: &str,
+        domain_name: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        #[derive(Serialize)]
+        struct DescribeRecordListRequest {
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "Offset")]
+            offset: u32,
+            #[serde(rename = "Limit")]
+            limit: u32,
+            #[serde(rename = "Keyword", skip_serializing_if = "Option::is_none")]
+            keyword: Option<String>,
+            #[serde(rename = "RecordType", skip_serializing_if = "Option::is_none")]
+            record_type: Option<String>,
+            #[serde(rename = "SortField", skip_serializing_if = "Option::is_none")]
+            sort_field: Option<&'static str>,
+            #[serde(rename = "SortType", skip_serializing_if = "Option::is_none")]
+            sort_type: Option<&'static str>,
+        }
+
+        let offset = (params.page - 1) * params.page_size;
+        let req = DescribeRecordListRequest {
+            domain: domain_name.to_string(),
+            offset,
+            limit: params.page_size.min(MAX_PAGE_SIZE),
+            keyword: params.keyword.clone(),
+            record_type: params
+                .record_type
+                .as_ref()
+                .map(|t| record_type_to_string(t).to_string()),
+            sort_field: params.sort.map(|sort| match sort.field {
+                RecordSortField::Name => "name",
+                RecordSortField::Type => "type",
+                RecordSortField::Value => "value",
+                RecordSortField::Ttl => "ttl",
+            }),
+            sort_type: params.sort.map(|sort| match sort.order {
+                SortOrder::Asc => "ASC",
+                SortOrder::Desc => "DESC",
+            }),
+        };
+
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let response: Result<RecordListResponse> =
+            self.request("DescribeRecordList", &req, ctx).await;
+
+        match response {
+            Ok(data) => {
+                let total_count = data
+                    .record_count_info
+                    .and_then(|c| c.total_count)
+                    .unwrap_or(0);
+
+                let records = data
+                    .record_list
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|r| {
+                        let data = Self::parse_record_data(&r.record_type, &r.value, r.mx).ok()?;
+                        Some(DnsRecord {
+                            id: r.record_id.to_string(),
+                            domain_id: domain_id.to_string(),
+                            name: r.name,
+                            ttl: r.ttl,
+                            data,
+                            proxied: None,
+                            created_at: None,
+                            updated_at: r.updated_on.and_then(|s| {
+                                chrono::DateTime::parse_from_rfc3339(&s)
+                                    .ok()
+                                    .map(|dt| dt.with_timezone(&chrono::Utc))
+                            }),
+                        })
+                    })
+                    .collect();
+
+                Ok(PaginatedResponse::new(
+                    records,
+                    params.page,
+                    params.page_size,
+                    total_count,
+                ))
+            }
+            Err(ProviderError::Unknown { raw_code, .. })
+                if raw_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
+            {
+                Ok(PaginatedResponse::new(
+                    vec![],
+                    params.page,
+                    params.page_size,
+                    0,
+                ))
+            }
+            Err(e) => Err(e),
         }
     }
 }
@@ -149,6 +269,8 @@ impl DnsProvider for DnspodProvider {
                     field_type: FieldType::Text,
                     placeholder: Some("输入 SecretId".to_string()),
                     help_text: None,
+                    pattern: Some(DNSPOD_SECRET_ID.pattern()),
+                    options: None,
                 },
                 ProviderCredentialField {
                     key: "secretKey".to_string(),
@@ -156,12 +278,23 @@ impl DnsProvider for DnspodProvider {
                     field_type: FieldType::Password,
                     placeholder: Some("输入 SecretKey".to_string()),
                     help_text: None,
+                    pattern: Some(DNSPOD_SECRET_KEY.pattern()),
+                    options: None,
                 },
             ],
-            features: ProviderFeatures::default(),
+            optional_fields: vec![],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                server_side_sort: true,
+                ..ProviderFeatures::default()
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 3000,
                 max_page_size_records: 3000,
+                min_ttl: 600,
+                max_ttl: 604_800,
+                max_batch_changes: 0,
+                max_txt_length: 512,
             },
         }
     }
@@ -230,6 +363,7 @@ impl DnsProvider for DnspodProvider {
                 name: d.name,
                 provider: ProviderType::Dnspod,
                 status: Self::convert_domain_status(&d.status, &d.dns_status),
+                provider_status_raw: Some(Self::raw_domain_status(&d.status, &d.dns_status)),
                 record_count: d.record_count,
             })
             .collect();
@@ -271,6 +405,7 @@ impl DnsProvider for DnspodProvider {
                 name: info.domain,
                 provider: ProviderType::Dnspod,
                 status: Self::convert_domain_status(&info.status, &info.dns_status),
+                provider_status_raw: Some(Self::raw_domain_status(&info.status, &info.dns_status)),
                 record_count: info.record_count,
             });
         }
@@ -298,91 +433,16 @@ impl DnsProvider for DnspodProvider {
         domain_id: &str,
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>> {
-        #[derive(Serialize)]
-        struct DescribeRecordListRequest {
-            #[serde(rename = "Domain")]
-            domain: String,
-            #[serde(rename = "Offset")]
-            offset: u32,
-            #[serde(rename = "Limit")]
-            limit: u32,
-            #[serde(rename = "Keyword", skip_serializing_if = "Option::is_none")]
-            keyword: Option<String>,
-            #[serde(rename = "RecordType", skip_serializing_if = "Option::is_none")]
-            record_type: Option<String>,
-        }
-
         let domain_info = self.get_domain(domain_id).await?;
 
-        let offset = (params.page - 1) * params.page_size;
-        let req = DescribeRecordListRequest {
-            domain: domain_info.name,
-            offset,
-            limit: params.page_size.min(MAX_PAGE_SIZE),
-            keyword: params.keyword.clone().filter(|k| !k.is_empty()),
-            record_type: params
-                .record_type
-                .as_ref()
-                .map(|t| record_type_to_string(t).to_string()),
-        };
-
-        let ctx = ErrorContext {
-            domain: Some(domain_id.to_string()),
-            ..Default::default()
-        };
-
-        let response: Result<RecordListResponse> =
-            self.request("DescribeRecordList", &req, ctx).await;
-
-        match response {
-            Ok(data) => {
-                let total_count = data
-                    .record_count_info
-                    .and_then(|c| c.total_count)
-                    .unwrap_or(0);
-
-                let records = data
-                    .record_list
-                    .unwrap_or_default()
-                    .into_iter()
-                    .filter_map(|r| {
-                        let data = Self::parse_record_data(&r.record_type, &r.value, r.mx).ok()?;
-                        Some(DnsRecord {
-                            id: r.record_id.to_string(),
-                            domain_id: domain_id.to_string(),
-                            name: r.name,
-                            ttl: r.ttl,
-                            data,
-                            proxied: None,
-                            created_at: None,
-                            updated_at: r.updated_on.and_then(|s| {
-                                chrono::DateTime::parse_from_rfc3339(&s)
-                                    .ok()
-                                    .map(|dt| dt.with_timezone(&chrono::Utc))
-                            }),
-                        })
-                    })
-                    .collect();
-
-                Ok(PaginatedResponse::new(
-                    records,
-                    params.page,
-                    params.page_size,
-                    total_count,
-                ))
-            }
-            Err(ProviderError::Unknown { raw_code, .. })
-                if raw_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
-            {
-                Ok(PaginatedResponse::new(
-                    vec![],
-                    params.page,
-                    params.page_size,
-                    0,
-                ))
-            }
-            Err(e) => Err(e),
-        }
+        // DNSPod 的 Keyword 同时匹配主机记录和记录值，需要在本地按名称二次过滤
+        let keyword = LiteralKeyword::new(params.keyword.as_deref(), KEYWORD_SPECIAL_CHARS)
+            .map(LiteralKeyword::always_post_filter);
+        list_records_by_keyword(keyword, params, MAX_PAGE_SIZE, |params| {
+            let domain_name = &domain_info.name;
+            async move { self.query_records(domain_id, domain_name, &params).await }
+        })
+        .await
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
@@ -550,3 +610,39 @@ impl DnsProvider for DnspodProvider {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_domain_status() {
+        for (status, dns_status, expected) in [
+            ("ENABLE", "", DomainStatus::Active),
+            ("enable", "", DomainStatus::Active),
+            ("ENABLE", "DNSERROR", DomainStatus::Pending),
+            ("PAUSE", "", DomainStatus::Suspended),
+            ("SPAM", "", DomainStatus::Suspended),
+            ("LOCK", "DNSERROR", DomainStatus::Suspended),
+            (
+                "ENABLE",
+                "OTHER",
+                DomainStatus::Unknown("ENABLE/OTHER".to_string()),
+            ),
+        ] {
+            assert_eq!(
+                DnspodProvider::convert_domain_status(status, dns_status),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn joins_raw_status() {
+        assert_eq!(DnspodProvider::raw_domain_status("ENABLE", ""), "ENABLE");
+        assert_eq!(
+            DnspodProvider::raw_domain_status("ENABLE", "DNSERROR"),
+            "ENABLE/DNSERROR"
+        );
+    }
+}
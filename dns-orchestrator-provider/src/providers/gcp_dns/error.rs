@@ -0,0 +1,113 @@
+//! Google Cloud DNS 错误映射
+//!
+//! 参考:
+//! - <https://cloud.google.com/dns/docs/troubleshooting>
+//! - <https://cloud.google.com/apis/design/errors>
+//!
+//! 错误码取响应中 `errors[0].reason`（如 `alreadyExists`），缺失时取 `status`
+//! （如 `UNAUTHENTICATED`）；token 端点的错误以 `oauth:` 前缀区分。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::GcpDnsProvider;
+
+/// Cloud DNS 错误码映射实现
+impl ProviderErrorMapper for GcpDnsProvider {
+    fn provider_name(&self) -> &'static str {
+        "gcp_dns"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some(
+                "authError"                    // access token 无效
+                | "UNAUTHENTICATED"
+                | "oauth:invalid_grant"        // 私钥无效、服务账号已删除或时钟偏差过大
+                | "oauth:invalid_client"
+                | "oauth:unauthorized_client"
+                | "invalidCredentials",        // 密钥文件无法解析
+            ) => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 权限/操作被拒绝 ============
+            Some(
+                "forbidden"                    // 服务账号缺少 IAM 角色
+                | "PERMISSION_DENIED"
+                | "accessNotConfigured",       // 项目未启用 Cloud DNS API
+            ) => ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 配额/频率限制 ============
+            Some(
+                "rateLimitExceeded"
+                | "userRateLimitExceeded"
+                | "quotaExceeded"              // 记录集数量或单次变更条数超限
+                | "RESOURCE_EXHAUSTED",
+            ) => ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 记录已存在 ============
+            Some("alreadyExists" | "ALREADY_EXISTS") => ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 域名不存在 ============
+            Some("notFound" | "NOT_FOUND")
+                if raw.message.contains("managedZone") || context.record_id.is_none() =>
+            {
+                ProviderError::DomainNotFound {
+                    provider: self.provider_name().to_string(),
+                    domain: context.domain.unwrap_or_default(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 记录不存在 ============
+            // 删除的记录集与现有记录集不一致时返回 conditionNotMet（期间被其他人修改）
+            Some("notFound" | "NOT_FOUND" | "conditionNotMet") => ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: context.record_id.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 参数无效 - TTL ============
+            Some("invalid" | "INVALID_ARGUMENT") if raw.message.contains("ttl") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: "ttl".to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 参数无效 - 记录值 ============
+            Some("invalid" | "INVALID_ARGUMENT" | "required" | "badRequest") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: "value".to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 网络/后端服务错误 ============
+            Some("backendError" | "internalError" | "UNAVAILABLE" | "INTERNAL") => {
+                ProviderError::NetworkError {
+                    provider: self.provider_name().to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
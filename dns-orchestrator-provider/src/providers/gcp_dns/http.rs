@@ -0,0 +1,354 @@
+//! Google Cloud DNS HTTP 请求方法
+
+use std::sync::PoisonError;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+use crate::http_client::HttpUtils;
+use crate::providers::common::encode_path_segment;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::auth::{AccessToken, ServiceAccountKey};
+use super::types::{Change, ErrorResponse, TokenErrorResponse, TokenResponse};
+use super::{GCP_DNS_API_BASE, GCP_DNS_SCOPE, GcpDnsProvider};
+
+/// JWT Bearer 授权类型
+const JWT_BEARER_GRANT: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+/// access token 到期前提前刷新的余量
+const TOKEN_REFRESH_MARGIN: chrono::Duration = chrono::Duration::minutes(5);
+/// 轮询 `changes.get` 的间隔
+const CHANGE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+impl GcpDnsProvider {
+    // ==================== 辅助方法 ====================
+
+    /// 项目下的 API 地址（`path` 以 `/` 开头）
+    fn url(&self, path: &str, query: &str) -> String {
+        let url = format!(
+            "{GCP_DNS_API_BASE}/projects/{}{path}",
+            encode_path_segment(&self.project_id)
+        );
+        if query.is_empty() {
+            url
+        } else {
+            format!("{url}?{query}")
+        }
+    }
+
+    /// 统一处理 Cloud DNS 响应错误
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+
+        // 通用错误结构：{"error": {"code", "message", "errors": [{"reason"}], "status"}}
+        if let Ok(response) = serde_json::from_str::<ErrorResponse>(response_text) {
+            let error = response.error;
+            let code = error
+                .errors
+                .into_iter()
+                .find_map(|item| item.reason)
+                .or(error.status)
+                .unwrap_or_else(|| error.code.unwrap_or(status).to_string());
+            return Err(self.map_error(
+                RawApiError::with_code(
+                    code,
+                    error
+                        .message
+                        .unwrap_or_else(|| "No error message provided by API".to_string()),
+                ),
+                ctx,
+            ));
+        }
+
+        // 回退到通用错误
+        Err(self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}"))))
+    }
+
+    /// 检查响应并解析 JSON；401 时丢弃缓存的 access token
+    fn parse_response<T: DeserializeOwned>(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        if status == 401 {
+            *self.token.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        }
+        self.handle_response_error(status, response_text, ctx)?;
+        HttpUtils::parse_json(response_text, self.provider_name())
+    }
+
+    /// 当前的 access token（缓存未临近过期时直接使用）
+    async fn access_token(&self) -> Result<String> {
+        if let Some(token) = self.cached_token() {
+            return Ok(token);
+        }
+
+        let token = self.fetch_token().await?;
+        let value = token.token.clone();
+        *self.token.lock().unwrap_or_else(PoisonError::into_inner) = Some(token);
+        Ok(value)
+    }
+
+    /// 未临近过期的缓存 access token
+    fn cached_token(&self) -> Option<String> {
+        let now = Utc::now();
+        self.token
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .filter(|token| token.expires_at - TOKEN_REFRESH_MARGIN > now)
+            .map(|token| token.token.clone())
+    }
+
+    /// 用服务账号签发的 JWT 换取 access token
+    async fn fetch_token(&self) -> Result<AccessToken> {
+        let invalid = |reason: String| {
+            self.map_error(
+                RawApiError::with_code("invalidCredentials", reason),
+                ErrorContext::default(),
+            )
+        };
+        let key = ServiceAccountKey::parse(&self.service_account_json).map_err(invalid)?;
+        let now = Utc::now();
+        let assertion = key.assertion(GCP_DNS_SCOPE, now).map_err(invalid)?;
+
+        let body = format!(
+            "grant_type={}&assertion={assertion}",
+            urlencoding::encode(JWT_BEARER_GRANT)
+        );
+        let request = self
+            .client
+            .post(&key.token_uri)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body);
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            &key.token_uri,
+            self.max_retries,
+        )
+        .await?;
+
+        // token 端点的错误结构：{"error": "invalid_grant", "error_description": "..."}
+        if !(200..300).contains(&status) {
+            return Err(
+                match serde_json::from_str::<TokenErrorResponse>(&response_text) {
+                    Ok(error) => {
+                        let code = format!("oauth:{}", error.error);
+                        self.map_error(
+                            RawApiError::with_code(
+                                code,
+                                error.error_description.unwrap_or(error.error),
+                            ),
+                            ErrorContext::default(),
+                        )
+                    }
+                    Err(_) => self
+                        .unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}"))),
+                },
+            );
+        }
+
+        let response: TokenResponse = HttpUtils::parse_json(&response_text, self.provider_name())?;
+        log::info!(
+            "[gcp_dns] Obtained access token for {}, expires in {}s",
+            key.client_email,
+            response.expires_in
+        );
+
+        Ok(AccessToken {
+            token: response.access_token,
+            expires_at: now + chrono::Duration::seconds(response.expires_in),
+        })
+    }
+
+    // ==================== 公开 API 方法 ====================
+
+    /// 执行 GET 请求（`path` 相对于 `/projects/{project}`）
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = self.url(path, query);
+        let request = self.client.get(&url).header(
+            "Authorization",
+            format!("Bearer {}", self.access_token().await?),
+        );
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "GET",
+            &url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.parse_response(status, &response_text, ctx)
+    }
+
+    /// 执行 POST 请求（`path` 相对于 `/projects/{project}`）
+    async fn post<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = self.url(path, "");
+
+        if log::log_enabled!(log::Level::Debug) {
+            let body_json = serde_json::to_string_pretty(body)
+                .unwrap_or_else(|_| "无法序列化请求体".to_string());
+            log::debug!("Request Body: {body_json}");
+        }
+
+        let request = self
+            .client
+            .post(&url)
+            .header(
+                "Authorization",
+                format!("Bearer {}", self.access_token().await?),
+            )
+            .json(body);
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            &url,
+            self.max_retries,
+        )
+        .await?;
+
+        self.parse_response(status, &response_text, ctx)
+    }
+
+    /// 提交变更并等待生效
+    ///
+    /// 同一变更内的增删原子生效；提交后轮询 `changes.get` 直到 `done`，
+    /// 超时只记录警告（变更已被接受，稍后仍会生效）。
+    pub(crate) async fn submit_change(
+        &self,
+        zone: &str,
+        change: &Change,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let path = format!("/managedZones/{}/changes", encode_path_segment(zone));
+        let response: Change = self.post(&path, change, ctx).await?;
+        self.wait_for_change(zone, response).await
+    }
+
+    /// 轮询变更状态直到 `done` 或超时
+    async fn wait_for_change(&self, zone: &str, change: Change) -> Result<()> {
+        if change.status == "done" || change.id.is_empty() || self.change_timeout.is_zero() {
+            return Ok(());
+        }
+
+        let path = format!(
+            "/managedZones/{}/changes/{}",
+            encode_path_segment(zone),
+            encode_path_segment(&change.id)
+        );
+        let deadline = Instant::now() + self.change_timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                log::warn!(
+                    "[gcp_dns] Change {} still pending after {}s",
+                    change.id,
+                    self.change_timeout.as_secs()
+                );
+                return Ok(());
+            }
+            tokio::time::sleep(CHANGE_POLL_INTERVAL.min(remaining)).await;
+
+            let response: Change = self.get(&path, "", ErrorContext::default()).await?;
+            if response.status == "done" {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ProviderError;
+
+    #[test]
+    fn maps_cloud_dns_errors() {
+        let provider = GcpDnsProvider::new("my-project".to_string(), String::new());
+        let ctx = || ErrorContext {
+            record_name: Some("www".to_string()),
+            record_id: Some("www.example.com|A|0123456789ab".to_string()),
+            domain: Some("example-zone".to_string()),
+        };
+        let error = |code: u16, reason: &str, message: &str| {
+            format!(
+                r#"{{"error": {{"code": {code}, "message": "{message}", "errors": [{{"message": "{message}", "domain": "global", "reason": "{reason}"}}]}}}}"#
+            )
+        };
+
+        let exists = provider.handle_response_error(
+            409,
+            &error(
+                409,
+                "alreadyExists",
+                "The resource 'entity.change.additions[0]' named 'www.example.com. (A)' already exists",
+            ),
+            ctx(),
+        );
+        assert!(
+            matches!(exists, Err(ProviderError::RecordExists { record_name, .. }) if record_name == "www")
+        );
+
+        let no_zone = provider.handle_response_error(
+            404,
+            &error(
+                404,
+                "notFound",
+                "The 'parameters.managedZone' resource named 'example-zone' does not exist.",
+            ),
+            ctx(),
+        );
+        assert!(
+            matches!(no_zone, Err(ProviderError::DomainNotFound { domain, .. }) if domain == "example-zone")
+        );
+
+        let stale = provider.handle_response_error(
+            412,
+            &error(
+                412,
+                "conditionNotMet",
+                "Precondition not met for 'entity.change.deletions[0]'",
+            ),
+            ctx(),
+        );
+        assert!(matches!(stale, Err(ProviderError::RecordNotFound { .. })));
+
+        let unauthenticated = provider.handle_response_error(
+            401,
+            r#"{"error": {"code": 401, "message": "Request had invalid authentication credentials.", "status": "UNAUTHENTICATED"}}"#,
+            ctx(),
+        );
+        assert!(matches!(
+            unauthenticated,
+            Err(ProviderError::InvalidCredentials { .. })
+        ));
+    }
+}
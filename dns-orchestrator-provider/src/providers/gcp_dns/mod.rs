@@ -0,0 +1,91 @@
+//! Google Cloud DNS Provider
+
+mod auth;
+mod error;
+mod http;
+mod provider;
+mod types;
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::providers::common::create_http_client;
+
+use auth::AccessToken;
+
+pub(crate) const GCP_DNS_API_BASE: &str = "https://dns.googleapis.com/dns/v1";
+/// Cloud DNS 读写权限
+pub(crate) const GCP_DNS_SCOPE: &str = "https://www.googleapis.com/auth/ndev.clouddns.readwrite";
+/// managedZones.list 单页数量
+pub(crate) const MAX_ZONE_PAGE_SIZE: u32 = 100;
+/// resourceRecordSets.list 单页数量
+pub(crate) const MAX_RECORD_PAGE_SIZE: u32 = 500;
+/// 批量提交的单批变更数上限（每个 change 默认最多 100 条 additions 与 100 条 deletions，
+/// 单条变更最多涉及两个记录集，保守取值）
+pub(crate) const MAX_BATCH_CHANGES: u32 = 50;
+/// 等待变更生效（done）的默认超时
+const DEFAULT_CHANGE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Google Cloud DNS Provider
+pub struct GcpDnsProvider {
+    pub(crate) client: Client,
+    pub(crate) project_id: String,
+    /// 服务账号 JSON 密钥文件内容
+    pub(crate) service_account_json: String,
+    pub(crate) max_retries: u32,
+    pub(crate) change_timeout: Duration,
+    pub(crate) token: Mutex<Option<AccessToken>>,
+}
+
+/// Google Cloud DNS Provider Builder
+pub struct GcpDnsProviderBuilder {
+    project_id: String,
+    service_account_json: String,
+    max_retries: u32,
+    change_timeout: Duration,
+}
+
+impl GcpDnsProviderBuilder {
+    fn new(project_id: String, service_account_json: String) -> Self {
+        Self {
+            project_id,
+            service_account_json,
+            max_retries: 2,
+            change_timeout: DEFAULT_CHANGE_TIMEOUT,
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置等待变更生效（done）的超时，为零时提交后不等待
+    pub fn change_timeout(mut self, timeout: Duration) -> Self {
+        self.change_timeout = timeout;
+        self
+    }
+
+    pub fn build(self) -> GcpDnsProvider {
+        GcpDnsProvider {
+            client: create_http_client(),
+            project_id: self.project_id,
+            service_account_json: self.service_account_json,
+            max_retries: self.max_retries,
+            change_timeout: self.change_timeout,
+            token: Mutex::new(None),
+        }
+    }
+}
+
+impl GcpDnsProvider {
+    pub fn new(project_id: String, service_account_json: String) -> Self {
+        Self::builder(project_id, service_account_json).build()
+    }
+
+    pub fn builder(project_id: String, service_account_json: String) -> GcpDnsProviderBuilder {
+        GcpDnsProviderBuilder::new(project_id, service_account_json)
+    }
+}
@@ -0,0 +1,964 @@
+//! Google Cloud DNS DnsProvider trait 实现
+//!
+//! Cloud DNS 以记录集（同名同类型一组值）为单位，没有单条记录 ID，单条记录的 ID 由
+//! `完整名称|类型|值摘要` 合成；增删改读取所在记录集后在一个 change 中删除旧记录集、
+//! 写入新记录集，TTL 对整组生效。带路由策略的记录集不在列表中展示。
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::credential_format::GCP_PROJECT_ID;
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    encode_path_segment, full_name_to_relative, normalize_domain_name, parse_record_type,
+    parse_soa, record_name_matches, record_type_to_string, relative_to_full_name,
+};
+use crate::traits::{DnsProvider, ErrorContext, fallback_after_batch_error};
+use crate::types::{
+    ChangeResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType,
+    PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordChange, RecordData, RecordQueryParams,
+    RecordSet, STANDARD_RECORD_TYPES, TxtFormat, UpdateDnsRecordRequest,
+};
+use crate::utils::txt::{self, EscapeRadix};
+
+use super::types::{
+    Change, ManagedZone, ManagedZonesListResponse, ResourceRecordSet,
+    ResourceRecordSetsListResponse,
+};
+use super::{GcpDnsProvider, MAX_BATCH_CHANGES, MAX_RECORD_PAGE_SIZE, MAX_ZONE_PAGE_SIZE};
+
+/// 合成记录 ID 中值摘要的长度（十六进制字符）
+const VALUE_DIGEST_LEN: usize = 12;
+
+/// 合成的记录 ID：`完整名称|类型|值摘要`
+#[derive(Debug)]
+struct RecordId {
+    /// 完整名称（小写，不含末尾的点）
+    name: String,
+    record_type: DnsRecordType,
+    digest: String,
+}
+
+impl RecordId {
+    fn new(name: &str, record_type: DnsRecordType, value: &str) -> Self {
+        Self {
+            name: normalize_domain_name(name).to_lowercase(),
+            digest: GcpDnsProvider::value_digest(&record_type, value),
+            record_type,
+        }
+    }
+
+    fn parse(id: &str) -> Result<Self> {
+        let invalid = || ProviderError::RecordNotFound {
+            provider: "gcp_dns".to_string(),
+            record_id: id.to_string(),
+            raw_message: Some("Invalid Cloud DNS record ID".to_string()),
+        };
+        let mut parts = id.splitn(3, '|');
+        let (Some(name), Some(record_type), Some(digest)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        Ok(Self {
+            name: name.to_string(),
+            record_type: parse_record_type(record_type, "gcp_dns").map_err(|_| invalid())?,
+            digest: digest.to_string(),
+        })
+    }
+
+    fn key(&self) -> SetKey {
+        (self.name.clone(), record_type_to_string(&self.record_type))
+    }
+}
+
+impl std::fmt::Display for RecordId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.name,
+            record_type_to_string(&self.record_type),
+            self.digest
+        )
+    }
+}
+
+/// 记录集键：（小写完整名称，类型）
+type SetKey = (String, &'static str);
+
+/// 记录集的工作副本
+struct PendingSet {
+    record_type: DnsRecordType,
+    /// 变更前的记录集（删除时原样回传），原本不存在时为 `None`
+    original: Option<ResourceRecordSet>,
+    /// 提交用的名称（完整名称，末尾带点）
+    name: String,
+    ttl: u32,
+    values: Vec<String>,
+}
+
+impl PendingSet {
+    fn position_of(&self, digest: &str) -> Option<usize> {
+        self.values
+            .iter()
+            .position(|value| GcpDnsProvider::value_digest(&self.record_type, value) == digest)
+    }
+}
+
+/// 在记录集快照上依次应用单条记录的增删改，最后合并为一个 change
+struct ChangePlan {
+    sets: BTreeMap<SetKey, PendingSet>,
+}
+
+impl ChangePlan {
+    /// 以现有记录集为起点（跳过带路由策略与类型不支持的记录集）
+    fn new(sets: impl IntoIterator<Item = ResourceRecordSet>) -> Self {
+        let sets = sets
+            .into_iter()
+            .filter(ResourceRecordSet::is_simple)
+            .filter_map(|set| {
+                let record_type = parse_record_type(&set.record_type, "gcp_dns").ok()?;
+                let key = (
+                    normalize_domain_name(&set.name).to_lowercase(),
+                    record_type_to_string(&record_type),
+                );
+                Some((
+                    key,
+                    PendingSet {
+                        record_type,
+                        name: set.name.clone(),
+                        ttl: set.ttl.unwrap_or(300),
+                        values: set.rrdatas.clone(),
+                        original: Some(set),
+                    },
+                ))
+            })
+            .collect();
+        Self { sets }
+    }
+
+    /// 按合成 ID 定位记录集与值的下标
+    fn locate(&self, record_id: &str) -> Result<(SetKey, usize)> {
+        let id = RecordId::parse(record_id)?;
+        let key = id.key();
+        let index = self
+            .sets
+            .get(&key)
+            .and_then(|set| set.position_of(&id.digest))
+            .ok_or_else(|| GcpDnsProvider::record_not_found(record_id))?;
+        Ok((key, index))
+    }
+
+    /// 把一个值加入（可能不存在的）记录集，返回新记录的 ID；值已存在时返回 `RecordExists`
+    fn add(&mut self, zone_name: &str, name: &str, ttl: u32, data: &RecordData) -> Result<String> {
+        let full_name = relative_to_full_name(name, zone_name);
+        let record_type = data.record_type();
+        let value = GcpDnsProvider::record_data_to_value(data);
+        let id = RecordId::new(&full_name, record_type.clone(), &value);
+
+        let set = self.sets.entry(id.key()).or_insert_with(|| PendingSet {
+            record_type,
+            original: None,
+            name: format!("{full_name}."),
+            ttl,
+            values: Vec::new(),
+        });
+        if set.position_of(&id.digest).is_some() {
+            return Err(ProviderError::RecordExists {
+                provider: "gcp_dns".to_string(),
+                record_name: name.to_string(),
+                raw_message: None,
+            });
+        }
+        set.values.push(value);
+        set.ttl = ttl;
+        Ok(id.to_string())
+    }
+
+    /// 更新一条记录，返回新记录的 ID
+    fn update(
+        &mut self,
+        zone_name: &str,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<String> {
+        let (old_key, index) = self.locate(record_id)?;
+        let full_name = relative_to_full_name(&req.name, zone_name);
+        let record_type = req.data.record_type();
+        let value = GcpDnsProvider::record_data_to_value(&req.data);
+        let id = RecordId::new(&full_name, record_type.clone(), &value);
+
+        if id.key() == old_key {
+            // 同一记录集内替换该值（新值与组内其他值重复时合并）
+            if let Some(set) = self.sets.get_mut(&old_key) {
+                set.values[index] = value;
+                let mut seen = Vec::new();
+                set.values.retain(|v| {
+                    let digest = GcpDnsProvider::value_digest(&record_type, v);
+                    let keep = !seen.contains(&digest);
+                    seen.push(digest);
+                    keep
+                });
+                set.ttl = req.ttl;
+            }
+            return Ok(id.to_string());
+        }
+
+        // 名称或类型变化：先确认目标记录集中没有该值，再从原记录集移除
+        if self
+            .sets
+            .get(&id.key())
+            .is_some_and(|set| set.position_of(&id.digest).is_some())
+        {
+            return Err(ProviderError::RecordExists {
+                provider: "gcp_dns".to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+        if let Some(set) = self.sets.get_mut(&old_key) {
+            set.values.remove(index);
+        }
+        self.add(zone_name, &req.name, req.ttl, &req.data)
+    }
+
+    fn delete(&mut self, record_id: &str) -> Result<()> {
+        let (key, index) = self.locate(record_id)?;
+        if let Some(set) = self.sets.get_mut(&key) {
+            set.values.remove(index);
+        }
+        Ok(())
+    }
+
+    /// 相对快照的变更：有变化的记录集删除原记录集、写入新记录集
+    fn into_change(self) -> Change {
+        let mut additions = Vec::new();
+        let mut deletions = Vec::new();
+        for set in self.sets.into_values() {
+            if let Some(original) = &set.original
+                && original.ttl.unwrap_or(300) == set.ttl
+                && original.rrdatas == set.values
+            {
+                continue;
+            }
+            if !set.values.is_empty() {
+                additions.push(ResourceRecordSet {
+                    name: set.name,
+                    record_type: record_type_to_string(&set.record_type).to_string(),
+                    ttl: Some(set.ttl),
+                    rrdatas: set.values,
+                    signature_rrdatas: Vec::new(),
+                    routing_policy: None,
+                });
+            }
+            deletions.extend(set.original);
+        }
+        Change::new(additions, deletions)
+    }
+}
+
+impl GcpDnsProvider {
+    fn convert_zone(zone: ManagedZone) -> ProviderDomain {
+        ProviderDomain {
+            id: zone.name,
+            name: normalize_domain_name(&zone.dns_name),
+            provider: ProviderType::GcpDns,
+            // Managed zone 没有状态字段，存在即生效
+            status: DomainStatus::Active,
+            provider_status_raw: zone.visibility.filter(|v| v == "private"),
+            record_count: None,
+        }
+    }
+
+    /// 解析 Cloud DNS 记录值（zone 文件格式）为 RecordData
+    /// 域名类字段去掉末尾的点，TXT 拼接各段引号内的内容
+    fn parse_record_data(record_type: &str, value: &str) -> Result<RecordData> {
+        match record_type {
+            "A" => Ok(RecordData::A {
+                address: value.to_string(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: value.to_string(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: normalize_domain_name(value),
+            }),
+            "MX" => match value.split_once(' ') {
+                Some((priority, exchange)) => Ok(RecordData::MX {
+                    priority: parse_number(priority, "MX priority")?,
+                    exchange: normalize_domain_name(exchange.trim()),
+                }),
+                None => Err(ProviderError::ParseError {
+                    provider: "gcp_dns".to_string(),
+                    detail: format!(
+                        "Invalid MX record format: expected 'priority exchange', got '{value}'"
+                    ),
+                }),
+            },
+            "TXT" => Ok(RecordData::TXT {
+                text: txt::unquote(value, EscapeRadix::Decimal),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: normalize_domain_name(value),
+            }),
+            "SRV" => match value.split_whitespace().collect::<Vec<_>>().as_slice() {
+                [priority, weight, port, target] => Ok(RecordData::SRV {
+                    priority: parse_number(priority, "SRV priority")?,
+                    weight: parse_number(weight, "SRV weight")?,
+                    port: parse_number(port, "SRV port")?,
+                    target: normalize_domain_name(target),
+                }),
+                _ => Err(ProviderError::ParseError {
+                    provider: "gcp_dns".to_string(),
+                    detail: format!(
+                        "Invalid SRV record format: expected 'priority weight port target', got '{value}'"
+                    ),
+                }),
+            },
+            "CAA" => match value.splitn(3, ' ').collect::<Vec<_>>().as_slice() {
+                [flags, tag, caa_value] => Ok(RecordData::CAA {
+                    flags: parse_number(flags, "CAA flags")?,
+                    tag: (*tag).to_string(),
+                    value: caa_value.trim_matches('"').to_string(),
+                }),
+                _ => Err(ProviderError::ParseError {
+                    provider: "gcp_dns".to_string(),
+                    detail: format!(
+                        "Invalid CAA record format: expected 'flags tag value', got '{value}'"
+                    ),
+                }),
+            },
+            "SOA" => parse_soa(value, "gcp_dns"),
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: "gcp_dns".to_string(),
+                record_type: record_type.to_string(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为 Cloud DNS 记录值（域名类字段须为末尾带点的完整域名）
+    fn record_data_to_value(data: &RecordData) -> String {
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+            RecordData::CNAME { target } => fqdn(target),
+            RecordData::MX { priority, exchange } => format!("{priority} {}", fqdn(exchange)),
+            RecordData::TXT { text } => txt::quote(text, EscapeRadix::Decimal),
+            RecordData::NS { nameserver } => fqdn(nameserver),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{priority} {weight} {port} {}", fqdn(target)),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::SOA { .. } => data.display_value(),
+        }
+    }
+
+    /// 记录值摘要：按解析后的内容计算，TXT 分段、末尾点等写法不同的同一值摘要相同
+    fn value_digest(record_type: &DnsRecordType, value: &str) -> String {
+        let canonical = Self::parse_record_data(record_type_to_string(record_type), value)
+            .map(|data| Self::record_data_to_value(&data))
+            .unwrap_or_else(|_| value.to_string());
+        hex::encode(Sha256::digest(canonical.as_bytes()))[..VALUE_DIGEST_LEN].to_string()
+    }
+
+    /// 展开记录集为单条记录（跳过路由策略记录与无法解析的值）
+    fn expand_record_set(
+        set: &ResourceRecordSet,
+        domain_id: &str,
+        zone_name: &str,
+    ) -> Vec<DnsRecord> {
+        if !set.is_simple() {
+            log::debug!(
+                "[gcp_dns] Skipping routing policy record set {} {}",
+                set.name,
+                set.record_type
+            );
+            return Vec::new();
+        }
+        let Ok(record_type) = parse_record_type(&set.record_type, "gcp_dns") else {
+            return Vec::new();
+        };
+
+        set.rrdatas
+            .iter()
+            .filter_map(|value| {
+                let data = Self::parse_record_data(&set.record_type, value).ok()?;
+                Some(DnsRecord {
+                    id: RecordId::new(&set.name, record_type.clone(), value).to_string(),
+                    domain_id: domain_id.to_string(),
+                    name: full_name_to_relative(&set.name, zone_name),
+                    ttl: set.ttl.unwrap_or(300),
+                    data,
+                    proxied: None,
+                    created_at: None,
+                    updated_at: None,
+                })
+            })
+            .collect()
+    }
+
+    fn zone_context(zone: &str) -> ErrorContext {
+        ErrorContext {
+            domain: Some(zone.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// 列出 managed zone 的全部记录集（按 pageToken 翻页）
+    async fn list_all_record_sets(&self, zone: &str) -> Result<Vec<ResourceRecordSet>> {
+        let path = format!("/managedZones/{}/rrsets", encode_path_segment(zone));
+        let mut sets = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = format!("maxResults={MAX_RECORD_PAGE_SIZE}");
+            if let Some(token) = &page_token {
+                query.push_str(&format!("&pageToken={}", urlencoding::encode(token)));
+            }
+            let response: ResourceRecordSetsListResponse =
+                self.get(&path, &query, Self::zone_context(zone)).await?;
+            sets.extend(response.rrsets);
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(sets),
+            }
+        }
+    }
+
+    /// 按完整名称（末尾带点）与类型精确查找普通记录集
+    async fn find_record_set(
+        &self,
+        zone: &str,
+        full_name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<Option<ResourceRecordSet>> {
+        let path = format!("/managedZones/{}/rrsets", encode_path_segment(zone));
+        let query = format!(
+            "name={}&type={}",
+            urlencoding::encode(full_name),
+            record_type_to_string(record_type)
+        );
+        let response: ResourceRecordSetsListResponse =
+            self.get(&path, &query, Self::zone_context(zone)).await?;
+        Ok(response
+            .rrsets
+            .into_iter()
+            .find(ResourceRecordSet::is_simple))
+    }
+
+    /// 读取合成 ID 所在的记录集
+    async fn find_record_set_of(
+        &self,
+        zone: &str,
+        id: &RecordId,
+    ) -> Result<Vec<ResourceRecordSet>> {
+        Ok(self
+            .find_record_set(zone, &format!("{}.", id.name), &id.record_type)
+            .await?
+            .into_iter()
+            .collect())
+    }
+
+    /// 提交非空的 change
+    async fn submit_plan(&self, zone: &str, plan: ChangePlan, ctx: ErrorContext) -> Result<()> {
+        let change = plan.into_change();
+        if change.is_empty() {
+            return Ok(());
+        }
+        self.submit_change(zone, &change, ctx).await
+    }
+
+    fn record_not_found(record_id: &str) -> ProviderError {
+        ProviderError::RecordNotFound {
+            provider: "gcp_dns".to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        }
+    }
+
+    /// 读取全部记录集快照、在内存中依次应用各变更后一次原子提交
+    ///
+    /// 校验失败（值已存在、记录不存在）的变更直接计为失败，不进入 change。
+    async fn submit_batch(
+        &self,
+        domain_id: &str,
+        changes: &[RecordChange],
+    ) -> Result<Vec<ChangeResult>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let mut plan = ChangePlan::new(self.list_all_record_sets(domain_id).await?);
+
+        let now = chrono::Utc::now();
+        let results = changes
+            .iter()
+            .map(|change| match change {
+                RecordChange::Create(req) => plan
+                    .add(&domain_info.name, &req.name, req.ttl, &req.data)
+                    .map(|id| DnsRecord {
+                        id,
+                        domain_id: domain_id.to_string(),
+                        name: req.name.clone(),
+                        ttl: req.ttl,
+                        data: req.data.clone(),
+                        proxied: None,
+                        created_at: Some(now),
+                        updated_at: Some(now),
+                    })
+                    .into(),
+                RecordChange::Update { record_id, request } => plan
+                    .update(&domain_info.name, record_id, request)
+                    .map(|id| DnsRecord {
+                        id,
+                        domain_id: domain_id.to_string(),
+                        name: request.name.clone(),
+                        ttl: request.ttl,
+                        data: request.data.clone(),
+                        proxied: None,
+                        created_at: None,
+                        updated_at: Some(now),
+                    })
+                    .into(),
+                RecordChange::Delete { record_id } => plan.delete(record_id).into(),
+            })
+            .collect();
+
+        self.submit_plan(domain_id, plan, Self::zone_context(domain_id))
+            .await?;
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for GcpDnsProvider {
+    fn id(&self) -> &'static str {
+        "gcp_dns"
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::GcpDns,
+            name: "Google Cloud DNS".to_string(),
+            description: "Google Cloud DNS 托管区域，使用服务账号密钥访问".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "projectId".to_string(),
+                    label: "Project ID".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("my-project-123456".to_string()),
+                    help_text: None,
+                    pattern: Some(GCP_PROJECT_ID.pattern()),
+                    options: None,
+                },
+                ProviderCredentialField {
+                    key: "serviceAccountJson".to_string(),
+                    label: "Service Account Key (JSON)".to_string(),
+                    field_type: FieldType::Textarea,
+                    placeholder: Some("粘贴服务账号 JSON 密钥文件的内容".to_string()),
+                    help_text: Some(
+                        "服务账号需要 DNS Administrator（roles/dns.admin）角色".to_string(),
+                    ),
+                    pattern: None,
+                    options: None,
+                },
+            ],
+            optional_fields: vec![],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                record_sets: true,
+                batch_changes: true,
+                txt_format: TxtFormat::Quoted,
+                ..ProviderFeatures::default()
+            },
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_ZONE_PAGE_SIZE,
+                max_page_size_records: MAX_RECORD_PAGE_SIZE,
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                max_batch_changes: MAX_BATCH_CHANGES,
+                max_txt_length: 0,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<ManagedZonesListResponse>(
+                "/managedZones",
+                "maxResults=1",
+                ErrorContext::default(),
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Cloud DNS 按 pageToken 翻页且不返回总数，取全部 managed zone 后在本地分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let mut zones = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut query = format!("maxResults={MAX_ZONE_PAGE_SIZE}");
+            if let Some(token) = &page_token {
+                query.push_str(&format!("&pageToken={}", urlencoding::encode(token)));
+            }
+            let response: ManagedZonesListResponse = self
+                .get("/managedZones", &query, ErrorContext::default())
+                .await?;
+            zones.extend(response.managed_zones);
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        let total_count = u32::try_from(zones.len()).unwrap_or(u32::MAX);
+        let domains = zones
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .map(Self::convert_zone)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let path = format!("/managedZones/{}", encode_path_segment(domain_id));
+        let zone: ManagedZone = self.get(&path, "", Self::zone_context(domain_id)).await?;
+        Ok(Self::convert_zone(zone))
+    }
+
+    /// Cloud DNS 只支持按完整名称精确过滤，取全部记录集后在本地过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let sets = self.list_all_record_sets(domain_id).await?;
+
+        let keyword = params
+            .keyword
+            .as_deref()
+            .filter(|k| !k.is_empty())
+            .map(str::to_lowercase);
+        let records: Vec<DnsRecord> = sets
+            .iter()
+            .flat_map(|set| Self::expand_record_set(set, domain_id, &domain_info.name))
+            .filter(|r| {
+                params
+                    .record_type
+                    .as_ref()
+                    .is_none_or(|record_type| r.data.record_type() == *record_type)
+            })
+            .filter(|r| {
+                keyword
+                    .as_ref()
+                    .is_none_or(|keyword| record_name_matches(r, keyword))
+            })
+            .collect();
+
+        let total_count = u32::try_from(records.len()).unwrap_or(u32::MAX);
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name);
+        let existing = self
+            .find_record_set(
+                &req.domain_id,
+                &format!("{full_name}."),
+                &req.data.record_type(),
+            )
+            .await?;
+
+        let mut plan = ChangePlan::new(existing);
+        let id = plan.add(&domain_info.name, &req.name, req.ttl, &req.data)?;
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        self.submit_plan(&req.domain_id, plan, ctx).await?;
+
+        let now = chrono::Utc::now();
+        Ok(DnsRecord {
+            id,
+            domain_id: req.domain_id.clone(),
+            name: req.name.clone(),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            created_at: Some(now),
+            updated_at: Some(now),
+        })
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let old = RecordId::parse(record_id)?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name);
+        let record_type = req.data.record_type();
+
+        let mut sets = self.find_record_set_of(&req.domain_id, &old).await?;
+        if !(old.name.eq_ignore_ascii_case(&full_name) && old.record_type == record_type) {
+            sets.extend(
+                self.find_record_set(&req.domain_id, &format!("{full_name}."), &record_type)
+                    .await?,
+            );
+        }
+
+        let mut plan = ChangePlan::new(sets);
+        let id = plan.update(&domain_info.name, record_id, req)?;
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        self.submit_plan(&req.domain_id, plan, ctx).await?;
+
+        Ok(DnsRecord {
+            id,
+            domain_id: req.domain_id.clone(),
+            name: req.name.clone(),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            created_at: None,
+            updated_at: Some(chrono::Utc::now()),
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let id = RecordId::parse(record_id)?;
+        let mut plan = ChangePlan::new(self.find_record_set_of(domain_id, &id).await?);
+        plan.delete(record_id)?;
+
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.submit_plan(domain_id, plan, ctx).await
+    }
+
+    async fn get_record_set(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<Option<RecordSet>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(name, &domain_info.name));
+
+        let Some(record_set) = self
+            .find_record_set(domain_id, &full_name, record_type)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let values = record_set
+            .rrdatas
+            .iter()
+            .map(|value| Self::parse_record_data(&record_set.record_type, value))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(RecordSet {
+            id: None,
+            name: name.to_string(),
+            record_type: record_type.clone(),
+            ttl: record_set.ttl.unwrap_or(300),
+            values,
+        }))
+    }
+
+    async fn replace_record_set(&self, domain_id: &str, set: &RecordSet) -> Result<()> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(&set.name, &domain_info.name));
+        let existing = self
+            .find_record_set(domain_id, &full_name, &set.record_type)
+            .await?;
+
+        let mut plan = ChangePlan::new(existing);
+        let key = (
+            normalize_domain_name(&full_name).to_lowercase(),
+            record_type_to_string(&set.record_type),
+        );
+        let pending = plan.sets.entry(key).or_insert_with(|| PendingSet {
+            record_type: set.record_type.clone(),
+            original: None,
+            name: full_name,
+            ttl: set.ttl,
+            values: Vec::new(),
+        });
+        pending.ttl = set.ttl;
+        pending.values = set.values.iter().map(Self::record_data_to_value).collect();
+
+        let ctx = ErrorContext {
+            record_name: Some(set.name.clone()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.submit_plan(domain_id, plan, ctx).await
+    }
+
+    /// 各变更在记录集快照上依次应用，整批在一个 change 中原子提交
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        if changes.is_empty() {
+            return Vec::new();
+        }
+        match self.submit_batch(domain_id, &changes).await {
+            Ok(results) => results,
+            Err(e) => fallback_after_batch_error(self, domain_id, changes, e).await,
+        }
+    }
+}
+
+/// 补全末尾的点
+fn fqdn(name: &str) -> String {
+    format!("{}.", normalize_domain_name(name))
+}
+
+fn parse_number<T: FromStr>(value: &str, field: &str) -> Result<T> {
+    value.parse().map_err(|_| ProviderError::ParseError {
+        provider: "gcp_dns".to_string(),
+        detail: format!("Invalid {field}: '{value}'"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rrset(name: &str, record_type: &str, ttl: u32, rrdatas: &[&str]) -> ResourceRecordSet {
+        ResourceRecordSet {
+            name: name.to_string(),
+            record_type: record_type.to_string(),
+            ttl: Some(ttl),
+            rrdatas: rrdatas.iter().map(ToString::to_string).collect(),
+            signature_rrdatas: Vec::new(),
+            routing_policy: None,
+        }
+    }
+
+    #[test]
+    fn round_trips_record_values() {
+        let long_text = "v".repeat(300);
+        let values = [
+            ("MX", "10 mail.example.com."),
+            ("SRV", "10 60 5060 sip.example.com."),
+            ("CAA", "0 issue \"letsencrypt.org\""),
+            ("TXT", "\"v=spf1 include:_spf.google.com ~all\""),
+        ];
+        for (record_type, value) in values {
+            let data = GcpDnsProvider::parse_record_data(record_type, value).unwrap();
+            assert_eq!(GcpDnsProvider::record_data_to_value(&data), value);
+        }
+
+        let data = GcpDnsProvider::parse_record_data("CNAME", "target.example.com.").unwrap();
+        assert!(matches!(&data, RecordData::CNAME { target } if target == "target.example.com"));
+
+        let quoted = txt::quote(&long_text, EscapeRadix::Decimal);
+        assert_eq!(quoted.matches('"').count(), 4);
+        assert_eq!(txt::unquote(&quoted, EscapeRadix::Decimal), long_text);
+        assert_eq!(
+            txt::unquote("\"say \\\"hi\\\"\\010\"", EscapeRadix::Decimal),
+            "say \"hi\"\n"
+        );
+        assert_eq!(
+            txt::quote("say \"hi\"\n", EscapeRadix::Decimal),
+            "\"say \\\"hi\\\"\\010\""
+        );
+    }
+
+    #[test]
+    fn plans_change_per_record_set() {
+        let zone = "example.com";
+        let www = rrset("www.example.com.", "A", 300, &["192.0.2.1", "192.0.2.2"]);
+        let api = rrset("api.example.com.", "A", 300, &["192.0.2.9"]);
+        let www_id = RecordId::new("www.example.com", DnsRecordType::A, "192.0.2.1").to_string();
+        let api_id = RecordId::new("api.example.com", DnsRecordType::A, "192.0.2.9").to_string();
+
+        let mut plan = ChangePlan::new([www.clone(), api.clone()]);
+        plan.delete(&www_id).unwrap();
+        plan.add(
+            zone,
+            "www",
+            300,
+            &RecordData::A {
+                address: "192.0.2.3".to_string(),
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            plan.add(
+                zone,
+                "www",
+                300,
+                &RecordData::A {
+                    address: "192.0.2.2".to_string(),
+                },
+            ),
+            Err(ProviderError::RecordExists { .. })
+        ));
+        plan.delete(&api_id).unwrap();
+        assert!(matches!(
+            plan.delete(&api_id),
+            Err(ProviderError::RecordNotFound { .. })
+        ));
+
+        let change = plan.into_change();
+        assert_eq!(change.kind, "dns#change");
+        assert_eq!(change.deletions, vec![api, www]);
+        assert_eq!(
+            change.additions,
+            vec![rrset(
+                "www.example.com.",
+                "A",
+                300,
+                &["192.0.2.2", "192.0.2.3"]
+            )]
+        );
+
+        let unchanged = ChangePlan::new([rrset("www.example.com.", "A", 300, &["192.0.2.1"])]);
+        assert!(unchanged.into_change().is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+//! Google Cloud DNS API 类型定义
+
+use serde::{Deserialize, Serialize};
+
+// ============ Cloud DNS API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedZonesListResponse {
+    #[serde(default)]
+    pub managed_zones: Vec<ManagedZone>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedZone {
+    /// 区域名称（项目内唯一，API 路径中使用）
+    pub name: String,
+    /// 域名（末尾带点）
+    pub dns_name: String,
+    /// `public` 或 `private`
+    pub visibility: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRecordSetsListResponse {
+    #[serde(default)]
+    pub rrsets: Vec<ResourceRecordSet>,
+    pub next_page_token: Option<String>,
+}
+
+/// 记录集（删除时须与现有记录集完全一致，按原样回传）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRecordSet {
+    /// 完整名称（末尾带点）
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rrdatas: Vec<String>,
+    /// DNSSEC 签名（RRSIG），由 Cloud DNS 维护
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signature_rrdatas: Vec<String>,
+    /// 加权轮询、地理位置等路由策略（此时没有 `rrdatas`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routing_policy: Option<serde_json::Value>,
+}
+
+impl ResourceRecordSet {
+    /// 是否为普通记录集（无路由策略）
+    pub fn is_simple(&self) -> bool {
+        self.routing_policy.is_none()
+    }
+}
+
+/// 变更（`changes.create` 的请求体与 `changes.create` / `changes.get` 的响应）
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additions: Vec<ResourceRecordSet>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deletions: Vec<ResourceRecordSet>,
+    #[serde(default, skip_serializing)]
+    pub id: String,
+    /// `pending` 或 `done`
+    #[serde(default, skip_serializing)]
+    pub status: String,
+}
+
+impl Change {
+    pub fn new(additions: Vec<ResourceRecordSet>, deletions: Vec<ResourceRecordSet>) -> Self {
+        Self {
+            kind: "dns#change".to_string(),
+            additions,
+            deletions,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.additions.is_empty() && self.deletions.is_empty()
+    }
+}
+
+/// 通用错误响应
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorDetail {
+    pub code: Option<u16>,
+    pub message: Option<String>,
+    #[serde(default)]
+    pub errors: Vec<ErrorItem>,
+    /// gRPC 风格状态码，如 `UNAUTHENTICATED`
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorItem {
+    /// 如 `alreadyExists`、`notFound`、`forbidden`
+    pub reason: Option<String>,
+}
+
+// ============ OAuth2 token 端点 ============
+
+#[derive(Debug, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    /// 有效期（秒）
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenErrorResponse {
+    /// 如 `invalid_grant`
+    pub error: String,
+    pub error_description: Option<String>,
+}
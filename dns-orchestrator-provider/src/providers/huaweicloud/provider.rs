@@ -3,19 +3,25 @@
 use async_trait::async_trait;
 use serde::Serialize;
 
+use crate::credential_format::{HUAWEICLOUD_ACCESS_KEY_ID, HUAWEICLOUD_SECRET_ACCESS_KEY};
 use crate::error::{ProviderError, Result};
 use crate::providers::common::{
-    full_name_to_relative, normalize_domain_name, record_type_to_string, relative_to_full_name,
+    KEYWORD_SPECIAL_CHARS, LiteralKeyword, encode_path_segment, full_name_to_relative,
+    list_records_by_keyword, normalize_domain_name, parse_soa, record_type_to_string,
+    relative_to_full_name,
 };
 use crate::traits::{DnsProvider, ErrorContext};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
     PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    ProviderMetadata, ProviderType, RecordData, RecordQueryParams, RecordSet,
+    STANDARD_RECORD_TYPES, TxtFormat, UpdateDnsRecordRequest,
 };
+use crate::utils::txt::{self, EscapeRadix};
 
 use super::types::{
-    CreateRecordSetResponse, ListRecordSetsResponse, ListZonesResponse, ShowPublicZoneResponse,
+    CreateRecordSetResponse, HuaweicloudRecordSet, ListRecordSetsResponse, ListZonesResponse,
+    ShowPublicZoneResponse,
 };
 use super::{HuaweicloudProvider, MAX_PAGE_SIZE};
 
@@ -25,16 +31,15 @@ impl HuaweicloudProvider {
     /// `PENDING_FREEZE`, FREEZE, ILLEGAL, POLICE, `PENDING_DISABLE`, DISABLE, ERROR
     pub(crate) fn convert_domain_status(status: Option<&str>) -> DomainStatus {
         match status {
-            Some("ACTIVE") => DomainStatus::Active,
-            // 各种 PENDING 状态
+            // 更新中的 zone 仍在正常解析
+            Some("ACTIVE" | "PENDING_UPDATE") => DomainStatus::Active,
+            Some("PENDING_CREATE") => DomainStatus::Pending,
+            // 冻结/禁用/删除中（含过渡状态）
             Some(
-                "PENDING_CREATE" | "PENDING_UPDATE" | "PENDING_DELETE" | "PENDING_FREEZE"
-                | "PENDING_DISABLE",
-            ) => DomainStatus::Pending,
-            // 冻结/暂停状态
-            Some("FREEZE" | "ILLEGAL" | "POLICE" | "DISABLE") => DomainStatus::Paused,
-            Some("ERROR") => DomainStatus::Error,
-            _ => DomainStatus::Unknown,
+                "FREEZE" | "ILLEGAL" | "POLICE" | "DISABLE" | "PENDING_FREEZE" | "PENDING_DISABLE"
+                | "PENDING_DELETE",
+            ) => DomainStatus::Suspended,
+            other => DomainStatus::Unknown(other.unwrap_or_default().to_string()),
         }
     }
 
@@ -71,8 +76,9 @@ impl HuaweicloudProvider {
                     })
                 }
             }
+            // 华为云 TXT 值为带引号的多段字符串
             "TXT" => Ok(RecordData::TXT {
-                text: record.to_string(),
+                text: txt::unquote(record, EscapeRadix::Decimal),
             }),
             "NS" => Ok(RecordData::NS {
                 nameserver: record.to_string(),
@@ -126,6 +132,8 @@ impl HuaweicloudProvider {
                     })
                 }
             }
+            // 华为云 SOA 格式: "mname rname (serial refresh retry expire minimum)"
+            "SOA" => parse_soa(record, "huaweicloud"),
             _ => Err(ProviderError::UnsupportedRecordType {
                 provider: "huaweicloud".to_string(),
                 record_type: record_type.to_string(),
@@ -140,7 +148,7 @@ impl HuaweicloudProvider {
             RecordData::AAAA { address } => address.clone(),
             RecordData::CNAME { target } => target.clone(),
             RecordData::MX { priority, exchange } => format!("{priority} {exchange}"),
-            RecordData::TXT { text } => text.clone(),
+            RecordData::TXT { text } => txt::quote(text, EscapeRadix::Decimal),
             RecordData::NS { nameserver } => nameserver.clone(),
             RecordData::SRV {
                 priority,
@@ -149,8 +157,109 @@ impl HuaweicloudProvider {
                 target,
             } => format!("{priority} {weight} {port} {target}"),
             RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::SOA { .. } => data.display_value(),
         }
     }
+
+    /// 按完整名称（末尾带点）与类型精确查找记录集
+    async fn find_record_set(
+        &self,
+        domain_id: &str,
+        full_name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<Option<HuaweicloudRecordSet>> {
+        let type_str = record_type_to_string(record_type);
+        let query = format!(
+            "name={}&type={}&search_mode=equal&limit={MAX_PAGE_SIZE}",
+            urlencoding::encode(full_name),
+            urlencoding::encode(type_str)
+        );
+        let path = format!("/v2/zones/{}/recordsets", encode_path_segment(domain_id));
+        let ctx = ErrorContext {
+            record_name: Some(full_name.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let response: ListRecordSetsResponse = self.get(&path, &query, ctx).await?;
+
+        let full_name = normalize_domain_name(full_name);
+        Ok(response
+            .recordsets
+            .unwrap_or_default()
+            .into_iter()
+            .find(|r| {
+                normalize_domain_name(&r.name).eq_ignore_ascii_case(&full_name)
+                    && r.record_type.eq_ignore_ascii_case(type_str)
+            }))
+    }
+
+    /// 执行一次记录集分页查询（`params.keyword` 原样作为 `name` 模糊匹配）
+    async fn query_records(
+        &self,
+        domain_id: &str,
+        zone_name: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        // 华为云使用 offset/limit 分页
+        let offset = (params.page - 1) * params.page_size;
+        let limit = params.page_size.min(MAX_PAGE_SIZE);
+        let mut query = format!("offset={offset}&limit={limit}");
+
+        if let Some(ref keyword) = params.keyword {
+            query.push_str(&format!("&name={}", urlencoding::encode(keyword)));
+        }
+
+        // 添加记录类型过滤
+        if let Some(ref record_type) = params.record_type {
+            let type_str = record_type_to_string(record_type);
+            query.push_str(&format!("&type={}", urlencoding::encode(type_str)));
+        }
+
+        let path = format!("/v2/zones/{}/recordsets", encode_path_segment(domain_id));
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let response: ListRecordSetsResponse = self.get(&path, &query, ctx).await?;
+
+        let total_count = response.metadata.and_then(|m| m.total_count).unwrap_or(0);
+
+        let records = response
+            .recordsets
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|r| {
+                let value = r.records.as_ref()?.first()?.clone();
+                let data = Self::parse_record_data(&r.record_type, &value).ok()?;
+
+                Some(DnsRecord {
+                    id: r.id,
+                    domain_id: domain_id.to_string(),
+                    name: full_name_to_relative(&r.name, zone_name),
+                    ttl: r.ttl.unwrap_or(300),
+                    data,
+                    proxied: None,
+                    created_at: r.created_at.and_then(|s| {
+                        chrono::DateTime::parse_from_rfc3339(&s)
+                            .ok()
+                            .map(|dt| dt.with_timezone(&chrono::Utc))
+                    }),
+                    updated_at: r.updated_at.and_then(|s| {
+                        chrono::DateTime::parse_from_rfc3339(&s)
+                            .ok()
+                            .map(|dt| dt.with_timezone(&chrono::Utc))
+                    }),
+                })
+            })
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
 }
 
 #[async_trait]
@@ -171,6 +280,8 @@ impl DnsProvider for HuaweicloudProvider {
                     field_type: FieldType::Text,
                     placeholder: Some("输入 Access Key ID".to_string()),
                     help_text: None,
+                    pattern: Some(HUAWEICLOUD_ACCESS_KEY_ID.pattern()),
+                    options: None,
                 },
                 ProviderCredentialField {
                     key: "secretAccessKey".to_string(),
@@ -178,12 +289,24 @@ impl DnsProvider for HuaweicloudProvider {
                     field_type: FieldType::Password,
                     placeholder: Some("输入 Secret Access Key".to_string()),
                     help_text: None,
+                    pattern: Some(HUAWEICLOUD_SECRET_ACCESS_KEY.pattern()),
+                    options: None,
                 },
             ],
-            features: ProviderFeatures::default(),
+            optional_fields: vec![],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                record_sets: true,
+                txt_format: TxtFormat::Quoted,
+                ..ProviderFeatures::default()
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 500,
                 max_page_size_records: 500,
+                min_ttl: 1,
+                max_ttl: 2_147_483_647,
+                max_batch_changes: 0,
+                max_txt_length: 4096,
             },
         }
     }
@@ -226,6 +349,7 @@ impl DnsProvider for HuaweicloudProvider {
                 name: normalize_domain_name(&z.name),
                 provider: ProviderType::Huaweicloud,
                 status: Self::convert_domain_status(z.status.as_deref()),
+                provider_status_raw: z.status.clone(),
                 record_count: z.record_num,
             })
             .collect();
@@ -240,7 +364,7 @@ impl DnsProvider for HuaweicloudProvider {
 
     /// 使用 ShowPublicZone API 直接获取域名信息
     async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
-        let path = format!("/v2/zones/{domain_id}");
+        let path = format!("/v2/zones/{}", encode_path_segment(domain_id));
         let ctx = ErrorContext {
             domain: Some(domain_id.to_string()),
             ..Default::default()
@@ -252,6 +376,7 @@ impl DnsProvider for HuaweicloudProvider {
             name: normalize_domain_name(&response.name),
             provider: ProviderType::Huaweicloud,
             status: Self::convert_domain_status(response.status.as_deref()),
+            provider_status_raw: response.status.clone(),
             record_count: response.record_num,
         })
     }
@@ -264,73 +389,12 @@ impl DnsProvider for HuaweicloudProvider {
         // 获取域名信息以获取域名名称
         let domain_info = self.get_domain(domain_id).await?;
 
-        // 华为云使用 offset/limit 分页
-        let offset = (params.page - 1) * params.page_size;
-        let limit = params.page_size.min(MAX_PAGE_SIZE);
-        let mut query = format!("offset={offset}&limit={limit}");
-
-        // 添加搜索关键词（华为云支持 name 参数模糊匹配）
-        if let Some(ref keyword) = params.keyword
-            && !keyword.is_empty()
-        {
-            query.push_str(&format!("&name={}", urlencoding::encode(keyword)));
-        }
-
-        // 添加记录类型过滤
-        if let Some(ref record_type) = params.record_type {
-            let type_str = record_type_to_string(record_type);
-            query.push_str(&format!("&type={}", urlencoding::encode(type_str)));
-        }
-
-        let path = format!("/v2/zones/{domain_id}/recordsets");
-        let ctx = ErrorContext {
-            domain: Some(domain_id.to_string()),
-            ..Default::default()
-        };
-        let response: ListRecordSetsResponse = self.get(&path, &query, ctx).await?;
-
-        let total_count = response.metadata.and_then(|m| m.total_count).unwrap_or(0);
-
-        let records = response
-            .recordsets
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|r| {
-                // 跳过 SOA 和 NS 根记录
-                if r.record_type == "SOA" {
-                    return None;
-                }
-
-                let value = r.records.as_ref()?.first()?.clone();
-                let data = Self::parse_record_data(&r.record_type, &value).ok()?;
-
-                Some(DnsRecord {
-                    id: r.id,
-                    domain_id: domain_id.to_string(),
-                    name: full_name_to_relative(&r.name, &domain_info.name),
-                    ttl: r.ttl.unwrap_or(300),
-                    data,
-                    proxied: None,
-                    created_at: r.created_at.and_then(|s| {
-                        chrono::DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&chrono::Utc))
-                    }),
-                    updated_at: r.updated_at.and_then(|s| {
-                        chrono::DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&chrono::Utc))
-                    }),
-                })
-            })
-            .collect();
-
-        Ok(PaginatedResponse::new(
-            records,
-            params.page,
-            params.page_size,
-            total_count,
-        ))
+        let keyword = LiteralKeyword::new(params.keyword.as_deref(), KEYWORD_SPECIAL_CHARS);
+        list_records_by_keyword(keyword, params, MAX_PAGE_SIZE, |params| {
+            let zone_name = &domain_info.name;
+            async move { self.query_records(domain_id, zone_name, &params).await }
+        })
+        .await
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
@@ -360,7 +424,10 @@ impl DnsProvider for HuaweicloudProvider {
             ttl: req.ttl,
         };
 
-        let path = format!("/v2/zones/{}/recordsets", req.domain_id);
+        let path = format!(
+            "/v2/zones/{}/recordsets",
+            encode_path_segment(&req.domain_id)
+        );
         let ctx = ErrorContext {
             record_name: Some(req.name.clone()),
             domain: Some(req.domain_id.clone()),
@@ -412,7 +479,11 @@ impl DnsProvider for HuaweicloudProvider {
             ttl: req.ttl,
         };
 
-        let path = format!("/v2/zones/{}/recordsets/{}", req.domain_id, record_id);
+        let path = format!(
+            "/v2/zones/{}/recordsets/{}",
+            encode_path_segment(&req.domain_id),
+            encode_path_segment(record_id)
+        );
         let ctx = ErrorContext {
             record_name: Some(req.name.clone()),
             record_id: Some(record_id.to_string()),
@@ -434,7 +505,11 @@ impl DnsProvider for HuaweicloudProvider {
     }
 
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
-        let path = format!("/v2/zones/{domain_id}/recordsets/{record_id}");
+        let path = format!(
+            "/v2/zones/{}/recordsets/{}",
+            encode_path_segment(domain_id),
+            encode_path_segment(record_id)
+        );
         let ctx = ErrorContext {
             record_id: Some(record_id.to_string()),
             domain: Some(domain_id.to_string()),
@@ -442,4 +517,134 @@ impl DnsProvider for HuaweicloudProvider {
         };
         self.delete(&path, ctx).await
     }
+
+    async fn get_record_set(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<Option<RecordSet>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(name, &domain_info.name));
+
+        let Some(record_set) = self
+            .find_record_set(domain_id, &full_name, record_type)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let values = record_set
+            .records
+            .unwrap_or_default()
+            .iter()
+            .map(|value| Self::parse_record_data(&record_set.record_type, value))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(RecordSet {
+            id: Some(record_set.id),
+            name: name.to_string(),
+            record_type: record_type.clone(),
+            ttl: record_set.ttl.unwrap_or(300),
+            values,
+        }))
+    }
+
+    async fn replace_record_set(&self, domain_id: &str, set: &RecordSet) -> Result<()> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(&set.name, &domain_info.name));
+        let existing = self
+            .find_record_set(domain_id, &full_name, &set.record_type)
+            .await?;
+
+        #[derive(Serialize)]
+        struct RecordSetBody {
+            name: String,
+            #[serde(rename = "type")]
+            record_type: String,
+            records: Vec<String>,
+            ttl: u32,
+        }
+
+        let body = RecordSetBody {
+            name: full_name,
+            record_type: record_type_to_string(&set.record_type).to_string(),
+            records: set
+                .values
+                .iter()
+                .map(Self::record_data_to_record_string)
+                .collect(),
+            ttl: set.ttl,
+        };
+        let ctx = ErrorContext {
+            record_name: Some(set.name.clone()),
+            record_id: existing.as_ref().map(|r| r.id.clone()),
+            domain: Some(domain_id.to_string()),
+        };
+        let zone_path = format!("/v2/zones/{}/recordsets", encode_path_segment(domain_id));
+
+        // 华为云的记录集不能为空：清空即删除整组
+        match existing {
+            Some(record_set) if set.values.is_empty() => {
+                let path = format!("{zone_path}/{}", encode_path_segment(&record_set.id));
+                self.delete(&path, ctx).await
+            }
+            Some(record_set) => {
+                let path = format!("{zone_path}/{}", encode_path_segment(&record_set.id));
+                let _response: CreateRecordSetResponse = self.put(&path, &body, ctx).await?;
+                Ok(())
+            }
+            None if set.values.is_empty() => Ok(()),
+            None => {
+                let _response: CreateRecordSetResponse = self.post(&zone_path, &body, ctx).await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_zone_status() {
+        for (raw, expected) in [
+            (Some("ACTIVE"), DomainStatus::Active),
+            (Some("PENDING_UPDATE"), DomainStatus::Active),
+            (Some("PENDING_CREATE"), DomainStatus::Pending),
+            (Some("PENDING_DELETE"), DomainStatus::Suspended),
+            (Some("PENDING_FREEZE"), DomainStatus::Suspended),
+            (Some("PENDING_DISABLE"), DomainStatus::Suspended),
+            (Some("FREEZE"), DomainStatus::Suspended),
+            (Some("ILLEGAL"), DomainStatus::Suspended),
+            (Some("POLICE"), DomainStatus::Suspended),
+            (Some("DISABLE"), DomainStatus::Suspended),
+            (Some("ERROR"), DomainStatus::Unknown("ERROR".to_string())),
+            (None, DomainStatus::Unknown(String::new())),
+        ] {
+            assert_eq!(HuaweicloudProvider::convert_domain_status(raw), expected);
+        }
+    }
+
+    #[test]
+    fn parses_soa_record_set() {
+        let data = HuaweicloudProvider::parse_record_data(
+            "SOA",
+            "ns1.huaweicloud-dns.com. hostmaster.example.com. (1 7200 900 1209600 300)",
+        );
+        assert_eq!(
+            data.ok(),
+            Some(RecordData::SOA {
+                mname: "ns1.huaweicloud-dns.com.".to_string(),
+                rname: "hostmaster.example.com.".to_string(),
+                serial: 1,
+                refresh: 7200,
+                retry: 900,
+                expire: 1_209_600,
+                minimum: 300,
+            })
+        );
+
+        assert!(HuaweicloudProvider::parse_record_data("SOA", "ns1.example.com. (1 2 3)").is_err());
+    }
 }
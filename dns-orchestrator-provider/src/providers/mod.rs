@@ -6,16 +6,28 @@ pub mod common;
 mod aliyun;
 #[cfg(feature = "cloudflare")]
 mod cloudflare;
+#[cfg(feature = "digitalocean")]
+mod digitalocean;
 #[cfg(feature = "dnspod")]
 mod dnspod;
+#[cfg(feature = "gcp-dns")]
+mod gcp_dns;
 #[cfg(feature = "huaweicloud")]
 mod huaweicloud;
+#[cfg(feature = "route53")]
+mod route53;
 
 #[cfg(feature = "aliyun")]
 pub use aliyun::AliyunProvider;
 #[cfg(feature = "cloudflare")]
 pub use cloudflare::CloudflareProvider;
+#[cfg(feature = "digitalocean")]
+pub use digitalocean::DigitalOceanProvider;
 #[cfg(feature = "dnspod")]
 pub use dnspod::DnspodProvider;
+#[cfg(feature = "gcp-dns")]
+pub use gcp_dns::GcpDnsProvider;
 #[cfg(feature = "huaweicloud")]
 pub use huaweicloud::HuaweicloudProvider;
+#[cfg(feature = "route53")]
+pub use route53::Route53Provider;
@@ -0,0 +1,118 @@
+//! AWS Route53 错误映射
+//!
+//! 参考:
+//! - <https://docs.aws.amazon.com/Route53/latest/APIReference/CommonErrors.html>
+//! - <https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html#API_ChangeResourceRecordSets_Errors>
+//!
+//! `InvalidChangeBatch` 只有一个错误码，具体原因在消息中（如 "but it already exists"），
+//! 按消息内容细分为记录已存在 / 记录不存在 / 参数无效。
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::Route53Provider;
+
+/// Route53 错误码映射实现
+impl ProviderErrorMapper for Route53Provider {
+    fn provider_name(&self) -> &'static str {
+        "route53"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some(
+                "InvalidClientTokenId"         // Access Key ID 不存在
+                | "SignatureDoesNotMatch"      // Secret Access Key 错误
+                | "IncompleteSignature"        // 签名不完整
+                | "MissingAuthenticationToken" // 缺少凭证
+                | "UnrecognizedClientException"
+                | "ExpiredToken"               // 临时凭证已过期
+                | "RequestExpired",            // 请求时间与服务器相差过大
+            ) => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 权限/操作被拒绝 ============
+            Some(
+                "AccessDenied"             // IAM 策略不允许（含 STS AssumeRole）
+                | "AccessDeniedException"
+                | "NotAuthorizedException"
+                | "OptInRequired",         // 账号未开通服务
+            ) => ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 配额/频率限制 ============
+            Some(
+                "Throttling"                   // 请求频率超限
+                | "ThrottlingException"
+                | "PriorRequestNotComplete"    // 同一 zone 上一批变更尚未处理完
+                | "LimitsExceeded"             // 记录集数量超限
+                | "TooManyHostedZones",
+            ) => ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 域名不存在 ============
+            Some("NoSuchHostedZone" | "HostedZoneNotFound") => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 变更批次校验失败 ============
+            Some("InvalidChangeBatch") if raw.message.contains("already exists") => {
+                ProviderError::RecordExists {
+                    provider: self.provider_name().to_string(),
+                    record_name: context.record_name.unwrap_or_default(),
+                    raw_message: Some(raw.message),
+                }
+            }
+            Some("InvalidChangeBatch") if raw.message.contains("not found") => {
+                ProviderError::RecordNotFound {
+                    provider: self.provider_name().to_string(),
+                    record_id: context.record_id.unwrap_or_default(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 参数无效 - TTL ============
+            Some("InvalidChangeBatch" | "InvalidInput") if raw.message.contains("TTL") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: "ttl".to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 参数无效 - 记录值 ============
+            Some("InvalidChangeBatch" | "InvalidInput") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "value".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 参数无效 - 域名 ============
+            Some("InvalidDomainName") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "name".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 网络/后端服务错误 ============
+            Some("ServiceUnavailable" | "InternalFailure" | "InternalError") => {
+                ProviderError::NetworkError {
+                    provider: self.provider_name().to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
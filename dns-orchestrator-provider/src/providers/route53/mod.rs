@@ -0,0 +1,170 @@
+//! AWS Route53 DNS Provider
+
+mod error;
+mod http;
+mod provider;
+mod sign;
+pub(crate) mod types;
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+
+use crate::providers::common::create_http_client;
+
+pub(crate) const ROUTE53_API_VERSION: &str = "2013-04-01";
+/// ListHostedZones 单页最大数量
+pub(crate) const MAX_ZONE_PAGE_SIZE: u32 = 100;
+/// ListResourceRecordSets 单页最大数量
+pub(crate) const MAX_RECORD_PAGE_SIZE: u32 = 300;
+/// 批量提交的单批变更数上限（`ChangeResourceRecordSets` 每批最多 1000 个值，
+/// `UPSERT` 按两倍计且需带上整组的值，保守取值）
+pub(crate) const MAX_BATCH_CHANGES: u32 = 100;
+/// 等待变更生效（INSYNC）的默认超时
+const DEFAULT_CHANGE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// AWS 分区（决定 Route53 / STS 端点与签名区域）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Partition {
+    Aws,
+    China,
+    UsGov,
+}
+
+impl Partition {
+    pub(crate) fn from_region(region: &str) -> Self {
+        if region.starts_with("cn-") {
+            Self::China
+        } else if region.starts_with("us-gov-") {
+            Self::UsGov
+        } else {
+            Self::Aws
+        }
+    }
+
+    /// Route53 是全局服务，每个分区只有一个端点
+    pub(crate) fn route53_host(self) -> &'static str {
+        match self {
+            Self::Aws => "route53.amazonaws.com",
+            Self::China => "route53.amazonaws.com.cn",
+            Self::UsGov => "route53.us-gov.amazonaws.com",
+        }
+    }
+
+    /// Route53 的签名区域固定为分区的主区域
+    pub(crate) fn route53_signing_region(self) -> &'static str {
+        match self {
+            Self::Aws => "us-east-1",
+            Self::China => "cn-northwest-1",
+            Self::UsGov => "us-gov-west-1",
+        }
+    }
+
+    /// 区域化的 STS 端点
+    pub(crate) fn sts_host(self, region: &str) -> String {
+        match self {
+            Self::China => format!("sts.{region}.amazonaws.com.cn"),
+            Self::Aws | Self::UsGov => format!("sts.{region}.amazonaws.com"),
+        }
+    }
+}
+
+/// 签名用的 AWS 凭证（`AssumeRole` 得到的临时凭证带 session token）
+#[derive(Debug, Clone)]
+pub(crate) struct AwsCredentials {
+    pub(crate) access_key_id: String,
+    pub(crate) secret_access_key: String,
+    pub(crate) session_token: Option<String>,
+}
+
+/// 缓存的临时凭证
+#[derive(Debug, Clone)]
+pub(crate) struct AssumedCredentials {
+    pub(crate) credentials: AwsCredentials,
+    pub(crate) expires_at: DateTime<Utc>,
+}
+
+/// AWS Route53 DNS Provider
+pub struct Route53Provider {
+    pub(crate) client: Client,
+    pub(crate) access_key_id: String,
+    pub(crate) secret_access_key: String,
+    pub(crate) region: String,
+    pub(crate) role_arn: Option<String>,
+    pub(crate) max_retries: u32,
+    pub(crate) change_timeout: Duration,
+    pub(crate) assumed: Mutex<Option<AssumedCredentials>>,
+}
+
+/// AWS Route53 Provider Builder
+pub struct Route53ProviderBuilder {
+    access_key_id: String,
+    secret_access_key: String,
+    region: String,
+    role_arn: Option<String>,
+    max_retries: u32,
+    change_timeout: Duration,
+}
+
+impl Route53ProviderBuilder {
+    fn new(access_key_id: String, secret_access_key: String, region: String) -> Self {
+        Self {
+            access_key_id,
+            secret_access_key,
+            region,
+            role_arn: None,
+            max_retries: 2,
+            change_timeout: DEFAULT_CHANGE_TIMEOUT,
+        }
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// 设置要扮演的 IAM 角色（通过 STS `AssumeRole` 获取临时凭证）
+    pub fn role_arn(mut self, role_arn: Option<String>) -> Self {
+        self.role_arn = role_arn.filter(|arn| !arn.trim().is_empty());
+        self
+    }
+
+    /// 设置等待变更生效（INSYNC）的超时，为零时提交后不等待
+    pub fn change_timeout(mut self, timeout: Duration) -> Self {
+        self.change_timeout = timeout;
+        self
+    }
+
+    pub fn build(self) -> Route53Provider {
+        Route53Provider {
+            client: create_http_client(),
+            access_key_id: self.access_key_id,
+            secret_access_key: self.secret_access_key,
+            region: self.region,
+            role_arn: self.role_arn,
+            max_retries: self.max_retries,
+            change_timeout: self.change_timeout,
+            assumed: Mutex::new(None),
+        }
+    }
+}
+
+impl Route53Provider {
+    pub fn new(access_key_id: String, secret_access_key: String, region: String) -> Self {
+        Self::builder(access_key_id, secret_access_key, region).build()
+    }
+
+    pub fn builder(
+        access_key_id: String,
+        secret_access_key: String,
+        region: String,
+    ) -> Route53ProviderBuilder {
+        Route53ProviderBuilder::new(access_key_id, secret_access_key, region)
+    }
+
+    pub(crate) fn partition(&self) -> Partition {
+        Partition::from_region(&self.region)
+    }
+}
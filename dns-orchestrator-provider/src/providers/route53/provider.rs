@@ -0,0 +1,1330 @@
+//! AWS Route53 DnsProvider trait 实现
+//!
+//! Route53 只有记录集（同名同类型一组值）没有单条记录 ID，单条记录的 ID 由
+//! `完整名称|类型|值摘要` 合成；单条记录的增删改读取所在记录集后整组 `UPSERT`
+//! （清空时 `DELETE`），TTL 对整组生效。别名记录与加权/延迟等路由策略记录不在列表中展示。
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::credential_format::{AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY};
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    encode_path_segment, full_name_to_relative, normalize_domain_name, parse_record_type,
+    parse_soa, record_name_matches, record_type_to_string, relative_to_full_name,
+};
+use crate::traits::{DnsProvider, ErrorContext, fallback_after_batch_error};
+use crate::types::{
+    ChangeResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType,
+    PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordChange, RecordData, RecordQueryParams,
+    RecordSet, STANDARD_RECORD_TYPES, TxtFormat, UpdateDnsRecordRequest,
+};
+use crate::utils::txt::{self, EscapeRadix};
+
+use super::sign::hash_payload;
+use super::types::{
+    Change, ChangeAction, GetHostedZoneResponse, HostedZone, ListHostedZonesResponse,
+    ListResourceRecordSetsResponse, ResourceRecordSet,
+};
+use super::{MAX_BATCH_CHANGES, MAX_RECORD_PAGE_SIZE, MAX_ZONE_PAGE_SIZE, Route53Provider};
+
+/// 合成记录 ID 中值摘要的长度（十六进制字符）
+const VALUE_DIGEST_LEN: usize = 12;
+
+/// 合成的记录 ID：`完整名称|类型|值摘要`
+#[derive(Debug)]
+struct RecordId {
+    /// 完整名称（小写，不含末尾的点）
+    name: String,
+    record_type: DnsRecordType,
+    digest: String,
+}
+
+impl RecordId {
+    fn new(name: &str, record_type: DnsRecordType, value: &str) -> Self {
+        Self {
+            name: normalize_domain_name(name).to_lowercase(),
+            digest: Route53Provider::value_digest(&record_type, value),
+            record_type,
+        }
+    }
+
+    fn parse(id: &str) -> Result<Self> {
+        let invalid = || ProviderError::RecordNotFound {
+            provider: "route53".to_string(),
+            record_id: id.to_string(),
+            raw_message: Some("Invalid Route53 record ID".to_string()),
+        };
+        let mut parts = id.splitn(3, '|');
+        let (Some(name), Some(record_type), Some(digest)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        Ok(Self {
+            name: name.to_string(),
+            record_type: parse_record_type(record_type, "route53").map_err(|_| invalid())?,
+            digest: digest.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for RecordId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.name,
+            record_type_to_string(&self.record_type),
+            self.digest
+        )
+    }
+}
+
+/// 批量提交时的记录集键：（小写完整名称，类型）
+type SetKey = (String, &'static str);
+
+/// 批量提交时记录集的工作副本
+struct PendingSet {
+    /// 提交用的名称（沿用 Route53 返回的转义写法，新建时为 `完整名称.`）
+    name: String,
+    record_type: DnsRecordType,
+    /// 批次开始前的 TTL 与值，记录集原本不存在时为 `None`
+    original: Option<(u32, Vec<String>)>,
+    ttl: u32,
+    values: Vec<String>,
+}
+
+impl PendingSet {
+    fn position_of(&self, digest: &str) -> Option<usize> {
+        self.values
+            .iter()
+            .position(|value| Route53Provider::value_digest(&self.record_type, value) == digest)
+    }
+
+    /// 相对批次开始前的变更，未变化时为 `None`
+    fn into_change(self) -> Option<Change> {
+        let record_type = record_type_to_string(&self.record_type);
+        match self.original {
+            Some((ttl, values)) if self.values.is_empty() => Some(Change {
+                action: ChangeAction::Delete,
+                name: self.name,
+                record_type,
+                ttl,
+                values,
+            }),
+            Some((ttl, values)) if ttl == self.ttl && values == self.values => None,
+            None if self.values.is_empty() => None,
+            _ => Some(Change {
+                action: ChangeAction::Upsert,
+                name: self.name,
+                record_type,
+                ttl: self.ttl,
+                values: self.values,
+            }),
+        }
+    }
+}
+
+impl Route53Provider {
+    /// 去掉 hosted zone ID 的 `/hostedzone/` 前缀
+    fn zone_id(raw: &str) -> String {
+        raw.trim_start_matches("/hostedzone/").to_string()
+    }
+
+    fn convert_zone(zone: HostedZone) -> ProviderDomain {
+        ProviderDomain {
+            id: Self::zone_id(&zone.id),
+            name: decode_escapes(&normalize_domain_name(&zone.name)),
+            provider: ProviderType::AwsRoute53,
+            // Hosted zone 没有状态字段，存在即生效
+            status: DomainStatus::Active,
+            provider_status_raw: zone
+                .config
+                .filter(|config| config.private_zone)
+                .map(|_| "private".to_string()),
+            record_count: zone.resource_record_set_count,
+        }
+    }
+
+    /// 解析 Route53 记录值为 RecordData
+    /// Route53 格式与 zone 文件一致：TXT 为带引号的字符串，MX/SRV/CAA 的字段以空格分隔
+    fn parse_record_data(record_type: &str, value: &str) -> Result<RecordData> {
+        match record_type {
+            "A" => Ok(RecordData::A {
+                address: value.to_string(),
+            }),
+            "AAAA" => Ok(RecordData::AAAA {
+                address: value.to_string(),
+            }),
+            "CNAME" => Ok(RecordData::CNAME {
+                target: value.to_string(),
+            }),
+            "MX" => match value.split_once(' ') {
+                Some((priority, exchange)) => Ok(RecordData::MX {
+                    priority: parse_number(priority, "MX priority")?,
+                    exchange: exchange.trim().to_string(),
+                }),
+                None => Err(ProviderError::ParseError {
+                    provider: "route53".to_string(),
+                    detail: format!(
+                        "Invalid MX record format: expected 'priority exchange', got '{value}'"
+                    ),
+                }),
+            },
+            "TXT" => Ok(RecordData::TXT {
+                text: txt::unquote(value, EscapeRadix::Octal),
+            }),
+            "NS" => Ok(RecordData::NS {
+                nameserver: value.to_string(),
+            }),
+            "SRV" => match value.split_whitespace().collect::<Vec<_>>().as_slice() {
+                [priority, weight, port, target] => Ok(RecordData::SRV {
+                    priority: parse_number(priority, "SRV priority")?,
+                    weight: parse_number(weight, "SRV weight")?,
+                    port: parse_number(port, "SRV port")?,
+                    target: (*target).to_string(),
+                }),
+                _ => Err(ProviderError::ParseError {
+                    provider: "route53".to_string(),
+                    detail: format!(
+                        "Invalid SRV record format: expected 'priority weight port target', got '{value}'"
+                    ),
+                }),
+            },
+            "CAA" => match value.splitn(3, ' ').collect::<Vec<_>>().as_slice() {
+                [flags, tag, caa_value] => Ok(RecordData::CAA {
+                    flags: parse_number(flags, "CAA flags")?,
+                    tag: (*tag).to_string(),
+                    value: caa_value.trim_matches('"').to_string(),
+                }),
+                _ => Err(ProviderError::ParseError {
+                    provider: "route53".to_string(),
+                    detail: format!(
+                        "Invalid CAA record format: expected 'flags tag value', got '{value}'"
+                    ),
+                }),
+            },
+            "SOA" => parse_soa(value, "route53"),
+            _ => Err(ProviderError::UnsupportedRecordType {
+                provider: "route53".to_string(),
+                record_type: record_type.to_string(),
+            }),
+        }
+    }
+
+    /// 将 RecordData 转换为 Route53 记录值
+    fn record_data_to_value(data: &RecordData) -> String {
+        match data {
+            RecordData::A { address } | RecordData::AAAA { address } => address.clone(),
+            RecordData::CNAME { target } => target.clone(),
+            RecordData::MX { priority, exchange } => format!("{priority} {exchange}"),
+            RecordData::TXT { text } => txt::quote(text, EscapeRadix::Octal),
+            RecordData::NS { nameserver } => nameserver.clone(),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{priority} {weight} {port} {target}"),
+            RecordData::CAA { flags, tag, value } => format!("{flags} {tag} \"{value}\""),
+            RecordData::SOA { .. } => data.display_value(),
+        }
+    }
+
+    /// 记录值摘要：按解析后的内容计算，TXT 分段、引号等写法不同的同一值摘要相同
+    fn value_digest(record_type: &DnsRecordType, value: &str) -> String {
+        let canonical = Self::parse_record_data(record_type_to_string(record_type), value)
+            .map(|data| Self::record_data_to_value(&data))
+            .unwrap_or_else(|_| value.to_string());
+        hash_payload(&canonical)[..VALUE_DIGEST_LEN].to_string()
+    }
+
+    /// 展开记录集为单条记录（跳过别名、路由策略记录与无法解析的值）
+    fn expand_record_set(
+        set: &ResourceRecordSet,
+        domain_id: &str,
+        zone_name: &str,
+    ) -> Vec<DnsRecord> {
+        if !set.is_simple() {
+            log::debug!(
+                "[route53] Skipping alias/routing policy record set {} {}",
+                set.name,
+                set.record_type
+            );
+            return Vec::new();
+        }
+        let Ok(record_type) = parse_record_type(&set.record_type, "route53") else {
+            return Vec::new();
+        };
+        let full_name = decode_escapes(&set.name);
+
+        set.values()
+            .into_iter()
+            .filter_map(|value| {
+                let data = Self::parse_record_data(&set.record_type, value).ok()?;
+                Some(DnsRecord {
+                    id: RecordId::new(&full_name, record_type.clone(), value).to_string(),
+                    domain_id: domain_id.to_string(),
+                    name: full_name_to_relative(&full_name, zone_name),
+                    ttl: set.ttl.unwrap_or(300),
+                    data,
+                    proxied: None,
+                    created_at: None,
+                    updated_at: None,
+                })
+            })
+            .collect()
+    }
+
+    /// 列出 hosted zone 的全部记录集（按 name/type 游标翻页）
+    async fn list_all_record_sets(&self, zone_id: &str) -> Result<Vec<ResourceRecordSet>> {
+        let path = format!("/hostedzone/{}/rrset", encode_path_segment(zone_id));
+        let mut sets = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            let query = format!("maxitems={MAX_RECORD_PAGE_SIZE}{cursor}");
+            let ctx = ErrorContext {
+                domain: Some(zone_id.to_string()),
+                ..Default::default()
+            };
+            let response: ListResourceRecordSetsResponse = self.get(&path, &query, ctx).await?;
+            sets.extend(response.resource_record_sets.items);
+
+            let (true, Some(name), Some(record_type)) = (
+                response.is_truncated,
+                response.next_record_name,
+                response.next_record_type,
+            ) else {
+                return Ok(sets);
+            };
+            cursor = format!(
+                "&name={}&type={}",
+                urlencoding::encode(&name),
+                urlencoding::encode(&record_type)
+            );
+            if let Some(identifier) = response.next_record_identifier {
+                cursor.push_str(&format!("&identifier={}", urlencoding::encode(&identifier)));
+            }
+        }
+    }
+
+    /// 按完整名称与类型精确查找普通记录集
+    async fn find_record_set(
+        &self,
+        zone_id: &str,
+        full_name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<Option<ResourceRecordSet>> {
+        let type_str = record_type_to_string(record_type);
+        // 列表从 name/type 处开始按字典序返回，同名同类型的路由策略记录排在一起
+        let query = format!(
+            "name={}&type={}&maxitems={MAX_RECORD_PAGE_SIZE}",
+            urlencoding::encode(full_name),
+            urlencoding::encode(type_str)
+        );
+        let path = format!("/hostedzone/{}/rrset", encode_path_segment(zone_id));
+        let ctx = ErrorContext {
+            record_name: Some(full_name.to_string()),
+            domain: Some(zone_id.to_string()),
+            ..Default::default()
+        };
+        let response: ListResourceRecordSetsResponse = self.get(&path, &query, ctx).await?;
+
+        let full_name = normalize_domain_name(full_name);
+        Ok(response.resource_record_sets.items.into_iter().find(|set| {
+            set.is_simple()
+                && normalize_domain_name(&decode_escapes(&set.name))
+                    .eq_ignore_ascii_case(&full_name)
+                && set.record_type.eq_ignore_ascii_case(type_str)
+        }))
+    }
+
+    /// 在记录集中按摘要查找值的下标
+    fn position_of(
+        set: &ResourceRecordSet,
+        record_type: &DnsRecordType,
+        digest: &str,
+    ) -> Option<usize> {
+        set.values()
+            .iter()
+            .position(|value| Self::value_digest(record_type, value) == digest)
+    }
+
+    /// 记录集的值改为 `values`：为空时删除整组（`DELETE` 须带上原 TTL 与值）
+    fn rewrite_change(
+        set: &ResourceRecordSet,
+        record_type: &DnsRecordType,
+        ttl: u32,
+        values: Vec<String>,
+    ) -> Change {
+        if values.is_empty() {
+            Change {
+                action: ChangeAction::Delete,
+                name: set.name.clone(),
+                record_type: record_type_to_string(record_type),
+                ttl: set.ttl.unwrap_or(ttl),
+                values: set.values().into_iter().map(ToString::to_string).collect(),
+            }
+        } else {
+            Change {
+                action: ChangeAction::Upsert,
+                name: set.name.clone(),
+                record_type: record_type_to_string(record_type),
+                ttl,
+                values,
+            }
+        }
+    }
+
+    /// 把一个值加入（可能不存在的）记录集，值已存在时返回 `RecordExists`
+    fn add_value_change(
+        existing: Option<&ResourceRecordSet>,
+        full_name: &str,
+        record_type: &DnsRecordType,
+        ttl: u32,
+        value: String,
+        ctx: &ErrorContext,
+    ) -> Result<Change> {
+        let mut values: Vec<String> = existing
+            .map(|set| set.values().into_iter().map(ToString::to_string).collect())
+            .unwrap_or_default();
+        let digest = Self::value_digest(record_type, &value);
+        if values
+            .iter()
+            .any(|existing| Self::value_digest(record_type, existing) == digest)
+        {
+            return Err(ProviderError::RecordExists {
+                provider: "route53".to_string(),
+                record_name: ctx.record_name.clone().unwrap_or_default(),
+                raw_message: None,
+            });
+        }
+        values.push(value);
+
+        Ok(Change {
+            action: ChangeAction::Upsert,
+            name: format!("{full_name}."),
+            record_type: record_type_to_string(record_type),
+            ttl,
+            values,
+        })
+    }
+
+    fn record_not_found(record_id: &str) -> ProviderError {
+        ProviderError::RecordNotFound {
+            provider: "route53".to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        }
+    }
+
+    /// 在记录集快照上依次应用各变更，合并为每个记录集至多一条 `UPSERT`/`DELETE`
+    ///
+    /// 返回逐条结果与待提交的变更；校验失败（值已存在、记录不存在）的变更直接计为失败，
+    /// 不进入批次。
+    fn plan_batch(
+        sets: Vec<ResourceRecordSet>,
+        domain_id: &str,
+        zone_name: &str,
+        changes: &[RecordChange],
+    ) -> (Vec<ChangeResult>, Vec<Change>) {
+        let mut pending: BTreeMap<SetKey, PendingSet> = sets
+            .into_iter()
+            .filter(ResourceRecordSet::is_simple)
+            .filter_map(|set| {
+                let record_type = parse_record_type(&set.record_type, "route53").ok()?;
+                let key = (
+                    normalize_domain_name(&decode_escapes(&set.name)).to_lowercase(),
+                    record_type_to_string(&record_type),
+                );
+                let values: Vec<String> =
+                    set.values().into_iter().map(ToString::to_string).collect();
+                let ttl = set.ttl.unwrap_or(300);
+                Some((
+                    key,
+                    PendingSet {
+                        name: set.name.clone(),
+                        record_type,
+                        original: Some((ttl, values.clone())),
+                        ttl,
+                        values,
+                    },
+                ))
+            })
+            .collect();
+
+        let now = chrono::Utc::now();
+        let mut results = Vec::with_capacity(changes.len());
+        for change in changes {
+            let result = match change {
+                RecordChange::Create(req) => {
+                    Self::plan_add(&mut pending, zone_name, &req.name, req.ttl, &req.data).map(
+                        |id| DnsRecord {
+                            id,
+                            domain_id: domain_id.to_string(),
+                            name: req.name.clone(),
+                            ttl: req.ttl,
+                            data: req.data.clone(),
+                            proxied: None,
+                            created_at: Some(now),
+                            updated_at: Some(now),
+                        },
+                    )
+                }
+                RecordChange::Update { record_id, request } => {
+                    Self::plan_update(&mut pending, zone_name, record_id, request).map(|id| {
+                        DnsRecord {
+                            id,
+                            domain_id: domain_id.to_string(),
+                            name: request.name.clone(),
+                            ttl: request.ttl,
+                            data: request.data.clone(),
+                            proxied: None,
+                            created_at: None,
+                            updated_at: Some(now),
+                        }
+                    })
+                }
+                RecordChange::Delete { record_id } => {
+                    results.push(
+                        Self::locate(&pending, record_id)
+                            .map(|(key, index)| {
+                                if let Some(set) = pending.get_mut(&key) {
+                                    set.values.remove(index);
+                                }
+                            })
+                            .into(),
+                    );
+                    continue;
+                }
+            };
+            results.push(result.into());
+        }
+
+        let batch = pending
+            .into_values()
+            .filter_map(PendingSet::into_change)
+            .collect();
+        (results, batch)
+    }
+
+    /// 按合成 ID 在工作副本中定位记录集与值的下标
+    fn locate(pending: &BTreeMap<SetKey, PendingSet>, record_id: &str) -> Result<(SetKey, usize)> {
+        let id = RecordId::parse(record_id)?;
+        let key = (id.name, record_type_to_string(&id.record_type));
+        let index = pending
+            .get(&key)
+            .and_then(|set| set.position_of(&id.digest))
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+        Ok((key, index))
+    }
+
+    /// 把一个值加入工作副本中（可能不存在的）记录集，返回新记录的 ID
+    fn plan_add(
+        pending: &mut BTreeMap<SetKey, PendingSet>,
+        zone_name: &str,
+        name: &str,
+        ttl: u32,
+        data: &RecordData,
+    ) -> Result<String> {
+        let full_name = relative_to_full_name(name, zone_name);
+        let record_type = data.record_type();
+        let value = Self::record_data_to_value(data);
+        let id = RecordId::new(&full_name, record_type.clone(), &value);
+
+        let set = pending
+            .entry((id.name.clone(), record_type_to_string(&record_type)))
+            .or_insert_with(|| PendingSet {
+                name: format!("{full_name}."),
+                record_type,
+                original: None,
+                ttl,
+                values: Vec::new(),
+            });
+        if set.position_of(&id.digest).is_some() {
+            return Err(ProviderError::RecordExists {
+                provider: "route53".to_string(),
+                record_name: name.to_string(),
+                raw_message: None,
+            });
+        }
+        set.values.push(value);
+        set.ttl = ttl;
+        Ok(id.to_string())
+    }
+
+    /// 在工作副本中更新一条记录，语义同 [`DnsProvider::update_record`]
+    fn plan_update(
+        pending: &mut BTreeMap<SetKey, PendingSet>,
+        zone_name: &str,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<String> {
+        let (old_key, index) = Self::locate(pending, record_id)?;
+        let full_name = relative_to_full_name(&req.name, zone_name);
+        let record_type = req.data.record_type();
+        let value = Self::record_data_to_value(&req.data);
+        let id = RecordId::new(&full_name, record_type.clone(), &value);
+
+        if (id.name.as_str(), record_type_to_string(&record_type))
+            == (old_key.0.as_str(), old_key.1)
+        {
+            // 同一记录集内替换该值（新值与组内其他值重复时合并）
+            if let Some(set) = pending.get_mut(&old_key) {
+                set.values[index] = value;
+                let mut seen = Vec::new();
+                set.values.retain(|v| {
+                    let digest = Self::value_digest(&record_type, v);
+                    let keep = !seen.contains(&digest);
+                    seen.push(digest);
+                    keep
+                });
+                set.ttl = req.ttl;
+            }
+            return Ok(id.to_string());
+        }
+
+        // 名称或类型变化：先确认目标记录集中没有该值，再从原记录集移除
+        let target_key = (id.name.clone(), record_type_to_string(&record_type));
+        if pending
+            .get(&target_key)
+            .is_some_and(|set| set.position_of(&id.digest).is_some())
+        {
+            return Err(ProviderError::RecordExists {
+                provider: "route53".to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+        if let Some(set) = pending.get_mut(&old_key) {
+            set.values.remove(index);
+        }
+        Self::plan_add(pending, zone_name, &req.name, req.ttl, &req.data)
+    }
+
+    /// 读取记录集快照、在内存中合并各变更后一次原子提交
+    async fn submit_batch(
+        &self,
+        domain_id: &str,
+        changes: &[RecordChange],
+    ) -> Result<Vec<ChangeResult>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let sets = self.list_all_record_sets(domain_id).await?;
+        let (results, batch) = Self::plan_batch(sets, domain_id, &domain_info.name, changes);
+        if !batch.is_empty() {
+            let ctx = ErrorContext {
+                domain: Some(domain_id.to_string()),
+                ..Default::default()
+            };
+            self.change_record_sets(domain_id, &batch, ctx).await?;
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Route53Provider {
+    fn id(&self) -> &'static str {
+        "route53"
+    }
+
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::AwsRoute53,
+            name: "AWS Route 53".to_string(),
+            description: "Amazon Route 53 托管区域，支持通过 IAM 角色访问".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "accessKeyId".to_string(),
+                    label: "Access Key ID".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 Access Key ID".to_string()),
+                    help_text: None,
+                    pattern: Some(AWS_ACCESS_KEY_ID.pattern()),
+                    options: None,
+                },
+                ProviderCredentialField {
+                    key: "secretAccessKey".to_string(),
+                    label: "Secret Access Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Secret Access Key".to_string()),
+                    help_text: None,
+                    pattern: Some(AWS_SECRET_ACCESS_KEY.pattern()),
+                    options: None,
+                },
+                ProviderCredentialField {
+                    key: "region".to_string(),
+                    label: "Region".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("us-east-1".to_string()),
+                    help_text: Some(
+                        "用于选择 AWS 分区与 STS 端点，中国区填 cn-north-1 或 cn-northwest-1"
+                            .to_string(),
+                    ),
+                    pattern: Some("^[a-z]{2}(-[a-z]+)+-[0-9]+$".to_string()),
+                    options: None,
+                },
+            ],
+            optional_fields: vec![ProviderCredentialField {
+                key: "roleArn".to_string(),
+                label: "Role ARN".to_string(),
+                field_type: FieldType::Text,
+                placeholder: Some("arn:aws:iam::123456789012:role/dns-admin".to_string()),
+                help_text: Some("填写后先通过 STS AssumeRole 获取临时凭证".to_string()),
+                pattern: Some("^arn:aws[a-z-]*:iam::[0-9]{12}:role/.+$".to_string()),
+                options: None,
+            }],
+            record_types: STANDARD_RECORD_TYPES.to_vec(),
+            features: ProviderFeatures {
+                record_sets: true,
+                batch_changes: true,
+                txt_format: TxtFormat::Quoted,
+                ..ProviderFeatures::default()
+            },
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_ZONE_PAGE_SIZE,
+                max_page_size_records: MAX_RECORD_PAGE_SIZE,
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                max_batch_changes: MAX_BATCH_CHANGES,
+                max_txt_length: 4000,
+            },
+        }
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<ListHostedZonesResponse>("/hostedzone", "maxitems=1", ErrorContext::default())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Route53 按 marker 翻页，取全部 hosted zone 后在本地分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let mut zones = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let mut query = format!("maxitems={MAX_ZONE_PAGE_SIZE}");
+            if let Some(marker) = &marker {
+                query.push_str(&format!("&marker={}", urlencoding::encode(marker)));
+            }
+            let response: ListHostedZonesResponse = self
+                .get("/hostedzone", &query, ErrorContext::default())
+                .await?;
+            zones.extend(response.hosted_zones.items);
+
+            match response.next_marker {
+                Some(next) if response.is_truncated => marker = Some(next),
+                _ => break,
+            }
+        }
+
+        let total_count = u32::try_from(zones.len()).unwrap_or(u32::MAX);
+        let domains = zones
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .map(Self::convert_zone)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let path = format!("/hostedzone/{}", encode_path_segment(domain_id));
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let response: GetHostedZoneResponse = self.get(&path, "", ctx).await?;
+        Ok(Self::convert_zone(response.hosted_zone))
+    }
+
+    /// Route53 不支持服务端搜索与按类型过滤，取全部记录集后在本地过滤与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let sets = self.list_all_record_sets(domain_id).await?;
+
+        let keyword = params
+            .keyword
+            .as_deref()
+            .filter(|k| !k.is_empty())
+            .map(str::to_lowercase);
+        let records: Vec<DnsRecord> = sets
+            .iter()
+            .flat_map(|set| Self::expand_record_set(set, domain_id, &domain_info.name))
+            .filter(|r| {
+                params
+                    .record_type
+                    .as_ref()
+                    .is_none_or(|record_type| r.data.record_type() == *record_type)
+            })
+            .filter(|r| {
+                keyword
+                    .as_ref()
+                    .is_none_or(|keyword| record_name_matches(r, keyword))
+            })
+            .collect();
+
+        let total_count = u32::try_from(records.len()).unwrap_or(u32::MAX);
+        let records = records
+            .into_iter()
+            .skip(((params.page.max(1) - 1) * params.page_size) as usize)
+            .take(params.page_size as usize)
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            records,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name);
+        let record_type = req.data.record_type();
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        let existing = self
+            .find_record_set(&req.domain_id, &format!("{full_name}."), &record_type)
+            .await?;
+        let change = Self::add_value_change(
+            existing.as_ref(),
+            &full_name,
+            &record_type,
+            req.ttl,
+            value.clone(),
+            &ctx,
+        )?;
+        self.change_record_sets(&req.domain_id, &[change], ctx)
+            .await?;
+
+        let now = chrono::Utc::now();
+        Ok(DnsRecord {
+            id: RecordId::new(&full_name, record_type, &value).to_string(),
+            domain_id: req.domain_id.clone(),
+            name: req.name.clone(),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            created_at: Some(now),
+            updated_at: Some(now),
+        })
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let old = RecordId::parse(record_id)?;
+        let domain_info = self.get_domain(&req.domain_id).await?;
+        let full_name = relative_to_full_name(&req.name, &domain_info.name);
+        let record_type = req.data.record_type();
+        let value = Self::record_data_to_value(&req.data);
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let old_set = self
+            .find_record_set(&req.domain_id, &format!("{}.", old.name), &old.record_type)
+            .await?
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+        let index = Self::position_of(&old_set, &old.record_type, &old.digest)
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+        let mut old_values: Vec<String> = old_set
+            .values()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let changes = if old.name.eq_ignore_ascii_case(&full_name) && old.record_type == record_type
+        {
+            // 同一记录集内替换该值（新值与组内其他值重复时合并）
+            old_values[index] = value.clone();
+            let mut seen = Vec::new();
+            old_values.retain(|v| {
+                let digest = Self::value_digest(&record_type, v);
+                let keep = !seen.contains(&digest);
+                seen.push(digest);
+                keep
+            });
+            vec![Self::rewrite_change(
+                &old_set,
+                &record_type,
+                req.ttl,
+                old_values,
+            )]
+        } else {
+            // 名称或类型变化：从原记录集移除、加入新记录集，同一批次原子提交
+            old_values.remove(index);
+            let removal = Self::rewrite_change(
+                &old_set,
+                &old.record_type,
+                old_set.ttl.unwrap_or(req.ttl),
+                old_values,
+            );
+            let target = self
+                .find_record_set(&req.domain_id, &format!("{full_name}."), &record_type)
+                .await?;
+            let addition = Self::add_value_change(
+                target.as_ref(),
+                &full_name,
+                &record_type,
+                req.ttl,
+                value.clone(),
+                &ctx,
+            )?;
+            vec![removal, addition]
+        };
+        self.change_record_sets(&req.domain_id, &changes, ctx)
+            .await?;
+
+        Ok(DnsRecord {
+            id: RecordId::new(&full_name, record_type, &value).to_string(),
+            domain_id: req.domain_id.clone(),
+            name: req.name.clone(),
+            ttl: req.ttl,
+            data: req.data.clone(),
+            proxied: None,
+            created_at: None,
+            updated_at: Some(chrono::Utc::now()),
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let id = RecordId::parse(record_id)?;
+        let set = self
+            .find_record_set(domain_id, &format!("{}.", id.name), &id.record_type)
+            .await?
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+        let index = Self::position_of(&set, &id.record_type, &id.digest)
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+
+        let mut values: Vec<String> = set.values().into_iter().map(ToString::to_string).collect();
+        values.remove(index);
+        let change = Self::rewrite_change(&set, &id.record_type, set.ttl.unwrap_or(300), values);
+
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.change_record_sets(domain_id, &[change], ctx).await
+    }
+
+    async fn get_record_set(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<Option<RecordSet>> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(name, &domain_info.name));
+
+        let Some(record_set) = self
+            .find_record_set(domain_id, &full_name, record_type)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let values = record_set
+            .values()
+            .into_iter()
+            .map(|value| Self::parse_record_data(&record_set.record_type, value))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(RecordSet {
+            id: None,
+            name: name.to_string(),
+            record_type: record_type.clone(),
+            ttl: record_set.ttl.unwrap_or(300),
+            values,
+        }))
+    }
+
+    async fn replace_record_set(&self, domain_id: &str, set: &RecordSet) -> Result<()> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(&set.name, &domain_info.name));
+        let existing = self
+            .find_record_set(domain_id, &full_name, &set.record_type)
+            .await?;
+
+        let ctx = ErrorContext {
+            record_name: Some(set.name.clone()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let values: Vec<String> = set.values.iter().map(Self::record_data_to_value).collect();
+        let change = match existing {
+            Some(record_set) => {
+                Self::rewrite_change(&record_set, &set.record_type, set.ttl, values)
+            }
+            None if values.is_empty() => return Ok(()),
+            None => Change {
+                action: ChangeAction::Upsert,
+                name: full_name,
+                record_type: record_type_to_string(&set.record_type),
+                ttl: set.ttl,
+                values,
+            },
+        };
+        self.change_record_sets(domain_id, &[change], ctx).await
+    }
+
+    /// 同一记录集的多条变更合并为一条，整批在一次 `ChangeResourceRecordSets` 中原子提交
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        if changes.is_empty() {
+            return Vec::new();
+        }
+        match self.submit_batch(domain_id, &changes).await {
+            Ok(results) => results,
+            Err(e) => fallback_after_batch_error(self, domain_id, changes, e).await,
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str, field: &str) -> Result<T> {
+    value.parse().map_err(|_| ProviderError::ParseError {
+        provider: "route53".to_string(),
+        detail: format!("Invalid {field}: '{value}'"),
+    })
+}
+
+/// 还原 Route53 的转义：`\ddd`（三位八进制）与 `\x`（字面字符）
+fn decode_escapes(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 1 < bytes.len() {
+            let digits = &bytes[i + 1..bytes.len().min(i + 4)];
+            if let Some(code) = std::str::from_utf8(digits)
+                .ok()
+                .filter(|d| d.len() == 3 && d.bytes().all(|b| (b'0'..=b'7').contains(&b)))
+                .and_then(|d| u8::from_str_radix(d, 8).ok())
+            {
+                decoded.push(code);
+                i += 4;
+            } else {
+                decoded.push(bytes[i + 1]);
+                i += 2;
+            }
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::route53::types::change_batch_xml;
+
+    const LIST_RECORD_SETS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListResourceRecordSetsResponse xmlns="https://route53.amazonaws.com/doc/2013-04-01/">
+  <ResourceRecordSets>
+    <ResourceRecordSet>
+      <Name>example.com.</Name>
+      <Type>MX</Type>
+      <TTL>3600</TTL>
+      <ResourceRecords>
+        <ResourceRecord><Value>10 mail1.example.com.</Value></ResourceRecord>
+        <ResourceRecord><Value>20 mail2.example.com.</Value></ResourceRecord>
+      </ResourceRecords>
+    </ResourceRecordSet>
+    <ResourceRecordSet>
+      <Name>example.com.</Name>
+      <Type>TXT</Type>
+      <TTL>300</TTL>
+      <ResourceRecords>
+        <ResourceRecord><Value>"v=spf1 include:_spf.example.net " "~all"</Value></ResourceRecord>
+      </ResourceRecords>
+    </ResourceRecordSet>
+    <ResourceRecordSet>
+      <Name>\052.example.com.</Name>
+      <Type>A</Type>
+      <TTL>60</TTL>
+      <ResourceRecords>
+        <ResourceRecord><Value>192.0.2.10</Value></ResourceRecord>
+      </ResourceRecords>
+    </ResourceRecordSet>
+    <ResourceRecordSet>
+      <Name>cdn.example.com.</Name>
+      <Type>A</Type>
+      <AliasTarget>
+        <HostedZoneId>Z2FDTNDATAQYW2</HostedZoneId>
+        <DNSName>d111111abcdef8.cloudfront.net.</DNSName>
+        <EvaluateTargetHealth>false</EvaluateTargetHealth>
+      </AliasTarget>
+    </ResourceRecordSet>
+    <ResourceRecordSet>
+      <Name>www.example.com.</Name>
+      <Type>A</Type>
+      <SetIdentifier>blue</SetIdentifier>
+      <Weight>50</Weight>
+      <TTL>60</TTL>
+      <ResourceRecords>
+        <ResourceRecord><Value>192.0.2.20</Value></ResourceRecord>
+      </ResourceRecords>
+    </ResourceRecordSet>
+  </ResourceRecordSets>
+  <IsTruncated>true</IsTruncated>
+  <NextRecordName>www.example.com.</NextRecordName>
+  <NextRecordType>A</NextRecordType>
+  <NextRecordIdentifier>green</NextRecordIdentifier>
+  <MaxItems>5</MaxItems>
+</ListResourceRecordSetsResponse>"#;
+
+    #[test]
+    fn expands_simple_record_sets() {
+        let response: ListResourceRecordSetsResponse =
+            quick_xml::de::from_str(LIST_RECORD_SETS).unwrap();
+        assert!(response.is_truncated);
+        assert_eq!(response.next_record_identifier.as_deref(), Some("green"));
+
+        let records: Vec<DnsRecord> = response
+            .resource_record_sets
+            .items
+            .iter()
+            .flat_map(|set| Route53Provider::expand_record_set(set, "Z1", "example.com"))
+            .collect();
+        let summary: Vec<(&str, String, u32)> = records
+            .iter()
+            .map(|r| (r.name.as_str(), r.data.display_value(), r.ttl))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("@", "mail1.example.com.".to_string(), 3600),
+                ("@", "mail2.example.com.".to_string(), 3600),
+                ("@", "v=spf1 include:_spf.example.net ~all".to_string(), 300),
+                ("*", "192.0.2.10".to_string(), 60),
+            ]
+        );
+
+        // 合成 ID 可还原出记录集位置
+        let id = RecordId::parse(&records[1].id).unwrap();
+        assert_eq!(id.name, "example.com");
+        assert_eq!(id.record_type, DnsRecordType::Mx);
+        assert_eq!(
+            id.digest,
+            Route53Provider::value_digest(&DnsRecordType::Mx, "20 mail2.example.com.")
+        );
+        assert_eq!(id.to_string(), records[1].id);
+        assert!(RecordId::parse("example.com|A").is_err());
+    }
+
+    #[test]
+    fn plans_batch_per_record_set() {
+        let response: ListResourceRecordSetsResponse =
+            quick_xml::de::from_str(LIST_RECORD_SETS).unwrap();
+        let records: Vec<DnsRecord> = response
+            .resource_record_sets
+            .items
+            .iter()
+            .flat_map(|set| Route53Provider::expand_record_set(set, "Z1", "example.com"))
+            .collect();
+        let mx = |priority: u16, exchange: &str| RecordData::MX {
+            priority,
+            exchange: exchange.to_string(),
+        };
+        let create = |name: &str, data: RecordData| {
+            RecordChange::Create(CreateDnsRecordRequest {
+                domain_id: "Z1".to_string(),
+                name: name.to_string(),
+                ttl: 3600,
+                data,
+                proxied: None,
+            })
+        };
+
+        let changes = vec![
+            create("@", mx(30, "mail3.example.com.")),
+            // 与现有值重复
+            create("@", mx(10, "mail1.example.com.")),
+            // 删除后记录集为空
+            RecordChange::Delete {
+                record_id: records[3].id.clone(),
+            },
+            RecordChange::Update {
+                record_id: records[2].id.clone(),
+                request: UpdateDnsRecordRequest {
+                    domain_id: "Z1".to_string(),
+                    name: "@".to_string(),
+                    ttl: 600,
+                    data: RecordData::TXT {
+                        text: "v=spf1 -all".to_string(),
+                    },
+                    proxied: None,
+                },
+            },
+            RecordChange::Delete {
+                record_id: "missing.example.com|A|0000000000000000".to_string(),
+            },
+        ];
+        let (results, batch) = Route53Provider::plan_batch(
+            response.resource_record_sets.items,
+            "Z1",
+            "example.com",
+            &changes,
+        );
+
+        let outcomes: Vec<&str> = results
+            .iter()
+            .map(|result| match result {
+                ChangeResult::Applied(_) => "applied",
+                ChangeResult::Deleted => "deleted",
+                ChangeResult::Failed(ProviderError::RecordExists { .. }) => "exists",
+                ChangeResult::Failed(ProviderError::RecordNotFound { .. }) => "not_found",
+                ChangeResult::Failed(_) => "failed",
+            })
+            .collect();
+        assert_eq!(
+            outcomes,
+            ["applied", "exists", "deleted", "applied", "not_found"]
+        );
+
+        // 每个记录集至多一条变更，DELETE 带原 TTL 与值
+        let summary: Vec<(ChangeAction, &str, &str, u32, Vec<&str>)> = batch
+            .iter()
+            .map(|c| {
+                (
+                    c.action,
+                    c.name.as_str(),
+                    c.record_type,
+                    c.ttl,
+                    c.values.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    ChangeAction::Delete,
+                    r"\052.example.com.",
+                    "A",
+                    60,
+                    vec!["192.0.2.10"]
+                ),
+                (
+                    ChangeAction::Upsert,
+                    "example.com.",
+                    "MX",
+                    3600,
+                    vec![
+                        "10 mail1.example.com.",
+                        "20 mail2.example.com.",
+                        "30 mail3.example.com."
+                    ]
+                ),
+                (
+                    ChangeAction::Upsert,
+                    "example.com.",
+                    "TXT",
+                    600,
+                    vec![r#""v=spf1 -all""#]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn quotes_and_unquotes_txt_values() {
+        assert_eq!(
+            txt::quote(r#"say "hi" \o/"#, EscapeRadix::Octal),
+            r#""say \"hi\" \\o/""#
+        );
+        assert_eq!(
+            txt::unquote(r#""say \"hi\" \\o/""#, EscapeRadix::Octal),
+            r#"say "hi" \o/"#
+        );
+        assert_eq!(txt::quote("é", EscapeRadix::Octal), r#""\303\251""#);
+        assert_eq!(txt::unquote(r#""\303\251""#, EscapeRadix::Octal), "é");
+        assert_eq!(txt::unquote("unquoted", EscapeRadix::Octal), "unquoted");
+
+        // 超过 255 字节时分段，拼接后还原
+        let long = "a".repeat(300);
+        let quoted = txt::quote(&long, EscapeRadix::Octal);
+        assert!(quoted.starts_with(&format!("\"{}\" \"", "a".repeat(255))));
+        assert_eq!(txt::unquote(&quoted, EscapeRadix::Octal), long);
+
+        // 分段方式不同的同一值摘要相同
+        assert_eq!(
+            Route53Provider::value_digest(&DnsRecordType::Txt, r#""ab" "c""#),
+            Route53Provider::value_digest(&DnsRecordType::Txt, r#""abc""#)
+        );
+    }
+
+    #[test]
+    fn builds_escaped_change_batch() {
+        let xml = change_batch_xml(&[Change {
+            action: ChangeAction::Upsert,
+            name: "example.com.".to_string(),
+            record_type: "TXT",
+            ttl: 300,
+            values: vec![txt::quote("a<b&c", EscapeRadix::Octal)],
+        }]);
+        assert!(xml.contains(
+            "<Change><Action>UPSERT</Action><ResourceRecordSet><Name>example.com.</Name>\
+             <Type>TXT</Type><TTL>300</TTL><ResourceRecords><ResourceRecord>\
+             <Value>&quot;a&lt;b&amp;c&quot;</Value></ResourceRecord></ResourceRecords>"
+        ));
+    }
+
+    #[test]
+    fn selects_partition_endpoints() {
+        use crate::providers::route53::Partition;
+
+        let china = Partition::from_region("cn-north-1");
+        assert_eq!(china.route53_host(), "route53.amazonaws.com.cn");
+        assert_eq!(
+            china.sts_host("cn-north-1"),
+            "sts.cn-north-1.amazonaws.com.cn"
+        );
+        assert_eq!(
+            Partition::from_region("us-gov-east-1").route53_signing_region(),
+            "us-gov-west-1"
+        );
+        assert_eq!(
+            Partition::from_region("eu-west-1").route53_signing_region(),
+            "us-east-1"
+        );
+    }
+}
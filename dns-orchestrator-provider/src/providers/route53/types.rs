@@ -0,0 +1,255 @@
+//! AWS Route53 / STS API 类型定义
+//!
+//! 两者的响应均为 XML，按元素名反序列化；请求体（变更批次）手工拼接。
+
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+
+use super::ROUTE53_API_VERSION;
+
+// ============ Route53 API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListHostedZonesResponse {
+    #[serde(default)]
+    pub hosted_zones: HostedZones,
+    #[serde(default)]
+    pub is_truncated: bool,
+    pub next_marker: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HostedZones {
+    #[serde(rename = "HostedZone", default)]
+    pub items: Vec<HostedZone>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostedZone {
+    /// 形如 `/hostedzone/Z0123456789ABC`
+    pub id: String,
+    pub name: String,
+    pub resource_record_set_count: Option<u32>,
+    pub config: Option<HostedZoneConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostedZoneConfig {
+    #[serde(default)]
+    pub private_zone: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetHostedZoneResponse {
+    pub hosted_zone: HostedZone,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListResourceRecordSetsResponse {
+    #[serde(default)]
+    pub resource_record_sets: ResourceRecordSets,
+    #[serde(default)]
+    pub is_truncated: bool,
+    pub next_record_name: Option<String>,
+    pub next_record_type: Option<String>,
+    pub next_record_identifier: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ResourceRecordSets {
+    #[serde(rename = "ResourceRecordSet", default)]
+    pub items: Vec<ResourceRecordSet>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRecordSet {
+    /// 完整名称（末尾带点，`*` 等字符以 `\052` 八进制转义）
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    #[serde(rename = "TTL")]
+    pub ttl: Option<u32>,
+    pub resource_records: Option<ResourceRecords>,
+    /// 别名记录（指向 AWS 资源，没有 TTL 与记录值）
+    pub alias_target: Option<IgnoredAny>,
+    /// 加权、延迟、地理位置等路由策略记录的标识
+    pub set_identifier: Option<String>,
+}
+
+impl ResourceRecordSet {
+    /// 是否为普通记录集（非别名、无路由策略）
+    pub fn is_simple(&self) -> bool {
+        self.alias_target.is_none() && self.set_identifier.is_none()
+    }
+
+    pub fn values(&self) -> Vec<&str> {
+        self.resource_records
+            .as_ref()
+            .map(|records| records.items.iter().map(|r| r.value.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ResourceRecords {
+    #[serde(rename = "ResourceRecord", default)]
+    pub items: Vec<ResourceRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRecord {
+    pub value: String,
+}
+
+/// `ChangeResourceRecordSets` 与 `GetChange` 的响应结构相同
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChangeInfoResponse {
+    pub change_info: ChangeInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ChangeInfo {
+    /// 形如 `/change/C0123456789ABC`
+    pub id: String,
+    /// `PENDING` 或 `INSYNC`
+    pub status: String,
+}
+
+/// 通用错误响应（Route53 与 STS 相同）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorResponse {
+    pub error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorDetail {
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+/// 变更批次校验失败（`InvalidChangeBatch`）的响应
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InvalidChangeBatchResponse {
+    #[serde(default)]
+    pub messages: Messages,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Messages {
+    #[serde(rename = "Message", default)]
+    pub items: Vec<String>,
+}
+
+// ============ STS API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AssumeRoleResponse {
+    pub assume_role_result: AssumeRoleResult,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AssumeRoleResult {
+    pub credentials: StsCredentials,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: String,
+    /// RFC 3339 时间
+    pub expiration: String,
+}
+
+// ============ 变更批次请求 ============
+
+/// 变更动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeAction {
+    Upsert,
+    Delete,
+}
+
+impl ChangeAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Upsert => "UPSERT",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+/// 单个记录集变更（`DELETE` 须与现有记录集的 TTL 与值完全一致）
+#[derive(Debug, Clone)]
+pub struct Change {
+    pub action: ChangeAction,
+    /// 完整名称（末尾带点）
+    pub name: String,
+    pub record_type: &'static str,
+    pub ttl: u32,
+    /// Route53 格式的记录值
+    pub values: Vec<String>,
+}
+
+/// 拼接 `ChangeResourceRecordSets` 请求体
+pub fn change_batch_xml(changes: &[Change]) -> String {
+    let changes: String = changes
+        .iter()
+        .map(|change| {
+            let records: String = change
+                .values
+                .iter()
+                .map(|value| {
+                    format!(
+                        "<ResourceRecord><Value>{}</Value></ResourceRecord>",
+                        xml_escape(value)
+                    )
+                })
+                .collect();
+            format!(
+                "<Change><Action>{}</Action><ResourceRecordSet><Name>{}</Name><Type>{}</Type>\
+                 <TTL>{}</TTL><ResourceRecords>{records}</ResourceRecords></ResourceRecordSet></Change>",
+                change.action.as_str(),
+                xml_escape(&change.name),
+                change.record_type,
+                change.ttl,
+            )
+        })
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+         <ChangeResourceRecordSetsRequest xmlns=\"https://route53.amazonaws.com/doc/{ROUTE53_API_VERSION}/\">\
+         <ChangeBatch><Changes>{changes}</Changes></ChangeBatch></ChangeResourceRecordSetsRequest>"
+    )
+}
+
+/// 转义 XML 文本中的特殊字符
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
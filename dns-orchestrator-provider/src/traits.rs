@@ -2,9 +2,9 @@ use async_trait::async_trait;
 
 use crate::error::{ProviderError, Result};
 use crate::types::{
-    BatchCreateResult, BatchDeleteResult, BatchUpdateItem, BatchUpdateResult,
-    CreateDnsRecordRequest, DnsRecord, PaginatedResponse, PaginationParams, ProviderDomain,
-    ProviderMetadata, RecordQueryParams, UpdateDnsRecordRequest,
+    CapabilityProbe, ChangeResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType,
+    PaginatedResponse, PaginationParams, ProviderDomain, ProviderMetadata, RecordChange,
+    RecordQueryParams, RecordSet, UpdateDnsRecordRequest,
 };
 
 /// 原始 API 错误（内部使用）
@@ -96,6 +96,21 @@ pub trait DnsProvider: Send + Sync {
     /// 验证凭证是否有效
     async fn validate_credentials(&self) -> Result<bool>;
 
+    /// 查询凭证（API token）的到期时间
+    ///
+    /// 默认返回 `None`（不支持查询或凭证不过期），能通过 API 查询 token 有效期的 Provider 需覆盖此方法。
+    async fn credentials_expires_at(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        Ok(None)
+    }
+
+    /// 运行时探测账户的实际能力
+    ///
+    /// 读取套餐信息等轻量操作，修正 `metadata()` 中按免费套餐填写的静态声明。
+    /// 默认返回空的修正（沿用静态声明），能通过 API 查询套餐的 Provider 需覆盖此方法。
+    async fn probe_capabilities(&self) -> Result<CapabilityProbe> {
+        Ok(CapabilityProbe::default())
+    }
+
     /// 获取域名列表 (分页)
     async fn list_domains(
         &self,
@@ -125,63 +140,122 @@ pub trait DnsProvider: Send + Sync {
     /// 删除 DNS 记录
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()>;
 
-    /// 批量创建 DNS 记录
+    /// 开启/关闭子域名的权重解析
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
-    ///
-    /// # TODO - 实现计划
-    /// - [ ] Cloudflare: 使用 `POST /zones/{zone_id}/dns_records/batch` API
-    ///       文档: https://developers.cloudflare.com/api/resources/dns/subresources/records/methods/batch/
-    ///       预期性能: 10,000 条记录快 850 倍
-    /// - [ ] DNSPod: 使用 `CreateRecordBatch` API（异步任务）
-    ///       文档: https://cloud.tencent.com/document/product/1427/56194
-    /// - [ ] Aliyun: 调研批量 API 支持
-    ///       文档: https://help.aliyun.com/zh/dns/pubz-batch-operation/
-    /// - [ ] Huaweicloud: 调研批量 API 支持
-    ///
-    /// # 未来优化
-    /// 默认实现可使用客户端并行循环 `join_all(create_record())`
-    async fn batch_create_records(
+    /// `subdomain` 为完整域名（如 `www.example.com`）。
+    /// 默认返回 `UnsupportedCapability`，支持权重解析的 Provider 需覆盖此方法，
+    /// 并在元数据中声明 `features.weighted_records`。
+    async fn set_weighted_routing(
         &self,
-        _requests: &[CreateDnsRecordRequest],
-    ) -> Result<BatchCreateResult> {
-        unimplemented!("批量创建 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+        _domain_id: &str,
+        _subdomain: &str,
+        _enabled: bool,
+    ) -> Result<()> {
+        Err(ProviderError::UnsupportedCapability {
+            provider: self.id().to_string(),
+            capability: "weighted_records".to_string(),
+        })
     }
 
-    /// 批量更新 DNS 记录
+    /// 设置单条记录的权重（1-100）
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
+    /// 默认返回 `UnsupportedCapability`。
+    async fn set_record_weight(&self, _record_id: &str, _weight: u8) -> Result<()> {
+        Err(ProviderError::UnsupportedCapability {
+            provider: self.id().to_string(),
+            capability: "weighted_records".to_string(),
+        })
+    }
+
+    /// 读取同名同类型的整组记录（`name` 为相对名称），不存在时返回 `None`
     ///
-    /// # TODO - 实现计划
-    /// - [ ] Cloudflare: 使用批量 API
-    /// - [ ] DNSPod: 使用 `ModifyRecordBatch` API
-    /// - [ ] Aliyun: 调研批量 API 支持
-    /// - [ ] Huaweicloud: 使用 `BatchUpdateRecordSetWithLine` API
-    ///       文档: https://support.huaweicloud.com/api-dns/BatchUpdateRecordSetWithLine.html
-    async fn batch_update_records(
+    /// 默认返回 `UnsupportedCapability`，原生使用 record set 模型的 Provider 需覆盖此方法
+    /// 与 [`Self::replace_record_set`]，并在元数据中声明 `features.record_sets`。
+    async fn get_record_set(
         &self,
-        _updates: &[BatchUpdateItem],
-    ) -> Result<BatchUpdateResult> {
-        unimplemented!("批量更新 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+        _domain_id: &str,
+        _name: &str,
+        _record_type: &DnsRecordType,
+    ) -> Result<Option<RecordSet>> {
+        Err(ProviderError::UnsupportedCapability {
+            provider: self.id().to_string(),
+            capability: "record_sets".to_string(),
+        })
     }
 
-    /// 批量删除 DNS 记录
+    /// 一次调用整组替换同名同类型记录的值与 TTL，`values` 为空时删除整组
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
+    /// 默认返回 `UnsupportedCapability`。
+    async fn replace_record_set(&self, _domain_id: &str, _set: &RecordSet) -> Result<()> {
+        Err(ProviderError::UnsupportedCapability {
+            provider: self.id().to_string(),
+            capability: "record_sets".to_string(),
+        })
+    }
+
+    /// 批量提交同一域名下的记录变更，结果与 `changes` 按顺序一一对应
     ///
-    /// # TODO - 实现计划
-    /// - [ ] Cloudflare: 使用批量 API
-    /// - [ ] DNSPod: 使用批量删除 API
-    /// - [ ] Aliyun: 调研批量 API 支持
-    /// - [ ] Huaweicloud: 调研批量 API 支持
-    async fn batch_delete_records(
-        &self,
-        _domain_id: &str,
-        _record_ids: &[String],
-    ) -> Result<BatchDeleteResult> {
-        unimplemented!("批量删除 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+    /// 每条变更独立成败：一条失败不影响同批其他变更的执行与结果。同一批内的变更
+    /// 不应相互依赖执行顺序（原生批量接口可能重排，如先删除后创建）。
+    ///
+    /// 默认实现逐条调用单条接口。声明 `features.batch_changes` 的 Provider 覆盖此方法
+    /// 一次提交整批，单批最多 `limits.max_batch_changes` 条（超出由调用方分批）。
+    /// 原生接口整批原子失败时：记录级错误（记录已存在/不存在、参数无效）改为逐条提交，
+    /// 其余变更照常生效；认证、配额、网络等错误作为每条的结果返回。
+    async fn batch_change(&self, domain_id: &str, changes: Vec<RecordChange>) -> Vec<ChangeResult> {
+        apply_changes_one_by_one(self, domain_id, changes).await
+    }
+}
+
+/// 逐条提交记录变更（[`DnsProvider::batch_change`] 的默认实现）
+pub(crate) async fn apply_changes_one_by_one<P: DnsProvider + ?Sized>(
+    provider: &P,
+    domain_id: &str,
+    changes: Vec<RecordChange>,
+) -> Vec<ChangeResult> {
+    let mut results = Vec::with_capacity(changes.len());
+    for change in changes {
+        let result = match change {
+            RecordChange::Create(request) => provider.create_record(&request).await.into(),
+            RecordChange::Update { record_id, request } => {
+                provider.update_record(&record_id, &request).await.into()
+            }
+            RecordChange::Delete { record_id } => {
+                provider.delete_record(&record_id, domain_id).await.into()
+            }
+        };
+        results.push(result);
+    }
+    results
+}
+
+/// 原生批量接口整批失败后得到逐条结果
+///
+/// 原生批量接口整批原子生效，失败时没有任何变更生效：
+/// - 记录级错误（记录已存在/不存在、参数或类型无效）只说明其中某条有问题，
+///   改为逐条提交，其余变更照常生效；
+/// - 其他错误（认证、权限、配额、网络等）逐条提交同样会失败，直接作为每条的结果。
+pub(crate) async fn fallback_after_batch_error<P: DnsProvider + ?Sized>(
+    provider: &P,
+    domain_id: &str,
+    changes: Vec<RecordChange>,
+    error: ProviderError,
+) -> Vec<ChangeResult> {
+    match error {
+        ProviderError::RecordExists { .. }
+        | ProviderError::RecordNotFound { .. }
+        | ProviderError::InvalidParameter { .. }
+        | ProviderError::UnsupportedRecordType { .. } => {
+            log::warn!(
+                "[{}] Batch of {} changes rejected, retrying one by one: {error}",
+                provider.id(),
+                changes.len()
+            );
+            apply_changes_one_by_one(provider, domain_id, changes).await
+        }
+        error => changes
+            .iter()
+            .map(|_| ChangeResult::Failed(error.clone()))
+            .collect(),
     }
 }
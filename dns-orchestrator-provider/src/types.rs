@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::ProviderError;
+
 // ============ 分页相关类型 ============
 
 /// 分页参数
@@ -25,12 +27,15 @@ impl Default for PaginationParams {
 pub struct RecordQueryParams {
     pub page: u32,
     pub page_size: u32,
-    /// 搜索关键词（匹配记录名称或值）
+    /// 搜索关键词（按字面量、不区分大小写匹配记录名称）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub keyword: Option<String>,
     /// 记录类型过滤
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_type: Option<DnsRecordType>,
+    /// 排序方式（仅 `features.server_side_sort` 的服务商使用，其他服务商忽略）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<RecordSort>,
 }
 
 impl Default for RecordQueryParams {
@@ -40,10 +45,40 @@ impl Default for RecordQueryParams {
             page_size: 20,
             keyword: None,
             record_type: None,
+            sort: None,
         }
     }
 }
 
+/// 记录排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordSortField {
+    Name,
+    Type,
+    /// 记录的主要值（见 `RecordData::display_value`）
+    Value,
+    Ttl,
+}
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// 记录排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSort {
+    pub field: RecordSortField,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
 impl RecordQueryParams {
     /// 转换为基础分页参数
     pub fn to_pagination(&self) -> PaginationParams {
@@ -81,7 +116,7 @@ impl<T> PaginatedResponse<T> {
 // ============ Provider 相关类型 ============
 
 /// Provider 类型枚举（原名 DnsProvider，重命名避免与 trait 冲突）
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum ProviderType {
     #[cfg(feature = "cloudflare")]
@@ -92,6 +127,15 @@ pub enum ProviderType {
     Dnspod,
     #[cfg(feature = "huaweicloud")]
     Huaweicloud,
+    #[cfg(feature = "route53")]
+    #[serde(rename = "route53")]
+    AwsRoute53,
+    #[cfg(feature = "gcp-dns")]
+    #[serde(rename = "gcp_dns")]
+    GcpDns,
+    #[cfg(feature = "digitalocean")]
+    #[serde(rename = "digitalocean")]
+    DigitalOcean,
 }
 
 impl std::fmt::Display for ProviderType {
@@ -105,20 +149,70 @@ impl std::fmt::Display for ProviderType {
             Self::Dnspod => write!(f, "dnspod"),
             #[cfg(feature = "huaweicloud")]
             Self::Huaweicloud => write!(f, "huaweicloud"),
+            #[cfg(feature = "route53")]
+            Self::AwsRoute53 => write!(f, "route53"),
+            #[cfg(feature = "gcp-dns")]
+            Self::GcpDns => write!(f, "gcp_dns"),
+            #[cfg(feature = "digitalocean")]
+            Self::DigitalOcean => write!(f, "digitalocean"),
         }
     }
 }
 
 // ============ 域名相关类型 ============
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+/// 域名状态（各 provider 原始状态归一化后的结果）
+///
+/// 序列化为小写字符串；`Unknown` 携带无法识别的原始值，但序列化时统一为 `"unknown"`，
+/// 原始值通过 `ProviderDomain::provider_status_raw` 透传。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum DomainStatus {
+    /// 正常解析中
     Active,
-    Paused,
+    /// 尚未生效（如 NS 未切换、创建中）
     Pending,
-    Error,
-    Unknown,
+    /// 被暂停、锁定或封禁
+    Suspended,
+    /// 域名或服务已过期
+    Expired,
+    /// 已迁出当前服务商
+    Moved,
+    /// 无法识别的状态（原始值）
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// 状态标识（与序列化值一致）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Pending => "pending",
+            Self::Suspended => "suspended",
+            Self::Expired => "expired",
+            Self::Moved => "moved",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+}
+
+impl From<DomainStatus> for String {
+    fn from(status: DomainStatus) -> Self {
+        status.as_str().to_string()
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "active" => Self::Active,
+            "pending" => Self::Pending,
+            "suspended" => Self::Suspended,
+            "expired" => Self::Expired,
+            "moved" => Self::Moved,
+            _ => Self::Unknown(value),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -127,6 +221,13 @@ pub struct ProviderDomain {
     pub name: String,
     pub provider: ProviderType,
     pub status: DomainStatus,
+    /// Provider 返回的原始状态字符串
+    #[serde(
+        rename = "providerStatusRaw",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub provider_status_raw: Option<String>,
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
 }
@@ -145,8 +246,21 @@ pub enum DnsRecordType {
     Ns,
     Srv,
     Caa,
+    Soa,
 }
 
+/// 各服务商均可创建的记录类型（SOA 只能修改，见 `ProviderFeatures::soa_editing`）
+pub const STANDARD_RECORD_TYPES: [DnsRecordType; 8] = [
+    DnsRecordType::A,
+    DnsRecordType::Aaaa,
+    DnsRecordType::Cname,
+    DnsRecordType::Mx,
+    DnsRecordType::Txt,
+    DnsRecordType::Ns,
+    DnsRecordType::Srv,
+    DnsRecordType::Caa,
+];
+
 /// DNS 记录数据 - 类型安全的多态表示
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "content")]
@@ -183,6 +297,18 @@ pub enum RecordData {
         tag: String,
         value: String,
     },
+
+    /// SOA 记录：由服务商创建，只有声明 `features.soa_editing` 的服务商允许修改
+    /// 计时参数与 rname，serial 始终由服务商维护
+    SOA {
+        mname: String,
+        rname: String,
+        serial: u32,
+        refresh: u32,
+        retry: u32,
+        expire: u32,
+        minimum: u32,
+    },
 }
 
 impl RecordData {
@@ -197,6 +323,7 @@ impl RecordData {
             Self::NS { .. } => DnsRecordType::Ns,
             Self::SRV { .. } => DnsRecordType::Srv,
             Self::CAA { .. } => DnsRecordType::Caa,
+            Self::SOA { .. } => DnsRecordType::Soa,
         }
     }
 
@@ -210,6 +337,15 @@ impl RecordData {
             Self::NS { nameserver } => nameserver.clone(),
             Self::SRV { target, .. } => target.clone(),
             Self::CAA { value, .. } => value.clone(),
+            Self::SOA {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => format!("{mname} {rname} {serial} {refresh} {retry} {expire} {minimum}"),
         }
     }
 }
@@ -227,11 +363,11 @@ pub struct DnsRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxied: Option<bool>,
 
-    #[serde(with = "crate::utils::datetime")]
+    #[serde(default, with = "crate::utils::datetime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 
-    #[serde(with = "crate::utils::datetime")]
+    #[serde(default, with = "crate::utils::datetime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -256,6 +392,19 @@ pub struct UpdateDnsRecordRequest {
     pub proxied: Option<bool>,
 }
 
+/// 同名同类型的一组记录（record set）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSet {
+    /// 服务商侧的记录组 ID（新建时为空）
+    pub id: Option<String>,
+    /// 相对名称（根域为 `@`）
+    pub name: String,
+    pub record_type: DnsRecordType,
+    pub ttl: u32,
+    pub values: Vec<RecordData>,
+}
+
 // ============ 批量操作类型 ============
 
 /// 批量创建结果
@@ -266,6 +415,9 @@ pub struct BatchCreateResult {
     pub failed_count: usize,
     pub created_records: Vec<DnsRecord>,
     pub failures: Vec<BatchCreateFailure>,
+    /// 提示信息（如规范化时做的字符替换）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// 批量创建失败项
@@ -275,6 +427,8 @@ pub struct BatchCreateFailure {
     pub request_index: usize,
     pub record_name: String,
     pub reason: String,
+    /// 失败的原始请求（供调用方选择性重试）
+    pub request: CreateDnsRecordRequest,
 }
 
 /// 批量更新结果
@@ -285,6 +439,9 @@ pub struct BatchUpdateResult {
     pub failed_count: usize,
     pub updated_records: Vec<DnsRecord>,
     pub failures: Vec<BatchUpdateFailure>,
+    /// 提示信息（如规范化时做的字符替换）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// 批量更新失败项
@@ -320,6 +477,53 @@ pub struct BatchDeleteFailure {
     pub reason: String,
 }
 
+/// 批量提交中的单条记录变更（见 [`crate::DnsProvider::batch_change`]）
+#[derive(Debug, Clone)]
+pub enum RecordChange {
+    Create(CreateDnsRecordRequest),
+    Update {
+        record_id: String,
+        request: UpdateDnsRecordRequest,
+    },
+    Delete {
+        record_id: String,
+    },
+}
+
+/// 单条记录变更的结果
+#[derive(Debug, Clone)]
+pub enum ChangeResult {
+    /// 创建或更新成功，附带变更后的记录
+    Applied(DnsRecord),
+    /// 删除成功
+    Deleted,
+    /// 该条变更失败
+    Failed(ProviderError),
+}
+
+impl ChangeResult {
+    /// 转换为 `Result`，删除成功时为 `Ok(None)`
+    pub fn into_result(self) -> Result<Option<DnsRecord>, ProviderError> {
+        match self {
+            Self::Applied(record) => Ok(Some(record)),
+            Self::Deleted => Ok(None),
+            Self::Failed(e) => Err(e),
+        }
+    }
+}
+
+impl From<Result<DnsRecord, ProviderError>> for ChangeResult {
+    fn from(result: Result<DnsRecord, ProviderError>) -> Self {
+        result.map_or_else(Self::Failed, Self::Applied)
+    }
+}
+
+impl From<Result<(), ProviderError>> for ChangeResult {
+    fn from(result: Result<(), ProviderError>) -> Self {
+        result.map_or_else(Self::Failed, |()| Self::Deleted)
+    }
+}
+
 // ============ Provider 元数据类型 ============
 
 /// 凭证字段类型
@@ -328,6 +532,18 @@ pub struct BatchDeleteFailure {
 pub enum FieldType {
     Text,
     Password,
+    /// 下拉选择（可选项见 `options`）
+    Select,
+    /// 多行文本（如 JSON 密钥文件内容）
+    Textarea,
+}
+
+/// 下拉选择项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldOption {
+    pub value: String,
+    pub label: String,
 }
 
 /// 提供商凭证字段定义
@@ -342,6 +558,12 @@ pub struct ProviderCredentialField {
     pub placeholder: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help_text: Option<String>,
+    /// 格式正则（与后端 `ProviderCredentials::precheck` 同源）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// 可选项（仅 `Select` 类型使用）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<FieldOption>>,
 }
 
 /// 提供商支持的功能
@@ -350,9 +572,41 @@ pub struct ProviderCredentialField {
 pub struct ProviderFeatures {
     /// 是否支持代理功能 (如 Cloudflare 的 CDN 代理)
     pub proxy: bool,
+    /// 是否支持权重解析（同名记录按权重分配流量）
+    #[serde(default)]
+    pub weighted_records: bool,
+    /// 是否支持自动 TTL（TTL 值为 1，如 Cloudflare）
+    #[serde(default)]
+    pub auto_ttl: bool,
+    /// 是否支持修改 SOA 的 refresh/retry/expire/minimum 与 rname（serial 由服务商维护）
+    #[serde(default)]
+    pub soa_editing: bool,
+    /// 是否原生使用 record set 模型（同名同类型的多个值属于同一个对象，可一次整组替换）
+    #[serde(default)]
+    pub record_sets: bool,
+    /// 是否原生支持一次提交多条记录变更（见 `DnsProvider::batch_change`）
+    #[serde(default)]
+    pub batch_changes: bool,
+    /// TXT 记录值提交给服务商的格式
+    #[serde(default)]
+    pub txt_format: TxtFormat,
+    /// 记录列表是否支持服务端排序（见 `RecordQueryParams::sort`）
+    #[serde(default)]
+    pub server_side_sort: bool,
+}
+
+/// TXT 记录值的提交格式（超过 255 字节的值如何分段，见 [`crate::txt`]）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum TxtFormat {
+    /// 提交完整文本，由服务商自行分段
+    #[default]
+    Raw,
+    /// 提交带引号的多段字符串（`"段1" "段2"`），每段不超过 255 字节
+    Quoted,
 }
 
-/// 提供商分页限制
+/// 提供商限制
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProviderLimits {
@@ -360,6 +614,16 @@ pub struct ProviderLimits {
     pub max_page_size_domains: u32,
     /// DNS 记录列表的最大分页大小
     pub max_page_size_records: u32,
+    /// 最小 TTL（秒，按免费套餐）
+    pub min_ttl: u32,
+    /// 最大 TTL（秒）
+    pub max_ttl: u32,
+    /// 原生批量接口单次最多提交的变更数（按免费套餐，不支持原生批量时为 0）
+    #[serde(default)]
+    pub max_batch_changes: u32,
+    /// TXT 记录值的最大总字节数（拼接各段后计算，未知时为 0）
+    #[serde(default)]
+    pub max_txt_length: u32,
 }
 
 /// 提供商元数据
@@ -370,12 +634,109 @@ pub struct ProviderMetadata {
     pub name: String,
     pub description: String,
     pub required_fields: Vec<ProviderCredentialField>,
+    /// 可选凭证字段（留空时使用默认值）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub optional_fields: Vec<ProviderCredentialField>,
+    /// 可创建的记录类型（不含 SOA，SOA 是否可修改见 `features.soa_editing`）
+    pub record_types: Vec<DnsRecordType>,
     pub features: ProviderFeatures,
     pub limits: ProviderLimits,
 }
 
+impl ProviderMetadata {
+    /// 按运行时探测结果修正 features 与 limits
+    pub fn apply_probe(&mut self, probe: &CapabilityProbe) {
+        if let Some(proxy) = probe.proxy {
+            self.features.proxy = proxy;
+        }
+        if let Some(weighted_records) = probe.weighted_records {
+            self.features.weighted_records = weighted_records;
+        }
+        if let Some(auto_ttl) = probe.auto_ttl {
+            self.features.auto_ttl = auto_ttl;
+        }
+        if let Some(min_ttl) = probe.min_ttl {
+            self.limits.min_ttl = min_ttl;
+        }
+        if let Some(max_ttl) = probe.max_ttl {
+            self.limits.max_ttl = max_ttl;
+        }
+        if let Some(max_batch_changes) = probe.max_batch_changes {
+            self.limits.max_batch_changes = max_batch_changes;
+        }
+    }
+}
+
+/// 运行时探测得到的账户能力修正
+///
+/// 静态声明（[`ProviderMetadata`]）按免费套餐填写，同一服务商不同套餐的能力可能不同。
+/// 字段为 `None` 表示未探测到，沿用静态声明。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CapabilityProbe {
+    /// 套餐名称（服务商返回的原始名称）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weighted_records: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_ttl: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_ttl: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_ttl: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_batch_changes: Option<u32>,
+}
+
 // ============ 凭证类型 ============
 
+/// 阿里云 API 站点变体
+#[cfg(feature = "aliyun")]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AliyunApiVariant {
+    /// 中国站
+    #[default]
+    Cn,
+    /// 国际站
+    Intl,
+}
+
+#[cfg(feature = "aliyun")]
+impl AliyunApiVariant {
+    pub const ALL: [Self; 2] = [Self::Cn, Self::Intl];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cn => "cn",
+            Self::Intl => "intl",
+        }
+    }
+
+    /// 前端展示名称
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Cn => "中国站",
+            Self::Intl => "国际站",
+        }
+    }
+}
+
+#[cfg(feature = "aliyun")]
+impl std::str::FromStr for AliyunApiVariant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|v| v.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Unknown API variant '{s}'"))
+    }
+}
+
 /// 凭证验证错误
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
@@ -426,6 +787,9 @@ pub enum ProviderCredentials {
     Aliyun {
         access_key_id: String,
         access_key_secret: String,
+        /// API 站点变体，缺省为中国站
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        api_variant: Option<AliyunApiVariant>,
     },
 
     #[cfg(feature = "dnspod")]
@@ -441,6 +805,33 @@ pub enum ProviderCredentials {
         access_key_id: String,
         secret_access_key: String,
     },
+
+    #[cfg(feature = "route53")]
+    #[serde(rename = "route53")]
+    AwsRoute53 {
+        access_key_id: String,
+        secret_access_key: String,
+        /// 区域，决定 AWS 分区（全球 / 中国 / GovCloud）与 STS 端点
+        region: String,
+        /// 要扮演的 IAM 角色，设置时先通过 STS `AssumeRole` 获取临时凭证
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        role_arn: Option<String>,
+    },
+
+    #[cfg(feature = "gcp-dns")]
+    #[serde(rename = "gcp_dns")]
+    GcpDns {
+        project_id: String,
+        /// 服务账号 JSON 密钥文件内容
+        service_account_json: String,
+    },
+
+    #[cfg(feature = "digitalocean")]
+    #[serde(rename = "digitalocean")]
+    DigitalOcean {
+        /// Personal Access Token
+        token: String,
+    },
 }
 
 impl ProviderCredentials {
@@ -468,6 +859,19 @@ impl ProviderCredentials {
                     "accessKeySecret",
                     "Access Key Secret",
                 )?,
+                api_variant: map
+                    .get("apiVariant")
+                    .filter(|v| !v.trim().is_empty())
+                    .map(|v| {
+                        v.parse()
+                            .map_err(|reason| CredentialValidationError::InvalidFormat {
+                                provider: provider.clone(),
+                                field: "apiVariant".to_string(),
+                                label: "API Variant".to_string(),
+                                reason,
+                            })
+                    })
+                    .transpose()?,
             }),
             #[cfg(feature = "dnspod")]
             ProviderType::Dnspod => Ok(Self::Dnspod {
@@ -489,6 +893,41 @@ impl ProviderCredentials {
                     "Secret Access Key",
                 )?,
             }),
+            #[cfg(feature = "route53")]
+            ProviderType::AwsRoute53 => Ok(Self::AwsRoute53 {
+                access_key_id: Self::get_required_field(
+                    provider,
+                    map,
+                    "accessKeyId",
+                    "Access Key ID",
+                )?,
+                secret_access_key: Self::get_required_field(
+                    provider,
+                    map,
+                    "secretAccessKey",
+                    "Secret Access Key",
+                )?,
+                region: Self::get_required_field(provider, map, "region", "Region")?,
+                role_arn: map
+                    .get("roleArn")
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty())
+                    .map(ToString::to_string),
+            }),
+            #[cfg(feature = "gcp-dns")]
+            ProviderType::GcpDns => Ok(Self::GcpDns {
+                project_id: Self::get_required_field(provider, map, "projectId", "Project ID")?,
+                service_account_json: Self::get_required_field(
+                    provider,
+                    map,
+                    "serviceAccountJson",
+                    "Service Account Key (JSON)",
+                )?,
+            }),
+            #[cfg(feature = "digitalocean")]
+            ProviderType::DigitalOcean => Ok(Self::DigitalOcean {
+                token: Self::get_required_field(provider, map, "token", "Personal Access Token")?,
+            }),
             #[allow(unreachable_patterns)]
             _ => Err(CredentialValidationError::InvalidFormat {
                 provider: provider.clone(),
@@ -531,11 +970,18 @@ impl ProviderCredentials {
             Self::Aliyun {
                 access_key_id,
                 access_key_secret,
-            } => [
-                ("accessKeyId".to_string(), access_key_id.clone()),
-                ("accessKeySecret".to_string(), access_key_secret.clone()),
-            ]
-            .into(),
+                api_variant,
+            } => {
+                let mut map: std::collections::HashMap<String, String> = [
+                    ("accessKeyId".to_string(), access_key_id.clone()),
+                    ("accessKeySecret".to_string(), access_key_secret.clone()),
+                ]
+                .into();
+                if let Some(variant) = api_variant {
+                    map.insert("apiVariant".to_string(), variant.as_str().to_string());
+                }
+                map
+            }
             Self::Dnspod {
                 secret_id,
                 secret_key,
@@ -552,6 +998,35 @@ impl ProviderCredentials {
                 ("secretAccessKey".to_string(), secret_access_key.clone()),
             ]
             .into(),
+            Self::AwsRoute53 {
+                access_key_id,
+                secret_access_key,
+                region,
+                role_arn,
+            } => {
+                let mut map: std::collections::HashMap<String, String> = [
+                    ("accessKeyId".to_string(), access_key_id.clone()),
+                    ("secretAccessKey".to_string(), secret_access_key.clone()),
+                    ("region".to_string(), region.clone()),
+                ]
+                .into();
+                if let Some(role_arn) = role_arn {
+                    map.insert("roleArn".to_string(), role_arn.clone());
+                }
+                map
+            }
+            Self::GcpDns {
+                project_id,
+                service_account_json,
+            } => [
+                ("projectId".to_string(), project_id.clone()),
+                (
+                    "serviceAccountJson".to_string(),
+                    service_account_json.clone(),
+                ),
+            ]
+            .into(),
+            Self::DigitalOcean { token } => [("token".to_string(), token.clone())].into(),
         }
     }
 
@@ -562,6 +1037,9 @@ impl ProviderCredentials {
             Self::Aliyun { .. } => ProviderType::Aliyun,
             Self::Dnspod { .. } => ProviderType::Dnspod,
             Self::Huaweicloud { .. } => ProviderType::Huaweicloud,
+            Self::AwsRoute53 { .. } => ProviderType::AwsRoute53,
+            Self::GcpDns { .. } => ProviderType::GcpDns,
+            Self::DigitalOcean { .. } => ProviderType::DigitalOcean,
         }
     }
 }
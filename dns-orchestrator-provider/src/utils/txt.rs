@@ -0,0 +1,166 @@
+//! TXT 记录值的分段与合并
+//!
+//! DNS 协议中 TXT 记录由若干个不超过 255 字节的字符串组成。应用内部统一使用拼接后的
+//! 完整文本，写入时按服务商的格式（见 [`TxtFormat`](crate::TxtFormat)）分段，读取时合并：
+//! - 分段只落在 UTF-8 字符边界上，转义在分段之后进行，不会把转义序列拆开
+//! - 带引号格式中引号、反斜杠与非可打印 ASCII 字节转义为 `\DDD`
+
+/// 单个字符串的最大字节数
+pub const MAX_SEGMENT_BYTES: usize = 255;
+
+/// `\DDD` 转义使用的进制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeRadix {
+    /// 十进制（RFC 1035 zone 文件格式）
+    Decimal,
+    /// 八进制（Route53）
+    Octal,
+}
+
+impl EscapeRadix {
+    fn value(self) -> u32 {
+        match self {
+            Self::Decimal => 10,
+            Self::Octal => 8,
+        }
+    }
+}
+
+/// 按字符边界切分为不超过 [`MAX_SEGMENT_BYTES`] 字节的段（空文本返回一个空段）
+pub fn split(text: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+    while rest.len() > MAX_SEGMENT_BYTES {
+        let mut end = MAX_SEGMENT_BYTES;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (segment, tail) = rest.split_at(end);
+        segments.push(segment);
+        rest = tail;
+    }
+    segments.push(rest);
+    segments
+}
+
+/// 合并各段为完整文本
+pub fn join<S: AsRef<str>>(segments: &[S]) -> String {
+    segments.iter().map(AsRef::as_ref).collect()
+}
+
+/// 转为带引号的多段格式：`"段1" "段2"`
+pub fn quote(text: &str, radix: EscapeRadix) -> String {
+    split(text)
+        .into_iter()
+        .map(|segment| {
+            let mut quoted = String::with_capacity(segment.len() + 2);
+            quoted.push('"');
+            for &byte in segment.as_bytes() {
+                match byte {
+                    b'"' => quoted.push_str("\\\""),
+                    b'\\' => quoted.push_str("\\\\"),
+                    0x20..=0x7e => quoted.push(char::from(byte)),
+                    _ => match radix {
+                        EscapeRadix::Decimal => quoted.push_str(&format!("\\{byte:03}")),
+                        EscapeRadix::Octal => quoted.push_str(&format!("\\{byte:03o}")),
+                    },
+                }
+            }
+            quoted.push('"');
+            quoted
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 解析带引号的多段格式：拼接各段引号内的内容并还原转义
+///
+/// 不以引号开头的值视为未分段的原始文本，原样返回。
+pub fn unquote(value: &str, radix: EscapeRadix) -> String {
+    let value = value.trim();
+    if !value.starts_with('"') {
+        return value.to_string();
+    }
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut in_quote = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => in_quote = !in_quote,
+            b'\\' if in_quote && i + 1 < bytes.len() => {
+                match escaped_byte(&bytes[i + 1..], radix) {
+                    Some(code) => {
+                        decoded.push(code);
+                        i += 3;
+                    }
+                    None => {
+                        decoded.push(bytes[i + 1]);
+                        i += 1;
+                    }
+                }
+            }
+            byte if in_quote => decoded.push(byte),
+            _ => {}
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// 解析 `\` 之后的三位数字转义
+fn escaped_byte(rest: &[u8], radix: EscapeRadix) -> Option<u8> {
+    let digits = std::str::from_utf8(rest.get(..3)?).ok()?;
+    if !digits.chars().all(|c| c.is_digit(radix.value())) {
+        return None;
+    }
+    u8::from_str_radix(digits, radix.value()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_on_char_boundaries() {
+        assert_eq!(split(""), vec![""]);
+        assert_eq!(split("v=spf1 -all"), vec!["v=spf1 -all"]);
+
+        let long = "a".repeat(600);
+        let segments = split(&long);
+        assert_eq!(
+            segments.iter().map(|s| s.len()).collect::<Vec<_>>(),
+            vec![255, 255, 90]
+        );
+        assert_eq!(join(&segments), long);
+
+        // 多字节字符不会被拆开
+        let text = format!("{}é", "a".repeat(254));
+        let segments = split(&text);
+        assert_eq!(segments, vec!["a".repeat(254).as_str(), "é"]);
+    }
+
+    #[test]
+    fn test_quote_round_trip() {
+        let text = format!("say \"hi\" \\o/ é\n{}", "x".repeat(500));
+        for radix in [EscapeRadix::Decimal, EscapeRadix::Octal] {
+            let quoted = quote(&text, radix);
+            assert_eq!(quoted.matches("\" \"").count(), 2);
+            assert_eq!(unquote(&quoted, radix), text);
+        }
+
+        assert_eq!(quote("", EscapeRadix::Decimal), "\"\"");
+        assert_eq!(quote("é", EscapeRadix::Decimal), r#""\195\169""#);
+        assert_eq!(quote("é", EscapeRadix::Octal), r#""\303\251""#);
+    }
+
+    #[test]
+    fn test_unquote_accepts_any_segmentation() {
+        let radix = EscapeRadix::Decimal;
+        assert_eq!(unquote(r#""v=spf1 " "-all""#, radix), "v=spf1 -all");
+        assert_eq!(unquote(r#""v=spf1 -all""#, radix), "v=spf1 -all");
+        assert_eq!(unquote("v=spf1 -all", radix), "v=spf1 -all");
+        // 非三位数字的转义按字面字符处理
+        assert_eq!(unquote(r#""a\;b\12""#, radix), "a;b12");
+    }
+}
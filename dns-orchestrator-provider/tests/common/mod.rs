@@ -4,8 +4,9 @@ use std::env;
 use std::sync::Arc;
 
 use dns_orchestrator_provider::{
-    CreateDnsRecordRequest, DnsProvider, DnsRecord, PaginationParams, ProviderCredentials,
-    RecordData, RecordQueryParams, create_provider,
+    ChangeResult, CreateDnsRecordRequest, DnsProvider, DnsRecord, PaginationParams,
+    ProviderCredentials, RecordChange, RecordData, RecordQueryParams, UpdateDnsRecordRequest,
+    create_provider, get_all_provider_metadata,
 };
 
 /// 跳过测试的宏（当环境变量缺失时）
@@ -151,6 +152,9 @@ impl TestContext {
         let credentials = ProviderCredentials::Aliyun {
             access_key_id,
             access_key_secret,
+            api_variant: env::var("ALIYUN_API_VARIANT")
+                .ok()
+                .and_then(|v| v.parse().ok()),
         };
         let provider = create_provider(credentials).ok()?;
 
@@ -199,6 +203,62 @@ impl TestContext {
         })
     }
 
+    /// 创建 AWS Route53 测试上下文
+    pub fn route53() -> Option<Self> {
+        let access_key_id = env::var("AWS_ACCESS_KEY_ID").ok()?;
+        let secret_access_key = env::var("AWS_SECRET_ACCESS_KEY").ok()?;
+        let region = env::var("AWS_REGION").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::AwsRoute53 {
+            access_key_id,
+            secret_access_key,
+            region,
+            role_arn: env::var("AWS_ROLE_ARN").ok(),
+        };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 Google Cloud DNS 测试上下文
+    pub fn gcp_dns() -> Option<Self> {
+        let project_id = env::var("GCP_PROJECT_ID").ok()?;
+        let service_account_json = env::var("GCP_SERVICE_ACCOUNT_JSON").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::GcpDns {
+            project_id,
+            service_account_json,
+        };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
+    /// 创建 DigitalOcean 测试上下文
+    pub fn digitalocean() -> Option<Self> {
+        let token = env::var("DIGITALOCEAN_TOKEN").ok()?;
+        let domain = env::var("TEST_DOMAIN").ok()?;
+
+        let credentials = ProviderCredentials::DigitalOcean { token };
+        let provider = create_provider(credentials).ok()?;
+
+        Some(Self {
+            provider,
+            domain,
+            domain_id: None,
+        })
+    }
+
     /// 查找测试域名的 domain_id
     pub async fn find_domain_id(&mut self) -> Option<String> {
         if self.domain_id.is_some() {
@@ -239,6 +299,227 @@ impl TestContext {
         let _ = self.provider.delete_record(record_id, domain_id).await;
     }
 
+    /// 关键字特殊字符一致性检查：`%`、`_`、`*` 等必须按字面量匹配
+    pub async fn check_keyword_literal_match(&self, domain_id: &str) {
+        let record = self
+            .create_test_record(domain_id)
+            .await
+            .expect("创建测试记录失败");
+
+        let search = |keyword: &str| RecordQueryParams {
+            page: 1,
+            page_size: 100,
+            keyword: Some(keyword.to_string()),
+            record_type: None,
+            sort: None,
+        };
+
+        // 记录名本身含 `_`，必须能搜到
+        let found = self
+            .provider
+            .list_records(domain_id, &search(&record.name))
+            .await;
+        let found_ok = found
+            .as_ref()
+            .is_ok_and(|r| r.items.iter().any(|item| item.id == record.id));
+
+        // 通配符不能扩大匹配范围：所有结果都必须字面包含关键字
+        let mut literal_ok = true;
+        for keyword in ["%", "_t", "*", "?"] {
+            match self
+                .provider
+                .list_records(domain_id, &search(keyword))
+                .await
+            {
+                Ok(response) => {
+                    if let Some(item) = response
+                        .items
+                        .iter()
+                        .find(|item| !item.name.to_lowercase().contains(keyword))
+                    {
+                        eprintln!("关键字 {keyword:?} 匹配到非字面量结果: {}", item.name);
+                        literal_ok = false;
+                    }
+                    // 总数必须与过滤后的结果一致，不能沿用服务端的候选总数
+                    if !response.has_more && response.total_count as usize != response.items.len() {
+                        eprintln!(
+                            "关键字 {keyword:?} 总数 {} 与结果数 {} 不一致",
+                            response.total_count,
+                            response.items.len()
+                        );
+                        literal_ok = false;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("关键字 {keyword:?} 搜索失败: {e}");
+                    literal_ok = false;
+                }
+            }
+        }
+
+        self.cleanup_record(&record.id, domain_id).await;
+
+        assert!(found_ok, "按记录名搜索失败: {found:?}");
+        assert!(literal_ok, "关键字特殊字符未按字面量匹配");
+    }
+
+    /// 批量变更语义检查：结果与输入一一对应，单条失败不影响其他变更
+    pub async fn check_batch_change_semantics(&self, domain_id: &str) {
+        // 先创建再删除一条记录，得到格式合法但已不存在的 ID
+        let stale = self
+            .create_test_record(domain_id)
+            .await
+            .expect("创建测试记录失败");
+        self.cleanup_record(&stale.id, domain_id).await;
+
+        let create = |data: RecordData| {
+            RecordChange::Create(CreateDnsRecordRequest {
+                domain_id: domain_id.to_string(),
+                name: generate_test_record_name(),
+                ttl: 600,
+                data,
+                proxied: None,
+            })
+        };
+        let (a_data, _) = get_test_record_data(TestRecordType::A);
+        let (txt_data, _) = get_test_record_data(TestRecordType::Txt);
+
+        let results = self
+            .provider
+            .batch_change(
+                domain_id,
+                vec![
+                    create(a_data.clone()),
+                    RecordChange::Delete {
+                        record_id: stale.id.clone(),
+                    },
+                    create(txt_data),
+                ],
+            )
+            .await;
+        assert_eq!(results.len(), 3, "结果数量应与变更数量一致");
+
+        let mut created: Vec<DnsRecord> = Vec::new();
+        for result in &results {
+            if let ChangeResult::Applied(record) = result {
+                created.push(record.clone());
+            }
+        }
+        let deleted_stale = matches!(results[1], ChangeResult::Failed(_));
+
+        // 第二批：更新一条、删除一条（互不依赖）
+        let mut second = Vec::new();
+        if let [a, txt] = created.as_slice() {
+            second = self
+                .provider
+                .batch_change(
+                    domain_id,
+                    vec![
+                        RecordChange::Update {
+                            record_id: a.id.clone(),
+                            request: UpdateDnsRecordRequest {
+                                domain_id: domain_id.to_string(),
+                                name: a.name.clone(),
+                                ttl: 300,
+                                data: a_data,
+                                proxied: None,
+                            },
+                        },
+                        RecordChange::Delete {
+                            record_id: txt.id.clone(),
+                        },
+                    ],
+                )
+                .await;
+        }
+
+        // 清理：更新成功时使用新 ID（部分服务商的 ID 随内容变化）
+        for record in &created {
+            self.cleanup_record(&record.id, domain_id).await;
+        }
+        if let Some(ChangeResult::Applied(updated)) = second.first() {
+            self.cleanup_record(&updated.id, domain_id).await;
+        }
+
+        assert!(
+            matches!(results[0], ChangeResult::Applied(_))
+                && matches!(results[2], ChangeResult::Applied(_)),
+            "有效变更应成功: {results:?}"
+        );
+        assert!(deleted_stale, "删除不存在的记录应失败: {:?}", results[1]);
+        assert!(
+            matches!(
+                second.as_slice(),
+                [ChangeResult::Applied(_), ChangeResult::Deleted]
+            ),
+            "更新与删除应成功: {second:?}"
+        );
+    }
+
+    /// 长 TXT 往返检查：超过 255 字节的值自动分段提交，读回后与原值一致
+    pub async fn check_long_txt_round_trip(&self, domain_id: &str) {
+        let includes: Vec<String> = (1..=10)
+            .map(|n| format!("include:_spf{n}.mail-relay.example.com"))
+            .collect();
+        let text = format!("v=spf1 {} ~all", includes.join(" "));
+        assert!(text.len() > 255 && text.len() <= 512);
+
+        let max_len = get_all_provider_metadata()
+            .into_iter()
+            .find(|metadata| metadata.id.to_string() == self.provider.id())
+            .map_or(0, |metadata| metadata.limits.max_txt_length);
+        assert!(
+            max_len == 0 || text.len() <= max_len as usize,
+            "测试值超过服务商声明的 TXT 长度上限 {max_len}"
+        );
+
+        let request = CreateDnsRecordRequest {
+            domain_id: domain_id.to_string(),
+            name: generate_test_record_name(),
+            ttl: 600,
+            data: RecordData::TXT { text: text.clone() },
+            proxied: None,
+        };
+        let record = self
+            .provider
+            .create_record(&request)
+            .await
+            .expect("创建长 TXT 记录失败");
+
+        let listed = self
+            .provider
+            .list_records(
+                domain_id,
+                &RecordQueryParams {
+                    page: 1,
+                    page_size: 100,
+                    keyword: Some(record.name.clone()),
+                    record_type: None,
+                    sort: None,
+                },
+            )
+            .await;
+
+        self.cleanup_record(&record.id, domain_id).await;
+
+        let created_ok = matches!(&record.data, RecordData::TXT { text: value } if *value == text);
+        let listed_text = listed
+            .as_ref()
+            .ok()
+            .and_then(|r| r.items.iter().find(|item| item.id == record.id))
+            .map(|item| item.data.display_value());
+        assert!(
+            created_ok,
+            "创建返回的 TXT 值与提交的不一致: {:?}",
+            record.data
+        );
+        assert_eq!(
+            listed_text.as_deref(),
+            Some(text.as_str()),
+            "读回的 TXT 值与提交的不一致"
+        );
+    }
+
     /// 查找并清理所有测试记录（以 _test- 开头的记录）
     pub async fn cleanup_all_test_records(&self, domain_id: &str) {
         let params = RecordQueryParams {
@@ -246,6 +527,7 @@ impl TestContext {
             page_size: 100,
             keyword: Some("_test-".to_string()),
             record_type: None,
+            sort: None,
         };
 
         if let Ok(response) = self.provider.list_records(domain_id, &params).await {
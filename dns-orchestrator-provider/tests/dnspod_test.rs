@@ -89,6 +89,42 @@ async fn test_dnspod_list_records() {
     );
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_dnspod_keyword_special_chars() {
+    skip_if_no_credentials!("DNSPOD_SECRET_ID", "DNSPOD_SECRET_KEY", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::dnspod().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    ctx.check_keyword_literal_match(&domain_id).await;
+    println!("✓ keyword 特殊字符测试通过");
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_dnspod_batch_change() {
+    skip_if_no_credentials!("DNSPOD_SECRET_ID", "DNSPOD_SECRET_KEY", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::dnspod().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    ctx.check_batch_change_semantics(&domain_id).await;
+    println!("✓ 批量变更测试通过");
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_dnspod_long_txt_round_trip() {
+    skip_if_no_credentials!("DNSPOD_SECRET_ID", "DNSPOD_SECRET_KEY", "TEST_DOMAIN");
+
+    let mut ctx = TestContext::dnspod().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    ctx.check_long_txt_round_trip(&domain_id).await;
+    println!("✓ 长 TXT 往返测试通过");
+}
+
 // ============ 清理测试 ============
 
 /// 清理所有残留的测试记录（手动运行）
@@ -127,6 +163,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: None,
                 record_type: None,
+                sort: None,
             };
             if let Ok(response) = ctx.provider.list_records(&domain_id, &list_params).await {
                 for record in response.items {
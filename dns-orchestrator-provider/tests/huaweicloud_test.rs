@@ -105,6 +105,54 @@ async fn test_huaweicloud_list_records() {
     );
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_huaweicloud_keyword_special_chars() {
+    skip_if_no_credentials!(
+        "HUAWEICLOUD_ACCESS_KEY_ID",
+        "HUAWEICLOUD_SECRET_ACCESS_KEY",
+        "TEST_DOMAIN"
+    );
+
+    let mut ctx = TestContext::huaweicloud().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    ctx.check_keyword_literal_match(&domain_id).await;
+    println!("✓ keyword 特殊字符测试通过");
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_huaweicloud_batch_change() {
+    skip_if_no_credentials!(
+        "HUAWEICLOUD_ACCESS_KEY_ID",
+        "HUAWEICLOUD_SECRET_ACCESS_KEY",
+        "TEST_DOMAIN"
+    );
+
+    let mut ctx = TestContext::huaweicloud().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    ctx.check_batch_change_semantics(&domain_id).await;
+    println!("✓ 批量变更测试通过");
+}
+
+#[tokio::test]
+#[ignore]
+async fn test_huaweicloud_long_txt_round_trip() {
+    skip_if_no_credentials!(
+        "HUAWEICLOUD_ACCESS_KEY_ID",
+        "HUAWEICLOUD_SECRET_ACCESS_KEY",
+        "TEST_DOMAIN"
+    );
+
+    let mut ctx = TestContext::huaweicloud().expect("创建测试上下文失败");
+    let domain_id = ctx.find_domain_id().await.expect("找不到测试域名");
+
+    ctx.check_long_txt_round_trip(&domain_id).await;
+    println!("✓ 长 TXT 往返测试通过");
+}
+
 // ============ 清理测试 ============
 
 /// 清理所有残留的测试记录（手动运行）
@@ -151,6 +199,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: Some(record_name.clone()),
                 record_type: None,
+                sort: None,
             };
             if let Ok(response) = ctx.provider.list_records(&domain_id, &cleanup_params).await {
                 for record in response.items {
@@ -187,6 +236,7 @@ macro_rules! crud_test {
                 page_size: 100,
                 keyword: Some(record_name.clone()),
                 record_type: None,
+                sort: None,
             };
 
             let list_result = ctx.provider.list_records(&domain_id, &search_params).await;
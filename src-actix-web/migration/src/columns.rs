@@ -0,0 +1,31 @@
+//! 各迁移共用的列定义
+//!
+//! 同一份迁移需要在 SQLite / Postgres / MySQL 上执行，列类型按以下原则选择：
+//! - 主键、参与索引的列使用带长度的 `varchar(n)`：MySQL 不允许无前缀长度的 TEXT 作主键，
+//!   而 `string()` 在 Postgres 上会生成不限长的 `varchar`，两端行为不一致
+//! - 可能超过 255 字符的内容（JSON 快照、错误信息）使用 `text`，且不设默认值
+//!   （MySQL 的 TEXT 列不支持 DEFAULT）
+//! - 布尔列使用 `boolean`（MySQL 落地为 `tinyint(1)`）
+//! - 时间戳见 [`utc_timestamp`]
+
+use sea_orm_migration::sea_orm::DbBackend;
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// ID 列长度（UUID 36 字符，留出余量）
+pub(crate) const ID_LEN: u32 = 64;
+
+/// 服务商返回的域名 ID 长度上限
+pub(crate) const DOMAIN_ID_LEN: u32 = 255;
+
+/// UTC 时间戳列
+///
+/// - Postgres：`timestamp with time zone`
+/// - MySQL：`datetime(6)`。sea-query 默认映射的 `timestamp` 受 2038 年上限和会话时区影响，
+///   且只有秒级精度，写入后读回的值与写入值不一致
+/// - SQLite：无原生时间类型，按文本存储
+pub(crate) fn utc_timestamp<T: IntoIden>(col: T, backend: DbBackend) -> ColumnDef {
+    match backend {
+        DbBackend::MySql => custom(col, "datetime(6)"),
+        _ => timestamp_with_time_zone(col),
+    }
+}
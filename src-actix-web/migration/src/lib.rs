@@ -1,12 +1,23 @@
 pub use sea_orm_migration::prelude::*;
 
-mod m20220101_000001_create_table;
+mod columns;
+mod m20261016_000001_create_audit_log;
+mod m20261016_000002_index_audit_log_record;
+mod m20261016_000003_add_audit_log_override;
+mod m20261016_000004_add_audit_log_source;
+mod m20261016_000005_create_api_usage;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20261016_000001_create_audit_log::Migration),
+            Box::new(m20261016_000002_index_audit_log_record::Migration),
+            Box::new(m20261016_000003_add_audit_log_override::Migration),
+            Box::new(m20261016_000004_add_audit_log_source::Migration),
+            Box::new(m20261016_000005_create_api_usage::Migration),
+        ]
     }
 }
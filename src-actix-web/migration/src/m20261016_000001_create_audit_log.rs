@@ -0,0 +1,112 @@
+//! DNS 记录变更审计日志表
+//!
+//! 列类型原则见 [`crate::columns`]；记录快照以 JSON 文本存储。
+//! `before` 在 MySQL 中是保留字，快照列统一加 `_record` 后缀。
+
+use sea_orm_migration::sea_orm::DbBackend;
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::columns::{DOMAIN_ID_LEN, ID_LEN, utc_timestamp};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    Id,
+    AccountId,
+    DomainId,
+    Operation,
+    RecordId,
+    BeforeRecord,
+    AfterRecord,
+    Success,
+    Error,
+    CreatedAt,
+}
+
+const IDX_ACCOUNT_DOMAIN_TIME: &str = "idx_audit_log_account_domain_time";
+const IDX_TIME: &str = "idx_audit_log_created_at";
+
+fn create_audit_log(backend: DbBackend) -> TableCreateStatement {
+    Table::create()
+        .table(AuditLog::Table)
+        .if_not_exists()
+        .col(string_len(AuditLog::Id, ID_LEN).primary_key())
+        .col(string_len(AuditLog::AccountId, ID_LEN))
+        .col(string_len(AuditLog::DomainId, DOMAIN_ID_LEN))
+        .col(string_len(AuditLog::Operation, 32))
+        .col(string_len_null(AuditLog::RecordId, 255))
+        .col(text_null(AuditLog::BeforeRecord))
+        .col(text_null(AuditLog::AfterRecord))
+        .col(boolean(AuditLog::Success))
+        .col(text_null(AuditLog::Error))
+        .col(utc_timestamp(AuditLog::CreatedAt, backend))
+        .to_owned()
+}
+
+fn create_indexes() -> Vec<IndexCreateStatement> {
+    vec![
+        Index::create()
+            .name(IDX_ACCOUNT_DOMAIN_TIME)
+            .table(AuditLog::Table)
+            .col(AuditLog::AccountId)
+            .col(AuditLog::DomainId)
+            .col(AuditLog::CreatedAt)
+            .if_not_exists()
+            .to_owned(),
+        Index::create()
+            .name(IDX_TIME)
+            .table(AuditLog::Table)
+            .col(AuditLog::CreatedAt)
+            .if_not_exists()
+            .to_owned(),
+    ]
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(create_audit_log(manager.get_database_backend()))
+            .await?;
+        for index in create_indexes() {
+            manager.create_index(index).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_audit_log_on_all_backends() {
+        let mysql = DbBackend::MySql
+            .build(&create_audit_log(DbBackend::MySql))
+            .to_string();
+        assert!(mysql.contains("`created_at` datetime(6) NOT NULL"));
+        assert!(mysql.contains("`before_record` text NULL"));
+        assert!(mysql.contains("`id` varchar(64) NOT NULL PRIMARY KEY"));
+        assert!(mysql.contains("`success` bool NOT NULL"));
+        assert!(!mysql.contains(" timestamp"));
+
+        let postgres = DbBackend::Postgres
+            .build(&create_audit_log(DbBackend::Postgres))
+            .to_string();
+        assert!(postgres.contains(r#""created_at" timestamp with time zone NOT NULL"#));
+        assert!(postgres.contains(r#""domain_id" varchar(255) NOT NULL"#));
+        assert!(!postgres.contains("varchar NOT NULL"));
+
+        let index = DbBackend::Sqlite.build(&create_indexes()[0]).to_string();
+        assert!(index.contains(r#"("account_id", "domain_id", "created_at")"#));
+    }
+}
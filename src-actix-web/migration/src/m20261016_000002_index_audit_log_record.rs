@@ -0,0 +1,61 @@
+//! 审计日志按记录查询的索引
+//!
+//! 单条记录变更历史按 (account_id, domain_id, record_id) 过滤、按时间倒序读取。
+
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    AccountId,
+    DomainId,
+    RecordId,
+    CreatedAt,
+}
+
+const IDX_RECORD_TIME: &str = "idx_audit_log_record_time";
+
+fn create_index() -> IndexCreateStatement {
+    Index::create()
+        .name(IDX_RECORD_TIME)
+        .table(AuditLog::Table)
+        .col(AuditLog::AccountId)
+        .col(AuditLog::DomainId)
+        .col(AuditLog::RecordId)
+        .col(AuditLog::CreatedAt)
+        .if_not_exists()
+        .to_owned()
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_index(create_index()).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_RECORD_TIME)
+                    .table(AuditLog::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm_migration::sea_orm::DbBackend;
+
+    #[test]
+    fn test_renders_record_index() {
+        let index = DbBackend::Postgres.build(&create_index()).to_string();
+        assert!(index.contains(r#"("account_id", "domain_id", "record_id", "created_at")"#));
+    }
+}
@@ -0,0 +1,51 @@
+//! 审计日志记录强制变更原因
+//!
+//! 在禁止变更时段内通过强制变更执行的写操作，审计记录附带强制变更原因。
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    OverrideReason,
+}
+
+fn add_column() -> TableAlterStatement {
+    Table::alter()
+        .table(AuditLog::Table)
+        .add_column(text_null(AuditLog::OverrideReason))
+        .to_owned()
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(add_column()).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuditLog::Table)
+                    .drop_column(AuditLog::OverrideReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm_migration::sea_orm::DbBackend;
+
+    #[test]
+    fn test_renders_override_column() {
+        let sql = DbBackend::Postgres.build(&add_column()).to_string();
+        assert!(sql.contains(r#"ADD COLUMN "override_reason" text NULL"#));
+    }
+}
@@ -0,0 +1,51 @@
+//! 审计日志记录变更来源
+//!
+//! 区分人工变更与自动化变更（DDNS、计划任务），已有记录视为人工变更。
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    Table,
+    Source,
+}
+
+fn add_column() -> TableAlterStatement {
+    Table::alter()
+        .table(AuditLog::Table)
+        .add_column(string_len(AuditLog::Source, 32).default("manual"))
+        .to_owned()
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.alter_table(add_column()).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuditLog::Table)
+                    .drop_column(AuditLog::Source)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm_migration::sea_orm::DbBackend;
+
+    #[test]
+    fn test_renders_source_column() {
+        let sql = DbBackend::Postgres.build(&add_column()).to_string();
+        assert!(sql.contains(r#"ADD COLUMN "source" varchar(32) NOT NULL DEFAULT 'manual'"#));
+    }
+}
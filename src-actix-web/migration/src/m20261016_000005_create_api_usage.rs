@@ -0,0 +1,83 @@
+//! 服务商 API 用量统计表
+//!
+//! 每个「账户 + 日期 + 操作」一行，调用次数按批累加。日期为 UTC 日期，
+//! 使用 `date` 类型以便按月范围查询和清理过期统计。
+
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::columns::ID_LEN;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum ApiUsage {
+    Table,
+    AccountId,
+    UsageDate,
+    Operation,
+    CallCount,
+}
+
+const IDX_DATE: &str = "idx_api_usage_usage_date";
+
+fn create_api_usage() -> TableCreateStatement {
+    Table::create()
+        .table(ApiUsage::Table)
+        .if_not_exists()
+        .col(string_len(ApiUsage::AccountId, ID_LEN))
+        .col(date(ApiUsage::UsageDate))
+        .col(string_len(ApiUsage::Operation, 32))
+        .col(big_integer(ApiUsage::CallCount).default(0))
+        .primary_key(
+            Index::create()
+                .col(ApiUsage::AccountId)
+                .col(ApiUsage::UsageDate)
+                .col(ApiUsage::Operation),
+        )
+        .to_owned()
+}
+
+/// 清理过期统计与导出全部账户时按日期范围扫描
+fn create_index() -> IndexCreateStatement {
+    Index::create()
+        .name(IDX_DATE)
+        .table(ApiUsage::Table)
+        .col(ApiUsage::UsageDate)
+        .if_not_exists()
+        .to_owned()
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(create_api_usage()).await?;
+        manager.create_index(create_index()).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ApiUsage::Table).if_exists().to_owned())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm_migration::sea_orm::DbBackend;
+
+    #[test]
+    fn test_renders_api_usage_on_all_backends() {
+        let mysql = DbBackend::MySql.build(&create_api_usage()).to_string();
+        assert!(mysql.contains("`usage_date` date NOT NULL"));
+        assert!(mysql.contains("`call_count` bigint NOT NULL DEFAULT 0"));
+
+        let postgres = DbBackend::Postgres.build(&create_api_usage()).to_string();
+        assert!(postgres.contains(r#""account_id" varchar(64) NOT NULL"#));
+        assert!(postgres.contains(r#""operation" varchar(32) NOT NULL"#));
+
+        let sqlite = DbBackend::Sqlite.build(&create_api_usage()).to_string();
+        assert!(sqlite.contains(r#"PRIMARY KEY ("account_id", "usage_date", "operation")"#));
+    }
+}
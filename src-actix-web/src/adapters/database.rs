@@ -0,0 +1,481 @@
+//! `SeaORM` 存储实现
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::{ApiUsageRepository, AuditLogRepository};
+use dns_orchestrator_core::types::{
+    ApiOperation, ApiUsageCount, AuditEntry, AuditLogFilter, AuditOperation, ChangeSource,
+    DnsRecord, PaginatedResponse, PaginationParams,
+};
+use sea_orm::sea_query::{Condition, Expr, ExprTrait, Func, Order, Query};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, QueryResult, TransactionTrait};
+
+const AUDIT_LOG_TABLE: &str = "audit_log";
+
+const AUDIT_LOG_COLUMNS: [&str; 12] = [
+    "id",
+    "account_id",
+    "domain_id",
+    "operation",
+    "source",
+    "record_id",
+    "before_record",
+    "after_record",
+    "success",
+    "error",
+    "override_reason",
+    "created_at",
+];
+
+const API_USAGE_TABLE: &str = "api_usage";
+
+const API_USAGE_COLUMNS: [&str; 4] = ["account_id", "usage_date", "operation", "call_count"];
+
+fn storage_error(e: &impl ToString) -> CoreError {
+    CoreError::StorageError(e.to_string())
+}
+
+/// 审计日志仓库（`audit_log` 表）
+pub struct SeaOrmAuditLogRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAuditLogRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn to_json(record: Option<&DnsRecord>) -> CoreResult<Option<String>> {
+    record
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| CoreError::SerializationError(e.to_string()))
+}
+
+fn from_json(text: Option<String>) -> CoreResult<Option<DnsRecord>> {
+    text.map(|text| serde_json::from_str(&text))
+        .transpose()
+        .map_err(|e| CoreError::SerializationError(e.to_string()))
+}
+
+fn filter_condition(filter: &AuditLogFilter) -> Condition {
+    let mut condition = Condition::all();
+    if let Some(account_id) = &filter.account_id {
+        condition = condition.add(Expr::col("account_id").eq(account_id.as_str()));
+    }
+    if let Some(domain_id) = &filter.domain_id {
+        condition = condition.add(Expr::col("domain_id").eq(domain_id.as_str()));
+    }
+    if let Some(record_id) = &filter.record_id {
+        condition = condition.add(Expr::col("record_id").eq(record_id.as_str()));
+    }
+    if let Some(since) = filter.since {
+        condition = condition.add(Expr::col("created_at").gte(since));
+    }
+    if let Some(until) = filter.until {
+        condition = condition.add(Expr::col("created_at").lt(until));
+    }
+    condition
+}
+
+fn entry_from_row(row: &QueryResult) -> CoreResult<AuditEntry> {
+    let get_str = |col: &str| {
+        row.try_get::<String>("", col)
+            .map_err(|e| storage_error(&e))
+    };
+    let operation = get_str("operation")?;
+    let source = get_str("source")?;
+    Ok(AuditEntry {
+        id: get_str("id")?,
+        timestamp: row
+            .try_get("", "created_at")
+            .map_err(|e| storage_error(&e))?,
+        account_id: get_str("account_id")?,
+        domain_id: get_str("domain_id")?,
+        operation: AuditOperation::parse(&operation)
+            .ok_or_else(|| storage_error(&format!("Unknown audit operation: {operation}")))?,
+        source: ChangeSource::parse(&source)
+            .ok_or_else(|| storage_error(&format!("Unknown change source: {source}")))?,
+        record_id: row
+            .try_get("", "record_id")
+            .map_err(|e| storage_error(&e))?,
+        before: from_json(
+            row.try_get("", "before_record")
+                .map_err(|e| storage_error(&e))?,
+        )?,
+        after: from_json(
+            row.try_get("", "after_record")
+                .map_err(|e| storage_error(&e))?,
+        )?,
+        success: row.try_get("", "success").map_err(|e| storage_error(&e))?,
+        error: row.try_get("", "error").map_err(|e| storage_error(&e))?,
+        override_reason: row
+            .try_get("", "override_reason")
+            .map_err(|e| storage_error(&e))?,
+    })
+}
+
+#[async_trait]
+impl AuditLogRepository for SeaOrmAuditLogRepository {
+    async fn append(&self, entry: &AuditEntry) -> CoreResult<()> {
+        let insert = Query::insert()
+            .into_table(AUDIT_LOG_TABLE)
+            .columns(AUDIT_LOG_COLUMNS)
+            .values([
+                entry.id.as_str().into(),
+                entry.account_id.as_str().into(),
+                entry.domain_id.as_str().into(),
+                entry.operation.as_str().into(),
+                entry.source.as_str().into(),
+                entry.record_id.clone().into(),
+                to_json(entry.before.as_ref())?.into(),
+                to_json(entry.after.as_ref())?.into(),
+                entry.success.into(),
+                entry.error.clone().into(),
+                entry.override_reason.clone().into(),
+                entry.timestamp.into(),
+            ])
+            .map_err(|e| storage_error(&e))?
+            .to_owned();
+        self.db
+            .execute(&insert)
+            .await
+            .map_err(|e| storage_error(&e))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<AuditEntry>> {
+        self.db
+            .query_one(
+                Query::select()
+                    .columns(AUDIT_LOG_COLUMNS)
+                    .from(AUDIT_LOG_TABLE)
+                    .and_where(Expr::col("id").eq(id)),
+            )
+            .await
+            .map_err(|e| storage_error(&e))?
+            .as_ref()
+            .map(entry_from_row)
+            .transpose()
+    }
+
+    async fn query(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: &PaginationParams,
+    ) -> CoreResult<PaginatedResponse<AuditEntry>> {
+        let condition = filter_condition(filter);
+
+        let total = self
+            .db
+            .query_one(
+                Query::select()
+                    .expr_as(Func::count(Expr::col("id")), "total")
+                    .from(AUDIT_LOG_TABLE)
+                    .cond_where(condition.clone()),
+            )
+            .await
+            .and_then(|row| {
+                row.ok_or_else(|| DbErr::RecordNotFound(AUDIT_LOG_TABLE.to_string()))?
+                    .try_get::<i64>("", "total")
+            })
+            .map_err(|e| storage_error(&e))?;
+
+        let rows = self
+            .db
+            .query_all(
+                Query::select()
+                    .columns(AUDIT_LOG_COLUMNS)
+                    .from(AUDIT_LOG_TABLE)
+                    .cond_where(condition)
+                    .order_by("created_at", Order::Desc)
+                    .order_by("id", Order::Desc)
+                    .limit(u64::from(pagination.page_size))
+                    .offset(u64::from(
+                        pagination.page.saturating_sub(1) * pagination.page_size,
+                    )),
+            )
+            .await
+            .map_err(|e| storage_error(&e))?;
+
+        let items = rows
+            .iter()
+            .map(entry_from_row)
+            .collect::<CoreResult<Vec<_>>>()?;
+        Ok(PaginatedResponse::new(
+            items,
+            pagination.page,
+            pagination.page_size,
+            u32::try_from(total).unwrap_or(u32::MAX),
+        ))
+    }
+}
+
+/// API 用量仓库（`api_usage` 表）
+pub struct SeaOrmApiUsageRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmApiUsageRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn usage_from_row(row: &QueryResult) -> CoreResult<ApiUsageCount> {
+    let operation: String = row
+        .try_get("", "operation")
+        .map_err(|e| storage_error(&e))?;
+    let count: i64 = row
+        .try_get("", "call_count")
+        .map_err(|e| storage_error(&e))?;
+    Ok(ApiUsageCount {
+        account_id: row
+            .try_get("", "account_id")
+            .map_err(|e| storage_error(&e))?,
+        date: row
+            .try_get("", "usage_date")
+            .map_err(|e| storage_error(&e))?,
+        operation: ApiOperation::parse(&operation)
+            .ok_or_else(|| storage_error(&format!("Unknown api operation: {operation}")))?,
+        count: u64::try_from(count).unwrap_or(0),
+    })
+}
+
+#[async_trait]
+impl ApiUsageRepository for SeaOrmApiUsageRepository {
+    /// 同一事务内逐条累加（已有行 `UPDATE`，没有时 `INSERT`），失败整批回滚，
+    /// 由调用方重试时不会重复计数
+    async fn add_counts(&self, counts: &[ApiUsageCount]) -> CoreResult<()> {
+        let txn = self.db.begin().await.map_err(|e| storage_error(&e))?;
+        for count in counts {
+            let delta = i64::try_from(count.count).unwrap_or(i64::MAX);
+            let key = Condition::all()
+                .add(Expr::col("account_id").eq(count.account_id.as_str()))
+                .add(Expr::col("usage_date").eq(count.date))
+                .add(Expr::col("operation").eq(count.operation.as_str()));
+            let update = Query::update()
+                .table(API_USAGE_TABLE)
+                .value("call_count", Expr::col("call_count").add(delta))
+                .cond_where(key)
+                .to_owned();
+            let updated = txn.execute(&update).await.map_err(|e| storage_error(&e))?;
+            if updated.rows_affected() > 0 {
+                continue;
+            }
+
+            let insert = Query::insert()
+                .into_table(API_USAGE_TABLE)
+                .columns(API_USAGE_COLUMNS)
+                .values([
+                    count.account_id.as_str().into(),
+                    count.date.into(),
+                    count.operation.as_str().into(),
+                    delta.into(),
+                ])
+                .map_err(|e| storage_error(&e))?
+                .to_owned();
+            txn.execute(&insert).await.map_err(|e| storage_error(&e))?;
+        }
+        txn.commit().await.map_err(|e| storage_error(&e))
+    }
+
+    async fn query(
+        &self,
+        account_id: Option<&str>,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> CoreResult<Vec<ApiUsageCount>> {
+        let mut condition = Condition::all()
+            .add(Expr::col("usage_date").gte(since))
+            .add(Expr::col("usage_date").lt(until));
+        if let Some(account_id) = account_id {
+            condition = condition.add(Expr::col("account_id").eq(account_id));
+        }
+
+        let rows = self
+            .db
+            .query_all(
+                Query::select()
+                    .columns(API_USAGE_COLUMNS)
+                    .from(API_USAGE_TABLE)
+                    .cond_where(condition)
+                    .order_by("usage_date", Order::Asc),
+            )
+            .await
+            .map_err(|e| storage_error(&e))?;
+        rows.iter().map(usage_from_row).collect()
+    }
+
+    async fn delete_before(&self, before: NaiveDate) -> CoreResult<u64> {
+        let delete = Query::delete()
+            .from_table(API_USAGE_TABLE)
+            .and_where(Expr::col("usage_date").lt(before))
+            .to_owned();
+        let result = self
+            .db
+            .execute(&delete)
+            .await
+            .map_err(|e| storage_error(&e))?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use dns_orchestrator_core::types::RecordData;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    use super::*;
+
+    /// 内存 SQLite，已执行全部迁移
+    async fn connect() -> CoreResult<DatabaseConnection> {
+        let db = Database::connect("sqlite::memory:")
+            .await
+            .map_err(|e| storage_error(&e))?;
+        Migrator::up(&db, None)
+            .await
+            .map_err(|e| storage_error(&e))?;
+        Ok(db)
+    }
+
+    fn record(address: &str) -> DnsRecord {
+        DnsRecord {
+            id: "record-1".to_string(),
+            domain_id: "zone-1".to_string(),
+            name: "www".to_string(),
+            ttl: 300,
+            data: RecordData::A {
+                address: address.to_string(),
+            },
+            proxied: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn audit_log_round_trips_and_filters() -> CoreResult<()> {
+        let repository = SeaOrmAuditLogRepository::new(connect().await?);
+        let now = Utc::now();
+
+        let mut update = AuditEntry::new("account-1", "zone-1", AuditOperation::Update);
+        update.timestamp = now - Duration::minutes(2);
+        update.source = ChangeSource::Scheduled;
+        update.record_id = Some("record-1".to_string());
+        update.before = Some(record("192.0.2.1"));
+        update.after = Some(record("192.0.2.2"));
+        let mut failed = AuditEntry::new("account-1", "zone-1", AuditOperation::Create);
+        failed.timestamp = now - Duration::minutes(1);
+        failed.success = false;
+        failed.error = Some("quota exceeded".to_string());
+        failed.override_reason = Some("incident".to_string());
+        let other = AuditEntry::new("account-2", "zone-9", AuditOperation::Delete);
+        for entry in [&update, &failed, &other] {
+            repository.append(entry).await?;
+        }
+
+        let stored = repository
+            .find_by_id(&update.id)
+            .await?
+            .ok_or_else(|| CoreError::StorageError("missing entry".to_string()))?;
+        assert_eq!(stored.source, ChangeSource::Scheduled);
+        assert_eq!(stored.record_id.as_deref(), Some("record-1"));
+        assert_eq!(
+            stored.before.map(|r| r.data.display_value()).as_deref(),
+            Some("192.0.2.1")
+        );
+        assert_eq!(
+            stored.after.map(|r| r.data.display_value()).as_deref(),
+            Some("192.0.2.2")
+        );
+        assert!(repository.find_by_id("missing").await?.is_none());
+
+        let filter = AuditLogFilter {
+            account_id: Some("account-1".to_string()),
+            ..AuditLogFilter::default()
+        };
+        let page = repository
+            .query(&filter, &PaginationParams::default())
+            .await?;
+        let ids: Vec<&str> = page.items.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec![failed.id.as_str(), update.id.as_str()]);
+        assert_eq!(page.items[0].error.as_deref(), Some("quota exceeded"));
+        assert_eq!(page.items[0].override_reason.as_deref(), Some("incident"));
+        assert!(!page.items[0].success);
+
+        let filter = AuditLogFilter {
+            since: Some(now - Duration::seconds(90)),
+            until: Some(now - Duration::seconds(30)),
+            ..AuditLogFilter::default()
+        };
+        let page = repository
+            .query(
+                &filter,
+                &PaginationParams {
+                    page: 1,
+                    page_size: 10,
+                },
+            )
+            .await?;
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.items[0].id, failed.id);
+
+        let page = repository
+            .query(
+                &AuditLogFilter::default(),
+                &PaginationParams {
+                    page: 2,
+                    page_size: 2,
+                },
+            )
+            .await?;
+        assert_eq!(page.total_count, 3);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, update.id);
+        Ok(())
+    }
+
+    fn usage(account_id: &str, date: NaiveDate, count: u64) -> ApiUsageCount {
+        ApiUsageCount {
+            account_id: account_id.to_string(),
+            date,
+            operation: ApiOperation::ListRecords,
+            count,
+        }
+    }
+
+    #[actix_web::test]
+    async fn api_usage_accumulates_and_expires() -> CoreResult<()> {
+        let repository = SeaOrmApiUsageRepository::new(connect().await?);
+        let today = Utc::now().date_naive();
+        let last_year = today - Duration::days(400);
+
+        repository
+            .add_counts(&[
+                usage("account-1", today, 3),
+                usage("account-2", today, 1),
+                usage("account-1", last_year, 7),
+            ])
+            .await?;
+        repository
+            .add_counts(&[usage("account-1", today, 2)])
+            .await?;
+
+        let tomorrow = today + Duration::days(1);
+        let counts = repository.query(Some("account-1"), today, tomorrow).await?;
+        assert_eq!(counts, vec![usage("account-1", today, 5)]);
+        assert_eq!(repository.query(None, last_year, tomorrow).await?.len(), 3);
+
+        assert_eq!(
+            repository.delete_before(today - Duration::days(1)).await?,
+            1
+        );
+        assert_eq!(repository.query(None, last_year, tomorrow).await?.len(), 2);
+        Ok(())
+    }
+}
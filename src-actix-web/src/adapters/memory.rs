@@ -0,0 +1,613 @@
+//! 进程内存存储
+//!
+//! 重启后数据丢失，仅在 `SeaORM` 存储实现之前占位，保证 core 服务可以在 Web 端组装。
+
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use dns_orchestrator_core::ProviderCredentials;
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::{
+    AccountCapabilityRepository, AccountRepository, ApiUsageRepository, AuditLogRepository,
+    ChangeWindowRepository, CredentialStore, CredentialsMap, DomainGroupRepository,
+    DomainMetadataRepository, MonitorRepository,
+};
+use dns_orchestrator_core::types::{
+    Account, AccountCapabilityCache, AccountStatus, ApiUsageCount, AuditEntry, AuditLogFilter,
+    ChangeWindowRule, DomainExpiryEntry, DomainGroup, DomainMetadata, DomainMetadataKey,
+    DomainMetadataUpdate, PaginatedResponse, PaginationParams,
+};
+use tokio::sync::RwLock;
+
+/// 内存账户仓库
+#[derive(Default)]
+pub struct InMemoryAccountRepository {
+    accounts: RwLock<Vec<Account>>,
+}
+
+#[async_trait]
+impl AccountRepository for InMemoryAccountRepository {
+    async fn find_all(&self) -> CoreResult<Vec<Account>> {
+        Ok(self.accounts.read().await.clone())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<Account>> {
+        Ok(self
+            .accounts
+            .read()
+            .await
+            .iter()
+            .find(|a| a.id == id)
+            .cloned())
+    }
+
+    async fn save(&self, account: &Account) -> CoreResult<()> {
+        let mut accounts = self.accounts.write().await;
+        if let Some(existing) = accounts.iter_mut().find(|a| a.id == account.id) {
+            *existing = account.clone();
+        } else {
+            accounts.push(account.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        self.accounts.write().await.retain(|a| a.id != id);
+        Ok(())
+    }
+
+    async fn save_all(&self, accounts: &[Account]) -> CoreResult<()> {
+        *self.accounts.write().await = accounts.to_vec();
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        id: &str,
+        status: AccountStatus,
+        error: Option<String>,
+    ) -> CoreResult<()> {
+        let mut accounts = self.accounts.write().await;
+        let account = accounts
+            .iter_mut()
+            .find(|a| a.id == id)
+            .ok_or_else(|| CoreError::AccountNotFound(id.to_string()))?;
+
+        account.status = Some(status);
+        account.error = error;
+        account.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+}
+
+/// 内存凭证存储
+#[derive(Default)]
+pub struct InMemoryCredentialStore {
+    credentials: RwLock<CredentialsMap>,
+}
+
+#[async_trait]
+impl CredentialStore for InMemoryCredentialStore {
+    async fn load_all(&self) -> CoreResult<CredentialsMap> {
+        Ok(self.credentials.read().await.clone())
+    }
+
+    async fn save_all(&self, credentials: &CredentialsMap) -> CoreResult<()> {
+        (*self.credentials.write().await).clone_from(credentials);
+        Ok(())
+    }
+
+    async fn get(&self, account_id: &str) -> CoreResult<Option<ProviderCredentials>> {
+        Ok(self.credentials.read().await.get(account_id).cloned())
+    }
+
+    async fn set(&self, account_id: &str, credentials: &ProviderCredentials) -> CoreResult<()> {
+        self.credentials
+            .write()
+            .await
+            .insert(account_id.to_string(), credentials.clone());
+        Ok(())
+    }
+
+    async fn remove(&self, account_id: &str) -> CoreResult<()> {
+        self.credentials.write().await.remove(account_id);
+        Ok(())
+    }
+
+    async fn load_raw_json(&self) -> CoreResult<String> {
+        let credentials = self.credentials.read().await;
+        serde_json::to_string(&*credentials)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    async fn save_raw_json(&self, json: &str) -> CoreResult<()> {
+        let credentials: CredentialsMap =
+            serde_json::from_str(json).map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        *self.credentials.write().await = credentials;
+        Ok(())
+    }
+}
+
+/// 内存域名元数据仓库
+#[derive(Default)]
+pub struct InMemoryDomainMetadataRepository {
+    entries: RwLock<HashMap<DomainMetadataKey, DomainMetadata>>,
+}
+
+#[async_trait]
+impl DomainMetadataRepository for InMemoryDomainMetadataRepository {
+    async fn find_by_key(&self, key: &DomainMetadataKey) -> CoreResult<Option<DomainMetadata>> {
+        Ok(self.entries.read().await.get(key).cloned())
+    }
+
+    async fn find_by_keys(
+        &self,
+        keys: &[DomainMetadataKey],
+    ) -> CoreResult<HashMap<DomainMetadataKey, DomainMetadata>> {
+        let entries = self.entries.read().await;
+        Ok(keys
+            .iter()
+            .filter_map(|key| entries.get(key).map(|m| (key.clone(), m.clone())))
+            .collect())
+    }
+
+    async fn save(&self, key: &DomainMetadataKey, metadata: &DomainMetadata) -> CoreResult<()> {
+        let mut entries = self.entries.write().await;
+        // 空元数据不保存
+        if metadata.is_empty() {
+            entries.remove(key);
+        } else {
+            entries.insert(key.clone(), metadata.clone());
+        }
+        Ok(())
+    }
+
+    async fn batch_save(&self, entries: &[(DomainMetadataKey, DomainMetadata)]) -> CoreResult<()> {
+        for (key, metadata) in entries {
+            self.save(key, metadata).await?;
+        }
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        key: &DomainMetadataKey,
+        update: &DomainMetadataUpdate,
+    ) -> CoreResult<()> {
+        let mut metadata = self.find_by_key(key).await?.unwrap_or_default();
+        update.apply_to(&mut metadata);
+        self.save(key, &metadata).await
+    }
+
+    async fn delete(&self, key: &DomainMetadataKey) -> CoreResult<()> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn delete_by_account(&self, account_id: &str) -> CoreResult<()> {
+        self.entries
+            .write()
+            .await
+            .retain(|key, _| key.account_id != account_id);
+        Ok(())
+    }
+
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<(DomainMetadataKey, DomainMetadata)>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|(key, _)| key.account_id == account_id)
+            .map(|(key, metadata)| (key.clone(), metadata.clone()))
+            .collect())
+    }
+
+    async fn find_favorites_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<DomainMetadataKey>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|(key, metadata)| key.account_id == account_id && metadata.is_favorite)
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+
+    async fn find_by_tag(&self, tag: &str) -> CoreResult<Vec<DomainMetadataKey>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|(_, metadata)| metadata.tags.iter().any(|t| t == tag))
+            .map(|(key, _)| key.clone())
+            .collect())
+    }
+
+    async fn list_all_tags(&self) -> CoreResult<Vec<String>> {
+        let entries = self.entries.read().await;
+        let tags: HashSet<&String> = entries.values().flat_map(|m| m.tags.iter()).collect();
+        let mut result: Vec<String> = tags.into_iter().cloned().collect();
+        result.sort();
+        Ok(result)
+    }
+}
+
+/// 内存域名组仓库
+#[derive(Default)]
+pub struct InMemoryDomainGroupRepository {
+    groups: RwLock<Vec<DomainGroup>>,
+}
+
+#[async_trait]
+impl DomainGroupRepository for InMemoryDomainGroupRepository {
+    async fn find_all(&self) -> CoreResult<Vec<DomainGroup>> {
+        Ok(self.groups.read().await.clone())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<DomainGroup>> {
+        Ok(self
+            .groups
+            .read()
+            .await
+            .iter()
+            .find(|g| g.id == id)
+            .cloned())
+    }
+
+    async fn save(&self, group: &DomainGroup) -> CoreResult<()> {
+        let mut groups = self.groups.write().await;
+        if let Some(existing) = groups.iter_mut().find(|g| g.id == group.id) {
+            *existing = group.clone();
+        } else {
+            groups.push(group.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        self.groups.write().await.retain(|g| g.id != id);
+        Ok(())
+    }
+}
+
+/// 内存禁止变更规则仓库
+#[derive(Default)]
+pub struct InMemoryChangeWindowRepository {
+    rules: RwLock<Vec<ChangeWindowRule>>,
+}
+
+#[async_trait]
+impl ChangeWindowRepository for InMemoryChangeWindowRepository {
+    async fn find_all(&self) -> CoreResult<Vec<ChangeWindowRule>> {
+        Ok(self.rules.read().await.clone())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<ChangeWindowRule>> {
+        Ok(self
+            .rules
+            .read()
+            .await
+            .iter()
+            .find(|r| r.id == id)
+            .cloned())
+    }
+
+    async fn save(&self, rule: &ChangeWindowRule) -> CoreResult<()> {
+        let mut rules = self.rules.write().await;
+        if let Some(existing) = rules.iter_mut().find(|r| r.id == rule.id) {
+            *existing = rule.clone();
+        } else {
+            rules.push(rule.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        self.rules.write().await.retain(|r| r.id != id);
+        Ok(())
+    }
+}
+
+/// 内存监控结果仓库
+#[derive(Default)]
+pub struct InMemoryMonitorRepository {
+    expiry_results: RwLock<Vec<DomainExpiryEntry>>,
+}
+
+#[async_trait]
+impl MonitorRepository for InMemoryMonitorRepository {
+    async fn find_expiry_results(&self) -> CoreResult<Vec<DomainExpiryEntry>> {
+        Ok(self.expiry_results.read().await.clone())
+    }
+
+    async fn save_expiry_results(&self, entries: &[DomainExpiryEntry]) -> CoreResult<()> {
+        *self.expiry_results.write().await = entries.to_vec();
+        Ok(())
+    }
+}
+
+/// 内存账户能力缓存仓库
+#[derive(Default)]
+pub struct InMemoryAccountCapabilityRepository {
+    entries: RwLock<HashMap<String, AccountCapabilityCache>>,
+}
+
+#[async_trait]
+impl AccountCapabilityRepository for InMemoryAccountCapabilityRepository {
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Option<AccountCapabilityCache>> {
+        Ok(self.entries.read().await.get(account_id).cloned())
+    }
+
+    async fn save(&self, cache: &AccountCapabilityCache) -> CoreResult<()> {
+        self.entries
+            .write()
+            .await
+            .insert(cache.account_id.clone(), cache.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, account_id: &str) -> CoreResult<()> {
+        self.entries.write().await.remove(account_id);
+        Ok(())
+    }
+}
+
+/// 内存审计日志仓库（未配置数据库时使用）
+#[derive(Default)]
+pub struct InMemoryAuditLogRepository {
+    entries: RwLock<Vec<AuditEntry>>,
+}
+
+#[async_trait]
+impl AuditLogRepository for InMemoryAuditLogRepository {
+    async fn append(&self, entry: &AuditEntry) -> CoreResult<()> {
+        self.entries.write().await.push(entry.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<AuditEntry>> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .find(|e| e.id == id)
+            .cloned())
+    }
+
+    async fn query(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: &PaginationParams,
+    ) -> CoreResult<PaginatedResponse<AuditEntry>> {
+        let entries = self.entries.read().await;
+        // 按追加顺序存储，倒序遍历即为时间倒序
+        let matched: Vec<&AuditEntry> =
+            entries.iter().rev().filter(|e| filter.matches(e)).collect();
+        let offset = (pagination.page.saturating_sub(1) * pagination.page_size) as usize;
+        let items = matched
+            .iter()
+            .skip(offset)
+            .take(pagination.page_size as usize)
+            .map(|e| (*e).clone())
+            .collect();
+        let total = u32::try_from(matched.len()).unwrap_or(u32::MAX);
+        Ok(PaginatedResponse::new(
+            items,
+            pagination.page,
+            pagination.page_size,
+            total,
+        ))
+    }
+}
+
+/// 内存 API 用量仓库（未配置数据库时使用）
+#[derive(Default)]
+pub struct InMemoryApiUsageRepository {
+    counts: RwLock<Vec<ApiUsageCount>>,
+}
+
+#[async_trait]
+impl ApiUsageRepository for InMemoryApiUsageRepository {
+    async fn add_counts(&self, counts: &[ApiUsageCount]) -> CoreResult<()> {
+        let mut entries = self.counts.write().await;
+        for count in counts {
+            match entries.iter_mut().find(|e| {
+                e.account_id == count.account_id
+                    && e.date == count.date
+                    && e.operation == count.operation
+            }) {
+                Some(existing) => existing.count += count.count,
+                None => entries.push(count.clone()),
+            }
+        }
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        account_id: Option<&str>,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> CoreResult<Vec<ApiUsageCount>> {
+        Ok(self
+            .counts
+            .read()
+            .await
+            .iter()
+            .filter(|e| {
+                account_id.is_none_or(|id| id == e.account_id) && e.date >= since && e.date < until
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_before(&self, before: NaiveDate) -> CoreResult<u64> {
+        let mut entries = self.counts.write().await;
+        let len = entries.len();
+        entries.retain(|e| e.date >= before);
+        Ok((len - entries.len()) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use dns_orchestrator_core::types::{ApiOperation, AuditOperation};
+
+    use super::*;
+
+    fn key(account_id: &str, domain_id: &str) -> DomainMetadataKey {
+        DomainMetadataKey::new(account_id.to_string(), domain_id.to_string())
+    }
+
+    fn tags(tags: &[&str]) -> DomainMetadataUpdate {
+        DomainMetadataUpdate {
+            is_favorite: None,
+            tags: Some(tags.iter().map(ToString::to_string).collect()),
+            color: None,
+            note: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn metadata_updates_merge_and_drop_empty_entries() -> CoreResult<()> {
+        let repository = InMemoryDomainMetadataRepository::default();
+        repository
+            .update(&key("a1", "d1"), &tags(&["prod", "web"]))
+            .await?;
+        repository
+            .update(&key("a1", "d2"), &tags(&["prod"]))
+            .await?;
+        repository.update(&key("a2", "d3"), &tags(&["lab"])).await?;
+        repository
+            .update(
+                &key("a1", "d2"),
+                &DomainMetadataUpdate {
+                    is_favorite: Some(true),
+                    tags: None,
+                    color: None,
+                    note: None,
+                },
+            )
+            .await?;
+
+        let stored = repository.find_by_key(&key("a1", "d2")).await?;
+        assert!(stored.is_some_and(|m| m.is_favorite && m.tags == ["prod"]));
+        assert_eq!(
+            repository.find_favorites_by_account("a1").await?,
+            vec![key("a1", "d2")]
+        );
+        assert_eq!(repository.list_all_tags().await?, ["lab", "prod", "web"]);
+        let mut prod = repository.find_by_tag("prod").await?;
+        prod.sort_by(|a, b| a.domain_id.cmp(&b.domain_id));
+        assert_eq!(prod, vec![key("a1", "d1"), key("a1", "d2")]);
+
+        // 清空全部字段后不再保存
+        repository.update(&key("a1", "d1"), &tags(&[])).await?;
+        assert!(repository.find_by_key(&key("a1", "d1")).await?.is_none());
+
+        repository.delete_by_account("a1").await?;
+        assert!(repository.find_by_account("a1").await?.is_empty());
+        assert_eq!(repository.find_by_account("a2").await?.len(), 1);
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn audit_log_is_newest_first_and_paginated() -> CoreResult<()> {
+        let repository = InMemoryAuditLogRepository::default();
+        let mut ids = Vec::new();
+        for (account_id, operation) in [
+            ("a1", AuditOperation::Create),
+            ("a2", AuditOperation::Update),
+            ("a1", AuditOperation::Delete),
+        ] {
+            let entry = AuditEntry::new(account_id, "zone-1", operation);
+            ids.push(entry.id.clone());
+            repository.append(&entry).await?;
+        }
+
+        let filter = AuditLogFilter {
+            account_id: Some("a1".to_string()),
+            ..AuditLogFilter::default()
+        };
+        let page = repository
+            .query(&filter, &PaginationParams::default())
+            .await?;
+        let found: Vec<&str> = page.items.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(found, [ids[2].as_str(), ids[0].as_str()]);
+
+        let page = repository
+            .query(
+                &AuditLogFilter::default(),
+                &PaginationParams {
+                    page: 2,
+                    page_size: 2,
+                },
+            )
+            .await?;
+        assert_eq!(page.total_count, 3);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, ids[0]);
+        assert!(repository.find_by_id(&ids[1]).await?.is_some());
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn api_usage_accumulates_and_expires() -> CoreResult<()> {
+        let repository = InMemoryApiUsageRepository::default();
+        let today = Utc::now().date_naive();
+        let usage = |account_id: &str, date: NaiveDate, count: u64| ApiUsageCount {
+            account_id: account_id.to_string(),
+            date,
+            operation: ApiOperation::ListRecords,
+            count,
+        };
+
+        repository
+            .add_counts(&[
+                usage("a1", today, 3),
+                usage("a1", today - Duration::days(400), 1),
+            ])
+            .await?;
+        repository.add_counts(&[usage("a1", today, 2)]).await?;
+
+        let tomorrow = today + Duration::days(1);
+        assert_eq!(
+            repository.query(Some("a1"), today, tomorrow).await?,
+            vec![usage("a1", today, 5)]
+        );
+        assert!(
+            repository
+                .query(Some("a2"), today, tomorrow)
+                .await?
+                .is_empty()
+        );
+        assert_eq!(repository.delete_before(today).await?, 1);
+        assert_eq!(repository.delete_before(today).await?, 0);
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn updating_a_missing_account_status_fails() {
+        let repository = InMemoryAccountRepository::default();
+        assert!(matches!(
+            repository
+                .update_status("missing", AccountStatus::Error, None)
+                .await,
+            Err(CoreError::AccountNotFound(id)) if id == "missing"
+        ));
+    }
+}
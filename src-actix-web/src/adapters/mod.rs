@@ -0,0 +1,11 @@
+//! core 存储抽象的 Web 端实现
+
+mod database;
+mod memory;
+
+pub use database::{SeaOrmApiUsageRepository, SeaOrmAuditLogRepository};
+pub use memory::{
+    InMemoryAccountCapabilityRepository, InMemoryAccountRepository, InMemoryApiUsageRepository,
+    InMemoryAuditLogRepository, InMemoryChangeWindowRepository, InMemoryCredentialStore,
+    InMemoryDomainGroupRepository, InMemoryDomainMetadataRepository, InMemoryMonitorRepository,
+};
@@ -0,0 +1,138 @@
+//! 管理接口鉴权
+//!
+//! `/api/admin/*` 及 `/api/invoke` 中的同类命令可导出全部凭证或覆盖现有账户，只在设置了
+//! `DNS_ORCHESTRATOR_ADMIN_TOKEN` 时开放，请求需携带 `Authorization: Bearer <token>`。
+//! 未设置时一律返回 403。
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::StatusCode;
+use actix_web::http::header::{AUTHORIZATION, HeaderMap};
+use actix_web::middleware::Next;
+use actix_web::{HttpRequest, HttpResponse, web};
+use dns_orchestrator_core::error::CoreError;
+
+use crate::state::AppState;
+use crate::types::ApiErrorResponse;
+
+/// 读取管理令牌的环境变量
+pub const ADMIN_TOKEN_ENV: &str = "DNS_ORCHESTRATOR_ADMIN_TOKEN";
+
+/// 从环境变量读取管理令牌（未设置或为空时不开放管理接口）
+pub fn admin_token_from_env() -> Option<String> {
+    std::env::var(ADMIN_TOKEN_ENV)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// 校验管理令牌的中间件
+pub async fn require_admin_token<B: MessageBody>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, actix_web::Error> {
+    let expected = req
+        .app_data::<web::Data<AppState>>()
+        .and_then(|state| state.admin_token.clone());
+    if let Err(response) = verify(expected.as_deref(), req.headers()) {
+        return Ok(req.into_response(response).map_into_right_body());
+    }
+
+    next.call(req)
+        .await
+        .map(ServiceResponse::map_into_left_body)
+}
+
+/// 校验 `/api/invoke` 中管理命令的令牌，规则同 [`require_admin_token`]
+pub fn check_admin_token(state: &AppState, req: &HttpRequest) -> Result<(), HttpResponse> {
+    verify(state.admin_token.as_deref(), req.headers())
+}
+
+fn verify(expected: Option<&str>, headers: &HeaderMap) -> Result<(), HttpResponse> {
+    let Some(expected) = expected else {
+        return Err(reject(
+            StatusCode::FORBIDDEN,
+            format!("Admin API is disabled, set {ADMIN_TOKEN_ENV} to enable it"),
+        ));
+    };
+
+    let provided = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+    if !provided.is_some_and(|provided| constant_time_eq(provided, expected)) {
+        return Err(reject(
+            StatusCode::UNAUTHORIZED,
+            "Invalid or missing admin token".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn reject(status: StatusCode, message: String) -> HttpResponse {
+    HttpResponse::build(status).json(ApiErrorResponse::from(CoreError::InvalidCredentials(
+        message,
+    )))
+}
+
+/// 比较令牌，耗时与第一个不同字节的位置无关
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::middleware::from_fn;
+    use actix_web::{App, test};
+
+    use super::*;
+
+    fn state(admin_token: Option<&str>) -> web::Data<AppState> {
+        let mut state = AppState::new(None);
+        state.admin_token = admin_token.map(str::to_string);
+        web::Data::new(state)
+    }
+
+    async fn status(state: web::Data<AppState>, authorization: Option<&str>) -> StatusCode {
+        let app = test::init_service(
+            App::new().app_data(state).service(
+                web::scope("/api/admin")
+                    .wrap(from_fn(require_admin_token))
+                    .route("/ping", web::post().to(HttpResponse::Ok)),
+            ),
+        )
+        .await;
+        let mut request = test::TestRequest::post().uri("/api/admin/ping");
+        if let Some(authorization) = authorization {
+            request = request.insert_header((AUTHORIZATION, authorization));
+        }
+        test::call_service(&app, request.to_request())
+            .await
+            .status()
+    }
+
+    #[actix_web::test]
+    async fn admin_routes_require_configured_token() {
+        assert_eq!(
+            status(state(None), Some("Bearer anything")).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status(state(Some("s3cret")), None).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(state(Some("s3cret")), Some("Bearer s3cre")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(state(Some("s3cret")), Some("Bearer s3cret")).await,
+            StatusCode::OK
+        );
+    }
+}
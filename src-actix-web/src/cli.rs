@@ -0,0 +1,157 @@
+//! 命令行子命令
+//!
+//! `dns-orchestrator-web changelog --since <时间> --until <时间> [选项]`：
+//! 从审计日志生成变更报告并输出到标准输出。时间为 RFC 3339 格式，统一按 UTC 处理。
+//! 需要设置 `DATABASE_URL`，否则没有可读取的审计日志。
+//!
+//! `dns-orchestrator-web usage --month <YYYY-MM> [--account <ID>]`：
+//! 导出某月每个账户每天各操作的服务商 API 调用次数（CSV）到标准输出。
+//! 同样需要设置 `DATABASE_URL`，统计由运行中的服务写入数据库。
+
+use chrono::{DateTime, Utc};
+use dns_orchestrator_core::types::{ChangelogFormat, ChangelogRequest, ChangelogSourceFilter};
+
+use crate::state::AppState;
+
+const CHANGELOG_USAGE: &str = "Usage: dns-orchestrator-web changelog --since <RFC3339> --until <RFC3339> \
+[--account <ID>] [--domain <ID>] [--format markdown|json] [--source all|manual|automated] [--max <N>]";
+
+const USAGE_USAGE: &str = "Usage: dns-orchestrator-web usage --month <YYYY-MM> [--account <ID>]";
+
+/// 解析 `changelog` 子命令参数
+fn parse_changelog_args(args: &[String]) -> Result<ChangelogRequest, String> {
+    let mut since = None;
+    let mut until = None;
+    let mut request = ChangelogRequest {
+        account_id: None,
+        domain_id: None,
+        since: DateTime::<Utc>::MIN_UTC,
+        until: DateTime::<Utc>::MIN_UTC,
+        format: ChangelogFormat::default(),
+        source: ChangelogSourceFilter::default(),
+        max_entries: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {flag}"))?;
+        match flag.as_str() {
+            "--since" => since = Some(parse_time(value)?),
+            "--until" => until = Some(parse_time(value)?),
+            "--account" => request.account_id = Some(value.clone()),
+            "--domain" => request.domain_id = Some(value.clone()),
+            "--format" => {
+                request.format = match value.as_str() {
+                    "markdown" | "md" => ChangelogFormat::Markdown,
+                    "json" => ChangelogFormat::Json,
+                    _ => return Err(format!("Unknown format: {value}")),
+                };
+            }
+            "--source" => {
+                request.source = match value.as_str() {
+                    "all" => ChangelogSourceFilter::All,
+                    "manual" => ChangelogSourceFilter::Manual,
+                    "automated" => ChangelogSourceFilter::Automated,
+                    _ => return Err(format!("Unknown source: {value}")),
+                };
+            }
+            "--max" => {
+                request.max_entries = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid --max value: {value}"))?,
+                );
+            }
+            _ => return Err(format!("Unknown option: {flag}")),
+        }
+    }
+
+    request.since = since.ok_or("--since is required")?;
+    request.until = until.ok_or("--until is required")?;
+    Ok(request)
+}
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid time {value}: {e}"))
+}
+
+/// 执行 `changelog` 子命令，报告写入标准输出
+pub async fn run_changelog(state: &AppState, args: &[String]) -> std::io::Result<()> {
+    let request = parse_changelog_args(args).map_err(|e| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{e}\n{CHANGELOG_USAGE}"),
+        )
+    })?;
+    if state.database.is_none() {
+        tracing::warn!("DATABASE_URL is not set; the changelog will be empty");
+    }
+
+    let output = state
+        .audit_log_service
+        .generate_changelog(request)
+        .await
+        .map_err(std::io::Error::other)?;
+    if output.truncated {
+        tracing::warn!(
+            "Changelog truncated: {} changes in range, raise --max or narrow the range",
+            output.total_changes
+        );
+    }
+    println!("{}", output.content);
+    Ok(())
+}
+
+/// `usage` 子命令参数
+struct UsageArgs {
+    month: String,
+    account_id: Option<String>,
+}
+
+/// 解析 `usage` 子命令参数
+fn parse_usage_args(args: &[String]) -> Result<UsageArgs, String> {
+    let mut month = None;
+    let mut account_id = None;
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("Missing value for {flag}"))?;
+        match flag.as_str() {
+            "--month" => month = Some(value.clone()),
+            "--account" => account_id = Some(value.clone()),
+            _ => return Err(format!("Unknown option: {flag}")),
+        }
+    }
+
+    Ok(UsageArgs {
+        month: month.ok_or("--month is required")?,
+        account_id,
+    })
+}
+
+/// 执行 `usage` 子命令，CSV 写入标准输出
+pub async fn run_usage(state: &AppState, args: &[String]) -> std::io::Result<()> {
+    let args = parse_usage_args(args).map_err(|e| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{e}\n{USAGE_USAGE}"),
+        )
+    })?;
+    if state.database.is_none() {
+        tracing::warn!("DATABASE_URL is not set; the usage export will be empty");
+    }
+
+    let csv = state
+        .api_usage_service
+        .export_csv(args.account_id.as_deref(), &args.month)
+        .await
+        .map_err(std::io::Error::other)?;
+    print!("{csv}");
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! 账户能力、连通性、凭证校验与 API 用量命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    AccountCapabilities, AccountValidation, AccountValidationSummary, ApiUsageReport,
+    ProviderHealthStatus,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountIdArgs {
+    pub account_id: String,
+}
+
+/// 获取账户的实际能力（服务商静态声明叠加探测修正）
+pub async fn get_account_capabilities(
+    state: &AppState,
+    args: AccountIdArgs,
+) -> CoreResult<ApiResponse<AccountCapabilities>> {
+    let capabilities = state
+        .account_capability_service
+        .get_capabilities(&args.account_id)
+        .await?;
+    Ok(ApiResponse::success(capabilities))
+}
+
+/// 重新探测账户的实际能力
+pub async fn refresh_account_capabilities(
+    state: &AppState,
+    args: AccountIdArgs,
+) -> CoreResult<ApiResponse<AccountCapabilities>> {
+    let capabilities = state
+        .account_capability_service
+        .refresh_capabilities(&args.account_id)
+        .await?;
+    Ok(ApiResponse::success(capabilities))
+}
+
+/// 检查账户服务商 API 的连通性（凭证是否有效、网络是否可达）
+pub async fn check_provider_health(
+    state: &AppState,
+    args: AccountIdArgs,
+) -> CoreResult<ApiResponse<ProviderHealthStatus>> {
+    let status = state
+        .provider_registry
+        .health_check(&args.account_id)
+        .await?;
+    Ok(ApiResponse::success(status))
+}
+
+/// 校验账户凭证，结果写入账户数据
+pub async fn validate_account(
+    state: &AppState,
+    args: AccountIdArgs,
+) -> CoreResult<ApiResponse<AccountValidation>> {
+    let validation = state
+        .account_validation_service
+        .validate_account(&args.account_id)
+        .await?;
+    Ok(ApiResponse::success(validation))
+}
+
+/// 并发校验全部账户的凭证
+pub async fn validate_all_accounts(
+    state: &AppState,
+) -> CoreResult<ApiResponse<AccountValidationSummary>> {
+    let summary = state
+        .account_validation_service
+        .validate_all_accounts()
+        .await?;
+    Ok(ApiResponse::success(summary))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageReportArgs {
+    account_id: String,
+    /// `YYYY-MM`
+    month: String,
+}
+
+/// 获取账户某月的服务商 API 用量报告
+pub async fn get_api_usage_report(
+    state: &AppState,
+    args: ApiUsageReportArgs,
+) -> CoreResult<ApiResponse<ApiUsageReport>> {
+    let report = state
+        .api_usage_service
+        .get_usage_report(&args.account_id, &args.month)
+        .await?;
+    Ok(ApiResponse::success(report))
+}
@@ -0,0 +1,48 @@
+//! 审计日志命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    AuditEntry, AuditLogFilter, ChangelogOutput, ChangelogRequest, PaginatedResponse,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAuditLogArgs {
+    #[serde(default)]
+    filter: AuditLogFilter,
+    page: Option<u32>,
+    page_size: Option<u32>,
+}
+
+/// 分页查询记录变更审计日志（按时间倒序）
+pub async fn list_audit_log(
+    state: &AppState,
+    args: ListAuditLogArgs,
+) -> CoreResult<ApiResponse<PaginatedResponse<AuditEntry>>> {
+    let result = state
+        .audit_log_service
+        .list_audit_log(args.filter, args.page, args.page_size)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenerateChangelogArgs {
+    request: ChangelogRequest,
+}
+
+/// 生成指定时间范围内的变更报告
+pub async fn generate_changelog(
+    state: &AppState,
+    args: GenerateChangelogArgs,
+) -> CoreResult<ApiResponse<ChangelogOutput>> {
+    let result = state
+        .audit_log_service
+        .generate_changelog(args.request)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
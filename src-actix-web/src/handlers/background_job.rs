@@ -0,0 +1,24 @@
+//! 后台任务命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::BackgroundJobInfo;
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+/// 列出所有后台任务状态
+pub async fn list_background_jobs(state: &AppState) -> ApiResponse<Vec<BackgroundJobInfo>> {
+    ApiResponse::success(state.background_job_runner.list_background_jobs().await)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TriggerJobArgs {
+    name: String,
+}
+
+/// 手动触发后台任务（异步执行，立即返回）
+pub async fn trigger_job(state: &AppState, args: TriggerJobArgs) -> CoreResult<ApiResponse<()>> {
+    state.background_job_runner.trigger_job(&args.name).await?;
+    Ok(ApiResponse::success(()))
+}
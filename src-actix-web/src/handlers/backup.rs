@@ -0,0 +1,133 @@
+//! 账户导入导出与完整备份恢复命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    ExportAccountsRequest, ExportAccountsResponse, ExportDiagnosticsRequest,
+    ExportDiagnosticsResponse, ImportAccountsRequest, ImportPreview, ImportResult,
+    RestoreBackupResult,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportAccountsArgs {
+    request: ExportAccountsRequest,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewImportArgs {
+    content: String,
+    password: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportAccountsArgs {
+    request: ImportAccountsRequest,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportDiagnosticsArgs {
+    request: ExportDiagnosticsRequest,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportBackupArgs {
+    password: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportBackupArgs {
+    content: String,
+    password: String,
+}
+
+/// 导出选中的账户（可用密码加密）
+pub async fn export_accounts(
+    state: &AppState,
+    args: ExportAccountsArgs,
+) -> CoreResult<ApiResponse<ExportAccountsResponse>> {
+    let response = state
+        .import_export_service
+        .export_accounts(args.request, env!("CARGO_PKG_VERSION"))
+        .await?;
+    Ok(ApiResponse::success(response))
+}
+
+/// 预览导入文件（加密文件未提供密码时只返回文件头）
+pub async fn preview_import(
+    state: &AppState,
+    args: PreviewImportArgs,
+) -> CoreResult<ApiResponse<ImportPreview>> {
+    let preview = state
+        .import_export_service
+        .preview_import(&args.content, args.password.as_deref())
+        .await?;
+    Ok(ApiResponse::success(preview))
+}
+
+/// 导入账户（与已有账户重复的跳过）
+pub async fn import_accounts(
+    state: &AppState,
+    args: ImportAccountsArgs,
+) -> CoreResult<ApiResponse<ImportResult>> {
+    let result = state
+        .import_export_service
+        .import_accounts(args.request)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
+
+/// 导出诊断数据（不含凭证，可匿名化）
+pub async fn export_diagnostics(
+    state: &AppState,
+    args: ExportDiagnosticsArgs,
+) -> CoreResult<ApiResponse<ExportDiagnosticsResponse>> {
+    let response = state
+        .import_export_service
+        .export_diagnostics(
+            args.request,
+            env!("CARGO_PKG_VERSION"),
+            Some(state.toolbox_service.network_env_info().await),
+        )
+        .await?;
+    Ok(ApiResponse::success(response))
+}
+
+/// 生成完整备份文件（全部账户、凭证与域名元数据，使用密码加密）
+pub async fn backup_file(
+    state: &AppState,
+    args: ExportBackupArgs,
+) -> CoreResult<ExportAccountsResponse> {
+    state
+        .import_export_service
+        .export_backup(&args.password, env!("CARGO_PKG_VERSION"))
+        .await
+}
+
+/// 导出完整备份
+pub async fn export_backup(
+    state: &AppState,
+    args: ExportBackupArgs,
+) -> CoreResult<ApiResponse<ExportAccountsResponse>> {
+    Ok(ApiResponse::success(backup_file(state, args).await?))
+}
+
+/// 从完整备份恢复（按账户 ID 覆盖，可重复执行）
+pub async fn import_backup(
+    state: &AppState,
+    args: ImportBackupArgs,
+) -> CoreResult<ApiResponse<RestoreBackupResult>> {
+    let result = state
+        .import_export_service
+        .import_backup(&args.content, &args.password)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
@@ -0,0 +1,121 @@
+//! 变更窗口命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    ChangeWindowOverride, ChangeWindowRule, ClosedPeriod, CreateChangeWindowRuleRequest,
+    UpdateChangeWindowRuleRequest,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+/// 列出所有禁止变更规则
+pub async fn list_change_window_rules(
+    state: &AppState,
+) -> CoreResult<ApiResponse<Vec<ChangeWindowRule>>> {
+    let rules = state.change_window_service.list_rules().await?;
+    Ok(ApiResponse::success(rules))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateChangeWindowRuleArgs {
+    request: CreateChangeWindowRuleRequest,
+}
+
+/// 创建禁止变更规则
+pub async fn create_change_window_rule(
+    state: &AppState,
+    args: CreateChangeWindowRuleArgs,
+) -> CoreResult<ApiResponse<ChangeWindowRule>> {
+    let rule = state
+        .change_window_service
+        .create_rule(args.request)
+        .await?;
+    Ok(ApiResponse::success(rule))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateChangeWindowRuleArgs {
+    request: UpdateChangeWindowRuleRequest,
+}
+
+/// 更新禁止变更规则
+pub async fn update_change_window_rule(
+    state: &AppState,
+    args: UpdateChangeWindowRuleArgs,
+) -> CoreResult<ApiResponse<ChangeWindowRule>> {
+    let rule = state
+        .change_window_service
+        .update_rule(args.request)
+        .await?;
+    Ok(ApiResponse::success(rule))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleIdArgs {
+    rule_id: String,
+}
+
+/// 删除禁止变更规则
+pub async fn delete_change_window_rule(
+    state: &AppState,
+    args: RuleIdArgs,
+) -> CoreResult<ApiResponse<()>> {
+    state
+        .change_window_service
+        .delete_rule(&args.rule_id)
+        .await?;
+    Ok(ApiResponse::success(()))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpcomingClosedPeriodsArgs {
+    days: u32,
+}
+
+/// 列出未来若干天内的禁止变更时段（日历展示）
+pub async fn list_upcoming_closed_periods(
+    state: &AppState,
+    args: UpcomingClosedPeriodsArgs,
+) -> CoreResult<ApiResponse<Vec<ClosedPeriod>>> {
+    let periods = state
+        .change_window_service
+        .list_upcoming_closed_periods(args.days)
+        .await?;
+    Ok(ApiResponse::success(periods))
+}
+
+/// 获取当前生效的强制变更
+pub async fn get_change_window_override(
+    state: &AppState,
+) -> CoreResult<ApiResponse<Option<ChangeWindowOverride>>> {
+    let active = state.change_window_service.current_override().await;
+    Ok(ApiResponse::success(active))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartOverrideArgs {
+    reason: String,
+    duration_minutes: u32,
+}
+
+/// 开启限时强制变更
+pub async fn start_change_window_override(
+    state: &AppState,
+    args: StartOverrideArgs,
+) -> CoreResult<ApiResponse<ChangeWindowOverride>> {
+    let active = state
+        .change_window_service
+        .start_override(&args.reason, args.duration_minutes)
+        .await?;
+    Ok(ApiResponse::success(active))
+}
+
+/// 提前结束强制变更
+pub async fn clear_change_window_override(state: &AppState) -> CoreResult<ApiResponse<()>> {
+    state.change_window_service.clear_override().await;
+    Ok(ApiResponse::success(()))
+}
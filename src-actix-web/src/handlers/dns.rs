@@ -0,0 +1,335 @@
+//! DNS 记录命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    BatchCreateRequest, BatchCreateResult, BatchUpdateRequest, BatchUpdateResult,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainDiffResult, RecordChangeEntry,
+    RecordCharacterWarning, RecordCopyRequest, RecordCopyResult, RecordSetDetail,
+    RecordValidationReport, ReplaceRecordValueRequest, UpdateRecordSetRequest,
+    UpdateRecordSetResult, ZoneFileImportResult,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAllDnsRecordsArgs {
+    account_id: String,
+    domain_id: String,
+}
+
+/// 拉取域名下的全部 DNS 记录（HTTP 模式不推送进度事件）
+pub async fn list_all_dns_records(
+    state: &AppState,
+    args: ListAllDnsRecordsArgs,
+) -> CoreResult<ApiResponse<Vec<DnsRecord>>> {
+    let records = state
+        .dns_service
+        .list_all_records(&args.account_id, &args.domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(records))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateDnsRecordArgs {
+    account_id: String,
+    request: CreateDnsRecordRequest,
+    record_id: Option<String>,
+}
+
+/// 校验 DNS 记录（不写入），`recordId` 为正在编辑的记录
+pub async fn validate_dns_record(
+    state: &AppState,
+    args: ValidateDnsRecordArgs,
+) -> CoreResult<ApiResponse<RecordValidationReport>> {
+    let report = state
+        .dns_service
+        .validate_record(&args.account_id, &args.request, args.record_id.as_deref())
+        .await?;
+
+    Ok(ApiResponse::success(report))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateDnsRecordsArgs {
+    account_id: String,
+    request: BatchCreateRequest,
+}
+
+/// 批量创建 DNS 记录
+pub async fn batch_create_dns_records(
+    state: &AppState,
+    args: BatchCreateDnsRecordsArgs,
+) -> CoreResult<ApiResponse<BatchCreateResult>> {
+    let result = state
+        .dns_service
+        .batch_create_records(&args.account_id, args.request)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdateDnsRecordsArgs {
+    account_id: String,
+    request: BatchUpdateRequest,
+}
+
+/// 批量更新 DNS 记录
+pub async fn batch_update_dns_records(
+    state: &AppState,
+    args: BatchUpdateDnsRecordsArgs,
+) -> CoreResult<ApiResponse<BatchUpdateResult>> {
+    let result = state
+        .dns_service
+        .batch_update_records(&args.account_id, args.request)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceDnsRecordValueArgs {
+    account_id: String,
+    request: ReplaceRecordValueRequest,
+}
+
+/// 按值批量替换 DNS 记录
+pub async fn replace_dns_record_value(
+    state: &AppState,
+    args: ReplaceDnsRecordValueArgs,
+) -> CoreResult<ApiResponse<BatchUpdateResult>> {
+    let result = state
+        .dns_service
+        .replace_record_value(&args.account_id, args.request)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDnsRecordSetArgs {
+    account_id: String,
+    domain_id: String,
+    name: String,
+    record_type: DnsRecordType,
+}
+
+/// 获取同名同类型的一组记录
+pub async fn get_dns_record_set(
+    state: &AppState,
+    args: GetDnsRecordSetArgs,
+) -> CoreResult<ApiResponse<RecordSetDetail>> {
+    let set = state
+        .dns_service
+        .get_record_set(
+            &args.account_id,
+            &args.domain_id,
+            &args.name,
+            args.record_type,
+        )
+        .await?;
+
+    Ok(ApiResponse::success(set))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDnsRecordSetArgs {
+    account_id: String,
+    request: UpdateRecordSetRequest,
+}
+
+/// 将一组记录整体更新为期望的值集合
+pub async fn update_dns_record_set(
+    state: &AppState,
+    args: UpdateDnsRecordSetArgs,
+) -> CoreResult<ApiResponse<UpdateRecordSetResult>> {
+    let result = state
+        .dns_service
+        .update_record_set(&args.account_id, args.request)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportZoneFileArgs {
+    account_id: String,
+    domain_id: String,
+}
+
+/// 导出 BIND zone 文件
+pub async fn export_zone_file(
+    state: &AppState,
+    args: ExportZoneFileArgs,
+) -> CoreResult<ApiResponse<String>> {
+    let zone = state
+        .dns_service
+        .export_zone_file(&args.account_id, &args.domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(zone))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportZoneFileArgs {
+    account_id: String,
+    domain_id: String,
+    zone_text: String,
+    dry_run: bool,
+    #[serde(default)]
+    normalize: bool,
+}
+
+/// 从 BIND zone 文件导入记录（`dry_run` 时只返回预览）
+pub async fn import_zone_file(
+    state: &AppState,
+    args: ImportZoneFileArgs,
+) -> CoreResult<ApiResponse<ZoneFileImportResult>> {
+    let result = state
+        .dns_service
+        .import_zone_file(
+            &args.account_id,
+            &args.domain_id,
+            &args.zone_text,
+            args.dry_run,
+            args.normalize,
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyDnsRecordsArgs {
+    request: RecordCopyRequest,
+}
+
+/// 复制域名记录到另一个账户的域名（`options.dryRun` 时只返回计划）
+pub async fn copy_dns_records(
+    state: &AppState,
+    args: CopyDnsRecordsArgs,
+) -> CoreResult<ApiResponse<RecordCopyResult>> {
+    let request = args.request;
+    let result = state
+        .record_copy_service
+        .copy_records(
+            &request.source_account_id,
+            &request.source_domain_id,
+            &request.target_account_id,
+            &request.target_domain_id,
+            request.options,
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffDomainsArgs {
+    account_a: String,
+    domain_a: String,
+    account_b: String,
+    domain_b: String,
+}
+
+/// 对比两个域名（可跨账户）的记录
+pub async fn diff_domains(
+    state: &AppState,
+    args: DiffDomainsArgs,
+) -> CoreResult<ApiResponse<DomainDiffResult>> {
+    let result = state
+        .dns_service
+        .diff_domains(
+            &args.account_a,
+            &args.domain_a,
+            &args.account_b,
+            &args.domain_b,
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanRecordCharactersArgs {
+    account_id: String,
+    domain_id: String,
+}
+
+/// 扫描现有记录中的不可见字符与同形字符
+pub async fn scan_record_characters(
+    state: &AppState,
+    args: ScanRecordCharactersArgs,
+) -> CoreResult<ApiResponse<Vec<RecordCharacterWarning>>> {
+    let warnings = state
+        .dns_service
+        .scan_record_characters(&args.account_id, &args.domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(warnings))
+}
+
+/// 单条记录变更历史的默认条数
+const DEFAULT_RECORD_HISTORY_LIMIT: u32 = 50;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRecordHistoryArgs {
+    account_id: String,
+    domain_id: String,
+    record_id: String,
+    limit: Option<u32>,
+}
+
+/// 查询单条记录的变更历史
+pub async fn get_record_history(
+    state: &AppState,
+    args: GetRecordHistoryArgs,
+) -> CoreResult<ApiResponse<Vec<RecordChangeEntry>>> {
+    let history = state
+        .dns_service
+        .get_record_history(
+            &args.account_id,
+            &args.domain_id,
+            &args.record_id,
+            args.limit.unwrap_or(DEFAULT_RECORD_HISTORY_LIMIT),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(history))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreRecordArgs {
+    account_id: String,
+    change_id: String,
+}
+
+/// 将记录恢复到某次变更之前的状态
+pub async fn restore_record(
+    state: &AppState,
+    args: RestoreRecordArgs,
+) -> CoreResult<ApiResponse<DnsRecord>> {
+    let record = state
+        .dns_service
+        .restore_record(&args.account_id, &args.change_id)
+        .await?;
+
+    Ok(ApiResponse::success(record))
+}
@@ -0,0 +1,119 @@
+//! 域名组命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    CreateDomainGroupRequest, DomainGroup, DomainGroupChangeset, DomainGroupComparison,
+    DomainGroupMember, DomainGroupSyncResult, UpdateDomainGroupRequest,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+/// 列出所有域名组
+pub async fn list_domain_groups(state: &AppState) -> CoreResult<ApiResponse<Vec<DomainGroup>>> {
+    let groups = state.domain_group_service.list_groups().await?;
+    Ok(ApiResponse::success(groups))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateDomainGroupArgs {
+    request: CreateDomainGroupRequest,
+}
+
+/// 创建域名组
+pub async fn create_domain_group(
+    state: &AppState,
+    args: CreateDomainGroupArgs,
+) -> CoreResult<ApiResponse<DomainGroup>> {
+    let group = state
+        .domain_group_service
+        .create_group(args.request)
+        .await?;
+    Ok(ApiResponse::success(group))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateDomainGroupArgs {
+    request: UpdateDomainGroupRequest,
+}
+
+/// 更新域名组
+pub async fn update_domain_group(
+    state: &AppState,
+    args: UpdateDomainGroupArgs,
+) -> CoreResult<ApiResponse<DomainGroup>> {
+    let group = state
+        .domain_group_service
+        .update_group(args.request)
+        .await?;
+    Ok(ApiResponse::success(group))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupIdArgs {
+    group_id: String,
+}
+
+/// 删除域名组
+pub async fn delete_domain_group(
+    state: &AppState,
+    args: GroupIdArgs,
+) -> CoreResult<ApiResponse<()>> {
+    state
+        .domain_group_service
+        .delete_group(&args.group_id)
+        .await?;
+    Ok(ApiResponse::success(()))
+}
+
+/// 并排比较域名组各成员的记录
+pub async fn get_group_records_comparison(
+    state: &AppState,
+    args: GroupIdArgs,
+) -> CoreResult<ApiResponse<DomainGroupComparison>> {
+    let comparison = state
+        .domain_group_service
+        .get_group_records_comparison(&args.group_id)
+        .await?;
+    Ok(ApiResponse::success(comparison))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewGroupSyncArgs {
+    group_id: String,
+    source: DomainGroupMember,
+}
+
+/// 以某一成员为准生成同步变更集（不执行）
+pub async fn preview_group_sync(
+    state: &AppState,
+    args: PreviewGroupSyncArgs,
+) -> CoreResult<ApiResponse<Vec<DomainGroupChangeset>>> {
+    let changesets = state
+        .domain_group_service
+        .preview_group_sync(&args.group_id, &args.source)
+        .await?;
+    Ok(ApiResponse::success(changesets))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyGroupSyncArgs {
+    group_id: String,
+    changesets: Vec<DomainGroupChangeset>,
+}
+
+/// 执行确认后的同步变更集
+pub async fn apply_group_sync(
+    state: &AppState,
+    args: ApplyGroupSyncArgs,
+) -> CoreResult<ApiResponse<DomainGroupSyncResult>> {
+    let result = state
+        .domain_group_service
+        .apply_group_sync(&args.group_id, args.changesets)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
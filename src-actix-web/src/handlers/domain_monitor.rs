@@ -0,0 +1,58 @@
+//! 域名到期监控命令
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::types::{
+    CertificateReport, DomainExpiryEntry, DomainExpiryReport, DomainExpiryThresholds,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct ExpiryThresholdsArgs {
+    #[serde(default)]
+    thresholds: Option<DomainExpiryThresholds>,
+}
+
+/// 检查所有账户下域名的注册到期时间
+pub async fn check_domain_expiry(
+    state: &AppState,
+    args: ExpiryThresholdsArgs,
+) -> CoreResult<ApiResponse<DomainExpiryReport>> {
+    let report = state
+        .domain_monitor_service
+        .check_domain_expiry(args.thresholds)
+        .await?;
+    Ok(ApiResponse::success(report))
+}
+
+/// 列出最近一次到期检查中需要关注的域名
+pub async fn list_expiry_warnings(
+    state: &AppState,
+    args: ExpiryThresholdsArgs,
+) -> CoreResult<ApiResponse<Vec<DomainExpiryEntry>>> {
+    let warnings = state
+        .domain_monitor_service
+        .list_expiry_warnings(args.thresholds)
+        .await?;
+    Ok(ApiResponse::success(warnings))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateReportArgs {
+    account_id: String,
+}
+
+/// 检查账户下域名（根域名与 `www`）的 SSL 证书
+pub async fn get_certificate_report(
+    state: &AppState,
+    args: CertificateReportArgs,
+) -> CoreResult<ApiResponse<CertificateReport>> {
+    let report = state
+        .domain_monitor_service
+        .certificate_report(&args.account_id)
+        .await?;
+    Ok(ApiResponse::success(report))
+}
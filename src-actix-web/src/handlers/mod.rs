@@ -0,0 +1,627 @@
+//! 命令分发
+//!
+//! 命令名与 Tauri 端的 `#[tauri::command]` 保持一致，参数同样使用 camelCase。
+
+mod account;
+mod audit_log;
+mod background_job;
+mod backup;
+mod change_window;
+mod dns;
+mod domain_group;
+mod domain_monitor;
+mod system;
+mod toolbox;
+
+use actix_web::http::header::ContentDisposition;
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::types::{ExportAccountsRequest, ImportAccountsRequest};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::auth;
+use crate::state::AppState;
+use crate::types::{ApiErrorResponse, ApiResponse};
+
+/// `POST /api/invoke` 请求体
+#[derive(Debug, Deserialize)]
+pub struct InvokeRequest {
+    command: String,
+    #[serde(default)]
+    args: Value,
+}
+
+/// 需要管理令牌的命令，与 `/api/admin/*` 下的接口对应
+const ADMIN_COMMANDS: &[&str] = &[
+    "export_backup",
+    "import_backup",
+    "export_accounts",
+    "import_accounts",
+];
+
+/// 按命令名分发请求
+pub async fn invoke(
+    state: web::Data<AppState>,
+    http_request: HttpRequest,
+    request: web::Json<InvokeRequest>,
+) -> HttpResponse {
+    let InvokeRequest { command, args } = request.into_inner();
+    if ADMIN_COMMANDS.contains(&command.as_str())
+        && let Err(response) = auth::check_admin_token(&state, &http_request)
+    {
+        return response;
+    }
+    dispatch(&state, &command, args)
+        .await
+        .unwrap_or_else(|response| response)
+}
+
+/// 命令表，每个分支只做参数解析和转发
+#[allow(clippy::too_many_lines)]
+async fn dispatch(
+    state: &AppState,
+    command: &str,
+    args: Value,
+) -> Result<HttpResponse, HttpResponse> {
+    match command {
+        "get_account_capabilities" => Ok(respond(
+            account::get_account_capabilities(state, parse(args)?).await,
+        )),
+        "refresh_account_capabilities" => Ok(respond(
+            account::refresh_account_capabilities(state, parse(args)?).await,
+        )),
+        "check_provider_health" => Ok(respond(
+            account::check_provider_health(state, parse(args)?).await,
+        )),
+        "validate_account" => Ok(respond(
+            account::validate_account(state, parse(args)?).await,
+        )),
+        "validate_all_accounts" => Ok(respond(account::validate_all_accounts(state).await)),
+        "get_api_usage_report" => Ok(respond(
+            account::get_api_usage_report(state, parse(args)?).await,
+        )),
+        "export_accounts" => Ok(respond(backup::export_accounts(state, parse(args)?).await)),
+        "preview_import" => Ok(respond(backup::preview_import(state, parse(args)?).await)),
+        "import_accounts" => Ok(respond(backup::import_accounts(state, parse(args)?).await)),
+        "export_diagnostics" => Ok(respond(
+            backup::export_diagnostics(state, parse(args)?).await,
+        )),
+        "export_backup" => Ok(respond(backup::export_backup(state, parse(args)?).await)),
+        "import_backup" => Ok(respond(backup::import_backup(state, parse(args)?).await)),
+        "list_all_dns_records" => Ok(respond(
+            dns::list_all_dns_records(state, parse(args)?).await,
+        )),
+        "validate_dns_record" => Ok(respond(dns::validate_dns_record(state, parse(args)?).await)),
+        "batch_create_dns_records" => Ok(respond(
+            dns::batch_create_dns_records(state, parse(args)?).await,
+        )),
+        "batch_update_dns_records" => Ok(respond(
+            dns::batch_update_dns_records(state, parse(args)?).await,
+        )),
+        "replace_dns_record_value" => Ok(respond(
+            dns::replace_dns_record_value(state, parse(args)?).await,
+        )),
+        "get_dns_record_set" => Ok(respond(dns::get_dns_record_set(state, parse(args)?).await)),
+        "update_dns_record_set" => Ok(respond(
+            dns::update_dns_record_set(state, parse(args)?).await,
+        )),
+        "export_zone_file" => Ok(respond(dns::export_zone_file(state, parse(args)?).await)),
+        "import_zone_file" => Ok(respond(dns::import_zone_file(state, parse(args)?).await)),
+        "copy_dns_records" => Ok(respond(dns::copy_dns_records(state, parse(args)?).await)),
+        "diff_domains" => Ok(respond(dns::diff_domains(state, parse(args)?).await)),
+        "scan_record_characters" => Ok(respond(
+            dns::scan_record_characters(state, parse(args)?).await,
+        )),
+        "get_record_history" => Ok(respond(dns::get_record_history(state, parse(args)?).await)),
+        "restore_record" => Ok(respond(dns::restore_record(state, parse(args)?).await)),
+        "list_domain_groups" => Ok(respond(domain_group::list_domain_groups(state).await)),
+        "create_domain_group" => Ok(respond(
+            domain_group::create_domain_group(state, parse(args)?).await,
+        )),
+        "update_domain_group" => Ok(respond(
+            domain_group::update_domain_group(state, parse(args)?).await,
+        )),
+        "delete_domain_group" => Ok(respond(
+            domain_group::delete_domain_group(state, parse(args)?).await,
+        )),
+        "get_group_records_comparison" => Ok(respond(
+            domain_group::get_group_records_comparison(state, parse(args)?).await,
+        )),
+        "preview_group_sync" => Ok(respond(
+            domain_group::preview_group_sync(state, parse(args)?).await,
+        )),
+        "apply_group_sync" => Ok(respond(
+            domain_group::apply_group_sync(state, parse(args)?).await,
+        )),
+        "check_domain_expiry" => Ok(respond(
+            domain_monitor::check_domain_expiry(state, parse(args)?).await,
+        )),
+        "list_expiry_warnings" => Ok(respond(
+            domain_monitor::list_expiry_warnings(state, parse(args)?).await,
+        )),
+        "get_certificate_report" => Ok(respond(
+            domain_monitor::get_certificate_report(state, parse(args)?).await,
+        )),
+        "list_audit_log" => Ok(respond(
+            audit_log::list_audit_log(state, parse(args)?).await,
+        )),
+        "generate_changelog" => Ok(respond(
+            audit_log::generate_changelog(state, parse(args)?).await,
+        )),
+        "list_change_window_rules" => Ok(respond(
+            change_window::list_change_window_rules(state).await,
+        )),
+        "create_change_window_rule" => Ok(respond(
+            change_window::create_change_window_rule(state, parse(args)?).await,
+        )),
+        "update_change_window_rule" => Ok(respond(
+            change_window::update_change_window_rule(state, parse(args)?).await,
+        )),
+        "delete_change_window_rule" => Ok(respond(
+            change_window::delete_change_window_rule(state, parse(args)?).await,
+        )),
+        "list_upcoming_closed_periods" => Ok(respond(
+            change_window::list_upcoming_closed_periods(state, parse(args)?).await,
+        )),
+        "get_change_window_override" => Ok(respond(
+            change_window::get_change_window_override(state).await,
+        )),
+        "start_change_window_override" => Ok(respond(
+            change_window::start_change_window_override(state, parse(args)?).await,
+        )),
+        "clear_change_window_override" => Ok(respond(
+            change_window::clear_change_window_override(state).await,
+        )),
+        "get_database_info" => Ok(respond(system::get_database_info(state).await)),
+        "list_background_jobs" => Ok(respond(Ok(
+            background_job::list_background_jobs(state).await
+        ))),
+        "trigger_job" => Ok(respond(
+            background_job::trigger_job(state, parse(args)?).await,
+        )),
+        "get_privacy_config" => Ok(respond(Ok(toolbox::get_privacy_config(state)))),
+        "set_privacy_config" => Ok(respond(Ok(toolbox::set_privacy_config(
+            state,
+            &parse(args)?,
+        )))),
+        "list_external_endpoints" => Ok(respond(toolbox::list_external_endpoints(state).await)),
+        "dns_propagation_check" => Ok(respond(
+            toolbox::dns_propagation_check(state, parse(args)?).await,
+        )),
+        "propagation_check" => Ok(respond(
+            toolbox::propagation_check(state, parse(args)?).await,
+        )),
+        "dns_lookup" => Ok(respond(toolbox::dns_lookup(parse(args)?).await)),
+        "dnssec_check" => Ok(respond_dnssec(toolbox::dnssec_check(parse(args)?).await)),
+        "reverse_lookup" => Ok(respond(toolbox::reverse_lookup(parse(args)?).await)),
+        "ip_lookup_batch" => Ok(respond(toolbox::ip_lookup_batch(state, parse(args)?).await)),
+        "email_security_check" => Ok(respond(toolbox::email_security_check(parse(args)?).await)),
+        "mail_check" => Ok(respond(toolbox::mail_check(parse(args)?).await)),
+        "caa_check" => Ok(respond(toolbox::caa_check(parse(args)?).await)),
+        "get_system_dns" => Ok(respond(Ok(toolbox::get_system_dns()))),
+        "network_env_info" => Ok(respond(Ok(toolbox::network_env_info(state).await))),
+        "ssl_check" => Ok(respond(toolbox::ssl_check(parse(args)?).await)),
+        "ssl_check_batch" => Ok(respond(toolbox::ssl_check_batch(parse(args)?).await)),
+        "http_header_check" => Ok(respond(toolbox::http_header_check(parse(args)?).await)),
+        "http_trace" => Ok(respond(toolbox::http_trace(parse(args)?).await)),
+        "port_check" => Ok(respond(toolbox::port_check(parse(args)?).await)),
+        "trace_route" => Ok(respond(toolbox::trace_route(parse(args)?).await)),
+        _ => Err(error_response(
+            StatusCode::NOT_FOUND,
+            CoreError::ValidationError(format!("Unknown command: {command}")),
+        )),
+    }
+}
+
+/// `GET /api/toolbox/email-security?domain=...&dkimSelector=...`
+pub async fn email_security(query: web::Query<toolbox::EmailSecurityCheckArgs>) -> HttpResponse {
+    respond(toolbox::email_security_check(query.into_inner()).await)
+}
+
+/// `GET /api/toolbox/ssl-check?domain=...&port=...&connectAddr=...&probeVersions=...`
+pub async fn ssl_check(query: web::Query<toolbox::SslCheckArgs>) -> HttpResponse {
+    respond(toolbox::ssl_check(query.into_inner()).await)
+}
+
+/// `GET /api/toolbox/dnssec?domain=...&nameserver=...`
+pub async fn dnssec(query: web::Query<toolbox::DnssecCheckArgs>) -> HttpResponse {
+    respond_dnssec(toolbox::dnssec_check(query.into_inner()).await)
+}
+
+/// `GET /api/toolbox/http-trace?url=...&maxRedirects=...`
+pub async fn http_trace(query: web::Query<toolbox::HttpTraceArgs>) -> HttpResponse {
+    respond(toolbox::http_trace(query.into_inner()).await)
+}
+
+/// `POST /api/toolbox/port-check`，请求体为 `{ host, ports, protocol?, timeoutMs? }`
+pub async fn port_check(body: web::Json<toolbox::PortCheckArgs>) -> HttpResponse {
+    respond(toolbox::port_check(body.into_inner()).await)
+}
+
+/// `GET /api/accounts/{id}/health`
+pub async fn account_health(state: web::Data<AppState>, path: web::Path<String>) -> HttpResponse {
+    let args = account::AccountIdArgs {
+        account_id: path.into_inner(),
+    };
+    respond(account::check_provider_health(&state, args).await)
+}
+
+/// `POST /api/domains/diff`，请求体为 `{ accountA, domainA, accountB, domainB }`
+pub async fn diff_domains(
+    state: web::Data<AppState>,
+    body: web::Json<dns::DiffDomainsArgs>,
+) -> HttpResponse {
+    respond(dns::diff_domains(&state, body.into_inner()).await)
+}
+
+/// `POST /api/admin/accounts/export`，请求体为 `{ accountIds, encrypt, password }`，返回导出文件
+pub async fn admin_export_accounts(
+    state: web::Data<AppState>,
+    body: web::Json<ExportAccountsRequest>,
+) -> HttpResponse {
+    match state
+        .import_export_service
+        .export_accounts(body.into_inner(), env!("CARGO_PKG_VERSION"))
+        .await
+    {
+        Ok(export) => HttpResponse::Ok()
+            .content_type("application/json")
+            .insert_header(ContentDisposition::attachment(export.suggested_filename))
+            .body(export.content),
+        Err(e) => error_response(error_status(&e), e),
+    }
+}
+
+/// `POST /api/admin/accounts/import`，请求体为 `{ content, password }`，与已有账户重复的跳过
+pub async fn admin_import_accounts(
+    state: web::Data<AppState>,
+    body: web::Json<ImportAccountsRequest>,
+) -> HttpResponse {
+    let result = state
+        .import_export_service
+        .import_accounts(body.into_inner())
+        .await;
+    respond(result.map(ApiResponse::success))
+}
+
+/// `POST /api/admin/backup`，请求体为 `{ password }`，返回加密的备份文件
+pub async fn admin_backup(
+    state: web::Data<AppState>,
+    body: web::Json<backup::ExportBackupArgs>,
+) -> HttpResponse {
+    match backup::backup_file(&state, body.into_inner()).await {
+        Ok(backup) => HttpResponse::Ok()
+            .content_type("application/octet-stream")
+            .insert_header(ContentDisposition::attachment(backup.suggested_filename))
+            .body(backup.content),
+        Err(e) => error_response(error_status(&e), e),
+    }
+}
+
+/// `POST /api/admin/restore`，请求体为 `{ content, password }`，`content` 为备份文件内容
+pub async fn admin_restore(
+    state: web::Data<AppState>,
+    body: web::Json<backup::ImportBackupArgs>,
+) -> HttpResponse {
+    respond(backup::import_backup(&state, body.into_inner()).await)
+}
+
+/// 解析命令参数
+fn parse<T: DeserializeOwned>(args: Value) -> Result<T, HttpResponse> {
+    serde_json::from_value(args).map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            CoreError::ValidationError(format!("Invalid arguments: {e}")),
+        )
+    })
+}
+
+fn respond<T: serde::Serialize>(result: CoreResult<ApiResponse<T>>) -> HttpResponse {
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => error_response(error_status(&e), e),
+    }
+}
+
+/// DNSSEC 检查的响应：域名不存在为 404，参数错误为 400，其余（解析器出错）为 500
+fn respond_dnssec<T: serde::Serialize>(result: CoreResult<ApiResponse<T>>) -> HttpResponse {
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => {
+            let status = match e {
+                CoreError::DomainNotFound(_) => StatusCode::NOT_FOUND,
+                CoreError::ValidationError(_) => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            error_response(status, e)
+        }
+    }
+}
+
+/// 错误对应的 HTTP 状态码：访问外部主机失败归为网关错误，写锁被占用为冲突，
+/// 服务器缺少所需权限为未实现，隐私模式拦截的外发为禁止，服务商熔断中为暂不可用，
+/// 其余视为请求错误
+fn error_status(error: &CoreError) -> StatusCode {
+    match error {
+        CoreError::DomainNotFound(_) => StatusCode::NOT_FOUND,
+        CoreError::NetworkError(_) => StatusCode::BAD_GATEWAY,
+        CoreError::Timeout { .. } => StatusCode::GATEWAY_TIMEOUT,
+        CoreError::ResourceBusy { .. } => StatusCode::CONFLICT,
+        CoreError::CapabilityUnavailable { .. } => StatusCode::NOT_IMPLEMENTED,
+        CoreError::ExternalAccessBlocked { .. } => StatusCode::FORBIDDEN,
+        CoreError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+fn error_response(status: StatusCode, error: CoreError) -> HttpResponse {
+    HttpResponse::build(status).json(ApiErrorResponse::from(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header::AUTHORIZATION;
+    use actix_web::{App, test};
+    use serde_json::json;
+
+    use super::*;
+
+    async fn invoke_status(
+        admin_token: Option<&str>,
+        command: &str,
+        authorization: Option<&str>,
+    ) -> StatusCode {
+        let mut state = AppState::new(None);
+        state.admin_token = admin_token.map(str::to_string);
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(state))
+                .route("/api/invoke", web::post().to(invoke)),
+        )
+        .await;
+        let mut request = test::TestRequest::post()
+            .uri("/api/invoke")
+            .set_json(json!({ "command": command, "args": {} }));
+        if let Some(authorization) = authorization {
+            request = request.insert_header((AUTHORIZATION, authorization));
+        }
+        test::call_service(&app, request.to_request())
+            .await
+            .status()
+    }
+
+    #[actix_web::test]
+    async fn invoke_admin_commands_require_token() {
+        for command in ADMIN_COMMANDS {
+            assert_eq!(
+                invoke_status(None, command, None).await,
+                StatusCode::FORBIDDEN
+            );
+            assert_eq!(
+                invoke_status(Some("s3cret"), command, Some("Bearer wrong")).await,
+                StatusCode::UNAUTHORIZED
+            );
+            // 令牌正确时进入分发，缺少参数返回 400
+            assert_eq!(
+                invoke_status(Some("s3cret"), command, Some("Bearer s3cret")).await,
+                StatusCode::BAD_REQUEST
+            );
+        }
+    }
+
+    /// 以同一份状态调用一次命令，返回状态码与响应体
+    async fn call(state: &web::Data<AppState>, command: &str, args: Value) -> (StatusCode, Value) {
+        let app = test::init_service(
+            App::new()
+                .app_data(state.clone())
+                .route("/api/invoke", web::post().to(invoke)),
+        )
+        .await;
+        let request = test::TestRequest::post()
+            .uri("/api/invoke")
+            .set_json(json!({ "command": command, "args": args }));
+        let response = test::call_service(&app, request.to_request()).await;
+        let status = response.status();
+        (status, test::read_body_json(response).await)
+    }
+
+    #[actix_web::test]
+    async fn change_window_commands_round_trip() {
+        let state = web::Data::new(AppState::new(None));
+        let (status, body) = call(
+            &state,
+            "create_change_window_rule",
+            json!({ "request": {
+                "name": "friday",
+                "utcOffset": "+08:00",
+                "schedule": { "type": "recurring", "cron": "0 12 * * 5", "durationMinutes": 720 },
+            } }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        let rule_id = body["data"]["id"].as_str().unwrap_or_default().to_string();
+
+        let (_, body) = call(&state, "list_change_window_rules", Value::Null).await;
+        assert_eq!(body["data"][0]["name"], "friday");
+
+        let (status, body) = call(
+            &state,
+            "start_change_window_override",
+            json!({ "reason": "incident", "durationMinutes": 30 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        let (_, body) = call(&state, "get_change_window_override", Value::Null).await;
+        assert_eq!(body["data"]["reason"], "incident");
+        call(&state, "clear_change_window_override", Value::Null).await;
+        let (_, body) = call(&state, "get_change_window_override", Value::Null).await;
+        assert!(body["data"].is_null());
+
+        let (status, _) = call(
+            &state,
+            "delete_change_window_rule",
+            json!({ "ruleId": rule_id }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (_, body) = call(&state, "list_change_window_rules", Value::Null).await;
+        assert_eq!(body["data"], json!([]));
+    }
+
+    #[actix_web::test]
+    async fn invalid_change_window_rule_is_a_validation_error() {
+        let state = web::Data::new(AppState::new(None));
+        let (status, body) = call(
+            &state,
+            "create_change_window_rule",
+            json!({ "request": {
+                "name": "broken",
+                "utcOffset": "+08:00",
+                "schedule": { "type": "recurring", "cron": "not a cron", "durationMinutes": 60 },
+            } }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["success"], false);
+        assert_eq!(body["error"]["code"], "ValidationError");
+    }
+
+    #[actix_web::test]
+    async fn domain_group_commands_round_trip() {
+        let state = web::Data::new(AppState::new(None));
+        let members = json!([
+            { "accountId": "a1", "domainId": "d1" },
+            { "accountId": "a2", "domainId": "d2" },
+        ]);
+        let (status, body) = call(
+            &state,
+            "create_domain_group",
+            json!({ "request": { "name": "mirrors", "members": members } }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        let group_id = body["data"]["id"].as_str().unwrap_or_default().to_string();
+
+        let (_, body) = call(&state, "list_domain_groups", Value::Null).await;
+        assert_eq!(body["data"][0]["members"], members);
+
+        let (status, body) = call(
+            &state,
+            "create_domain_group",
+            json!({ "request": { "name": "single", "members": [members[0].clone()] } }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+
+        call(
+            &state,
+            "delete_domain_group",
+            json!({ "groupId": group_id }),
+        )
+        .await;
+        let (_, body) = call(&state, "list_domain_groups", Value::Null).await;
+        assert_eq!(body["data"], json!([]));
+    }
+
+    #[actix_web::test]
+    async fn dispatch_reports_unknown_commands_accounts_and_bad_arguments() {
+        let state = web::Data::new(AppState::new(None));
+
+        let (status, body) = call(&state, "no_such_command", Value::Null).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], "ValidationError");
+
+        let (status, body) = call(
+            &state,
+            "list_all_dns_records",
+            json!({ "accountId": "missing", "domainId": "zone-1" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], "AccountNotFound");
+
+        let (status, body) = call(
+            &state,
+            "list_all_dns_records",
+            json!({ "accountId": "missing" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(
+            body["error"]["details"]
+                .as_str()
+                .is_some_and(|details| details.starts_with("Invalid arguments"))
+        );
+    }
+
+    #[actix_web::test]
+    async fn port_check_reports_closed_local_port() {
+        // 绑定后立即释放，得到一个没有监听者的端口
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .map_or(1, |address| address.port());
+        let app = test::init_service(
+            App::new().route("/api/toolbox/port-check", web::post().to(super::port_check)),
+        )
+        .await;
+
+        let request = test::TestRequest::post()
+            .uri("/api/toolbox/port-check")
+            .set_json(json!({ "host": "127.0.0.1", "ports": [port], "timeoutMs": 1000 }));
+        let response = test::call_service(&app, request.to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["data"]["results"][0]["state"], "closed");
+
+        let request = test::TestRequest::post()
+            .uri("/api/toolbox/port-check")
+            .set_json(json!({ "host": "127.0.0.1", "ports": [] }));
+        let response = test::call_service(&app, request.to_request()).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn error_status_maps_core_errors() {
+        let cases = [
+            (
+                CoreError::DomainNotFound("example.com".to_string()),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                CoreError::NetworkError("reset".to_string()),
+                StatusCode::BAD_GATEWAY,
+            ),
+            (
+                CoreError::ServiceUnavailable("circuit open".to_string()),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                CoreError::ValidationError("bad".to_string()),
+                StatusCode::BAD_REQUEST,
+            ),
+        ];
+        for (error, status) in cases {
+            assert_eq!(error_status(&error), status, "{error}");
+        }
+    }
+
+    /// 需要访问外部 DNS：`cargo test -- --ignored`
+    #[actix_web::test]
+    #[ignore = "requires network access"]
+    async fn dnssec_reports_keys_for_signed_domain() {
+        let app =
+            test::init_service(App::new().route("/api/toolbox/dnssec", web::get().to(dnssec)))
+                .await;
+        let request = test::TestRequest::get()
+            .uri("/api/toolbox/dnssec?domain=cloudflare.com&nameserver=1.1.1.1")
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        let keys = body["data"]["dnskeyRecords"].as_array();
+        assert!(keys.is_some_and(|keys| !keys.is_empty()), "{body}");
+    }
+}
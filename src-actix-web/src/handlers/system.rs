@@ -0,0 +1,46 @@
+//! 系统诊断命令
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use sea_orm::{ConnectionTrait, DbBackend, Statement};
+use serde::Serialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+/// 存储后端信息
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseInfo {
+    /// `memory` / `sqlite` / `postgres` / `mysql`
+    backend: &'static str,
+    /// 数据库服务端版本（内存存储时为 `None`）
+    version: Option<String>,
+}
+
+/// 获取当前存储后端类型与版本
+pub async fn get_database_info(state: &AppState) -> CoreResult<ApiResponse<DatabaseInfo>> {
+    let Some(db) = &state.database else {
+        return Ok(ApiResponse::success(DatabaseInfo {
+            backend: "memory",
+            version: None,
+        }));
+    };
+
+    let backend = db.get_database_backend();
+    let (name, sql) = match backend {
+        DbBackend::Postgres => ("postgres", "SHOW server_version"),
+        DbBackend::MySql => ("mysql", "SELECT VERSION()"),
+        _ => ("sqlite", "SELECT sqlite_version()"),
+    };
+
+    let version = db
+        .query_one_raw(Statement::from_string(backend, sql))
+        .await
+        .and_then(|row| row.map(|row| row.try_get_by_index::<String>(0)).transpose())
+        .map_err(|e| CoreError::StorageError(e.to_string()))?;
+
+    Ok(ApiResponse::success(DatabaseInfo {
+        backend: name,
+        version,
+    }))
+}
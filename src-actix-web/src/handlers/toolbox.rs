@@ -0,0 +1,333 @@
+//! 工具箱命令
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::services::ToolboxService;
+use dns_orchestrator_core::types::{
+    CaaCheckResult, DnsLookupResult, DnsLookupTransport, DnsPropagationResult, DnssecResult,
+    EmailSecurityResult, ExternalEndpoint, HttpHeaderCheckRequest, HttpHeaderCheckResult,
+    HttpTraceResult, IpLookupBatchEntry, MailCheckResult, NetworkEnvInfo, PortCheckResult,
+    PortProtocol, PrivacyConfig, PropagationCheckResult, ReverseLookupResult, SslCheckResult,
+    SslCheckTarget, TraceRouteResult,
+};
+use serde::Deserialize;
+
+use crate::state::AppState;
+use crate::types::ApiResponse;
+
+/// 获取隐私模式配置
+pub fn get_privacy_config(state: &AppState) -> ApiResponse<PrivacyConfig> {
+    ApiResponse::success(state.toolbox_service.privacy_config())
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPrivacyConfigArgs {
+    config: PrivacyConfig,
+}
+
+/// 设置隐私模式（仅在进程内生效，重启后以 `DNS_ORCHESTRATOR_PRIVACY_MODE` 为准）
+pub fn set_privacy_config(state: &AppState, args: &SetPrivacyConfigArgs) -> ApiResponse<()> {
+    state.toolbox_service.set_privacy_config(&args.config);
+
+    ApiResponse::success(())
+}
+
+/// 列出当前配置下可能访问的第三方端点及用途
+pub async fn list_external_endpoints(
+    state: &AppState,
+) -> CoreResult<ApiResponse<Vec<ExternalEndpoint>>> {
+    Ok(ApiResponse::success(
+        state.toolbox_service.list_external_endpoints().await,
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsPropagationCheckArgs {
+    domain: String,
+    record_type: String,
+    #[serde(default)]
+    confirm_external: bool,
+}
+
+/// DNS 传播检查（隐私模式下需要 `confirmExternal`）
+pub async fn dns_propagation_check(
+    state: &AppState,
+    args: DnsPropagationCheckArgs,
+) -> CoreResult<ApiResponse<DnsPropagationResult>> {
+    let result = state
+        .toolbox_service
+        .dns_propagation_check(&args.domain, &args.record_type, args.confirm_external)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationCheckArgs {
+    domain: String,
+    record_type: String,
+    expected_value: String,
+    #[serde(default)]
+    confirm_external: bool,
+}
+
+/// 期望值传播检查（隐私模式下需要 `confirmExternal`）
+pub async fn propagation_check(
+    state: &AppState,
+    args: PropagationCheckArgs,
+) -> CoreResult<ApiResponse<PropagationCheckResult>> {
+    let result = state
+        .toolbox_service
+        .propagation_check(
+            &args.domain,
+            &args.record_type,
+            &args.expected_value,
+            args.confirm_external,
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsLookupArgs {
+    domain: String,
+    record_type: String,
+    nameserver: Option<String>,
+    transport: Option<DnsLookupTransport>,
+}
+
+/// DNS 查询（`transport` 可选 udp / tcp / doh / dot）
+pub async fn dns_lookup(args: DnsLookupArgs) -> CoreResult<ApiResponse<DnsLookupResult>> {
+    let result = ToolboxService::dns_lookup(
+        &args.domain,
+        &args.record_type,
+        args.nameserver.as_deref(),
+        args.transport,
+    )
+    .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseLookupArgs {
+    ip: String,
+    nameserver: Option<String>,
+}
+
+/// 反向 DNS（PTR）查询
+pub async fn reverse_lookup(
+    args: ReverseLookupArgs,
+) -> CoreResult<ApiResponse<ReverseLookupResult>> {
+    let result = ToolboxService::reverse_lookup(&args.ip, args.nameserver.as_deref()).await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpLookupBatchArgs {
+    queries: Vec<String>,
+    #[serde(default)]
+    confirm_external: bool,
+}
+
+/// 批量 IP/域名/CIDR 地理位置查询（最多 100 条，隐私模式下需要 `confirmExternal`）
+pub async fn ip_lookup_batch(
+    state: &AppState,
+    args: IpLookupBatchArgs,
+) -> CoreResult<ApiResponse<BTreeMap<String, IpLookupBatchEntry>>> {
+    let result = state
+        .toolbox_service
+        .ip_lookup_batch(args.queries, args.confirm_external)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DnssecCheckArgs {
+    domain: String,
+    nameserver: Option<String>,
+}
+
+/// DNSSEC 验证（`nameserver` 为空时使用系统 DNS）
+pub async fn dnssec_check(args: DnssecCheckArgs) -> CoreResult<ApiResponse<DnssecResult>> {
+    let result = ToolboxService::dnssec_check(&args.domain, args.nameserver.as_deref()).await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSecurityCheckArgs {
+    domain: String,
+    dkim_selector: Option<String>,
+}
+
+/// 邮件安全检查（SPF / DKIM / DMARC）
+pub async fn email_security_check(
+    args: EmailSecurityCheckArgs,
+) -> CoreResult<ApiResponse<EmailSecurityResult>> {
+    let result =
+        ToolboxService::email_security_check(&args.domain, args.dkim_selector.as_deref()).await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailCheckArgs {
+    domain: String,
+    timeout_secs: Option<u64>,
+}
+
+/// 邮件服务健康检查（MX / SMTP 连通性 / SPF / DMARC），`timeoutSecs` 为整体超时
+pub async fn mail_check(args: MailCheckArgs) -> CoreResult<ApiResponse<MailCheckResult>> {
+    let result =
+        ToolboxService::mail_check(&args.domain, args.timeout_secs.map(Duration::from_secs))
+            .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CaaCheckArgs {
+    domain: String,
+}
+
+/// 获取系统 DNS 服务器
+pub fn get_system_dns() -> ApiResponse<Vec<String>> {
+    ApiResponse::success(ToolboxService::get_system_dns())
+}
+
+/// 本机网络环境诊断（隐私模式下不探测出口 IP）
+pub async fn network_env_info(state: &AppState) -> ApiResponse<NetworkEnvInfo> {
+    ApiResponse::success(state.toolbox_service.network_env_info().await)
+}
+
+/// CAA 记录检查
+pub async fn caa_check(args: CaaCheckArgs) -> CoreResult<ApiResponse<CaaCheckResult>> {
+    let result = ToolboxService::caa_check(&args.domain).await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SslCheckArgs {
+    domain: String,
+    port: Option<u16>,
+    connect_addr: Option<String>,
+    #[serde(default)]
+    probe_versions: bool,
+}
+
+/// SSL 证书检查
+///
+/// `port` 默认 443；`connectAddr` 指定时连接该地址、SNI 仍使用 `domain`；
+/// `probeVersions` 为 true 时逐一探测 TLS 1.0 ~ 1.3。
+pub async fn ssl_check(args: SslCheckArgs) -> CoreResult<ApiResponse<SslCheckResult>> {
+    let result = ToolboxService::ssl_check(
+        &args.domain,
+        args.port,
+        args.connect_addr.as_deref(),
+        args.probe_versions,
+    )
+    .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SslCheckBatchArgs {
+    targets: Vec<SslCheckTarget>,
+}
+
+/// 批量 SSL 证书检查（结果与输入顺序一致，单个目标失败不影响整批）
+pub async fn ssl_check_batch(
+    args: SslCheckBatchArgs,
+) -> CoreResult<ApiResponse<Vec<SslCheckResult>>> {
+    let results = ToolboxService::ssl_check_batch(args.targets).await;
+
+    Ok(ApiResponse::success(results))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HttpHeaderCheckArgs {
+    request: HttpHeaderCheckRequest,
+}
+
+/// HTTP 头检查（`request.followRedirects` 为 true 时跟随重定向并返回各跳）
+pub async fn http_header_check(
+    args: HttpHeaderCheckArgs,
+) -> CoreResult<ApiResponse<HttpHeaderCheckResult>> {
+    let result = ToolboxService::http_header_check(&args.request).await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpTraceArgs {
+    url: String,
+    max_redirects: Option<u8>,
+}
+
+/// HTTP 重定向链跟踪（`maxRedirects` 默认 10）
+pub async fn http_trace(args: HttpTraceArgs) -> CoreResult<ApiResponse<HttpTraceResult>> {
+    let result = ToolboxService::http_trace(&args.url, args.max_redirects.unwrap_or(10)).await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortCheckArgs {
+    host: String,
+    ports: Vec<u16>,
+    #[serde(default)]
+    protocol: PortProtocol,
+    timeout_ms: Option<u64>,
+}
+
+/// 端口连通性检查（`protocol` 默认 TCP，`timeoutMs` 默认 3000）
+pub async fn port_check(args: PortCheckArgs) -> CoreResult<ApiResponse<PortCheckResult>> {
+    let result = ToolboxService::port_check(
+        &args.host,
+        args.ports,
+        args.protocol,
+        args.timeout_ms.unwrap_or(3000),
+    )
+    .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceRouteArgs {
+    host: String,
+    max_hops: Option<u8>,
+    probes_per_hop: Option<u8>,
+}
+
+/// 路径追踪（`maxHops` 默认 30，`probesPerHop` 默认 3）
+pub async fn trace_route(args: TraceRouteArgs) -> CoreResult<ApiResponse<TraceRouteResult>> {
+    let result = ToolboxService::trace_route(
+        &args.host,
+        args.max_hops.unwrap_or(30),
+        args.probes_per_hop.unwrap_or(3),
+    )
+    .await?;
+
+    Ok(ApiResponse::success(result))
+}
@@ -0,0 +1,142 @@
+//! Actix-Web 端注册的后台任务
+//!
+//! 任务持有共享的 [`AppState`]，由 core 的 `BackgroundJobRunner` 统一调度，
+//! 状态与手动触发通过 `list_background_jobs` / `trigger_job` 命令暴露。
+
+use std::sync::Arc;
+
+use actix_web::web;
+use async_trait::async_trait;
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::traits::BackgroundJob;
+use dns_orchestrator_core::types::JobSchedule;
+
+use crate::state::AppState;
+
+/// 凭证校验任务名称
+pub const CREDENTIAL_VALIDATION_JOB: &str = "credential_validation";
+
+/// 凭证定期校验间隔（12 小时）
+const CREDENTIAL_VALIDATION_INTERVAL_SECS: u64 = 12 * 60 * 60;
+
+/// 定期校验全部账户的凭证，提前发现被吊销的凭证
+pub struct CredentialValidationJob {
+    state: web::Data<AppState>,
+}
+
+impl CredentialValidationJob {
+    pub fn new(state: web::Data<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for CredentialValidationJob {
+    fn name(&self) -> &str {
+        CREDENTIAL_VALIDATION_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: CREDENTIAL_VALIDATION_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let summary = self
+            .state
+            .account_validation_service
+            .validate_all_accounts()
+            .await?;
+        Ok(Some(format!(
+            "{} valid, {} invalid, {} unknown",
+            summary.valid_count, summary.invalid_count, summary.unknown_count
+        )))
+    }
+}
+
+/// API 用量写入任务名称
+pub const API_USAGE_FLUSH_JOB: &str = "api_usage_flush";
+
+/// API 用量写入间隔（1 分钟）
+const API_USAGE_FLUSH_INTERVAL_SECS: u64 = 60;
+
+/// 把内存中累积的服务商 API 调用次数批量写入存储
+pub struct ApiUsageFlushJob {
+    state: web::Data<AppState>,
+}
+
+impl ApiUsageFlushJob {
+    pub fn new(state: web::Data<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for ApiUsageFlushJob {
+    fn name(&self) -> &str {
+        API_USAGE_FLUSH_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: API_USAGE_FLUSH_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let written = self.state.api_usage_service.flush().await?;
+        Ok(Some(format!("{written} entries written")))
+    }
+}
+
+/// API 用量清理任务名称
+pub const API_USAGE_CLEANUP_JOB: &str = "api_usage_cleanup";
+
+/// API 用量清理间隔（24 小时）
+const API_USAGE_CLEANUP_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// 删除保留期（12 个月）之前的 API 用量统计
+pub struct ApiUsageCleanupJob {
+    state: web::Data<AppState>,
+}
+
+impl ApiUsageCleanupJob {
+    pub fn new(state: web::Data<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for ApiUsageCleanupJob {
+    fn name(&self) -> &str {
+        API_USAGE_CLEANUP_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: API_USAGE_CLEANUP_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let deleted = self.state.api_usage_service.cleanup().await?;
+        Ok(Some(format!("{deleted} entries removed")))
+    }
+}
+
+/// 注册全部后台任务并启动调度
+pub async fn start(state: &web::Data<AppState>) {
+    let runner = &state.background_job_runner;
+    let background_jobs: [Arc<dyn BackgroundJob>; 3] = [
+        Arc::new(CredentialValidationJob::new(state.clone())),
+        Arc::new(ApiUsageFlushJob::new(state.clone())),
+        Arc::new(ApiUsageCleanupJob::new(state.clone())),
+    ];
+    for job in background_jobs {
+        if let Err(e) = runner.register(job).await {
+            tracing::error!("Failed to register background job: {e}");
+        }
+    }
+    runner.start().await;
+}
@@ -1,3 +1,107 @@
-fn main() {
-    println!("Hello, world!");
+//! DNS Orchestrator Web 后端
+//!
+//! 与 Tauri 桌面端共用 core 业务层。前端的 HTTP transport 以类 RPC 方式
+//! 向 `POST /api/invoke` 发送 `{ command, args }`，由 `handlers` 按命令名分发。
+//! 部分只读工具另外提供 REST 风格的 GET 路由，便于脚本直接调用。
+//! `POST /api/admin/backup` / `POST /api/admin/restore` 导出与恢复加密的完整备份，用于迁移服务器；
+//! `POST /api/admin/accounts/export` / `POST /api/admin/accounts/import` 只迁移选中的账户。
+//! 管理接口需设置 `DNS_ORCHESTRATOR_ADMIN_TOKEN` 并携带该令牌访问（见 [`auth`]）。
+//!
+//! 设置 `DATABASE_URL`（`sqlite://`、`postgres://`、`mysql://`）后启动时会连接数据库并执行迁移。
+//! 设置 `DNS_ORCHESTRATOR_PRIVACY_MODE=1` 开启隐私模式，非必需的第三方外发需要逐次确认。
+//! 以 `changelog` 子命令启动时只生成变更报告并退出，`usage` 子命令导出 API 用量 CSV，见 [`cli`]。
+//! 定时任务由 core 的 `BackgroundJobRunner` 调度（见 [`jobs`]），可通过 `list_background_jobs` / `trigger_job` 查看与手动触发；
+//! 服务运行期间每分钟把累积的服务商 API 调用次数写入存储，每天清理 12 个月前的统计。
+
+mod adapters;
+mod auth;
+mod cli;
+mod handlers;
+mod jobs;
+mod state;
+mod types;
+
+use actix_web::middleware::from_fn;
+use actix_web::{App, HttpServer, web};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{Database, DatabaseConnection};
+use tracing_subscriber::EnvFilter;
+
+use crate::state::AppState;
+
+/// 默认监听地址（可通过 `DNS_ORCHESTRATOR_BIND` 覆盖）
+const DEFAULT_BIND_ADDR: &str = "127.0.0.1:8080";
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .init();
+
+    let database = match std::env::var("DATABASE_URL") {
+        Ok(url) => Some(connect_database(&url).await?),
+        Err(_) => None,
+    };
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("changelog") => return cli::run_changelog(&AppState::new(database), &args[1..]).await,
+        Some("usage") => return cli::run_usage(&AppState::new(database), &args[1..]).await,
+        _ => {}
+    }
+
+    let bind_addr =
+        std::env::var("DNS_ORCHESTRATOR_BIND").unwrap_or_else(|_| DEFAULT_BIND_ADDR.to_string());
+    tracing::info!("Listening on {bind_addr}");
+    let state = web::Data::new(AppState::new(database));
+    jobs::start(&state).await;
+
+    HttpServer::new(move || {
+        App::new().app_data(state.clone()).service(
+            web::scope("/api")
+                .route("/invoke", web::post().to(handlers::invoke))
+                .route(
+                    "/accounts/{id}/health",
+                    web::get().to(handlers::account_health),
+                )
+                .route("/domains/diff", web::post().to(handlers::diff_domains))
+                .service(
+                    web::scope("/admin")
+                        .wrap(from_fn(auth::require_admin_token))
+                        .route("/backup", web::post().to(handlers::admin_backup))
+                        .route("/restore", web::post().to(handlers::admin_restore))
+                        .route(
+                            "/accounts/export",
+                            web::post().to(handlers::admin_export_accounts),
+                        )
+                        .route(
+                            "/accounts/import",
+                            web::post().to(handlers::admin_import_accounts),
+                        ),
+                )
+                .route(
+                    "/toolbox/email-security",
+                    web::get().to(handlers::email_security),
+                )
+                .route("/toolbox/dnssec", web::get().to(handlers::dnssec))
+                .route("/toolbox/ssl-check", web::get().to(handlers::ssl_check))
+                .route("/toolbox/http-trace", web::get().to(handlers::http_trace))
+                .route("/toolbox/port-check", web::post().to(handlers::port_check)),
+        )
+    })
+    .bind(bind_addr)?
+    .run()
+    .await
+}
+
+/// 连接数据库并执行迁移
+async fn connect_database(url: &str) -> std::io::Result<DatabaseConnection> {
+    let db = Database::connect(url)
+        .await
+        .map_err(std::io::Error::other)?;
+    Migrator::up(&db, None)
+        .await
+        .map_err(std::io::Error::other)?;
+    tracing::info!("Database ready: {:?}", db.get_database_backend());
+    Ok(db)
 }
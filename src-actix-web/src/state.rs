@@ -0,0 +1,119 @@
+//! 应用状态
+
+use std::sync::Arc;
+
+use dns_orchestrator_core::ServiceContext;
+use dns_orchestrator_core::services::{
+    AccountCapabilityService, AccountValidationService, ApiUsageService, AuditLogService,
+    BackgroundJobRunner, ChangeWindowService, DnsService, DomainGroupService, DomainMonitorService,
+    ImportExportService, RecordCopyService, ToolboxService,
+};
+use dns_orchestrator_core::traits::{
+    AccountRepository, ApiUsageRepository, AuditLogRepository, InMemoryProviderRegistry,
+    ProviderRegistry, RateLimitConfig,
+};
+use dns_orchestrator_core::types::PrivacyConfig;
+use sea_orm::DatabaseConnection;
+
+use crate::adapters::{
+    InMemoryAccountCapabilityRepository, InMemoryAccountRepository, InMemoryApiUsageRepository,
+    InMemoryAuditLogRepository, InMemoryChangeWindowRepository, InMemoryCredentialStore,
+    InMemoryDomainGroupRepository, InMemoryDomainMetadataRepository, InMemoryMonitorRepository,
+    SeaOrmApiUsageRepository, SeaOrmAuditLogRepository,
+};
+use crate::auth::admin_token_from_env;
+
+/// 各 handler 共享的 core 服务
+pub struct AppState {
+    pub account_capability_service: AccountCapabilityService,
+    pub account_validation_service: AccountValidationService,
+    pub dns_service: DnsService,
+    pub domain_group_service: DomainGroupService,
+    pub domain_monitor_service: DomainMonitorService,
+    pub record_copy_service: RecordCopyService,
+    pub import_export_service: ImportExportService,
+    pub audit_log_service: AuditLogService,
+    pub api_usage_service: Arc<ApiUsageService>,
+    pub change_window_service: Arc<ChangeWindowService>,
+    pub toolbox_service: Arc<ToolboxService>,
+    /// 后台任务运行器（任务在启动服务时注册，见 [`crate::jobs`]）
+    pub background_job_runner: BackgroundJobRunner,
+    /// Provider 注册表（连通性检查）
+    pub provider_registry: Arc<dyn ProviderRegistry>,
+    /// 配置了 `DATABASE_URL` 时的数据库连接（已执行迁移）
+    pub database: Option<DatabaseConnection>,
+    /// 管理接口令牌，未设置时不开放管理接口（见 [`crate::auth`]）
+    pub admin_token: Option<String>,
+}
+
+impl AppState {
+    pub fn new(database: Option<DatabaseConnection>) -> Self {
+        let audit_log: Arc<dyn AuditLogRepository> = match &database {
+            Some(db) => Arc::new(SeaOrmAuditLogRepository::new(db.clone())),
+            None => Arc::new(InMemoryAuditLogRepository::default()),
+        };
+        let api_usage: Arc<dyn ApiUsageRepository> = match &database {
+            Some(db) => Arc::new(SeaOrmApiUsageRepository::new(db.clone())),
+            None => Arc::new(InMemoryApiUsageRepository::default()),
+        };
+        let account_repository: Arc<dyn AccountRepository> =
+            Arc::new(InMemoryAccountRepository::default());
+        let api_usage_service = Arc::new(ApiUsageService::new(
+            api_usage,
+            Arc::clone(&account_repository),
+        ));
+        let change_window_service = Arc::new(ChangeWindowService::new(Arc::new(
+            InMemoryChangeWindowRepository::default(),
+        )));
+        let provider_registry: Arc<dyn ProviderRegistry> =
+            Arc::new(InMemoryProviderRegistry::new());
+        let toolbox_service = Arc::new(ToolboxService::default());
+        toolbox_service.set_privacy_config(&PrivacyConfig {
+            privacy_mode: privacy_mode_from_env(),
+        });
+        let ctx = Arc::new(
+            ServiceContext::new(
+                Arc::new(InMemoryCredentialStore::default()),
+                account_repository,
+                Arc::clone(&provider_registry),
+                Arc::new(InMemoryDomainMetadataRepository::default()),
+            )
+            .with_audit_log(audit_log)
+            .with_change_windows(Arc::clone(&change_window_service))
+            .with_capability_cache(Arc::new(InMemoryAccountCapabilityRepository::default()))
+            .with_api_usage(Arc::clone(&api_usage_service))
+            .with_rate_limits(RateLimitConfig::recommended()),
+        );
+
+        Self {
+            account_capability_service: AccountCapabilityService::new(Arc::clone(&ctx)),
+            account_validation_service: AccountValidationService::new(Arc::clone(&ctx)),
+            dns_service: DnsService::new(Arc::clone(&ctx)),
+            record_copy_service: RecordCopyService::new(Arc::clone(&ctx)),
+            import_export_service: ImportExportService::new(Arc::clone(&ctx)),
+            audit_log_service: AuditLogService::new(Arc::clone(&ctx)),
+            api_usage_service,
+            change_window_service,
+            toolbox_service: Arc::clone(&toolbox_service),
+            background_job_runner: BackgroundJobRunner::new(),
+            provider_registry,
+            domain_monitor_service: DomainMonitorService::new(
+                Arc::clone(&ctx),
+                Arc::clone(&toolbox_service),
+                Arc::new(InMemoryMonitorRepository::default()),
+            ),
+            domain_group_service: DomainGroupService::new(
+                ctx,
+                Arc::new(InMemoryDomainGroupRepository::default()),
+            ),
+            database,
+            admin_token: admin_token_from_env(),
+        }
+    }
+}
+
+/// 是否通过 `DNS_ORCHESTRATOR_PRIVACY_MODE`（`1` / `true`）开启隐私模式
+fn privacy_mode_from_env() -> bool {
+    std::env::var("DNS_ORCHESTRATOR_PRIVACY_MODE")
+        .is_ok_and(|value| matches!(value.trim(), "1" | "true" | "TRUE" | "True"))
+}
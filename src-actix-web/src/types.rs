@@ -0,0 +1,36 @@
+//! HTTP 响应类型（`ApiResponse` 与 Tauri 端共用 core 的实现）
+
+use dns_orchestrator_core::error::CoreError;
+use dns_orchestrator_core::types::ErrorSuggestion;
+use dns_orchestrator_core::utils::error_suggestions;
+use serde::Serialize;
+
+pub use dns_orchestrator_core::types::ApiResponse;
+
+/// 失败响应：`error` 为 `CoreError` 的 `{ code, details }` 序列化结果，
+/// 有匹配的修复建议时附带 `suggestions`
+#[derive(Debug, Serialize)]
+pub struct ApiErrorResponse {
+    pub success: bool,
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorBody {
+    #[serde(flatten)]
+    pub error: CoreError,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<ErrorSuggestion>,
+}
+
+impl From<CoreError> for ApiErrorResponse {
+    fn from(error: CoreError) -> Self {
+        Self {
+            success: false,
+            error: ErrorBody {
+                suggestions: error_suggestions::suggestions_for(&error),
+                error,
+            },
+        }
+    }
+}
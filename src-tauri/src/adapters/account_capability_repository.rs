@@ -0,0 +1,106 @@
+//! Tauri 账户能力缓存仓库适配器
+//!
+//! 使用 tauri-plugin-store 实现能力探测结果持久化
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::AccountCapabilityRepository;
+use dns_orchestrator_core::types::AccountCapabilityCache;
+
+const STORE_FILE_NAME: &str = "account_capabilities.json";
+const CAPABILITIES_KEY: &str = "capabilities";
+
+/// Tauri 账户能力缓存仓库实现（`account_id` -> 探测结果）
+pub struct TauriAccountCapabilityRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<HashMap<String, AccountCapabilityCache>>>>,
+}
+
+impl TauriAccountCapabilityRepository {
+    /// 创建新的账户能力缓存仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载探测结果
+    fn load_from_store(&self) -> CoreResult<HashMap<String, AccountCapabilityCache>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(CAPABILITIES_KEY) else {
+            return Ok(HashMap::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存探测结果到 Store 并更新缓存
+    async fn save_to_store(
+        &self,
+        entries: HashMap<String, AccountCapabilityCache>,
+    ) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&entries)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(CAPABILITIES_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved capabilities of {} accounts to store", entries.len());
+        *self.cache.write().await = Some(entries);
+        Ok(())
+    }
+
+    async fn find_all(&self) -> CoreResult<HashMap<String, AccountCapabilityCache>> {
+        if let Some(ref entries) = *self.cache.read().await {
+            return Ok(entries.clone());
+        }
+
+        let entries = self.load_from_store()?;
+        *self.cache.write().await = Some(entries.clone());
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl AccountCapabilityRepository for TauriAccountCapabilityRepository {
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Option<AccountCapabilityCache>> {
+        Ok(self.find_all().await?.remove(account_id))
+    }
+
+    async fn save(&self, cache: &AccountCapabilityCache) -> CoreResult<()> {
+        let mut entries = self.find_all().await?;
+        entries.insert(cache.account_id.clone(), cache.clone());
+        self.save_to_store(entries).await
+    }
+
+    async fn delete(&self, account_id: &str) -> CoreResult<()> {
+        let mut entries = self.find_all().await?;
+        if entries.remove(account_id).is_none() {
+            return Ok(());
+        }
+        self.save_to_store(entries).await
+    }
+}
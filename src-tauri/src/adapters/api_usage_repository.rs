@@ -0,0 +1,124 @@
+//! Tauri API 用量仓库适配器
+//!
+//! 使用 tauri-plugin-store 实现用量统计持久化
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::ApiUsageRepository;
+use dns_orchestrator_core::types::ApiUsageCount;
+
+const STORE_FILE_NAME: &str = "api_usage.json";
+const COUNTS_KEY: &str = "counts";
+
+/// Tauri API 用量仓库实现（每个「账户 + 日期 + 操作」一条）
+pub struct TauriApiUsageRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<ApiUsageCount>>>>,
+}
+
+impl TauriApiUsageRepository {
+    /// 创建新的 API 用量仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载用量统计
+    fn load_from_store(&self) -> CoreResult<Vec<ApiUsageCount>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(COUNTS_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存用量统计到 Store 并更新缓存
+    async fn save_to_store(&self, counts: Vec<ApiUsageCount>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&counts)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(COUNTS_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved {} api usage entries to store", counts.len());
+        *self.cache.write().await = Some(counts);
+        Ok(())
+    }
+
+    async fn find_all(&self) -> CoreResult<Vec<ApiUsageCount>> {
+        if let Some(ref counts) = *self.cache.read().await {
+            return Ok(counts.clone());
+        }
+
+        let counts = self.load_from_store()?;
+        *self.cache.write().await = Some(counts.clone());
+        Ok(counts)
+    }
+}
+
+#[async_trait]
+impl ApiUsageRepository for TauriApiUsageRepository {
+    async fn add_counts(&self, counts: &[ApiUsageCount]) -> CoreResult<()> {
+        let mut entries = self.find_all().await?;
+        for count in counts {
+            match entries.iter_mut().find(|e| {
+                e.account_id == count.account_id
+                    && e.date == count.date
+                    && e.operation == count.operation
+            }) {
+                Some(existing) => existing.count += count.count,
+                None => entries.push(count.clone()),
+            }
+        }
+        self.save_to_store(entries).await
+    }
+
+    async fn query(
+        &self,
+        account_id: Option<&str>,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> CoreResult<Vec<ApiUsageCount>> {
+        Ok(self
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|e| {
+                account_id.is_none_or(|id| id == e.account_id) && e.date >= since && e.date < until
+            })
+            .collect())
+    }
+
+    async fn delete_before(&self, before: NaiveDate) -> CoreResult<u64> {
+        let mut entries = self.find_all().await?;
+        let len = entries.len();
+        entries.retain(|e| e.date >= before);
+        let deleted = len - entries.len();
+        if deleted > 0 {
+            self.save_to_store(entries).await?;
+        }
+        Ok(deleted as u64)
+    }
+}
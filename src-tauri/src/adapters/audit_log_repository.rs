@@ -0,0 +1,124 @@
+//! Tauri 审计日志仓库适配器
+//!
+//! 审计日志只追加、条目多，不适合整体读写的 tauri-plugin-store，
+//! 改为在应用数据目录下按行写入 JSON（JSON Lines）。
+
+use async_trait::async_trait;
+use std::io::Write;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+use tokio::sync::Mutex;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::AuditLogRepository;
+use dns_orchestrator_core::types::{
+    AuditEntry, AuditLogFilter, PaginatedResponse, PaginationParams,
+};
+
+const LOG_FILE_NAME: &str = "audit_log.jsonl";
+
+/// Tauri 审计日志仓库实现
+pub struct TauriAuditLogRepository {
+    app_handle: AppHandle,
+    /// 串行化文件读写，避免并发追加时行交错
+    lock: Mutex<()>,
+}
+
+impl TauriAuditLogRepository {
+    /// 创建新的审计日志仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn log_path(&self) -> CoreResult<PathBuf> {
+        let data_dir = self
+            .app_handle
+            .path()
+            .app_data_dir()
+            .map_err(|e| CoreError::StorageError(format!("Failed to get data dir: {e}")))?;
+        Ok(data_dir.join(LOG_FILE_NAME))
+    }
+
+    /// 读取整个日志文件（文件不存在时为空）
+    async fn read_log(&self) -> CoreResult<String> {
+        let path = self.log_path()?;
+        let _guard = self.lock.lock().await;
+        match std::fs::read_to_string(&path) {
+            Ok(content) => Ok(content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(CoreError::StorageError(format!(
+                "Failed to read audit log: {e}"
+            ))),
+        }
+    }
+}
+
+/// 按追加顺序存储，倒序遍历即为时间倒序；损坏的行跳过
+fn entries_newest_first(content: &str) -> impl Iterator<Item = AuditEntry> + '_ {
+    content
+        .lines()
+        .rev()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str::<AuditEntry>(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                log::warn!("Skipping malformed audit log line: {e}");
+                None
+            }
+        })
+}
+
+#[async_trait]
+impl AuditLogRepository for TauriAuditLogRepository {
+    async fn append(&self, entry: &AuditEntry) -> CoreResult<()> {
+        let mut line = serde_json::to_string(entry)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        line.push('\n');
+
+        let path = self.log_path()?;
+        let _guard = self.lock.lock().await;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| CoreError::StorageError(format!("Failed to create data dir: {e}")))?;
+        }
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| CoreError::StorageError(format!("Failed to write audit log: {e}")))
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<AuditEntry>> {
+        let content = self.read_log().await?;
+        Ok(entries_newest_first(&content).find(|entry| entry.id == id))
+    }
+
+    async fn query(
+        &self,
+        filter: &AuditLogFilter,
+        pagination: &PaginationParams,
+    ) -> CoreResult<PaginatedResponse<AuditEntry>> {
+        let content = self.read_log().await?;
+        let matched: Vec<AuditEntry> = entries_newest_first(&content)
+            .filter(|entry| filter.matches(entry))
+            .collect();
+
+        let total = u32::try_from(matched.len()).unwrap_or(u32::MAX);
+        let offset = (pagination.page.saturating_sub(1) * pagination.page_size) as usize;
+        let items = matched
+            .into_iter()
+            .skip(offset)
+            .take(pagination.page_size as usize)
+            .collect();
+        Ok(PaginatedResponse::new(
+            items,
+            pagination.page,
+            pagination.page_size,
+            total,
+        ))
+    }
+}
@@ -0,0 +1,104 @@
+//! Tauri 变更窗口规则仓库适配器
+//!
+//! 使用 tauri-plugin-store 实现禁止变更规则持久化
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::ChangeWindowRepository;
+use dns_orchestrator_core::types::ChangeWindowRule;
+
+const STORE_FILE_NAME: &str = "change_windows.json";
+const RULES_KEY: &str = "rules";
+
+/// Tauri 变更窗口规则仓库实现
+pub struct TauriChangeWindowRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<ChangeWindowRule>>>>,
+}
+
+impl TauriChangeWindowRepository {
+    /// 创建新的变更窗口规则仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载规则
+    fn load_from_store(&self) -> CoreResult<Vec<ChangeWindowRule>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(RULES_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存规则到 Store 并更新缓存
+    async fn save_to_store(&self, rules: Vec<ChangeWindowRule>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&rules)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(RULES_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved {} change window rules to store", rules.len());
+        *self.cache.write().await = Some(rules);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ChangeWindowRepository for TauriChangeWindowRepository {
+    async fn find_all(&self) -> CoreResult<Vec<ChangeWindowRule>> {
+        if let Some(ref rules) = *self.cache.read().await {
+            return Ok(rules.clone());
+        }
+
+        let rules = self.load_from_store()?;
+        *self.cache.write().await = Some(rules.clone());
+        Ok(rules)
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<ChangeWindowRule>> {
+        let rules = self.find_all().await?;
+        Ok(rules.into_iter().find(|r| r.id == id))
+    }
+
+    async fn save(&self, rule: &ChangeWindowRule) -> CoreResult<()> {
+        let mut rules = self.find_all().await?;
+
+        if let Some(pos) = rules.iter().position(|r| r.id == rule.id) {
+            rules[pos] = rule.clone();
+        } else {
+            rules.push(rule.clone());
+        }
+
+        self.save_to_store(rules).await
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        let mut rules = self.find_all().await?;
+        rules.retain(|r| r.id != id);
+        self.save_to_store(rules).await
+    }
+}
@@ -0,0 +1,104 @@
+//! Tauri 域名组仓库适配器
+//!
+//! 使用 tauri-plugin-store 实现域名组持久化
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::DomainGroupRepository;
+use dns_orchestrator_core::types::DomainGroup;
+
+const STORE_FILE_NAME: &str = "domain_groups.json";
+const GROUPS_KEY: &str = "groups";
+
+/// Tauri 域名组仓库实现
+pub struct TauriDomainGroupRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<DomainGroup>>>>,
+}
+
+impl TauriDomainGroupRepository {
+    /// 创建新的域名组仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载域名组
+    fn load_from_store(&self) -> CoreResult<Vec<DomainGroup>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(GROUPS_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存域名组到 Store 并更新缓存
+    async fn save_to_store(&self, groups: Vec<DomainGroup>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&groups)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(GROUPS_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved {} domain groups to store", groups.len());
+        *self.cache.write().await = Some(groups);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DomainGroupRepository for TauriDomainGroupRepository {
+    async fn find_all(&self) -> CoreResult<Vec<DomainGroup>> {
+        if let Some(ref groups) = *self.cache.read().await {
+            return Ok(groups.clone());
+        }
+
+        let groups = self.load_from_store()?;
+        *self.cache.write().await = Some(groups.clone());
+        Ok(groups)
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<DomainGroup>> {
+        let groups = self.find_all().await?;
+        Ok(groups.into_iter().find(|g| g.id == id))
+    }
+
+    async fn save(&self, group: &DomainGroup) -> CoreResult<()> {
+        let mut groups = self.find_all().await?;
+
+        if let Some(pos) = groups.iter().position(|g| g.id == group.id) {
+            groups[pos] = group.clone();
+        } else {
+            groups.push(group.clone());
+        }
+
+        self.save_to_store(groups).await
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        let mut groups = self.find_all().await?;
+        groups.retain(|g| g.id != id);
+        self.save_to_store(groups).await
+    }
+}
@@ -221,6 +221,28 @@ impl DomainMetadataRepository for TauriDomainMetadataRepository {
         Ok(())
     }
 
+    async fn find_by_account(
+        &self,
+        account_id: &str,
+    ) -> CoreResult<Vec<(DomainMetadataKey, DomainMetadata)>> {
+        self.ensure_cache().await?;
+        let cache = self.cache.read().await;
+
+        Ok(cache
+            .as_ref()
+            .map(|cache_data| {
+                cache_data
+                    .iter()
+                    .filter_map(|(storage_key, metadata)| {
+                        DomainMetadataKey::from_storage_key(storage_key)
+                            .filter(|key| key.account_id == account_id)
+                            .map(|key| (key, metadata.clone()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
     async fn find_favorites_by_account(
         &self,
         account_id: &str,
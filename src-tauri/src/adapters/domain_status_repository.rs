@@ -0,0 +1,114 @@
+//! Tauri 域名派生状态仓库适配器
+//!
+//! 使用 tauri-plugin-store 实现域名派生状态持久化
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::DomainDerivedStatusRepository;
+use dns_orchestrator_core::types::DomainDerivedStatus;
+
+const STORE_FILE_NAME: &str = "domain_status.json";
+const STATUSES_KEY: &str = "statuses";
+
+/// Tauri 域名派生状态仓库实现（每个「账户 + 域名」一条）
+pub struct TauriDomainStatusRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<DomainDerivedStatus>>>>,
+}
+
+impl TauriDomainStatusRepository {
+    /// 创建新的域名派生状态仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载派生状态
+    fn load_from_store(&self) -> CoreResult<Vec<DomainDerivedStatus>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(STATUSES_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存派生状态到 Store 并更新缓存
+    async fn save_to_store(&self, statuses: Vec<DomainDerivedStatus>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&statuses)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(STATUSES_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved {} domain derived statuses to store", statuses.len());
+        *self.cache.write().await = Some(statuses);
+        Ok(())
+    }
+
+    async fn find_all(&self) -> CoreResult<Vec<DomainDerivedStatus>> {
+        if let Some(ref statuses) = *self.cache.read().await {
+            return Ok(statuses.clone());
+        }
+
+        let statuses = self.load_from_store()?;
+        *self.cache.write().await = Some(statuses.clone());
+        Ok(statuses)
+    }
+}
+
+#[async_trait]
+impl DomainDerivedStatusRepository for TauriDomainStatusRepository {
+    async fn find_by_account(&self, account_id: &str) -> CoreResult<Vec<DomainDerivedStatus>> {
+        Ok(self
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|s| s.account_id == account_id)
+            .collect())
+    }
+
+    async fn save_batch(&self, statuses: &[DomainDerivedStatus]) -> CoreResult<()> {
+        let mut entries = self.find_all().await?;
+        for status in statuses {
+            match entries
+                .iter_mut()
+                .find(|e| e.account_id == status.account_id && e.domain_id == status.domain_id)
+            {
+                Some(existing) => *existing = status.clone(),
+                None => entries.push(status.clone()),
+            }
+        }
+        self.save_to_store(entries).await
+    }
+
+    async fn delete_by_account(&self, account_id: &str) -> CoreResult<()> {
+        let mut entries = self.find_all().await?;
+        let len = entries.len();
+        entries.retain(|e| e.account_id != account_id);
+        if entries.len() != len {
+            self.save_to_store(entries).await?;
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,27 @@
 //! 平台适配器模块
 
+mod account_capability_repository;
 mod account_repository;
+mod api_usage_repository;
+mod audit_log_repository;
+mod change_window_repository;
 mod credential_store;
+mod domain_group_repository;
 mod domain_metadata_repository;
+mod domain_status_repository;
+mod monitor_repository;
+mod temporary_record_repository;
+mod write_queue_repository;
 
+pub use account_capability_repository::TauriAccountCapabilityRepository;
 pub use account_repository::TauriAccountRepository;
+pub use api_usage_repository::TauriApiUsageRepository;
+pub use audit_log_repository::TauriAuditLogRepository;
+pub use change_window_repository::TauriChangeWindowRepository;
 pub use credential_store::TauriCredentialStore;
+pub use domain_group_repository::TauriDomainGroupRepository;
 pub use domain_metadata_repository::TauriDomainMetadataRepository;
+pub use domain_status_repository::TauriDomainStatusRepository;
+pub use monitor_repository::TauriMonitorRepository;
+pub use temporary_record_repository::TauriTemporaryRecordRepository;
+pub use write_queue_repository::TauriWriteQueueRepository;
@@ -0,0 +1,80 @@
+//! Tauri 监控结果仓库适配器
+//!
+//! 使用 tauri-plugin-store 保存最近一次域名到期检查的结果
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::MonitorRepository;
+use dns_orchestrator_core::types::DomainExpiryEntry;
+
+const STORE_FILE_NAME: &str = "domain_monitor.json";
+const EXPIRY_RESULTS_KEY: &str = "expiryResults";
+
+/// Tauri 监控结果仓库实现
+pub struct TauriMonitorRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<DomainExpiryEntry>>>>,
+}
+
+impl TauriMonitorRepository {
+    /// 创建新的监控结果仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载到期检查结果
+    fn load_from_store(&self) -> CoreResult<Vec<DomainExpiryEntry>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(EXPIRY_RESULTS_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl MonitorRepository for TauriMonitorRepository {
+    async fn find_expiry_results(&self) -> CoreResult<Vec<DomainExpiryEntry>> {
+        if let Some(ref entries) = *self.cache.read().await {
+            return Ok(entries.clone());
+        }
+
+        let entries = self.load_from_store()?;
+        *self.cache.write().await = Some(entries.clone());
+        Ok(entries)
+    }
+
+    async fn save_expiry_results(&self, entries: &[DomainExpiryEntry]) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(entries)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(EXPIRY_RESULTS_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved {} domain expiry results to store", entries.len());
+        *self.cache.write().await = Some(entries.to_vec());
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+//! Tauri 临时记录仓库适配器
+//!
+//! 使用 tauri-plugin-store 实现临时记录持久化
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::TemporaryRecordRepository;
+use dns_orchestrator_core::types::TemporaryRecord;
+
+const STORE_FILE_NAME: &str = "temporary_records.json";
+const RECORDS_KEY: &str = "records";
+
+/// Tauri 临时记录仓库实现
+pub struct TauriTemporaryRecordRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<TemporaryRecord>>>>,
+}
+
+impl TauriTemporaryRecordRepository {
+    /// 创建新的临时记录仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// 从 Store 加载临时记录
+    fn load_from_store(&self) -> CoreResult<Vec<TemporaryRecord>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(RECORDS_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存临时记录到 Store 并更新缓存
+    async fn save_to_store(&self, records: Vec<TemporaryRecord>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&records)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(RECORDS_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        log::debug!("Saved {} temporary records to store", records.len());
+        *self.cache.write().await = Some(records);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TemporaryRecordRepository for TauriTemporaryRecordRepository {
+    async fn find_all(&self) -> CoreResult<Vec<TemporaryRecord>> {
+        if let Some(ref records) = *self.cache.read().await {
+            return Ok(records.clone());
+        }
+
+        let records = self.load_from_store()?;
+        *self.cache.write().await = Some(records.clone());
+        Ok(records)
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<TemporaryRecord>> {
+        let records = self.find_all().await?;
+        Ok(records.into_iter().find(|r| r.id == id))
+    }
+
+    async fn save(&self, record: &TemporaryRecord) -> CoreResult<()> {
+        let mut records = self.find_all().await?;
+
+        if let Some(pos) = records.iter().position(|r| r.id == record.id) {
+            records[pos] = record.clone();
+        } else {
+            records.push(record.clone());
+        }
+
+        self.save_to_store(records).await
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        let mut records = self.find_all().await?;
+        records.retain(|r| r.id != id);
+        self.save_to_store(records).await
+    }
+}
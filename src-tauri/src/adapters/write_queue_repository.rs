@@ -0,0 +1,169 @@
+//! Tauri 离线写队列仓库适配器
+//!
+//! 队列包含记录值，整体加密后存入 tauri-plugin-store（AES-256-GCM）。
+//! 加密口令首次使用时随机生成：桌面端保存在系统 Keychain，Android 端与凭证一样保存在应用私有 Store。
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::RwLock;
+
+use dns_orchestrator_core::crypto;
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::WriteQueueRepository;
+use dns_orchestrator_core::types::QueuedWrite;
+
+const STORE_FILE_NAME: &str = "write_queue.json";
+const QUEUE_KEY: &str = "queue";
+
+/// 加密后的队列
+#[derive(Serialize, Deserialize)]
+struct EncryptedQueue {
+    salt: String,
+    nonce: String,
+    data: String,
+}
+
+/// Tauri 离线写队列仓库实现
+pub struct TauriWriteQueueRepository {
+    app_handle: AppHandle,
+    /// 内存缓存（解密后的队列），避免每次读取都派生密钥
+    cache: Arc<RwLock<Option<Vec<QueuedWrite>>>>,
+}
+
+impl TauriWriteQueueRepository {
+    /// 创建新的离线写队列仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    fn load_from_store(&self) -> CoreResult<Option<EncryptedQueue>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+        store
+            .get(QUEUE_KEY)
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    fn save_to_store(&self, encrypted: &EncryptedQueue) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+        let value = serde_json::to_value(encrypted)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        store.set(QUEUE_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))
+    }
+}
+
+#[async_trait]
+impl WriteQueueRepository for TauriWriteQueueRepository {
+    async fn find_all(&self) -> CoreResult<Vec<QueuedWrite>> {
+        if let Some(ref entries) = *self.cache.read().await {
+            return Ok(entries.clone());
+        }
+
+        let entries = match self.load_from_store()? {
+            Some(encrypted) => {
+                let passphrase = queue_passphrase(&self.app_handle)?;
+                let plaintext = tokio::task::spawn_blocking(move || {
+                    crypto::decrypt(
+                        &encrypted.data,
+                        &passphrase,
+                        &encrypted.salt,
+                        &encrypted.nonce,
+                    )
+                })
+                .await
+                .map_err(|e| CoreError::StorageError(e.to_string()))??;
+                serde_json::from_slice(&plaintext)
+                    .map_err(|e| CoreError::SerializationError(e.to_string()))?
+            }
+            None => Vec::new(),
+        };
+        *self.cache.write().await = Some(entries.clone());
+        Ok(entries)
+    }
+
+    async fn save_all(&self, entries: &[QueuedWrite]) -> CoreResult<()> {
+        let plaintext = serde_json::to_vec(entries)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+        let passphrase = queue_passphrase(&self.app_handle)?;
+        let (salt, nonce, data) =
+            tokio::task::spawn_blocking(move || crypto::encrypt(&plaintext, &passphrase))
+                .await
+                .map_err(|e| CoreError::StorageError(e.to_string()))??;
+        self.save_to_store(&EncryptedQueue { salt, nonce, data })?;
+
+        log::debug!("Saved {} queued writes to store", entries.len());
+        *self.cache.write().await = Some(entries.to_vec());
+        Ok(())
+    }
+}
+
+/// 随机生成的加密口令
+fn new_passphrase() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// 队列加密口令（桌面端：系统 Keychain）
+#[cfg(not(target_os = "android"))]
+fn queue_passphrase(_app_handle: &AppHandle) -> CoreResult<String> {
+    use keyring::Entry;
+
+    const SERVICE_NAME: &str = "dns-orchestrator";
+    const PASSPHRASE_KEY: &str = "write-queue-key";
+
+    let entry = Entry::new(SERVICE_NAME, PASSPHRASE_KEY)
+        .map_err(|e| CoreError::CredentialError(e.to_string()))?;
+    match entry.get_password() {
+        Ok(passphrase) => Ok(passphrase),
+        Err(keyring::Error::NoEntry) => {
+            let passphrase = new_passphrase();
+            entry
+                .set_password(&passphrase)
+                .map_err(|e| CoreError::CredentialError(e.to_string()))?;
+            Ok(passphrase)
+        }
+        Err(e) => Err(CoreError::CredentialError(e.to_string())),
+    }
+}
+
+/// 队列加密口令（Android：应用私有 Store）
+#[cfg(target_os = "android")]
+fn queue_passphrase(app_handle: &AppHandle) -> CoreResult<String> {
+    const KEY_STORE_FILE_NAME: &str = "write_queue_key.json";
+    const PASSPHRASE_KEY: &str = "passphrase";
+
+    let store = app_handle
+        .store(KEY_STORE_FILE_NAME)
+        .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+    if let Some(passphrase) = store
+        .get(PASSPHRASE_KEY)
+        .and_then(|value| value.as_str().map(ToString::to_string))
+    {
+        return Ok(passphrase);
+    }
+    let passphrase = new_passphrase();
+    store.set(PASSPHRASE_KEY.to_string(), passphrase.clone());
+    store
+        .save()
+        .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+    Ok(passphrase)
+}
@@ -1,5 +1,12 @@
 use tauri::State;
 
+use dns_orchestrator_core::services::EXPIRY_REMINDER_DAYS;
+use dns_orchestrator_core::types::{
+    AccountCapabilities, AccountDeletionImpact, AccountDeletionResult, AccountValidation,
+    AccountValidationSummary, ApiUsageReport, ExpiringCredential, ExportDiagnosticsRequest,
+    ExportDiagnosticsResponse, ProviderHealthStatus, RestoreBackupResult,
+};
+
 use crate::error::DnsError;
 use crate::types::{
     Account, ApiResponse, BatchDeleteResult, CreateAccountRequest, ExportAccountsRequest,
@@ -18,6 +25,9 @@ fn convert_account(core_account: dns_orchestrator_core::types::Account) -> Accou
         updated_at: core_account.updated_at,
         status: core_account.status.map(convert_account_status),
         error: core_account.error,
+        credentials_expires_at: core_account.credentials_expires_at,
+        credential_status: core_account.credential_status,
+        last_validated_at: core_account.last_validated_at,
     }
 }
 
@@ -27,6 +37,9 @@ fn convert_account_status(
     match status {
         dns_orchestrator_core::types::AccountStatus::Active => crate::types::AccountStatus::Active,
         dns_orchestrator_core::types::AccountStatus::Error => crate::types::AccountStatus::Error,
+        dns_orchestrator_core::types::AccountStatus::CredentialsExpired => {
+            crate::types::AccountStatus::CredentialsExpired
+        }
     }
 }
 
@@ -59,6 +72,7 @@ fn convert_import_preview(preview: dns_orchestrator_core::types::ImportPreview)
 fn convert_import_result(result: dns_orchestrator_core::types::ImportResult) -> ImportResult {
     ImportResult {
         success_count: result.success_count,
+        skipped_count: result.skipped_count,
         failures: result
             .failures
             .into_iter()
@@ -86,18 +100,38 @@ pub async fn create_account(
     state: State<'_, AppState>,
     request: CreateAccountRequest,
 ) -> Result<ApiResponse<Account>, DnsError> {
+    // 本地格式预检，清理空白的提示随响应返回
+    let mut credentials = request.credentials;
+    let notices = credentials
+        .precheck()
+        .map_err(DnsError::CredentialValidation)?;
+
     // 转换请求类型
     let core_request = dns_orchestrator_core::types::CreateAccountRequest {
         name: request.name,
         provider: request.provider,
-        credentials: request.credentials,
+        credentials,
+        credentials_expires_at: request.credentials_expires_at,
     };
 
     let account = state
         .account_lifecycle_service
         .create_account(core_request)
         .await?;
-    Ok(ApiResponse::success(convert_account(account)))
+    Ok(ApiResponse::success(convert_account(account)).with_warnings(notices))
+}
+
+/// 删除账号前的影响面分析
+#[tauri::command]
+pub async fn get_account_deletion_impact(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<AccountDeletionImpact>, DnsError> {
+    let impact = state
+        .account_lifecycle_service
+        .get_account_deletion_impact(&account_id)
+        .await?;
+    Ok(ApiResponse::success(impact))
 }
 
 /// 删除账号
@@ -105,12 +139,12 @@ pub async fn create_account(
 pub async fn delete_account(
     state: State<'_, AppState>,
     account_id: String,
-) -> Result<ApiResponse<()>, DnsError> {
-    state
+) -> Result<ApiResponse<AccountDeletionResult>, DnsError> {
+    let result = state
         .account_lifecycle_service
         .delete_account(&account_id)
         .await?;
-    Ok(ApiResponse::success(()))
+    Ok(ApiResponse::success(result))
 }
 
 /// 更新账号
@@ -119,18 +153,28 @@ pub async fn update_account(
     state: State<'_, AppState>,
     request: UpdateAccountRequest,
 ) -> Result<ApiResponse<Account>, DnsError> {
+    // 本地格式预检，清理空白的提示随响应返回
+    let mut credentials = request.credentials;
+    let notices = match credentials.as_mut() {
+        Some(credentials) => credentials
+            .precheck()
+            .map_err(DnsError::CredentialValidation)?,
+        None => Vec::new(),
+    };
+
     // 转换请求类型
     let core_request = dns_orchestrator_core::types::UpdateAccountRequest {
         id: request.id,
         name: request.name,
-        credentials: request.credentials,
+        credentials,
+        credentials_expires_at: request.credentials_expires_at,
     };
 
     let account = state
         .account_lifecycle_service
         .update_account(core_request)
         .await?;
-    Ok(ApiResponse::success(convert_account(account)))
+    Ok(ApiResponse::success(convert_account(account)).with_warnings(notices))
 }
 
 fn convert_batch_delete_result(
@@ -145,6 +189,7 @@ fn convert_batch_delete_result(
             .map(|f| crate::types::BatchDeleteFailure {
                 record_id: f.record_id,
                 reason: f.reason,
+                attempts: f.attempts,
             })
             .collect(),
     }
@@ -172,6 +217,85 @@ pub async fn list_providers(
     Ok(ApiResponse::success(providers))
 }
 
+/// 获取账户的实际能力（服务商静态声明叠加探测修正）
+#[tauri::command]
+pub async fn get_account_capabilities(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<AccountCapabilities>, DnsError> {
+    let capabilities = state
+        .account_capability_service
+        .get_capabilities(&account_id)
+        .await?;
+    Ok(ApiResponse::success(capabilities))
+}
+
+/// 重新探测账户的实际能力
+#[tauri::command]
+pub async fn refresh_account_capabilities(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<AccountCapabilities>, DnsError> {
+    let capabilities = state
+        .account_capability_service
+        .refresh_capabilities(&account_id)
+        .await?;
+    Ok(ApiResponse::success(capabilities))
+}
+
+/// 检查账户服务商 API 的连通性（凭证是否有效、网络是否可达）
+#[tauri::command]
+pub async fn check_provider_health(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<ProviderHealthStatus>, DnsError> {
+    let status = state
+        .ctx
+        .provider_registry
+        .health_check(&account_id)
+        .await?;
+    Ok(ApiResponse::success(status))
+}
+
+/// 校验账户凭证，结果写入账户数据（`credentialStatus`、`lastValidatedAt`）
+#[tauri::command]
+pub async fn validate_account(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<AccountValidation>, DnsError> {
+    let validation = state
+        .account_validation_service
+        .validate_account(&account_id)
+        .await?;
+    Ok(ApiResponse::success(validation))
+}
+
+/// 并发校验全部账户的凭证
+#[tauri::command]
+pub async fn validate_all_accounts(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<AccountValidationSummary>, DnsError> {
+    let summary = state
+        .account_validation_service
+        .validate_all_accounts()
+        .await?;
+    Ok(ApiResponse::success(summary))
+}
+
+/// 获取账户某月（`YYYY-MM`）的服务商 API 用量报告
+#[tauri::command]
+pub async fn get_api_usage_report(
+    state: State<'_, AppState>,
+    account_id: String,
+    month: String,
+) -> Result<ApiResponse<ApiUsageReport>, DnsError> {
+    let report = state
+        .api_usage_service
+        .get_usage_report(&account_id, &month)
+        .await?;
+    Ok(ApiResponse::success(report))
+}
+
 /// 导出账号
 #[tauri::command]
 pub async fn export_accounts(
@@ -182,6 +306,7 @@ pub async fn export_accounts(
         account_ids: request.account_ids,
         encrypt: request.encrypt,
         password: request.password,
+        reproducible: request.reproducible,
     };
 
     let app_version = env!("CARGO_PKG_VERSION");
@@ -227,6 +352,55 @@ pub async fn import_accounts(
     Ok(ApiResponse::success(convert_import_result(result)))
 }
 
+/// 导出诊断数据（不含凭证，可匿名化），用于提交问题
+#[tauri::command]
+pub async fn export_diagnostics(
+    state: State<'_, AppState>,
+    request: ExportDiagnosticsRequest,
+) -> Result<ApiResponse<ExportDiagnosticsResponse>, DnsError> {
+    let app_version = env!("CARGO_PKG_VERSION");
+    let response = state
+        .import_export_service
+        .export_diagnostics(
+            request,
+            app_version,
+            Some(state.toolbox_service.network_env_info().await),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(response))
+}
+
+/// 导出完整备份（全部账户、凭证与域名元数据，使用密码加密）
+#[tauri::command]
+pub async fn export_backup(
+    state: State<'_, AppState>,
+    password: String,
+) -> Result<ApiResponse<ExportAccountsResponse>, DnsError> {
+    let app_version = env!("CARGO_PKG_VERSION");
+    let response = state
+        .import_export_service
+        .export_backup(&password, app_version)
+        .await?;
+
+    Ok(ApiResponse::success(convert_export_response(response)))
+}
+
+/// 从完整备份恢复（按账户 ID 覆盖，可重复执行）
+#[tauri::command]
+pub async fn import_backup(
+    state: State<'_, AppState>,
+    content: String,
+    password: String,
+) -> Result<ApiResponse<RestoreBackupResult>, DnsError> {
+    let result = state
+        .import_export_service
+        .import_backup(&content, &password)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
 /// 检查账户恢复是否完成
 #[tauri::command]
 pub fn is_restore_completed(state: State<'_, AppState>) -> bool {
@@ -234,3 +408,19 @@ pub fn is_restore_completed(state: State<'_, AppState>) -> bool {
         .restore_completed
         .load(std::sync::atomic::Ordering::SeqCst)
 }
+
+/// 列出即将到期（含已过期）的账户凭证
+///
+/// `within_days` 缺省时取最大提醒档位
+#[tauri::command]
+pub async fn list_expiring_credentials(
+    state: State<'_, AppState>,
+    within_days: Option<u32>,
+) -> Result<ApiResponse<Vec<ExpiringCredential>>, DnsError> {
+    let within_days = within_days.unwrap_or(EXPIRY_REMINDER_DAYS[0]);
+    let credentials = state
+        .credential_expiry_service
+        .list_expiring_credentials(within_days)
+        .await?;
+    Ok(ApiResponse::success(credentials))
+}
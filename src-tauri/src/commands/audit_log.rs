@@ -0,0 +1,34 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{
+    AuditEntry, AuditLogFilter, ChangelogOutput, ChangelogRequest, PaginatedResponse,
+};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 分页查询记录变更审计日志（按时间倒序）
+#[tauri::command]
+pub async fn list_audit_log(
+    state: State<'_, AppState>,
+    filter: Option<AuditLogFilter>,
+    page: Option<u32>,
+    page_size: Option<u32>,
+) -> Result<ApiResponse<PaginatedResponse<AuditEntry>>, DnsError> {
+    let result = state
+        .audit_log_service
+        .list_audit_log(filter.unwrap_or_default(), page, page_size)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
+
+/// 生成指定时间范围内的变更报告
+#[tauri::command]
+pub async fn generate_changelog(
+    state: State<'_, AppState>,
+    request: ChangelogRequest,
+) -> Result<ApiResponse<ChangelogOutput>, DnsError> {
+    let result = state.audit_log_service.generate_changelog(request).await?;
+    Ok(ApiResponse::success(result))
+}
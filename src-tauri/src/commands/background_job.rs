@@ -0,0 +1,26 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::BackgroundJobInfo;
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 列出所有后台任务状态
+#[tauri::command]
+pub async fn list_background_jobs(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<BackgroundJobInfo>>, DnsError> {
+    let jobs = state.background_job_runner.list_background_jobs().await;
+    Ok(ApiResponse::success(jobs))
+}
+
+/// 手动触发后台任务
+#[tauri::command]
+pub async fn trigger_job(
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<ApiResponse<()>, DnsError> {
+    state.background_job_runner.trigger_job(&name).await?;
+    Ok(ApiResponse::success(()))
+}
@@ -0,0 +1,94 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{
+    ChangeWindowOverride, ChangeWindowRule, ClosedPeriod, CreateChangeWindowRuleRequest,
+    UpdateChangeWindowRuleRequest,
+};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 列出所有禁止变更规则
+#[tauri::command]
+pub async fn list_change_window_rules(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<ChangeWindowRule>>, DnsError> {
+    let rules = state.change_window_service.list_rules().await?;
+    Ok(ApiResponse::success(rules))
+}
+
+/// 创建禁止变更规则
+#[tauri::command]
+pub async fn create_change_window_rule(
+    state: State<'_, AppState>,
+    request: CreateChangeWindowRuleRequest,
+) -> Result<ApiResponse<ChangeWindowRule>, DnsError> {
+    let rule = state.change_window_service.create_rule(request).await?;
+    Ok(ApiResponse::success(rule))
+}
+
+/// 更新禁止变更规则
+#[tauri::command]
+pub async fn update_change_window_rule(
+    state: State<'_, AppState>,
+    request: UpdateChangeWindowRuleRequest,
+) -> Result<ApiResponse<ChangeWindowRule>, DnsError> {
+    let rule = state.change_window_service.update_rule(request).await?;
+    Ok(ApiResponse::success(rule))
+}
+
+/// 删除禁止变更规则
+#[tauri::command]
+pub async fn delete_change_window_rule(
+    state: State<'_, AppState>,
+    rule_id: String,
+) -> Result<ApiResponse<()>, DnsError> {
+    state.change_window_service.delete_rule(&rule_id).await?;
+    Ok(ApiResponse::success(()))
+}
+
+/// 列出未来若干天内的禁止变更时段（日历展示）
+#[tauri::command]
+pub async fn list_upcoming_closed_periods(
+    state: State<'_, AppState>,
+    days: u32,
+) -> Result<ApiResponse<Vec<ClosedPeriod>>, DnsError> {
+    let periods = state
+        .change_window_service
+        .list_upcoming_closed_periods(days)
+        .await?;
+    Ok(ApiResponse::success(periods))
+}
+
+/// 获取当前生效的强制变更
+#[tauri::command]
+pub async fn get_change_window_override(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Option<ChangeWindowOverride>>, DnsError> {
+    let active = state.change_window_service.current_override().await;
+    Ok(ApiResponse::success(active))
+}
+
+/// 开启限时强制变更
+#[tauri::command]
+pub async fn start_change_window_override(
+    state: State<'_, AppState>,
+    reason: String,
+    duration_minutes: u32,
+) -> Result<ApiResponse<ChangeWindowOverride>, DnsError> {
+    let active = state
+        .change_window_service
+        .start_override(&reason, duration_minutes)
+        .await?;
+    Ok(ApiResponse::success(active))
+}
+
+/// 提前结束强制变更
+#[tauri::command]
+pub async fn clear_change_window_override(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<()>, DnsError> {
+    state.change_window_service.clear_override().await;
+    Ok(ApiResponse::success(()))
+}
@@ -0,0 +1,408 @@
+//! 深链接：`dnsorch://domain/{account}/{domain}?action=create_record&type=A&name=foo`
+//!
+//! 深链接只负责导航与预填：解析并校验后确认目标域名存在，暂存目标并通知前端，
+//! 由前端跳转到记录页、打开表单。任何写操作仍需用户在界面上确认提交。
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::sync::atomic::Ordering;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use tauri::{AppHandle, Emitter, Manager};
+use tauri::{State, Url};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 注册的 URL scheme（见 `tauri.conf.json` 的 `plugins.deep-link`）
+pub const DEEP_LINK_SCHEME: &str = "dnsorch";
+
+/// 深链接目标已就绪，前端收到后调用 `take_pending_deep_link` 取出
+pub const DEEP_LINK_EVENT: &str = "deep-link";
+
+/// 深链接被拒绝（格式非法或目标不存在），载荷为原因
+pub const DEEP_LINK_REJECTED_EVENT: &str = "deep-link-rejected";
+
+/// 唯一支持的 host，预留给以后的其他页面
+const DOMAIN_HOST: &str = "domain";
+
+const MAX_URL_LEN: usize = 2048;
+const MAX_ID_LEN: usize = 128;
+const MAX_NAME_LEN: usize = 253;
+const MAX_VALUE_LEN: usize = 1024;
+
+/// 冷启动时等待账户恢复完成的上限
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const RESTORE_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const RESTORE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 允许预填的记录类型（与前端记录表单支持的类型一致）
+const PREFILL_RECORD_TYPES: &[&str] = &["A", "AAAA", "CNAME", "MX", "TXT", "NS", "SRV", "CAA"];
+
+/// 深链接要执行的界面操作（均不直接写入）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeepLinkAction {
+    /// 打开新建记录表单并预填
+    CreateRecord,
+    /// 按名称/类型筛选记录列表
+    FilterRecords,
+}
+
+impl DeepLinkAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CreateRecord => "create_record",
+            Self::FilterRecords => "filter_records",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "create_record" => Some(Self::CreateRecord),
+            "filter_records" => Some(Self::FilterRecords),
+            _ => None,
+        }
+    }
+}
+
+/// 深链接目标
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLinkTarget {
+    pub account_id: String,
+    pub domain_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<DeepLinkAction>,
+    /// 记录类型（大写，如 `A`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// 主机记录（如 `foo`、`@`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 记录值，仅 `create_record` 可用
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl DeepLinkTarget {
+    /// 校验字段取值与长度，返回拒绝原因
+    pub fn validate(&self) -> Result<(), String> {
+        validate_id("account", &self.account_id)?;
+        validate_id("domain", &self.domain_id)?;
+
+        let has_prefill = self.record_type.is_some() || self.name.is_some() || self.value.is_some();
+        if self.action.is_none() && has_prefill {
+            return Err("type/name/value require an action".to_string());
+        }
+        if let Some(record_type) = &self.record_type {
+            if !PREFILL_RECORD_TYPES.contains(&record_type.as_str()) {
+                return Err(format!("unsupported record type '{record_type}'"));
+            }
+        }
+        if let Some(name) = &self.name {
+            validate_name(name)?;
+        }
+        if let Some(value) = &self.value {
+            if self.action != Some(DeepLinkAction::CreateRecord) {
+                return Err("value is only allowed with create_record".to_string());
+            }
+            if value.is_empty() || value.len() > MAX_VALUE_LEN {
+                return Err(format!("value must be 1-{MAX_VALUE_LEN} bytes"));
+            }
+            if value.chars().any(char::is_control) {
+                return Err("value contains control characters".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// 生成深链接 URL（调用方需先 `validate`）
+    pub fn to_url(&self) -> String {
+        let mut url = format!(
+            "{DEEP_LINK_SCHEME}://{DOMAIN_HOST}/{}/{}",
+            urlencoding::encode(&self.account_id),
+            urlencoding::encode(&self.domain_id)
+        );
+
+        let params = [
+            ("action", self.action.map(DeepLinkAction::as_str)),
+            ("type", self.record_type.as_deref()),
+            ("name", self.name.as_deref()),
+            ("value", self.value.as_deref()),
+        ];
+        let mut separator = '?';
+        for (key, value) in params {
+            if let Some(value) = value {
+                url.push(separator);
+                url.push_str(key);
+                url.push('=');
+                url.push_str(&urlencoding::encode(value));
+                separator = '&';
+            }
+        }
+        url
+    }
+}
+
+/// 账户/域名 ID：非空、限长、不含控制字符
+fn validate_id(field: &str, id: &str) -> Result<(), String> {
+    if id.is_empty() || id.len() > MAX_ID_LEN {
+        return Err(format!("{field} id must be 1-{MAX_ID_LEN} bytes"));
+    }
+    if id.chars().any(char::is_control) {
+        return Err(format!("{field} id contains control characters"));
+    }
+    Ok(())
+}
+
+/// 主机记录：字母、数字、`-`、`_`、`.`、`*`、`@`
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        return Err(format!("name must be 1-{MAX_NAME_LEN} bytes"));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '*' | '@'))
+    {
+        return Err(format!("name '{name}' contains invalid characters"));
+    }
+    Ok(())
+}
+
+/// 解析并校验深链接，返回拒绝原因
+pub fn parse_deep_link(url: &Url) -> Result<DeepLinkTarget, String> {
+    if url.as_str().len() > MAX_URL_LEN {
+        return Err(format!("link exceeds {MAX_URL_LEN} bytes"));
+    }
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("unsupported scheme '{}'", url.scheme()));
+    }
+    if !url
+        .host_str()
+        .is_some_and(|host| host.eq_ignore_ascii_case(DOMAIN_HOST))
+    {
+        return Err("unsupported link target".to_string());
+    }
+
+    let segments = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .map(|segment| urlencoding::decode(segment).map(std::borrow::Cow::into_owned))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|_| "path is not valid UTF-8".to_string())?
+        .unwrap_or_default();
+    let [account_id, domain_id] = <[String; 2]>::try_from(segments)
+        .map_err(|_| "expected /{account}/{domain}".to_string())?;
+
+    let mut target = DeepLinkTarget {
+        account_id,
+        domain_id,
+        action: None,
+        record_type: None,
+        name: None,
+        value: None,
+    };
+
+    for (key, value) in url.query_pairs() {
+        let slot = match key.as_ref() {
+            "action" => {
+                if target.action.is_some() {
+                    return Err("duplicate parameter 'action'".to_string());
+                }
+                target.action = Some(
+                    DeepLinkAction::parse(&value)
+                        .ok_or_else(|| format!("unsupported action '{value}'"))?,
+                );
+                continue;
+            }
+            "type" => &mut target.record_type,
+            "name" => &mut target.name,
+            "value" => &mut target.value,
+            other => return Err(format!("unknown parameter '{other}'")),
+        };
+        if slot.is_some() {
+            return Err(format!("duplicate parameter '{key}'"));
+        }
+        *slot = Some(if key == "type" {
+            value.to_ascii_uppercase()
+        } else {
+            value.into_owned()
+        });
+    }
+
+    target.validate()?;
+    Ok(target)
+}
+
+/// 处理系统转交的深链接（冷启动参数或单实例转发）
+///
+/// 仅校验、暂存并通知前端，不执行任何写操作。
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn handle_deep_link(app: &AppHandle, url: &Url) {
+    let target = match parse_deep_link(url) {
+        Ok(target) => target,
+        Err(reason) => {
+            reject(app, &reason);
+            return;
+        }
+    };
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<AppState>();
+        if let Err(e) = verify_target(&state, &target).await {
+            reject(&app, &e.to_string());
+            return;
+        }
+
+        log::info!(
+            "[deep_link] Opening domain {} of account {}",
+            target.domain_id,
+            target.account_id
+        );
+        *state.pending_deep_link.write().await = Some(target);
+        focus_main_window(&app);
+        if let Err(e) = app.emit(DEEP_LINK_EVENT, ()) {
+            log::warn!("[deep_link] Failed to emit deep link event: {e}");
+        }
+    });
+}
+
+/// 确认账户与域名存在（冷启动时先等待账户恢复）
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+async fn verify_target(state: &AppState, target: &DeepLinkTarget) -> Result<(), DnsError> {
+    let started = Instant::now();
+    while !state.restore_completed.load(Ordering::SeqCst)
+        && started.elapsed() < RESTORE_WAIT_TIMEOUT
+    {
+        tokio::time::sleep(RESTORE_POLL_INTERVAL).await;
+    }
+
+    if state
+        .account_metadata_service
+        .get_account(&target.account_id)
+        .await?
+        .is_none()
+    {
+        return Err(DnsError::AccountNotFound(target.account_id.clone()));
+    }
+    state
+        .domain_service
+        .get_domain(&target.account_id, &target.domain_id)
+        .await?;
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn reject(app: &AppHandle, reason: &str) {
+    log::warn!("[deep_link] Rejected deep link: {reason}");
+    focus_main_window(app);
+    if let Err(e) = app.emit(DEEP_LINK_REJECTED_EVENT, reason) {
+        log::warn!("[deep_link] Failed to emit deep link rejection: {e}");
+    }
+}
+
+/// 恢复并聚焦主窗口
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+pub fn focus_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    if let Err(e) = window
+        .unminimize()
+        .and_then(|()| window.show())
+        .and_then(|()| window.set_focus())
+    {
+        log::warn!("[deep_link] Failed to focus main window: {e}");
+    }
+}
+
+/// 取出待处理的深链接目标（取出后清空，避免重复处理）
+#[tauri::command]
+pub async fn take_pending_deep_link(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Option<DeepLinkTarget>>, DnsError> {
+    let target = state.pending_deep_link.write().await.take();
+    Ok(ApiResponse::success(target))
+}
+
+/// 为指定目标生成可分享的深链接
+#[tauri::command]
+pub fn generate_deep_link(target: DeepLinkTarget) -> Result<ApiResponse<String>, DnsError> {
+    target.validate().map_err(DnsError::ValidationError)?;
+    Ok(ApiResponse::success(target.to_url()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(link: &str) -> Result<DeepLinkTarget, String> {
+        let url = Url::parse(link).map_err(|e| e.to_string())?;
+        parse_deep_link(&url)
+    }
+
+    #[test]
+    fn test_parse_deep_link() {
+        let target =
+            parse("dnsorch://domain/acc-1/example.com?action=create_record&type=a&name=foo");
+        assert_eq!(
+            target,
+            Ok(DeepLinkTarget {
+                account_id: "acc-1".to_string(),
+                domain_id: "example.com".to_string(),
+                action: Some(DeepLinkAction::CreateRecord),
+                record_type: Some("A".to_string()),
+                name: Some("foo".to_string()),
+                value: None,
+            })
+        );
+
+        assert!(parse("dnsorch://domain/acc-1/example.com").is_ok());
+        assert!(parse("https://domain/acc-1/example.com").is_err());
+        assert!(parse("dnsorch://settings/acc-1/example.com").is_err());
+        assert!(parse("dnsorch://domain/acc-1").is_err());
+        assert!(parse("dnsorch://domain/acc-1/example.com/extra").is_err());
+        assert!(parse("dnsorch://domain/acc-1/example.com?action=delete_record").is_err());
+        assert!(
+            parse("dnsorch://domain/acc-1/example.com?action=create_record&confirm=1").is_err()
+        );
+        assert!(parse("dnsorch://domain/acc-1/example.com?type=A").is_err());
+        assert!(parse("dnsorch://domain/acc-1/example.com?action=create_record&type=SOA").is_err());
+        assert!(
+            parse("dnsorch://domain/acc-1/example.com?action=create_record&name=a%0Ab").is_err()
+        );
+        assert!(
+            parse("dnsorch://domain/acc-1/example.com?action=filter_records&value=1.2.3.4")
+                .is_err()
+        );
+        assert!(parse(&format!(
+            "dnsorch://domain/acc-1/example.com?action=create_record&value={}",
+            "a".repeat(MAX_VALUE_LEN + 1)
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_generate_deep_link_round_trip() {
+        let target = DeepLinkTarget {
+            account_id: "acc 1".to_string(),
+            domain_id: "/hostedzone/Z123".to_string(),
+            action: Some(DeepLinkAction::CreateRecord),
+            record_type: Some("TXT".to_string()),
+            name: Some("_acme-challenge".to_string()),
+            value: Some("v=spf1 include:example.com ~all".to_string()),
+        };
+        let link = target.to_url();
+        assert!(link.starts_with("dnsorch://domain/acc%201/%2Fhostedzone%2FZ123?action="));
+        assert_eq!(parse(&link), Ok(target));
+    }
+}
@@ -1,12 +1,23 @@
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
+
+use dns_orchestrator_core::services::RecordValidator;
+use dns_orchestrator_core::types::{
+    DomainDiffResult, RecordChangeEntry, RecordCharacterWarning, RecordListProgress,
+    RecordSetDetail, RecordSort, RecordValidationReport, UnsafeCharacter, UpdateRecordSetRequest,
+    UpdateRecordSetResult, ZoneFileImportResult,
+};
 
 use crate::error::DnsError;
 use crate::types::{
-    ApiResponse, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DnsRecord,
-    DnsRecordType, PaginatedResponse, UpdateDnsRecordRequest,
+    ApiResponse, BatchCreateRequest, BatchCreateResult, BatchDeleteRequest, BatchDeleteResult,
+    BatchUpdateRequest, BatchUpdateResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType,
+    PaginatedResponse, ReplaceRecordValueRequest, UpdateDnsRecordRequest,
 };
 use crate::AppState;
 
+/// 全量拉取记录的进度，载荷为 [`RecordListProgress`]
+pub const RECORD_LIST_PROGRESS_EVENT: &str = "record-list-progress";
+
 // 从 core 类型转换到本地类型的辅助函数
 fn convert_batch_delete_result(
     result: dns_orchestrator_core::types::BatchDeleteResult,
@@ -20,13 +31,15 @@ fn convert_batch_delete_result(
             .map(|f| crate::types::BatchDeleteFailure {
                 record_id: f.record_id,
                 reason: f.reason,
+                attempts: f.attempts,
             })
             .collect(),
     }
 }
 
-/// 列出域名下的所有 DNS 记录（分页 + 搜索）
+/// 列出域名下的所有 DNS 记录（分页 + 搜索 + 排序）
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn list_dns_records(
     state: State<'_, AppState>,
     account_id: String,
@@ -35,6 +48,7 @@ pub async fn list_dns_records(
     page_size: Option<u32>,
     keyword: Option<String>,
     record_type: Option<DnsRecordType>,
+    sort: Option<RecordSort>,
 ) -> Result<ApiResponse<PaginatedResponse<DnsRecord>>, DnsError> {
     let response = state
         .dns_service
@@ -45,41 +59,106 @@ pub async fn list_dns_records(
             page_size,
             keyword,
             record_type,
+            sort,
         )
         .await?;
 
     Ok(ApiResponse::success(response))
 }
 
+/// 拉取域名下的全部 DNS 记录，每拉取一页发送一次 `record-list-progress` 事件
+#[tauri::command]
+pub async fn list_all_dns_records(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<Vec<DnsRecord>>, DnsError> {
+    let records = state
+        .dns_service
+        .list_all_records_with_progress(&account_id, &domain_id, |progress| {
+            if let Err(e) = app.emit(RECORD_LIST_PROGRESS_EVENT, progress) {
+                log::warn!("[dns] Failed to emit record list progress: {e}");
+            }
+        })
+        .await?;
+
+    Ok(ApiResponse::success(records))
+}
+
 /// 创建 DNS 记录
+///
+/// `normalize` 为 true 时先把不可见字符与同形字符替换为 ASCII，所做替换作为警告返回。
 #[tauri::command]
 pub async fn create_dns_record(
     state: State<'_, AppState>,
     account_id: String,
-    request: CreateDnsRecordRequest,
+    mut request: CreateDnsRecordRequest,
+    normalize: Option<bool>,
 ) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    let replaced = if normalize.unwrap_or(false) {
+        RecordValidator::normalize_record(&mut request)
+    } else {
+        Vec::new()
+    };
+    let domain_id = request.domain_id.clone();
     let record = state
         .dns_service
         .create_record(&account_id, request)
         .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
 
-    Ok(ApiResponse::success(record))
+    Ok(ApiResponse::success(record)
+        .with_warnings(replaced.iter().map(UnsafeCharacter::replacement_note))
+        .with_warnings(warning))
+}
+
+/// 校验 DNS 记录（不写入），`record_id` 为正在编辑的记录
+#[tauri::command]
+pub async fn validate_dns_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: CreateDnsRecordRequest,
+    record_id: Option<String>,
+) -> Result<ApiResponse<RecordValidationReport>, DnsError> {
+    let report = state
+        .dns_service
+        .validate_record(&account_id, &request, record_id.as_deref())
+        .await?;
+
+    Ok(ApiResponse::success(report))
 }
 
-/// 更新 DNS 记录
+/// 更新 DNS 记录（`normalize` 含义同创建）
 #[tauri::command]
 pub async fn update_dns_record(
     state: State<'_, AppState>,
     account_id: String,
     record_id: String,
-    request: UpdateDnsRecordRequest,
+    mut request: UpdateDnsRecordRequest,
+    normalize: Option<bool>,
 ) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    let replaced = if normalize.unwrap_or(false) {
+        RecordValidator::normalize_update(&mut request)
+    } else {
+        Vec::new()
+    };
+    let domain_id = request.domain_id.clone();
     let record = state
         .dns_service
         .update_record(&account_id, &record_id, request)
         .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
 
-    Ok(ApiResponse::success(record))
+    Ok(ApiResponse::success(record)
+        .with_warnings(replaced.iter().map(UnsafeCharacter::replacement_note))
+        .with_warnings(warning))
 }
 
 /// 删除 DNS 记录
@@ -94,8 +173,109 @@ pub async fn delete_dns_record(
         .dns_service
         .delete_record(&account_id, &record_id, &domain_id)
         .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
+
+    Ok(ApiResponse::success(()).with_warnings(warning))
+}
+
+/// 批量创建 DNS 记录
+#[tauri::command]
+pub async fn batch_create_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: BatchCreateRequest,
+) -> Result<ApiResponse<BatchCreateResult>, DnsError> {
+    let domain_id = request.domain_id.clone();
+    let result = state
+        .dns_service
+        .batch_create_records(&account_id, request)
+        .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
+
+    Ok(ApiResponse::success(result).with_warnings(warning))
+}
+
+/// 批量更新 DNS 记录
+#[tauri::command]
+pub async fn batch_update_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: BatchUpdateRequest,
+) -> Result<ApiResponse<BatchUpdateResult>, DnsError> {
+    let domain_id = request.domain_id.clone();
+    let result = state
+        .dns_service
+        .batch_update_records(&account_id, request)
+        .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
+
+    Ok(ApiResponse::success(result).with_warnings(warning))
+}
+
+/// 按值批量替换 DNS 记录
+#[tauri::command]
+pub async fn replace_dns_record_value(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: ReplaceRecordValueRequest,
+) -> Result<ApiResponse<BatchUpdateResult>, DnsError> {
+    let domain_id = request.domain_id.clone();
+    let result = state
+        .dns_service
+        .replace_record_value(&account_id, request)
+        .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
+
+    Ok(ApiResponse::success(result).with_warnings(warning))
+}
+
+/// 获取同名同类型的一组记录
+#[tauri::command]
+pub async fn get_dns_record_set(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    name: String,
+    record_type: DnsRecordType,
+) -> Result<ApiResponse<RecordSetDetail>, DnsError> {
+    let set = state
+        .dns_service
+        .get_record_set(&account_id, &domain_id, &name, record_type)
+        .await?;
 
-    Ok(ApiResponse::success(()))
+    Ok(ApiResponse::success(set))
+}
+
+/// 将一组记录整体更新为期望的值集合
+#[tauri::command]
+pub async fn update_dns_record_set(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: UpdateRecordSetRequest,
+) -> Result<ApiResponse<UpdateRecordSetResult>, DnsError> {
+    let domain_id = request.domain_id.clone();
+    let result = state
+        .dns_service
+        .update_record_set(&account_id, request)
+        .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
+
+    Ok(ApiResponse::success(result).with_warnings(warning))
 }
 
 /// 批量删除 DNS 记录
@@ -111,10 +291,126 @@ pub async fn batch_delete_dns_records(
         record_ids: request.record_ids,
     };
 
+    let domain_id = core_request.domain_id.clone();
     let result = state
         .dns_service
         .batch_delete_records(&account_id, core_request)
         .await?;
+    let warning = state
+        .dns_service
+        .pending_domain_warning(&account_id, &domain_id)
+        .await;
+
+    Ok(ApiResponse::success(convert_batch_delete_result(result)).with_warnings(warning))
+}
+
+/// 导出域名记录为 BIND zone 文件
+#[tauri::command]
+pub async fn export_zone_file(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<String>, DnsError> {
+    let content = state
+        .dns_service
+        .export_zone_file(&account_id, &domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(content))
+}
+
+/// 从 BIND zone 文件导入记录（`dry_run` 时只返回预览）
+#[tauri::command]
+pub async fn import_zone_file(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    zone_text: String,
+    dry_run: bool,
+    normalize: Option<bool>,
+) -> Result<ApiResponse<ZoneFileImportResult>, DnsError> {
+    let result = state
+        .dns_service
+        .import_zone_file(
+            &account_id,
+            &domain_id,
+            &zone_text,
+            dry_run,
+            normalize.unwrap_or(false),
+        )
+        .await?;
 
-    Ok(ApiResponse::success(convert_batch_delete_result(result)))
+    Ok(ApiResponse::success(result))
+}
+
+/// 对比两个域名（可跨账户）的记录
+#[tauri::command]
+pub async fn diff_domains(
+    state: State<'_, AppState>,
+    account_a: String,
+    domain_a: String,
+    account_b: String,
+    domain_b: String,
+) -> Result<ApiResponse<DomainDiffResult>, DnsError> {
+    let result = state
+        .dns_service
+        .diff_domains(&account_a, &domain_a, &account_b, &domain_b)
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 扫描现有记录中的不可见字符与同形字符
+#[tauri::command]
+pub async fn scan_record_characters(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<Vec<RecordCharacterWarning>>, DnsError> {
+    let warnings = state
+        .dns_service
+        .scan_record_characters(&account_id, &domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(warnings))
+}
+
+/// 单条记录变更历史的默认条数
+const DEFAULT_RECORD_HISTORY_LIMIT: u32 = 50;
+
+/// 查询单条记录的变更历史（按时间倒序）
+#[tauri::command]
+pub async fn get_record_history(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    record_id: String,
+    limit: Option<u32>,
+) -> Result<ApiResponse<Vec<RecordChangeEntry>>, DnsError> {
+    let history = state
+        .dns_service
+        .get_record_history(
+            &account_id,
+            &domain_id,
+            &record_id,
+            limit.unwrap_or(DEFAULT_RECORD_HISTORY_LIMIT),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(history))
+}
+
+/// 将记录恢复到某次变更之前的状态（删除的记录重新创建，更新的记录改回原值）
+#[tauri::command]
+pub async fn restore_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    change_id: String,
+) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    let record = state
+        .dns_service
+        .restore_record(&account_id, &change_id)
+        .await?;
+
+    Ok(ApiResponse::success(record))
 }
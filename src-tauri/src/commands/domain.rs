@@ -12,6 +12,7 @@ fn convert_domain(core_domain: dns_orchestrator_core::types::AppDomain) -> Domai
         account_id: core_domain.account_id,
         provider: core_domain.provider,
         status: core_domain.status,
+        provider_status_raw: core_domain.provider_status_raw,
         record_count: core_domain.record_count,
         metadata: core_domain.metadata,
     }
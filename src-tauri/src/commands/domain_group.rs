@@ -0,0 +1,90 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{
+    CreateDomainGroupRequest, DomainGroup, DomainGroupChangeset, DomainGroupComparison,
+    DomainGroupMember, DomainGroupSyncResult, UpdateDomainGroupRequest,
+};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 列出所有域名组
+#[tauri::command]
+pub async fn list_domain_groups(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<DomainGroup>>, DnsError> {
+    let groups = state.domain_group_service.list_groups().await?;
+    Ok(ApiResponse::success(groups))
+}
+
+/// 创建域名组
+#[tauri::command]
+pub async fn create_domain_group(
+    state: State<'_, AppState>,
+    request: CreateDomainGroupRequest,
+) -> Result<ApiResponse<DomainGroup>, DnsError> {
+    let group = state.domain_group_service.create_group(request).await?;
+    Ok(ApiResponse::success(group))
+}
+
+/// 更新域名组
+#[tauri::command]
+pub async fn update_domain_group(
+    state: State<'_, AppState>,
+    request: UpdateDomainGroupRequest,
+) -> Result<ApiResponse<DomainGroup>, DnsError> {
+    let group = state.domain_group_service.update_group(request).await?;
+    Ok(ApiResponse::success(group))
+}
+
+/// 删除域名组
+#[tauri::command]
+pub async fn delete_domain_group(
+    state: State<'_, AppState>,
+    group_id: String,
+) -> Result<ApiResponse<()>, DnsError> {
+    state.domain_group_service.delete_group(&group_id).await?;
+    Ok(ApiResponse::success(()))
+}
+
+/// 并排比较域名组各成员的记录
+#[tauri::command]
+pub async fn get_group_records_comparison(
+    state: State<'_, AppState>,
+    group_id: String,
+) -> Result<ApiResponse<DomainGroupComparison>, DnsError> {
+    let comparison = state
+        .domain_group_service
+        .get_group_records_comparison(&group_id)
+        .await?;
+    Ok(ApiResponse::success(comparison))
+}
+
+/// 以某一成员为准生成同步变更集（不执行）
+#[tauri::command]
+pub async fn preview_group_sync(
+    state: State<'_, AppState>,
+    group_id: String,
+    source: DomainGroupMember,
+) -> Result<ApiResponse<Vec<DomainGroupChangeset>>, DnsError> {
+    let changesets = state
+        .domain_group_service
+        .preview_group_sync(&group_id, &source)
+        .await?;
+    Ok(ApiResponse::success(changesets))
+}
+
+/// 执行确认后的同步变更集
+#[tauri::command]
+pub async fn apply_group_sync(
+    state: State<'_, AppState>,
+    group_id: String,
+    changesets: Vec<DomainGroupChangeset>,
+) -> Result<ApiResponse<DomainGroupSyncResult>, DnsError> {
+    let result = state
+        .domain_group_service
+        .apply_group_sync(&group_id, changesets)
+        .await?;
+    Ok(ApiResponse::success(result))
+}
@@ -0,0 +1,48 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{
+    CertificateReport, DomainExpiryEntry, DomainExpiryReport, DomainExpiryThresholds,
+};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 检查所有账户下域名的注册到期时间
+#[tauri::command]
+pub async fn check_domain_expiry(
+    state: State<'_, AppState>,
+    thresholds: Option<DomainExpiryThresholds>,
+) -> Result<ApiResponse<DomainExpiryReport>, DnsError> {
+    let report = state
+        .domain_monitor_service
+        .check_domain_expiry(thresholds)
+        .await?;
+    Ok(ApiResponse::success(report))
+}
+
+/// 列出最近一次到期检查中需要关注的域名
+#[tauri::command]
+pub async fn list_expiry_warnings(
+    state: State<'_, AppState>,
+    thresholds: Option<DomainExpiryThresholds>,
+) -> Result<ApiResponse<Vec<DomainExpiryEntry>>, DnsError> {
+    let warnings = state
+        .domain_monitor_service
+        .list_expiry_warnings(thresholds)
+        .await?;
+    Ok(ApiResponse::success(warnings))
+}
+
+/// 检查账户下域名（根域名与 `www`）的 SSL 证书
+#[tauri::command]
+pub async fn get_certificate_report(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<CertificateReport>, DnsError> {
+    let report = state
+        .domain_monitor_service
+        .certificate_report(&account_id)
+        .await?;
+    Ok(ApiResponse::success(report))
+}
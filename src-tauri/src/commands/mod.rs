@@ -1,8 +1,19 @@
 pub mod account;
+pub mod audit_log;
+pub mod background_job;
+pub mod change_window;
+pub mod deep_link;
 pub mod dns;
 pub mod domain;
+pub mod domain_group;
 pub mod domain_metadata;
+pub mod domain_monitor;
+pub mod record_copy;
+pub mod record_import;
+pub mod temporary_record;
 pub mod toolbox;
+pub mod weighted_rollout;
+pub mod write_queue;
 
 #[cfg(target_os = "android")]
 pub mod updater;
@@ -0,0 +1,26 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{RecordCopyRequest, RecordCopyResult};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 复制域名记录到另一个账户的域名（`options.dryRun` 时只返回计划）
+#[tauri::command]
+pub async fn copy_dns_records(
+    state: State<'_, AppState>,
+    request: RecordCopyRequest,
+) -> Result<ApiResponse<RecordCopyResult>, DnsError> {
+    let result = state
+        .record_copy_service
+        .copy_records(
+            &request.source_account_id,
+            &request.source_domain_id,
+            &request.target_account_id,
+            &request.target_domain_id,
+            request.options,
+        )
+        .await?;
+    Ok(ApiResponse::success(result))
+}
@@ -0,0 +1,30 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{RecordImportPreview, RecordImportRequest, RecordImportResult};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 预览记录导入（octoDNS / dnscontrol）
+#[tauri::command]
+pub async fn preview_record_import(
+    state: State<'_, AppState>,
+    request: RecordImportRequest,
+) -> Result<ApiResponse<RecordImportPreview>, DnsError> {
+    let preview = state
+        .record_import_service
+        .preview_record_import(&request)
+        .await?;
+    Ok(ApiResponse::success(preview))
+}
+
+/// 执行记录导入
+#[tauri::command]
+pub async fn import_records(
+    state: State<'_, AppState>,
+    request: RecordImportRequest,
+) -> Result<ApiResponse<RecordImportResult>, DnsError> {
+    let result = state.record_import_service.import_records(request).await?;
+    Ok(ApiResponse::success(result))
+}
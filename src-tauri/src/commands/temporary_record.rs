@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use tauri::State;
+
+use dns_orchestrator_core::types::{CreateDnsRecordRequest, TemporaryRecord};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 创建临时记录（到点创建、到点删除）
+#[tauri::command]
+pub async fn create_temporary_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    request: CreateDnsRecordRequest,
+    active_from: DateTime<Utc>,
+    active_until: DateTime<Utc>,
+) -> Result<ApiResponse<TemporaryRecord>, DnsError> {
+    let record = state
+        .temporary_record_service
+        .create_temporary_record(&account_id, &domain_id, request, active_from, active_until)
+        .await?;
+    Ok(ApiResponse::success(record))
+}
+
+/// 列出所有临时记录
+#[tauri::command]
+pub async fn list_temporary_records(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<TemporaryRecord>>, DnsError> {
+    let records = state
+        .temporary_record_service
+        .list_temporary_records()
+        .await?;
+    Ok(ApiResponse::success(records))
+}
+
+/// 获取临时记录状态
+#[tauri::command]
+pub async fn get_temporary_record(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<ApiResponse<TemporaryRecord>, DnsError> {
+    let record = state
+        .temporary_record_service
+        .get_temporary_record(&id)
+        .await?;
+    Ok(ApiResponse::success(record))
+}
+
+/// 修改临时记录的生效时间段
+#[tauri::command]
+pub async fn reschedule_temporary_record(
+    state: State<'_, AppState>,
+    id: String,
+    active_from: DateTime<Utc>,
+    active_until: DateTime<Utc>,
+) -> Result<ApiResponse<TemporaryRecord>, DnsError> {
+    let record = state
+        .temporary_record_service
+        .reschedule_temporary_record(&id, active_from, active_until)
+        .await?;
+    Ok(ApiResponse::success(record))
+}
+
+/// 取消临时记录（已创建的记录保留）
+#[tauri::command]
+pub async fn cancel_temporary_record(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<ApiResponse<()>, DnsError> {
+    state
+        .temporary_record_service
+        .cancel_temporary_record(&id)
+        .await?;
+    Ok(ApiResponse::success(()))
+}
@@ -1,15 +1,37 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_store::StoreExt;
+
 use dns_orchestrator_core::services::ToolboxService;
 use dns_orchestrator_core::types::{
-    DnsLookupResult, DnsPropagationResult, DnssecResult, HttpHeaderCheckRequest,
-    HttpHeaderCheckResult, IpLookupResult, SslCheckResult, WhoisResult,
+    CaaCheckResult, DnsBenchmarkProgress, DnsBenchmarkResult, DnsLookupResult, DnsLookupTransport,
+    DnsPropagationResult, DnssecResult, EmailSecurityResult, ExternalEndpoint,
+    HttpHeaderCheckRequest, HttpHeaderCheckResult, HttpTraceResult, IpLookupBatchEntry,
+    IpLookupResult, MailCheckResult, NetworkEnvInfo, PortCheckResult, PortProtocol, PrivacyConfig,
+    PropagationCheckResult, ReverseLookupResult, SslCheckResult, SslCheckTarget, ThreatIntelConfig,
+    TraceRouteResult, WhoisResult,
 };
 
+use crate::error::DnsError;
 use crate::types::ApiResponse;
+use crate::AppState;
 
-/// WHOIS 查询
+/// WHOIS 查询（`force_refresh` 为 true 时跳过缓存）
 #[tauri::command]
-pub async fn whois_lookup(domain: String) -> Result<ApiResponse<WhoisResult>, String> {
-    let result = ToolboxService::whois_lookup(&domain)
+pub async fn whois_lookup(
+    state: State<'_, AppState>,
+    domain: String,
+    force_refresh: Option<bool>,
+) -> Result<ApiResponse<WhoisResult>, String> {
+    let result = state
+        .toolbox_service
+        .whois_lookup(&domain, force_refresh.unwrap_or(false))
         .await
         .map_err(|e| e.to_string())?;
 
@@ -22,37 +44,223 @@ pub async fn dns_lookup(
     domain: String,
     record_type: String,
     nameserver: Option<String>,
+    transport: Option<DnsLookupTransport>,
 ) -> Result<ApiResponse<DnsLookupResult>, String> {
-    let result = ToolboxService::dns_lookup(&domain, &record_type, nameserver.as_deref())
-        .await
-        .map_err(|e| e.to_string())?;
+    let result =
+        ToolboxService::dns_lookup(&domain, &record_type, nameserver.as_deref(), transport)
+            .await
+            .map_err(|e| e.to_string())?;
 
     Ok(ApiResponse::success(result))
 }
 
-/// IP/域名 地理位置查询
+/// 反向 DNS（PTR）查询
 #[tauri::command]
-pub async fn ip_lookup(query: String) -> Result<ApiResponse<IpLookupResult>, String> {
-    let result = ToolboxService::ip_lookup(&query)
+pub async fn reverse_lookup(
+    ip: String,
+    nameserver: Option<String>,
+) -> Result<ApiResponse<ReverseLookupResult>, String> {
+    let result = ToolboxService::reverse_lookup(&ip, nameserver.as_deref())
         .await
         .map_err(|e| e.to_string())?;
 
     Ok(ApiResponse::success(result))
 }
 
+/// IP/域名 地理位置查询（配置了威胁情报源时附带标注）
+///
+/// 隐私模式下需要 `confirmExternal` 为 true。
+#[tauri::command]
+pub async fn ip_lookup(
+    state: State<'_, AppState>,
+    query: String,
+    confirm_external: Option<bool>,
+) -> Result<ApiResponse<IpLookupResult>, DnsError> {
+    let result = state
+        .toolbox_service
+        .ip_lookup(&query, confirm_external.unwrap_or(false))
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 批量 IP/域名/CIDR 地理位置查询（最多 100 条，结果以原始查询为键）
+///
+/// 隐私模式下需要 `confirmExternal` 为 true。
+#[tauri::command]
+pub async fn ip_lookup_batch(
+    state: State<'_, AppState>,
+    queries: Vec<String>,
+    confirm_external: Option<bool>,
+) -> Result<ApiResponse<BTreeMap<String, IpLookupBatchEntry>>, DnsError> {
+    let result = state
+        .toolbox_service
+        .ip_lookup_batch(queries, confirm_external.unwrap_or(false))
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+const PRIVACY_STORE: &str = "privacy.json";
+const PRIVACY_CONFIG_KEY: &str = "config";
+
+/// 读取已保存的隐私模式配置并应用到工具箱服务（启动时调用）
+pub fn restore_privacy_config(app_handle: &AppHandle) {
+    let config: PrivacyConfig = app_handle
+        .store(PRIVACY_STORE)
+        .ok()
+        .and_then(|store| store.get(PRIVACY_CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default();
+    app_handle
+        .state::<AppState>()
+        .toolbox_service
+        .set_privacy_config(&config);
+}
+
+/// 获取隐私模式配置
+#[tauri::command]
+pub async fn get_privacy_config(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<PrivacyConfig>, String> {
+    Ok(ApiResponse::success(state.toolbox_service.privacy_config()))
+}
+
+/// 保存隐私模式配置
+#[tauri::command]
+pub async fn set_privacy_config(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    config: PrivacyConfig,
+) -> Result<ApiResponse<()>, String> {
+    let store = app_handle
+        .store(PRIVACY_STORE)
+        .map_err(|e| format!("Failed to access store: {e}"))?;
+    let value = serde_json::to_value(&config).map_err(|e| e.to_string())?;
+    store.set(PRIVACY_CONFIG_KEY.to_string(), value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save store: {e}"))?;
+
+    state.toolbox_service.set_privacy_config(&config);
+    Ok(ApiResponse::success(()))
+}
+
+/// 列出当前配置下可能访问的第三方端点及用途
+#[tauri::command]
+pub async fn list_external_endpoints(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<ExternalEndpoint>>, String> {
+    Ok(ApiResponse::success(
+        state.toolbox_service.list_external_endpoints().await,
+    ))
+}
+
+const THREAT_INTEL_STORE: &str = "threat_intel.json";
+const THREAT_INTEL_CONFIG_KEY: &str = "config";
+const DROP_SNAPSHOT_FILE: &str = "spamhaus_drop.json";
+
+/// Spamhaus DROP 列表快照路径（应用数据目录）
+fn drop_snapshot_path(app_handle: &AppHandle) -> Option<PathBuf> {
+    app_handle
+        .path()
+        .app_data_dir()
+        .ok()
+        .map(|dir| dir.join(DROP_SNAPSHOT_FILE))
+}
+
+/// 读取已保存的威胁情报配置并应用到工具箱服务（启动时调用）
+pub async fn restore_threat_intel_config(app_handle: &AppHandle) -> ThreatIntelConfig {
+    let config = app_handle
+        .store(THREAT_INTEL_STORE)
+        .ok()
+        .and_then(|store| store.get(THREAT_INTEL_CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default();
+    let state = app_handle.state::<AppState>();
+    state
+        .toolbox_service
+        .set_threat_intel(config.clone(), drop_snapshot_path(app_handle))
+        .await;
+    config
+}
+
+/// 获取威胁情报源配置
+#[tauri::command]
+pub async fn get_threat_intel_config(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<ThreatIntelConfig>, String> {
+    Ok(ApiResponse::success(
+        state.toolbox_service.threat_intel_config().await,
+    ))
+}
+
+/// 保存威胁情报源配置；启用 DROP 时立即下载一次列表
+#[tauri::command]
+pub async fn set_threat_intel_config(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    config: ThreatIntelConfig,
+) -> Result<ApiResponse<()>, String> {
+    let store = app_handle
+        .store(THREAT_INTEL_STORE)
+        .map_err(|e| format!("Failed to access store: {e}"))?;
+    let value = serde_json::to_value(&config).map_err(|e| e.to_string())?;
+    store.set(THREAT_INTEL_CONFIG_KEY.to_string(), value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save store: {e}"))?;
+
+    let spamhaus_drop = config.spamhaus_drop;
+    state
+        .toolbox_service
+        .set_threat_intel(config, drop_snapshot_path(&app_handle))
+        .await;
+    if spamhaus_drop {
+        if let Err(e) = state
+            .background_job_runner
+            .trigger_job(crate::jobs::THREAT_INTEL_REFRESH_JOB)
+            .await
+        {
+            log::warn!("Failed to refresh threat intel: {e}");
+        }
+    }
+    Ok(ApiResponse::success(()))
+}
+
 /// SSL 证书检查
+///
+/// `connect_addr` 指定时连接该地址、SNI 仍使用 `domain`；
+/// `probe_versions` 为 true 时逐一探测 TLS 1.0 ~ 1.3。
 #[tauri::command]
 pub async fn ssl_check(
     domain: String,
     port: Option<u16>,
+    connect_addr: Option<String>,
+    probe_versions: Option<bool>,
 ) -> Result<ApiResponse<SslCheckResult>, String> {
-    let result = ToolboxService::ssl_check(&domain, port)
-        .await
-        .map_err(|e| e.to_string())?;
+    let result = ToolboxService::ssl_check(
+        &domain,
+        port,
+        connect_addr.as_deref(),
+        probe_versions.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     Ok(ApiResponse::success(result))
 }
 
+/// 批量 SSL 证书检查（结果与输入顺序一致，单个目标失败不影响整批）
+#[tauri::command]
+pub async fn ssl_check_batch(
+    targets: Vec<SslCheckTarget>,
+) -> Result<ApiResponse<Vec<SslCheckResult>>, String> {
+    let results = ToolboxService::ssl_check_batch(targets).await;
+
+    Ok(ApiResponse::success(results))
+}
+
 /// HTTP 头检查
 #[tauri::command]
 pub async fn http_header_check(
@@ -65,15 +273,89 @@ pub async fn http_header_check(
     Ok(ApiResponse::success(result))
 }
 
-/// DNS 传播检查
+/// HTTP 重定向链跟踪（`max_redirects` 默认 10）
+#[tauri::command]
+pub async fn http_trace(
+    url: String,
+    max_redirects: Option<u8>,
+) -> Result<ApiResponse<HttpTraceResult>, String> {
+    let result = ToolboxService::http_trace(&url, max_redirects.unwrap_or(10))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 端口连通性检查（`protocol` 默认 TCP，`timeout_ms` 默认 3000）
+#[tauri::command]
+pub async fn port_check(
+    host: String,
+    ports: Vec<u16>,
+    protocol: Option<PortProtocol>,
+    timeout_ms: Option<u64>,
+) -> Result<ApiResponse<PortCheckResult>, String> {
+    let result = ToolboxService::port_check(
+        &host,
+        ports,
+        protocol.unwrap_or_default(),
+        timeout_ms.unwrap_or(3000),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 路径追踪（`max_hops` 默认 30，`probes_per_hop` 默认 3）
+///
+/// 缺少原始套接字权限且无法回退时返回结构化的 `CAPABILITY_UNAVAILABLE` 错误。
+#[tauri::command]
+pub async fn trace_route(
+    host: String,
+    max_hops: Option<u8>,
+    probes_per_hop: Option<u8>,
+) -> Result<ApiResponse<TraceRouteResult>, DnsError> {
+    let result =
+        ToolboxService::trace_route(&host, max_hops.unwrap_or(30), probes_per_hop.unwrap_or(3))
+            .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// DNS 传播检查（隐私模式下需要 `confirmExternal` 为 true）
 #[tauri::command]
 pub async fn dns_propagation_check(
+    state: State<'_, AppState>,
     domain: String,
     record_type: String,
-) -> Result<ApiResponse<DnsPropagationResult>, String> {
-    let result = ToolboxService::dns_propagation_check(&domain, &record_type)
-        .await
-        .map_err(|e| e.to_string())?;
+    confirm_external: Option<bool>,
+) -> Result<ApiResponse<DnsPropagationResult>, DnsError> {
+    let result = state
+        .toolbox_service
+        .dns_propagation_check(&domain, &record_type, confirm_external.unwrap_or(false))
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 期望值传播检查（隐私模式下需要 `confirmExternal` 为 true）
+#[tauri::command]
+pub async fn propagation_check(
+    state: State<'_, AppState>,
+    domain: String,
+    record_type: String,
+    expected_value: String,
+    confirm_external: Option<bool>,
+) -> Result<ApiResponse<PropagationCheckResult>, DnsError> {
+    let result = state
+        .toolbox_service
+        .propagation_check(
+            &domain,
+            &record_type,
+            &expected_value,
+            confirm_external.unwrap_or(false),
+        )
+        .await?;
 
     Ok(ApiResponse::success(result))
 }
@@ -90,3 +372,113 @@ pub async fn dnssec_check(
 
     Ok(ApiResponse::success(result))
 }
+
+/// 邮件安全检查（SPF / DKIM / DMARC）
+#[tauri::command]
+pub async fn email_security_check(
+    domain: String,
+    dkim_selector: Option<String>,
+) -> Result<ApiResponse<EmailSecurityResult>, String> {
+    let result = ToolboxService::email_security_check(&domain, dkim_selector.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 邮件服务健康检查（MX / SMTP 连通性 / SPF / DMARC），`timeoutSecs` 为整体超时
+#[tauri::command]
+pub async fn mail_check(
+    domain: String,
+    timeout_secs: Option<u64>,
+) -> Result<ApiResponse<MailCheckResult>, String> {
+    let result = ToolboxService::mail_check(&domain, timeout_secs.map(Duration::from_secs))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// CAA 记录检查
+#[tauri::command]
+pub async fn caa_check(domain: String) -> Result<ApiResponse<CaaCheckResult>, String> {
+    let result = ToolboxService::caa_check(&domain)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 获取系统 DNS 服务器
+#[tauri::command]
+pub async fn get_system_dns() -> Result<ApiResponse<Vec<String>>, String> {
+    Ok(ApiResponse::success(ToolboxService::get_system_dns()))
+}
+
+/// 本机网络环境诊断（隐私模式下不探测出口 IP）
+#[tauri::command]
+pub async fn network_env_info(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<NetworkEnvInfo>, String> {
+    Ok(ApiResponse::success(
+        state.toolbox_service.network_env_info().await,
+    ))
+}
+
+/// DNS 解析性能基准测试
+///
+/// `benchmark_id` 由前端生成，用于 `cancel_dns_benchmark` 取消
+#[tauri::command]
+pub async fn dns_benchmark(
+    state: State<'_, AppState>,
+    benchmark_id: String,
+    domain: String,
+    nameservers: Vec<String>,
+    iterations: u32,
+    concurrency: u32,
+    on_progress: Channel<DnsBenchmarkProgress>,
+) -> Result<ApiResponse<DnsBenchmarkResult>, String> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    state
+        .dns_benchmark_cancellations
+        .write()
+        .await
+        .insert(benchmark_id.clone(), Arc::clone(&cancelled));
+
+    let result = ToolboxService::dns_benchmark(
+        &domain,
+        &nameservers,
+        iterations,
+        concurrency,
+        &|progress| {
+            let _ = on_progress.send(progress);
+        },
+        &cancelled,
+    )
+    .await;
+
+    state
+        .dns_benchmark_cancellations
+        .write()
+        .await
+        .remove(&benchmark_id);
+
+    Ok(ApiResponse::success(result.map_err(|e| e.to_string())?))
+}
+
+/// 取消正在进行的 DNS 基准测试（已结束时无效果）
+#[tauri::command]
+pub async fn cancel_dns_benchmark(
+    state: State<'_, AppState>,
+    benchmark_id: String,
+) -> Result<ApiResponse<()>, String> {
+    if let Some(cancelled) = state
+        .dns_benchmark_cancellations
+        .read()
+        .await
+        .get(&benchmark_id)
+    {
+        cancelled.store(true, Ordering::SeqCst);
+    }
+    Ok(ApiResponse::success(()))
+}
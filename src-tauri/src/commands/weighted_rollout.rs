@@ -0,0 +1,84 @@
+use tauri::State;
+
+use dns_orchestrator_core::types::{CreateWeightedRolloutRequest, WeightedRollout};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+/// 创建权重灰度切换任务
+#[tauri::command]
+pub async fn create_weighted_rollout(
+    state: State<'_, AppState>,
+    request: CreateWeightedRolloutRequest,
+) -> Result<ApiResponse<WeightedRollout>, DnsError> {
+    let rollout = state
+        .weighted_rollout_service
+        .create_weighted_rollout(request)
+        .await?;
+    Ok(ApiResponse::success(rollout))
+}
+
+/// 获取灰度任务状态
+#[tauri::command]
+pub async fn get_weighted_rollout(
+    state: State<'_, AppState>,
+    rollout_id: String,
+) -> Result<ApiResponse<WeightedRollout>, DnsError> {
+    let rollout = state
+        .weighted_rollout_service
+        .get_weighted_rollout(&rollout_id)
+        .await?;
+    Ok(ApiResponse::success(rollout))
+}
+
+/// 列出所有灰度任务
+#[tauri::command]
+pub async fn list_weighted_rollouts(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<WeightedRollout>>, DnsError> {
+    let rollouts = state
+        .weighted_rollout_service
+        .list_weighted_rollouts()
+        .await;
+    Ok(ApiResponse::success(rollouts))
+}
+
+/// 暂停灰度任务
+#[tauri::command]
+pub async fn pause_weighted_rollout(
+    state: State<'_, AppState>,
+    rollout_id: String,
+) -> Result<ApiResponse<WeightedRollout>, DnsError> {
+    let rollout = state
+        .weighted_rollout_service
+        .pause_weighted_rollout(&rollout_id)
+        .await?;
+    Ok(ApiResponse::success(rollout))
+}
+
+/// 恢复灰度任务
+#[tauri::command]
+pub async fn resume_weighted_rollout(
+    state: State<'_, AppState>,
+    rollout_id: String,
+) -> Result<ApiResponse<WeightedRollout>, DnsError> {
+    let rollout = state
+        .weighted_rollout_service
+        .resume_weighted_rollout(&rollout_id)
+        .await?;
+    Ok(ApiResponse::success(rollout))
+}
+
+/// 立即回退到 0%
+#[tauri::command]
+pub async fn rollback_weighted_rollout(
+    state: State<'_, AppState>,
+    rollout_id: String,
+) -> Result<ApiResponse<WeightedRollout>, DnsError> {
+    let rollout = state
+        .weighted_rollout_service
+        .rollback_weighted_rollout(&rollout_id)
+        .await?;
+    Ok(ApiResponse::success(rollout))
+}
@@ -0,0 +1,88 @@
+use tauri::{AppHandle, State};
+use tauri_plugin_store::StoreExt;
+
+use dns_orchestrator_core::error::CoreError;
+use dns_orchestrator_core::types::{QueuedWrite, WriteQueueConfig, WriteReplayOutcome};
+
+use crate::error::DnsError;
+use crate::types::ApiResponse;
+use crate::AppState;
+
+const WRITE_QUEUE_STORE: &str = "write_queue.json";
+const WRITE_QUEUE_CONFIG_KEY: &str = "config";
+
+/// 读取已保存的离线写队列配置（启动时调用，未配置时默认关闭）
+pub fn load_write_queue_config(app_handle: &AppHandle) -> WriteQueueConfig {
+    app_handle
+        .store(WRITE_QUEUE_STORE)
+        .ok()
+        .and_then(|store| store.get(WRITE_QUEUE_CONFIG_KEY))
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+/// 获取离线写队列配置
+#[tauri::command]
+pub async fn get_write_queue_config(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<WriteQueueConfig>, DnsError> {
+    Ok(ApiResponse::success(
+        state.write_queue_service.config().await,
+    ))
+}
+
+/// 保存离线写队列配置
+#[tauri::command]
+pub async fn set_write_queue_config(
+    app_handle: AppHandle,
+    state: State<'_, AppState>,
+    config: WriteQueueConfig,
+) -> Result<ApiResponse<()>, DnsError> {
+    state.write_queue_service.set_config(config.clone()).await?;
+
+    let store = app_handle
+        .store(WRITE_QUEUE_STORE)
+        .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+    let value =
+        serde_json::to_value(&config).map_err(|e| CoreError::SerializationError(e.to_string()))?;
+    store.set(WRITE_QUEUE_CONFIG_KEY.to_string(), value);
+    store
+        .save()
+        .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+    Ok(ApiResponse::success(()))
+}
+
+/// 列出待重放的离线写操作（已过期的条目会被清理）
+#[tauri::command]
+pub async fn list_queued_writes(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<Vec<QueuedWrite>>, DnsError> {
+    let entries = state.write_queue_service.list().await?;
+    Ok(ApiResponse::success(entries))
+}
+
+/// 丢弃指定的离线写操作
+#[tauri::command]
+pub async fn discard_queued_writes(
+    state: State<'_, AppState>,
+    ids: Vec<String>,
+) -> Result<ApiResponse<usize>, DnsError> {
+    let removed = state.write_queue_service.discard(&ids).await?;
+    Ok(ApiResponse::success(removed))
+}
+
+/// 重放指定的离线写操作
+///
+/// 重放前会重新检查冲突；`force` 为 true 时才会覆盖已被他人修改的记录。
+#[tauri::command]
+pub async fn replay_queued_writes(
+    state: State<'_, AppState>,
+    ids: Vec<String>,
+    force: Option<bool>,
+) -> Result<ApiResponse<Vec<WriteReplayOutcome>>, DnsError> {
+    let outcomes = state
+        .dns_service
+        .replay_queued_writes(&ids, force.unwrap_or(false))
+        .await?;
+    Ok(ApiResponse::success(outcomes))
+}
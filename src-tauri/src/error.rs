@@ -1,4 +1,7 @@
-use serde::Serialize;
+use chrono::{DateTime, Utc};
+use dns_orchestrator_core::types::ErrorSuggestion;
+use dns_orchestrator_core::utils::error_suggestions;
+use serde::{Serialize, Serializer};
 use thiserror::Error;
 
 // ============ Re-export 库错误类型 ============
@@ -15,8 +18,9 @@ pub use dns_orchestrator_core::error::CoreError;
 
 // ============ 应用层错误类型 ============
 
+/// 序列化为 `{ code, details }`，有匹配的修复建议时附带 `suggestions`（见下方 `Serialize` 实现）
 #[derive(Error, Debug, Serialize)]
-#[serde(tag = "code", content = "details")]
+#[serde(remote = "Self", tag = "code", content = "details")]
 pub enum DnsError {
     #[error("Provider not found: {0}")]
     ProviderNotFound(String),
@@ -30,6 +34,9 @@ pub enum DnsError {
     #[error("Record not found: {0}")]
     RecordNotFound(String),
 
+    #[error("Rollout not found: {0}")]
+    RolloutNotFound(String),
+
     #[error("Credential error: {0}")]
     CredentialError(String),
 
@@ -43,12 +50,24 @@ pub enum DnsError {
     #[error("Invalid credentials")]
     InvalidCredentials,
 
+    /// 凭证（API token）已过期，需要更新
+    #[error("Credentials of account {account_id} expired at {expired_at}")]
+    CredentialsExpired {
+        account_id: String,
+        expired_at: DateTime<Utc>,
+    },
+
     #[error("Serialization error: {0}")]
     SerializationError(String),
 
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// 记录内容校验失败，`field` 供前端定位出错的输入框
+    #[error("Validation error: {message}")]
+    #[serde(rename = "RECORD_VALIDATION")]
+    RecordValidation { field: String, message: String },
+
     #[error("Import/Export error: {0}")]
     ImportExportError(String),
 
@@ -60,6 +79,47 @@ pub enum DnsError {
     #[error("Unsupported file version")]
     UnsupportedFileVersion,
 
+    /// Provider 调用超时
+    #[error("Operation timed out: {operation} ({timeout_ms}ms)")]
+    Timeout { operation: String, timeout_ms: u64 },
+
+    /// 当前处于禁止变更时段
+    #[error("Change window closed by rule '{rule_name}', next allowed at {next_open_at}")]
+    #[serde(rename = "CHANGE_WINDOW_CLOSED")]
+    ChangeWindowClosed {
+        rule_name: String,
+        next_open_at: DateTime<Utc>,
+    },
+
+    /// 因网络错误未能写入，已加入离线写队列
+    #[error("Write queued for replay as {queue_id}: {reason}")]
+    #[serde(rename = "WRITE_QUEUED")]
+    WriteQueued { queue_id: String, reason: String },
+
+    /// 资源正被其他写操作占用
+    #[error("Resource {resource} is busy with operation {holder} (waited {timeout_ms}ms)")]
+    #[serde(rename = "RESOURCE_BUSY")]
+    ResourceBusy {
+        resource: String,
+        holder: String,
+        timeout_ms: u64,
+    },
+
+    /// 当前平台或权限下无法执行该操作
+    #[error("Missing capability '{capability}': {detail}")]
+    #[serde(rename = "CAPABILITY_UNAVAILABLE")]
+    CapabilityUnavailable { capability: String, detail: String },
+
+    /// 隐私模式下未经确认访问非必需的第三方端点
+    #[error("External access to '{endpoint}' blocked by privacy mode: {purpose}")]
+    #[serde(rename = "EXTERNAL_ACCESS_BLOCKED")]
+    ExternalAccessBlocked { endpoint: String, purpose: String },
+
+    /// 服务商连续网络错误，暂停调用
+    #[error("Provider temporarily unavailable for account {0}, retry later")]
+    #[serde(rename = "SERVICE_UNAVAILABLE")]
+    ServiceUnavailable(String),
+
     /// Provider 错误（从库转换）
     #[error("{0}")]
     Provider(#[from] ProviderError),
@@ -73,12 +133,23 @@ impl From<CoreError> for DnsError {
             CoreError::AccountNotFound(s) => Self::AccountNotFound(s),
             CoreError::DomainNotFound(s) => Self::DomainNotFound(s),
             CoreError::RecordNotFound(s) => Self::RecordNotFound(s),
+            CoreError::RolloutNotFound(s) => Self::RolloutNotFound(s),
             CoreError::CredentialError(s) | CoreError::StorageError(s) => Self::CredentialError(s),
             CoreError::CredentialValidation(e) => Self::CredentialValidation(e),
             CoreError::ApiError { provider, message } => Self::ApiError { provider, message },
             CoreError::InvalidCredentials(_) => Self::InvalidCredentials,
+            CoreError::CredentialsExpired {
+                account_id,
+                expired_at,
+            } => Self::CredentialsExpired {
+                account_id,
+                expired_at,
+            },
             CoreError::SerializationError(s) => Self::SerializationError(s),
             CoreError::ValidationError(s) => Self::ValidationError(s),
+            CoreError::RecordValidation { field, message } => {
+                Self::RecordValidation { field, message }
+            }
             CoreError::ImportExportError(s) => Self::ImportExportError(s),
             CoreError::NoAccountsSelected => Self::NoAccountsSelected,
             CoreError::UnsupportedFileVersion => Self::UnsupportedFileVersion,
@@ -93,7 +164,66 @@ impl From<CoreError> for DnsError {
             CoreError::MigrationFailed(s) => {
                 Self::CredentialError(format!("Migration failed: {s}"))
             }
+            CoreError::Timeout {
+                operation,
+                timeout_ms,
+            } => Self::Timeout {
+                operation,
+                timeout_ms,
+            },
+            CoreError::ChangeWindowClosed {
+                rule_name,
+                next_open_at,
+            } => Self::ChangeWindowClosed {
+                rule_name,
+                next_open_at,
+            },
+            CoreError::WriteQueued { queue_id, reason } => Self::WriteQueued { queue_id, reason },
+            CoreError::ResourceBusy {
+                resource,
+                holder,
+                timeout_ms,
+            } => Self::ResourceBusy {
+                resource,
+                holder,
+                timeout_ms,
+            },
+            CoreError::CapabilityUnavailable { capability, detail } => {
+                Self::CapabilityUnavailable { capability, detail }
+            }
+            CoreError::ExternalAccessBlocked { endpoint, purpose } => {
+                Self::ExternalAccessBlocked { endpoint, purpose }
+            }
+            CoreError::ServiceUnavailable(account_id) => Self::ServiceUnavailable(account_id),
             CoreError::Provider(e) => Self::Provider(e),
         }
     }
 }
+
+/// 派生的 `{ code, details }` 序列化（`remote = "Self"` 生成的 `DnsError::serialize`）
+struct DnsErrorFields<'a>(&'a DnsError);
+
+impl Serialize for DnsErrorFields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DnsError::serialize(self.0, serializer)
+    }
+}
+
+impl Serialize for DnsError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            #[serde(flatten)]
+            error: DnsErrorFields<'a>,
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            suggestions: Vec<ErrorSuggestion>,
+        }
+
+        let error = DnsErrorFields(self);
+        Body {
+            suggestions: error_suggestions::suggestions_for(&error),
+            error,
+        }
+        .serialize(serializer)
+    }
+}
@@ -0,0 +1,312 @@
+//! Tauri 端注册的后台任务
+
+use std::sync::atomic::Ordering;
+
+use async_trait::async_trait;
+use tauri::{AppHandle, Emitter, Manager};
+
+use dns_orchestrator_core::error::CoreResult;
+use dns_orchestrator_core::traits::BackgroundJob;
+use dns_orchestrator_core::types::{JobSchedule, TemporaryRecordStatus};
+
+use crate::AppState;
+
+/// 账户恢复任务名称
+pub const ACCOUNT_RESTORE_JOB: &str = "account_restore";
+
+/// 账户恢复（启动时触发一次，也可在系统状态页手动重试）
+pub struct AccountRestoreJob {
+    app_handle: AppHandle,
+}
+
+impl AccountRestoreJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for AccountRestoreJob {
+    fn name(&self) -> &str {
+        ACCOUNT_RESTORE_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Manual
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        let result = state.account_bootstrap_service.restore_accounts().await;
+        state.restore_completed.store(true, Ordering::SeqCst);
+
+        let restore_result = result?;
+        log::info!(
+            "Account restoration complete: {} succeeded, {} failed",
+            restore_result.success_count,
+            restore_result.error_count
+        );
+        Ok(Some(format!(
+            "{} succeeded, {} failed",
+            restore_result.success_count, restore_result.error_count
+        )))
+    }
+}
+
+/// 凭证到期检查任务名称
+pub const CREDENTIAL_EXPIRY_JOB: &str = "credential_expiry";
+
+/// 凭证到期检查间隔（6 小时）
+const CREDENTIAL_EXPIRY_INTERVAL_SECS: u64 = 6 * 60 * 60;
+
+/// 前端监听的凭证到期事件名
+pub const CREDENTIAL_EXPIRY_EVENT: &str = "credential-expiry";
+
+/// 凭证到期检查：标记已过期账户，进入新提醒档位时通知前端
+pub struct CredentialExpiryJob {
+    app_handle: AppHandle,
+}
+
+impl CredentialExpiryJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for CredentialExpiryJob {
+    fn name(&self) -> &str {
+        CREDENTIAL_EXPIRY_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: CREDENTIAL_EXPIRY_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        let check = state.credential_expiry_service.check_expirations().await?;
+
+        if !check.reminders.is_empty() || !check.expired.is_empty() {
+            if let Err(e) = self.app_handle.emit(CREDENTIAL_EXPIRY_EVENT, &check) {
+                log::warn!("Failed to emit credential expiry event: {e}");
+            }
+        }
+
+        Ok(Some(format!(
+            "{} reminders, {} expired",
+            check.reminders.len(),
+            check.expired.len()
+        )))
+    }
+}
+
+/// 凭证校验任务名称
+pub const CREDENTIAL_VALIDATION_JOB: &str = "credential_validation";
+
+/// 凭证定期校验间隔（12 小时）
+const CREDENTIAL_VALIDATION_INTERVAL_SECS: u64 = 12 * 60 * 60;
+
+/// 定期校验全部账户的凭证，提前发现被吊销的凭证
+pub struct CredentialValidationJob {
+    app_handle: AppHandle,
+}
+
+impl CredentialValidationJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for CredentialValidationJob {
+    fn name(&self) -> &str {
+        CREDENTIAL_VALIDATION_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: CREDENTIAL_VALIDATION_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        let summary = state
+            .account_validation_service
+            .validate_all_accounts()
+            .await?;
+        Ok(Some(format!(
+            "{} valid, {} invalid, {} unknown",
+            summary.valid_count, summary.invalid_count, summary.unknown_count
+        )))
+    }
+}
+
+/// 威胁情报刷新任务名称
+pub const THREAT_INTEL_REFRESH_JOB: &str = "threat_intel_refresh";
+
+/// Spamhaus DROP 列表刷新间隔（24 小时）
+const THREAT_INTEL_REFRESH_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// 下载最新的 Spamhaus DROP 列表到本地快照（未启用 DROP 时跳过）
+pub struct ThreatIntelRefreshJob {
+    app_handle: AppHandle,
+}
+
+impl ThreatIntelRefreshJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for ThreatIntelRefreshJob {
+    fn name(&self) -> &str {
+        THREAT_INTEL_REFRESH_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: THREAT_INTEL_REFRESH_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        Ok(Some(
+            match state.toolbox_service.refresh_threat_intel().await? {
+                Some(count) => format!("{count} DROP entries"),
+                None => "Spamhaus DROP disabled or privacy mode on".to_string(),
+            },
+        ))
+    }
+}
+
+/// 临时记录任务名称
+pub const TEMPORARY_RECORD_JOB: &str = "temporary_record";
+
+/// 临时记录检查间隔（1 分钟）
+const TEMPORARY_RECORD_INTERVAL_SECS: u64 = 60;
+
+/// 前端监听的临时记录异常事件名
+pub const TEMPORARY_RECORD_EVENT: &str = "temporary-record-failed";
+
+/// 执行到期的临时记录任务（到点创建、到点删除），进入异常状态时通知前端
+pub struct TemporaryRecordJob {
+    app_handle: AppHandle,
+}
+
+impl TemporaryRecordJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for TemporaryRecordJob {
+    fn name(&self) -> &str {
+        TEMPORARY_RECORD_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: TEMPORARY_RECORD_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        let changed = state.temporary_record_service.run_due_tasks().await?;
+
+        let failed: Vec<_> = changed
+            .iter()
+            .filter(|record| record.status == TemporaryRecordStatus::Failed)
+            .collect();
+        if !failed.is_empty() {
+            if let Err(e) = self.app_handle.emit(TEMPORARY_RECORD_EVENT, &failed) {
+                log::warn!("Failed to emit temporary record event: {e}");
+            }
+        }
+
+        Ok(Some(format!(
+            "{} changed, {} failed",
+            changed.len(),
+            failed.len()
+        )))
+    }
+}
+
+/// API 用量写入任务名称
+pub const API_USAGE_FLUSH_JOB: &str = "api_usage_flush";
+
+/// API 用量写入间隔（1 分钟）
+const API_USAGE_FLUSH_INTERVAL_SECS: u64 = 60;
+
+/// 把内存中累积的服务商 API 调用次数批量写入存储
+pub struct ApiUsageFlushJob {
+    app_handle: AppHandle,
+}
+
+impl ApiUsageFlushJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for ApiUsageFlushJob {
+    fn name(&self) -> &str {
+        API_USAGE_FLUSH_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: API_USAGE_FLUSH_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        let written = state.api_usage_service.flush().await?;
+        Ok(Some(format!("{written} entries written")))
+    }
+}
+
+/// API 用量清理任务名称
+pub const API_USAGE_CLEANUP_JOB: &str = "api_usage_cleanup";
+
+/// API 用量清理间隔（24 小时）
+const API_USAGE_CLEANUP_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// 删除保留期（12 个月）之前的 API 用量统计
+pub struct ApiUsageCleanupJob {
+    app_handle: AppHandle,
+}
+
+impl ApiUsageCleanupJob {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+#[async_trait]
+impl BackgroundJob for ApiUsageCleanupJob {
+    fn name(&self) -> &str {
+        API_USAGE_CLEANUP_JOB
+    }
+
+    fn schedule(&self) -> JobSchedule {
+        JobSchedule::Interval {
+            interval_secs: API_USAGE_CLEANUP_INTERVAL_SECS,
+        }
+    }
+
+    async fn run(&self) -> CoreResult<Option<String>> {
+        let state = self.app_handle.state::<AppState>();
+        let deleted = state.api_usage_service.cleanup().await?;
+        Ok(Some(format!("{deleted} entries removed")))
+    }
+}
@@ -1,25 +1,40 @@
 mod adapters;
 mod commands;
 mod error;
+mod jobs;
 mod types;
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 #[cfg(target_os = "android")]
 use commands::updater;
-use commands::{account, dns, domain, domain_metadata, toolbox};
+use commands::{
+    account, audit_log, background_job, change_window, deep_link, dns, domain, domain_group,
+    domain_metadata, domain_monitor, record_copy, record_import, temporary_record, toolbox,
+    weighted_rollout, write_queue,
+};
 use tauri::Manager;
 use tauri_plugin_log::{Target, TargetKind};
 
-use adapters::{TauriAccountRepository, TauriCredentialStore, TauriDomainMetadataRepository};
+use adapters::{
+    TauriAccountCapabilityRepository, TauriAccountRepository, TauriApiUsageRepository,
+    TauriAuditLogRepository, TauriChangeWindowRepository, TauriCredentialStore,
+    TauriDomainGroupRepository, TauriDomainMetadataRepository, TauriDomainStatusRepository,
+    TauriMonitorRepository, TauriTemporaryRecordRepository, TauriWriteQueueRepository,
+};
 use dns_orchestrator_core::services::{
-    AccountBootstrapService, AccountLifecycleService, AccountMetadataService,
-    CredentialManagementService, DnsService, DomainMetadataService, DomainService,
+    AccountBootstrapService, AccountCapabilityService, AccountLifecycleService,
+    AccountMetadataService, AccountValidationService, ApiUsageService, AuditLogService,
+    BackgroundJobRunner, ChangeWindowService, CredentialExpiryService, CredentialManagementService,
+    DnsService, DomainGroupService, DomainMetadataService, DomainMonitorService, DomainService,
     ImportExportService, MigrationResult, MigrationService, ProviderMetadataService,
-    ServiceContext,
+    RecordCopyService, RecordImportService, ServiceContext, TemporaryRecordService, ToolboxService,
+    WeightedRolloutService, WriteQueueService,
 };
-use dns_orchestrator_core::traits::InMemoryProviderRegistry;
+use dns_orchestrator_core::traits::{BackgroundJob, InMemoryProviderRegistry, RateLimitConfig};
+use tokio::sync::RwLock;
 
 /// 应用全局状态
 pub struct AppState {
@@ -31,20 +46,54 @@ pub struct AppState {
     pub credential_management_service: Arc<CredentialManagementService>,
     /// 账户生命周期服务
     pub account_lifecycle_service: Arc<AccountLifecycleService>,
+    /// 账户能力服务
+    pub account_capability_service: Arc<AccountCapabilityService>,
+    /// 账户凭证校验服务
+    pub account_validation_service: AccountValidationService,
+    /// 服务商 API 用量统计服务
+    pub api_usage_service: Arc<ApiUsageService>,
     /// 账户启动恢复服务
     pub account_bootstrap_service: Arc<AccountBootstrapService>,
+    /// 凭证到期提醒服务
+    pub credential_expiry_service: CredentialExpiryService,
     /// Provider 元数据服务
     pub provider_metadata_service: ProviderMetadataService,
     /// 导入导出服务
     pub import_export_service: ImportExportService,
     /// 域名服务
     pub domain_service: DomainService,
+    /// 域名组服务
+    pub domain_group_service: DomainGroupService,
     /// 域名元数据服务
     pub domain_metadata_service: Arc<DomainMetadataService>,
+    /// 域名到期监控服务
+    pub domain_monitor_service: DomainMonitorService,
     /// DNS 服务
     pub dns_service: DnsService,
+    /// 记录导入服务
+    pub record_import_service: RecordImportService,
+    /// 跨账户记录复制服务
+    pub record_copy_service: RecordCopyService,
+    /// 审计日志服务
+    pub audit_log_service: AuditLogService,
+    /// 变更窗口服务
+    pub change_window_service: Arc<ChangeWindowService>,
+    /// 离线写队列服务
+    pub write_queue_service: Arc<WriteQueueService>,
+    /// 权重灰度切换服务
+    pub weighted_rollout_service: WeightedRolloutService,
+    /// 临时记录服务
+    pub temporary_record_service: TemporaryRecordService,
+    /// 工具箱服务（持有 WHOIS 查询缓存）
+    pub toolbox_service: Arc<ToolboxService>,
+    /// 后台任务运行器
+    pub background_job_runner: BackgroundJobRunner,
+    /// 进行中的 DNS 基准测试取消标记（benchmark_id -> 取消标记）
+    pub dns_benchmark_cancellations: RwLock<HashMap<String, Arc<AtomicBool>>>,
     /// 账户恢复是否完成
     pub restore_completed: AtomicBool,
+    /// 待前端处理的深链接目标
+    pub pending_deep_link: RwLock<Option<deep_link::DeepLinkTarget>>,
 }
 
 impl AppState {
@@ -58,58 +107,139 @@ impl AppState {
 
         let account_repository = Arc::new(TauriAccountRepository::new(app_handle.clone()));
         let provider_registry = Arc::new(InMemoryProviderRegistry::new());
-        let domain_metadata_repository = Arc::new(TauriDomainMetadataRepository::new(app_handle));
-
-        // 创建服务上下文
-        let ctx = Arc::new(ServiceContext::new(
-            credential_store.clone(),
+        let domain_metadata_repository =
+            Arc::new(TauriDomainMetadataRepository::new(app_handle.clone()));
+        let domain_group_repository = Arc::new(TauriDomainGroupRepository::new(app_handle.clone()));
+        let audit_log_repository = Arc::new(TauriAuditLogRepository::new(app_handle.clone()));
+        let monitor_repository = Arc::new(TauriMonitorRepository::new(app_handle.clone()));
+        let domain_status_repository =
+            Arc::new(TauriDomainStatusRepository::new(app_handle.clone()));
+        let change_window_service = Arc::new(ChangeWindowService::new(Arc::new(
+            TauriChangeWindowRepository::new(app_handle.clone()),
+        )));
+        let write_queue_service = Arc::new(WriteQueueService::new(
+            Arc::new(TauriWriteQueueRepository::new(app_handle.clone())),
+            write_queue::load_write_queue_config(&app_handle),
+        ));
+        let api_usage_service = Arc::new(ApiUsageService::new(
+            Arc::new(TauriApiUsageRepository::new(app_handle.clone())),
             account_repository.clone(),
-            provider_registry.clone(),
-            domain_metadata_repository.clone(),
         ));
 
+        // 创建服务上下文
+        let ctx = Arc::new(
+            ServiceContext::new(
+                credential_store.clone(),
+                account_repository.clone(),
+                provider_registry.clone(),
+                domain_metadata_repository.clone(),
+            )
+            .with_audit_log(audit_log_repository)
+            .with_change_windows(Arc::clone(&change_window_service))
+            .with_write_queue(Arc::clone(&write_queue_service))
+            .with_capability_cache(Arc::new(TauriAccountCapabilityRepository::new(
+                app_handle.clone(),
+            )))
+            .with_api_usage(Arc::clone(&api_usage_service))
+            .with_domain_status(domain_status_repository.clone())
+            .with_rate_limits(RateLimitConfig::recommended()),
+        );
+
         // 创建细粒度账户服务
         let account_metadata_service = Arc::new(AccountMetadataService::new(account_repository));
         let credential_management_service = Arc::new(CredentialManagementService::new(
             credential_store,
             provider_registry,
         ));
-        let account_lifecycle_service = Arc::new(AccountLifecycleService::new(
-            Arc::clone(&account_metadata_service),
-            Arc::clone(&credential_management_service),
-        ));
+        let domain_metadata_service =
+            Arc::new(DomainMetadataService::new(domain_metadata_repository));
+        let account_capability_service = Arc::new(AccountCapabilityService::new(Arc::clone(&ctx)));
+        let account_validation_service = AccountValidationService::new(Arc::clone(&ctx));
+        let account_lifecycle_service = Arc::new(
+            AccountLifecycleService::new(
+                Arc::clone(&account_metadata_service),
+                Arc::clone(&credential_management_service),
+                Arc::clone(&domain_metadata_service),
+            )
+            .with_write_queue(Arc::clone(&write_queue_service))
+            .with_capabilities(Arc::clone(&account_capability_service))
+            .with_domain_status(domain_status_repository),
+        );
         let account_bootstrap_service = Arc::new(AccountBootstrapService::new(
             Arc::clone(&account_metadata_service),
             Arc::clone(&credential_management_service),
         ));
+        let credential_expiry_service =
+            CredentialExpiryService::new(Arc::clone(&account_metadata_service));
         let provider_metadata_service = ProviderMetadataService::new();
 
         // 创建其他服务
         let import_export_service = ImportExportService::new(Arc::clone(&ctx));
         let domain_service = DomainService::new(Arc::clone(&ctx));
-        let domain_metadata_service =
-            Arc::new(DomainMetadataService::new(domain_metadata_repository));
         let dns_service = DnsService::new(Arc::clone(&ctx));
+        let domain_group_service =
+            DomainGroupService::new(Arc::clone(&ctx), domain_group_repository);
+        let record_import_service = RecordImportService::new(Arc::clone(&ctx));
+        let record_copy_service = RecordCopyService::new(Arc::clone(&ctx));
+        let audit_log_service = AuditLogService::new(Arc::clone(&ctx));
+        let weighted_rollout_service = WeightedRolloutService::new(Arc::clone(&ctx));
+        let temporary_record_service = TemporaryRecordService::new(
+            Arc::clone(&ctx),
+            Arc::new(TauriTemporaryRecordRepository::new(app_handle.clone())),
+        );
+        let toolbox_service = Arc::new(ToolboxService::default());
+        let domain_monitor_service = DomainMonitorService::new(
+            Arc::clone(&ctx),
+            Arc::clone(&toolbox_service),
+            monitor_repository,
+        );
 
         Self {
             ctx,
             account_metadata_service,
             credential_management_service,
             account_lifecycle_service,
+            account_capability_service,
+            account_validation_service,
+            api_usage_service,
             account_bootstrap_service,
+            credential_expiry_service,
             provider_metadata_service,
             import_export_service,
             domain_service,
+            domain_group_service,
             domain_metadata_service,
+            domain_monitor_service,
             dns_service,
+            record_import_service,
+            record_copy_service,
+            audit_log_service,
+            change_window_service,
+            write_queue_service,
+            weighted_rollout_service,
+            temporary_record_service,
+            toolbox_service,
+            background_job_runner: BackgroundJobRunner::new(),
+            dns_benchmark_cancellations: RwLock::new(HashMap::new()),
             restore_completed: AtomicBool::new(false),
+            pending_deep_link: RwLock::new(None),
         }
     }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let mut builder = tauri::Builder::default()
+    let mut builder = tauri::Builder::default();
+
+    // 单实例必须最先注册：再次启动（含深链接唤起）时转发给已运行的实例并聚焦窗口
+    #[cfg(not(any(target_os = "android", target_os = "ios")))]
+    {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            deep_link::focus_main_window(app);
+        }));
+    }
+
+    builder = builder
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_store::Builder::default().build())
@@ -137,10 +267,12 @@ pub fn run() {
         );
     }
 
-    // 仅桌面端启用 updater
+    // 仅桌面端启用 updater 和深链接
     #[cfg(not(any(target_os = "android", target_os = "ios")))]
     {
-        builder = builder.plugin(tauri_plugin_updater::Builder::new().build());
+        builder = builder
+            .plugin(tauri_plugin_updater::Builder::new().build())
+            .plugin(tauri_plugin_deep_link::init());
     }
 
     // Android 启用 Stronghold 和 APK Installer
@@ -278,28 +410,72 @@ pub fn run() {
             }
         });
 
-        // 后台恢复账户，不阻塞启动
+        // 注册并启动后台任务，账户恢复作为手动任务立即触发一次，不阻塞启动
         let app_handle = app.handle().clone();
         tauri::async_runtime::spawn(async move {
             let state = app_handle.state::<AppState>();
-            let result = state.account_bootstrap_service.restore_accounts().await;
-
-            match result {
-                Ok(restore_result) => {
-                    log::info!(
-                        "Account restoration complete: {} succeeded, {} failed",
-                        restore_result.success_count,
-                        restore_result.error_count
-                    );
-                }
-                Err(e) => {
-                    log::error!("Failed to restore accounts: {e}");
+            let runner = &state.background_job_runner;
+
+            // 先恢复隐私模式，启动时的 DROP 列表刷新需要遵守
+            toolbox::restore_privacy_config(&app_handle);
+            let threat_intel = toolbox::restore_threat_intel_config(&app_handle).await;
+
+            let background_jobs: [Arc<dyn BackgroundJob>; 7] = [
+                Arc::new(jobs::AccountRestoreJob::new(app_handle.clone())),
+                Arc::new(jobs::CredentialExpiryJob::new(app_handle.clone())),
+                Arc::new(jobs::CredentialValidationJob::new(app_handle.clone())),
+                Arc::new(jobs::ThreatIntelRefreshJob::new(app_handle.clone())),
+                Arc::new(jobs::TemporaryRecordJob::new(app_handle.clone())),
+                Arc::new(jobs::ApiUsageFlushJob::new(app_handle.clone())),
+                Arc::new(jobs::ApiUsageCleanupJob::new(app_handle.clone())),
+            ];
+            for job in background_jobs {
+                if let Err(e) = runner.register(job).await {
+                    log::error!("Failed to register background job: {e}");
                 }
             }
+            runner.start().await;
 
-            state.restore_completed.store(true, Ordering::SeqCst);
+            if let Err(e) = runner.trigger_job(jobs::ACCOUNT_RESTORE_JOB).await {
+                log::error!("Failed to restore accounts: {e}");
+                state.restore_completed.store(true, Ordering::SeqCst);
+            }
+            if threat_intel.spamhaus_drop {
+                if let Err(e) = runner.trigger_job(jobs::THREAT_INTEL_REFRESH_JOB).await {
+                    log::warn!("Failed to refresh threat intel: {e}");
+                }
+            }
         });
 
+        // 深链接：已运行时由 on_open_url 接收，冷启动时从 get_current 读取
+        #[cfg(not(any(target_os = "android", target_os = "ios")))]
+        {
+            use tauri_plugin_deep_link::DeepLinkExt;
+
+            // Windows/Linux 未经安装包注册时（开发环境、AppImage）在运行时注册 scheme
+            #[cfg(any(windows, target_os = "linux"))]
+            if let Err(e) = app.deep_link().register_all() {
+                log::warn!("Failed to register deep link schemes: {e}");
+            }
+
+            let app_handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    deep_link::handle_deep_link(&app_handle, &url);
+                }
+            });
+
+            match app.deep_link().get_current() {
+                Ok(Some(urls)) => {
+                    for url in urls {
+                        deep_link::handle_deep_link(app.handle(), &url);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => log::warn!("Failed to read launch deep link: {e}"),
+            }
+        }
+
         Ok(())
     });
 
@@ -309,13 +485,24 @@ pub fn run() {
         account::list_accounts,
         account::create_account,
         account::update_account,
+        account::get_account_deletion_impact,
         account::delete_account,
         account::batch_delete_accounts,
         account::list_providers,
+        account::get_account_capabilities,
+        account::refresh_account_capabilities,
+        account::check_provider_health,
+        account::validate_account,
+        account::validate_all_accounts,
+        account::get_api_usage_report,
         account::export_accounts,
         account::preview_import,
         account::import_accounts,
+        account::export_diagnostics,
+        account::export_backup,
+        account::import_backup,
         account::is_restore_completed,
+        account::list_expiring_credentials,
         // Domain commands
         domain::list_domains,
         domain::get_domain,
@@ -332,20 +519,103 @@ pub fn run() {
         domain_metadata::batch_remove_domain_tags,
         domain_metadata::batch_set_domain_tags,
         domain_metadata::update_domain_metadata,
+        // Domain monitor commands
+        domain_monitor::check_domain_expiry,
+        domain_monitor::list_expiry_warnings,
+        domain_monitor::get_certificate_report,
         // DNS commands
         dns::list_dns_records,
+        dns::list_all_dns_records,
         dns::create_dns_record,
+        dns::validate_dns_record,
         dns::update_dns_record,
         dns::delete_dns_record,
+        dns::batch_create_dns_records,
+        dns::batch_update_dns_records,
+        dns::replace_dns_record_value,
+        dns::get_dns_record_set,
+        dns::update_dns_record_set,
         dns::batch_delete_dns_records,
+        dns::export_zone_file,
+        dns::import_zone_file,
+        dns::diff_domains,
+        dns::scan_record_characters,
+        dns::get_record_history,
+        dns::restore_record,
+        // Record import commands
+        record_import::preview_record_import,
+        record_import::import_records,
+        record_copy::copy_dns_records,
+        // Audit log commands
+        audit_log::list_audit_log,
+        audit_log::generate_changelog,
+        // Change window commands
+        change_window::list_change_window_rules,
+        change_window::create_change_window_rule,
+        change_window::update_change_window_rule,
+        change_window::delete_change_window_rule,
+        change_window::list_upcoming_closed_periods,
+        change_window::get_change_window_override,
+        change_window::start_change_window_override,
+        change_window::clear_change_window_override,
+        // Deep link commands
+        deep_link::take_pending_deep_link,
+        deep_link::generate_deep_link,
+        // Offline write queue commands
+        write_queue::get_write_queue_config,
+        write_queue::set_write_queue_config,
+        write_queue::list_queued_writes,
+        write_queue::discard_queued_writes,
+        write_queue::replay_queued_writes,
+        weighted_rollout::create_weighted_rollout,
+        weighted_rollout::get_weighted_rollout,
+        weighted_rollout::list_weighted_rollouts,
+        weighted_rollout::pause_weighted_rollout,
+        weighted_rollout::resume_weighted_rollout,
+        weighted_rollout::rollback_weighted_rollout,
+        temporary_record::create_temporary_record,
+        temporary_record::list_temporary_records,
+        temporary_record::get_temporary_record,
+        temporary_record::reschedule_temporary_record,
+        temporary_record::cancel_temporary_record,
+        // Domain group commands
+        domain_group::list_domain_groups,
+        domain_group::create_domain_group,
+        domain_group::update_domain_group,
+        domain_group::delete_domain_group,
+        domain_group::get_group_records_comparison,
+        domain_group::preview_group_sync,
+        domain_group::apply_group_sync,
+        // Background job commands
+        background_job::list_background_jobs,
+        background_job::trigger_job,
         // Toolbox commands
         toolbox::whois_lookup,
         toolbox::dns_lookup,
+        toolbox::reverse_lookup,
         toolbox::ip_lookup,
+        toolbox::ip_lookup_batch,
+        toolbox::get_threat_intel_config,
+        toolbox::set_threat_intel_config,
+        toolbox::get_privacy_config,
+        toolbox::set_privacy_config,
+        toolbox::list_external_endpoints,
         toolbox::ssl_check,
+        toolbox::ssl_check_batch,
         toolbox::http_header_check,
+        toolbox::http_trace,
+        toolbox::port_check,
+        toolbox::trace_route,
         toolbox::dns_propagation_check,
+        toolbox::propagation_check,
         toolbox::dnssec_check,
+        toolbox::email_security_check,
+        toolbox::mail_check,
+        toolbox::caa_check,
+        toolbox::get_system_dns,
+        toolbox::network_env_info,
+        toolbox::dns_benchmark,
+        toolbox::cancel_dns_benchmark,
     ]);
 
     #[cfg(target_os = "android")]
@@ -354,13 +624,24 @@ pub fn run() {
         account::list_accounts,
         account::create_account,
         account::update_account,
+        account::get_account_deletion_impact,
         account::delete_account,
         account::batch_delete_accounts,
         account::list_providers,
+        account::get_account_capabilities,
+        account::refresh_account_capabilities,
+        account::check_provider_health,
+        account::validate_account,
+        account::validate_all_accounts,
+        account::get_api_usage_report,
         account::export_accounts,
         account::preview_import,
         account::import_accounts,
+        account::export_diagnostics,
+        account::export_backup,
+        account::import_backup,
         account::is_restore_completed,
+        account::list_expiring_credentials,
         // Domain commands
         domain::list_domains,
         domain::get_domain,
@@ -377,20 +658,103 @@ pub fn run() {
         domain_metadata::batch_remove_domain_tags,
         domain_metadata::batch_set_domain_tags,
         domain_metadata::update_domain_metadata,
+        // Domain monitor commands
+        domain_monitor::check_domain_expiry,
+        domain_monitor::list_expiry_warnings,
+        domain_monitor::get_certificate_report,
         // DNS commands
         dns::list_dns_records,
+        dns::list_all_dns_records,
         dns::create_dns_record,
+        dns::validate_dns_record,
         dns::update_dns_record,
         dns::delete_dns_record,
+        dns::batch_create_dns_records,
+        dns::batch_update_dns_records,
+        dns::replace_dns_record_value,
+        dns::get_dns_record_set,
+        dns::update_dns_record_set,
         dns::batch_delete_dns_records,
+        dns::export_zone_file,
+        dns::import_zone_file,
+        dns::diff_domains,
+        dns::scan_record_characters,
+        dns::get_record_history,
+        dns::restore_record,
+        // Record import commands
+        record_import::preview_record_import,
+        record_import::import_records,
+        record_copy::copy_dns_records,
+        // Audit log commands
+        audit_log::list_audit_log,
+        audit_log::generate_changelog,
+        // Change window commands
+        change_window::list_change_window_rules,
+        change_window::create_change_window_rule,
+        change_window::update_change_window_rule,
+        change_window::delete_change_window_rule,
+        change_window::list_upcoming_closed_periods,
+        change_window::get_change_window_override,
+        change_window::start_change_window_override,
+        change_window::clear_change_window_override,
+        // Deep link commands
+        deep_link::take_pending_deep_link,
+        deep_link::generate_deep_link,
+        // Offline write queue commands
+        write_queue::get_write_queue_config,
+        write_queue::set_write_queue_config,
+        write_queue::list_queued_writes,
+        write_queue::discard_queued_writes,
+        write_queue::replay_queued_writes,
+        weighted_rollout::create_weighted_rollout,
+        weighted_rollout::get_weighted_rollout,
+        weighted_rollout::list_weighted_rollouts,
+        weighted_rollout::pause_weighted_rollout,
+        weighted_rollout::resume_weighted_rollout,
+        weighted_rollout::rollback_weighted_rollout,
+        temporary_record::create_temporary_record,
+        temporary_record::list_temporary_records,
+        temporary_record::get_temporary_record,
+        temporary_record::reschedule_temporary_record,
+        temporary_record::cancel_temporary_record,
+        // Domain group commands
+        domain_group::list_domain_groups,
+        domain_group::create_domain_group,
+        domain_group::update_domain_group,
+        domain_group::delete_domain_group,
+        domain_group::get_group_records_comparison,
+        domain_group::preview_group_sync,
+        domain_group::apply_group_sync,
+        // Background job commands
+        background_job::list_background_jobs,
+        background_job::trigger_job,
         // Toolbox commands
         toolbox::whois_lookup,
         toolbox::dns_lookup,
+        toolbox::reverse_lookup,
         toolbox::ip_lookup,
+        toolbox::ip_lookup_batch,
+        toolbox::get_threat_intel_config,
+        toolbox::set_threat_intel_config,
+        toolbox::get_privacy_config,
+        toolbox::set_privacy_config,
+        toolbox::list_external_endpoints,
         toolbox::ssl_check,
+        toolbox::ssl_check_batch,
         toolbox::http_header_check,
+        toolbox::http_trace,
+        toolbox::port_check,
+        toolbox::trace_route,
         toolbox::dns_propagation_check,
+        toolbox::propagation_check,
         toolbox::dnssec_check,
+        toolbox::email_security_check,
+        toolbox::mail_check,
+        toolbox::caa_check,
+        toolbox::get_system_dns,
+        toolbox::network_env_info,
+        toolbox::dns_benchmark,
+        toolbox::cancel_dns_benchmark,
         // Android updater commands
         updater::check_android_update,
         updater::download_apk,
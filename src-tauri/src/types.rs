@@ -22,10 +22,13 @@ pub use dns_orchestrator_provider::{
 
 // ============ Re-export Core 库类型 ============
 
-pub use dns_orchestrator_core::types::DomainMetadata;
+pub use dns_orchestrator_core::types::{ApiResponse, CredentialStatus, DomainMetadata};
 
 // 工具箱类型
-pub use dns_orchestrator_core::types::BatchDeleteRequest;
+pub use dns_orchestrator_core::types::{
+    BatchCreateRequest, BatchCreateResult, BatchDeleteRequest, BatchUpdateRequest,
+    BatchUpdateResult, ReplaceRecordValueRequest,
+};
 
 // ============ 应用层 Provider 相关类型 ============
 
@@ -34,6 +37,8 @@ pub use dns_orchestrator_core::types::BatchDeleteRequest;
 pub enum AccountStatus {
     Active,
     Error,
+    #[serde(rename = "credentials_expired")]
+    CredentialsExpired,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,6 +54,15 @@ pub struct Account {
     pub status: Option<AccountStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(
+        rename = "credentialsExpiresAt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
+    #[serde(rename = "credentialStatus", skip_serializing_if = "Option::is_none")]
+    pub credential_status: Option<CredentialStatus>,
+    #[serde(rename = "lastValidatedAt", skip_serializing_if = "Option::is_none")]
+    pub last_validated_at: Option<DateTime<Utc>>,
 }
 
 /// 创建账户请求（v1.7.0 类型安全重构）
@@ -57,6 +71,8 @@ pub struct CreateAccountRequest {
     pub name: String,
     pub provider: ProviderType,
     pub credentials: ProviderCredentials,
+    #[serde(rename = "credentialsExpiresAt", default)]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
 }
 
 /// 更新账户请求（v1.7.0 类型安全重构）
@@ -67,6 +83,12 @@ pub struct UpdateAccountRequest {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<ProviderCredentials>,
+    #[serde(
+        rename = "credentialsExpiresAt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub credentials_expires_at: Option<DateTime<Utc>>,
 }
 
 // ============ 应用层 Domain（包含 account_id）============
@@ -80,29 +102,14 @@ pub struct Domain {
     pub account_id: String,
     pub provider: ProviderType,
     pub status: DomainStatus,
+    #[serde(rename = "providerStatusRaw", skip_serializing_if = "Option::is_none")]
+    pub provider_status_raw: Option<String>,
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<DomainMetadata>,
 }
 
-// ============ API 响应类型 ============
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ApiResponse<T> {
-    pub success: bool,
-    pub data: Option<T>,
-}
-
-impl<T> ApiResponse<T> {
-    pub fn success(data: T) -> Self {
-        Self {
-            success: true,
-            data: Some(data),
-        }
-    }
-}
-
 // ============ 批量操作相关类型 ============
 
 /// 批量删除结果
@@ -120,6 +127,9 @@ pub struct BatchDeleteResult {
 pub struct BatchDeleteFailure {
     pub record_id: String,
     pub reason: String,
+    /// 放弃前对服务商的尝试次数（含重试）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
 }
 
 // ============ 导入导出相关类型 ============
@@ -135,6 +145,9 @@ pub struct ExportAccountsRequest {
     /// 加密密码（仅当 encrypt=true 时需要）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    /// 可复现导出（保留原账户 ID、省略时间戳）
+    #[serde(default)]
+    pub reproducible: bool,
 }
 
 /// 导出响应
@@ -187,6 +200,9 @@ pub struct ImportPreviewAccount {
 pub struct ImportResult {
     /// 成功导入的账号数量
     pub success_count: usize,
+    /// 与已有账户重复（服务商与凭证相同）而跳过的账号数量
+    #[serde(default)]
+    pub skipped_count: usize,
     /// 失败的账号及原因
     pub failures: Vec<ImportFailure>,
 }
@@ -19,6 +19,7 @@ import {
   SelectTrigger,
   SelectValue,
 } from "@/components/ui/select"
+import { Textarea } from "@/components/ui/textarea"
 import { useAccountStore } from "@/stores"
 import type { Account, ProviderCredentials } from "@/types"
 import { ProviderIcon } from "./ProviderIcon"
@@ -47,6 +48,7 @@ function buildProviderCredentials(
         credentials: {
           access_key_id: credentialsMap.accessKeyId,
           access_key_secret: credentialsMap.accessKeySecret,
+          api_variant: credentialsMap.apiVariant || undefined,
         },
       }
     case "dnspod":
@@ -65,11 +67,46 @@ function buildProviderCredentials(
           secret_access_key: credentialsMap.secretAccessKey,
         },
       }
+    case "route53":
+      return {
+        provider: "route53",
+        credentials: {
+          access_key_id: credentialsMap.accessKeyId,
+          secret_access_key: credentialsMap.secretAccessKey,
+          region: credentialsMap.region,
+          role_arn: credentialsMap.roleArn?.trim() || undefined,
+        },
+      }
+    case "gcp_dns":
+      return {
+        provider: "gcp_dns",
+        credentials: {
+          project_id: credentialsMap.projectId,
+          service_account_json: credentialsMap.serviceAccountJson,
+        },
+      }
+    case "digitalocean":
+      return {
+        provider: "digitalocean",
+        credentials: {
+          token: credentialsMap.token,
+        },
+      }
     default:
       throw new Error(`Unknown provider: ${provider}`)
   }
 }
 
+/** ISO 时间转日期输入框的值（UTC 日期） */
+function toDateInput(value?: string): string {
+  return value ? value.slice(0, 10) : ""
+}
+
+/** 日期输入框的值转 ISO 时间（UTC 当天结束） */
+function fromDateInput(value: string): string | undefined {
+  return value ? `${value}T23:59:59Z` : undefined
+}
+
 interface AccountFormProps {
   open: boolean
   onOpenChange: (open: boolean) => void
@@ -95,6 +132,8 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
   const [name, setName] = useState("")
   const [credentials, setCredentials] = useState<Record<string, string>>({})
   const [showPasswords, setShowPasswords] = useState<Record<string, boolean>>({})
+  /** 凭证到期日期（YYYY-MM-DD，按 UTC 当天结束计） */
+  const [expiresOn, setExpiresOn] = useState("")
 
   // 获取提供商列表 + 默认选中第一个
   useEffect(() => {
@@ -112,11 +151,13 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
       setName(account.name)
       setCredentials({}) // 凭证不回显
       setShowPasswords({})
+      setExpiresOn(toDateInput(account.credentialsExpiresAt))
     } else if (open && !account) {
       // 创建模式：重置表单
       setName("")
       setCredentials({})
       setShowPasswords({})
+      setExpiresOn("")
       if (providers.length > 0) {
         setProvider(providers[0].id)
       }
@@ -124,6 +165,13 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
   }, [open, account, providers])
 
   const providerInfo = providers.find((p) => p.id === provider)
+  // 必填字段在前，可选字段（留空使用默认值）在后
+  const credentialFields = providerInfo
+    ? [
+        ...providerInfo.requiredFields.map((field) => ({ field, optional: false })),
+        ...(providerInfo.optionalFields ?? []).map((field) => ({ field, optional: true })),
+      ]
+    : []
 
   const handleProviderChange = (value: string) => {
     setProvider(value)
@@ -154,12 +202,14 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
       name: name || `${providerInfo.name} 账号`,
       provider,
       credentials: providerCredentials,
+      credentialsExpiresAt: fromDateInput(expiresOn),
     })
 
     if (result) {
       setName("")
       setCredentials({})
       setShowPasswords({})
+      setExpiresOn("")
       onOpenChange(false)
     }
   }
@@ -171,10 +221,12 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
     if (!account) return
 
     const hasCredentials = Object.values(credentials).some((v) => v.trim())
+    const expiryChanged = expiresOn !== toDateInput(account.credentialsExpiresAt)
     const result = await updateAccount({
       id: account.id,
       name: name || undefined,
       credentials: hasCredentials ? buildProviderCredentials(provider, credentials) : undefined,
+      credentialsExpiresAt: expiryChanged ? fromDateInput(expiresOn) : undefined,
     })
 
     if (result) {
@@ -193,10 +245,13 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
   }
 
   // 创建模式：所有必填字段都要填写
-  // 编辑模式：至少修改了名称或凭证中的任意一个
+  // 编辑模式：至少修改了名称、凭证或到期日期中的任意一个
   const isValidForCreate =
     providerInfo?.requiredFields.every((field) => credentials[field.key]?.trim()) ?? false
-  const isValidForEdit = name !== account?.name || Object.values(credentials).some((v) => v.trim())
+  const isValidForEdit =
+    name !== account?.name ||
+    Object.values(credentials).some((v) => v.trim()) ||
+    expiresOn !== toDateInput(account?.credentialsExpiresAt)
   const isValid = isEditing ? isValidForEdit : isValidForCreate
 
   const handleOpenChange = (isOpen: boolean) => {
@@ -262,44 +317,77 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
           )}
 
           {/* 凭证字段 */}
-          {providerInfo?.requiredFields.map((field) => (
+          {credentialFields.map(({ field, optional }) => (
             <div key={field.key} className="space-y-2">
               <Label htmlFor={field.key}>
                 {field.label}
+                {optional && !isEditing && (
+                  <span className="ml-1 font-normal text-muted-foreground">
+                    ({t("account.optionalField")})
+                  </span>
+                )}
                 {isEditing && (
                   <span className="ml-1 font-normal text-muted-foreground">
                     ({t("account.leaveEmptyToKeep")})
                   </span>
                 )}
               </Label>
-              <div className="relative">
-                <Input
+              {field.type === "select" ? (
+                <Select
+                  value={credentials[field.key] || ""}
+                  onValueChange={(value) => handleCredentialChange(field.key, value)}
+                >
+                  <SelectTrigger id={field.key}>
+                    <SelectValue placeholder={field.placeholder} />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {field.options?.map((option) => (
+                      <SelectItem key={option.value} value={option.value}>
+                        {option.label}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              ) : field.type === "textarea" ? (
+                <Textarea
                   id={field.key}
-                  type={
-                    field.type === "password" && !showPasswords[field.key] ? "password" : "text"
-                  }
                   value={credentials[field.key] || ""}
                   onChange={(e) => handleCredentialChange(field.key, e.target.value)}
                   placeholder={isEditing ? t("account.enterNewValue") : field.placeholder}
-                  className={`pr-10 ${fieldErrors[field.key] ? "border-destructive" : ""}`}
-                  required={!isEditing}
+                  className={`font-mono ${fieldErrors[field.key] ? "border-destructive" : ""}`}
+                  spellCheck={false}
+                  required={!(isEditing || optional)}
                 />
-                {field.type === "password" && (
-                  <Button
-                    type="button"
-                    variant="ghost"
-                    size="icon"
-                    className="absolute top-0 right-0 h-full px-3"
-                    onClick={() => togglePasswordVisibility(field.key)}
-                  >
-                    {showPasswords[field.key] ? (
-                      <EyeOff className="h-4 w-4" />
-                    ) : (
-                      <Eye className="h-4 w-4" />
-                    )}
-                  </Button>
-                )}
-              </div>
+              ) : (
+                <div className="relative">
+                  <Input
+                    id={field.key}
+                    type={
+                      field.type === "password" && !showPasswords[field.key] ? "password" : "text"
+                    }
+                    value={credentials[field.key] || ""}
+                    onChange={(e) => handleCredentialChange(field.key, e.target.value)}
+                    placeholder={isEditing ? t("account.enterNewValue") : field.placeholder}
+                    className={`pr-10 ${fieldErrors[field.key] ? "border-destructive" : ""}`}
+                    required={!(isEditing || optional)}
+                  />
+                  {field.type === "password" && (
+                    <Button
+                      type="button"
+                      variant="ghost"
+                      size="icon"
+                      className="absolute top-0 right-0 h-full px-3"
+                      onClick={() => togglePasswordVisibility(field.key)}
+                    >
+                      {showPasswords[field.key] ? (
+                        <EyeOff className="h-4 w-4" />
+                      ) : (
+                        <Eye className="h-4 w-4" />
+                      )}
+                    </Button>
+                  )}
+                </div>
+              )}
               {fieldErrors[field.key] && (
                 <p className="text-destructive text-xs">{fieldErrors[field.key]}</p>
               )}
@@ -309,6 +397,22 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
             </div>
           ))}
 
+          {/* 凭证到期日期（可选） */}
+          {providerInfo && (
+            <div className="space-y-2">
+              <Label htmlFor="credentialsExpiresAt">{t("account.credentialsExpiresAt")}</Label>
+              <Input
+                id="credentialsExpiresAt"
+                type="date"
+                value={expiresOn}
+                onChange={(e) => setExpiresOn(e.target.value)}
+              />
+              <p className="text-muted-foreground text-xs">
+                {t("account.credentialsExpiresAtHelp")}
+              </p>
+            </div>
+          )}
+
           <DialogFooter>
             <Button type="button" variant="outline" onClick={() => handleOpenChange(false)}>
               {t("common.cancel")}
@@ -19,7 +19,7 @@ import {
   DropdownMenuTrigger,
 } from "@/components/ui/dropdown-menu"
 import { cn } from "@/lib/utils"
-import type { Account } from "@/types"
+import { type Account, isAccountUnavailable } from "@/types"
 import { getProviderName, ProviderIcon } from "./ProviderIcon"
 
 interface AccountListProps {
@@ -55,7 +55,7 @@ export function AccountList({
     <>
       <div className="space-y-1">
         {accounts.map((account) => {
-          const hasError = account.status === "error"
+          const hasError = isAccountUnavailable(account)
           return (
             <div
               key={account.id}
@@ -1,4 +1,4 @@
-import { Download, Loader2, Lock } from "lucide-react"
+import { Download, EyeOff, GitCompare, Loader2, Lock, Stethoscope } from "lucide-react"
 import { useState } from "react"
 import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
@@ -19,7 +19,12 @@ import { Switch } from "@/components/ui/switch"
 import { extractErrorMessage, getErrorMessage } from "@/lib/error"
 import { accountService } from "@/services"
 import { saveFile } from "@/services/file.service"
-import type { Account, ExportAccountsRequest } from "@/types"
+import {
+  type Account,
+  type ExportAccountsRequest,
+  type ExportDiagnosticsResponse,
+  isAccountUnavailable,
+} from "@/types"
 import { getProviderName, ProviderIcon } from "./ProviderIcon"
 
 interface ExportDialogProps {
@@ -34,6 +39,9 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
   const [encrypt, setEncrypt] = useState(false)
   const [password, setPassword] = useState("")
   const [confirmPassword, setConfirmPassword] = useState("")
+  const [reproducible, setReproducible] = useState(false)
+  const [diagnostics, setDiagnostics] = useState(false)
+  const [anonymize, setAnonymize] = useState(true)
   const [isExporting, setIsExporting] = useState(false)
 
   // 全选/取消全选
@@ -62,7 +70,8 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
       toast.error(t("export.noAccountsSelected"))
       return false
     }
-    if (!encrypt) return true
+    // 诊断导出只在匿名化时用密码加密映射表
+    if (!encrypt || (diagnostics && !anonymize)) return true
 
     if (!password) {
       toast.error(t("export.passwordRequired"))
@@ -84,6 +93,26 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
     setEncrypt(false)
     setPassword("")
     setConfirmPassword("")
+    setReproducible(false)
+    setDiagnostics(false)
+    setAnonymize(true)
+  }
+
+  /** 保存诊断报告，匿名化时再单独保存加密的映射表 */
+  const saveDiagnostics = async (data: ExportDiagnosticsResponse): Promise<boolean> => {
+    const saved = await saveFile(data.content, {
+      defaultFilename: data.suggestedFilename,
+      filters: [{ name: "JSON", extensions: ["json"] }],
+    })
+    if (!saved) return false
+    if (data.mapping && data.mappingFilename) {
+      const mappingSaved = await saveFile(data.mapping, {
+        defaultFilename: data.mappingFilename,
+        filters: [{ name: "DNS Orchestrator Backup", extensions: ["dnso"] }],
+      })
+      if (!mappingSaved) toast.warning(t("export.mappingNotSaved"))
+    }
+    return true
   }
 
   const handleExport = async () => {
@@ -91,10 +120,28 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
 
     setIsExporting(true)
     try {
+      if (diagnostics) {
+        const response = await accountService.exportDiagnostics({
+          accountIds: Array.from(selectedIds),
+          anonymize,
+          mappingPassword: anonymize && encrypt ? password : undefined,
+        })
+        if (!(response.success && response.data)) {
+          toast.error(getErrorMessage(response.error))
+          return
+        }
+        if (!(await saveDiagnostics(response.data))) return
+        toast.success(t("export.diagnosticsSuccess", { count: selectedIds.size }))
+        onOpenChange(false)
+        resetForm()
+        return
+      }
+
       const request: ExportAccountsRequest = {
         accountIds: Array.from(selectedIds),
         encrypt,
         password: encrypt ? password : undefined,
+        reproducible,
       }
 
       const response = await accountService.exportAccounts(request)
@@ -142,7 +189,7 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
                     <Checkbox
                       checked={selectedIds.has(account.id)}
                       onCheckedChange={() => handleToggle(account.id)}
-                      disabled={account.status === "error"}
+                      disabled={isAccountUnavailable(account)}
                     />
                     <ProviderIcon provider={account.provider} className="h-4 w-4" />
                     <span className="flex-1 truncate text-sm">{account.name}</span>
@@ -158,42 +205,89 @@ export function ExportDialog({ open, onOpenChange, accounts }: ExportDialogProps
             </p>
           </div>
 
-          {/* 加密选项 */}
-          <div className="space-y-3">
+          {/* 诊断报告 */}
+          <div className="space-y-1">
             <div className="flex items-center justify-between">
               <div className="flex items-center gap-2">
-                <Lock className="h-4 w-4" />
-                <Label htmlFor="encrypt">{t("export.encryptExport")}</Label>
+                <Stethoscope className="h-4 w-4" />
+                <Label htmlFor="diagnostics">{t("export.diagnostics")}</Label>
               </div>
-              <Switch id="encrypt" checked={encrypt} onCheckedChange={setEncrypt} />
+              <Switch id="diagnostics" checked={diagnostics} onCheckedChange={setDiagnostics} />
             </div>
+            <p className="pl-6 text-muted-foreground text-xs">{t("export.diagnosticsNote")}</p>
+          </div>
 
-            {encrypt && (
-              <div className="space-y-3 pl-6">
-                <div className="space-y-2">
-                  <Label htmlFor="password">{t("export.password")}</Label>
-                  <Input
-                    id="password"
-                    type="password"
-                    value={password}
-                    onChange={(e) => setPassword(e.target.value)}
-                    placeholder={t("export.passwordPlaceholder")}
-                  />
+          {diagnostics && (
+            <div className="space-y-1">
+              <div className="flex items-center justify-between">
+                <div className="flex items-center gap-2">
+                  <EyeOff className="h-4 w-4" />
+                  <Label htmlFor="anonymize">{t("export.anonymize")}</Label>
                 </div>
-                <div className="space-y-2">
-                  <Label htmlFor="confirmPassword">{t("export.confirmPassword")}</Label>
-                  <Input
-                    id="confirmPassword"
-                    type="password"
-                    value={confirmPassword}
-                    onChange={(e) => setConfirmPassword(e.target.value)}
-                    placeholder={t("export.confirmPasswordPlaceholder")}
-                  />
+                <Switch id="anonymize" checked={anonymize} onCheckedChange={setAnonymize} />
+              </div>
+              <p className="pl-6 text-muted-foreground text-xs">{t("export.anonymizeNote")}</p>
+            </div>
+          )}
+
+          {/* 可复现导出 */}
+          {!diagnostics && (
+            <div className="space-y-1">
+              <div className="flex items-center justify-between">
+                <div className="flex items-center gap-2">
+                  <GitCompare className="h-4 w-4" />
+                  <Label htmlFor="reproducible">{t("export.reproducible")}</Label>
                 </div>
-                <p className="text-muted-foreground text-xs">{t("export.encryptionNote")}</p>
+                <Switch
+                  id="reproducible"
+                  checked={reproducible}
+                  onCheckedChange={setReproducible}
+                />
               </div>
-            )}
-          </div>
+              <p className="pl-6 text-muted-foreground text-xs">{t("export.reproducibleNote")}</p>
+            </div>
+          )}
+
+          {/* 加密选项（诊断导出时加密映射表） */}
+          {(!diagnostics || anonymize) && (
+            <div className="space-y-3">
+              <div className="flex items-center justify-between">
+                <div className="flex items-center gap-2">
+                  <Lock className="h-4 w-4" />
+                  <Label htmlFor="encrypt">
+                    {diagnostics ? t("export.saveMapping") : t("export.encryptExport")}
+                  </Label>
+                </div>
+                <Switch id="encrypt" checked={encrypt} onCheckedChange={setEncrypt} />
+              </div>
+
+              {encrypt && (
+                <div className="space-y-3 pl-6">
+                  <div className="space-y-2">
+                    <Label htmlFor="password">{t("export.password")}</Label>
+                    <Input
+                      id="password"
+                      type="password"
+                      value={password}
+                      onChange={(e) => setPassword(e.target.value)}
+                      placeholder={t("export.passwordPlaceholder")}
+                    />
+                  </div>
+                  <div className="space-y-2">
+                    <Label htmlFor="confirmPassword">{t("export.confirmPassword")}</Label>
+                    <Input
+                      id="confirmPassword"
+                      type="password"
+                      value={confirmPassword}
+                      onChange={(e) => setConfirmPassword(e.target.value)}
+                      placeholder={t("export.confirmPasswordPlaceholder")}
+                    />
+                  </div>
+                  <p className="text-muted-foreground text-xs">{t("export.encryptionNote")}</p>
+                </div>
+              )}
+            </div>
+          )}
         </div>
 
         <DialogFooter>
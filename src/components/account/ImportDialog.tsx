@@ -18,7 +18,7 @@ import { ScrollArea } from "@/components/ui/scroll-area"
 import { extractErrorMessage, getErrorMessage } from "@/lib/error"
 import { accountService } from "@/services"
 import { openFile } from "@/services/file.service"
-import type { ImportAccountsRequest, ImportPreview } from "@/types"
+import type { ImportAccountsRequest, ImportPreview, ImportResult } from "@/types"
 import { getProviderName, ProviderIcon } from "./ProviderIcon"
 
 interface ImportDialogProps {
@@ -101,11 +101,14 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
     }
   }
 
-  const showImportResult = (successCount: number, failures: { name: string; reason: string }[]) => {
+  const showImportResult = ({ successCount, skippedCount, failures }: ImportResult) => {
     if (successCount > 0) {
       toast.success(t("import.success", { count: successCount }))
       onImportSuccess()
     }
+    if (skippedCount > 0) {
+      toast.info(t("import.skippedDuplicates", { count: skippedCount }))
+    }
     if (failures.length > 0) {
       toast.warning(t("import.partialFailure", { count: failures.length }), {
         description: failures.map((f) => `${f.name}: ${f.reason}`).join("\n"),
@@ -127,7 +130,7 @@ export function ImportDialog({ open, onOpenChange, onImportSuccess }: ImportDial
       const response = await accountService.importAccounts(request)
 
       if (response.success && response.data) {
-        showImportResult(response.data.successCount, response.data.failures)
+        showImportResult(response.data)
         onOpenChange(false)
         resetState()
       } else {
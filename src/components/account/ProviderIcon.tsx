@@ -11,6 +11,9 @@ const PROVIDER_ICONS: Record<string, React.ComponentType<{ className?: string }>
   aliyun: Server,
   dnspod: Server,
   huaweicloud: Server,
+  route53: Cloud,
+  gcp_dns: Cloud,
+  digitalocean: Cloud,
 }
 
 export function ProviderIcon({ provider, className }: ProviderIconProps) {
@@ -25,6 +28,9 @@ export function getProviderName(provider: string): string {
     aliyun: "阿里云",
     dnspod: "DNSPod",
     huaweicloud: "华为云",
+    route53: "AWS Route53",
+    gcp_dns: "Google Cloud DNS",
+    digitalocean: "DigitalOcean",
   }
   return PROVIDER_NAMES[provider] || provider
 }
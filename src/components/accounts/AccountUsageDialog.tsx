@@ -0,0 +1,219 @@
+import { Loader2 } from "lucide-react"
+import { useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { Badge, type BadgeProps } from "@/components/ui/badge"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select"
+import { extractErrorMessage, getErrorMessage } from "@/lib/error"
+import { cn } from "@/lib/utils"
+import { accountService } from "@/services"
+import type { Account, ApiUsageReport, ApiUsageRisk } from "@/types"
+
+/** 可查看的月份数（与后端保留期一致） */
+const MONTH_OPTIONS = 12
+
+/** 最近 12 个月（UTC，与后端统计日期一致），最新在前 */
+function recentMonths(): string[] {
+  const now = new Date()
+  return Array.from({ length: MONTH_OPTIONS }, (_, i) => {
+    const date = new Date(Date.UTC(now.getUTCFullYear(), now.getUTCMonth() - i, 1))
+    return date.toISOString().slice(0, 7)
+  })
+}
+
+const RISK_VARIANT: Record<ApiUsageRisk, BadgeProps["variant"]> = {
+  unknown: "outline",
+  normal: "secondary",
+  warning: "default",
+  exceeded: "destructive",
+}
+
+interface AccountUsageDialogProps {
+  account: Account | null
+  onOpenChange: (open: boolean) => void
+}
+
+/**
+ * 账户的服务商 API 用量：每日调用曲线、按操作分布、与上月对比及配额预测
+ */
+export function AccountUsageDialog({ account, onOpenChange }: AccountUsageDialogProps) {
+  const { t } = useTranslation()
+  const [months] = useState(recentMonths)
+  const [month, setMonth] = useState(months[0])
+  const [report, setReport] = useState<ApiUsageReport | null>(null)
+  const [error, setError] = useState<string | null>(null)
+  const [isLoading, setIsLoading] = useState(false)
+
+  const accountId = account?.id
+  useEffect(() => {
+    if (!accountId) {
+      return
+    }
+    let cancelled = false
+    setIsLoading(true)
+    setError(null)
+    accountService
+      .getApiUsageReport(accountId, month)
+      .then((response) => {
+        if (cancelled) return
+        if (response.success && response.data) {
+          setReport(response.data)
+        } else {
+          setReport(null)
+          setError(getErrorMessage(response.error))
+        }
+      })
+      .catch((err) => {
+        if (cancelled) return
+        setReport(null)
+        setError(extractErrorMessage(err))
+      })
+      .finally(() => {
+        if (!cancelled) setIsLoading(false)
+      })
+    return () => {
+      cancelled = true
+    }
+  }, [accountId, month])
+
+  const peak = report ? Math.max(1, ...report.days.map((day) => day.count)) : 1
+  const change =
+    report && report.previousMonthTotal > 0
+      ? Math.round(((report.total - report.previousMonthTotal) / report.previousMonthTotal) * 100)
+      : null
+
+  const quotaText = ({ quota, quotaUsageRatio }: ApiUsageReport) =>
+    quota
+      ? t(quota.window === "day" ? "accountUsage.quotaDay" : "accountUsage.quotaMonth", {
+          limit: quota.limit.toLocaleString(),
+          percent: Math.round((quotaUsageRatio ?? 0) * 100),
+        })
+      : t("accountUsage.quotaUnknown")
+
+  return (
+    <Dialog open={!!account} onOpenChange={onOpenChange}>
+      <DialogContent className="max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>{t("accountUsage.title", { name: account?.name })}</DialogTitle>
+          <DialogDescription>{t("accountUsage.description")}</DialogDescription>
+        </DialogHeader>
+
+        <div className="flex items-center justify-between gap-2">
+          <Select value={month} onValueChange={setMonth}>
+            <SelectTrigger className="w-36">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {months.map((value) => (
+                <SelectItem key={value} value={value}>
+                  {value}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          {report && (
+            <Badge variant={RISK_VARIANT[report.risk]}>
+              {t(`accountUsage.risk.${report.risk}`)}
+            </Badge>
+          )}
+        </div>
+
+        {isLoading ? (
+          <div className="flex justify-center py-10">
+            <Loader2 className="h-6 w-6 animate-spin text-muted-foreground" />
+          </div>
+        ) : error ? (
+          <p className="py-6 text-center text-destructive text-sm">{error}</p>
+        ) : report ? (
+          <div className="space-y-5">
+            {/* 本月 / 上月 / 预测 */}
+            <div className="grid grid-cols-3 gap-3">
+              <div className="rounded-lg border p-3">
+                <p className="text-muted-foreground text-xs">{t("accountUsage.total")}</p>
+                <p className="font-semibold text-lg">{report.total.toLocaleString()}</p>
+                {change !== null && (
+                  <p className="text-muted-foreground text-xs">
+                    {t("accountUsage.change", { percent: change > 0 ? `+${change}` : change })}
+                  </p>
+                )}
+              </div>
+              <div className="rounded-lg border p-3">
+                <p className="text-muted-foreground text-xs">{t("accountUsage.previousMonth")}</p>
+                <p className="font-semibold text-lg">
+                  {report.previousMonthTotal.toLocaleString()}
+                </p>
+              </div>
+              <div className="rounded-lg border p-3">
+                <p className="text-muted-foreground text-xs">{t("accountUsage.forecast")}</p>
+                <p className="font-semibold text-lg">{report.forecastTotal.toLocaleString()}</p>
+              </div>
+            </div>
+
+            <p className="text-muted-foreground text-xs">{quotaText(report)}</p>
+
+            {/* 每日调用曲线 */}
+            <div>
+              <p className="mb-2 font-medium text-sm">{t("accountUsage.dailyCalls")}</p>
+              {report.total === 0 ? (
+                <p className="text-muted-foreground text-sm">{t("accountUsage.noData")}</p>
+              ) : (
+                <div className="flex h-32 items-end gap-px">
+                  {report.days.map((day) => (
+                    <div
+                      key={day.date}
+                      title={`${day.date}: ${day.count.toLocaleString()}`}
+                      className={cn(
+                        "min-w-0 flex-1 rounded-t-sm",
+                        day.count > 0 ? "bg-primary/70" : "bg-muted"
+                      )}
+                      style={{ height: `${Math.max(2, (day.count / peak) * 100)}%` }}
+                    />
+                  ))}
+                </div>
+              )}
+            </div>
+
+            {/* 按操作分布 */}
+            {report.operations.length > 0 && (
+              <div>
+                <p className="mb-2 font-medium text-sm">{t("accountUsage.operations")}</p>
+                <div className="space-y-1.5">
+                  {report.operations.map((item) => (
+                    <div key={item.operation} className="flex items-center gap-3 text-sm">
+                      <span className="w-40 shrink-0 truncate">
+                        {t(`accountUsage.operation.${item.operation}`)}
+                      </span>
+                      <div className="h-2 flex-1 rounded-full bg-muted">
+                        <div
+                          className="h-2 rounded-full bg-primary/70"
+                          style={{ width: `${(item.count / report.total) * 100}%` }}
+                        />
+                      </div>
+                      <span className="w-16 shrink-0 text-right tabular-nums">
+                        {item.count.toLocaleString()}
+                      </span>
+                    </div>
+                  ))}
+                </div>
+              </div>
+            )}
+
+            <p className="text-muted-foreground text-xs">{t("accountUsage.note")}</p>
+          </div>
+        ) : null}
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -1,10 +1,12 @@
 import {
+  BarChart3,
   Download,
   Globe,
   Loader2,
   MoreHorizontal,
   Pencil,
   Plus,
+  ShieldCheck,
   Trash2,
   TriangleAlert,
   Upload,
@@ -18,6 +20,7 @@ import { ExportDialog } from "@/components/account/ExportDialog"
 import { ImportDialog } from "@/components/account/ImportDialog"
 import { getProviderName, ProviderIcon } from "@/components/account/ProviderIcon"
 import { AccountBatchActionBar } from "@/components/accounts/AccountBatchActionBar"
+import { AccountUsageDialog } from "@/components/accounts/AccountUsageDialog"
 import {
   AlertDialog,
   AlertDialogAction,
@@ -43,7 +46,7 @@ import { ScrollArea } from "@/components/ui/scroll-area"
 import { Skeleton } from "@/components/ui/skeleton"
 import { cn } from "@/lib/utils"
 import { useAccountStore } from "@/stores"
-import type { Account } from "@/types"
+import { type Account, isAccountUnavailable } from "@/types"
 
 export function AccountsPage() {
   const { t } = useTranslation()
@@ -53,6 +56,7 @@ export function AccountsPage() {
     accounts,
     isLoading,
     isDeleting,
+    isValidating,
     isExportDialogOpen,
     isImportDialogOpen,
     selectedAccountIds,
@@ -61,6 +65,7 @@ export function AccountsPage() {
       accounts: state.accounts,
       isLoading: state.isLoading,
       isDeleting: state.isDeleting,
+      isValidating: state.isValidating,
       isExportDialogOpen: state.isExportDialogOpen,
       isImportDialogOpen: state.isImportDialogOpen,
       selectedAccountIds: state.selectedAccountIds,
@@ -75,12 +80,15 @@ export function AccountsPage() {
   const openImportDialog = useAccountStore((state) => state.openImportDialog)
   const closeImportDialog = useAccountStore((state) => state.closeImportDialog)
   const toggleAccountSelection = useAccountStore((state) => state.toggleAccountSelection)
+  const validateAccount = useAccountStore((state) => state.validateAccount)
+  const validateAllAccounts = useAccountStore((state) => state.validateAllAccounts)
 
   const isSelectMode = selectedAccountIds.size > 0
 
   const [showAccountForm, setShowAccountForm] = useState(false)
   const [editTarget, setEditTarget] = useState<Account | null>(null)
   const [deleteTarget, setDeleteTarget] = useState<Account | null>(null)
+  const [usageTarget, setUsageTarget] = useState<Account | null>(null)
 
   const handleDelete = async () => {
     if (deleteTarget) {
@@ -114,6 +122,19 @@ export function AccountsPage() {
               <Download className="mr-2 h-4 w-4" />
               {t("export.title")}
             </Button>
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={validateAllAccounts}
+              disabled={accounts.length === 0 || isValidating}
+            >
+              {isValidating ? (
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+              ) : (
+                <ShieldCheck className="mr-2 h-4 w-4" />
+              )}
+              {t("account.validateAll")}
+            </Button>
           </div>
 
           {/* 移动端：收起到下拉菜单 */}
@@ -132,6 +153,13 @@ export function AccountsPage() {
                 <Download className="mr-2 h-4 w-4" />
                 {t("export.title")}
               </DropdownMenuItem>
+              <DropdownMenuItem
+                onClick={validateAllAccounts}
+                disabled={accounts.length === 0 || isValidating}
+              >
+                <ShieldCheck className="mr-2 h-4 w-4" />
+                {t("account.validateAll")}
+              </DropdownMenuItem>
             </DropdownMenuContent>
           </DropdownMenu>
 
@@ -174,7 +202,9 @@ export function AccountsPage() {
           ) : (
             <div className="grid gap-3 sm:grid-cols-2 lg:grid-cols-3">
               {accounts.map((account) => {
-                const hasError = account.status === "error"
+                const hasError = isAccountUnavailable(account)
+                const credentialStatus = account.credentialStatus ?? "unknown"
+                const credentialsInvalid = credentialStatus === "invalid"
                 const isSelected = selectedAccountIds.has(account.id)
                 return (
                   <Card
@@ -194,7 +224,7 @@ export function AccountsPage() {
                         <div className="min-w-0 flex-1">
                           <div className="flex items-center gap-2">
                             <h3 className="truncate font-medium">{account.name}</h3>
-                            {hasError && (
+                            {(hasError || credentialsInvalid) && (
                               <TriangleAlert className="h-4 w-4 shrink-0 text-destructive" />
                             )}
                           </div>
@@ -206,6 +236,20 @@ export function AccountsPage() {
                               {account.error}
                             </p>
                           )}
+                          {account.lastValidatedAt && (
+                            <p
+                              className={cn(
+                                "mt-1 truncate text-xs",
+                                credentialsInvalid ? "text-destructive" : "text-muted-foreground"
+                              )}
+                            >
+                              {t(`account.credentialStatus.${credentialStatus}`)}
+                              {" · "}
+                              {t("account.lastValidatedAt", {
+                                time: new Date(account.lastValidatedAt).toLocaleString(),
+                              })}
+                            </p>
+                          )}
                         </div>
                         {!isSelectMode && (
                           <DropdownMenu>
@@ -224,6 +268,25 @@ export function AccountsPage() {
                                 <Pencil className="mr-2 h-4 w-4" />
                                 {t("account.editAccount")}
                               </DropdownMenuItem>
+                              <DropdownMenuItem
+                                onClick={(e) => {
+                                  e.stopPropagation()
+                                  setUsageTarget(account)
+                                }}
+                              >
+                                <BarChart3 className="mr-2 h-4 w-4" />
+                                {t("accountUsage.menu")}
+                              </DropdownMenuItem>
+                              <DropdownMenuItem
+                                disabled={isValidating}
+                                onClick={(e) => {
+                                  e.stopPropagation()
+                                  validateAccount(account.id)
+                                }}
+                              >
+                                <ShieldCheck className="mr-2 h-4 w-4" />
+                                {t("account.validate")}
+                              </DropdownMenuItem>
                               <DropdownMenuItem
                                 onClick={(e) => {
                                   e.stopPropagation()
@@ -268,6 +331,10 @@ export function AccountsPage() {
         onOpenChange={closeImportDialog}
         onImportSuccess={fetchAccounts}
       />
+      <AccountUsageDialog
+        account={usageTarget}
+        onOpenChange={(open) => !open && setUsageTarget(null)}
+      />
 
       {/* 删除确认 */}
       <AlertDialog open={!!deleteTarget} onOpenChange={(open) => !open && setDeleteTarget(null)}>
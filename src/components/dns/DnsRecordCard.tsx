@@ -102,6 +102,8 @@ function renderRecordValueMobile(record: DnsRecord) {
           {data.content.value}
         </>
       )
+    case "SOA":
+      return `${data.content.mname} ${data.content.rname}`
   }
 }
 
@@ -1,5 +1,5 @@
 import { Loader2 } from "lucide-react"
-import { useCallback, useState } from "react"
+import { useCallback, useEffect, useState } from "react"
 import { useTranslation } from "react-i18next"
 import { Button } from "@/components/ui/button"
 import {
@@ -20,15 +20,24 @@ import {
 } from "@/components/ui/select"
 import { Switch } from "@/components/ui/switch"
 import { DNS } from "@/constants"
+import { accountService } from "@/services"
 import { useDnsStore, useDomainStore } from "@/stores"
 import { useSettingsStore } from "@/stores/settingsStore"
-import type { DnsRecord, DnsRecordType, RecordData } from "@/types"
+import type {
+  AccountCapabilities,
+  DeepLinkTarget,
+  DnsRecord,
+  DnsRecordType,
+  RecordData,
+} from "@/types"
 import { RECORD_TYPE_INFO, RECORD_TYPES, TTL_OPTIONS } from "@/types/dns"
 
 interface DnsRecordFormProps {
   accountId: string
   domainId: string
   record?: DnsRecord | null
+  /** 新建时的预填值（来自深链接），仅填入表单，提交仍由用户确认 */
+  prefill?: DeepLinkTarget | null
   onClose: () => void
   supportsProxy?: boolean
 }
@@ -50,8 +59,38 @@ type FormData = {
   | { type: "CAA"; flags: number; tag: string; value: string }
 )
 
+// 按预填的类型/值初始化新建表单数据
+function initPrefillFormData(prefill: DeepLinkTarget): FormData {
+  const baseData = { name: prefill.name ?? "", ttl: DNS.DEFAULT_TTL }
+  const value = prefill.value ?? ""
+
+  switch (prefill.recordType ?? "A") {
+    case "A":
+      return { ...baseData, type: "A", address: value }
+    case "AAAA":
+      return { ...baseData, type: "AAAA", address: value }
+    case "CNAME":
+      return { ...baseData, type: "CNAME", target: value }
+    case "MX":
+      return { ...baseData, type: "MX", priority: 10, exchange: value }
+    case "TXT":
+      return { ...baseData, type: "TXT", text: value }
+    case "NS":
+      return { ...baseData, type: "NS", nameserver: value }
+    case "SRV":
+      return { ...baseData, type: "SRV", priority: 10, weight: 5, port: 80, target: value }
+    case "CAA":
+      return { ...baseData, type: "CAA", flags: 0, tag: "issue", value }
+    default:
+      return { ...baseData, type: "A", address: "" }
+  }
+}
+
 // 从 DnsRecord 初始化表单数据
-function initFormData(record: DnsRecord | null | undefined): FormData {
+function initFormData(
+  record: DnsRecord | null | undefined,
+  prefill?: DeepLinkTarget | null
+): FormData {
   const baseData = {
     name: record?.name || "",
     ttl: record?.ttl || DNS.DEFAULT_TTL,
@@ -59,7 +98,7 @@ function initFormData(record: DnsRecord | null | undefined): FormData {
   }
 
   if (!record) {
-    return { ...baseData, type: "A", address: "" }
+    return prefill ? initPrefillFormData(prefill) : { ...baseData, type: "A", address: "" }
   }
 
   const { data } = record
@@ -108,6 +147,7 @@ export function DnsRecordForm({
   accountId,
   domainId,
   record,
+  prefill,
   onClose,
   supportsProxy = false,
 }: DnsRecordFormProps) {
@@ -115,7 +155,7 @@ export function DnsRecordForm({
   const { createRecord, updateRecord, isLoading } = useDnsStore()
   const isEditing = !!record
 
-  const [formData, setFormData] = useState<FormData>(initFormData(record))
+  const [formData, setFormData] = useState<FormData>(initFormData(record, prefill))
 
   // 获取当前域名（从缓存中根据 accountId 和 domainId 查找）
   const currentDomain = useDomainStore((state) => {
@@ -123,6 +163,28 @@ export function DnsRecordForm({
     return domains.find((d) => d.id === domainId) ?? null
   })
 
+  // 账户的实际能力（记录类型、TTL 范围），获取失败时不做限制，由后端校验兜底
+  const [capabilities, setCapabilities] = useState<AccountCapabilities | null>(null)
+  useEffect(() => {
+    let cancelled = false
+    accountService.getAccountCapabilities(accountId).then((response) => {
+      if (!cancelled && response.success && response.data) {
+        setCapabilities(response.data)
+      }
+    })
+    return () => {
+      cancelled = true
+    }
+  }, [accountId])
+
+  const isTypeSupported = (type: DnsRecordType) =>
+    !capabilities || capabilities.recordTypes.includes(type)
+  const isTtlSupported = (ttl: number) => {
+    if (!capabilities) return true
+    const { features, limits } = capabilities
+    return (ttl === 1 && features.autoTtl) || (ttl >= limits.minTtl && ttl <= limits.maxTtl)
+  }
+
   // 获取设置开关状态
   const showRecordHints = useSettingsStore((state) => state.showRecordHints)
 
@@ -332,7 +394,7 @@ export function DnsRecordForm({
               </SelectTrigger>
               <SelectContent>
                 {RECORD_TYPES.map((type) => (
-                  <SelectItem key={type} value={type}>
+                  <SelectItem key={type} value={type} disabled={!isTypeSupported(type)}>
                     <span className="font-medium">{type}</span>
                     <span className="ml-2 text-muted-foreground text-xs">
                       - {t(RECORD_TYPE_INFO[type].descriptionKey)}
@@ -563,7 +625,11 @@ export function DnsRecordForm({
               </SelectTrigger>
               <SelectContent>
                 {TTL_OPTIONS.map((option) => (
-                  <SelectItem key={option.value} value={String(option.value)}>
+                  <SelectItem
+                    key={option.value}
+                    value={String(option.value)}
+                    disabled={!isTtlSupported(option.value)}
+                  >
                     {t(option.labelKey, { count: "count" in option ? option.count : undefined })}
                   </SelectItem>
                 ))}
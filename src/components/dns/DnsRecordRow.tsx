@@ -97,6 +97,8 @@ function renderRecordValue(record: DnsRecord) {
           {data.content.value}
         </>
       )
+    case "SOA":
+      return `${data.content.mname} ${data.content.rname}`
   }
 }
 
@@ -120,6 +122,8 @@ function getDisplayText(record: DnsRecord): string {
       return `[${data.content.priority}] [${data.content.weight}] [${data.content.port}] ${data.content.target}`
     case "CAA":
       return `[${data.content.flags}] [${data.content.tag}] ${data.content.value}`
+    case "SOA":
+      return `${data.content.mname} ${data.content.rname} ${data.content.serial}`
   }
 }
 
@@ -38,12 +38,18 @@ import { DnsBatchActionBar } from "../DnsBatchActionBar"
 import { DnsRecordForm } from "../DnsRecordForm"
 import { DnsRecordWizard } from "../DnsRecordWizard"
 import { DnsTableToolbar } from "../DnsTableToolbar"
+import { SoaInfoCard } from "../SoaInfoCard"
 import { useDnsTableSort } from "../useDnsTableSort"
 import { DesktopTable } from "./DesktopTable"
 import { MobileCardList } from "./MobileCardList"
 import type { DnsRecordTableProps } from "./types"
 
-export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecordTableProps) {
+export function DnsRecordTable({
+  accountId,
+  domainId,
+  supportsProxy,
+  deepLink,
+}: DnsRecordTableProps) {
   const { t } = useTranslation()
   const isMobile = useIsMobile()
   const paginationMode = useSettingsStore((state) => state.paginationMode)
@@ -104,7 +110,11 @@ export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecord
   const clearSelection = useDnsStore((state) => state.clearSelection)
   const batchDeleteRecords = useDnsStore((state) => state.batchDeleteRecords)
 
-  const [showAddForm, setShowAddForm] = useState(false)
+  // 深链接 create_record：挂载时直接打开预填的新建表单，由用户确认提交
+  const [formPrefill, setFormPrefill] = useState(
+    deepLink?.action === "create_record" ? deepLink : null
+  )
+  const [showAddForm, setShowAddForm] = useState(formPrefill !== null)
   const [showWizard, setShowWizard] = useState(false)
   const [editingRecord, setEditingRecord] = useState<DnsRecord | null>(null)
   const [deletingRecord, setDeletingRecord] = useState<DnsRecord | null>(null)
@@ -112,8 +122,17 @@ export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecord
   const sentinelRef = useRef<HTMLElement | null>(null)
   const scrollContainerRef = useRef<HTMLDivElement>(null)
 
+  // SOA 以只读信息卡展示，不进入记录列表
+  const { soaRecord, tableRecords } = useMemo(
+    () => ({
+      soaRecord: records.find((r) => r.data.type === "SOA"),
+      tableRecords: records.filter((r) => r.data.type !== "SOA"),
+    }),
+    [records]
+  )
+
   // 使用排序 hook
-  const { sortField, sortDirection, sortedRecords, handleSort } = useDnsTableSort(records)
+  const { sortField, sortDirection, sortedRecords, handleSort } = useDnsTableSort(tableRecords)
 
   // 统一的 ref callback
   const setSentinelRef = useCallback((node: HTMLElement | null) => {
@@ -152,8 +171,13 @@ export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecord
   }, [setKeyword, setRecordType, fetchRecords, accountId, domainId])
 
   useEffect(() => {
-    fetchRecords(accountId, domainId)
-  }, [accountId, domainId, fetchRecords])
+    // 深链接 filter_records：以链接中的名称/类型作为初始筛选
+    if (deepLink?.action === "filter_records") {
+      fetchRecords(accountId, domainId, deepLink.name ?? "", deepLink.recordType ?? "")
+    } else {
+      fetchRecords(accountId, domainId)
+    }
+  }, [accountId, domainId, fetchRecords, deepLink])
 
   // 无限滚动 IntersectionObserver（仅在无限滚动模式下启用）
   const handleObserver = useCallback(
@@ -193,6 +217,7 @@ export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecord
   const handleFormClose = useCallback(() => {
     setShowAddForm(false)
     setEditingRecord(null)
+    setFormPrefill(null)
   }, [])
 
   const handleRefresh = useCallback(() => {
@@ -277,6 +302,7 @@ export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecord
 
       {/* Table / Card List */}
       <div ref={scrollContainerRef} className="min-h-0 flex-1 overflow-auto">
+        {soaRecord?.data.type === "SOA" && <SoaInfoCard soa={soaRecord.data.content} />}
         {isMobile ? (
           <MobileCardList
             records={sortedRecords}
@@ -407,6 +433,7 @@ export function DnsRecordTable({ accountId, domainId, supportsProxy }: DnsRecord
           accountId={accountId}
           domainId={domainId}
           record={editingRecord}
+          prefill={formPrefill}
           onClose={handleFormClose}
           supportsProxy={supportsProxy}
         />
@@ -1,10 +1,12 @@
-import type { DnsRecord } from "@/types"
+import type { DeepLinkTarget, DnsRecord } from "@/types"
 import type { SortField } from "../useDnsTableSort"
 
 export interface DnsRecordTableProps {
   accountId: string
   domainId: string
   supportsProxy: boolean
+  /** 深链接预填参数（只打开表单或筛选，不直接提交） */
+  deepLink?: DeepLinkTarget | null
 }
 
 export interface MobileCardListProps {
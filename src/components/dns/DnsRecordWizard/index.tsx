@@ -57,7 +57,7 @@ export function DnsRecordWizard({
   onOpenAdvancedForm,
 }: DnsRecordWizardProps) {
   const { t } = useTranslation()
-  const { createRecord, isLoading } = useDnsStore()
+  const { createRecord, batchCreateRecords, isLoading } = useDnsStore()
 
   // 获取当前域名
   const currentDomain = useDomainStore((state) => {
@@ -126,17 +126,16 @@ export function DnsRecordWizard({
     const preset = getMailPresetById(state.mailPresetId)
     if (!preset) return
 
-    for (const record of preset.records) {
-      const data = presetRecordToRecordData(record, domainName)
-      const request: CreateDnsRecordRequest = {
-        domainId,
-        name: record.name,
-        ttl: record.ttl,
-        data,
-      }
-      await createRecord(accountId, request)
+    const requests: CreateDnsRecordRequest[] = preset.records.map((record) => ({
+      domainId,
+      name: record.name,
+      ttl: record.ttl,
+      data: presetRecordToRecordData(record, domainName),
+    }))
+    const result = await batchCreateRecords(accountId, domainId, requests)
+    if (result) {
+      onClose()
     }
-    onClose()
   }
 
   // 创建单条记录
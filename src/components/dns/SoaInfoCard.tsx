@@ -0,0 +1,46 @@
+import { Info } from "lucide-react"
+import { memo } from "react"
+import { useTranslation } from "react-i18next"
+import { Badge } from "@/components/ui/badge"
+import { Card } from "@/components/ui/card"
+import type { SoaRecordContent } from "@/types"
+
+interface SoaInfoCardProps {
+  soa: SoaRecordContent
+}
+
+/** SOA 只读信息卡（SOA 由服务商维护，不作为普通记录行展示） */
+export const SoaInfoCard = memo(function SoaInfoCard({ soa }: SoaInfoCardProps) {
+  const { t } = useTranslation()
+
+  const fields: { label: string; value: string | number }[] = [
+    { label: t("dns.soa.mname"), value: soa.mname },
+    { label: t("dns.soa.rname"), value: soa.rname },
+    { label: t("dns.soa.serial"), value: soa.serial },
+    { label: t("dns.soa.refresh"), value: t("dns.ttlSeconds", { count: soa.refresh }) },
+    { label: t("dns.soa.retry"), value: t("dns.ttlSeconds", { count: soa.retry }) },
+    { label: t("dns.soa.expire"), value: t("dns.ttlSeconds", { count: soa.expire }) },
+    { label: t("dns.soa.minimum"), value: t("dns.ttlSeconds", { count: soa.minimum }) },
+  ]
+
+  return (
+    <Card className="mx-4 mt-3 p-3">
+      <div className="flex items-center gap-2">
+        <Badge variant="secondary">SOA</Badge>
+        <span className="font-medium text-sm">{t("dns.soa.title")}</span>
+        <span className="flex items-center gap-1 text-muted-foreground text-xs">
+          <Info className="h-3 w-3" />
+          {t("dns.soa.managedByProvider")}
+        </span>
+      </div>
+      <dl className="mt-2 grid grid-cols-2 gap-x-4 gap-y-1 text-sm md:grid-cols-4">
+        {fields.map((field) => (
+          <div key={field.label} className="min-w-0">
+            <dt className="text-muted-foreground text-xs">{field.label}</dt>
+            <dd className="select-text truncate font-mono">{field.value}</dd>
+          </div>
+        ))}
+      </dl>
+    </Card>
+  )
+})
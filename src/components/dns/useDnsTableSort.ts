@@ -31,6 +31,8 @@ function getRecordDisplayValue(record: DnsRecord): string {
       return data.content.target
     case "CAA":
       return data.content.value
+    case "SOA":
+      return data.content.mname
   }
 }
 
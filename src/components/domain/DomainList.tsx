@@ -69,9 +69,10 @@ export function DomainList({
     { labelKey: string; variant: "default" | "secondary" | "destructive" | "outline" }
   > = {
     active: { labelKey: "domain.status.active", variant: "default" },
-    paused: { labelKey: "domain.status.paused", variant: "secondary" },
     pending: { labelKey: "domain.status.pending", variant: "outline" },
-    error: { labelKey: "domain.status.error", variant: "destructive" },
+    suspended: { labelKey: "domain.status.suspended", variant: "destructive" },
+    expired: { labelKey: "domain.status.expired", variant: "destructive" },
+    moved: { labelKey: "domain.status.moved", variant: "secondary" },
     unknown: { labelKey: "domain.status.unknown", variant: "outline" },
   }
 
@@ -132,6 +133,7 @@ export function DomainList({
             </div>
             <Badge
               variant={statusConfig[domain.status]?.variant ?? "secondary"}
+              title={domain.providerStatusRaw}
               className="text-xs"
             >
               {t(statusConfig[domain.status]?.labelKey ?? "domain.status.active")}
@@ -0,0 +1,77 @@
+import { ArrowUpDown } from "lucide-react"
+import { useTranslation } from "react-i18next"
+import { useShallow } from "zustand/react/shallow"
+import { Badge } from "@/components/ui/badge"
+import { Button } from "@/components/ui/button"
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuLabel,
+  DropdownMenuRadioGroup,
+  DropdownMenuRadioItem,
+  DropdownMenuSeparator,
+  DropdownMenuTrigger,
+} from "@/components/ui/dropdown-menu"
+import type { DomainSortKey, DomainStatusFilter } from "@/lib/domain-status"
+import { useDomainStore } from "@/stores"
+
+const FILTERS: DomainStatusFilter[] = ["all", "expiringSoon", "certificateExpiringSoon", "stale"]
+const SORTS: DomainSortKey[] = ["default", "expiry", "certificate"]
+
+/**
+ * 按派生状态（到期、证书检查结果）筛选与排序的按钮
+ * 与标签筛选按钮同行显示
+ */
+export function DomainStatusFilterButton() {
+  const { t } = useTranslation()
+  const { statusFilter, domainSort, setStatusFilter, setDomainSort } = useDomainStore(
+    useShallow((state) => ({
+      statusFilter: state.statusFilter,
+      domainSort: state.domainSort,
+      setStatusFilter: state.setStatusFilter,
+      setDomainSort: state.setDomainSort,
+    }))
+  )
+  const activeCount = Number(statusFilter !== "all") + Number(domainSort !== "default")
+
+  return (
+    <DropdownMenu>
+      <DropdownMenuTrigger asChild>
+        <Button variant="outline" size="sm" className="h-10 w-full sm:w-auto">
+          <ArrowUpDown className="mr-2 h-4 w-4" />
+          {t("domain.derivedStatus.button")}
+          {activeCount > 0 && (
+            <Badge variant="secondary" className="ml-2 rounded-full px-1.5 py-0 text-xs">
+              {activeCount}
+            </Badge>
+          )}
+        </Button>
+      </DropdownMenuTrigger>
+      <DropdownMenuContent className="w-56" align="start">
+        <DropdownMenuLabel>{t("domain.derivedStatus.filterLabel")}</DropdownMenuLabel>
+        <DropdownMenuRadioGroup
+          value={statusFilter}
+          onValueChange={(value) => setStatusFilter(value as DomainStatusFilter)}
+        >
+          {FILTERS.map((filter) => (
+            <DropdownMenuRadioItem key={filter} value={filter}>
+              {t(`domain.derivedStatus.filter.${filter}`)}
+            </DropdownMenuRadioItem>
+          ))}
+        </DropdownMenuRadioGroup>
+        <DropdownMenuSeparator />
+        <DropdownMenuLabel>{t("domain.derivedStatus.sortLabel")}</DropdownMenuLabel>
+        <DropdownMenuRadioGroup
+          value={domainSort}
+          onValueChange={(value) => setDomainSort(value as DomainSortKey)}
+        >
+          {SORTS.map((sort) => (
+            <DropdownMenuRadioItem key={sort} value={sort}>
+              {t(`domain.derivedStatus.sort.${sort}`)}
+            </DropdownMenuRadioItem>
+          ))}
+        </DropdownMenuRadioGroup>
+      </DropdownMenuContent>
+    </DropdownMenu>
+  )
+}
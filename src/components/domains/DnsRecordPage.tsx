@@ -1,20 +1,28 @@
-import { ArrowLeft, StickyNote } from "lucide-react"
-import { useEffect, useMemo } from "react"
+import { ArrowLeft, Link2, StickyNote } from "lucide-react"
+import { useCallback, useEffect, useMemo } from "react"
 import { useTranslation } from "react-i18next"
-import { useNavigate, useParams } from "react-router-dom"
+import { useLocation, useNavigate, useParams } from "react-router-dom"
 import { useShallow } from "zustand/react/shallow"
 import { DnsRecordTable } from "@/components/dns/DnsRecordTable"
 import { Button } from "@/components/ui/button"
 import { PageHeader } from "@/components/ui/page-header"
 import { PageLayout } from "@/components/ui/page-layout"
+import { useCopyToClipboard } from "@/hooks/useCopyToClipboard"
 import { addRecentDomain } from "@/lib/recent-domains"
+import { deepLinkService } from "@/services"
 import { useAccountStore, useDomainStore } from "@/stores"
+import type { DeepLinkTarget } from "@/types"
 
 export function DnsRecordPage() {
   const { t } = useTranslation()
   const navigate = useNavigate()
+  const location = useLocation()
+  const copyToClipboard = useCopyToClipboard()
   const { accountId, domainId } = useParams<{ accountId: string; domainId: string }>()
 
+  // 由深链接跳转时携带的预填参数
+  const deepLink = (location.state as { deepLink?: DeepLinkTarget } | null)?.deepLink ?? null
+
   // 使用 useShallow 优化 store 订阅粒度
   const { accounts, providers } = useAccountStore(
     useShallow((state) => ({
@@ -60,6 +68,15 @@ export function DnsRecordPage() {
     return provider?.features ?? null
   }, [selectedAccount, providers])
 
+  // 复制指向当前域名的深链接
+  const handleCopyLink = useCallback(async () => {
+    if (!(accountId && domainId)) return
+    const response = await deepLinkService.generate({ accountId, domainId })
+    if (response.success && response.data) {
+      await copyToClipboard(response.data, t("deepLink.copied"))
+    }
+  }, [accountId, domainId, copyToClipboard, t])
+
   // 参数缺失时不渲染
   if (!(accountId && domainId)) {
     return null
@@ -77,23 +94,37 @@ export function DnsRecordPage() {
           </Button>
         }
         actions={
-          selectedDomain?.metadata?.note ? (
-            <div className="hidden max-w-md items-center gap-2 rounded-md border bg-muted/50 px-3 py-1.5 md:flex">
-              <StickyNote className="h-4 w-4 shrink-0 text-muted-foreground" />
-              <span className="truncate text-muted-foreground text-sm">
-                {selectedDomain.metadata.note}
-              </span>
-            </div>
-          ) : undefined
+          <>
+            {selectedDomain?.metadata?.note && (
+              <div className="hidden max-w-md items-center gap-2 rounded-md border bg-muted/50 px-3 py-1.5 md:flex">
+                <StickyNote className="h-4 w-4 shrink-0 text-muted-foreground" />
+                <span className="truncate text-muted-foreground text-sm">
+                  {selectedDomain.metadata.note}
+                </span>
+              </div>
+            )}
+            {__PLATFORM__ !== "web" && (
+              <Button
+                variant="ghost"
+                size="icon"
+                onClick={handleCopyLink}
+                title={t("deepLink.copyLink")}
+              >
+                <Link2 className="h-4 w-4" />
+              </Button>
+            )}
+          </>
         }
       />
 
       {/* DNS 记录表格 */}
       <div className="flex min-h-0 flex-1 flex-col overflow-hidden">
         <DnsRecordTable
+          key={location.key}
           accountId={accountId}
           domainId={domainId}
           supportsProxy={providerFeatures?.proxy ?? false}
+          deepLink={deepLink}
         />
       </div>
     </PageLayout>
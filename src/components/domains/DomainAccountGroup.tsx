@@ -7,7 +7,7 @@ import { Collapsible, CollapsibleContent, CollapsibleTrigger } from "@/component
 import { Skeleton } from "@/components/ui/skeleton"
 import { cn } from "@/lib/utils"
 import { useDomainStore, useSettingsStore } from "@/stores"
-import type { Account } from "@/types"
+import { type Account, isAccountUnavailable } from "@/types"
 import { DomainItem } from "./DomainItem"
 import { useFilteredDomains } from "./hooks/useFilteredDomains"
 
@@ -46,7 +46,7 @@ export function DomainAccountGroup({ account, searchQuery, isBatchMode }: Domain
   const filteredDomains = useFilteredDomains({ accountId: account.id, searchQuery })
 
   const isExpanded = expandedAccounts.has(account.id)
-  const hasError = account.status === "error"
+  const hasError = isAccountUnavailable(account)
   const isLoading = isAccountLoading(account.id)
   const isLoadingMore = isAccountLoadingMore(account.id)
   const hasMore = hasMoreDomains(account.id)
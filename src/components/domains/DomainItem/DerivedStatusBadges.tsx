@@ -0,0 +1,64 @@
+import { useTranslation } from "react-i18next"
+import { Badge } from "@/components/ui/badge"
+import { EXPIRING_SOON_DAYS, isStale } from "@/lib/domain-status"
+import type { DomainDerivedStatus } from "@/types"
+
+interface DerivedStatusBadgesProps {
+  status?: DomainDerivedStatus
+}
+
+/**
+ * 到期与证书检查结果徽标
+ * 只在即将到期或结果已过时时显示，避免列表过于拥挤
+ */
+export function DerivedStatusBadges({ status }: DerivedStatusBadgesProps) {
+  const { t } = useTranslation()
+  if (!status) return null
+
+  const items = [
+    {
+      key: "expiry",
+      days: status.expiryDaysRemaining,
+      checkedAt: status.expiryCheckedAt,
+    },
+    {
+      key: "certificate",
+      days: status.certificateDaysRemaining,
+      checkedAt: status.certificateCheckedAt,
+    },
+  ]
+
+  return (
+    <>
+      {items.map(({ key, days, checkedAt }) => {
+        if (days === undefined || !checkedAt) return null
+        const checked = new Date(checkedAt).toLocaleString()
+        if (isStale(checkedAt)) {
+          return (
+            <Badge
+              key={key}
+              variant="outline"
+              className="shrink-0 text-muted-foreground"
+              title={t("domain.derivedStatus.checkedAt", { time: checked })}
+            >
+              {t(`domain.derivedStatus.${key}Stale`)}
+            </Badge>
+          )
+        }
+        if (days > EXPIRING_SOON_DAYS) return null
+        return (
+          <Badge
+            key={key}
+            variant={days <= 7 ? "destructive" : "secondary"}
+            className="shrink-0"
+            title={t("domain.derivedStatus.checkedAt", { time: checked })}
+          >
+            {days < 0
+              ? t(`domain.derivedStatus.${key}Expired`)
+              : t(`domain.derivedStatus.${key}Days`, { days })}
+          </Badge>
+        )
+      })}
+    </>
+  )
+}
@@ -10,6 +10,7 @@ import { Checkbox } from "@/components/ui/checkbox"
 import { type DomainColorKey, getDomainColor } from "@/constants/colors"
 import { cn } from "@/lib/utils"
 import { statusConfig } from "./constants"
+import { DerivedStatusBadges } from "./DerivedStatusBadges"
 import type { DomainItemBaseProps } from "./types"
 
 export const DomainItemDesktop = memo(function DomainItemDesktop({
@@ -75,8 +76,11 @@ export const DomainItemDesktop = memo(function DomainItemDesktop({
         />
       </div>
 
+      {/* Expiry / certificate check results */}
+      <DerivedStatusBadges status={domain.derivedStatus} />
+
       {/* Status badge */}
-      <Badge variant={config.variant} className="shrink-0">
+      <Badge variant={config.variant} title={domain.providerStatusRaw} className="shrink-0">
         {t(config.labelKey)}
       </Badge>
 
@@ -11,6 +11,7 @@ import { Checkbox } from "@/components/ui/checkbox"
 import { type DomainColorKey, getDomainColor } from "@/constants/colors"
 import { cn } from "@/lib/utils"
 import { statusConfig } from "./constants"
+import { DerivedStatusBadges } from "./DerivedStatusBadges"
 import type { DomainItemBaseProps } from "./types"
 
 export const DomainItemMobile = memo(function DomainItemMobile({
@@ -73,7 +74,9 @@ export const DomainItemMobile = memo(function DomainItemMobile({
             {domain.name}
           </button>
 
-          <Badge variant={config.variant} className="shrink-0">
+          <DerivedStatusBadges status={domain.derivedStatus} />
+
+          <Badge variant={config.variant} title={domain.providerStatusRaw} className="shrink-0">
             {t(config.labelKey)}
           </Badge>
 
@@ -5,8 +5,9 @@ export const statusConfig: Record<
   { labelKey: string; variant: "default" | "secondary" | "destructive" | "outline" }
 > = {
   active: { labelKey: "domain.status.active", variant: "default" },
-  paused: { labelKey: "domain.status.paused", variant: "secondary" },
   pending: { labelKey: "domain.status.pending", variant: "outline" },
-  error: { labelKey: "domain.status.error", variant: "destructive" },
+  suspended: { labelKey: "domain.status.suspended", variant: "destructive" },
+  expired: { labelKey: "domain.status.expired", variant: "destructive" },
+  moved: { labelKey: "domain.status.moved", variant: "secondary" },
   unknown: { labelKey: "domain.status.unknown", variant: "outline" },
 }
@@ -3,6 +3,7 @@ import { useCallback, useEffect, useMemo, useRef, useState } from "react"
 import { useTranslation } from "react-i18next"
 import { useShallow } from "zustand/react/shallow"
 import { DomainBatchActionBar } from "@/components/domain/DomainBatchActionBar"
+import { DomainStatusFilterButton } from "@/components/domain/DomainStatusFilter"
 import { SelectedTagsList, TagFilterButton } from "@/components/domain/TagFilter"
 import { Button } from "@/components/ui/button"
 import { EmptyState } from "@/components/ui/empty-state"
@@ -13,6 +14,7 @@ import { ScrollArea } from "@/components/ui/scroll-area"
 import { Skeleton } from "@/components/ui/skeleton"
 import { cn } from "@/lib/utils"
 import { useAccountStore, useDomainStore } from "@/stores"
+import { isAccountUnavailable } from "@/types"
 import { DomainAccountGroup } from "./DomainAccountGroup"
 
 export function DomainSelectorPage() {
@@ -59,7 +61,7 @@ export function DomainSelectorPage() {
   const getDomainsForAccount = useDomainStore((state) => state.getDomainsForAccount)
 
   // Valid accounts (exclude error status)
-  const validAccounts = useMemo(() => accounts.filter((a) => a.status !== "error"), [accounts])
+  const validAccounts = useMemo(() => accounts.filter((a) => !isAccountUnavailable(a)), [accounts])
 
   // Scroll position restoration
   // biome-ignore lint/correctness/useExhaustiveDependencies: 只在挂载时恢复一次滚动位置
@@ -141,6 +143,7 @@ export function DomainSelectorPage() {
               />
             </div>
             <TagFilterButton />
+            <DomainStatusFilterButton />
           </div>
           <SelectedTagsList />
         </div>
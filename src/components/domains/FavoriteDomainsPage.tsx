@@ -16,6 +16,7 @@ import { ScrollArea } from "@/components/ui/scroll-area"
 import { type DomainColorKey, getDomainColor } from "@/constants/colors"
 import { cn } from "@/lib/utils"
 import { type FavoriteDomain, useAccountStore, useDomainStore, useSettingsStore } from "@/stores"
+import { isAccountUnavailable } from "@/types"
 
 export function FavoriteDomainsPage() {
   const { t } = useTranslation()
@@ -142,7 +143,7 @@ export function FavoriteDomainsPage() {
 
   // 刷新所有账户
   const handleRefresh = useCallback(() => {
-    const validAccounts = accounts.filter((a) => a.status !== "error")
+    const validAccounts = accounts.filter((a) => !isAccountUnavailable(a))
     refreshAllAccounts(validAccounts)
   }, [refreshAllAccounts, accounts])
 
@@ -1,4 +1,5 @@
 import { useMemo } from "react"
+import { matchesStatusFilter, sortDomains } from "@/lib/domain-status"
 import { useDomainStore } from "@/stores"
 import type { Domain } from "@/types"
 
@@ -13,6 +14,8 @@ export function useFilteredDomains({
 }: UseFilteredDomainsOptions): Domain[] {
   const domains = useDomainStore((state) => state.domainsByAccount[accountId]?.domains ?? [])
   const selectedTags = useDomainStore((state) => state.selectedTags)
+  const statusFilter = useDomainStore((state) => state.statusFilter)
+  const domainSort = useDomainStore((state) => state.domainSort)
 
   return useMemo(() => {
     let filtered = domains
@@ -31,6 +34,11 @@ export function useFilteredDomains({
       })
     }
 
-    return filtered
-  }, [domains, searchQuery, selectedTags])
+    // Derived status filter (expiry / certificate / stale checks)
+    if (statusFilter !== "all") {
+      filtered = filtered.filter((domain) => matchesStatusFilter(domain, statusFilter))
+    }
+
+    return sortDomains(filtered, domainSort)
+  }, [domains, searchQuery, selectedTags, statusFilter, domainSort])
 }
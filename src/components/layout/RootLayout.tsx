@@ -4,7 +4,9 @@
  */
 
 import { useEffect } from "react"
+import { useTranslation } from "react-i18next"
 import { Outlet, useLocation, useNavigate } from "react-router-dom"
+import { toast } from "sonner"
 import { ErrorBoundary } from "@/components/error"
 import { Toaster } from "@/components/ui/sonner"
 import { StatusBar } from "@/components/ui/status-bar"
@@ -15,18 +17,20 @@ import { initDebugMode } from "@/lib/debug"
 import { isDesktop } from "@/lib/env"
 import { logger } from "@/lib/logger"
 import { cleanupInvalidRecentDomains } from "@/lib/recent-domains"
+import { accountService, deepLinkService, writeQueueService } from "@/services"
 import { initTheme, useAccountStore, useDomainStore } from "@/stores"
 import { useUpdaterStore } from "@/stores/updaterStore"
-import { getNavItemFromPath, NAV_PATHS, type NavItem } from "@/types"
+import { getNavItemFromPath, isAccountUnavailable, NAV_PATHS, type NavItem } from "@/types"
 import { AppLayout } from "./AppLayout"
 
 export function RootLayout() {
   const location = useLocation()
   const navigate = useNavigate()
   const isMobile = useIsMobile()
+  const { t } = useTranslation()
 
   const { checkForUpdates, showUpdateDialog, setShowUpdateDialog } = useUpdaterStore()
-  const { accounts, checkRestoreStatus, fetchProviders } = useAccountStore()
+  const { accounts, checkRestoreStatus, fetchAccounts, fetchProviders } = useAccountStore()
   const { loadFromStorage, refreshAllAccounts } = useDomainStore()
 
   // 初始化
@@ -64,13 +68,94 @@ export function RootLayout() {
 
     // 刷新有效账户的域名
     if (accounts.length > 0) {
-      const validAccounts = accounts.filter((a) => a.status !== "error")
+      const validAccounts = accounts.filter((a) => !isAccountUnavailable(a))
       if (validAccounts.length > 0) {
         refreshAllAccounts(validAccounts)
       }
     }
   }, [accounts, refreshAllAccounts])
 
+  // 凭证到期提醒（后台任务推送）
+  useEffect(() => {
+    const unlisten = accountService.onCredentialExpiry(({ reminders, expired }) => {
+      for (const item of reminders) {
+        toast.warning(
+          t("account.credentialsExpiringSoon", {
+            name: item.accountName,
+            count: item.daysRemaining,
+          }),
+          { duration: TIMING.TOAST_DURATION }
+        )
+      }
+      for (const item of expired) {
+        toast.error(t("account.credentialsExpiredNotice", { name: item.accountName }), {
+          duration: TIMING.TOAST_DURATION,
+        })
+      }
+      // 过期账户状态已变更，刷新列表
+      if (expired.length > 0) {
+        fetchAccounts()
+      }
+    })
+
+    return () => {
+      unlisten.then((fn) => fn())
+    }
+  }, [t, fetchAccounts])
+
+  // 深链接：跳转到记录页并交给记录表格预填，写操作仍需用户确认
+  useEffect(() => {
+    if (__PLATFORM__ === "web") return
+
+    const openPending = async () => {
+      try {
+        const response = await deepLinkService.takePending()
+        const target = response.success ? response.data : null
+        if (!target) return
+        const accountId = encodeURIComponent(target.accountId)
+        const domainId = encodeURIComponent(target.domainId)
+        navigate(`/domains/${accountId}/${domainId}`, { state: { deepLink: target } })
+      } catch (error) {
+        logger.error("Failed to open deep link:", error)
+      }
+    }
+
+    // 冷启动时深链接可能先于监听就绪
+    openPending()
+    const unlistenOpen = deepLinkService.onDeepLink(openPending)
+    const unlistenRejected = deepLinkService.onRejected((reason) => {
+      toast.error(t("deepLink.rejected", { reason }), { duration: TIMING.TOAST_DURATION })
+    })
+
+    return () => {
+      unlistenOpen.then((fn) => fn())
+      unlistenRejected.then((fn) => fn())
+    }
+  }, [t, navigate])
+
+  // 网络恢复后提示确认排队的写操作（后端不支持时忽略）
+  useEffect(() => {
+    const handleOnline = async () => {
+      try {
+        const response = await writeQueueService.list()
+        if (response.success && response.data && response.data.length > 0) {
+          toast.info(t("settings.writeQueue.pendingNotice", { count: response.data.length }), {
+            duration: TIMING.TOAST_DURATION,
+            action: {
+              label: t("settings.writeQueue.review"),
+              onClick: () => navigate(NAV_PATHS.settings),
+            },
+          })
+        }
+      } catch {
+        // ignore
+      }
+    }
+
+    window.addEventListener("online", handleOnline)
+    return () => window.removeEventListener("online", handleOnline)
+  }, [t, navigate])
+
   // 检查更新（仅桌面端，基于真实平台而非屏幕宽度）
   useEffect(() => {
     if (!isDesktop()) return
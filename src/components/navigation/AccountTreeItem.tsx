@@ -11,7 +11,7 @@ import {
 } from "@/components/ui/dropdown-menu"
 import { Skeleton } from "@/components/ui/skeleton"
 import { cn } from "@/lib/utils"
-import type { Account, Domain } from "@/types"
+import { type Account, type Domain, isAccountUnavailable } from "@/types"
 import { DomainTreeItem } from "./DomainTreeItem"
 
 interface AccountTreeItemProps {
@@ -42,7 +42,7 @@ export function AccountTreeItem({
   onLoadMore,
 }: AccountTreeItemProps) {
   const { t } = useTranslation()
-  const hasError = account.status === "error"
+  const hasError = isAccountUnavailable(account)
 
   return (
     <Collapsible open={isExpanded} onOpenChange={onToggle}>
@@ -17,9 +17,10 @@ const statusConfig: Record<
   { labelKey: string; variant: "default" | "secondary" | "destructive" | "outline" }
 > = {
   active: { labelKey: "domain.status.active", variant: "default" },
-  paused: { labelKey: "domain.status.paused", variant: "secondary" },
   pending: { labelKey: "domain.status.pending", variant: "outline" },
-  error: { labelKey: "domain.status.error", variant: "destructive" },
+  suspended: { labelKey: "domain.status.suspended", variant: "destructive" },
+  expired: { labelKey: "domain.status.expired", variant: "destructive" },
+  moved: { labelKey: "domain.status.moved", variant: "secondary" },
   unknown: { labelKey: "domain.status.unknown", variant: "outline" },
 }
 
@@ -52,7 +53,7 @@ export function DomainTreeItem({ domain, isSelected, onSelect }: DomainTreeItemP
       />
       <Globe className="h-3.5 w-3.5 shrink-0 text-muted-foreground" />
       <span className="flex-1 truncate text-left">{domain.name}</span>
-      <Badge variant={config.variant} className="px-1.5 py-0 text-[10px]">
+      <Badge variant={config.variant} title={domain.providerStatusRaw} className="px-1.5 py-0 text-[10px]">
         {t(config.labelKey)}
       </Badge>
     </button>
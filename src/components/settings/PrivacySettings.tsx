@@ -0,0 +1,128 @@
+import { useCallback, useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { toast } from "sonner"
+import { Badge } from "@/components/ui/badge"
+import { Label } from "@/components/ui/label"
+import { SettingItem, SettingRow, SettingSection } from "@/components/ui/setting-section"
+import { Switch } from "@/components/ui/switch"
+import { getErrorMessage } from "@/lib/error"
+import { toolboxService } from "@/services"
+import type { ExternalEndpoint, ExternalEndpointStatus, PrivacyConfig } from "@/types"
+
+/** 每个端点最多展示的地址数 */
+const MAX_VISIBLE_TARGETS = 3
+
+const STATUS_VARIANT: Record<ExternalEndpointStatus, "secondary" | "outline" | "destructive"> = {
+  allowed: "secondary",
+  requiresConfirmation: "outline",
+  disabled: "destructive",
+}
+
+/**
+ * 隐私模式设置与第三方端点清单
+ * 后端不支持时不显示
+ */
+export function PrivacySettings() {
+  const { t } = useTranslation()
+  const [config, setConfig] = useState<PrivacyConfig | null>(null)
+  const [endpoints, setEndpoints] = useState<ExternalEndpoint[]>([])
+  const [isSaving, setIsSaving] = useState(false)
+
+  const loadEndpoints = useCallback(() => {
+    toolboxService
+      .listExternalEndpoints()
+      .then((response) => {
+        if (response.success && response.data) {
+          setEndpoints(response.data)
+        }
+      })
+      .catch(() => {})
+  }, [])
+
+  useEffect(() => {
+    toolboxService
+      .getPrivacyConfig()
+      .then((response) => {
+        if (response.success && response.data) {
+          setConfig(response.data)
+        }
+      })
+      .catch(() => {})
+    loadEndpoints()
+  }, [loadEndpoints])
+
+  if (!config) {
+    return null
+  }
+
+  const handleToggle = async (privacyMode: boolean) => {
+    setIsSaving(true)
+    try {
+      const response = await toolboxService.setPrivacyConfig({ privacyMode })
+      if (response.success) {
+        setConfig({ privacyMode })
+        loadEndpoints()
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch {
+      toast.error(t("settings.privacy.saveFailed"))
+    } finally {
+      setIsSaving(false)
+    }
+  }
+
+  return (
+    <SettingSection
+      title={t("settings.privacy.title")}
+      description={t("settings.privacy.description")}
+    >
+      <SettingItem className="space-y-4">
+        <SettingRow
+          label={
+            <Label htmlFor="privacy-mode" className="font-medium text-sm">
+              {t("settings.privacy.privacyMode")}
+            </Label>
+          }
+          description={t("settings.privacy.privacyModeDesc")}
+          control={
+            <Switch
+              id="privacy-mode"
+              checked={config.privacyMode}
+              disabled={isSaving}
+              onCheckedChange={handleToggle}
+            />
+          }
+        />
+        <div className="space-y-2">
+          <p className="font-medium text-sm">{t("settings.privacy.endpoints")}</p>
+          <p className="text-muted-foreground text-xs">{t("settings.privacy.endpointsDesc")}</p>
+          <ul className="space-y-2">
+            {endpoints.map((endpoint) => (
+              <li key={endpoint.id} className="rounded-md border p-3 text-sm">
+                <div className="flex flex-wrap items-center justify-between gap-2">
+                  <span className="font-medium">{endpoint.purpose}</span>
+                  <Badge variant={STATUS_VARIANT[endpoint.status]}>
+                    {t(`settings.privacy.status.${endpoint.status}`)}
+                  </Badge>
+                </div>
+                <p className="mt-1 break-all font-mono text-muted-foreground text-xs">
+                  {endpoint.targets.slice(0, MAX_VISIBLE_TARGETS).join(", ")}
+                  {endpoint.targets.length > MAX_VISIBLE_TARGETS &&
+                    ` ${t("settings.privacy.moreTargets", {
+                      count: endpoint.targets.length - MAX_VISIBLE_TARGETS,
+                    })}`}
+                </p>
+                {endpoint.sendsQueryTarget && (
+                  <p className="mt-1 text-muted-foreground text-xs">
+                    {t("settings.privacy.sendsQueryTarget")}
+                  </p>
+                )}
+              </li>
+            ))}
+          </ul>
+        </div>
+      </SettingItem>
+    </SettingSection>
+  )
+}
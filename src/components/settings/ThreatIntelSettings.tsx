@@ -0,0 +1,104 @@
+import { useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { toast } from "sonner"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import { SettingItem, SettingRow, SettingSection } from "@/components/ui/setting-section"
+import { Switch } from "@/components/ui/switch"
+import { getErrorMessage } from "@/lib/error"
+import { toolboxService } from "@/services"
+import type { ThreatIntelConfig } from "@/types"
+
+/**
+ * 威胁情报源设置
+ * 后端不支持（如 Web 版）时不显示
+ */
+export function ThreatIntelSettings() {
+  const { t } = useTranslation()
+  const [config, setConfig] = useState<ThreatIntelConfig | null>(null)
+  const [isSaving, setIsSaving] = useState(false)
+
+  useEffect(() => {
+    toolboxService
+      .getThreatIntelConfig()
+      .then((response) => {
+        if (response.success && response.data) {
+          setConfig(response.data)
+        }
+      })
+      .catch(() => {})
+  }, [])
+
+  if (!config) {
+    return null
+  }
+
+  const handleSave = async () => {
+    setIsSaving(true)
+    try {
+      const response = await toolboxService.setThreatIntelConfig({
+        ...config,
+        abuseipdbApiKey: config.abuseipdbApiKey?.trim() || undefined,
+      })
+      if (response.success) {
+        toast.success(t("settings.threatIntel.saved"))
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch {
+      toast.error(t("settings.threatIntel.saveFailed"))
+    } finally {
+      setIsSaving(false)
+    }
+  }
+
+  return (
+    <SettingSection
+      title={t("settings.threatIntel.title")}
+      description={t("settings.threatIntel.description")}
+    >
+      <SettingItem className="space-y-4">
+        <SettingRow
+          label={
+            <Label htmlFor="spamhaus-drop" className="font-medium text-sm">
+              {t("settings.threatIntel.spamhausDrop")}
+            </Label>
+          }
+          description={t("settings.threatIntel.spamhausDropDesc")}
+          control={
+            <Switch
+              id="spamhaus-drop"
+              checked={config.spamhausDrop}
+              onCheckedChange={(spamhausDrop) => setConfig({ ...config, spamhausDrop })}
+            />
+          }
+        />
+        <div className="space-y-2">
+          <Label htmlFor="abuseipdb-key" className="font-medium text-sm">
+            {t("settings.threatIntel.abuseipdbKey")}
+          </Label>
+          <Input
+            id="abuseipdb-key"
+            type="password"
+            autoComplete="off"
+            value={config.abuseipdbApiKey ?? ""}
+            onChange={(e) => setConfig({ ...config, abuseipdbApiKey: e.target.value })}
+            placeholder={t("settings.threatIntel.abuseipdbKeyPlaceholder")}
+          />
+          <p className="text-muted-foreground text-xs">
+            {t("settings.threatIntel.abuseipdbKeyDesc", {
+              confidence: config.abuseipdbMinConfidence,
+              limit: config.abuseipdbDailyLimit,
+            })}
+          </p>
+        </div>
+        <div className="flex justify-end">
+          <Button size="sm" onClick={handleSave} disabled={isSaving}>
+            {t("common.save")}
+          </Button>
+        </div>
+      </SettingItem>
+    </SettingSection>
+  )
+}
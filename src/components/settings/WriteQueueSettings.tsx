@@ -0,0 +1,256 @@
+import { useCallback, useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { toast } from "sonner"
+import { Button } from "@/components/ui/button"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import { SettingItem, SettingRow, SettingSection } from "@/components/ui/setting-section"
+import { Switch } from "@/components/ui/switch"
+import { getErrorMessage } from "@/lib/error"
+import { writeQueueService } from "@/services"
+import type { QueuedWrite, WriteQueueConfig, WriteReplayOutcome } from "@/types"
+
+/** 写操作摘要，如 "create A www" */
+function describeOperation(entry: QueuedWrite): string {
+  const { operation } = entry
+  if (operation.type !== "delete") {
+    return `${operation.type} ${operation.request.data.type} ${operation.request.name}`
+  }
+  return entry.before
+    ? `${operation.type} ${entry.before.data.type} ${entry.before.name}`
+    : `${operation.type} ${operation.recordId}`
+}
+
+/**
+ * 离线写队列设置与待重放写操作审阅
+ * 后端不支持（如 Web 版）时不显示
+ */
+export function WriteQueueSettings() {
+  const { t } = useTranslation()
+  const [config, setConfig] = useState<WriteQueueConfig | null>(null)
+  const [entries, setEntries] = useState<QueuedWrite[]>([])
+  const [outcomes, setOutcomes] = useState<Record<string, WriteReplayOutcome>>({})
+  const [isBusy, setIsBusy] = useState(false)
+
+  const fetchEntries = useCallback(() => {
+    writeQueueService
+      .list()
+      .then((response) => {
+        if (response.success && response.data) {
+          setEntries(response.data)
+        }
+      })
+      .catch(() => {})
+  }, [])
+
+  useEffect(() => {
+    writeQueueService
+      .getConfig()
+      .then((response) => {
+        if (response.success && response.data) {
+          setConfig(response.data)
+          fetchEntries()
+        }
+      })
+      .catch(() => {})
+  }, [fetchEntries])
+
+  // 网络恢复后刷新待重放列表
+  useEffect(() => {
+    window.addEventListener("online", fetchEntries)
+    return () => window.removeEventListener("online", fetchEntries)
+  }, [fetchEntries])
+
+  if (!config) {
+    return null
+  }
+
+  // 最近一次重放的冲突/失败原因，未重放时显示入队时的网络错误
+  const entryMessage = (entry: QueuedWrite, outcome: WriteReplayOutcome | undefined) => {
+    if (outcome?.status === "conflict") {
+      return t("settings.writeQueue.conflict", { reason: outcome.message ?? "" })
+    }
+    if (outcome?.status === "failed") {
+      return t("settings.writeQueue.failed", { reason: outcome.message ?? "" })
+    }
+    return entry.error
+  }
+
+  const handleSave = async () => {
+    setIsBusy(true)
+    try {
+      const response = await writeQueueService.setConfig(config)
+      if (response.success) {
+        toast.success(t("settings.writeQueue.saved"))
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch {
+      toast.error(t("settings.writeQueue.saveFailed"))
+    } finally {
+      setIsBusy(false)
+    }
+  }
+
+  const handleReplay = async (ids: string[], force: boolean) => {
+    setIsBusy(true)
+    try {
+      const response = await writeQueueService.replay(ids, force)
+      if (response.success && response.data) {
+        const results = response.data
+        setOutcomes((prev) => ({
+          ...prev,
+          ...Object.fromEntries(results.map((outcome) => [outcome.id, outcome])),
+        }))
+        const applied = results.filter(
+          (outcome) => outcome.status === "applied" || outcome.status === "alreadyApplied"
+        ).length
+        const conflicts = results.filter((outcome) => outcome.status === "conflict").length
+        toast.info(t("settings.writeQueue.replayResult", { applied, conflicts }))
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch {
+      toast.error(t("settings.writeQueue.replayFailed"))
+    } finally {
+      setIsBusy(false)
+      fetchEntries()
+    }
+  }
+
+  const handleDiscard = async (ids: string[]) => {
+    setIsBusy(true)
+    try {
+      const response = await writeQueueService.discard(ids)
+      if (!response.success) {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch {
+      toast.error(t("settings.writeQueue.discardFailed"))
+    } finally {
+      setIsBusy(false)
+      fetchEntries()
+    }
+  }
+
+  return (
+    <SettingSection
+      title={t("settings.writeQueue.title")}
+      description={t("settings.writeQueue.description")}
+    >
+      <SettingItem className="space-y-4">
+        <SettingRow
+          label={
+            <Label htmlFor="write-queue-enabled" className="font-medium text-sm">
+              {t("settings.writeQueue.enabled")}
+            </Label>
+          }
+          description={t("settings.writeQueue.enabledDesc")}
+          control={
+            <Switch
+              id="write-queue-enabled"
+              checked={config.enabled}
+              onCheckedChange={(enabled) => setConfig({ ...config, enabled })}
+            />
+          }
+        />
+        <div className="grid grid-cols-2 gap-4">
+          <div className="space-y-2">
+            <Label htmlFor="write-queue-max" className="font-medium text-sm">
+              {t("settings.writeQueue.maxEntries")}
+            </Label>
+            <Input
+              id="write-queue-max"
+              type="number"
+              min={1}
+              max={1000}
+              value={config.maxEntries}
+              onChange={(e) => setConfig({ ...config, maxEntries: Number(e.target.value) })}
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="write-queue-ttl" className="font-medium text-sm">
+              {t("settings.writeQueue.ttlHours")}
+            </Label>
+            <Input
+              id="write-queue-ttl"
+              type="number"
+              min={1}
+              max={168}
+              value={config.ttlHours}
+              onChange={(e) => setConfig({ ...config, ttlHours: Number(e.target.value) })}
+            />
+          </div>
+        </div>
+        <div className="flex justify-end">
+          <Button size="sm" onClick={handleSave} disabled={isBusy}>
+            {t("common.save")}
+          </Button>
+        </div>
+      </SettingItem>
+
+      {entries.length > 0 && (
+        <SettingItem className="space-y-3">
+          <div className="flex items-center justify-between gap-2">
+            <p className="font-medium text-sm">
+              {t("settings.writeQueue.pending", { count: entries.length })}
+            </p>
+            <div className="flex gap-2">
+              <Button
+                size="sm"
+                variant="outline"
+                disabled={isBusy}
+                onClick={() => handleDiscard(entries.map((entry) => entry.id))}
+              >
+                {t("settings.writeQueue.discardAll")}
+              </Button>
+              <Button
+                size="sm"
+                disabled={isBusy}
+                onClick={() => handleReplay(entries.map((entry) => entry.id), false)}
+              >
+                {t("settings.writeQueue.replayAll")}
+              </Button>
+            </div>
+          </div>
+          <ul className="space-y-2">
+            {entries.map((entry) => {
+              const outcome = outcomes[entry.id]
+              return (
+                <li key={entry.id} className="space-y-1 rounded-lg border p-3 text-sm">
+                  <div className="flex items-center justify-between gap-2">
+                    <span className="font-mono text-xs">{describeOperation(entry)}</span>
+                    <span className="text-muted-foreground text-xs">
+                      {new Date(entry.queuedAt).toLocaleString()}
+                    </span>
+                  </div>
+                  <p className="text-muted-foreground text-xs">{entryMessage(entry, outcome)}</p>
+                  <div className="flex justify-end gap-2">
+                    <Button
+                      size="sm"
+                      variant="ghost"
+                      disabled={isBusy}
+                      onClick={() => handleDiscard([entry.id])}
+                    >
+                      {t("settings.writeQueue.discard")}
+                    </Button>
+                    {outcome?.status === "conflict" && (
+                      <Button
+                        size="sm"
+                        variant="destructive"
+                        disabled={isBusy}
+                        onClick={() => handleReplay([entry.id], true)}
+                      >
+                        {t("settings.writeQueue.forceReplay")}
+                      </Button>
+                    )}
+                  </div>
+                </li>
+              )
+            })}
+          </ul>
+        </SettingItem>
+      )}
+    </SettingSection>
+  )
+}
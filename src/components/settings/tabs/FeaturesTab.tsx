@@ -5,10 +5,13 @@ import { SettingItem, SettingRow, SettingSection } from "@/components/ui/setting
 import { Switch } from "@/components/ui/switch"
 import { cn } from "@/lib/utils"
 import { useSettingsStore } from "@/stores/settingsStore"
+import { PrivacySettings } from "../PrivacySettings"
+import { ThreatIntelSettings } from "../ThreatIntelSettings"
+import { WriteQueueSettings } from "../WriteQueueSettings"
 
 /**
  * 功能设置 Tab
- * 包含通知、DNS 提示、分页模式设置
+ * 包含通知、DNS 提示、分页模式、威胁情报、隐私模式、离线写队列设置
  */
 export function FeaturesTab() {
   const { t } = useTranslation()
@@ -114,6 +117,15 @@ export function FeaturesTab() {
           ))}
         </div>
       </SettingSection>
+
+      {/* 威胁情报设置 */}
+      <ThreatIntelSettings />
+
+      {/* 隐私模式设置 */}
+      <PrivacySettings />
+
+      {/* 离线写队列设置 */}
+      <WriteQueueSettings />
     </div>
   )
 }
@@ -21,18 +21,27 @@ import {
 } from "@/components/ui/table"
 import { DNS_SERVERS } from "@/constants"
 import { useEnterKeyHandler } from "@/hooks/useEnterKeyHandler"
-import type { DnsLookupResult, DnsLookupType } from "@/types"
-import { DNS_RECORD_TYPES } from "@/types"
+import type { DnsLookupResult, DnsLookupTransport, DnsLookupType } from "@/types"
+import { DNS_LOOKUP_TRANSPORTS, DNS_RECORD_TYPES } from "@/types"
 import { HistoryChips } from "./HistoryChips"
 import { toolboxService, useToolboxQuery } from "./hooks/useToolboxQuery"
 import { CopyableText, ToolCard } from "./shared"
 
+const TRANSPORT_LABELS: Record<DnsLookupTransport, string> = {
+  udp: "UDP",
+  tcp: "TCP",
+  doh: "DoH",
+  dot: "DoT",
+}
+
 export function DnsLookup() {
   const { t } = useTranslation()
   const [domain, setDomain] = useState("")
   const [recordType, setRecordType] = useState<DnsLookupType>("ALL")
   const [dnsServer, setDnsServer] = useState("system")
   const [customDns, setCustomDns] = useState("")
+  const [dnsTransport, setDnsTransport] = useState<DnsLookupTransport>("udp")
+  const encrypted = dnsTransport === "doh" || dnsTransport === "dot"
 
   const { isLoading, result, execute } = useToolboxQuery<DnsLookupResult>()
 
@@ -43,9 +52,9 @@ export function DnsLookup() {
       return
     }
 
-    // 计算实际使用的 nameserver
+    // 计算实际使用的 nameserver（DoH / DoT 必须填写自定义服务器）
     let nameserver: string | null = null
-    if (dnsServer === "custom") {
+    if (encrypted || dnsServer === "custom") {
       if (!customDns.trim()) {
         toast.error(t("toolbox.enterCustomDns"))
         return
@@ -55,11 +64,14 @@ export function DnsLookup() {
       nameserver = dnsServer
     }
 
-    const data = await execute(() => toolboxService.dnsLookup(trimmed, recordType, nameserver), {
-      type: "dns",
-      query: trimmed,
-      recordType,
-    })
+    const data = await execute(
+      () => toolboxService.dnsLookup(trimmed, recordType, nameserver, dnsTransport),
+      {
+        type: "dns",
+        query: trimmed,
+        recordType,
+      }
+    )
     if (data && data.records.length === 0) {
       toast.info(t("toolbox.noRecords"))
     }
@@ -116,21 +128,45 @@ export function DnsLookup() {
           <span>{t("toolbox.dnsServer")}:</span>
         </div>
         <div className="flex flex-1 flex-col gap-2 sm:flex-row">
-          <Select value={dnsServer} onValueChange={setDnsServer} disabled={isLoading}>
-            <SelectTrigger className="w-full sm:w-48">
-              <SelectValue placeholder={t("toolbox.systemDefault")} />
+          <Select
+            value={dnsTransport}
+            onValueChange={(v) => setDnsTransport(v as DnsLookupTransport)}
+            disabled={isLoading}
+          >
+            <SelectTrigger className="w-full sm:w-24" aria-label={t("toolbox.dnsTransport")}>
+              <SelectValue />
             </SelectTrigger>
             <SelectContent>
-              {DNS_SERVERS.map((server) => (
-                <SelectItem key={server.value} value={server.value}>
-                  {server.isRaw ? server.label : t(server.label)}
+              {DNS_LOOKUP_TRANSPORTS.map((value) => (
+                <SelectItem key={value} value={value}>
+                  {TRANSPORT_LABELS[value]}
                 </SelectItem>
               ))}
             </SelectContent>
           </Select>
-          {dnsServer === "custom" && (
+          {!encrypted && (
+            <Select value={dnsServer} onValueChange={setDnsServer} disabled={isLoading}>
+              <SelectTrigger className="w-full sm:w-48">
+                <SelectValue placeholder={t("toolbox.systemDefault")} />
+              </SelectTrigger>
+              <SelectContent>
+                {DNS_SERVERS.map((server) => (
+                  <SelectItem key={server.value} value={server.value}>
+                    {server.isRaw ? server.label : t(server.label)}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          )}
+          {(encrypted || dnsServer === "custom") && (
             <Input
-              placeholder={t("toolbox.customDnsPlaceholder")}
+              placeholder={
+                dnsTransport === "doh"
+                  ? t("toolbox.dohServerPlaceholder")
+                  : dnsTransport === "dot"
+                    ? t("toolbox.dotServerPlaceholder")
+                    : t("toolbox.customDnsPlaceholder")
+              }
               value={customDns}
               onChange={(e) => setCustomDns(e.target.value)}
               onKeyDown={handleKeyDown}
@@ -158,7 +194,14 @@ export function DnsLookup() {
           <Server className="h-4 w-4" />
           <span>
             {t("toolbox.usedDnsServer")}: <span className="font-mono">{result.nameserver}</span>
+            {` (${TRANSPORT_LABELS[result.transport]})`}
           </span>
+          {/* 国际化域名按 punycode 查询 */}
+          {result.unicodeDomain && (
+            <span className="font-mono text-xs">
+              {result.unicodeDomain} → {result.domain}
+            </span>
+          )}
         </div>
       )}
 
@@ -44,11 +44,15 @@ export function DnsPropagation() {
       return
     }
 
-    const data = await execute(() => toolboxService.dnsPropagationCheck(trimmed, recordType), {
-      type: "dns-propagation",
-      query: trimmed,
-      recordType,
-    })
+    const data = await execute(
+      (confirmExternal) =>
+        toolboxService.dnsPropagationCheck(trimmed, recordType, confirmExternal),
+      {
+        type: "dns-propagation",
+        query: trimmed,
+        recordType,
+      }
+    )
 
     if (data && data.results.length === 0) {
       toast.info(t("toolbox.dnsPropagation.noResults"))
@@ -1,10 +1,22 @@
-import { Copy, FileCode, Info, Loader2, Plus, Send, Shield, Trash2 } from "lucide-react"
+import {
+  AlertTriangle,
+  ArrowRight,
+  Copy,
+  FileCode,
+  Info,
+  Loader2,
+  Plus,
+  Send,
+  Shield,
+  Trash2,
+} from "lucide-react"
 import { useCallback, useState } from "react"
 import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
 import { Badge } from "@/components/ui/badge"
 import { Button } from "@/components/ui/button"
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card"
+import { Checkbox } from "@/components/ui/checkbox"
 import { Input } from "@/components/ui/input"
 import { Label } from "@/components/ui/label"
 import {
@@ -34,22 +46,28 @@ interface CustomHeaderWithId extends HttpHeader {
   id: string
 }
 
-/** 获取安全头状态徽章样式 */
-function getStatusBadgeVariant(
-  status: SecurityHeaderAnalysis["status"]
+/** 获取安全头严重程度徽章样式 */
+function getSeverityBadgeVariant(
+  severity: SecurityHeaderAnalysis["severity"]
 ): "default" | "secondary" | "destructive" {
-  switch (status) {
-    case "good":
+  switch (severity) {
+    case "ok":
       return "default"
     case "warning":
       return "secondary"
-    case "missing":
+    case "error":
       return "destructive"
     default:
       return "default"
   }
 }
 
+function getGradeBadgeVariant(grade: string): "default" | "secondary" | "destructive" {
+  if (grade === "A" || grade === "B") return "default"
+  if (grade === "C" || grade === "D") return "secondary"
+  return "destructive"
+}
+
 export function HttpHeaderCheck() {
   const { t } = useTranslation()
 
@@ -59,6 +77,7 @@ export function HttpHeaderCheck() {
   const [customHeaders, setCustomHeaders] = useState<CustomHeaderWithId[]>([])
   const [body, setBody] = useState("")
   const [contentType, setContentType] = useState("application/json")
+  const [followRedirects, setFollowRedirects] = useState(true)
 
   // Query Hook
   const { isLoading, result, execute } = useToolboxQuery<HttpHeaderCheckResult>()
@@ -100,10 +119,11 @@ export function HttpHeaderCheck() {
       customHeaders: customHeaders.filter((h) => h.name && h.value),
       body: ["POST", "PUT", "PATCH"].includes(method) && body ? body : undefined,
       contentType: ["POST", "PUT", "PATCH"].includes(method) && body ? contentType : undefined,
+      followRedirects,
     }
 
     await execute(() => toolboxService.httpHeaderCheck(request), { type: "http", query: url })
-  }, [url, method, customHeaders, body, contentType, execute, t])
+  }, [url, method, customHeaders, body, contentType, followRedirects, execute, t])
 
   return (
     <div className="space-y-6">
@@ -144,6 +164,18 @@ export function HttpHeaderCheck() {
             </div>
           </div>
 
+          <div className="flex items-center gap-2">
+            <Checkbox
+              id="follow-redirects"
+              checked={followRedirects}
+              onCheckedChange={(checked) => setFollowRedirects(checked === true)}
+              disabled={isLoading}
+            />
+            <Label htmlFor="follow-redirects" className="font-normal text-muted-foreground text-sm">
+              {t("toolbox.httpHeaderCheck.followRedirects")}
+            </Label>
+          </div>
+
           {/* 历史记录 */}
           <HistoryChips type="http" onSelect={(item) => setUrl(item.query)} />
 
@@ -270,6 +302,55 @@ export function HttpHeaderCheck() {
             </CardContent>
           </Card>
 
+          {/* 重定向链 */}
+          {result.redirectChain.length > 1 && (
+            <Card>
+              <CardHeader>
+                <CardTitle className="text-lg">
+                  {t("toolbox.httpHeaderCheck.redirectChain")} ({result.redirectChain.length})
+                </CardTitle>
+              </CardHeader>
+              <CardContent className="space-y-2">
+                {(result.loopDetected || result.redirectLimitReached) && (
+                  <div className="flex items-center gap-2 text-amber-600 text-sm dark:text-amber-400">
+                    <AlertTriangle className="size-4 shrink-0" />
+                    <span>
+                      {t(
+                        result.loopDetected
+                          ? "toolbox.httpHeaderCheck.loopDetected"
+                          : "toolbox.httpHeaderCheck.redirectLimitReached"
+                      )}
+                    </span>
+                  </div>
+                )}
+                {result.redirectChain.map((hop, index) => (
+                  <div
+                    key={`hop-${hop.url}-${index}`}
+                    className="flex items-center gap-2 rounded-md border p-2 text-sm"
+                  >
+                    <Badge variant={hop.statusCode < 400 ? "secondary" : "destructive"}>
+                      {hop.statusCode}
+                    </Badge>
+                    <code className="min-w-0 flex-1 truncate font-mono">{hop.url}</code>
+                    {hop.locationHeader && (
+                      <span className="flex min-w-0 items-center gap-1 text-muted-foreground">
+                        <ArrowRight className="size-4 shrink-0" />
+                        <code className="truncate font-mono">{hop.locationHeader}</code>
+                      </span>
+                    )}
+                    <span className="shrink-0 text-muted-foreground">
+                      {hop.responseTimeMs} ms
+                    </span>
+                  </div>
+                ))}
+                <div className="text-muted-foreground text-sm">
+                  {t("toolbox.httpHeaderCheck.finalUrl")}:{" "}
+                  <code className="break-all font-mono">{result.finalUrl}</code>
+                </div>
+              </CardContent>
+            </Card>
+          )}
+
           {/* 安全头分析 */}
           <Card>
             <CardHeader>
@@ -278,6 +359,18 @@ export function HttpHeaderCheck() {
                 <CardTitle className="text-lg">
                   {t("toolbox.httpHeaderCheck.securityAnalysis")}
                 </CardTitle>
+                <Badge
+                  variant={getGradeBadgeVariant(result.securityScore.overallGrade)}
+                  className="ml-auto"
+                >
+                  {result.securityScore.overallGrade}
+                </Badge>
+                <span className="text-muted-foreground text-sm">
+                  {t("toolbox.httpHeaderCheck.securityScore", {
+                    score: result.securityScore.score,
+                    max: result.securityScore.maxPossible,
+                  })}
+                </span>
               </div>
             </CardHeader>
             <CardContent>
@@ -290,16 +383,22 @@ export function HttpHeaderCheck() {
                     <div className="flex-1 space-y-1">
                       <div className="flex items-center gap-2">
                         <code className="font-mono text-sm">{analysis.name}</code>
-                        <Badge variant={getStatusBadgeVariant(analysis.status)}>
-                          {t(`toolbox.httpHeaderCheck.status.${analysis.status}`)}
+                        <Badge variant={getSeverityBadgeVariant(analysis.severity)}>
+                          {t(`toolbox.httpHeaderCheck.severity.${analysis.severity}`)}
                         </Badge>
+                        {!analysis.present && (
+                          <Badge variant="outline">{t("toolbox.httpHeaderCheck.missing")}</Badge>
+                        )}
                       </div>
-                      {analysis.recommendation && (
-                        <div className="mt-2 flex items-start gap-2 text-amber-600 text-sm dark:text-amber-400">
+                      {analysis.findings.map((finding) => (
+                        <div
+                          key={finding}
+                          className="mt-2 flex items-start gap-2 text-amber-600 text-sm dark:text-amber-400"
+                        >
                           <Info className="mt-0.5 size-4 shrink-0" />
-                          <span>{analysis.recommendation}</span>
+                          <span>{finding}</span>
                         </div>
-                      )}
+                      ))}
                     </div>
                   </div>
                 ))}
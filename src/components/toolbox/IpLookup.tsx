@@ -1,4 +1,4 @@
-import { Globe, MapPin, Server } from "lucide-react"
+import { Globe, MapPin, Server, ShieldAlert, ShieldCheck } from "lucide-react"
 import { useCallback, useState } from "react"
 import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
@@ -28,7 +28,7 @@ export function IpLookup() {
       toast.error(t("toolbox.enterIpOrDomain"))
       return
     }
-    execute(() => toolboxService.ipLookup(trimmed), {
+    execute((confirmExternal) => toolboxService.ipLookup(trimmed, confirmExternal), {
       type: "ip",
       query: trimmed,
     })
@@ -133,6 +133,63 @@ export function IpLookup() {
             )}
           </div>
         </InfoCard>
+
+        {/* 威胁情报卡片（配置了情报源时显示） */}
+        {ipInfo.threat && (
+          <InfoCard
+            icon={
+              ipInfo.threat.listed ? (
+                <ShieldAlert className="h-5 w-5 text-destructive" />
+              ) : (
+                <ShieldCheck className="h-5 w-5" />
+              )
+            }
+            title={t("toolbox.ip.threat")}
+          >
+            <div className="space-y-3">
+              <Badge variant={ipInfo.threat.listed ? "destructive" : "secondary"}>
+                {ipInfo.threat.listed ? t("toolbox.ip.threatListed") : t("toolbox.ip.threatClean")}
+              </Badge>
+              {ipInfo.threat.sources.length > 0 && (
+                <div className="flex flex-wrap items-center gap-2">
+                  <span className="text-muted-foreground text-sm">
+                    {t("toolbox.ip.threatSources")}:
+                  </span>
+                  {ipInfo.threat.sources.map((source) => (
+                    <Badge key={source} variant="outline">
+                      {source}
+                    </Badge>
+                  ))}
+                </div>
+              )}
+              {ipInfo.threat.categories.length > 0 && (
+                <div className="flex flex-wrap items-center gap-2">
+                  <span className="text-muted-foreground text-sm">
+                    {t("toolbox.ip.threatCategories")}:
+                  </span>
+                  {ipInfo.threat.categories.map((category) => (
+                    <Badge key={category} variant="outline">
+                      {category}
+                    </Badge>
+                  ))}
+                </div>
+              )}
+              {ipInfo.threat.lastReported && (
+                <div className="flex items-center gap-2">
+                  <span className="text-muted-foreground text-sm">
+                    {t("toolbox.ip.threatLastReported")}:
+                  </span>
+                  <span className="text-sm">
+                    {new Date(ipInfo.threat.lastReported).toLocaleString()}
+                  </span>
+                </div>
+              )}
+              <div className="text-muted-foreground text-xs">
+                {t("toolbox.ip.threatChecked")}: {ipInfo.threat.checkedSources.join(", ")}
+              </div>
+            </div>
+          </InfoCard>
+        )}
       </div>
     ),
     [result, t]
@@ -156,7 +213,8 @@ export function IpLookup() {
           {result.isDomain && (
             <div className="flex items-center gap-2 text-muted-foreground text-sm">
               <span>{t("toolbox.ip.resolvedFrom")}:</span>
-              <span className="font-mono">{result.query}</span>
+              <span className="font-mono">{result.unicodeDomain ?? result.query}</span>
+              {result.unicodeDomain && <span className="font-mono text-xs">{result.query}</span>}
               <Badge variant="outline">{result.results.length} IP(s)</Badge>
             </div>
           )}
@@ -17,15 +17,37 @@ import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
 import { Badge } from "@/components/ui/badge"
 import { Button } from "@/components/ui/button"
+import { Checkbox } from "@/components/ui/checkbox"
 import { Collapsible, CollapsibleContent, CollapsibleTrigger } from "@/components/ui/collapsible"
 import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
 import { NETWORK } from "@/constants"
 import { useEnterKeyHandler } from "@/hooks/useEnterKeyHandler"
-import type { SslCheckResult } from "@/types"
+import type { OcspStatus, SslCheckResult, TlsVersionSupport } from "@/types"
 import { HistoryChips } from "./HistoryChips"
 import { toolboxService, useToolboxQuery } from "./hooks/useToolboxQuery"
 import { CopyableText, InfoCard, ToolCard } from "./shared"
 
+/** OCSP 状态对应的徽章样式 */
+const OCSP_BADGE_VARIANTS: Record<OcspStatus["status"], "secondary" | "destructive" | "outline"> = {
+  good: "secondary",
+  revoked: "destructive",
+  unknown: "outline",
+  notChecked: "outline",
+}
+
+/**
+ * 协议版本探测结果对应的徽章样式
+ * 已弃用的 TLS 1.0 / 1.1 被接受视为风险
+ */
+function versionBadgeVariant(
+  support: TlsVersionSupport,
+  deprecated: boolean
+): "secondary" | "destructive" | "outline" {
+  if (support === "accepted") return deprecated ? "destructive" : "secondary"
+  return "outline"
+}
+
 /** 获取状态信息 */
 function getStatusInfo(result: SslCheckResult | null, t: (key: string) => string) {
   if (!result) return null
@@ -96,6 +118,8 @@ export function SslCheck() {
   const [domain, setDomain] = useState("")
   const [port, setPort] = useState("")
   const [chainOpen, setChainOpen] = useState(false)
+  const [probeVersions, setProbeVersions] = useState(false)
+  const [connectAddr, setConnectAddr] = useState("")
 
   const { isLoading, result, execute } = useToolboxQuery<SslCheckResult>()
 
@@ -115,7 +139,8 @@ export function SslCheck() {
       return
     }
 
-    execute(() => toolboxService.sslCheck(trimmed, portNum), {
+    const addr = connectAddr.trim() || undefined
+    execute(() => toolboxService.sslCheck(trimmed, portNum, probeVersions, addr), {
       type: "ssl",
       query: portNum ? `${trimmed}:${portNum}` : trimmed,
     })
@@ -137,6 +162,10 @@ export function SslCheck() {
   // 使用 useMemo 缓存状态信息计算
   const statusInfo = useMemo(() => getStatusInfo(result, t), [result, t])
   const cert = result?.certInfo
+  // 证书链中最先到期的证书剩余天数
+  const firstExpiringDays = cert?.certificateChain.length
+    ? Math.min(...cert.certificateChain.map((item) => item.daysRemaining))
+    : undefined
 
   return (
     <ToolCard title={t("toolbox.sslCheck")}>
@@ -173,6 +202,28 @@ export function SslCheck() {
         </Button>
       </div>
 
+      <div className="flex flex-col gap-2 sm:flex-row sm:items-center sm:gap-4">
+        <Input
+          placeholder={t("toolbox.ssl.connectAddrPlaceholder")}
+          value={connectAddr}
+          onChange={(e) => setConnectAddr(e.target.value)}
+          onKeyDown={handleKeyDown}
+          disabled={isLoading}
+          className="h-8 sm:w-64"
+        />
+        <div className="flex items-center gap-2">
+          <Checkbox
+            id="ssl-probe-versions"
+            checked={probeVersions}
+            onCheckedChange={(checked) => setProbeVersions(checked === true)}
+            disabled={isLoading}
+          />
+          <Label htmlFor="ssl-probe-versions" className="font-normal text-muted-foreground text-sm">
+            {t("toolbox.ssl.probeVersions")}
+          </Label>
+        </div>
+      </div>
+
       <HistoryChips type="ssl" onSelect={handleHistorySelect} />
 
       {result && statusInfo && (
@@ -187,7 +238,10 @@ export function SslCheck() {
                 </div>
                 <div className="flex items-center gap-2 text-muted-foreground text-sm">
                   <Globe className="h-4 w-4" />
-                  <span>{result.domain}</span>
+                  <span>{result.unicodeDomain ?? result.domain}</span>
+                  {result.unicodeDomain && (
+                    <span className="font-mono text-xs">{result.domain}</span>
+                  )}
                   <Badge variant="outline">:{result.port}</Badge>
                   <Badge
                     variant={
@@ -200,6 +254,11 @@ export function SslCheck() {
                   >
                     {result.connectionStatus.toUpperCase()}
                   </Badge>
+                  {result.connectedAddr && (
+                    <Badge variant="outline" className="font-mono">
+                      {t("toolbox.ssl.connectedTo", { addr: result.connectedAddr })}
+                    </Badge>
+                  )}
                 </div>
                 {cert && (
                   <div className="mt-1 text-muted-foreground text-sm">
@@ -257,6 +316,57 @@ export function SslCheck() {
                 </div>
               </InfoCard>
 
+              {/* 协议 */}
+              <InfoCard icon={<Shield className="h-5 w-5" />} title={t("toolbox.ssl.protocol")}>
+                <div className="grid grid-cols-1 gap-3 text-sm">
+                  <div className="flex flex-col gap-1 sm:flex-row sm:items-center sm:gap-2">
+                    <span className="text-muted-foreground">{t("toolbox.ssl.tlsVersion")}:</span>
+                    <span className="font-mono">{cert.tlsVersion ?? "-"}</span>
+                  </div>
+                  <div className="flex flex-col gap-1 sm:flex-row sm:items-center sm:gap-2">
+                    <span className="text-muted-foreground">{t("toolbox.ssl.cipher")}:</span>
+                    <span className="break-all font-mono">{cert.negotiatedCipher ?? "-"}</span>
+                  </div>
+                  <div className="flex flex-col gap-1 sm:flex-row sm:items-center sm:gap-2">
+                    <span className="text-muted-foreground">{t("toolbox.ssl.legacyTls")}:</span>
+                    <div className="flex flex-wrap gap-1">
+                      {(
+                        [
+                          ["TLS 1.0", cert.supportsTls10],
+                          ["TLS 1.1", cert.supportsTls11],
+                        ] as const
+                      ).map(([version, accepted]) => (
+                        <Badge key={version} variant={accepted ? "destructive" : "secondary"}>
+                          {version}{" "}
+                          {accepted ? t("toolbox.ssl.legacyAccepted") : t("toolbox.ssl.legacyRejected")}
+                        </Badge>
+                      ))}
+                    </div>
+                  </div>
+                  {cert.protocolProbe && (
+                    <div className="flex flex-col gap-1 sm:flex-row sm:items-center sm:gap-2">
+                      <span className="text-muted-foreground">
+                        {t("toolbox.ssl.protocolSupport")}:
+                      </span>
+                      <div className="flex flex-wrap gap-1">
+                        {(
+                          [
+                            ["TLS 1.0", cert.protocolProbe.tls10, true],
+                            ["TLS 1.1", cert.protocolProbe.tls11, true],
+                            ["TLS 1.2", cert.protocolProbe.tls12, false],
+                            ["TLS 1.3", cert.protocolProbe.tls13, false],
+                          ] as const
+                        ).map(([version, support, deprecated]) => (
+                          <Badge key={version} variant={versionBadgeVariant(support, deprecated)}>
+                            {version} {t(`toolbox.ssl.versionSupport.${support}`)}
+                          </Badge>
+                        ))}
+                      </div>
+                    </div>
+                  )}
+                </div>
+              </InfoCard>
+
               {/* 有效期 */}
               <InfoCard icon={<Clock className="h-5 w-5" />} title={t("toolbox.ssl.validity")}>
                 <div className="grid grid-cols-1 gap-3 text-sm sm:grid-cols-2">
@@ -268,6 +378,24 @@ export function SslCheck() {
                     <span className="text-muted-foreground">{t("toolbox.ssl.validTo")}:</span>
                     <div className="font-mono">{cert.validTo}</div>
                   </div>
+                  {cert.ocspStatus && (
+                    <div className="sm:col-span-2">
+                      <span className="text-muted-foreground">{t("toolbox.ssl.ocsp")}:</span>
+                      <div className="mt-1 flex flex-wrap items-center gap-1">
+                        <Badge variant={OCSP_BADGE_VARIANTS[cert.ocspStatus.status]}>
+                          {t(`toolbox.ssl.ocspStatus.${cert.ocspStatus.status}`)}
+                        </Badge>
+                        {cert.ocspStapled && (
+                          <Badge variant="outline">{t("toolbox.ssl.ocspStapled")}</Badge>
+                        )}
+                      </div>
+                      {cert.ocspStatus.status === "revoked" && (
+                        <div className="mt-1 font-mono text-xs">
+                          {cert.ocspStatus.reason} · {cert.ocspStatus.revocationTime}
+                        </div>
+                      )}
+                    </div>
+                  )}
                 </div>
               </InfoCard>
 
@@ -323,6 +451,12 @@ export function SslCheck() {
                                     CA
                                   </span>
                                 )}
+                                {cert.certificateChain.length > 1 &&
+                                  chainCert.daysRemaining === firstExpiringDays && (
+                                    <span className="rounded bg-yellow-500/10 px-2 py-0.5 text-xs text-yellow-600 dark:text-yellow-500">
+                                      {t("toolbox.ssl.expiresFirst")}
+                                    </span>
+                                  )}
                               </div>
                               <div className="text-muted-foreground">
                                 <span className="text-foreground">{t("toolbox.ssl.subject")}:</span>{" "}
@@ -332,6 +466,18 @@ export function SslCheck() {
                                 <span className="text-foreground">{t("toolbox.ssl.issuer")}:</span>{" "}
                                 {chainCert.issuer}
                               </div>
+                              <div className="text-muted-foreground">
+                                <span className="text-foreground">{t("toolbox.ssl.validTo")}:</span>{" "}
+                                {chainCert.validTo} (
+                                {chainCert.daysRemaining < 0
+                                  ? t("toolbox.ssl.expiredDays", {
+                                      count: Math.abs(chainCert.daysRemaining),
+                                    })
+                                  : t("toolbox.ssl.daysRemaining", {
+                                      count: chainCert.daysRemaining,
+                                    })}
+                                )
+                              </div>
                             </div>
                           ))}
                         </div>
@@ -1,4 +1,4 @@
-import { ChevronDown, ChevronUp } from "lucide-react"
+import { ChevronDown, ChevronUp, RefreshCw } from "lucide-react"
 import { useState } from "react"
 import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
@@ -14,16 +14,20 @@ export function WhoisLookup() {
 
   const { isLoading, result, execute } = useToolboxQuery<WhoisResult>()
 
+  const lookup = (query: string, forceRefresh: boolean) => {
+    execute(() => toolboxService.whoisLookup(query, forceRefresh), {
+      type: "whois",
+      query,
+    })
+  }
+
   const handleLookup = () => {
     const trimmed = domain.trim()
     if (!trimmed) {
       toast.error(t("toolbox.enterDomain"))
       return
     }
-    execute(() => toolboxService.whoisLookup(trimmed), {
-      type: "whois",
-      query: trimmed,
-    })
+    lookup(trimmed, false)
   }
 
   return (
@@ -40,10 +44,32 @@ export function WhoisLookup() {
 
       {result && (
         <div className="space-y-3 pt-2">
+          <div className="flex items-center justify-between gap-2 text-muted-foreground text-xs">
+            <span>
+              {t(result.cached ? "toolbox.whois.fetchedAtCached" : "toolbox.whois.fetchedAt", {
+                time: new Date(result.fetchedAt).toLocaleString(),
+              })}
+            </span>
+            <Button
+              variant="ghost"
+              size="sm"
+              className="h-auto px-2 py-1 text-xs"
+              disabled={isLoading}
+              onClick={() => lookup(result.domain, true)}
+            >
+              <RefreshCw className="mr-1 h-3 w-3" />
+              {t("toolbox.whois.refresh")}
+            </Button>
+          </div>
           <div className="grid grid-cols-1 gap-3 text-sm sm:grid-cols-2 sm:gap-4">
             <div>
               <span className="text-muted-foreground">{t("toolbox.whois.domain")}:</span>
-              <span className="ml-2 font-medium">{result.domain}</span>
+              <span className="ml-2 font-medium">{result.unicodeDomain ?? result.domain}</span>
+              {result.unicodeDomain && (
+                <span className="ml-2 font-mono text-muted-foreground text-xs">
+                  {result.domain}
+                </span>
+              )}
             </div>
             {result.registrar && (
               <div>
@@ -69,6 +95,46 @@ export function WhoisLookup() {
                 <span className="ml-2">{result.updatedDate}</span>
               </div>
             )}
+            {result.registrantOrg && (
+              <div>
+                <span className="text-muted-foreground">{t("toolbox.whois.registrantOrg")}:</span>
+                <span className="ml-2">{result.registrantOrg}</span>
+              </div>
+            )}
+            {result.registrantCountry && (
+              <div>
+                <span className="text-muted-foreground">
+                  {t("toolbox.whois.registrantCountry")}:
+                </span>
+                <span className="ml-2">{result.registrantCountry}</span>
+              </div>
+            )}
+            {result.registrarAbuseEmail && (
+              <div>
+                <span className="text-muted-foreground">{t("toolbox.whois.abuseEmail")}:</span>
+                <span className="ml-2">{result.registrarAbuseEmail}</span>
+              </div>
+            )}
+            {result.registrarAbusePhone && (
+              <div>
+                <span className="text-muted-foreground">{t("toolbox.whois.abusePhone")}:</span>
+                <span className="ml-2">{result.registrarAbusePhone}</span>
+              </div>
+            )}
+            {result.dnssec && (
+              <div>
+                <span className="text-muted-foreground">{t("toolbox.whois.dnssec")}:</span>
+                <span className="ml-2">{result.dnssec}</span>
+              </div>
+            )}
+            {result.queriedServers && result.queriedServers.length > 0 && (
+              <div className="sm:col-span-2">
+                <span className="text-muted-foreground">
+                  {t("toolbox.whois.queriedServers")}:
+                </span>
+                <span className="ml-2 font-mono text-xs">{result.queriedServers.join(" → ")}</span>
+              </div>
+            )}
           </div>
 
           {result.nameServers.length > 0 && (
@@ -1,6 +1,7 @@
 import { useCallback, useState } from "react"
+import { useTranslation } from "react-i18next"
 import { toast } from "sonner"
-import { extractErrorMessage, getErrorMessage } from "@/lib/error"
+import { extractErrorMessage, getErrorMessage, isExternalAccessBlocked } from "@/lib/error"
 import { toolboxService } from "@/services"
 import { useToolboxStore } from "@/stores"
 import type { ApiResponse, QueryHistoryItem } from "@/types"
@@ -10,9 +11,9 @@ export interface UseToolboxQueryReturn<TResult> {
   isLoading: boolean
   /** 查询结果 */
   result: TResult | null
-  /** 执行查询 */
+  /** 执行查询，`confirmExternal` 为用户是否已确认隐私模式下的第三方外发 */
   execute: (
-    serviceFn: () => Promise<ApiResponse<TResult>>,
+    serviceFn: (confirmExternal: boolean) => Promise<ApiResponse<TResult>>,
     historyItem: Omit<QueryHistoryItem, "id" | "timestamp">
   ) => Promise<TResult | null>
   /** 重置结果 */
@@ -22,8 +23,10 @@ export interface UseToolboxQueryReturn<TResult> {
 /**
  * 通用工具箱查询 Hook
  * 统一管理：loading 状态、Service 调用、历史记录添加、错误提示
+ * 隐私模式拦截第三方外发时提示用户确认，确认后重新执行
  */
 export function useToolboxQuery<TResult>(): UseToolboxQueryReturn<TResult> {
+  const { t } = useTranslation()
   const { addHistory } = useToolboxStore()
 
   const [isLoading, setIsLoading] = useState(false)
@@ -31,31 +34,52 @@ export function useToolboxQuery<TResult>(): UseToolboxQueryReturn<TResult> {
 
   const execute = useCallback(
     async (
-      serviceFn: () => Promise<ApiResponse<TResult>>,
+      serviceFn: (confirmExternal: boolean) => Promise<ApiResponse<TResult>>,
       historyItem: Omit<QueryHistoryItem, "id" | "timestamp">
     ): Promise<TResult | null> => {
-      setIsLoading(true)
-      setResult(null)
+      const run = async (confirmExternal: boolean): Promise<TResult | null> => {
+        setIsLoading(true)
+        setResult(null)
 
-      try {
-        const response = await serviceFn()
-
-        if (response.success && response.data) {
-          setResult(response.data)
-          addHistory(historyItem)
-          return response.data
+        const promptConfirm = (message: string) => {
+          toast.warning(message, {
+            action: {
+              label: t("toolbox.confirmExternal"),
+              onClick: () => void run(true),
+            },
+          })
         }
 
-        toast.error(getErrorMessage(response.error))
-        return null
-      } catch (err) {
-        toast.error(extractErrorMessage(err))
-        return null
-      } finally {
-        setIsLoading(false)
+        try {
+          const response = await serviceFn(confirmExternal)
+
+          if (response.success && response.data) {
+            setResult(response.data)
+            addHistory(historyItem)
+            return response.data
+          }
+
+          if (isExternalAccessBlocked(response.error)) {
+            promptConfirm(getErrorMessage(response.error))
+          } else {
+            toast.error(getErrorMessage(response.error))
+          }
+          return null
+        } catch (err) {
+          if (isExternalAccessBlocked(err)) {
+            promptConfirm(getErrorMessage(err))
+          } else {
+            toast.error(extractErrorMessage(err))
+          }
+          return null
+        } finally {
+          setIsLoading(false)
+        }
       }
+
+      return run(false)
     },
-    [addHistory]
+    [addHistory, t]
   )
 
   const reset = useCallback(() => {
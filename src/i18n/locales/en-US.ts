@@ -40,6 +40,7 @@ export default {
     deleteAccount: "Delete Account",
     accountName: "Account Name",
     accountNameOptional: "Account Name (optional)",
+    optionalField: "optional",
     accountNamePlaceholder: "e.g., {{provider}} Main Account",
     provider: "DNS Provider",
     selectProvider: "Select Provider",
@@ -50,6 +51,24 @@ export default {
     loadFailed: "Load failed",
     // Toast messages
     loadFailedCount: "{{count}} account(s) failed to load, please check Keychain permissions",
+    validate: "Validate credentials",
+    validateAll: "Validate all",
+    validateValid: 'Credentials of "{{name}}" are valid',
+    validateInvalid: 'Credentials of "{{name}}" were rejected by the provider',
+    validateUnknown: 'Could not validate "{{name}}": {{error}}',
+    validateAllSummary: "Validated: {{valid}} valid, {{invalid}} invalid, {{unknown}} unknown",
+    credentialStatus: {
+      valid: "Credentials valid",
+      invalid: "Credentials invalid",
+      unknown: "Credentials unverified",
+    },
+    lastValidatedAt: "checked {{time}}",
+    credentialsExpiresAt: "Credentials expiry date (optional)",
+    credentialsExpiresAtHelp:
+      "Reminders are sent 14 / 7 / 1 days before expiry; detected automatically for Cloudflare",
+    credentialsExpiringSoon:
+      'API token of account "{{name}}" expires within {{count}} day(s), please renew it',
+    credentialsExpiredNotice: 'API token of account "{{name}}" has expired, please update it',
     fetchFailed: "Failed to fetch accounts",
     createSuccess: 'Account "{{name}}" added successfully',
     createFailed: "Failed to create account",
@@ -77,7 +96,44 @@ export default {
     total: "{{count}} account(s)",
     empty: "No accounts",
     emptyDesc: "Add a DNS provider account to start managing your domains",
+  },  accountUsage: {
+    menu: "API Usage",
+    title: "API usage · {{name}}",
+    description: "Calls made to the provider API by this account, counted per day (UTC)",
+    total: "This month",
+    change: "{{percent}}% vs last month",
+    previousMonth: "Last month",
+    forecast: "Forecast",
+    quotaDay: "Daily quota {{limit}} calls, peak day at {{percent}}%",
+    quotaMonth: "Monthly quota {{limit}} calls, forecast at {{percent}}%",
+    quotaUnknown: "This provider does not publish a call quota",
+    dailyCalls: "Daily calls",
+    noData: "No calls recorded this month",
+    operations: "By operation",
+    operation: {
+      validate_credentials: "Validate credentials",
+      credentials_expiry: "Credentials expiry",
+      probe_capabilities: "Probe capabilities",
+      list_domains: "List domains",
+      get_domain: "Get domain",
+      list_records: "List records",
+      create_record: "Create record",
+      update_record: "Update record",
+      delete_record: "Delete record",
+      weighted_routing: "Weighted routing",
+      get_record_set: "Get record set",
+      replace_record_set: "Replace record set",
+      batch_change: "Batch change",
+    },
+    risk: {
+      unknown: "No quota",
+      normal: "Normal",
+      warning: "Near quota",
+      exceeded: "Over quota",
+    },
+    note: "Counts are per operation; paginated or retried requests may use more calls than shown.",
   },
+
   export: {
     title: "Export Accounts",
     description: "Select accounts to export, optionally with encryption",
@@ -89,6 +145,9 @@ export default {
     confirmPassword: "Confirm Password",
     confirmPasswordPlaceholder: "Re-enter password",
     encryptionNote: "Keep your password safe, you cannot recover data without it",
+    reproducible: "Reproducible export",
+    reproducibleNote:
+      "Keeps original account IDs and omits timestamps so identical data always exports identically, suitable for Git tracking",
     exportButton: "Export",
     noAccountsSelected: "Please select at least one account",
     passwordRequired: "Please enter encryption password",
@@ -96,6 +155,15 @@ export default {
     passwordTooShort: "Password must be at least 6 characters",
     success: "Successfully exported {{count}} account(s)",
     failed: "Export failed",
+    diagnostics: "Diagnostics report",
+    diagnosticsNote:
+      "Exports domains, records and errors without credentials, for attaching to a bug report",
+    anonymize: "Anonymize",
+    anonymizeNote:
+      "Replaces domains, IPs, TXT values, account names and IDs with placeholders; structure, counts and record types are kept",
+    saveMapping: "Save encrypted mapping table",
+    mappingNotSaved: "Mapping table was not saved; placeholders cannot be traced back",
+    diagnosticsSuccess: "Exported diagnostics for {{count}} account(s)",
   },
   import: {
     title: "Import Accounts",
@@ -116,6 +184,7 @@ export default {
     importing: "Importing...",
     success: "Successfully imported {{count}} account(s)",
     partialFailure: "{{count}} account(s) failed to import",
+    skippedDuplicates: "{{count}} account(s) already exist and were skipped",
     failed: "Import failed",
     invalidFile: "Invalid import file",
     decryptFailed: "Decryption failed, please check password",
@@ -183,6 +252,18 @@ export default {
       CAA: "💡 {{fqdn}} allows {{value}} to issue SSL certificates (tag: {{tag}})",
       proxyEnabled: "(Proxy enabled, real IP will be hidden)",
     },
+    // SOA info card
+    soa: {
+      title: "Start of Authority",
+      managedByProvider: "Managed by the DNS provider; most providers do not allow changes",
+      mname: "Primary server",
+      rname: "Admin email",
+      serial: "Serial",
+      refresh: "Refresh",
+      retry: "Retry",
+      expire: "Expire",
+      minimum: "Negative cache TTL",
+    },
     // Toast messages
     fetchFailed: "Failed to fetch DNS records",
     createSuccess: 'Record "{{name}}" added successfully',
@@ -193,6 +274,8 @@ export default {
     deleteFailed: "Failed to delete record",
     batchDeleteSuccess: "Successfully deleted {{count}} records",
     batchDeletePartial: "Deleted {{success}} records, {{failed}} failed",
+    batchCreateSuccess: "Successfully added {{count}} records",
+    batchCreatePartial: "Added {{success}} records, {{failed}} failed",
     // Wizard mode
     wizard: {
       title: "Add DNS Record",
@@ -294,11 +377,35 @@ export default {
     // Status
     status: {
       active: "Active",
-      paused: "Paused",
       pending: "Pending",
-      error: "Error",
+      suspended: "Suspended",
+      expired: "Expired",
+      moved: "Moved",
       unknown: "Unknown",
     },
+    derivedStatus: {
+      button: "Expiry & Sort",
+      filterLabel: "Show",
+      filter: {
+        all: "All domains",
+        expiringSoon: "Domain expiring within 30 days",
+        certificateExpiringSoon: "Certificate expiring within 30 days",
+        stale: "Checks older than 7 days",
+      },
+      sortLabel: "Sort by",
+      sort: {
+        default: "Provider order",
+        expiry: "Domain expiry",
+        certificate: "Certificate expiry",
+      },
+      expiryDays: "Expires in {{days}}d",
+      expiryExpired: "Domain expired",
+      expiryStale: "Expiry stale",
+      certificateDays: "Cert {{days}}d",
+      certificateExpired: "Cert expired",
+      certificateStale: "Cert check stale",
+      checkedAt: "Last checked {{time}}",
+    },
     // Toast messages
     fetchFailed: "Failed to fetch domains",
     // Tags
@@ -372,6 +479,11 @@ export default {
     switch: "Switch",
     refresh: "Refresh domain list",
   },
+  deepLink: {
+    copyLink: "Copy link to this domain",
+    copied: "Link copied",
+    rejected: "Could not open link: {{reason}}",
+  },
   nav: {
     home: "Home",
     domains: "Domains",
@@ -439,6 +551,61 @@ export default {
     infiniteScrollDesc: "Auto-load more when scrolling, mobile-friendly",
     traditionalPagination: "Traditional Pagination",
     traditionalPaginationDesc: "Use page numbers for quick navigation, desktop-friendly",
+    // Threat intelligence
+    threatIntel: {
+      title: "Threat Intelligence",
+      description: "Annotate IP lookup results with known malicious address sources",
+      spamhausDrop: "Spamhaus DROP list",
+      spamhausDropDesc: "Download the list daily and match locally, no API key required",
+      abuseipdbKey: "AbuseIPDB API key",
+      abuseipdbKeyPlaceholder: "Leave empty to disable",
+      abuseipdbKeyDesc:
+        "Addresses with confidence ≥ {{confidence}} are flagged; at most {{limit}} requests per day, results are cached",
+      saved: "Threat intelligence settings saved",
+      saveFailed: "Failed to save threat intelligence settings",
+    },
+    // Privacy mode
+    privacy: {
+      title: "Privacy",
+      description: "Review and restrict the third-party services the tools may contact",
+      privacyMode: "Privacy mode",
+      privacyModeDesc:
+        "Third-party lookups (geolocation, threat intelligence, public DNS propagation) require confirmation each time and background requests are disabled. Provider APIs and nameservers you specify are not affected",
+      endpoints: "Third-party endpoints",
+      endpointsDesc: "Every third-party service the tools may contact with the current settings",
+      sendsQueryTarget: "Requests include the queried IP or domain",
+      moreTargets: "and {{count}} more",
+      status: {
+        allowed: "Allowed",
+        requiresConfirmation: "Asks first",
+        disabled: "Disabled",
+      },
+      saveFailed: "Failed to save privacy settings",
+    },
+    writeQueue: {
+      title: "Offline Write Queue",
+      description:
+        "Queue record changes that fail due to network errors and replay them after review",
+      enabled: "Queue writes when offline",
+      enabledDesc:
+        "Queued changes are stored encrypted and never replayed automatically; conflicting records are never overwritten without confirmation",
+      maxEntries: "Maximum queued writes",
+      ttlHours: "Expire after (hours)",
+      saved: "Offline write queue settings saved",
+      saveFailed: "Failed to save offline write queue settings",
+      pending: "{{count}} queued writes awaiting review",
+      pendingNotice: "Network restored: {{count}} queued writes awaiting review",
+      review: "Review",
+      replayAll: "Replay all",
+      discardAll: "Discard all",
+      discard: "Discard",
+      forceReplay: "Overwrite",
+      replayResult: "{{applied}} applied, {{conflicts}} conflicts",
+      replayFailed: "Failed to replay queued writes",
+      discardFailed: "Failed to discard queued writes",
+      conflict: "Conflict: {{reason}}",
+      failed: "Failed: {{reason}}",
+    },
     // Debug
     debug: "Debug Mode",
     debugDesc: "Developer Options",
@@ -504,15 +671,19 @@ export default {
     enterDomain: "Please enter a domain",
     enterIp: "Please enter an IP address",
     enterIpOrDomain: "Please enter an IP address or domain",
+    confirmExternal: "Continue",
     queryFailed: "Query failed",
     noRecords: "No records found",
     // DNS server
     dnsServer: "DNS Server",
     systemDefault: "System Default",
     custom: "Custom",
-    customDnsPlaceholder: "Enter DNS server IP",
+    customDnsPlaceholder: "DNS server IP or doh:https://...",
     enterCustomDns: "Please enter custom DNS server address",
     usedDnsServer: "DNS Server",
+    dnsTransport: "Transport",
+    dohServerPlaceholder: "DoH URL, e.g. https://dns.google/dns-query",
+    dotServerPlaceholder: "host:port#TLS name, e.g. 1.1.1.1:853#cloudflare-dns.com",
     // History
     history: "History",
     noHistory: "No query history",
@@ -530,9 +701,18 @@ export default {
       creationDate: "Created",
       expirationDate: "Expires",
       updatedDate: "Updated",
+      registrantOrg: "Registrant",
+      registrantCountry: "Registrant Country",
+      abuseEmail: "Abuse Email",
+      abusePhone: "Abuse Phone",
+      dnssec: "DNSSEC",
+      queriedServers: "Data Source",
       nameServers: "Name Servers",
       status: "Status",
       rawData: "Raw Data",
+      fetchedAt: "Fetched at {{time}}",
+      fetchedAtCached: "Cached result, fetched at {{time}}",
+      refresh: "Refresh",
     },
     // IP lookup result
     ip: {
@@ -548,6 +728,13 @@ export default {
       org: "Organization",
       asn: "ASN",
       resolvedFrom: "Resolved from",
+      threat: "Threat Intelligence",
+      threatListed: "Listed",
+      threatClean: "Not listed",
+      threatSources: "Listed by",
+      threatCategories: "Categories",
+      threatLastReported: "Last reported",
+      threatChecked: "Checked sources",
     },
     // SSL check result
     ssl: {
@@ -567,6 +754,7 @@ export default {
       validTo: "Valid To",
       san: "Subject Alternative Names",
       chain: "Certificate Chain",
+      expiresFirst: "Expires first",
       portPlaceholder: "Port (443)",
       invalidPort: "Invalid port number",
       connectionFailed: "Connection Failed",
@@ -575,6 +763,29 @@ export default {
       httpWarningTitle: "This connection is not using HTTPS encryption",
       httpWarningDesc:
         "This port uses HTTP protocol, data is transmitted unencrypted, which may pose security risks",
+      protocol: "Protocol",
+      tlsVersion: "TLS Version",
+      cipher: "Cipher Suite",
+      legacyTls: "Legacy Protocols",
+      legacyAccepted: "accepted",
+      legacyRejected: "disabled",
+      probeVersions: "Probe each TLS version (1.0 – 1.3)",
+      connectAddrPlaceholder: "Connect to IP (optional, SNI uses the domain)",
+      connectedTo: "via {{addr}}",
+      protocolSupport: "Protocol Support",
+      versionSupport: {
+        accepted: "accepted",
+        rejected: "rejected",
+        unknown: "unknown",
+      },
+      ocsp: "Revocation (OCSP)",
+      ocspStapled: "Stapled",
+      ocspStatus: {
+        good: "Not revoked",
+        revoked: "Revoked",
+        unknown: "Unknown",
+        notChecked: "Not checked",
+      },
     },
     // HTTP Header Check
     httpHeaderCheck: {
@@ -595,6 +806,7 @@ export default {
       statusCode: "Status Code",
       responseTime: "Response Time",
       securityAnalysis: "Security Header Analysis",
+      securityScore: "{{score}} / {{max}} points",
       allHeaders: "All Response Headers",
       rawMessages: "Raw Messages",
       rawRequest: "Raw Request",
@@ -602,10 +814,16 @@ export default {
       copyHeader: "Copy",
       showLess: "Show less",
       showFullValue: "Show full value",
-      status: {
-        good: "Good",
+      followRedirects: "Follow redirects",
+      redirectChain: "Redirect Chain",
+      finalUrl: "Final URL",
+      loopDetected: "Redirect loop detected, showing the last response",
+      redirectLimitReached: "Redirect limit reached, showing the last response",
+      missing: "Missing",
+      severity: {
+        ok: "OK",
         warning: "Warning",
-        missing: "Missing",
+        error: "Error",
       },
     },
     // DNS Propagation
@@ -688,6 +906,14 @@ export default {
     credential_validation_failed: "Credential validation failed",
     credential_load_failed: "Failed to load credentials: {{detail}}",
     credential_not_found: "Credentials not found",
+    credentials_expired: "API token has expired, please update the credentials",
+    change_window_closed: "Changes are blocked by \"{{rule}}\" until {{time}}",
+    write_queued: "Network unavailable, the change was queued for review in Settings",
+    resource_busy: "Another change to this record is still running ({{operation}}), try again later",
+    service_unavailable:
+      "The provider failed repeatedly with network errors, requests are paused for a minute",
+    capability_unavailable: "Not available on this system: requires {{capability}}",
+    external_access_blocked: "Privacy mode blocked a third-party request ({{purpose}}), confirm to continue",
     credential_save_failed: "Failed to save credentials: {{detail}}",
     // Provider errors
     provider_create_failed: "Failed to create provider: {{detail}}",
@@ -719,6 +945,7 @@ export default {
         record_not_found: "Record not found",
         invalid_parameter: 'Invalid parameter "{{param}}": {{detail}}',
         quota_exceeded: "Quota exceeded",
+        http_error: "Provider server error (HTTP {{status}}), please try again later",
         domain_not_found: 'Domain "{{domain}}" not found',
         domain_locked: 'Domain "{{domain}}" is locked or disabled',
         permission_denied: "Permission denied",
@@ -741,6 +968,88 @@ export default {
       huaweicloud: {
         invalid_credentials: "AK/SK is invalid",
       },
+      // AWS Route53 specific errors
+      route53: {
+        invalid_credentials: "Access key is invalid or the signature does not match",
+      },
+      // Google Cloud DNS specific errors
+      gcp_dns: {
+        invalid_credentials: "Service account key is invalid or has been disabled",
+      },
+      // DigitalOcean specific errors
+      digitalocean: {
+        invalid_credentials: "Personal access token is invalid, expired or revoked",
+      },
+    },
+    suggestion_line: "{{action}}: {{description}}",
+    // Fix suggestions attached to errors (keys referenced by the backend suggestion table)
+    suggestions: {
+      reduce_concurrency: {
+        action: "Reduce batch concurrency",
+        description:
+          "The provider is rate limiting requests. Wait a moment and retry with fewer records per batch.",
+      },
+      check_plan_quota: {
+        action: "Check plan quota",
+        description:
+          "The record or zone limit of your plan may be reached. Remove unused records or upgrade the plan.",
+      },
+      check_token_permissions: {
+        action: "Check token permissions",
+        description:
+          "Make sure the API token is still valid and grants DNS edit access to this domain.",
+      },
+      update_credentials: {
+        action: "Update credentials",
+        description: "The API token has expired. Create a new one and update the account.",
+      },
+      record_type_unsupported: {
+        action: "Use a supported record type",
+        description:
+          "This provider or TLD does not support this record type. Check the provider's supported types.",
+      },
+      check_record_value: {
+        action: "Check the record value",
+        description:
+          "The value does not match the record type, e.g. an IP address for A, a hostname for CNAME.",
+      },
+      ttl_out_of_range: {
+        action: "Adjust the TTL",
+        description: "The TTL is outside the range allowed by your plan. Use a larger value.",
+      },
+      line_unavailable: {
+        action: "Choose another line",
+        description: "The selected resolution line is not available for this domain or plan.",
+      },
+      disable_proxy: {
+        action: "Turn off proxy",
+        description:
+          "Only A, AAAA and CNAME records can be proxied. Disable the proxy for this record.",
+      },
+      edit_existing_record: {
+        action: "Edit the existing record",
+        description:
+          "A conflicting record already exists. Edit or delete it instead of creating a new one.",
+      },
+      domain_locked: {
+        action: "Unlock the domain",
+        description:
+          "The domain is locked or suspended at the provider. Unlock it in the provider console first.",
+      },
+      check_network: {
+        action: "Check network connection",
+        description: "The provider could not be reached. Check your connection or proxy settings.",
+      },
+      retry_later: {
+        action: "Retry later",
+        description:
+          "The operation did not finish in time or is blocked by another change. Try again shortly.",
+      },
+      review_change_window: {
+        action: "Review change windows",
+        description:
+          "Changes are currently blocked by a change window. Wait for it to open or add an override in Settings.",
+      },
     },
   },
 }
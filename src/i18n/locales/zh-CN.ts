@@ -40,6 +40,7 @@ export default {
     deleteAccount: "删除账号",
     accountName: "账号名称",
     accountNameOptional: "账号名称 (可选)",
+    optionalField: "可选",
     accountNamePlaceholder: "例如: {{provider}} 主账号",
     provider: "DNS 提供商",
     selectProvider: "选择提供商",
@@ -49,6 +50,22 @@ export default {
     loadFailed: "加载失败",
     // Toast messages
     loadFailedCount: "{{count}} 个账号加载失败，请检查 Keychain 权限",
+    validate: "校验凭证",
+    validateAll: "全部校验",
+    validateValid: '账号 "{{name}}" 的凭证有效',
+    validateInvalid: '账号 "{{name}}" 的凭证被服务商拒绝',
+    validateUnknown: '无法校验账号 "{{name}}"：{{error}}',
+    validateAllSummary: "校验完成：{{valid}} 个有效，{{invalid}} 个无效，{{unknown}} 个无法确认",
+    credentialStatus: {
+      valid: "凭证有效",
+      invalid: "凭证无效",
+      unknown: "凭证未确认",
+    },
+    lastValidatedAt: "校验于 {{time}}",
+    credentialsExpiresAt: "凭证到期日期（可选）",
+    credentialsExpiresAtHelp: "到期前 14 / 7 / 1 天提醒；Cloudflare 账号会自动获取",
+    credentialsExpiringSoon: '账号 "{{name}}" 的 API Token 将在 {{count}} 天内过期，请及时更新',
+    credentialsExpiredNotice: '账号 "{{name}}" 的 API Token 已过期，请更新凭证',
     fetchFailed: "获取账号列表失败",
     createSuccess: '账号 "{{name}}" 添加成功',
     createFailed: "创建账号失败",
@@ -75,7 +92,44 @@ export default {
     total: "共 {{count}} 个账户",
     empty: "暂无账户",
     emptyDesc: "添加一个 DNS 服务商账户来开始管理你的域名",
+  },  accountUsage: {
+    menu: "API 用量",
+    title: "API 用量 · {{name}}",
+    description: "该账户调用服务商 API 的次数，按天（UTC）统计",
+    total: "本月",
+    change: "较上月 {{percent}}%",
+    previousMonth: "上月",
+    forecast: "预测",
+    quotaDay: "每日配额 {{limit}} 次，单日峰值已达 {{percent}}%",
+    quotaMonth: "每月配额 {{limit}} 次，预测将达 {{percent}}%",
+    quotaUnknown: "该服务商未公开调用配额",
+    dailyCalls: "每日调用",
+    noData: "本月暂无调用记录",
+    operations: "按操作",
+    operation: {
+      validate_credentials: "验证凭证",
+      credentials_expiry: "凭证有效期",
+      probe_capabilities: "探测能力",
+      list_domains: "列出域名",
+      get_domain: "获取域名",
+      list_records: "列出记录",
+      create_record: "创建记录",
+      update_record: "更新记录",
+      delete_record: "删除记录",
+      weighted_routing: "权重路由",
+      get_record_set: "获取记录集",
+      replace_record_set: "替换记录集",
+      batch_change: "批量变更",
+    },
+    risk: {
+      unknown: "无配额",
+      normal: "正常",
+      warning: "接近配额",
+      exceeded: "超出配额",
+    },
+    note: "按操作计数；分页或重试的请求实际消耗的调用次数可能多于显示值。",
   },
+
   export: {
     title: "导出账号",
     description: "选择要导出的账号，可选择是否加密",
@@ -87,6 +141,9 @@ export default {
     confirmPassword: "确认密码",
     confirmPasswordPlaceholder: "再次输入密码",
     encryptionNote: "请妥善保管密码，遗失密码将无法恢复账号数据",
+    reproducible: "可复现导出",
+    reproducibleNote:
+      "保留原账户 ID 并省略时间戳，相同数据每次导出内容一致，便于纳入 Git 管理",
     exportButton: "导出",
     noAccountsSelected: "请选择至少一个账号",
     passwordRequired: "请输入加密密码",
@@ -94,6 +151,13 @@ export default {
     passwordTooShort: "密码长度至少 6 位",
     success: "成功导出 {{count}} 个账号",
     failed: "导出失败",
+    diagnostics: "诊断报告",
+    diagnosticsNote: "导出域名、记录与错误信息（不含凭证），用于附在问题反馈中",
+    anonymize: "匿名化",
+    anonymizeNote: "域名、IP、TXT 值、账户名与 ID 替换为假名，保留结构、数量与记录类型分布",
+    saveMapping: "保存加密的映射表",
+    mappingNotSaved: "未保存映射表，假名将无法对照回原始数据",
+    diagnosticsSuccess: "已导出 {{count}} 个账号的诊断报告",
   },
   import: {
     title: "导入账号",
@@ -113,6 +177,7 @@ export default {
     importing: "正在导入...",
     success: "成功导入 {{count}} 个账号",
     partialFailure: "{{count}} 个账号导入失败",
+    skippedDuplicates: "{{count}} 个账号已存在，已跳过",
     failed: "导入失败",
     invalidFile: "无效的导入文件",
     decryptFailed: "解密失败，请检查密码",
@@ -178,6 +243,18 @@ export default {
       CAA: "💡 {{fqdn}} 允许 {{value}} 签发 SSL 证书（标签：{{tag}}）",
       proxyEnabled: "（已启用代理，真实 IP 将被隐藏）",
     },
+    // SOA 信息卡
+    soa: {
+      title: "SOA 起始授权",
+      managedByProvider: "由 DNS 服务商维护，多数服务商不允许修改",
+      mname: "主服务器",
+      rname: "管理员邮箱",
+      serial: "序列号",
+      refresh: "刷新间隔",
+      retry: "重试间隔",
+      expire: "过期时间",
+      minimum: "否定缓存时间",
+    },
     // Toast messages
     fetchFailed: "获取 DNS 记录失败",
     createSuccess: '记录 "{{name}}" 添加成功',
@@ -188,6 +265,8 @@ export default {
     deleteFailed: "删除记录失败",
     batchDeleteSuccess: "成功删除 {{count}} 条记录",
     batchDeletePartial: "成功删除 {{success}} 条记录，{{failed}} 条失败",
+    batchCreateSuccess: "成功添加 {{count}} 条记录",
+    batchCreatePartial: "成功添加 {{success}} 条记录，{{failed}} 条失败",
     // 向导模式
     wizard: {
       title: "添加 DNS 记录",
@@ -289,11 +368,35 @@ export default {
     // Status
     status: {
       active: "活跃",
-      paused: "暂停",
       pending: "待验证",
-      error: "异常",
+      suspended: "已暂停",
+      expired: "已过期",
+      moved: "已迁出",
       unknown: "未知",
     },
+    derivedStatus: {
+      button: "到期与排序",
+      filterLabel: "显示",
+      filter: {
+        all: "全部域名",
+        expiringSoon: "30 天内域名到期",
+        certificateExpiringSoon: "30 天内证书到期",
+        stale: "检查结果超过 7 天",
+      },
+      sortLabel: "排序",
+      sort: {
+        default: "服务商顺序",
+        expiry: "域名到期时间",
+        certificate: "证书到期时间",
+      },
+      expiryDays: "{{days}} 天后到期",
+      expiryExpired: "域名已到期",
+      expiryStale: "到期信息已过时",
+      certificateDays: "证书 {{days}} 天",
+      certificateExpired: "证书已过期",
+      certificateStale: "证书检查已过时",
+      checkedAt: "上次检查于 {{time}}",
+    },
     // Toast messages
     fetchFailed: "获取域名列表失败",
     // Tags
@@ -367,6 +470,11 @@ export default {
     switch: "切换",
     refresh: "刷新域名列表",
   },
+  deepLink: {
+    copyLink: "复制指向此域名的链接",
+    copied: "链接已复制",
+    rejected: "无法打开链接：{{reason}}",
+  },
   nav: {
     home: "主页",
     domains: "域名",
@@ -434,6 +542,58 @@ export default {
     infiniteScrollDesc: "滚动到底部自动加载更多，适合移动端",
     traditionalPagination: "传统分页",
     traditionalPaginationDesc: "使用分页器快速跳转，适合桌面端",
+    // 威胁情报
+    threatIntel: {
+      title: "威胁情报",
+      description: "在 IP 查询结果中标注已知的恶意地址",
+      spamhausDrop: "Spamhaus DROP 列表",
+      spamhausDropDesc: "每天下载列表到本地离线匹配，无需 API Key",
+      abuseipdbKey: "AbuseIPDB API Key",
+      abuseipdbKeyPlaceholder: "留空则不启用",
+      abuseipdbKeyDesc: "置信度 ≥ {{confidence}} 的地址视为恶意；每天最多请求 {{limit}} 次，结果会被缓存",
+      saved: "威胁情报设置已保存",
+      saveFailed: "保存威胁情报设置失败",
+    },
+    // 隐私模式
+    privacy: {
+      title: "隐私",
+      description: "审查并限制工具可能访问的第三方服务",
+      privacyMode: "隐私模式",
+      privacyModeDesc:
+        "访问第三方服务的查询（地理位置、威胁情报、公共 DNS 传播检查）每次需要确认，后台外发直接禁用；服务商 API 与你指定的服务器不受影响",
+      endpoints: "第三方端点",
+      endpointsDesc: "当前配置下工具可能访问的全部第三方服务",
+      sendsQueryTarget: "请求中包含查询的 IP 或域名",
+      moreTargets: "等 {{count}} 个",
+      status: {
+        allowed: "允许",
+        requiresConfirmation: "需确认",
+        disabled: "已禁用",
+      },
+      saveFailed: "保存隐私设置失败",
+    },
+    writeQueue: {
+      title: "离线写队列",
+      description: "因网络错误失败的记录变更先加入队列，网络恢复后经确认再重放",
+      enabled: "离线时将写操作加入队列",
+      enabledDesc: "队列加密保存，不会自动重放；记录已被修改时不会在未确认的情况下覆盖",
+      maxEntries: "队列上限",
+      ttlHours: "有效期（小时）",
+      saved: "离线写队列设置已保存",
+      saveFailed: "保存离线写队列设置失败",
+      pending: "{{count}} 个写操作等待确认",
+      pendingNotice: "网络已恢复，{{count}} 个排队的写操作等待确认",
+      review: "查看",
+      replayAll: "全部重放",
+      discardAll: "全部丢弃",
+      discard: "丢弃",
+      forceReplay: "覆盖",
+      replayResult: "已应用 {{applied}} 个，冲突 {{conflicts}} 个",
+      replayFailed: "重放写操作失败",
+      discardFailed: "丢弃写操作失败",
+      conflict: "冲突：{{reason}}",
+      failed: "失败：{{reason}}",
+    },
     // Debug
     debug: "调试模式",
     debugDesc: "开发者选项",
@@ -498,15 +658,19 @@ export default {
     enterDomain: "请输入域名",
     enterIp: "请输入 IP 地址",
     enterIpOrDomain: "请输入 IP 地址或域名",
+    confirmExternal: "继续",
     queryFailed: "查询失败",
     noRecords: "未找到记录",
     // DNS server
     dnsServer: "DNS 服务器",
     systemDefault: "系统默认",
     custom: "自定义",
-    customDnsPlaceholder: "输入 DNS 服务器 IP",
+    customDnsPlaceholder: "DNS 服务器 IP 或 doh:https://...",
     enterCustomDns: "请输入自定义 DNS 服务器地址",
     usedDnsServer: "使用 DNS 服务器",
+    dnsTransport: "传输协议",
+    dohServerPlaceholder: "DoH 地址，如 https://dns.google/dns-query",
+    dotServerPlaceholder: "主机:端口#TLS 名称，如 1.1.1.1:853#cloudflare-dns.com",
     // History
     history: "历史记录",
     noHistory: "暂无查询记录",
@@ -524,9 +688,18 @@ export default {
       creationDate: "注册日期",
       expirationDate: "到期日期",
       updatedDate: "更新日期",
+      registrantOrg: "注册人",
+      registrantCountry: "注册人国家/地区",
+      abuseEmail: "滥用投诉邮箱",
+      abusePhone: "滥用投诉电话",
+      dnssec: "DNSSEC",
+      queriedServers: "数据来源",
       nameServers: "域名服务器",
       status: "状态",
       rawData: "原始数据",
+      fetchedAt: "查询于 {{time}}",
+      fetchedAtCached: "缓存结果，查询于 {{time}}",
+      refresh: "重新查询",
     },
     // IP lookup result
     ip: {
@@ -542,6 +715,13 @@ export default {
       org: "组织",
       asn: "ASN",
       resolvedFrom: "解析自",
+      threat: "威胁情报",
+      threatListed: "已被列入",
+      threatClean: "未被列入",
+      threatSources: "列入来源",
+      threatCategories: "类别",
+      threatLastReported: "最近举报",
+      threatChecked: "已查询来源",
     },
     // SSL check result
     ssl: {
@@ -561,6 +741,7 @@ export default {
       validTo: "过期时间",
       san: "备用名称 (SAN)",
       chain: "证书链",
+      expiresFirst: "最先到期",
       portPlaceholder: "端口 (443)",
       invalidPort: "无效的端口号",
       connectionFailed: "连接失败",
@@ -568,6 +749,29 @@ export default {
       noCertInfo: "无法获取证书信息",
       httpWarningTitle: "此连接未使用 HTTPS 加密",
       httpWarningDesc: "该端口使用 HTTP 协议，数据传输未加密，可能存在安全风险",
+      protocol: "协议",
+      tlsVersion: "TLS 版本",
+      cipher: "密码套件",
+      legacyTls: "旧版协议",
+      legacyAccepted: "仍被接受",
+      legacyRejected: "已禁用",
+      probeVersions: "逐一探测 TLS 版本（1.0 – 1.3）",
+      connectAddrPlaceholder: "连接到指定 IP（可选，SNI 仍使用域名）",
+      connectedTo: "经由 {{addr}}",
+      protocolSupport: "协议支持",
+      versionSupport: {
+        accepted: "接受",
+        rejected: "拒绝",
+        unknown: "未知",
+      },
+      ocsp: "吊销状态 (OCSP)",
+      ocspStapled: "已装订",
+      ocspStatus: {
+        good: "未吊销",
+        revoked: "已吊销",
+        unknown: "未知",
+        notChecked: "未检查",
+      },
     },
     // HTTP Header Check
     httpHeaderCheck: {
@@ -588,6 +792,7 @@ export default {
       statusCode: "状态码",
       responseTime: "响应时间",
       securityAnalysis: "安全头分析",
+      securityScore: "{{score}} / {{max}} 分",
       allHeaders: "所有响应头",
       rawMessages: "原始报文",
       rawRequest: "原始请求",
@@ -595,10 +800,16 @@ export default {
       copyHeader: "复制",
       showLess: "收起",
       showFullValue: "展开完整值",
-      status: {
-        good: "良好",
-        warning: "建议",
-        missing: "缺失",
+      followRedirects: "跟随重定向",
+      redirectChain: "重定向链",
+      finalUrl: "最终 URL",
+      loopDetected: "检测到重定向循环，显示最后一个响应",
+      redirectLimitReached: "已达到重定向次数上限，显示最后一个响应",
+      missing: "缺失",
+      severity: {
+        ok: "良好",
+        warning: "警告",
+        error: "错误",
       },
     },
     // DNS Propagation
@@ -681,6 +892,13 @@ export default {
     credential_validation_failed: "凭证验证失败",
     credential_load_failed: "凭证加载失败: {{detail}}",
     credential_not_found: "凭证未找到",
+    credentials_expired: "API Token 已过期，请更新凭证",
+    change_window_closed: "当前处于禁止变更时段（{{rule}}），{{time}} 后可变更",
+    write_queued: "网络不可用，变更已加入离线写队列，可在设置中确认重放",
+    resource_busy: "该记录正在被其他操作修改（{{operation}}），请稍后重试",
+    service_unavailable: "服务商连续出现网络错误，已暂停请求，请一分钟后重试",
+    capability_unavailable: "当前系统不支持此操作：需要 {{capability}}",
+    external_access_blocked: "隐私模式已拦截第三方请求（{{purpose}}），确认后可继续",
     credential_save_failed: "保存凭证失败: {{detail}}",
     // Provider 错误
     provider_create_failed: "Provider 创建失败: {{detail}}",
@@ -712,6 +930,7 @@ export default {
         record_not_found: "记录不存在",
         invalid_parameter: '参数 "{{param}}" 无效: {{detail}}',
         quota_exceeded: "配额已用尽",
+        http_error: "服务商服务器错误 (HTTP {{status}})，请稍后重试",
         domain_not_found: '域名 "{{domain}}" 不存在',
         domain_locked: '域名 "{{domain}}" 已被锁定或禁用',
         permission_denied: "操作被拒绝，权限不足",
@@ -734,6 +953,78 @@ export default {
       huaweicloud: {
         invalid_credentials: "AK/SK 无效",
       },
+      // AWS Route53 特定错误
+      route53: {
+        invalid_credentials: "Access Key 无效或签名不匹配",
+      },
+      // Google Cloud DNS 特定错误
+      gcp_dns: {
+        invalid_credentials: "服务账号密钥无效或已被禁用",
+      },
+      // DigitalOcean 特定错误
+      digitalocean: {
+        invalid_credentials: "Personal Access Token 无效、已过期或已被吊销",
+      },
+    },
+    suggestion_line: "{{action}}：{{description}}",
+    // 错误附带的修复建议（键由后端建议映射表引用）
+    suggestions: {
+      reduce_concurrency: {
+        action: "降低批量并发",
+        description: "服务商正在限流，请稍候并减少每批的记录数后重试。",
+      },
+      check_plan_quota: {
+        action: "检查套餐配额",
+        description: "可能已达到套餐的记录数或域名数上限，请删除不用的记录或升级套餐。",
+      },
+      check_token_permissions: {
+        action: "检查 token 权限",
+        description: "确认 API token 仍然有效，并拥有该域名的 DNS 编辑权限。",
+      },
+      update_credentials: {
+        action: "更新凭证",
+        description: "API token 已过期，请创建新的 token 并更新账户。",
+      },
+      record_type_unsupported: {
+        action: "改用支持的记录类型",
+        description: "该服务商或 TLD 不支持此记录类型，请查看服务商支持的类型。",
+      },
+      check_record_value: {
+        action: "检查记录值",
+        description: "记录值与类型不匹配，例如 A 记录应为 IP 地址，CNAME 应为主机名。",
+      },
+      ttl_out_of_range: {
+        action: "调整 TTL",
+        description: "TTL 超出当前套餐允许的范围，请使用更大的值。",
+      },
+      line_unavailable: {
+        action: "更换解析线路",
+        description: "所选线路对该域名或套餐不可用。",
+      },
+      disable_proxy: {
+        action: "关闭代理",
+        description: "只有 A、AAAA 和 CNAME 记录可以开启代理，请为该记录关闭代理。",
+      },
+      edit_existing_record: {
+        action: "编辑已有记录",
+        description: "已存在冲突的记录，请编辑或删除它，而不是新建。",
+      },
+      domain_locked: {
+        action: "解锁域名",
+        description: "域名在服务商处被锁定或暂停，请先在服务商控制台解锁。",
+      },
+      check_network: {
+        action: "检查网络连接",
+        description: "无法连接服务商，请检查网络或代理设置。",
+      },
+      retry_later: {
+        action: "稍后重试",
+        description: "操作未能及时完成或被其他变更占用，请稍后再试。",
+      },
+      review_change_window: {
+        action: "查看禁止变更时段",
+        description: "当前处于禁止变更时段，请等待时段结束，或在设置中添加例外。",
+      },
     },
   },
 }
@@ -0,0 +1,64 @@
+import type { Domain, DomainDerivedStatus } from "@/types"
+
+/** 检查结果超过该天数未更新视为过时 */
+export const DERIVED_STATUS_STALE_DAYS = 7
+
+/** 剩余天数不超过该值视为即将到期 */
+export const EXPIRING_SOON_DAYS = 30
+
+const DAY_MS = 24 * 60 * 60 * 1000
+
+/** 按派生状态筛选 */
+export type DomainStatusFilter = "all" | "expiringSoon" | "certificateExpiringSoon" | "stale"
+
+/** 域名列表排序方式（`default` 为服务商返回的顺序） */
+export type DomainSortKey = "default" | "expiry" | "certificate"
+
+/** 检查时间是否已过时（从未检查也算） */
+export function isStale(checkedAt: string | undefined, now = Date.now()): boolean {
+  return !checkedAt || now - new Date(checkedAt).getTime() > DERIVED_STATUS_STALE_DAYS * DAY_MS
+}
+
+/** 未过时的注册到期剩余天数 */
+export function freshExpiryDays(status: DomainDerivedStatus | undefined): number | undefined {
+  return status && !isStale(status.expiryCheckedAt) ? status.expiryDaysRemaining : undefined
+}
+
+/** 未过时的证书到期剩余天数 */
+export function freshCertificateDays(status: DomainDerivedStatus | undefined): number | undefined {
+  return status && !isStale(status.certificateCheckedAt)
+    ? status.certificateDaysRemaining
+    : undefined
+}
+
+/** 任一检查结果已过时 */
+function hasStaleStatus(status: DomainDerivedStatus | undefined): boolean {
+  return isStale(status?.expiryCheckedAt) || isStale(status?.certificateCheckedAt)
+}
+
+export function matchesStatusFilter(domain: Domain, filter: DomainStatusFilter): boolean {
+  const status = domain.derivedStatus
+  switch (filter) {
+    case "all":
+      return true
+    case "expiringSoon":
+      return (freshExpiryDays(status) ?? Number.POSITIVE_INFINITY) <= EXPIRING_SOON_DAYS
+    case "certificateExpiringSoon":
+      return (freshCertificateDays(status) ?? Number.POSITIVE_INFINITY) <= EXPIRING_SOON_DAYS
+    case "stale":
+      return hasStaleStatus(status)
+  }
+}
+
+/** 按剩余天数升序排序；没有（或已过时）数据的域名排在最后，同值按名称 */
+export function sortDomains(domains: Domain[], sort: DomainSortKey): Domain[] {
+  if (sort === "default") return domains
+  const days = sort === "expiry" ? freshExpiryDays : freshCertificateDays
+  const key = (domain: Domain) => days(domain.derivedStatus) ?? Number.POSITIVE_INFINITY
+  return [...domains].sort((a, b) => {
+    const ka = key(a)
+    const kb = key(b)
+    if (ka !== kb) return ka < kb ? -1 : 1
+    return a.name.localeCompare(b.name)
+  })
+}
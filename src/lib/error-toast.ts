@@ -0,0 +1,32 @@
+import { toast } from "sonner"
+import i18n from "@/i18n"
+import { extractErrorMessage, getErrorSuggestions } from "@/lib/error"
+import { openExternal } from "@/lib/open-external"
+
+/**
+ * 弹出错误提示，并列出后端附带的修复建议
+ * 第一条带链接的建议渲染为可点击的操作按钮
+ */
+export function toastError(err: unknown, message = extractErrorMessage(err)): void {
+  const suggestions = getErrorSuggestions(err)
+  const linked = suggestions.find((suggestion) => suggestion.link)
+  const link = linked?.link
+
+  toast.error(message, {
+    description:
+      suggestions.length > 0
+        ? suggestions
+            .map((suggestion) =>
+              i18n.t("errors.suggestion_line", {
+                action: i18n.t(suggestion.actionKey),
+                description: i18n.t(suggestion.descriptionKey),
+              })
+            )
+            .join("\n")
+        : undefined,
+    action:
+      linked && link
+        ? { label: i18n.t(linked.actionKey), onClick: () => void openExternal(link) }
+        : undefined,
+  })
+}
@@ -4,6 +4,7 @@ import type {
   ApiError,
   CredentialValidationDetails,
   DnsErrorCode,
+  ErrorSuggestion,
   ProviderErrorDetails,
 } from "@/types"
 
@@ -116,6 +117,43 @@ export function getErrorMessage(error: ApiError | undefined): string {
     return getProviderErrorMessage(error.details)
   }
 
+  // 禁止变更时段：附带规则名称与下一个允许变更的时间
+  if (error.code === "CHANGE_WINDOW_CLOSED" && typeof error.details === "object") {
+    const details = error.details as { rule_name: string; next_open_at: string }
+    return i18n.t("errors.change_window_closed", {
+      rule: details.rule_name,
+      time: new Date(details.next_open_at).toLocaleString(),
+    })
+  }
+
+  // 已加入离线写队列
+  if (error.code === "WRITE_QUEUED") {
+    return i18n.t("errors.write_queued")
+  }
+
+  // 写锁被其他操作占用
+  if (error.code === "RESOURCE_BUSY" && typeof error.details === "object") {
+    const details = error.details as { resource: string; holder: string }
+    return i18n.t("errors.resource_busy", { operation: details.holder })
+  }
+
+  // 缺少平台能力或权限
+  if (error.code === "CAPABILITY_UNAVAILABLE" && typeof error.details === "object") {
+    const details = error.details as { capability: string; detail: string }
+    return i18n.t("errors.capability_unavailable", { capability: details.capability })
+  }
+
+  // 隐私模式拦截的第三方外发
+  if (error.code === "EXTERNAL_ACCESS_BLOCKED" && typeof error.details === "object") {
+    const details = error.details as { endpoint: string; purpose: string }
+    return i18n.t("errors.external_access_blocked", { purpose: details.purpose })
+  }
+
+  // 服务商连续网络错误，熔断中
+  if (error.code === "SERVICE_UNAVAILABLE") {
+    return i18n.t("errors.service_unavailable")
+  }
+
   // 直接尝试用 error.code 查找翻译（支持 PascalCase 的 DnsError 变体）
   const snakeCaseCode = toSnakeCase(error.code)
   const directKey = `errors.${snakeCaseCode}`
@@ -146,6 +184,28 @@ export function isErrorCode(error: ApiError | undefined, code: DnsErrorCode): bo
   return error?.code === code
 }
 
+/**
+ * 检查是否为隐私模式拦截的第三方外发
+ * 兼容 Tauri 抛出的 DnsError 与 HTTP 响应中的 ApiError
+ */
+export function isExternalAccessBlocked(err: unknown): err is ApiError {
+  return (
+    typeof err === "object" &&
+    err !== null &&
+    (err as { code?: unknown }).code === "EXTERNAL_ACCESS_BLOCKED"
+  )
+}
+
+/**
+ * 获取后端附带的修复建议
+ * 兼容 Tauri 抛出的 DnsError 与 HTTP 响应中的 ApiError
+ */
+export function getErrorSuggestions(err: unknown): ErrorSuggestion[] {
+  if (typeof err !== "object" || err === null) return []
+  const suggestions = (err as { suggestions?: unknown }).suggestions
+  return Array.isArray(suggestions) ? (suggestions as ErrorSuggestion[]) : []
+}
+
 /**
  * 从 catch 块的 unknown 错误中提取消息
  * 处理 Tauri 抛出的各种错误格式
@@ -215,8 +275,8 @@ export function isCredentialError(err: unknown): boolean {
     return details.code === "InvalidCredentials"
   }
 
-  // 检查 DnsError::InvalidCredentials
-  if (obj.code === "InvalidCredentials") {
+  // 检查 DnsError::InvalidCredentials / DnsError::CredentialsExpired
+  if (obj.code === "InvalidCredentials" || obj.code === "CredentialsExpired") {
     return true
   }
 
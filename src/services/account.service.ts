@@ -4,15 +4,27 @@
 
 import type {
   Account,
+  AccountCapabilities,
+  AccountDeletionImpact,
+  AccountDeletionResult,
+  AccountValidation,
+  AccountValidationSummary,
   ApiResponse,
+  ApiUsageReport,
   BatchDeleteResult,
   CreateAccountRequest,
+  CredentialExpiryCheck,
+  ExpiringCredential,
   ExportAccountsRequest,
   ExportAccountsResponse,
+  ExportDiagnosticsRequest,
+  ExportDiagnosticsResponse,
   ImportAccountsRequest,
   ImportPreview,
   ImportResult,
+  ProviderHealthStatus,
   ProviderInfo,
+  RestoreBackupResult,
   UpdateAccountRequest,
 } from "@/types"
 import { transport } from "./transport"
@@ -26,7 +38,11 @@ class AccountService {
     return transport.invoke("create_account", { request })
   }
 
-  deleteAccount(accountId: string): Promise<ApiResponse<void>> {
+  getAccountDeletionImpact(accountId: string): Promise<ApiResponse<AccountDeletionImpact>> {
+    return transport.invoke("get_account_deletion_impact", { accountId })
+  }
+
+  deleteAccount(accountId: string): Promise<ApiResponse<AccountDeletionResult>> {
     return transport.invoke("delete_account", { accountId })
   }
 
@@ -42,6 +58,36 @@ class AccountService {
     return transport.invoke("list_providers")
   }
 
+  /** 账户的实际能力（首次查询时探测） */
+  getAccountCapabilities(accountId: string): Promise<ApiResponse<AccountCapabilities>> {
+    return transport.invoke("get_account_capabilities", { accountId })
+  }
+
+  /** 重新探测账户的实际能力 */
+  refreshAccountCapabilities(accountId: string): Promise<ApiResponse<AccountCapabilities>> {
+    return transport.invoke("refresh_account_capabilities", { accountId })
+  }
+
+  /** 检查账户服务商 API 的连通性 */
+  checkProviderHealth(accountId: string): Promise<ApiResponse<ProviderHealthStatus>> {
+    return transport.invoke("check_provider_health", { accountId })
+  }
+
+  /** 校验账户凭证，结果写入账户数据 */
+  validateAccount(accountId: string): Promise<ApiResponse<AccountValidation>> {
+    return transport.invoke("validate_account", { accountId })
+  }
+
+  /** 校验全部账户的凭证 */
+  validateAllAccounts(): Promise<ApiResponse<AccountValidationSummary>> {
+    return transport.invoke("validate_all_accounts", {})
+  }
+
+  /** 账户某月（YYYY-MM）的服务商 API 用量报告 */
+  getApiUsageReport(accountId: string, month: string): Promise<ApiResponse<ApiUsageReport>> {
+    return transport.invoke("get_api_usage_report", { accountId, month })
+  }
+
   exportAccounts(request: ExportAccountsRequest): Promise<ApiResponse<ExportAccountsResponse>> {
     return transport.invoke("export_accounts", { request })
   }
@@ -53,6 +99,32 @@ class AccountService {
   importAccounts(request: ImportAccountsRequest): Promise<ApiResponse<ImportResult>> {
     return transport.invoke("import_accounts", { request })
   }
+
+  /** 导出诊断数据（不含凭证，可匿名化），用于提交问题 */
+  exportDiagnostics(
+    request: ExportDiagnosticsRequest
+  ): Promise<ApiResponse<ExportDiagnosticsResponse>> {
+    return transport.invoke("export_diagnostics", { request })
+  }
+
+  /** 导出完整备份（全部账户、凭证与域名元数据，使用密码加密） */
+  exportBackup(password: string): Promise<ApiResponse<ExportAccountsResponse>> {
+    return transport.invoke("export_backup", { password })
+  }
+
+  /** 从完整备份恢复（按账户 ID 覆盖，可重复执行） */
+  importBackup(content: string, password: string): Promise<ApiResponse<RestoreBackupResult>> {
+    return transport.invoke("import_backup", { content, password })
+  }
+
+  listExpiringCredentials(withinDays?: number): Promise<ApiResponse<ExpiringCredential[]>> {
+    return transport.invoke("list_expiring_credentials", { withinDays })
+  }
+
+  /** 订阅后台凭证到期检查的推送（仅 Tauri 端） */
+  onCredentialExpiry(handler: (check: CredentialExpiryCheck) => void): Promise<() => void> {
+    return transport.listen("credential-expiry", handler)
+  }
 }
 
 export const accountService = new AccountService()
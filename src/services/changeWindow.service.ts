@@ -0,0 +1,66 @@
+/**
+ * 变更窗口服务
+ */
+
+import type { CreateChangeWindowRuleRequest, UpdateChangeWindowRuleRequest } from "@/types"
+import { transport } from "./transport"
+
+class ChangeWindowService {
+  /**
+   * 列出所有禁止变更规则
+   */
+  async listRules() {
+    return transport.invoke("list_change_window_rules")
+  }
+
+  /**
+   * 创建禁止变更规则
+   */
+  async createRule(request: CreateChangeWindowRuleRequest) {
+    return transport.invoke("create_change_window_rule", { request })
+  }
+
+  /**
+   * 更新禁止变更规则
+   */
+  async updateRule(request: UpdateChangeWindowRuleRequest) {
+    return transport.invoke("update_change_window_rule", { request })
+  }
+
+  /**
+   * 删除禁止变更规则
+   */
+  async deleteRule(ruleId: string) {
+    return transport.invoke("delete_change_window_rule", { ruleId })
+  }
+
+  /**
+   * 未来若干天内的禁止变更时段（日历展示）
+   */
+  async listUpcomingClosedPeriods(days: number) {
+    return transport.invoke("list_upcoming_closed_periods", { days })
+  }
+
+  /**
+   * 获取当前生效的强制变更
+   */
+  async getOverride() {
+    return transport.invoke("get_change_window_override")
+  }
+
+  /**
+   * 开启限时强制变更
+   */
+  async startOverride(reason: string, durationMinutes: number) {
+    return transport.invoke("start_change_window_override", { reason, durationMinutes })
+  }
+
+  /**
+   * 提前结束强制变更
+   */
+  async clearOverride() {
+    return transport.invoke("clear_change_window_override")
+  }
+}
+
+export const changeWindowService = new ChangeWindowService()
@@ -0,0 +1,34 @@
+/**
+ * 深链接服务（仅 Tauri 桌面端）
+ */
+
+import type { DeepLinkTarget } from "@/types"
+import { transport } from "./transport"
+
+class DeepLinkService {
+  /**
+   * 取出待处理的深链接目标（取出后后端清空）
+   */
+  async takePending() {
+    return transport.invoke("take_pending_deep_link")
+  }
+
+  /**
+   * 生成可分享的深链接
+   */
+  async generate(target: DeepLinkTarget) {
+    return transport.invoke("generate_deep_link", { target })
+  }
+
+  /** 订阅深链接就绪通知，收到后调用 takePending 取出目标 */
+  onDeepLink(handler: () => void): Promise<() => void> {
+    return transport.listen("deep-link", handler)
+  }
+
+  /** 订阅深链接被拒绝的通知（载荷为原因） */
+  onRejected(handler: (reason: string) => void): Promise<() => void> {
+    return transport.listen("deep-link-rejected", handler)
+  }
+}
+
+export const deepLinkService = new DeepLinkService()
@@ -4,12 +4,37 @@
 
 import type {
   ApiResponse,
+  AuditEntry,
+  AuditLogFilter,
+  BatchCreateRequest,
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
+  BatchUpdateRequest,
+  BatchUpdateResult,
+  ChangelogOutput,
+  ChangelogRequest,
   CreateDnsRecordRequest,
   DnsRecord,
+  DnsRecordType,
+  DomainDiffResult,
   PaginatedResponse,
+  RecordChangeEntry,
+  RecordCharacterWarning,
+  RecordCopyRequest,
+  RecordCopyResult,
+  RecordImportPreview,
+  RecordImportRequest,
+  RecordImportResult,
+  RecordListProgress,
+  RecordSetDetail,
+  RecordSort,
+  RecordValidationReport,
+  ReplaceRecordValueRequest,
   UpdateDnsRecordRequest,
+  UpdateRecordSetRequest,
+  UpdateRecordSetResult,
+  ZoneFileImportResult,
 } from "@/types"
 import { transport } from "./transport"
 
@@ -20,6 +45,8 @@ export interface ListDnsRecordsParams {
   pageSize?: number
   keyword?: string | null
   recordType?: string | null
+  /** 不支持服务端排序的服务商在本地排序后分页 */
+  sort?: RecordSort | null
 }
 
 class DnsService {
@@ -27,31 +54,173 @@ class DnsService {
     return transport.invoke("list_dns_records", params)
   }
 
+  /** 拉取域名下的全部记录（自动翻页），进度见 onListProgress */
+  listAllRecords(accountId: string, domainId: string): Promise<ApiResponse<DnsRecord[]>> {
+    return transport.invoke("list_all_dns_records", { accountId, domainId })
+  }
+
+  /** 订阅全量拉取记录的进度（仅 Tauri 端） */
+  onListProgress(handler: (progress: RecordListProgress) => void): Promise<() => void> {
+    return transport.listen("record-list-progress", handler)
+  }
+
+  /** normalize 为 true 时把不可见字符与同形字符替换为 ASCII，所做替换见 warnings */
   createRecord(
     accountId: string,
-    request: CreateDnsRecordRequest
+    request: CreateDnsRecordRequest,
+    normalize = false
   ): Promise<ApiResponse<DnsRecord>> {
-    return transport.invoke("create_dns_record", { accountId, request })
+    return transport.invoke("create_dns_record", { accountId, request, normalize })
+  }
+
+  /** 校验记录（不写入），编辑已有记录时传入 recordId */
+  validateRecord(
+    accountId: string,
+    request: CreateDnsRecordRequest,
+    recordId?: string
+  ): Promise<ApiResponse<RecordValidationReport>> {
+    return transport.invoke("validate_dns_record", { accountId, request, recordId })
   }
 
   updateRecord(
     accountId: string,
     recordId: string,
-    request: UpdateDnsRecordRequest
+    request: UpdateDnsRecordRequest,
+    normalize = false
   ): Promise<ApiResponse<DnsRecord>> {
-    return transport.invoke("update_dns_record", { accountId, recordId, request })
+    return transport.invoke("update_dns_record", { accountId, recordId, request, normalize })
   }
 
   deleteRecord(accountId: string, recordId: string, domainId: string): Promise<ApiResponse<void>> {
     return transport.invoke("delete_dns_record", { accountId, recordId, domainId })
   }
 
+  batchCreateRecords(
+    accountId: string,
+    request: BatchCreateRequest
+  ): Promise<ApiResponse<BatchCreateResult>> {
+    return transport.invoke("batch_create_dns_records", { accountId, request })
+  }
+
+  batchUpdateRecords(
+    accountId: string,
+    request: BatchUpdateRequest
+  ): Promise<ApiResponse<BatchUpdateResult>> {
+    return transport.invoke("batch_update_dns_records", { accountId, request })
+  }
+
+  /** 按值批量替换记录（如服务器换 IP） */
+  replaceRecordValue(
+    accountId: string,
+    request: ReplaceRecordValueRequest
+  ): Promise<ApiResponse<BatchUpdateResult>> {
+    return transport.invoke("replace_dns_record_value", { accountId, request })
+  }
+
+  /** 获取同名同类型的一组记录 */
+  getRecordSet(
+    accountId: string,
+    domainId: string,
+    name: string,
+    recordType: DnsRecordType
+  ): Promise<ApiResponse<RecordSetDetail>> {
+    return transport.invoke("get_dns_record_set", { accountId, domainId, name, recordType })
+  }
+
+  /** 将一组记录整体更新为期望的值集合（values 为空时删除整组） */
+  updateRecordSet(
+    accountId: string,
+    request: UpdateRecordSetRequest
+  ): Promise<ApiResponse<UpdateRecordSetResult>> {
+    return transport.invoke("update_dns_record_set", { accountId, request })
+  }
+
   batchDeleteRecords(
     accountId: string,
     request: BatchDeleteRequest
   ): Promise<ApiResponse<BatchDeleteResult>> {
     return transport.invoke("batch_delete_dns_records", { accountId, request })
   }
+
+  exportZoneFile(accountId: string, domainId: string): Promise<ApiResponse<string>> {
+    return transport.invoke("export_zone_file", { accountId, domainId })
+  }
+
+  importZoneFile(
+    accountId: string,
+    domainId: string,
+    zoneText: string,
+    dryRun: boolean,
+    normalize = false
+  ): Promise<ApiResponse<ZoneFileImportResult>> {
+    return transport.invoke("import_zone_file", {
+      accountId,
+      domainId,
+      zoneText,
+      dryRun,
+      normalize,
+    })
+  }
+
+  previewRecordImport(request: RecordImportRequest): Promise<ApiResponse<RecordImportPreview>> {
+    return transport.invoke("preview_record_import", { request })
+  }
+
+  importRecords(request: RecordImportRequest): Promise<ApiResponse<RecordImportResult>> {
+    return transport.invoke("import_records", { request })
+  }
+
+  /** 复制记录到另一个账户的域名（options.dryRun 时只返回计划） */
+  copyRecords(request: RecordCopyRequest): Promise<ApiResponse<RecordCopyResult>> {
+    return transport.invoke("copy_dns_records", { request })
+  }
+
+  /** 对比两个域名（可跨账户）的记录 */
+  diffDomains(
+    accountA: string,
+    domainA: string,
+    accountB: string,
+    domainB: string
+  ): Promise<ApiResponse<DomainDiffResult>> {
+    return transport.invoke("diff_domains", { accountA, domainA, accountB, domainB })
+  }
+
+  /** 扫描现有记录中的不可见字符与同形字符 */
+  scanRecordCharacters(
+    accountId: string,
+    domainId: string
+  ): Promise<ApiResponse<RecordCharacterWarning[]>> {
+    return transport.invoke("scan_record_characters", { accountId, domainId })
+  }
+
+  /** 分页查询记录变更审计日志（按时间倒序） */
+  listAuditLog(
+    filter: AuditLogFilter,
+    page?: number,
+    pageSize?: number
+  ): Promise<ApiResponse<PaginatedResponse<AuditEntry>>> {
+    return transport.invoke("list_audit_log", { filter, page, pageSize })
+  }
+
+  /** 生成指定时间范围内的变更报告（Markdown 或 JSON） */
+  generateChangelog(request: ChangelogRequest): Promise<ApiResponse<ChangelogOutput>> {
+    return transport.invoke("generate_changelog", { request })
+  }
+
+  /** 查询单条记录的变更历史（按时间倒序） */
+  getRecordHistory(
+    accountId: string,
+    domainId: string,
+    recordId: string,
+    limit?: number
+  ): Promise<ApiResponse<RecordChangeEntry[]>> {
+    return transport.invoke("get_record_history", { accountId, domainId, recordId, limit })
+  }
+
+  /** 将记录恢复到某次变更之前的状态 */
+  restoreRecord(accountId: string, changeId: string): Promise<ApiResponse<DnsRecord>> {
+    return transport.invoke("restore_record", { accountId, changeId })
+  }
 }
 
 export const dnsService = new DnsService()
@@ -0,0 +1,64 @@
+/**
+ * 域名组服务
+ */
+
+import type {
+  CreateDomainGroupRequest,
+  DomainGroupChangeset,
+  DomainGroupMember,
+  UpdateDomainGroupRequest,
+} from "@/types"
+import { transport } from "./transport"
+
+class DomainGroupService {
+  /**
+   * 列出所有域名组
+   */
+  async listGroups() {
+    return transport.invoke("list_domain_groups")
+  }
+
+  /**
+   * 创建域名组
+   */
+  async createGroup(request: CreateDomainGroupRequest) {
+    return transport.invoke("create_domain_group", { request })
+  }
+
+  /**
+   * 更新域名组
+   */
+  async updateGroup(request: UpdateDomainGroupRequest) {
+    return transport.invoke("update_domain_group", { request })
+  }
+
+  /**
+   * 删除域名组
+   */
+  async deleteGroup(groupId: string) {
+    return transport.invoke("delete_domain_group", { groupId })
+  }
+
+  /**
+   * 并排比较各成员的记录
+   */
+  async getRecordsComparison(groupId: string) {
+    return transport.invoke("get_group_records_comparison", { groupId })
+  }
+
+  /**
+   * 以某一成员为准生成同步变更集（不执行）
+   */
+  async previewSync(groupId: string, source: DomainGroupMember) {
+    return transport.invoke("preview_group_sync", { groupId, source })
+  }
+
+  /**
+   * 执行确认后的同步变更集
+   */
+  async applySync(groupId: string, changesets: DomainGroupChangeset[]) {
+    return transport.invoke("apply_group_sync", { groupId, changesets })
+  }
+}
+
+export const domainGroupService = new DomainGroupService()
@@ -0,0 +1,31 @@
+/**
+ * 域名到期监控服务
+ */
+
+import type { DomainExpiryThresholds } from "@/types"
+import { transport } from "./transport"
+
+class DomainMonitorService {
+  /**
+   * 检查所有账号下域名的注册到期时间（逐个查询 WHOIS/RDAP）
+   */
+  async checkDomainExpiry(thresholds?: DomainExpiryThresholds) {
+    return transport.invoke("check_domain_expiry", { thresholds })
+  }
+
+  /**
+   * 列出最近一次检查中需要关注的域名（不重新查询）
+   */
+  async listExpiryWarnings(thresholds?: DomainExpiryThresholds) {
+    return transport.invoke("list_expiry_warnings", { thresholds })
+  }
+
+  /**
+   * 检查账号下域名（根域名与 www）的 SSL 证书
+   */
+  async getCertificateReport(accountId: string) {
+    return transport.invoke("get_certificate_report", { accountId })
+  }
+}
+
+export const domainMonitorService = new DomainMonitorService()
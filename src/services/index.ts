@@ -3,10 +3,16 @@
  */
 
 export { accountService } from "./account.service"
+export { changeWindowService } from "./changeWindow.service"
+export { deepLinkService } from "./deepLink.service"
 export { dnsService, type ListDnsRecordsParams } from "./dns.service"
 export { domainService } from "./domain.service"
+export { domainGroupService } from "./domainGroup.service"
 export { domainMetadataService } from "./domainMetadata.service"
+export { domainMonitorService } from "./domainMonitor.service"
+export { systemService } from "./system.service"
 export { toolboxService } from "./toolbox.service"
+export { writeQueueService } from "./writeQueue.service"
 
 // Transport 相关类型导出
 export type {
@@ -0,0 +1,26 @@
+import { transport } from "./transport"
+
+class SystemService {
+  /**
+   * 列出所有后台任务状态
+   */
+  async listBackgroundJobs() {
+    return transport.invoke("list_background_jobs")
+  }
+
+  /**
+   * 手动触发后台任务
+   */
+  async triggerJob(name: string) {
+    return transport.invoke("trigger_job", { name })
+  }
+
+  /**
+   * 获取 Web 后端的数据库类型与版本（仅 Web 模式可用）
+   */
+  async getDatabaseInfo() {
+    return transport.invoke("get_database_info")
+  }
+}
+
+export const systemService = new SystemService()
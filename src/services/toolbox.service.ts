@@ -1,51 +1,212 @@
+import type { Channel } from "@tauri-apps/api/core"
 import type {
   ApiResponse,
+  CaaCheckResult,
+  DnsBenchmarkProgress,
+  DnsBenchmarkResult,
   DnsLookupResult,
+  DnsLookupTransport,
   DnsPropagationResult,
   DnssecResult,
+  EmailSecurityResult,
+  ExternalEndpoint,
   HttpHeaderCheckRequest,
   HttpHeaderCheckResult,
+  HttpTraceResult,
+  IpLookupBatchEntry,
   IpLookupResult,
+  MailCheckResult,
+  NetworkEnvInfo,
+  PortCheckResult,
+  PortProtocol,
+  PrivacyConfig,
+  PropagationCheckResult,
+  ReverseLookupResult,
   SslCheckResult,
+  SslCheckTarget,
+  ThreatIntelConfig,
+  TraceRouteResult,
   WhoisResult,
 } from "@/types"
 import { transport } from "./transport"
 
 class ToolboxService {
-  whoisLookup(domain: string): Promise<ApiResponse<WhoisResult>> {
-    return transport.invoke("whois_lookup", { domain })
+  /** WHOIS 查询，`forceRefresh` 为 true 时跳过后端缓存 */
+  whoisLookup(domain: string, forceRefresh = false): Promise<ApiResponse<WhoisResult>> {
+    return transport.invoke("whois_lookup", { domain, forceRefresh })
   }
 
   dnsLookup(
     domain: string,
     recordType: string,
-    nameserver: string | null
+    nameserver: string | null,
+    dnsTransport: DnsLookupTransport | null = null
   ): Promise<ApiResponse<DnsLookupResult>> {
-    return transport.invoke("dns_lookup", { domain, recordType, nameserver })
+    return transport.invoke("dns_lookup", {
+      domain,
+      recordType,
+      nameserver,
+      transport: dnsTransport,
+    })
   }
 
-  ipLookup(query: string): Promise<ApiResponse<IpLookupResult>> {
-    return transport.invoke("ip_lookup", { query })
+  reverseLookup(ip: string, nameserver: string | null): Promise<ApiResponse<ReverseLookupResult>> {
+    return transport.invoke("reverse_lookup", { ip, nameserver })
   }
 
-  sslCheck(domain: string, port?: number): Promise<ApiResponse<SslCheckResult>> {
-    return transport.invoke("ssl_check", { domain, port })
+  /** 隐私模式下需要 `confirmExternal` 为 true 才会访问地理位置服务 */
+  ipLookup(query: string, confirmExternal = false): Promise<ApiResponse<IpLookupResult>> {
+    return transport.invoke("ip_lookup", { query, confirmExternal })
+  }
+
+  /** 批量查询 IP/域名/CIDR（最多 100 条），结果以原始查询为键 */
+  ipLookupBatch(
+    queries: string[],
+    confirmExternal = false
+  ): Promise<ApiResponse<Record<string, IpLookupBatchEntry>>> {
+    return transport.invoke("ip_lookup_batch", { queries, confirmExternal })
+  }
+
+  getPrivacyConfig(): Promise<ApiResponse<PrivacyConfig>> {
+    return transport.invoke("get_privacy_config")
+  }
+
+  setPrivacyConfig(config: PrivacyConfig): Promise<ApiResponse<void>> {
+    return transport.invoke("set_privacy_config", { config })
+  }
+
+  /** 列出当前配置下可能访问的第三方端点及用途 */
+  listExternalEndpoints(): Promise<ApiResponse<ExternalEndpoint[]>> {
+    return transport.invoke("list_external_endpoints")
+  }
+
+  getThreatIntelConfig(): Promise<ApiResponse<ThreatIntelConfig>> {
+    return transport.invoke("get_threat_intel_config")
+  }
+
+  setThreatIntelConfig(config: ThreatIntelConfig): Promise<ApiResponse<void>> {
+    return transport.invoke("set_threat_intel_config", { config })
+  }
+
+  /**
+   * SSL 证书检查
+   * `probeVersions` 为 true 时逐一探测 TLS 1.0 ~ 1.3；
+   * `connectAddr` 指定时连接该 IP/主机，SNI 仍使用 `domain`
+   */
+  sslCheck(
+    domain: string,
+    port?: number,
+    probeVersions = false,
+    connectAddr?: string
+  ): Promise<ApiResponse<SslCheckResult>> {
+    return transport.invoke("ssl_check", { domain, port, connectAddr, probeVersions })
+  }
+
+  /** 批量 SSL 检查，结果与输入顺序一致 */
+  sslCheckBatch(targets: SslCheckTarget[]): Promise<ApiResponse<SslCheckResult[]>> {
+    return transport.invoke("ssl_check_batch", { targets })
   }
 
   httpHeaderCheck(request: HttpHeaderCheckRequest): Promise<ApiResponse<HttpHeaderCheckResult>> {
     return transport.invoke("http_header_check", { request })
   }
 
+  /** 跟踪重定向链，maxRedirects 默认 10、最大 20 */
+  httpTrace(url: string, maxRedirects?: number): Promise<ApiResponse<HttpTraceResult>> {
+    return transport.invoke("http_trace", { url, maxRedirects })
+  }
+
+  /** 端口连通性检查，最多 100 个端口，protocol 默认 tcp，timeoutMs 默认 3000、最大 30000 */
+  portCheck(
+    host: string,
+    ports: number[],
+    protocol?: PortProtocol,
+    timeoutMs?: number
+  ): Promise<ApiResponse<PortCheckResult>> {
+    return transport.invoke("port_check", { host, ports, protocol, timeoutMs })
+  }
+
+  /** 路径追踪，maxHops 默认 30、最大 64，probesPerHop 默认 3、最大 10 */
+  traceRoute(
+    host: string,
+    maxHops?: number,
+    probesPerHop?: number
+  ): Promise<ApiResponse<TraceRouteResult>> {
+    return transport.invoke("trace_route", { host, maxHops, probesPerHop })
+  }
+
   dnsPropagationCheck(
     domain: string,
-    recordType: string
+    recordType: string,
+    confirmExternal = false
   ): Promise<ApiResponse<DnsPropagationResult>> {
-    return transport.invoke("dns_propagation_check", { domain, recordType })
+    return transport.invoke("dns_propagation_check", { domain, recordType, confirmExternal })
+  }
+
+  propagationCheck(
+    domain: string,
+    recordType: string,
+    expectedValue: string,
+    confirmExternal = false
+  ): Promise<ApiResponse<PropagationCheckResult>> {
+    return transport.invoke("propagation_check", {
+      domain,
+      recordType,
+      expectedValue,
+      confirmExternal,
+    })
   }
 
   dnssecCheck(domain: string, nameserver: string | null): Promise<ApiResponse<DnssecResult>> {
     return transport.invoke("dnssec_check", { domain, nameserver })
   }
+
+  emailSecurityCheck(
+    domain: string,
+    dkimSelector: string | null
+  ): Promise<ApiResponse<EmailSecurityResult>> {
+    return transport.invoke("email_security_check", { domain, dkimSelector })
+  }
+
+  /** 邮件服务健康检查（MX / SMTP 连通性 / SPF / DMARC），timeoutSecs 为整体超时 */
+  mailCheck(domain: string, timeoutSecs: number | null): Promise<ApiResponse<MailCheckResult>> {
+    return transport.invoke("mail_check", { domain, timeoutSecs })
+  }
+
+  caaCheck(domain: string): Promise<ApiResponse<CaaCheckResult>> {
+    return transport.invoke("caa_check", { domain })
+  }
+
+  dnsBenchmark(
+    benchmarkId: string,
+    domain: string,
+    nameservers: string[],
+    iterations: number,
+    concurrency: number,
+    onProgress: Channel<DnsBenchmarkProgress>
+  ): Promise<ApiResponse<DnsBenchmarkResult>> {
+    return transport.invoke("dns_benchmark", {
+      benchmarkId,
+      domain,
+      nameservers,
+      iterations,
+      concurrency,
+      onProgress,
+    })
+  }
+
+  cancelDnsBenchmark(benchmarkId: string): Promise<ApiResponse<void>> {
+    return transport.invoke("cancel_dns_benchmark", { benchmarkId })
+  }
+
+  getSystemDns(): Promise<ApiResponse<string[]>> {
+    return transport.invoke("get_system_dns")
+  }
+
+  /** 本机网络环境诊断（隐私模式下不探测出口 IP） */
+  networkEnvInfo(): Promise<ApiResponse<NetworkEnvInfo>> {
+    return transport.invoke("network_env_info")
+  }
 }
 
 export const toolboxService = new ToolboxService()
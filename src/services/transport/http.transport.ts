@@ -48,6 +48,11 @@ class HttpTransport implements ITransport {
       clearTimeout(timeoutId)
     }
   }
+
+  /** HTTP 模式没有服务端推送，监听为空操作 */
+  listen<T>(_event: string, _handler: (payload: T) => void): Promise<() => void> {
+    return Promise.resolve(() => {})
+  }
 }
 
 export const transport: ITransport = new HttpTransport()
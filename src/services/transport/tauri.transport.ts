@@ -4,6 +4,7 @@
  */
 
 import { invoke as tauriInvoke } from "@tauri-apps/api/core"
+import { listen as tauriListen } from "@tauri-apps/api/event"
 import type { CommandMap, ITransport } from "./types"
 
 class TauriTransport implements ITransport {
@@ -13,6 +14,10 @@ class TauriTransport implements ITransport {
   ): Promise<CommandMap[K]["result"]> {
     return tauriInvoke<CommandMap[K]["result"]>(command, args ?? {})
   }
+
+  listen<T>(event: string, handler: (payload: T) => void): Promise<() => void> {
+    return tauriListen<T>(event, (e) => handler(e.payload))
+  }
 }
 
 export const transport: ITransport = new TauriTransport()
@@ -3,35 +3,111 @@
  * 抽象 Tauri IPC 和 HTTP 调用的统一接口
  */
 
+import type { Channel } from "@tauri-apps/api/core"
 import type {
   Account,
+  AccountCapabilities,
+  AccountDeletionImpact,
+  AccountDeletionResult,
+  AccountValidation,
+  AccountValidationSummary,
   ApiResponse,
+  ApiUsageReport,
+  AuditEntry,
+  AuditLogFilter,
+  BackgroundJobInfo,
+  BatchCreateRequest,
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
   BatchTagRequest,
   BatchTagResult,
+  BatchUpdateRequest,
+  BatchUpdateResult,
+  CaaCheckResult,
+  ChangelogOutput,
+  ChangelogRequest,
+  ChangeWindowOverride,
+  ChangeWindowRule,
+  ClosedPeriod,
+  CreateChangeWindowRuleRequest,
   CreateAccountRequest,
+  DatabaseInfo,
+  DeepLinkTarget,
   CreateDnsRecordRequest,
+  CreateDomainGroupRequest,
+  DnsBenchmarkProgress,
+  DnsBenchmarkResult,
   DnsLookupResult,
+  DnsLookupTransport,
   DnsPropagationResult,
   DnsRecord,
+  DnsRecordType,
   DnssecResult,
+  EmailSecurityResult,
   Domain,
+  DomainDiffResult,
+  DomainGroup,
+  DomainGroupChangeset,
+  DomainGroupComparison,
+  DomainGroupMember,
+  DomainGroupSyncResult,
   DomainMetadata,
   DomainMetadataUpdate,
+  CertificateReport,
+  DomainExpiryEntry,
+  DomainExpiryReport,
+  DomainExpiryThresholds,
   ExportAccountsRequest,
   ExportAccountsResponse,
+  ExportDiagnosticsRequest,
+  ExportDiagnosticsResponse,
+  ExpiringCredential,
+  ExternalEndpoint,
   HttpHeaderCheckRequest,
   HttpHeaderCheckResult,
+  HttpTraceResult,
   ImportAccountsRequest,
   ImportPreview,
   ImportResult,
+  RecordCopyRequest,
+  RecordCopyResult,
+  RecordImportPreview,
+  RecordImportRequest,
+  RecordImportResult,
+  RecordSetDetail,
+  RecordSort,
+  RecordValidationReport,
+  ReplaceRecordValueRequest,
+  RestoreBackupResult,
+  IpLookupBatchEntry,
   IpLookupResult,
+  MailCheckResult,
+  NetworkEnvInfo,
   PaginatedResponse,
+  PortCheckResult,
+  PortProtocol,
+  PrivacyConfig,
+  PropagationCheckResult,
+  RecordChangeEntry,
+  RecordCharacterWarning,
+  ProviderHealthStatus,
   ProviderInfo,
+  QueuedWrite,
+  ReverseLookupResult,
   SslCheckResult,
+  SslCheckTarget,
+  ThreatIntelConfig,
+  TraceRouteResult,
+  UpdateChangeWindowRuleRequest,
   UpdateDnsRecordRequest,
+  UpdateRecordSetRequest,
+  UpdateRecordSetResult,
+  UpdateDomainGroupRequest,
   WhoisResult,
+  WriteQueueConfig,
+  WriteReplayOutcome,
+  ZoneFileImportResult,
 } from "@/types"
 
 // ============ Command 类型映射 ============
@@ -47,9 +123,13 @@ export interface CommandMap {
     args: { request: CreateAccountRequest }
     result: ApiResponse<Account>
   }
+  get_account_deletion_impact: {
+    args: { accountId: string }
+    result: ApiResponse<AccountDeletionImpact>
+  }
   delete_account: {
     args: { accountId: string }
-    result: ApiResponse<void>
+    result: ApiResponse<AccountDeletionResult>
   }
   batch_delete_accounts: {
     args: { accountIds: string[] }
@@ -59,6 +139,30 @@ export interface CommandMap {
     args: Record<string, never>
     result: ApiResponse<ProviderInfo[]>
   }
+  get_account_capabilities: {
+    args: { accountId: string }
+    result: ApiResponse<AccountCapabilities>
+  }
+  refresh_account_capabilities: {
+    args: { accountId: string }
+    result: ApiResponse<AccountCapabilities>
+  }
+  check_provider_health: {
+    args: { accountId: string }
+    result: ApiResponse<ProviderHealthStatus>
+  }
+  validate_account: {
+    args: { accountId: string }
+    result: ApiResponse<AccountValidation>
+  }
+  validate_all_accounts: {
+    args: Record<string, never>
+    result: ApiResponse<AccountValidationSummary>
+  }
+  get_api_usage_report: {
+    args: { accountId: string; month: string }
+    result: ApiResponse<ApiUsageReport>
+  }
   export_accounts: {
     args: { request: ExportAccountsRequest }
     result: ApiResponse<ExportAccountsResponse>
@@ -71,10 +175,26 @@ export interface CommandMap {
     args: { request: ImportAccountsRequest }
     result: ApiResponse<ImportResult>
   }
+  export_diagnostics: {
+    args: { request: ExportDiagnosticsRequest }
+    result: ApiResponse<ExportDiagnosticsResponse>
+  }
+  export_backup: {
+    args: { password: string }
+    result: ApiResponse<ExportAccountsResponse>
+  }
+  import_backup: {
+    args: { content: string; password: string }
+    result: ApiResponse<RestoreBackupResult>
+  }
   is_restore_completed: {
     args: Record<string, never>
     result: boolean
   }
+  list_expiring_credentials: {
+    args: { withinDays?: number }
+    result: ApiResponse<ExpiringCredential[]>
+  }
 
   // Domain commands
   list_domains: {
@@ -145,55 +265,358 @@ export interface CommandMap {
       pageSize?: number
       keyword?: string | null
       recordType?: string | null
+      sort?: RecordSort | null
     }
     result: ApiResponse<PaginatedResponse<DnsRecord>>
   }
+  list_all_dns_records: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<DnsRecord[]>
+  }
   create_dns_record: {
-    args: { accountId: string; request: CreateDnsRecordRequest }
+    args: { accountId: string; request: CreateDnsRecordRequest; normalize?: boolean }
     result: ApiResponse<DnsRecord>
   }
+  validate_dns_record: {
+    args: { accountId: string; request: CreateDnsRecordRequest; recordId?: string | null }
+    result: ApiResponse<RecordValidationReport>
+  }
   update_dns_record: {
-    args: { accountId: string; recordId: string; request: UpdateDnsRecordRequest }
+    args: {
+      accountId: string
+      recordId: string
+      request: UpdateDnsRecordRequest
+      normalize?: boolean
+    }
     result: ApiResponse<DnsRecord>
   }
   delete_dns_record: {
     args: { accountId: string; recordId: string; domainId: string }
     result: ApiResponse<void>
   }
+  batch_create_dns_records: {
+    args: { accountId: string; request: BatchCreateRequest }
+    result: ApiResponse<BatchCreateResult>
+  }
+  batch_update_dns_records: {
+    args: { accountId: string; request: BatchUpdateRequest }
+    result: ApiResponse<BatchUpdateResult>
+  }
+  replace_dns_record_value: {
+    args: { accountId: string; request: ReplaceRecordValueRequest }
+    result: ApiResponse<BatchUpdateResult>
+  }
+  get_dns_record_set: {
+    args: { accountId: string; domainId: string; name: string; recordType: DnsRecordType }
+    result: ApiResponse<RecordSetDetail>
+  }
+  update_dns_record_set: {
+    args: { accountId: string; request: UpdateRecordSetRequest }
+    result: ApiResponse<UpdateRecordSetResult>
+  }
   batch_delete_dns_records: {
     args: { accountId: string; request: BatchDeleteRequest }
     result: ApiResponse<BatchDeleteResult>
   }
+  export_zone_file: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<string>
+  }
+  import_zone_file: {
+    args: {
+      accountId: string
+      domainId: string
+      zoneText: string
+      dryRun: boolean
+      normalize?: boolean
+    }
+    result: ApiResponse<ZoneFileImportResult>
+  }
+  preview_record_import: {
+    args: { request: RecordImportRequest }
+    result: ApiResponse<RecordImportPreview>
+  }
+  import_records: {
+    args: { request: RecordImportRequest }
+    result: ApiResponse<RecordImportResult>
+  }
+  copy_dns_records: {
+    args: { request: RecordCopyRequest }
+    result: ApiResponse<RecordCopyResult>
+  }
+  diff_domains: {
+    args: { accountA: string; domainA: string; accountB: string; domainB: string }
+    result: ApiResponse<DomainDiffResult>
+  }
+  scan_record_characters: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<RecordCharacterWarning[]>
+  }
+
+  // Audit log commands
+  list_audit_log: {
+    args: { filter?: AuditLogFilter; page?: number; pageSize?: number }
+    result: ApiResponse<PaginatedResponse<AuditEntry>>
+  }
+  generate_changelog: {
+    args: { request: ChangelogRequest }
+    result: ApiResponse<ChangelogOutput>
+  }
+  get_record_history: {
+    args: { accountId: string; domainId: string; recordId: string; limit?: number }
+    result: ApiResponse<RecordChangeEntry[]>
+  }
+  restore_record: {
+    args: { accountId: string; changeId: string }
+    result: ApiResponse<DnsRecord>
+  }
+
+  // Domain group commands
+  list_domain_groups: {
+    args: Record<string, never>
+    result: ApiResponse<DomainGroup[]>
+  }
+  create_domain_group: {
+    args: { request: CreateDomainGroupRequest }
+    result: ApiResponse<DomainGroup>
+  }
+  update_domain_group: {
+    args: { request: UpdateDomainGroupRequest }
+    result: ApiResponse<DomainGroup>
+  }
+  delete_domain_group: {
+    args: { groupId: string }
+    result: ApiResponse<void>
+  }
+  get_group_records_comparison: {
+    args: { groupId: string }
+    result: ApiResponse<DomainGroupComparison>
+  }
+  preview_group_sync: {
+    args: { groupId: string; source: DomainGroupMember }
+    result: ApiResponse<DomainGroupChangeset[]>
+  }
+  apply_group_sync: {
+    args: { groupId: string; changesets: DomainGroupChangeset[] }
+    result: ApiResponse<DomainGroupSyncResult>
+  }
+
+  // Domain monitor commands
+  check_domain_expiry: {
+    args: { thresholds?: DomainExpiryThresholds }
+    result: ApiResponse<DomainExpiryReport>
+  }
+  list_expiry_warnings: {
+    args: { thresholds?: DomainExpiryThresholds }
+    result: ApiResponse<DomainExpiryEntry[]>
+  }
+  get_certificate_report: {
+    args: { accountId: string }
+    result: ApiResponse<CertificateReport>
+  }
+
+  // Change window commands
+  list_change_window_rules: {
+    args: Record<string, never>
+    result: ApiResponse<ChangeWindowRule[]>
+  }
+  create_change_window_rule: {
+    args: { request: CreateChangeWindowRuleRequest }
+    result: ApiResponse<ChangeWindowRule>
+  }
+  update_change_window_rule: {
+    args: { request: UpdateChangeWindowRuleRequest }
+    result: ApiResponse<ChangeWindowRule>
+  }
+  delete_change_window_rule: {
+    args: { ruleId: string }
+    result: ApiResponse<void>
+  }
+  list_upcoming_closed_periods: {
+    args: { days: number }
+    result: ApiResponse<ClosedPeriod[]>
+  }
+  get_change_window_override: {
+    args: Record<string, never>
+    result: ApiResponse<ChangeWindowOverride | null>
+  }
+  start_change_window_override: {
+    args: { reason: string; durationMinutes: number }
+    result: ApiResponse<ChangeWindowOverride>
+  }
+  clear_change_window_override: {
+    args: Record<string, never>
+    result: ApiResponse<void>
+  }
+
+  // Deep link commands
+  take_pending_deep_link: {
+    args: Record<string, never>
+    result: ApiResponse<DeepLinkTarget | null>
+  }
+  generate_deep_link: {
+    args: { target: DeepLinkTarget }
+    result: ApiResponse<string>
+  }
+
+  // Offline write queue commands
+  get_write_queue_config: {
+    args: Record<string, never>
+    result: ApiResponse<WriteQueueConfig>
+  }
+  set_write_queue_config: {
+    args: { config: WriteQueueConfig }
+    result: ApiResponse<void>
+  }
+  list_queued_writes: {
+    args: Record<string, never>
+    result: ApiResponse<QueuedWrite[]>
+  }
+  discard_queued_writes: {
+    args: { ids: string[] }
+    result: ApiResponse<number>
+  }
+  replay_queued_writes: {
+    args: { ids: string[]; force?: boolean }
+    result: ApiResponse<WriteReplayOutcome[]>
+  }
 
   // Toolbox commands
   whois_lookup: {
-    args: { domain: string }
+    args: { domain: string; forceRefresh?: boolean }
     result: ApiResponse<WhoisResult>
   }
   dns_lookup: {
-    args: { domain: string; recordType: string; nameserver: string | null }
+    args: {
+      domain: string
+      recordType: string
+      nameserver: string | null
+      transport?: DnsLookupTransport | null
+    }
     result: ApiResponse<DnsLookupResult>
   }
+  reverse_lookup: {
+    args: { ip: string; nameserver: string | null }
+    result: ApiResponse<ReverseLookupResult>
+  }
   ip_lookup: {
-    args: { query: string }
+    args: { query: string; confirmExternal?: boolean }
     result: ApiResponse<IpLookupResult>
   }
+  ip_lookup_batch: {
+    args: { queries: string[]; confirmExternal?: boolean }
+    result: ApiResponse<Record<string, IpLookupBatchEntry>>
+  }
+  get_threat_intel_config: {
+    args: Record<string, never>
+    result: ApiResponse<ThreatIntelConfig>
+  }
+  set_threat_intel_config: {
+    args: { config: ThreatIntelConfig }
+    result: ApiResponse<void>
+  }
+  get_privacy_config: {
+    args: Record<string, never>
+    result: ApiResponse<PrivacyConfig>
+  }
+  set_privacy_config: {
+    args: { config: PrivacyConfig }
+    result: ApiResponse<void>
+  }
+  list_external_endpoints: {
+    args: Record<string, never>
+    result: ApiResponse<ExternalEndpoint[]>
+  }
   ssl_check: {
-    args: { domain: string; port?: number }
+    args: { domain: string; port?: number; connectAddr?: string; probeVersions?: boolean }
     result: ApiResponse<SslCheckResult>
   }
+  ssl_check_batch: {
+    args: { targets: SslCheckTarget[] }
+    result: ApiResponse<SslCheckResult[]>
+  }
   http_header_check: {
     args: { request: HttpHeaderCheckRequest }
     result: ApiResponse<HttpHeaderCheckResult>
   }
+  http_trace: {
+    args: { url: string; maxRedirects?: number }
+    result: ApiResponse<HttpTraceResult>
+  }
+  port_check: {
+    args: { host: string; ports: number[]; protocol?: PortProtocol; timeoutMs?: number }
+    result: ApiResponse<PortCheckResult>
+  }
+  trace_route: {
+    args: { host: string; maxHops?: number; probesPerHop?: number }
+    result: ApiResponse<TraceRouteResult>
+  }
   dns_propagation_check: {
-    args: { domain: string; recordType: string }
+    args: { domain: string; recordType: string; confirmExternal?: boolean }
     result: ApiResponse<DnsPropagationResult>
   }
+  propagation_check: {
+    args: {
+      domain: string
+      recordType: string
+      expectedValue: string
+      confirmExternal?: boolean
+    }
+    result: ApiResponse<PropagationCheckResult>
+  }
   dnssec_check: {
     args: { domain: string; nameserver: string | null }
     result: ApiResponse<DnssecResult>
   }
+  email_security_check: {
+    args: { domain: string; dkimSelector: string | null }
+    result: ApiResponse<EmailSecurityResult>
+  }
+  mail_check: {
+    args: { domain: string; timeoutSecs: number | null }
+    result: ApiResponse<MailCheckResult>
+  }
+  caa_check: {
+    args: { domain: string }
+    result: ApiResponse<CaaCheckResult>
+  }
+  dns_benchmark: {
+    args: {
+      benchmarkId: string
+      domain: string
+      nameservers: string[]
+      iterations: number
+      concurrency: number
+      onProgress: Channel<DnsBenchmarkProgress>
+    }
+    result: ApiResponse<DnsBenchmarkResult>
+  }
+  cancel_dns_benchmark: {
+    args: { benchmarkId: string }
+    result: ApiResponse<void>
+  }
+  get_system_dns: {
+    args: Record<string, never>
+    result: ApiResponse<string[]>
+  }
+  network_env_info: {
+    args: Record<string, never>
+    result: ApiResponse<NetworkEnvInfo>
+  }
+
+  // Background job commands
+  list_background_jobs: {
+    args: Record<string, never>
+    result: ApiResponse<BackgroundJobInfo[]>
+  }
+  trigger_job: {
+    args: { name: string }
+    result: ApiResponse<void>
+  }
+  get_database_info: {
+    args: Record<string, never>
+    result: ApiResponse<DatabaseInfo>
+  }
 }
 
 // ============ 类型工具 ============
@@ -218,4 +641,7 @@ export interface ITransport {
     command: K,
     args: CommandMap[K]["args"]
   ): Promise<CommandMap[K]["result"]>
+
+  /** 监听后端推送的事件，返回取消监听函数 */
+  listen<T>(event: string, handler: (payload: T) => void): Promise<() => void>
 }
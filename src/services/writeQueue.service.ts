@@ -0,0 +1,45 @@
+/**
+ * 离线写队列服务（仅桌面/移动端）
+ */
+
+import type { WriteQueueConfig } from "@/types"
+import { transport } from "./transport"
+
+class WriteQueueService {
+  /**
+   * 获取离线写队列配置
+   */
+  async getConfig() {
+    return transport.invoke("get_write_queue_config")
+  }
+
+  /**
+   * 保存离线写队列配置
+   */
+  async setConfig(config: WriteQueueConfig) {
+    return transport.invoke("set_write_queue_config", { config })
+  }
+
+  /**
+   * 列出待重放的写操作
+   */
+  async list() {
+    return transport.invoke("list_queued_writes")
+  }
+
+  /**
+   * 丢弃指定的写操作
+   */
+  async discard(ids: string[]) {
+    return transport.invoke("discard_queued_writes", { ids })
+  }
+
+  /**
+   * 重放指定的写操作；force 为 true 时覆盖冲突的记录
+   */
+  async replay(ids: string[], force = false) {
+    return transport.invoke("replay_queued_writes", { ids, force })
+  }
+}
+
+export const writeQueueService = new WriteQueueService()
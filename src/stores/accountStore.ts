@@ -26,6 +26,7 @@ interface AccountState {
   isDeleting: boolean
   isUpdating: boolean
   isRestoring: boolean
+  isValidating: boolean
   error: string | null
   fieldErrors: Record<string, string> // 字段级错误
   isExportDialogOpen: boolean
@@ -48,6 +49,62 @@ interface AccountState {
   openImportDialog: () => void
   closeImportDialog: () => void
   checkRestoreStatus: () => Promise<void>
+  validateAccount: (id: string) => Promise<void>
+  validateAllAccounts: () => Promise<void>
+
+  validateAccount: async (id) => {
+    set({ isValidating: true })
+    try {
+      const response = await accountService.validateAccount(id)
+      const data = response.data
+      if (response.success && data) {
+        if (data.credentialStatus === "valid") {
+          toast.success(i18n.t("account.validateValid", { name: data.accountName }))
+        } else if (data.credentialStatus === "invalid") {
+          toast.error(i18n.t("account.validateInvalid", { name: data.accountName }))
+        } else {
+          toast.warning(
+            i18n.t("account.validateUnknown", { name: data.accountName, error: data.error ?? "" })
+          )
+        }
+        // 校验结果与账户状态已写入，重新获取列表
+        await get().fetchAccounts()
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch (err) {
+      toast.error(extractErrorMessage(err))
+    } finally {
+      set({ isValidating: false })
+    }
+  },
+
+  validateAllAccounts: async () => {
+    set({ isValidating: true })
+    try {
+      const response = await accountService.validateAllAccounts()
+      const data = response.data
+      if (response.success && data) {
+        const summary = i18n.t("account.validateAllSummary", {
+          valid: data.validCount,
+          invalid: data.invalidCount,
+          unknown: data.unknownCount,
+        })
+        if (data.invalidCount > 0 || data.unknownCount > 0) {
+          toast.warning(summary)
+        } else {
+          toast.success(summary)
+        }
+        await get().fetchAccounts()
+      } else {
+        toast.error(getErrorMessage(response.error))
+      }
+    } catch (err) {
+      toast.error(extractErrorMessage(err))
+    } finally {
+      set({ isValidating: false })
+    }
+  },
 
   // 批量选择方法
   toggleAccountSelection: (id: string) => void
@@ -65,6 +122,7 @@ export const useAccountStore = create<AccountState>((set, get) => ({
   isDeleting: false,
   isUpdating: false,
   isRestoring: false,
+  isValidating: false,
   error: null,
   fieldErrors: {},
   isExportDialogOpen: false,
@@ -122,6 +180,9 @@ export const useAccountStore = create<AccountState>((set, get) => ({
       if (response.success && data) {
         set((state) => ({ accounts: [...state.accounts, data] }))
         toast.success(i18n.t("account.createSuccess", { name: data.name }))
+        for (const warning of response.warnings ?? []) {
+          toast.warning(warning)
+        }
         return data
       }
       // 处理凭证验证错误（字段级）
@@ -183,6 +244,9 @@ export const useAccountStore = create<AccountState>((set, get) => ({
           accounts: state.accounts.map((a) => (a.id === request.id ? data : a)),
         }))
         toast.success(i18n.t("account.updateSuccess", { name: data.name }))
+        for (const warning of response.warnings ?? []) {
+          toast.warning(warning)
+        }
         return data
       }
       // 处理凭证验证错误（字段级）
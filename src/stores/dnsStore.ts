@@ -3,10 +3,12 @@ import { create } from "zustand"
 import { PAGINATION } from "@/constants"
 import i18n from "@/i18n"
 import { extractErrorMessage, getErrorMessage } from "@/lib/error"
+import { toastError } from "@/lib/error-toast"
 import { logger } from "@/lib/logger"
 import { dnsService } from "@/services"
 import { useAccountStore } from "@/stores/accountStore"
 import type {
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
   CreateDnsRecordRequest,
@@ -14,6 +16,13 @@ import type {
   UpdateDnsRecordRequest,
 } from "@/types"
 
+/** 展示后端附带的警告（如域名尚未激活） */
+const showWarnings = (warnings?: string[]) => {
+  for (const warning of warnings ?? []) {
+    toast.warning(warning)
+  }
+}
+
 /**
  * 获取 DNS 记录的分页大小
  *
@@ -64,6 +73,11 @@ interface DnsState {
   setKeyword: (keyword: string) => void
   setRecordType: (recordType: string) => void
   createRecord: (accountId: string, request: CreateDnsRecordRequest) => Promise<DnsRecord | null>
+  batchCreateRecords: (
+    accountId: string,
+    domainId: string,
+    records: CreateDnsRecordRequest[]
+  ) => Promise<BatchCreateResult | null>
   updateRecord: (
     accountId: string,
     recordId: string,
@@ -153,7 +167,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
       } else {
         const msg = getErrorMessage(response.error)
         set({ error: msg })
-        toast.error(msg)
+        toastError(response.error, msg)
       }
     } catch (err) {
       if (get().currentDomainId !== domainId) {
@@ -161,7 +175,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
       }
       const msg = extractErrorMessage(err)
       set({ error: msg })
-      toast.error(msg)
+      toastError(err, msg)
     } finally {
       if (get().currentDomainId === domainId) {
         set({ isLoading: false })
@@ -253,7 +267,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
       } else {
         const msg = getErrorMessage(response.error)
         set({ error: msg })
-        toast.error(msg)
+        toastError(response.error, msg)
       }
     } catch (err) {
       if (get().currentDomainId !== domainId) {
@@ -261,7 +275,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
       }
       const msg = extractErrorMessage(err)
       set({ error: msg })
-      toast.error(msg)
+      toastError(err, msg)
     } finally {
       if (get().currentDomainId === domainId) {
         set({ isLoading: false })
@@ -280,16 +294,54 @@ export const useDnsStore = create<DnsState>((set, get) => ({
           totalCount: state.totalCount + 1,
         }))
         toast.success(i18n.t("dns.createSuccess", { name: data.name }))
+        showWarnings(response.warnings)
         return data
       }
       const msg = getErrorMessage(response.error)
       set({ error: msg })
-      toast.error(msg)
+      toastError(response.error, msg)
+      return null
+    } catch (err) {
+      const msg = extractErrorMessage(err)
+      set({ error: msg })
+      toastError(err, msg)
+      return null
+    } finally {
+      set({ isLoading: false })
+    }
+  },
+
+  batchCreateRecords: async (accountId, domainId, records) => {
+    set({ isLoading: true, error: null })
+    try {
+      const response = await dnsService.batchCreateRecords(accountId, { domainId, records })
+      const result = response.data
+      if (response.success && result) {
+        set((state) => ({
+          records: [...state.records, ...result.createdRecords],
+          totalCount: state.totalCount + result.successCount,
+        }))
+        if (result.failedCount === 0) {
+          toast.success(i18n.t("dns.batchCreateSuccess", { count: result.successCount }))
+        } else {
+          toast.warning(
+            i18n.t("dns.batchCreatePartial", {
+              success: result.successCount,
+              failed: result.failedCount,
+            })
+          )
+        }
+        showWarnings(response.warnings)
+        return result
+      }
+      const msg = getErrorMessage(response.error)
+      set({ error: msg })
+      toastError(response.error, msg)
       return null
     } catch (err) {
       const msg = extractErrorMessage(err)
       set({ error: msg })
-      toast.error(msg)
+      toastError(err, msg)
       return null
     } finally {
       set({ isLoading: false })
@@ -306,12 +358,13 @@ export const useDnsStore = create<DnsState>((set, get) => ({
           records: state.records.map((r) => (r.id === recordId ? data : r)),
         }))
         toast.success(i18n.t("dns.updateSuccess"))
+        showWarnings(response.warnings)
         return true
       }
       toast.error(i18n.t("dns.updateFailed"))
       return false
     } catch (err) {
-      toast.error(extractErrorMessage(err))
+      toastError(err)
       return false
     } finally {
       set({ isLoading: false })
@@ -328,12 +381,13 @@ export const useDnsStore = create<DnsState>((set, get) => ({
           totalCount: Math.max(0, state.totalCount - 1),
         }))
         toast.success(i18n.t("dns.deleteSuccess"))
+        showWarnings(response.warnings)
         return true
       }
       toast.error(i18n.t("dns.deleteFailed"))
       return false
     } catch (err) {
-      toast.error(extractErrorMessage(err))
+      toastError(err)
       return false
     } finally {
       set({ isDeleting: false })
@@ -416,12 +470,13 @@ export const useDnsStore = create<DnsState>((set, get) => ({
             })
           )
         }
+        showWarnings(response.warnings)
         return result
       }
-      toast.error(getErrorMessage(response.error))
+      toastError(response.error)
       return null
     } catch (err) {
-      toast.error(extractErrorMessage(err))
+      toastError(err)
       return null
     } finally {
       set({ isBatchDeleting: false })
@@ -2,6 +2,7 @@ import { toast } from "sonner"
 import { create } from "zustand"
 import { PAGINATION, TIMING } from "@/constants"
 import i18n from "@/i18n"
+import type { DomainSortKey, DomainStatusFilter } from "@/lib/domain-status"
 import { extractErrorMessage, getErrorMessage, isCredentialError } from "@/lib/error"
 import { logger } from "@/lib/logger"
 import { domainMetadataService, domainService } from "@/services"
@@ -299,6 +300,10 @@ interface DomainState {
   // 标签筛选状态（会话内保持，不持久化）
   selectedTags: Set<string>
 
+  // 派生状态筛选与排序（会话内保持，不持久化）
+  statusFilter: DomainStatusFilter
+  domainSort: DomainSortKey
+
   // 批量选择状态（会话内保持，不持久化）
   selectedDomainKeys: Set<string> // "accountId::domainId" 格式
   isBatchMode: boolean
@@ -341,6 +346,10 @@ interface DomainState {
   clearTagFilters: () => void
   getAllUsedTags: () => string[]
 
+  // 派生状态筛选与排序
+  setStatusFilter: (filter: DomainStatusFilter) => void
+  setDomainSort: (sort: DomainSortKey) => void
+
   // 批量选择操作
   toggleBatchMode: () => void
   toggleDomainSelection: (accountId: string, domainId: string) => void
@@ -369,6 +378,8 @@ export const useDomainStore = create<DomainState>((set, get) => ({
   expandedAccounts: new Set(),
   scrollPosition: initialCache.scrollPosition,
   selectedTags: new Set(),
+  statusFilter: "all",
+  domainSort: "default",
   selectedDomainKeys: new Set(),
   isBatchMode: false,
   isBatchOperating: false,
@@ -804,6 +815,14 @@ export const useDomainStore = create<DomainState>((set, get) => ({
     set({ selectedTags: new Set() })
   },
 
+  setStatusFilter: (filter) => {
+    set({ statusFilter: filter })
+  },
+
+  setDomainSort: (sort) => {
+    set({ domainSort: sort })
+  },
+
   // 获取所有使用过的标签
   getAllUsedTags: () => {
     const { domainsByAccount } = get()
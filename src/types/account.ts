@@ -1,5 +1,8 @@
 /** 账号状态 */
-export type AccountStatus = "active" | "error"
+export type AccountStatus = "active" | "error" | "credentials_expired"
+
+/** 凭证校验结果 */
+export type CredentialStatus = "valid" | "invalid" | "unknown"
 
 /** 账号基础信息 */
 export interface Account {
@@ -10,6 +13,51 @@ export interface Account {
   updatedAt: string
   status?: AccountStatus
   error?: string
+  /** 凭证（API token）到期时间 */
+  credentialsExpiresAt?: string
+  /** 最近一次凭证校验结果 */
+  credentialStatus?: CredentialStatus
+  /** 最近一次凭证校验时间 */
+  lastValidatedAt?: string
+}
+
+/** 账号当前是否不可用（加载失败或凭证已过期） */
+export function isAccountUnavailable(account: Pick<Account, "status">): boolean {
+  return account.status === "error" || account.status === "credentials_expired"
+}
+
+/** 即将到期（或已过期）的账号凭证 */
+export interface ExpiringCredential {
+  accountId: string
+  accountName: string
+  provider: string
+  expiresAt: string
+  daysRemaining: number
+  expired: boolean
+}
+
+/** 凭证到期检查结果（后台任务通过 credential-expiry 事件推送） */
+export interface CredentialExpiryCheck {
+  reminders: ExpiringCredential[]
+  expired: ExpiringCredential[]
+}
+
+/** 单个账号的凭证校验结果 */
+export interface AccountValidation {
+  accountId: string
+  accountName: string
+  provider: string
+  credentialStatus: CredentialStatus
+  validatedAt: string
+  error?: string
+}
+
+/** 批量凭证校验汇总 */
+export interface AccountValidationSummary {
+  validCount: number
+  invalidCount: number
+  unknownCount: number
+  results: AccountValidation[]
 }
 
 // ============ Provider 凭证类型（v1.7.0 类型安全重构）============
@@ -27,6 +75,7 @@ export type ProviderCredentials =
       credentials: {
         access_key_id: string
         access_key_secret: string
+        api_variant?: string
       }
     }
   | {
@@ -43,12 +92,36 @@ export type ProviderCredentials =
         secret_access_key: string
       }
     }
+  | {
+      provider: "route53"
+      credentials: {
+        access_key_id: string
+        secret_access_key: string
+        region: string
+        role_arn?: string
+      }
+    }
+  | {
+      provider: "gcp_dns"
+      credentials: {
+        project_id: string
+        service_account_json: string
+      }
+    }
+  | {
+      provider: "digitalocean"
+      credentials: {
+        token: string
+      }
+    }
 
 /** 创建账号请求 */
 export interface CreateAccountRequest {
   name: string
   provider: string
   credentials: ProviderCredentials
+  /** 凭证到期时间（可选，Cloudflare 会自动获取） */
+  credentialsExpiresAt?: string
 }
 
 /** 更新账号请求 */
@@ -56,6 +129,8 @@ export interface UpdateAccountRequest {
   id: string
   name?: string
   credentials?: ProviderCredentials
+  /** 凭证到期时间 */
+  credentialsExpiresAt?: string
 }
 
 // ============ 导入导出相关类型 ============
@@ -65,6 +140,8 @@ export interface ExportAccountsRequest {
   accountIds: string[]
   encrypt: boolean
   password?: string
+  /** 可复现导出：保留原账户 ID、省略时间戳 */
+  reproducible?: boolean
 }
 
 /** 导出响应 */
@@ -73,6 +150,24 @@ export interface ExportAccountsResponse {
   suggestedFilename: string
 }
 
+/** 诊断数据导出请求 */
+export interface ExportDiagnosticsRequest {
+  accountIds: string[]
+  /** 域名、IP、TXT 值、账户名与 ID 替换为假名 */
+  anonymize: boolean
+  /** 匿名化映射表的加密密码，不提供时不生成映射表 */
+  mappingPassword?: string
+}
+
+/** 诊断数据导出响应 */
+export interface ExportDiagnosticsResponse {
+  content: string
+  suggestedFilename: string
+  /** 加密的匿名化映射表，需单独保存在本地 */
+  mapping?: string
+  mappingFilename?: string
+}
+
 /** 导入请求 */
 export interface ImportAccountsRequest {
   content: string
@@ -96,6 +191,8 @@ export interface ImportPreviewAccount {
 /** 导入结果 */
 export interface ImportResult {
   successCount: number
+  /** 与已有账户重复（服务商与凭证相同）而跳过的账号数量 */
+  skippedCount: number
   failures: ImportFailure[]
 }
 
@@ -104,3 +201,39 @@ export interface ImportFailure {
   name: string
   reason: string
 }
+
+/** 完整备份恢复结果 */
+export interface RestoreBackupResult {
+  createdAccounts: number
+  /** 按 ID 覆盖更新的已有账户数量 */
+  updatedAccounts: number
+  restoredMetadata: number
+  failures: ImportFailure[]
+}
+
+// ============ 账号删除影响面分析 ============
+
+/** 受影响的数据类别 */
+export type DeletionImpactKind = "domains" | "favorites" | "tags" | "notes" | "credentials"
+
+/** 单项影响 */
+export interface DeletionImpactItem {
+  kind: DeletionImpactKind
+  /** 数量（null 表示查询失败） */
+  count: number | null
+  action: "delete" | "keep"
+  error?: string
+}
+
+/** 账号删除影响面分析结果 */
+export interface AccountDeletionImpact {
+  accountId: string
+  accountName: string
+  items: DeletionImpactItem[]
+}
+
+/** 账号删除结果 */
+export interface AccountDeletionResult {
+  accountId: string
+  cleanup: { kind: DeletionImpactKind; success: boolean; error?: string }[]
+}
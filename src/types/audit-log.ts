@@ -0,0 +1,90 @@
+import type { DnsRecord } from "./dns"
+
+/** 审计操作类型（批量操作按记录逐条记录） */
+export type AuditOperation =
+  | "create"
+  | "update"
+  | "delete"
+  | "batchCreate"
+  | "batchUpdate"
+  | "batchDelete"
+
+/** 变更来源 */
+export type ChangeSource = "manual" | "ddns" | "scheduled"
+
+/** 一次记录变更的审计记录 */
+export interface AuditEntry {
+  id: string
+  /** RFC3339 时间 */
+  timestamp: string
+  accountId: string
+  domainId: string
+  operation: AuditOperation
+  source: ChangeSource
+  recordId?: string
+  /** 变更前的记录（创建时缺省） */
+  before?: DnsRecord
+  /** 变更后的记录（删除或失败时缺省） */
+  after?: DnsRecord
+  success: boolean
+  error?: string
+  /** 强制变更原因（变更发生在强制变更生效期间） */
+  overrideReason?: string
+}
+
+/** 审计日志查询条件（缺省字段不过滤） */
+export interface AuditLogFilter {
+  accountId?: string
+  domainId?: string
+  recordId?: string
+  /** 起始时间（含，RFC3339） */
+  since?: string
+  /** 截止时间（不含，RFC3339） */
+  until?: string
+}
+
+/** 记录变更类型（批量操作归入对应的单条类型） */
+export type RecordChangeType = "create" | "update" | "delete"
+
+/** 单条记录的变更历史项 */
+export interface RecordChangeEntry {
+  /** 对应审计记录 ID，恢复时使用 */
+  id: string
+  accountId: string
+  domainId: string
+  recordId: string
+  changeType: RecordChangeType
+  before?: DnsRecord
+  after?: DnsRecord
+  /** RFC3339 时间 */
+  changedAt: string
+}
+
+/** 变更报告输出格式 */
+export type ChangelogFormat = "markdown" | "json"
+
+/** 按来源过滤：automated 包含 DDNS 与计划任务 */
+export type ChangelogSourceFilter = "all" | "manual" | "automated"
+
+/** 生成变更报告请求（时间均为 UTC） */
+export interface ChangelogRequest {
+  accountId?: string
+  domainId?: string
+  /** 起始时间（含，RFC3339） */
+  since: string
+  /** 截止时间（不含，RFC3339） */
+  until: string
+  format?: ChangelogFormat
+  source?: ChangelogSourceFilter
+  /** 最多列出的变更条数（默认 500） */
+  maxEntries?: number
+}
+
+/** 变更报告生成结果 */
+export interface ChangelogOutput {
+  format: ChangelogFormat
+  /** 按 format 渲染的报告正文 */
+  content: string
+  totalChanges: number
+  truncated: boolean
+}
@@ -0,0 +1,51 @@
+/** 禁止变更时段的时间规则 */
+export type ChangeWindowSchedule =
+  /** 周期规则：cron（分 时 日 月 周）命中的时刻开始禁止，持续 durationMinutes */
+  | { type: "recurring"; cron: string; durationMinutes: number }
+  /** 一次性日期区间（规则时区下的本地时间，如 2026-11-11T00:00:00，左闭右开） */
+  | { type: "oneOff"; start: string; end: string }
+
+/** 禁止变更规则 */
+export interface ChangeWindowRule {
+  id: string
+  name: string
+  enabled: boolean
+  /** UTC 偏移，如 +08:00 */
+  utcOffset: string
+  schedule: ChangeWindowSchedule
+  createdAt: string
+  updatedAt: string
+}
+
+/** 创建禁止变更规则请求 */
+export interface CreateChangeWindowRuleRequest {
+  name: string
+  enabled?: boolean
+  utcOffset: string
+  schedule: ChangeWindowSchedule
+}
+
+/** 更新禁止变更规则请求（缺省字段不修改） */
+export interface UpdateChangeWindowRuleRequest {
+  id: string
+  name?: string
+  enabled?: boolean
+  utcOffset?: string
+  schedule?: ChangeWindowSchedule
+}
+
+/** 一段禁止变更时段（RFC3339，UTC） */
+export interface ClosedPeriod {
+  ruleId: string
+  ruleName: string
+  start: string
+  /** 结束时间（不含） */
+  end: string
+}
+
+/** 强制变更（禁止时段内放行写操作） */
+export interface ChangeWindowOverride {
+  reason: string
+  startedAt: string
+  expiresAt: string
+}
@@ -0,0 +1,15 @@
+import type { DnsRecordType } from "./dns"
+
+/** 深链接界面操作（只做导航与预填，不直接写入） */
+export type DeepLinkAction = "create_record" | "filter_records"
+
+/** 深链接目标：dnsorch://domain/{accountId}/{domainId}?action=...&type=...&name=...&value=... */
+export interface DeepLinkTarget {
+  accountId: string
+  domainId: string
+  action?: DeepLinkAction
+  recordType?: DnsRecordType
+  name?: string
+  /** 记录值，仅 create_record 可用 */
+  value?: string
+}
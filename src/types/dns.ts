@@ -14,6 +14,23 @@ export type RecordData =
   | { type: "NS"; content: { nameserver: string } }
   | { type: "SRV"; content: { priority: number; weight: number; port: number; target: string } }
   | { type: "CAA"; content: { flags: number; tag: string; value: string } }
+  | { type: "SOA"; content: SoaRecordContent }
+
+/**
+ * SOA 记录内容
+ *
+ * SOA 由 DNS 服务商创建，不在 `DnsRecordType` 中（不能新建）；
+ * 仅声明 `soaEditing` 的服务商允许修改计时参数与 rname，serial 由服务商维护。
+ */
+export interface SoaRecordContent {
+  mname: string
+  rname: string
+  serial: number
+  refresh: number
+  retry: number
+  expire: number
+  minimum: number
+}
 
 /** DNS 记录 */
 export interface DnsRecord {
@@ -27,6 +44,30 @@ export interface DnsRecord {
   updatedAt?: string
 }
 
+/** 记录列表的排序字段 */
+export type RecordSortField = "name" | "type" | "value" | "ttl"
+
+/** 排序方向 */
+export type SortOrder = "asc" | "desc"
+
+/** 记录列表排序 */
+export interface RecordSort {
+  field: RecordSortField
+  order?: SortOrder
+}
+
+/** 全量拉取记录的进度（record-list-progress 事件载荷） */
+export interface RecordListProgress {
+  accountId: string
+  domainId: string
+  /** 已拉取到的页码（从 1 开始） */
+  page: number
+  /** 已拉取的记录数 */
+  fetched: number
+  /** 服务商报告的记录总数 */
+  total: number
+}
+
 /** 创建 DNS 记录请求 */
 export interface CreateDnsRecordRequest {
   domainId: string
@@ -45,6 +86,88 @@ export interface UpdateDnsRecordRequest {
   proxied?: boolean
 }
 
+/** 批量创建请求 */
+export interface BatchCreateRequest {
+  domainId: string
+  records: CreateDnsRecordRequest[]
+  /** 把不可见字符与同形字符替换为 ASCII（默认含此类字符的记录直接失败） */
+  normalize?: boolean
+}
+
+/** 批量创建结果（createdRecords 按请求顺序排列） */
+export interface BatchCreateResult {
+  successCount: number
+  failedCount: number
+  createdRecords: DnsRecord[]
+  failures: BatchCreateFailure[]
+  /** 规范化时做的字符替换 */
+  warnings?: string[]
+}
+
+/** 批量创建失败项 */
+export interface BatchCreateFailure {
+  /** 对应 records 中的下标 */
+  requestIndex: number
+  recordName: string
+  reason: string
+  /** 失败的原始请求（可用于选择性重试） */
+  request: CreateDnsRecordRequest
+}
+
+/** 同名同类型下值或 TTL 不同的一对记录 */
+export interface RecordPair {
+  /** 相对名称（小写，根域为 `@`） */
+  name: string
+  recordType: DnsRecordType
+  a: DnsRecord
+  b: DnsRecord
+}
+
+/** 两个域名的记录对比结果 */
+export interface DomainDiffResult {
+  onlyInA: DnsRecord[]
+  onlyInB: DnsRecord[]
+  different: RecordPair[]
+}
+
+/** 批量更新请求项 */
+export interface BatchUpdateItem {
+  recordId: string
+  request: UpdateDnsRecordRequest
+}
+
+/** 批量更新请求 */
+export interface BatchUpdateRequest {
+  domainId: string
+  updates: BatchUpdateItem[]
+  /** 同 BatchCreateRequest.normalize */
+  normalize?: boolean
+}
+
+/** 按值批量替换请求（IP 按地址比较，主机名忽略大小写和末尾的点） */
+export interface ReplaceRecordValueRequest {
+  domainId: string
+  oldValue: string
+  newValue: string
+  recordType?: DnsRecordType | null
+}
+
+/** 批量更新结果（updatedRecords 按请求顺序排列） */
+export interface BatchUpdateResult {
+  successCount: number
+  failedCount: number
+  updatedRecords: DnsRecord[]
+  failures: BatchUpdateFailure[]
+  /** 规范化时做的字符替换 */
+  warnings?: string[]
+}
+
+/** 批量更新失败项 */
+export interface BatchUpdateFailure {
+  recordId: string
+  reason: string
+}
+
 /** 批量删除请求 */
 export interface BatchDeleteRequest {
   domainId: string
@@ -62,6 +185,133 @@ export interface BatchDeleteResult {
 export interface BatchDeleteFailure {
   recordId: string
   reason: string
+  /** 放弃前对服务商的尝试次数（含重试） */
+  attempts?: number
+}
+
+/** 记录定义文件格式 */
+export type RecordImportFormat = "octodns" | "dnscontrol"
+
+/** 记录冲突策略 */
+export type RecordConflictStrategy = "skip" | "overwrite" | "append"
+
+/** 记录导入请求 */
+export interface RecordImportRequest {
+  accountId: string
+  domainId: string
+  format: RecordImportFormat
+  /** 文件内容 */
+  content: string
+  conflictStrategy?: RecordConflictStrategy
+  /** 把不可见字符与同形字符替换为 ASCII（默认含此类字符的记录导入失败） */
+  normalize?: boolean
+}
+
+/** 记录导入预览项 */
+export interface RecordImportPreviewItem {
+  request: CreateDnsRecordRequest
+  /** 与现有记录完全相同（总是跳过） */
+  duplicate: boolean
+  /** 与现有记录同名同类型但值不同 */
+  hasConflict: boolean
+}
+
+/** 记录导入预览 */
+export interface RecordImportPreview {
+  items: RecordImportPreviewItem[]
+  warnings: string[]
+}
+
+/** 记录导入结果 */
+export interface RecordImportResult {
+  createdCount: number
+  updatedCount: number
+  skippedCount: number
+  failures: { name: string; recordType: string; reason: string }[]
+  warnings: string[]
+}
+
+/** zone 文件中被跳过的条目 */
+export interface ZoneFileSkippedEntry {
+  /** 行号（从 1 开始） */
+  line: number
+  content: string
+  reason: string
+}
+
+/** zone 文件导入结果（dryRun 时只包含预览） */
+export interface ZoneFileImportResult {
+  dryRun: boolean
+  records: CreateDnsRecordRequest[]
+  skipped: ZoneFileSkippedEntry[]
+  successCount: number
+  failedCount: number
+  failures: { name: string; recordType: string; reason: string }[]
+  /** 可疑字符提示（规范化时为所做替换） */
+  warnings: string[]
+}
+
+/** 记录校验规则 */
+export type RecordValidationRule =
+  | "ttl"
+  | "ipAddress"
+  | "txtLength"
+  | "mx"
+  | "cnameConflict"
+  | "srvName"
+  | "target"
+  | "soa"
+  | "unsafeCharacter"
+  | "name"
+  | "caa"
+
+/** 可疑字符类别 */
+export type UnsafeCharKind = "invisible" | "fullwidth" | "punctuation" | "homoglyph"
+
+/** 记录中的单个可疑字符 */
+export interface UnsafeCharacter {
+  /** 所在字段（name 或记录内容中的字段名） */
+  field: string
+  /** 字符位置（从 1 开始） */
+  position: number
+  /** 码点，如 U+200B */
+  codepoint: string
+  character: string
+  kind: UnsafeCharKind
+  /** 对应的 ASCII 替换，空字符串表示删除 */
+  replacement: string
+}
+
+/** 现有记录中的可疑字符（扫描结果） */
+export interface RecordCharacterWarning {
+  recordId: string
+  name: string
+  recordType: string
+  characters: UnsafeCharacter[]
+}
+
+/** 记录校验警告（不阻止写入） */
+export interface RecordValidationWarning {
+  rule: RecordValidationRule
+  message: string
+  /** 建议的 TTL（仅 TTL 相关警告） */
+  suggestedTtl?: number
+}
+
+/** 记录校验错误（拒绝写入） */
+export interface RecordValidationError {
+  rule: RecordValidationRule
+  /** 出错的字段（name、ttl、type 或记录内容中的字段名，如 address） */
+  field: string
+  message: string
+  /** 触发错误的字符（仅可疑字符错误） */
+  character?: UnsafeCharacter
+}
+
+/** 记录校验结果 */
+export interface RecordValidationReport {
+  warnings: RecordValidationWarning[]
+  errors: RecordValidationError[]
 }
 
 /** 常用 TTL 选项 */
@@ -0,0 +1,107 @@
+import type { CreateDnsRecordRequest, DnsRecordType, UpdateDnsRecordRequest } from "./dns"
+
+/** 域名组成员 */
+export interface DomainGroupMember {
+  accountId: string
+  domainId: string
+}
+
+/**
+ * 域名组（同一主域在多个账户/服务商下的组合）
+ */
+export interface DomainGroup {
+  id: string
+  name: string
+  members: DomainGroupMember[]
+  /** 创建时间（ISO8601 字符串） */
+  createdAt: string
+  /** 最后修改时间（ISO8601 字符串） */
+  updatedAt: string
+}
+
+/** 创建域名组请求 */
+export interface CreateDomainGroupRequest {
+  name: string
+  members: DomainGroupMember[]
+}
+
+/** 更新域名组请求 */
+export interface UpdateDomainGroupRequest {
+  id: string
+  name?: string
+  members?: DomainGroupMember[]
+}
+
+/** 某一成员在比较行中的值 */
+export interface RecordComparisonCell {
+  /** 规范化后的记录值（已排序） */
+  values: string[]
+  /** 最小的非自动 TTL（全部为自动 TTL 时为空） */
+  ttl?: number
+}
+
+/** 按名称 + 类型对齐的一行 */
+export interface RecordComparisonRow {
+  name: string
+  recordType: DnsRecordType
+  /** 与 members 顺序一致，成员没有该记录时为 null */
+  cells: (RecordComparisonCell | null)[]
+  consistent: boolean
+}
+
+/** 成员拉取状态 */
+export interface DomainGroupMemberStatus {
+  member: DomainGroupMember
+  /** 域名名称（获取失败时为空） */
+  domainName: string
+  recordCount: number
+  /** 拉取失败原因 */
+  error?: string
+}
+
+/** 域名组记录比较结果 */
+export interface DomainGroupComparison {
+  groupId: string
+  members: DomainGroupMemberStatus[]
+  rows: RecordComparisonRow[]
+  inconsistentCount: number
+}
+
+/** 单条记录变更 */
+export type RecordChange =
+  | { action: "create"; request: CreateDnsRecordRequest }
+  | {
+      action: "update"
+      recordId: string
+      /** 变更前的值 */
+      previousValue: string
+      request: UpdateDnsRecordRequest
+    }
+  | {
+      action: "delete"
+      recordId: string
+      name: string
+      recordType: DnsRecordType
+      value: string
+    }
+
+/** 同步到单个目标成员的变更集 */
+export interface DomainGroupChangeset {
+  target: DomainGroupMember
+  changes: RecordChange[]
+}
+
+/** 同步失败项 */
+export interface DomainGroupSyncFailure {
+  target: DomainGroupMember
+  /** 变更描述（如 create www A） */
+  change: string
+  reason: string
+}
+
+/** 同步结果 */
+export interface DomainGroupSyncResult {
+  successCount: number
+  failedCount: number
+  failures: DomainGroupSyncFailure[]
+}
@@ -0,0 +1,82 @@
+import type { SslCheckResult } from "./toolbox"
+
+/** 域名到期提醒档位（距到期天数） */
+export interface DomainExpiryThresholds {
+  criticalDays: number
+  warningDays: number
+  noticeDays: number
+}
+
+/** 域名到期状态 */
+export type DomainExpiryStatus = "unknown" | "ok" | "notice" | "warning" | "critical" | "expired"
+
+/** 托管域名的账号 */
+export interface DomainExpiryAccount {
+  accountId: string
+  accountName: string
+  provider: string
+  domainId: string
+}
+
+/** 单个域名的到期检查结果 */
+export interface DomainExpiryEntry {
+  domainName: string
+  /** 同一域名在多个账号下时全部列出 */
+  accounts: DomainExpiryAccount[]
+  expiresAt?: string
+  daysRemaining?: number
+  status: DomainExpiryStatus
+  /** 无法确定到期时间的原因 */
+  error?: string
+  checkedAt: string
+}
+
+/** 拉取域名列表失败的账号 */
+export interface DomainExpiryAccountError {
+  accountId: string
+  accountName: string
+  error: string
+}
+
+/** 一次域名到期检查的结果 */
+export interface DomainExpiryReport {
+  checkedAt: string
+  thresholds: DomainExpiryThresholds
+  totalDomains: number
+  /** 进入提醒档位或已过期的域名 */
+  expiring: DomainExpiryEntry[]
+  /** 无法确定到期时间的域名 */
+  unknown: DomainExpiryEntry[]
+  /** 相比上次检查进入了更严重档位的域名 */
+  newlyFlagged: DomainExpiryEntry[]
+  failedAccounts: DomainExpiryAccountError[]
+}
+
+/** 即将到期的 SSL 证书 */
+export interface CertificateExpiryEntry {
+  /** 所属域名 */
+  domainName: string
+  /** 检查的主机名（根域名或 www 子域名） */
+  host: string
+  issuer: string
+  validTo: string
+  /** 剩余天数（已过期时为负数） */
+  daysRemaining: number
+  isExpired: boolean
+}
+
+/** 账号证书检查报告 */
+export interface CertificateReport {
+  accountId: string
+  accountName: string
+  checkedAt: string
+  /** 剩余天数少于该值的证书视为即将到期 */
+  warningDays: number
+  totalDomains: number
+  /** 全部检查结果，每个域名依次为根域名与 www 子域名 */
+  results: SslCheckResult[]
+  /** 即将到期或已过期的证书，按剩余天数升序 */
+  expiring: CertificateExpiryEntry[]
+  /** 未取得证书的目标数 */
+  failedCount: number
+}
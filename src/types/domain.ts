@@ -1,7 +1,19 @@
 import type { DomainMetadata } from "./domain-metadata"
 
 /** 域名状态 */
-export type DomainStatus = "active" | "paused" | "pending" | "error" | "unknown"
+export type DomainStatus = "active" | "pending" | "suspended" | "expired" | "moved" | "unknown"
+
+/** 检查任务生成的域名派生状态（到期时间、证书），每项带各自的检查时间 */
+export interface DomainDerivedStatus {
+  accountId: string
+  domainId: string
+  expiresAt?: string
+  expiryDaysRemaining?: number
+  expiryCheckedAt?: string
+  certificateExpiresAt?: string
+  certificateDaysRemaining?: number
+  certificateCheckedAt?: string
+}
 
 /** 域名信息 */
 export interface Domain {
@@ -10,7 +22,10 @@ export interface Domain {
   accountId: string
   provider: string
   status: DomainStatus
+  /** 服务商返回的原始状态 */
+  providerStatusRaw?: string
   recordCount?: number
   createdAt?: string
   metadata?: DomainMetadata
+  derivedStatus?: DomainDerivedStatus
 }
@@ -1,16 +1,27 @@
 export * from "./account"
+export * from "./audit-log"
+export * from "./change-window"
+export * from "./deep-link"
 export * from "./dns"
 export * from "./domain"
+export * from "./domain-group"
 export * from "./domain-metadata"
+export * from "./domain-monitor"
 export * from "./navigation"
 export * from "./provider"
+export * from "./record-copy"
+export * from "./record-set"
+export * from "./system"
 export * from "./toolbox"
+export * from "./write-queue"
 
 /** 通用 API 响应 */
 export interface ApiResponse<T> {
   success: boolean
   data?: T
   error?: ApiError
+  /** 操作成功但需要提示的警告 */
+  warnings?: string[]
 }
 
 /** 后端错误码 */
@@ -19,15 +30,26 @@ export type DnsErrorCode =
   | "AccountNotFound"
   | "DomainNotFound"
   | "RecordNotFound"
+  | "RolloutNotFound"
   | "CredentialError"
   | "CredentialValidation" // 凭证验证错误（结构化）
   | "ApiError"
   | "InvalidCredentials"
+  | "CredentialsExpired" // 凭证（API token）已过期
   | "SerializationError"
   | "ValidationError"
+  | "RECORD_VALIDATION" // 记录内容校验失败，details.field 为出错的字段
   | "ImportExportError"
   | "NoAccountsSelected" // 导出时没有选中账号
   | "UnsupportedFileVersion" // 导入时版本不支持
+  | "NETWORK_ERROR" // 访问外部主机失败（工具箱查询等）
+  | "Timeout" // Provider 调用超时
+  | "CHANGE_WINDOW_CLOSED" // 禁止变更时段内的写操作
+  | "WRITE_QUEUED" // 网络错误，写操作已加入离线写队列
+  | "RESOURCE_BUSY" // 记录或域名正被其他写操作占用
+  | "CAPABILITY_UNAVAILABLE" // 当前平台或权限下无法执行（如路径追踪缺少原始套接字权限）
+  | "EXTERNAL_ACCESS_BLOCKED" // 隐私模式下未经确认访问非必需的第三方端点
+  | "SERVICE_UNAVAILABLE" // 服务商连续网络错误，暂停调用（details 为账户 ID）
   | "Provider" // ProviderError 变体
 
 /** 凭证验证错误详情 */
@@ -43,7 +65,9 @@ export type ProviderErrorCode =
   | "RecordExists"
   | "RecordNotFound"
   | "InvalidParameter"
+  | "UnsupportedCapability"
   | "QuotaExceeded"
+  | "HttpError"
   | "DomainNotFound"
   | "DomainLocked"
   | "PermissionDenied"
@@ -73,7 +97,9 @@ export type ProviderErrorDetails =
       param: string
       detail: string
     }
+  | { code: "UnsupportedCapability"; provider: string; capability: string }
   | { code: "QuotaExceeded"; provider: string; raw_message?: string }
+  | { code: "HttpError"; provider: string; status: number; raw_message?: string }
   | { code: "DomainNotFound"; provider: string; domain: string; raw_message?: string }
   | { code: "DomainLocked"; provider: string; domain: string; raw_message?: string }
   | { code: "PermissionDenied"; provider: string; raw_message?: string }
@@ -94,6 +120,18 @@ export interface ApiError {
     | { provider: string; message: string }
     | ProviderErrorDetails
     | CredentialValidationDetails
+    | { rule_name: string; next_open_at: string }
+    | { field: string; message: string }
+  /** 修复建议（无匹配建议时省略） */
+  suggestions?: ErrorSuggestion[]
+}
+
+/** 错误的修复建议，文案为 i18n 键 */
+export interface ErrorSuggestion {
+  actionKey: string
+  descriptionKey: string
+  /** 相关文档或控制台链接 */
+  link?: string
 }
 
 /** 分页参数 */
@@ -1,24 +1,55 @@
+import type { DnsRecordType } from "./dns"
+
 /** 凭证字段定义 */
 export interface ProviderCredentialField {
   key: string
   label: string
-  type: "text" | "password"
+  type: "text" | "password" | "select" | "textarea"
   placeholder?: string
   helpText?: string
+  /** 格式正则（与后端本地预检同源） */
+  pattern?: string
+  /** 可选项（仅 select 类型使用） */
+  options?: { value: string; label: string }[]
 }
 
 /** 提供商支持的功能 */
 export interface ProviderFeatures {
   /** 是否支持代理功能 (如 Cloudflare 的 CDN 代理) */
   proxy: boolean
+  /** 是否支持权重解析 */
+  weightedRecords: boolean
+  /** 是否支持自动 TTL（TTL 值为 1） */
+  autoTtl: boolean
+  /** 是否允许修改 SOA（多数云服务商不开放） */
+  soaEditing: boolean
+  /** 是否原生使用 record set 模型（同名同类型的多值整组读写） */
+  recordSets: boolean
+  /** 是否原生支持一次提交多条记录变更（批量操作按单批上限分块提交） */
+  batchChanges: boolean
+  /** TXT 记录值提交给服务商的格式（超过 255 字节的值自动分段） */
+  txtFormat: TxtFormat
+  /** 记录列表是否支持服务端排序（不支持时在本地拉取全部记录后排序） */
+  serverSideSort: boolean
 }
 
-/** 提供商分页限制 */
+/** TXT 提交格式：raw = 完整文本由服务商分段，quoted = 带引号的多段字符串 */
+export type TxtFormat = "raw" | "quoted"
+
+/** 提供商限制 */
 export interface ProviderLimits {
   /** 域名列表的最大分页大小 */
   maxPageSizeDomains: number
   /** DNS 记录列表的最大分页大小 */
   maxPageSizeRecords: number
+  /** 最小 TTL（秒） */
+  minTtl: number
+  /** 最大 TTL（秒） */
+  maxTtl: number
+  /** 原生批量接口单次最多提交的变更数（不支持原生批量时为 0） */
+  maxBatchChanges: number
+  /** TXT 记录值的最大总字节数（未知时为 0） */
+  maxTxtLength: number
 }
 
 /** 提供商信息 (从后端获取) */
@@ -27,6 +58,106 @@ export interface ProviderInfo {
   name: string
   description: string
   requiredFields: ProviderCredentialField[]
+  /** 可选凭证字段（留空时使用默认值） */
+  optionalFields?: ProviderCredentialField[]
+  /** 可创建的记录类型（不含 SOA） */
+  recordTypes: DnsRecordType[]
+  features: ProviderFeatures
+  limits: ProviderLimits
+}
+
+/** 运行时探测得到的能力修正（未探测到的字段沿用静态声明） */
+export interface CapabilityProbe {
+  /** 套餐名称（服务商返回的原始名称） */
+  plan?: string
+  proxy?: boolean
+  weightedRecords?: boolean
+  autoTtl?: boolean
+  minTtl?: number
+  maxTtl?: number
+  maxBatchChanges?: number
+}
+
+/** 能力来源：static = 服务商静态声明，probed = 经运行时探测修正 */
+export type CapabilitySource = "static" | "probed"
+
+/** 账户的实际能力（服务商静态声明叠加探测修正） */
+export interface AccountCapabilities {
+  accountId: string
+  provider: string
+  /** 探测到的套餐名称 */
+  plan?: string
+  /** 可创建的记录类型（不含 SOA） */
+  recordTypes: DnsRecordType[]
   features: ProviderFeatures
   limits: ProviderLimits
+  source: CapabilitySource
+  /** 相对静态声明的修正项 */
+  corrections: CapabilityProbe
+  /** 上次探测时间（从未探测时为空） */
+  probedAt?: string
+  /** 上次探测失败的原因 */
+  probeError?: string
+}
+
+/** 连通性检查失败的类别 */
+export type HealthCheckFailure = "network" | "timeout" | "credentials" | "permission" | "provider"
+
+/** 账户服务商 API 的连通性检查结果 */
+export interface ProviderHealthStatus {
+  accountId: string
+  providerType: string
+  /** 服务商 API 是否在超时内响应（凭证或权限被拒绝也算可达） */
+  reachable: boolean
+  latencyMs: number
+  /** 失败原因（凭证失效、网络错误、超时等） */
+  error?: string
+  /** 失败类别，检查成功时为空 */
+  failure?: HealthCheckFailure
+  checkedAt: string
+}
+
+/** 计入用量的服务商接口调用 */
+export type ApiOperation =
+  | "validate_credentials"
+  | "credentials_expiry"
+  | "probe_capabilities"
+  | "list_domains"
+  | "get_domain"
+  | "list_records"
+  | "create_record"
+  | "update_record"
+  | "delete_record"
+  | "weighted_routing"
+  | "get_record_set"
+  | "replace_record_set"
+  | "batch_change"
+
+/** 服务商公开的调用配额（day 由分钟/小时级限流折算） */
+export interface ApiQuotaRule {
+  window: "day" | "month"
+  limit: number
+}
+
+/** 超限风险：unknown = 服务商未公开配额 */
+export type ApiUsageRisk = "unknown" | "normal" | "warning" | "exceeded"
+
+/** 账户某月的 API 用量报告 */
+export interface ApiUsageReport {
+  accountId: string
+  provider: string
+  /** YYYY-MM */
+  month: string
+  /** 每日调用次数（月初到今天或月末） */
+  days: { date: string; count: number }[]
+  /** 按操作的调用次数，按次数倒序 */
+  operations: { operation: ApiOperation; count: number }[]
+  total: number
+  previousMonthTotal: number
+  /** 按日均值推算的本月总数 */
+  forecastTotal: number
+  quota?: ApiQuotaRule
+  /** 按月配额比较预测总数，按天配额比较单日峰值 */
+  quotaUsageRatio?: number
+  risk: ApiUsageRisk
 }
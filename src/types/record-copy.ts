@@ -0,0 +1,48 @@
+import type { DnsRecordType, RecordConflictStrategy } from "./dns"
+import type { RecordChange } from "./domain-group"
+
+/** 记录复制选项 */
+export interface RecordCopyOptions {
+  /** 跳过子域 NS 记录（顶级 NS 与 SOA 总是跳过） */
+  skipNs?: boolean
+  /** 只复制该类型的记录，为空时复制全部 */
+  recordType?: DnsRecordType | null
+  conflictStrategy?: RecordConflictStrategy
+  /** 只生成复制计划 */
+  dryRun?: boolean
+}
+
+/** 跨账户记录复制请求 */
+export interface RecordCopyRequest {
+  sourceAccountId: string
+  sourceDomainId: string
+  targetAccountId: string
+  targetDomainId: string
+  options?: RecordCopyOptions
+}
+
+/** 单条源记录的复制计划及执行结果 */
+export interface RecordCopyItem {
+  sourceRecordId: string
+  /** 相对名称（根域为 @） */
+  name: string
+  recordType: DnsRecordType
+  value: string
+  /** 计划的变更，缺省表示跳过 */
+  change?: RecordChange
+  skipReason?: string
+  /** 为适配目标服务商所做的调整（TTL 范围、代理等） */
+  adjustments?: string[]
+  /** 执行失败原因 */
+  error?: string
+}
+
+/** 记录复制结果（dryRun 时只包含计划） */
+export interface RecordCopyResult {
+  dryRun: boolean
+  items: RecordCopyItem[]
+  createdCount: number
+  updatedCount: number
+  skippedCount: number
+  failedCount: number
+}
@@ -0,0 +1,41 @@
+import type { DnsRecord, DnsRecordType, RecordData } from "./dns"
+import type { RecordChange } from "./domain-group"
+
+/** 记录组（同名同类型的多值记录）详情 */
+export interface RecordSetDetail {
+  domainId: string
+  /** 相对名称（根域为 @） */
+  name: string
+  recordType: DnsRecordType
+  /** 组内各值（原生记录组的各值共用整组的 ID） */
+  records: DnsRecord[]
+  /** 共同 TTL（组为空或 TTL 不一致时为空） */
+  ttl?: number | null
+  ttlMismatch: boolean
+  /** 服务商是否原生使用 record set 模型（整组一次替换） */
+  native: boolean
+}
+
+/** 整组更新请求：values 为期望的值集合，为空时删除整组 */
+export interface UpdateRecordSetRequest {
+  domainId: string
+  name: string
+  recordType: DnsRecordType
+  ttl: number
+  values: RecordData[]
+}
+
+/** 执行失败的单条变更 */
+export interface RecordSetChangeFailure {
+  change: RecordChange
+  reason: string
+}
+
+/** 整组更新结果（逐条执行时遇到第一个失败即停止） */
+export interface UpdateRecordSetResult {
+  native: boolean
+  applied: RecordChange[]
+  failure?: RecordSetChangeFailure | null
+  /** 未执行的变更 */
+  skipped: RecordChange[]
+}
@@ -0,0 +1,39 @@
+/** 后台任务调度方式 */
+export type JobSchedule =
+  | { type: "interval"; intervalSecs: number }
+  | { type: "daily"; hour: number; minute: number } // UTC
+  | { type: "manual" }
+
+/** 并发策略 */
+export type JobConcurrency = "serial" | "parallel"
+
+/** 单次执行结果状态 */
+export type JobRunStatus = "success" | "failed" | "timedOut" | "panicked"
+
+/** 单次执行记录 */
+export interface JobRunRecord {
+  status: JobRunStatus
+  message?: string
+  startedAt: string
+  durationMs: number
+}
+
+/** 后台任务状态 */
+export interface BackgroundJobInfo {
+  name: string
+  schedule: JobSchedule
+  concurrency: JobConcurrency
+  timeoutSecs: number
+  /** 正在执行的实例数 */
+  running: number
+  /** 累计执行次数 */
+  runCount: number
+  lastRun: JobRunRecord | null
+  nextRunAt: string | null
+}
+
+/** Web 后端存储信息（`memory` 表示未配置 `DATABASE_URL`） */
+export interface DatabaseInfo {
+  backend: "memory" | "sqlite" | "postgres" | "mysql"
+  version: string | null
+}
@@ -1,13 +1,27 @@
 /** WHOIS 查询结果 */
 export interface WhoisResult {
   domain: string
+  /** 国际化域名的 Unicode 形式（domain 为 punycode） */
+  unicodeDomain?: string | null
   registrar?: string
+  registrantOrg?: string | null
+  registrantCountry?: string | null
+  registrarAbuseEmail?: string | null
+  registrarAbusePhone?: string | null
   creationDate?: string
   expirationDate?: string
   updatedDate?: string
   nameServers: string[]
   status: string[]
+  /** DNSSEC 状态（注册局原文） */
+  dnssec?: string | null
+  /** 实际查询过的服务器（WHOIS 按转介顺序；RDAP 为服务端点主机名） */
+  queriedServers?: string[]
   raw: string
+  /** 是否来自查询缓存 */
+  cached: boolean
+  /** 实际向服务器查询的时间（ISO 8601） */
+  fetchedAt: string
 }
 
 /** DNS 查询记录 */
@@ -17,16 +31,75 @@ export interface DnsLookupRecord {
   value: string
   ttl: number
   priority?: number
+  /** 结构化数据（CAA/SRV/SOA/NAPTR 记录） */
+  recordData?: DnsLookupRecordData
 }
 
+/** DNS 查询记录的结构化数据（域名不含末尾的点） */
+export type DnsLookupRecordData =
+  | { type: "CAA"; content: { flags: number; tag: string; value: string } }
+  | { type: "SRV"; content: { priority: number; weight: number; port: number; target: string } }
+  | {
+      type: "SOA"
+      content: {
+        mname: string
+        rname: string
+        serial: number
+        refresh: number
+        retry: number
+        expire: number
+        minimum: number
+      }
+    }
+  | {
+      type: "NAPTR"
+      content: {
+        order: number
+        preference: number
+        flags: string
+        services: string
+        regexp: string
+        replacement: string
+      }
+    }
+
+/** DNS 查询传输协议 */
+export const DNS_LOOKUP_TRANSPORTS = ["udp", "tcp", "doh", "dot"] as const
+
+export type DnsLookupTransport = (typeof DNS_LOOKUP_TRANSPORTS)[number]
+
 /** DNS 查询结果（包含 nameserver 信息） */
 export interface DnsLookupResult {
+  /** 查询的域名（punycode 形式） */
+  domain: string
+  /** 国际化域名的 Unicode 形式 */
+  unicodeDomain?: string | null
   /** 使用的 DNS 服务器 */
   nameserver: string
+  /** 实际使用的传输协议 */
+  transport: DnsLookupTransport
   /** 查询记录列表 */
   records: DnsLookupRecord[]
 }
 
+/** PTR 记录 */
+export interface PtrRecord {
+  /** 指向的主机名（不含末尾的点） */
+  hostname: string
+  ttl: number
+}
+
+/** 反向 DNS（PTR）查询结果 */
+export interface ReverseLookupResult {
+  /** 查询的 IP 地址（规范化后） */
+  ip: string
+  /** 反向解析域名（in-addr.arpa / ip6.arpa） */
+  reverseName: string
+  ptrRecords: PtrRecord[]
+  /** 使用的 DNS 服务器 */
+  nameserver: string
+}
+
 /** IP 地理位置信息 */
 export interface IpGeoInfo {
   ip: string
@@ -43,18 +116,82 @@ export interface IpGeoInfo {
   org?: string
   asn?: string
   asName?: string
+  /** 威胁情报标注（未配置情报源时不出现） */
+  threat?: IpThreatInfo
+}
+
+/** IP 威胁情报标注 */
+export interface IpThreatInfo {
+  /** 是否被任一情报源列为恶意地址 */
+  listed: boolean
+  /** 将该地址列为恶意的情报源 */
+  sources: string[]
+  /** 恶意类别（如 SBL 编号、Port Scan） */
+  categories: string[]
+  lastReported?: string
+  /** 本次实际查询到的情报源 */
+  checkedSources: string[]
+}
+
+/** 威胁情报源配置 */
+export interface ThreatIntelConfig {
+  abuseipdbApiKey?: string
+  /** AbuseIPDB 置信度达到该值（0-100）时视为恶意 */
+  abuseipdbMinConfidence: number
+  /** AbuseIPDB 每日最多请求次数 */
+  abuseipdbDailyLimit: number
+  /** API 查询结果缓存时间（秒） */
+  cacheTtlSecs: number
+  /** 是否启用 Spamhaus DROP 列表 */
+  spamhausDrop: boolean
+}
+
+/** 隐私模式配置 */
+export interface PrivacyConfig {
+  /** 开启后非必需的第三方外发需要逐次确认，后台外发直接禁用 */
+  privacyMode: boolean
+}
+
+/** 第三方端点在当前配置下的访问状态 */
+export type ExternalEndpointStatus = "allowed" | "requiresConfirmation" | "disabled"
+
+/** 工具可能访问的第三方端点 */
+export interface ExternalEndpoint {
+  /** 端点标识（如 `ip.geolocation`） */
+  id: string
+  /** 声明该端点的功能模块 */
+  module: string
+  /** 访问的地址（URL、主机名或 `IP:端口`） */
+  targets: string[]
+  /** 用途 */
+  purpose: string
+  /** 请求中是否携带查询目标 */
+  sendsQueryTarget: boolean
+  /** 是否为必需外发（不受隐私模式影响） */
+  essential: boolean
+  status: ExternalEndpointStatus
 }
 
 /** IP 查询结果（支持域名解析多个 IP） */
 export interface IpLookupResult {
-  /** 查询的原始输入（IP 或域名） */
+  /** 查询的 IP 或域名（国际化域名为 punycode 形式） */
   query: string
+  /** 国际化域名的 Unicode 形式 */
+  unicodeDomain?: string | null
   /** 是否为域名查询 */
   isDomain: boolean
   /** IP 地理位置结果列表 */
   results: IpGeoInfo[]
 }
 
+/** 批量 IP 查询中单个条目的结果 */
+export interface IpLookupBatchEntry {
+  /** 条目对应各地址的地理位置（域名、CIDR 可能有多个；部分地址失败时只含成功项） */
+  results: IpGeoInfo[]
+  /** 条目整体失败的原因 */
+  error?: string | null
+}
+
 /** SSL 证书信息 */
 export interface SslCertInfo {
   domain: string
@@ -69,6 +206,45 @@ export interface SslCertInfo {
   serialNumber: string
   signatureAlgorithm: string
   certificateChain: CertChainItem[]
+  /** 协商的 TLS 版本（如 "TLS 1.3"） */
+  tlsVersion?: string
+  /** 协商的密码套件 */
+  negotiatedCipher?: string
+  /** 是否仍接受已弃用的 TLS 1.0 */
+  supportsTls10: boolean
+  /** 是否仍接受已弃用的 TLS 1.1 */
+  supportsTls11: boolean
+  /** 逐版本协议探测结果（仅在请求逐版本探测时存在） */
+  protocolProbe?: TlsProtocolInfo | null
+  /** OCSP 吊销状态（后端未启用 OCSP 检查时为空） */
+  ocspStatus?: OcspStatus
+  /** 服务器是否装订了 OCSP 响应 */
+  ocspStapled: boolean
+}
+
+/** 单个 TLS 版本的探测结果（unknown：连接失败或超时） */
+export type TlsVersionSupport = "accepted" | "rejected" | "unknown"
+
+/** 逐版本的 TLS 协议探测结果 */
+export interface TlsProtocolInfo {
+  tls10: TlsVersionSupport
+  tls11: TlsVersionSupport
+  tls12: TlsVersionSupport
+  tls13: TlsVersionSupport
+}
+
+/** 证书的 OCSP 吊销状态 */
+export type OcspStatus =
+  | { status: "good" }
+  | { status: "revoked"; reason: string; revocationTime: string }
+  | { status: "unknown" }
+  | { status: "notChecked" }
+
+/** 批量 SSL 检查的目标 */
+export interface SslCheckTarget {
+  domain: string
+  /** 端口，默认 443 */
+  port?: number
 }
 
 /** 证书链项 */
@@ -76,14 +252,24 @@ export interface CertChainItem {
   subject: string
   issuer: string
   isCa: boolean
+  /** 有效期起始（RFC 2822） */
+  validFrom: string
+  /** 有效期截止（RFC 2822） */
+  validTo: string
+  /** 剩余天数（已过期时为负数） */
+  daysRemaining: number
 }
 
 /** SSL 检查结果（包含连接状态） */
 export interface SslCheckResult {
   /** 查询的域名 */
   domain: string
+  /** 国际化域名的 Unicode 形式（domain 为 punycode） */
+  unicodeDomain?: string | null
   /** 检查的端口 */
   port: number
+  /** 实际连接的地址（IP:端口，TCP 连接失败时为空） */
+  connectedAddr?: string | null
   /** 连接状态: "https" | "http" | "failed" */
   connectionStatus: "https" | "http" | "failed"
   /** 证书信息（仅当 HTTPS 连接成功时存在） */
@@ -108,6 +294,10 @@ export interface HttpHeaderCheckRequest {
   customHeaders: HttpHeader[]
   body?: string
   contentType?: string
+  /** 是否跟随重定向，默认 false */
+  followRedirects?: boolean
+  /** 最多跟随的重定向次数（默认 5，不超过 20） */
+  maxRedirects?: number
 }
 
 /** 安全头分析结果 */
@@ -115,8 +305,23 @@ export interface SecurityHeaderAnalysis {
   name: string
   present: boolean
   value?: string
-  status: "good" | "warning" | "missing"
-  recommendation?: string
+  /** 严重程度（取各项问题中最严重的） */
+  severity: SecuritySeverity
+  /** 发现的具体问题或建议 */
+  findings: string[]
+}
+
+/** 安全头问题的严重程度 */
+export type SecuritySeverity = "ok" | "warning" | "error"
+
+/** 安全头评分 */
+export interface SecurityScore {
+  /** 等级（A–F） */
+  overallGrade: string
+  /** 得分（0–100） */
+  score: number
+  /** 参与评分的安全头满分之和 */
+  maxPossible: number
 }
 
 /** HTTP 头检查结果 */
@@ -127,9 +332,96 @@ export interface HttpHeaderCheckResult {
   responseTimeMs: number
   headers: HttpHeader[]
   securityAnalysis: SecurityHeaderAnalysis[]
+  securityScore: SecurityScore
   contentLength?: number
   rawRequest: string
   rawResponse: string
+  /** 最终响应的 URL */
+  finalUrl: string
+  /** 各跳（含最终响应），未跟随重定向时为空 */
+  redirectChain: HttpHop[]
+  redirectLimitReached: boolean
+  loopDetected: boolean
+}
+
+/** 重定向链中的一跳 */
+export interface HttpHop {
+  url: string
+  statusCode: number
+  /** 原样保留的 Location 头，可能为相对地址 */
+  locationHeader?: string
+  responseTimeMs: number
+  /** HTTPS 跳的证书信息 */
+  tlsInfo?: SslCertInfo
+}
+
+/** 重定向链跟踪结果 */
+export interface HttpTraceResult {
+  url: string
+  hops: HttpHop[]
+  finalUrl: string
+  redirectLimitReached: boolean
+  loopDetected: boolean
+  /** 跟踪中途失败的原因 */
+  error?: string
+}
+
+/** 端口检查的协议 */
+export type PortProtocol = "tcp" | "udp"
+
+/** 端口状态：filtered = 超时或不可达（UDP 无响应也可能是开放端口） */
+export type PortState = "open" | "closed" | "filtered"
+
+/** 单个端口的检查结果 */
+export interface PortStatus {
+  port: number
+  state: PortState
+  /** 建立连接（TCP）或收到响应（UDP）耗时（毫秒，端口未开放时为空） */
+  responseTimeMs?: number | null
+  /** TCP 连接后服务器主动发送的欢迎信息（最多 64 字节） */
+  banner?: string | null
+}
+
+/** 端口连通性检查结果 */
+export interface PortCheckResult {
+  host: string
+  /** 实际探测的地址（主机解析结果中的第一个） */
+  address: string
+  protocol: PortProtocol
+  /** 按请求顺序排列（已去重） */
+  results: PortStatus[]
+}
+
+/** 路径追踪的探测方式：icmp = 原始 ICMP，tcp = TCP 443 连接（中间跳无地址） */
+export type TraceMethod = "icmp" | "tcp"
+
+/** 路径中的一跳 */
+export interface TraceHop {
+  ttl: number
+  /** 响应的地址（无响应或 TCP 回退的中间跳为空） */
+  ip?: string | null
+  /** 反向解析得到的主机名 */
+  hostname?: string | null
+  sent: number
+  received: number
+  /** 丢包率（0-100%） */
+  lossRate: number
+  minMs?: number | null
+  avgMs?: number | null
+  maxMs?: number | null
+  /** 返回了目标不可达，追踪在此终止 */
+  unreachable: boolean
+}
+
+/** 路径追踪结果 */
+export interface TraceRouteResult {
+  host: string
+  targetIp: string
+  method: TraceMethod
+  /** 回退到 TCP 的原因 */
+  fallbackReason?: string | null
+  hops: TraceHop[]
+  reached: boolean
 }
 
 /** 查询历史项 */
@@ -152,6 +444,7 @@ export const DNS_RECORD_TYPES = [
   "SOA",
   "SRV",
   "CAA",
+  "NAPTR",
   "PTR",
   "ALL",
 ] as const
@@ -183,6 +476,34 @@ export interface DnsPropagationResult {
   totalTimeMs: number
   consistencyPercentage: number
   uniqueValues: string[]
+  /** 多数服务器返回的应答 */
+  consensusValue?: string
+  /** 返回多数应答的服务器数 */
+  propagatedCount: number
+  /** 查询的服务器总数 */
+  totalCount: number
+}
+
+/** 单个解析器的期望值传播检查结果 */
+export interface PropagationResolverResult {
+  server: DnsPropagationServer
+  values: string[]
+  /** 是否解析到期望值（任一值匹配即可） */
+  matches: boolean
+  responseTimeMs: number
+  error?: string
+}
+
+/** 期望值传播检查结果 */
+export interface PropagationCheckResult {
+  domain: string
+  recordType: string
+  expectedValue: string
+  results: PropagationResolverResult[]
+  /** 已解析到期望值的解析器数 */
+  matchedCount: number
+  totalCount: number
+  totalTimeMs: number
 }
 
 /** DNSSEC DNSKEY 记录 */
@@ -232,3 +553,213 @@ export interface DnssecResult {
   responseTimeMs: number
   error?: string
 }
+
+/** SPF 限定符 */
+export type SpfQualifier = "pass" | "fail" | "softfail" | "neutral"
+
+/** SPF 机制 */
+export interface SpfMechanism {
+  qualifier: SpfQualifier
+  /** all | include | a | mx | ptr | ip4 | ip6 | exists */
+  mechanism: string
+  value?: string
+}
+
+/** SPF include / redirect 展开结果 */
+export interface SpfInclude {
+  domain: string
+  /** 嵌套深度（根记录直接引用为 1） */
+  depth: number
+  raw?: string
+  error?: string
+}
+
+/** SPF 记录 */
+export interface SpfRecord {
+  raw: string
+  mechanisms: SpfMechanism[]
+  all?: SpfQualifier
+  redirect?: string
+  includes: SpfInclude[]
+  /** 需要 DNS 查询的机制数量（上限 10） */
+  dnsLookupCount: number
+}
+
+/** DMARC 记录 */
+export interface DmarcRecord {
+  raw: string
+  policy: "none" | "quarantine" | "reject"
+  subdomainPolicy?: string
+  percentage: number
+  rua: string[]
+  ruf: string[]
+  adkim?: string
+  aspf?: string
+}
+
+/** DKIM 记录 */
+export interface DkimRecord {
+  selector: string
+  raw: string
+  keyType: string
+  /** p= 为空表示密钥已撤销 */
+  hasPublicKey: boolean
+  /** RSA 密钥长度（估算） */
+  keyBits?: number
+  testing: boolean
+}
+
+/** 邮件安全检查结果 */
+export interface EmailSecurityResult {
+  domain: string
+  spf?: SpfRecord
+  dmarc?: DmarcRecord
+  /** 未指定 selector 时不检查 */
+  dkim?: DkimRecord
+  /** 0-100：SPF 35、DMARC 40、DKIM 25 */
+  score: number
+  issues: string[]
+}
+
+/** SMTP 端口探测结果（单个地址） */
+export interface SmtpProbe {
+  address: string
+  connected: boolean
+  /** 欢迎消息首行，正常应以 220 开头 */
+  banner?: string
+  connectTimeMs?: number
+  error?: string
+}
+
+/** 单个 MX 主机的检查结果 */
+export interface MxHostCheck {
+  host: string
+  preference: number
+  addresses: string[]
+  probes: SmtpProbe[]
+  /** 主机名解析失败的原因 */
+  error?: string
+}
+
+/** 邮件策略记录（SPF / DMARC）检查结果 */
+export interface MailPolicyCheck {
+  present: boolean
+  /** 唯一一条且没有无法识别的内容 */
+  valid: boolean
+  raw?: string
+  issues: string[]
+}
+
+/** 邮件服务健康检查结果 */
+export interface MailCheckResult {
+  domain: string
+  /** 按优先级排序 */
+  mxHosts: MxHostCheck[]
+  /** 声明不接收邮件（RFC 7505） */
+  nullMx: boolean
+  spf: MailPolicyCheck
+  dmarc: MailPolicyCheck
+  issues: string[]
+}
+
+/** 解析后的 CAA 记录 */
+export interface ParsedCaaRecord {
+  /** 关键标志：CA 不认识该标签时必须拒绝签发 */
+  critical: boolean
+  tag: string
+  value: string
+  /** issue / issuewild 授权的 CA，值为 ";" 时为 null（禁止签发） */
+  issuer: string | null
+  parameters: string[]
+}
+
+/** CAA 检查结果 */
+export interface CaaCheckResult {
+  domain: string
+  /** 找到 CAA 记录的域名（自身或最近的上级域名），未找到时为空 */
+  effectiveDomain: string
+  records: ParsedCaaRecord[]
+  /** 是否限制了证书签发 */
+  isProtected: boolean
+  authorizedCas: string[]
+  /** 未配置 issuewild 时沿用 issue */
+  wildcardCas: string[]
+  iodef: string[]
+  contactEmails: string[]
+  issues: string[]
+}
+
+/** DNS 基准测试进度 */
+export interface DnsBenchmarkProgress {
+  completed: number
+  total: number
+  nameserver: string
+}
+
+/** 延迟分布统计（毫秒） */
+export interface LatencyStats {
+  min: number | null
+  p50: number | null
+  p95: number | null
+  max: number | null
+}
+
+/** 单个 DNS 服务器的基准测试结果 */
+export interface DnsBenchmarkServerResult {
+  nameserver: string
+  /** 首次查询耗时（可能未命中服务器缓存） */
+  coldMs: number | null
+  /** 后续查询耗时样本（箱线图数据） */
+  samplesMs: number[]
+  stats: LatencyStats
+  sent: number
+  timeouts: number
+  errors: number
+  /** 丢包（超时）率 0-100 */
+  lossRate: number
+  ednsSupported: boolean | null
+  tcpSupported: boolean | null
+  error: string | null
+}
+
+/** DNS 基准测试结果 */
+export interface DnsBenchmarkResult {
+  domain: string
+  iterations: number
+  results: DnsBenchmarkServerResult[]
+  totalTimeMs: number
+  cancelled: boolean
+  timeLimited: boolean
+}
+
+/** DNS 劫持检测 */
+export interface DnsHijackCheck {
+  /** 用于探测的随机域名（理论上不存在） */
+  probeDomain: string
+  /** 是否检测到劫持（探测失败时为 null） */
+  hijacked: boolean | null
+  /** 不存在的域名竟然返回的应答 */
+  answers: string[]
+  error: string | null
+}
+
+/** 系统代理设置摘要（来自环境变量，凭证已脱敏） */
+export interface ProxySettings {
+  httpProxy: string | null
+  httpsProxy: string | null
+  allProxy: string | null
+  noProxy: string | null
+}
+
+/** 本机网络环境信息 */
+export interface NetworkEnvInfo {
+  systemDnsServers: string[]
+  /** DNS 配置来源（无法区分 DHCP/手动/VPN 推送时为 "system"） */
+  dnsSource: string
+  dnsHijack: DnsHijackCheck
+  /** 默认出口 IPv4（探测失败或隐私模式下为 null） */
+  publicIpv4: string | null
+  publicIpv6: string | null
+  proxy: ProxySettings
+  elapsedMs: number
+}
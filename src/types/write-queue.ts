@@ -0,0 +1,50 @@
+import type { CreateDnsRecordRequest, DnsRecord, UpdateDnsRecordRequest } from "./dns"
+
+/** 离线写队列配置 */
+export interface WriteQueueConfig {
+  /** 是否启用（默认关闭） */
+  enabled: boolean
+  /** 队列最多保存的写操作数（1-1000） */
+  maxEntries: number
+  /** 入队后的有效期（小时，1-168） */
+  ttlHours: number
+}
+
+/** 排队的写操作（保留原始请求） */
+export type QueuedWriteOperation =
+  | { type: "create"; request: CreateDnsRecordRequest }
+  | { type: "update"; recordId: string; request: UpdateDnsRecordRequest }
+  | { type: "delete"; recordId: string }
+
+/** 因网络错误进入离线写队列的写操作 */
+export interface QueuedWrite {
+  id: string
+  accountId: string
+  domainId: string
+  operation: QueuedWriteOperation
+  /** 入队时目标记录的快照（创建时为空；离线时通常无法获取） */
+  before?: DnsRecord
+  /** 入队时的网络错误 */
+  error: string
+  queuedAt: string
+  expiresAt: string
+}
+
+/** 单个写操作的重放结果 */
+export type WriteReplayStatus =
+  | "applied"
+  | "alreadyApplied"
+  | "conflict"
+  | "failed"
+  | "expired"
+  | "notFound"
+
+/** 重放结果 */
+export interface WriteReplayOutcome {
+  id: string
+  status: WriteReplayStatus
+  /** 冲突或失败原因 */
+  message?: string
+  /** 目标记录的当前状态（冲突时供对比） */
+  current?: DnsRecord
+}